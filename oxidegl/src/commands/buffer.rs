@@ -1,13 +1,15 @@
-use core::{ffi::c_void, fmt::Debug, ptr::NonNull};
-
-use objc2::rc::Retained;
-use objc2_foundation::NSString;
-use objc2_metal::{MTLBuffer, MTLDevice, MTLResource, MTLResourceOptions};
+use core::{ffi::c_void, fmt::Debug, ptr::NonNull, slice};
 
 use crate::{
-    context::Context,
-    conversions::{MaybeIndex, NoIndex},
-    debug::gl_debug,
+    context::{
+        Context,
+        state::{
+            MAX_ATOMIC_COUNTER_BUFFER_BINDINGS, MAX_SHADER_STORAGE_BUFFER_BINDINGS,
+            MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS, MAX_UNIFORM_BUFFER_BINDINGS,
+        },
+    },
+    conversions::{MaybeIndex, NoIndex, sizei},
+    debug::{gl_debug, gl_warn},
     error::{GlError, GlFallible, gl_assert},
     gl_enums::{
         BufferAccess, BufferStorageMask, BufferStorageTarget, BufferTarget, BufferUsage,
@@ -15,7 +17,8 @@ use crate::{
     },
     gl_object::{LateInit, NamedObject, ObjectName},
     gl_types::{GLboolean, GLintptr, GLsizei, GLsizeiptr, GLuint, GLvoid},
-    util::{ProtoObjRef, debug_unreachable},
+    render::BufferStorage,
+    util::debug_unreachable,
 };
 //TODO move logical components out of this file, should be ffi only
 
@@ -297,7 +300,13 @@ impl Context {
         index: GLuint,
         buffer: GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_bind_buffer_base not yet implemented");
+        self.bind_buffer_indexed(
+            target,
+            index,
+            ObjectName::try_from_raw(buffer).ok(),
+            None,
+            true,
+        )
     }
     /// ### Parameters
     /// `target`
@@ -352,7 +361,16 @@ impl Context {
         offset: GLintptr,
         size: GLsizeiptr,
     ) -> GlFallible {
-        panic!("command oxidegl_bind_buffer_range not yet implemented");
+        let name = ObjectName::try_from_raw(buffer).ok();
+        // the range is ignored when unbinding
+        let range = if name.is_some() {
+            gl_assert!(offset >= 0 && size > 0, InvalidValue);
+            #[expect(clippy::cast_sign_loss, reason = "checked above")]
+            Some((offset as usize, size as usize))
+        } else {
+            None
+        };
+        self.bind_buffer_indexed(target, index, name, range, true)
     }
     /// ### Parameters
     /// `target`
@@ -401,7 +419,17 @@ impl Context {
         count: GLsizei,
         buffers: *const GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_bind_buffers_base not yet implemented");
+        sizei!(count);
+        // a null array unbinds all of the bindings
+        let buffers = (!buffers.is_null()).then(|| {
+            // Safety: Caller ensures buffers points to count buffer names if it isn't null
+            unsafe { slice::from_raw_parts(buffers, count as usize) }
+        });
+        for (i, index) in (0..count as usize).zip(first..) {
+            let name = buffers.and_then(|b| ObjectName::try_from_raw(b[i]).ok());
+            self.bind_buffer_indexed(target, index, name, None, false)?;
+        }
+        Ok(())
     }
     /// ### Parameters
     /// `target`
@@ -465,7 +493,34 @@ impl Context {
         offsets: *const GLintptr,
         sizes: *const GLsizeiptr,
     ) -> GlFallible {
-        panic!("command oxidegl_bind_buffers_range not yet implemented");
+        sizei!(count);
+        // a null array unbinds all of the bindings, along with their ranges
+        let bindings = (!buffers.is_null()).then(|| {
+            // Safety: Caller ensures buffers, offsets and sizes point to count elements if buffers isn't null
+            unsafe {
+                (
+                    slice::from_raw_parts(buffers, count as usize),
+                    slice::from_raw_parts(offsets, count as usize),
+                    slice::from_raw_parts(sizes, count as usize),
+                )
+            }
+        });
+        for (i, index) in (0..count as usize).zip(first..) {
+            let (name, range) = match bindings {
+                Some((buffers, offsets, sizes)) => {
+                    let name = ObjectName::try_from_raw(buffers[i]).ok();
+                    if name.is_some() {
+                        gl_assert!(offsets[i] >= 0 && sizes[i] > 0, InvalidValue);
+                    }
+                    #[expect(clippy::cast_sign_loss, reason = "checked above")]
+                    let range = name.map(|_| (offsets[i] as usize, sizes[i] as usize));
+                    (name, range)
+                }
+                None => (None, None),
+            };
+            self.bind_buffer_indexed(target, index, name, range, false)?;
+        }
+        Ok(())
     }
     /// ### Parameters
     /// `buffer`
@@ -710,6 +765,7 @@ impl Context {
     ) -> GlFallible {
         // a bound buffer might have just gained its storage
         self.update_encoder();
+        self.upload_uniforms();
 
         let buf = self
             .gl_state
@@ -734,30 +790,13 @@ impl Context {
         }
         #[allow(clippy::cast_sign_loss)]
         let size = size as usize;
-        // TODO: lower-coherence storage modes (StorageModeManaged or single-upload StorageModePrivate).
-        // Shared backing buffers are going to annihilate perf with larger buffers
-        let options = MTLResourceOptions::StorageModeShared;
-
         let maybe_ptr = NonNull::new(data.cast_mut());
 
-        let buffer;
-        if let Some(ptr) = maybe_ptr {
-            // Safety: caller ensures pointer validity, and that the slice implicitly formed by (data, size) is correctly initialized
-            buffer = unsafe {
-                self.renderer
-                    .device
-                    .newBufferWithBytes_length_options(ptr, size, options)
-            };
-        } else {
-            buffer = self
-                .renderer
-                .device
-                .newBufferWithLength_options(size, options);
-        }
-        let buffer = buffer.expect("Metal Buffer allocation failiure");
+        // Safety: caller ensures pointer validity, and that the slice implicitly formed by (data, size) is correctly initialized
+        let storage = unsafe { self.renderer.backend.new_buffer_storage(size, maybe_ptr) };
         buf.allocation = Some(RealizedBufferInternal {
             mapping: None,
-            mtl: buffer,
+            storage,
         });
         Ok(())
     }
@@ -770,18 +809,31 @@ impl Context {
         target: BufferTarget,
         idx: I,
     ) -> &mut Option<ObjectName<Buffer>> {
-        match target {
-            BufferTarget::UniformBuffer => &mut self.gl_state.buffer_bindings.uniform[idx.get()],
-            BufferTarget::AtomicCounterBuffer => {
-                &mut self.gl_state.buffer_bindings.atomic_counter[idx.get()]
+        match (target, idx.get_opt()) {
+            (BufferTarget::UniformBuffer, Some(i)) => &mut self.gl_state.buffer_bindings.uniform[i],
+            (BufferTarget::UniformBuffer, None) => {
+                &mut self.gl_state.buffer_bindings.generic_uniform
+            }
+            (BufferTarget::AtomicCounterBuffer, Some(i)) => {
+                &mut self.gl_state.buffer_bindings.atomic_counter[i]
+            }
+            (BufferTarget::AtomicCounterBuffer, None) => {
+                &mut self.gl_state.buffer_bindings.generic_atomic_counter
+            }
+            (BufferTarget::ShaderStorageBuffer, Some(i)) => {
+                &mut self.gl_state.buffer_bindings.shader_storage[i]
             }
-            BufferTarget::ShaderStorageBuffer => {
-                &mut self.gl_state.buffer_bindings.shader_storage[idx.get()]
+            (BufferTarget::ShaderStorageBuffer, None) => {
+                &mut self.gl_state.buffer_bindings.generic_shader_storage
             }
-            BufferTarget::TransformFeedbackBuffer => {
-                &mut self.gl_state.buffer_bindings.transform_feedback[idx.get()]
+            // the indexed transform feedback bindings belong to the current transform feedback object
+            (BufferTarget::TransformFeedbackBuffer, Some(i)) => {
+                &mut self.gl_state.transform_feedback_mut().buffers[i]
             }
-            t => {
+            (BufferTarget::TransformFeedbackBuffer, None) => {
+                &mut self.gl_state.buffer_bindings.transform_feedback
+            }
+            (t, _) => {
                 debug_assert!(
                     idx.get_opt().is_none(),
                     "UB: Tried to bind at an index of a non-indexed binding target"
@@ -829,6 +881,60 @@ impl Context {
         gl_debug!("bound buffer {to_bind:?} to target {target:?} at index {idx:?}");
         Ok(())
     }
+    /// Bind a buffer (or a range of it, as an offset and size in bytes) to an indexed binding of `target`, and to its
+    /// generic binding if `set_generic` is set
+    pub(crate) fn bind_buffer_indexed(
+        &mut self,
+        target: BufferTarget,
+        index: GLuint,
+        to_bind: Option<ObjectName<Buffer>>,
+        range: Option<(usize, usize)>,
+        set_generic: bool,
+    ) -> GlFallible {
+        let max = match target {
+            BufferTarget::UniformBuffer => MAX_UNIFORM_BUFFER_BINDINGS,
+            BufferTarget::AtomicCounterBuffer => MAX_ATOMIC_COUNTER_BUFFER_BINDINGS,
+            BufferTarget::ShaderStorageBuffer => MAX_SHADER_STORAGE_BUFFER_BINDINGS,
+            BufferTarget::TransformFeedbackBuffer => MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS,
+            _ => return Err(GlError::InvalidEnum.e()),
+        };
+        gl_assert!((index as usize) < max, InvalidValue);
+        if target == BufferTarget::TransformFeedbackBuffer {
+            gl_assert!(
+                self.gl_state.transform_feedback().active.is_none(),
+                InvalidOperation,
+                "tried to change a transform feedback buffer binding while transform feedback is active"
+            );
+            // captured components are written as whole words
+            gl_assert!(
+                range.is_none_or(|(offset, size)| offset % 4 == 0 && size % 4 == 0),
+                InvalidValue
+            );
+        }
+        if target == BufferTarget::AtomicCounterBuffer {
+            // counters are words, so the ranges they are read from have to start on one
+            gl_assert!(
+                range.is_none_or(|(offset, _)| offset % 4 == 0),
+                InvalidValue
+            );
+        }
+        self.bind_buffer_internal(to_bind, target, index)?;
+        if set_generic {
+            self.bind_buffer_internal(to_bind, target, NoIndex)?;
+        }
+        if target == BufferTarget::TransformFeedbackBuffer {
+            self.gl_state.transform_feedback_mut().ranges[index as usize] = range;
+        } else if target == BufferTarget::AtomicCounterBuffer {
+            self.gl_state.buffer_bindings.atomic_counter_ranges[index as usize] = range;
+            // atomic counter buffers are bound along with the default uniform blocks
+            self.upload_uniforms();
+        } else if range.is_some() {
+            gl_warn!(
+                "ranges of {target:?} bindings are not supported yet, binding the whole buffer instead"
+            );
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -856,7 +962,7 @@ pub(crate) struct Buffer {
 #[derive(Debug)]
 pub(crate) struct RealizedBufferInternal {
     pub mapping: Option<MappingInfo>,
-    pub mtl: ProtoObjRef<dyn MTLBuffer>,
+    pub storage: BufferStorage,
 }
 impl Buffer {
     // fn get_best_storage_mode_for_access_hint(access: BufferAccess, usage_hint: BufferUsage) -> MTLStorageMode {
@@ -910,16 +1016,12 @@ pub struct MappingInfo {
 impl NamedObject for Buffer {
     type LateInitType = LateInit<Self>;
     const LATE_INIT_FUNC: fn(ObjectName<Self>) -> Self = Self::new_default;
-    fn set_debug_label(
-        ctx: &mut Context,
-        name: ObjectName<Self>,
-        label: Option<Retained<NSString>>,
-    ) {
+    fn set_debug_label(ctx: &mut Context, name: ObjectName<Self>, label: Option<&str>) {
         ctx.gl_state
             .buffer_list
             .get(name)
             .allocation
             .as_ref()
-            .inspect(|&a| a.mtl.setLabel(label.as_deref()));
+            .inspect(|&a| a.storage.set_label(label));
     }
 }
@@ -275,7 +275,7 @@ impl Context {
             EnableCap::StencilTest => todo!(),
             EnableCap::Dither => todo!(),
             EnableCap::Blend => todo!(),
-            EnableCap::ScissorTest => todo!(),
+            EnableCap::ScissorTest => (Dirty::UPDATE_RENDER_ENCODER, Capabilities::SCISSOR_TEST),
             EnableCap::Texture1D => todo!(),
             EnableCap::Texture2D => todo!(),
             EnableCap::ColorLogicOp => todo!(),
@@ -287,19 +287,21 @@ impl Context {
             EnableCap::SampleAlphaToOne => todo!(),
             EnableCap::SampleCoverage => todo!(),
             EnableCap::TextureCubeMap => todo!(),
-            EnableCap::ClipDistance0 => todo!(),
-            EnableCap::ClipDistance1 => todo!(),
-            EnableCap::ClipDistance2 => todo!(),
-            EnableCap::ClipDistance3 => todo!(),
-            EnableCap::ClipDistance4 => todo!(),
-            EnableCap::ClipDistance5 => todo!(),
-            EnableCap::ClipDistance6 => todo!(),
-            EnableCap::ClipDistance7 => todo!(),
+            EnableCap::ClipDistance0 => (Dirty::NEW_RENDER_PIPELINE, Capabilities::CLIP_DISTANCE0),
+            EnableCap::ClipDistance1 => (Dirty::NEW_RENDER_PIPELINE, Capabilities::CLIP_DISTANCE1),
+            EnableCap::ClipDistance2 => (Dirty::NEW_RENDER_PIPELINE, Capabilities::CLIP_DISTANCE2),
+            EnableCap::ClipDistance3 => (Dirty::NEW_RENDER_PIPELINE, Capabilities::CLIP_DISTANCE3),
+            EnableCap::ClipDistance4 => (Dirty::NEW_RENDER_PIPELINE, Capabilities::CLIP_DISTANCE4),
+            EnableCap::ClipDistance5 => (Dirty::NEW_RENDER_PIPELINE, Capabilities::CLIP_DISTANCE5),
+            EnableCap::ClipDistance6 => (Dirty::NEW_RENDER_PIPELINE, Capabilities::CLIP_DISTANCE6),
+            EnableCap::ClipDistance7 => (Dirty::NEW_RENDER_PIPELINE, Capabilities::CLIP_DISTANCE7),
             EnableCap::RasterizerDiscard => todo!(),
             EnableCap::FramebufferSrgb => todo!(),
             EnableCap::TextureRectangle => todo!(),
             EnableCap::PrimitiveRestart => todo!(),
-            EnableCap::ProgramPointSize => (Dirty::empty(), Capabilities::PROGRAM_POINT_SIZE),
+            EnableCap::ProgramPointSize => {
+                (Dirty::NEW_RENDER_PIPELINE, Capabilities::PROGRAM_POINT_SIZE)
+            }
             EnableCap::DepthClamp => todo!(),
             EnableCap::TextureCubeMapSeamless => todo!(),
             EnableCap::SampleMask => todo!(),
@@ -0,0 +1,506 @@
+use crate::{
+    context::Context,
+    debug::{gl_debug, gl_err},
+    error::{GlError, GlFallible, gl_assert},
+    gl_enums::{MemoryBarrierMask, ShaderType},
+    gl_types::{GLintptr, GLuint},
+    program::compute::MAX_COMPUTE_WORK_GROUP_COUNT,
+    render::ComputeDispatch,
+};
+
+impl Context {
+    /// ### Parameters
+    /// `num_groups_x`
+    ///
+    /// > The number of work groups to be launched in the X dimension.
+    ///
+    /// `num_groups_y`
+    ///
+    /// > The number of work groups to be launched in the Y dimension.
+    ///
+    /// `num_groups_z`
+    ///
+    /// > The number of work groups to be launched in the Z dimension.
+    ///
+    /// ### Description
+    /// [**glDispatchCompute**](crate::context::Context::oxidegl_dispatch_compute)
+    /// launches one or more compute work groups. Each work group is processed
+    /// by the active program object for the compute shader stage. While the individual
+    /// shader invocations within a work group are executed as a unit, work groups
+    /// are executed completely independently and in unspecified order. `num_groups_x`,
+    /// `num_groups_y` and `num_groups_z` specify the number of local work groups
+    /// that will be dispatched in the X, Y and Z dimensions, respectively.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_COMPUTE_WORK_GROUP_COUNT`](crate::gl_enums::GL_MAX_COMPUTE_WORK_GROUP_COUNT)
+    pub fn oxidegl_dispatch_compute(
+        &mut self,
+        num_groups_x: GLuint,
+        num_groups_y: GLuint,
+        num_groups_z: GLuint,
+    ) -> GlFallible {
+        let groups = [num_groups_x, num_groups_y, num_groups_z];
+        gl_assert!(
+            groups
+                .iter()
+                .zip(MAX_COMPUTE_WORK_GROUP_COUNT)
+                .all(|(&n, max)| n <= max),
+            InvalidValue,
+            "tried to dispatch {groups:?} work groups, but at most {MAX_COMPUTE_WORK_GROUP_COUNT:?} can be dispatched"
+        );
+        self.validate_compute_program()?;
+        if groups.contains(&0) {
+            gl_debug!("skipping dispatch of {groups:?} work groups");
+            return Ok(());
+        }
+        let Context {
+            gl_state: state,
+            renderer,
+        } = self;
+        renderer.dispatch_compute(state, &ComputeDispatch::Direct { groups });
+        Ok(())
+    }
+    /// ### Parameters
+    /// `indirect`
+    ///
+    /// > The offset into the buffer object currently bound to the [`GL_DISPATCH_INDIRECT_BUFFER`](crate::gl_enums::GL_DISPATCH_INDIRECT_BUFFER)
+    /// > buffer target at which the dispatch parameters are stored.
+    ///
+    /// ### Description
+    /// [**glDispatchComputeIndirect**](crate::context::Context::oxidegl_dispatch_compute_indirect)
+    /// launches one or more compute work groups using parameters stored in the
+    /// buffer object currently bound to the [`GL_DISPATCH_INDIRECT_BUFFER`](crate::gl_enums::GL_DISPATCH_INDIRECT_BUFFER)
+    /// target. Each work group is processed by the active program object for the
+    /// compute shader stage. While the individual shader invocations within a
+    /// work group are executed as a unit, work groups are executed completely
+    /// independently and in unspecified order. `indirect` contains the offset
+    /// into the data store of the buffer object bound to the [`GL_DISPATCH_INDIRECT_BUFFER`](crate::gl_enums::GL_DISPATCH_INDIRECT_BUFFER)
+    /// target at which the parameters are stored.
+    ///
+    /// The parameters addressed by `indirect` are packed a structure, which takes
+    /// the form (in C):
+    ///
+    /// A call to [**glDispatchComputeIndirect**](crate::context::Context::oxidegl_dispatch_compute_indirect)
+    /// is equivalent, assuming no errors are generated, to:
+    ///
+    /// Unlike [**glDispatchCompute**](crate::context::Context::oxidegl_dispatch_compute),
+    /// no error is generated if any of the [`GL_MAX_COMPUTE_WORK_GROUP_COUNT`](crate::gl_enums::GL_MAX_COMPUTE_WORK_GROUP_COUNT)
+    /// for the corresponding dimension. In such circumstances, behavior is undefined
+    /// and may lead to application termination.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_COMPUTE_WORK_GROUP_COUNT`](crate::gl_enums::GL_MAX_COMPUTE_WORK_GROUP_COUNT)
+    pub fn oxidegl_dispatch_compute_indirect(&mut self, indirect: GLintptr) -> GlFallible {
+        gl_assert!(
+            indirect >= 0 && indirect % 4 == 0,
+            InvalidValue,
+            "indirect dispatch offset must be a non-negative multiple of 4"
+        );
+        self.validate_compute_program()?;
+        let Some(buffer) = self.gl_state.buffer_bindings.dispatch_indirect else {
+            gl_err!(ty: Error, "tried to dispatch indirectly without a buffer bound to GL_DISPATCH_INDIRECT_BUFFER");
+            return Err(GlError::InvalidOperation.e());
+        };
+        #[expect(clippy::cast_sign_loss, reason = "checked above")]
+        let offset = indirect as usize;
+        let size = self.gl_state.buffer_list.get(buffer).size;
+        // the parameters are 3 GLuints
+        gl_assert!(
+            offset.checked_add(12).is_some_and(|end| end <= size),
+            InvalidOperation,
+            "indirect dispatch parameters at offset {offset} exceed the size of {buffer:?} ({size} bytes)"
+        );
+        let Context {
+            gl_state: state,
+            renderer,
+        } = self;
+        renderer.dispatch_compute(
+            state,
+            &ComputeDispatch::Indirect {
+                buffer: buffer.to_raw(),
+                offset,
+            },
+        );
+        Ok(())
+    }
+    /// Check that the current program or program pipeline has an executable for the compute stage, as required by the
+    /// dispatch commands
+    fn validate_compute_program(&mut self) -> GlFallible {
+        self.gl_state.validate_current_pipeline()?;
+        let linked = self
+            .gl_state
+            .stage_program(ShaderType::ComputeShader)
+            .and_then(|name| self.gl_state.program_list.get_mut(name).linkage())
+            .is_some_and(|l| l.compute.is_some());
+        gl_assert!(
+            linked,
+            InvalidOperation,
+            "tried to dispatch without an active program for the compute stage"
+        );
+        Ok(())
+    }
+}
+
+/// ### Parameters
+/// `barriers`
+///
+/// > Specifies the barriers to insert.
+///
+/// > For [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier),
+/// > must be a bitwise combination of any of [`GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT`](crate::gl_enums::GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT),
+/// > [`GL_ELEMENT_ARRAY_BARRIER_BIT`](crate::gl_enums::GL_ELEMENT_ARRAY_BARRIER_BIT),
+/// > [`GL_UNIFORM_BARRIER_BIT`](crate::gl_enums::GL_UNIFORM_BARRIER_BIT), [`GL_TEXTURE_FETCH_BARRIER_BIT`](crate::gl_enums::GL_TEXTURE_FETCH_BARRIER_BIT),
+/// > [`GL_SHADER_IMAGE_ACCESS_BARRIER_BIT`](crate::gl_enums::GL_SHADER_IMAGE_ACCESS_BARRIER_BIT),
+/// > [`GL_COMMAND_BARRIER_BIT`](crate::gl_enums::GL_COMMAND_BARRIER_BIT), [`GL_PIXEL_BUFFER_BARRIER_BIT`](crate::gl_enums::GL_PIXEL_BUFFER_BARRIER_BIT),
+/// > [`GL_TEXTURE_UPDATE_BARRIER_BIT`](crate::gl_enums::GL_TEXTURE_UPDATE_BARRIER_BIT),
+/// > [`GL_BUFFER_UPDATE_BARRIER_BIT`](crate::gl_enums::GL_BUFFER_UPDATE_BARRIER_BIT),
+/// > [`GL_FRAMEBUFFER_BARRIER_BIT`](crate::gl_enums::GL_FRAMEBUFFER_BARRIER_BIT),
+/// > [`GL_TRANSFORM_FEEDBACK_BARRIER_BIT`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BARRIER_BIT),
+/// > [`GL_ATOMIC_COUNTER_BARRIER_BIT`](crate::gl_enums::GL_ATOMIC_COUNTER_BARRIER_BIT),
+/// > or [`GL_SHADER_STORAGE_BARRIER_BIT`](crate::gl_enums::GL_SHADER_STORAGE_BARRIER_BIT).
+///
+/// > For [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier),
+/// > must be a bitwise combination of any of [`GL_ATOMIC_COUNTER_BARRIER_BIT`](crate::gl_enums::GL_ATOMIC_COUNTER_BARRIER_BIT),
+/// > or [`GL_FRAMEBUFFER_BARRIER_BIT`](crate::gl_enums::GL_FRAMEBUFFER_BARRIER_BIT),
+/// > [`GL_SHADER_IMAGE_ACCESS_BARRIER_BIT`](crate::gl_enums::GL_SHADER_IMAGE_ACCESS_BARRIER_BIT),
+/// > [`GL_SHADER_STORAGE_BARRIER_BIT`](crate::gl_enums::GL_SHADER_STORAGE_BARRIER_BIT).
+/// > [`GL_TEXTURE_FETCH_BARRIER_BIT`](crate::gl_enums::GL_TEXTURE_FETCH_BARRIER_BIT),
+/// > or [`GL_UNIFORM_BARRIER_BIT`](crate::gl_enums::GL_UNIFORM_BARRIER_BIT).
+///
+/// > If the special value [`GL_ALL_BARRIER_BITS`](crate::gl_enums::GL_ALL_BARRIER_BITS)
+/// > is specified, all supported barriers for the corresponding command will
+/// > be inserted.
+///
+/// ### Description
+/// [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier)
+/// defines a barrier ordering the memory transactions issued prior to the
+/// command relative to those issued after the barrier. For the purposes of
+/// this ordering, memory transactions performed by shaders are considered
+/// to be issued by the rendering command that triggered the execution of the
+/// shader. `barriers` is a bitfield indicating the set of operations that
+/// are synchronized with shader stores; the bits used in `barriers` are as
+/// follows:
+///
+/// [`GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT`](crate::gl_enums::GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT)
+///
+/// > If set, vertex data sourced from buffer objects after the barrier will
+/// > reflect data written by shaders prior to the barrier. The set of buffer
+/// > objects affected by this bit is derived from the buffer object bindings
+/// > used for generic vertex attributes derived from the [`GL_VERTEX_ATTRIB_ARRAY_BUFFER`](crate::gl_enums::GL_VERTEX_ATTRIB_ARRAY_BUFFER)
+/// > bindings.
+///
+/// [`GL_ELEMENT_ARRAY_BARRIER_BIT`](crate::gl_enums::GL_ELEMENT_ARRAY_BARRIER_BIT)
+///
+/// > If set, vertex array indices sourced from buffer objects after the barrier
+/// > will reflect data written by shaders prior to the barrier. The buffer objects
+/// > affected by this bit are derived from the [`GL_ELEMENT_ARRAY_BUFFER`](crate::gl_enums::GL_ELEMENT_ARRAY_BUFFER)
+/// > binding.
+///
+/// [`GL_UNIFORM_BARRIER_BIT`](crate::gl_enums::GL_UNIFORM_BARRIER_BIT)
+///
+/// > Shader uniforms sourced from buffer objects after the barrier will reflect
+/// > data written by shaders prior to the barrier.
+///
+/// [`GL_TEXTURE_FETCH_BARRIER_BIT`](crate::gl_enums::GL_TEXTURE_FETCH_BARRIER_BIT)
+///
+/// > Texture fetches from shaders, including fetches from buffer object memory
+/// > via buffer textures, after the barrier will reflect data written by shaders
+/// > prior to the barrier.
+///
+/// [`GL_SHADER_IMAGE_ACCESS_BARRIER_BIT`](crate::gl_enums::GL_SHADER_IMAGE_ACCESS_BARRIER_BIT)
+///
+/// > Memory accesses using shader image load, store, and atomic built-in functions
+/// > issued after the barrier will reflect data written by shaders prior to
+/// > the barrier. Additionally, image stores and atomics issued after the barrier
+/// > will not execute until all memory accesses (e.g., loads, stores, texture
+/// > fetches, vertex fetches) initiated prior to the barrier complete.
+///
+/// [`GL_COMMAND_BARRIER_BIT`](crate::gl_enums::GL_COMMAND_BARRIER_BIT)
+///
+/// > Command data sourced from buffer objects by Draw*Indirect commands after
+/// > the barrier will reflect data written by shaders prior to the barrier.
+/// > The buffer objects affected by this bit are derived from the [`GL_DRAW_INDIRECT_BUFFER`](crate::gl_enums::GL_DRAW_INDIRECT_BUFFER)
+/// > binding.
+///
+/// [`GL_PIXEL_BUFFER_BARRIER_BIT`](crate::gl_enums::GL_PIXEL_BUFFER_BARRIER_BIT)
+///
+/// > Reads and writes of buffer objects via the [`GL_PIXEL_PACK_BUFFER`](crate::gl_enums::GL_PIXEL_PACK_BUFFER)
+/// > and [`GL_PIXEL_UNPACK_BUFFER`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER)
+/// > bindings (via [**glReadPixels**](crate::context::Context::oxidegl_read_pixels),
+/// > [**glTexSubImage1D**](crate::context::Context::oxidegl_tex_sub_image1_d),
+/// > etc.) after the barrier will reflect data written by shaders prior to
+/// > the barrier. Additionally, buffer object writes issued after the barrier
+/// > will wait on the completion of all shader writes initiated prior to the
+/// > barrier.
+///
+/// [`GL_TEXTURE_UPDATE_BARRIER_BIT`](crate::gl_enums::GL_TEXTURE_UPDATE_BARRIER_BIT)
+///
+/// > Writes to a texture via [**glTex(Sub)Image***](crate::context::Context::oxidegl_tex(_sub)_image*),
+/// > [**glCopyTex(Sub)Image***](crate::context::Context::oxidegl_copy_tex(_sub)_image*),
+/// > [**glCompressedTex(Sub)Image***](crate::context::Context::oxidegl_compressed_tex(_sub)_image*),
+/// > and reads via [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+/// > after the barrier will reflect data written by shaders prior to the barrier.
+/// > Additionally, texture writes from these commands issued after the barrier
+/// > will not execute until all shader writes initiated prior to the barrier
+/// > complete.
+///
+/// [`GL_BUFFER_UPDATE_BARRIER_BIT`](crate::gl_enums::GL_BUFFER_UPDATE_BARRIER_BIT)
+///
+/// > Reads or writes via [**glBufferSubData**](crate::context::Context::oxidegl_buffer_sub_data),
+/// > [**glCopyBufferSubData**](crate::context::Context::oxidegl_copy_buffer_sub_data),
+/// > or [**glGetBufferSubData**](crate::context::Context::oxidegl_get_buffer_sub_data),
+/// > or to buffer object memory mapped by [**glMapBuffer**](crate::context::Context::oxidegl_map_buffer)
+/// > or [**glMapBufferRange**](crate::context::Context::oxidegl_map_buffer_range)
+/// > after the barrier will reflect data written by shaders prior to the barrier.
+/// > Additionally, writes via these commands issued after the barrier will wait
+/// > on the completion of any shader writes to the same memory initiated prior
+/// > to the barrier.
+///
+/// [`GL_CLIENT_MAPPED_BUFFER_BARRIER_BIT`](crate::gl_enums::GL_CLIENT_MAPPED_BUFFER_BARRIER_BIT)
+///
+/// > Access by the client to persistent mapped regions of buffer objects will
+/// > reflect data written by shaders prior to the barrier. Note that this may
+/// > cause additional synchronization operations.
+///
+/// [`GL_FRAMEBUFFER_BARRIER_BIT`](crate::gl_enums::GL_FRAMEBUFFER_BARRIER_BIT)
+///
+/// > Reads and writes via framebuffer object attachments after the barrier will
+/// > reflect data written by shaders prior to the barrier. Additionally, framebuffer
+/// > writes issued after the barrier will wait on the completion of all shader
+/// > writes issued prior to the barrier.
+///
+/// [`GL_TRANSFORM_FEEDBACK_BARRIER_BIT`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BARRIER_BIT)
+///
+/// > Writes via transform feedback bindings after the barrier will reflect data
+/// > written by shaders prior to the barrier. Additionally, transform feedback
+/// > writes issued after the barrier will wait on the completion of all shader
+/// > writes issued prior to the barrier.
+///
+/// [`GL_ATOMIC_COUNTER_BARRIER_BIT`](crate::gl_enums::GL_ATOMIC_COUNTER_BARRIER_BIT)
+///
+/// > Accesses to atomic counters after the barrier will reflect writes prior
+/// > to the barrier.
+///
+/// [`GL_SHADER_STORAGE_BARRIER_BIT`](crate::gl_enums::GL_SHADER_STORAGE_BARRIER_BIT)
+///
+/// > Accesses to shader storage blocks after the barrier will reflect writes
+/// > prior to the barrier.
+///
+/// [`GL_QUERY_BUFFER_BARRIER_BIT`](crate::gl_enums::GL_QUERY_BUFFER_BARRIER_BIT)
+///
+/// > Writes of buffer objects via the [`GL_QUERY_BUFFER`](crate::gl_enums::GL_QUERY_BUFFER)
+/// > binding after the barrier will reflect data written by shaders prior to
+/// > the barrier. Additionally, buffer object writes issued after the barrier
+/// > will wait on the completion of all shader writes initiated prior to the
+/// > barrier.
+///
+///
+/// If `barriers` is [`GL_ALL_BARRIER_BITS`](crate::gl_enums::GL_ALL_BARRIER_BITS),
+/// shader memory accesses will be synchronized relative to all the operations
+/// described above.
+///
+/// Implementations may cache buffer object and texture image memory that could
+/// be written by shaders in multiple caches; for example, there may be separate
+/// caches for texture, vertex fetching, and one or more caches for shader
+/// memory accesses. Implementations are not required to keep these caches
+/// coherent with shader memory writes. Stores issued by one invocation may
+/// not be immediately observable by other pipeline stages or other shader
+/// invocations because the value stored may remain in a cache local to the
+/// processor executing the store, or because data overwritten by the store
+/// is still in a cache elsewhere in the system. When [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier)
+/// is called, the GL flushes and/or invalidates any caches relevant to the
+/// operations specified by the `barriers` parameter to ensure consistent ordering
+/// of operations across the barrier.
+///
+/// To allow for independent shader invocations to communicate by reads and
+/// writes to a common memory address, image variables in the OpenGL Shading
+/// Language may be declared as "coherent". Buffer object or texture image
+/// memory accessed through such variables may be cached only if caches are
+/// automatically updated due to stores issued by any other shader invocation.
+/// If the same address is accessed using both coherent and non-coherent variables,
+/// the accesses using variables declared as coherent will observe the results
+/// stored using coherent variables in other invocations. Using variables declared
+/// as "coherent" guarantees only that the results of stores will be immediately
+/// visible to shader invocations using similarly-declared variables; calling
+/// [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier)
+/// is required to ensure that the stores are visible to other operations.
+///
+/// The following guidelines may be helpful in choosing when to use coherent
+/// memory accesses and when to use barriers.
+///
+/// > Data that are read-only or constant may be accessed without using coherent
+/// > variables or calling [**MemoryBarrier**](crate::context::Context::oxide_memory_barrier)
+/// > (). Updates to the read-only data via API calls such as [**glBufferSubData**](crate::context::Context::oxidegl_buffer_sub_data)
+/// > will invalidate shader caches implicitly as required.
+///
+/// > Data that are shared between shader invocations at a fine granularity (e.g.,
+/// > written by one invocation, consumed by another invocation) should use coherent
+/// > variables to read and write the shared data.
+///
+/// > Data written by one shader invocation and consumed by other shader invocations
+/// > launched as a result of its execution ("dependent invocations") should
+/// > use coherent variables in the producing shader invocation and call [**memoryBarrier**](crate::context::Context::oxidememory_barrier)
+/// > () after the last write. The consuming shader invocation should also use
+/// > coherent variables.
+///
+/// > Data written to image variables in one rendering pass and read by the shader
+/// > in a later pass need not use coherent variables or memoryBarrier(). Calling
+/// > [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier)
+/// > with the SHADER_IMAGE_ACCESS_BARRIER_BIT set in `barriers` between passes
+/// > is necessary.
+///
+/// > Data written by the shader in one rendering pass and read by another mechanism
+/// > (e.g., vertex or index buffer pulling) in a later pass need not use coherent
+/// > variables or [**memoryBarrier**](crate::context::Context::oxidememory_barrier)
+/// > (). Calling [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier)
+/// > with the appropriate bits set in `barriers` between passes is necessary.
+///
+///
+/// ### Notes
+/// [`GL_SHADER_STORAGE_BARRIER_BIT`](crate::gl_enums::GL_SHADER_STORAGE_BARRIER_BIT)
+/// is available only if the GL version is 4.3 or higher.
+///
+/// [`GL_QUERY_BUFFER_BARRIER_BIT`](crate::gl_enums::GL_QUERY_BUFFER_BARRIER_BIT)
+/// is available only if the GL version is 4.4 or higher.
+impl Context {
+    pub fn oxidegl_memory_barrier(&mut self, barriers: MemoryBarrierMask) -> GlFallible {
+        gl_debug!("inserting memory barrier for {barriers:?}");
+        self.renderer.memory_barrier(barriers);
+        Ok(())
+    }
+    pub fn oxidegl_memory_barrier_by_region(&mut self, barriers: MemoryBarrierMask) -> GlFallible {
+        let by_region = MemoryBarrierMask::ATOMIC_COUNTER_BARRIER_BIT
+            | MemoryBarrierMask::FRAMEBUFFER_BARRIER_BIT
+            | MemoryBarrierMask::SHADER_IMAGE_ACCESS_BARRIER_BIT
+            | MemoryBarrierMask::SHADER_STORAGE_BARRIER_BIT
+            | MemoryBarrierMask::TEXTURE_FETCH_BARRIER_BIT
+            | MemoryBarrierMask::UNIFORM_BARRIER_BIT;
+        gl_assert!(
+            barriers == MemoryBarrierMask::ALL_BARRIER_BITS || by_region.contains(barriers),
+            InvalidValue,
+            "{barriers:?} contains barriers that can't be limited to a region"
+        );
+        // a barrier for the whole framebuffer also covers every region of it
+        self.oxidegl_memory_barrier(barriers)
+    }
+}
+
+#[test]
+fn headless_compute_dispatch() {
+    use crate::{
+        gl_enums::{BufferStorageMask, BufferTarget, ProgramProperty},
+        render::{BufferArgument, DEFAULT_UNIFORM_BLOCK_INDEX, headless::RecordedCommand},
+        test_util::{compile, link},
+    };
+
+    let mut ctx = Context::new_headless(8, 8);
+    let src = "#version 430 core
+        layout(local_size_x = 8, local_size_y = 4) in;
+        layout(std430, binding = 1) buffer Data { uint values[]; };
+        layout(location = 0) uniform uint scale;
+        void main() {
+            uint i = gl_GlobalInvocationID.y * gl_NumWorkGroups.x * 8u + gl_GlobalInvocationID.x;
+            values[i] *= scale;
+        }";
+    let shader = compile(&mut ctx, ShaderType::ComputeShader, src);
+    let program = link(&mut ctx, &[shader]);
+    let mut size = [0; 3];
+    // Safety: params points to 3 GLints
+    unsafe {
+        ctx.oxidegl_get_programiv(
+            program,
+            ProgramProperty::ComputeWorkGroupSize,
+            size.as_mut_ptr(),
+        )
+    }
+    .unwrap();
+    assert_eq!(size, [8, 4, 1]);
+
+    // dispatches need a program with a compute stage, and are limited in size
+    assert!(ctx.oxidegl_dispatch_compute(1, 1, 1).is_err());
+    ctx.oxidegl_use_program(program).unwrap();
+    assert!(ctx.oxidegl_dispatch_compute(65536, 1, 1).is_err());
+
+    let params: [u32; 3] = [2, 1, 1];
+    let mut buffers = [0; 2];
+    // Safety: pointers are valid for the duration of the calls
+    unsafe {
+        ctx.oxidegl_create_buffers(2, buffers.as_mut_ptr());
+        ctx.oxidegl_named_buffer_storage(
+            buffers[0],
+            256,
+            core::ptr::null(),
+            BufferStorageMask::empty(),
+        )
+        .unwrap();
+        ctx.oxidegl_named_buffer_storage(
+            buffers[1],
+            12,
+            params.as_ptr().cast(),
+            BufferStorageMask::empty(),
+        )
+        .unwrap();
+    }
+    ctx.oxidegl_bind_buffer_base(BufferTarget::ShaderStorageBuffer, 1, buffers[0])
+        .unwrap();
+    ctx.oxidegl_uniform1ui(0, 3).unwrap();
+    ctx.oxidegl_dispatch_compute(4, 2, 1).unwrap();
+    // empty dispatches are skipped
+    ctx.oxidegl_dispatch_compute(0, 2, 1).unwrap();
+    ctx.oxidegl_memory_barrier(MemoryBarrierMask::SHADER_STORAGE_BARRIER_BIT)
+        .unwrap();
+    assert!(ctx.oxidegl_dispatch_compute_indirect(0).is_err());
+    ctx.oxidegl_bind_buffer(BufferTarget::DispatchIndirectBuffer, buffers[1])
+        .unwrap();
+    assert!(ctx.oxidegl_dispatch_compute_indirect(2).is_err());
+    assert!(ctx.oxidegl_dispatch_compute_indirect(4).is_err());
+    ctx.oxidegl_dispatch_compute_indirect(0).unwrap();
+
+    let cmds = ctx.recorded_commands().unwrap();
+    let start = cmds
+        .iter()
+        .position(|c| *c == RecordedCommand::NewComputeEncoder)
+        .unwrap();
+    let cmds = &cmds[start..];
+    let RecordedCommand::NewComputePipeline(desc) = &cmds[1] else {
+        panic!("expected a new compute pipeline, got {:?}", cmds[1]);
+    };
+    assert_eq!(desc.threads_per_threadgroup, [8, 4, 1]);
+    let bind = RecordedCommand::BindBuffer(BufferArgument {
+        stage: ShaderType::ComputeShader,
+        buffer: buffers[0],
+        offset: 0,
+        index: 1,
+    });
+    // the default block is padded, but starts with the uniform
+    let is_uniforms = |c: &RecordedCommand| {
+        matches!(c, RecordedCommand::SetBytes { stage: ShaderType::ComputeShader, index, bytes }
+            if *index == DEFAULT_UNIFORM_BLOCK_INDEX && bytes[..4] == 3u32.to_ne_bytes())
+    };
+    // the encoder and pipeline are reused by the second dispatch, the buffers and uniforms are bound again
+    assert_eq!(cmds.len(), 10);
+    assert_eq!(cmds[2], RecordedCommand::BindComputePipeline);
+    for i in [3, 7] {
+        assert_eq!(cmds[i], bind);
+        assert!(
+            is_uniforms(&cmds[i + 1]),
+            "expected uniforms, got {:?}",
+            cmds[i + 1]
+        );
+    }
+    assert_eq!(
+        cmds[5],
+        RecordedCommand::Dispatch(ComputeDispatch::Direct { groups: [4, 2, 1] })
+    );
+    assert_eq!(
+        cmds[6],
+        RecordedCommand::MemoryBarrier(MemoryBarrierMask::SHADER_STORAGE_BARRIER_BIT)
+    );
+    assert_eq!(
+        cmds[9],
+        RecordedCommand::Dispatch(ComputeDispatch::Indirect {
+            buffer: buffers[1],
+            offset: 0
+        })
+    );
+}
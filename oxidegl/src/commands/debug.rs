@@ -1,17 +1,18 @@
-use objc2_foundation::NSString;
-
 use crate::{
     commands::buffer::Buffer,
     context::Context,
     conversions::sizei,
-    debug::{gl_warn, with_debug_state, with_debug_state_mut},
-    error::{GlFallible, gl_assert},
+    debug::{DebugMessageMeta, gl_err, gl_warn, with_debug_state, with_debug_state_mut},
+    error::{GlError, GlFallible, gl_assert},
     framebuffer::Framebuffer,
     gl_enums::{DebugSeverity, DebugSource, DebugType, GetPointervPName, ObjectIdentifier},
     gl_object::ObjectName,
     gl_types::{GLDEBUGPROC, GLboolean, GLchar, GLsizei, GLuint, GLvoid},
     program::Program,
+    program_pipeline::ProgramPipeline,
+    query::Query,
     shader::Shader,
+    transform_feedback::TransformFeedback,
     vao::Vao,
 };
 use core::slice;
@@ -96,11 +97,15 @@ impl Context {
             ObjectIdentifier::Shader => state.set_label(self, name.cast::<Shader>(), label),
             ObjectIdentifier::Program => state.set_label(self, name.cast::<Program>(), label),
             ObjectIdentifier::VertexArray => state.set_label(self, name.cast::<Vao>(), label),
-            ObjectIdentifier::ProgramPipeline => todo!(),
+            ObjectIdentifier::ProgramPipeline => {
+                state.set_label(self, name.cast::<ProgramPipeline>(), label);
+            }
             ObjectIdentifier::Texture => todo!(),
             ObjectIdentifier::Renderbuffer => todo!(),
-            ObjectIdentifier::TransformFeedback => todo!(),
-            ObjectIdentifier::Query => todo!(),
+            ObjectIdentifier::TransformFeedback => {
+                state.set_label(self, name.cast::<TransformFeedback>(), label);
+            }
+            ObjectIdentifier::Query => state.set_label(self, name.cast::<Query>(), label),
             ObjectIdentifier::Sampler => todo!(),
         });
         Ok(())
@@ -134,8 +139,10 @@ impl Context {
         ptr: *const GLvoid,
         length: GLsizei,
         label: *const GLchar,
-    ) {
-        panic!("command oxidegl_object_ptr_label not yet implemented");
+    ) -> GlFallible {
+        // sync objects are the only objects named by a pointer, and there aren't any yet
+        gl_err!(ty: Error, "{ptr:?} is not the name of a sync object");
+        Err(GlError::InvalidValue.e())
     }
 
     /// ### Parameters
@@ -200,11 +207,13 @@ impl Context {
             ObjectIdentifier::Shader => state.get_label(name.cast::<Shader>()),
             ObjectIdentifier::Program => state.get_label(name.cast::<Program>()),
             ObjectIdentifier::VertexArray => state.get_label(name.cast::<Vao>()),
-            ObjectIdentifier::ProgramPipeline => todo!(),
+            ObjectIdentifier::ProgramPipeline => state.get_label(name.cast::<ProgramPipeline>()),
             ObjectIdentifier::Texture => todo!(),
             ObjectIdentifier::Renderbuffer => todo!(),
-            ObjectIdentifier::TransformFeedback => todo!(),
-            ObjectIdentifier::Query => todo!(),
+            ObjectIdentifier::TransformFeedback => {
+                state.get_label(name.cast::<TransformFeedback>())
+            }
+            ObjectIdentifier::Query => state.get_label(name.cast::<Query>()),
             ObjectIdentifier::Sampler => todo!(),
         })
         .flatten();
@@ -312,8 +321,10 @@ impl Context {
         buf_size: GLsizei,
         length: *mut GLsizei,
         label: *mut GLchar,
-    ) {
-        panic!("command oxidegl_get_object_ptr_label not yet implemented");
+    ) -> GlFallible {
+        // sync objects are the only objects named by a pointer, and there aren't any yet
+        gl_err!(ty: Error, "{ptr:?} is not the name of a sync object");
+        Err(GlError::InvalidValue.e())
     }
     /// ### Parameters
     /// `pname`
@@ -574,8 +585,29 @@ impl Context {
         severity: DebugSeverity,
         length: GLsizei,
         buf: *const GLchar,
-    ) {
-        panic!("command oxidegl_debug_message_insert not yet implemented");
+    ) -> GlFallible {
+        gl_assert!(
+            matches!(
+                source,
+                DebugSource::DebugSourceApplication | DebugSource::DebugSourceThirdParty
+            ),
+            InvalidEnum,
+            "the other debug sources are reserved for the GL"
+        );
+        gl_assert!(
+            r#type != DebugType::DontCare && severity != DebugSeverity::DontCare,
+            InvalidEnum
+        );
+        let meta = DebugMessageMeta {
+            src: source,
+            ty: r#type,
+            sev: severity,
+            id,
+        };
+        // Safety: caller ensures buf and length describe a valid string
+        with_debug_state_mut(|mut state| unsafe { state.insert_message(meta, length, buf) })
+            .expect("no debug state");
+        Ok(())
     }
     /// ### Parameters
     /// `count`
@@ -729,9 +761,8 @@ impl Context {
         let s = with_debug_state_mut(|s| s.debug_groups.last().unwrap().message.clone())
             .expect("no debug state");
 
-        self.renderer.push_debug_group(&NSString::from_str(
-            s.to_str().expect("non utf-8 debug group name"),
-        ));
+        self.renderer
+            .push_debug_group(s.to_str().expect("non utf-8 debug group name"));
         Ok(())
     }
     /// ### Description
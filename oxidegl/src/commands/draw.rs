@@ -1,9 +1,13 @@
-use objc2_metal::{MTLPrimitiveType, MTLRenderCommandEncoder};
+use std::{array, ptr, slice};
 
 use crate::{
     context::Context,
-    gl_enums::{DrawElementsType, PrimitiveType},
-    gl_types::{GLint, GLsizei, GLuint, GLvoid},
+    debug::gl_err,
+    error::{GlError, GlFallible, gl_assert},
+    gl_enums::{DrawElementsType, PatchParameterName, PrimitiveType, ShaderType},
+    gl_types::{GLfloat, GLint, GLsizei, GLuint, GLvoid},
+    program::{feedback, geometry::MAX_VERTEX_STREAMS, tessellation::MAX_PATCH_VERTICES},
+    render::DrawCall,
 };
 
 impl Context {
@@ -50,28 +54,13 @@ impl Context {
     /// [`GL_TRIANGLE_STRIP_ADJACENCY`](crate::gl_enums::GL_TRIANGLE_STRIP_ADJACENCY)
     /// and [`GL_TRIANGLES_ADJACENCY`](crate::gl_enums::GL_TRIANGLES_ADJACENCY) are
     /// available only if the GL version is 3.2 or greater.
-    pub fn oxidegl_draw_arrays(&mut self, mode: PrimitiveType, first: GLint, count: GLsizei) {
-        //FIXME hack, move to module
-        let Context {
-            gl_state: state,
-            renderer: platform_state,
-        } = self;
-        platform_state.update_state(state, true);
-        #[expect(
-            clippy::cast_sign_loss,
-            reason = "OpenGL is a state of the art 3D graphics API"
-        )]
-        // Safety: todo
-        unsafe {
-            platform_state
-                .current_render_encoder()
-                .drawPrimitives_vertexStart_vertexCount(
-                    MTLPrimitiveType::Triangle,
-                    first as usize,
-                    count as usize,
-                );
-        };
-        //panic!("command oxidegl_draw_arrays not yet implemented");
+    pub fn oxidegl_draw_arrays(
+        &mut self,
+        mode: PrimitiveType,
+        first: GLint,
+        count: GLsizei,
+    ) -> GlFallible {
+        self.draw_arrays_internal(mode, first, count, 1, 0)
     }
     /// ### Parameters
     /// `mode`
@@ -119,12 +108,23 @@ impl Context {
     /// GL less than 4.2, this parameter is present but is reserved and should
     /// be set to zero. On earlier versions of the GL, behavior is undefined if
     /// it is non-zero.
+    #[expect(
+        clippy::cast_possible_wrap,
+        reason = "out of range parameters are undefined behavior in GL"
+    )]
     pub unsafe fn oxidegl_draw_arrays_indirect(
         &mut self,
         mode: PrimitiveType,
         indirect: *const GLvoid,
-    ) {
-        panic!("command oxidegl_draw_arrays_indirect not yet implemented");
+    ) -> GlFallible {
+        let [count, instancecount, first, baseinstance] = self.indirect_draw_params(indirect)?;
+        self.draw_arrays_internal(
+            mode,
+            first as GLint,
+            count as GLsizei,
+            instancecount as GLsizei,
+            baseinstance,
+        )
     }
 
     /// ### Parameters
@@ -167,8 +167,8 @@ impl Context {
         first: GLint,
         count: GLsizei,
         instancecount: GLsizei,
-    ) {
-        panic!("command oxidegl_draw_arrays_instanced not yet implemented");
+    ) -> GlFallible {
+        self.draw_arrays_internal(mode, first, count, instancecount, 0)
     }
     /// ### Parameters
     /// `mode`
@@ -222,8 +222,8 @@ impl Context {
         count: GLsizei,
         instancecount: GLsizei,
         baseinstance: GLuint,
-    ) {
-        panic!("command oxidegl_draw_arrays_instanced_base_instance not yet implemented");
+    ) -> GlFallible {
+        self.draw_arrays_internal(mode, first, count, instancecount, baseinstance)
     }
     /// ### Parameters
     /// `mode`
@@ -279,8 +279,8 @@ impl Context {
         count: GLsizei,
         r#type: DrawElementsType,
         indices: *const GLvoid,
-    ) {
-        panic!("command oxidegl_draw_elements not yet implemented");
+    ) -> GlFallible {
+        self.draw_elements_internal(mode, count, r#type, indices, 1, 0, 0)
     }
     /// ### Parameters
     /// `mode`
@@ -331,8 +331,8 @@ impl Context {
         r#type: DrawElementsType,
         indices: *const GLvoid,
         basevertex: GLint,
-    ) {
-        panic!("command oxidegl_draw_elements_base_vertex not yet implemented");
+    ) -> GlFallible {
+        self.draw_elements_internal(mode, count, r#type, indices, 1, basevertex, 0)
     }
     /// ### Parameters
     /// `mode`
@@ -392,13 +392,32 @@ impl Context {
     /// GL less than 4.2, this parameter is present but is reserved and should
     /// be set to zero. On earlier versions of the GL, behavior is undefined if
     /// it is non-zero.
+    #[expect(
+        clippy::cast_possible_wrap,
+        reason = "out of range parameters are undefined behavior in GL"
+    )]
     pub unsafe fn oxidegl_draw_elements_indirect(
         &mut self,
         mode: PrimitiveType,
         r#type: DrawElementsType,
         indirect: *const GLvoid,
-    ) {
-        panic!("command oxidegl_draw_elements_indirect not yet implemented");
+    ) -> GlFallible {
+        let [count, instancecount, first_index, basevertex, baseinstance] =
+            self.indirect_draw_params(indirect)?;
+        let index_size = match r#type {
+            DrawElementsType::UnsignedByte => 1,
+            DrawElementsType::UnsignedShort => 2,
+            DrawElementsType::UnsignedInt => 4,
+        };
+        self.draw_elements_internal(
+            mode,
+            count as GLsizei,
+            r#type,
+            ptr::without_provenance(first_index as usize * index_size),
+            instancecount as GLsizei,
+            basevertex as GLint,
+            baseinstance,
+        )
     }
     /// ### Parameters
     /// `mode`
@@ -456,8 +475,8 @@ impl Context {
         r#type: DrawElementsType,
         indices: *const GLvoid,
         instancecount: GLsizei,
-    ) {
-        panic!("command oxidegl_draw_elements_instanced not yet implemented");
+    ) -> GlFallible {
+        self.draw_elements_internal(mode, count, r#type, indices, instancecount, 0, 0)
     }
     /// ### Parameters
     /// `mode`
@@ -527,8 +546,8 @@ impl Context {
         indices: *const GLvoid,
         instancecount: GLsizei,
         baseinstance: GLuint,
-    ) {
-        panic!("command oxidegl_draw_elements_instanced_base_instance not yet implemented");
+    ) -> GlFallible {
+        self.draw_elements_internal(mode, count, r#type, indices, instancecount, 0, baseinstance)
     }
     /// ### Parameters
     /// `mode`
@@ -585,8 +604,8 @@ impl Context {
         indices: *const GLvoid,
         instancecount: GLsizei,
         basevertex: GLint,
-    ) {
-        panic!("command oxidegl_draw_elements_instanced_base_vertex not yet implemented");
+    ) -> GlFallible {
+        self.draw_elements_internal(mode, count, r#type, indices, instancecount, basevertex, 0)
     }
     /// ### Parameters
     /// `mode`
@@ -655,10 +674,16 @@ impl Context {
         instancecount: GLsizei,
         basevertex: GLint,
         baseinstance: GLuint,
-    ) {
-        panic!(
-            "command oxidegl_draw_elements_instanced_base_vertex_base_instance not yet implemented"
-        );
+    ) -> GlFallible {
+        self.draw_elements_internal(
+            mode,
+            count,
+            r#type,
+            indices,
+            instancecount,
+            basevertex,
+            baseinstance,
+        )
     }
     /// ### Parameters
     /// `mode`
@@ -737,8 +762,14 @@ impl Context {
         count: GLsizei,
         r#type: DrawElementsType,
         indices: *const GLvoid,
-    ) {
-        panic!("command oxidegl_draw_range_elements not yet implemented");
+    ) -> GlFallible {
+        gl_assert!(
+            end >= start,
+            InvalidValue,
+            "end must not be less than start"
+        );
+        // start and end are only a hint as to the range of indices referenced by the draw, so we can ignore them
+        self.draw_elements_internal(mode, count, r#type, indices, 1, 0, 0)
     }
     /// ### Parameters
     /// `mode`
@@ -800,8 +831,14 @@ impl Context {
         r#type: DrawElementsType,
         indices: *const GLvoid,
         basevertex: GLint,
-    ) {
-        panic!("command oxidegl_draw_range_elements_base_vertex not yet implemented");
+    ) -> GlFallible {
+        gl_assert!(
+            end >= start,
+            InvalidValue,
+            "end must not be less than start"
+        );
+        // start and end are only a hint as to the range of indices referenced by the draw, so we can ignore them
+        self.draw_elements_internal(mode, count, r#type, indices, 1, basevertex, 0)
     }
     /// ### Parameters
     /// `mode`
@@ -828,8 +865,12 @@ impl Context {
     /// with `mode` as specified, `first` set to zero, and `count` set to the number
     /// of vertices captured on vertex stream zero the last time transform feedback
     /// was active on the transform feedback object named by `id`.
-    pub fn oxidegl_draw_transform_feedback(&mut self, mode: PrimitiveType, id: GLuint) {
-        panic!("command oxidegl_draw_transform_feedback not yet implemented");
+    pub fn oxidegl_draw_transform_feedback(
+        &mut self,
+        mode: PrimitiveType,
+        id: GLuint,
+    ) -> GlFallible {
+        self.draw_transform_feedback_internal(mode, id, 0, 1)
     }
     /// ### Parameters
     /// `mode`
@@ -871,8 +912,8 @@ impl Context {
         mode: PrimitiveType,
         id: GLuint,
         instancecount: GLsizei,
-    ) {
-        panic!("command oxidegl_draw_transform_feedback_instanced not yet implemented");
+    ) -> GlFallible {
+        self.draw_transform_feedback_internal(mode, id, 0, instancecount)
     }
     /// ### Parameters
     /// `mode`
@@ -914,8 +955,8 @@ impl Context {
         mode: PrimitiveType,
         id: GLuint,
         stream: GLuint,
-    ) {
-        panic!("command oxidegl_draw_transform_feedback_stream not yet implemented");
+    ) -> GlFallible {
+        self.draw_transform_feedback_internal(mode, id, stream, 1)
     }
     /// ### Parameters
     /// `mode`
@@ -963,7 +1004,266 @@ impl Context {
         id: GLuint,
         stream: GLuint,
         instancecount: GLsizei,
-    ) {
-        panic!("command oxidegl_draw_transform_feedback_stream_instanced not yet implemented");
+    ) -> GlFallible {
+        self.draw_transform_feedback_internal(mode, id, stream, instancecount)
+    }
+    /// ### Parameters
+    /// `pname`
+    ///
+    /// > Specifies the name of the parameter to set. The symbolc constants [`GL_PATCH_VERTICES`](crate::gl_enums::GL_PATCH_VERTICES),
+    /// > [`GL_PATCH_DEFAULT_OUTER_LEVEL`](crate::gl_enums::GL_PATCH_DEFAULT_OUTER_LEVEL),
+    /// > and [`GL_PATCH_DEFAULT_INNER_LEVEL`](crate::gl_enums::GL_PATCH_DEFAULT_INNER_LEVEL)
+    /// > are accepted.
+    ///
+    /// `value`
+    ///
+    /// > Specifies the new value for the parameter given by `pname`.
+    ///
+    /// `values`
+    ///
+    /// > Specifies the address of an array containing the new values for the parameter
+    /// > given by `pname`.
+    ///
+    /// ### Description
+    /// [**glPatchParameter**](crate::context::Context::oxidegl_patch_parameter)
+    /// specifies the parameters that will be used for patch primitives. `pname`
+    /// specifies the parameter to modify and must be either [`GL_PATCH_VERTICES`](crate::gl_enums::GL_PATCH_VERTICES),
+    /// [`GL_PATCH_DEFAULT_OUTER_LEVEL`](crate::gl_enums::GL_PATCH_DEFAULT_OUTER_LEVEL)
+    /// or [`GL_PATCH_DEFAULT_INNER_LEVEL`](crate::gl_enums::GL_PATCH_DEFAULT_INNER_LEVEL).
+    /// For [**glPatchParameteri**](crate::context::Context::oxidegl_patch_parameteri),
+    /// `value` specifies the new value for the parameter specified by `pname`.
+    /// For [**glPatchParameterfv**](crate::context::Context::oxidegl_patch_parameterfv),
+    /// `values` specifies the address of an array containing the new values for
+    /// the parameter specified by `pname`.
+    ///
+    /// When `pname` is [`GL_PATCH_VERTICES`](crate::gl_enums::GL_PATCH_VERTICES),
+    /// `value` specifies the number of vertices that will be used to make up a
+    /// single patch primitive. Patch primitives are consumed by the tessellation
+    /// control shader (if present) and subsequently used for tessellation. When
+    /// primitives are specified using [**glDrawArrays**](crate::context::Context::oxidegl_draw_arrays)
+    /// or a similar function, each patch will be made from `parameter` control
+    /// points, each represented by a vertex taken from the enabeld vertex arrays.
+    /// `parameter` must be greater than zero, and less than or equal to the value
+    /// of [`GL_MAX_PATCH_VERTICES`](crate::gl_enums::GL_MAX_PATCH_VERTICES).
+    ///
+    /// When `pname` is [`GL_PATCH_DEFAULT_OUTER_LEVEL`](crate::gl_enums::GL_PATCH_DEFAULT_OUTER_LEVEL)
+    /// or [`GL_PATCH_DEFAULT_INNER_LEVEL`](crate::gl_enums::GL_PATCH_DEFAULT_INNER_LEVEL),
+    /// `values` contains the address of an array contiaining the default outer
+    /// or inner tessellation levels, respectively, to be used when no tessellation
+    /// control shader is present.
+    pub fn oxidegl_patch_parameteri(
+        &mut self,
+        pname: PatchParameterName,
+        value: GLint,
+    ) -> GlFallible {
+        if pname != PatchParameterName::PatchVertices {
+            return Err(GlError::InvalidEnum.e());
+        }
+        let vertices = u32::try_from(value).unwrap_or(0);
+        gl_assert!(
+            (1..=MAX_PATCH_VERTICES).contains(&vertices),
+            InvalidValue,
+            "patch vertex count must be between 1 and GL_MAX_PATCH_VERTICES ({MAX_PATCH_VERTICES})"
+        );
+        self.gl_state.patch.vertices = vertices;
+        Ok(())
+    }
+    pub unsafe fn oxidegl_patch_parameterfv(
+        &mut self,
+        pname: PatchParameterName,
+        values: *const GLfloat,
+    ) -> GlFallible {
+        let levels = match pname {
+            PatchParameterName::PatchDefaultOuterLevel => {
+                &mut self.gl_state.patch.default_outer_level[..]
+            }
+            PatchParameterName::PatchDefaultInnerLevel => {
+                &mut self.gl_state.patch.default_inner_level[..]
+            }
+            PatchParameterName::PatchVertices => return Err(GlError::InvalidEnum.e()),
+        };
+        // Safety: caller ensures `values` points to as many floats as there are tessellation levels of the given kind
+        let values = unsafe { slice::from_raw_parts(values, levels.len()) };
+        levels.copy_from_slice(values);
+        Ok(())
+    }
+}
+
+impl Context {
+    /// Check that primitives of the given type can be fed to the geometry or tessellation stages of the current program,
+    /// if it has them
+    fn validate_draw_mode(&mut self, mode: PrimitiveType) -> GlFallible {
+        gl_assert!(
+            mode != PrimitiveType::Quads,
+            InvalidEnum,
+            "quads can't be drawn in the core profile"
+        );
+        let feedback = self.gl_state.transform_feedback();
+        if let Some(active) = feedback.active.filter(|_| feedback.capturing()) {
+            let primitive_mode = active.primitive_mode;
+            gl_assert!(
+                feedback::captures(primitive_mode, mode),
+                InvalidOperation,
+                "can't draw {mode:?} primitives while capturing {primitive_mode:?} primitives with transform feedback"
+            );
+        }
+        self.gl_state.validate_current_pipeline()?;
+        // geometry and tessellation stages always come from the program of the vertex stage
+        let Some(program) = self.gl_state.stage_program(ShaderType::VertexShader) else {
+            return Ok(());
+        };
+        let Some(linkage) = self.gl_state.program_list.get_mut(program).linkage() else {
+            return Ok(());
+        };
+        gl_assert!(
+            (mode == PrimitiveType::Patches) == linkage.tessellation.is_some(),
+            InvalidOperation,
+            "patches must be drawn with a program that has tessellation shaders, and only patches can be drawn with one"
+        );
+        if let Some(geometry) = &linkage.geometry {
+            let input = geometry.layout.info.input;
+            gl_assert!(
+                geometry.layout.info.accepts(mode),
+                InvalidOperation,
+                "can't draw {mode:?} primitives with a geometry shader that takes {input:?} input"
+            );
+        }
+        Ok(())
+    }
+    /// Draw the vertices captured on vertex stream `stream` by the last transform feedback of object `id`
+    fn draw_transform_feedback_internal(
+        &mut self,
+        mode: PrimitiveType,
+        id: GLuint,
+        stream: GLuint,
+        instancecount: GLsizei,
+    ) -> GlFallible {
+        gl_assert!(
+            stream < MAX_VERTEX_STREAMS,
+            InvalidValue,
+            "vertex stream must be less than GL_MAX_VERTEX_STREAMS ({MAX_VERTEX_STREAMS})"
+        );
+        let feedback = self
+            .feedback_object(id)
+            .map_err(|_| GlError::InvalidValue.e())?;
+        let Some(vertices) = feedback.captured_vertices else {
+            return Err(GlError::InvalidOperation.e());
+        };
+        // only vertex stream 0 is captured, so nothing was ever written to the others
+        let count = if stream == 0 { vertices } else { 0 };
+        #[expect(
+            clippy::cast_possible_wrap,
+            reason = "captured vertex counts are limited by buffer sizes"
+        )]
+        let count = count as GLsizei;
+        self.draw_arrays_internal(mode, 0, count, instancecount, 0)
+    }
+    /// Read the `N` words of the parameters of an indirect draw from the buffer bound to `GL_DRAW_INDIRECT_BUFFER`.
+    /// Like index buffers, they are read on the host when the draw is encoded
+    fn indirect_draw_params<const N: usize>(
+        &self,
+        indirect: *const GLvoid,
+    ) -> GlFallible<[u32; N]> {
+        // the core profile has no client-side indirect draw parameters, indirect is a byte offset into the buffer
+        let offset = indirect.addr();
+        gl_assert!(
+            offset.is_multiple_of(4),
+            InvalidValue,
+            "indirect draw offset must be a multiple of 4"
+        );
+        let Some(buffer) = self.gl_state.buffer_bindings.draw_indirect else {
+            gl_err!(ty: Error, "tried to draw indirectly without a buffer bound to GL_DRAW_INDIRECT_BUFFER");
+            return Err(GlError::InvalidOperation.e());
+        };
+        let buffer = self.gl_state.buffer_list.get(buffer);
+        let bytes = buffer
+            .allocation
+            .as_ref()
+            .and_then(|a| a.storage.contents().get(offset..offset.checked_add(4 * N)?));
+        let Some(bytes) = bytes else {
+            gl_err!(ty: Error, "indirect draw parameters at offset {offset} exceed the size of {:?} ({} bytes)", buffer.name, buffer.size);
+            return Err(GlError::InvalidOperation.e());
+        };
+        Ok(array::from_fn(|i| {
+            u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap())
+        }))
+    }
+    #[expect(clippy::cast_sign_loss, reason = "checked above")]
+    fn draw_arrays_internal(
+        &mut self,
+        mode: PrimitiveType,
+        first: GLint,
+        count: GLsizei,
+        instancecount: GLsizei,
+        baseinstance: GLuint,
+    ) -> GlFallible {
+        gl_assert!(
+            first >= 0 && count >= 0 && instancecount >= 0,
+            InvalidValue,
+            "draw parameters must not be negative"
+        );
+        self.validate_draw_mode(mode)?;
+        let Context {
+            gl_state: state,
+            renderer,
+        } = self;
+        renderer.draw(
+            state,
+            &DrawCall::Arrays {
+                mode,
+                first: first as u32,
+                count: count as u32,
+                instance_count: instancecount as u32,
+                base_instance: baseinstance,
+            },
+        );
+        Ok(())
+    }
+    #[expect(clippy::cast_sign_loss, reason = "checked above")]
+    fn draw_elements_internal(
+        &mut self,
+        mode: PrimitiveType,
+        count: GLsizei,
+        r#type: DrawElementsType,
+        indices: *const GLvoid,
+        instancecount: GLsizei,
+        basevertex: GLint,
+        baseinstance: GLuint,
+    ) -> GlFallible {
+        gl_assert!(
+            count >= 0 && instancecount >= 0,
+            InvalidValue,
+            "draw parameters must not be negative"
+        );
+        self.validate_draw_mode(mode)?;
+        // the core profile has no client-side index arrays, so indices always come from an element array buffer
+        let index_buffer = self.gl_state.buffer_bindings.element_array;
+        gl_assert!(
+            index_buffer.is_some(),
+            InvalidOperation,
+            "can't draw elements without an element array buffer bound"
+        );
+        let Some(index_buffer) = index_buffer else {
+            unreachable!()
+        };
+        let Context {
+            gl_state: state,
+            renderer,
+        } = self;
+        renderer.draw(
+            state,
+            &DrawCall::Elements {
+                mode,
+                count: count as u32,
+                index_type: r#type,
+                index_buffer: index_buffer.to_raw(),
+                // when an element array buffer is bound, indices is interpreted as a byte offset into it
+                index_offset: indices.addr(),
+                instance_count: instancecount as u32,
+                base_vertex: basevertex,
+                base_instance: baseinstance,
+            },
+        );
+        Ok(())
     }
 }
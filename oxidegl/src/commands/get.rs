@@ -8,6 +8,9 @@ use crate::{
     debug::{gl_debug, gl_err},
     framebuffer::MAX_COLOR_ATTACHMENTS,
     gl_enums::GetPName::{self, *},
+    program::{
+        binary::PROGRAM_BINARY_FORMAT, clip_control, compute, feedback, geometry, tessellation,
+    },
 };
 /// ### Parameters
 /// `pname`
@@ -1526,15 +1529,19 @@ impl Context {
         // Safety: Parameters are guaranteed to uphold invariants needed to write to them by the GL spec
         unsafe {
             match parameter_name {
-                subst!(Max{Combined, Compute, Vertex, TessControl, TessEvaluation, Fragment}AtomicCounterBuffers) =>
-                {
+                MaxCombinedAtomicCounterBuffers | MaxAtomicCounterBufferBindings => {
                     crate::context::state::MAX_ATOMIC_COUNTER_BUFFER_BINDINGS.write_out(ptr);
                 }
-                subst!(Max{Combined, Compute, Vertex, TessControl, TessEvaluation, Fragment}ShaderStorageBlocks) =>
+                subst!(Max{Compute, Vertex, TessControl, TessEvaluation, Geometry, Fragment}AtomicCounterBuffers) =>
+                {
+                    crate::program::atomic_counters::MAX_STAGE_ATOMIC_COUNTER_BUFFERS
+                        .write_out(ptr);
+                }
+                subst!(Max{Combined, Compute, Vertex, TessControl, TessEvaluation, Geometry, Fragment}ShaderStorageBlocks) =>
                 {
                     crate::context::state::MAX_SHADER_STORAGE_BUFFER_BINDINGS.write_out(ptr);
                 }
-                subst!(Max{Combined, Compute, Vertex, TessControl, TessEvaluation, Fragment}UniformBlocks) =>
+                subst!(Max{Combined, Compute, Vertex, TessControl, TessEvaluation, Geometry, Fragment}UniformBlocks) =>
                 {
                     crate::context::state::MAX_UNIFORM_BUFFER_BINDINGS.write_out(ptr);
                 }
@@ -1542,6 +1549,39 @@ impl Context {
                     crate::context::state::MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS.write_out(ptr);
                 }
                 MaxColorAttachments => MAX_COLOR_ATTACHMENTS.write_out(ptr),
+                MaxGeometryInputComponents => geometry::MAX_INPUT_COMPONENTS.write_out(ptr),
+                MaxGeometryOutputComponents => geometry::MAX_OUTPUT_COMPONENTS.write_out(ptr),
+                MaxVertexStreams => geometry::MAX_VERTEX_STREAMS.write_out(ptr),
+                PatchVertices => state.patch.vertices.write_out(ptr),
+                PatchDefaultInnerLevel => state.patch.default_inner_level.write_out(ptr),
+                PatchDefaultOuterLevel => state.patch.default_outer_level.write_out(ptr),
+                MaxPatchVertices => tessellation::MAX_PATCH_VERTICES.write_out(ptr),
+                MaxTessGenLevel => tessellation::MAX_TESS_GEN_LEVEL.write_out(ptr),
+                MaxTessControlInputComponents => {
+                    tessellation::MAX_CONTROL_INPUT_COMPONENTS.write_out(ptr);
+                }
+                MaxTessControlOutputComponents => {
+                    tessellation::MAX_CONTROL_OUTPUT_COMPONENTS.write_out(ptr);
+                }
+                MaxTessPatchComponents => tessellation::MAX_PATCH_COMPONENTS.write_out(ptr),
+                MaxTessControlTotalOutputComponents => {
+                    tessellation::MAX_CONTROL_TOTAL_OUTPUT_COMPONENTS.write_out(ptr);
+                }
+                MaxTessEvaluationInputComponents => {
+                    tessellation::MAX_EVALUATION_INPUT_COMPONENTS.write_out(ptr);
+                }
+                MaxTessEvaluationOutputComponents => {
+                    tessellation::MAX_EVALUATION_OUTPUT_COMPONENTS.write_out(ptr);
+                }
+                MaxComputeWorkGroupInvocations => {
+                    compute::MAX_COMPUTE_WORK_GROUP_INVOCATIONS.write_out(ptr);
+                }
+                MaxComputeWorkGroupCount => {
+                    compute::MAX_COMPUTE_WORK_GROUP_COUNT.write_out_index(idx, ptr);
+                }
+                MaxComputeWorkGroupSize => {
+                    compute::MAX_COMPUTE_WORK_GROUP_SIZE.write_out_index(idx, ptr);
+                }
 
                 // singleton buffer bindings
                 ArrayBufferBinding => {
@@ -1574,30 +1614,80 @@ impl Context {
                 QueryBufferBinding => state.buffer_bindings.query.write_out(ptr),
                 TextureBufferBinding => state.buffer_bindings.texture.write_out(ptr),
 
-                //Indexed buffer bindings
-                TransformFeedbackBufferBinding => state
-                    .buffer_bindings
-                    .transform_feedback
-                    .write_out_index(idx, ptr),
-                ShaderStorageBufferBinding => {
-                    state
+                //Indexed buffer bindings (their generic binding when queried without an index)
+                TransformFeedbackBufferBinding => match idx.get_opt() {
+                    Some(_) => state.transform_feedback().buffers.write_out_index(idx, ptr),
+                    None => state.buffer_bindings.transform_feedback.write_out(ptr),
+                },
+                ShaderStorageBufferBinding => match idx.get_opt() {
+                    Some(_) => state
                         .buffer_bindings
                         .shader_storage
-                        .write_out_index(idx, ptr);
+                        .write_out_index(idx, ptr),
+                    None => state.buffer_bindings.generic_shader_storage.write_out(ptr),
+                },
+                UniformBufferBinding => match idx.get_opt() {
+                    Some(_) => state.buffer_bindings.uniform.write_out_index(idx, ptr),
+                    None => state.buffer_bindings.generic_uniform.write_out(ptr),
+                },
+                AtomicCounterBufferBinding => match idx.get_opt() {
+                    Some(_) => state
+                        .buffer_bindings
+                        .atomic_counter
+                        .write_out_index(idx, ptr),
+                    None => state.buffer_bindings.generic_atomic_counter.write_out(ptr),
+                },
+                TransformFeedbackBufferStart => {
+                    let feedback = state.transform_feedback();
+                    feedback
+                        .ranges
+                        .write_out_index_mapped(idx, ptr, |r| r.map_or(0, |(offset, _)| offset));
                 }
-                UniformBufferBinding => {
-                    state.buffer_bindings.uniform.write_out_index(idx, ptr);
+                TransformFeedbackBufferSize => {
+                    let feedback = state.transform_feedback();
+                    feedback
+                        .ranges
+                        .write_out_index_mapped(idx, ptr, |r| r.map_or(0, |(_, size)| size));
                 }
-                AtomicCounterBufferBinding => {
+                AtomicCounterBufferStart => {
                     state
                         .buffer_bindings
-                        .atomic_counter
-                        .write_out_index(idx, ptr);
+                        .atomic_counter_ranges
+                        .write_out_index_mapped(idx, ptr, |r| r.map_or(0, |(offset, _)| offset));
+                }
+                AtomicCounterBufferSize => {
+                    state
+                        .buffer_bindings
+                        .atomic_counter_ranges
+                        .write_out_index_mapped(idx, ptr, |r| r.map_or(0, |(_, size)| size));
+                }
+
+                // transform feedback
+                TransformFeedbackBinding => state.transform_feedback_binding.write_out(ptr),
+                TransformFeedbackBufferActive => {
+                    state.transform_feedback().active.is_some().write_out(ptr);
+                }
+                TransformFeedbackBufferPaused => state
+                    .transform_feedback()
+                    .active
+                    .is_some_and(|a| a.paused)
+                    .write_out(ptr),
+                MaxTransformFeedbackInterleavedComponents => {
+                    feedback::MAX_INTERLEAVED_COMPONENTS.write_out(ptr);
+                }
+                MaxTransformFeedbackSeparateAttribs => {
+                    feedback::MAX_SEPARATE_ATTRIBS.write_out(ptr);
+                }
+                MaxTransformFeedbackSeparateComponents => {
+                    feedback::MAX_SEPARATE_COMPONENTS.write_out(ptr);
                 }
                 //VAO binding
                 VertexArrayBinding => state.vao_binding.write_out(ptr),
+                ProgramPipelineBinding => state.program_pipeline_binding.write_out(ptr),
 
-                PointSize => state.characteristics.point_size.write_out(ptr),
+                PointSize => state.points.size.write_out(ptr),
+                PointFadeThresholdSize => state.points.fade_threshold_size.write_out(ptr),
+                PointSpriteCoordOrigin => state.points.sprite_coord_origin.write_out(ptr),
                 PointSizeRange => self
                     .gl_state
                     .characteristics
@@ -1610,6 +1700,7 @@ impl Context {
 
                 //Context Attributes
                 NumExtensions => self.gl_state.characteristics.num_extensions.write_out(ptr),
+                MaxShaderCompilerThreads => state.compile_pool.max_threads().write_out(ptr),
                 ContextFlags => self.gl_state.characteristics.context_flags.write_out(ptr),
                 ContextProfileMask => self
                     .gl_state
@@ -1623,10 +1714,23 @@ impl Context {
                 MaxTextureBufferSize => 64_000_000.write_out(ptr),
                 MaxArrayTextureLayers => 2048.write_out(ptr),
 
+                // SPIR-V is the only supported shader binary format
+                NumShaderBinaryFormats => 1.write_out(ptr),
+                ShaderBinaryFormats => {
+                    crate::gl_enums::GL_SHADER_BINARY_FORMAT_SPIR_V.write_out(ptr);
+                }
+                // as is the OxideGL program binary format
+                NumProgramBinaryFormats => 1.write_out(ptr),
+                ProgramBinaryFormats => PROGRAM_BINARY_FORMAT.write_out(ptr),
+
                 // Depth state
                 DepthWritemask => state.writemasks.depth.write_out(ptr),
                 DepthClearValue => state.clear_values.depth.write_out(ptr),
                 DepthFunc => state.depth_func.write_out(ptr),
+                DepthRange => [state.depth_range.near, state.depth_range.far].write_out(ptr),
+                ClipOrigin => state.clip_control.origin.write_out(ptr),
+                ClipDepthMode => state.clip_control.depth.write_out(ptr),
+                MaxClipDistances => clip_control::MAX_CLIP_DISTANCES.write_out(ptr),
 
                 // Stencil state
                 StencilClearValue => state.clear_values.stencil.write_out(ptr),
@@ -1655,7 +1759,7 @@ impl Context {
 
                 // TODO: indexed viewports (scissor rect and viewport is per-viewport indexed state)
                 Viewport => state.viewport.write_out(ptr),
-                //ScissorBox => state.scissor_box.write_out(idx, ptr),
+                ScissorBox => state.scissor_box.write_out(ptr),
 
                 //Bindings
 
@@ -1668,8 +1772,6 @@ impl Context {
                 //GL_CULL_FACE_MODE => self.gl_state.cull_face_mode.into(), // GL_CULL_FACE_MODE
                 // 0x0B46 => self.state.front_face.into(), // GL_FRONT_FACE
 
-                // 0x0B70 => self.attribs.depth_range, 2.into(), // GL_DEPTH_RANGE
-
                 // 0x0BE0 => self.state.blend_dst_rgb[0].into(), // GL_BLEND_DST
                 // 0x0BE1 => self.state.blend_src_rgb[0].into(), // GL_BLEND_SRC
 
@@ -1884,9 +1986,6 @@ impl Context {
                 // 0x8DFB => self.state.max_vertex_uniform_vectors.into(), // GL_MAX_VERTEX_UNIFORM_VECTORS
                 // 0x8DFC => self.state.max_varying_vectors.into(), // GL_MAX_VARYING_VECTORS
                 // 0x8DFD => self.state.max_fragment_uniform_vectors.into(), // GL_MAX_FRAGMENT_UNIFORM_VECTORS
-                // 0x87FE => self.state.num_program_binary_formats.into(), // GL_NUM_PROGRAM_BINARY_FORMATS
-                // 0x87FF => self.state.program_binary_formats.into(), // GL_PROGRAM_BINARY_FORMATS
-                // 0x825A => self.state.program_pipeline_binding.into(), // GL_PROGRAM_PIPELINE_BINDING
                 // 0x825B => self.state.max_viewports.into(), // GL_MAX_VIEWPORTS
                 // 0x825C => self.state.viewport_subpixel_bits.into(), // GL_VIEWPORT_SUBPIXEL_BITS
                 // 0x825D => self.state.viewport_bounds_range.into(), // GL_VIEWPORT_BOUNDS_RANGE
@@ -1906,9 +2005,6 @@ impl Context {
                 // 0x8264 => self.state.max_compute_atomic_counter_buffers.into(), // GL_MAX_COMPUTE_ATOMIC_COUNTER_BUFFERS
                 // 0x8265 => self.state.max_compute_atomic_counters.into(), // GL_MAX_COMPUTE_ATOMIC_COUNTERS
                 // 0x8266 => self.state.max_combined_compute_uniform_components.into(), // GL_MAX_COMBINED_COMPUTE_UNIFORM_COMPONENTS
-                // 0x90EB => self.state.max_compute_work_group_invocations.into(), // GL_MAX_COMPUTE_WORK_GROUP_INVOCATIONS
-                // 0x91BE => self.state.max_compute_work_group_count[0].into(), // GL_MAX_COMPUTE_WORK_GROUP_COUNT
-                // 0x91BF => self.state.max_compute_work_group_size[0].into(), // GL_MAX_COMPUTE_WORK_GROUP_SIZE
                 // 0x90EF => self.state.dispatch_indirect_buffer_binding.into(), // GL_DISPATCH_INDIRECT_BUFFER_BINDING
                 // 0x826C => self.state.max_debug_group_stack_depth.into(), // GL_MAX_DEBUG_GROUP_STACK_DEPTH
                 // 0x826D => self.state.debug_group_stack_depth.into(), // GL_DEBUG_GROUP_STACK_DEPTH
@@ -2088,7 +2184,11 @@ impl Context {
 /// always returns a compatible version number. The release number always describes
 /// the server.
 pub mod get_string {
-    use std::ffi::CStr;
+    use std::{
+        ffi::{CStr, CString},
+        ptr,
+        sync::LazyLock,
+    };
 
     use log::debug;
 
@@ -2106,12 +2206,21 @@ pub mod get_string {
                 }
             }
         };
+        /// Extensions supported by OxideGL, as enumerated by `glGetStringi(GL_EXTENSIONS, ..)`
+        pub(crate) const EXTENSIONS: &'static [&'static CStr] = &[
+            c"GL_ARB_parallel_shader_compile",
+            c"GL_KHR_parallel_shader_compile",
+        ];
         fn get_string(name: StringName) -> *const GLubyte {
             const VENDOR: &CStr = c"Charles Liske";
             const RENDERER: &CStr = c"OxideGL";
             const VERSION_PREFIX: &[u8] = b"4.6.0 OxideGL ";
 
-            const FAKEEXT: &CStr = c"EXTFakeExtensionToMakeGladHappy";
+            // the extensions listed in `EXTENSIONS`, separated by spaces
+            static EXTENSIONS: LazyLock<CString> = LazyLock::new(|| {
+                let names: Vec<_> = Context::EXTENSIONS.iter().map(|e| e.to_bytes()).collect();
+                CString::new(names.join(&b' ')).expect("extension names don't contain NUL bytes")
+            });
 
             const VERSION: &[u8] =
                 constcat::concat_bytes!(VERSION_PREFIX, Context::VERSION_INFO.as_bytes(), &[0]);
@@ -2121,14 +2230,43 @@ pub mod get_string {
                 StringName::Vendor => VENDOR.as_ptr().cast(),
                 StringName::Renderer => RENDERER.as_ptr().cast(),
                 StringName::Version | StringName::ShadingLanguageVersion => VERSION.as_ptr(),
-                StringName::Extensions => FAKEEXT.as_ptr().cast(),
+                StringName::Extensions => EXTENSIONS.as_ptr().cast(),
             }
         }
         pub fn oxidegl_get_string(&mut self, name: StringName) -> *const GLubyte {
             Self::get_string(name)
         }
         pub fn oxidegl_get_stringi(&mut self, name: StringName, index: GLuint) -> *const GLubyte {
-            Self::get_string(name)
+            match name {
+                StringName::Extensions => Self::EXTENSIONS
+                    .get(index as usize)
+                    .map_or(ptr::null(), |ext| ext.as_ptr().cast()),
+                _ => Self::get_string(name),
+            }
         }
     }
 }
+
+#[test]
+fn extension_strings() {
+    use crate::gl_enums::StringName;
+    use std::ffi::CStr;
+
+    let mut ctx = Context::new_headless(1, 1);
+    // Safety: glGetString returns a NUL terminated string
+    let all = unsafe { CStr::from_ptr(ctx.oxidegl_get_string(StringName::Extensions).cast()) };
+    let names: Vec<_> = all.to_bytes().split(|&b| b == b' ').collect();
+    assert_eq!(names.len(), Context::EXTENSIONS.len());
+    for (i, (name, ext)) in names.into_iter().zip(Context::EXTENSIONS).enumerate() {
+        assert_eq!(name, ext.to_bytes());
+        let index = GLuint::try_from(i).unwrap();
+        // Safety: glGetStringi returns a NUL terminated string for indices below GL_NUM_EXTENSIONS
+        let indexed = unsafe {
+            CStr::from_ptr(
+                ctx.oxidegl_get_stringi(StringName::Extensions, index)
+                    .cast(),
+            )
+        };
+        assert_eq!(indexed, *ext);
+    }
+}
@@ -1,9 +1,15 @@
 use crate::{
-    context::{Context, state::PixelAlignedRect},
+    context::{
+        Context,
+        state::{ClipControl, DepthRange, PixelAlignedRect},
+    },
     debug::gl_debug,
-    error::{GlFallible, gl_assert},
-    gl_enums::ErrorCode,
-    gl_types::{GLenum, GLfloat, GLint, GLsizei, GLuint},
+    error::{GlError, GlFallible, gl_assert},
+    gl_enums::{
+        ClipControlDepth, ClipControlOrigin, ErrorCode, GL_POINT_FADE_THRESHOLD_SIZE,
+        GL_POINT_SPRITE_COORD_ORIGIN,
+    },
+    gl_types::{GLdouble, GLenum, GLfloat, GLint, GLsizei, GLuint},
     util::run_if_changed,
 };
 
@@ -46,8 +52,27 @@ impl Context {
     ///
     /// [**glIsEnabled**](crate::context::Context::oxidegl_is_enabled) with argument
     /// [`GL_SCISSOR_TEST`](crate::gl_enums::GL_SCISSOR_TEST)
-    pub fn oxidegl_scissor(&mut self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
-        panic!("command oxidegl_scissor not yet implemented");
+    #[expect(clippy::cast_sign_loss, reason = "checked above")]
+    pub fn oxidegl_scissor(
+        &mut self,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+    ) -> GlFallible {
+        gl_debug!("glScissor x {x} y {y} width {width} height {height}");
+        gl_assert!(width >= 0 && height >= 0, InvalidValue);
+        // only pixels of the framebuffer are ever scissored, so the part of the box left of or below the origin can be
+        // cut off
+        let width = width.saturating_add(x.min(0)).max(0);
+        let height = height.saturating_add(y.min(0)).max(0);
+        run_if_changed!(self.gl_state.scissor_box;= PixelAlignedRect {
+            x: x.max(0) as u32,
+            y: y.max(0) as u32,
+            width: width as u32,
+            height: height as u32,
+        } => self.update_encoder());
+        Ok(())
     }
 
     /// ### Parameters
@@ -90,13 +115,23 @@ impl Context {
     ///
     /// [**glIsEnabled**](crate::context::Context::oxidegl_is_enabled) with argument
     /// [`GL_SCISSOR_TEST`](crate::gl_enums::GL_SCISSOR_TEST)
+    #[expect(clippy::cast_sign_loss, reason = "checked above")]
     pub unsafe fn oxidegl_scissor_arrayv(
         &mut self,
         first: GLuint,
         count: GLsizei,
         v: *const GLint,
-    ) {
-        panic!("command oxidegl_scissor_arrayv not yet implemented");
+    ) -> GlFallible {
+        gl_debug!("glScissorArrayv first {first} count {count} v {v:?}");
+        gl_assert!(count >= 0, InvalidValue);
+        // there is a single viewport
+        gl_assert!(u64::from(first) + count as u64 <= 1, InvalidValue);
+        if count == 1 {
+            // Safety: caller ensures v points to count scissor boxes of 4 values
+            let [x, y, width, height] = unsafe { v.cast::<[GLint; 4]>().read() };
+            self.oxidegl_scissor(x, y, width, height)?;
+        }
+        Ok(())
     }
 
     // glViewport ---------------
@@ -198,13 +233,377 @@ impl Context {
     /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_VIEWPORT_BOUNDS_RANGE`](crate::gl_enums::GL_VIEWPORT_BOUNDS_RANGE)
     ///
     /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_VIEWPORT_SUBPIXEL_BITS`](crate::gl_enums::GL_VIEWPORT_SUBPIXEL_BITS)
+    #[expect(clippy::cast_sign_loss, reason = "checked above")]
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "viewport bounds have no subpixel precision"
+    )]
     pub unsafe fn oxidegl_viewport_arrayv(
         &mut self,
         first: GLuint,
         count: GLsizei,
         v: *const GLfloat,
-    ) {
-        panic!("command oxidegl_viewport_arrayv not yet implemented");
+    ) -> GlFallible {
+        gl_debug!("glViewportArrayv first {first} count {count} v {v:?}");
+        gl_assert!(count >= 0, InvalidValue);
+        // there is a single viewport
+        gl_assert!(u64::from(first) + count as u64 <= 1, InvalidValue);
+        if count == 1 {
+            // Safety: caller ensures v points to count viewports of 4 values
+            let [x, y, width, height] = unsafe { v.cast::<[GLfloat; 4]>().read() };
+            self.oxidegl_viewport(x as GLint, y as GLint, width as GLsizei, height as GLsizei)?;
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `nearVal`
+    ///
+    /// > Specifies the mapping of the near clipping plane to window coordinates.
+    /// > The initial value is 0.
+    ///
+    /// `farVal`
+    ///
+    /// > Specifies the mapping of the far clipping plane to window coordinates.
+    /// > The initial value is 1.
+    ///
+    /// ### Description
+    /// After clipping and division by *w*, depth coordinates range from `[inlineq]`
+    /// [**glDepthRange**](crate::context::Context::oxidegl_depth_range) specifies
+    /// a linear mapping of the normalized depth coordinates in this range to window
+    /// depth coordinates. Regardless of the actual depth buffer implementation,
+    /// window coordinate depth values are treated as though they range from 0
+    /// through 1 (like color components). Thus, the values accepted by [**glDepthRange**](crate::context::Context::oxidegl_depth_range)
+    /// are both clamped to this range before they are accepted.
+    ///
+    /// The setting of (0,1) maps the near plane to 0 and the far plane to 1. With
+    /// this mapping, the depth buffer range is fully utilized.
+    ///
+    /// ### Notes
+    /// It is not necessary that `nearVal` be less than `farVal`. Reverse mappings
+    /// such as `[inlineq]` `[inlineq]`
+    ///
+    /// The type of the `nearVal` and `farVal` parameters was changed from `GLclampf`
+    /// to `GLfloat` for [**glDepthRangef**](crate::context::Context::oxidegl_depth_rangef)
+    /// and from `GLclampd` to `GLdouble` for [**glDepthRange**](crate::context::Context::oxidegl_depth_range).
+    /// This change is transparent to user code and is described in detail on
+    /// the `removedTypes` page.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_DEPTH_RANGE`](crate::gl_enums::GL_DEPTH_RANGE)
+    pub fn oxidegl_depth_range(&mut self, n: GLdouble, f: GLdouble) -> GlFallible {
+        gl_debug!("glDepthRange n {n} f {f}");
+        run_if_changed!(self.gl_state.depth_range;= DepthRange::new(n, f) => self.update_encoder());
+        Ok(())
+    }
+    pub fn oxidegl_depth_rangef(&mut self, n: GLfloat, f: GLfloat) -> GlFallible {
+        self.oxidegl_depth_range(n.into(), f.into())
+    }
+    /// ### Parameters
+    /// `origin`
+    ///
+    /// > Specifies the clip control origin. Must be one of [`GL_LOWER_LEFT`](crate::gl_enums::GL_LOWER_LEFT)
+    /// > or [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT).
+    ///
+    /// `depth`
+    ///
+    /// > Specifies the clip control depth mode. Must be one of [`GL_NEGATIVE_ONE_TO_ONE`](crate::gl_enums::GL_NEGATIVE_ONE_TO_ONE)
+    /// > or [`GL_ZERO_TO_ONE`](crate::gl_enums::GL_ZERO_TO_ONE).
+    ///
+    /// ### Description
+    /// [**glClipControl**](crate::context::Context::oxidegl_clip_control) controls
+    /// the clipping volume behavior and the clip coordinate to window coordinate
+    /// transformation behavior.
+    ///
+    /// The view volume is defined by $$z_{min} \leq `z_c` \leq `w_c`$$ where $z_{min}
+    ///= -`w_c`$ when `depth` is [`GL_NEGATIVE_ONE_TO_ONE`](crate::gl_enums::GL_NEGATIVE_ONE_TO_ONE),
+    /// and $z_{min}= 0$ when `depth` is [`GL_ZERO_TO_ONE`](crate::gl_enums::GL_ZERO_TO_ONE).
+    ///
+    /// The normalized device coordinate $`y_d`$ is given by $$`y_d`={{ f \times `y_c`
+    ///} \over `w_c` }$$ where $f= 1$ when `origin` is [`GL_LOWER_LEFT`](crate::gl_enums::GL_LOWER_LEFT),
+    /// and $f= -1$ when `origin` is [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT).
+    ///
+    /// The window coordinate $`z_w`$ is given by $$`z_w`= s \times `z_d`+ b$$ where
+    /// $s={{ f- n} \over 2 }$ and $b={ {n+ f} \over 2 }$ when `depth` is [`GL_NEGATIVE_ONE_TO_ONE`](crate::gl_enums::GL_NEGATIVE_ONE_TO_ONE),
+    /// and $s= f- n$ and $b= n$ when `depth` is [`GL_ZERO_TO_ONE`](crate::gl_enums::GL_ZERO_TO_ONE).
+    /// $n$ and $f$ are the near and far depth range values set with [**glDepthRange**](crate::context::Context::oxidegl_depth_range).
+    ///
+    /// Finally, the polygon area computation defined by [**`gl_FrontFacing`**](crate::context::Context::oxidegl__front_facing)
+    /// to determine if a polygon is front- or back-facing has its sign negated
+    /// when `origin` is [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT).
+    ///
+    /// ### Notes
+    /// The default GL clip volume definition is for a `origin` of [`GL_LOWER_LEFT`](crate::gl_enums::GL_LOWER_LEFT)
+    /// and a `depth` of [`GL_NEGATIVE_ONE_TO_ONE`](crate::gl_enums::GL_NEGATIVE_ONE_TO_ONE).
+    ///
+    /// An `origin` of [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT) and a
+    /// `depth` of [`GL_ZERO_TO_ONE`](crate::gl_enums::GL_ZERO_TO_ONE) corresponds
+    /// to Direct3D's clip volume definition.
+    ///
+    /// An `origin` of [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT) and a
+    /// `depth` of [`GL_NEGATIVE_ONE_TO_ONE`](crate::gl_enums::GL_NEGATIVE_ONE_TO_ONE)
+    /// corresponds to the upper-left origin of the window coordinate system of
+    /// Microsoft Windows and the X Window System.
+    ///
+    /// There is extensive discussion of the uses and further consequences of the
+    /// different clip volume settings in the
+    pub fn oxidegl_clip_control(
+        &mut self,
+        origin: ClipControlOrigin,
+        depth: ClipControlDepth,
+    ) -> GlFallible {
+        gl_debug!("glClipControl origin {origin:?} depth {depth:?}");
+        // the clip volume decides which variant of the vertex function the pipeline uses, and the winding of front
+        // facing triangles
+        run_if_changed!(self.gl_state.clip_control;= ClipControl { origin, depth } => {
+            self.new_pipeline();
+            self.update_encoder();
+        });
+        Ok(())
+    }
+    /// ### Parameters
+    /// `first`
+    ///
+    /// > Specifies the index of the first viewport whose depth range to update.
+    ///
+    /// `count`
+    ///
+    /// > Specifies the number of viewports whose depth range to update.
+    ///
+    /// `v`
+    ///
+    /// > Specifies the address of an array containing the near and far values for
+    /// > the depth range of each modified viewport.
+    ///
+    /// ### Description
+    /// After clipping and division by *w*, depth coordinates range from `[inlineq]`
+    /// [**glDepthRangeArray**](crate::context::Context::oxidegl_depth_range_array)
+    /// specifies a linear mapping of the normalized depth coordinates in this
+    /// range to window depth coordinates for each viewport in the range \[ `first`,
+    /// `first`+ `count` ). Thus, the values accepted by [**glDepthRangeArray**](crate::context::Context::oxidegl_depth_range_array)
+    /// are both clamped to this range before they are accepted.
+    ///
+    /// The `first` parameter specifies the index of the first viewport whose depth
+    /// range to modify and must be less than the value of [`GL_MAX_VIEWPORTS`](crate::gl_enums::GL_MAX_VIEWPORTS).
+    /// `count` specifies the number of viewports whose depth range to modify.
+    /// `first`+ `count` must be less than or equal to the value of [`GL_MAX_VIEWPORTS`](crate::gl_enums::GL_MAX_VIEWPORTS).
+    /// `v` specifies the address of an array of pairs of double precision floating
+    /// point values representing the near and far values of the depth range for
+    /// each viewport, in that order.
+    ///
+    /// The setting of (0,1) maps the near plane to 0 and the far plane to 1. With
+    /// this mapping, the depth buffer range is fully utilized.
+    ///
+    /// ### Notes
+    /// It is not necessary that the near plane distance be less than the far plane
+    /// distance. Reverse mappings such as `[inlineq]` `[inlineq]`
+    ///
+    /// The type of the `v` parameter was changed from `GLclampd` to `GLdouble`. This
+    /// change is transparent to user code and is described in detail on the `removedTypes`
+    /// page.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_DEPTH_RANGE`](crate::gl_enums::GL_DEPTH_RANGE)
+    #[expect(clippy::cast_sign_loss, reason = "checked above")]
+    pub unsafe fn oxidegl_depth_range_arrayv(
+        &mut self,
+        first: GLuint,
+        count: GLsizei,
+        v: *const GLdouble,
+    ) -> GlFallible {
+        gl_debug!("glDepthRangeArrayv first {first} count {count} v {v:?}");
+        gl_assert!(count >= 0, InvalidValue);
+        // there is a single viewport
+        gl_assert!(u64::from(first) + count as u64 <= 1, InvalidValue);
+        if count == 1 {
+            // Safety: caller ensures v points to count pairs of depth range values
+            let [n, f] = unsafe { v.cast::<[GLdouble; 2]>().read() };
+            self.oxidegl_depth_range(n, f)?;
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `index`
+    ///
+    /// > Specifies the index of the viewport whose depth range to update.
+    ///
+    /// `nearVal`
+    ///
+    /// > Specifies the mapping of the near clipping plane to window coordinates.
+    /// > The initial value is 0.
+    ///
+    /// `farVal`
+    ///
+    /// > Specifies the mapping of the far clipping plane to window coordinates.
+    /// > The initial value is 1.
+    ///
+    /// ### Description
+    /// After clipping and division by *w*, depth coordinates range from `[inlineq]`
+    /// [**glDepthRangeIndexed**](crate::context::Context::oxidegl_depth_range_indexed)
+    /// specifies a linear mapping of the normalized depth coordinates in this
+    /// range to window depth coordinates for a specified viewport. Thus, the values
+    /// accepted by [**glDepthRangeIndexed**](crate::context::Context::oxidegl_depth_range_indexed)
+    /// are both clamped to this range before they are accepted.
+    ///
+    /// The `index` parameter specifies the index of first viewport whose depth
+    /// range to modify and must be less than the value of [`GL_MAX_VIEWPORTS`](crate::gl_enums::GL_MAX_VIEWPORTS).
+    /// `nearVal` and `farVal` specify near and far values of the depth range for
+    /// the specified viewport, respectively.
+    ///
+    /// The setting of (0,1) maps the near plane to 0 and the far plane to 1. With
+    /// this mapping, the depth buffer range is fully utilized.
+    ///
+    /// ### Notes
+    /// It is not necessary that the near plane distance be less than the far plane
+    /// distance. Reverse mappings such as `[inlineq]` `[inlineq]`
+    ///
+    /// The type of the `nearVal` and `farVal` parameters was changed from `GLclampd`
+    /// to `GLdouble`. This change is transparent to user code and is described in
+    /// detail on the `removedTypes`
+    /// page.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_DEPTH_RANGE`](crate::gl_enums::GL_DEPTH_RANGE)
+    pub fn oxidegl_depth_range_indexed(
+        &mut self,
+        index: GLuint,
+        n: GLdouble,
+        f: GLdouble,
+    ) -> GlFallible {
+        gl_debug!("glDepthRangeIndexed index {index} n {n} f {f}");
+        // there is a single viewport
+        gl_assert!(index == 0, InvalidValue);
+        self.oxidegl_depth_range(n, f)
+    }
+    /// ### Parameters
+    /// `size`
+    ///
+    /// > Specifies the diameter of rasterized points. The initial value is 1.
+    ///
+    /// ### Description
+    /// [**glPointSize**](crate::context::Context::oxidegl_point_size) specifies
+    /// the rasterized diameter of points. If point size mode is disabled (see
+    /// [**glEnable**](crate::context::Context::oxidegl_enable) with parameter
+    /// [`GL_PROGRAM_POINT_SIZE`](crate::gl_enums::GL_PROGRAM_POINT_SIZE) ), this
+    /// value will be used to rasterize points. Otherwise, the value written to
+    /// the shading language built-in variable
+    ///
+    /// ### Notes
+    /// The point size specified by [**glPointSize**](crate::context::Context::oxidegl_point_size)
+    /// is always returned when [`GL_POINT_SIZE`](crate::gl_enums::GL_POINT_SIZE)
+    /// is queried. Clamping and rounding for points have no effect on the specified
+    /// value.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_POINT_SIZE_RANGE`](crate::gl_enums::GL_POINT_SIZE_RANGE)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_POINT_SIZE_GRANULARITY`](crate::gl_enums::GL_POINT_SIZE_GRANULARITY)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_POINT_SIZE`](crate::gl_enums::GL_POINT_SIZE)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_POINT_FADE_THRESHOLD_SIZE`](crate::gl_enums::GL_POINT_FADE_THRESHOLD_SIZE)
+    ///
+    /// [**glIsEnabled**](crate::context::Context::oxidegl_is_enabled) with argument
+    /// [`GL_PROGRAM_POINT_SIZE`](crate::gl_enums::GL_PROGRAM_POINT_SIZE)
+    pub fn oxidegl_point_size(&mut self, size: GLfloat) -> GlFallible {
+        gl_debug!("glPointSize size {size}");
+        gl_assert!(size > 0.0, InvalidValue, "point size must be positive");
+        // the point size is a function constant of the vertex function of the pipeline
+        run_if_changed!(self.gl_state.points.size;= size => self.new_pipeline());
+        Ok(())
+    }
+    /// ### Parameters
+    /// `pname`
+    ///
+    /// > Specifies a single-valued point parameter. [`GL_POINT_FADE_THRESHOLD_SIZE`](crate::gl_enums::GL_POINT_FADE_THRESHOLD_SIZE),
+    /// > and [`GL_POINT_SPRITE_COORD_ORIGIN`](crate::gl_enums::GL_POINT_SPRITE_COORD_ORIGIN)
+    /// > are accepted.
+    ///
+    /// `param`
+    ///
+    /// > For [**glPointParameterf**](crate::context::Context::oxidegl_point_parameterf)
+    /// > and [**glPointParameteri**](crate::context::Context::oxidegl_point_parameteri),
+    /// > specifies the value that `pname` will be set to.
+    ///
+    /// `params`
+    ///
+    /// > For [**glPointParameterfv**](crate::context::Context::oxidegl_point_parameterfv)
+    /// > and [**glPointParameteriv**](crate::context::Context::oxidegl_point_parameteriv),
+    /// > specifies a pointer to an array where the value or values to be assigned
+    /// > to `pname` are stored.
+    ///
+    /// ### Description
+    /// The following values are accepted for `pname`:
+    ///
+    /// [`GL_POINT_FADE_THRESHOLD_SIZE`](crate::gl_enums::GL_POINT_FADE_THRESHOLD_SIZE)
+    ///
+    ///
+    /// > `params` is a single floating-point value that specifies the threshold
+    /// > value to which point sizes are clamped if they exceed the specified value.
+    /// > The default value is 1.0.
+    ///
+    /// [`GL_POINT_SPRITE_COORD_ORIGIN`](crate::gl_enums::GL_POINT_SPRITE_COORD_ORIGIN)
+    ///
+    ///
+    /// > `params` is a single enum specifying the point sprite texture coordinate
+    /// > origin, either [`GL_LOWER_LEFT`](crate::gl_enums::GL_LOWER_LEFT) or [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT).
+    /// > The default value is [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT).
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_POINT_FADE_THRESHOLD_SIZE`](crate::gl_enums::GL_POINT_FADE_THRESHOLD_SIZE)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_POINT_SPRITE_COORD_ORIGIN`](crate::gl_enums::GL_POINT_SPRITE_COORD_ORIGIN)
+    pub fn oxidegl_point_parameterf(&mut self, pname: GLenum, param: GLfloat) -> GlFallible {
+        gl_debug!("glPointParameterf pname {pname:#x} param {param}");
+        match pname {
+            GL_POINT_FADE_THRESHOLD_SIZE => {
+                gl_assert!(
+                    param >= 0.0,
+                    InvalidValue,
+                    "point fade threshold size can't be negative"
+                );
+                // points are only faded out when multisampling, which isn't supported yet
+                self.gl_state.points.fade_threshold_size = param;
+            }
+            GL_POINT_SPRITE_COORD_ORIGIN => {
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss,
+                    reason = "enum values are exactly representable as floats"
+                )]
+                let origin =
+                    ClipControlOrigin::from_repr(param as GLenum).ok_or(GlError::InvalidEnum)?;
+                // the point sprite origin is a function constant of the fragment function of the pipeline
+                run_if_changed!(self.gl_state.points.sprite_coord_origin;= origin => self.new_pipeline());
+            }
+            _ => return Err(GlError::InvalidEnum.e()),
+        }
+        Ok(())
+    }
+    pub unsafe fn oxidegl_point_parameterfv(
+        &mut self,
+        pname: GLenum,
+        params: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures `params` points to a value, all point parameters are single-valued
+        let param = unsafe { params.read() };
+        self.oxidegl_point_parameterf(pname, param)
+    }
+    pub fn oxidegl_point_parameteri(&mut self, pname: GLenum, param: GLint) -> GlFallible {
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "point parameters are small enough to be exactly representable as floats"
+        )]
+        let param = param as GLfloat;
+        self.oxidegl_point_parameterf(pname, param)
+    }
+    pub unsafe fn oxidegl_point_parameteriv(
+        &mut self,
+        pname: GLenum,
+        params: *const GLint,
+    ) -> GlFallible {
+        // Safety: caller ensures `params` points to a value, all point parameters are single-valued
+        let param = unsafe { params.read() };
+        self.oxidegl_point_parameteri(pname, param)
     }
     /// ### Description
     /// [**glGetError**](crate::context::Context::oxidegl_get_error) returns the
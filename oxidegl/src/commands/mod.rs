@@ -1,12 +1,20 @@
 pub mod buffer;
 pub mod caps;
 pub mod clear;
+pub mod compute;
 pub mod debug;
 pub mod draw;
 pub mod get;
 pub mod misc;
+pub mod program_interface;
+pub mod program_pipeline;
 pub mod programs;
+pub mod query;
 pub mod shaders;
+pub mod subroutines;
+pub mod texture;
+pub mod transform_feedback;
+pub mod uniforms;
 #[allow(clippy::missing_safety_doc, clippy::missing_errors_doc)]
 pub mod unimplemented;
 pub mod vao;
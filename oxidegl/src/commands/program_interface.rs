@@ -0,0 +1,2325 @@
+use core::{ffi::CStr, ptr, slice};
+
+use crate::{
+    commands::programs::ProgramListExt,
+    context::Context,
+    conversions::{sizei, write_gl_string},
+    error::{GlError, GlFallible, gl_assert},
+    gl_enums::{
+        AtomicCounterBufferPName, AttributeType, GL_INVALID_INDEX, ProgramInterface,
+        ProgramInterfacePName, ProgramResourceProperty, ShaderType, UniformType,
+    },
+    gl_types::{GLchar, GLint, GLsizei, GLuint},
+    program::{
+        LinkedProgram,
+        interface::{InterfaceVariable, ProgramInterfaces, Resource, subroutine_interface},
+    },
+};
+
+impl Context {
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of a program object whose interface to query.
+    ///
+    /// `programInterface`
+    ///
+    /// > A token identifying the interface within `program` to query.
+    ///
+    /// `pname`
+    ///
+    /// > The name of the parameter within `programInterface` to query.
+    ///
+    /// `params`
+    ///
+    /// > The address of a variable to retrieve the value of `pname` for the program
+    /// > interface.
+    ///
+    /// ### Description
+    /// [**glGetProgramInterfaceiv**](crate::context::Context::oxidegl_get_program_interfaceiv)
+    /// queries the property of the interface identifed by `programInterface` in
+    /// `program`, the property name of which is given by `pname`.
+    ///
+    /// `program` must be the name of an existing program object. `programInterface`
+    /// is the name of the interface within `program` to query and must be one
+    /// of the following values:
+    ///
+    /// [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM)
+    ///
+    /// > The query is targeted at the set of active uniforms within `program`.
+    ///
+    /// [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK)
+    ///
+    /// > The query is targeted at the set of active uniform blocks within `program`.
+    ///
+    /// [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER)
+    ///
+    /// > The query is targeted at the set of active atomic counter buffer binding
+    /// > points within `program`.
+    ///
+    /// [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT)
+    ///
+    /// > The query is targeted at the set of active input variables used by the
+    /// > first shader stage of `program`. If `program` contains multiple shader
+    /// > stages then input variables from any stage other than the first will not
+    /// > be enumerated.
+    ///
+    /// [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT)
+    ///
+    /// > The query is targeted at the set of active output variables produced by
+    /// > the last shader stage of `program`. If `program` contains multiple shader
+    /// > stages then output variables from any stage other than the last will not
+    /// > be enumerated.
+    ///
+    /// [`GL_VERTEX_SUBROUTINE`](crate::gl_enums::GL_VERTEX_SUBROUTINE)
+    ///
+    /// [`GL_TESS_CONTROL_SUBROUTINE`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE)
+    ///
+    /// [`GL_TESS_EVALUATION_SUBROUTINE`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE)
+    ///
+    /// [`GL_GEOMETRY_SUBROUTINE`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE)
+    ///
+    /// [`GL_FRAGMENT_SUBROUTINE`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE)
+    ///
+    /// [`GL_COMPUTE_SUBROUTINE`](crate::gl_enums::GL_COMPUTE_SUBROUTINE)
+    ///
+    /// > The query is targeted at the set of active subroutines for the vertex,
+    /// > tessellation control, tessellation evaluation, geometry, fragment and compute
+    /// > shader stages of `program`, respectively.
+    ///
+    /// [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM)
+    ///
+    /// > The query is targeted at the set of active subroutine uniform variables
+    /// > used by the vertex, tessellation control, tessellation evaluation, geometry,
+    /// > fragment and compute shader stages of `program`, respectively.
+    ///
+    /// [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING)
+    ///
+    /// > The query is targeted at the set of output variables from the last non-fragment
+    /// > stage of `program` that would be captured if transform feedback were active.
+    ///
+    /// [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE)
+    ///
+    /// > The query is targeted at the set of active buffer variables used by `program`.
+    ///
+    /// [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK)
+    ///
+    /// > The query is targeted at the set of active shader storage blocks used by
+    /// > `program`.
+    ///
+    /// [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER)
+    ///
+    /// > The query is targeted at the set of active buffer binding points to which
+    /// > output variables in the [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING)
+    /// > interface are written.
+    ///
+    /// `pname` identifies the property of `programInterface` to return in `params`.
+    ///
+    /// If `pname` is [`GL_ACTIVE_RESOURCES`](crate::gl_enums::GL_ACTIVE_RESOURCES),
+    /// the value returned is the number of resources in the active resource list
+    /// for `programInterface`. If the list of active resources for `programInterface`
+    /// is empty, zero is returned.
+    ///
+    /// If `pname` is [`GL_MAX_NAME_LENGTH`](crate::gl_enums::GL_MAX_NAME_LENGTH),
+    /// the value returned is the length of the longest active name string for
+    /// an active resource in `programInterface`. This length includes an extra
+    /// character for the null terminator. If the list of active resources for
+    /// `programInterface` is empty, zero is returned. It is an error to specify
+    /// [`GL_MAX_NAME_LENGTH`](crate::gl_enums::GL_MAX_NAME_LENGTH) when `programInterface`
+    /// is [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER),
+    /// as active atomic counter buffer resources are not assigned name strings.
+    ///
+    /// If `pname` is [`GL_MAX_NUM_ACTIVE_VARIABLES`](crate::gl_enums::GL_MAX_NUM_ACTIVE_VARIABLES),
+    /// the value returned is the number of active variables belonging to the
+    /// interface block or atomic counter buffer resource in `programInterface`
+    /// with the most active variables. If the list of active resources for `programInterface`
+    /// is empty, zero is returned. When `pname` is [`GL_MAX_NUM_ACTIVE_VARIABLES`](crate::gl_enums::GL_MAX_NUM_ACTIVE_VARIABLES),
+    /// `programInterface` must be [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK),
+    /// [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER),
+    /// or [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK).
+    ///
+    /// If `pname` is [`GL_MAX_NUM_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_MAX_NUM_COMPATIBLE_SUBROUTINES),
+    /// the value returned is the number of compatible subroutines belonging to
+    /// the active subroutine uniform in `programInterface` with the most compatible
+    /// subroutines. If the list of active resources for `programInterface` is
+    /// empty, zero is returned. When `pname` is [`GL_MAX_NUM_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_MAX_NUM_COMPATIBLE_SUBROUTINES),
+    /// `programInterface` must be one of [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM),
+    /// [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM),
+    /// [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM),
+    /// [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM),
+    /// [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM),
+    /// or [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM).
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_LABEL_LENGTH`](crate::gl_enums::GL_MAX_LABEL_LENGTH).
+    pub unsafe fn oxidegl_get_program_interfaceiv(
+        &mut self,
+        program: GLuint,
+        program_interface: ProgramInterface,
+        pname: ProgramInterfacePName,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let interfaces = self.program_interfaces(program)?;
+        let count = interfaces.resource_count(program_interface);
+        let value = match pname {
+            ProgramInterfacePName::ActiveResources => count,
+            ProgramInterfacePName::MaxNameLength => {
+                // transform feedback and atomic counter buffers are the only resources without a name
+                gl_assert!(
+                    !matches!(
+                        program_interface,
+                        ProgramInterface::TransformFeedbackBuffer
+                            | ProgramInterface::AtomicCounterBuffer
+                    ),
+                    InvalidOperation
+                );
+                interfaces.max_name_length(program_interface)
+            }
+            ProgramInterfacePName::MaxNumActiveVariables => {
+                gl_assert!(
+                    matches!(
+                        program_interface,
+                        ProgramInterface::UniformBlock
+                            | ProgramInterface::ShaderStorageBlock
+                            | ProgramInterface::TransformFeedbackBuffer
+                            | ProgramInterface::AtomicCounterBuffer
+                    ),
+                    InvalidOperation
+                );
+                (0..count)
+                    .map(|i| interfaces.block_members(program_interface, i).len())
+                    .max()
+                    .unwrap_or(0)
+            }
+            ProgramInterfacePName::MaxNumCompatibleSubroutines => {
+                let Some((stage, true)) = subroutine_interface(program_interface) else {
+                    return Err(GlError::InvalidOperation.e());
+                };
+                interfaces
+                    .stage_subroutines(stage)
+                    .uniforms
+                    .iter()
+                    .map(|u| u.compatible.len())
+                    .max()
+                    .unwrap_or(0)
+            }
+        };
+        // Safety: caller ensures params is valid for writes
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            reason = "resource counts and name lengths are less than i32::MAX"
+        )]
+        unsafe {
+            params.write(value as GLint);
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of a program object whose resources to query.
+    ///
+    /// `programInterface`
+    ///
+    /// > A token identifying the interface within `program` containing the resource
+    /// > named `name`.
+    ///
+    /// ### Description
+    /// [**glGetProgramResourceiv**](crate::context::Context::oxidegl_get_program_resourceiv)
+    /// returns values for multiple properties of a single active resource with
+    /// an index of `index` in the interface `programInterface` of program object
+    /// `program`. For each resource, values for `propCount` properties specified
+    /// by the array `props` are returned. `propCount` may not be zero. An error
+    /// is generated if any value in `props` is not one of the properties described
+    /// immediately belowor if any value in `props` is not allowed for `programInterface`.
+    /// The set of allowed `programInterface` values for each property can be
+    /// found in the following table:
+    ///
+    /// | Property      | Supported Interfaces                              |
+    /// |---------------|---------------------------------------------------|
+    /// | [`GL_NAME_LENGTH`](crate::gl_enums::GL_NAME_LENGTH) | Any except [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER) and [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER) |
+    /// | [`GL_TYPE`](crate::gl_enums::GL_TYPE) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT), [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
+    /// | [`GL_ARRAY_SIZE`](crate::gl_enums::GL_ARRAY_SIZE) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT, VERTEX_SUBROUTINE_UNIFORM`](`crate::gl_enums::GL_PROGRAM_OUTPUT`, `VERTEX_SUBROUTINE_UNIFORM`), [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM), [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM), [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM), [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM), [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM), [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING) |
+    /// | [`GL_OFFSET`](crate::gl_enums::GL_OFFSET) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING) |
+    /// | [`GL_BLOCK_INDEX`](crate::gl_enums::GL_BLOCK_INDEX) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
+    /// | [`GL_ARRAY_STRIDE`](crate::gl_enums::GL_ARRAY_STRIDE) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
+    /// | [`GL_MATRIX_STRIDE`](crate::gl_enums::GL_MATRIX_STRIDE) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
+    /// | [`GL_IS_ROW_MAJOR`](crate::gl_enums::GL_IS_ROW_MAJOR) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
+    /// | [`GL_ATOMIC_COUNTER_BUFFER_INDEX`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_INDEX) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM) |
+    /// | [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER) | *none* |
+    /// | [`GL_BUFFER_BINDING`](crate::gl_enums::GL_BUFFER_BINDING) | [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER) |
+    /// | [`GL_BUFFER_DATA_SIZE`](crate::gl_enums::GL_BUFFER_DATA_SIZE) | [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK) |
+    /// | [`GL_NUM_ACTIVE_VARIABLES`](crate::gl_enums::GL_NUM_ACTIVE_VARIABLES) | [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER) |
+    /// | [`GL_ACTIVE_VARIABLES`](crate::gl_enums::GL_ACTIVE_VARIABLES) | [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER) |
+    /// | [`GL_REFERENCED_BY_VERTEX_SHADER`](crate::gl_enums::GL_REFERENCED_BY_VERTEX_SHADER) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_SHADER), [`GL_BUFFER`](crate::gl_enums::GL_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
+    /// | [`GL_REFERENCED_BY_TESS_CONTROL_SHADER`](crate::gl_enums::GL_REFERENCED_BY_TESS_CONTROL_SHADER) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_SHADER), [`GL_BUFFER`](crate::gl_enums::GL_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
+    /// | [`GL_REFERENCED_BY_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_REFERENCED_BY_TESS_EVALUATION_SHADER) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_SHADER), [`GL_BUFFER`](crate::gl_enums::GL_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
+    /// | [`GL_REFERENCED_BY_GEOMETRY_SHADER`](crate::gl_enums::GL_REFERENCED_BY_GEOMETRY_SHADER) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_SHADER), [`GL_BUFFER`](crate::gl_enums::GL_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
+    /// | [`GL_REFERENCED_BY_FRAGMENT_SHADER`](crate::gl_enums::GL_REFERENCED_BY_FRAGMENT_SHADER) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_SHADER), [`GL_BUFFER`](crate::gl_enums::GL_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
+    /// | [`GL_REFERENCED_BY_COMPUTE_SHADER`](crate::gl_enums::GL_REFERENCED_BY_COMPUTE_SHADER) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_SHADER), [`GL_BUFFER`](crate::gl_enums::GL_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
+    /// | [`GL_NUM_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_NUM_COMPATIBLE_SUBROUTINES) | [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM), [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM), [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM), [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM), [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM), [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM) |
+    /// | [`GL_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_COMPATIBLE_SUBROUTINES) | [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM), [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM), [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM), [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM), [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM), [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM) |
+    /// | [`GL_TOP_LEVEL_ARRAY_SIZE`](crate::gl_enums::GL_TOP_LEVEL_ARRAY_SIZE) | [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
+    /// | [`GL_TOP_LEVEL_ARRAY_STRIDE`](crate::gl_enums::GL_TOP_LEVEL_ARRAY_STRIDE) | [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
+    /// | [`GL_LOCATION`](crate::gl_enums::GL_LOCATION) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT), [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM), [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM), [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM), [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM), [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM), [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM) |
+    /// | [`GL_LOCATION_INDEX`](crate::gl_enums::GL_LOCATION_INDEX) | [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
+    /// | [`GL_IS_PER_PATCH`](crate::gl_enums::GL_IS_PER_PATCH) | [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
+    /// | [`GL_LOCATION_COMPONENT`](crate::gl_enums::GL_LOCATION_COMPONENT) | [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
+    /// | [`GL_TRANSFORM_FEEDBACK_BUFFER_INDEX`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_INDEX) | [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING) |
+    /// | [`GL_TRANSFORM_FEEDBACK_BUFFER_STRIDE`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_STRIDE) | [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER) |
+    ///
+    /// For the property [`GL_NAME_LENGTH`](crate::gl_enums::GL_NAME_LENGTH), a
+    /// single integer identifying the length of the name string associated with
+    /// an active variable, interface block, or subroutine is written to `params`.
+    /// The name length includes a terminating null character.
+    ///
+    /// For the property [`GL_TYPE`](crate::gl_enums::GL_TYPE), a single integer
+    /// identifying the type of an active variable is written to `params`. The
+    /// integer returned is one of the values found in table 2.16.
+    ///
+    /// For the property [`GL_ARRAY_SIZE`](crate::gl_enums::GL_ARRAY_SIZE), a single
+    /// integer identifying the number of active array elements of an active variable
+    /// is written to `params`. The array size returned is in units of the type
+    /// associated with the property [`GL_TYPE`](crate::gl_enums::GL_TYPE). For
+    /// active variables not corresponding to an array of basic types, the value
+    /// zero is written to `params`.
+    ///
+    /// For the property [`GL_BLOCK_INDEX`](crate::gl_enums::GL_BLOCK_INDEX), a
+    /// single integer identifying the index of the active interface block containing
+    /// an active variable is written to `params`. If the variable is not the member
+    /// of an interface block, the value -1 is written to `params`.
+    ///
+    /// For the property [`GL_OFFSET`](crate::gl_enums::GL_OFFSET), a single integer
+    /// identifying the offset of an active variable is written to `params`. For
+    /// variables in the [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM) and [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE)
+    /// interfaces that are backed by a buffer object, the value written is the
+    /// offset of that variable relative to the base of the buffer range holding
+    /// its value. For variables in the [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING)
+    /// interface, the value written is the offset in the transform feedback buffer
+    /// storage assigned to each vertex captured in transform feedback mode where
+    /// the value of the variable will be stored. Such offsets are specified via
+    /// the [**glTransformFeedbackVaryings**](crate::context::Context::oxidegl_transform_feedback_varyings).
+    /// Offsets are expressed in basic machine units. For all variables not recorded
+    /// in transform feedback mode, including the special names `params`.
+    ///
+    /// For the property [`GL_ARRAY_STRIDE`](crate::gl_enums::GL_ARRAY_STRIDE),
+    /// a single integer identifying the stride between array elements in an active
+    /// variable is written to `params`. For active variables declared as an array
+    /// of basic types, the value written is the difference, in basic machine units,
+    /// between the offsets of consecutive elements in an array. For active variables
+    /// not declared as an array of basic types, zero is written to `params`. For
+    /// active variables not backed by a buffer object, -1 is written to `params`,
+    /// regardless of the variable type.
+    ///
+    /// For the property [`GL_MATRIX_STRIDE`](crate::gl_enums::GL_MATRIX_STRIDE),
+    /// a single integer identifying the stride between columns of a column-major
+    /// matrix or rows of a row-major matrix is written to `params`. For active
+    /// variables declared a single matrix or array of matrices, the value written
+    /// is the difference, in basic machine units, between the offsets of consecutive
+    /// columns or rows in each matrix. For active variables not declared as a
+    /// matrix or array of matrices, zero is written to `params`. For active variables
+    /// not backed by a buffer object, -1 is written to `params`, regardless of
+    /// the variable type.
+    ///
+    /// For the property [`GL_IS_ROW_MAJOR`](crate::gl_enums::GL_IS_ROW_MAJOR),
+    /// a single integer identifying whether an active variable is a row-major
+    /// matrix is written to `params`. For active variables backed by a buffer
+    /// object, declared as a single matrix or array of matrices, and stored in
+    /// row-major order, one is written to `params`. For all other active variables,
+    /// zero is written to `params`.
+    ///
+    /// For the property [`GL_ATOMIC_COUNTER_BUFFER_INDEX`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_INDEX),
+    /// a single integer identifying the index of the active atomic counter buffer
+    /// containing an active variable is written to `params`. If the variable is
+    /// not an atomic counter uniform, the value -1 is written to `params`.
+    ///
+    /// For the property [`GL_BUFFER_BINDING`](crate::gl_enums::GL_BUFFER_BINDING),
+    /// to index of the buffer binding point associated with the active uniform
+    /// block, shader storage block, atomic counter buffer or transform feedback
+    /// buffer is written to `params`.
+    ///
+    /// For the property [`GL_BUFFER_DATA_SIZE`](crate::gl_enums::GL_BUFFER_DATA_SIZE),
+    /// then the implementation-dependent minimum total buffer object size, in
+    /// basic machine units, required to hold all active variables associated with
+    /// an active uniform block, shader storage block, or atomic counter buffer
+    /// is written to `params`. If the final member of an active shader storage
+    /// block is array with no declared size, the minimum buffer size is computed
+    /// assuming the array was declared as an array with one element.
+    ///
+    /// For the property [`GL_NUM_ACTIVE_VARIABLES`](crate::gl_enums::GL_NUM_ACTIVE_VARIABLES),
+    /// the number of active variables associated with an active uniform block,
+    /// shader storage block, atomic counter buffer or transform feedback buffer
+    /// is written to `params`.
+    ///
+    /// For the property [`GL_ACTIVE_VARIABLES`](crate::gl_enums::GL_ACTIVE_VARIABLES),
+    /// an array of active variable indices associated with an active uniform
+    /// block, shader storage block, atomic counter buffer or transform feedback
+    /// buffer is written to `params`. The number of values written to `params`
+    /// for an active resource is given by the value of the property [`GL_NUM_ACTIVE_VARIABLES`](crate::gl_enums::GL_NUM_ACTIVE_VARIABLES)
+    /// for the resource.
+    ///
+    /// For the properties [`GL_REFERENCED_BY_VERTEX_SHADER`](crate::gl_enums::GL_REFERENCED_BY_VERTEX_SHADER),
+    /// [`GL_REFERENCED_BY_TESS_CONTROL_SHADER`](crate::gl_enums::GL_REFERENCED_BY_TESS_CONTROL_SHADER),
+    /// [`GL_REFERENCED_BY_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_REFERENCED_BY_TESS_EVALUATION_SHADER),
+    /// [`GL_REFERENCED_BY_GEOMETRY_SHADER`](crate::gl_enums::GL_REFERENCED_BY_GEOMETRY_SHADER),
+    /// [`GL_REFERENCED_BY_FRAGMENT_SHADER`](crate::gl_enums::GL_REFERENCED_BY_FRAGMENT_SHADER),
+    /// and [`GL_REFERENCED_BY_COMPUTE_SHADER`](crate::gl_enums::GL_REFERENCED_BY_COMPUTE_SHADER),
+    /// a single integer is written to `params`, identifying whether the active
+    /// resource is referenced by the vertex, tessellation control, tessellation
+    /// evaluation, geometry, or fragment shaders, respectively, in the program
+    /// object. The value one is written to `params` if an active variable is referenced
+    /// by the corresponding shader, or if an active uniform block, shader storage
+    /// block, or atomic counter buffer contains at least one variable referenced
+    /// by the corresponding shader. Otherwise, the value zero is written to `params`.
+    ///
+    /// For the property [`GL_TOP_LEVEL_ARRAY_SIZE`](crate::gl_enums::GL_TOP_LEVEL_ARRAY_SIZE),
+    /// a single integer identifying the number of active array elements of the
+    /// top-level shader storage block member containing to the active variable
+    /// is written to `params`. If the top-level block member is not declared as
+    /// an array, the value one is written to `params`. If the top-level block
+    /// member is an array with no declared size, the value zero is written to
+    /// `params`.
+    ///
+    /// For the property [`GL_TOP_LEVEL_ARRAY_STRIDE`](crate::gl_enums::GL_TOP_LEVEL_ARRAY_STRIDE),
+    /// a single integer identifying the stride between array elements of the
+    /// top-level shader storage block member containing the active variable is
+    /// written to `params`. For top-level block members declared as arrays, the
+    /// value written is the difference, in basic machine units, between the offsets
+    /// of the active variable for consecutive elements in the top-level array.
+    /// For top-level block members not declared as an array, zero is written to
+    /// `params`.
+    ///
+    /// For the property [`GL_LOCATION`](crate::gl_enums::GL_LOCATION), a single
+    /// integer identifying the assigned location for an active uniform, input,
+    /// output, or subroutine uniform variable is written to `params`. For input,
+    /// output, or uniform variables with locations specified by a layout qualifier,
+    /// the specified location is used. For vertex shader input or fragment shader
+    /// output variables without a layout qualifier, the location assigned when
+    /// a program is linked is written to `params`. For all other input and output
+    /// variables, the value -1 is written to `params`. For uniforms in uniform
+    /// blocks, the value -1 is written to `params`.
+    ///
+    /// For the property [`GL_LOCATION_INDEX`](crate::gl_enums::GL_LOCATION_INDEX),
+    /// a single integer identifying the fragment color index of an active fragment
+    /// shader output variable is written to `params`. If the active variable is
+    /// an output for a non-fragment shader, the value -1 will be written to `params`.
+    ///
+    /// For the property [`GL_IS_PER_PATCH`](crate::gl_enums::GL_IS_PER_PATCH),
+    /// a single integer identifying whether the input or output is a per-patch
+    /// attribute. If the active variable is a per-patch attribute (declared with
+    /// the `params`; otherwise, the value zero is written to `params`.
+    ///
+    /// For the property [`GL_LOCATION_COMPONENT`](crate::gl_enums::GL_LOCATION_COMPONENT),
+    /// a single integer indicating the first component of the location assigned
+    /// to an active input or output variable is written to `params`. For input
+    /// and output variables with a component specified by a
+    ///
+    /// For the property [`GL_TRANSFORM_FEEDBACK_BUFFER_INDEX`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_INDEX),
+    /// a single integer identifying the index of the active transform feedback
+    /// buffer associated with an active variable is written to `params`. For variables
+    /// corresponding to the special names `params`.
+    ///
+    /// For the property [`GL_TRANSFORM_FEEDBACK_BUFFER_STRIDE`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_STRIDE),
+    /// a single integer identifying the stride, in basic machine units, between
+    /// consecutive vertices written to the transform feedback buffer is written
+    /// to `params`.
+    pub unsafe fn oxidegl_get_program_resourceiv(
+        &mut self,
+        program: GLuint,
+        program_interface: ProgramInterface,
+        index: GLuint,
+        prop_count: GLsizei,
+        props: *const ProgramResourceProperty,
+        count: GLsizei,
+        length: *mut GLsizei,
+        params: *mut GLint,
+    ) -> GlFallible {
+        sizei!(prop_count);
+        sizei!(count);
+        gl_assert!(prop_count > 0, InvalidValue);
+        let interfaces = self.program_interfaces(program)?;
+        let index = index as usize;
+        gl_assert!(
+            index < interfaces.resource_count(program_interface),
+            InvalidValue
+        );
+        // Safety: caller ensures props points to prop_count properties
+        let props = unsafe { slice::from_raw_parts(props, prop_count as usize) };
+        let mut values = Vec::with_capacity(props.len());
+        for &prop in props {
+            resource_property(interfaces, program_interface, index, prop, &mut values)?;
+        }
+        let written = values.len().min(count as usize);
+        // Safety: caller ensures params is valid for writes of count integers, and length is either null or valid for writes
+        unsafe {
+            ptr::copy_nonoverlapping(values.as_ptr(), params, written);
+            if !length.is_null() {
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_possible_wrap,
+                    reason = "written is at most count, which came from a GLsizei"
+                )]
+                length.write(written as GLsizei);
+            }
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of a program object whose resources to query.
+    ///
+    /// `programInterface`
+    ///
+    /// > A token identifying the interface within `program` containing the resource
+    /// > named `name`.
+    ///
+    /// `name`
+    ///
+    /// > The name of the resource to query the index of.
+    ///
+    /// ### Description
+    /// [**glGetProgramResourceIndex**](crate::context::Context::oxidegl_get_program_resource_index)
+    /// returns the unsigned integer index assigned to a resource named `name`
+    /// in the interface type `programInterface` of program object `program`.
+    ///
+    /// `program` must be the name of an existing program object. `programInterface`
+    /// is the name of the interface within `program` which contains the resource
+    /// named `name` and must be one of the following values:
+    ///
+    /// [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM)
+    ///
+    /// > The query is targeted at the set of active uniforms within `program`.
+    ///
+    /// [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK)
+    ///
+    /// > The query is targeted at the set of active uniform blocks within `program`.
+    ///
+    /// [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT)
+    ///
+    /// > The query is targeted at the set of active input variables used by the
+    /// > first shader stage of `program`. If `program` contains multiple shader
+    /// > stages then input variables from any stage other than the first will not
+    /// > be enumerated.
+    ///
+    /// [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT)
+    ///
+    /// > The query is targeted at the set of active output variables produced by
+    /// > the last shader stage of `program`. If `program` contains multiple shader
+    /// > stages then output variables from any stage other than the last will not
+    /// > be enumerated.
+    ///
+    /// [`GL_VERTEX_SUBROUTINE`](crate::gl_enums::GL_VERTEX_SUBROUTINE)
+    ///
+    /// [`GL_TESS_CONTROL_SUBROUTINE`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE)
+    ///
+    /// [`GL_TESS_EVALUATION_SUBROUTINE`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE)
+    ///
+    /// [`GL_GEOMETRY_SUBROUTINE`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE)
+    ///
+    /// [`GL_FRAGMENT_SUBROUTINE`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE)
+    ///
+    /// [`GL_COMPUTE_SUBROUTINE`](crate::gl_enums::GL_COMPUTE_SUBROUTINE)
+    ///
+    /// > The query is targeted at the set of active subroutines for the vertex,
+    /// > tessellation control, tessellation evaluation, geometry, fragment and compute
+    /// > shader stages of `program`, respectively.
+    ///
+    /// [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM)
+    ///
+    /// > The query is targeted at the set of active subroutine uniform variables
+    /// > used by the vertex, tessellation control, tessellation evaluation, geometry,
+    /// > fragment and compute shader stages of `program`, respectively.
+    ///
+    /// [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING)
+    ///
+    /// > The query is targeted at the set of output variables from the last non-fragment
+    /// > stage of `program` that would be captured if transform feedback were active.
+    ///
+    /// [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER)
+    ///
+    /// > The query is targeted at the set of active buffer binding points to which
+    /// > output variables in the [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING)
+    /// > interface are written.
+    ///
+    /// [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE)
+    ///
+    /// > The query is targeted at the set of active buffer variables used by `program`.
+    ///
+    /// [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK)
+    ///
+    /// > The query is targeted at the set of active shader storage blocks used by
+    /// > `program`.
+    ///
+    /// If `name` exactly matches the name string of one of the active resources
+    /// for `programInterface`, the index of the matched resource is returned.
+    /// Additionally, if `name` would exactly match the name string of an active
+    /// resource if "\[0\]" were appended to `name`, the index of the matched resource
+    /// is returned. Otherwise, `name` is considered not to be the name of an active
+    /// resource, and [`GL_INVALID_INDEX`](crate::gl_enums::GL_INVALID_INDEX) is
+    /// returned.
+    ///
+    /// For the interface [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING),
+    /// the value [`GL_INVALID_INDEX`](crate::gl_enums::GL_INVALID_INDEX) should
+    /// be returned when querying the index assigned to the special names
+    pub unsafe fn oxidegl_get_program_resource_index(
+        &mut self,
+        program: GLuint,
+        program_interface: ProgramInterface,
+        name: *const GLchar,
+    ) -> GlFallible<GLuint> {
+        gl_assert!(
+            !matches!(
+                program_interface,
+                ProgramInterface::TransformFeedbackBuffer | ProgramInterface::AtomicCounterBuffer
+            ),
+            InvalidEnum
+        );
+        let interfaces = self.program_interfaces(program)?;
+        // Safety: caller ensures name is a valid, null-terminated string
+        let name = unsafe { CStr::from_ptr(name) }.to_str().unwrap_or_default();
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "more than u32::MAX resources cannot exist at once"
+        )]
+        let index = interfaces
+            .resource_index(program_interface, name)
+            .map_or(GL_INVALID_INDEX, |i| i as GLuint);
+        Ok(index)
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of a program object whose resources to query.
+    ///
+    /// `programInterface`
+    ///
+    /// > A token identifying the interface within `program` containing the indexed
+    /// > resource.
+    ///
+    /// `index`
+    ///
+    /// > The index of the resource within `programInterface` of `program`.
+    ///
+    /// `bufSize`
+    ///
+    /// > The size of the character array whose address is given by `name`.
+    ///
+    /// `length`
+    ///
+    /// > The address of a variable which will receive the length of the resource
+    /// > name.
+    ///
+    /// `name`
+    ///
+    /// > The address of a character array into which will be written the name of
+    /// > the resource.
+    ///
+    /// ### Description
+    /// [**glGetProgramResourceName**](crate::context::Context::oxidegl_get_program_resource_name)
+    /// retrieves the name string assigned to the single active resource with an
+    /// index of `index` in the interface `programInterface` of program object
+    /// `program`. `index` must be less than the number of entries in the active
+    /// resource list for `programInterface`.
+    ///
+    /// `program` must be the name of an existing program object. `programInterface`
+    /// is the name of the interface within `program` which contains the resource
+    /// and must be one of the following values:
+    ///
+    /// [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM)
+    ///
+    /// > The query is targeted at the set of active uniforms within `program`.
+    ///
+    /// [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK)
+    ///
+    /// > The query is targeted at the set of active uniform blocks within `program`.
+    ///
+    /// [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT)
+    ///
+    /// > The query is targeted at the set of active input variables used by the
+    /// > first shader stage of `program`. If `program` contains multiple shader
+    /// > stages then input variables from any stage other than the first will not
+    /// > be enumerated.
+    ///
+    /// [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT)
+    ///
+    /// > The query is targeted at the set of active output variables produced by
+    /// > the last shader stage of `program`. If `program` contains multiple shader
+    /// > stages then output variables from any stage other than the last will not
+    /// > be enumerated.
+    ///
+    /// [`GL_VERTEX_SUBROUTINE`](crate::gl_enums::GL_VERTEX_SUBROUTINE)
+    ///
+    /// [`GL_TESS_CONTROL_SUBROUTINE`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE)
+    ///
+    /// [`GL_TESS_EVALUATION_SUBROUTINE`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE)
+    ///
+    /// [`GL_GEOMETRY_SUBROUTINE`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE)
+    ///
+    /// [`GL_FRAGMENT_SUBROUTINE`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE)
+    ///
+    /// [`GL_COMPUTE_SUBROUTINE`](crate::gl_enums::GL_COMPUTE_SUBROUTINE)
+    ///
+    /// > The query is targeted at the set of active subroutines for the vertex,
+    /// > tessellation control, tessellation evaluation, geometry, fragment and compute
+    /// > shader stages of `program`, respectively.
+    ///
+    /// [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM)
+    ///
+    /// [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM)
+    ///
+    /// > The query is targeted at the set of active subroutine uniform variables
+    /// > used by the vertex, tessellation control, tessellation evaluation, geometry,
+    /// > fragment and compute shader stages of `program`, respectively.
+    ///
+    /// [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING)
+    ///
+    /// > The query is targeted at the set of output variables from the last non-fragment
+    /// > stage of `program` that would be captured if transform feedback were active.
+    ///
+    /// [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE)
+    ///
+    /// > The query is targeted at the set of active buffer variables used by `program`.
+    ///
+    /// [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK)
+    ///
+    /// > The query is targeted at the set of active shader storage blocks used by
+    /// > `program`.
+    ///
+    /// The name string assigned to the active resource identified by `index` is
+    /// returned as a null-terminated string in the character array whose address
+    /// is given in `name`. The actual number of characters written into `name`,
+    /// excluding the null terminator, is returned in `length`. If `length` is
+    /// NULL, no length is returned. The maximum number of characters that may
+    /// be written into `name`, including the null terminator, is specified by
+    /// `bufSize`. If the length of the name string *including the null terminator*
+    /// is greater than `bufSize`, the first `bufSize` -1 characters of the name
+    /// string will be written to `name`, followed by a null terminator. If `bufSize`
+    /// is zero, no error will be generated but no characters will be written to
+    /// `name`. The length of the longest name string for `programInterface` >,
+    /// including a null terminator, can be queried by calling [**glGetProgramInterface**](crate::context::Context::oxidegl_get_program_interface)
+    /// with a `pname` of [`GL_MAX_NAME_LENGTH`](crate::gl_enums::GL_MAX_NAME_LENGTH).
+    pub unsafe fn oxidegl_get_program_resource_name(
+        &mut self,
+        program: GLuint,
+        program_interface: ProgramInterface,
+        index: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        name: *mut GLchar,
+    ) -> GlFallible {
+        sizei!(buf_size);
+        gl_assert!(
+            !matches!(
+                program_interface,
+                ProgramInterface::TransformFeedbackBuffer | ProgramInterface::AtomicCounterBuffer
+            ),
+            InvalidEnum
+        );
+        let interfaces = self.program_interfaces(program)?;
+        let Some(resource) = interfaces.resource(program_interface, index as usize) else {
+            return Err(GlError::InvalidValue.e());
+        };
+        // Safety: caller ensures length and name are either null or valid for writes
+        unsafe { write_gl_string(&resource.gl_name(), buf_size, length, name) };
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of a program object whose resources to query.
+    ///
+    /// `programInterface`
+    ///
+    /// > A token identifying the interface within `program` containing the resource
+    /// > named `name`.
+    ///
+    /// `name`
+    ///
+    /// > The name of the resource to query the location of.
+    ///
+    /// ### Description
+    /// [**glGetProgramResourceLocation**](crate::context::Context::oxidegl_get_program_resource_location)
+    /// returns the location assigned to the variable named `name` in interface
+    /// `programInterface` of program object `program`. `program` must be the name
+    /// of a program that has been linked successfully. `programInterface` must
+    /// be one of [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT),
+    /// [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT), [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM),
+    /// [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM),
+    /// [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM),
+    /// [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM),
+    /// [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM),
+    /// [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM),
+    /// or [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER).
+    ///
+    /// The value -1 will be returned if an error occurs, if `name` does not identify
+    /// an active variable on `programInterface`, or if `name` identifies an active
+    /// variable that does not have a valid location assigned, as described above.
+    /// The locations returned by these commands are the same locations returned
+    /// when querying the [`GL_LOCATION`](crate::gl_enums::GL_LOCATION) and [`GL_LOCATION_INDEX`](crate::gl_enums::GL_LOCATION_INDEX)
+    /// resource properties.
+    ///
+    /// A string provided to [**glGetProgramResourceLocation**](crate::context::Context::oxidegl_get_program_resource_location)
+    /// is considered to match an active variable if:
+    ///
+    /// > the string exactly matches the name of the active variable
+    ///
+    /// > if the string identifies the base name of an active array, where the string
+    /// > would exactly match the name of the variable if the suffix "\[0\]" were
+    /// > appended to the string
+    ///
+    /// > if the string identifies an active element of the array, where the string
+    /// > ends with the concatenation of the "\[" character, an integer *with no
+    /// > "+" sign, extra leading zeroes, or whitespace* identifying an array element,
+    /// > and the "\]" character, the integer is less than the number of active elements
+    /// > of the array variable, and where the string would exactly match the enumerated
+    /// > name of the array if the decimal integer were replaced with zero.
+    ///
+    /// Any other string is considered not to identify an active variable. If the
+    /// string specifies an element of an array variable, [**glGetProgramResourceLocation**](crate::context::Context::oxidegl_get_program_resource_location)
+    /// returns the location assigned to that element. If it specifies the base
+    /// name of an array, it identifies the resources associated with the first
+    /// element of the array.
+    pub unsafe fn oxidegl_get_program_resource_location(
+        &mut self,
+        program: GLuint,
+        program_interface: ProgramInterface,
+        name: *const GLchar,
+    ) -> GlFallible<GLint> {
+        gl_assert!(
+            matches!(
+                program_interface,
+                ProgramInterface::Uniform
+                    | ProgramInterface::ProgramInput
+                    | ProgramInterface::ProgramOutput
+                    | ProgramInterface::VertexSubroutineUniform
+                    | ProgramInterface::TessControlSubroutineUniform
+                    | ProgramInterface::TessEvaluationSubroutineUniform
+                    | ProgramInterface::GeometrySubroutineUniform
+                    | ProgramInterface::FragmentSubroutineUniform
+                    | ProgramInterface::ComputeSubroutineUniform
+            ),
+            InvalidEnum
+        );
+        let linkage = self.linked_program(program)?;
+        // Safety: caller ensures name is a valid, null-terminated string
+        let name = unsafe { CStr::from_ptr(name) }.to_str().unwrap_or_default();
+        if name.starts_with("gl_") {
+            return Ok(-1);
+        }
+        Ok(location_value(match program_interface {
+            ProgramInterface::Uniform => linkage.uniforms.location(name),
+            ProgramInterface::ProgramInput | ProgramInterface::ProgramOutput => linkage
+                .interfaces
+                .variable_location(program_interface, name),
+            _ => subroutine_interface(program_interface)
+                .and_then(|(stage, _)| linkage.interfaces.stage_subroutines(stage).location(name)),
+        }))
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of a program object whose resources to query.
+    ///
+    /// `programInterface`
+    ///
+    /// > A token identifying the interface within `program` containing the resource
+    /// > named `name`.
+    ///
+    /// `name`
+    ///
+    /// > The name of the resource to query the location of.
+    ///
+    /// ### Description
+    /// [**glGetProgramResourceLocationIndex**](crate::context::Context::oxidegl_get_program_resource_location_index)
+    /// returns the fragment color index assigned to the variable named `name`
+    /// in interface `programInterface` of program object `program`. `program`
+    /// must be the name of a program that has been linked successfully. `programInterface`
+    /// must be [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT).
+    ///
+    /// The value -1 will be returned if an error occurs, if `name` does not identify
+    /// an active variable on `programInterface`, or if `name` identifies an active
+    /// variable that does not have a valid location assigned, as described above.
+    /// The locations returned by these commands are the same locations returned
+    /// when querying the [`GL_LOCATION`](crate::gl_enums::GL_LOCATION) and [`GL_LOCATION_INDEX`](crate::gl_enums::GL_LOCATION_INDEX)
+    /// resource properties.
+    ///
+    /// A string provided to [**glGetProgramResourceLocationIndex**](crate::context::Context::oxidegl_get_program_resource_location_index)
+    /// is considered to match an active variable if:
+    ///
+    /// > the string exactly matches the name of the active variable
+    ///
+    /// > if the string identifies the base name of an active array, where the string
+    /// > would exactly match the name of the variable if the suffix "\[0\]" were
+    /// > appended to the string
+    ///
+    /// > if the string identifies an active element of the array, where the string
+    /// > ends with the concatenation of the "\[" character, an integer *with no
+    /// > "+" sign, extra leading zeroes, or whitespace* identifying an array element,
+    /// > and the "\]" character, the integer is less than the number of active elements
+    /// > of the array variable, and where the string would exactly match the enumerated
+    /// > name of the array if the decimal integer were replaced with zero.
+    ///
+    /// Any other string is considered not to identify an active variable. If the
+    /// string specifies an element of an array variable, [**glGetProgramResourceLocation**](crate::context::Context::oxidegl_get_program_resource_location)
+    /// returns the location assigned to that element. If it specifies the base
+    /// name of an array, it identifies the resources associated with the first
+    /// element of the array.
+    pub unsafe fn oxidegl_get_program_resource_location_index(
+        &mut self,
+        program: GLuint,
+        program_interface: ProgramInterface,
+        name: *const GLchar,
+    ) -> GlFallible<GLint> {
+        gl_assert!(
+            program_interface == ProgramInterface::ProgramOutput,
+            InvalidEnum
+        );
+        let linkage = self.linked_program(program)?;
+        // Safety: caller ensures name is a valid, null-terminated string
+        let name = unsafe { CStr::from_ptr(name) }.to_str().unwrap_or_default();
+        #[expect(
+            clippy::cast_possible_wrap,
+            reason = "location indices are either 0 or 1"
+        )]
+        let index = linkage
+            .interfaces
+            .find_variable(program_interface, name)
+            .filter(|(v, _)| v.location.is_some() && v.stages.contains(&ShaderType::FragmentShader))
+            .map_or(-1, |(v, _)| v.location_index.unwrap_or(0) as GLint);
+        Ok(index)
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the program object to be queried.
+    ///
+    /// `index`
+    ///
+    /// > Specifies the index of the uniform variable to be queried.
+    ///
+    /// `bufSize`
+    ///
+    /// > Specifies the maximum number of characters OpenGL is allowed to write in
+    /// > the character buffer indicated by `name`.
+    ///
+    /// `length`
+    ///
+    /// > Returns the number of characters actually written by OpenGL in the string
+    /// > indicated by `name` (excluding the null terminator) if a value other than
+    /// > [`NULL`](crate::gl_enums::NULL) is passed.
+    ///
+    /// `size`
+    ///
+    /// > Returns the size of the uniform variable.
+    ///
+    /// `type`
+    ///
+    /// > Returns the data type of the uniform variable.
+    ///
+    /// `name`
+    ///
+    /// > Returns a null terminated string containing the name of the uniform variable.
+    ///
+    /// ### Description
+    /// [**glGetActiveUniform**](crate::context::Context::oxidegl_get_active_uniform)
+    /// returns information about an active uniform variable in the program object
+    /// specified by `program`. The number of active uniform variables can be obtained
+    /// by calling [**glGetProgram**](crate::context::Context::oxidegl_get_program)
+    /// with the value [`GL_ACTIVE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_UNIFORMS).
+    /// A value of 0 for `index` selects the first active uniform variable. Permissible
+    /// values for `index` range from zero to the number of active uniform variables
+    /// minus one.
+    ///
+    /// Shaders may use either built-in uniform variables, user-defined uniform
+    /// variables, or both. Built-in uniform variables have a prefix of "gl_" and
+    /// reference existing OpenGL state or values derived from such state (e.g.,
+    /// `gl_DepthRangeParameters`, see the OpenGL Shading Language specification
+    /// for a complete list.) User-defined uniform variables have arbitrary names
+    /// and obtain their values from the application through calls to [**glUniform**](crate::context::Context::oxidegl_uniform).
+    /// A uniform variable (either built-in or user-defined) is considered active
+    /// if it is determined during the link operation that it may be accessed during
+    /// program execution. Therefore, `program` should have previously been the
+    /// target of a call to [**glLinkProgram**](crate::context::Context::oxidegl_link_program),
+    /// but it is not necessary for it to have been linked successfully.
+    ///
+    /// The size of the character buffer required to store the longest uniform
+    /// variable name in `program` can be obtained by calling [**glGetProgram**](crate::context::Context::oxidegl_get_program)
+    /// with the value [`GL_ACTIVE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_UNIFORM_MAX_LENGTH).
+    /// This value should be used to allocate a buffer of sufficient size to store
+    /// the returned uniform variable name. The size of this character buffer is
+    /// passed in `bufSize`, and a pointer to this character buffer is passed in
+    /// `name.`
+    ///
+    /// [**glGetActiveUniform**](crate::context::Context::oxidegl_get_active_uniform)
+    /// returns the name of the uniform variable indicated by `index`, storing
+    /// it in the character buffer specified by `name`. The string returned will
+    /// be null terminated. The actual number of characters written into this buffer
+    /// is returned in `length`, and this count does not include the null termination
+    /// character. If the length of the returned string is not required, a value
+    /// of [`NULL`](crate::gl_enums::NULL) can be passed in the `length` argument.
+    ///
+    /// The `type` argument will return a pointer to the uniform variable's data
+    /// type. The symbolic constants returned for uniform types are shown in the
+    /// table below.
+    /// |* Returned Symbolic Contant*                                 |* Shader Uniform Type*                              |
+    /// |-------------------------------------------------------------|----------------------------------------------------|
+    /// | [`GL_FLOAT`](crate::gl_enums::GL_FLOAT)                     | [`float`](crate::gl_enums::float)                  |
+    /// | [`GL_FLOAT_VEC2`](crate::gl_enums::GL_FLOAT_VEC2)           | [`vec2`](crate::gl_enums::vec2)                    |
+    /// | [`GL_FLOAT_VEC3`](crate::gl_enums::GL_FLOAT_VEC3)           | [`vec3`](crate::gl_enums::vec3)                    |
+    /// | [`GL_FLOAT_VEC4`](crate::gl_enums::GL_FLOAT_VEC4)           | [`vec4`](crate::gl_enums::vec4)                    |
+    /// | [`GL_DOUBLE`](crate::gl_enums::GL_DOUBLE)                   | [`double`](crate::gl_enums::double)                |
+    /// | [`GL_DOUBLE_VEC2`](crate::gl_enums::GL_DOUBLE_VEC2)         | [`dvec2`](crate::gl_enums::dvec2)                  |
+    /// | [`GL_DOUBLE_VEC3`](crate::gl_enums::GL_DOUBLE_VEC3)         | [`dvec3`](crate::gl_enums::dvec3)                  |
+    /// | [`GL_DOUBLE_VEC4`](crate::gl_enums::GL_DOUBLE_VEC4)         | [`dvec4`](crate::gl_enums::dvec4)                  |
+    /// | [`GL_INT`](crate::gl_enums::GL_INT)                         | [`int`](crate::gl_enums::int)                      |
+    /// | [`GL_INT_VEC2`](crate::gl_enums::GL_INT_VEC2)               | [`ivec2`](crate::gl_enums::ivec2)                  |
+    /// | [`GL_INT_VEC3`](crate::gl_enums::GL_INT_VEC3)               | [`ivec3`](crate::gl_enums::ivec3)                  |
+    /// | [`GL_INT_VEC4`](crate::gl_enums::GL_INT_VEC4)               | [`ivec4`](crate::gl_enums::ivec4)                  |
+    /// | [`GL_UNSIGNED_INT`](crate::gl_enums::GL_UNSIGNED_INT)       | [`unsigned int`](`crate::gl_enums::unsigned` int)    |
+    /// | [`GL_UNSIGNED_INT_VEC2`](crate::gl_enums::GL_UNSIGNED_INT_VEC2) | [`uvec2`](crate::gl_enums::uvec2)              |
+    /// | [`GL_UNSIGNED_INT_VEC3`](crate::gl_enums::GL_UNSIGNED_INT_VEC3) | [`uvec3`](crate::gl_enums::uvec3)              |
+    /// | [`GL_UNSIGNED_INT_VEC4`](crate::gl_enums::GL_UNSIGNED_INT_VEC4) | [`uvec4`](crate::gl_enums::uvec4)              |
+    /// | [`GL_BOOL`](crate::gl_enums::GL_BOOL)                       | [`bool`](crate::gl_enums::bool)                    |
+    /// | [`GL_BOOL_VEC2`](crate::gl_enums::GL_BOOL_VEC2)             | [`bvec2`](crate::gl_enums::bvec2)                  |
+    /// | [`GL_BOOL_VEC3`](crate::gl_enums::GL_BOOL_VEC3)             | [`bvec3`](crate::gl_enums::bvec3)                  |
+    /// | [`GL_BOOL_VEC4`](crate::gl_enums::GL_BOOL_VEC4)             | [`bvec4`](crate::gl_enums::bvec4)                  |
+    /// | [`GL_FLOAT_MAT2`](crate::gl_enums::GL_FLOAT_MAT2)           | [`mat2`](crate::gl_enums::mat2)                    |
+    /// | [`GL_FLOAT_MAT3`](crate::gl_enums::GL_FLOAT_MAT3)           | [`mat3`](crate::gl_enums::mat3)                    |
+    /// | [`GL_FLOAT_MAT4`](crate::gl_enums::GL_FLOAT_MAT4)           | [`mat4`](crate::gl_enums::mat4)                    |
+    /// | [`GL_FLOAT_MAT2x3`](crate::gl_enums::GL_FLOAT_MAT2x3)       | [`mat2x3`](crate::gl_enums::mat2x3)                |
+    /// | [`GL_FLOAT_MAT2x4`](crate::gl_enums::GL_FLOAT_MAT2x4)       | [`mat2x4`](crate::gl_enums::mat2x4)                |
+    /// | [`GL_FLOAT_MAT3x2`](crate::gl_enums::GL_FLOAT_MAT3x2)       | [`mat3x2`](crate::gl_enums::mat3x2)                |
+    /// | [`GL_FLOAT_MAT3x4`](crate::gl_enums::GL_FLOAT_MAT3x4)       | [`mat3x4`](crate::gl_enums::mat3x4)                |
+    /// | [`GL_FLOAT_MAT4x2`](crate::gl_enums::GL_FLOAT_MAT4x2)       | [`mat4x2`](crate::gl_enums::mat4x2)                |
+    /// | [`GL_FLOAT_MAT4x3`](crate::gl_enums::GL_FLOAT_MAT4x3)       | [`mat4x3`](crate::gl_enums::mat4x3)                |
+    /// | [`GL_DOUBLE_MAT2`](crate::gl_enums::GL_DOUBLE_MAT2)         | [`dmat2`](crate::gl_enums::dmat2)                  |
+    /// | [`GL_DOUBLE_MAT3`](crate::gl_enums::GL_DOUBLE_MAT3)         | [`dmat3`](crate::gl_enums::dmat3)                  |
+    /// | [`GL_DOUBLE_MAT4`](crate::gl_enums::GL_DOUBLE_MAT4)         | [`dmat4`](crate::gl_enums::dmat4)                  |
+    /// | [`GL_DOUBLE_MAT2x3`](crate::gl_enums::GL_DOUBLE_MAT2x3)     | [`dmat2x3`](crate::gl_enums::dmat2x3)              |
+    /// | [`GL_DOUBLE_MAT2x4`](crate::gl_enums::GL_DOUBLE_MAT2x4)     | [`dmat2x4`](crate::gl_enums::dmat2x4)              |
+    /// | [`GL_DOUBLE_MAT3x2`](crate::gl_enums::GL_DOUBLE_MAT3x2)     | [`dmat3x2`](crate::gl_enums::dmat3x2)              |
+    /// | [`GL_DOUBLE_MAT3x4`](crate::gl_enums::GL_DOUBLE_MAT3x4)     | [`dmat3x4`](crate::gl_enums::dmat3x4)              |
+    /// | [`GL_DOUBLE_MAT4x2`](crate::gl_enums::GL_DOUBLE_MAT4x2)     | [`dmat4x2`](crate::gl_enums::dmat4x2)              |
+    /// | [`GL_DOUBLE_MAT4x3`](crate::gl_enums::GL_DOUBLE_MAT4x3)     | [`dmat4x3`](crate::gl_enums::dmat4x3)              |
+    /// | [`GL_SAMPLER_1D`](crate::gl_enums::GL_SAMPLER_1D)           | [`sampler1D`](crate::gl_enums::sampler1D)          |
+    /// | [`GL_SAMPLER_2D`](crate::gl_enums::GL_SAMPLER_2D)           | [`sampler2D`](crate::gl_enums::sampler2D)          |
+    /// | [`GL_SAMPLER_3D`](crate::gl_enums::GL_SAMPLER_3D)           | [`sampler3D`](crate::gl_enums::sampler3D)          |
+    /// | [`GL_SAMPLER_CUBE`](crate::gl_enums::GL_SAMPLER_CUBE)       | [`samplerCube`](crate::gl_enums::samplerCube)      |
+    /// | [`GL_SAMPLER_1D_SHADOW`](crate::gl_enums::GL_SAMPLER_1D_SHADOW) | [`sampler1DShadow`](crate::gl_enums::sampler1DShadow) |
+    /// | [`GL_SAMPLER_2D_SHADOW`](crate::gl_enums::GL_SAMPLER_2D_SHADOW) | [`sampler2DShadow`](crate::gl_enums::sampler2DShadow) |
+    /// | [`GL_SAMPLER_1D_ARRAY`](crate::gl_enums::GL_SAMPLER_1D_ARRAY) | [`sampler1DArray`](crate::gl_enums::sampler1DArray) |
+    /// | [`GL_SAMPLER_2D_ARRAY`](crate::gl_enums::GL_SAMPLER_2D_ARRAY) | [`sampler2DArray`](crate::gl_enums::sampler2DArray) |
+    /// | [`GL_SAMPLER_1D_ARRAY_SHADOW`](crate::gl_enums::GL_SAMPLER_1D_ARRAY_SHADOW) | [`sampler1DArrayShadow`](crate::gl_enums::sampler1DArrayShadow) |
+    /// | [`GL_SAMPLER_2D_ARRAY_SHADOW`](crate::gl_enums::GL_SAMPLER_2D_ARRAY_SHADOW) | [`sampler2DArrayShadow`](crate::gl_enums::sampler2DArrayShadow) |
+    /// | [`GL_SAMPLER_2D_MULTISAMPLE`](crate::gl_enums::GL_SAMPLER_2D_MULTISAMPLE) | [`sampler2DMS`](crate::gl_enums::sampler2DMS) |
+    /// | [`GL_SAMPLER_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_SAMPLER_2D_MULTISAMPLE_ARRAY) | [`sampler2DMSArray`](crate::gl_enums::sampler2DMSArray) |
+    /// | [`GL_SAMPLER_CUBE_SHADOW`](crate::gl_enums::GL_SAMPLER_CUBE_SHADOW) | [`samplerCubeShadow`](crate::gl_enums::samplerCubeShadow) |
+    /// | [`GL_SAMPLER_BUFFER`](crate::gl_enums::GL_SAMPLER_BUFFER)   | [`samplerBuffer`](crate::gl_enums::samplerBuffer)  |
+    /// | [`GL_SAMPLER_2D_RECT`](crate::gl_enums::GL_SAMPLER_2D_RECT) | [`sampler2DRect`](crate::gl_enums::sampler2DRect)  |
+    /// | [`GL_SAMPLER_2D_RECT_SHADOW`](crate::gl_enums::GL_SAMPLER_2D_RECT_SHADOW) | [`sampler2DRectShadow`](crate::gl_enums::sampler2DRectShadow) |
+    /// | [`GL_INT_SAMPLER_1D`](crate::gl_enums::GL_INT_SAMPLER_1D)   | [`isampler1D`](crate::gl_enums::isampler1D)        |
+    /// | [`GL_INT_SAMPLER_2D`](crate::gl_enums::GL_INT_SAMPLER_2D)   | [`isampler2D`](crate::gl_enums::isampler2D)        |
+    /// | [`GL_INT_SAMPLER_3D`](crate::gl_enums::GL_INT_SAMPLER_3D)   | [`isampler3D`](crate::gl_enums::isampler3D)        |
+    /// | [`GL_INT_SAMPLER_CUBE`](crate::gl_enums::GL_INT_SAMPLER_CUBE) | [`isamplerCube`](crate::gl_enums::isamplerCube)  |
+    /// | [`GL_INT_SAMPLER_1D_ARRAY`](crate::gl_enums::GL_INT_SAMPLER_1D_ARRAY) | [`isampler1DArray`](crate::gl_enums::isampler1DArray) |
+    /// | [`GL_INT_SAMPLER_2D_ARRAY`](crate::gl_enums::GL_INT_SAMPLER_2D_ARRAY) | [`isampler2DArray`](crate::gl_enums::isampler2DArray) |
+    /// | [`GL_INT_SAMPLER_2D_MULTISAMPLE`](crate::gl_enums::GL_INT_SAMPLER_2D_MULTISAMPLE) | [`isampler2DMS`](crate::gl_enums::isampler2DMS) |
+    /// | [`GL_INT_SAMPLER_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_INT_SAMPLER_2D_MULTISAMPLE_ARRAY) | [`isampler2DMSArray`](crate::gl_enums::isampler2DMSArray) |
+    /// | [`GL_INT_SAMPLER_BUFFER`](crate::gl_enums::GL_INT_SAMPLER_BUFFER) | [`isamplerBuffer`](crate::gl_enums::isamplerBuffer) |
+    /// | [`GL_INT_SAMPLER_2D_RECT`](crate::gl_enums::GL_INT_SAMPLER_2D_RECT) | [`isampler2DRect`](crate::gl_enums::isampler2DRect) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_1D`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_1D) | [`usampler1D`](crate::gl_enums::usampler1D) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_2D`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D) | [`usampler2D`](crate::gl_enums::usampler2D) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_3D`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_3D) | [`usampler3D`](crate::gl_enums::usampler3D) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_CUBE`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_CUBE) | [`usamplerCube`](crate::gl_enums::usamplerCube) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_1D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_1D_ARRAY) | [`usampler2DArray`](crate::gl_enums::usampler2DArray) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_ARRAY) | [`usampler2DArray`](crate::gl_enums::usampler2DArray) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE) | [`usampler2DMS`](crate::gl_enums::usampler2DMS) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY) | [`usampler2DMSArray`](crate::gl_enums::usampler2DMSArray) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_BUFFER`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_BUFFER) | [`usamplerBuffer`](crate::gl_enums::usamplerBuffer) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_RECT`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_RECT) | [`usampler2DRect`](crate::gl_enums::usampler2DRect) |
+    /// | [`GL_IMAGE_1D`](crate::gl_enums::GL_IMAGE_1D)               | [`image1D`](crate::gl_enums::image1D)              |
+    /// | [`GL_IMAGE_2D`](crate::gl_enums::GL_IMAGE_2D)               | [`image2D`](crate::gl_enums::image2D)              |
+    /// | [`GL_IMAGE_3D`](crate::gl_enums::GL_IMAGE_3D)               | [`image3D`](crate::gl_enums::image3D)              |
+    /// | [`GL_IMAGE_2D_RECT`](crate::gl_enums::GL_IMAGE_2D_RECT)     | [`image2DRect`](crate::gl_enums::image2DRect)      |
+    /// | [`GL_IMAGE_CUBE`](crate::gl_enums::GL_IMAGE_CUBE)           | [`imageCube`](crate::gl_enums::imageCube)          |
+    /// | [`GL_IMAGE_BUFFER`](crate::gl_enums::GL_IMAGE_BUFFER)       | [`imageBuffer`](crate::gl_enums::imageBuffer)      |
+    /// | [`GL_IMAGE_1D_ARRAY`](crate::gl_enums::GL_IMAGE_1D_ARRAY)   | [`image1DArray`](crate::gl_enums::image1DArray)    |
+    /// | [`GL_IMAGE_2D_ARRAY`](crate::gl_enums::GL_IMAGE_2D_ARRAY)   | [`image2DArray`](crate::gl_enums::image2DArray)    |
+    /// | [`GL_IMAGE_2D_MULTISAMPLE`](crate::gl_enums::GL_IMAGE_2D_MULTISAMPLE) | [`image2DMS`](crate::gl_enums::image2DMS) |
+    /// | [`GL_IMAGE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_IMAGE_2D_MULTISAMPLE_ARRAY) | [`image2DMSArray`](crate::gl_enums::image2DMSArray) |
+    /// | [`GL_INT_IMAGE_1D`](crate::gl_enums::GL_INT_IMAGE_1D)       | [`iimage1D`](crate::gl_enums::iimage1D)            |
+    /// | [`GL_INT_IMAGE_2D`](crate::gl_enums::GL_INT_IMAGE_2D)       | [`iimage2D`](crate::gl_enums::iimage2D)            |
+    /// | [`GL_INT_IMAGE_3D`](crate::gl_enums::GL_INT_IMAGE_3D)       | [`iimage3D`](crate::gl_enums::iimage3D)            |
+    /// | [`GL_INT_IMAGE_2D_RECT`](crate::gl_enums::GL_INT_IMAGE_2D_RECT) | [`iimage2DRect`](crate::gl_enums::iimage2DRect) |
+    /// | [`GL_INT_IMAGE_CUBE`](crate::gl_enums::GL_INT_IMAGE_CUBE)   | [`iimageCube`](crate::gl_enums::iimageCube)        |
+    /// | [`GL_INT_IMAGE_BUFFER`](crate::gl_enums::GL_INT_IMAGE_BUFFER) | [`iimageBuffer`](crate::gl_enums::iimageBuffer)  |
+    /// | [`GL_INT_IMAGE_1D_ARRAY`](crate::gl_enums::GL_INT_IMAGE_1D_ARRAY) | [`iimage1DArray`](crate::gl_enums::iimage1DArray) |
+    /// | [`GL_INT_IMAGE_2D_ARRAY`](crate::gl_enums::GL_INT_IMAGE_2D_ARRAY) | [`iimage2DArray`](crate::gl_enums::iimage2DArray) |
+    /// | [`GL_INT_IMAGE_2D_MULTISAMPLE`](crate::gl_enums::GL_INT_IMAGE_2D_MULTISAMPLE) | [`iimage2DMS`](crate::gl_enums::iimage2DMS) |
+    /// | [`GL_INT_IMAGE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_INT_IMAGE_2D_MULTISAMPLE_ARRAY) | [`iimage2DMSArray`](crate::gl_enums::iimage2DMSArray) |
+    /// | [`GL_UNSIGNED_INT_IMAGE_1D`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_1D) | [`uimage1D`](crate::gl_enums::uimage1D) |
+    /// | [`GL_UNSIGNED_INT_IMAGE_2D`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D) | [`uimage2D`](crate::gl_enums::uimage2D) |
+    /// | [`GL_UNSIGNED_INT_IMAGE_3D`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_3D) | [`uimage3D`](crate::gl_enums::uimage3D) |
+    /// | [`GL_UNSIGNED_INT_IMAGE_2D_RECT`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_RECT) | [`uimage2DRect`](crate::gl_enums::uimage2DRect) |
+    /// | [`GL_UNSIGNED_INT_IMAGE_CUBE`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_CUBE) | [`uimageCube`](crate::gl_enums::uimageCube) |
+    /// | [`GL_UNSIGNED_INT_IMAGE_BUFFER`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_BUFFER) | [`uimageBuffer`](crate::gl_enums::uimageBuffer) |
+    /// | [`GL_UNSIGNED_INT_IMAGE_1D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_1D_ARRAY) | [`uimage1DArray`](crate::gl_enums::uimage1DArray) |
+    /// | [`GL_UNSIGNED_INT_IMAGE_2D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_ARRAY) | [`uimage2DArray`](crate::gl_enums::uimage2DArray) |
+    /// | [`GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE) | [`uimage2DMS`](crate::gl_enums::uimage2DMS) |
+    /// | [`GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY) | [`uimage2DMSArray`](crate::gl_enums::uimage2DMSArray) |
+    /// | [`GL_UNSIGNED_INT_ATOMIC_COUNTER`](crate::gl_enums::GL_UNSIGNED_INT_ATOMIC_COUNTER) | [`atomic_uint`](crate::gl_enums::atomic_uint) |
+    ///
+    ///
+    /// If one or more elements of an array are active, the name of the array is
+    /// returned in `name`, the type is returned in `type`, and the `size` parameter
+    /// returns the highest array element index used, plus one, as determined by
+    /// the compiler and/or linker. Only one active uniform variable will be reported
+    /// for a uniform array.
+    ///
+    /// Uniform variables that are declared as structures or arrays of structures
+    /// will not be returned directly by this function. Instead, each of these
+    /// uniform variables will be reduced to its fundamental components containing
+    /// the "." and "\[\]" operators such that each of the names is valid as an
+    /// argument to [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location).
+    /// Each of these reduced uniform variables is counted as one active uniform
+    /// variable and is assigned an index. A valid name cannot be a structure,
+    /// an array of structures, or a subcomponent of a vector or matrix.
+    ///
+    /// The size of the uniform variable will be returned in `size`. Uniform variables
+    /// other than arrays will have a size of 1. Structures and arrays of structures
+    /// will be reduced as described earlier, such that each of the names returned
+    /// will be a data type in the earlier list. If this reduction results in an
+    /// array, the size returned will be as described for uniform arrays; otherwise,
+    /// the size returned will be 1.
+    ///
+    /// The list of active uniform variables may include both built-in uniform
+    /// variables (which begin with the prefix "gl_") as well as user-defined uniform
+    /// variable names.
+    ///
+    /// This function will return as much information as it can about the specified
+    /// active uniform variable. If no information is available, `length` will
+    /// be 0, and `name` will be an empty string. This situation could occur if
+    /// this function is called after a link operation that failed. If an error
+    /// occurs, the return values `length`, `size`, `type`, and `name` will be
+    /// unmodified.
+    ///
+    /// ### Notes
+    /// The double types, [`GL_DOUBLE`](crate::gl_enums::GL_DOUBLE), [`GL_DOUBLE_VEC2`](crate::gl_enums::GL_DOUBLE_VEC2),
+    /// [`GL_DOUBLE_VEC3`](crate::gl_enums::GL_DOUBLE_VEC3), [`GL_DOUBLE_VEC4`](crate::gl_enums::GL_DOUBLE_VEC4),
+    /// [`GL_DOUBLE_MAT2`](crate::gl_enums::GL_DOUBLE_MAT2), [`GL_DOUBLE_MAT3`](crate::gl_enums::GL_DOUBLE_MAT3),
+    /// [`GL_DOUBLE_MAT4`](crate::gl_enums::GL_DOUBLE_MAT4), [`GL_DOUBLE_MAT2x3`](crate::gl_enums::GL_DOUBLE_MAT2x3),
+    /// [`GL_DOUBLE_MAT2x4`](crate::gl_enums::GL_DOUBLE_MAT2x4), [`GL_DOUBLE_MAT3x2`](crate::gl_enums::GL_DOUBLE_MAT3x2),
+    /// [`GL_DOUBLE_MAT3x4`](crate::gl_enums::GL_DOUBLE_MAT3x4), [`GL_DOUBLE_MAT4x2`](crate::gl_enums::GL_DOUBLE_MAT4x2),
+    /// and [`GL_DOUBLE_MAT4x3`](crate::gl_enums::GL_DOUBLE_MAT4x3) are only available
+    /// if the GL version is 4.1 or higher.
+    ///
+    /// The image types, [`GL_IMAGE_1D`](crate::gl_enums::GL_IMAGE_1D), [`GL_IMAGE_2D`](crate::gl_enums::GL_IMAGE_2D),
+    /// [`GL_IMAGE_3D`](crate::gl_enums::GL_IMAGE_3D), [`GL_IMAGE_2D_RECT`](crate::gl_enums::GL_IMAGE_2D_RECT),
+    /// [`GL_IMAGE_CUBE`](crate::gl_enums::GL_IMAGE_CUBE), [`GL_IMAGE_BUFFER`](crate::gl_enums::GL_IMAGE_BUFFER),
+    /// [`GL_IMAGE_1D_ARRAY`](crate::gl_enums::GL_IMAGE_1D_ARRAY), [`GL_IMAGE_2D_ARRAY`](crate::gl_enums::GL_IMAGE_2D_ARRAY),
+    /// [`GL_IMAGE_2D_MULTISAMPLE`](crate::gl_enums::GL_IMAGE_2D_MULTISAMPLE), [`GL_IMAGE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_IMAGE_2D_MULTISAMPLE_ARRAY),
+    /// [`GL_INT_IMAGE_1D`](crate::gl_enums::GL_INT_IMAGE_1D), [`GL_INT_IMAGE_2D`](crate::gl_enums::GL_INT_IMAGE_2D),
+    /// [`GL_INT_IMAGE_3D`](crate::gl_enums::GL_INT_IMAGE_3D), [`GL_INT_IMAGE_2D_RECT`](crate::gl_enums::GL_INT_IMAGE_2D_RECT),
+    /// [`GL_INT_IMAGE_CUBE`](crate::gl_enums::GL_INT_IMAGE_CUBE), [`GL_INT_IMAGE_BUFFER`](crate::gl_enums::GL_INT_IMAGE_BUFFER),
+    /// [`GL_INT_IMAGE_1D_ARRAY`](crate::gl_enums::GL_INT_IMAGE_1D_ARRAY), [`GL_INT_IMAGE_2D_ARRAY`](crate::gl_enums::GL_INT_IMAGE_2D_ARRAY),
+    /// [`GL_INT_IMAGE_2D_MULTISAMPLE`](crate::gl_enums::GL_INT_IMAGE_2D_MULTISAMPLE),
+    /// [`GL_INT_IMAGE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_INT_IMAGE_2D_MULTISAMPLE_ARRAY),
+    /// [`GL_UNSIGNED_INT_IMAGE_1D`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_1D),
+    /// [`GL_UNSIGNED_INT_IMAGE_2D`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D),
+    /// [`GL_UNSIGNED_INT_IMAGE_3D`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_3D),
+    /// [`GL_UNSIGNED_INT_IMAGE_2D_RECT`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_RECT),
+    /// [`GL_UNSIGNED_INT_IMAGE_CUBE`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_CUBE),
+    /// [`GL_UNSIGNED_INT_IMAGE_BUFFER`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_BUFFER),
+    /// [`GL_UNSIGNED_INT_IMAGE_1D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_1D_ARRAY),
+    /// [`GL_UNSIGNED_INT_IMAGE_2D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_ARRAY),
+    /// [`GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE),
+    /// [`GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY),
+    /// and the atomic counter type, [`GL_UNSIGNED_INT_ATOMIC_COUNTER`](crate::gl_enums::GL_UNSIGNED_INT_ATOMIC_COUNTER)
+    /// are only available if the GL version is 4.2 or higher.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_VERTEX_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_VERTEX_UNIFORM_COMPONENTS),
+    /// [`GL_MAX_GEOMETRY_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_GEOMETRY_UNIFORM_COMPONENTS),
+    /// [`GL_MAX_TESS_CONTROL_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_TESS_CONTROL_UNIFORM_COMPONENTS),
+    /// [`GL_MAX_TESS_EVALUATION_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_TESS_EVALUATION_UNIFORM_COMPONENTS),
+    /// [`GL_MAX_FRAGMENT_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_FRAGMENT_UNIFORM_COMPONENTS).
+    ///
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
+    /// [`GL_ACTIVE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_UNIFORMS) or [`GL_ACTIVE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_UNIFORM_MAX_LENGTH).
+    ///
+    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
+    pub unsafe fn oxidegl_get_active_uniform(
+        &mut self,
+        program: GLuint,
+        index: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        size: *mut GLint,
+        r#type: *mut UniformType,
+        name: *mut GLchar,
+    ) -> GlFallible {
+        sizei!(buf_size);
+        let interfaces = self.program_interfaces(program)?;
+        let Some(uniform) = interfaces.uniforms.get(index as usize) else {
+            return Err(GlError::InvalidValue.e());
+        };
+        // Safety: caller ensures length and name are either null or valid for writes, and size and type are valid for writes
+        #[expect(
+            clippy::cast_possible_wrap,
+            reason = "arrays are shorter than i32::MAX"
+        )]
+        unsafe {
+            write_gl_string(&uniform.gl_name(), buf_size, length, name);
+            size.write(uniform.len as GLint);
+            r#type.write(uniform.ty);
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the program object to be queried.
+    ///
+    /// `name`
+    ///
+    /// > Points to a null terminated string containing the name of the uniform variable
+    /// > whose location is to be queried.
+    ///
+    /// ### Description
+    /// [**glGetUniformLocation **](crate::context::Context::oxidegl_get_uniform_location
+    ///) returns an integer that represents the location of a specific uniform
+    /// variable within a program object. `name` must be a null terminated string
+    /// that contains no white space. `name` must be an active uniform variable
+    /// name in `program` that is not a structure, an array of structures, or a
+    /// subcomponent of a vector or a matrix. This function returns -1 if `name`
+    /// does not correspond to an active uniform variable in `program`, if `name`
+    /// starts with the reserved prefix "gl_", or if `name` is associated with
+    /// an atomic counter or a named uniform block.
+    ///
+    /// Uniform variables that are structures or arrays of structures may be queried
+    /// by calling [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location)
+    /// for each field within the structure. The array element operator "\[\]"
+    /// and the structure field operator "." may be used in `name` in order to
+    /// select elements within an array or fields within a structure. The result
+    /// of using these operators is not allowed to be another structure, an array
+    /// of structures, or a subcomponent of a vector or a matrix. Except if the
+    /// last part of `name` indicates a uniform variable array, the location of
+    /// the first element of an array can be retrieved by using the name of the
+    /// array, or by using the name appended by "\[0\]".
+    ///
+    /// The actual locations assigned to uniform variables are not known until
+    /// the program object is linked successfully. After linking has occurred,
+    /// the command [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location)
+    /// can be used to obtain the location of a uniform variable. This location
+    /// value can then be passed to [**glUniform**](crate::context::Context::oxidegl_uniform)
+    /// to set the value of the uniform variable or to [**glGetUniform**](crate::context::Context::oxidegl_get_uniform)
+    /// in order to query the current value of the uniform variable. After a program
+    /// object has been linked successfully, the index values for uniform variables
+    /// remain fixed until the next link command occurs. Uniform variable locations
+    /// and values can only be queried after a link if the link was successful.
+    ///
+    /// ### Associated Gets
+    /// [**glGetActiveUniform**](crate::context::Context::oxidegl_get_active_uniform)
+    /// with arguments `program` and the index of an active uniform variable
+    ///
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with arguments
+    /// `program` and [`GL_ACTIVE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_UNIFORMS)
+    /// or [`GL_ACTIVE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_UNIFORM_MAX_LENGTH)
+    ///
+    /// [**glGetUniform**](crate::context::Context::oxidegl_get_uniform) with arguments
+    /// `program` and the name of a uniform variable
+    ///
+    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
+    pub unsafe fn oxidegl_get_uniform_location(
+        &mut self,
+        program: GLuint,
+        name: *const GLchar,
+    ) -> GlFallible<GLint> {
+        let linkage = self.linked_program(program)?;
+        // Safety: caller ensures name is a valid, null-terminated string
+        let name = unsafe { CStr::from_ptr(name) }.to_str().unwrap_or_default();
+        if name.starts_with("gl_") {
+            return Ok(-1);
+        }
+        Ok(location_value(linkage.uniforms.location(name)))
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the program object to be queried.
+    ///
+    /// `index`
+    ///
+    /// > Specifies the index of the attribute variable to be queried.
+    ///
+    /// `bufSize`
+    ///
+    /// > Specifies the maximum number of characters OpenGL is allowed to write in
+    /// > the character buffer indicated by `name`.
+    ///
+    /// `length`
+    ///
+    /// > Returns the number of characters actually written by OpenGL in the string
+    /// > indicated by `name` (excluding the null terminator) if a value other than
+    /// > [`NULL`](crate::gl_enums::NULL) is passed.
+    ///
+    /// `size`
+    ///
+    /// > Returns the size of the attribute variable.
+    ///
+    /// `type`
+    ///
+    /// > Returns the data type of the attribute variable.
+    ///
+    /// `name`
+    ///
+    /// > Returns a null terminated string containing the name of the attribute variable.
+    ///
+    /// ### Description
+    /// [**glGetActiveAttrib**](crate::context::Context::oxidegl_get_active_attrib)
+    /// returns information about an active attribute variable in the program object
+    /// specified by `program`. The number of active attributes can be obtained
+    /// by calling [**glGetProgram**](crate::context::Context::oxidegl_get_program)
+    /// with the value [`GL_ACTIVE_ATTRIBUTES`](crate::gl_enums::GL_ACTIVE_ATTRIBUTES).
+    /// A value of 0 for `index` selects the first active attribute variable.
+    /// Permissible values for `index` range from zero to the number of active
+    /// attribute variables minus one.
+    ///
+    /// A vertex shader may use either built-in attribute variables, user-defined
+    /// attribute variables, or both. Built-in attribute variables have a prefix
+    /// of "gl_" and reference conventional OpenGL vertex attribtes (e.g., `gl_Vertex`,
+    /// `gl_Normal`, etc., see the OpenGL Shading Language specification for a
+    /// complete list.) User-defined attribute variables have arbitrary names and
+    /// obtain their values through numbered generic vertex attributes. An attribute
+    /// variable (either built-in or user-defined) is considered active if it is
+    /// determined during the link operation that it may be accessed during program
+    /// execution. Therefore, `program` should have previously been the target
+    /// of a call to [**glLinkProgram**](crate::context::Context::oxidegl_link_program),
+    /// but it is not necessary for it to have been linked successfully.
+    ///
+    /// The size of the character buffer required to store the longest attribute
+    /// variable name in `program` can be obtained by calling [**glGetProgram**](crate::context::Context::oxidegl_get_program)
+    /// with the value [`GL_ACTIVE_ATTRIBUTE_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_ATTRIBUTE_MAX_LENGTH).
+    /// This value should be used to allocate a buffer of sufficient size to store
+    /// the returned attribute name. The size of this character buffer is passed
+    /// in `bufSize`, and a pointer to this character buffer is passed in `name`.
+    ///
+    /// [**glGetActiveAttrib**](crate::context::Context::oxidegl_get_active_attrib)
+    /// returns the name of the attribute variable indicated by `index`, storing
+    /// it in the character buffer specified by `name`. The string returned will
+    /// be null terminated. The actual number of characters written into this buffer
+    /// is returned in `length`, and this count does not include the null termination
+    /// character. If the length of the returned string is not required, a value
+    /// of [`NULL`](crate::gl_enums::NULL) can be passed in the `length` argument.
+    ///
+    /// The `type` argument specifies a pointer to a variable into which the attribute
+    /// variable's data type will be written. The symbolic constants [`GL_FLOAT`](crate::gl_enums::GL_FLOAT),
+    /// [`GL_FLOAT_VEC2`](crate::gl_enums::GL_FLOAT_VEC2), [`GL_FLOAT_VEC3`](crate::gl_enums::GL_FLOAT_VEC3),
+    /// [`GL_FLOAT_VEC4`](crate::gl_enums::GL_FLOAT_VEC4), [`GL_FLOAT_MAT2`](crate::gl_enums::GL_FLOAT_MAT2),
+    /// [`GL_FLOAT_MAT3`](crate::gl_enums::GL_FLOAT_MAT3), [`GL_FLOAT_MAT4`](crate::gl_enums::GL_FLOAT_MAT4),
+    /// [`GL_FLOAT_MAT2x3`](crate::gl_enums::GL_FLOAT_MAT2x3), [`GL_FLOAT_MAT2x4`](crate::gl_enums::GL_FLOAT_MAT2x4),
+    /// [`GL_FLOAT_MAT3x2`](crate::gl_enums::GL_FLOAT_MAT3x2), [`GL_FLOAT_MAT3x4`](crate::gl_enums::GL_FLOAT_MAT3x4),
+    /// [`GL_FLOAT_MAT4x2`](crate::gl_enums::GL_FLOAT_MAT4x2), [`GL_FLOAT_MAT4x3`](crate::gl_enums::GL_FLOAT_MAT4x3),
+    /// [`GL_INT`](crate::gl_enums::GL_INT), [`GL_INT_VEC2`](crate::gl_enums::GL_INT_VEC2),
+    /// [`GL_INT_VEC3`](crate::gl_enums::GL_INT_VEC3), [`GL_INT_VEC4`](crate::gl_enums::GL_INT_VEC4),
+    /// [`GL_UNSIGNED_INT`](crate::gl_enums::GL_UNSIGNED_INT), [`GL_UNSIGNED_INT_VEC2`](crate::gl_enums::GL_UNSIGNED_INT_VEC2),
+    /// [`GL_UNSIGNED_INT_VEC3`](crate::gl_enums::GL_UNSIGNED_INT_VEC3), [`GL_UNSIGNED_INT_VEC4`](crate::gl_enums::GL_UNSIGNED_INT_VEC4),
+    /// [`GL_DOUBLE`](crate::gl_enums::GL_DOUBLE), [`GL_DOUBLE_VEC2`](crate::gl_enums::GL_DOUBLE_VEC2),
+    /// [`GL_DOUBLE_VEC3`](crate::gl_enums::GL_DOUBLE_VEC3), [`GL_DOUBLE_VEC4`](crate::gl_enums::GL_DOUBLE_VEC4),
+    /// [`GL_DOUBLE_MAT2`](crate::gl_enums::GL_DOUBLE_MAT2), [`GL_DOUBLE_MAT3`](crate::gl_enums::GL_DOUBLE_MAT3),
+    /// [`GL_DOUBLE_MAT4`](crate::gl_enums::GL_DOUBLE_MAT4), [`GL_DOUBLE_MAT2x3`](crate::gl_enums::GL_DOUBLE_MAT2x3),
+    /// [`GL_DOUBLE_MAT2x4`](crate::gl_enums::GL_DOUBLE_MAT2x4), [`GL_DOUBLE_MAT3x2`](crate::gl_enums::GL_DOUBLE_MAT3x2),
+    /// [`GL_DOUBLE_MAT3x4`](crate::gl_enums::GL_DOUBLE_MAT3x4), [`GL_DOUBLE_MAT4x2`](crate::gl_enums::GL_DOUBLE_MAT4x2),
+    /// or [`GL_DOUBLE_MAT4x3`](crate::gl_enums::GL_DOUBLE_MAT4x3) may be returned.
+    /// The `size` argument will return the size of the attribute, in units of
+    /// the type returned in `type`.
+    ///
+    /// The list of active attribute variables may include both built-in attribute
+    /// variables (which begin with the prefix "gl_") as well as user-defined attribute
+    /// variable names.
+    ///
+    /// This function will return as much information as it can about the specified
+    /// active attribute variable. If no information is available, `length` will
+    /// be 0, and `name` will be an empty string. This situation could occur if
+    /// this function is called after a link operation that failed. If an error
+    /// occurs, the return values `length`, `size`, `type`, and `name` will be
+    /// unmodified.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_VERTEX_ATTRIBS`](crate::gl_enums::GL_MAX_VERTEX_ATTRIBS).
+    ///
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
+    /// [`GL_ACTIVE_ATTRIBUTES`](crate::gl_enums::GL_ACTIVE_ATTRIBUTES) or [`GL_ACTIVE_ATTRIBUTE_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_ATTRIBUTE_MAX_LENGTH).
+    ///
+    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
+    pub unsafe fn oxidegl_get_active_attrib(
+        &mut self,
+        program: GLuint,
+        index: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        size: *mut GLint,
+        r#type: *mut AttributeType,
+        name: *mut GLchar,
+    ) -> GlFallible {
+        sizei!(buf_size);
+        let interfaces = self.program_interfaces(program)?;
+        let Some(attribute) = interfaces.attributes().get(index as usize) else {
+            return Err(GlError::InvalidValue.e());
+        };
+        // Safety: caller ensures length and name are either null or valid for writes, and size and type are valid for writes
+        #[expect(
+            clippy::cast_possible_wrap,
+            reason = "arrays are shorter than i32::MAX"
+        )]
+        unsafe {
+            write_gl_string(&attribute.gl_name(), buf_size, length, name);
+            size.write(attribute.len as GLint);
+            r#type.write(
+                AttributeType::from_repr(attribute.ty as u32)
+                    .expect("attribute should have a valid attribute type"),
+            );
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of the target program object.
+    ///
+    /// `index`
+    ///
+    /// > The index of the varying variable whose information to retrieve.
+    ///
+    /// `bufSize`
+    ///
+    /// > The maximum number of characters, including the null terminator, that may
+    /// > be written into `name`.
+    ///
+    /// `length`
+    ///
+    /// > The address of a variable which will receive the number of characters written
+    /// > into `name`, excluding the null-terminator. If `length` is [`NULL`](crate::gl_enums::NULL)
+    /// > no length is returned.
+    ///
+    /// `size`
+    ///
+    /// > The address of a variable that will receive the size of the varying.
+    ///
+    /// `type`
+    ///
+    /// > The address of a variable that will receive the type of the varying.
+    ///
+    /// `name`
+    ///
+    /// > The address of a buffer into which will be written the name of the varying.
+    ///
+    /// ### Description
+    /// Information about the set of varying variables in a linked program that
+    /// will be captured during transform feedback may be retrieved by calling
+    /// [**glGetTransformFeedbackVarying**](crate::context::Context::oxidegl_get_transform_feedback_varying).
+    /// [**glGetTransformFeedbackVarying**](crate::context::Context::oxidegl_get_transform_feedback_varying)
+    /// provides information about the varying variable selected by `index`. An
+    /// `index` of 0 selects the first varying variable specified in the `varyings`
+    /// array passed to [**glTransformFeedbackVaryings**](crate::context::Context::oxidegl_transform_feedback_varyings),
+    /// and an `index` of the value of [`GL_TRANSFORM_FEEDBACK_VARYINGS`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYINGS)
+    /// minus one selects the last such variable.
+    ///
+    /// The name of the selected varying is returned as a null-terminated string
+    /// in `name`. The actual number of characters written into `name`, excluding
+    /// the null terminator, is returned in `length`. If `length` is NULL, no length
+    /// is returned. The maximum number of characters that may be written into
+    /// `name`, including the null terminator, is specified by `bufSize`.
+    ///
+    /// The length of the longest varying name in program is given by [`GL_TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH),
+    /// which can be queried with [**glGetProgram**](crate::context::Context::oxidegl_get_program).
+    ///
+    /// For the selected varying variable, its type is returned into `type`. The
+    /// size of the varying is returned into `size`. The value in `size` is in
+    /// units of the type returned in `type`. The type returned can be any of the
+    /// scalar, vector, or matrix attribute types returned by [**glGetActiveAttrib**](crate::context::Context::oxidegl_get_active_attrib).
+    /// If an error occurred, the return parameters `length`, `size`, `type` and
+    /// `name` will be unmodified. This command will return as much information
+    /// about the varying variables as possible. If no information is available,
+    /// `length` will be set to zero and `name` will be an empty string. This situation
+    /// could arise if [**glGetTransformFeedbackVarying**](crate::context::Context::oxidegl_get_transform_feedback_varying)
+    /// is called after a failed link.
+    ///
+    /// ### Associated Gets
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
+    /// [`GL_TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH).
+    pub unsafe fn oxidegl_get_transform_feedback_varying(
+        &mut self,
+        program: GLuint,
+        index: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        size: *mut GLsizei,
+        r#type: *mut AttributeType,
+        name: *mut GLchar,
+    ) -> GlFallible {
+        sizei!(buf_size);
+        let interfaces = self.program_interfaces(program)?;
+        let Some(varying) = interfaces.transform_feedback_varyings.get(index as usize) else {
+            return Err(GlError::InvalidValue.e());
+        };
+        // Safety: caller ensures length and name are either null or valid for writes, and size and type are valid for writes
+        #[expect(
+            clippy::cast_possible_wrap,
+            reason = "arrays are shorter than i32::MAX"
+        )]
+        unsafe {
+            write_gl_string(&varying.gl_name(), buf_size, length, name);
+            size.write(varying.len as GLsizei);
+            r#type.write(
+                AttributeType::from_repr(varying.ty as u32)
+                    .expect("captured varying should have a valid attribute type"),
+            );
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the program object to be queried.
+    ///
+    /// `name`
+    ///
+    /// > Points to a null terminated string containing the name of the attribute
+    /// > variable whose location is to be queried.
+    ///
+    /// ### Description
+    /// [**glGetAttribLocation**](crate::context::Context::oxidegl_get_attrib_location)
+    /// queries the previously linked program object specified by `program` for
+    /// the attribute variable specified by `name` and returns the index of the
+    /// generic vertex attribute that is bound to that attribute variable. If `name`
+    /// is a matrix attribute variable, the index of the first column of the matrix
+    /// is returned. If the named attribute variable is not an active attribute
+    /// in the specified program object or if `name` starts with the reserved prefix
+    /// "gl_", a value of -1 is returned.
+    ///
+    /// The association between an attribute variable name and a generic attribute
+    /// index can be specified at any time by calling [**glBindAttribLocation**](crate::context::Context::oxidegl_bind_attrib_location).
+    /// Attribute bindings do not go into effect until [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// is called. After a program object has been linked successfully, the index
+    /// values for attribute variables remain fixed until the next link command
+    /// occurs. The attribute values can only be queried after a link if the link
+    /// was successful. [**glGetAttribLocation**](crate::context::Context::oxidegl_get_attrib_location)
+    /// returns the binding that actually went into effect the last time [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// was called for the specified program object. Attribute bindings that have
+    /// been specified since the last link operation are not returned by [**glGetAttribLocation**](crate::context::Context::oxidegl_get_attrib_location).
+    ///
+    /// ### Associated Gets
+    /// [**glGetActiveAttrib**](crate::context::Context::oxidegl_get_active_attrib)
+    /// with argument `program` and the index of an active attribute
+    ///
+    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
+    pub unsafe fn oxidegl_get_attrib_location(
+        &mut self,
+        program: GLuint,
+        name: *const GLchar,
+    ) -> GlFallible<GLint> {
+        let linkage = self.linked_program(program)?;
+        // Safety: caller ensures name is a valid, null-terminated string
+        let name = unsafe { CStr::from_ptr(name) }.to_str().unwrap_or_default();
+        if name.starts_with("gl_") || linkage.interfaces.attributes().is_empty() {
+            return Ok(-1);
+        }
+        Ok(location_value(
+            linkage
+                .interfaces
+                .variable_location(ProgramInterface::ProgramInput, name),
+        ))
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of the program containing varying out variable whose binding to
+    /// > query
+    ///
+    /// `name`
+    ///
+    /// > The name of the user-defined varying out variable whose index to query
+    ///
+    /// ### Description
+    /// [**glGetFragDataIndex**](crate::context::Context::oxidegl_get_frag_data_index)
+    /// returns the index of the fragment color to which the variable `name` was
+    /// bound when the program object `program` was last linked. If `name` is not
+    /// a varying out variable of `program`, or if an error occurs, -1 will be
+    /// returned.
+    ///
+    /// ### Notes
+    /// [**glGetFragDataIndex**](crate::context::Context::oxidegl_get_frag_data_index)
+    /// is available only if the GL version is 3.3 or greater.
+    pub unsafe fn oxidegl_get_frag_data_index(
+        &mut self,
+        program: GLuint,
+        name: *const GLchar,
+    ) -> GlFallible<GLint> {
+        #[expect(
+            clippy::cast_possible_wrap,
+            reason = "location indices are either 0 or 1"
+        )]
+        let index = self
+            .frag_data_output(program, name)?
+            .map_or(-1, |(v, _)| v.location_index.unwrap_or(0) as GLint);
+        Ok(index)
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of the program containing varying out variable whose binding to
+    /// > query
+    ///
+    /// `name`
+    ///
+    /// > The name of the user-defined varying out variable whose binding to query
+    ///
+    /// ### Description
+    /// [**glGetFragDataLocation**](crate::context::Context::oxidegl_get_frag_data_location)
+    /// retrieves the assigned color number binding for the user-defined varying
+    /// out variable `name` for program `program`. `program` must have previously
+    /// been linked. `name` must be a null-terminated string. If `name` is not
+    /// the name of an active user-defined varying out fragment shader variable
+    /// within `program`, -1 will be returned.
+    pub unsafe fn oxidegl_get_frag_data_location(
+        &mut self,
+        program: GLuint,
+        name: *const GLchar,
+    ) -> GlFallible<GLint> {
+        Ok(location_value(
+            self.frag_data_output(program, name)?
+                .and_then(|(v, element)| Some(v.location? + element * v.element_locations())),
+        ))
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of a program object from which to retrieve information.
+    ///
+    /// `bufferIndex`
+    ///
+    /// > Specifies index of an active atomic counter buffer.
+    ///
+    /// `pname`
+    ///
+    /// > Specifies which parameter of the atomic counter buffer to retrieve.
+    ///
+    /// `params`
+    ///
+    /// > Specifies the address of a variable into which to write the retrieved information.
+    ///
+    /// ### Description
+    /// [**glGetActiveAtomicCounterBufferiv**](crate::context::Context::oxidegl_get_active_atomic_counter_bufferiv)
+    /// retrieves information about the set of active atomic counter buffers for
+    /// a program object. `program` is the name of a program object for which the
+    /// command [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// has been issued in the past. It is not necessary for `program` to have
+    /// been linked successfully. The link may have failed because the number of
+    /// active atomic counters exceeded the limits.
+    ///
+    /// `bufferIndex` specifies the index of an active atomic counter buffer and
+    /// must be in the range zero to the value of [`GL_ACTIVE_ATOMIC_COUNTER_BUFFERS`](crate::gl_enums::GL_ACTIVE_ATOMIC_COUNTER_BUFFERS)
+    /// minus one. The value of [`GL_ACTIVE_ATOMIC_COUNTER_BUFFERS`](crate::gl_enums::GL_ACTIVE_ATOMIC_COUNTER_BUFFERS)
+    /// for `program` indicates the number of active atomic counter buffer and
+    /// can be queried with [**glGetProgram**](crate::context::Context::oxidegl_get_program).
+    ///
+    /// If no error occurs, the parameter(s) specified by `pname` are returned
+    /// in `params`. If an error is generated, the contents of `params` are not
+    /// modified.
+    ///
+    /// If `pname` is [`GL_ATOMIC_COUNTER_BUFFER_BINDING`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_BINDING),
+    /// then the index of the counter buffer binding point associated with the
+    /// active atomic counter buffer `bufferIndex` for `program` is returned.
+    ///
+    /// If `pname` is [`GL_ATOMIC_COUNTER_BUFFER_DATA_SIZE`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_DATA_SIZE),
+    /// then the implementation-dependent minimum total buffer object size, in
+    /// baseic machine units, required to hold all active atomic counters in the
+    /// atomic counter binding point identified by `bufferIndex` is returned.
+    ///
+    /// If `pname` is [`GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTERS`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTERS),
+    /// then the number of active atomic counters for the atomic counter buffer
+    /// identified by `bufferIndex` is returned.
+    ///
+    /// If `pname` is [`GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTER_INDICES`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTER_INDICES),
+    /// then a list of the active atomic counter indices for the atomic counter
+    /// buffer identified by `bufferIndex` is returned. The number of elements
+    /// that will be written into `params` is the value of [`GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTERS`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTERS)
+    /// for `bufferIndex`.
+    ///
+    /// If `pname` is [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_VERTEX_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_VERTEX_SHADER),
+    /// [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_TESS_CONTROL_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_TESS_CONTROL_SHADER),
+    /// [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_TESS_EVALUATION_SHADER),
+    /// [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_GEOMETRY_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_GEOMETRY_SHADER),
+    /// [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_FRAGMENT_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_FRAGMENT_SHADER),
+    /// [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_COMPUTE_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_COMPUTE_SHADER)
+    /// then a boolean value indicating whether the atomic counter buffer identified
+    /// by `bufferIndex` is referenced by the vertex, tessellation control, tessellation
+    /// evaluation, geometry, fragment or compute processing stages of `program`,
+    /// respectively, is returned.
+    ///
+    /// ### Notes
+    /// [**glGetActiveAtomicCounterBufferiv**](crate::context::Context::oxidegl_get_active_atomic_counter_bufferiv)
+    /// is available only if the GL version is 4.2 or higher.
+    ///
+    /// [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_COMPUTE_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_COMPUTE_SHADER)
+    /// is available only of the GL version is 4.3 or higher.
+    pub unsafe fn oxidegl_get_active_atomic_counter_bufferiv(
+        &mut self,
+        program: GLuint,
+        buffer_index: GLuint,
+        pname: AtomicCounterBufferPName,
+        params: *mut GLint,
+    ) -> GlFallible {
+        use AtomicCounterBufferPName as N;
+        use ProgramResourceProperty as P;
+        let interfaces = self.program_interfaces(program)?;
+        let index = buffer_index as usize;
+        gl_assert!(
+            index < interfaces.resource_count(ProgramInterface::AtomicCounterBuffer),
+            InvalidValue
+        );
+        let prop = match pname {
+            N::AtomicCounterBufferBinding => P::BufferBinding,
+            N::AtomicCounterBufferDataSize => P::BufferDataSize,
+            N::AtomicCounterBufferActiveAtomicCounters => P::NumActiveVariables,
+            N::AtomicCounterBufferActiveAtomicCounterIndices => P::ActiveVariables,
+            N::AtomicCounterBufferReferencedByVertexShader => P::ReferencedByVertexShader,
+            N::AtomicCounterBufferReferencedByTessControlShader => P::ReferencedByTessControlShader,
+            N::AtomicCounterBufferReferencedByTessEvaluationShader => {
+                P::ReferencedByTessEvaluationShader
+            }
+            N::AtomicCounterBufferReferencedByGeometryShader => P::ReferencedByGeometryShader,
+            N::AtomicCounterBufferReferencedByFragmentShader => P::ReferencedByFragmentShader,
+            N::AtomicCounterBufferReferencedByComputeShader => P::ReferencedByComputeShader,
+        };
+        let mut values = Vec::new();
+        resource_property(
+            interfaces,
+            ProgramInterface::AtomicCounterBuffer,
+            index,
+            prop,
+            &mut values,
+        )?;
+        // Safety: caller ensures params is valid for writes of the values of pname
+        unsafe { ptr::copy_nonoverlapping(values.as_ptr(), params, values.len()) };
+        Ok(())
+    }
+}
+
+impl Context {
+    /// Get the interfaces of a program, which have no active resources if the program isn't linked
+    fn program_interfaces(&mut self, program: GLuint) -> GlFallible<&ProgramInterfaces> {
+        Ok(self
+            .gl_state
+            .program_list
+            .get_program_raw_mut(&self.gl_state.shader_list, program)?
+            .linkage()
+            .map_or(&NO_RESOURCES, |l| &l.interfaces))
+    }
+    /// Get the active fragment shader output of a linked program that `name` refers to, along with the array element
+    /// it refers to, for `glGetFragDataLocation` and `glGetFragDataIndex`
+    fn frag_data_output(
+        &mut self,
+        program: GLuint,
+        name: *const GLchar,
+    ) -> GlFallible<Option<(&InterfaceVariable, u32)>> {
+        let linkage = self.linked_program(program)?;
+        // Safety: caller ensures name is a valid, null-terminated string
+        let name = unsafe { CStr::from_ptr(name) }.to_str().unwrap_or_default();
+        if name.starts_with("gl_") || linkage.stage(ShaderType::FragmentShader).is_none() {
+            return Ok(None);
+        }
+        Ok(linkage
+            .interfaces
+            .find_variable(ProgramInterface::ProgramOutput, name))
+    }
+    /// Get the latest linkage of a program, for the commands that require it to be linked successfully
+    fn linked_program(&mut self, program: GLuint) -> GlFallible<&LinkedProgram> {
+        self.gl_state
+            .program_list
+            .get_program_raw_mut(&self.gl_state.shader_list, program)?
+            .linkage()
+            .ok_or_else(|| GlError::InvalidOperation.e())
+    }
+}
+
+/// Interfaces of a program that isn't linked
+static NO_RESOURCES: ProgramInterfaces = ProgramInterfaces {
+    uniforms: Vec::new(),
+    uniform_blocks: Vec::new(),
+    atomic_counter_buffers: Vec::new(),
+    buffer_variables: Vec::new(),
+    shader_storage_blocks: Vec::new(),
+    inputs: Vec::new(),
+    outputs: Vec::new(),
+    transform_feedback_varyings: Vec::new(),
+    transform_feedback_buffers: Vec::new(),
+    subroutines: Vec::new(),
+};
+
+#[inline]
+#[expect(
+    clippy::cast_possible_wrap,
+    reason = "locations are less than i32::MAX"
+)]
+fn location_value(location: Option<u32>) -> GLint {
+    location.map_or(-1, |l| l as GLint)
+}
+
+/// Append the value(s) of a property of the resource at `index` in an interface to `values`
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    reason = "sizes, offsets and indices of resources are less than i32::MAX"
+)]
+fn resource_property(
+    interfaces: &ProgramInterfaces,
+    interface: ProgramInterface,
+    index: usize,
+    prop: ProgramResourceProperty,
+    values: &mut Vec<GLint>,
+) -> GlFallible {
+    use ProgramInterface as I;
+    use ProgramResourceProperty as P;
+    let resource = interfaces
+        .resource(interface, index)
+        .expect("resource index should be in bounds");
+    let in_block = matches!(interface, I::Uniform | I::BufferVariable);
+    let in_stage_interface = matches!(interface, I::ProgramInput | I::ProgramOutput);
+    let captured = interface == I::TransformFeedbackVarying;
+    // transform feedback and atomic counter buffers are the only resources without a name
+    let unnamed = matches!(
+        interface,
+        I::TransformFeedbackBuffer | I::AtomicCounterBuffer
+    );
+    let value = match (resource, prop) {
+        (_, P::NameLength) if !unnamed => resource.gl_name().len() as GLint + 1,
+        (Resource::Variable(v), P::Type) => v.ty as GLint,
+        (Resource::Variable(v), P::ArraySize) => v.len as GLint,
+        (Resource::Variable(v), P::Offset) if in_block || captured => {
+            v.layout.map_or(-1, |l| l.offset as GLint)
+        }
+        (Resource::Variable(v), P::ArrayStride) if in_block => {
+            v.layout.map_or(-1, |l| l.array_stride as GLint)
+        }
+        (Resource::Variable(v), P::MatrixStride) if in_block => {
+            v.layout.map_or(-1, |l| l.matrix_stride as GLint)
+        }
+        (Resource::Variable(v), P::IsRowMajor) if in_block => {
+            v.layout.map_or(0, |l| GLint::from(l.row_major))
+        }
+        // the block of an atomic counter is its buffer, which isn't a uniform block
+        (Resource::Variable(v), P::BlockIndex) if in_block => v
+            .block
+            .filter(|_| !v.is_atomic_counter())
+            .map_or(-1, |b| b as GLint),
+        (Resource::Variable(v), P::AtomicCounterBufferIndex) if interface == I::Uniform => v
+            .block
+            .filter(|_| v.is_atomic_counter())
+            .map_or(-1, |b| b as GLint),
+        (Resource::Variable(v), P::TopLevelArraySize) if interface == I::BufferVariable => {
+            v.layout.map_or(1, |l| l.top_level_size as GLint)
+        }
+        (Resource::Variable(v), P::TopLevelArrayStride) if interface == I::BufferVariable => {
+            v.layout.map_or(0, |l| l.top_level_stride as GLint)
+        }
+        (Resource::Variable(v), P::Location) if interface == I::Uniform || in_stage_interface => {
+            location_value(v.location)
+        }
+        (Resource::Variable(v), P::LocationIndex) if interface == I::ProgramOutput => {
+            if v.location.is_some() && v.stages.contains(&ShaderType::FragmentShader) {
+                v.location_index.unwrap_or(0) as GLint
+            } else {
+                -1
+            }
+        }
+        (Resource::Variable(v), P::LocationComponent) if in_stage_interface => {
+            v.component.unwrap_or(0) as GLint
+        }
+        //TODO tessellation
+        (Resource::Variable(_), P::IsPerPatch) if in_stage_interface => 0,
+        (Resource::Variable(v), P::TransformFeedbackBufferIndex) if captured => {
+            v.block.map_or(-1, |b| b as GLint)
+        }
+        (Resource::SubroutineUniform(u), P::ArraySize) => u.len as GLint,
+        (Resource::SubroutineUniform(u), P::Location) => u.location as GLint,
+        (Resource::SubroutineUniform(u), P::NumCompatibleSubroutines) => {
+            u.compatible.len() as GLint
+        }
+        (Resource::SubroutineUniform(u), P::CompatibleSubroutines) => {
+            values.extend(u.compatible.iter().map(|&i| i as GLint));
+            return Ok(());
+        }
+        (Resource::Block(b), P::BufferBinding) => b.binding.unwrap_or(0) as GLint,
+        (Resource::Block(b), P::BufferDataSize) if interface != I::TransformFeedbackBuffer => {
+            b.data_size as GLint
+        }
+        // the data size of a transform feedback buffer is the stride of its captured vertices
+        (Resource::Block(b), P::TransformFeedbackBufferStride) => b.data_size as GLint,
+        (Resource::Block(_), P::NumActiveVariables) => {
+            interfaces.block_members(interface, index).len() as GLint
+        }
+        (Resource::Block(_), P::ActiveVariables) => {
+            values.extend(
+                interfaces
+                    .block_members(interface, index)
+                    .into_iter()
+                    .map(|i| i as GLint),
+            );
+            return Ok(());
+        }
+        (
+            Resource::Variable(_) | Resource::Block(_),
+            P::ReferencedByVertexShader
+            | P::ReferencedByTessControlShader
+            | P::ReferencedByTessEvaluationShader
+            | P::ReferencedByGeometryShader
+            | P::ReferencedByFragmentShader
+            | P::ReferencedByComputeShader,
+        ) => {
+            let stage = match prop {
+                P::ReferencedByVertexShader => ShaderType::VertexShader,
+                P::ReferencedByTessControlShader => ShaderType::TessControlShader,
+                P::ReferencedByTessEvaluationShader => ShaderType::TessEvaluationShader,
+                P::ReferencedByGeometryShader => ShaderType::GeometryShader,
+                P::ReferencedByFragmentShader => ShaderType::FragmentShader,
+                _ => ShaderType::ComputeShader,
+            };
+            GLint::from(resource.stages().contains(&stage))
+        }
+        _ => return Err(GlError::InvalidOperation.e()),
+    };
+    values.push(value);
+    Ok(())
+}
+
+#[test]
+fn software_program_introspection() {
+    use crate::{
+        gl_enums::ProgramProperty,
+        test_util::{compile, get_programiv, link},
+    };
+
+    let mut ctx = Context::new_software(8, 8);
+    let vert = compile(
+        &mut ctx,
+        ShaderType::VertexShader,
+        "#version 430 core
+        layout(location = 0) in vec2 position;
+        layout(location = 2) in vec4 colors[2];
+        layout(location = 4) uniform float scale[3];
+        layout(std140, binding = 1) uniform Transform {
+            mat4 model;
+            vec3 tint;
+            float weights[2];
+        } transform;
+        layout(location = 0) out vec4 v_color;
+        void main() {
+            gl_Position = transform.model * vec4(position * scale[1], 0.0, 1.0);
+            v_color = colors[1] * transform.weights[0] + vec4(transform.tint, 0.0);
+        }",
+    );
+    let frag = compile(
+        &mut ctx,
+        ShaderType::FragmentShader,
+        "#version 430 core
+        layout(std140, binding = 1) uniform Transform {
+            mat4 model;
+            vec3 tint;
+            float weights[2];
+        } transform;
+        layout(location = 0) in vec4 v_color;
+        layout(location = 0) out vec4 frag_color;
+        void main() {
+            frag_color = v_color * transform.weights[1];
+        }",
+    );
+    let program = link(&mut ctx, &[vert, frag]);
+
+    let index = |ctx: &mut Context, interface, name: &CStr| {
+        // Safety: name is a valid string
+        unsafe { ctx.oxidegl_get_program_resource_index(program, interface, name.as_ptr()) }
+            .unwrap()
+    };
+    let props = |ctx: &mut Context, interface, index, props: &[ProgramResourceProperty]| {
+        let mut values = [0; 8];
+        let mut length = 0;
+        // Safety: pointers are valid for the duration of the call
+        unsafe {
+            ctx.oxidegl_get_program_resourceiv(
+                program,
+                interface,
+                index,
+                i32::try_from(props.len()).unwrap(),
+                props.as_ptr(),
+                8,
+                &raw mut length,
+                values.as_mut_ptr(),
+            )
+        }
+        .unwrap();
+        values[..usize::try_from(length).unwrap()].to_vec()
+    };
+
+    // loose uniforms: arrays are named with a [0] suffix, and their elements have consecutive locations
+    let uniform_location = |ctx: &mut Context, name: &CStr| {
+        // Safety: name is a valid string
+        unsafe { ctx.oxidegl_get_uniform_location(program, name.as_ptr()) }.unwrap()
+    };
+    assert_eq!(uniform_location(&mut ctx, c"scale"), 4);
+    assert_eq!(uniform_location(&mut ctx, c"scale[2]"), 6);
+    assert_eq!(uniform_location(&mut ctx, c"scale[3]"), -1);
+    assert_eq!(uniform_location(&mut ctx, c"Transform.model"), -1);
+    let scale = index(&mut ctx, ProgramInterface::Uniform, c"scale");
+    assert_eq!(
+        scale,
+        index(&mut ctx, ProgramInterface::Uniform, c"scale[0]")
+    );
+    let mut name: [GLchar; 16] = [0; 16];
+    let mut length = 0;
+    let mut size = 0;
+    let mut ty = UniformType::Bool;
+    // Safety: pointers are valid for the duration of the call
+    unsafe {
+        ctx.oxidegl_get_active_uniform(
+            program,
+            scale,
+            16,
+            &raw mut length,
+            &raw mut size,
+            &raw mut ty,
+            name.as_mut_ptr(),
+        )
+    }
+    .unwrap();
+    // Safety: the name was nul terminated
+    assert_eq!(unsafe { CStr::from_ptr(name.as_ptr()) }, c"scale[0]");
+    assert_eq!((length, size, ty), (8, 3, UniformType::Float));
+
+    // uniform block members, shared by both stages
+    let weights = index(&mut ctx, ProgramInterface::Uniform, c"Transform.weights");
+    assert_eq!(
+        props(
+            &mut ctx,
+            ProgramInterface::Uniform,
+            weights,
+            &[
+                ProgramResourceProperty::Type,
+                ProgramResourceProperty::ArraySize,
+                ProgramResourceProperty::Offset,
+                ProgramResourceProperty::ArrayStride,
+                ProgramResourceProperty::BlockIndex,
+                ProgramResourceProperty::Location,
+            ]
+        ),
+        [UniformType::Float as GLint, 2, 80, 16, 0, -1]
+    );
+    let block = index(&mut ctx, ProgramInterface::UniformBlock, c"Transform");
+    assert_eq!(
+        props(
+            &mut ctx,
+            ProgramInterface::UniformBlock,
+            block,
+            &[
+                ProgramResourceProperty::BufferBinding,
+                ProgramResourceProperty::BufferDataSize,
+                ProgramResourceProperty::NumActiveVariables,
+                ProgramResourceProperty::ReferencedByVertexShader,
+                ProgramResourceProperty::ReferencedByFragmentShader,
+            ]
+        ),
+        [1, 112, 3, 1, 1]
+    );
+    assert_eq!(
+        get_programiv(&mut ctx, program, ProgramProperty::ActiveUniforms),
+        4
+    );
+    // "Transform.weights[0]" and a nul terminator
+    assert_eq!(
+        get_programiv(&mut ctx, program, ProgramProperty::ActiveUniformMaxLength),
+        21
+    );
+
+    // vertex attributes and fragment outputs
+    assert_eq!(
+        get_programiv(&mut ctx, program, ProgramProperty::ActiveAttributes),
+        2
+    );
+    let attrib_location = |ctx: &mut Context, name: &CStr| {
+        // Safety: name is a valid string
+        unsafe { ctx.oxidegl_get_attrib_location(program, name.as_ptr()) }.unwrap()
+    };
+    assert_eq!(attrib_location(&mut ctx, c"colors[1]"), 3);
+    assert_eq!(attrib_location(&mut ctx, c"v_color"), -1);
+    let output = index(&mut ctx, ProgramInterface::ProgramOutput, c"frag_color");
+    assert_eq!(
+        props(
+            &mut ctx,
+            ProgramInterface::ProgramOutput,
+            output,
+            &[
+                ProgramResourceProperty::Type,
+                ProgramResourceProperty::Location,
+                ProgramResourceProperty::LocationIndex,
+            ]
+        ),
+        [UniformType::FloatVec4 as GLint, 0, 0]
+    );
+
+    // properties that don't apply to an interface are rejected
+    let mut value = 0;
+    // Safety: pointer is valid for the duration of the call
+    let res = unsafe {
+        ctx.oxidegl_get_program_interfaceiv(
+            program,
+            ProgramInterface::Uniform,
+            ProgramInterfacePName::MaxNumActiveVariables,
+            &raw mut value,
+        )
+    };
+    assert!(res.is_err());
+}
+
+#[test]
+fn software_location_binding() {
+    use crate::test_util::compile;
+    let mut ctx = Context::new_software(8, 8);
+    let vert = compile(
+        &mut ctx,
+        ShaderType::VertexShader,
+        "#version 330 core
+        in vec4 color;
+        layout(location = 1) in float weight;
+        in mat2 transform;
+        in vec2 position;
+        out vec4 v_color;
+        void main() {
+            gl_Position = vec4(transform * position * weight, 0.0, 1.0);
+            v_color = color;
+        }",
+    );
+    let frag = compile(
+        &mut ctx,
+        ShaderType::FragmentShader,
+        "#version 330 core
+        in vec4 v_color;
+        out vec4 source0;
+        out vec4 source1;
+        void main() {
+            source0 = v_color;
+            source1 = vec4(1.0);
+        }",
+    );
+    let program = ctx.oxidegl_create_program();
+    ctx.oxidegl_attach_shader(program, vert).unwrap();
+    ctx.oxidegl_attach_shader(program, frag).unwrap();
+    // Safety: names are valid strings
+    unsafe {
+        ctx.oxidegl_bind_attrib_location(program, 0, c"color".as_ptr())
+            .unwrap();
+        ctx.oxidegl_bind_attrib_location(program, 3, c"weight".as_ptr())
+            .unwrap();
+        assert!(
+            ctx.oxidegl_bind_attrib_location(program, 32, c"color".as_ptr())
+                .is_err()
+        );
+        assert!(
+            ctx.oxidegl_bind_attrib_location(program, 2, c"gl_Vertex".as_ptr())
+                .is_err()
+        );
+        ctx.oxidegl_bind_frag_data_location_indexed(program, 0, 1, c"source1".as_ptr())
+            .unwrap();
+        ctx.oxidegl_bind_frag_data_location(program, 0, c"source0".as_ptr())
+            .unwrap();
+        assert!(
+            ctx.oxidegl_bind_frag_data_location_indexed(program, 1, 1, c"source1".as_ptr())
+                .is_err()
+        );
+    }
+    ctx.oxidegl_link_program(program).unwrap();
+
+    let attrib = |ctx: &mut Context, name: &CStr| {
+        // Safety: name is a valid string
+        unsafe { ctx.oxidegl_get_attrib_location(program, name.as_ptr()) }.unwrap()
+    };
+    let frag_data = |ctx: &mut Context, name: &CStr| {
+        // Safety: name is a valid string
+        unsafe {
+            (
+                ctx.oxidegl_get_frag_data_location(program, name.as_ptr())
+                    .unwrap(),
+                ctx.oxidegl_get_frag_data_index(program, name.as_ptr())
+                    .unwrap(),
+            )
+        }
+    };
+    // explicit locations win over bindings, and the remaining attributes get the lowest free range they fit in
+    assert_eq!(attrib(&mut ctx, c"color"), 0);
+    assert_eq!(attrib(&mut ctx, c"weight"), 1);
+    assert_eq!(attrib(&mut ctx, c"transform"), 2);
+    assert_eq!(attrib(&mut ctx, c"position"), 4);
+    assert_eq!(frag_data(&mut ctx, c"source0"), (0, 0));
+    assert_eq!(frag_data(&mut ctx, c"source1"), (0, 1));
+    assert_eq!(frag_data(&mut ctx, c"v_color"), (-1, -1));
+}
+
+#[test]
+fn software_atomic_counter_introspection() {
+    use crate::{
+        gl_enums::ProgramProperty,
+        test_util::{compile, link},
+    };
+    use AtomicCounterBufferPName as N;
+
+    let mut ctx = Context::new_software(8, 8);
+    let vert = compile(
+        &mut ctx,
+        ShaderType::VertexShader,
+        "#version 430 core
+        layout(binding = 0) uniform atomic_uint vertices;
+        void main() {
+            atomicCounterIncrement(vertices);
+            gl_Position = vec4(0.0, 0.0, 0.0, 1.0);
+        }",
+    );
+    let frag = compile(
+        &mut ctx,
+        ShaderType::FragmentShader,
+        "#version 430 core
+        layout(binding = 2, offset = 4) uniform atomic_uint hits;
+        layout(binding = 2, offset = 8) uniform atomic_uint misses[2];
+        layout(location = 0) out vec4 frag_color;
+        void main() {
+            uint hit = atomicCounterIncrement(hits);
+            atomicCounterDecrement(misses[hit % 2u]);
+            frag_color = vec4(1.0);
+        }",
+    );
+    let program = link(&mut ctx, &[vert, frag]);
+
+    let mut count = 0;
+    // Safety: pointer is valid for the duration of the call
+    unsafe {
+        ctx.oxidegl_get_programiv(
+            program,
+            ProgramProperty::ActiveAtomicCounterBuffers,
+            &raw mut count,
+        )
+    }
+    .unwrap();
+    assert_eq!(count, 2);
+    let buffer = |ctx: &mut Context, index, pname| {
+        let mut values = [0; 2];
+        // Safety: pointer is valid for the duration of the call
+        unsafe {
+            ctx.oxidegl_get_active_atomic_counter_bufferiv(
+                program,
+                index,
+                pname,
+                values.as_mut_ptr(),
+            )
+        }
+        .unwrap();
+        values
+    };
+    // buffers are enumerated in the order of the stages that first use them
+    assert_eq!(buffer(&mut ctx, 0, N::AtomicCounterBufferBinding)[0], 0);
+    assert_eq!(buffer(&mut ctx, 1, N::AtomicCounterBufferBinding)[0], 2);
+    assert_eq!(buffer(&mut ctx, 1, N::AtomicCounterBufferDataSize)[0], 16);
+    assert_eq!(
+        buffer(&mut ctx, 1, N::AtomicCounterBufferActiveAtomicCounters)[0],
+        2
+    );
+    assert_eq!(
+        buffer(&mut ctx, 1, N::AtomicCounterBufferReferencedByVertexShader)[0],
+        0
+    );
+
+    // counters are uniforms whose block is their buffer rather than a uniform block
+    let index = |ctx: &mut Context, name: &CStr| {
+        // Safety: name is a valid string
+        unsafe {
+            ctx.oxidegl_get_program_resource_index(
+                program,
+                ProgramInterface::Uniform,
+                name.as_ptr(),
+            )
+        }
+        .unwrap()
+    };
+    let hits = index(&mut ctx, c"hits");
+    let misses = index(&mut ctx, c"misses");
+    assert_eq!(
+        buffer(
+            &mut ctx,
+            1,
+            N::AtomicCounterBufferActiveAtomicCounterIndices
+        ),
+        [hits as GLint, misses as GLint]
+    );
+    let props = [
+        ProgramResourceProperty::Type,
+        ProgramResourceProperty::ArraySize,
+        ProgramResourceProperty::Offset,
+        ProgramResourceProperty::ArrayStride,
+        ProgramResourceProperty::BlockIndex,
+        ProgramResourceProperty::AtomicCounterBufferIndex,
+    ];
+    let mut values = [0; 6];
+    // Safety: pointers are valid for the duration of the call
+    unsafe {
+        ctx.oxidegl_get_program_resourceiv(
+            program,
+            ProgramInterface::Uniform,
+            misses,
+            6,
+            props.as_ptr(),
+            6,
+            ptr::null_mut(),
+            values.as_mut_ptr(),
+        )
+    }
+    .unwrap();
+    assert_eq!(
+        values,
+        [
+            UniformType::UnsignedIntAtomicCounter as GLint,
+            2,
+            8,
+            4,
+            -1,
+            1
+        ]
+    );
+}
@@ -0,0 +1,529 @@
+use core::slice;
+
+use crate::{
+    context::Context,
+    conversions::{GlGetItem, gl_string_len, sizei, write_gl_string},
+    debug::gl_debug,
+    error::{GlError, GlFallible, gl_assert},
+    gl_enums::{PipelineParameterName, ShaderType, UseProgramStageMask},
+    gl_object::ObjectName,
+    gl_types::{GLboolean, GLchar, GLint, GLsizei, GLuint},
+    program_pipeline::ProgramPipeline,
+    util::run_if_changed,
+};
+
+use crate::commands::programs::ProgramListExt;
+
+impl Context {
+    /// The name of the program pipeline object `pipeline`, creating its state if the name was generated but never
+    /// bound
+    fn init_program_pipeline(
+        &mut self,
+        pipeline: GLuint,
+    ) -> GlFallible<ObjectName<ProgramPipeline>> {
+        let name = ObjectName::try_from_raw(pipeline)?;
+        self.gl_state
+            .program_pipeline_list
+            .ensure_init(name, ProgramPipeline::new_default)?;
+        Ok(name)
+    }
+    /// Regenerate the program-related render state, if the current program pipeline is used for rendering
+    fn current_pipeline_changed(&mut self) {
+        if self.gl_state.program_binding.is_none() {
+            self.new_pipeline();
+            self.new_compute_pipeline();
+            self.new_encoder();
+            self.remap_buffers();
+            self.upload_uniforms();
+        }
+    }
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of program pipeline object names to reserve.
+    ///
+    /// `pipelines`
+    ///
+    /// > Specifies an array of into which the reserved names will be written.
+    ///
+    /// ### Description
+    /// [**glGenProgramPipelines**](crate::context::Context::oxidegl_gen_program_pipelines)
+    /// returns `n` previously unused program pipeline object names in `pipelines`.
+    /// These names are marked as used, for the purposes of [**glGenProgramPipelines**](crate::context::Context::oxidegl_gen_program_pipelines)
+    /// only, but they acquire program pipeline state only when they are first
+    /// bound.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PROGRAM_PIPELINE_BINDING`](crate::gl_enums::GL_PROGRAM_PIPELINE_BINDING)
+    ///
+    /// [**glIsProgramPipeline**](crate::context::Context::oxidegl_is_program_pipeline)
+    pub unsafe fn oxidegl_gen_program_pipelines(
+        &mut self,
+        n: GLsizei,
+        pipelines: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller guarantees invariants are upheld
+        unsafe {
+            self.gl_state.program_pipeline_list.gen_obj(n, pipelines);
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Number of program pipeline objects to create.
+    ///
+    /// `pipelines`
+    ///
+    /// > Specifies an array in which names of the new program pipeline objects are
+    /// > stored.
+    ///
+    /// ### Description
+    /// [**glCreateProgramPipelines**](crate::context::Context::oxidegl_create_program_pipelines)
+    /// returns `n` previously unused program pipeline names in `pipelines`, each
+    /// representing a new program pipeline object initialized to the default state.
+    pub unsafe fn oxidegl_create_program_pipelines(
+        &mut self,
+        n: GLsizei,
+        pipelines: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller guarantees invariants are upheld
+        unsafe {
+            self.gl_state.program_pipeline_list.create_obj(
+                ProgramPipeline::new_default,
+                n,
+                pipelines,
+            );
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `pipeline`
+    ///
+    /// > Specifies the name of the pipeline object to bind to the context.
+    ///
+    /// ### Description
+    /// [**glBindProgramPipeline**](crate::context::Context::oxidegl_bind_program_pipeline)
+    /// binds a program pipeline object to the current context. `pipeline` must
+    /// be a name previously returned from a call to [**glGenProgramPipelines**](crate::context::Context::oxidegl_gen_program_pipelines).
+    /// If no program pipeline exists with name `pipeline` then a new pipeline
+    /// object is created with that name and initialized to the default state vector.
+    ///
+    /// When a program pipeline object is bound using [**glBindProgramPipeline**](crate::context::Context::oxidegl_bind_program_pipeline),
+    /// any previous binding is broken and is replaced with a binding to the specified
+    /// pipeline object. If `pipeline` is zero, the previous binding is broken
+    /// and is not replaced, leaving no pipeline object bound. If no current program
+    /// object has been established by [**glUseProgram**](crate::context::Context::oxidegl_use_program),
+    /// the program objects used for each stage and for uniform updates are taken
+    /// from the bound program pipeline object, if any. If there is a current program
+    /// object established by [**glUseProgram**](crate::context::Context::oxidegl_use_program),
+    /// the bound program pipeline object has no effect on rendering or uniform
+    /// updates. When a bound program pipeline object is used for rendering, individual
+    /// shader executables are taken from its program objects.
+    pub fn oxidegl_bind_program_pipeline(&mut self, pipeline: GLuint) -> GlFallible {
+        gl_assert!(
+            !self.gl_state.transform_feedback().capturing(),
+            InvalidOperation,
+            "tried to bind a program pipeline while transform feedback is active and not paused"
+        );
+        let name = ObjectName::try_from_raw(pipeline).ok();
+        if let Some(name) = name {
+            self.gl_state
+                .program_pipeline_list
+                .ensure_init(name, ProgramPipeline::new_default)?;
+            // its programs may have been relinked while it wasn't bound
+            self.gl_state.program_pipeline_list.get_mut(name).validation = None;
+        }
+        self.reset_subroutine_selections();
+        run_if_changed!(self.gl_state.program_pipeline_binding;= name => {
+            self.current_pipeline_changed();
+        });
+        gl_debug!("bound {name:?} as current program pipeline");
+        Ok(())
+    }
+    /// ### Parameters
+    /// `pipeline`
+    ///
+    /// > Specifies a value that may be the name of a program pipeline object.
+    ///
+    /// ### Description
+    /// [**glIsProgramPipeline**](crate::context::Context::oxidegl_is_program_pipeline)
+    /// returns [`GL_TRUE`](crate::gl_enums::GL_TRUE) if `pipeline` is currently
+    /// the name of a program pipeline object. If `pipeline` is zero, or if [`pipeline`](crate::gl_enums::pipeline)
+    /// is not the name of a program pipeline object, or if an error occurs, [**glIsProgramPipeline**](crate::context::Context::oxidegl_is_program_pipeline)
+    /// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE). If `pipeline` is a name
+    /// returned by [**glGenProgramPipelines**](crate::context::Context::oxidegl_gen_program_pipelines),
+    /// but that has not yet been bound through a call to [**glBindProgramPipeline**](crate::context::Context::oxidegl_bind_program_pipeline),
+    /// then the name is not a program pipeline object and [**glIsProgramPipeline**](crate::context::Context::oxidegl_is_program_pipeline)
+    /// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE).
+    pub fn oxidegl_is_program_pipeline(&mut self, pipeline: GLuint) -> GlFallible<GLboolean> {
+        Ok(self.gl_state.program_pipeline_list.is_obj(pipeline))
+    }
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of program pipeline objects to delete.
+    ///
+    /// `pipelines`
+    ///
+    /// > Specifies an array of names of program pipeline objects to delete.
+    ///
+    /// ### Description
+    /// [**glDeleteProgramPipelines**](crate::context::Context::oxidegl_delete_program_pipelines)
+    /// deletes the `n` program pipeline objects whose names are stored in the
+    /// array `pipelines`. Unused names in `pipelines` are ignored, as is the name
+    /// zero. After a program pipeline object is deleted, its name is again unused
+    /// and it has no contents. If program pipeline object that is currently bound
+    /// is deleted, the binding for that object reverts to zero and no program
+    /// pipeline object becomes current.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PROGRAM_PIPELINE_BINDING`](crate::gl_enums::GL_PROGRAM_PIPELINE_BINDING)
+    pub unsafe fn oxidegl_delete_program_pipelines(
+        &mut self,
+        n: GLsizei,
+        pipelines: *const GLuint,
+    ) -> GlFallible {
+        sizei!(n, len);
+        // Safety: Caller ensures pipelines points to n object names. Cast from [u32] to [Option<ObjectName>] is valid
+        // because of the niche optimization guarantees of Option
+        let names = unsafe {
+            slice::from_raw_parts(
+                pipelines.cast::<Option<ObjectName<ProgramPipeline>>>(),
+                len as usize,
+            )
+        };
+        if self
+            .gl_state
+            .program_pipeline_binding
+            .is_some_and(|b| names.contains(&Some(b)))
+        {
+            self.gl_state.program_pipeline_binding = None;
+            self.current_pipeline_changed();
+        }
+        // Safety: Caller ensures invariants are upheld
+        unsafe {
+            self.gl_state
+                .program_pipeline_list
+                .delete_objects(n, pipelines);
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `pipeline`
+    ///
+    /// > Specifies the program pipeline object to which to bind stages from `program`.
+    ///
+    /// `stages`
+    ///
+    /// > Specifies a set of program stages to bind to the program pipeline object.
+    ///
+    /// `program`
+    ///
+    /// > Specifies the program object containing the shader executables to use in
+    /// > `pipeline`.
+    ///
+    /// ### Description
+    /// [**glUseProgramStages**](crate::context::Context::oxidegl_use_program_stages)
+    /// binds executables from a program object associated with a specified set
+    /// of shader stages to the program pipeline object given by `pipeline`. `pipeline`
+    /// specifies the program pipeline object to which to bind the executables.
+    /// `stages` contains a logical combination of bits indicating the shader stages
+    /// to use within `program` with the program pipeline object `pipeline`. `stages`
+    /// must be a logical combination of [`GL_VERTEX_SHADER_BIT`](crate::gl_enums::GL_VERTEX_SHADER_BIT),
+    /// [`GL_TESS_CONTROL_SHADER_BIT`](crate::gl_enums::GL_TESS_CONTROL_SHADER_BIT),
+    /// [`GL_TESS_EVALUATION_SHADER_BIT`](crate::gl_enums::GL_TESS_EVALUATION_SHADER_BIT),
+    /// [`GL_GEOMETRY_SHADER_BIT`](crate::gl_enums::GL_GEOMETRY_SHADER_BIT), [`GL_FRAGMENT_SHADER_BIT`](crate::gl_enums::GL_FRAGMENT_SHADER_BIT)
+    /// and [`GL_COMPUTE_SHADER_BIT`](crate::gl_enums::GL_COMPUTE_SHADER_BIT). Additionally,
+    /// the special value [`GL_ALL_SHADER_BITS`](crate::gl_enums::GL_ALL_SHADER_BITS)
+    /// may be specified to indicate that all executables contained in `program`
+    /// should be installed in `pipeline`.
+    ///
+    /// If `program` refers to a program object with a valid shader attached for
+    /// an indicated shader stage, [**glUseProgramStages**](crate::context::Context::oxidegl_use_program_stages)
+    /// installs the executable code for that stage in the indicated program pipeline
+    /// object `pipeline`. If `program` is zero, or refers to a program object
+    /// with no valid shader executable for a given stage, it is as if the pipeline
+    /// object has no programmable stage configured for the indicated shader stages.
+    /// If `stages` contains bits other than those listed above, and is not equal
+    /// to [`GL_ALL_SHADER_BITS`](crate::gl_enums::GL_ALL_SHADER_BITS), an error
+    /// is generated.
+    ///
+    /// ### Notes
+    /// The [`GL_COMPUTE_SHADER_BIT`](crate::gl_enums::GL_COMPUTE_SHADER_BIT) bit
+    /// is available only if the GL version is 4.3 or greater.
+    pub fn oxidegl_use_program_stages(
+        &mut self,
+        pipeline: GLuint,
+        stages: UseProgramStageMask,
+        program: GLuint,
+    ) -> GlFallible {
+        let stage_bits = UseProgramStageMask::VERTEX_SHADER_BIT
+            | UseProgramStageMask::TESS_CONTROL_SHADER_BIT
+            | UseProgramStageMask::TESS_EVALUATION_SHADER_BIT
+            | UseProgramStageMask::GEOMETRY_SHADER_BIT
+            | UseProgramStageMask::FRAGMENT_SHADER_BIT
+            | UseProgramStageMask::COMPUTE_SHADER_BIT;
+        gl_assert!(
+            stages == UseProgramStageMask::ALL_SHADER_BITS || stage_bits.contains(stages),
+            InvalidValue,
+            "{stages:?} is not a valid set of program stages"
+        );
+        let pipeline = self.init_program_pipeline(pipeline)?;
+        let current = self.gl_state.program_binding.is_none()
+            && self.gl_state.program_pipeline_binding == Some(pipeline);
+        gl_assert!(
+            !current || !self.gl_state.transform_feedback().capturing(),
+            InvalidOperation,
+            "tried to change the stages of the current program pipeline while transform feedback is active and not paused"
+        );
+        let program = if program == 0 {
+            None
+        } else {
+            let program = self
+                .gl_state
+                .program_list
+                .get_program_raw_mut(&self.gl_state.shader_list, program)?;
+            let name = program.name;
+            let Some(linkage) = program.linkage() else {
+                return Err(GlError::InvalidOperation.e());
+            };
+            gl_assert!(
+                linkage.separable,
+                InvalidOperation,
+                "tried to use the stages of {name:?}, which was not linked as separable"
+            );
+            Some((name, linkage))
+        };
+        self.gl_state
+            .program_pipeline_list
+            .get_mut(pipeline)
+            .use_stages(stages, program);
+        gl_debug!(
+            "using {:?} for stages {stages:?} of {pipeline:?}",
+            program.map(|(name, _)| name)
+        );
+        self.reset_subroutine_selections();
+        if current {
+            self.current_pipeline_changed();
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `pipeline`
+    ///
+    /// > Specifies the program pipeline object to set the active program object
+    /// > for.
+    ///
+    /// `program`
+    ///
+    /// > Specifies the program object to set as the active program pipeline object
+    /// > `pipeline`.
+    ///
+    /// ### Description
+    /// [**glActiveShaderProgram**](crate::context::Context::oxidegl_active_shader_program)
+    /// sets the linked program named by `program` to be the active program for
+    /// the program pipeline object `pipeline`. The active program in the active
+    /// program pipeline object is the target of calls to [**glUniform**](crate::context::Context::oxidegl_uniform)
+    /// when no program has been made current through a call to [**glUseProgram**](crate::context::Context::oxidegl_use_program).
+    pub fn oxidegl_active_shader_program(
+        &mut self,
+        pipeline: GLuint,
+        program: GLuint,
+    ) -> GlFallible {
+        let pipeline = self.init_program_pipeline(pipeline)?;
+        let program = if program == 0 {
+            None
+        } else {
+            let program = self
+                .gl_state
+                .program_list
+                .get_program_raw_mut(&self.gl_state.shader_list, program)?;
+            gl_assert!(
+                program.linkage().is_some(),
+                InvalidOperation,
+                "tried to make {:?} the active program of {pipeline:?} without it being linked",
+                program.name
+            );
+            Some(program.name)
+        };
+        self.gl_state
+            .program_pipeline_list
+            .get_mut(pipeline)
+            .active_program = program;
+        gl_debug!("made {program:?} the active program of {pipeline:?}");
+        Ok(())
+    }
+    /// ### Parameters
+    /// `pipeline`
+    ///
+    /// > Specifies the name of a program pipeline object whose parameter retrieve.
+    ///
+    /// `pname`
+    ///
+    /// > Specifies the name of the parameter to retrieve.
+    ///
+    /// `params`
+    ///
+    /// > Specifies the address of a variable into which will be written the value
+    /// > or values of `pname` for `pipeline`.
+    ///
+    /// ### Description
+    /// [**glGetProgramPipelineiv**](crate::context::Context::oxidegl_get_program_pipelineiv)
+    /// retrieves the value of a property of the program pipeline object `pipeline`.
+    /// `pname` specifies the name of the parameter whose value to retrieve. The
+    /// value of the parameter is written to the variable whose address is given
+    /// by `params`.
+    ///
+    /// If `pname` is [`GL_ACTIVE_PROGRAM`](crate::gl_enums::GL_ACTIVE_PROGRAM),
+    /// the name of the active program object of the program pipeline object is
+    /// returned in `params`.
+    ///
+    /// If `pname` is [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER), the
+    /// name of the current program object for the vertex shader type of the program
+    /// pipeline object is returned in `params`.
+    ///
+    /// If `pname` is [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER),
+    /// the name of the current program object for the tessellation control shader
+    /// type of the program pipeline object is returned in `params`.
+    ///
+    /// If `pname` is [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
+    /// the name of the current program object for the tessellation evaluation
+    /// shader type of the program pipeline object is returned in `params`.
+    ///
+    /// If `pname` is [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER),
+    /// the name of the current program object for the geometry shader type of
+    /// the program pipeline object is returned in `params`.
+    ///
+    /// If `pname` is [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER),
+    /// the name of the current program object for the fragment shader type of
+    /// the program pipeline object is returned in `params`.
+    ///
+    /// If `pname` is [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH),
+    /// the length of the info log, including the null terminator, is returned
+    /// in `params`. If there is no info log, zero is returned.
+    pub unsafe fn oxidegl_get_program_pipelineiv(
+        &mut self,
+        pipeline: GLuint,
+        pname: PipelineParameterName,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let pipeline = self.init_program_pipeline(pipeline)?;
+        let pipeline = self.gl_state.program_pipeline_list.get(pipeline);
+        let program = match pname {
+            PipelineParameterName::InfoLogLength => {
+                #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                let len = gl_string_len(&pipeline.info_log) as GLint;
+                // Safety: caller ensures params points to a valid storage and is aligned correctly for `i32`
+                unsafe { core::ptr::write(params, len) };
+                return Ok(());
+            }
+            PipelineParameterName::ActiveProgram => pipeline.active_program,
+            PipelineParameterName::VertexShader => pipeline.stage(ShaderType::VertexShader),
+            PipelineParameterName::TessControlShader => {
+                pipeline.stage(ShaderType::TessControlShader)
+            }
+            PipelineParameterName::TessEvaluationShader => {
+                pipeline.stage(ShaderType::TessEvaluationShader)
+            }
+            PipelineParameterName::GeometryShader => pipeline.stage(ShaderType::GeometryShader),
+            PipelineParameterName::FragmentShader => pipeline.stage(ShaderType::FragmentShader),
+        };
+        // Safety: caller ensures params points to a valid storage and is aligned correctly for `i32`
+        unsafe { program.write_out(params) };
+        Ok(())
+    }
+    /// ### Parameters
+    /// `pipeline`
+    ///
+    /// > Specifies the name of a program pipeline object from which to retrieve
+    /// > the info log.
+    ///
+    /// `bufSize`
+    ///
+    /// > Specifies the maximum number of characters, including the null terminator,
+    /// > that may be written into `infoLog`.
+    ///
+    /// `length`
+    ///
+    /// > Specifies the address of a variable into which will be written the number
+    /// > of characters written into `infoLog`.
+    ///
+    /// `infoLog`
+    ///
+    /// > Specifies the address of an array of characters into which will be written
+    /// > the info log for `pipeline`.
+    ///
+    /// ### Description
+    /// [**glGetProgramPipelineInfoLog**](crate::context::Context::oxidegl_get_program_pipeline_info_log)
+    /// retrieves the info log for the program pipeline object `pipeline`. The
+    /// info log, including its null terminator, is written into the array of characters
+    /// whose address is given by `infoLog`. The maximum number of characters that
+    /// may be written into `infoLog` is given by `bufSize`, and the actual number
+    /// of characters written into `infoLog` is returned in the integer whose address
+    /// is given by `length`. If `length` is [`NULL`](crate::gl_enums::NULL), no
+    /// length is returned.
+    ///
+    /// The actual length of the info log for the program pipeline may be determined
+    /// by calling [**glGetProgramPipeline**](crate::context::Context::oxidegl_get_program_pipeline)
+    /// with `pname` set to [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH).
+    ///
+    /// ### Associated Gets
+    /// [**glGetProgramPipeline**](crate::context::Context::oxidegl_get_program_pipeline)
+    /// with parameter [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH).
+    pub unsafe fn oxidegl_get_program_pipeline_info_log(
+        &mut self,
+        pipeline: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        info_log: *mut GLchar,
+    ) -> GlFallible {
+        sizei!(buf_size);
+        let pipeline = self.init_program_pipeline(pipeline)?;
+        let pipeline = self.gl_state.program_pipeline_list.get(pipeline);
+        // Safety: caller ensures info_log is valid for writes of buf_size bytes and length is valid for a write
+        unsafe { write_gl_string(&pipeline.info_log, buf_size, length, info_log) };
+        Ok(())
+    }
+    /// ### Parameters
+    /// `pipeline`
+    ///
+    /// > Specifies the name of a program pipeline object to validate.
+    ///
+    /// ### Description
+    /// [**glValidateProgramPipeline**](crate::context::Context::oxidegl_validate_program_pipeline)
+    /// instructs the implementation to validate the shader executables contained
+    /// in `pipeline` against the current GL state. The implementation may use
+    /// this as an opportunity to perform any internal shader modifications that
+    /// may be required to ensure correct operation of the installed shaders given
+    /// the current GL state.
+    ///
+    /// After a program pipeline has been validated, its validation status is set
+    /// to [`GL_TRUE`](crate::gl_enums::GL_TRUE). The validation status of a program
+    /// pipeline object may be queried by calling [**glGetProgramPipeline**](crate::context::Context::oxidegl_get_program_pipeline)
+    /// with parameter [`GL_VALIDATE_STATUS`](crate::gl_enums::GL_VALIDATE_STATUS).
+    ///
+    /// If `pipeline` is a name previously returned from a call to [**glGenProgramPipelines**](crate::context::Context::oxidegl_gen_program_pipelines)
+    /// but that has not yet been bound by a call to [**glBindProgramPipeline**](crate::context::Context::oxidegl_bind_program_pipeline),
+    /// a new program pipeline object is created with name `pipeline` and the
+    /// default state vector.
+    ///
+    /// ### Associated Gets
+    /// [**glGetProgramPipeline**](crate::context::Context::oxidegl_get_program_pipeline)
+    /// with parameter [`GL_VALIDATE_STATUS`](crate::gl_enums::GL_VALIDATE_STATUS).
+    pub fn oxidegl_validate_program_pipeline(&mut self, pipeline: GLuint) -> GlFallible {
+        let name = self.init_program_pipeline(pipeline)?;
+        let pipeline = self.gl_state.program_pipeline_list.get_mut(name);
+        pipeline.validation = None;
+        pipeline.info_log = match pipeline.validate(&mut self.gl_state.program_list) {
+            Ok(()) => String::new(),
+            Err(e) => {
+                gl_debug!("{name:?} failed validation: {e}");
+                format!("{e}\n")
+            }
+        };
+        Ok(())
+    }
+}
@@ -1,13 +1,24 @@
+use core::{ffi::CStr, ptr, slice};
+
 use crate::{
     context::Context,
-    debug::gl_debug,
-    error::{GlError, GlFallible},
-    gl_enums::ProgramProperty,
+    conversions::{gl_string_len, sizei, write_gl_string},
+    debug::{gl_debug, gl_err},
+    error::{GlError, GlFallible, gl_assert},
+    framebuffer::MAX_COLOR_ATTACHMENTS,
+    gl_enums::{
+        ProgramInterface, ProgramParameterPName, ProgramProperty, ShaderType,
+        TransformFeedbackBufferMode,
+    },
     gl_object::{NamedObjectList, ObjectName},
-    gl_types::{GLint, GLuint},
-    program::Program,
+    gl_types::{GLchar, GLenum, GLint, GLsizei, GLuint, GLvoid},
+    program::{
+        FeedbackVaryings, Program, binary::PROGRAM_BINARY_FORMAT, feedback,
+        locations::MAX_DUAL_SOURCE_DRAW_BUFFERS,
+    },
     shader::Shader,
     util::run_if_changed,
+    vao::MAX_VERTEX_ATTRIBUTES,
 };
 
 use crate::commands::shaders::ShaderListExt;
@@ -251,23 +262,398 @@ impl Context {
     ///
     /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
     pub fn oxidegl_link_program(&mut self, program: GLuint) -> GlFallible {
-        // regenerate program-related state if this program is the currently bound one
-        // TODO program pipelines will complicate this
-        if self
+        self.replace_executable(program)?;
+        let program = self
             .gl_state
-            .program_binding
-            .is_some_and(|n| n.to_raw() == program)
-        {
-            self.new_pipeline();
-            self.new_encoder();
-            self.remap_buffers();
+            .program_list
+            .get_program_raw_mut(&self.gl_state.shader_list, program)?;
+        program.link(
+            &self.gl_state.shader_list,
+            &mut self.gl_state.compile_pool,
+            self.renderer.backend.stage_compiler(),
+        );
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of the target program object.
+    ///
+    /// `count`
+    ///
+    /// > The number of varying variables used for transform feedback.
+    ///
+    /// `varyings`
+    ///
+    /// > An array of `count` zero-terminated strings specifying the names of the
+    /// > varying variables to use for transform feedback.
+    ///
+    /// `bufferMode`
+    ///
+    /// > Identifies the mode used to capture the varying variables when transform
+    /// > feedback is active. `bufferMode` must be [`GL_INTERLEAVED_ATTRIBS`](crate::gl_enums::GL_INTERLEAVED_ATTRIBS)
+    /// > or [`GL_SEPARATE_ATTRIBS`](crate::gl_enums::GL_SEPARATE_ATTRIBS).
+    ///
+    /// ### Description
+    /// The names of the vertex or geometry shader outputs to be recorded in transform
+    /// feedback mode are specified using [**glTransformFeedbackVaryings**](crate::context::Context::oxidegl_transform_feedback_varyings).
+    /// When a geometry shader is active, transform feedback records the values
+    /// of selected geometry shader output variables from the emitted vertices.
+    /// Otherwise, the values of the selected vertex shader outputs are recorded.
+    ///
+    /// The state set by [**glTranformFeedbackVaryings**](crate::context::Context::oxidegl_tranform_feedback_varyings)
+    /// is stored and takes effect next time [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// is called on `program`. When [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// is called, `program` is linked so that the values of the specified varying
+    /// variables for the vertices of each primitive generated by the GL are written
+    /// to a single buffer object if `bufferMode` is [`GL_INTERLEAVED_ATTRIBS`](crate::gl_enums::GL_INTERLEAVED_ATTRIBS)
+    /// or multiple buffer objects if `bufferMode` is [`GL_SEPARATE_ATTRIBS`](crate::gl_enums::GL_SEPARATE_ATTRIBS).
+    ///
+    /// [**glTransformFeedbackVaryings**](crate::context::Context::oxidegl_transform_feedback_varyings)
+    /// can also special identifiers if `bufferMode` is [`GL_INTERLEAVED_ATTRIBS`](crate::gl_enums::GL_INTERLEAVED_ATTRIBS):
+    ///
+    /// Subsequent variables in `varyings` will be assigned to the next buffer
+    /// binding point.
+    ///
+    /// Where# may be 1, 2, 3, or 4. The variable is treated as having this number
+    /// of components, but the contents of the memory are preserved under feedback
+    /// operations.
+    ///
+    /// In addition to the errors generated by [**glTransformFeedbackVaryings**](crate::context::Context::oxidegl_transform_feedback_varyings),
+    /// the program `program` will fail to link if: The count specified by [**glTransformFeedbackVaryings**](crate::context::Context::oxidegl_transform_feedback_varyings)
+    /// > is non-zero, but the program object has no vertex or geometry shader.
+    ///
+    /// > Any variable name specified in the `varyings` array is not declared as
+    /// > an output in the vertex shader (or the geometry shader, if active), or
+    /// > is not one of the special identifiers listed above.
+    ///
+    /// > Special identifiers appear in a `varyings` array where `bufferMode` is
+    /// > not [`GL_INTERLEAVED_ATTRIBS`](crate::gl_enums::GL_INTERLEAVED_ATTRIBS).
+    ///
+    /// > Any two entries in the `varyings` array, which are not one of the special
+    /// > varyings above, specify the same varying variable.
+    ///
+    /// > Discounting any special identifiers, the total number of components to
+    /// > capture in any varying variable in `varyings` is greater than the constant
+    /// > [`GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_COMPONENTS`](crate::gl_enums::GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_COMPONENTS)
+    /// > and the buffer mode is [`GL_SEPARATE_ATTRIBS`](crate::gl_enums::GL_SEPARATE_ATTRIBS).
+    ///
+    /// > The total number of components to capture is greater than the constant
+    /// > [`GL_MAX_TRANSFORM_FEEDBACK_INTERLEAVED_COMPONENTS`](crate::gl_enums::GL_MAX_TRANSFORM_FEEDBACK_INTERLEAVED_COMPONENTS)
+    /// > and the buffer mode is [`GL_INTERLEAVED_ATTRIBS`](crate::gl_enums::GL_INTERLEAVED_ATTRIBS).
+    ///
+    ///
+    /// ### Notes
+    /// [**glGetTransformFeedbackVarying**](crate::context::Context::oxidegl_get_transform_feedback_varying)
+    /// is available only if the GL version is 3.0 or greater.
+    ///
+    /// ### Associated Gets
+    /// [**glGetTransformFeedbackVarying**](crate::context::Context::oxidegl_get_transform_feedback_varying)
+    pub unsafe fn oxidegl_transform_feedback_varyings(
+        &mut self,
+        program: GLuint,
+        count: GLsizei,
+        varyings: *const *const GLchar,
+        buffer_mode: TransformFeedbackBufferMode,
+    ) -> GlFallible {
+        sizei!(count);
+        gl_assert!(
+            buffer_mode != TransformFeedbackBufferMode::SeparateAttribs
+                || count <= feedback::MAX_SEPARATE_ATTRIBS,
+            InvalidValue
+        );
+        let program = self
+            .gl_state
+            .program_list
+            .get_program_raw_mut(&self.gl_state.shader_list, program)?;
+        let names = (0..count as usize)
+            .map(|i| {
+                // Safety: Caller ensures varyings points to count nul-terminated strings
+                unsafe { CStr::from_ptr(*varyings.add(i)) }
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect();
+        program.feedback_varyings = FeedbackVaryings {
+            names,
+            mode: buffer_mode,
+        };
+        gl_debug!(
+            "{:?} will capture {:?} with transform feedback after its next link",
+            program.name,
+            program.feedback_varyings
+        );
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the handle of the program object in which the association is
+    /// > to be made.
+    ///
+    /// `index`
+    ///
+    /// > Specifies the index of the generic vertex attribute to be bound.
+    ///
+    /// `name`
+    ///
+    /// > Specifies a null terminated string containing the name of the vertex shader
+    /// > attribute variable to which `index` is to be bound.
+    ///
+    /// ### Description
+    /// [**glBindAttribLocation**](crate::context::Context::oxidegl_bind_attrib_location)
+    /// is used to associate a user-defined attribute variable in the program object
+    /// specified by `program` with a generic vertex attribute index. The name
+    /// of the user-defined attribute variable is passed as a null terminated string
+    /// in `name`. The generic vertex attribute index to be bound to this variable
+    /// is specified by `index`. When `program` is made part of current state,
+    /// values provided via the generic vertex attribute `index` will modify the
+    /// value of the user-defined attribute variable specified by `name`.
+    ///
+    /// If `name` refers to a matrix attribute variable, `index` refers to the
+    /// first column of the matrix. Other matrix columns are then automatically
+    /// bound to locations `index+1` for a matrix of type `mat2`;
+    /// `index+1` and `index+2` for a matrix of type `mat3`;
+    /// and `index+1`, `index+2`, and `index+3` for a matrix of type `mat4`.
+    ///
+    /// This command makes it possible for vertex shaders to use descriptive names
+    /// for attribute variables rather than generic variables that are numbered
+    /// from zero to the value of [`GL_MAX_VERTEX_ATTRIBS`](crate::gl_enums::GL_MAX_VERTEX_ATTRIBS)
+    /// minus one. The values sent to each generic attribute index are part of
+    /// current state. If a different program object is made current by calling
+    /// [**glUseProgram**](crate::context::Context::oxidegl_use_program), the generic
+    /// vertex attributes are tracked in such a way that the same values will be
+    /// observed by attributes in the new program object that are also bound to
+    /// `index`.
+    ///
+    /// Attribute variable name-to-generic attribute index bindings for a program
+    /// object can be explicitly assigned at any time by calling [**glBindAttribLocation**](crate::context::Context::oxidegl_bind_attrib_location).
+    /// Attribute bindings do not go into effect until [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// is called. After a program object has been linked successfully, the index
+    /// values for generic attributes remain fixed (and their values can be queried)
+    /// until the next link command occurs.
+    ///
+    /// Any attribute binding that occurs after the program object has been linked
+    /// will not take effect until the next time the program object is linked.
+    ///
+    /// ### Notes
+    /// [**glBindAttribLocation**](crate::context::Context::oxidegl_bind_attrib_location)
+    /// can be called before any vertex shader objects are bound to the specified
+    /// program object. It is also permissible to bind a generic attribute index
+    /// to an attribute variable name that is never used in a vertex shader.
+    ///
+    /// If `name` was bound previously, that information is lost. Thus you cannot
+    /// bind one user-defined attribute variable to multiple indices, but you can
+    /// bind multiple user-defined attribute variables to the same index.
+    ///
+    /// Applications are allowed to bind more than one user-defined attribute variable
+    /// to the same generic vertex attribute index. This is called *aliasing*, and
+    /// it is allowed only if just one of the aliased attributes is active in the
+    /// executable program, or if no path through the shader consumes more than
+    /// one attribute of a set of attributes aliased to the same location. The
+    /// compiler and linker are allowed to assume that no aliasing is done and
+    /// are free to employ optimizations that work only in the absence of aliasing.
+    /// OpenGL implementations are not required to do error checking to detect
+    /// aliasing.
+    ///
+    /// Active attributes that are not explicitly bound will be bound by the linker
+    /// when [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// is called. The locations assigned can be queried by calling [**glGetAttribLocation**](crate::context::Context::oxidegl_get_attrib_location).
+    ///
+    /// OpenGL copies the `name` string when [**glBindAttribLocation**](crate::context::Context::oxidegl_bind_attrib_location)
+    /// is called, so an application may free its copy of the `name` string immediately
+    /// after the function returns.
+    ///
+    /// Generic attribute locations may be specified in the shader source text
+    /// using a `location` layout qualifier.
+    /// In this case, the location of the attribute specified in the shader's source
+    /// takes precedence and may be queried by calling [**glGetAttribLocation**](crate::context::Context::oxidegl_get_attrib_location).
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_VERTEX_ATTRIBS`](crate::gl_enums::GL_MAX_VERTEX_ATTRIBS)
+    ///
+    /// [**glGetActiveAttrib**](crate::context::Context::oxidegl_get_active_attrib)
+    /// with argument `program`
+    ///
+    /// [**glGetAttribLocation**](crate::context::Context::oxidegl_get_attrib_location)
+    /// with arguments `program` and `name`
+    ///
+    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
+    pub unsafe fn oxidegl_bind_attrib_location(
+        &mut self,
+        program: GLuint,
+        index: GLuint,
+        name: *const GLchar,
+    ) -> GlFallible {
+        let program = self
+            .gl_state
+            .program_list
+            .get_program_raw_mut(&self.gl_state.shader_list, program)?;
+        gl_assert!((index as usize) < MAX_VERTEX_ATTRIBUTES, InvalidValue);
+        // Safety: caller ensures name is a valid, null-terminated string
+        let name = unsafe { CStr::from_ptr(name) }.to_string_lossy();
+        if name.starts_with("gl_") {
+            gl_err!(ty: Error, "tried to bind the reserved attribute name {name:?}");
+            return Err(GlError::InvalidOperation.e());
         }
-
+        gl_debug!(
+            "{:?} will bind {name:?} to generic vertex attribute {index} after its next link",
+            program.name
+        );
+        program
+            .location_bindings
+            .attributes
+            .insert(name.into_owned(), index);
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of the program containing varying out variable whose binding to
+    /// > modify
+    ///
+    /// `colorNumber`
+    ///
+    /// > The color number to bind the user-defined varying out variable to
+    ///
+    /// `name`
+    ///
+    /// > The name of the user-defined varying out variable whose binding to modify
+    ///
+    /// ### Description
+    /// [**glBindFragDataLocation**](crate::context::Context::oxidegl_bind_frag_data_location)
+    /// explicitly specifies the binding of the user-defined varying out variable
+    /// `name` to fragment shader color number `colorNumber` for program `program`.
+    /// If `name` was bound previously, its assigned binding is replaced with
+    /// `colorNumber`. `name` must be a null-terminated string. `colorNumber` must
+    /// be less than [`GL_MAX_DRAW_BUFFERS`](crate::gl_enums::GL_MAX_DRAW_BUFFERS).
+    ///
+    /// The bindings specified by [**glBindFragDataLocation**](crate::context::Context::oxidegl_bind_frag_data_location)
+    /// have no effect until `program` is next linked. Bindings may be specified
+    /// at any time after `program` has been created. Specifically, they may be
+    /// specified before shader objects are attached to the program. Therefore,
+    /// any name may be specified in `name`, including a name that is never used
+    /// as a varying out variable in any fragment shader object. Names beginning
+    /// with [`gl_`](crate::gl_enums::gl_) are reserved by the GL.
+    ///
+    /// In addition to the errors generated by [**glBindFragDataLocation**](crate::context::Context::oxidegl_bind_frag_data_location),
+    /// the program `program` will fail to link if: The number of active outputs
+    /// > is greater than the value [`GL_MAX_DRAW_BUFFERS`](crate::gl_enums::GL_MAX_DRAW_BUFFERS).
+    ///
+    /// > More than one varying out variable is bound to the same color number.
+    ///
+    ///
+    /// ### Notes
+    /// Varying out varyings may have indexed locations assigned explicitly in
+    /// the shader text using a [**glBindFragDataLocation**](crate::context::Context::oxidegl_bind_frag_data_location)
+    /// is ignored.
+    ///
+    /// ### Associated Gets
+    /// [**glGetFragDataLocation**](crate::context::Context::oxidegl_get_frag_data_location)
+    /// with a valid program object and the name of a user-defined varying out
+    /// variable
+    pub unsafe fn oxidegl_bind_frag_data_location(
+        &mut self,
+        program: GLuint,
+        color: GLuint,
+        name: *const GLchar,
+    ) -> GlFallible {
+        // Safety: caller ensures name is a valid, null-terminated string
+        unsafe { self.oxidegl_bind_frag_data_location_indexed(program, color, 0, name) }
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > The name of the program containing varying out variable whose binding to
+    /// > modify
+    ///
+    /// `colorNumber`
+    ///
+    /// > The color number to bind the user-defined varying out variable to
+    ///
+    /// `index`
+    ///
+    /// > The index of the color input to bind the user-defined varying out variable
+    /// > to
+    ///
+    /// `name`
+    ///
+    /// > The name of the user-defined varying out variable whose binding to modify
+    ///
+    /// ### Description
+    /// [**glBindFragDataLocationIndexed**](crate::context::Context::oxidegl_bind_frag_data_location_indexed)
+    /// specifies that the varying out variable `name` in `program` should be bound
+    /// to fragment color `colorNumber` when the program is next linked. `index`
+    /// may be zero or one to specify that the color be used as either the first
+    /// or second color input to the blend equation, respectively.
+    ///
+    /// The bindings specified by [**glBindFragDataLocationIndexed**](crate::context::Context::oxidegl_bind_frag_data_location_indexed)
+    /// have no effect until `program` is next linked. Bindings may be specified
+    /// at any time after `program` has been created. Specifically, they may be
+    /// specified before shader objects are attached to the program. Therefore,
+    /// any name may be specified in `name`, including a name that is never used
+    /// as a varying out variable in any fragment shader object. Names beginning
+    /// with [`gl_`](crate::gl_enums::gl_) are reserved by the GL.
+    ///
+    /// If `name` was bound previously, its assigned binding is replaced with `colorNumber`
+    /// and `index`. `name` must be a null-terminated string. `index` must be less
+    /// than or equal to one, and `colorNumber` must be less than the value of
+    /// [`GL_MAX_DRAW_BUFFERS`](crate::gl_enums::GL_MAX_DRAW_BUFFERS) if `index`
+    /// is zero, and less than the value of [`GL_MAX_DUAL_SOURCE_DRAW_BUFFERS`](crate::gl_enums::GL_MAX_DUAL_SOURCE_DRAW_BUFFERS)
+    /// if index is greater than or equal to one.
+    ///
+    /// In addition to the errors generated by [**glBindFragDataLocationIndexed**](crate::context::Context::oxidegl_bind_frag_data_location_indexed),
+    /// the program `program` will fail to link if: The number of active outputs
+    /// > is greater than the value [`GL_MAX_DRAW_BUFFERS`](crate::gl_enums::GL_MAX_DRAW_BUFFERS).
+    ///
+    /// > More than one varying out variable is bound to the same color number.
+    ///
+    ///
+    /// ### Notes
+    /// Varying out varyings may have locations assigned explicitly in the shader
+    /// text using a [**glBindFragDataLocation**](crate::context::Context::oxidegl_bind_frag_data_location)
+    /// is ignored.
+    ///
+    /// ### Associated Gets
+    /// [**glGetFragDataLocation**](crate::context::Context::oxidegl_get_frag_data_location)
+    /// with a valid program object and the name of a user-defined varying out
+    /// variable
+    ///
+    /// [**glGetFragDataIndex**](crate::context::Context::oxidegl_get_frag_data_index)
+    /// with a valid program object and the name of a user-defined varying out
+    /// variable
+    pub unsafe fn oxidegl_bind_frag_data_location_indexed(
+        &mut self,
+        program: GLuint,
+        color_number: GLuint,
+        index: GLuint,
+        name: *const GLchar,
+    ) -> GlFallible {
         let program = self
             .gl_state
             .program_list
             .get_program_raw_mut(&self.gl_state.shader_list, program)?;
-        program.link(&mut self.gl_state.shader_list, &self.renderer.device);
+        gl_assert!(index <= 1, InvalidValue);
+        let max = if index == 0 {
+            MAX_COLOR_ATTACHMENTS
+        } else {
+            MAX_DUAL_SOURCE_DRAW_BUFFERS
+        };
+        gl_assert!(color_number < max, InvalidValue);
+        // Safety: caller ensures name is a valid, null-terminated string
+        let name = unsafe { CStr::from_ptr(name) }.to_string_lossy();
+        if name.starts_with("gl_") {
+            gl_err!(ty: Error, "tried to bind the reserved fragment output name {name:?}");
+            return Err(GlError::InvalidOperation.e());
+        }
+        gl_debug!(
+            "{:?} will bind {name:?} to color number {color_number} and index {index} after its next link",
+            program.name
+        );
+        program
+            .location_bindings
+            .frag_data
+            .insert(name.into_owned(), (color_number, index));
         Ok(())
     }
     /// ### Parameters
@@ -557,6 +943,50 @@ impl Context {
             .program_list
             .get_program_raw_mut(&self.gl_state.shader_list, program)?;
         gl_debug!("getting program property {pname:?} of {:?}", program.name);
+        // completion status is the only query that must not wait for the link to finish
+        if pname != ProgramProperty::CompletionStatus {
+            program.finish_link();
+        }
+        let interfaces = program.latest_linkage.as_ref().map(|l| &l.interfaces);
+        let geometry = program
+            .latest_linkage
+            .as_ref()
+            .and_then(|l| l.geometry.as_ref())
+            .map(|g| g.layout.info);
+        if matches!(
+            pname,
+            ProgramProperty::GeometryVerticesOut
+                | ProgramProperty::GeometryInputType
+                | ProgramProperty::GeometryOutputType
+        ) {
+            gl_assert!(
+                geometry.is_some(),
+                InvalidOperation,
+                "{pname:?} requires a program with a geometry stage"
+            );
+        }
+        if pname == ProgramProperty::ComputeWorkGroupSize {
+            let size = program
+                .latest_linkage
+                .as_ref()
+                .and_then(|l| l.compute.as_ref())
+                .map(|c| c.work_group_size);
+            gl_assert!(
+                size.is_some(),
+                InvalidOperation,
+                "{pname:?} requires a program with a compute stage"
+            );
+            for (i, dim) in size.into_iter().flatten().enumerate() {
+                #[expect(
+                    clippy::cast_possible_wrap,
+                    reason = "work group sizes are at most 1024"
+                )]
+                let dim = dim as GLint;
+                // Safety: caller ensures params points to storage for 3 `i32`s for this property
+                unsafe { core::ptr::write(params.add(i), dim) };
+            }
+            return Ok(());
+        }
         //4gb shader is not real :3
         #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
         let ret = match pname {
@@ -566,29 +996,129 @@ impl Context {
             ProgramProperty::LinkStatus | ProgramProperty::ValidateStatus => {
                 i32::from(program.latest_linkage.is_some())
             }
+            ProgramProperty::CompletionStatus => i32::from(program.link_complete()),
 
-            ProgramProperty::InfoLogLength => program.info_log.len() as i32,
+            ProgramProperty::InfoLogLength => gl_string_len(&program.info_log) as i32,
             ProgramProperty::AttachedShaders => program.attached_shader_count() as i32,
-            ProgramProperty::ActiveUniforms => todo!(),
-            ProgramProperty::ActiveUniformMaxLength => todo!(),
-            ProgramProperty::ActiveAttributes => todo!(),
-            ProgramProperty::ActiveAttributeMaxLength => todo!(),
-            ProgramProperty::TransformFeedbackVaryingMaxLength => todo!(),
-            ProgramProperty::TransformFeedbackBufferMode => todo!(),
-            ProgramProperty::TransformFeedbackVaryings => todo!(),
-            ProgramProperty::ActiveUniformBlockMaxNameLength => todo!(),
-            ProgramProperty::ActiveUniformBlocks => todo!(),
-            ProgramProperty::GeometryVerticesOut => todo!(),
-            ProgramProperty::GeometryInputType => todo!(),
-            ProgramProperty::GeometryOutputType => todo!(),
-            ProgramProperty::ProgramBinaryLength => todo!(),
-            ProgramProperty::ActiveAtomicCounterBuffers => todo!(),
-            ProgramProperty::ComputeWorkGroupSize => todo!(),
+            ProgramProperty::ActiveUniforms => {
+                interfaces.map_or(0, |i| i.resource_count(ProgramInterface::Uniform)) as i32
+            }
+            ProgramProperty::ActiveUniformMaxLength => {
+                interfaces.map_or(0, |i| i.max_name_length(ProgramInterface::Uniform)) as i32
+            }
+            ProgramProperty::ActiveAttributes => {
+                interfaces.map_or(0, |i| i.attributes().len()) as i32
+            }
+            ProgramProperty::ActiveAttributeMaxLength => interfaces
+                .and_then(|i| i.attributes().iter().map(|a| a.gl_name().len() + 1).max())
+                .unwrap_or(0) as i32,
+            ProgramProperty::TransformFeedbackVaryingMaxLength => interfaces.map_or(0, |i| {
+                i.max_name_length(ProgramInterface::TransformFeedbackVarying)
+            }) as i32,
+            ProgramProperty::TransformFeedbackBufferMode => program
+                .latest_linkage
+                .as_ref()
+                .and_then(|l| l.feedback.as_ref())
+                .map_or(TransformFeedbackBufferMode::InterleavedAttribs, |f| {
+                    f.layout.mode
+                }) as i32,
+            ProgramProperty::TransformFeedbackVaryings => interfaces.map_or(0, |i| {
+                i.resource_count(ProgramInterface::TransformFeedbackVarying)
+            }) as i32,
+            ProgramProperty::ActiveUniformBlockMaxNameLength => {
+                interfaces.map_or(0, |i| i.max_name_length(ProgramInterface::UniformBlock)) as i32
+            }
+            ProgramProperty::ActiveUniformBlocks => {
+                interfaces.map_or(0, |i| i.resource_count(ProgramInterface::UniformBlock)) as i32
+            }
+            ProgramProperty::GeometryVerticesOut => geometry.map_or(0, |g| g.max_vertices) as i32,
+            ProgramProperty::GeometryInputType => geometry.map_or(0, |g| g.input as u32) as i32,
+            ProgramProperty::GeometryOutputType => geometry.map_or(0, |g| g.output as u32) as i32,
+            ProgramProperty::ProgramBinaryLength => program
+                .latest_linkage
+                .as_ref()
+                .map_or(0, |l| l.binary().len())
+                as i32,
+            ProgramProperty::ActiveAtomicCounterBuffers => interfaces.map_or(0, |i| {
+                i.resource_count(ProgramInterface::AtomicCounterBuffer)
+            }) as i32,
+            ProgramProperty::ComputeWorkGroupSize => unreachable!("handled above"),
         };
         // Safety: caller ensures params points to a valid storage and is aligned correctly for `i32`
         unsafe { core::ptr::write(params, ret) };
         Ok(())
     }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the program object whose information log is to be queried.
+    ///
+    /// `maxLength`
+    ///
+    /// > Specifies the size of the character buffer for storing the returned information
+    /// > log.
+    ///
+    /// `length`
+    ///
+    /// > Returns the length of the string returned in `infoLog` (excluding the null
+    /// > terminator).
+    ///
+    /// `infoLog`
+    ///
+    /// > Specifies an array of characters that is used to return the information
+    /// > log.
+    ///
+    /// ### Description
+    /// [**glGetProgramInfoLog**](crate::context::Context::oxidegl_get_program_info_log)
+    /// returns the information log for the specified program object. The information
+    /// log for a program object is modified when the program object is linked
+    /// or validated. The string that is returned will be null terminated.
+    ///
+    /// [**glGetProgramInfoLog**](crate::context::Context::oxidegl_get_program_info_log)
+    /// returns in `infoLog` as much of the information log as it can, up to a
+    /// maximum of `maxLength` characters. The number of characters actually returned,
+    /// excluding the null termination character, is specified by `length`. If
+    /// the length of the returned string is not required, a value of [`NULL`](crate::gl_enums::NULL)
+    /// can be passed in the `length` argument. The size of the buffer required
+    /// to store the returned information log can be obtained by calling [**glGetProgram**](crate::context::Context::oxidegl_get_program)
+    /// with the value [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH).
+    ///
+    /// The information log for a program object is either an empty string, or
+    /// a string containing information about the last link operation, or a string
+    /// containing information about the last validation operation. It may contain
+    /// diagnostic messages, warning messages, and other information. When a program
+    /// object is created, its information log will be a string of length 0.
+    ///
+    /// ### Notes
+    /// The information log for a program object is the OpenGL implementer's primary
+    /// mechanism for conveying information about linking and validating. Therefore,
+    /// the information log can be helpful to application developers during the
+    /// development process, even when these operations are successful. Application
+    /// developers should not expect different OpenGL implementations to produce
+    /// identical information logs.
+    ///
+    /// ### Associated Gets
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
+    /// [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH)
+    ///
+    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
+    pub unsafe fn oxidegl_get_program_info_log(
+        &mut self,
+        program: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        info_log: *mut GLchar,
+    ) -> GlFallible {
+        sizei!(buf_size);
+        let program = self
+            .gl_state
+            .program_list
+            .get_program_raw_mut(&self.gl_state.shader_list, program)?;
+        program.finish_link();
+        // Safety: caller ensures info_log is valid for writes of buf_size bytes and length is valid for a write
+        unsafe { write_gl_string(&program.info_log, buf_size, length, info_log) };
+        Ok(())
+    }
 
     /// ### Parameters
     /// `program`
@@ -733,26 +1263,376 @@ impl Context {
     ///
     /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
     pub fn oxidegl_use_program(&mut self, program: GLuint) -> GlFallible {
-        let name = self
-            .gl_state
-            .program_list
-            .get_program_raw_mut(&self.gl_state.shader_list, program)
-            .and_then(|p| {
-                if p.latest_linkage.is_some() {
-                    Ok(p.name)
-                } else {
-                    Err(GlError::InvalidOperation.e())
-                }
-            })?;
-        run_if_changed!(self.gl_state.program_binding;= Some(name) => {
+        gl_assert!(
+            !self.gl_state.transform_feedback().capturing(),
+            InvalidOperation,
+            "tried to change the current program while transform feedback is active and not paused"
+        );
+        // zero makes the current program pipeline (if any) used for rendering instead
+        let name = if program == 0 {
+            None
+        } else {
+            Some(
+                self.gl_state
+                    .program_list
+                    .get_program_raw_mut(&self.gl_state.shader_list, program)
+                    .and_then(|p| {
+                        if p.linkage().is_some() {
+                            Ok(p.name)
+                        } else {
+                            Err(GlError::InvalidOperation.e())
+                        }
+                    })?,
+            )
+        };
+        self.reset_subroutine_selections();
+        run_if_changed!(self.gl_state.program_binding;= name => {
                 self.new_pipeline();
+                self.new_compute_pipeline();
                 self.new_encoder();
                 self.remap_buffers();
+                self.upload_uniforms();
             }
         );
         gl_debug!("bound {name:?} as current shader program");
         Ok(())
     }
+    /// ### Parameters
+    /// `type`
+    ///
+    /// > Specifies the type of shader to create.
+    ///
+    /// `count`
+    ///
+    /// > Specifies the number of source code strings in the array `strings`.
+    ///
+    /// `strings`
+    ///
+    /// > Specifies the address of an array of pointers to source code strings from
+    /// > which to create the program object.
+    ///
+    /// ### Description
+    /// [**glCreateShaderProgram**](crate::context::Context::oxidegl_create_shader_program)
+    /// creates a program object containing compiled and linked shaders for a single
+    /// stage specified by `type`. `strings` refers to an array of `count` strings
+    /// from which to create the shader executables.
+    ///
+    /// [**glCreateShaderProgram**](crate::context::Context::oxidegl_create_shader_program)
+    /// is equivalent (assuming no errors are generated) to:
+    ///
+    /// The program object created by [**glCreateShaderProgram**](crate::context::Context::oxidegl_create_shader_program)
+    /// has its [`GL_PROGRAM_SEPARABLE`](crate::gl_enums::GL_PROGRAM_SEPARABLE)
+    /// status set to [`GL_TRUE`](crate::gl_enums::GL_TRUE).
+    pub unsafe fn oxidegl_create_shader_programv(
+        &mut self,
+        r#type: ShaderType,
+        count: GLsizei,
+        strings: *const *const GLchar,
+    ) -> GlFallible<GLuint> {
+        gl_assert!(count >= 0, InvalidValue, "negative string count");
+        let shader = self.oxidegl_create_shader(r#type);
+        // Safety: caller ensures strings points to count nul-terminated strings
+        unsafe { self.oxidegl_shader_source(shader, count, strings, ptr::null()) }?;
+        self.oxidegl_compile_shader(shader)?;
+        let program = self.oxidegl_create_program();
+        self.gl_state
+            .program_list
+            .get_mut(ObjectName::from_raw(program))
+            .separable = true;
+        self.oxidegl_attach_shader(program, shader)?;
+        self.oxidegl_link_program(program)?;
+        // the shader is deleted right away, so its compiler log is only available from the program
+        let log = self
+            .gl_state
+            .shader_list
+            .get(ObjectName::from_raw(shader))
+            .info_log()
+            .into_owned();
+        self.gl_state
+            .program_list
+            .get_mut(ObjectName::from_raw(program))
+            .info_log
+            .push_str(&log);
+        // detaching the shader after marking it for deletion deletes it
+        self.oxidegl_delete_shader(shader);
+        self.oxidegl_detach_shader(program, shader)?;
+        Ok(program)
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the name of a program object whose parameter to modify.
+    ///
+    /// `pname`
+    ///
+    /// > Specifies the name of the parameter to modify.
+    ///
+    /// `value`
+    ///
+    /// > Specifies the new value of the parameter specified by `pname` for `program`.
+    ///
+    /// ### Description
+    /// [**glProgramParameter**](crate::context::Context::oxidegl_program_parameter)
+    /// specifies a new value for the parameter nameed by `pname` for the program
+    /// object `program`.
+    ///
+    /// If `pname` is [`GL_PROGRAM_BINARY_RETRIEVABLE_HINT`](crate::gl_enums::GL_PROGRAM_BINARY_RETRIEVABLE_HINT),
+    /// `value` should be [`GL_FALSE`](crate::gl_enums::GL_FALSE) or [`GL_TRUE`](crate::gl_enums::GL_TRUE)
+    /// to indicate to the implementation the intention of the application to retrieve
+    /// the program's binary representation with [**glGetProgramBinary**](crate::context::Context::oxidegl_get_program_binary).
+    /// The implementation may use this information to store information that
+    /// may be useful for a future query of the program's binary. It is recommended
+    /// to set [`GL_PROGRAM_BINARY_RETRIEVABLE_HINT`](crate::gl_enums::GL_PROGRAM_BINARY_RETRIEVABLE_HINT)
+    /// for the program to [`GL_TRUE`](crate::gl_enums::GL_TRUE) before calling
+    /// [**glLinkProgram**](crate::context::Context::oxidegl_link_program), and
+    /// using the program at run-time if the binary is to be retrieved later.
+    ///
+    /// If `pname` is [`GL_PROGRAM_SEPARABLE`](crate::gl_enums::GL_PROGRAM_SEPARABLE),
+    /// `value` must be [`GL_TRUE`](crate::gl_enums::GL_TRUE) or [`GL_FALSE`](crate::gl_enums::GL_FALSE)
+    /// and indicates whether `program` can be bound to individual pipeline stages
+    /// via [**glUseProgramStages**](crate::context::Context::oxidegl_use_program_stages).
+    /// A program's [`GL_PROGRAM_SEPARABLE`](crate::gl_enums::GL_PROGRAM_SEPARABLE)
+    /// parameter must be set to [`GL_TRUE`](crate::gl_enums::GL_TRUE) *before*
+    /// [**glLinkProgram**](crate::context::Context::oxidegl_link_program) is called
+    /// in order for it to be usable with a program pipeline object. The initial
+    /// state of [`GL_PROGRAM_SEPARABLE`](crate::gl_enums::GL_PROGRAM_SEPARABLE)
+    /// is [`GL_FALSE`](crate::gl_enums::GL_FALSE).
+    ///
+    /// ### Associated Gets
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program).
+    pub fn oxidegl_program_parameteri(
+        &mut self,
+        program: GLuint,
+        pname: ProgramParameterPName,
+        value: GLint,
+    ) -> GlFallible {
+        gl_assert!(
+            value == 0 || value == 1,
+            InvalidValue,
+            "{pname:?} must be GL_TRUE or GL_FALSE"
+        );
+        let program = self
+            .gl_state
+            .program_list
+            .get_program_raw_mut(&self.gl_state.shader_list, program)?;
+        match pname {
+            // the binary of every linked program can be retrieved, so the hint has no effect
+            ProgramParameterPName::ProgramBinaryRetrievableHint => {}
+            ProgramParameterPName::ProgramSeparable => program.separable = value == 1,
+        }
+        gl_debug!("set {pname:?} of {:?} to {value}", program.name);
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the name of a program object whose binary representation to retrieve.
+    ///
+    /// `bufSize`
+    ///
+    /// > Specifies the size of the buffer whose address is given by `binary`.
+    ///
+    /// `length`
+    ///
+    /// > Specifies the address of a variable to receive the number of bytes written
+    /// > into `binary`.
+    ///
+    /// `binaryFormat`
+    ///
+    /// > Specifies the address of a variable to receive a token indicating the format
+    /// > of the binary data returned by the GL.
+    ///
+    /// `binary`
+    ///
+    /// > Specifies the address an array into which the GL will return `program`
+    /// > 's binary representation.
+    ///
+    /// ### Description
+    /// [**glGetProgramBinary**](crate::context::Context::oxidegl_get_program_binary)
+    /// returns a binary representation of the compiled and linked executable for
+    /// `program` into the array of bytes whose address is specified in `binary`.
+    /// The maximum number of bytes that may be written into `binary` is specified
+    /// by `bufSize`. If the program binary is greater in size than `bufSize` bytes,
+    /// then an error is generated, otherwise the actual number of bytes written
+    /// into `binary` is returned in the variable whose address is given by `length`.
+    /// If `length` is [`NULL`](crate::gl_enums::NULL), then no length is returned.
+    ///
+    /// The format of the program binary written into `binary` is returned in the
+    /// variable whose address is given by `binaryFormat`, and may be implementation
+    /// dependent. The binary produced by the GL may subsequently be returned to
+    /// the GL by calling [**glProgramBinary**](crate::context::Context::oxidegl_program_binary),
+    /// with `binaryFormat` and `length` set to the values returned by [**glGetProgramBinary**](crate::context::Context::oxidegl_get_program_binary),
+    /// and passing the returned binary data in the `binary` parameter.
+    ///
+    /// ### Associated Gets
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
+    /// [`GL_PROGRAM_BINARY_LENGTH`](crate::gl_enums::GL_PROGRAM_BINARY_LENGTH)
+    pub unsafe fn oxidegl_get_program_binary(
+        &mut self,
+        program: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        binary_format: *mut GLenum,
+        binary: *mut GLvoid,
+    ) -> GlFallible {
+        sizei!(buf_size);
+        let program = self
+            .gl_state
+            .program_list
+            .get_program_raw_mut(&self.gl_state.shader_list, program)?;
+        let name = program.name;
+        let Some(linkage) = program.linkage() else {
+            gl_err!(src: Api, ty: Error, "tried to get the binary of {name:?}, which is not linked");
+            return Err(GlError::InvalidOperation.e());
+        };
+        let bytes = linkage.binary();
+        gl_assert!(
+            bytes.len() <= buf_size as usize,
+            InvalidOperation,
+            "binary of {name:?} is {} bytes, which does not fit in a buffer of {buf_size} bytes",
+            bytes.len()
+        );
+        gl_debug!(src: ShaderCompiler, "returning a binary of {} bytes for {name:?}", bytes.len());
+        // Safety: caller ensures binary is valid for writes of buf_size bytes, binary_format is valid for a write and
+        // length is either null or valid for a write
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), binary.cast::<u8>(), bytes.len());
+            ptr::write(binary_format, PROGRAM_BINARY_FORMAT);
+            if !length.is_null() {
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_possible_wrap,
+                    reason = "the binary fits in a buffer of buf_size bytes"
+                )]
+                let len = bytes.len() as GLsizei;
+                ptr::write(length, len);
+            }
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the name of a program object into which to load a program binary.
+    ///
+    /// `binaryFormat`
+    ///
+    /// > Specifies the format of the binary data in binary.
+    ///
+    /// `binary`
+    ///
+    /// > Specifies the address an array containing the binary to be loaded into
+    /// > `program`.
+    ///
+    /// `length`
+    ///
+    /// > Specifies the number of bytes contained in `binary`.
+    ///
+    /// ### Description
+    /// [**glProgramBinary**](crate::context::Context::oxidegl_program_binary)
+    /// loads a program object with a program binary previously returned from [**glGetProgramBinary**](crate::context::Context::oxidegl_get_program_binary).
+    /// `binaryFormat` and `binary` must be those returned by a previous call to
+    /// [**glGetProgramBinary**](crate::context::Context::oxidegl_get_program_binary),
+    /// and `length` must be the length returned by [**glGetProgramBinary**](crate::context::Context::oxidegl_get_program_binary),
+    /// or by [**glGetProgram**](crate::context::Context::oxidegl_get_program)
+    /// when called with `pname` set to [`GL_PROGRAM_BINARY_LENGTH`](crate::gl_enums::GL_PROGRAM_BINARY_LENGTH).
+    /// If these conditions are not met, loading the program binary will fail
+    /// and `program` 's [`GL_LINK_STATUS`](crate::gl_enums::GL_LINK_STATUS) will
+    /// be set to [`GL_FALSE`](crate::gl_enums::GL_FALSE).
+    ///
+    /// A program object's program binary is replaced by calls to [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// or [**glProgramBinary**](crate::context::Context::oxidegl_program_binary).
+    /// When linking success or failure is concerned, [**glProgramBinary**](crate::context::Context::oxidegl_program_binary)
+    /// can be considered to perform an implicit linking operation. [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// and [**glProgramBinary**](crate::context::Context::oxidegl_program_binary)
+    /// both set the program object's [`GL_LINK_STATUS`](crate::gl_enums::GL_LINK_STATUS)
+    /// to [`GL_TRUE`](crate::gl_enums::GL_TRUE) or [`GL_FALSE`](crate::gl_enums::GL_FALSE).
+    ///
+    /// A successful call to [**glProgramBinary**](crate::context::Context::oxidegl_program_binary)
+    /// will reset all uniform variables to their initial values. The initial value
+    /// is either the value of the variable's initializer as specified in the original
+    /// shader source, or zero if no initializer was present. Additionally, all
+    /// vertex shader input and fragment shader output assignments that were in
+    /// effect when the program was linked before saving are restored with [**glProgramBinary**](crate::context::Context::oxidegl_program_binary)
+    /// is called.
+    ///
+    /// ### Notes
+    /// A program binary may fail to load if the implementation determines that
+    /// there has been a change in hardware or software configuration from when
+    /// the program binary was produced such as having been compiled with an incompatible
+    /// or outdated version of the compiler.
+    ///
+    /// ### Associated Gets
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
+    /// [`GL_PROGRAM_BINARY_LENGTH`](crate::gl_enums::GL_PROGRAM_BINARY_LENGTH)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_NUM_PROGRAM_BINARY_FORMATS`](crate::gl_enums::GL_NUM_PROGRAM_BINARY_FORMATS)
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PROGRAM_BINARY_FORMATS`](crate::gl_enums::GL_PROGRAM_BINARY_FORMATS)
+    pub unsafe fn oxidegl_program_binary(
+        &mut self,
+        program: GLuint,
+        binary_format: GLenum,
+        binary: *const GLvoid,
+        length: GLsizei,
+    ) -> GlFallible {
+        gl_assert!(
+            binary_format == PROGRAM_BINARY_FORMAT,
+            InvalidEnum,
+            "{binary_format:#x} is not a supported program binary format"
+        );
+        sizei!(length);
+        // Safety: caller ensures binary points to length bytes
+        let bytes = unsafe { slice::from_raw_parts(binary.cast::<u8>(), length as usize) };
+        self.replace_executable(program)?;
+        let program = self
+            .gl_state
+            .program_list
+            .get_program_raw_mut(&self.gl_state.shader_list, program)?;
+        program.load_binary(
+            bytes.into(),
+            &mut self.gl_state.compile_pool,
+            self.renderer.backend.stage_compiler(),
+        );
+        Ok(())
+    }
+}
+impl Context {
+    /// Check that the executable of a program can be replaced by `glLinkProgram` or `glProgramBinary`, and regenerate
+    /// the program-related state if the program is used for rendering
+    fn replace_executable(&mut self, program: GLuint) -> GlFallible {
+        gl_assert!(
+            !self
+                .gl_state
+                .transform_feedback()
+                .active
+                .is_some_and(|a| a.program.to_raw() == program),
+            InvalidOperation,
+            "tried to replace the executable of a program while transform feedback is active with it"
+        );
+        // the program is used for rendering either as the current program or by the current program pipeline
+        if let Some(pipeline) = self.gl_state.program_pipeline_binding {
+            self.gl_state
+                .program_pipeline_list
+                .get_mut(pipeline)
+                .validation = None;
+        }
+        if self
+            .gl_state
+            .current_programs()
+            .iter()
+            .any(|n| n.to_raw() == program)
+        {
+            // the subroutine indices and locations of the new executable may differ
+            self.reset_subroutine_selections();
+            self.new_pipeline();
+            self.new_compute_pipeline();
+            self.new_encoder();
+            self.remap_buffers();
+            self.upload_uniforms();
+        }
+        Ok(())
+    }
 }
 pub(crate) trait ProgramListExt {
     /// gets a reference to a Program from an object name with the correct GL error semantics for `glProgram*`
@@ -784,3 +1664,62 @@ impl ProgramListExt for NamedObjectList<Program> {
         }
     }
 }
+
+#[test]
+fn software_parallel_compile_and_link() {
+    use crate::{
+        gl_enums::{ShaderParameterName, ShaderType},
+        test_util::{compile, get_programiv, link},
+    };
+
+    let mut ctx = Context::new_software(8, 8);
+    let vert_src = "#version 430 core
+        layout(location = 0) in vec2 position;
+        void main() { gl_Position = vec4(position, 0.0, 1.0); }";
+    let frag_src = "#version 430 core
+        out vec4 color;
+        void main() { color = vec4(1.0); }";
+    ctx.oxidegl_max_shader_compiler_threads(2);
+    let vert = compile(&mut ctx, ShaderType::VertexShader, vert_src);
+    let frag = compile(&mut ctx, ShaderType::FragmentShader, frag_src);
+    let program = link(&mut ctx, &[vert, frag]);
+    // polling the completion status never blocks, the link eventually finishes on the pool
+    while get_programiv(&mut ctx, program, ProgramProperty::CompletionStatus) == 0 {
+        std::thread::yield_now();
+    }
+    assert_eq!(
+        get_programiv(&mut ctx, program, ProgramProperty::LinkStatus),
+        1
+    );
+
+    // without worker threads everything is complete as soon as the command returns
+    ctx.oxidegl_max_shader_compiler_threads(0);
+    let broken = compile(
+        &mut ctx,
+        ShaderType::VertexShader,
+        "#version 430 core\nvoid main() { undeclared = 1.0; }",
+    );
+    let mut complete = 0;
+    // Safety: params points to a single GLint
+    unsafe {
+        ctx.oxidegl_get_shaderiv(
+            broken,
+            ShaderParameterName::CompletionStatus,
+            &raw mut complete,
+        )
+    }
+    .unwrap();
+    assert_eq!(complete, 1);
+    ctx.oxidegl_detach_shader(program, vert).unwrap();
+    ctx.oxidegl_attach_shader(program, broken).unwrap();
+    ctx.oxidegl_link_program(program).unwrap();
+    assert_eq!(
+        get_programiv(&mut ctx, program, ProgramProperty::CompletionStatus),
+        1
+    );
+    assert_eq!(
+        get_programiv(&mut ctx, program, ProgramProperty::LinkStatus),
+        0
+    );
+    assert!(get_programiv(&mut ctx, program, ProgramProperty::InfoLogLength) > 1);
+}
@@ -0,0 +1,772 @@
+use crate::{
+    context::Context,
+    conversions::{GlDstType, GlGetItem},
+    debug::{gl_debug, gl_err},
+    error::{GlError, GlFallible, gl_assert},
+    gl_enums::{QueryObjectParameterName, QueryParameterName, QueryTarget},
+    gl_object::ObjectName,
+    gl_types::{GLboolean, GLint, GLint64, GLsizei, GLuint, GLuint64},
+    program::geometry::MAX_VERTEX_STREAMS,
+    query::{ActiveQuery, Query},
+};
+
+impl Context {
+    /// Begin counting with the query named `id` on index `index` of `target`
+    fn begin_query_internal(
+        &mut self,
+        target: QueryTarget,
+        index: GLuint,
+        id: GLuint,
+    ) -> GlFallible {
+        let streams = match target {
+            QueryTarget::PrimitivesGenerated | QueryTarget::TransformFeedbackPrimitivesWritten => {
+                MAX_VERTEX_STREAMS
+            }
+            QueryTarget::TransformFeedbackOverflow => 1,
+            t => {
+                gl_err!(ty: Error, "queries of {t:?} are not supported yet");
+                return Err(GlError::InvalidEnum.e());
+            }
+        };
+        gl_assert!(index < streams, InvalidValue);
+        let name = ObjectName::try_from_raw(id)?;
+        self.gl_state
+            .query_list
+            .ensure_init(name, |name| Query::new_with_target(name, target))?;
+        gl_assert!(
+            !self
+                .gl_state
+                .active_queries
+                .iter()
+                .any(|q| q.query == name || (q.target == target && q.index == index)),
+            InvalidOperation,
+            "tried to begin {name:?}, but it or another {target:?} query is already active"
+        );
+        let query = self.gl_state.query_list.get_mut(name);
+        gl_assert!(
+            query.target.is_none_or(|t| t == target),
+            InvalidOperation,
+            "tried to begin {name:?} on {target:?}, but it was previously used with {:?}",
+            query.target
+        );
+        query.target = Some(target);
+        query.result = 0;
+        self.gl_state.active_queries.push(ActiveQuery {
+            target,
+            index,
+            query: name,
+        });
+        gl_debug!("began {target:?} query {name:?} at index {index}");
+        Ok(())
+    }
+    fn end_query_internal(&mut self, target: QueryTarget, index: GLuint) -> GlFallible {
+        let Some(i) = self
+            .gl_state
+            .active_queries
+            .iter()
+            .position(|q| q.target == target && q.index == index)
+        else {
+            return Err(GlError::InvalidOperation.e());
+        };
+        let query = self.gl_state.active_queries.remove(i).query;
+        gl_debug!(
+            "ended {target:?} query {query:?} with a result of {}",
+            self.gl_state.query_list.get(query).result
+        );
+        Ok(())
+    }
+    /// Write a parameter of the query named `id` to `params`
+    unsafe fn get_query_object_internal<T: GlDstType>(
+        &self,
+        id: GLuint,
+        pname: QueryObjectParameterName,
+        params: *mut T,
+    ) -> GlFallible {
+        let query = self.gl_state.query_list.get_raw(id)?;
+        gl_assert!(
+            !self
+                .gl_state
+                .active_queries
+                .iter()
+                .any(|q| q.query == query.name),
+            InvalidOperation,
+            "tried to get the result of {:?} while it is active",
+            query.name
+        );
+        // results are counted while encoding draws, so they are available as soon as the query ends
+        // Safety: Caller ensures params is valid for a write
+        unsafe {
+            match pname {
+                QueryObjectParameterName::QueryResult
+                | QueryObjectParameterName::QueryResultNoWait => query.result.write_out(params),
+                QueryObjectParameterName::QueryResultAvailable => true.write_out(params),
+                QueryObjectParameterName::QueryTarget => {
+                    query.target.map_or(0, |t| t as u32).write_out(params);
+                }
+            }
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of query object names to be generated.
+    ///
+    /// `ids`
+    ///
+    /// > Specifies an array in which the generated query object names are stored.
+    ///
+    /// ### Description
+    /// [**glGenQueries**](crate::context::Context::oxidegl_gen_queries) returns
+    /// `n` query object names in `ids`. There is no guarantee that the names form
+    /// a contiguous set of integers; however, it is guaranteed that none of the
+    /// returned names was in use immediately before the call to [**glGenQueries**](crate::context::Context::oxidegl_gen_queries).
+    ///
+    /// Query object names returned by a call to [**glGenQueries**](crate::context::Context::oxidegl_gen_queries)
+    /// are not returned by subsequent calls, unless they are first deleted with
+    /// [**glDeleteQueries**](crate::context::Context::oxidegl_delete_queries).
+    ///
+    /// No query objects are associated with the returned query object names until
+    /// they are first used by calling [**glBeginQuery**](crate::context::Context::oxidegl_begin_query).
+    ///
+    /// ### Associated Gets
+    /// [**glIsQuery**](crate::context::Context::oxidegl_is_query)
+    pub unsafe fn oxidegl_gen_queries(&mut self, n: GLsizei, ids: *mut GLuint) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller guarantees invariants are upheld
+        unsafe {
+            self.gl_state.query_list.gen_obj(n, ids);
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the target of each created query object.
+    ///
+    /// `n`
+    ///
+    /// > Number of query objects to create.
+    ///
+    /// `ids`
+    ///
+    /// > Specifies an array in which names of the new query objects are stored.
+    ///
+    /// ### Description
+    /// [**glCreateQueries**](crate::context::Context::oxidegl_create_queries)
+    /// returns `n` previously unused query object names in `ids`, each representing
+    /// a new query object with the specified `target`.
+    ///
+    /// `target` may be one of [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
+    /// [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE),
+    /// [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED), [`GL_TIMESTAMP`](crate::gl_enums::GL_TIMESTAMP),
+    /// [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED) or
+    /// [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN).
+    pub unsafe fn oxidegl_create_queries(
+        &mut self,
+        target: QueryTarget,
+        n: GLsizei,
+        ids: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller guarantees invariants are upheld
+        unsafe {
+            self.gl_state.query_list.create_obj(
+                |name| Query::new_with_target(name, target),
+                n,
+                ids,
+            );
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of query objects to be deleted.
+    ///
+    /// `ids`
+    ///
+    /// > Specifies an array of query objects to be deleted.
+    ///
+    /// ### Description
+    /// [**glDeleteQueries**](crate::context::Context::oxidegl_delete_queries)
+    /// deletes `n` query objects named by the elements of the array `ids`. After
+    /// a query object is deleted, it has no contents, and its name is free for
+    /// reuse (for example by [**glGenQueries**](crate::context::Context::oxidegl_gen_queries)
+    /// ).
+    ///
+    /// [**glDeleteQueries**](crate::context::Context::oxidegl_delete_queries)
+    /// silently ignores 0's and names that do not correspond to existing query
+    /// objects.
+    ///
+    /// ### Associated Gets
+    /// [**glIsQuery**](crate::context::Context::oxidegl_is_query)
+    pub unsafe fn oxidegl_delete_queries(&mut self, n: GLsizei, ids: *const GLuint) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller guarantees invariants are upheld
+        unsafe {
+            self.gl_state.query_list.delete_objects(n, ids);
+        }
+        // deleting an active query ends it
+        let list = &self.gl_state.query_list;
+        self.gl_state.active_queries.retain(|q| list.is(q.query));
+        Ok(())
+    }
+    /// ### Parameters
+    /// `id`
+    ///
+    /// > Specifies a value that may be the name of a query object.
+    ///
+    /// ### Description
+    /// [**glIsQuery**](crate::context::Context::oxidegl_is_query) returns [`GL_TRUE`](crate::gl_enums::GL_TRUE)
+    /// if `id` is currently the name of a query object. If `id` is zero, or is
+    /// a non-zero value that is not currently the name of a query object, or if
+    /// an error occurs, [**glIsQuery**](crate::context::Context::oxidegl_is_query)
+    /// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE).
+    ///
+    /// A name returned by [**glGenQueries**](crate::context::Context::oxidegl_gen_queries),
+    /// but not yet associated with a query object by calling [**glBeginQuery**](crate::context::Context::oxidegl_begin_query),
+    /// is not the name of a query object.
+    pub fn oxidegl_is_query(&mut self, id: GLuint) -> GlFallible<GLboolean> {
+        Ok(self.gl_state.query_list.is_obj(id))
+    }
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies a query object target. Must be [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
+    /// > [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE)
+    /// > [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED), [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
+    /// > [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED), or [`GL_TIMESTAMP`](crate::gl_enums::GL_TIMESTAMP).
+    ///
+    /// `pname`
+    ///
+    /// > Specifies the symbolic name of a query object target parameter. Accepted
+    /// > values are [`GL_CURRENT_QUERY`](crate::gl_enums::GL_CURRENT_QUERY) or [`GL_QUERY_COUNTER_BITS`](crate::gl_enums::GL_QUERY_COUNTER_BITS).
+    ///
+    /// `params`
+    ///
+    /// > Returns the requested data.
+    ///
+    /// ### Description
+    /// [**glGetQueryiv**](crate::context::Context::oxidegl_get_queryiv) returns
+    /// in `params` a selected parameter of the query object target specified by
+    /// `target`.
+    ///
+    /// `pname` names a specific query object target parameter. When `pname` is
+    /// [`GL_CURRENT_QUERY`](crate::gl_enums::GL_CURRENT_QUERY), the name of the
+    /// currently active query for `target`, or zero if no query is active, will
+    /// be placed in `params`. If `pname` is [`GL_QUERY_COUNTER_BITS`](crate::gl_enums::GL_QUERY_COUNTER_BITS),
+    /// the implementation-dependent number of bits used to hold the result of
+    /// queries for `target` is returned in `params`.
+    ///
+    /// ### Notes
+    /// The target [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE)
+    /// is available only if the GL version is 4.3 or greater.
+    ///
+    /// If an error is generated, no change is made to the contents of `params`.
+    pub unsafe fn oxidegl_get_queryiv(
+        &mut self,
+        target: QueryTarget,
+        pname: QueryParameterName,
+        params: *mut GLint,
+    ) -> GlFallible {
+        // Safety: Caller ensures params is valid for a write
+        unsafe { self.oxidegl_get_query_indexediv(target, 0, pname, params) }
+    }
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies a query object target. Must be [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
+    /// > [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE)
+    /// > [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED), [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
+    /// > [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED), or [`GL_TIMESTAMP`](crate::gl_enums::GL_TIMESTAMP).
+    ///
+    /// `index`
+    ///
+    /// > Specifies the index of the query object target.
+    ///
+    /// `pname`
+    ///
+    /// > Specifies the symbolic name of a query object target parameter. Accepted
+    /// > values are [`GL_CURRENT_QUERY`](crate::gl_enums::GL_CURRENT_QUERY) or [`GL_QUERY_COUNTER_BITS`](crate::gl_enums::GL_QUERY_COUNTER_BITS).
+    ///
+    /// `params`
+    ///
+    /// > Returns the requested data.
+    ///
+    /// ### Description
+    /// [**glGetQueryIndexediv**](crate::context::Context::oxidegl_get_query_indexediv)
+    /// returns in `params` a selected parameter of the indexed query object target
+    /// specified by `target` and `index`. `index` specifies the index of the query
+    /// object target and must be between zero and a target-specific maxiumum.
+    ///
+    /// `pname` names a specific query object target parameter. When `pname` is
+    /// [`GL_CURRENT_QUERY`](crate::gl_enums::GL_CURRENT_QUERY), the name of the
+    /// currently active query for the specified `index` of `target`, or zero if
+    /// no query is active, will be placed in `params`. If `pname` is [`GL_QUERY_COUNTER_BITS`](crate::gl_enums::GL_QUERY_COUNTER_BITS),
+    /// the implementation-dependent number of bits used to hold the result of
+    /// queries for `target` is returned in `params`.
+    ///
+    /// ### Notes
+    /// The target [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE)
+    /// is available only if the GL version is 4.3 or greater.
+    ///
+    /// If an error is generated, no change is made to the contents of `params`.
+    ///
+    /// Calling [**glGetQueryiv**](crate::context::Context::oxidegl_get_queryiv)
+    /// is equivalent to calling [**glGetQueryIndexediv**](crate::context::Context::oxidegl_get_query_indexediv)
+    /// with `index` set to zero.
+    pub unsafe fn oxidegl_get_query_indexediv(
+        &mut self,
+        target: QueryTarget,
+        index: GLuint,
+        pname: QueryParameterName,
+        params: *mut GLint,
+    ) -> GlFallible {
+        let value = match pname {
+            QueryParameterName::CurrentQuery => self
+                .gl_state
+                .active_queries
+                .iter()
+                .find(|q| q.target == target && q.index == index)
+                .map_or(0, |q| q.query.to_raw()),
+            // results are counted with 64 bit integers
+            QueryParameterName::QueryCounterBits => 64,
+        };
+        // Safety: Caller ensures params is valid for a write
+        unsafe { value.write_out(params) };
+        Ok(())
+    }
+}
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target type of query object established between [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
+/// > and the subsequent [**glEndQuery**](crate::context::Context::oxidegl_end_query).
+/// > The symbolic constant must be one of [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
+/// > [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE),
+/// > [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED), [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
+/// > or [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED).
+///
+/// `id`
+///
+/// > Specifies the name of a query object.
+///
+/// ### Description
+/// [**glBeginQuery**](crate::context::Context::oxidegl_begin_query) and [**glEndQuery**](crate::context::Context::oxidegl_end_query)
+/// delimit the boundaries of a query object. `query` must be a name previously
+/// returned from a call to [**glGenQueries**](crate::context::Context::oxidegl_gen_queries).
+/// If a query object with name `id` does not yet exist it is created with
+/// the type determined by `target`. `target` must be one of [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
+/// [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED),
+/// [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
+/// or [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED). The behavior
+/// of the query object depends on its type and is as follows.
+///
+/// If `target` is [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
+/// `id` must be an unused name, or the name of an existing occlusion query
+/// object. When [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
+/// is executed, the query object's samples-passed counter is reset to 0. Subsequent
+/// rendering will increment the counter for every sample that passes the depth
+/// test. If the value of [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS)
+/// is 0, then the samples-passed count is incremented by 1 for each fragment.
+/// If the value of [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS)
+/// is 1, then the samples-passed count is incremented by the number of samples
+/// whose coverage bit is set. However, implementations, at their discression
+/// may instead increase the samples-passed count by the value of [`GL_SAMPLES`](crate::gl_enums::GL_SAMPLES)
+/// if any sample in the fragment is covered. When [**glEndQuery**](crate::context::Context::oxidegl_end_query)
+/// is executed, the samples-passed counter is assigned to the query object's
+/// result value. This value can be queried by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT).
+///
+/// If `target` is [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED)
+/// or [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE),
+/// `id` must be an unused name, or the name of an existing boolean occlusion
+/// query object. When [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
+/// is executed, the query object's samples-passed flag is reset to [`GL_FALSE`](crate::gl_enums::GL_FALSE).
+/// Subsequent rendering causes the flag to be set to [`GL_TRUE`](crate::gl_enums::GL_TRUE)
+/// if any sample passes the depth test in the case of [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED),
+/// or if the implementation determines that any sample might pass the depth
+/// test in the case of [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE).
+/// The implementation may be able to provide a more efficient test in the
+/// case of [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE)
+/// if some false positives are acceptable to the application. When [**glEndQuery**](crate::context::Context::oxidegl_end_query)
+/// is executed, the samples-passed flag is assigned to the query object's
+/// result value. This value can be queried by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT).
+///
+/// If `target` is [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED),
+/// `id` must be an unused name, or the name of an existing primitive query
+/// object previously bound to the [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED)
+/// query binding. When [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
+/// is executed, the query object's primitives-generated counter is reset to
+/// 0. Subsequent rendering will increment the counter once for every vertex
+/// that is emitted from the geometry shader, or from the vertex shader if
+/// no geometry shader is present. When [**glEndQuery**](crate::context::Context::oxidegl_end_query)
+/// is executed, the primitives-generated counter is assigned to the query
+/// object's result value. This value can be queried by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT).
+///
+/// If `target` is [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
+/// `id` must be an unused name, or the name of an existing primitive query
+/// object previously bound to the [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN)
+/// query binding. When [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
+/// is executed, the query object's primitives-written counter is reset to
+/// 0. Subsequent rendering will increment the counter once for every vertex
+/// that is written into the bound transform feedback buffer(s). If transform
+/// feedback mode is not activated between the call to [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
+/// and [**glEndQuery**](crate::context::Context::oxidegl_end_query), the counter
+/// will not be incremented. When [**glEndQuery**](crate::context::Context::oxidegl_end_query)
+/// is executed, the primitives-written counter is assigned to the query object's
+/// result value. This value can be queried by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT).
+///
+/// If `target` is [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED), `id`
+/// must be an unused name, or the name of an existing timer query object previously
+/// bound to the [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED) query
+/// binding. When [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
+/// is executed, the query object's time counter is reset to 0. When [**glEndQuery**](crate::context::Context::oxidegl_end_query)
+/// is executed, the elapsed server time that has passed since the call to
+/// [**glBeginQuery**](crate::context::Context::oxidegl_begin_query) is written
+/// into the query object's time counter. This value can be queried by calling
+/// [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT).
+///
+/// Querying the [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT) implicitly
+/// flushes the GL pipeline until the rendering delimited by the query object
+/// has completed and the result is available. [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE)
+/// can be queried to determine if the result is immediately available or if
+/// the rendering is not yet complete.
+///
+/// ### Notes
+/// If the query target's count exceeds the maximum value representable in
+/// the number of available bits, as reported by [**glGetQueryiv**](crate::context::Context::oxidegl_get_queryiv)
+/// with `target` set to the appropriate query target and `pname` [`GL_QUERY_COUNTER_BITS`](crate::gl_enums::GL_QUERY_COUNTER_BITS),
+/// the count becomes undefined.
+///
+/// An implementation may support 0 bits in its counter, in which case query
+/// results are always undefined and essentially useless.
+///
+/// When [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS) is 0, the
+/// samples-passed counter of an occlusion query will increment once for each
+/// fragment that passes the depth test. When [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS)
+/// is 1, an implementation may either increment the samples-passed counter
+/// individually for each sample of a fragment that passes the depth test,
+/// or it may choose to increment the counter for all samples of a fragment
+/// if any one of them passes the depth test.
+///
+/// The query targets [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED),
+/// and [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED) are availale
+/// only if the GL version is 3.3 or higher.
+///
+/// The query target [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE)
+/// is available only of the GL version is 4.3 or higher.
+impl Context {
+    pub fn oxidegl_begin_query(&mut self, target: QueryTarget, id: GLuint) -> GlFallible {
+        self.begin_query_internal(target, 0, id)
+    }
+    pub fn oxidegl_end_query(&mut self, target: QueryTarget) -> GlFallible {
+        self.end_query_internal(target, 0)
+    }
+}
+/// ### Parameters
+/// `target`
+///
+/// > Specifies the target type of query object established between [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
+/// > and the subsequent [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed).
+/// > The symbolic constant must be one of [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
+/// > [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED),
+/// > [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
+/// > or [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED).
+///
+/// `index`
+///
+/// > Specifies the index of the query target upon which to begin the query.
+///
+/// `id`
+///
+/// > Specifies the name of a query object.
+///
+/// ### Description
+/// [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
+/// and [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
+/// delimit the boundaries of a query object. `query` must be a name previously
+/// returned from a call to [**glGenQueries**](crate::context::Context::oxidegl_gen_queries).
+/// If a query object with name `id` does not yet exist it is created with
+/// the type determined by `target`. `target` must be one of [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
+/// [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED),
+/// [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
+/// or [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED). The behavior
+/// of the query object depends on its type and is as follows.
+///
+/// `index` specifies the index of the query target and must be between a `target`
+/// -specific maximum.
+///
+/// If `target` is [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
+/// `id` must be an unused name, or the name of an existing occlusion query
+/// object. When [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
+/// is executed, the query object's samples-passed counter is reset to 0. Subsequent
+/// rendering will increment the counter for every sample that passes the depth
+/// test. If the value of [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS)
+/// is 0, then the samples-passed count is incremented by 1 for each fragment.
+/// If the value of [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS)
+/// is 1, then the samples-passed count is incremented by the number of samples
+/// whose coverage bit is set. However, implementations, at their discression
+/// may instead increase the samples-passed count by the value of [`GL_SAMPLES`](crate::gl_enums::GL_SAMPLES)
+/// if any sample in the fragment is covered. When [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
+/// is executed, the samples-passed counter is assigned to the query object's
+/// result value. This value can be queried by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT). When
+/// `target` is [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED), `index`
+/// must be zero.
+///
+/// If `target` is [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED),
+/// `id` must be an unused name, or the name of an existing boolean occlusion
+/// query object. When [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
+/// is executed, the query object's samples-passed flag is reset to [`GL_FALSE`](crate::gl_enums::GL_FALSE).
+/// Subsequent rendering causes the flag to be set to [`GL_TRUE`](crate::gl_enums::GL_TRUE)
+/// if any sample passes the depth test. When [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
+/// is executed, the samples-passed flag is assigned to the query object's
+/// result value. This value can be queried by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT). When
+/// `target` is [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED),
+/// `index` must be zero.
+///
+/// If `target` is [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED),
+/// `id` must be an unused name, or the name of an existing primitive query
+/// object previously bound to the [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED)
+/// query binding. When [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
+/// is executed, the query object's primitives-generated counter is reset to
+/// 0. Subsequent rendering will increment the counter once for every vertex
+/// that is emitted from the geometry shader to the stream given by `index`,
+/// or from the vertex shader if `index` is zero and no geometry shader is
+/// present. When [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
+/// is executed, the primitives-generated counter for stream `index` is assigned
+/// to the query object's result value. This value can be queried by calling
+/// [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT). When
+/// `target` is [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED),
+/// `index` must be less than the value of [`GL_MAX_VERTEX_STREAMS`](crate::gl_enums::GL_MAX_VERTEX_STREAMS).
+///
+/// If `target` is [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
+/// `id` must be an unused name, or the name of an existing primitive query
+/// object previously bound to the [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN)
+/// query binding. When [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
+/// is executed, the query object's primitives-written counter for the stream
+/// specified by `index` is reset to 0. Subsequent rendering will increment
+/// the counter once for every vertex that is written into the bound transform
+/// feedback buffer(s) for stream `index`. If transform feedback mode is not
+/// activated between the call to [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
+/// and [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed),
+/// the counter will not be incremented. When [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
+/// is executed, the primitives-written counter for stream `index` is assigned
+/// to the query object's result value. This value can be queried by calling
+/// [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT). When
+/// `target` is [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
+/// `index` must be less than the value of [`GL_MAX_VERTEX_STREAMS`](crate::gl_enums::GL_MAX_VERTEX_STREAMS).
+///
+/// If `target` is [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED), `id`
+/// must be an unused name, or the name of an existing timer query object previously
+/// bound to the [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED) query
+/// binding. When [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
+/// is executed, the query object's time counter is reset to 0. When [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
+/// is executed, the elapsed server time that has passed since the call to
+/// [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
+/// is written into the query object's time counter. This value can be queried
+/// by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT). When
+/// `target` is [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED), `index`
+/// must be zero.
+///
+/// Querying the [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT) implicitly
+/// flushes the GL pipeline until the rendering delimited by the query object
+/// has completed and the result is available. [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE)
+/// can be queried to determine if the result is immediately available or if
+/// the rendering is not yet complete.
+///
+/// ### Notes
+/// If the query target's count exceeds the maximum value representable in
+/// the number of available bits, as reported by [**glGetQueryiv**](crate::context::Context::oxidegl_get_queryiv)
+/// with `target` set to the appropriate query target and `pname` [`GL_QUERY_COUNTER_BITS`](crate::gl_enums::GL_QUERY_COUNTER_BITS),
+/// the count becomes undefined.
+///
+/// An implementation may support 0 bits in its counter, in which case query
+/// results are always undefined and essentially useless.
+///
+/// When [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS) is 0, the
+/// samples-passed counter of an occlusion query will increment once for each
+/// fragment that passes the depth test. When [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS)
+/// is 1, an implementation may either increment the samples-passed counter
+/// individually for each sample of a fragment that passes the depth test,
+/// or it may choose to increment the counter for all samples of a fragment
+/// if any one of them passes the depth test.
+///
+/// Calling [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
+/// or [**glEndQuery**](crate::context::Context::oxidegl_end_query) is equivalent
+/// to calling [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
+/// or [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
+/// with `index` set to zero, respectively.
+impl Context {
+    pub fn oxidegl_begin_query_indexed(
+        &mut self,
+        target: QueryTarget,
+        index: GLuint,
+        id: GLuint,
+    ) -> GlFallible {
+        self.begin_query_internal(target, index, id)
+    }
+    pub fn oxidegl_end_query_indexed(&mut self, target: QueryTarget, index: GLuint) -> GlFallible {
+        self.end_query_internal(target, index)
+    }
+}
+/// ### Parameters
+/// `id`
+///
+/// > Specifies the name of a query object.
+///
+/// `buffer`
+///
+/// > Specifies the name of a buffer object.
+///
+/// `pname`
+///
+/// > Specifies the symbolic name of a query object parameter. Accepted values
+/// > are [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT), [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE),
+/// > [`GL_QUERY_RESULT_NO_WAIT`](crate::gl_enums::GL_QUERY_RESULT_NO_WAIT), or
+/// > [`GL_QUERY_TARGET`](crate::gl_enums::GL_QUERY_TARGET).
+///
+/// `params`
+///
+/// > If a buffer is bound to the [`GL_QUERY_RESULT_BUFFER`](crate::gl_enums::GL_QUERY_RESULT_BUFFER)
+/// > target, then `params` is treated as an offset to a location within that
+/// > buffer's data store to receive the result of the query. If no buffer is
+/// > bound to [`GL_QUERY_RESULT_BUFFER`](crate::gl_enums::GL_QUERY_RESULT_BUFFER),
+/// > then `params` is treated as an address in client memory of a variable
+/// > to receive the resulting data.
+///
+/// `offset`
+///
+/// > Specifies the byte offset into `buffer` 's data store where the queried
+/// > result will be written.
+///
+/// ### Description
+/// These commands return a selected parameter of the query object specified
+/// by `id`. [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// returns in `params` a selected parameter of the query object specified
+/// by `id`. [**glGetQueryBufferObject**](crate::context::Context::oxidegl_get_query_buffer_object)
+/// returns in `buffer` a selected parameter of the query object specified
+/// by `id`, by writing it to `buffer` 's data store at the byte offset specified
+/// by `offset`.
+///
+/// `pname` names a specific query object parameter. `pname` can be as follows:
+///
+/// [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT)
+///
+/// > `params` or `buffer` returns the value of the query object's passed samples
+/// > counter. The initial value is 0.
+///
+/// [`GL_QUERY_RESULT_NO_WAIT`](crate::gl_enums::GL_QUERY_RESULT_NO_WAIT)
+///
+/// > If the result of the query is available (that is, a query of [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE)
+/// > would return non-zero), then `params` or `buffer` returns the value of
+/// > the query object's passed samples counter, otherwise, the data referred
+/// > to by `params` or `buffer` is not modified. The initial value is 0.
+///
+/// [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE)
+///
+/// > `params` or `buffer` returns whether the passed samples counter is immediately
+/// > available. If a delay would occur waiting for the query result, [`GL_FALSE`](crate::gl_enums::GL_FALSE)
+/// > is returned. Otherwise, [`GL_TRUE`](crate::gl_enums::GL_TRUE) is returned,
+/// > which also indicates that the results of all previous queries are available
+/// > as well.
+///
+/// [`GL_QUERY_TARGET`](crate::gl_enums::GL_QUERY_TARGET)
+///
+/// > `params` or `buffer` returns the query object's target.
+///
+/// ### Notes
+/// If an error is generated, no change is made to the contents of `params`
+/// or `buffer`.
+///
+/// [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// and [**glGetQueryBufferObject**](crate::context::Context::oxidegl_get_query_buffer_object)
+/// implicitly flush the GL pipeline so that any incomplete rendering delimited
+/// by the occlusion query completes in finite time.
+///
+/// If multiple queries are issued using the same query object `id` before
+/// calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// or [**glGetQueryBufferObject**](crate::context::Context::oxidegl_get_query_buffer_object),
+/// the results of the most recent query will be returned. In this case, when
+/// issuing a new query, the results of the previous query are discarded.
+///
+/// [**glGetQueryObjecti64v**](crate::context::Context::oxidegl_get_query_objecti64v)
+/// and [**glGetQueryObjectui64v**](crate::context::Context::oxidegl_get_query_objectui64v)
+/// are available only if the GL version is 3.3 or greater.
+///
+/// [`GL_QUERY_RESULT_NO_WAIT`](crate::gl_enums::GL_QUERY_RESULT_NO_WAIT) is
+/// accepted for `pname` only if the GL version is 4.4 or greater.
+///
+/// The [`GL_QUERY_RESULT_BUFFER`](crate::gl_enums::GL_QUERY_RESULT_BUFFER)
+/// target is available only if the GL version is 4.4 or higher. On earlier
+/// versions of the GL, `params` is always an address in client memory.
+impl Context {
+    pub unsafe fn oxidegl_get_query_objectiv(
+        &mut self,
+        id: GLuint,
+        pname: QueryObjectParameterName,
+        params: *mut GLint,
+    ) -> GlFallible {
+        // Safety: Caller ensures params is valid for a write
+        unsafe { self.get_query_object_internal(id, pname, params) }
+    }
+    pub unsafe fn oxidegl_get_query_objectuiv(
+        &mut self,
+        id: GLuint,
+        pname: QueryObjectParameterName,
+        params: *mut GLuint,
+    ) -> GlFallible {
+        // Safety: Caller ensures params is valid for a write
+        unsafe { self.get_query_object_internal(id, pname, params) }
+    }
+    pub unsafe fn oxidegl_get_query_objecti64v(
+        &mut self,
+        id: GLuint,
+        pname: QueryObjectParameterName,
+        params: *mut GLint64,
+    ) -> GlFallible {
+        // Safety: Caller ensures params is valid for a write
+        unsafe { self.get_query_object_internal(id, pname, params) }
+    }
+    pub unsafe fn oxidegl_get_query_objectui64v(
+        &mut self,
+        id: GLuint,
+        pname: QueryObjectParameterName,
+        params: *mut GLuint64,
+    ) -> GlFallible {
+        // Safety: Caller ensures params is valid for a write
+        unsafe { self.get_query_object_internal(id, pname, params) }
+    }
+}
+
+#[test]
+fn headless_query_targets() {
+    let mut ctx = Context::new_headless(1, 1);
+    let mut id = 0;
+    // Safety: ids points to a single GLuint
+    unsafe { ctx.oxidegl_gen_queries(1, &raw mut id) }.unwrap();
+
+    // occlusion and timer queries are rejected rather than taking down the process
+    for target in [
+        QueryTarget::SamplesPassed,
+        QueryTarget::AnySamplesPassed,
+        QueryTarget::TimeElapsed,
+    ] {
+        let err = ctx.oxidegl_begin_query(target, id).unwrap_err();
+        assert_eq!(err.get(), crate::gl_enums::ErrorCode::InvalidEnum);
+    }
+    ctx.oxidegl_begin_query(QueryTarget::PrimitivesGenerated, id)
+        .unwrap();
+    ctx.oxidegl_end_query(QueryTarget::PrimitivesGenerated)
+        .unwrap();
+}
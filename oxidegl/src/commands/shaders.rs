@@ -1,14 +1,15 @@
 use crate::{
     context::Context,
-    conversions::sizei,
+    conversions::{gl_string_len, sizei, write_gl_string},
     debug::{gl_debug, gl_trace},
     error::{GlError, GlFallible},
-    gl_enums::{ShaderParameterName, ShaderType},
+    gl_enums::{GL_SHADER_BINARY_FORMAT_SPIR_V, ShaderParameterName, ShaderType},
     gl_object::{NamedObjectList, ObjectName},
-    gl_types::{GLchar, GLint, GLsizei, GLuint},
+    gl_types::{GLchar, GLenum, GLint, GLsizei, GLuint, GLvoid},
     program::Program,
-    shader::{GlslShaderInternal, Shader, ShaderInternal},
+    shader::{GlslShaderInternal, SPIRV_MAGIC, Shader, ShaderInternal, SpecializationError},
 };
+use std::ffi::CStr;
 
 impl Context {
     /// ### Parameters
@@ -140,10 +141,13 @@ impl Context {
             .gl_state
             .shader_list
             .get_shader_raw_mut(&self.gl_state.program_list, shader)?;
+        if shader.internal.is_spirv() {
+            // setting the source of a SPIR-V shader turns it back into a GLSL shader
+            shader.internal = ShaderInternal::Glsl(GlslShaderInternal::default());
+        }
         let ShaderInternal::Glsl(GlslShaderInternal { ref mut source, .. }) = shader.internal
         else {
-            // TODO this branch should reset shader.internal to a GLSLShader and write sources to that
-            panic!("tried to write shader source to a SPIR-V shader object")
+            unreachable!()
         };
         source.clear();
         for (i, &string) in sources.iter().enumerate() {
@@ -195,10 +199,32 @@ impl Context {
     ///
     /// [**glIsShader**](crate::context::Context::oxidegl_is_shader)
     pub fn oxidegl_compile_shader(&mut self, shader: GLuint) -> GlFallible {
-        self.gl_state.shader_list.get_raw_mut(shader)?.compile();
+        let pool = &mut self.gl_state.compile_pool;
+        self.gl_state.shader_list.get_raw_mut(shader)?.compile(pool);
         Ok(())
     }
     /// ### Parameters
+    /// `count`
+    ///
+    /// > Specifies the maximum number of background threads used to compile shaders
+    /// > and link programs.
+    ///
+    /// ### Description
+    /// [**glMaxShaderCompilerThreadsKHR**](crate::context::Context::oxidegl_max_shader_compiler_threads)
+    /// sets the maximum number of background threads that shader compilation and
+    /// program linking may be offloaded to. A `count` of zero disables background
+    /// compilation, and a `count` of `0xFFFFFFFF` requests an implementation-specific
+    /// maximum, which is the initial value.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_SHADER_COMPILER_THREADS_KHR`](crate::gl_enums::GL_MAX_SHADER_COMPILER_THREADS_KHR)
+    ///
+    /// [**glGetShader**](crate::context::Context::oxidegl_get_shader) and [**glGetProgram**](crate::context::Context::oxidegl_get_program)
+    /// with argument [`GL_COMPLETION_STATUS_KHR`](crate::gl_enums::GL_COMPLETION_STATUS_KHR)
+    pub fn oxidegl_max_shader_compiler_threads(&mut self, count: GLuint) {
+        self.gl_state.compile_pool.set_max_threads(count);
+    }
+    /// ### Parameters
     /// `shader`
     ///
     /// > Specifies the shader object to be queried.
@@ -280,8 +306,10 @@ impl Context {
             ShaderParameterName::ShaderType => shader.stage.into(),
             ShaderParameterName::DeleteStatus => u32::from(delete_status),
             ShaderParameterName::CompileStatus => u32::from(shader.internal.compile_status()),
-            ShaderParameterName::InfoLogLength => shader.compiler_log.len() as u32,
+            ShaderParameterName::InfoLogLength => gl_string_len(&shader.info_log()) as u32,
             ShaderParameterName::ShaderSourceLength => shader.internal.source_len(),
+            ShaderParameterName::SpirVBinary => u32::from(shader.internal.is_spirv()),
+            ShaderParameterName::CompletionStatus => u32::from(shader.internal.completion_status()),
         };
         // Safety: caller ensures params pointer is correct
         unsafe { *params.cast() = ret };
@@ -290,6 +318,320 @@ impl Context {
     /// ### Parameters
     /// `shader`
     ///
+    /// > Specifies the shader object whose information log is to be queried.
+    ///
+    /// `maxLength`
+    ///
+    /// > Specifies the size of the character buffer for storing the returned information
+    /// > log.
+    ///
+    /// `length`
+    ///
+    /// > Returns the length of the string returned in `infoLog` (excluding the null
+    /// > terminator).
+    ///
+    /// `infoLog`
+    ///
+    /// > Specifies an array of characters that is used to return the information
+    /// > log.
+    ///
+    /// ### Description
+    /// [**glGetShaderInfoLog**](crate::context::Context::oxidegl_get_shader_info_log)
+    /// returns the information log for the specified shader object. The information
+    /// log for a shader object is modified when the shader is compiled. The string
+    /// that is returned will be null terminated.
+    ///
+    /// [**glGetShaderInfoLog**](crate::context::Context::oxidegl_get_shader_info_log)
+    /// returns in `infoLog` as much of the information log as it can, up to a
+    /// maximum of `maxLength` characters. The number of characters actually returned,
+    /// excluding the null termination character, is specified by `length`. If
+    /// the length of the returned string is not required, a value of [`NULL`](crate::gl_enums::NULL)
+    /// can be passed in the `length` argument. The size of the buffer required
+    /// to store the returned information log can be obtained by calling [**glGetShader**](crate::context::Context::oxidegl_get_shader)
+    /// with the value [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH).
+    ///
+    /// The information log for a shader object is a string that may contain diagnostic
+    /// messages, warning messages, and other information about the last compile
+    /// operation. When a shader object is created, its information log will be
+    /// a string of length 0.
+    ///
+    /// ### Notes
+    /// The information log for a shader object is the OpenGL implementer's primary
+    /// mechanism for conveying information about the compilation process. Therefore,
+    /// the information log can be helpful to application developers during the
+    /// development process, even when compilation is successful. Application developers
+    /// should not expect different OpenGL implementations to produce identical
+    /// information logs.
+    ///
+    /// ### Associated Gets
+    /// [**glGetShader**](crate::context::Context::oxidegl_get_shader) with argument
+    /// [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH)
+    ///
+    /// [**glIsShader**](crate::context::Context::oxidegl_is_shader)
+    pub unsafe fn oxidegl_get_shader_info_log(
+        &mut self,
+        shader: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        info_log: *mut GLchar,
+    ) -> GlFallible {
+        sizei!(buf_size);
+        let shader = self
+            .gl_state
+            .shader_list
+            .get_shader_raw_mut(&self.gl_state.program_list, shader)?;
+        // Safety: caller ensures info_log is valid for writes of buf_size bytes and length is valid for a write
+        unsafe { write_gl_string(&shader.info_log(), buf_size, length, info_log) };
+        Ok(())
+    }
+    /// ### Parameters
+    /// `shader`
+    ///
+    /// > Specifies the shader object to be queried.
+    ///
+    /// `bufSize`
+    ///
+    /// > Specifies the size of the character buffer for storing the returned source
+    /// > code string.
+    ///
+    /// `length`
+    ///
+    /// > Returns the length of the string returned in `source` (excluding the null
+    /// > terminator).
+    ///
+    /// `source`
+    ///
+    /// > Specifies an array of characters that is used to return the source code
+    /// > string.
+    ///
+    /// ### Description
+    /// [**glGetShaderSource**](crate::context::Context::oxidegl_get_shader_source)
+    /// returns the concatenation of the source code strings from the shader object
+    /// specified by `shader`. The source code strings for a shader object are
+    /// the result of a previous call to [**glShaderSource**](crate::context::Context::oxidegl_shader_source).
+    /// The string returned by the function will be null terminated.
+    ///
+    /// [**glGetShaderSource**](crate::context::Context::oxidegl_get_shader_source)
+    /// returns in `source` as much of the source code string as it can, up to
+    /// a maximum of `bufSize` characters. The number of characters actually returned,
+    /// excluding the null termination character, is specified by `length`. If
+    /// the length of the returned string is not required, a value of [`NULL`](crate::gl_enums::NULL)
+    /// can be passed in the `length` argument. The size of the buffer required
+    /// to store the returned source code string can be obtained by calling [**glGetShader**](crate::context::Context::oxidegl_get_shader)
+    /// with the value [`GL_SHADER_SOURCE_LENGTH`](crate::gl_enums::GL_SHADER_SOURCE_LENGTH).
+    ///
+    /// ### Associated Gets
+    /// [**glGetShader**](crate::context::Context::oxidegl_get_shader) with argument
+    /// [`GL_SHADER_SOURCE_LENGTH`](crate::gl_enums::GL_SHADER_SOURCE_LENGTH)
+    ///
+    /// [**glIsShader**](crate::context::Context::oxidegl_is_shader)
+    pub unsafe fn oxidegl_get_shader_source(
+        &mut self,
+        shader: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        source: *mut GLchar,
+    ) -> GlFallible {
+        sizei!(buf_size);
+        let shader = self
+            .gl_state
+            .shader_list
+            .get_shader_raw_mut(&self.gl_state.program_list, shader)?;
+        // Safety: caller ensures source is valid for writes of buf_size bytes and length is valid for a write
+        unsafe { write_gl_string(shader.internal.source_str(), buf_size, length, source) };
+        Ok(())
+    }
+    /// ### Parameters
+    /// `count`
+    ///
+    /// > Specifies the number of shader object handles contained in `shaders`.
+    ///
+    /// `shaders`
+    ///
+    /// > Specifies the address of an array of shader handles into which to load
+    /// > pre-compiled shader binaries.
+    ///
+    /// `binaryFormat`
+    ///
+    /// > Specifies the format of the shader binaries contained in `binary`.
+    ///
+    /// `binary`
+    ///
+    /// > Specifies the address of an array of bytes containing pre-compiled binary
+    /// > shader code.
+    ///
+    /// `length`
+    ///
+    /// > Specifies the length of the array whose address is given in `binary`.
+    ///
+    /// ### Description
+    /// [**glShaderBinary**](crate::context::Context::oxidegl_shader_binary) loads
+    /// pre-compiled shader binary code into the `count` shader objects whose handles
+    /// are given in `shaders`. `binary` points to `length` bytes of binary shader
+    /// code stored in client memory. `binaryFormat` specifies the format of the
+    /// pre-compiled code.
+    ///
+    /// The binary image contained in `binary` will be decoded according to the
+    /// extension specification defining the specified `binaryFormat` token. OpenGL
+    /// does not define any specific binary formats, but it does provide a mechanism
+    /// to obtain token vaues for such formats provided by such extensions.
+    ///
+    /// Depending on the types of the shader objects in `shaders`, [**glShaderBinary**](crate::context::Context::oxidegl_shader_binary)
+    /// will individually load binary vertex or fragment shaders, or load an executable
+    /// binary that contains an optimized pair of vertex and fragment shaders stored
+    /// in the same binary.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with parameter [`GL_NUM_SHADER_BINARY_FORMATS`](crate::gl_enums::GL_NUM_SHADER_BINARY_FORMATS).
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with parameter [`GL_SHADER_BINARY_FORMATS`](crate::gl_enums::GL_SHADER_BINARY_FORMATS).
+    pub unsafe fn oxidegl_shader_binary(
+        &mut self,
+        count: GLsizei,
+        shaders: *const GLuint,
+        binary_format: GLenum,
+        binary: *const GLvoid,
+        length: GLsizei,
+    ) -> GlFallible {
+        sizei!(count);
+        sizei!(length);
+        if binary_format != GL_SHADER_BINARY_FORMAT_SPIR_V {
+            return Err(GlError::InvalidEnum.e());
+        }
+        if length % 4 != 0 || binary.is_null() || shaders.is_null() {
+            return Err(GlError::InvalidValue.e());
+        }
+        let mut words: Vec<u32> = (0..length as usize / 4)
+            .map(|i| {
+                // Safety: caller ensures binary points to length bytes of client memory. read_unaligned is used
+                // because the bytes are not required to be aligned to a word boundary
+                unsafe { binary.cast::<u32>().add(i).read_unaligned() }
+            })
+            .collect();
+        // SPIR-V modules may be stored in either byte order, identified by their magic number
+        if words.first() == Some(&SPIRV_MAGIC.swap_bytes()) {
+            words.iter_mut().for_each(|w| *w = w.swap_bytes());
+        }
+        if words.len() < 5 || words[0] != SPIRV_MAGIC {
+            return Err(GlError::InvalidValue.e());
+        }
+        // Safety: caller ensures shaders points to an array of count shader names
+        let shaders = unsafe { core::slice::from_raw_parts(shaders, count as usize) };
+        for &shader in shaders {
+            self.gl_state
+                .shader_list
+                .get_shader_raw_mut(&self.gl_state.program_list, shader)?
+                .load_spirv(words.clone());
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `shader`
+    ///
+    /// > Specifies the name of a shader object containing unspecialized SPIR-V
+    /// > as its shader binary.
+    ///
+    /// `pEntryPoint`
+    ///
+    /// > Specifies a pointer to a null-terminated UTF-8 string specifying the name
+    /// > of the entry point in the SPIR-V module for this shader.
+    ///
+    /// `numSpecializationConstants`
+    ///
+    /// > Specifies the number of specialization constants whose values to set in
+    /// > this call.
+    ///
+    /// `pConstantIndex`
+    ///
+    /// > Specifies a pointer to an array of `numSpecializationConstants` unsigned
+    /// > integers, each holding the index of a specialization constant in the SPIR-V
+    /// > module whose value to set.
+    ///
+    /// `pConstantValue`
+    ///
+    /// > Specifies a pointer to an entry in an array of `numSpecializationConstants`
+    /// > unsigned integers, each holding the value to set for the specialization
+    /// > constant indexed by the corresponding entry in `pConstantIndex`.
+    ///
+    /// ### Description
+    /// [**glSpecializeShader**](crate::context::Context::oxidegl_specialize_shader)
+    /// specializes a shader created from a SPIR-V module. Shaders associated with
+    /// SPIR-V modules must be specialized before they can be linked into a program
+    /// object. It is not necessary to specialize the shader before it is attached
+    /// to a program object. Once specialized, a shader may not be specialized again
+    /// without first re-associating the original SPIR-V module with it, through
+    /// [**glShaderBinary**](crate::context::Context::oxidegl_shader_binary).
+    ///
+    /// Specialization does two things:
+    ///
+    /// Selects the name of the entry point, for that shader’s stage, from the SPIR-V
+    /// module.
+    ///
+    /// Sets the values of all, or a subset of, the specialization constants in
+    /// the SPIR-V module.
+    ///
+    /// On successful shader specialization, the compile status for shader is set
+    /// to [`GL_TRUE`](crate::gl_enums::GL_TRUE). On failure, the compile status
+    /// for shader is set to [`GL_FALSE`](crate::gl_enums::GL_FALSE) and additional
+    /// information about the cause of the failure may be available in the shader
+    /// compilation log.
+    pub unsafe fn oxidegl_specialize_shader(
+        &mut self,
+        shader: GLuint,
+        p_entry_point: *const GLchar,
+        num_specialization_constants: GLuint,
+        p_constant_index: *const GLuint,
+        p_constant_value: *const GLuint,
+    ) -> GlFallible {
+        let shader = self
+            .gl_state
+            .shader_list
+            .get_shader_raw_mut(&self.gl_state.program_list, shader)?;
+        let ShaderInternal::Spirv(internal) = &shader.internal else {
+            return Err(GlError::InvalidOperation.e());
+        };
+        if internal.specialized.is_some() || p_entry_point.is_null() {
+            return Err(GlError::InvalidOperation.e());
+        }
+        // Safety: caller ensures p_entry_point points to a valid, null-terminated string
+        let entry_point = unsafe { CStr::from_ptr(p_entry_point) }
+            .to_str()
+            .map_err(|_| GlError::InvalidValue.e())?;
+        let constants = if num_specialization_constants == 0
+            || p_constant_index.is_null()
+            || p_constant_value.is_null()
+        {
+            Vec::new()
+        } else {
+            // Safety: caller ensures the constant arrays hold num_specialization_constants elements
+            let (indices, values) = unsafe {
+                (
+                    core::slice::from_raw_parts(
+                        p_constant_index,
+                        num_specialization_constants as usize,
+                    ),
+                    core::slice::from_raw_parts(
+                        p_constant_value,
+                        num_specialization_constants as usize,
+                    ),
+                )
+            };
+            indices
+                .iter()
+                .copied()
+                .zip(values.iter().copied())
+                .collect()
+        };
+        match shader.specialize(entry_point, &constants) {
+            Ok(()) | Err(SpecializationError::Malformed(_)) => Ok(()),
+            Err(SpecializationError::NoSuchEntryPoint | SpecializationError::NoSuchConstant(_)) => {
+                Err(GlError::InvalidValue.e())
+            }
+        }
+    }
+    /// ### Parameters
+    /// `shader`
+    ///
     /// > Specifies the shader object to be deleted.
     ///
     /// ### Description
@@ -351,3 +693,153 @@ impl ShaderListExt for NamedObjectList<Shader> {
         }
     }
 }
+
+#[test]
+fn software_shader_logs_and_source() {
+    let mut ctx = Context::new_software(8, 8);
+    let src = "#version 430 core\nvoid main() { undeclared = 1.0; }";
+    let shader = ctx.oxidegl_create_shader(ShaderType::FragmentShader);
+    let ptr = src.as_ptr().cast();
+    let len = i32::try_from(src.len()).unwrap();
+    // Safety: pointers are valid for the duration of the call
+    unsafe { ctx.oxidegl_shader_source(shader, 1, &raw const ptr, &raw const len) }.unwrap();
+    let get = |ctx: &mut Context, pname| {
+        let mut value = 0;
+        // Safety: params points to a single GLint
+        unsafe { ctx.oxidegl_get_shaderiv(shader, pname, &raw mut value) }.unwrap();
+        value
+    };
+    assert_eq!(get(&mut ctx, ShaderParameterName::InfoLogLength), 0);
+    assert_eq!(
+        get(&mut ctx, ShaderParameterName::ShaderSourceLength),
+        len + 1
+    );
+
+    // the source is kept across compilation
+    ctx.oxidegl_compile_shader(shader).unwrap();
+    assert_eq!(get(&mut ctx, ShaderParameterName::CompileStatus), 0);
+    let mut buf = vec![0; 128];
+    let mut written = 0;
+    // Safety: buf is valid for writes of its length
+    unsafe {
+        ctx.oxidegl_get_shader_source(shader, 128, &raw mut written, buf.as_mut_ptr().cast())
+    }
+    .unwrap();
+    assert_eq!(written, len);
+    assert_eq!(&buf[..src.len()], src.as_bytes());
+    assert_eq!(buf[src.len()], 0);
+
+    // the info log is truncated to fit the buffer along with its nul terminator
+    let log_len = get(&mut ctx, ShaderParameterName::InfoLogLength);
+    assert!(log_len > 1);
+    let mut buf = vec![0xffu8; 4];
+    // Safety: buf is valid for writes of its length
+    unsafe {
+        ctx.oxidegl_get_shader_info_log(shader, 4, &raw mut written, buf.as_mut_ptr().cast())
+    }
+    .unwrap();
+    assert_eq!(written, 3);
+    assert_eq!(buf[3], 0);
+}
+
+#[test]
+fn headless_spirv_shader_specialization() {
+    use crate::{gl_enums::ProgramProperty, shader::parse_glsl, test_util::link};
+
+    let mut ctx = Context::new_headless(8, 8);
+    let src = "#version 450 core
+        layout(local_size_x_id = 3, local_size_y = 2) in;
+        layout(std430, binding = 0) buffer Data { uint values[]; };
+        void main() { values[gl_LocalInvocationIndex] = 1u; }";
+    let compiler = glslang::Compiler::acquire().unwrap();
+    let parsed = parse_glsl(compiler, ShaderType::ComputeShader, src).unwrap();
+    let mut program = compiler.create_program();
+    program.add_shader(&parsed);
+    let words = program
+        .compile(ShaderType::ComputeShader.to_glslang_stage())
+        .unwrap();
+    let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+    let len = GLsizei::try_from(bytes.len()).unwrap();
+
+    let shader = ctx.oxidegl_create_shader(ShaderType::ComputeShader);
+    let status = |ctx: &mut Context| {
+        let mut value = 0;
+        // Safety: params points to a single GLint
+        unsafe {
+            ctx.oxidegl_get_shaderiv(shader, ShaderParameterName::CompileStatus, &raw mut value)
+        }
+        .unwrap();
+        value
+    };
+    let load = |ctx: &mut Context, format, bytes: &[u8], len| {
+        // Safety: shaders points to a single name and binary to len bytes
+        unsafe {
+            ctx.oxidegl_shader_binary(1, &raw const shader, format, bytes.as_ptr().cast(), len)
+        }
+    };
+    let specialize = |ctx: &mut Context, entry: &CStr, constants: &[(GLuint, GLuint)]| {
+        let (indices, values): (Vec<_>, Vec<_>) = constants.iter().copied().unzip();
+        // Safety: the constant arrays hold as many elements as passed
+        unsafe {
+            ctx.oxidegl_specialize_shader(
+                shader,
+                entry.as_ptr(),
+                GLuint::try_from(constants.len()).unwrap(),
+                indices.as_ptr(),
+                values.as_ptr(),
+            )
+        }
+    };
+
+    // GLSL shaders can't be specialized, and only SPIR-V binaries of whole words are accepted
+    assert!(specialize(&mut ctx, c"main", &[]).is_err());
+    assert!(load(&mut ctx, 0, &bytes, len).is_err());
+    assert!(load(&mut ctx, GL_SHADER_BINARY_FORMAT_SPIR_V, &bytes, len - 1).is_err());
+    assert!(load(&mut ctx, GL_SHADER_BINARY_FORMAT_SPIR_V, &[0; 20], 20).is_err());
+
+    // modules are accepted in either byte order
+    let swapped: Vec<u8> = words.iter().flat_map(|w| w.to_be_bytes()).collect();
+    load(&mut ctx, GL_SHADER_BINARY_FORMAT_SPIR_V, &swapped, len).unwrap();
+    assert_eq!(status(&mut ctx), 0);
+    assert!(specialize(&mut ctx, c"not_main", &[]).is_err());
+    assert!(specialize(&mut ctx, c"main", &[(7, 1)]).is_err());
+    assert_eq!(status(&mut ctx), 0);
+    specialize(&mut ctx, c"main", &[(3, 4)]).unwrap();
+    assert_eq!(status(&mut ctx), 1);
+    // a shader may only be specialized again after reloading its binary
+    assert!(specialize(&mut ctx, c"main", &[(3, 8)]).is_err());
+
+    let program = link(&mut ctx, &[shader]);
+    let mut size = [0; 3];
+    // Safety: params points to 3 GLints
+    unsafe {
+        ctx.oxidegl_get_programiv(
+            program,
+            ProgramProperty::ComputeWorkGroupSize,
+            size.as_mut_ptr(),
+        )
+    }
+    .unwrap();
+    assert_eq!(size, [4, 2, 1]);
+
+    // glCompileShader fails for SPIR-V shaders without allowing them to be specialized again
+    ctx.oxidegl_compile_shader(shader).unwrap();
+    assert_eq!(status(&mut ctx), 0);
+    assert!(specialize(&mut ctx, c"main", &[(3, 8)]).is_err());
+
+    // reloading the binary resets the specialization
+    load(&mut ctx, GL_SHADER_BINARY_FORMAT_SPIR_V, &bytes, len).unwrap();
+    assert_eq!(status(&mut ctx), 0);
+    specialize(&mut ctx, c"main", &[(3, 8)]).unwrap();
+    ctx.oxidegl_link_program(program).unwrap();
+    // Safety: params points to 3 GLints
+    unsafe {
+        ctx.oxidegl_get_programiv(
+            program,
+            ProgramProperty::ComputeWorkGroupSize,
+            size.as_mut_ptr(),
+        )
+    }
+    .unwrap();
+    assert_eq!(size, [8, 2, 1]);
+}
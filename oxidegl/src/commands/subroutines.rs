@@ -0,0 +1,697 @@
+use core::{ffi::CStr, ptr, slice};
+
+use crate::{
+    context::Context,
+    conversions::{sizei, write_gl_string},
+    debug::gl_err,
+    error::{GlError, GlFallible, gl_assert},
+    gl_enums::{GL_INVALID_INDEX, ProgramStagePName, ShaderType, SubroutineParameterName},
+    gl_types::{GLchar, GLint, GLsizei, GLuint},
+    program::subroutines::{NO_SUBROUTINES, StageSubroutines},
+    program_pipeline::ProgramPipeline,
+};
+
+use crate::commands::programs::ProgramListExt;
+
+impl Context {
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the name of the program containing shader stage.
+    ///
+    /// `shadertype`
+    ///
+    /// > Specifies the shader stage from which to query for subroutine uniform index.
+    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
+    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
+    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
+    ///
+    /// `name`
+    ///
+    /// > Specifies the name of the subroutine uniform whose index to query.
+    ///
+    /// ### Description
+    /// [**glGetSubroutineIndex**](crate::context::Context::oxidegl_get_subroutine_index)
+    /// returns the index of a subroutine uniform within a shader stage attached
+    /// to a program object. `program` contains the name of the program to which
+    /// the shader is attached. `shadertype` specifies the stage from which to
+    /// query shader subroutine index. `name` contains the null-terminated name
+    /// of the subroutine uniform whose name to query.
+    ///
+    /// If `name` is not the name of a subroutine uniform in the shader stage,
+    /// [`GL_INVALID_INDEX`](crate::gl_enums::GL_INVALID_INDEX) is returned, but
+    /// no error is generated. If `name` is the name of a subroutine uniform in
+    /// the shader stage, a value between zero and the value of [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES)
+    /// minus one will be returned. Subroutine indices are assigned using consecutive
+    /// integers in the range from zero to the value of [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES)
+    /// minus one for the shader stage.
+    pub unsafe fn oxidegl_get_subroutine_index(
+        &mut self,
+        program: GLuint,
+        shadertype: ShaderType,
+        name: *const GLchar,
+    ) -> GlFallible<GLuint> {
+        let subroutines = self.linked_subroutines(program, shadertype)?;
+        // Safety: caller ensures name is a valid, null-terminated string
+        let name = unsafe { CStr::from_ptr(name) }.to_str().unwrap_or_default();
+        Ok(subroutines.index(name).unwrap_or(GL_INVALID_INDEX))
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the name of the program containing shader stage.
+    ///
+    /// `shadertype`
+    ///
+    /// > Specifies the shader stage from which to query for subroutine uniform index.
+    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
+    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
+    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
+    ///
+    /// `name`
+    ///
+    /// > Specifies the name of the subroutine uniform whose index to query.
+    ///
+    /// ### Description
+    /// [**glGetSubroutineUniformLocation**](crate::context::Context::oxidegl_get_subroutine_uniform_location)
+    /// returns the location of the subroutine uniform variable `name` in the shader
+    /// stage of type `shadertype` attached to `program`, with behavior otherwise
+    /// identical to [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location).
+    ///
+    /// If `name` is not the name of a subroutine uniform in the shader stage,
+    /// -1 is returned, but no error is generated. If `name` is the name of a subroutine
+    /// uniform in the shader stage, a value between zero and the value of [`GL_ACTIVE_SUBROUTINE_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_LOCATIONS)
+    /// minus one will be returned. Subroutine locations are assigned using consecutive
+    /// integers in the range from zero to the value of [`GL_ACTIVE_SUBROUTINE_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_LOCATIONS)
+    /// minus one for the shader stage. For active subroutine uniforms declared
+    /// as arrays, the declared array elements are assigned consecutive locations.
+    pub unsafe fn oxidegl_get_subroutine_uniform_location(
+        &mut self,
+        program: GLuint,
+        shadertype: ShaderType,
+        name: *const GLchar,
+    ) -> GlFallible<GLint> {
+        let subroutines = self.linked_subroutines(program, shadertype)?;
+        // Safety: caller ensures name is a valid, null-terminated string
+        let name = unsafe { CStr::from_ptr(name) }.to_str().unwrap_or_default();
+        #[expect(
+            clippy::cast_possible_wrap,
+            reason = "locations are less than i32::MAX"
+        )]
+        let location = subroutines.location(name).map_or(-1, |l| l as GLint);
+        Ok(location)
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the name of the program containing the subroutine.
+    ///
+    /// `shadertype`
+    ///
+    /// > Specifies the shader stage from which to query the subroutine name.
+    ///
+    /// `index`
+    ///
+    /// > Specifies the index of the shader subroutine uniform.
+    ///
+    /// `bufSize`
+    ///
+    /// > Specifies the size of the buffer whose address is given in `name`.
+    ///
+    /// `length`
+    ///
+    /// > Specifies the address of a variable which is to receive the length of the
+    /// > shader subroutine uniform name.
+    ///
+    /// `name`
+    ///
+    /// > Specifies the address of an array into which the name of the shader subroutine
+    /// > uniform will be written.
+    ///
+    /// ### Description
+    /// [**glGetActiveSubroutineName**](crate::context::Context::oxidegl_get_active_subroutine_name)
+    /// queries the name of an active shader subroutine uniform from the program
+    /// object given in `program`. `index` specifies the index of the shader subroutine
+    /// uniform within the shader stage given by `stage`, and must between zero
+    /// and the value of [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES)
+    /// minus one for the shader stage.
+    ///
+    /// The name of the selected subroutine is returned as a null-terminated string
+    /// in `name`. The actual number of characters written into `name`, not including
+    /// the null-terminator, is returned in `length`. If `length` is [`NULL`](crate::gl_enums::NULL),
+    /// no length is returned. The maximum number of characters that may be written
+    /// into `name`, including the null-terminator, is given in `bufSize`.
+    ///
+    /// ### Associated Gets
+    /// [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage)
+    /// with argument [`GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH)
+    pub unsafe fn oxidegl_get_active_subroutine_name(
+        &mut self,
+        program: GLuint,
+        shadertype: ShaderType,
+        index: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        name: *mut GLchar,
+    ) -> GlFallible {
+        sizei!(buf_size);
+        let subroutines = self.program_subroutines(program, shadertype)?;
+        let Some(subroutine) = subroutines.functions.get(index as usize) else {
+            return Err(GlError::InvalidValue.e());
+        };
+        // Safety: caller ensures length and name are either null or valid for writes
+        unsafe { write_gl_string(subroutine, buf_size, length, name) };
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the name of the program containing the subroutine.
+    ///
+    /// `shadertype`
+    ///
+    /// > Specifies the shader stage from which to query for the subroutine parameter.
+    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
+    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
+    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
+    ///
+    /// `index`
+    ///
+    /// > Specifies the index of the shader subroutine uniform.
+    ///
+    /// `bufSize`
+    ///
+    /// > Specifies the size of the buffer whose address is given in `name`.
+    ///
+    /// `length`
+    ///
+    /// > Specifies the address of a variable into which is written the number of
+    /// > characters copied into `name`.
+    ///
+    /// `name`
+    ///
+    /// > Specifies the address of a buffer that will receive the name of the specified
+    /// > shader subroutine uniform.
+    ///
+    /// ### Description
+    /// [**glGetActiveSubroutineUniformName**](crate::context::Context::oxidegl_get_active_subroutine_uniform_name)
+    /// retrieves the name of an active shader subroutine uniform. `program` contains
+    /// the name of the program containing the uniform. `shadertype` specifies
+    /// the stage for which the uniform location, given by `index`, is valid. `index`
+    /// must be between zero and the value of [`GL_ACTIVE_SUBROUTINE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORMS)
+    /// minus one for the shader stage.
+    ///
+    /// The uniform name is returned as a null-terminated string in `name`. The
+    /// actual number of characters written into `name`, excluding the null terminator
+    /// is returned in `length`. If `length` is [`NULL`](crate::gl_enums::NULL),
+    /// no length is returned. The maximum number of characters that may be written
+    /// into `name`, including the null terminator, is specified by `bufSize`. The
+    /// length of the longest subroutine uniform name in `program` and `shadertype`
+    /// is given by the value of [`GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH),
+    /// which can be queried with [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage).
+    ///
+    /// ### Associated Gets
+    /// [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage)
+    /// with argument [`GL_ACTIVE_SUBROUTINE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORMS)
+    pub unsafe fn oxidegl_get_active_subroutine_uniform_name(
+        &mut self,
+        program: GLuint,
+        shadertype: ShaderType,
+        index: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        name: *mut GLchar,
+    ) -> GlFallible {
+        sizei!(buf_size);
+        let subroutines = self.program_subroutines(program, shadertype)?;
+        let Some(uniform) = subroutines.uniforms.get(index as usize) else {
+            return Err(GlError::InvalidValue.e());
+        };
+        // Safety: caller ensures length and name are either null or valid for writes
+        unsafe { write_gl_string(&uniform.gl_name(), buf_size, length, name) };
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the name of the program containing the subroutine.
+    ///
+    /// `shadertype`
+    ///
+    /// > Specifies the shader stage from which to query for the subroutine parameter.
+    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
+    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
+    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
+    ///
+    /// `index`
+    ///
+    /// > Specifies the index of the shader subroutine uniform.
+    ///
+    /// `pname`
+    ///
+    /// > Specifies the parameter of the shader subroutine uniform to query. `pname`
+    /// > must be [`GL_NUM_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_NUM_COMPATIBLE_SUBROUTINES),
+    /// > [`GL_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_COMPATIBLE_SUBROUTINES),
+    /// > [`GL_UNIFORM_SIZE`](crate::gl_enums::GL_UNIFORM_SIZE) or [`GL_UNIFORM_NAME_LENGTH`](crate::gl_enums::GL_UNIFORM_NAME_LENGTH).
+    ///
+    /// `values`
+    ///
+    /// > Specifies the address of a into which the queried value or values will
+    /// > be placed.
+    ///
+    /// ### Description
+    /// [**glGetActiveSubroutineUniform**](crate::context::Context::oxidegl_get_active_subroutine_uniform)
+    /// queries a parameter of an active shader subroutine uniform. `program` contains
+    /// the name of the program containing the uniform. `shadertype` specifies
+    /// the stage which the uniform location, given by `index`, is valid. `index`
+    /// must be between zero and the value of [`GL_ACTIVE_SUBROUTINE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORMS)
+    /// minus one for the shader stage.
+    ///
+    /// If `pname` is [`GL_NUM_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_NUM_COMPATIBLE_SUBROUTINES),
+    /// a single integer indicating the number of subroutines that can be assigned
+    /// to the uniform is returned in `values`.
+    ///
+    /// If `pname` is [`GL_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_COMPATIBLE_SUBROUTINES),
+    /// an array of integers is returned in `values`, with each integer specifying
+    /// the index of an active subroutine that can be assigned to the selected
+    /// subroutine uniform. The number of integers returned is the same as the
+    /// value returned for [`GL_NUM_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_NUM_COMPATIBLE_SUBROUTINES).
+    ///
+    /// If `pname` is [`GL_UNIFORM_SIZE`](crate::gl_enums::GL_UNIFORM_SIZE), a
+    /// single integer is returned in `values`. If the selected subroutine uniform
+    /// is an array, the declared size of the array is returned; otherwise, one
+    /// is returned.
+    ///
+    /// If `pname` is [`GL_UNIFORM_NAME_LENGTH`](crate::gl_enums::GL_UNIFORM_NAME_LENGTH),
+    /// a single integer specifying the length of the subroutine uniform name
+    /// (including the terminating null character) is returned in `values`.
+    ///
+    /// ### Associated Gets
+    /// [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage)
+    /// with argument [`GL_ACTIVE_SUBROUTINE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORMS)
+    pub unsafe fn oxidegl_get_active_subroutine_uniformiv(
+        &mut self,
+        program: GLuint,
+        shadertype: ShaderType,
+        index: GLuint,
+        pname: SubroutineParameterName,
+        values: *mut GLint,
+    ) -> GlFallible {
+        let subroutines = self.program_subroutines(program, shadertype)?;
+        let Some(uniform) = subroutines.uniforms.get(index as usize) else {
+            return Err(GlError::InvalidValue.e());
+        };
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            reason = "counts, indices and name lengths are less than i32::MAX"
+        )]
+        let written = match pname {
+            SubroutineParameterName::NumCompatibleSubroutines => {
+                vec![uniform.compatible.len() as GLint]
+            }
+            SubroutineParameterName::CompatibleSubroutines => {
+                uniform.compatible.iter().map(|&i| i as GLint).collect()
+            }
+            SubroutineParameterName::UniformSize => vec![uniform.len as GLint],
+            SubroutineParameterName::UniformNameLength => {
+                vec![uniform.gl_name().len() as GLint + 1]
+            }
+        };
+        // Safety: caller ensures values is valid for writes of as many integers as pname returns
+        unsafe { ptr::copy_nonoverlapping(written.as_ptr(), values, written.len()) };
+        Ok(())
+    }
+    /// ### Parameters
+    /// `shadertype`
+    ///
+    /// > Specifies the shader stage from which to query for subroutine uniform index.
+    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
+    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
+    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
+    ///
+    /// `count`
+    ///
+    /// > Specifies the number of uniform indices stored in `indices`.
+    ///
+    /// `indices`
+    ///
+    /// > Specifies the address of an array holding the indices to load into the
+    /// > shader subroutine variables.
+    ///
+    /// ### Description
+    /// [**glUniformSubroutines**](crate::context::Context::oxidegl_uniform_subroutines)
+    /// loads all active subroutine uniforms for shader stage `shadertype` of the
+    /// current program with subroutine indices from `indices`, storing `indices[i]`
+    /// into the uniform at location `i`. `count` must be equal to the value of
+    /// [`GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS)
+    /// for the program currently in use at shader stage `shadertype`. Furthermore,
+    /// all values in `indices` must be less than the value of [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES)
+    /// for the shader stage.
+    ///
+    /// ### Associated Gets
+    /// [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage)
+    /// with argument [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES)
+    ///
+    /// [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage)
+    /// with argument [`GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS)
+    pub unsafe fn oxidegl_uniform_subroutinesuiv(
+        &mut self,
+        shadertype: ShaderType,
+        count: GLsizei,
+        indices: *const GLuint,
+    ) -> GlFallible {
+        sizei!(count);
+        let (subroutines, _) = self.current_subroutines(shadertype)?;
+        gl_assert!(
+            count == subroutines.location_count(),
+            InvalidValue,
+            "expected a subroutine index for each of the {} subroutine uniform locations of {shadertype:?}",
+            subroutines.location_count()
+        );
+        let selections = if count == 0 {
+            Vec::new()
+        } else {
+            // Safety: caller ensures indices points to count indices
+            unsafe { slice::from_raw_parts(indices, count as usize) }.to_vec()
+        };
+        for (location, &index) in (0..).zip(&selections) {
+            let Some((uniform, _)) = subroutines.uniform_at(location) else {
+                // locations that no uniform uses are ignored
+                continue;
+            };
+            gl_assert!(
+                uniform.compatible.contains(&index),
+                InvalidValue,
+                "subroutine {index} is not compatible with subroutine uniform {}",
+                uniform.name
+            );
+        }
+        self.gl_state.subroutine_selections[ProgramPipeline::stage_index(shadertype)] = selections;
+        self.upload_uniforms();
+        Ok(())
+    }
+    /// ### Parameters
+    /// `shadertype`
+    ///
+    /// > Specifies the shader stage from which to query for subroutine uniform index.
+    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
+    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
+    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
+    ///
+    /// `location`
+    ///
+    /// > Specifies the location of the subroutine uniform.
+    ///
+    /// `values`
+    ///
+    /// > Specifies the address of a variable to receive the value or values of the
+    /// > subroutine uniform.
+    ///
+    /// ### Description
+    /// [**glGetUniformSubroutine**](crate::context::Context::oxidegl_get_uniform_subroutine)
+    /// retrieves the value of the subroutine uniform at location `location` for
+    /// shader stage `shadertype` of the current program. `location` must be less
+    /// than the value of [`GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS)
+    /// for the shader currently in use at shader stage `shadertype`. The value
+    /// of the subroutine uniform is returned in `values`.
+    pub unsafe fn oxidegl_get_uniform_subroutineuiv(
+        &mut self,
+        shadertype: ShaderType,
+        location: GLint,
+        params: *mut GLuint,
+    ) -> GlFallible {
+        let (subroutines, selections) = self.current_subroutines(shadertype)?;
+        let Some(index) = u32::try_from(location)
+            .ok()
+            .and_then(|l| subroutines.selected(selections, l))
+        else {
+            return Err(GlError::InvalidValue.e());
+        };
+        // Safety: caller ensures params is valid for writes
+        unsafe { params.write(index) };
+        Ok(())
+    }
+    /// ### Parameters
+    /// `program`
+    ///
+    /// > Specifies the name of the program containing shader stage.
+    ///
+    /// `shadertype`
+    ///
+    /// > Specifies the shader stage from which to query for the subroutine parameter.
+    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
+    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
+    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
+    ///
+    /// `pname`
+    ///
+    /// > Specifies the parameter of the shader to query. `pname` must be [`GL_ACTIVE_SUBROUTINE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORMS),
+    /// > [`GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS),
+    /// > [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES), [`GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH),
+    /// > or [`GL_ACTIVE_SUBROUTINE_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_MAX_LENGTH).
+    ///
+    /// `values`
+    ///
+    /// > Specifies the address of a variable into which the queried value or values
+    /// > will be placed.
+    ///
+    /// ### Description
+    /// [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage)
+    /// queries a parameter of a shader stage attached to a program object. `program`
+    /// contains the name of the program to which the shader is attached. `shadertype`
+    /// specifies the stage from which to query the parameter. `pname` specifies
+    /// which parameter should be queried. The value or values of the parameter
+    /// to be queried is returned in the variable whose address is given in `values`.
+    ///
+    /// If `pname` is [`GL_ACTIVE_SUBROUTINE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORMS),
+    /// the number of active subroutine variables in the stage is returned in
+    /// `values`.
+    ///
+    /// If `pname` is [`GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS),
+    /// the number of active subroutine variable locations in the stage is returned
+    /// in `values`.
+    ///
+    /// If `pname` is [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES),
+    /// the number of active subroutines in the stage is returned in `values`.
+    ///
+    /// If `pname` is [`GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH),
+    /// the length of the longest subroutine uniform for the stage is returned
+    /// in `values`.
+    ///
+    /// If `pname` is [`GL_ACTIVE_SUBROUTINE_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_MAX_LENGTH),
+    /// the length of the longest subroutine name for the stage is returned in
+    /// `values`. The returned name length includes space for the null-terminator.
+    ///
+    /// If there is no shader present of type `shadertype`, the returned value
+    /// will be consistent with a shader containing no subroutines or subroutine
+    /// uniforms.
+    pub unsafe fn oxidegl_get_program_stageiv(
+        &mut self,
+        program: GLuint,
+        shadertype: ShaderType,
+        pname: ProgramStagePName,
+        values: *mut GLint,
+    ) -> GlFallible {
+        let subroutines = self.program_subroutines(program, shadertype)?;
+        let value = match pname {
+            ProgramStagePName::ActiveSubroutines => subroutines.functions.len(),
+            ProgramStagePName::ActiveSubroutineUniforms => subroutines.uniforms.len(),
+            ProgramStagePName::ActiveSubroutineUniformLocations => {
+                subroutines.location_count() as usize
+            }
+            ProgramStagePName::ActiveSubroutineMaxLength => {
+                // including the nul terminator
+                subroutines
+                    .functions
+                    .iter()
+                    .map(|f| f.len() + 1)
+                    .max()
+                    .unwrap_or(0)
+            }
+            ProgramStagePName::ActiveSubroutineUniformMaxLength => subroutines
+                .uniforms
+                .iter()
+                .map(|u| u.gl_name().len() + 1)
+                .max()
+                .unwrap_or(0),
+        };
+        // Safety: caller ensures values is valid for writes
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            reason = "counts and name lengths are less than i32::MAX"
+        )]
+        unsafe {
+            values.write(value as GLint);
+        }
+        Ok(())
+    }
+}
+impl Context {
+    /// Forget the subroutines selected for every stage, which `glUseProgram`, `glBindProgramPipeline` and
+    /// `glUseProgramStages` do
+    pub(crate) fn reset_subroutine_selections(&mut self) {
+        self.gl_state
+            .subroutine_selections
+            .iter_mut()
+            .for_each(Vec::clear);
+        self.upload_uniforms();
+    }
+    /// Subroutines of a stage of a program, which has none if it was not linked successfully
+    fn program_subroutines(
+        &mut self,
+        program: GLuint,
+        stage: ShaderType,
+    ) -> GlFallible<&StageSubroutines> {
+        Ok(self
+            .gl_state
+            .program_list
+            .get_program_raw_mut(&self.gl_state.shader_list, program)?
+            .linkage()
+            .map_or(&NO_SUBROUTINES, |l| l.interfaces.stage_subroutines(stage)))
+    }
+    /// Subroutines of a stage of a program that was linked successfully
+    fn linked_subroutines(
+        &mut self,
+        program: GLuint,
+        stage: ShaderType,
+    ) -> GlFallible<&StageSubroutines> {
+        self.gl_state
+            .program_list
+            .get_program_raw_mut(&self.gl_state.shader_list, program)?
+            .linkage()
+            .map(|l| l.interfaces.stage_subroutines(stage))
+            .ok_or_else(|| GlError::InvalidOperation.e())
+    }
+    /// Subroutines of the executable that runs a stage, and the subroutines selected for its locations
+    fn current_subroutines(
+        &mut self,
+        stage: ShaderType,
+    ) -> GlFallible<(&StageSubroutines, &[u32])> {
+        let linked = self
+            .gl_state
+            .stage_program(stage)
+            .and_then(|program| self.gl_state.program_list.get_opt_mut(program))
+            .and_then(|program| program.linkage())
+            .is_some_and(|l| l.stage(stage).is_some());
+        if !linked {
+            gl_err!(src: Api, ty: Error, "there is no program for {stage:?} to select subroutines of");
+            return Err(GlError::InvalidOperation.e());
+        }
+        let state = &self.gl_state;
+        let program = state
+            .program_list
+            .get(state.stage_program(stage).expect("checked above"));
+        let subroutines = program
+            .latest_linkage
+            .as_ref()
+            .expect("checked above")
+            .interfaces
+            .stage_subroutines(stage);
+        Ok((subroutines, state.subroutine_selections(stage)))
+    }
+}
+
+#[test]
+fn software_subroutines() {
+    use crate::{
+        gl_enums::{ClearBufferMask, PrimitiveType},
+        test_util::{compile, link},
+    };
+    let mut ctx = Context::new_software(4, 4);
+    // a triangle covering the whole viewport
+    let vert = compile(
+        &mut ctx,
+        ShaderType::VertexShader,
+        "#version 430 core
+        void main() {
+            vec2 corner = vec2((gl_VertexID & 1) << 2, (gl_VertexID & 2) << 1);
+            gl_Position = vec4(corner - 1.0, 0.0, 1.0);
+        }",
+    );
+    let frag = compile(
+        &mut ctx,
+        ShaderType::FragmentShader,
+        "#version 430 core
+        subroutine vec4 Color(float alpha);
+        subroutine(Color) vec4 red(float alpha) { return vec4(1.0, 0.0, 0.0, alpha); }
+        layout(index = 0) subroutine(Color) vec4 green(float alpha) { return vec4(0.0, 1.0, 0.0, alpha); }
+        subroutine uniform Color color;
+        layout(location = 0) out vec4 frag_color;
+        void main() {
+            frag_color = color(1.0);
+        }",
+    );
+    let program = link(&mut ctx, &[vert, frag]);
+    ctx.oxidegl_use_program(program).unwrap();
+    let mut vao = 0;
+    // Safety: pointer is valid for the duration of the call
+    unsafe { ctx.oxidegl_create_vertex_arrays(1, &raw mut vao) };
+    ctx.oxidegl_bind_vertex_array(vao).unwrap();
+
+    let stage = ShaderType::FragmentShader;
+    // Safety: names are valid strings
+    unsafe {
+        assert_eq!(
+            ctx.oxidegl_get_subroutine_index(program, stage, c"red".as_ptr())
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            ctx.oxidegl_get_subroutine_uniform_location(program, stage, c"color".as_ptr())
+                .unwrap(),
+            0
+        );
+    }
+    let stageiv = |ctx: &mut Context, pname| {
+        let mut value = 0;
+        // Safety: pointer is valid for the duration of the call
+        unsafe { ctx.oxidegl_get_program_stageiv(program, stage, pname, &raw mut value) }.unwrap();
+        value
+    };
+    assert_eq!(stageiv(&mut ctx, ProgramStagePName::ActiveSubroutines), 2);
+    assert_eq!(
+        stageiv(
+            &mut ctx,
+            ProgramStagePName::ActiveSubroutineUniformLocations
+        ),
+        1
+    );
+    let selected = |ctx: &mut Context| {
+        let mut index = 0;
+        // Safety: pointer is valid for the duration of the call
+        unsafe { ctx.oxidegl_get_uniform_subroutineuiv(stage, 0, &raw mut index) }.unwrap();
+        index
+    };
+    let draw = |ctx: &mut Context| {
+        ctx.oxidegl_clear(ClearBufferMask::COLOR_BUFFER_BIT);
+        ctx.oxidegl_draw_arrays(PrimitiveType::Triangles, 0, 3)
+            .unwrap();
+        let (_, color) = ctx.color_buffer().unwrap();
+        color[0]
+    };
+
+    // the first compatible subroutine is called until another one is selected
+    assert_eq!(selected(&mut ctx), 0);
+    assert_eq!(draw(&mut ctx), [0.0, 1.0, 0.0, 1.0]);
+    // Safety: indices point to the given number of indices
+    unsafe {
+        assert!(
+            ctx.oxidegl_uniform_subroutinesuiv(stage, 2, [1, 1].as_ptr())
+                .is_err()
+        );
+        assert!(
+            ctx.oxidegl_uniform_subroutinesuiv(stage, 1, [2].as_ptr())
+                .is_err()
+        );
+        ctx.oxidegl_uniform_subroutinesuiv(stage, 1, [1].as_ptr())
+            .unwrap();
+    }
+    assert_eq!(selected(&mut ctx), 1);
+    assert_eq!(draw(&mut ctx), [1.0, 0.0, 0.0, 1.0]);
+    // using a program forgets the selection
+    ctx.oxidegl_use_program(program).unwrap();
+    assert_eq!(selected(&mut ctx), 0);
+    assert_eq!(draw(&mut ctx), [0.0, 1.0, 0.0, 1.0]);
+}
@@ -0,0 +1,455 @@
+use core::slice;
+
+use crate::{
+    context::Context,
+    conversions::sizei,
+    debug::gl_err,
+    error::{GlError, GlFallible, gl_assert},
+    gl_enums::{BufferAccess, InternalFormat, TextureTarget},
+    gl_object::ObjectName,
+    gl_types::{GLboolean, GLint, GLsizei, GLuint},
+    texture::{
+        Texture,
+        image::{self, ImageUnit, MAX_IMAGE_UNITS},
+    },
+};
+
+impl Context {
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of texture names to be generated.
+    ///
+    /// `textures`
+    ///
+    /// > Specifies an array in which the generated texture names are stored.
+    ///
+    /// ### Description
+    /// [**glGenTextures**](crate::context::Context::oxidegl_gen_textures) returns
+    /// `n` texture names in `textures`. There is no guarantee that the names form
+    /// a contiguous set of integers; however, it is guaranteed that none of the
+    /// returned names was in use immediately before the call to [**glGenTextures**](crate::context::Context::oxidegl_gen_textures).
+    ///
+    /// The generated textures have no dimensionality; they assume the dimensionality
+    /// of the texture target to which they are first bound (see [**glBindTexture**](crate::context::Context::oxidegl_bind_texture)
+    /// ).
+    ///
+    /// Texture names returned by a call to [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
+    /// are not returned by subsequent calls, unless they are first deleted with
+    /// [**glDeleteTextures**](crate::context::Context::oxidegl_delete_textures).
+    ///
+    /// ### Associated Gets
+    /// [**glIsTexture**](crate::context::Context::oxidegl_is_texture)
+    pub unsafe fn oxidegl_gen_textures(&mut self, n: GLsizei, textures: *mut GLuint) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller guarantees invariants are upheld
+        unsafe {
+            self.gl_state.texture_list.gen_obj(n, textures);
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the effective texture target of each created texture.
+    ///
+    /// `n`
+    ///
+    /// > Number of texture objects to create.
+    ///
+    /// `textures`
+    ///
+    /// > Specifies an array in which names of the new texture objects are stored.
+    ///
+    /// ### Description
+    /// [**glCreateTextures**](crate::context::Context::oxidegl_create_textures)
+    /// returns `n` previously unused texture names in `textures`, each representing
+    /// a new texture object of the dimensionality and type specified by `target`
+    /// and initialized to the default values for that texture type.
+    ///
+    /// `target` must be one of [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D),
+    /// [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D), [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D),
+    /// [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY),
+    /// [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE), [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP),
+    /// [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+    /// [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE)
+    /// or [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY).
+    pub unsafe fn oxidegl_create_textures(
+        &mut self,
+        target: TextureTarget,
+        n: GLsizei,
+        textures: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(
+            !matches!(
+                target,
+                TextureTarget::ProxyTexture1D
+                    | TextureTarget::ProxyTexture2D
+                    | TextureTarget::ProxyTexture3D
+                    | TextureTarget::ProxyTextureCubeMap
+                    | TextureTarget::ProxyTexture1DArray
+                    | TextureTarget::ProxyTexture2DArray
+                    | TextureTarget::ProxyTextureRectangle
+                    | TextureTarget::ProxyTexture2DMultisample
+                    | TextureTarget::ProxyTexture2DMultisampleArray
+                    | TextureTarget::ProxyTextureCubeMapArray
+                    | TextureTarget::TextureCubeMapPositiveX
+                    | TextureTarget::TextureCubeMapNegativeX
+                    | TextureTarget::TextureCubeMapPositiveY
+                    | TextureTarget::TextureCubeMapNegativeY
+                    | TextureTarget::TextureCubeMapPositiveZ
+                    | TextureTarget::TextureCubeMapNegativeZ
+                    | TextureTarget::Renderbuffer
+            ),
+            InvalidEnum,
+            "{target:?} is not a valid target for glCreateTextures"
+        );
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller guarantees invariants are upheld
+        unsafe {
+            self.gl_state.texture_list.create_obj(
+                |name| Texture::new_named(name, target),
+                n,
+                textures,
+            );
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of textures to be deleted.
+    ///
+    /// `textures`
+    ///
+    /// > Specifies an array of textures to be deleted.
+    ///
+    /// ### Description
+    /// [**glDeleteTextures**](crate::context::Context::oxidegl_delete_textures)
+    /// deletes `n` textures named by the elements of the array `textures`. After
+    /// a texture is deleted, it has no contents or dimensionality, and its name
+    /// is free for reuse (for example by [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
+    /// ). If a texture that is currently bound is deleted, the binding reverts
+    /// to 0 (the default texture).
+    ///
+    /// [**glDeleteTextures**](crate::context::Context::oxidegl_delete_textures)
+    /// silently ignores 0's and names that do not correspond to existing textures.
+    ///
+    /// ### Associated Gets
+    /// [**glIsTexture**](crate::context::Context::oxidegl_is_texture)
+    pub unsafe fn oxidegl_delete_textures(
+        &mut self,
+        n: GLsizei,
+        textures: *const GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller guarantees invariants are upheld
+        unsafe {
+            self.gl_state.texture_list.delete_objects(n, textures);
+        }
+        // deleting a texture breaks its bindings to image units
+        let list = &self.gl_state.texture_list;
+        for unit in &mut self.gl_state.image_units {
+            if unit.texture.is_some_and(|t| !list.is(t)) {
+                *unit = ImageUnit::default();
+            }
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `texture`
+    ///
+    /// > Specifies a value that may be the name of a texture.
+    ///
+    /// ### Description
+    /// [**glIsTexture**](crate::context::Context::oxidegl_is_texture) returns
+    /// [`GL_TRUE`](crate::gl_enums::GL_TRUE) if `texture` is currently the name
+    /// of a texture. If `texture` is zero, or is a non-zero value that is not
+    /// currently the name of a texture, or if an error occurs, [**glIsTexture**](crate::context::Context::oxidegl_is_texture)
+    /// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE).
+    ///
+    /// A name returned by [**glGenTextures**](crate::context::Context::oxidegl_gen_textures),
+    /// but not yet associated with a texture by calling [**glBindTexture**](crate::context::Context::oxidegl_bind_texture),
+    /// is not the name of a texture.
+    pub fn oxidegl_is_texture(&mut self, texture: GLuint) -> GlFallible<GLboolean> {
+        Ok(self.gl_state.texture_list.is_obj(texture))
+    }
+    /// ### Parameters
+    /// `unit`
+    ///
+    /// > Specifies the index of the image unit to which to bind the texture
+    ///
+    /// `texture`
+    ///
+    /// > Specifies the name of the texture to bind to the image unit.
+    ///
+    /// `level`
+    ///
+    /// > Specifies the level of the texture that is to be bound.
+    ///
+    /// `layered`
+    ///
+    /// > Specifies whether a layered texture binding is to be established.
+    ///
+    /// `layer`
+    ///
+    /// > If `layered` is [`GL_FALSE`](crate::gl_enums::GL_FALSE), specifies the
+    /// > layer of `texture` to be bound to the image unit. Ignored otherwise.
+    ///
+    /// `access`
+    ///
+    /// > Specifies a token indicating the type of access that will be performed
+    /// > on the image.
+    ///
+    /// `format`
+    ///
+    /// > Specifies the format that the elements of the image will be treated as
+    /// > for the purposes of formatted stores.
+    ///
+    /// ### Description
+    /// [**glBindImageTexture**](crate::context::Context::oxidegl_bind_image_texture)
+    /// binds a single level of a texture to an image unit for the purpose of reading
+    /// and writing it from shaders. `unit` specifies the zero-based index of the
+    /// image unit to which to bind the texture level. `texture` specifies the
+    /// name of an existing texture object to bind to the image unit. If `texture`
+    /// is zero, then any existing binding to the image unit is broken. `level`
+    /// specifies the level of the texture to bind to the image unit.
+    ///
+    /// If `texture` is the name of a one-, two-, or three-dimensional array texture,
+    /// a cube map or cube map array texture, or a two-dimensional multisample
+    /// array texture, then it is possible to bind either the entire array, or
+    /// only a single layer of the array to the image unit. In such cases, if `layered`
+    /// is [`GL_TRUE`](crate::gl_enums::GL_TRUE), the entire array is attached
+    /// to the image unit and `layer` is ignored. However, if `layered` is [`GL_FALSE`](crate::gl_enums::GL_FALSE)
+    /// then `layer` specifies the layer of the array to attach to the image unit.
+    ///
+    /// `access` specifies the access types to be performed by shaders and may
+    /// be set to [`GL_READ_ONLY`](crate::gl_enums::GL_READ_ONLY), [`GL_WRITE_ONLY`](crate::gl_enums::GL_WRITE_ONLY),
+    /// or [`GL_READ_WRITE`](crate::gl_enums::GL_READ_WRITE) to indicate read-only,
+    /// write-only or read-write access, respectively. Violation of the access
+    /// type specified in `access` (for example, if a shader writes to an image
+    /// bound with `access` set to [`GL_READ_ONLY`](crate::gl_enums::GL_READ_ONLY))
+    /// will lead to undefined results, possibly including program termination.
+    ///
+    /// `format` specifies the format that is to be used when performing formatted
+    /// stores into the image from shaders. `format` must be compatible with the
+    /// texture's internal format and must be one of the formats listed in the
+    /// following table.
+    ///
+    /// |* Image Unit Format*                             |* Format Qualifier*                             |
+    /// |-------------------------------------------------|------------------------------------------------|
+    /// | [`GL_RGBA32F`](crate::gl_enums::GL_RGBA32F)     |                                                |
+    /// | [`GL_RGBA16F`](crate::gl_enums::GL_RGBA16F)     |                                                |
+    /// | [`GL_RG32F`](crate::gl_enums::GL_RG32F)         |                                                |
+    /// | [`GL_RG16F`](crate::gl_enums::GL_RG16F)         |                                                |
+    /// | [`GL_R11F_G11F_B10F`](crate::gl_enums::GL_R11F_G11F_B10F) |                                      |
+    /// | [`GL_R32F`](crate::gl_enums::GL_R32F)           |                                                |
+    /// | [`GL_R16F`](crate::gl_enums::GL_R16F)           |                                                |
+    /// | [`GL_RGBA32UI`](crate::gl_enums::GL_RGBA32UI)   |                                                |
+    /// | [`GL_RGBA16UI`](crate::gl_enums::GL_RGBA16UI)   |                                                |
+    /// | [`GL_RGB10_A2UI`](crate::gl_enums::GL_RGB10_A2UI) |                                              |
+    /// | [`GL_RGBA8UI`](crate::gl_enums::GL_RGBA8UI)     |                                                |
+    /// | [`GL_RG32UI`](crate::gl_enums::GL_RG32UI)       |                                                |
+    /// | [`GL_RG16UI`](crate::gl_enums::GL_RG16UI)       |                                                |
+    /// | [`GL_RG8UI`](crate::gl_enums::GL_RG8UI)         |                                                |
+    /// | [`GL_R32UI`](crate::gl_enums::GL_R32UI)         |                                                |
+    /// | [`GL_R16UI`](crate::gl_enums::GL_R16UI)         |                                                |
+    /// | [`GL_R8UI`](crate::gl_enums::GL_R8UI)           |                                                |
+    /// | [`GL_RGBA32I`](crate::gl_enums::GL_RGBA32I)     |                                                |
+    /// | [`GL_RGBA16I`](crate::gl_enums::GL_RGBA16I)     |                                                |
+    /// | [`GL_RGBA8I`](crate::gl_enums::GL_RGBA8I)       |                                                |
+    /// | [`GL_RG32I`](crate::gl_enums::GL_RG32I)         |                                                |
+    /// | [`GL_RG16I`](crate::gl_enums::GL_RG16I)         |                                                |
+    /// | [`GL_RG8I`](crate::gl_enums::GL_RG8I)           |                                                |
+    /// | [`GL_R32I`](crate::gl_enums::GL_R32I)           |                                                |
+    /// | [`GL_R16I`](crate::gl_enums::GL_R16I)           |                                                |
+    /// | [`GL_R8I`](crate::gl_enums::GL_R8I)             |                                                |
+    /// | [`GL_RGBA16`](crate::gl_enums::GL_RGBA16)       |                                                |
+    /// | [`GL_RGB10_A2`](crate::gl_enums::GL_RGB10_A2)   |                                                |
+    /// | [`GL_RGBA8`](crate::gl_enums::GL_RGBA8)         |                                                |
+    /// | [`GL_RG16`](crate::gl_enums::GL_RG16)           |                                                |
+    /// | [`GL_RG8`](crate::gl_enums::GL_RG8)             |                                                |
+    /// | [`GL_R16`](crate::gl_enums::GL_R16)             |                                                |
+    /// | [`GL_R8`](crate::gl_enums::GL_R8)               |                                                |
+    /// | [`GL_RGBA16_SNORM`](crate::gl_enums::GL_RGBA16_SNORM) |                                          |
+    /// | [`GL_RGBA8_SNORM`](crate::gl_enums::GL_RGBA8_SNORM) |                                            |
+    /// | [`GL_RG16_SNORM`](crate::gl_enums::GL_RG16_SNORM) |                                              |
+    /// | [`GL_RG8_SNORM`](crate::gl_enums::GL_RG8_SNORM) |                                                |
+    /// | [`GL_R16_SNORM`](crate::gl_enums::GL_R16_SNORM) |                                                |
+    /// | [`GL_R8_SNORM`](crate::gl_enums::GL_R8_SNORM)   |                                                |
+    ///
+    ///
+    /// When a texture is bound to an image unit, the `format` parameter for the
+    /// image unit need not exactly match the texture internal format as long as
+    /// the formats are considered compatible as defined in the OpenGL Specification.
+    /// The matching criterion used for a given texture may be determined by calling
+    /// [**glGetTexParameter**](crate::context::Context::oxidegl_get_tex_parameter)
+    /// with `value` set to [`GL_IMAGE_FORMAT_COMPATIBILITY_TYPE`](crate::gl_enums::GL_IMAGE_FORMAT_COMPATIBILITY_TYPE),
+    /// with return values of [`GL_IMAGE_FORMAT_COMPATIBILITY_BY_SIZE`](crate::gl_enums::GL_IMAGE_FORMAT_COMPATIBILITY_BY_SIZE)
+    /// and [`GL_IMAGE_FORMAT_COMPATIBILITY_BY_CLASS`](crate::gl_enums::GL_IMAGE_FORMAT_COMPATIBILITY_BY_CLASS),
+    /// specifying matches by size and class, respectively.
+    ///
+    /// ### Notes
+    /// The [**glBindImageTexture**](crate::context::Context::oxidegl_bind_image_texture)
+    /// is available only if the GL version is 4.2 or greater.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_IMAGE_BINDING_NAME`](crate::gl_enums::GL_IMAGE_BINDING_NAME).
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_IMAGE_BINDING_LEVEL`](crate::gl_enums::GL_IMAGE_BINDING_LEVEL).
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_IMAGE_BINDING_LAYERED`](crate::gl_enums::GL_IMAGE_BINDING_LAYERED).
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_IMAGE_BINDING_LAYER`](crate::gl_enums::GL_IMAGE_BINDING_LAYER).
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_IMAGE_BINDING_ACCESS`](crate::gl_enums::GL_IMAGE_BINDING_ACCESS).
+    ///
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_IMAGE_BINDING_FORMAT`](crate::gl_enums::GL_IMAGE_BINDING_FORMAT).
+    pub fn oxidegl_bind_image_texture(
+        &mut self,
+        unit: GLuint,
+        texture: GLuint,
+        level: GLint,
+        layered: GLboolean,
+        layer: GLint,
+        access: BufferAccess,
+        format: InternalFormat,
+    ) -> GlFallible {
+        gl_assert!(
+            unit < MAX_IMAGE_UNITS,
+            InvalidValue,
+            "image unit {unit} is out of range"
+        );
+        let (Ok(level), Ok(layer)) = (u32::try_from(level), u32::try_from(layer)) else {
+            return Err(GlError::InvalidValue.e());
+        };
+        gl_assert!(
+            image::image_format(format).is_some(),
+            InvalidValue,
+            "{format:?} is not a valid image unit format"
+        );
+        let texture = ObjectName::try_from_raw(texture).ok();
+        if let Some(texture) = texture {
+            gl_assert!(
+                self.gl_state.texture_list.is(texture),
+                InvalidValue,
+                "{texture:?} is not an existing texture"
+            );
+        }
+        self.gl_state.image_units[unit as usize] =
+            texture.map_or_else(ImageUnit::default, |texture| ImageUnit {
+                texture: Some(texture),
+                level,
+                layered,
+                layer,
+                access,
+                format,
+            });
+        self.upload_uniforms();
+        Ok(())
+    }
+    /// ### Parameters
+    /// `first`
+    ///
+    /// > Specifies the first image unit to which a texture is to be bound.
+    ///
+    /// `count`
+    ///
+    /// > Specifies the number of textures to bind.
+    ///
+    /// `textures`
+    ///
+    /// > Specifies the address of an array of names of existing texture objects.
+    ///
+    /// ### Description
+    /// [**glBindImageTextures**](crate::context::Context::oxidegl_bind_image_textures)
+    /// binds images from an array of existing texture objects to a specified number
+    /// of consecutive image units. `count` specifies the number of texture objects
+    /// whose names are stored in the array `textures`. That number of texture
+    /// names are read from the array and bound to the `count` consecutive texture
+    /// units starting from `first`. If the name zero appears in the `textures`
+    /// array, any existing binding to the image unit is reset. Any non-zero entry
+    /// in `textures` must be the name of an existing texture object. When a non-zero
+    /// entry in `textures` is present, the image at level zero is bound, the binding
+    /// is considered layered, with the first layer set to zero, and the image
+    /// is bound for read-write access. The image unit format parameter is taken
+    /// from the internal format of the image at level zero of the texture object.
+    /// For cube map textures, the internal format of the positive X image of level
+    /// zero is used. If `textures` is [`NULL`](crate::gl_enums::NULL) then it
+    /// is as if an appropriately sized array containing only zeros had been specified.
+    ///
+    /// [**glBindImageTextures**](crate::context::Context::oxidegl_bind_image_textures)
+    /// is equivalent to the following pseudo code:
+    ///
+    /// Each entry in `textures` will be checked individually and if found to be
+    /// invalid, the state for that image unit will not be changed and an error
+    /// will be generated. However, the state for other texture image units referenced
+    /// by the command will still be updated.
+    ///
+    /// ### Notes
+    /// [**glBindImageTextures**](crate::context::Context::oxidegl_bind_image_textures)
+    /// is available only if the GL version is 4.4 or higher.
+    ///
+    /// Note that because [**glBindImageTextures**](crate::context::Context::oxidegl_bind_image_textures)
+    /// cannot create new textures (even if a name passed has been previously generated
+    /// by call to [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
+    /// ), names passed to [**glBindImageTextures**](crate::context::Context::oxidegl_bind_image_textures)
+    /// must have been bound at least once previously via a call to [**glBindTexture**](crate::context::Context::oxidegl_bind_texture).
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_1D`](crate::gl_enums::GL_TEXTURE_BINDING_1D),
+    /// [`GL_TEXTURE_BINDING_2D`](crate::gl_enums::GL_TEXTURE_BINDING_2D), [`GL_TEXTURE_BINDING_3D`](crate::gl_enums::GL_TEXTURE_BINDING_3D),
+    /// [`GL_TEXTURE_BINDING_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_1D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_RECTANGLE`](crate::gl_enums::GL_TEXTURE_BINDING_RECTANGLE),
+    /// [`GL_TEXTURE_BINDING_BUFFER`](crate::gl_enums::GL_TEXTURE_BINDING_BUFFER),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE),
+    /// or [`GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY).
+    pub unsafe fn oxidegl_bind_image_textures(
+        &mut self,
+        first: GLuint,
+        count: GLsizei,
+        textures: *const GLuint,
+    ) -> GlFallible {
+        sizei!(count);
+        gl_assert!(
+            first
+                .checked_add(count)
+                .is_some_and(|end| end <= MAX_IMAGE_UNITS),
+            InvalidOperation,
+            "{count} image units starting at {first} are out of range"
+        );
+        // a null array unbinds all of the units
+        let textures = (!textures.is_null()).then(|| {
+            // Safety: Caller ensures textures points to count texture names if it isn't null
+            unsafe { slice::from_raw_parts(textures, count as usize) }
+        });
+        // units with invalid textures are left alone, but the other units are still bound
+        let mut result = Ok(());
+        for (i, unit) in (0..count as usize).zip(first as usize..) {
+            let Some(texture) = textures.and_then(|t| ObjectName::try_from_raw(t[i]).ok()) else {
+                self.gl_state.image_units[unit] = ImageUnit::default();
+                continue;
+            };
+            let Some(tex) = self.gl_state.texture_list.get_opt(texture) else {
+                gl_err!(ty: Error, "{texture:?} is not an existing texture");
+                result = Err(GlError::InvalidOperation.e());
+                continue;
+            };
+            self.gl_state.image_units[unit] = ImageUnit {
+                texture: Some(texture),
+                level: 0,
+                layered: true,
+                layer: 0,
+                access: BufferAccess::ReadWrite,
+                // images without storage have the default internal format, which is treated as RGBA8
+                format: tex.format().unwrap_or(InternalFormat::Rgba8),
+            };
+        }
+        self.upload_uniforms();
+        result
+    }
+}
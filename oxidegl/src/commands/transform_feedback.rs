@@ -0,0 +1,623 @@
+use core::slice;
+
+use crate::{
+    context::{Context, state::MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS},
+    conversions::{GlGetItem, sizei},
+    debug::gl_debug,
+    error::{GlError, GlFallible, gl_assert},
+    gl_enums::{GL_TRANSFORM_FEEDBACK, PrimitiveType, ShaderType, TransformFeedbackPName},
+    gl_object::ObjectName,
+    gl_types::{GLboolean, GLenum, GLint, GLint64, GLintptr, GLsizei, GLsizeiptr, GLuint},
+    transform_feedback::{ActiveFeedback, FeedbackState, TransformFeedback},
+};
+
+impl Context {
+    /// State of the transform feedback object named `xfb`, or of the default transform feedback object if it is zero
+    pub(crate) fn feedback_object(&self, xfb: GLuint) -> GlFallible<&FeedbackState> {
+        match ObjectName::try_from_raw(xfb) {
+            Ok(name) => self
+                .gl_state
+                .transform_feedback_list
+                .get_opt(name)
+                .map(|t| &t.state)
+                .ok_or(GlError::InvalidOperation.e()),
+            Err(_) => Ok(&self.gl_state.default_transform_feedback),
+        }
+    }
+    fn feedback_object_mut(&mut self, xfb: GLuint) -> GlFallible<&mut FeedbackState> {
+        match ObjectName::try_from_raw(xfb) {
+            Ok(name) => self
+                .gl_state
+                .transform_feedback_list
+                .get_opt_mut(name)
+                .map(|t| &mut t.state)
+                .ok_or(GlError::InvalidOperation.e()),
+            Err(_) => Ok(&mut self.gl_state.default_transform_feedback),
+        }
+    }
+    /// Bind a buffer (or a range of it, as an offset and size in bytes) to binding `index` of the transform feedback
+    /// object named `xfb`
+    fn feedback_buffer_internal(
+        &mut self,
+        xfb: GLuint,
+        index: GLuint,
+        buffer: GLuint,
+        range: Option<(GLintptr, GLsizeiptr)>,
+    ) -> GlFallible {
+        gl_assert!(
+            (index as usize) < MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS,
+            InvalidValue
+        );
+        let buffer = ObjectName::try_from_raw(buffer).ok();
+        if let Some(buffer) = buffer {
+            gl_assert!(self.gl_state.buffer_list.is(buffer), InvalidOperation);
+        }
+        let range = match range {
+            Some((offset, size)) => {
+                // captured components are written as whole words
+                gl_assert!(
+                    offset >= 0 && size > 0 && offset % 4 == 0 && size % 4 == 0,
+                    InvalidValue
+                );
+                #[expect(clippy::cast_sign_loss, reason = "checked above")]
+                Some((offset as usize, size as usize))
+            }
+            None => None,
+        };
+        let state = self.feedback_object_mut(xfb)?;
+        gl_assert!(
+            state.active.is_none(),
+            InvalidOperation,
+            "tried to change a transform feedback buffer binding while transform feedback is active"
+        );
+        state.buffers[index as usize] = buffer;
+        state.ranges[index as usize] = range;
+        gl_debug!(
+            "bound {buffer:?} to transform feedback buffer binding {index} of {xfb} with range {range:?}"
+        );
+        Ok(())
+    }
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of transform feedback object names to reserve.
+    ///
+    /// `ids`
+    ///
+    /// > Specifies an array of into which the reserved names will be written.
+    ///
+    /// ### Description
+    /// [**glGenTransformFeedbacks**](crate::context::Context::oxidegl_gen_transform_feedbacks)
+    /// returns `n` previously unused transform feedback object names in `ids`.
+    /// These names are marked as used, for the purposes of [**glGenTransformFeedbacks**](crate::context::Context::oxidegl_gen_transform_feedbacks)
+    /// only, but they acquire transform feedback state only when they are first
+    /// bound.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TRANSFORM_FEEDBACK_BINDING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BINDING)
+    ///
+    /// [**glIsTransformFeedback**](crate::context::Context::oxidegl_is_transform_feedback)
+    pub unsafe fn oxidegl_gen_transform_feedbacks(
+        &mut self,
+        n: GLsizei,
+        ids: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller guarantees invariants are upheld
+        unsafe {
+            self.gl_state.transform_feedback_list.gen_obj(n, ids);
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Number of transform feedback objects to create.
+    ///
+    /// `ids`
+    ///
+    /// > Specifies an array in which names of the new transform feedback objects
+    /// > are stored.
+    ///
+    /// ### Description
+    /// [**glCreateTransformFeedbacks**](crate::context::Context::oxidegl_create_transform_feedbacks)
+    /// returns `n` previously unused transform feedback object names in `ids`,
+    /// each representing a new transform feedback object initialized to the default
+    /// state.
+    pub unsafe fn oxidegl_create_transform_feedbacks(
+        &mut self,
+        n: GLsizei,
+        ids: *mut GLuint,
+    ) -> GlFallible {
+        gl_assert!(n >= 0, InvalidValue);
+        // Safety: Caller guarantees invariants are upheld
+        unsafe {
+            self.gl_state.transform_feedback_list.create_obj(
+                TransformFeedback::new_default,
+                n,
+                ids,
+            );
+        }
+        Ok(())
+    }
+    /// ### Parameters
+    /// `target`
+    ///
+    /// > Specifies the target to which to bind the transform feedback object `id`.
+    /// > `target` must be [`GL_TRANSFORM_FEEDBACK`](crate::gl_enums::GL_TRANSFORM_FEEDBACK).
+    ///
+    /// `id`
+    ///
+    /// > Specifies the name of a transform feedback object reserved by [**glGenTransformFeedbacks**](crate::context::Context::oxidegl_gen_transform_feedbacks).
+    ///
+    /// ### Description
+    /// [**glBindTransformFeedback**](crate::context::Context::oxidegl_bind_transform_feedback)
+    /// binds the transform feedback object with name `id` to the current GL state.
+    /// `id` must be a name previously returned from a call to [**glGenTransformFeedbacks**](crate::context::Context::oxidegl_gen_transform_feedbacks).
+    /// If `id` has not previously been bound, a new transform feedback object
+    /// with name `id` and initialized with the default transform state vector
+    /// is created.
+    ///
+    /// In the initial state, a default transform feedback object is bound and
+    /// treated as a transform feedback object with a name of zero. If the name
+    /// zero is subsequently bound, the default transform feedback object is again
+    /// bound to the GL state.
+    ///
+    /// While a transform feedback buffer object is bound, GL operations on the
+    /// target to which it is bound affect the bound transform feedback object,
+    /// and queries of the target to which a transform feedback object is bound
+    /// return state from the bound object. When buffer objects are bound for transform
+    /// feedback, they are attached to the currently bound transform feedback object.
+    /// Buffer objects are used for trans- form feedback only if they are attached
+    /// to the currently bound transform feedback object.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TRANSFORM_FEEDBACK_BINDING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BINDING)
+    pub fn oxidegl_bind_transform_feedback(&mut self, target: GLenum, id: GLuint) -> GlFallible {
+        gl_assert!(target == GL_TRANSFORM_FEEDBACK, InvalidEnum);
+        gl_assert!(
+            !self.gl_state.transform_feedback().capturing(),
+            InvalidOperation,
+            "tried to bind a transform feedback object while transform feedback is active and not paused"
+        );
+        let name = ObjectName::try_from_raw(id).ok();
+        if let Some(name) = name {
+            self.gl_state
+                .transform_feedback_list
+                .ensure_init(name, TransformFeedback::new_default)?;
+        }
+        self.gl_state.transform_feedback_binding = name;
+        gl_debug!("bound {name:?} as current transform feedback object");
+        Ok(())
+    }
+    /// ### Parameters
+    /// `id`
+    ///
+    /// > Specifies a value that may be the name of a transform feedback object.
+    ///
+    /// ### Description
+    /// [**glIsTransformFeedback**](crate::context::Context::oxidegl_is_transform_feedback)
+    /// returns [`GL_TRUE`](crate::gl_enums::GL_TRUE) if `id` is currently the
+    /// name of a transform feedback object. If `id` is zero, or if [`id`](crate::gl_enums::id)
+    /// is not the name of a transform feedback object, or if an error occurs,
+    /// [**glIsTransformFeedback**](crate::context::Context::oxidegl_is_transform_feedback)
+    /// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE). If `id` is a name returned
+    /// by [**glGenTransformFeedbacks**](crate::context::Context::oxidegl_gen_transform_feedbacks),
+    /// but that has not yet been bound through a call to [**glBindTransformFeedback**](crate::context::Context::oxidegl_bind_transform_feedback),
+    /// then the name is not a transform feedback object and [**glIsTransformFeedback**](crate::context::Context::oxidegl_is_transform_feedback)
+    /// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE).
+    pub fn oxidegl_is_transform_feedback(&mut self, id: GLuint) -> GlFallible<GLboolean> {
+        Ok(self.gl_state.transform_feedback_list.is_obj(id))
+    }
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Specifies the number of transform feedback objects to delete.
+    ///
+    /// `ids`
+    ///
+    /// > Specifies an array of names of transform feedback objects to delete.
+    ///
+    /// ### Description
+    /// [**glDeleteTransformFeedbacks**](crate::context::Context::oxidegl_delete_transform_feedbacks)
+    /// deletes the `n` transform feedback objects whose names are stored in the
+    /// array `ids`. Unused names in `ids` are ignored, as is the name zero. After
+    /// a transform feedback object is deleted, its name is again unused and it
+    /// has no contents. If an active transform feedback object is deleted, its
+    /// name immediately becomes unused, but the underlying object is not deleted
+    /// until it is no longer active.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TRANSFORM_FEEDBACK_BINDING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BINDING)
+    pub unsafe fn oxidegl_delete_transform_feedbacks(
+        &mut self,
+        n: GLsizei,
+        ids: *const GLuint,
+    ) -> GlFallible {
+        sizei!(n, len);
+        // Safety: Caller ensures ids points to n object names. Cast from [u32] to [Option<ObjectName>] is valid
+        // because of the niche optimization guarantees of Option
+        let names = unsafe {
+            slice::from_raw_parts(
+                ids.cast::<Option<ObjectName<TransformFeedback>>>(),
+                len as usize,
+            )
+        };
+        for &name in names.iter().flatten() {
+            gl_assert!(
+                self.gl_state
+                    .transform_feedback_list
+                    .get_opt(name)
+                    .is_none_or(|t| t.state.active.is_none()),
+                InvalidOperation,
+                "tried to delete {name:?} while transform feedback is active on it"
+            );
+        }
+        // deleting the current transform feedback object binds the default one in its place
+        if self
+            .gl_state
+            .transform_feedback_binding
+            .is_some_and(|b| names.contains(&Some(b)))
+        {
+            self.gl_state.transform_feedback_binding = None;
+        }
+        // Safety: Caller ensures invariants are upheld
+        unsafe {
+            self.gl_state.transform_feedback_list.delete_objects(n, ids);
+        }
+        Ok(())
+    }
+    /// ### Description
+    /// [**glPauseTransformFeedback**](crate::context::Context::oxidegl_pause_transform_feedback)
+    /// pauses transform feedback operations on the currently active transform
+    /// feedback object. When transform feedback operations are paused, transform
+    /// feedback is still considered active and changing most transform feedback
+    /// state related to the object results in an error. However, a new transform
+    /// feedback object may be bound while transform feedback is paused.
+    pub fn oxidegl_pause_transform_feedback(&mut self) -> GlFallible {
+        let active = self
+            .gl_state
+            .transform_feedback_mut()
+            .active
+            .as_mut()
+            .filter(|a| !a.paused)
+            .ok_or(GlError::InvalidOperation.e())?;
+        active.paused = true;
+        gl_debug!("paused transform feedback");
+        Ok(())
+    }
+    /// ### Description
+    /// [**glResumeTransformFeedback**](crate::context::Context::oxidegl_resume_transform_feedback)
+    /// resumes transform feedback operations on the currently active transform
+    /// feedback object. When transform feedback operations are paused, transform
+    /// feedback is still considered active and changing most transform feedback
+    /// state related to the object results in an error. However, a new transform
+    /// feedback object may be bound while transform feedback is paused.
+    pub fn oxidegl_resume_transform_feedback(&mut self) -> GlFallible {
+        let program = self.gl_state.stage_program(ShaderType::VertexShader);
+        let active = self
+            .gl_state
+            .transform_feedback_mut()
+            .active
+            .as_mut()
+            .filter(|a| a.paused)
+            .ok_or(GlError::InvalidOperation.e())?;
+        gl_assert!(
+            program == Some(active.program),
+            InvalidOperation,
+            "tried to resume transform feedback with a different program than the one it was begun with"
+        );
+        active.paused = false;
+        gl_debug!("resumed transform feedback");
+        Ok(())
+    }
+    /// ### Parameters
+    /// `xfb`
+    ///
+    /// > Name of the transform feedback buffer object.
+    ///
+    /// `index`
+    ///
+    /// > Index of the binding point within `xfb`.
+    ///
+    /// `buffer`
+    ///
+    /// > Name of the buffer object to bind to the specified binding point.
+    ///
+    /// ### Description
+    /// [**glTransformFeedbackBufferBase**](crate::context::Context::oxidegl_transform_feedback_buffer_base)
+    /// binds the buffer object `buffer` to the binding point at index `index`
+    /// of the transform feedback object `xfb`.
+    ///
+    /// ### Notes
+    /// Calling [**glTransformFeedbackBufferBase**](crate::context::Context::oxidegl_transform_feedback_buffer_base)
+    /// is equivalent to calling [**glTransformFeedbackBufferRange**](crate::context::Context::oxidegl_transform_feedback_buffer_range)
+    /// with `offset` zero and `size` equal to the size of `buffer`.
+    pub fn oxidegl_transform_feedback_buffer_base(
+        &mut self,
+        xfb: GLuint,
+        index: GLuint,
+        buffer: GLuint,
+    ) -> GlFallible {
+        self.feedback_buffer_internal(xfb, index, buffer, None)
+    }
+    /// ### Parameters
+    /// `xfb`
+    ///
+    /// > Name of the transform feedback buffer object.
+    ///
+    /// `index`
+    ///
+    /// > Index of the binding point within `xfb`.
+    ///
+    /// `buffer`
+    ///
+    /// > Name of the buffer object to bind to the specified binding point.
+    ///
+    /// `offset`
+    ///
+    /// > The starting offset in basic machine units into the buffer object.
+    ///
+    /// `size`
+    ///
+    /// > The amount of data in basic machine units that can be read from or written
+    /// > to the buffer object while used as an indexed target.
+    ///
+    /// ### Description
+    /// [**glTransformFeedbackBufferRange**](crate::context::Context::oxidegl_transform_feedback_buffer_range)
+    /// binds a range of the buffer object `buffer` represented by `offset` and
+    /// `size` to the binding point at index `index` of the transform feedback
+    /// object `xfb`.
+    ///
+    /// `offset` specifies the offset in basic machine units into the buffer object
+    /// `buffer` and `size` specifies the amount of data that can be read from
+    /// the buffer object while used as an indexed target.
+    ///
+    /// ### Notes
+    pub fn oxidegl_transform_feedback_buffer_range(
+        &mut self,
+        xfb: GLuint,
+        index: GLuint,
+        buffer: GLuint,
+        offset: GLintptr,
+        size: GLsizeiptr,
+    ) -> GlFallible {
+        self.feedback_buffer_internal(xfb, index, buffer, Some((offset, size)))
+    }
+}
+/// ### Parameters
+/// `primitiveMode`
+///
+/// > Specify the output type of the primitives that will be recorded into the
+/// > buffer objects that are bound for transform feedback.
+///
+/// ### Description
+/// Transform feedback mode captures the values of varying variables written
+/// by the vertex shader (or, if active, the geometry shader). Transform feedback
+/// is said to be active after a call to [**glBeginTransformFeedback**](crate::context::Context::oxidegl_begin_transform_feedback)
+/// until a subsequent call to [**glEndTransformFeedback**](crate::context::Context::oxidegl_end_transform_feedback).
+/// Transform feedback commands must be paired.
+///
+/// If no geometry shader is present, while transform feedback is active the
+/// `mode` parameter to [**glDrawArrays**](crate::context::Context::oxidegl_draw_arrays)
+/// must match those specified in the following table:
+///
+/// |* Transform Feedback*                              |* Allowed Render Primitive*                                 |
+/// |---------------------------------------------------|------------------------------------------------------------|
+/// | [`GL_POINTS`](crate::gl_enums::GL_POINTS)         | [`GL_POINTS`](crate::gl_enums::GL_POINTS)                  |
+/// | [`GL_LINES`](crate::gl_enums::GL_LINES)           | [`GL_LINES`](crate::gl_enums::GL_LINES), [`GL_LINE_LOOP`](crate::gl_enums::GL_LINE_LOOP), [`GL_LINE_STRIP`](crate::gl_enums::GL_LINE_STRIP), [`GL_LINES_ADJACENCY`](crate::gl_enums::GL_LINES_ADJACENCY), [`GL_LINE_STRIP_ADJACENCY`](crate::gl_enums::GL_LINE_STRIP_ADJACENCY) |
+/// | [`GL_TRIANGLES`](crate::gl_enums::GL_TRIANGLES)   | [`GL_TRIANGLES`](crate::gl_enums::GL_TRIANGLES), [`GL_TRIANGLE_STRIP`](crate::gl_enums::GL_TRIANGLE_STRIP), [`GL_TRIANGLE_FAN`](crate::gl_enums::GL_TRIANGLE_FAN), [`GL_TRIANGLES_ADJACENCY`](crate::gl_enums::GL_TRIANGLES_ADJACENCY), [`GL_TRIANGLE_STRIP_ADJACENCY`](crate::gl_enums::GL_TRIANGLE_STRIP_ADJACENCY) |
+///
+/// If a geometry shader is present, the output primitive type from the geometry
+/// shader must match those provided in the following table:
+///
+/// |* Transform Feedback*                              |* Allowed Geometry Shader Output Primitive Type*                                           |
+/// |---------------------------------------------------|-------------------------------------------------------------------------------------------|
+/// | [`GL_POINTS`](crate::gl_enums::GL_POINTS)         | [`points`](crate::gl_enums::points)                                                       |
+/// | [`GL_LINES`](crate::gl_enums::GL_LINES)           | [`line_strip`](crate::gl_enums::line_strip)                                               |
+/// | [`GL_TRIANGLES`](crate::gl_enums::GL_TRIANGLES)   | [`triangle_strip`](crate::gl_enums::triangle_strip)                                       |
+///
+/// ### Notes
+/// Geometry shaders, and the [`GL_TRIANGLES_ADJACENCY`](crate::gl_enums::GL_TRIANGLES_ADJACENCY),
+/// [`GL_TRIANGLE_STRIP_ADJACENCY`](crate::gl_enums::GL_TRIANGLE_STRIP_ADJACENCY),
+/// [`GL_LINES_ADJACENCY`](crate::gl_enums::GL_LINES_ADJACENCY) and [`GL_LINE_STRIP_ADJACENCY`](crate::gl_enums::GL_LINE_STRIP_ADJACENCY)
+/// primtive modes are available only if the GL version is 3.2 or greater.
+impl Context {
+    pub fn oxidegl_begin_transform_feedback(
+        &mut self,
+        primitive_mode: PrimitiveType,
+    ) -> GlFallible {
+        gl_assert!(
+            matches!(
+                primitive_mode,
+                PrimitiveType::Points | PrimitiveType::Lines | PrimitiveType::Triangles
+            ),
+            InvalidEnum
+        );
+        gl_assert!(
+            self.gl_state.transform_feedback().active.is_none(),
+            InvalidOperation,
+            "transform feedback is already active"
+        );
+        let Some(program) = self.gl_state.stage_program(ShaderType::VertexShader) else {
+            return Err(GlError::InvalidOperation.e());
+        };
+        let strides = self
+            .gl_state
+            .program_list
+            .get_mut(program)
+            .linkage()
+            .and_then(|l| l.feedback.as_ref())
+            .map(|f| f.layout.strides.clone());
+        // the program must capture some varyings
+        let Some(strides) = strides else {
+            return Err(GlError::InvalidOperation.e());
+        };
+        let state = self.gl_state.transform_feedback_mut();
+        for (i, stride) in strides.into_iter().enumerate() {
+            gl_assert!(
+                stride == 0 || state.buffers[i].is_some(),
+                InvalidOperation,
+                "transform feedback buffer binding {i} has no buffer bound"
+            );
+        }
+        state.active = Some(ActiveFeedback {
+            primitive_mode,
+            program,
+            paused: false,
+            vertices: 0,
+        });
+        gl_debug!("began capturing {primitive_mode:?} with transform feedback");
+        Ok(())
+    }
+    pub fn oxidegl_end_transform_feedback(&mut self) -> GlFallible {
+        let state = self.gl_state.transform_feedback_mut();
+        let Some(active) = state.active.take() else {
+            return Err(GlError::InvalidOperation.e());
+        };
+        state.captured_vertices = Some(active.vertices);
+        gl_debug!(
+            "ended transform feedback after capturing {} vertices",
+            active.vertices
+        );
+        Ok(())
+    }
+}
+/// ### Parameters
+/// `xfb`
+///
+/// > The name of an existing transform feedback object, or zero for the default
+/// > transform feedback object.
+///
+/// `pname`
+///
+/// > Property to use for the query. Must be one of the values: [`GL_TRANSFORM_FEEDBACK_BUFFER_BINDING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_BINDING),
+/// > [`GL_TRANSFORM_FEEDBACK_BUFFER_START`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_START),
+/// > [`GL_TRANSFORM_FEEDBACK_BUFFER_SIZE`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_SIZE),
+/// > [`GL_TRANSFORM_FEEDBACK_PAUSED`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PAUSED),
+/// > [`GL_TRANSFORM_FEEDBACK_ACTIVE`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_ACTIVE).
+///
+/// `index`
+///
+/// > Index of the transform feedback stream (for indexed state).
+///
+/// `param`
+///
+/// > The address of a buffer into which will be written the requested state
+/// > information.
+///
+/// ### Description
+/// In order to use the Transform Feedback functionality, you need to configure
+/// the Transform Feedback Buffer indexed bindings. This can be achieved by
+/// either using [**glBindBufferBase**](crate::context::Context::oxidegl_bind_buffer_base)
+/// or [**glBindBuffersBase**](crate::context::Context::oxidegl_bind_buffers_base)
+/// to associate whole buffer object storage to one of the Transform Feedback
+/// Binding Points, or by calling [**glBindBufferRange**](crate::context::Context::oxidegl_bind_buffer_range)
+/// or [**glBindBuffersRange**](crate::context::Context::oxidegl_bind_buffers_range)
+/// to use a region of a buffer object storage for the binding. You may want
+/// to (but are not required to) bind a Transform Feedback Object first, in
+/// order to cache the binding configuration. This usually allows you to restore
+/// the Transform Feedback configuration faster, than if you were to execute
+/// a list of API calls necessary to set up the Transform Feedback state of
+/// your liking.
+///
+/// This reference page discusses two types of getters that operate on Transform
+/// Feedback Objects and their bindings.
+///
+/// The first class operates on general Transform Feedback binding point and
+/// includes [**glGetTransformFeedbackiv**](crate::context::Context::oxidegl_get_transform_feedbackiv)
+/// function. [**glGetTransformFeedbackiv**](crate::context::Context::oxidegl_get_transform_feedbackiv)
+/// can be used to retrieve information about Transform Feedback object bound
+/// to the general Transform Feedback binding point, as configured with a [**glBindTransformFeedback**](crate::context::Context::oxidegl_bind_transform_feedback)
+/// call. In this case, you can check:
+///
+/// > What the ID of the currently bound Transform Feedback Object is;( [`GL_TRANSFORM_FEEDBACK_BUFFER_BINDING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_BINDING))
+///
+/// > Whether the Transform Feedback process is currently paused;( [`GL_TRANSFORM_FEEDBACK_PAUSED`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PAUSED))
+///
+/// > Whether the Transform Feedback process has been begun and is currently
+/// > undergoing;( [`GL_TRANSFORM_FEEDBACK_ACTIVE`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_ACTIVE))
+///
+///
+/// The latter class, which includes [**`glGetTransformFeedbacki_v`**](crate::context::Context::oxidegl_get_transform_feedbacki_v)
+/// and [**`glGetTransformFeedbacki64_v`**](crate::context::Context::oxidegl_get_transform_feedbacki64_v)
+/// functions, can be used to check what the current configuration of each
+/// of the buffer object regions bound to Transform Feedback Buffer binding
+/// points is. This allows you to query for the following information:
+///
+/// > [**glGetTransformFeedbacki_v**](crate::context::Context::oxidegl_get_transform_feedbacki_v)
+/// > only: What the ID of the Buffer Object bound to a Transform Feedback Binding
+/// > Point of user-specified index is;( [`GL_TRANSFORM_FEEDBACK_BUFFER_BINDING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_BINDING)
+/// > ).
+///
+/// > [**glGetTransformFeedbacki64_v**](crate::context::Context::oxidegl_get_transform_feedbacki64_v)
+/// > only: What the start offset configured for the binding is;( [`GL_TRANSFORM_FEEDBACK_BUFFER_START`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_START)
+/// > );
+///
+/// > [**glGetTransformFeedbacki64_v**](crate::context::Context::oxidegl_get_transform_feedbacki64_v)
+/// > only: What the length of the region used for the binding is;( [`GL_TRANSFORM_FEEDBACK_BUFFER_SIZE`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_SIZE)
+/// > );
+///
+impl Context {
+    pub unsafe fn oxidegl_get_transform_feedbackiv(
+        &mut self,
+        xfb: GLuint,
+        pname: TransformFeedbackPName,
+        param: *mut GLint,
+    ) -> GlFallible {
+        let state = self.feedback_object(xfb)?;
+        let value = match pname {
+            TransformFeedbackPName::TransformFeedbackActive => state.active.is_some(),
+            TransformFeedbackPName::TransformFeedbackPaused => {
+                state.active.is_some_and(|a| a.paused)
+            }
+            _ => return Err(GlError::InvalidEnum.e()),
+        };
+        // Safety: Caller ensures param is valid for a write
+        unsafe { value.write_out(param) };
+        Ok(())
+    }
+    pub unsafe fn oxidegl_get_transform_feedbacki_v(
+        &mut self,
+        xfb: GLuint,
+        pname: TransformFeedbackPName,
+        index: GLuint,
+        param: *mut GLint,
+    ) -> GlFallible {
+        gl_assert!(
+            pname == TransformFeedbackPName::TransformFeedbackBufferBinding,
+            InvalidEnum
+        );
+        let state = self.feedback_object(xfb)?;
+        let Some(&buffer) = state.buffers.get(index as usize) else {
+            return Err(GlError::InvalidValue.e());
+        };
+        // Safety: Caller ensures param is valid for a write
+        unsafe { buffer.write_out(param) };
+        Ok(())
+    }
+    pub unsafe fn oxidegl_get_transform_feedbacki64_v(
+        &mut self,
+        xfb: GLuint,
+        pname: TransformFeedbackPName,
+        index: GLuint,
+        param: *mut GLint64,
+    ) -> GlFallible {
+        let state = self.feedback_object(xfb)?;
+        let Some(&range) = state.ranges.get(index as usize) else {
+            return Err(GlError::InvalidValue.e());
+        };
+        let value = match pname {
+            TransformFeedbackPName::TransformFeedbackBufferStart => {
+                range.map_or(0, |(offset, _)| offset)
+            }
+            TransformFeedbackPName::TransformFeedbackBufferSize => {
+                range.map_or(0, |(_, size)| size)
+            }
+            _ => return Err(GlError::InvalidEnum.e()),
+        };
+        // Safety: Caller ensures param is valid for a write
+        unsafe { value.write_out(param) };
+        Ok(())
+    }
+}
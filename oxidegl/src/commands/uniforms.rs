@@ -0,0 +1,1600 @@
+use std::slice;
+
+use crate::{
+    commands::programs::ProgramListExt,
+    context::Context,
+    conversions::sizei,
+    error::{GlError, GlFallible},
+    gl_types::{GLboolean, GLdouble, GLfloat, GLint, GLsizei, GLuint},
+    program::default_block::UniformKind,
+};
+
+/// ### Parameters
+/// `location`
+///
+/// > Specifies the location of the uniform variable to be modified.
+///
+/// `count`
+///
+/// > For the vector( [**glUniform*v**](crate::context::Context::oxidegl_uniform*v))
+/// > commands, specifies the number of elements that are to be modified. This
+/// > should be 1 if the targeted uniform variable is not an array, and 1 or
+/// > more if it is an array.
+///
+/// > For the matrix( [**glUniformMatrix***](crate::context::Context::oxidegl_uniform_matrix*))
+/// > commands, specifies the number of matrices that are to be modified. This
+/// > should be 1 if the targeted uniform variable is not an array of matrices,
+/// > and 1 or more if it is an array of matrices.
+///
+/// `transpose`
+///
+/// > For the matrix commands, specifies whether to transpose the matrix as the
+/// > values are loaded into the uniform variable.
+///
+/// `v0`, `v1`, `v2`, `v3`
+///
+/// > For the scalar commands, specifies the new values to be used for the specified
+/// > uniform variable.
+///
+/// `value`
+///
+/// > For the vector and matrix commands, specifies a pointer to an array of
+/// > `count` values that will be used to update the specified uniform variable.
+///
+/// ### Description
+/// [**glUniform**](crate::context::Context::oxidegl_uniform) modifies the
+/// value of a uniform variable or a uniform variable array. The location of
+/// the uniform variable to be modified is specified by `location`, which should
+/// be a value returned by [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location).
+/// [**glUniform**](crate::context::Context::oxidegl_uniform) operates on the
+/// program object that was made part of current state by calling [**glUseProgram**](crate::context::Context::oxidegl_use_program).
+///
+/// The commands [**glUniform{1|2|3|4}{f|i|ui}**](crate::context::Context::oxidegl_uniform{1|2|3|4}{f|i|ui})
+/// are used to change the value of the uniform variable specified by `location`
+/// using the values passed as arguments. The number specified in the command
+/// should match the number of components in the data type of the specified
+/// uniform variable (e.g., `1` for `2`
+/// for `f` indicates that floating-point
+/// values are being passed; the suffix `i`
+/// indicates that integer values are being passed; the suffix `ui`
+/// indicates that unsigned integer values are being passed, and this type
+/// should also match the data type of the specified uniform variable. The
+/// `i` variants of this function should
+/// be used to provide values for uniform variables defined as `ui`
+/// variants of this function should be used to provide values for uniform
+/// variables defined as `f` variants
+/// should be used to provide values for uniform variables of type `i`,
+/// `ui` or `f`
+/// variants may be used to provide values for uniform variables of type
+///
+/// All active uniform variables defined in a program object are initialized
+/// to 0 when the program object is linked successfully. They retain the values
+/// assigned to them by a call to [**glUniform **](crate::context::Context::oxidegl_uniform
+///) until the next successful link operation occurs on the program object,
+/// when they are once again initialized to 0.
+///
+/// The commands [**glUniform{1|2|3|4}{f|i|ui}v**](crate::context::Context::oxidegl_uniform{1|2|3|4}{f|i|ui}v)
+/// can be used to modify a single uniform variable or a uniform variable array.
+/// These commands pass a count and a pointer to the values to be loaded into
+/// a uniform variable or a uniform variable array. A count of 1 should be
+/// used if modifying the value of a single uniform variable, and a count of
+/// 1 or greater can be used to modify an entire array or part of an array.
+/// When loading *n* elements starting at an arbitrary position *m* in a uniform
+/// variable array, elements *m*+ *n*- 1 in the array will be replaced with
+/// the new values. If `m`+ `n`- 1 is larger than the size of the uniform variable
+/// array, values for all array elements beyond the end of the array will be
+/// ignored. The number specified in the name of the command indicates the
+/// number of components for each element in `value`, and it should match the
+/// number of components in the data type of the specified uniform variable
+/// (e.g., `1` for float, int, bool; `2`
+/// for vec2, ivec2, bvec2, etc.). The data type specified in the name of the
+/// command must match the data type for the specified uniform variable as
+/// described previously for [**glUniform{1|2|3|4}{f|i|ui}**](crate::context::Context::oxidegl_uniform{1|2|3|4}{f|i|ui}).
+///
+/// For uniform variable arrays, each element of the array is considered to
+/// be of the type indicated in the name of the command (e.g., [**glUniform3f**](crate::context::Context::oxidegl_uniform3f)
+/// or [**glUniform3fv**](crate::context::Context::oxidegl_uniform3fv) can
+/// be used to load a uniform variable array of type vec3). The number of elements
+/// of the uniform variable array to be modified is specified by `count`
+///
+/// The commands [**glUniformMatrix{2|3|4|2x3|3x2|2x4|4x2|3x4|4x3}fv**](crate::context::Context::oxidegl_uniform_matrix{2|3|4|2x3|3x2|2x4|4x2|3x4|4x3}fv)
+/// are used to modify a matrix or an array of matrices. The numbers in the
+/// command name are interpreted as the dimensionality of the matrix. The number
+/// `2` indicates a 2 × 2 matrix (i.e.,
+/// 4 values), the number `3` indicates
+/// a 3 × 3 matrix (i.e., 9 values), and the number `4`
+/// indicates a 4 × 4 matrix (i.e., 16 values). Non-square matrix dimensionality
+/// is explicit, with the first number representing the number of columns and
+/// the second number representing the number of rows. For example, `2x4`
+/// indicates a 2 × 4 matrix with 2 columns and 4 rows (i.e., 8 values). If
+/// `transpose` is [`GL_FALSE`](crate::gl_enums::GL_FALSE), each matrix is
+/// assumed to be supplied in column major order. If `transpose` is [`GL_TRUE`](crate::gl_enums::GL_TRUE),
+/// each matrix is assumed to be supplied in row major order. The `count`
+/// argument indicates the number of matrices to be passed. A count of 1 should
+/// be used if modifying the value of a single matrix, and a count greater
+/// than 1 can be used to modify an array of matrices.
+///
+/// ### Notes
+/// [**glUniform1i**](crate::context::Context::oxidegl_uniform1i) and [**glUniform1iv**](crate::context::Context::oxidegl_uniform1iv)
+/// are the only two functions that may be used to load uniform variables defined
+/// as sampler types. Loading samplers with any other function will result
+/// in a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION) error.
+///
+/// If `count` is greater than 1 and the indicated uniform variable is not
+/// an array, a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION)
+/// error is generated and the specified uniform variable will remain unchanged.
+///
+/// Other than the preceding exceptions, if the type and size of the uniform
+/// variable as defined in the shader do not match the type and size specified
+/// in the name of the command used to load its value, a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION)
+/// error will be generated and the specified uniform variable will remain
+/// unchanged.
+///
+/// If `location` is a value other than -1 and it does not represent a valid
+/// uniform variable location in the current program object, an error will
+/// be generated, and no changes will be made to the uniform variable storage
+/// of the current program object. If `location` is equal to -1, the data passed
+/// in will be silently ignored and the specified uniform variable will not
+/// be changed.
+///
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with the argument [`GL_CURRENT_PROGRAM`](crate::gl_enums::GL_CURRENT_PROGRAM)
+///
+/// [**glGetActiveUniform**](crate::context::Context::oxidegl_get_active_uniform)
+/// with the handle of a program object and the index of an active uniform
+/// variable
+///
+/// [**glGetUniform**](crate::context::Context::oxidegl_get_uniform) with the
+/// handle of a program object and the location of a uniform variable
+///
+/// [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location)
+/// with the handle of a program object and the name of a uniform variable
+impl Context {
+    /// Set the `float` uniform at `location` of the current program
+    pub fn oxidegl_uniform1f(&mut self, location: GLint, v0: GLfloat) -> GlFallible {
+        let value = [v0];
+        // Safety: value holds a single value
+        unsafe { self.set_uniform(None, location, 1, (1, 1), false, value.as_ptr()) }
+    }
+    /// Set the `vec2` uniform at `location` of the current program
+    pub fn oxidegl_uniform2f(&mut self, location: GLint, v0: GLfloat, v1: GLfloat) -> GlFallible {
+        let value = [v0, v1];
+        // Safety: value holds a single 2 component vector
+        unsafe { self.set_uniform(None, location, 1, (1, 2), false, value.as_ptr()) }
+    }
+    /// Set the `vec3` uniform at `location` of the current program
+    pub fn oxidegl_uniform3f(
+        &mut self,
+        location: GLint,
+        v0: GLfloat,
+        v1: GLfloat,
+        v2: GLfloat,
+    ) -> GlFallible {
+        let value = [v0, v1, v2];
+        // Safety: value holds a single 3 component vector
+        unsafe { self.set_uniform(None, location, 1, (1, 3), false, value.as_ptr()) }
+    }
+    /// Set the `vec4` uniform at `location` of the current program
+    pub fn oxidegl_uniform4f(
+        &mut self,
+        location: GLint,
+        v0: GLfloat,
+        v1: GLfloat,
+        v2: GLfloat,
+        v3: GLfloat,
+    ) -> GlFallible {
+        let value = [v0, v1, v2, v3];
+        // Safety: value holds a single 4 component vector
+        unsafe { self.set_uniform(None, location, 1, (1, 4), false, value.as_ptr()) }
+    }
+    /// Set the `int` (or sampler or image) uniform at `location` of the current program
+    pub fn oxidegl_uniform1i(&mut self, location: GLint, v0: GLint) -> GlFallible {
+        let value = [v0];
+        // Safety: value holds a single value
+        unsafe { self.set_uniform(None, location, 1, (1, 1), false, value.as_ptr()) }
+    }
+    /// Set the `ivec2` uniform at `location` of the current program
+    pub fn oxidegl_uniform2i(&mut self, location: GLint, v0: GLint, v1: GLint) -> GlFallible {
+        let value = [v0, v1];
+        // Safety: value holds a single 2 component vector
+        unsafe { self.set_uniform(None, location, 1, (1, 2), false, value.as_ptr()) }
+    }
+    /// Set the `ivec3` uniform at `location` of the current program
+    pub fn oxidegl_uniform3i(
+        &mut self,
+        location: GLint,
+        v0: GLint,
+        v1: GLint,
+        v2: GLint,
+    ) -> GlFallible {
+        let value = [v0, v1, v2];
+        // Safety: value holds a single 3 component vector
+        unsafe { self.set_uniform(None, location, 1, (1, 3), false, value.as_ptr()) }
+    }
+    /// Set the `ivec4` uniform at `location` of the current program
+    pub fn oxidegl_uniform4i(
+        &mut self,
+        location: GLint,
+        v0: GLint,
+        v1: GLint,
+        v2: GLint,
+        v3: GLint,
+    ) -> GlFallible {
+        let value = [v0, v1, v2, v3];
+        // Safety: value holds a single 4 component vector
+        unsafe { self.set_uniform(None, location, 1, (1, 4), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `float` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform1fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid values
+        unsafe { self.set_uniform(None, location, count, (1, 1), false, value) }
+    }
+    /// Set `count` elements of the `vec2` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform2fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2 component vectors
+        unsafe { self.set_uniform(None, location, count, (1, 2), false, value) }
+    }
+    /// Set `count` elements of the `vec3` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform3fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3 component vectors
+        unsafe { self.set_uniform(None, location, count, (1, 3), false, value) }
+    }
+    /// Set `count` elements of the `vec4` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform4fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4 component vectors
+        unsafe { self.set_uniform(None, location, count, (1, 4), false, value) }
+    }
+    /// Set `count` elements of the `int` (or sampler or image) uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform1iv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid values
+        unsafe { self.set_uniform(None, location, count, (1, 1), false, value) }
+    }
+    /// Set `count` elements of the `ivec2` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform2iv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2 component vectors
+        unsafe { self.set_uniform(None, location, count, (1, 2), false, value) }
+    }
+    /// Set `count` elements of the `ivec3` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform3iv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3 component vectors
+        unsafe { self.set_uniform(None, location, count, (1, 3), false, value) }
+    }
+    /// Set `count` elements of the `ivec4` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform4iv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4 component vectors
+        unsafe { self.set_uniform(None, location, count, (1, 4), false, value) }
+    }
+    /// Set `count` elements of the `mat2` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix2fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2x2 matrices
+        unsafe { self.set_uniform(None, location, count, (2, 2), transpose, value) }
+    }
+    /// Set `count` elements of the `mat3` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix3fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3x3 matrices
+        unsafe { self.set_uniform(None, location, count, (3, 3), transpose, value) }
+    }
+    /// Set `count` elements of the `mat4` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix4fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4x4 matrices
+        unsafe { self.set_uniform(None, location, count, (4, 4), transpose, value) }
+    }
+    /// Set `count` elements of the `mat2x3` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix2x3fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2x3 matrices
+        unsafe { self.set_uniform(None, location, count, (2, 3), transpose, value) }
+    }
+    /// Set `count` elements of the `mat3x2` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix3x2fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3x2 matrices
+        unsafe { self.set_uniform(None, location, count, (3, 2), transpose, value) }
+    }
+    /// Set `count` elements of the `mat2x4` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix2x4fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2x4 matrices
+        unsafe { self.set_uniform(None, location, count, (2, 4), transpose, value) }
+    }
+    /// Set `count` elements of the `mat4x2` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix4x2fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4x2 matrices
+        unsafe { self.set_uniform(None, location, count, (4, 2), transpose, value) }
+    }
+    /// Set `count` elements of the `mat3x4` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix3x4fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3x4 matrices
+        unsafe { self.set_uniform(None, location, count, (3, 4), transpose, value) }
+    }
+    /// Set `count` elements of the `mat4x3` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix4x3fv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4x3 matrices
+        unsafe { self.set_uniform(None, location, count, (4, 3), transpose, value) }
+    }
+    /// Set the `uint` uniform at `location` of the current program
+    pub fn oxidegl_uniform1ui(&mut self, location: GLint, v0: GLuint) -> GlFallible {
+        let value = [v0];
+        // Safety: value holds a single value
+        unsafe { self.set_uniform(None, location, 1, (1, 1), false, value.as_ptr()) }
+    }
+    /// Set the `uvec2` uniform at `location` of the current program
+    pub fn oxidegl_uniform2ui(&mut self, location: GLint, v0: GLuint, v1: GLuint) -> GlFallible {
+        let value = [v0, v1];
+        // Safety: value holds a single 2 component vector
+        unsafe { self.set_uniform(None, location, 1, (1, 2), false, value.as_ptr()) }
+    }
+    /// Set the `uvec3` uniform at `location` of the current program
+    pub fn oxidegl_uniform3ui(
+        &mut self,
+        location: GLint,
+        v0: GLuint,
+        v1: GLuint,
+        v2: GLuint,
+    ) -> GlFallible {
+        let value = [v0, v1, v2];
+        // Safety: value holds a single 3 component vector
+        unsafe { self.set_uniform(None, location, 1, (1, 3), false, value.as_ptr()) }
+    }
+    /// Set the `uvec4` uniform at `location` of the current program
+    pub fn oxidegl_uniform4ui(
+        &mut self,
+        location: GLint,
+        v0: GLuint,
+        v1: GLuint,
+        v2: GLuint,
+        v3: GLuint,
+    ) -> GlFallible {
+        let value = [v0, v1, v2, v3];
+        // Safety: value holds a single 4 component vector
+        unsafe { self.set_uniform(None, location, 1, (1, 4), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `uint` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform1uiv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid values
+        unsafe { self.set_uniform(None, location, count, (1, 1), false, value) }
+    }
+    /// Set `count` elements of the `uvec2` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform2uiv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2 component vectors
+        unsafe { self.set_uniform(None, location, count, (1, 2), false, value) }
+    }
+    /// Set `count` elements of the `uvec3` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform3uiv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3 component vectors
+        unsafe { self.set_uniform(None, location, count, (1, 3), false, value) }
+    }
+    /// Set `count` elements of the `uvec4` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform4uiv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4 component vectors
+        unsafe { self.set_uniform(None, location, count, (1, 4), false, value) }
+    }
+}
+/// ### Parameters
+/// `program`
+///
+/// > Specifies the handle of the program containing the uniform variable to
+/// > be modified.
+///
+/// `location`
+///
+/// > Specifies the location of the uniform variable to be modified.
+///
+/// `count`
+///
+/// > For the vector commands( [**glProgramUniform*v**](crate::context::Context::oxidegl_program_uniform*v)
+/// > ), specifies the number of elements that are to be modified. This should
+/// > be 1 if the targeted uniform variable is not an array, and 1 or more if
+/// > it is an array.
+///
+/// > For the matrix commands( [**glProgramUniformMatrix***](crate::context::Context::oxidegl_program_uniform_matrix*)
+/// > ), specifies the number of matrices that are to be modified. This should
+/// > be 1 if the targeted uniform variable is not an array of matrices, and
+/// > 1 or more if it is an array of matrices.
+///
+/// `transpose`
+///
+/// > For the matrix commands, specifies whether to transpose the matrix as the
+/// > values are loaded into the uniform variable.
+///
+/// `v0`, `v1`, `v2`, `v3`
+///
+/// > For the scalar commands, specifies the new values to be used for the specified
+/// > uniform variable.
+///
+/// `value`
+///
+/// > For the vector and matrix commands, specifies a pointer to an array of
+/// > `count` values that will be used to update the specified uniform variable.
+///
+/// ### Description
+/// [**glProgramUniform**](crate::context::Context::oxidegl_program_uniform)
+/// modifies the value of a uniform variable or a uniform variable array. The
+/// location of the uniform variable to be modified is specified by `location`,
+/// which should be a value returned by [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location).
+/// [**glProgramUniform**](crate::context::Context::oxidegl_program_uniform)
+/// operates on the program object specified by `program`.
+///
+/// The commands [**glProgramUniform{1|2|3|4}{f|i|ui}**](crate::context::Context::oxidegl_program_uniform{1|2|3|4}{f|i|ui})
+/// are used to change the value of the uniform variable specified by `location`
+/// using the values passed as arguments. The number specified in the command
+/// should match the number of components in the data type of the specified
+/// uniform variable (e.g., `1` for `2`
+/// for `f` indicates that floating-point
+/// values are being passed; the suffix `i`
+/// indicates that integer values are being passed; the suffix `ui`
+/// indicates that unsigned integer values are being passed, and this type
+/// should also match the data type of the specified uniform variable. The
+/// `i` variants of this function should
+/// be used to provide values for uniform variables defined as `ui`
+/// variants of this function should be used to provide values for uniform
+/// variables defined as `f` variants
+/// should be used to provide values for uniform variables of type `i`,
+/// `ui` or `f`
+/// variants may be used to provide values for uniform variables of type
+///
+/// All active uniform variables defined in a program object are initialized
+/// to 0 when the program object is linked successfully. They retain the values
+/// assigned to them by a call to [**glProgramUniform**](crate::context::Context::oxidegl_program_uniform)
+/// until the next successful link operation occurs on the program object,
+/// when they are once again initialized to 0.
+///
+/// The commands [**glProgramUniform{1|2|3|4}{f|i|ui}v**](crate::context::Context::oxidegl_program_uniform{1|2|3|4}{f|i|ui}v)
+/// can be used to modify a single uniform variable or a uniform variable array.
+/// These commands pass a count and a pointer to the values to be loaded into
+/// a uniform variable or a uniform variable array. A count of 1 should be
+/// used if modifying the value of a single uniform variable, and a count of
+/// 1 or greater can be used to modify an entire array or part of an array.
+/// When loading *n* elements starting at an arbitrary position *m* in a uniform
+/// variable array, elements *m*+ *n*- 1 in the array will be replaced with
+/// the new values. If `m`+ `n`- 1 is larger than the size of the uniform variable
+/// array, values for all array elements beyond the end of the array will be
+/// ignored. The number specified in the name of the command indicates the
+/// number of components for each element in `value`, and it should match the
+/// number of components in the data type of the specified uniform variable
+/// (e.g., `1` for `2`
+/// for [**glProgramUniform{1|2|3|4}{f|i|ui}**](crate::context::Context::oxidegl_program_uniform{1|2|3|4}{f|i|ui}).
+///
+/// For uniform variable arrays, each element of the array is considered to
+/// be of the type indicated in the name of the command (e.g., [**glProgramUniform3f**](crate::context::Context::oxidegl_program_uniform3f)
+/// or [**glProgramUniform3fv**](crate::context::Context::oxidegl_program_uniform3fv)
+/// can be used to load a uniform variable array of type vec3). The number
+/// of elements of the uniform variable array to be modified is specified by
+/// `count`
+///
+/// The commands [**glProgramUniformMatrix{2|3|4|2x3|3x2|2x4|4x2|3x4|4x3}fv**](crate::context::Context::oxidegl_program_uniform_matrix{2|3|4|2x3|3x2|2x4|4x2|3x4|4x3}fv)
+/// are used to modify a matrix or an array of matrices. The numbers in the
+/// command name are interpreted as the dimensionality of the matrix. The number
+/// `2` indicates a 2 × 2 matrix (i.e.,
+/// 4 values), the number `3` indicates
+/// a 3 × 3 matrix (i.e., 9 values), and the number `4`
+/// indicates a 4 × 4 matrix (i.e., 16 values). Non-square matrix dimensionality
+/// is explicit, with the first number representing the number of columns and
+/// the second number representing the number of rows. For example, `2x4`
+/// indicates a 2 × 4 matrix with 2 columns and 4 rows (i.e., 8 values). If
+/// `transpose` is [`GL_FALSE`](crate::gl_enums::GL_FALSE), each matrix is
+/// assumed to be supplied in column major order. If `transpose` is [`GL_TRUE`](crate::gl_enums::GL_TRUE),
+/// each matrix is assumed to be supplied in row major order. The `count`
+/// argument indicates the number of matrices to be passed. A count of 1 should
+/// be used if modifying the value of a single matrix, and a count greater
+/// than 1 can be used to modify an array of matrices.
+///
+/// ### Notes
+/// [**glProgramUniform1i**](crate::context::Context::oxidegl_program_uniform1i)
+/// and [**glProgramUniform1iv**](crate::context::Context::oxidegl_program_uniform1iv)
+/// are the only two functions that may be used to load uniform variables defined
+/// as sampler types. Loading samplers with any other function will result
+/// in a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION) error.
+///
+/// If `count` is greater than 1 and the indicated uniform variable is not
+/// an array, a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION)
+/// error is generated and the specified uniform variable will remain unchanged.
+///
+/// Other than the preceding exceptions, if the type and size of the uniform
+/// variable as defined in the shader do not match the type and size specified
+/// in the name of the command used to load its value, a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION)
+/// error will be generated and the specified uniform variable will remain
+/// unchanged.
+///
+/// If `location` is a value other than -1 and it does not represent a valid
+/// uniform variable location in within `program`, an error will be generated,
+/// and no changes will be made to the uniform variable storage of `program`.
+/// If `location` is equal to -1, the data passed in will be silently ignored
+/// and the specified uniform variable will not be changed.
+///
+/// ### Associated Gets
+/// [**glGetActiveUniform**](crate::context::Context::oxidegl_get_active_uniform)
+/// with the handle of a program object and the index of an active uniform
+/// variable
+///
+/// [**glGetUniform**](crate::context::Context::oxidegl_get_uniform) with the
+/// handle of a program object and the location of a uniform variable
+///
+/// [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location)
+/// with the handle of a program object and the name of a uniform variable
+impl Context {
+    /// Set the `int` (or sampler or image) uniform at `location` of `program`
+    pub fn oxidegl_program_uniform1i(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLint,
+    ) -> GlFallible {
+        let value = [v0];
+        // Safety: value holds a single value
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 1), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `int` (or sampler or image) uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform1iv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid values
+        unsafe { self.set_uniform(Some(program), location, count, (1, 1), false, value) }
+    }
+    /// Set the `float` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform1f(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLfloat,
+    ) -> GlFallible {
+        let value = [v0];
+        // Safety: value holds a single value
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 1), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `float` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform1fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid values
+        unsafe { self.set_uniform(Some(program), location, count, (1, 1), false, value) }
+    }
+    /// Set the `uint` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform1ui(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLuint,
+    ) -> GlFallible {
+        let value = [v0];
+        // Safety: value holds a single value
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 1), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `uint` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform1uiv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid values
+        unsafe { self.set_uniform(Some(program), location, count, (1, 1), false, value) }
+    }
+    /// Set the `ivec2` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform2i(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLint,
+        v1: GLint,
+    ) -> GlFallible {
+        let value = [v0, v1];
+        // Safety: value holds a single 2 component vector
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 2), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `ivec2` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform2iv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2 component vectors
+        unsafe { self.set_uniform(Some(program), location, count, (1, 2), false, value) }
+    }
+    /// Set the `vec2` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform2f(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLfloat,
+        v1: GLfloat,
+    ) -> GlFallible {
+        let value = [v0, v1];
+        // Safety: value holds a single 2 component vector
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 2), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `vec2` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform2fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2 component vectors
+        unsafe { self.set_uniform(Some(program), location, count, (1, 2), false, value) }
+    }
+    /// Set the `uvec2` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform2ui(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLuint,
+        v1: GLuint,
+    ) -> GlFallible {
+        let value = [v0, v1];
+        // Safety: value holds a single 2 component vector
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 2), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `uvec2` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform2uiv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2 component vectors
+        unsafe { self.set_uniform(Some(program), location, count, (1, 2), false, value) }
+    }
+    /// Set the `ivec3` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform3i(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLint,
+        v1: GLint,
+        v2: GLint,
+    ) -> GlFallible {
+        let value = [v0, v1, v2];
+        // Safety: value holds a single 3 component vector
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 3), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `ivec3` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform3iv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3 component vectors
+        unsafe { self.set_uniform(Some(program), location, count, (1, 3), false, value) }
+    }
+    /// Set the `vec3` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform3f(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLfloat,
+        v1: GLfloat,
+        v2: GLfloat,
+    ) -> GlFallible {
+        let value = [v0, v1, v2];
+        // Safety: value holds a single 3 component vector
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 3), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `vec3` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform3fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3 component vectors
+        unsafe { self.set_uniform(Some(program), location, count, (1, 3), false, value) }
+    }
+    /// Set the `uvec3` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform3ui(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLuint,
+        v1: GLuint,
+        v2: GLuint,
+    ) -> GlFallible {
+        let value = [v0, v1, v2];
+        // Safety: value holds a single 3 component vector
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 3), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `uvec3` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform3uiv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3 component vectors
+        unsafe { self.set_uniform(Some(program), location, count, (1, 3), false, value) }
+    }
+    /// Set the `ivec4` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform4i(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLint,
+        v1: GLint,
+        v2: GLint,
+        v3: GLint,
+    ) -> GlFallible {
+        let value = [v0, v1, v2, v3];
+        // Safety: value holds a single 4 component vector
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 4), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `ivec4` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform4iv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4 component vectors
+        unsafe { self.set_uniform(Some(program), location, count, (1, 4), false, value) }
+    }
+    /// Set the `vec4` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform4f(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLfloat,
+        v1: GLfloat,
+        v2: GLfloat,
+        v3: GLfloat,
+    ) -> GlFallible {
+        let value = [v0, v1, v2, v3];
+        // Safety: value holds a single 4 component vector
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 4), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `vec4` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform4fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4 component vectors
+        unsafe { self.set_uniform(Some(program), location, count, (1, 4), false, value) }
+    }
+    /// Set the `uvec4` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform4ui(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLuint,
+        v1: GLuint,
+        v2: GLuint,
+        v3: GLuint,
+    ) -> GlFallible {
+        let value = [v0, v1, v2, v3];
+        // Safety: value holds a single 4 component vector
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 4), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `uvec4` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform4uiv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLuint,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4 component vectors
+        unsafe { self.set_uniform(Some(program), location, count, (1, 4), false, value) }
+    }
+    /// Set `count` elements of the `mat2` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix2fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2x2 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (2, 2), transpose, value) }
+    }
+    /// Set `count` elements of the `mat3` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix3fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3x3 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (3, 3), transpose, value) }
+    }
+    /// Set `count` elements of the `mat4` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix4fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4x4 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (4, 4), transpose, value) }
+    }
+    /// Set `count` elements of the `mat2x3` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix2x3fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2x3 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (2, 3), transpose, value) }
+    }
+    /// Set `count` elements of the `mat3x2` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix3x2fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3x2 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (3, 2), transpose, value) }
+    }
+    /// Set `count` elements of the `mat2x4` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix2x4fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2x4 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (2, 4), transpose, value) }
+    }
+    /// Set `count` elements of the `mat4x2` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix4x2fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4x2 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (4, 2), transpose, value) }
+    }
+    /// Set `count` elements of the `mat3x4` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix3x4fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3x4 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (3, 4), transpose, value) }
+    }
+    /// Set `count` elements of the `mat4x3` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix4x3fv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4x3 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (4, 3), transpose, value) }
+    }
+}
+impl Context {
+    /// Set the `double` uniform at `location` of the current program
+    pub fn oxidegl_uniform1d(&mut self, location: GLint, x: GLdouble) -> GlFallible {
+        let value = [x];
+        // Safety: value holds a single value
+        unsafe { self.set_uniform(None, location, 1, (1, 1), false, value.as_ptr()) }
+    }
+    /// Set the `dvec2` uniform at `location` of the current program
+    pub fn oxidegl_uniform2d(&mut self, location: GLint, x: GLdouble, y: GLdouble) -> GlFallible {
+        let value = [x, y];
+        // Safety: value holds a single 2 component vector
+        unsafe { self.set_uniform(None, location, 1, (1, 2), false, value.as_ptr()) }
+    }
+    /// Set the `dvec3` uniform at `location` of the current program
+    pub fn oxidegl_uniform3d(
+        &mut self,
+        location: GLint,
+        x: GLdouble,
+        y: GLdouble,
+        z: GLdouble,
+    ) -> GlFallible {
+        let value = [x, y, z];
+        // Safety: value holds a single 3 component vector
+        unsafe { self.set_uniform(None, location, 1, (1, 3), false, value.as_ptr()) }
+    }
+    /// Set the `dvec4` uniform at `location` of the current program
+    pub fn oxidegl_uniform4d(
+        &mut self,
+        location: GLint,
+        x: GLdouble,
+        y: GLdouble,
+        z: GLdouble,
+        w: GLdouble,
+    ) -> GlFallible {
+        let value = [x, y, z, w];
+        // Safety: value holds a single 4 component vector
+        unsafe { self.set_uniform(None, location, 1, (1, 4), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `double` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform1dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid values
+        unsafe { self.set_uniform(None, location, count, (1, 1), false, value) }
+    }
+    /// Set `count` elements of the `dvec2` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform2dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2 component vectors
+        unsafe { self.set_uniform(None, location, count, (1, 2), false, value) }
+    }
+    /// Set `count` elements of the `dvec3` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform3dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3 component vectors
+        unsafe { self.set_uniform(None, location, count, (1, 3), false, value) }
+    }
+    /// Set `count` elements of the `dvec4` uniform at `location` of the current program
+    pub unsafe fn oxidegl_uniform4dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4 component vectors
+        unsafe { self.set_uniform(None, location, count, (1, 4), false, value) }
+    }
+    /// Set `count` elements of the `dmat2` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix2dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2x2 matrices
+        unsafe { self.set_uniform(None, location, count, (2, 2), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat3` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix3dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3x3 matrices
+        unsafe { self.set_uniform(None, location, count, (3, 3), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat4` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix4dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4x4 matrices
+        unsafe { self.set_uniform(None, location, count, (4, 4), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat2x3` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix2x3dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2x3 matrices
+        unsafe { self.set_uniform(None, location, count, (2, 3), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat2x4` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix2x4dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2x4 matrices
+        unsafe { self.set_uniform(None, location, count, (2, 4), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat3x2` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix3x2dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3x2 matrices
+        unsafe { self.set_uniform(None, location, count, (3, 2), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat3x4` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix3x4dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3x4 matrices
+        unsafe { self.set_uniform(None, location, count, (3, 4), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat4x2` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix4x2dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4x2 matrices
+        unsafe { self.set_uniform(None, location, count, (4, 2), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat4x3` uniform at `location` of the current program,
+    /// transposed if `transpose` is set
+    pub unsafe fn oxidegl_uniform_matrix4x3dv(
+        &mut self,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4x3 matrices
+        unsafe { self.set_uniform(None, location, count, (4, 3), transpose, value) }
+    }
+    /// Set the `double` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform1d(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLdouble,
+    ) -> GlFallible {
+        let value = [v0];
+        // Safety: value holds a single value
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 1), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `double` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform1dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid values
+        unsafe { self.set_uniform(Some(program), location, count, (1, 1), false, value) }
+    }
+    /// Set the `dvec2` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform2d(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLdouble,
+        v1: GLdouble,
+    ) -> GlFallible {
+        let value = [v0, v1];
+        // Safety: value holds a single 2 component vector
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 2), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `dvec2` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform2dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2 component vectors
+        unsafe { self.set_uniform(Some(program), location, count, (1, 2), false, value) }
+    }
+    /// Set the `dvec3` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform3d(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLdouble,
+        v1: GLdouble,
+        v2: GLdouble,
+    ) -> GlFallible {
+        let value = [v0, v1, v2];
+        // Safety: value holds a single 3 component vector
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 3), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `dvec3` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform3dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3 component vectors
+        unsafe { self.set_uniform(Some(program), location, count, (1, 3), false, value) }
+    }
+    /// Set the `dvec4` uniform at `location` of `program`
+    pub fn oxidegl_program_uniform4d(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        v0: GLdouble,
+        v1: GLdouble,
+        v2: GLdouble,
+        v3: GLdouble,
+    ) -> GlFallible {
+        let value = [v0, v1, v2, v3];
+        // Safety: value holds a single 4 component vector
+        unsafe { self.set_uniform(Some(program), location, 1, (1, 4), false, value.as_ptr()) }
+    }
+    /// Set `count` elements of the `dvec4` uniform at `location` of `program`
+    pub unsafe fn oxidegl_program_uniform4dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4 component vectors
+        unsafe { self.set_uniform(Some(program), location, count, (1, 4), false, value) }
+    }
+    /// Set `count` elements of the `dmat2` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix2dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2x2 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (2, 2), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat3` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix3dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3x3 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (3, 3), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat4` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix4dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4x4 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (4, 4), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat2x3` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix2x3dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2x3 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (2, 3), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat3x2` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix3x2dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3x2 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (3, 2), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat2x4` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix2x4dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 2x4 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (2, 4), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat4x2` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix4x2dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4x2 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (4, 2), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat3x4` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix3x4dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 3x4 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (3, 4), transpose, value) }
+    }
+    /// Set `count` elements of the `dmat4x3` uniform at `location` of `program`, transposed if `transpose` is set
+    pub unsafe fn oxidegl_program_uniform_matrix4x3dv(
+        &mut self,
+        program: GLuint,
+        location: GLint,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLdouble,
+    ) -> GlFallible {
+        // Safety: caller ensures value points to `count` valid 4x3 matrices
+        unsafe { self.set_uniform(Some(program), location, count, (4, 3), transpose, value) }
+    }
+}
+
+/// Component type of the values passed to a `glUniform*` command
+trait UniformComponent: Copy {
+    const KIND: UniformKind;
+    /// Convert this value to a component of a uniform of the given kind
+    fn convert(self, kind: UniformKind) -> u32;
+}
+impl UniformComponent for GLfloat {
+    const KIND: UniformKind = UniformKind::Float;
+    #[inline]
+    fn convert(self, kind: UniformKind) -> u32 {
+        if kind == UniformKind::Bool {
+            u32::from(self != 0.0)
+        } else {
+            self.to_bits()
+        }
+    }
+}
+impl UniformComponent for GLint {
+    const KIND: UniformKind = UniformKind::Int;
+    #[inline]
+    fn convert(self, kind: UniformKind) -> u32 {
+        if kind == UniformKind::Bool {
+            u32::from(self != 0)
+        } else {
+            self.cast_unsigned()
+        }
+    }
+}
+impl UniformComponent for GLuint {
+    const KIND: UniformKind = UniformKind::UnsignedInt;
+    #[inline]
+    fn convert(self, kind: UniformKind) -> u32 {
+        if kind == UniformKind::Bool {
+            u32::from(self != 0)
+        } else {
+            self
+        }
+    }
+}
+impl UniformComponent for GLdouble {
+    const KIND: UniformKind = UniformKind::Double;
+    fn convert(self, _kind: UniformKind) -> u32 {
+        unreachable!("programs with double precision uniforms fail to link")
+    }
+}
+
+impl Context {
+    /// Shared implementation of the `glUniform*` and `glProgramUniform*` commands. `program` is `None` for the commands
+    /// that operate on the current program, or the active program of the current program pipeline
+    /// # Safety
+    /// `value` must point to `count` elements of `columns * rows` values each
+    unsafe fn set_uniform<T: UniformComponent>(
+        &mut self,
+        program: Option<GLuint>,
+        location: GLint,
+        count: GLsizei,
+        (columns, rows): (u8, u8),
+        transpose: GLboolean,
+        value: *const T,
+    ) -> GlFallible {
+        sizei!(count);
+        let current = self.gl_state.uniform_program();
+        let program = match program {
+            Some(name) => self
+                .gl_state
+                .program_list
+                .get_program_raw_mut(&self.gl_state.shader_list, name)?,
+            None => self
+                .gl_state
+                .program_list
+                .get_mut(current.ok_or(GlError::InvalidOperation.e())?),
+        };
+        let name = program.name;
+        let Some(linkage) = program.linkage_mut() else {
+            return Err(GlError::InvalidOperation.e());
+        };
+        if location == -1 {
+            return Ok(());
+        }
+        let Some((uniform, element)) = u32::try_from(location)
+            .ok()
+            .and_then(|l| linkage.uniforms.get(l))
+        else {
+            return Err(GlError::InvalidOperation.e());
+        };
+
+        let u = &linkage.uniforms.uniforms[uniform];
+        let (kind, uniform_columns, uniform_rows) = u.ty.shape();
+        let kind_matches = match kind {
+            // booleans can be set with any of the non-double commands
+            UniformKind::Bool => T::KIND != UniformKind::Double,
+            // samplers can only be set with glUniform1i{v}
+            UniformKind::Opaque => T::KIND == UniformKind::Int,
+            kind => T::KIND == kind,
+        };
+        if !kind_matches
+            || (uniform_columns, uniform_rows) != (columns, rows)
+            || (count > 1 && !u.is_array)
+        {
+            return Err(GlError::InvalidOperation.e());
+        }
+        // values for elements past the end of the array are ignored
+        let count = count.min(u.len - element) as usize;
+        if count == 0 {
+            return Ok(());
+        }
+        let (columns, rows) = (usize::from(columns), usize::from(rows));
+        let element_len = columns * rows;
+        // Safety: caller ensures value points to at least count elements of columns * rows values each
+        let values = unsafe { slice::from_raw_parts(value, count * element_len) };
+        let mut words = Vec::with_capacity(values.len());
+        for element in values.chunks_exact(element_len) {
+            for i in 0..element_len {
+                // transposed matrices are supplied in row major order
+                let v = if transpose {
+                    element[(i % rows) * columns + i / rows]
+                } else {
+                    element[i]
+                };
+                words.push(v.convert(kind));
+            }
+        }
+        linkage.write_uniform(uniform, element, &words);
+        if self.gl_state.current_programs().contains(&name) {
+            self.upload_uniforms();
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn software_default_block_uniforms() {
+    use crate::{
+        gl_enums::{PrimitiveType, ShaderType, VertexAttribType},
+        test_util::{SCENE_SIZE, Scene},
+    };
+
+    let mut scene = Scene::with_program(&[
+        (
+            ShaderType::VertexShader,
+            "#version 430 core
+            layout(location = 0) in vec2 position;
+            layout(location = 0) uniform vec2 offset;
+            void main() {
+                gl_Position = vec4(position + offset, 0.0, 1.0);
+            }",
+        ),
+        (
+            ShaderType::FragmentShader,
+            "#version 430 core
+            layout(location = 1) uniform vec4 color;
+            layout(location = 2) uniform bool use_color;
+            out vec4 frag_color;
+            void main() {
+                frag_color = use_color ? color : vec4(1.0);
+            }",
+        ),
+    ]);
+    // a triangle covering the whole viewport
+    let vertices: [f32; 6] = [-1.0, -1.0, 3.0, -1.0, -1.0, 3.0];
+    scene.vertices(&vertices, &[(2, VertexAttribType::Float, false)]);
+    scene.ctx.oxidegl_clear_color(0.25, 0.25, 0.25, 1.0);
+
+    let ctx = &mut scene.ctx;
+    // type mismatches are rejected, unknown uniforms are ignored
+    assert!(ctx.oxidegl_uniform1i(1, 0).is_err());
+    assert!(ctx.oxidegl_uniform2f(2, 0.0, 0.0).is_err());
+    ctx.oxidegl_uniform1f(-1, 0.0).unwrap();
+
+    ctx.oxidegl_uniform4f(1, 0.0, 1.0, 0.0, 1.0).unwrap();
+    ctx.oxidegl_uniform1i(2, 1).unwrap();
+    scene.draw(PrimitiveType::Triangles, 3);
+    assert_eq!(
+        scene.pixel(SCENE_SIZE / 2, SCENE_SIZE / 2),
+        [0.0, 1.0, 0.0, 1.0]
+    );
+
+    // move the triangle over so it only covers the right half of the viewport, and stop using the color uniform
+    scene.ctx.oxidegl_uniform2f(0, 1.0, 0.0).unwrap();
+    scene.ctx.oxidegl_uniform1f(2, 0.0).unwrap();
+    scene.draw(PrimitiveType::Triangles, 3);
+    assert_eq!(scene.pixel(1, SCENE_SIZE / 2), [0.25, 0.25, 0.25, 1.0]);
+    assert_eq!(scene.pixel(SCENE_SIZE - 2, 1), [1.0, 1.0, 1.0, 1.0]);
+}
@@ -4,27 +4,386 @@
 use crate::context::Context;
 use crate::error::GlFallible;
 use crate::gl_enums::{
-    AtomicCounterBufferPName, AttributeType, BlendEquationModeEXT, BlendingFactor,
-    BlitFramebufferFilter, Buffer, BufferAccess, BufferPName, BufferStorageTarget, BufferTarget,
-    BufferUsage, ClampColorMode, ClearBufferMask, ClipControlDepth, ClipControlOrigin, ColorBuffer,
+    BlendEquationModeEXT, BlendingFactor, BlitFramebufferFilter, Buffer, BufferAccess, BufferPName,
+    BufferStorageTarget, BufferTarget, BufferUsage, ClampColorMode, ClearBufferMask, ColorBuffer,
     ConditionalRenderMode, CopyBufferSubDataTarget, CopyImageSubDataTarget, DepthFunction,
     DrawBufferMode, DrawElementsType, EnableCap, FramebufferAttachment,
     FramebufferAttachmentParameterName, FramebufferParameterName, FramebufferTarget,
     FrontFaceDirection, GetFramebufferParameter, GetTextureParameter, HintMode, HintTarget,
     InternalFormat, InternalFormatPName, InvalidateFramebufferAttachment, LogicOp,
-    MapBufferAccessMask, MemoryBarrierMask, PatchParameterName, PipelineParameterName, PixelFormat,
-    PixelStoreParameter, PixelType, PolygonMode, PrecisionType, PrimitiveType, ProgramInterface,
-    ProgramInterfacePName, ProgramParameterPName, ProgramResourceProperty, ProgramStagePName,
-    QueryObjectParameterName, QueryParameterName, QueryTarget, ReadBufferMode,
+    MapBufferAccessMask, PixelFormat, PixelStoreParameter, PixelType, PolygonMode, PrecisionType,
+    PrimitiveType, ProgramInterface, QueryObjectParameterName, ReadBufferMode,
     RenderbufferParameterName, SamplerParameter, ShaderType, SizedInternalFormat, StencilFunction,
-    StencilOp, SubroutineParameterName, SyncParameterName, TextureParameterName, TextureTarget,
-    TextureUnit, TransformFeedbackBufferMode, TransformFeedbackPName, TriangleFace,
-    UniformBlockPName, UniformPName, UniformType, UseProgramStageMask, VertexArrayPName,
-    VertexAttribEnum, VertexAttribPointerType, VertexAttribProperty, VertexBufferObjectUsage,
-    VertexProvokingMode,
+    StencilOp, SyncParameterName, TextureParameterName, TextureTarget, TextureUnit, TriangleFace,
+    UniformBlockPName, UniformPName, UniformType, VertexArrayPName, VertexAttribEnum,
+    VertexAttribPointerType, VertexAttribProperty, VertexBufferObjectUsage, VertexProvokingMode,
 };
 use crate::gl_types::*;
 
+/// GL commands whose method on [`Context`] is a placeholder that panics, so that callers which can't abort (e.g. the
+/// trace replayer) can avoid them
+pub const UNIMPLEMENTED_COMMANDS: &[&str] = &[
+    "glBeginConditionalRender",
+    "glEndConditionalRender",
+    "glBlendEquation",
+    "glBlendEquationi",
+    "glBlendEquationSeparate",
+    "glBlendEquationSeparatei",
+    "glBlendFunc",
+    "glBlendFunci",
+    "glBlendFuncSeparate",
+    "glBlendFuncSeparatei",
+    "glBlitFramebuffer",
+    "glBlitNamedFramebuffer",
+    "glBufferData",
+    "glNamedBufferData",
+    "glBufferSubData",
+    "glNamedBufferSubData",
+    "glCheckFramebufferStatus",
+    "glCheckNamedFramebufferStatus",
+    "glClearBufferiv",
+    "glClearBufferuiv",
+    "glClearBufferfv",
+    "glClearBufferfi",
+    "glClearNamedFramebufferiv",
+    "glClearNamedFramebufferuiv",
+    "glClearNamedFramebufferfv",
+    "glClearNamedFramebufferfi",
+    "glClearBufferData",
+    "glClearNamedBufferData",
+    "glClearBufferSubData",
+    "glClearNamedBufferSubData",
+    "glColorMask",
+    "glColorMaski",
+    "glCompressedTexSubImage1D",
+    "glCompressedTextureSubImage1D",
+    "glCompressedTexSubImage2D",
+    "glCompressedTextureSubImage2D",
+    "glCompressedTexSubImage3D",
+    "glCompressedTextureSubImage3D",
+    "glCopyBufferSubData",
+    "glCopyNamedBufferSubData",
+    "glCopyTexSubImage1D",
+    "glCopyTextureSubImage1D",
+    "glCopyTexSubImage2D",
+    "glCopyTextureSubImage2D",
+    "glCopyTexSubImage3D",
+    "glCopyTextureSubImage3D",
+    "glDrawBuffer",
+    "glNamedFramebufferDrawBuffer",
+    "glDrawBuffers",
+    "glNamedFramebufferDrawBuffers",
+    "glFlushMappedBufferRange",
+    "glFlushMappedNamedBufferRange",
+    "glFramebufferParameteri",
+    "glNamedFramebufferParameteri",
+    "glFramebufferRenderbuffer",
+    "glNamedFramebufferRenderbuffer",
+    "glFramebufferTexture1D",
+    "glFramebufferTexture2D",
+    "glFramebufferTexture3D",
+    "glFramebufferTexture",
+    "glNamedFramebufferTexture",
+    "glFramebufferTextureLayer",
+    "glNamedFramebufferTextureLayer",
+    "glGenerateMipmap",
+    "glGenerateTextureMipmap",
+    "glGetBufferParameteriv",
+    "glGetBufferParameteri64v",
+    "glGetNamedBufferParameteriv",
+    "glGetNamedBufferParameteri64v",
+    "glGetBufferPointerv",
+    "glGetNamedBufferPointerv",
+    "glGetBufferSubData",
+    "glGetNamedBufferSubData",
+    "glGetCompressedTexImage",
+    "glGetCompressedTextureImage",
+    "glGetnCompressedTexImage",
+    "glGetFramebufferAttachmentParameteriv",
+    "glGetNamedFramebufferAttachmentParameteriv",
+    "glGetFramebufferParameteriv",
+    "glGetNamedFramebufferParameteriv",
+    "glGetInternalformativ",
+    "glGetInternalformati64v",
+    "glGetRenderbufferParameteriv",
+    "glGetNamedRenderbufferParameteriv",
+    "glGetSamplerParameteriv",
+    "glGetSamplerParameterIiv",
+    "glGetSamplerParameterfv",
+    "glGetSamplerParameterIuiv",
+    "glGetTexImage",
+    "glGetTextureImage",
+    "glGetnTexImage",
+    "glGetTexLevelParameterfv",
+    "glGetTexLevelParameteriv",
+    "glGetTextureLevelParameterfv",
+    "glGetTextureLevelParameteriv",
+    "glGetTexParameterfv",
+    "glGetTexParameteriv",
+    "glGetTexParameterIiv",
+    "glGetTexParameterIuiv",
+    "glGetTextureParameterfv",
+    "glGetTextureParameterIiv",
+    "glGetTextureParameterIuiv",
+    "glGetTextureParameteriv",
+    "glGetUniformfv",
+    "glGetUniformiv",
+    "glGetUniformuiv",
+    "glGetUniformdv",
+    "glGetnUniformdv",
+    "glGetnUniformfv",
+    "glGetnUniformiv",
+    "glGetnUniformuiv",
+    "glGetVertexArrayIndexediv",
+    "glGetVertexArrayIndexed64iv",
+    "glGetVertexAttribdv",
+    "glGetVertexAttribfv",
+    "glGetVertexAttribiv",
+    "glGetVertexAttribIiv",
+    "glGetVertexAttribIuiv",
+    "glGetVertexAttribLdv",
+    "glInvalidateFramebuffer",
+    "glInvalidateNamedFramebufferData",
+    "glInvalidateSubFramebuffer",
+    "glInvalidateNamedFramebufferSubData",
+    "glIsEnabled",
+    "glIsEnabledi",
+    "glMapBuffer",
+    "glMapNamedBuffer",
+    "glMapBufferRange",
+    "glMapNamedBufferRange",
+    "glPixelStoref",
+    "glPixelStorei",
+    "glReadBuffer",
+    "glNamedFramebufferReadBuffer",
+    "glReadPixels",
+    "glReadnPixels",
+    "glRenderbufferStorage",
+    "glNamedRenderbufferStorage",
+    "glRenderbufferStorageMultisample",
+    "glNamedRenderbufferStorageMultisample",
+    "glSamplerParameteri",
+    "glSamplerParameteriv",
+    "glSamplerParameterf",
+    "glSamplerParameterfv",
+    "glSamplerParameterIiv",
+    "glSamplerParameterIuiv",
+    "glScissorIndexed",
+    "glScissorIndexedv",
+    "glTexBuffer",
+    "glTextureBuffer",
+    "glTexBufferRange",
+    "glTextureBufferRange",
+    "glTexParameterf",
+    "glTexParameterfv",
+    "glTexParameteri",
+    "glTexParameteriv",
+    "glTexParameterIiv",
+    "glTexParameterIuiv",
+    "glTextureParameterf",
+    "glTextureParameterfv",
+    "glTextureParameteri",
+    "glTextureParameterIiv",
+    "glTextureParameterIuiv",
+    "glTextureParameteriv",
+    "glTexStorage1D",
+    "glTextureStorage1D",
+    "glTexStorage2D",
+    "glTextureStorage2D",
+    "glTexStorage2DMultisample",
+    "glTextureStorage2DMultisample",
+    "glTexStorage3D",
+    "glTextureStorage3D",
+    "glTexStorage3DMultisample",
+    "glTextureStorage3DMultisample",
+    "glTexSubImage1D",
+    "glTextureSubImage1D",
+    "glTexSubImage2D",
+    "glTextureSubImage2D",
+    "glTexSubImage3D",
+    "glTextureSubImage3D",
+    "glUnmapBuffer",
+    "glUnmapNamedBuffer",
+    "glVertexAttrib1d",
+    "glVertexAttrib1dv",
+    "glVertexAttrib1f",
+    "glVertexAttrib1fv",
+    "glVertexAttrib1s",
+    "glVertexAttrib1sv",
+    "glVertexAttrib2d",
+    "glVertexAttrib2dv",
+    "glVertexAttrib2f",
+    "glVertexAttrib2fv",
+    "glVertexAttrib2s",
+    "glVertexAttrib2sv",
+    "glVertexAttrib3d",
+    "glVertexAttrib3dv",
+    "glVertexAttrib3f",
+    "glVertexAttrib3fv",
+    "glVertexAttrib3s",
+    "glVertexAttrib3sv",
+    "glVertexAttrib4Nbv",
+    "glVertexAttrib4Niv",
+    "glVertexAttrib4Nsv",
+    "glVertexAttrib4Nub",
+    "glVertexAttrib4Nubv",
+    "glVertexAttrib4Nuiv",
+    "glVertexAttrib4Nusv",
+    "glVertexAttrib4bv",
+    "glVertexAttrib4d",
+    "glVertexAttrib4dv",
+    "glVertexAttrib4f",
+    "glVertexAttrib4fv",
+    "glVertexAttrib4iv",
+    "glVertexAttrib4s",
+    "glVertexAttrib4sv",
+    "glVertexAttrib4ubv",
+    "glVertexAttrib4uiv",
+    "glVertexAttrib4usv",
+    "glVertexAttribI1i",
+    "glVertexAttribI2i",
+    "glVertexAttribI3i",
+    "glVertexAttribI4i",
+    "glVertexAttribI1ui",
+    "glVertexAttribI2ui",
+    "glVertexAttribI3ui",
+    "glVertexAttribI4ui",
+    "glVertexAttribI1iv",
+    "glVertexAttribI2iv",
+    "glVertexAttribI3iv",
+    "glVertexAttribI4iv",
+    "glVertexAttribI1uiv",
+    "glVertexAttribI2uiv",
+    "glVertexAttribI3uiv",
+    "glVertexAttribI4uiv",
+    "glVertexAttribI4bv",
+    "glVertexAttribI4sv",
+    "glVertexAttribI4ubv",
+    "glVertexAttribI4usv",
+    "glVertexAttribP1ui",
+    "glVertexAttribP2ui",
+    "glVertexAttribP3ui",
+    "glVertexAttribP4ui",
+    "glVertexAttribL1d",
+    "glVertexAttribL2d",
+    "glVertexAttribL3d",
+    "glVertexAttribL4d",
+    "glVertexAttribL1dv",
+    "glVertexAttribL2dv",
+    "glVertexAttribL3dv",
+    "glVertexAttribL4dv",
+    "glViewportIndexedf",
+    "glViewportIndexedfv",
+    "glActiveTexture",
+    "glBindFramebuffer",
+    "glBindRenderbuffer",
+    "glBindSampler",
+    "glBindSamplers",
+    "glBindTexture",
+    "glBindTextures",
+    "glBindTextureUnit",
+    "glBlendColor",
+    "glClampColor",
+    "glClearTexImage",
+    "glClearTexSubImage",
+    "glClientWaitSync",
+    "glCompressedTexImage1D",
+    "glCompressedTexImage2D",
+    "glCompressedTexImage3D",
+    "glCopyImageSubData",
+    "glCopyTexImage1D",
+    "glCopyTexImage2D",
+    "glCreateFramebuffers",
+    "glCreateRenderbuffers",
+    "glCreateSamplers",
+    "glCullFace",
+    "glDeleteFramebuffers",
+    "glDeleteProgram",
+    "glDeleteRenderbuffers",
+    "glDeleteSamplers",
+    "glDeleteSync",
+    "glDepthFunc",
+    "glDepthMask",
+    "glFenceSync",
+    "glFinish",
+    "glFlush",
+    "glFrontFace",
+    "glGenFramebuffers",
+    "glGenRenderbuffers",
+    "glGenSamplers",
+    "glGetActiveUniformBlockiv",
+    "glGetActiveUniformBlockName",
+    "glGetActiveUniformName",
+    "glGetActiveUniformsiv",
+    "glGetAttachedShaders",
+    "glGetCompressedTextureSubImage",
+    "glGetGraphicsResetStatus",
+    "glGetMultisamplefv",
+    "glGetShaderPrecisionFormat",
+    "glGetSynciv",
+    "glGetTextureSubImage",
+    "glGetUniformBlockIndex",
+    "glGetUniformIndices",
+    "glGetVertexArrayiv",
+    "glGetVertexAttribPointerv",
+    "glHint",
+    "glInvalidateBufferData",
+    "glInvalidateBufferSubData",
+    "glInvalidateTexImage",
+    "glInvalidateTexSubImage",
+    "glIsFramebuffer",
+    "glIsProgram",
+    "glIsRenderbuffer",
+    "glIsSampler",
+    "glIsShader",
+    "glIsSync",
+    "glLineWidth",
+    "glLogicOp",
+    "glMinSampleShading",
+    "glMultiDrawArrays",
+    "glMultiDrawArraysIndirect",
+    "glMultiDrawElements",
+    "glMultiDrawElementsBaseVertex",
+    "glMultiDrawElementsIndirect",
+    "glPolygonMode",
+    "glPolygonOffset",
+    "glPrimitiveRestartIndex",
+    "glProvokingVertex",
+    "glQueryCounter",
+    "glReleaseShaderCompiler",
+    "glSampleCoverage",
+    "glSampleMaski",
+    "glShaderStorageBlockBinding",
+    "glStencilFunc",
+    "glStencilFuncSeparate",
+    "glStencilMask",
+    "glStencilMaskSeparate",
+    "glStencilOp",
+    "glStencilOpSeparate",
+    "glTexImage1D",
+    "glTexImage2D",
+    "glTexImage2DMultisample",
+    "glTexImage3D",
+    "glTexImage3DMultisample",
+    "glTextureBarrier",
+    "glTextureView",
+    "glUniformBlockBinding",
+    "glVertexArrayElementBuffer",
+    "glVertexAttribDivisor",
+    "glWaitSync",
+    "glVertexAttribP1uiv",
+    "glVertexAttribP2uiv",
+    "glVertexAttribP3uiv",
+    "glVertexAttribP4uiv",
+    "glMultiDrawArraysIndirectCount",
+    "glMultiDrawElementsIndirectCount",
+    "glPolygonOffsetClamp",
+    "glGetQueryBufferObjecti64v",
+    "glGetQueryBufferObjectiv",
+    "glGetQueryBufferObjectui64v",
+    "glGetQueryBufferObjectuiv",
+];
+
 /// ### Parameters
 /// `id`
 ///
@@ -91,336 +450,6 @@ impl Context {
     }
 }
 /// ### Parameters
-/// `target`
-///
-/// > Specifies the target type of query object established between [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
-/// > and the subsequent [**glEndQuery**](crate::context::Context::oxidegl_end_query).
-/// > The symbolic constant must be one of [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
-/// > [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE),
-/// > [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED), [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
-/// > or [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED).
-///
-/// `id`
-///
-/// > Specifies the name of a query object.
-///
-/// ### Description
-/// [**glBeginQuery**](crate::context::Context::oxidegl_begin_query) and [**glEndQuery**](crate::context::Context::oxidegl_end_query)
-/// delimit the boundaries of a query object. `query` must be a name previously
-/// returned from a call to [**glGenQueries**](crate::context::Context::oxidegl_gen_queries).
-/// If a query object with name `id` does not yet exist it is created with
-/// the type determined by `target`. `target` must be one of [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
-/// [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED),
-/// [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
-/// or [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED). The behavior
-/// of the query object depends on its type and is as follows.
-///
-/// If `target` is [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
-/// `id` must be an unused name, or the name of an existing occlusion query
-/// object. When [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
-/// is executed, the query object's samples-passed counter is reset to 0. Subsequent
-/// rendering will increment the counter for every sample that passes the depth
-/// test. If the value of [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS)
-/// is 0, then the samples-passed count is incremented by 1 for each fragment.
-/// If the value of [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS)
-/// is 1, then the samples-passed count is incremented by the number of samples
-/// whose coverage bit is set. However, implementations, at their discression
-/// may instead increase the samples-passed count by the value of [`GL_SAMPLES`](crate::gl_enums::GL_SAMPLES)
-/// if any sample in the fragment is covered. When [**glEndQuery**](crate::context::Context::oxidegl_end_query)
-/// is executed, the samples-passed counter is assigned to the query object's
-/// result value. This value can be queried by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT).
-///
-/// If `target` is [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED)
-/// or [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE),
-/// `id` must be an unused name, or the name of an existing boolean occlusion
-/// query object. When [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
-/// is executed, the query object's samples-passed flag is reset to [`GL_FALSE`](crate::gl_enums::GL_FALSE).
-/// Subsequent rendering causes the flag to be set to [`GL_TRUE`](crate::gl_enums::GL_TRUE)
-/// if any sample passes the depth test in the case of [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED),
-/// or if the implementation determines that any sample might pass the depth
-/// test in the case of [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE).
-/// The implementation may be able to provide a more efficient test in the
-/// case of [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE)
-/// if some false positives are acceptable to the application. When [**glEndQuery**](crate::context::Context::oxidegl_end_query)
-/// is executed, the samples-passed flag is assigned to the query object's
-/// result value. This value can be queried by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT).
-///
-/// If `target` is [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED),
-/// `id` must be an unused name, or the name of an existing primitive query
-/// object previously bound to the [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED)
-/// query binding. When [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
-/// is executed, the query object's primitives-generated counter is reset to
-/// 0. Subsequent rendering will increment the counter once for every vertex
-/// that is emitted from the geometry shader, or from the vertex shader if
-/// no geometry shader is present. When [**glEndQuery**](crate::context::Context::oxidegl_end_query)
-/// is executed, the primitives-generated counter is assigned to the query
-/// object's result value. This value can be queried by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT).
-///
-/// If `target` is [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
-/// `id` must be an unused name, or the name of an existing primitive query
-/// object previously bound to the [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN)
-/// query binding. When [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
-/// is executed, the query object's primitives-written counter is reset to
-/// 0. Subsequent rendering will increment the counter once for every vertex
-/// that is written into the bound transform feedback buffer(s). If transform
-/// feedback mode is not activated between the call to [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
-/// and [**glEndQuery**](crate::context::Context::oxidegl_end_query), the counter
-/// will not be incremented. When [**glEndQuery**](crate::context::Context::oxidegl_end_query)
-/// is executed, the primitives-written counter is assigned to the query object's
-/// result value. This value can be queried by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT).
-///
-/// If `target` is [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED), `id`
-/// must be an unused name, or the name of an existing timer query object previously
-/// bound to the [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED) query
-/// binding. When [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
-/// is executed, the query object's time counter is reset to 0. When [**glEndQuery**](crate::context::Context::oxidegl_end_query)
-/// is executed, the elapsed server time that has passed since the call to
-/// [**glBeginQuery**](crate::context::Context::oxidegl_begin_query) is written
-/// into the query object's time counter. This value can be queried by calling
-/// [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT).
-///
-/// Querying the [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT) implicitly
-/// flushes the GL pipeline until the rendering delimited by the query object
-/// has completed and the result is available. [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE)
-/// can be queried to determine if the result is immediately available or if
-/// the rendering is not yet complete.
-///
-/// ### Notes
-/// If the query target's count exceeds the maximum value representable in
-/// the number of available bits, as reported by [**glGetQueryiv**](crate::context::Context::oxidegl_get_queryiv)
-/// with `target` set to the appropriate query target and `pname` [`GL_QUERY_COUNTER_BITS`](crate::gl_enums::GL_QUERY_COUNTER_BITS),
-/// the count becomes undefined.
-///
-/// An implementation may support 0 bits in its counter, in which case query
-/// results are always undefined and essentially useless.
-///
-/// When [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS) is 0, the
-/// samples-passed counter of an occlusion query will increment once for each
-/// fragment that passes the depth test. When [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS)
-/// is 1, an implementation may either increment the samples-passed counter
-/// individually for each sample of a fragment that passes the depth test,
-/// or it may choose to increment the counter for all samples of a fragment
-/// if any one of them passes the depth test.
-///
-/// The query targets [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED),
-/// and [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED) are availale
-/// only if the GL version is 3.3 or higher.
-///
-/// The query target [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE)
-/// is available only of the GL version is 4.3 or higher.
-impl Context {
-    pub fn oxidegl_begin_query(&mut self, target: QueryTarget, id: GLuint) -> GlFallible {
-        panic!("command oxidegl_begin_query not yet implemented");
-    }
-    pub fn oxidegl_end_query(&mut self, target: QueryTarget) -> GlFallible {
-        panic!("command oxidegl_end_query not yet implemented");
-    }
-}
-/// ### Parameters
-/// `target`
-///
-/// > Specifies the target type of query object established between [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
-/// > and the subsequent [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed).
-/// > The symbolic constant must be one of [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
-/// > [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED),
-/// > [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
-/// > or [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED).
-///
-/// `index`
-///
-/// > Specifies the index of the query target upon which to begin the query.
-///
-/// `id`
-///
-/// > Specifies the name of a query object.
-///
-/// ### Description
-/// [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
-/// and [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
-/// delimit the boundaries of a query object. `query` must be a name previously
-/// returned from a call to [**glGenQueries**](crate::context::Context::oxidegl_gen_queries).
-/// If a query object with name `id` does not yet exist it is created with
-/// the type determined by `target`. `target` must be one of [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
-/// [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED),
-/// [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
-/// or [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED). The behavior
-/// of the query object depends on its type and is as follows.
-///
-/// `index` specifies the index of the query target and must be between a `target`
-/// -specific maximum.
-///
-/// If `target` is [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
-/// `id` must be an unused name, or the name of an existing occlusion query
-/// object. When [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
-/// is executed, the query object's samples-passed counter is reset to 0. Subsequent
-/// rendering will increment the counter for every sample that passes the depth
-/// test. If the value of [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS)
-/// is 0, then the samples-passed count is incremented by 1 for each fragment.
-/// If the value of [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS)
-/// is 1, then the samples-passed count is incremented by the number of samples
-/// whose coverage bit is set. However, implementations, at their discression
-/// may instead increase the samples-passed count by the value of [`GL_SAMPLES`](crate::gl_enums::GL_SAMPLES)
-/// if any sample in the fragment is covered. When [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
-/// is executed, the samples-passed counter is assigned to the query object's
-/// result value. This value can be queried by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT). When
-/// `target` is [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED), `index`
-/// must be zero.
-///
-/// If `target` is [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED),
-/// `id` must be an unused name, or the name of an existing boolean occlusion
-/// query object. When [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
-/// is executed, the query object's samples-passed flag is reset to [`GL_FALSE`](crate::gl_enums::GL_FALSE).
-/// Subsequent rendering causes the flag to be set to [`GL_TRUE`](crate::gl_enums::GL_TRUE)
-/// if any sample passes the depth test. When [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
-/// is executed, the samples-passed flag is assigned to the query object's
-/// result value. This value can be queried by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT). When
-/// `target` is [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED),
-/// `index` must be zero.
-///
-/// If `target` is [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED),
-/// `id` must be an unused name, or the name of an existing primitive query
-/// object previously bound to the [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED)
-/// query binding. When [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
-/// is executed, the query object's primitives-generated counter is reset to
-/// 0. Subsequent rendering will increment the counter once for every vertex
-/// that is emitted from the geometry shader to the stream given by `index`,
-/// or from the vertex shader if `index` is zero and no geometry shader is
-/// present. When [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
-/// is executed, the primitives-generated counter for stream `index` is assigned
-/// to the query object's result value. This value can be queried by calling
-/// [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT). When
-/// `target` is [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED),
-/// `index` must be less than the value of [`GL_MAX_VERTEX_STREAMS`](crate::gl_enums::GL_MAX_VERTEX_STREAMS).
-///
-/// If `target` is [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
-/// `id` must be an unused name, or the name of an existing primitive query
-/// object previously bound to the [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN)
-/// query binding. When [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
-/// is executed, the query object's primitives-written counter for the stream
-/// specified by `index` is reset to 0. Subsequent rendering will increment
-/// the counter once for every vertex that is written into the bound transform
-/// feedback buffer(s) for stream `index`. If transform feedback mode is not
-/// activated between the call to [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
-/// and [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed),
-/// the counter will not be incremented. When [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
-/// is executed, the primitives-written counter for stream `index` is assigned
-/// to the query object's result value. This value can be queried by calling
-/// [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT). When
-/// `target` is [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
-/// `index` must be less than the value of [`GL_MAX_VERTEX_STREAMS`](crate::gl_enums::GL_MAX_VERTEX_STREAMS).
-///
-/// If `target` is [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED), `id`
-/// must be an unused name, or the name of an existing timer query object previously
-/// bound to the [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED) query
-/// binding. When [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
-/// is executed, the query object's time counter is reset to 0. When [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
-/// is executed, the elapsed server time that has passed since the call to
-/// [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
-/// is written into the query object's time counter. This value can be queried
-/// by calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// with `pname` [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT). When
-/// `target` is [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED), `index`
-/// must be zero.
-///
-/// Querying the [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT) implicitly
-/// flushes the GL pipeline until the rendering delimited by the query object
-/// has completed and the result is available. [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE)
-/// can be queried to determine if the result is immediately available or if
-/// the rendering is not yet complete.
-///
-/// ### Notes
-/// If the query target's count exceeds the maximum value representable in
-/// the number of available bits, as reported by [**glGetQueryiv**](crate::context::Context::oxidegl_get_queryiv)
-/// with `target` set to the appropriate query target and `pname` [`GL_QUERY_COUNTER_BITS`](crate::gl_enums::GL_QUERY_COUNTER_BITS),
-/// the count becomes undefined.
-///
-/// An implementation may support 0 bits in its counter, in which case query
-/// results are always undefined and essentially useless.
-///
-/// When [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS) is 0, the
-/// samples-passed counter of an occlusion query will increment once for each
-/// fragment that passes the depth test. When [`GL_SAMPLE_BUFFERS`](crate::gl_enums::GL_SAMPLE_BUFFERS)
-/// is 1, an implementation may either increment the samples-passed counter
-/// individually for each sample of a fragment that passes the depth test,
-/// or it may choose to increment the counter for all samples of a fragment
-/// if any one of them passes the depth test.
-///
-/// Calling [**glBeginQuery**](crate::context::Context::oxidegl_begin_query)
-/// or [**glEndQuery**](crate::context::Context::oxidegl_end_query) is equivalent
-/// to calling [**glBeginQueryIndexed**](crate::context::Context::oxidegl_begin_query_indexed)
-/// or [**glEndQueryIndexed**](crate::context::Context::oxidegl_end_query_indexed)
-/// with `index` set to zero, respectively.
-impl Context {
-    pub fn oxidegl_begin_query_indexed(
-        &mut self,
-        target: QueryTarget,
-        index: GLuint,
-        id: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_begin_query_indexed not yet implemented");
-    }
-    pub fn oxidegl_end_query_indexed(&mut self, target: QueryTarget, index: GLuint) -> GlFallible {
-        panic!("command oxidegl_end_query_indexed not yet implemented");
-    }
-}
-/// ### Parameters
-/// `primitiveMode`
-///
-/// > Specify the output type of the primitives that will be recorded into the
-/// > buffer objects that are bound for transform feedback.
-///
-/// ### Description
-/// Transform feedback mode captures the values of varying variables written
-/// by the vertex shader (or, if active, the geometry shader). Transform feedback
-/// is said to be active after a call to [**glBeginTransformFeedback**](crate::context::Context::oxidegl_begin_transform_feedback)
-/// until a subsequent call to [**glEndTransformFeedback**](crate::context::Context::oxidegl_end_transform_feedback).
-/// Transform feedback commands must be paired.
-///
-/// If no geometry shader is present, while transform feedback is active the
-/// `mode` parameter to [**glDrawArrays**](crate::context::Context::oxidegl_draw_arrays)
-/// must match those specified in the following table:
-///
-/// |* Transform Feedback*                              |* Allowed Render Primitive*                                 |
-/// |---------------------------------------------------|------------------------------------------------------------|
-/// | [`GL_POINTS`](crate::gl_enums::GL_POINTS)         | [`GL_POINTS`](crate::gl_enums::GL_POINTS)                  |
-/// | [`GL_LINES`](crate::gl_enums::GL_LINES)           | [`GL_LINES`](crate::gl_enums::GL_LINES), [`GL_LINE_LOOP`](crate::gl_enums::GL_LINE_LOOP), [`GL_LINE_STRIP`](crate::gl_enums::GL_LINE_STRIP), [`GL_LINES_ADJACENCY`](crate::gl_enums::GL_LINES_ADJACENCY), [`GL_LINE_STRIP_ADJACENCY`](crate::gl_enums::GL_LINE_STRIP_ADJACENCY) |
-/// | [`GL_TRIANGLES`](crate::gl_enums::GL_TRIANGLES)   | [`GL_TRIANGLES`](crate::gl_enums::GL_TRIANGLES), [`GL_TRIANGLE_STRIP`](crate::gl_enums::GL_TRIANGLE_STRIP), [`GL_TRIANGLE_FAN`](crate::gl_enums::GL_TRIANGLE_FAN), [`GL_TRIANGLES_ADJACENCY`](crate::gl_enums::GL_TRIANGLES_ADJACENCY), [`GL_TRIANGLE_STRIP_ADJACENCY`](crate::gl_enums::GL_TRIANGLE_STRIP_ADJACENCY) |
-///
-/// If a geometry shader is present, the output primitive type from the geometry
-/// shader must match those provided in the following table:
-///
-/// |* Transform Feedback*                              |* Allowed Geometry Shader Output Primitive Type*                                           |
-/// |---------------------------------------------------|-------------------------------------------------------------------------------------------|
-/// | [`GL_POINTS`](crate::gl_enums::GL_POINTS)         | [`points`](crate::gl_enums::points)                                                       |
-/// | [`GL_LINES`](crate::gl_enums::GL_LINES)           | [`line_strip`](crate::gl_enums::line_strip)                                               |
-/// | [`GL_TRIANGLES`](crate::gl_enums::GL_TRIANGLES)   | [`triangle_strip`](crate::gl_enums::triangle_strip)                                       |
-///
-/// ### Notes
-/// Geometry shaders, and the [`GL_TRIANGLES_ADJACENCY`](crate::gl_enums::GL_TRIANGLES_ADJACENCY),
-/// [`GL_TRIANGLE_STRIP_ADJACENCY`](crate::gl_enums::GL_TRIANGLE_STRIP_ADJACENCY),
-/// [`GL_LINES_ADJACENCY`](crate::gl_enums::GL_LINES_ADJACENCY) and [`GL_LINE_STRIP_ADJACENCY`](crate::gl_enums::GL_LINE_STRIP_ADJACENCY)
-/// primtive modes are available only if the GL version is 3.2 or greater.
-impl Context {
-    pub fn oxidegl_begin_transform_feedback(
-        &mut self,
-        primitive_mode: PrimitiveType,
-    ) -> GlFallible {
-        panic!("command oxidegl_begin_transform_feedback not yet implemented");
-    }
-    pub fn oxidegl_end_transform_feedback(&mut self) -> GlFallible {
-        panic!("command oxidegl_end_transform_feedback not yet implemented");
-    }
-}
-/// ### Parameters
 /// `buf`
 ///
 /// > for [**glBlendEquationi**](crate::context::Context::oxidegl_blend_equationi),
@@ -2529,49 +2558,6 @@ impl Context {
     }
 }
 /// ### Parameters
-/// `nearVal`
-///
-/// > Specifies the mapping of the near clipping plane to window coordinates.
-/// > The initial value is 0.
-///
-/// `farVal`
-///
-/// > Specifies the mapping of the far clipping plane to window coordinates.
-/// > The initial value is 1.
-///
-/// ### Description
-/// After clipping and division by *w*, depth coordinates range from `[inlineq]`
-/// [**glDepthRange**](crate::context::Context::oxidegl_depth_range) specifies
-/// a linear mapping of the normalized depth coordinates in this range to window
-/// depth coordinates. Regardless of the actual depth buffer implementation,
-/// window coordinate depth values are treated as though they range from 0
-/// through 1 (like color components). Thus, the values accepted by [**glDepthRange**](crate::context::Context::oxidegl_depth_range)
-/// are both clamped to this range before they are accepted.
-///
-/// The setting of (0,1) maps the near plane to 0 and the far plane to 1. With
-/// this mapping, the depth buffer range is fully utilized.
-///
-/// ### Notes
-/// It is not necessary that `nearVal` be less than `farVal`. Reverse mappings
-/// such as `[inlineq]` `[inlineq]`
-///
-/// The type of the `nearVal` and `farVal` parameters was changed from `GLclampf`
-/// to `GLfloat` for [**glDepthRangef**](crate::context::Context::oxidegl_depth_rangef)
-/// and from `GLclampd` to `GLdouble` for [**glDepthRange**](crate::context::Context::oxidegl_depth_range).
-/// This change is transparent to user code and is described in detail on
-/// the [**removedTypes**](crate::context::Context::oxideremoved_types) page.
-///
-/// ### Associated Gets
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_DEPTH_RANGE`](crate::gl_enums::GL_DEPTH_RANGE)
-impl Context {
-    pub fn oxidegl_depth_range(&mut self, n: GLdouble, f: GLdouble) -> GlFallible {
-        panic!("command oxidegl_depth_range not yet implemented");
-    }
-    pub fn oxidegl_depth_rangef(&mut self, n: GLfloat, f: GLfloat) -> GlFallible {
-        panic!("command oxidegl_depth_rangef not yet implemented");
-    }
-}
-/// ### Parameters
 /// `framebuffer`
 ///
 /// > Specifies the name of the framebuffer object for [**glNamedFramebufferDrawBuffer**](crate::context::Context::oxidegl_named_framebuffer_draw_buffer)
@@ -4500,166 +4486,6 @@ impl Context {
     }
 }
 /// ### Parameters
-/// `id`
-///
-/// > Specifies the name of a query object.
-///
-/// `buffer`
-///
-/// > Specifies the name of a buffer object.
-///
-/// `pname`
-///
-/// > Specifies the symbolic name of a query object parameter. Accepted values
-/// > are [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT), [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE),
-/// > [`GL_QUERY_RESULT_NO_WAIT`](crate::gl_enums::GL_QUERY_RESULT_NO_WAIT), or
-/// > [`GL_QUERY_TARGET`](crate::gl_enums::GL_QUERY_TARGET).
-///
-/// `params`
-///
-/// > If a buffer is bound to the [`GL_QUERY_RESULT_BUFFER`](crate::gl_enums::GL_QUERY_RESULT_BUFFER)
-/// > target, then `params` is treated as an offset to a location within that
-/// > buffer's data store to receive the result of the query. If no buffer is
-/// > bound to [`GL_QUERY_RESULT_BUFFER`](crate::gl_enums::GL_QUERY_RESULT_BUFFER),
-/// > then `params` is treated as an address in client memory of a variable
-/// > to receive the resulting data.
-///
-/// `offset`
-///
-/// > Specifies the byte offset into `buffer` 's data store where the queried
-/// > result will be written.
-///
-/// ### Description
-/// These commands return a selected parameter of the query object specified
-/// by `id`. [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// returns in `params` a selected parameter of the query object specified
-/// by `id`. [**glGetQueryBufferObject**](crate::context::Context::oxidegl_get_query_buffer_object)
-/// returns in `buffer` a selected parameter of the query object specified
-/// by `id`, by writing it to `buffer` 's data store at the byte offset specified
-/// by `offset`.
-///
-/// `pname` names a specific query object parameter. `pname` can be as follows:
-///
-/// [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT)
-///
-/// > `params` or `buffer` returns the value of the query object's passed samples
-/// > counter. The initial value is 0.
-///
-/// [`GL_QUERY_RESULT_NO_WAIT`](crate::gl_enums::GL_QUERY_RESULT_NO_WAIT)
-///
-/// > If the result of the query is available (that is, a query of [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE)
-/// > would return non-zero), then `params` or `buffer` returns the value of
-/// > the query object's passed samples counter, otherwise, the data referred
-/// > to by `params` or `buffer` is not modified. The initial value is 0.
-///
-/// [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE)
-///
-/// > `params` or `buffer` returns whether the passed samples counter is immediately
-/// > available. If a delay would occur waiting for the query result, [`GL_FALSE`](crate::gl_enums::GL_FALSE)
-/// > is returned. Otherwise, [`GL_TRUE`](crate::gl_enums::GL_TRUE) is returned,
-/// > which also indicates that the results of all previous queries are available
-/// > as well.
-///
-/// [`GL_QUERY_TARGET`](crate::gl_enums::GL_QUERY_TARGET)
-///
-/// > `params` or `buffer` returns the query object's target.
-///
-/// ### Notes
-/// If an error is generated, no change is made to the contents of `params`
-/// or `buffer`.
-///
-/// [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// and [**glGetQueryBufferObject**](crate::context::Context::oxidegl_get_query_buffer_object)
-/// implicitly flush the GL pipeline so that any incomplete rendering delimited
-/// by the occlusion query completes in finite time.
-///
-/// If multiple queries are issued using the same query object `id` before
-/// calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
-/// or [**glGetQueryBufferObject**](crate::context::Context::oxidegl_get_query_buffer_object),
-/// the results of the most recent query will be returned. In this case, when
-/// issuing a new query, the results of the previous query are discarded.
-///
-/// [**glGetQueryObjecti64v**](crate::context::Context::oxidegl_get_query_objecti64v)
-/// and [**glGetQueryObjectui64v**](crate::context::Context::oxidegl_get_query_objectui64v)
-/// are available only if the GL version is 3.3 or greater.
-///
-/// [`GL_QUERY_RESULT_NO_WAIT`](crate::gl_enums::GL_QUERY_RESULT_NO_WAIT) is
-/// accepted for `pname` only if the GL version is 4.4 or greater.
-///
-/// The [`GL_QUERY_RESULT_BUFFER`](crate::gl_enums::GL_QUERY_RESULT_BUFFER)
-/// target is available only if the GL version is 4.4 or higher. On earlier
-/// versions of the GL, `params` is always an address in client memory.
-impl Context {
-    pub unsafe fn oxidegl_get_query_objectiv(
-        &mut self,
-        id: GLuint,
-        pname: QueryObjectParameterName,
-        params: *mut GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_query_objectiv not yet implemented");
-    }
-    pub unsafe fn oxidegl_get_query_objectuiv(
-        &mut self,
-        id: GLuint,
-        pname: QueryObjectParameterName,
-        params: *mut GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_query_objectuiv not yet implemented");
-    }
-    pub unsafe fn oxidegl_get_query_objecti64v(
-        &mut self,
-        id: GLuint,
-        pname: QueryObjectParameterName,
-        params: *mut GLint64,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_query_objecti64v not yet implemented");
-    }
-    pub unsafe fn oxidegl_get_query_objectui64v(
-        &mut self,
-        id: GLuint,
-        pname: QueryObjectParameterName,
-        params: *mut GLuint64,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_query_objectui64v not yet implemented");
-    }
-    pub fn oxidegl_get_query_buffer_objecti64v(
-        &mut self,
-        id: GLuint,
-        buffer: GLuint,
-        pname: QueryObjectParameterName,
-        offset: GLintptr,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_query_buffer_objecti64v not yet implemented");
-    }
-    pub fn oxidegl_get_query_buffer_objectiv(
-        &mut self,
-        id: GLuint,
-        buffer: GLuint,
-        pname: QueryObjectParameterName,
-        offset: GLintptr,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_query_buffer_objectiv not yet implemented");
-    }
-    pub fn oxidegl_get_query_buffer_objectui64v(
-        &mut self,
-        id: GLuint,
-        buffer: GLuint,
-        pname: QueryObjectParameterName,
-        offset: GLintptr,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_query_buffer_objectui64v not yet implemented");
-    }
-    pub fn oxidegl_get_query_buffer_objectuiv(
-        &mut self,
-        id: GLuint,
-        buffer: GLuint,
-        pname: QueryObjectParameterName,
-        offset: GLintptr,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_query_buffer_objectuiv not yet implemented");
-    }
-}
-/// ### Parameters
 /// `target`
 ///
 /// > Specifies the target to which the renderbuffer object is bound for [**glGetRenderbufferParameteriv**](crate::context::Context::oxidegl_get_renderbuffer_parameteriv).
@@ -5534,109 +5360,6 @@ impl Context {
     }
 }
 /// ### Parameters
-/// `xfb`
-///
-/// > The name of an existing transform feedback object, or zero for the default
-/// > transform feedback object.
-///
-/// `pname`
-///
-/// > Property to use for the query. Must be one of the values: [`GL_TRANSFORM_FEEDBACK_BUFFER_BINDING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_BINDING),
-/// > [`GL_TRANSFORM_FEEDBACK_BUFFER_START`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_START),
-/// > [`GL_TRANSFORM_FEEDBACK_BUFFER_SIZE`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_SIZE),
-/// > [`GL_TRANSFORM_FEEDBACK_PAUSED`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PAUSED),
-/// > [`GL_TRANSFORM_FEEDBACK_ACTIVE`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_ACTIVE).
-///
-/// `index`
-///
-/// > Index of the transform feedback stream (for indexed state).
-///
-/// `param`
-///
-/// > The address of a buffer into which will be written the requested state
-/// > information.
-///
-/// ### Description
-/// In order to use the Transform Feedback functionality, you need to configure
-/// the Transform Feedback Buffer indexed bindings. This can be achieved by
-/// either using [**glBindBufferBase**](crate::context::Context::oxidegl_bind_buffer_base)
-/// or [**glBindBuffersBase**](crate::context::Context::oxidegl_bind_buffers_base)
-/// to associate whole buffer object storage to one of the Transform Feedback
-/// Binding Points, or by calling [**glBindBufferRange**](crate::context::Context::oxidegl_bind_buffer_range)
-/// or [**glBindBuffersRange**](crate::context::Context::oxidegl_bind_buffers_range)
-/// to use a region of a buffer object storage for the binding. You may want
-/// to (but are not required to) bind a Transform Feedback Object first, in
-/// order to cache the binding configuration. This usually allows you to restore
-/// the Transform Feedback configuration faster, than if you were to execute
-/// a list of API calls necessary to set up the Transform Feedback state of
-/// your liking.
-///
-/// This reference page discusses two types of getters that operate on Transform
-/// Feedback Objects and their bindings.
-///
-/// The first class operates on general Transform Feedback binding point and
-/// includes [**glGetTransformFeedbackiv**](crate::context::Context::oxidegl_get_transform_feedbackiv)
-/// function. [**glGetTransformFeedbackiv**](crate::context::Context::oxidegl_get_transform_feedbackiv)
-/// can be used to retrieve information about Transform Feedback object bound
-/// to the general Transform Feedback binding point, as configured with a [**glBindTransformFeedback**](crate::context::Context::oxidegl_bind_transform_feedback)
-/// call. In this case, you can check:
-///
-/// > What the ID of the currently bound Transform Feedback Object is;( [`GL_TRANSFORM_FEEDBACK_BUFFER_BINDING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_BINDING))
-///
-/// > Whether the Transform Feedback process is currently paused;( [`GL_TRANSFORM_FEEDBACK_PAUSED`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PAUSED))
-///
-/// > Whether the Transform Feedback process has been begun and is currently
-/// > undergoing;( [`GL_TRANSFORM_FEEDBACK_ACTIVE`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_ACTIVE))
-///
-///
-/// The latter class, which includes [**`glGetTransformFeedbacki_v`**](crate::context::Context::oxidegl_get_transform_feedbacki_v)
-/// and [**`glGetTransformFeedbacki64_v`**](crate::context::Context::oxidegl_get_transform_feedbacki64_v)
-/// functions, can be used to check what the current configuration of each
-/// of the buffer object regions bound to Transform Feedback Buffer binding
-/// points is. This allows you to query for the following information:
-///
-/// > [**glGetTransformFeedbacki_v**](crate::context::Context::oxidegl_get_transform_feedbacki_v)
-/// > only: What the ID of the Buffer Object bound to a Transform Feedback Binding
-/// > Point of user-specified index is;( [`GL_TRANSFORM_FEEDBACK_BUFFER_BINDING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_BINDING)
-/// > ).
-///
-/// > [**glGetTransformFeedbacki64_v**](crate::context::Context::oxidegl_get_transform_feedbacki64_v)
-/// > only: What the start offset configured for the binding is;( [`GL_TRANSFORM_FEEDBACK_BUFFER_START`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_START)
-/// > );
-///
-/// > [**glGetTransformFeedbacki64_v**](crate::context::Context::oxidegl_get_transform_feedbacki64_v)
-/// > only: What the length of the region used for the binding is;( [`GL_TRANSFORM_FEEDBACK_BUFFER_SIZE`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_SIZE)
-/// > );
-///
-impl Context {
-    pub unsafe fn oxidegl_get_transform_feedbackiv(
-        &mut self,
-        xfb: GLuint,
-        pname: TransformFeedbackPName,
-        param: *mut GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_transform_feedbackiv not yet implemented");
-    }
-    pub unsafe fn oxidegl_get_transform_feedbacki_v(
-        &mut self,
-        xfb: GLuint,
-        pname: TransformFeedbackPName,
-        index: GLuint,
-        param: *mut GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_transform_feedbacki_v not yet implemented");
-    }
-    pub unsafe fn oxidegl_get_transform_feedbacki64_v(
-        &mut self,
-        xfb: GLuint,
-        pname: TransformFeedbackPName,
-        index: GLuint,
-        param: *mut GLint64,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_transform_feedbacki64_v not yet implemented");
-    }
-}
-/// ### Parameters
 /// `program`
 ///
 /// > Specifies the program object to be queried.
@@ -6660,296 +6383,7 @@ impl Context {
     }
 }
 /// ### Parameters
-/// `barriers`
-///
-/// > Specifies the barriers to insert.
-///
-/// > For [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier),
-/// > must be a bitwise combination of any of [`GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT`](crate::gl_enums::GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT),
-/// > [`GL_ELEMENT_ARRAY_BARRIER_BIT`](crate::gl_enums::GL_ELEMENT_ARRAY_BARRIER_BIT),
-/// > [`GL_UNIFORM_BARRIER_BIT`](crate::gl_enums::GL_UNIFORM_BARRIER_BIT), [`GL_TEXTURE_FETCH_BARRIER_BIT`](crate::gl_enums::GL_TEXTURE_FETCH_BARRIER_BIT),
-/// > [`GL_SHADER_IMAGE_ACCESS_BARRIER_BIT`](crate::gl_enums::GL_SHADER_IMAGE_ACCESS_BARRIER_BIT),
-/// > [`GL_COMMAND_BARRIER_BIT`](crate::gl_enums::GL_COMMAND_BARRIER_BIT), [`GL_PIXEL_BUFFER_BARRIER_BIT`](crate::gl_enums::GL_PIXEL_BUFFER_BARRIER_BIT),
-/// > [`GL_TEXTURE_UPDATE_BARRIER_BIT`](crate::gl_enums::GL_TEXTURE_UPDATE_BARRIER_BIT),
-/// > [`GL_BUFFER_UPDATE_BARRIER_BIT`](crate::gl_enums::GL_BUFFER_UPDATE_BARRIER_BIT),
-/// > [`GL_FRAMEBUFFER_BARRIER_BIT`](crate::gl_enums::GL_FRAMEBUFFER_BARRIER_BIT),
-/// > [`GL_TRANSFORM_FEEDBACK_BARRIER_BIT`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BARRIER_BIT),
-/// > [`GL_ATOMIC_COUNTER_BARRIER_BIT`](crate::gl_enums::GL_ATOMIC_COUNTER_BARRIER_BIT),
-/// > or [`GL_SHADER_STORAGE_BARRIER_BIT`](crate::gl_enums::GL_SHADER_STORAGE_BARRIER_BIT).
-///
-/// > For [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier),
-/// > must be a bitwise combination of any of [`GL_ATOMIC_COUNTER_BARRIER_BIT`](crate::gl_enums::GL_ATOMIC_COUNTER_BARRIER_BIT),
-/// > or [`GL_FRAMEBUFFER_BARRIER_BIT`](crate::gl_enums::GL_FRAMEBUFFER_BARRIER_BIT),
-/// > [`GL_SHADER_IMAGE_ACCESS_BARRIER_BIT`](crate::gl_enums::GL_SHADER_IMAGE_ACCESS_BARRIER_BIT),
-/// > [`GL_SHADER_STORAGE_BARRIER_BIT`](crate::gl_enums::GL_SHADER_STORAGE_BARRIER_BIT).
-/// > [`GL_TEXTURE_FETCH_BARRIER_BIT`](crate::gl_enums::GL_TEXTURE_FETCH_BARRIER_BIT),
-/// > or [`GL_UNIFORM_BARRIER_BIT`](crate::gl_enums::GL_UNIFORM_BARRIER_BIT).
-///
-/// > If the special value [`GL_ALL_BARRIER_BITS`](crate::gl_enums::GL_ALL_BARRIER_BITS)
-/// > is specified, all supported barriers for the corresponding command will
-/// > be inserted.
-///
-/// ### Description
-/// [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier)
-/// defines a barrier ordering the memory transactions issued prior to the
-/// command relative to those issued after the barrier. For the purposes of
-/// this ordering, memory transactions performed by shaders are considered
-/// to be issued by the rendering command that triggered the execution of the
-/// shader. `barriers` is a bitfield indicating the set of operations that
-/// are synchronized with shader stores; the bits used in `barriers` are as
-/// follows:
-///
-/// [`GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT`](crate::gl_enums::GL_VERTEX_ATTRIB_ARRAY_BARRIER_BIT)
-///
-/// > If set, vertex data sourced from buffer objects after the barrier will
-/// > reflect data written by shaders prior to the barrier. The set of buffer
-/// > objects affected by this bit is derived from the buffer object bindings
-/// > used for generic vertex attributes derived from the [`GL_VERTEX_ATTRIB_ARRAY_BUFFER`](crate::gl_enums::GL_VERTEX_ATTRIB_ARRAY_BUFFER)
-/// > bindings.
-///
-/// [`GL_ELEMENT_ARRAY_BARRIER_BIT`](crate::gl_enums::GL_ELEMENT_ARRAY_BARRIER_BIT)
-///
-/// > If set, vertex array indices sourced from buffer objects after the barrier
-/// > will reflect data written by shaders prior to the barrier. The buffer objects
-/// > affected by this bit are derived from the [`GL_ELEMENT_ARRAY_BUFFER`](crate::gl_enums::GL_ELEMENT_ARRAY_BUFFER)
-/// > binding.
-///
-/// [`GL_UNIFORM_BARRIER_BIT`](crate::gl_enums::GL_UNIFORM_BARRIER_BIT)
-///
-/// > Shader uniforms sourced from buffer objects after the barrier will reflect
-/// > data written by shaders prior to the barrier.
-///
-/// [`GL_TEXTURE_FETCH_BARRIER_BIT`](crate::gl_enums::GL_TEXTURE_FETCH_BARRIER_BIT)
-///
-/// > Texture fetches from shaders, including fetches from buffer object memory
-/// > via buffer textures, after the barrier will reflect data written by shaders
-/// > prior to the barrier.
-///
-/// [`GL_SHADER_IMAGE_ACCESS_BARRIER_BIT`](crate::gl_enums::GL_SHADER_IMAGE_ACCESS_BARRIER_BIT)
-///
-/// > Memory accesses using shader image load, store, and atomic built-in functions
-/// > issued after the barrier will reflect data written by shaders prior to
-/// > the barrier. Additionally, image stores and atomics issued after the barrier
-/// > will not execute until all memory accesses (e.g., loads, stores, texture
-/// > fetches, vertex fetches) initiated prior to the barrier complete.
-///
-/// [`GL_COMMAND_BARRIER_BIT`](crate::gl_enums::GL_COMMAND_BARRIER_BIT)
-///
-/// > Command data sourced from buffer objects by Draw*Indirect commands after
-/// > the barrier will reflect data written by shaders prior to the barrier.
-/// > The buffer objects affected by this bit are derived from the [`GL_DRAW_INDIRECT_BUFFER`](crate::gl_enums::GL_DRAW_INDIRECT_BUFFER)
-/// > binding.
-///
-/// [`GL_PIXEL_BUFFER_BARRIER_BIT`](crate::gl_enums::GL_PIXEL_BUFFER_BARRIER_BIT)
-///
-/// > Reads and writes of buffer objects via the [`GL_PIXEL_PACK_BUFFER`](crate::gl_enums::GL_PIXEL_PACK_BUFFER)
-/// > and [`GL_PIXEL_UNPACK_BUFFER`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER)
-/// > bindings (via [**glReadPixels**](crate::context::Context::oxidegl_read_pixels),
-/// > [**glTexSubImage1D**](crate::context::Context::oxidegl_tex_sub_image1_d),
-/// > etc.) after the barrier will reflect data written by shaders prior to
-/// > the barrier. Additionally, buffer object writes issued after the barrier
-/// > will wait on the completion of all shader writes initiated prior to the
-/// > barrier.
-///
-/// [`GL_TEXTURE_UPDATE_BARRIER_BIT`](crate::gl_enums::GL_TEXTURE_UPDATE_BARRIER_BIT)
-///
-/// > Writes to a texture via [**glTex(Sub)Image***](crate::context::Context::oxidegl_tex(_sub)_image*),
-/// > [**glCopyTex(Sub)Image***](crate::context::Context::oxidegl_copy_tex(_sub)_image*),
-/// > [**glCompressedTex(Sub)Image***](crate::context::Context::oxidegl_compressed_tex(_sub)_image*),
-/// > and reads via [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
-/// > after the barrier will reflect data written by shaders prior to the barrier.
-/// > Additionally, texture writes from these commands issued after the barrier
-/// > will not execute until all shader writes initiated prior to the barrier
-/// > complete.
-///
-/// [`GL_BUFFER_UPDATE_BARRIER_BIT`](crate::gl_enums::GL_BUFFER_UPDATE_BARRIER_BIT)
-///
-/// > Reads or writes via [**glBufferSubData**](crate::context::Context::oxidegl_buffer_sub_data),
-/// > [**glCopyBufferSubData**](crate::context::Context::oxidegl_copy_buffer_sub_data),
-/// > or [**glGetBufferSubData**](crate::context::Context::oxidegl_get_buffer_sub_data),
-/// > or to buffer object memory mapped by [**glMapBuffer**](crate::context::Context::oxidegl_map_buffer)
-/// > or [**glMapBufferRange**](crate::context::Context::oxidegl_map_buffer_range)
-/// > after the barrier will reflect data written by shaders prior to the barrier.
-/// > Additionally, writes via these commands issued after the barrier will wait
-/// > on the completion of any shader writes to the same memory initiated prior
-/// > to the barrier.
-///
-/// [`GL_CLIENT_MAPPED_BUFFER_BARRIER_BIT`](crate::gl_enums::GL_CLIENT_MAPPED_BUFFER_BARRIER_BIT)
-///
-/// > Access by the client to persistent mapped regions of buffer objects will
-/// > reflect data written by shaders prior to the barrier. Note that this may
-/// > cause additional synchronization operations.
-///
-/// [`GL_FRAMEBUFFER_BARRIER_BIT`](crate::gl_enums::GL_FRAMEBUFFER_BARRIER_BIT)
-///
-/// > Reads and writes via framebuffer object attachments after the barrier will
-/// > reflect data written by shaders prior to the barrier. Additionally, framebuffer
-/// > writes issued after the barrier will wait on the completion of all shader
-/// > writes issued prior to the barrier.
-///
-/// [`GL_TRANSFORM_FEEDBACK_BARRIER_BIT`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BARRIER_BIT)
-///
-/// > Writes via transform feedback bindings after the barrier will reflect data
-/// > written by shaders prior to the barrier. Additionally, transform feedback
-/// > writes issued after the barrier will wait on the completion of all shader
-/// > writes issued prior to the barrier.
-///
-/// [`GL_ATOMIC_COUNTER_BARRIER_BIT`](crate::gl_enums::GL_ATOMIC_COUNTER_BARRIER_BIT)
-///
-/// > Accesses to atomic counters after the barrier will reflect writes prior
-/// > to the barrier.
-///
-/// [`GL_SHADER_STORAGE_BARRIER_BIT`](crate::gl_enums::GL_SHADER_STORAGE_BARRIER_BIT)
-///
-/// > Accesses to shader storage blocks after the barrier will reflect writes
-/// > prior to the barrier.
-///
-/// [`GL_QUERY_BUFFER_BARRIER_BIT`](crate::gl_enums::GL_QUERY_BUFFER_BARRIER_BIT)
-///
-/// > Writes of buffer objects via the [`GL_QUERY_BUFFER`](crate::gl_enums::GL_QUERY_BUFFER)
-/// > binding after the barrier will reflect data written by shaders prior to
-/// > the barrier. Additionally, buffer object writes issued after the barrier
-/// > will wait on the completion of all shader writes initiated prior to the
-/// > barrier.
-///
-///
-/// If `barriers` is [`GL_ALL_BARRIER_BITS`](crate::gl_enums::GL_ALL_BARRIER_BITS),
-/// shader memory accesses will be synchronized relative to all the operations
-/// described above.
-///
-/// Implementations may cache buffer object and texture image memory that could
-/// be written by shaders in multiple caches; for example, there may be separate
-/// caches for texture, vertex fetching, and one or more caches for shader
-/// memory accesses. Implementations are not required to keep these caches
-/// coherent with shader memory writes. Stores issued by one invocation may
-/// not be immediately observable by other pipeline stages or other shader
-/// invocations because the value stored may remain in a cache local to the
-/// processor executing the store, or because data overwritten by the store
-/// is still in a cache elsewhere in the system. When [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier)
-/// is called, the GL flushes and/or invalidates any caches relevant to the
-/// operations specified by the `barriers` parameter to ensure consistent ordering
-/// of operations across the barrier.
-///
-/// To allow for independent shader invocations to communicate by reads and
-/// writes to a common memory address, image variables in the OpenGL Shading
-/// Language may be declared as "coherent". Buffer object or texture image
-/// memory accessed through such variables may be cached only if caches are
-/// automatically updated due to stores issued by any other shader invocation.
-/// If the same address is accessed using both coherent and non-coherent variables,
-/// the accesses using variables declared as coherent will observe the results
-/// stored using coherent variables in other invocations. Using variables declared
-/// as "coherent" guarantees only that the results of stores will be immediately
-/// visible to shader invocations using similarly-declared variables; calling
-/// [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier)
-/// is required to ensure that the stores are visible to other operations.
-///
-/// The following guidelines may be helpful in choosing when to use coherent
-/// memory accesses and when to use barriers.
-///
-/// > Data that are read-only or constant may be accessed without using coherent
-/// > variables or calling [**MemoryBarrier**](crate::context::Context::oxide_memory_barrier)
-/// > (). Updates to the read-only data via API calls such as [**glBufferSubData**](crate::context::Context::oxidegl_buffer_sub_data)
-/// > will invalidate shader caches implicitly as required.
-///
-/// > Data that are shared between shader invocations at a fine granularity (e.g.,
-/// > written by one invocation, consumed by another invocation) should use coherent
-/// > variables to read and write the shared data.
-///
-/// > Data written by one shader invocation and consumed by other shader invocations
-/// > launched as a result of its execution ("dependent invocations") should
-/// > use coherent variables in the producing shader invocation and call [**memoryBarrier**](crate::context::Context::oxidememory_barrier)
-/// > () after the last write. The consuming shader invocation should also use
-/// > coherent variables.
-///
-/// > Data written to image variables in one rendering pass and read by the shader
-/// > in a later pass need not use coherent variables or memoryBarrier(). Calling
-/// > [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier)
-/// > with the SHADER_IMAGE_ACCESS_BARRIER_BIT set in `barriers` between passes
-/// > is necessary.
-///
-/// > Data written by the shader in one rendering pass and read by another mechanism
-/// > (e.g., vertex or index buffer pulling) in a later pass need not use coherent
-/// > variables or [**memoryBarrier**](crate::context::Context::oxidememory_barrier)
-/// > (). Calling [**glMemoryBarrier**](crate::context::Context::oxidegl_memory_barrier)
-/// > with the appropriate bits set in `barriers` between passes is necessary.
-///
-///
-/// ### Notes
-/// [`GL_SHADER_STORAGE_BARRIER_BIT`](crate::gl_enums::GL_SHADER_STORAGE_BARRIER_BIT)
-/// is available only if the GL version is 4.3 or higher.
-///
-/// [`GL_QUERY_BUFFER_BARRIER_BIT`](crate::gl_enums::GL_QUERY_BUFFER_BARRIER_BIT)
-/// is available only if the GL version is 4.4 or higher.
-impl Context {
-    pub fn oxidegl_memory_barrier(&mut self, barriers: MemoryBarrierMask) -> GlFallible {
-        panic!("command oxidegl_memory_barrier not yet implemented");
-    }
-    pub fn oxidegl_memory_barrier_by_region(&mut self, barriers: MemoryBarrierMask) -> GlFallible {
-        panic!("command oxidegl_memory_barrier_by_region not yet implemented");
-    }
-}
-/// ### Parameters
-/// `pname`
-///
-/// > Specifies the name of the parameter to set. The symbolc constants [`GL_PATCH_VERTICES`](crate::gl_enums::GL_PATCH_VERTICES),
-/// > [`GL_PATCH_DEFAULT_OUTER_LEVEL`](crate::gl_enums::GL_PATCH_DEFAULT_OUTER_LEVEL),
-/// > and [`GL_PATCH_DEFAULT_INNER_LEVEL`](crate::gl_enums::GL_PATCH_DEFAULT_INNER_LEVEL)
-/// > are accepted.
-///
-/// `value`
-///
-/// > Specifies the new value for the parameter given by `pname`.
-///
-/// `values`
-///
-/// > Specifies the address of an array containing the new values for the parameter
-/// > given by `pname`.
-///
-/// ### Description
-/// [**glPatchParameter**](crate::context::Context::oxidegl_patch_parameter)
-/// specifies the parameters that will be used for patch primitives. `pname`
-/// specifies the parameter to modify and must be either [`GL_PATCH_VERTICES`](crate::gl_enums::GL_PATCH_VERTICES),
-/// [`GL_PATCH_DEFAULT_OUTER_LEVEL`](crate::gl_enums::GL_PATCH_DEFAULT_OUTER_LEVEL)
-/// or [`GL_PATCH_DEFAULT_INNER_LEVEL`](crate::gl_enums::GL_PATCH_DEFAULT_INNER_LEVEL).
-/// For [**glPatchParameteri**](crate::context::Context::oxidegl_patch_parameteri),
-/// `value` specifies the new value for the parameter specified by `pname`.
-/// For [**glPatchParameterfv**](crate::context::Context::oxidegl_patch_parameterfv),
-/// `values` specifies the address of an array containing the new values for
-/// the parameter specified by `pname`.
-///
-/// When `pname` is [`GL_PATCH_VERTICES`](crate::gl_enums::GL_PATCH_VERTICES),
-/// `value` specifies the number of vertices that will be used to make up a
-/// single patch primitive. Patch primitives are consumed by the tessellation
-/// control shader (if present) and subsequently used for tessellation. When
-/// primitives are specified using [**glDrawArrays**](crate::context::Context::oxidegl_draw_arrays)
-/// or a similar function, each patch will be made from `parameter` control
-/// points, each represented by a vertex taken from the enabeld vertex arrays.
-/// `parameter` must be greater than zero, and less than or equal to the value
-/// of [`GL_MAX_PATCH_VERTICES`](crate::gl_enums::GL_MAX_PATCH_VERTICES).
-///
-/// When `pname` is [`GL_PATCH_DEFAULT_OUTER_LEVEL`](crate::gl_enums::GL_PATCH_DEFAULT_OUTER_LEVEL)
-/// or [`GL_PATCH_DEFAULT_INNER_LEVEL`](crate::gl_enums::GL_PATCH_DEFAULT_INNER_LEVEL),
-/// `values` contains the address of an array contiaining the default outer
-/// or inner tessellation levels, respectively, to be used when no tessellation
-/// control shader is present.
-impl Context {
-    pub fn oxidegl_patch_parameteri(
-        &mut self,
-        pname: PatchParameterName,
-        value: GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_patch_parameteri not yet implemented");
-    }
-    pub unsafe fn oxidegl_patch_parameterfv(
-        &mut self,
-        pname: PatchParameterName,
-        values: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_patch_parameterfv not yet implemented");
-    }
-}
-/// ### Parameters
-/// `pname`
+/// `pname`
 ///
 /// > Specifies the symbolic name of the parameter to be set. Six values affect
 /// > the packing of pixel data into memory: [`GL_PACK_SWAP_BYTES`](crate::gl_enums::GL_PACK_SWAP_BYTES),
@@ -7230,1098 +6664,1067 @@ impl Context {
     }
 }
 /// ### Parameters
-/// `pname`
-///
-/// > Specifies a single-valued point parameter. [`GL_POINT_FADE_THRESHOLD_SIZE`](crate::gl_enums::GL_POINT_FADE_THRESHOLD_SIZE),
-/// > and [`GL_POINT_SPRITE_COORD_ORIGIN`](crate::gl_enums::GL_POINT_SPRITE_COORD_ORIGIN)
-/// > are accepted.
-///
-/// `param`
+/// `framebuffer`
 ///
-/// > For [**glPointParameterf**](crate::context::Context::oxidegl_point_parameterf)
-/// > and [**glPointParameteri**](crate::context::Context::oxidegl_point_parameteri),
-/// > specifies the value that `pname` will be set to.
+/// > Specifies the name of the framebuffer object for [**glNamedFramebufferReadBuffer**](crate::context::Context::oxidegl_named_framebuffer_read_buffer)
+/// > function.
 ///
-/// `params`
+/// `mode`
 ///
-/// > For [**glPointParameterfv**](crate::context::Context::oxidegl_point_parameterfv)
-/// > and [**glPointParameteriv**](crate::context::Context::oxidegl_point_parameteriv),
-/// > specifies a pointer to an array where the value or values to be assigned
-/// > to `pname` are stored.
+/// > Specifies a color buffer. Accepted values are [`GL_FRONT_LEFT`](crate::gl_enums::GL_FRONT_LEFT),
+/// > [`GL_FRONT_RIGHT`](crate::gl_enums::GL_FRONT_RIGHT), [`GL_BACK_LEFT`](crate::gl_enums::GL_BACK_LEFT),
+/// > [`GL_BACK_RIGHT`](crate::gl_enums::GL_BACK_RIGHT), [`GL_FRONT`](crate::gl_enums::GL_FRONT),
+/// > [`GL_BACK`](crate::gl_enums::GL_BACK), [`GL_LEFT`](crate::gl_enums::GL_LEFT),
+/// > [`GL_RIGHT`](crate::gl_enums::GL_RIGHT), and the constants [`GL_COLOR_ATTACHMENT`](crate::gl_enums::GL_COLOR_ATTACHMENT)
+/// > *i*.
 ///
 /// ### Description
-/// The following values are accepted for `pname`:
-///
-/// [`GL_POINT_FADE_THRESHOLD_SIZE`](crate::gl_enums::GL_POINT_FADE_THRESHOLD_SIZE)
-///
-///
-/// > `params` is a single floating-point value that specifies the threshold
-/// > value to which point sizes are clamped if they exceed the specified value.
-/// > The default value is 1.0.
+/// [**glReadBuffer**](crate::context::Context::oxidegl_read_buffer) specifies
+/// a color buffer as the source for subsequent [**glReadPixels**](crate::context::Context::oxidegl_read_pixels),
+/// [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d),
+/// [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d),
+/// [**glCopyTexSubImage1D**](crate::context::Context::oxidegl_copy_tex_sub_image1_d),
+/// [**glCopyTexSubImage2D**](crate::context::Context::oxidegl_copy_tex_sub_image2_d),
+/// and [**glCopyTexSubImage3D**](crate::context::Context::oxidegl_copy_tex_sub_image3_d)
+/// commands. `mode` accepts one of twelve or more predefined values. In a
+/// fully configured system, [`GL_FRONT`](crate::gl_enums::GL_FRONT), [`GL_LEFT`](crate::gl_enums::GL_LEFT),
+/// and [`GL_FRONT_LEFT`](crate::gl_enums::GL_FRONT_LEFT) all name the front
+/// left buffer, [`GL_FRONT_RIGHT`](crate::gl_enums::GL_FRONT_RIGHT) and [`GL_RIGHT`](crate::gl_enums::GL_RIGHT)
+/// name the front right buffer, and [`GL_BACK_LEFT`](crate::gl_enums::GL_BACK_LEFT)
+/// and [`GL_BACK`](crate::gl_enums::GL_BACK) name the back left buffer. Further
+/// more, the constants [`GL_COLOR_ATTACHMENT`](crate::gl_enums::GL_COLOR_ATTACHMENT)
+/// *i* may be used to indicate the *i* *i* ranges from zero to the value of
+/// [`GL_MAX_COLOR_ATTACHMENTS`](crate::gl_enums::GL_MAX_COLOR_ATTACHMENTS)
+/// minus one.
 ///
-/// [`GL_POINT_SPRITE_COORD_ORIGIN`](crate::gl_enums::GL_POINT_SPRITE_COORD_ORIGIN)
+/// Nonstereo double-buffered configurations have only a front left and a back
+/// left buffer. Single-buffered configurations have a front left and a front
+/// right buffer if stereo, and only a front left buffer if nonstereo. It is
+/// an error to specify a nonexistent buffer to [**glReadBuffer**](crate::context::Context::oxidegl_read_buffer).
 ///
+/// `mode` is initially [`GL_FRONT`](crate::gl_enums::GL_FRONT) in single-buffered
+/// configurations and [`GL_BACK`](crate::gl_enums::GL_BACK) in double-buffered
+/// configurations.
 ///
-/// > `params` is a single enum specifying the point sprite texture coordinate
-/// > origin, either [`GL_LOWER_LEFT`](crate::gl_enums::GL_LOWER_LEFT) or [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT).
-/// > The default value is [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT).
+/// For [**glReadBuffer**](crate::context::Context::oxidegl_read_buffer), the
+/// target framebuffer object is that bound to [`GL_READ_FRAMEBUFFER`](crate::gl_enums::GL_READ_FRAMEBUFFER).
+/// For [**glNamedFramebufferReadBuffer**](crate::context::Context::oxidegl_named_framebuffer_read_buffer),
+/// `framebuffer` must either be zero or the name of the target framebuffer
+/// object. If `framebuffer` is zero, then the default read framebuffer is
+/// affected.
 ///
 /// ### Associated Gets
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_POINT_FADE_THRESHOLD_SIZE`](crate::gl_enums::GL_POINT_FADE_THRESHOLD_SIZE)
-///
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_POINT_SPRITE_COORD_ORIGIN`](crate::gl_enums::GL_POINT_SPRITE_COORD_ORIGIN)
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_READ_BUFFER`](crate::gl_enums::GL_READ_BUFFER)
 impl Context {
-    pub fn oxidegl_point_parameterf(&mut self, pname: GLenum, param: GLfloat) -> GlFallible {
-        panic!("command oxidegl_point_parameterf not yet implemented");
-    }
-    pub unsafe fn oxidegl_point_parameterfv(
-        &mut self,
-        pname: GLenum,
-        params: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_point_parameterfv not yet implemented");
-    }
-    pub fn oxidegl_point_parameteri(&mut self, pname: GLenum, param: GLint) -> GlFallible {
-        panic!("command oxidegl_point_parameteri not yet implemented");
+    pub fn oxidegl_read_buffer(&mut self, src: ReadBufferMode) -> GlFallible {
+        panic!("command oxidegl_read_buffer not yet implemented");
     }
-    pub unsafe fn oxidegl_point_parameteriv(
+    pub fn oxidegl_named_framebuffer_read_buffer(
         &mut self,
-        pname: GLenum,
-        params: *const GLint,
+        framebuffer: GLuint,
+        src: ColorBuffer,
     ) -> GlFallible {
-        panic!("command oxidegl_point_parameteriv not yet implemented");
+        panic!("command oxidegl_named_framebuffer_read_buffer not yet implemented");
     }
 }
 /// ### Parameters
-/// `program`
+/// `x`
 ///
-/// > Specifies the handle of the program containing the uniform variable to
-/// > be modified.
+/// `y`
 ///
-/// `location`
+/// > Specify the window coordinates of the first pixel that is read from the
+/// > frame buffer. This location is the lower left corner of a rectangular block
+/// > of pixels.
 ///
-/// > Specifies the location of the uniform variable to be modified.
+/// `width`
 ///
-/// `count`
+/// `height`
 ///
-/// > For the vector commands( [**glProgramUniform*v**](crate::context::Context::oxidegl_program_uniform*v)
-/// > ), specifies the number of elements that are to be modified. This should
-/// > be 1 if the targeted uniform variable is not an array, and 1 or more if
-/// > it is an array.
+/// > Specify the dimensions of the pixel rectangle. `width` and `height` of
+/// > one correspond to a single pixel.
 ///
-/// > For the matrix commands( [**glProgramUniformMatrix***](crate::context::Context::oxidegl_program_uniform_matrix*)
-/// > ), specifies the number of matrices that are to be modified. This should
-/// > be 1 if the targeted uniform variable is not an array of matrices, and
-/// > 1 or more if it is an array of matrices.
+/// `format`
 ///
-/// `transpose`
+/// > Specifies the format of the pixel data. The following symbolic values are
+/// > accepted: [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX), [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT),
+/// > [`GL_DEPTH_STENCIL`](crate::gl_enums::GL_DEPTH_STENCIL), [`GL_RED`](crate::gl_enums::GL_RED),
+/// > [`GL_GREEN`](crate::gl_enums::GL_GREEN), [`GL_BLUE`](crate::gl_enums::GL_BLUE),
+/// > [`GL_RGB`](crate::gl_enums::GL_RGB), [`GL_BGR`](crate::gl_enums::GL_BGR),
+/// > [`GL_RGBA`](crate::gl_enums::GL_RGBA), and [`GL_BGRA`](crate::gl_enums::GL_BGRA).
 ///
-/// > For the matrix commands, specifies whether to transpose the matrix as the
-/// > values are loaded into the uniform variable.
+/// `type`
 ///
-/// `v0`, `v1`, `v2`, `v3`
+/// > Specifies the data type of the pixel data. Must be one of [`GL_UNSIGNED_BYTE`](crate::gl_enums::GL_UNSIGNED_BYTE),
+/// > [`GL_BYTE`](crate::gl_enums::GL_BYTE), [`GL_UNSIGNED_SHORT`](crate::gl_enums::GL_UNSIGNED_SHORT),
+/// > [`GL_SHORT`](crate::gl_enums::GL_SHORT), [`GL_UNSIGNED_INT`](crate::gl_enums::GL_UNSIGNED_INT),
+/// > [`GL_INT`](crate::gl_enums::GL_INT), [`GL_HALF_FLOAT`](crate::gl_enums::GL_HALF_FLOAT),
+/// > [`GL_FLOAT`](crate::gl_enums::GL_FLOAT), [`GL_UNSIGNED_BYTE_3_3_2`](crate::gl_enums::GL_UNSIGNED_BYTE_3_3_2),
+/// > [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::gl_enums::GL_UNSIGNED_BYTE_2_3_3_REV),
+/// > [`GL_UNSIGNED_SHORT_5_6_5`](crate::gl_enums::GL_UNSIGNED_SHORT_5_6_5), [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::gl_enums::GL_UNSIGNED_SHORT_5_6_5_REV),
+/// > [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::gl_enums::GL_UNSIGNED_SHORT_4_4_4_4),
+/// > [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::gl_enums::GL_UNSIGNED_SHORT_4_4_4_4_REV),
+/// > [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::gl_enums::GL_UNSIGNED_SHORT_5_5_5_1),
+/// > [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::gl_enums::GL_UNSIGNED_SHORT_1_5_5_5_REV),
+/// > [`GL_UNSIGNED_INT_8_8_8_8`](crate::gl_enums::GL_UNSIGNED_INT_8_8_8_8), [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::gl_enums::GL_UNSIGNED_INT_8_8_8_8_REV),
+/// > [`GL_UNSIGNED_INT_10_10_10_2`](crate::gl_enums::GL_UNSIGNED_INT_10_10_10_2),
+/// > [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::gl_enums::GL_UNSIGNED_INT_2_10_10_10_REV),
+/// > [`GL_UNSIGNED_INT_24_8`](crate::gl_enums::GL_UNSIGNED_INT_24_8), [`GL_UNSIGNED_INT_10F_11F_11F_REV`](crate::gl_enums::GL_UNSIGNED_INT_10F_11F_11F_REV),
+/// > [`GL_UNSIGNED_INT_5_9_9_9_REV`](crate::gl_enums::GL_UNSIGNED_INT_5_9_9_9_REV),
+/// > or [`GL_FLOAT_32_UNSIGNED_INT_24_8_REV`](crate::gl_enums::GL_FLOAT_32_UNSIGNED_INT_24_8_REV).
 ///
-/// > For the scalar commands, specifies the new values to be used for the specified
-/// > uniform variable.
+/// `bufSize`
 ///
-/// `value`
+/// > Specifies the size of the buffer `data` for [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels)
+/// > function.
+///
+/// `data`
 ///
-/// > For the vector and matrix commands, specifies a pointer to an array of
-/// > `count` values that will be used to update the specified uniform variable.
+/// > Returns the pixel data.
 ///
 /// ### Description
-/// [**glProgramUniform**](crate::context::Context::oxidegl_program_uniform)
-/// modifies the value of a uniform variable or a uniform variable array. The
-/// location of the uniform variable to be modified is specified by `location`,
-/// which should be a value returned by [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location).
-/// [**glProgramUniform**](crate::context::Context::oxidegl_program_uniform)
-/// operates on the program object specified by `program`.
-///
-/// The commands [**glProgramUniform{1|2|3|4}{f|i|ui}**](crate::context::Context::oxidegl_program_uniform{1|2|3|4}{f|i|ui})
-/// are used to change the value of the uniform variable specified by `location`
-/// using the values passed as arguments. The number specified in the command
-/// should match the number of components in the data type of the specified
-/// uniform variable (e.g., [**1**](crate::context::Context::oxide1) for [**2**](crate::context::Context::oxide2)
-/// for [**f**](crate::context::Context::oxidef) indicates that floating-point
-/// values are being passed; the suffix [**i**](crate::context::Context::oxidei)
-/// indicates that integer values are being passed; the suffix [**ui**](crate::context::Context::oxideui)
-/// indicates that unsigned integer values are being passed, and this type
-/// should also match the data type of the specified uniform variable. The
-/// [**i**](crate::context::Context::oxidei) variants of this function should
-/// be used to provide values for uniform variables defined as [**ui**](crate::context::Context::oxideui)
-/// variants of this function should be used to provide values for uniform
-/// variables defined as [**f**](crate::context::Context::oxidef) variants
-/// should be used to provide values for uniform variables of type [**i**](crate::context::Context::oxidei),
-/// [**ui**](crate::context::Context::oxideui) or [**f**](crate::context::Context::oxidef)
-/// variants may be used to provide values for uniform variables of type
-///
-/// All active uniform variables defined in a program object are initialized
-/// to 0 when the program object is linked successfully. They retain the values
-/// assigned to them by a call to [**glProgramUniform**](crate::context::Context::oxidegl_program_uniform)
-/// until the next successful link operation occurs on the program object,
-/// when they are once again initialized to 0.
-///
-/// The commands [**glProgramUniform{1|2|3|4}{f|i|ui}v**](crate::context::Context::oxidegl_program_uniform{1|2|3|4}{f|i|ui}v)
-/// can be used to modify a single uniform variable or a uniform variable array.
-/// These commands pass a count and a pointer to the values to be loaded into
-/// a uniform variable or a uniform variable array. A count of 1 should be
-/// used if modifying the value of a single uniform variable, and a count of
-/// 1 or greater can be used to modify an entire array or part of an array.
-/// When loading *n* elements starting at an arbitrary position *m* in a uniform
-/// variable array, elements *m*+ *n*- 1 in the array will be replaced with
-/// the new values. If `m`+ `n`- 1 is larger than the size of the uniform variable
-/// array, values for all array elements beyond the end of the array will be
-/// ignored. The number specified in the name of the command indicates the
-/// number of components for each element in `value`, and it should match the
-/// number of components in the data type of the specified uniform variable
-/// (e.g., [**1**](crate::context::Context::oxide1) for [**2**](crate::context::Context::oxide2)
-/// for [**glProgramUniform{1|2|3|4}{f|i|ui}**](crate::context::Context::oxidegl_program_uniform{1|2|3|4}{f|i|ui}).
-///
-/// For uniform variable arrays, each element of the array is considered to
-/// be of the type indicated in the name of the command (e.g., [**glProgramUniform3f**](crate::context::Context::oxidegl_program_uniform3f)
-/// or [**glProgramUniform3fv**](crate::context::Context::oxidegl_program_uniform3fv)
-/// can be used to load a uniform variable array of type vec3). The number
-/// of elements of the uniform variable array to be modified is specified by
-/// `count`
-///
-/// The commands [**glProgramUniformMatrix{2|3|4|2x3|3x2|2x4|4x2|3x4|4x3}fv**](crate::context::Context::oxidegl_program_uniform_matrix{2|3|4|2x3|3x2|2x4|4x2|3x4|4x3}fv)
-/// are used to modify a matrix or an array of matrices. The numbers in the
-/// command name are interpreted as the dimensionality of the matrix. The number
-/// [**2**](crate::context::Context::oxide2) indicates a 2 × 2 matrix (i.e.,
-/// 4 values), the number [**3**](crate::context::Context::oxide3) indicates
-/// a 3 × 3 matrix (i.e., 9 values), and the number [**4**](crate::context::Context::oxide4)
-/// indicates a 4 × 4 matrix (i.e., 16 values). Non-square matrix dimensionality
-/// is explicit, with the first number representing the number of columns and
-/// the second number representing the number of rows. For example, [**2x4**](crate::context::Context::oxide2x4)
-/// indicates a 2 × 4 matrix with 2 columns and 4 rows (i.e., 8 values). If
-/// `transpose` is [`GL_FALSE`](crate::gl_enums::GL_FALSE), each matrix is
-/// assumed to be supplied in column major order. If `transpose` is [`GL_TRUE`](crate::gl_enums::GL_TRUE),
-/// each matrix is assumed to be supplied in row major order. The `count`
-/// argument indicates the number of matrices to be passed. A count of 1 should
-/// be used if modifying the value of a single matrix, and a count greater
-/// than 1 can be used to modify an array of matrices.
+/// [**glReadPixels**](crate::context::Context::oxidegl_read_pixels) and [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels)
+/// return pixel data from the frame buffer, starting with the pixel whose
+/// lower left corner is at location( `x`, `y` ), into client memory starting
+/// at location `data`. Several parameters control the processing of the pixel
+/// data before it is placed into client memory. These parameters are set with
+/// [**glPixelStore**](crate::context::Context::oxidegl_pixel_store). This
+/// reference page describes the effects on [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
+/// and [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels)
+/// of most, but not all of the parameters specified by these three commands.
 ///
-/// ### Notes
-/// [**glProgramUniform1i**](crate::context::Context::oxidegl_program_uniform1i)
-/// and [**glProgramUniform1iv**](crate::context::Context::oxidegl_program_uniform1iv)
-/// are the only two functions that may be used to load uniform variables defined
-/// as sampler types. Loading samplers with any other function will result
-/// in a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION) error.
-///
-/// If `count` is greater than 1 and the indicated uniform variable is not
-/// an array, a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION)
-/// error is generated and the specified uniform variable will remain unchanged.
-///
-/// Other than the preceding exceptions, if the type and size of the uniform
-/// variable as defined in the shader do not match the type and size specified
-/// in the name of the command used to load its value, a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION)
-/// error will be generated and the specified uniform variable will remain
-/// unchanged.
-///
-/// If `location` is a value other than -1 and it does not represent a valid
-/// uniform variable location in within `program`, an error will be generated,
-/// and no changes will be made to the uniform variable storage of `program`.
-/// If `location` is equal to -1, the data passed in will be silently ignored
-/// and the specified uniform variable will not be changed.
+/// If a non-zero named buffer object is bound to the [`GL_PIXEL_PACK_BUFFER`](crate::gl_enums::GL_PIXEL_PACK_BUFFER)
+/// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+/// while a block of pixels is requested, `data` is treated as a byte offset
+/// into the buffer object's data store rather than a pointer to client memory.
 ///
-/// ### Associated Gets
-/// [**glGetActiveUniform**](crate::context::Context::oxidegl_get_active_uniform)
-/// with the handle of a program object and the index of an active uniform
-/// variable
+/// [**glReadPixels**](crate::context::Context::oxidegl_read_pixels) and [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels)
+/// return values from each pixel with lower left corner at `[inlineq]` `[inlineq]`
+/// `[inlineq]` `[inlineq]` `[inlineq]`
 ///
-/// [**glGetUniform**](crate::context::Context::oxidegl_get_uniform) with the
-/// handle of a program object and the location of a uniform variable
+/// `format` specifies the format for the returned pixel values; accepted values
+/// are:
 ///
-/// [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location)
-/// with the handle of a program object and the name of a uniform variable
-impl Context {
-    pub fn oxidegl_program_uniform1i(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform1i not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform1iv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform1iv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform1f(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform1f not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform1fv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform1fv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform1ui(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform1ui not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform1uiv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform1uiv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform2i(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLint,
-        v1: GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform2i not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform2iv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform2iv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform2f(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLfloat,
-        v1: GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform2f not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform2fv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform2fv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform2ui(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLuint,
-        v1: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform2ui not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform2uiv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform2uiv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform3i(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLint,
-        v1: GLint,
-        v2: GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform3i not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform3iv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform3iv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform3f(
+/// [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX)
+///
+/// > Stencil values are read from the stencil buffer.
+///
+/// [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT)
+///
+/// > Depth values are read from the depth buffer. Each component is converted
+/// > to floating point such that the minimum depth value maps to 0 and the maximum
+/// > value maps to 1. Each component is clamped to the range `[inlineq]`
+///
+/// [`GL_DEPTH_STENCIL`](crate::gl_enums::GL_DEPTH_STENCIL)
+///
+/// > Values are taken from both the depth and stencil buffers. The `type` parameter
+/// > must be [`GL_UNSIGNED_INT_24_8`](crate::gl_enums::GL_UNSIGNED_INT_24_8)
+/// > or [`GL_FLOAT_32_UNSIGNED_INT_24_8_REV`](crate::gl_enums::GL_FLOAT_32_UNSIGNED_INT_24_8_REV).
+///
+/// [`GL_RED`](crate::gl_enums::GL_RED), [`GL_GREEN`](crate::gl_enums::GL_GREEN),
+/// [`GL_BLUE`](crate::gl_enums::GL_BLUE), [`GL_RGB`](crate::gl_enums::GL_RGB),
+/// [`GL_BGR`](crate::gl_enums::GL_BGR), [`GL_RGBA`](crate::gl_enums::GL_RGBA),
+/// [`GL_BGRA`](crate::gl_enums::GL_BGRA)
+///
+/// > Color values are taken from the color buffer.
+///
+/// Finally, the indices or components are converted to the proper format,
+/// as specified by `type`. If `format` is [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX)
+/// and `type` is not [`GL_FLOAT`](crate::gl_enums::GL_FLOAT), each index is
+/// masked with the mask value given in the following table. If `type` is [`GL_FLOAT`](crate::gl_enums::GL_FLOAT),
+/// then each integer index is converted to single-precision floating-point
+/// format.
+///
+/// If `format` is [`GL_RED`](crate::gl_enums::GL_RED), [`GL_GREEN`](crate::gl_enums::GL_GREEN),
+/// [`GL_BLUE`](crate::gl_enums::GL_BLUE), [`GL_RGB`](crate::gl_enums::GL_RGB),
+/// [`GL_BGR`](crate::gl_enums::GL_BGR), [`GL_RGBA`](crate::gl_enums::GL_RGBA),
+/// or [`GL_BGRA`](crate::gl_enums::GL_BGRA) and `type` is not [`GL_FLOAT`](crate::gl_enums::GL_FLOAT),
+/// each component is multiplied by the multiplier shown in the following
+/// table. If type is [`GL_FLOAT`](crate::gl_enums::GL_FLOAT), then each component
+/// is passed as is (or converted to the client's single-precision floating-point
+/// format if it is different from the one used by the GL).
+///
+///
+/// | `type`     |* Index Mask*        |* Component Conversion*                               |
+/// |------------|---------------------|------------------------------------------------------|
+/// | [`GL_UNSIGNED_BYTE`](crate::gl_enums::GL_UNSIGNED_BYTE) | `[inlineq]` | `[inlineq]`     |
+/// | [`GL_BYTE`](crate::gl_enums::GL_BYTE) | `[inlineq]` | `[inlineq]`                       |
+/// | [`GL_UNSIGNED_SHORT`](crate::gl_enums::GL_UNSIGNED_SHORT) | `[inlineq]` | `[inlineq]`   |
+/// | [`GL_SHORT`](crate::gl_enums::GL_SHORT) | `[inlineq]` | `[inlineq]`                     |
+/// | [`GL_UNSIGNED_INT`](crate::gl_enums::GL_UNSIGNED_INT) | `[inlineq]` | `[inlineq]`       |
+/// | [`GL_INT`](crate::gl_enums::GL_INT) | `[inlineq]` | `[inlineq]`                         |
+/// | [`GL_HALF_FLOAT`](crate::gl_enums::GL_HALF_FLOAT) | none | `[inlineq]`                  |
+/// | [`GL_FLOAT`](crate::gl_enums::GL_FLOAT) | none | `[inlineq]`                            |
+/// | [`GL_UNSIGNED_BYTE_3_3_2`](crate::gl_enums::GL_UNSIGNED_BYTE_3_3_2) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::gl_enums::GL_UNSIGNED_BYTE_2_3_3_REV) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_SHORT_5_6_5`](crate::gl_enums::GL_UNSIGNED_SHORT_5_6_5) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::gl_enums::GL_UNSIGNED_SHORT_5_6_5_REV) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::gl_enums::GL_UNSIGNED_SHORT_4_4_4_4) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::gl_enums::GL_UNSIGNED_SHORT_4_4_4_4_REV) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::gl_enums::GL_UNSIGNED_SHORT_5_5_5_1) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::gl_enums::GL_UNSIGNED_SHORT_1_5_5_5_REV) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_INT_8_8_8_8`](crate::gl_enums::GL_UNSIGNED_INT_8_8_8_8) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::gl_enums::GL_UNSIGNED_INT_8_8_8_8_REV) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_INT_10_10_10_2`](crate::gl_enums::GL_UNSIGNED_INT_10_10_10_2) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::gl_enums::GL_UNSIGNED_INT_2_10_10_10_REV) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_INT_24_8`](crate::gl_enums::GL_UNSIGNED_INT_24_8) | `[inlineq]` | `[inlineq]` |
+/// | [`GL_UNSIGNED_INT_10F_11F_11F_REV`](crate::gl_enums::GL_UNSIGNED_INT_10F_11F_11F_REV) | -- | Special |
+/// | [`GL_UNSIGNED_INT_5_9_9_9_REV`](crate::gl_enums::GL_UNSIGNED_INT_5_9_9_9_REV) | -- | Special |
+/// | [`GL_FLOAT_32_UNSIGNED_INT_24_8_REV`](crate::gl_enums::GL_FLOAT_32_UNSIGNED_INT_24_8_REV) | none | `[inlineq]` |
+///
+/// Return values are placed in memory as follows. If `format` is [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX),
+/// [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT), [`GL_RED`](crate::gl_enums::GL_RED),
+/// [`GL_GREEN`](crate::gl_enums::GL_GREEN), or [`GL_BLUE`](crate::gl_enums::GL_BLUE),
+/// a single value is returned and the data for the `[inlineq]` `[inlineq]`
+/// `[inlineq]` [`GL_RGB`](crate::gl_enums::GL_RGB) and [`GL_BGR`](crate::gl_enums::GL_BGR)
+/// return three values, [`GL_RGBA`](crate::gl_enums::GL_RGBA) and [`GL_BGRA`](crate::gl_enums::GL_BGRA)
+/// return four values for each pixel, with all values corresponding to a single
+/// pixel occupying contiguous space in `data`. Storage parameters set by [**glPixelStore**](crate::context::Context::oxidegl_pixel_store),
+/// such as [`GL_PACK_LSB_FIRST`](crate::gl_enums::GL_PACK_LSB_FIRST) and
+/// [`GL_PACK_SWAP_BYTES`](crate::gl_enums::GL_PACK_SWAP_BYTES), affect the
+/// way that data is written into memory. See [**glPixelStore**](crate::context::Context::oxidegl_pixel_store)
+/// for a description.
+///
+/// [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels) function
+/// will only handle the call if `bufSize` is at least of the size required
+/// to store the requested data. Otherwise, it will generate a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION)
+/// error.
+///
+/// ### Notes
+/// Values for pixels that lie outside the window connected to the current
+/// GL context are undefined.
+///
+/// If an error is generated, no change is made to the contents of `data`.
+///
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_PACK_BUFFER_BINDING`](crate::gl_enums::GL_PIXEL_PACK_BUFFER_BINDING)
+impl Context {
+    pub unsafe fn oxidegl_read_pixels(
         &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLfloat,
-        v1: GLfloat,
-        v2: GLfloat,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        pixels: *mut GLvoid,
     ) -> GlFallible {
-        panic!("command oxidegl_program_uniform3f not yet implemented");
+        panic!("command oxidegl_read_pixels not yet implemented");
     }
-    pub unsafe fn oxidegl_program_uniform3fv(
+    pub unsafe fn oxidegl_readn_pixels(
         &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLfloat,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        buf_size: GLsizei,
+        data: *mut GLvoid,
     ) -> GlFallible {
-        panic!("command oxidegl_program_uniform3fv not yet implemented");
+        panic!("command oxidegl_readn_pixels not yet implemented");
     }
-    pub fn oxidegl_program_uniform3ui(
+}
+/// ### Parameters
+/// `target`
+///
+/// > Specifies a binding target of the allocation for [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage)
+/// > function. Must be [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER).
+///
+/// `renderbuffer`
+///
+/// > Specifies the name of the renderbuffer object for [**glNamedRenderbufferStorage**](crate::context::Context::oxidegl_named_renderbuffer_storage)
+/// > function.
+///
+/// `internalformat`
+///
+/// > Specifies the internal format to use for the renderbuffer object's image.
+///
+/// `width`
+///
+/// > Specifies the width of the renderbuffer, in pixels.
+///
+/// `height`
+///
+/// > Specifies the height of the renderbuffer, in pixels.
+///
+/// ### Description
+/// [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage)
+/// is equivalent to calling [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample)
+/// with the `samples` set to zero, and [**glNamedRenderbufferStorage**](crate::context::Context::oxidegl_named_renderbuffer_storage)
+/// is equivalent to calling [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample)
+/// with the samples set to zero.
+///
+/// For [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage),
+/// the target of the operation, specified by `target` must be [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER).
+/// For [**glNamedRenderbufferStorage**](crate::context::Context::oxidegl_named_renderbuffer_storage),
+/// `renderbuffer` must be a name of an existing renderbuffer object. `internalformat`
+/// specifies the internal format to be used for the renderbuffer object's
+/// storage and must be a color-renderable, depth-renderable, or stencil-renderable
+/// format. `width` and `height` are the dimensions, in pixels, of the renderbuffer.
+/// Both `width` and `height` must be less than or equal to the value of [`GL_MAX_RENDERBUFFER_SIZE`](crate::gl_enums::GL_MAX_RENDERBUFFER_SIZE).
+///
+/// Upon success, [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage)
+/// and [**glNamedRenderbufferStorage**](crate::context::Context::oxidegl_named_renderbuffer_storage)
+/// delete any existing data store for the renderbuffer image and the contents
+/// of the data store after calling [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage)
+/// are undefined.
+impl Context {
+    pub fn oxidegl_renderbuffer_storage(
         &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLuint,
-        v1: GLuint,
-        v2: GLuint,
+        target: GLenum,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
     ) -> GlFallible {
-        panic!("command oxidegl_program_uniform3ui not yet implemented");
+        panic!("command oxidegl_renderbuffer_storage not yet implemented");
     }
-    pub unsafe fn oxidegl_program_uniform3uiv(
+    pub fn oxidegl_named_renderbuffer_storage(
         &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLuint,
+        renderbuffer: GLuint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
     ) -> GlFallible {
-        panic!("command oxidegl_program_uniform3uiv not yet implemented");
+        panic!("command oxidegl_named_renderbuffer_storage not yet implemented");
     }
-    pub fn oxidegl_program_uniform4i(
+}
+/// ### Parameters
+/// `target`
+///
+/// > Specifies a binding target of the allocation for [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample)
+/// > function. Must be [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER).
+///
+/// `renderbuffer`
+///
+/// > Specifies the name of the renderbuffer object for [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample)
+/// > function.
+///
+/// `samples`
+///
+/// > Specifies the number of samples to be used for the renderbuffer object's
+/// > storage.
+///
+/// `internalformat`
+///
+/// > Specifies the internal format to use for the renderbuffer object's image.
+///
+/// `width`
+///
+/// > Specifies the width of the renderbuffer, in pixels.
+///
+/// `height`
+///
+/// > Specifies the height of the renderbuffer, in pixels.
+///
+/// ### Description
+/// [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample)
+/// and [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample)
+/// establish the data storage, format, dimensions and number of samples of
+/// a renderbuffer object's image.
+///
+/// For [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample),
+/// the target of the operation, specified by `target` must be [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER).
+/// For [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample),
+/// `renderbuffer` must be an ID of an existing renderbuffer object. `internalformat`
+/// specifies the internal format to be used for the renderbuffer object's
+/// storage and must be a color-renderable, depth-renderable, or stencil-renderable
+/// format. `width` and `height` are the dimensions, in pixels, of the renderbuffer.
+/// Both `width` and `height` must be less than or equal to the value of [`GL_MAX_RENDERBUFFER_SIZE`](crate::gl_enums::GL_MAX_RENDERBUFFER_SIZE).
+/// `samples` specifies the number of samples to be used for the renderbuffer
+/// object's image, and must be less than or equal to the value of [`GL_MAX_SAMPLES`](crate::gl_enums::GL_MAX_SAMPLES).
+/// If `internalformat` is a signed or unsigned integer format then `samples`
+/// must be less than or equal to the value of [`GL_MAX_INTEGER_SAMPLES`](crate::gl_enums::GL_MAX_INTEGER_SAMPLES).
+///
+/// Upon success, [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample)
+/// and [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample)
+/// delete any existing data store for the renderbuffer image and the contents
+/// of the data store after calling either of the functions are undefined.
+impl Context {
+    pub fn oxidegl_renderbuffer_storage_multisample(
         &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLint,
-        v1: GLint,
-        v2: GLint,
-        v3: GLint,
+        target: GLenum,
+        samples: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
     ) -> GlFallible {
-        panic!("command oxidegl_program_uniform4i not yet implemented");
+        panic!("command oxidegl_renderbuffer_storage_multisample not yet implemented");
     }
-    pub unsafe fn oxidegl_program_uniform4iv(
+    pub fn oxidegl_named_renderbuffer_storage_multisample(
         &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLint,
+        renderbuffer: GLuint,
+        samples: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
     ) -> GlFallible {
-        panic!("command oxidegl_program_uniform4iv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform4f(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLfloat,
-        v1: GLfloat,
-        v2: GLfloat,
-        v3: GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform4f not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform4fv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform4fv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform4ui(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLuint,
-        v1: GLuint,
-        v2: GLuint,
-        v3: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform4ui not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform4uiv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform4uiv not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform_matrix2fv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix2fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform_matrix3fv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix3fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform_matrix4fv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix4fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform_matrix2x3fv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix2x3fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform_matrix3x2fv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix3x2fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform_matrix2x4fv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix2x4fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform_matrix4x2fv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix4x2fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform_matrix3x4fv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix3x4fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform_matrix4x3fv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix4x3fv not yet implemented");
+        panic!("command oxidegl_named_renderbuffer_storage_multisample not yet implemented");
     }
 }
 /// ### Parameters
-/// `framebuffer`
+/// `sampler`
 ///
-/// > Specifies the name of the framebuffer object for [**glNamedFramebufferReadBuffer**](crate::context::Context::oxidegl_named_framebuffer_read_buffer)
-/// > function.
+/// > Specifies the sampler object whose parameter to modify.
 ///
-/// `mode`
+/// `pname`
 ///
-/// > Specifies a color buffer. Accepted values are [`GL_FRONT_LEFT`](crate::gl_enums::GL_FRONT_LEFT),
-/// > [`GL_FRONT_RIGHT`](crate::gl_enums::GL_FRONT_RIGHT), [`GL_BACK_LEFT`](crate::gl_enums::GL_BACK_LEFT),
-/// > [`GL_BACK_RIGHT`](crate::gl_enums::GL_BACK_RIGHT), [`GL_FRONT`](crate::gl_enums::GL_FRONT),
-/// > [`GL_BACK`](crate::gl_enums::GL_BACK), [`GL_LEFT`](crate::gl_enums::GL_LEFT),
-/// > [`GL_RIGHT`](crate::gl_enums::GL_RIGHT), and the constants [`GL_COLOR_ATTACHMENT`](crate::gl_enums::GL_COLOR_ATTACHMENT)
-/// > *i*.
+/// > Specifies the symbolic name of a sampler parameter. `pname` can be one
+/// > of the following: [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S),
+/// > [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T), [`GL_TEXTURE_WRAP_R`](crate::gl_enums::GL_TEXTURE_WRAP_R),
+/// > [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER), [`GL_TEXTURE_MAG_FILTER`](crate::gl_enums::GL_TEXTURE_MAG_FILTER),
+/// > [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR), [`GL_TEXTURE_MIN_LOD`](crate::gl_enums::GL_TEXTURE_MIN_LOD),
+/// > [`GL_TEXTURE_MAX_LOD`](crate::gl_enums::GL_TEXTURE_MAX_LOD), [`GL_TEXTURE_LOD_BIAS`](crate::gl_enums::GL_TEXTURE_LOD_BIAS)
+/// > [`GL_TEXTURE_COMPARE_MODE`](crate::gl_enums::GL_TEXTURE_COMPARE_MODE), or
+/// > [`GL_TEXTURE_COMPARE_FUNC`](crate::gl_enums::GL_TEXTURE_COMPARE_FUNC).
 ///
-/// ### Description
-/// [**glReadBuffer**](crate::context::Context::oxidegl_read_buffer) specifies
-/// a color buffer as the source for subsequent [**glReadPixels**](crate::context::Context::oxidegl_read_pixels),
-/// [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d),
-/// [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d),
-/// [**glCopyTexSubImage1D**](crate::context::Context::oxidegl_copy_tex_sub_image1_d),
-/// [**glCopyTexSubImage2D**](crate::context::Context::oxidegl_copy_tex_sub_image2_d),
-/// and [**glCopyTexSubImage3D**](crate::context::Context::oxidegl_copy_tex_sub_image3_d)
-/// commands. `mode` accepts one of twelve or more predefined values. In a
-/// fully configured system, [`GL_FRONT`](crate::gl_enums::GL_FRONT), [`GL_LEFT`](crate::gl_enums::GL_LEFT),
-/// and [`GL_FRONT_LEFT`](crate::gl_enums::GL_FRONT_LEFT) all name the front
-/// left buffer, [`GL_FRONT_RIGHT`](crate::gl_enums::GL_FRONT_RIGHT) and [`GL_RIGHT`](crate::gl_enums::GL_RIGHT)
-/// name the front right buffer, and [`GL_BACK_LEFT`](crate::gl_enums::GL_BACK_LEFT)
-/// and [`GL_BACK`](crate::gl_enums::GL_BACK) name the back left buffer. Further
-/// more, the constants [`GL_COLOR_ATTACHMENT`](crate::gl_enums::GL_COLOR_ATTACHMENT)
-/// *i* may be used to indicate the *i* *i* ranges from zero to the value of
-/// [`GL_MAX_COLOR_ATTACHMENTS`](crate::gl_enums::GL_MAX_COLOR_ATTACHMENTS)
-/// minus one.
+/// `param`
 ///
-/// Nonstereo double-buffered configurations have only a front left and a back
-/// left buffer. Single-buffered configurations have a front left and a front
-/// right buffer if stereo, and only a front left buffer if nonstereo. It is
-/// an error to specify a nonexistent buffer to [**glReadBuffer**](crate::context::Context::oxidegl_read_buffer).
+/// > For the scalar commands, specifies the value of `pname`.
 ///
-/// `mode` is initially [`GL_FRONT`](crate::gl_enums::GL_FRONT) in single-buffered
-/// configurations and [`GL_BACK`](crate::gl_enums::GL_BACK) in double-buffered
-/// configurations.
+/// `params`
 ///
-/// For [**glReadBuffer**](crate::context::Context::oxidegl_read_buffer), the
-/// target framebuffer object is that bound to [`GL_READ_FRAMEBUFFER`](crate::gl_enums::GL_READ_FRAMEBUFFER).
-/// For [**glNamedFramebufferReadBuffer**](crate::context::Context::oxidegl_named_framebuffer_read_buffer),
-/// `framebuffer` must either be zero or the name of the target framebuffer
-/// object. If `framebuffer` is zero, then the default read framebuffer is
-/// affected.
+/// > For the vector commands( [**glSamplerParameter*v**](crate::context::Context::oxidegl_sampler_parameter*v)
+/// > ), specifies a pointer to an array where the value or values of `pname`
+/// > are stored.
 ///
-/// ### Associated Gets
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_READ_BUFFER`](crate::gl_enums::GL_READ_BUFFER)
-impl Context {
-    pub fn oxidegl_read_buffer(&mut self, src: ReadBufferMode) -> GlFallible {
-        panic!("command oxidegl_read_buffer not yet implemented");
-    }
-    pub fn oxidegl_named_framebuffer_read_buffer(
-        &mut self,
-        framebuffer: GLuint,
-        src: ColorBuffer,
-    ) -> GlFallible {
-        panic!("command oxidegl_named_framebuffer_read_buffer not yet implemented");
-    }
-}
-/// ### Parameters
-/// `x`
+/// ### Description
+/// [**glSamplerParameter**](crate::context::Context::oxidegl_sampler_parameter)
+/// assigns the value or values in `params` to the sampler parameter specified
+/// as `pname`. `sampler` specifies the sampler object to be modified, and
+/// must be the name of a sampler object previously returned from a call to
+/// [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers). The
+/// following symbols are accepted in `pname`:
 ///
-/// `y`
+/// [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER)
 ///
-/// > Specify the window coordinates of the first pixel that is read from the
-/// > frame buffer. This location is the lower left corner of a rectangular block
-/// > of pixels.
+/// > The texture minifying function is used whenever the pixel being textured
+/// > maps to an area greater than one texture element. There are six defined
+/// > minifying functions. Two of them use the nearest one or nearest four texture
+/// > elements to compute the texture value. The other four use mipmaps.
 ///
-/// `width`
+/// > A mipmap is an ordered set of arrays representing the same image at progressively
+/// > lower resolutions. If the texture has dimensions `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
+/// > [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d),
+/// > [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d),
+/// > or [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d)
+/// > with the *level* argument indicating the order of the mipmaps. Level 0
+/// > is the original texture; level `[inlineq]` `[inlineq]`
 ///
-/// `height`
+/// > `params` supplies a function for minifying the texture as one of the following:
+/// > [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
 ///
-/// > Specify the dimensions of the pixel rectangle. `width` and `height` of
-/// > one correspond to a single pixel.
+/// >> Returns the value of the texture element that is nearest (in Manhattan
+/// >> distance) to the center of the pixel being textured.
 ///
-/// `format`
+/// > [`GL_LINEAR`](crate::gl_enums::GL_LINEAR)
 ///
-/// > Specifies the format of the pixel data. The following symbolic values are
-/// > accepted: [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX), [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT),
-/// > [`GL_DEPTH_STENCIL`](crate::gl_enums::GL_DEPTH_STENCIL), [`GL_RED`](crate::gl_enums::GL_RED),
-/// > [`GL_GREEN`](crate::gl_enums::GL_GREEN), [`GL_BLUE`](crate::gl_enums::GL_BLUE),
-/// > [`GL_RGB`](crate::gl_enums::GL_RGB), [`GL_BGR`](crate::gl_enums::GL_BGR),
-/// > [`GL_RGBA`](crate::gl_enums::GL_RGBA), and [`GL_BGRA`](crate::gl_enums::GL_BGRA).
+/// >> Returns the weighted average of the four texture elements that are closest
+/// >> to the center of the pixel being textured. These can include border texture
+/// >> elements, depending on the values of [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S)
+/// >> and [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T), and on the
+/// >> exact mapping.
 ///
-/// `type`
+/// > [`GL_NEAREST_MIPMAP_NEAREST`](crate::gl_enums::GL_NEAREST_MIPMAP_NEAREST)
 ///
-/// > Specifies the data type of the pixel data. Must be one of [`GL_UNSIGNED_BYTE`](crate::gl_enums::GL_UNSIGNED_BYTE),
-/// > [`GL_BYTE`](crate::gl_enums::GL_BYTE), [`GL_UNSIGNED_SHORT`](crate::gl_enums::GL_UNSIGNED_SHORT),
-/// > [`GL_SHORT`](crate::gl_enums::GL_SHORT), [`GL_UNSIGNED_INT`](crate::gl_enums::GL_UNSIGNED_INT),
-/// > [`GL_INT`](crate::gl_enums::GL_INT), [`GL_HALF_FLOAT`](crate::gl_enums::GL_HALF_FLOAT),
-/// > [`GL_FLOAT`](crate::gl_enums::GL_FLOAT), [`GL_UNSIGNED_BYTE_3_3_2`](crate::gl_enums::GL_UNSIGNED_BYTE_3_3_2),
-/// > [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::gl_enums::GL_UNSIGNED_BYTE_2_3_3_REV),
-/// > [`GL_UNSIGNED_SHORT_5_6_5`](crate::gl_enums::GL_UNSIGNED_SHORT_5_6_5), [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::gl_enums::GL_UNSIGNED_SHORT_5_6_5_REV),
-/// > [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::gl_enums::GL_UNSIGNED_SHORT_4_4_4_4),
-/// > [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::gl_enums::GL_UNSIGNED_SHORT_4_4_4_4_REV),
-/// > [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::gl_enums::GL_UNSIGNED_SHORT_5_5_5_1),
-/// > [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::gl_enums::GL_UNSIGNED_SHORT_1_5_5_5_REV),
-/// > [`GL_UNSIGNED_INT_8_8_8_8`](crate::gl_enums::GL_UNSIGNED_INT_8_8_8_8), [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::gl_enums::GL_UNSIGNED_INT_8_8_8_8_REV),
-/// > [`GL_UNSIGNED_INT_10_10_10_2`](crate::gl_enums::GL_UNSIGNED_INT_10_10_10_2),
-/// > [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::gl_enums::GL_UNSIGNED_INT_2_10_10_10_REV),
-/// > [`GL_UNSIGNED_INT_24_8`](crate::gl_enums::GL_UNSIGNED_INT_24_8), [`GL_UNSIGNED_INT_10F_11F_11F_REV`](crate::gl_enums::GL_UNSIGNED_INT_10F_11F_11F_REV),
-/// > [`GL_UNSIGNED_INT_5_9_9_9_REV`](crate::gl_enums::GL_UNSIGNED_INT_5_9_9_9_REV),
-/// > or [`GL_FLOAT_32_UNSIGNED_INT_24_8_REV`](crate::gl_enums::GL_FLOAT_32_UNSIGNED_INT_24_8_REV).
+/// >> Chooses the mipmap that most closely matches the size of the pixel being
+/// >> textured and uses the [`GL_NEAREST`](crate::gl_enums::GL_NEAREST) criterion
+/// >> (the texture element nearest to the center of the pixel) to produce a texture
+/// >> value.
 ///
-/// `bufSize`
+/// > [`GL_LINEAR_MIPMAP_NEAREST`](crate::gl_enums::GL_LINEAR_MIPMAP_NEAREST)
 ///
-/// > Specifies the size of the buffer `data` for [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels)
-/// > function.
+/// >> Chooses the mipmap that most closely matches the size of the pixel being
+/// >> textured and uses the [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) criterion
+/// >> (a weighted average of the four texture elements that are closest to the
+/// >> center of the pixel) to produce a texture value.
 ///
-/// `data`
+/// > [`GL_NEAREST_MIPMAP_LINEAR`](crate::gl_enums::GL_NEAREST_MIPMAP_LINEAR)
 ///
-/// > Returns the pixel data.
+/// >> Chooses the two mipmaps that most closely match the size of the pixel being
+/// >> textured and uses the [`GL_NEAREST`](crate::gl_enums::GL_NEAREST) criterion
+/// >> (the texture element nearest to the center of the pixel) to produce a texture
+/// >> value from each mipmap. The final texture value is a weighted average of
+/// >> those two values.
 ///
-/// ### Description
-/// [**glReadPixels**](crate::context::Context::oxidegl_read_pixels) and [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels)
-/// return pixel data from the frame buffer, starting with the pixel whose
-/// lower left corner is at location( `x`, `y` ), into client memory starting
-/// at location `data`. Several parameters control the processing of the pixel
-/// data before it is placed into client memory. These parameters are set with
-/// [**glPixelStore**](crate::context::Context::oxidegl_pixel_store). This
-/// reference page describes the effects on [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
-/// and [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels)
-/// of most, but not all of the parameters specified by these three commands.
+/// > [`GL_LINEAR_MIPMAP_LINEAR`](crate::gl_enums::GL_LINEAR_MIPMAP_LINEAR)
 ///
-/// If a non-zero named buffer object is bound to the [`GL_PIXEL_PACK_BUFFER`](crate::gl_enums::GL_PIXEL_PACK_BUFFER)
-/// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
-/// while a block of pixels is requested, `data` is treated as a byte offset
-/// into the buffer object's data store rather than a pointer to client memory.
+/// >> Chooses the two mipmaps that most closely match the size of the pixel being
+/// >> textured and uses the [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) criterion
+/// >> (a weighted average of the four texture elements that are closest to the
+/// >> center of the pixel) to produce a texture value from each mipmap. The final
+/// >> texture value is a weighted average of those two values.
 ///
-/// [**glReadPixels**](crate::context::Context::oxidegl_read_pixels) and [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels)
-/// return values from each pixel with lower left corner at `[inlineq]` `[inlineq]`
-/// `[inlineq]` `[inlineq]` `[inlineq]`
 ///
-/// `format` specifies the format for the returned pixel values; accepted values
-/// are:
+/// > As more texture elements are sampled in the minification process, fewer
+/// > aliasing artifacts will be apparent. While the [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
+/// > and [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) minification functions can
+/// > be faster than the other four, they sample only one or four texture elements
+/// > to determine the texture value of the pixel being rendered and can produce
+/// > moire patterns or ragged transitions. The initial value of [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER)
+/// > is [`GL_NEAREST_MIPMAP_LINEAR`](crate::gl_enums::GL_NEAREST_MIPMAP_LINEAR).
 ///
-/// [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX)
+/// [`GL_TEXTURE_MAG_FILTER`](crate::gl_enums::GL_TEXTURE_MAG_FILTER)
 ///
-/// > Stencil values are read from the stencil buffer.
+/// > The texture magnification function is used when the pixel being textured
+/// > maps to an area less than or equal to one texture element. It sets the
+/// > texture magnification function to either [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
+/// > or [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) (see below). [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
+/// > is generally faster than [`GL_LINEAR`](crate::gl_enums::GL_LINEAR), but
+/// > it can produce textured images with sharper edges because the transition
+/// > between texture elements is not as smooth. The initial value of [`GL_TEXTURE_MAG_FILTER`](crate::gl_enums::GL_TEXTURE_MAG_FILTER)
+/// > is [`GL_LINEAR`](crate::gl_enums::GL_LINEAR). [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
 ///
-/// [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT)
+/// >> Returns the value of the texture element that is nearest (in Manhattan
+/// >> distance) to the center of the pixel being textured.
 ///
-/// > Depth values are read from the depth buffer. Each component is converted
-/// > to floating point such that the minimum depth value maps to 0 and the maximum
-/// > value maps to 1. Each component is clamped to the range `[inlineq]`
+/// > [`GL_LINEAR`](crate::gl_enums::GL_LINEAR)
 ///
-/// [`GL_DEPTH_STENCIL`](crate::gl_enums::GL_DEPTH_STENCIL)
+/// >> Returns the weighted average of the four texture elements that are closest
+/// >> to the center of the pixel being textured. These can include border texture
+/// >> elements, depending on the values of [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S)
+/// >> and [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T), and on the
+/// >> exact mapping.
 ///
-/// > Values are taken from both the depth and stencil buffers. The `type` parameter
-/// > must be [`GL_UNSIGNED_INT_24_8`](crate::gl_enums::GL_UNSIGNED_INT_24_8)
-/// > or [`GL_FLOAT_32_UNSIGNED_INT_24_8_REV`](crate::gl_enums::GL_FLOAT_32_UNSIGNED_INT_24_8_REV).
 ///
-/// [`GL_RED`](crate::gl_enums::GL_RED), [`GL_GREEN`](crate::gl_enums::GL_GREEN),
-/// [`GL_BLUE`](crate::gl_enums::GL_BLUE), [`GL_RGB`](crate::gl_enums::GL_RGB),
-/// [`GL_BGR`](crate::gl_enums::GL_BGR), [`GL_RGBA`](crate::gl_enums::GL_RGBA),
-/// [`GL_BGRA`](crate::gl_enums::GL_BGRA)
 ///
-/// > Color values are taken from the color buffer.
+/// [`GL_TEXTURE_MIN_LOD`](crate::gl_enums::GL_TEXTURE_MIN_LOD)
 ///
-/// Finally, the indices or components are converted to the proper format,
-/// as specified by `type`. If `format` is [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX)
-/// and `type` is not [`GL_FLOAT`](crate::gl_enums::GL_FLOAT), each index is
-/// masked with the mask value given in the following table. If `type` is [`GL_FLOAT`](crate::gl_enums::GL_FLOAT),
-/// then each integer index is converted to single-precision floating-point
-/// format.
+/// > Sets the minimum level-of-detail parameter. This floating-point value limits
+/// > the selection of highest resolution mipmap (lowest mipmap level). The initial
+/// > value is -1000.
 ///
-/// If `format` is [`GL_RED`](crate::gl_enums::GL_RED), [`GL_GREEN`](crate::gl_enums::GL_GREEN),
-/// [`GL_BLUE`](crate::gl_enums::GL_BLUE), [`GL_RGB`](crate::gl_enums::GL_RGB),
-/// [`GL_BGR`](crate::gl_enums::GL_BGR), [`GL_RGBA`](crate::gl_enums::GL_RGBA),
-/// or [`GL_BGRA`](crate::gl_enums::GL_BGRA) and `type` is not [`GL_FLOAT`](crate::gl_enums::GL_FLOAT),
-/// each component is multiplied by the multiplier shown in the following
-/// table. If type is [`GL_FLOAT`](crate::gl_enums::GL_FLOAT), then each component
-/// is passed as is (or converted to the client's single-precision floating-point
-/// format if it is different from the one used by the GL).
 ///
+/// [`GL_TEXTURE_MAX_LOD`](crate::gl_enums::GL_TEXTURE_MAX_LOD)
 ///
-/// | `type`     |* Index Mask*        |* Component Conversion*                               |
-/// |------------|---------------------|------------------------------------------------------|
-/// | [`GL_UNSIGNED_BYTE`](crate::gl_enums::GL_UNSIGNED_BYTE) | `[inlineq]` | `[inlineq]`     |
-/// | [`GL_BYTE`](crate::gl_enums::GL_BYTE) | `[inlineq]` | `[inlineq]`                       |
-/// | [`GL_UNSIGNED_SHORT`](crate::gl_enums::GL_UNSIGNED_SHORT) | `[inlineq]` | `[inlineq]`   |
-/// | [`GL_SHORT`](crate::gl_enums::GL_SHORT) | `[inlineq]` | `[inlineq]`                     |
-/// | [`GL_UNSIGNED_INT`](crate::gl_enums::GL_UNSIGNED_INT) | `[inlineq]` | `[inlineq]`       |
-/// | [`GL_INT`](crate::gl_enums::GL_INT) | `[inlineq]` | `[inlineq]`                         |
-/// | [`GL_HALF_FLOAT`](crate::gl_enums::GL_HALF_FLOAT) | none | `[inlineq]`                  |
-/// | [`GL_FLOAT`](crate::gl_enums::GL_FLOAT) | none | `[inlineq]`                            |
-/// | [`GL_UNSIGNED_BYTE_3_3_2`](crate::gl_enums::GL_UNSIGNED_BYTE_3_3_2) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_BYTE_2_3_3_REV`](crate::gl_enums::GL_UNSIGNED_BYTE_2_3_3_REV) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_SHORT_5_6_5`](crate::gl_enums::GL_UNSIGNED_SHORT_5_6_5) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_SHORT_5_6_5_REV`](crate::gl_enums::GL_UNSIGNED_SHORT_5_6_5_REV) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_SHORT_4_4_4_4`](crate::gl_enums::GL_UNSIGNED_SHORT_4_4_4_4) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_SHORT_4_4_4_4_REV`](crate::gl_enums::GL_UNSIGNED_SHORT_4_4_4_4_REV) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_SHORT_5_5_5_1`](crate::gl_enums::GL_UNSIGNED_SHORT_5_5_5_1) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_SHORT_1_5_5_5_REV`](crate::gl_enums::GL_UNSIGNED_SHORT_1_5_5_5_REV) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_INT_8_8_8_8`](crate::gl_enums::GL_UNSIGNED_INT_8_8_8_8) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_INT_8_8_8_8_REV`](crate::gl_enums::GL_UNSIGNED_INT_8_8_8_8_REV) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_INT_10_10_10_2`](crate::gl_enums::GL_UNSIGNED_INT_10_10_10_2) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_INT_2_10_10_10_REV`](crate::gl_enums::GL_UNSIGNED_INT_2_10_10_10_REV) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_INT_24_8`](crate::gl_enums::GL_UNSIGNED_INT_24_8) | `[inlineq]` | `[inlineq]` |
-/// | [`GL_UNSIGNED_INT_10F_11F_11F_REV`](crate::gl_enums::GL_UNSIGNED_INT_10F_11F_11F_REV) | -- | Special |
-/// | [`GL_UNSIGNED_INT_5_9_9_9_REV`](crate::gl_enums::GL_UNSIGNED_INT_5_9_9_9_REV) | -- | Special |
-/// | [`GL_FLOAT_32_UNSIGNED_INT_24_8_REV`](crate::gl_enums::GL_FLOAT_32_UNSIGNED_INT_24_8_REV) | none | `[inlineq]` |
+/// > Sets the maximum level-of-detail parameter. This floating-point value limits
+/// > the selection of the lowest resolution mipmap (highest mipmap level). The
+/// > initial value is 1000.
 ///
-/// Return values are placed in memory as follows. If `format` is [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX),
-/// [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT), [`GL_RED`](crate::gl_enums::GL_RED),
-/// [`GL_GREEN`](crate::gl_enums::GL_GREEN), or [`GL_BLUE`](crate::gl_enums::GL_BLUE),
-/// a single value is returned and the data for the `[inlineq]` `[inlineq]`
-/// `[inlineq]` [`GL_RGB`](crate::gl_enums::GL_RGB) and [`GL_BGR`](crate::gl_enums::GL_BGR)
-/// return three values, [`GL_RGBA`](crate::gl_enums::GL_RGBA) and [`GL_BGRA`](crate::gl_enums::GL_BGRA)
-/// return four values for each pixel, with all values corresponding to a single
-/// pixel occupying contiguous space in `data`. Storage parameters set by [**glPixelStore**](crate::context::Context::oxidegl_pixel_store),
-/// such as [`GL_PACK_LSB_FIRST`](crate::gl_enums::GL_PACK_LSB_FIRST) and
-/// [`GL_PACK_SWAP_BYTES`](crate::gl_enums::GL_PACK_SWAP_BYTES), affect the
-/// way that data is written into memory. See [**glPixelStore**](crate::context::Context::oxidegl_pixel_store)
-/// for a description.
 ///
-/// [**glReadnPixels**](crate::context::Context::oxidegl_readn_pixels) function
-/// will only handle the call if `bufSize` is at least of the size required
-/// to store the requested data. Otherwise, it will generate a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION)
-/// error.
+/// [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S)
 ///
-/// ### Notes
-/// Values for pixels that lie outside the window connected to the current
-/// GL context are undefined.
+/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE),
+/// > [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT), [`GL_REPEAT`](crate::gl_enums::GL_REPEAT),
+/// > or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE).
+/// > [`GL_CLAMP_TO_BORDER`](crate::gl_enums::GL_CLAMP_TO_BORDER) causes the
+/// > `[inlineq]` `[inlineq]` `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE)
+/// > causes `[inlineq]` `[inlineq]` `[inlineq]` [`GL_REPEAT`](crate::gl_enums::GL_REPEAT)
+/// > causes the integer part of the `[inlineq]` [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT)
+/// > causes the `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE)
+/// > causes the `[inlineq]` [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT)
+/// > for one repetition of the texture, at which point the coordinate to be
+/// > clamped as in [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE). Initially,
+/// > [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S) is set to [`GL_REPEAT`](crate::gl_enums::GL_REPEAT).
 ///
-/// If an error is generated, no change is made to the contents of `data`.
 ///
-/// ### Associated Gets
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_PACK_BUFFER_BINDING`](crate::gl_enums::GL_PIXEL_PACK_BUFFER_BINDING)
-impl Context {
-    pub unsafe fn oxidegl_read_pixels(
-        &mut self,
-        x: GLint,
-        y: GLint,
-        width: GLsizei,
-        height: GLsizei,
-        format: PixelFormat,
-        r#type: PixelType,
-        pixels: *mut GLvoid,
-    ) -> GlFallible {
-        panic!("command oxidegl_read_pixels not yet implemented");
-    }
-    pub unsafe fn oxidegl_readn_pixels(
-        &mut self,
-        x: GLint,
-        y: GLint,
-        width: GLsizei,
-        height: GLsizei,
-        format: PixelFormat,
-        r#type: PixelType,
-        buf_size: GLsizei,
-        data: *mut GLvoid,
-    ) -> GlFallible {
-        panic!("command oxidegl_readn_pixels not yet implemented");
-    }
-}
-/// ### Parameters
-/// `target`
+/// [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T)
 ///
-/// > Specifies a binding target of the allocation for [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage)
-/// > function. Must be [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER).
+/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE),
+/// > [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT), [`GL_REPEAT`](crate::gl_enums::GL_REPEAT),
+/// > or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE).
+/// > See the discussion under [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S).
+/// > Initially, [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T) is
+/// > set to [`GL_REPEAT`](crate::gl_enums::GL_REPEAT).
 ///
-/// `renderbuffer`
+/// [`GL_TEXTURE_WRAP_R`](crate::gl_enums::GL_TEXTURE_WRAP_R)
 ///
-/// > Specifies the name of the renderbuffer object for [**glNamedRenderbufferStorage**](crate::context::Context::oxidegl_named_renderbuffer_storage)
-/// > function.
+/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE),
+/// > [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT), [`GL_REPEAT`](crate::gl_enums::GL_REPEAT),
+/// > or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE).
+/// > See the discussion under [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S).
+/// > Initially, [`GL_TEXTURE_WRAP_R`](crate::gl_enums::GL_TEXTURE_WRAP_R) is
+/// > set to [`GL_REPEAT`](crate::gl_enums::GL_REPEAT).
 ///
-/// `internalformat`
+/// [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR)
 ///
-/// > Specifies the internal format to use for the renderbuffer object's image.
+/// > The data in `params` specifies four values that define the border values
+/// > that should be used for border texels. If a texel is sampled from the border
+/// > of the texture, the values of [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR)
+/// > are interpreted as an RGBA color to match the texture's internal format
+/// > and substituted for the non-existent texel data. If the texture contains
+/// > depth components, the first component of [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR)
+/// > is interpreted as a depth value. The initial value is `[inlineq]`
 ///
-/// `width`
+/// [`GL_TEXTURE_COMPARE_MODE`](crate::gl_enums::GL_TEXTURE_COMPARE_MODE)
 ///
-/// > Specifies the width of the renderbuffer, in pixels.
+/// > Specifies the texture comparison mode for currently bound textures. That
+/// > is, a texture whose internal format is [`GL_DEPTH_COMPONENT_*`](crate::gl_enums::GL_DEPTH_COMPONENT_*);
+/// > see [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d))
+/// > Permissible values are: [`GL_COMPARE_REF_TO_TEXTURE`](crate::gl_enums::GL_COMPARE_REF_TO_TEXTURE)
 ///
-/// `height`
+/// >> Specifies that the interpolated and clamped `[inlineq]` [`GL_TEXTURE_COMPARE_FUNC`](crate::gl_enums::GL_TEXTURE_COMPARE_FUNC)
+/// >> for details of how the comparison is evaluated. The result of the comparison
+/// >> is assigned to the red channel.
 ///
-/// > Specifies the height of the renderbuffer, in pixels.
+/// > [`GL_NONE`](crate::gl_enums::GL_NONE)
 ///
-/// ### Description
-/// [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage)
-/// is equivalent to calling [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample)
-/// with the `samples` set to zero, and [**glNamedRenderbufferStorage**](crate::context::Context::oxidegl_named_renderbuffer_storage)
-/// is equivalent to calling [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample)
-/// with the samples set to zero.
+/// >> Specifies that the red channel should be assigned the appropriate value
+/// >> from the currently bound texture.
 ///
-/// For [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage),
-/// the target of the operation, specified by `target` must be [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER).
-/// For [**glNamedRenderbufferStorage**](crate::context::Context::oxidegl_named_renderbuffer_storage),
-/// `renderbuffer` must be a name of an existing renderbuffer object. `internalformat`
-/// specifies the internal format to be used for the renderbuffer object's
-/// storage and must be a color-renderable, depth-renderable, or stencil-renderable
-/// format. `width` and `height` are the dimensions, in pixels, of the renderbuffer.
-/// Both `width` and `height` must be less than or equal to the value of [`GL_MAX_RENDERBUFFER_SIZE`](crate::gl_enums::GL_MAX_RENDERBUFFER_SIZE).
 ///
-/// Upon success, [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage)
-/// and [**glNamedRenderbufferStorage**](crate::context::Context::oxidegl_named_renderbuffer_storage)
-/// delete any existing data store for the renderbuffer image and the contents
-/// of the data store after calling [**glRenderbufferStorage**](crate::context::Context::oxidegl_renderbuffer_storage)
-/// are undefined.
-impl Context {
-    pub fn oxidegl_renderbuffer_storage(
-        &mut self,
-        target: GLenum,
-        internalformat: InternalFormat,
-        width: GLsizei,
-        height: GLsizei,
-    ) -> GlFallible {
-        panic!("command oxidegl_renderbuffer_storage not yet implemented");
-    }
-    pub fn oxidegl_named_renderbuffer_storage(
-        &mut self,
-        renderbuffer: GLuint,
-        internalformat: InternalFormat,
-        width: GLsizei,
-        height: GLsizei,
-    ) -> GlFallible {
-        panic!("command oxidegl_named_renderbuffer_storage not yet implemented");
-    }
-}
-/// ### Parameters
-/// `target`
+/// [`GL_TEXTURE_COMPARE_FUNC`](crate::gl_enums::GL_TEXTURE_COMPARE_FUNC)
 ///
-/// > Specifies a binding target of the allocation for [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample)
-/// > function. Must be [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER).
+/// > Specifies the comparison operator used when [`GL_TEXTURE_COMPARE_MODE`](crate::gl_enums::GL_TEXTURE_COMPARE_MODE)
+/// > is set to [`GL_COMPARE_REF_TO_TEXTURE`](crate::gl_enums::GL_COMPARE_REF_TO_TEXTURE).
+/// > Permissible values are:
+/// > |* Texture Comparison Function*                                  |* Computed result*                            |
+/// > |----------------------------------------------------------------|----------------------------------------------|
+/// > | [`GL_LEQUAL`](crate::gl_enums::GL_LEQUAL)                      | `[inlineq]`                                  |
+/// > | [`GL_GEQUAL`](crate::gl_enums::GL_GEQUAL)                      | `[inlineq]`                                  |
+/// > | [`GL_LESS`](crate::gl_enums::GL_LESS)                          | `[inlineq]`                                  |
+/// > | [`GL_GREATER`](crate::gl_enums::GL_GREATER)                    | `[inlineq]`                                  |
+/// > | [`GL_EQUAL`](crate::gl_enums::GL_EQUAL)                        | `[inlineq]`                                  |
+/// > | [`GL_NOTEQUAL`](crate::gl_enums::GL_NOTEQUAL)                  | `[inlineq]`                                  |
+/// > | [`GL_ALWAYS`](crate::gl_enums::GL_ALWAYS)                      | `[inlineq]`                                  |
+/// > | [`GL_NEVER`](crate::gl_enums::GL_NEVER)                        | `[inlineq]`                                  |
 ///
-/// `renderbuffer`
+/// > `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
 ///
-/// > Specifies the name of the renderbuffer object for [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample)
-/// > function.
+/// ### Notes
+/// [**glSamplerParameter**](crate::context::Context::oxidegl_sampler_parameter)
+/// is available only if the GL version is 3.3 or higher.
 ///
-/// `samples`
+/// If a sampler object is bound to a texture unit and that unit is used to
+/// sample from a texture, the parameters in the sampler are used to sample
+/// from the texture, rather than the equivalent parameters in the texture
+/// object bound to that unit. This introduces the possibility of sampling
+/// from the same texture object with different sets of sampler state, which
+/// may lead to a condition where a texture is *incomplete* with respect to
+/// one sampler object and not with respect to another. Thus, completeness
+/// can be considered a function of a sampler object and a texture object bound
+/// to a single texture unit, rather than a property of the texture object
+/// itself.
 ///
-/// > Specifies the number of samples to be used for the renderbuffer object's
-/// > storage.
+/// [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE) is
+/// available only if the GL version is 4.4 or greater.
 ///
-/// `internalformat`
+/// ### Associated Gets
+/// [**glGetSamplerParameter**](crate::context::Context::oxidegl_get_sampler_parameter)
+impl Context {
+    pub fn oxidegl_sampler_parameteri(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        param: GLint,
+    ) -> GlFallible {
+        panic!("command oxidegl_sampler_parameteri not yet implemented");
+    }
+    pub unsafe fn oxidegl_sampler_parameteriv(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        param: *const GLint,
+    ) -> GlFallible {
+        panic!("command oxidegl_sampler_parameteriv not yet implemented");
+    }
+    pub fn oxidegl_sampler_parameterf(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        param: GLfloat,
+    ) -> GlFallible {
+        panic!("command oxidegl_sampler_parameterf not yet implemented");
+    }
+    pub unsafe fn oxidegl_sampler_parameterfv(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        param: *const GLfloat,
+    ) -> GlFallible {
+        panic!("command oxidegl_sampler_parameterfv not yet implemented");
+    }
+    pub unsafe fn oxidegl_sampler_parameter_iiv(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        param: *const GLint,
+    ) -> GlFallible {
+        panic!("command oxidegl_sampler_parameter_iiv not yet implemented");
+    }
+    pub unsafe fn oxidegl_sampler_parameter_iuiv(
+        &mut self,
+        sampler: GLuint,
+        pname: SamplerParameter,
+        param: *const GLuint,
+    ) -> GlFallible {
+        panic!("command oxidegl_sampler_parameter_iuiv not yet implemented");
+    }
+}
+/// ### Parameters
+/// `index`
 ///
-/// > Specifies the internal format to use for the renderbuffer object's image.
+/// > Specifies the index of the viewport whose scissor box to modify.
 ///
-/// `width`
+/// `left`
 ///
-/// > Specifies the width of the renderbuffer, in pixels.
+/// `bottom`
+///
+/// > Specify the coordinate of the bottom left corner of the scissor box, in
+/// > pixels.
+///
+/// `width`
 ///
 /// `height`
 ///
-/// > Specifies the height of the renderbuffer, in pixels.
+/// > Specify ths dimensions of the scissor box, in pixels.
+///
+/// `v`
+///
+/// > For [**glScissorIndexedv**](crate::context::Context::oxidegl_scissor_indexedv),
+/// > specifies the address of an array containing the left, bottom, width and
+/// > height of each scissor box, in that order.
 ///
 /// ### Description
-/// [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample)
-/// and [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample)
-/// establish the data storage, format, dimensions and number of samples of
-/// a renderbuffer object's image.
+/// [**glScissorIndexed**](crate::context::Context::oxidegl_scissor_indexed)
+/// defines the scissor box for a specified viewport. `index` specifies the
+/// index of scissor box to modify. `index` must be less than the value of
+/// [`GL_MAX_VIEWPORTS`](crate::gl_enums::GL_MAX_VIEWPORTS). For [**glScissorIndexed**](crate::context::Context::oxidegl_scissor_indexed),
+/// `left`, `bottom`, `width` and `height` specify the left, bottom, width
+/// and height of the scissor box, in pixels, respectively. For [**glScissorIndexedv**](crate::context::Context::oxidegl_scissor_indexedv),
+/// `v` specifies the address of an array containing integers specifying the
+/// lower left corner of the scissor box, and the width and height of the scissor
+/// box, in that order.
 ///
-/// For [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample),
-/// the target of the operation, specified by `target` must be [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER).
-/// For [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample),
-/// `renderbuffer` must be an ID of an existing renderbuffer object. `internalformat`
-/// specifies the internal format to be used for the renderbuffer object's
-/// storage and must be a color-renderable, depth-renderable, or stencil-renderable
-/// format. `width` and `height` are the dimensions, in pixels, of the renderbuffer.
-/// Both `width` and `height` must be less than or equal to the value of [`GL_MAX_RENDERBUFFER_SIZE`](crate::gl_enums::GL_MAX_RENDERBUFFER_SIZE).
-/// `samples` specifies the number of samples to be used for the renderbuffer
-/// object's image, and must be less than or equal to the value of [`GL_MAX_SAMPLES`](crate::gl_enums::GL_MAX_SAMPLES).
-/// If `internalformat` is a signed or unsigned integer format then `samples`
-/// must be less than or equal to the value of [`GL_MAX_INTEGER_SAMPLES`](crate::gl_enums::GL_MAX_INTEGER_SAMPLES).
+/// To enable and disable the scissor test, call [**glEnable**](crate::context::Context::oxidegl_enable)
+/// and [**glDisable**](crate::context::Context::oxidegl_disable) with argument
+/// [`GL_SCISSOR_TEST`](crate::gl_enums::GL_SCISSOR_TEST). The test is initially
+/// disabled for all viewports. While the test is enabled, only pixels that
+/// lie within the scissor box can be modified by drawing commands. Window
+/// coordinates have integer values at the shared corners of frame buffer pixels.
 ///
-/// Upon success, [**glRenderbufferStorageMultisample**](crate::context::Context::oxidegl_renderbuffer_storage_multisample)
-/// and [**glNamedRenderbufferStorageMultisample**](crate::context::Context::oxidegl_named_renderbuffer_storage_multisample)
-/// delete any existing data store for the renderbuffer image and the contents
-/// of the data store after calling either of the functions are undefined.
+/// When the scissor test is disabled, it is as though the scissor box includes
+/// the entire window.
+///
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_SCISSOR_BOX`](crate::gl_enums::GL_SCISSOR_BOX)
+///
+/// [**glIsEnabled**](crate::context::Context::oxidegl_is_enabled) with argument
+/// [`GL_SCISSOR_TEST`](crate::gl_enums::GL_SCISSOR_TEST)
 impl Context {
-    pub fn oxidegl_renderbuffer_storage_multisample(
+    pub fn oxidegl_scissor_indexed(
         &mut self,
-        target: GLenum,
-        samples: GLsizei,
-        internalformat: InternalFormat,
+        index: GLuint,
+        left: GLint,
+        bottom: GLint,
         width: GLsizei,
         height: GLsizei,
     ) -> GlFallible {
-        panic!("command oxidegl_renderbuffer_storage_multisample not yet implemented");
+        panic!("command oxidegl_scissor_indexed not yet implemented");
     }
-    pub fn oxidegl_named_renderbuffer_storage_multisample(
+    pub unsafe fn oxidegl_scissor_indexedv(
         &mut self,
-        renderbuffer: GLuint,
-        samples: GLsizei,
-        internalformat: InternalFormat,
-        width: GLsizei,
-        height: GLsizei,
+        index: GLuint,
+        v: *const GLint,
     ) -> GlFallible {
-        panic!("command oxidegl_named_renderbuffer_storage_multisample not yet implemented");
+        panic!("command oxidegl_scissor_indexedv not yet implemented");
     }
 }
 /// ### Parameters
-/// `sampler`
+/// `target`
 ///
-/// > Specifies the sampler object whose parameter to modify.
+/// > Specifies the target to which the texture is bound for [**glTexBuffer**](crate::context::Context::oxidegl_tex_buffer).
+/// > Must be [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER).
 ///
-/// `pname`
+/// `texture`
 ///
-/// > Specifies the symbolic name of a sampler parameter. `pname` can be one
-/// > of the following: [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S),
-/// > [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T), [`GL_TEXTURE_WRAP_R`](crate::gl_enums::GL_TEXTURE_WRAP_R),
-/// > [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER), [`GL_TEXTURE_MAG_FILTER`](crate::gl_enums::GL_TEXTURE_MAG_FILTER),
-/// > [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR), [`GL_TEXTURE_MIN_LOD`](crate::gl_enums::GL_TEXTURE_MIN_LOD),
-/// > [`GL_TEXTURE_MAX_LOD`](crate::gl_enums::GL_TEXTURE_MAX_LOD), [`GL_TEXTURE_LOD_BIAS`](crate::gl_enums::GL_TEXTURE_LOD_BIAS)
-/// > [`GL_TEXTURE_COMPARE_MODE`](crate::gl_enums::GL_TEXTURE_COMPARE_MODE), or
-/// > [`GL_TEXTURE_COMPARE_FUNC`](crate::gl_enums::GL_TEXTURE_COMPARE_FUNC).
+/// > Specifies the texture object name for [**glTextureBuffer**](crate::context::Context::oxidegl_texture_buffer).
 ///
-/// `param`
+/// `internalformat`
 ///
-/// > For the scalar commands, specifies the value of `pname`.
+/// > Specifies the internal format of the data in the store belonging to `buffer`.
 ///
-/// `params`
+/// `buffer`
 ///
-/// > For the vector commands( [**glSamplerParameter*v**](crate::context::Context::oxidegl_sampler_parameter*v)
-/// > ), specifies a pointer to an array where the value or values of `pname`
-/// > are stored.
+/// > Specifies the name of the buffer object whose storage to attach to the
+/// > active buffer texture.
 ///
 /// ### Description
-/// [**glSamplerParameter**](crate::context::Context::oxidegl_sampler_parameter)
-/// assigns the value or values in `params` to the sampler parameter specified
-/// as `pname`. `sampler` specifies the sampler object to be modified, and
-/// must be the name of a sampler object previously returned from a call to
-/// [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers). The
-/// following symbols are accepted in `pname`:
-///
-/// [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER)
-///
-/// > The texture minifying function is used whenever the pixel being textured
-/// > maps to an area greater than one texture element. There are six defined
-/// > minifying functions. Two of them use the nearest one or nearest four texture
-/// > elements to compute the texture value. The other four use mipmaps.
-///
-/// > A mipmap is an ordered set of arrays representing the same image at progressively
-/// > lower resolutions. If the texture has dimensions `[inlineq]` `[inlineq]`
-/// > `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
-/// > `[inlineq]` `[inlineq]` [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
-/// > [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d),
-/// > [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d),
-/// > or [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d)
-/// > with the *level* argument indicating the order of the mipmaps. Level 0
-/// > is the original texture; level `[inlineq]` `[inlineq]`
-///
-/// > `params` supplies a function for minifying the texture as one of the following:
-/// > [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
-///
-/// >> Returns the value of the texture element that is nearest (in Manhattan
-/// >> distance) to the center of the pixel being textured.
-///
-/// > [`GL_LINEAR`](crate::gl_enums::GL_LINEAR)
-///
-/// >> Returns the weighted average of the four texture elements that are closest
-/// >> to the center of the pixel being textured. These can include border texture
-/// >> elements, depending on the values of [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S)
-/// >> and [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T), and on the
-/// >> exact mapping.
-///
-/// > [`GL_NEAREST_MIPMAP_NEAREST`](crate::gl_enums::GL_NEAREST_MIPMAP_NEAREST)
+/// [**glTexBuffer**](crate::context::Context::oxidegl_tex_buffer) and [**glTextureBuffer**](crate::context::Context::oxidegl_texture_buffer)
+/// attaches the data store of a specified buffer object to a specified texture
+/// object, and specify the storage format for the texture image found in the
+/// buffer object. The texture object must be a buffer texture.
 ///
-/// >> Chooses the mipmap that most closely matches the size of the pixel being
-/// >> textured and uses the [`GL_NEAREST`](crate::gl_enums::GL_NEAREST) criterion
-/// >> (the texture element nearest to the center of the pixel) to produce a texture
-/// >> value.
+/// If `buffer` is zero, any buffer object attached to the buffer texture is
+/// detached and no new buffer object is attached. If `buffer` is non-zero,
+/// it must be the name of an existing buffer object.
 ///
-/// > [`GL_LINEAR_MIPMAP_NEAREST`](crate::gl_enums::GL_LINEAR_MIPMAP_NEAREST)
+/// `internalformat` specifies the storage format, and must be one of the following
+/// sized internal formats:
 ///
-/// >> Chooses the mipmap that most closely matches the size of the pixel being
-/// >> textured and uses the [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) criterion
-/// >> (a weighted average of the four texture elements that are closest to the
-/// >> center of the pixel) to produce a texture value.
+/// | *Sized Internal Format*                               | *Base Type*       | *Components*        | *Norm*     | 0  | 1  | 2  | 3  |
+/// |-------------------------------------------------------|-------------------|---------------------|------------|----|----|----|----|
+/// | [`GL_R8`](crate::gl_enums::GL_R8)                     | ubyte             | 1                   | YES        | R  | 0  | 0  | 1  |
+/// | [`GL_R16`](crate::gl_enums::GL_R16)                   | ushort            | 1                   | YES        | R  | 0  | 0  | 1  |
+/// | [`GL_R16F`](crate::gl_enums::GL_R16F)                 | half              | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R32F`](crate::gl_enums::GL_R32F)                 | float             | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R8I`](crate::gl_enums::GL_R8I)                   | byte              | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R16I`](crate::gl_enums::GL_R16I)                 | short             | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R32I`](crate::gl_enums::GL_R32I)                 | int               | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R8UI`](crate::gl_enums::GL_R8UI)                 | ubyte             | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R16UI`](crate::gl_enums::GL_R16UI)               | ushort            | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R32UI`](crate::gl_enums::GL_R32UI)               | uint              | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_RG8`](crate::gl_enums::GL_RG8)                   | ubyte             | 2                   | YES        | R  | G  | 0  | 1  |
+/// | [`GL_RG16`](crate::gl_enums::GL_RG16)                 | ushort            | 2                   | YES        | R  | G  | 0  | 1  |
+/// | [`GL_RG16F`](crate::gl_enums::GL_RG16F)               | half              | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG32F`](crate::gl_enums::GL_RG32F)               | float             | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG8I`](crate::gl_enums::GL_RG8I)                 | byte              | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG16I`](crate::gl_enums::GL_RG16I)               | short             | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG32I`](crate::gl_enums::GL_RG32I)               | int               | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG8UI`](crate::gl_enums::GL_RG8UI)               | ubyte             | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG16UI`](crate::gl_enums::GL_RG16UI)             | ushort            | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG32UI`](crate::gl_enums::GL_RG32UI)             | uint              | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RGB32F`](crate::gl_enums::GL_RGB32F)             | float             | 3                   | NO         | R  | G  | B  | 1  |
+/// | [`GL_RGB32I`](crate::gl_enums::GL_RGB32I)             | int               | 3                   | NO         | R  | G  | B  | 1  |
+/// | [`GL_RGB32UI`](crate::gl_enums::GL_RGB32UI)           | uint              | 3                   | NO         | R  | G  | B  | 1  |
+/// | [`GL_RGBA8`](crate::gl_enums::GL_RGBA8)               | uint              | 4                   | YES        | R  | G  | B  | A  |
+/// | [`GL_RGBA16`](crate::gl_enums::GL_RGBA16)             | short             | 4                   | YES        | R  | G  | B  | A  |
+/// | [`GL_RGBA16F`](crate::gl_enums::GL_RGBA16F)           | half              | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA32F`](crate::gl_enums::GL_RGBA32F)           | float             | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA8I`](crate::gl_enums::GL_RGBA8I)             | byte              | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA16I`](crate::gl_enums::GL_RGBA16I)           | short             | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA32I`](crate::gl_enums::GL_RGBA32I)           | int               | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA8UI`](crate::gl_enums::GL_RGBA8UI)           | ubyte             | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA16UI`](crate::gl_enums::GL_RGBA16UI)         | ushort            | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA32UI`](crate::gl_enums::GL_RGBA32UI)         | uint              | 4                   | NO         | R  | G  | B  | A  |
 ///
-/// > [`GL_NEAREST_MIPMAP_LINEAR`](crate::gl_enums::GL_NEAREST_MIPMAP_LINEAR)
+/// When a buffer object is attached to a buffer texture, the buffer object's
+/// data store is taken as the texture's texel array. The number of texels
+/// in the buffer texture's texel array is given by $$ \left\lfloor{ size \over
+///{ components \times sizeof(base\_type)}} \right\rfloor $$ where $size$ is
+/// the size of the buffer object in basic machine units (the value of [`GL_BUFFER_SIZE`](crate::gl_enums::GL_BUFFER_SIZE)
+/// for `buffer` ), and $components$ and $base\_type$ are the element count
+/// and base data type for elements, as specified in the table above. The number
+/// of texels in the texel array is then clamped to the value of the implementation-dependent
+/// limit [`GL_MAX_TEXTURE_BUFFER_SIZE`](crate::gl_enums::GL_MAX_TEXTURE_BUFFER_SIZE).
+/// When a buffer texture is accessed in a shader, the results of a texel
+/// fetch are undefined if the specified texel coordinate is negative, or greater
+/// than or equal to the clamped number of texels in the texel array.
 ///
-/// >> Chooses the two mipmaps that most closely match the size of the pixel being
-/// >> textured and uses the [`GL_NEAREST`](crate::gl_enums::GL_NEAREST) criterion
-/// >> (the texture element nearest to the center of the pixel) to produce a texture
-/// >> value from each mipmap. The final texture value is a weighted average of
-/// >> those two values.
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_TEXTURE_BUFFER_SIZE`](crate::gl_enums::GL_MAX_TEXTURE_BUFFER_SIZE)
 ///
-/// > [`GL_LINEAR_MIPMAP_LINEAR`](crate::gl_enums::GL_LINEAR_MIPMAP_LINEAR)
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_BUFFER`](crate::gl_enums::GL_TEXTURE_BINDING_BUFFER)
 ///
-/// >> Chooses the two mipmaps that most closely match the size of the pixel being
-/// >> textured and uses the [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) criterion
-/// >> (a weighted average of the four texture elements that are closest to the
-/// >> center of the pixel) to produce a texture value from each mipmap. The final
-/// >> texture value is a weighted average of those two values.
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with argument [`GL_TEXTURE_BUFFER_DATA_STORE_BINDING`](crate::gl_enums::GL_TEXTURE_BUFFER_DATA_STORE_BINDING)
+impl Context {
+    pub fn oxidegl_tex_buffer(
+        &mut self,
+        target: TextureTarget,
+        internalformat: SizedInternalFormat,
+        buffer: GLuint,
+    ) -> GlFallible {
+        panic!("command oxidegl_tex_buffer not yet implemented");
+    }
+    pub fn oxidegl_texture_buffer(
+        &mut self,
+        texture: GLuint,
+        internalformat: SizedInternalFormat,
+        buffer: GLuint,
+    ) -> GlFallible {
+        panic!("command oxidegl_texture_buffer not yet implemented");
+    }
+}
+/// ### Parameters
+/// `target`
 ///
+/// > Specifies the target to which the texture object is bound for [**glTexBufferRange**](crate::context::Context::oxidegl_tex_buffer_range).
+/// > Must be [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER).
 ///
-/// > As more texture elements are sampled in the minification process, fewer
-/// > aliasing artifacts will be apparent. While the [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
-/// > and [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) minification functions can
-/// > be faster than the other four, they sample only one or four texture elements
-/// > to determine the texture value of the pixel being rendered and can produce
-/// > moire patterns or ragged transitions. The initial value of [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER)
-/// > is [`GL_NEAREST_MIPMAP_LINEAR`](crate::gl_enums::GL_NEAREST_MIPMAP_LINEAR).
+/// `texture`
 ///
-/// [`GL_TEXTURE_MAG_FILTER`](crate::gl_enums::GL_TEXTURE_MAG_FILTER)
+/// > Specifies the texture object name for [**glTextureBufferRange**](crate::context::Context::oxidegl_texture_buffer_range).
 ///
-/// > The texture magnification function is used when the pixel being textured
-/// > maps to an area less than or equal to one texture element. It sets the
-/// > texture magnification function to either [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
-/// > or [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) (see below). [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
-/// > is generally faster than [`GL_LINEAR`](crate::gl_enums::GL_LINEAR), but
-/// > it can produce textured images with sharper edges because the transition
-/// > between texture elements is not as smooth. The initial value of [`GL_TEXTURE_MAG_FILTER`](crate::gl_enums::GL_TEXTURE_MAG_FILTER)
-/// > is [`GL_LINEAR`](crate::gl_enums::GL_LINEAR). [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
+/// `internalformat`
 ///
-/// >> Returns the value of the texture element that is nearest (in Manhattan
-/// >> distance) to the center of the pixel being textured.
+/// > Specifies the internal format of the data in the store belonging to `buffer`.
 ///
-/// > [`GL_LINEAR`](crate::gl_enums::GL_LINEAR)
+/// `buffer`
 ///
-/// >> Returns the weighted average of the four texture elements that are closest
-/// >> to the center of the pixel being textured. These can include border texture
-/// >> elements, depending on the values of [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S)
-/// >> and [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T), and on the
-/// >> exact mapping.
+/// > Specifies the name of the buffer object whose storage to attach to the
+/// > active buffer texture.
 ///
+/// `offset`
 ///
+/// > Specifies the offset of the start of the range of the buffer's data store
+/// > to attach.
 ///
-/// [`GL_TEXTURE_MIN_LOD`](crate::gl_enums::GL_TEXTURE_MIN_LOD)
+/// `size`
 ///
-/// > Sets the minimum level-of-detail parameter. This floating-point value limits
-/// > the selection of highest resolution mipmap (lowest mipmap level). The initial
-/// > value is -1000.
+/// > Specifies the size of the range of the buffer's data store to attach.
 ///
+/// ### Description
+/// [**glTexBufferRange**](crate::context::Context::oxidegl_tex_buffer_range)
+/// and [**glTextureBufferRange**](crate::context::Context::oxidegl_texture_buffer_range)
+/// attach a range of the data store of a specified buffer object to a specified
+/// texture object, and specify the storage format for the texture image found
+/// in the buffer object. The texture object must be a buffer texture.
 ///
-/// [`GL_TEXTURE_MAX_LOD`](crate::gl_enums::GL_TEXTURE_MAX_LOD)
+/// If `buffer` is zero, any buffer object attached to the buffer texture is
+/// detached and no new buffer object is attached. If `buffer` is non-zero,
+/// it must be the name of an existing buffer object.
 ///
-/// > Sets the maximum level-of-detail parameter. This floating-point value limits
-/// > the selection of the lowest resolution mipmap (highest mipmap level). The
-/// > initial value is 1000.
+/// The start and size of the range are specified by `offset` and `size` respectively,
+/// both measured in basic machine units. `offset` must be greater than or
+/// equal to zero, `size` must be greater than zero, and the sum of `offset`
+/// and `size` must not exceed the value of [`GL_BUFFER_SIZE`](crate::gl_enums::GL_BUFFER_SIZE)
+/// for `buffer`. Furthermore, `offset` must be an integer multiple of the
+/// value of [`GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT`](crate::gl_enums::GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT).
 ///
+/// `internalformat` specifies the storage format, and must be one of the following
+/// sized internal formats:
 ///
-/// [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S)
+/// | *Sized Internal Format*                               | *Base Type*       | *Components*        | *Norm*     | 0  | 1  | 2  | 3  |
+/// |-------------------------------------------------------|-------------------|---------------------|------------|----|----|----|----|
+/// | [`GL_R8`](crate::gl_enums::GL_R8)                     | ubyte             | 1                   | YES        | R  | 0  | 0  | 1  |
+/// | [`GL_R16`](crate::gl_enums::GL_R16)                   | ushort            | 1                   | YES        | R  | 0  | 0  | 1  |
+/// | [`GL_R16F`](crate::gl_enums::GL_R16F)                 | half              | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R32F`](crate::gl_enums::GL_R32F)                 | float             | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R8I`](crate::gl_enums::GL_R8I)                   | byte              | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R16I`](crate::gl_enums::GL_R16I)                 | short             | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R32I`](crate::gl_enums::GL_R32I)                 | int               | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R8UI`](crate::gl_enums::GL_R8UI)                 | ubyte             | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R16UI`](crate::gl_enums::GL_R16UI)               | ushort            | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_R32UI`](crate::gl_enums::GL_R32UI)               | uint              | 1                   | NO         | R  | 0  | 0  | 1  |
+/// | [`GL_RG8`](crate::gl_enums::GL_RG8)                   | ubyte             | 2                   | YES        | R  | G  | 0  | 1  |
+/// | [`GL_RG16`](crate::gl_enums::GL_RG16)                 | ushort            | 2                   | YES        | R  | G  | 0  | 1  |
+/// | [`GL_RG16F`](crate::gl_enums::GL_RG16F)               | half              | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG32F`](crate::gl_enums::GL_RG32F)               | float             | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG8I`](crate::gl_enums::GL_RG8I)                 | byte              | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG16I`](crate::gl_enums::GL_RG16I)               | short             | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG32I`](crate::gl_enums::GL_RG32I)               | int               | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG8UI`](crate::gl_enums::GL_RG8UI)               | ubyte             | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG16UI`](crate::gl_enums::GL_RG16UI)             | ushort            | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RG32UI`](crate::gl_enums::GL_RG32UI)             | uint              | 2                   | NO         | R  | G  | 0  | 1  |
+/// | [`GL_RGB32F`](crate::gl_enums::GL_RGB32F)             | float             | 3                   | NO         | R  | G  | B  | 1  |
+/// | [`GL_RGB32I`](crate::gl_enums::GL_RGB32I)             | int               | 3                   | NO         | R  | G  | B  | 1  |
+/// | [`GL_RGB32UI`](crate::gl_enums::GL_RGB32UI)           | uint              | 3                   | NO         | R  | G  | B  | 1  |
+/// | [`GL_RGBA8`](crate::gl_enums::GL_RGBA8)               | uint              | 4                   | YES        | R  | G  | B  | A  |
+/// | [`GL_RGBA16`](crate::gl_enums::GL_RGBA16)             | short             | 4                   | YES        | R  | G  | B  | A  |
+/// | [`GL_RGBA16F`](crate::gl_enums::GL_RGBA16F)           | half              | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA32F`](crate::gl_enums::GL_RGBA32F)           | float             | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA8I`](crate::gl_enums::GL_RGBA8I)             | byte              | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA16I`](crate::gl_enums::GL_RGBA16I)           | short             | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA32I`](crate::gl_enums::GL_RGBA32I)           | int               | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA8UI`](crate::gl_enums::GL_RGBA8UI)           | ubyte             | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA16UI`](crate::gl_enums::GL_RGBA16UI)         | ushort            | 4                   | NO         | R  | G  | B  | A  |
+/// | [`GL_RGBA32UI`](crate::gl_enums::GL_RGBA32UI)         | uint              | 4                   | NO         | R  | G  | B  | A  |
 ///
-/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE),
-/// > [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT), [`GL_REPEAT`](crate::gl_enums::GL_REPEAT),
-/// > or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE).
-/// > [`GL_CLAMP_TO_BORDER`](crate::gl_enums::GL_CLAMP_TO_BORDER) causes the
-/// > `[inlineq]` `[inlineq]` `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE)
-/// > causes `[inlineq]` `[inlineq]` `[inlineq]` [`GL_REPEAT`](crate::gl_enums::GL_REPEAT)
-/// > causes the integer part of the `[inlineq]` [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT)
-/// > causes the `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
-/// > `[inlineq]` `[inlineq]` [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE)
-/// > causes the `[inlineq]` [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT)
-/// > for one repetition of the texture, at which point the coordinate to be
-/// > clamped as in [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE). Initially,
-/// > [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S) is set to [`GL_REPEAT`](crate::gl_enums::GL_REPEAT).
+/// When a range of a buffer object is attached to a buffer texture, the specified
+/// range of the buffer object's data store is taken as the texture's texel
+/// array. The number of texels in the buffer texture's texel array is given
+/// by $$ \left\lfloor{ size \over{ components \times sizeof(base\_type)}} \right\rfloor
+/// $$ where $components$ and $base\_type$ are the element count and base data
+/// type for elements, as specified in the table above. The number of texels
+/// in the texel array is then clamped to the value of the implementation-dependent
+/// limit [`GL_MAX_TEXTURE_BUFFER_SIZE`](crate::gl_enums::GL_MAX_TEXTURE_BUFFER_SIZE).
+/// When a buffer texture is accessed in a shader, the results of a texel
+/// fetch are undefined if the specified texel coordinate is negative, or greater
+/// than or equal to the clamped number of texels in the texel array.
 ///
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT`](crate::gl_enums::GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT)
 ///
-/// [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T)
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// with arguments [`GL_TEXTURE_BUFFER_OFFSET`](crate::gl_enums::GL_TEXTURE_BUFFER_OFFSET)
+/// or [`GL_TEXTURE_BUFFER_SIZE`](crate::gl_enums::GL_TEXTURE_BUFFER_SIZE).
+impl Context {
+    pub fn oxidegl_tex_buffer_range(
+        &mut self,
+        target: TextureTarget,
+        internalformat: SizedInternalFormat,
+        buffer: GLuint,
+        offset: GLintptr,
+        size: GLsizeiptr,
+    ) -> GlFallible {
+        panic!("command oxidegl_tex_buffer_range not yet implemented");
+    }
+    pub fn oxidegl_texture_buffer_range(
+        &mut self,
+        texture: GLuint,
+        internalformat: SizedInternalFormat,
+        buffer: GLuint,
+        offset: GLintptr,
+        size: GLsizeiptr,
+    ) -> GlFallible {
+        panic!("command oxidegl_texture_buffer_range not yet implemented");
+    }
+}
+/// ### Parameters
+/// `target`
 ///
-/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE),
-/// > [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT), [`GL_REPEAT`](crate::gl_enums::GL_REPEAT),
-/// > or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE).
-/// > See the discussion under [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S).
-/// > Initially, [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T) is
-/// > set to [`GL_REPEAT`](crate::gl_enums::GL_REPEAT).
+/// > Specifies the target to which the texture is bound for [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter)
+/// > functions. Must be one of [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D),
+/// > [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D),
+/// > [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE),
+/// > [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY),
+/// > [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D), [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP),
+/// > [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+/// > or [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE).
 ///
-/// [`GL_TEXTURE_WRAP_R`](crate::gl_enums::GL_TEXTURE_WRAP_R)
+/// `texture`
+///
+/// > Specifies the texture object name for [**glTextureParameter**](crate::context::Context::oxidegl_texture_parameter)
+/// > functions.
+///
+/// `pname`
+///
+/// > Specifies the symbolic name of a single-valued texture parameter. `pname`
+/// > can be one of the following: [`GL_DEPTH_STENCIL_TEXTURE_MODE`](crate::gl_enums::GL_DEPTH_STENCIL_TEXTURE_MODE),
+/// > [`GL_TEXTURE_BASE_LEVEL`](crate::gl_enums::GL_TEXTURE_BASE_LEVEL), [`GL_TEXTURE_COMPARE_FUNC`](crate::gl_enums::GL_TEXTURE_COMPARE_FUNC),
+/// > [`GL_TEXTURE_COMPARE_MODE`](crate::gl_enums::GL_TEXTURE_COMPARE_MODE), [`GL_TEXTURE_LOD_BIAS`](crate::gl_enums::GL_TEXTURE_LOD_BIAS),
+/// > [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER), [`GL_TEXTURE_MAG_FILTER`](crate::gl_enums::GL_TEXTURE_MAG_FILTER),
+/// > [`GL_TEXTURE_MIN_LOD`](crate::gl_enums::GL_TEXTURE_MIN_LOD), [`GL_TEXTURE_MAX_LOD`](crate::gl_enums::GL_TEXTURE_MAX_LOD),
+/// > [`GL_TEXTURE_MAX_LEVEL`](crate::gl_enums::GL_TEXTURE_MAX_LEVEL), [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R),
+/// > [`GL_TEXTURE_SWIZZLE_G`](crate::gl_enums::GL_TEXTURE_SWIZZLE_G), [`GL_TEXTURE_SWIZZLE_B`](crate::gl_enums::GL_TEXTURE_SWIZZLE_B),
+/// > [`GL_TEXTURE_SWIZZLE_A`](crate::gl_enums::GL_TEXTURE_SWIZZLE_A), [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S),
+/// > [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T), or [`GL_TEXTURE_WRAP_R`](crate::gl_enums::GL_TEXTURE_WRAP_R).
+///
+/// > For the vector commands( [**glTexParameter*v**](crate::context::Context::oxidegl_tex_parameter*v)
+/// > ), `pname` can also be one of [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR)
+/// > or [`GL_TEXTURE_SWIZZLE_RGBA`](crate::gl_enums::GL_TEXTURE_SWIZZLE_RGBA).
+///
+/// `param`
+///
+/// > For the scalar commands, specifies the value of `pname`.
+///
+/// `params`
+///
+/// > For the vector commands, specifies a pointer to an array where the value
+/// > or values of `pname` are stored.
+///
+/// ### Description
+/// [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter) and
+/// [**glTextureParameter**](crate::context::Context::oxidegl_texture_parameter)
+/// assign the value or values in `params` to the texture parameter specified
+/// as `pname`. For [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter),
+/// `target` defines the target texture, either [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D),
+/// [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D),
+/// [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE),
+/// [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY),
+/// [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D), [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP),
+/// [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+/// or [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE). The
+/// following symbols are accepted in `pname`:
+///
+/// [`GL_DEPTH_STENCIL_TEXTURE_MODE`](crate::gl_enums::GL_DEPTH_STENCIL_TEXTURE_MODE)
+///
+/// > Specifies the mode used to read from depth-stencil format textures. `params`
+/// > must be one of [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT)
+/// > or [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX). If the depth
+/// > stencil mode is [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT),
+/// > then reads from depth-stencil format textures will return the depth component
+/// > of the texel in `[inlineq]` [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX)
+/// > then the stencil component is returned in `[inlineq]` [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT).
+///
+/// [`GL_TEXTURE_BASE_LEVEL`](crate::gl_enums::GL_TEXTURE_BASE_LEVEL)
+///
+/// > Specifies the index of the lowest defined mipmap level. This is an integer
+/// > value. The initial value is 0.
 ///
-/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE),
-/// > [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT), [`GL_REPEAT`](crate::gl_enums::GL_REPEAT),
-/// > or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE).
-/// > See the discussion under [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S).
-/// > Initially, [`GL_TEXTURE_WRAP_R`](crate::gl_enums::GL_TEXTURE_WRAP_R) is
-/// > set to [`GL_REPEAT`](crate::gl_enums::GL_REPEAT).
 ///
 /// [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR)
 ///
@@ -8333,22 +7736,14 @@ impl Context {
 /// > depth components, the first component of [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR)
 /// > is interpreted as a depth value. The initial value is `[inlineq]`
 ///
-/// [`GL_TEXTURE_COMPARE_MODE`](crate::gl_enums::GL_TEXTURE_COMPARE_MODE)
-///
-/// > Specifies the texture comparison mode for currently bound textures. That
-/// > is, a texture whose internal format is [`GL_DEPTH_COMPONENT_*`](crate::gl_enums::GL_DEPTH_COMPONENT_*);
-/// > see [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d))
-/// > Permissible values are: [`GL_COMPARE_REF_TO_TEXTURE`](crate::gl_enums::GL_COMPARE_REF_TO_TEXTURE)
-///
-/// >> Specifies that the interpolated and clamped `[inlineq]` [`GL_TEXTURE_COMPARE_FUNC`](crate::gl_enums::GL_TEXTURE_COMPARE_FUNC)
-/// >> for details of how the comparison is evaluated. The result of the comparison
-/// >> is assigned to the red channel.
-///
-/// > [`GL_NONE`](crate::gl_enums::GL_NONE)
-///
-/// >> Specifies that the red channel should be assigned the appropriate value
-/// >> from the currently bound texture.
-///
+/// > If the values for [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR)
+/// > are specified with [**glTexParameterIiv**](crate::context::Context::oxidegl_tex_parameter_iiv)
+/// > or [**glTexParameterIuiv**](crate::context::Context::oxidegl_tex_parameter_iuiv),
+/// > the values are stored unmodified with an internal data type of integer.
+/// > If specified with [**glTexParameteriv**](crate::context::Context::oxidegl_tex_parameteriv),
+/// > they are converted to floating point with the following equation: `[inlineq]`
+/// > [**glTexParameterfv**](crate::context::Context::oxidegl_tex_parameterfv),
+/// > they are stored unmodified as floating-point values.
 ///
 /// [`GL_TEXTURE_COMPARE_FUNC`](crate::gl_enums::GL_TEXTURE_COMPARE_FUNC)
 ///
@@ -8366,752 +7761,272 @@ impl Context {
 /// > | [`GL_ALWAYS`](crate::gl_enums::GL_ALWAYS)                      | `[inlineq]`                                  |
 /// > | [`GL_NEVER`](crate::gl_enums::GL_NEVER)                        | `[inlineq]`                                  |
 ///
-/// > `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` `[inlineq]`
 ///
-/// ### Notes
-/// [**glSamplerParameter**](crate::context::Context::oxidegl_sampler_parameter)
-/// is available only if the GL version is 3.3 or higher.
+/// [`GL_TEXTURE_COMPARE_MODE`](crate::gl_enums::GL_TEXTURE_COMPARE_MODE)
 ///
-/// If a sampler object is bound to a texture unit and that unit is used to
-/// sample from a texture, the parameters in the sampler are used to sample
-/// from the texture, rather than the equivalent parameters in the texture
-/// object bound to that unit. This introduces the possibility of sampling
-/// from the same texture object with different sets of sampler state, which
-/// may lead to a condition where a texture is *incomplete* with respect to
-/// one sampler object and not with respect to another. Thus, completeness
-/// can be considered a function of a sampler object and a texture object bound
-/// to a single texture unit, rather than a property of the texture object
-/// itself.
+/// > Specifies the texture comparison mode for currently bound depth textures.
+/// > That is, a texture whose internal format is [`GL_DEPTH_COMPONENT_*`](crate::gl_enums::GL_DEPTH_COMPONENT_*);
+/// > see [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d))
+/// > Permissible values are: [`GL_COMPARE_REF_TO_TEXTURE`](crate::gl_enums::GL_COMPARE_REF_TO_TEXTURE)
 ///
-/// [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE) is
-/// available only if the GL version is 4.4 or greater.
+/// >> Specifies that the interpolated and clamped `[inlineq]` [`GL_TEXTURE_COMPARE_FUNC`](crate::gl_enums::GL_TEXTURE_COMPARE_FUNC)
+/// >> for details of how the comparison is evaluated. The result of the comparison
+/// >> is assigned to the red channel.
 ///
-/// ### Associated Gets
-/// [**glGetSamplerParameter**](crate::context::Context::oxidegl_get_sampler_parameter)
-impl Context {
-    pub fn oxidegl_sampler_parameteri(
-        &mut self,
-        sampler: GLuint,
-        pname: SamplerParameter,
-        param: GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_sampler_parameteri not yet implemented");
-    }
-    pub unsafe fn oxidegl_sampler_parameteriv(
-        &mut self,
-        sampler: GLuint,
-        pname: SamplerParameter,
-        param: *const GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_sampler_parameteriv not yet implemented");
-    }
-    pub fn oxidegl_sampler_parameterf(
-        &mut self,
-        sampler: GLuint,
-        pname: SamplerParameter,
-        param: GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_sampler_parameterf not yet implemented");
-    }
-    pub unsafe fn oxidegl_sampler_parameterfv(
-        &mut self,
-        sampler: GLuint,
-        pname: SamplerParameter,
-        param: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_sampler_parameterfv not yet implemented");
-    }
-    pub unsafe fn oxidegl_sampler_parameter_iiv(
-        &mut self,
-        sampler: GLuint,
-        pname: SamplerParameter,
-        param: *const GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_sampler_parameter_iiv not yet implemented");
-    }
-    pub unsafe fn oxidegl_sampler_parameter_iuiv(
-        &mut self,
-        sampler: GLuint,
-        pname: SamplerParameter,
-        param: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_sampler_parameter_iuiv not yet implemented");
-    }
-}
-/// ### Parameters
-/// `index`
+/// > [`GL_NONE`](crate::gl_enums::GL_NONE)
 ///
-/// > Specifies the index of the viewport whose scissor box to modify.
+/// >> Specifies that the red channel should be assigned the appropriate value
+/// >> from the currently bound depth texture.
 ///
-/// `left`
 ///
-/// `bottom`
+/// [`GL_TEXTURE_LOD_BIAS`](crate::gl_enums::GL_TEXTURE_LOD_BIAS)
 ///
-/// > Specify the coordinate of the bottom left corner of the scissor box, in
-/// > pixels.
+/// > `params` specifies a fixed bias value that is to be added to the level-of-detail
+/// > parameter for the texture before texture sampling. The specified value
+/// > is added to the shader-supplied bias value (if any) and subsequently clamped
+/// > into the implementation-defined range `[inlineq]` `[inlineq]` [`GL_MAX_TEXTURE_LOD_BIAS`](crate::gl_enums::GL_MAX_TEXTURE_LOD_BIAS).
+/// > The initial value is 0.0.
 ///
-/// `width`
+/// [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER)
 ///
-/// `height`
+/// > The texture minifying function is used whenever the level-of-detail function
+/// > used when sampling from the texture determines that the texture should
+/// > be minified. There are six defined minifying functions. Two of them use
+/// > either the nearest texture elements or a weighted average of multiple texture
+/// > elements to compute the texture value. The other four use mipmaps.
 ///
-/// > Specify ths dimensions of the scissor box, in pixels.
+/// > A mipmap is an ordered set of arrays representing the same image at progressively
+/// > lower resolutions. If the texture has dimensions `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
+/// > [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d),
+/// > [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d),
+/// > or [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d)
+/// > with the *level* argument indicating the order of the mipmaps. Level 0
+/// > is the original texture; level `[inlineq]` `[inlineq]`
 ///
-/// `v`
+/// > `params` supplies a function for minifying the texture as one of the following:
+/// > [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
 ///
-/// > For [**glScissorIndexedv**](crate::context::Context::oxidegl_scissor_indexedv),
-/// > specifies the address of an array containing the left, bottom, width and
-/// > height of each scissor box, in that order.
+/// >> Returns the value of the texture element that is nearest (in Manhattan
+/// >> distance) to the specified texture coordinates.
 ///
-/// ### Description
-/// [**glScissorIndexed**](crate::context::Context::oxidegl_scissor_indexed)
-/// defines the scissor box for a specified viewport. `index` specifies the
-/// index of scissor box to modify. `index` must be less than the value of
-/// [`GL_MAX_VIEWPORTS`](crate::gl_enums::GL_MAX_VIEWPORTS). For [**glScissorIndexed**](crate::context::Context::oxidegl_scissor_indexed),
-/// `left`, `bottom`, `width` and `height` specify the left, bottom, width
-/// and height of the scissor box, in pixels, respectively. For [**glScissorIndexedv**](crate::context::Context::oxidegl_scissor_indexedv),
-/// `v` specifies the address of an array containing integers specifying the
-/// lower left corner of the scissor box, and the width and height of the scissor
-/// box, in that order.
+/// > [`GL_LINEAR`](crate::gl_enums::GL_LINEAR)
 ///
-/// To enable and disable the scissor test, call [**glEnable**](crate::context::Context::oxidegl_enable)
-/// and [**glDisable**](crate::context::Context::oxidegl_disable) with argument
-/// [`GL_SCISSOR_TEST`](crate::gl_enums::GL_SCISSOR_TEST). The test is initially
-/// disabled for all viewports. While the test is enabled, only pixels that
-/// lie within the scissor box can be modified by drawing commands. Window
-/// coordinates have integer values at the shared corners of frame buffer pixels.
+/// >> Returns the weighted average of the four texture elements that are closest
+/// >> to the specified texture coordinates. These can include items wrapped or
+/// >> repeated from other parts of a texture, depending on the values of [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S)
+/// >> and [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T), and on the
+/// >> exact mapping.
 ///
-/// When the scissor test is disabled, it is as though the scissor box includes
-/// the entire window.
+/// > [`GL_NEAREST_MIPMAP_NEAREST`](crate::gl_enums::GL_NEAREST_MIPMAP_NEAREST)
 ///
-/// ### Associated Gets
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_SCISSOR_BOX`](crate::gl_enums::GL_SCISSOR_BOX)
+/// >> Chooses the mipmap that most closely matches the size of the pixel being
+/// >> textured and uses the [`GL_NEAREST`](crate::gl_enums::GL_NEAREST) criterion
+/// >> (the texture element closest to the specified texture coordinates) to produce
+/// >> a texture value.
 ///
-/// [**glIsEnabled**](crate::context::Context::oxidegl_is_enabled) with argument
-/// [`GL_SCISSOR_TEST`](crate::gl_enums::GL_SCISSOR_TEST)
-impl Context {
-    pub fn oxidegl_scissor_indexed(
-        &mut self,
-        index: GLuint,
-        left: GLint,
-        bottom: GLint,
-        width: GLsizei,
-        height: GLsizei,
-    ) -> GlFallible {
-        panic!("command oxidegl_scissor_indexed not yet implemented");
-    }
-    pub unsafe fn oxidegl_scissor_indexedv(
-        &mut self,
-        index: GLuint,
-        v: *const GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_scissor_indexedv not yet implemented");
-    }
-}
-/// ### Parameters
-/// `target`
+/// > [`GL_LINEAR_MIPMAP_NEAREST`](crate::gl_enums::GL_LINEAR_MIPMAP_NEAREST)
 ///
-/// > Specifies the target to which the texture is bound for [**glTexBuffer**](crate::context::Context::oxidegl_tex_buffer).
-/// > Must be [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER).
+/// >> Chooses the mipmap that most closely matches the size of the pixel being
+/// >> textured and uses the [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) criterion
+/// >> (a weighted average of the four texture elements that are closest to the
+/// >> specified texture coordinates) to produce a texture value.
 ///
-/// `texture`
+/// > [`GL_NEAREST_MIPMAP_LINEAR`](crate::gl_enums::GL_NEAREST_MIPMAP_LINEAR)
 ///
-/// > Specifies the texture object name for [**glTextureBuffer**](crate::context::Context::oxidegl_texture_buffer).
+/// >> Chooses the two mipmaps that most closely match the size of the pixel being
+/// >> textured and uses the [`GL_NEAREST`](crate::gl_enums::GL_NEAREST) criterion
+/// >> (the texture element closest to the specified texture coordinates) to produce
+/// >> a texture value from each mipmap. The final texture value is a weighted
+/// >> average of those two values.
 ///
-/// `internalformat`
+/// > [`GL_LINEAR_MIPMAP_LINEAR`](crate::gl_enums::GL_LINEAR_MIPMAP_LINEAR)
 ///
-/// > Specifies the internal format of the data in the store belonging to `buffer`.
+/// >> Chooses the two mipmaps that most closely match the size of the pixel being
+/// >> textured and uses the [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) criterion
+/// >> (a weighted average of the texture elements that are closest to the specified
+/// >> texture coordinates) to produce a texture value from each mipmap. The final
+/// >> texture value is a weighted average of those two values.
 ///
-/// `buffer`
 ///
-/// > Specifies the name of the buffer object whose storage to attach to the
-/// > active buffer texture.
+/// > As more texture elements are sampled in the minification process, fewer
+/// > aliasing artifacts will be apparent. While the [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
+/// > and [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) minification functions can
+/// > be faster than the other four, they sample only one or multiple texture
+/// > elements to determine the texture value of the pixel being rendered and
+/// > can produce moire patterns or ragged transitions. The initial value of
+/// > [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER) is [`GL_NEAREST_MIPMAP_LINEAR`](crate::gl_enums::GL_NEAREST_MIPMAP_LINEAR).
 ///
-/// ### Description
-/// [**glTexBuffer**](crate::context::Context::oxidegl_tex_buffer) and [**glTextureBuffer**](crate::context::Context::oxidegl_texture_buffer)
-/// attaches the data store of a specified buffer object to a specified texture
-/// object, and specify the storage format for the texture image found in the
-/// buffer object. The texture object must be a buffer texture.
 ///
-/// If `buffer` is zero, any buffer object attached to the buffer texture is
-/// detached and no new buffer object is attached. If `buffer` is non-zero,
-/// it must be the name of an existing buffer object.
+/// [`GL_TEXTURE_MAG_FILTER`](crate::gl_enums::GL_TEXTURE_MAG_FILTER)
 ///
-/// `internalformat` specifies the storage format, and must be one of the following
-/// sized internal formats:
+/// > The texture magnification function is used whenever the level-of-detail
+/// > function used when sampling from the texture determines that the texture
+/// > should be magified. It sets the texture magnification function to either
+/// > [`GL_NEAREST`](crate::gl_enums::GL_NEAREST) or [`GL_LINEAR`](crate::gl_enums::GL_LINEAR)
+/// > (see below). [`GL_NEAREST`](crate::gl_enums::GL_NEAREST) is generally faster
+/// > than [`GL_LINEAR`](crate::gl_enums::GL_LINEAR), but it can produce textured
+/// > images with sharper edges because the transition between texture elements
+/// > is not as smooth. The initial value of [`GL_TEXTURE_MAG_FILTER`](crate::gl_enums::GL_TEXTURE_MAG_FILTER)
+/// > is [`GL_LINEAR`](crate::gl_enums::GL_LINEAR). [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
 ///
-/// | *Sized Internal Format*                               | *Base Type*       | *Components*        | *Norm*     | 0  | 1  | 2  | 3  |
-/// |-------------------------------------------------------|-------------------|---------------------|------------|----|----|----|----|
-/// | [`GL_R8`](crate::gl_enums::GL_R8)                     | ubyte             | 1                   | YES        | R  | 0  | 0  | 1  |
-/// | [`GL_R16`](crate::gl_enums::GL_R16)                   | ushort            | 1                   | YES        | R  | 0  | 0  | 1  |
-/// | [`GL_R16F`](crate::gl_enums::GL_R16F)                 | half              | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R32F`](crate::gl_enums::GL_R32F)                 | float             | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R8I`](crate::gl_enums::GL_R8I)                   | byte              | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R16I`](crate::gl_enums::GL_R16I)                 | short             | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R32I`](crate::gl_enums::GL_R32I)                 | int               | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R8UI`](crate::gl_enums::GL_R8UI)                 | ubyte             | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R16UI`](crate::gl_enums::GL_R16UI)               | ushort            | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R32UI`](crate::gl_enums::GL_R32UI)               | uint              | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_RG8`](crate::gl_enums::GL_RG8)                   | ubyte             | 2                   | YES        | R  | G  | 0  | 1  |
-/// | [`GL_RG16`](crate::gl_enums::GL_RG16)                 | ushort            | 2                   | YES        | R  | G  | 0  | 1  |
-/// | [`GL_RG16F`](crate::gl_enums::GL_RG16F)               | half              | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG32F`](crate::gl_enums::GL_RG32F)               | float             | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG8I`](crate::gl_enums::GL_RG8I)                 | byte              | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG16I`](crate::gl_enums::GL_RG16I)               | short             | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG32I`](crate::gl_enums::GL_RG32I)               | int               | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG8UI`](crate::gl_enums::GL_RG8UI)               | ubyte             | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG16UI`](crate::gl_enums::GL_RG16UI)             | ushort            | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG32UI`](crate::gl_enums::GL_RG32UI)             | uint              | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RGB32F`](crate::gl_enums::GL_RGB32F)             | float             | 3                   | NO         | R  | G  | B  | 1  |
-/// | [`GL_RGB32I`](crate::gl_enums::GL_RGB32I)             | int               | 3                   | NO         | R  | G  | B  | 1  |
-/// | [`GL_RGB32UI`](crate::gl_enums::GL_RGB32UI)           | uint              | 3                   | NO         | R  | G  | B  | 1  |
-/// | [`GL_RGBA8`](crate::gl_enums::GL_RGBA8)               | uint              | 4                   | YES        | R  | G  | B  | A  |
-/// | [`GL_RGBA16`](crate::gl_enums::GL_RGBA16)             | short             | 4                   | YES        | R  | G  | B  | A  |
-/// | [`GL_RGBA16F`](crate::gl_enums::GL_RGBA16F)           | half              | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA32F`](crate::gl_enums::GL_RGBA32F)           | float             | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA8I`](crate::gl_enums::GL_RGBA8I)             | byte              | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA16I`](crate::gl_enums::GL_RGBA16I)           | short             | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA32I`](crate::gl_enums::GL_RGBA32I)           | int               | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA8UI`](crate::gl_enums::GL_RGBA8UI)           | ubyte             | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA16UI`](crate::gl_enums::GL_RGBA16UI)         | ushort            | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA32UI`](crate::gl_enums::GL_RGBA32UI)         | uint              | 4                   | NO         | R  | G  | B  | A  |
+/// >> Returns the value of the texture element that is nearest (in Manhattan
+/// >> distance) to the specified texture coordinates.
 ///
-/// When a buffer object is attached to a buffer texture, the buffer object's
-/// data store is taken as the texture's texel array. The number of texels
-/// in the buffer texture's texel array is given by $$ \left\lfloor{ size \over
-///{ components \times sizeof(base\_type)}} \right\rfloor $$ where $size$ is
-/// the size of the buffer object in basic machine units (the value of [`GL_BUFFER_SIZE`](crate::gl_enums::GL_BUFFER_SIZE)
-/// for `buffer` ), and $components$ and $base\_type$ are the element count
-/// and base data type for elements, as specified in the table above. The number
-/// of texels in the texel array is then clamped to the value of the implementation-dependent
-/// limit [`GL_MAX_TEXTURE_BUFFER_SIZE`](crate::gl_enums::GL_MAX_TEXTURE_BUFFER_SIZE).
-/// When a buffer texture is accessed in a shader, the results of a texel
-/// fetch are undefined if the specified texel coordinate is negative, or greater
-/// than or equal to the clamped number of texels in the texel array.
+/// > [`GL_LINEAR`](crate::gl_enums::GL_LINEAR)
 ///
-/// ### Associated Gets
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_TEXTURE_BUFFER_SIZE`](crate::gl_enums::GL_MAX_TEXTURE_BUFFER_SIZE)
+/// >> Returns the weighted average of the texture elements that are closest to
+/// >> the specified texture coordinates. These can include items wrapped or repeated
+/// >> from other parts of a texture, depending on the values of [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S)
+/// >> and [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T), and on the
+/// >> exact mapping.
 ///
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_BUFFER`](crate::gl_enums::GL_TEXTURE_BINDING_BUFFER)
 ///
-/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
-/// with argument [`GL_TEXTURE_BUFFER_DATA_STORE_BINDING`](crate::gl_enums::GL_TEXTURE_BUFFER_DATA_STORE_BINDING)
-impl Context {
-    pub fn oxidegl_tex_buffer(
-        &mut self,
-        target: TextureTarget,
-        internalformat: SizedInternalFormat,
-        buffer: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_tex_buffer not yet implemented");
-    }
-    pub fn oxidegl_texture_buffer(
-        &mut self,
-        texture: GLuint,
-        internalformat: SizedInternalFormat,
-        buffer: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_texture_buffer not yet implemented");
-    }
-}
-/// ### Parameters
-/// `target`
 ///
-/// > Specifies the target to which the texture object is bound for [**glTexBufferRange**](crate::context::Context::oxidegl_tex_buffer_range).
-/// > Must be [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER).
+/// [`GL_TEXTURE_MIN_LOD`](crate::gl_enums::GL_TEXTURE_MIN_LOD)
 ///
-/// `texture`
+/// > Sets the minimum level-of-detail parameter. This floating-point value limits
+/// > the selection of highest resolution mipmap (lowest mipmap level). The initial
+/// > value is -1000.
 ///
-/// > Specifies the texture object name for [**glTextureBufferRange**](crate::context::Context::oxidegl_texture_buffer_range).
 ///
-/// `internalformat`
+/// [`GL_TEXTURE_MAX_LOD`](crate::gl_enums::GL_TEXTURE_MAX_LOD)
 ///
-/// > Specifies the internal format of the data in the store belonging to `buffer`.
+/// > Sets the maximum level-of-detail parameter. This floating-point value limits
+/// > the selection of the lowest resolution mipmap (highest mipmap level). The
+/// > initial value is 1000.
 ///
-/// `buffer`
 ///
-/// > Specifies the name of the buffer object whose storage to attach to the
-/// > active buffer texture.
+/// [`GL_TEXTURE_MAX_LEVEL`](crate::gl_enums::GL_TEXTURE_MAX_LEVEL)
 ///
-/// `offset`
+/// > Sets the index of the highest defined mipmap level. This is an integer
+/// > value. The initial value is 1000.
 ///
-/// > Specifies the offset of the start of the range of the buffer's data store
-/// > to attach.
 ///
-/// `size`
+/// [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R)
 ///
-/// > Specifies the size of the range of the buffer's data store to attach.
+/// > Sets the swizzle that will be applied to the `[inlineq]` `param` are [`GL_RED`](crate::gl_enums::GL_RED),
+/// > [`GL_GREEN`](crate::gl_enums::GL_GREEN), [`GL_BLUE`](crate::gl_enums::GL_BLUE),
+/// > [`GL_ALPHA`](crate::gl_enums::GL_ALPHA), [`GL_ZERO`](crate::gl_enums::GL_ZERO)
+/// > and [`GL_ONE`](crate::gl_enums::GL_ONE). If [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R)
+/// > is [`GL_RED`](crate::gl_enums::GL_RED), the value for `[inlineq]` [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R)
+/// > is [`GL_GREEN`](crate::gl_enums::GL_GREEN), the value for `[inlineq]` [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R)
+/// > is [`GL_BLUE`](crate::gl_enums::GL_BLUE), the value for `[inlineq]` [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R)
+/// > is [`GL_ALPHA`](crate::gl_enums::GL_ALPHA), the value for `[inlineq]` [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R)
+/// > is [`GL_ZERO`](crate::gl_enums::GL_ZERO), the value for `[inlineq]` `[inlineq]`
+/// > [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R) is [`GL_ONE`](crate::gl_enums::GL_ONE),
+/// > the value for `[inlineq]` `[inlineq]` [`GL_RED`](crate::gl_enums::GL_RED).
 ///
-/// ### Description
-/// [**glTexBufferRange**](crate::context::Context::oxidegl_tex_buffer_range)
-/// and [**glTextureBufferRange**](crate::context::Context::oxidegl_texture_buffer_range)
-/// attach a range of the data store of a specified buffer object to a specified
-/// texture object, and specify the storage format for the texture image found
-/// in the buffer object. The texture object must be a buffer texture.
 ///
-/// If `buffer` is zero, any buffer object attached to the buffer texture is
-/// detached and no new buffer object is attached. If `buffer` is non-zero,
-/// it must be the name of an existing buffer object.
+/// [`GL_TEXTURE_SWIZZLE_G`](crate::gl_enums::GL_TEXTURE_SWIZZLE_G)
 ///
-/// The start and size of the range are specified by `offset` and `size` respectively,
-/// both measured in basic machine units. `offset` must be greater than or
-/// equal to zero, `size` must be greater than zero, and the sum of `offset`
-/// and `size` must not exceed the value of [`GL_BUFFER_SIZE`](crate::gl_enums::GL_BUFFER_SIZE)
-/// for `buffer`. Furthermore, `offset` must be an integer multiple of the
-/// value of [`GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT`](crate::gl_enums::GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT).
+/// > Sets the swizzle that will be applied to the `[inlineq]` `param` and their
+/// > effects are similar to those of [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R).
+/// > The initial value is [`GL_GREEN`](crate::gl_enums::GL_GREEN).
 ///
-/// `internalformat` specifies the storage format, and must be one of the following
-/// sized internal formats:
 ///
-/// | *Sized Internal Format*                               | *Base Type*       | *Components*        | *Norm*     | 0  | 1  | 2  | 3  |
-/// |-------------------------------------------------------|-------------------|---------------------|------------|----|----|----|----|
-/// | [`GL_R8`](crate::gl_enums::GL_R8)                     | ubyte             | 1                   | YES        | R  | 0  | 0  | 1  |
-/// | [`GL_R16`](crate::gl_enums::GL_R16)                   | ushort            | 1                   | YES        | R  | 0  | 0  | 1  |
-/// | [`GL_R16F`](crate::gl_enums::GL_R16F)                 | half              | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R32F`](crate::gl_enums::GL_R32F)                 | float             | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R8I`](crate::gl_enums::GL_R8I)                   | byte              | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R16I`](crate::gl_enums::GL_R16I)                 | short             | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R32I`](crate::gl_enums::GL_R32I)                 | int               | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R8UI`](crate::gl_enums::GL_R8UI)                 | ubyte             | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R16UI`](crate::gl_enums::GL_R16UI)               | ushort            | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_R32UI`](crate::gl_enums::GL_R32UI)               | uint              | 1                   | NO         | R  | 0  | 0  | 1  |
-/// | [`GL_RG8`](crate::gl_enums::GL_RG8)                   | ubyte             | 2                   | YES        | R  | G  | 0  | 1  |
-/// | [`GL_RG16`](crate::gl_enums::GL_RG16)                 | ushort            | 2                   | YES        | R  | G  | 0  | 1  |
-/// | [`GL_RG16F`](crate::gl_enums::GL_RG16F)               | half              | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG32F`](crate::gl_enums::GL_RG32F)               | float             | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG8I`](crate::gl_enums::GL_RG8I)                 | byte              | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG16I`](crate::gl_enums::GL_RG16I)               | short             | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG32I`](crate::gl_enums::GL_RG32I)               | int               | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG8UI`](crate::gl_enums::GL_RG8UI)               | ubyte             | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG16UI`](crate::gl_enums::GL_RG16UI)             | ushort            | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RG32UI`](crate::gl_enums::GL_RG32UI)             | uint              | 2                   | NO         | R  | G  | 0  | 1  |
-/// | [`GL_RGB32F`](crate::gl_enums::GL_RGB32F)             | float             | 3                   | NO         | R  | G  | B  | 1  |
-/// | [`GL_RGB32I`](crate::gl_enums::GL_RGB32I)             | int               | 3                   | NO         | R  | G  | B  | 1  |
-/// | [`GL_RGB32UI`](crate::gl_enums::GL_RGB32UI)           | uint              | 3                   | NO         | R  | G  | B  | 1  |
-/// | [`GL_RGBA8`](crate::gl_enums::GL_RGBA8)               | uint              | 4                   | YES        | R  | G  | B  | A  |
-/// | [`GL_RGBA16`](crate::gl_enums::GL_RGBA16)             | short             | 4                   | YES        | R  | G  | B  | A  |
-/// | [`GL_RGBA16F`](crate::gl_enums::GL_RGBA16F)           | half              | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA32F`](crate::gl_enums::GL_RGBA32F)           | float             | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA8I`](crate::gl_enums::GL_RGBA8I)             | byte              | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA16I`](crate::gl_enums::GL_RGBA16I)           | short             | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA32I`](crate::gl_enums::GL_RGBA32I)           | int               | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA8UI`](crate::gl_enums::GL_RGBA8UI)           | ubyte             | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA16UI`](crate::gl_enums::GL_RGBA16UI)         | ushort            | 4                   | NO         | R  | G  | B  | A  |
-/// | [`GL_RGBA32UI`](crate::gl_enums::GL_RGBA32UI)         | uint              | 4                   | NO         | R  | G  | B  | A  |
+/// [`GL_TEXTURE_SWIZZLE_B`](crate::gl_enums::GL_TEXTURE_SWIZZLE_B)
 ///
-/// When a range of a buffer object is attached to a buffer texture, the specified
-/// range of the buffer object's data store is taken as the texture's texel
-/// array. The number of texels in the buffer texture's texel array is given
-/// by $$ \left\lfloor{ size \over{ components \times sizeof(base\_type)}} \right\rfloor
-/// $$ where $components$ and $base\_type$ are the element count and base data
-/// type for elements, as specified in the table above. The number of texels
-/// in the texel array is then clamped to the value of the implementation-dependent
-/// limit [`GL_MAX_TEXTURE_BUFFER_SIZE`](crate::gl_enums::GL_MAX_TEXTURE_BUFFER_SIZE).
-/// When a buffer texture is accessed in a shader, the results of a texel
-/// fetch are undefined if the specified texel coordinate is negative, or greater
-/// than or equal to the clamped number of texels in the texel array.
+/// > Sets the swizzle that will be applied to the `[inlineq]` `param` and their
+/// > effects are similar to those of [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R).
+/// > The initial value is [`GL_BLUE`](crate::gl_enums::GL_BLUE).
 ///
-/// ### Associated Gets
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT`](crate::gl_enums::GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT)
 ///
-/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
-/// with arguments [`GL_TEXTURE_BUFFER_OFFSET`](crate::gl_enums::GL_TEXTURE_BUFFER_OFFSET)
-/// or [`GL_TEXTURE_BUFFER_SIZE`](crate::gl_enums::GL_TEXTURE_BUFFER_SIZE).
-impl Context {
-    pub fn oxidegl_tex_buffer_range(
-        &mut self,
-        target: TextureTarget,
-        internalformat: SizedInternalFormat,
-        buffer: GLuint,
-        offset: GLintptr,
-        size: GLsizeiptr,
-    ) -> GlFallible {
-        panic!("command oxidegl_tex_buffer_range not yet implemented");
-    }
-    pub fn oxidegl_texture_buffer_range(
-        &mut self,
-        texture: GLuint,
-        internalformat: SizedInternalFormat,
-        buffer: GLuint,
-        offset: GLintptr,
-        size: GLsizeiptr,
-    ) -> GlFallible {
-        panic!("command oxidegl_texture_buffer_range not yet implemented");
-    }
-}
-/// ### Parameters
-/// `target`
-///
-/// > Specifies the target to which the texture is bound for [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter)
-/// > functions. Must be one of [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D),
-/// > [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D),
-/// > [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE),
-/// > [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY),
-/// > [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D), [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP),
-/// > [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY),
-/// > or [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE).
+/// [`GL_TEXTURE_SWIZZLE_A`](crate::gl_enums::GL_TEXTURE_SWIZZLE_A)
 ///
-/// `texture`
+/// > Sets the swizzle that will be applied to the `[inlineq]` `param` and their
+/// > effects are similar to those of [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R).
+/// > The initial value is [`GL_ALPHA`](crate::gl_enums::GL_ALPHA).
 ///
-/// > Specifies the texture object name for [**glTextureParameter**](crate::context::Context::oxidegl_texture_parameter)
-/// > functions.
 ///
-/// `pname`
+/// [`GL_TEXTURE_SWIZZLE_RGBA`](crate::gl_enums::GL_TEXTURE_SWIZZLE_RGBA)
 ///
-/// > Specifies the symbolic name of a single-valued texture parameter. `pname`
-/// > can be one of the following: [`GL_DEPTH_STENCIL_TEXTURE_MODE`](crate::gl_enums::GL_DEPTH_STENCIL_TEXTURE_MODE),
-/// > [`GL_TEXTURE_BASE_LEVEL`](crate::gl_enums::GL_TEXTURE_BASE_LEVEL), [`GL_TEXTURE_COMPARE_FUNC`](crate::gl_enums::GL_TEXTURE_COMPARE_FUNC),
-/// > [`GL_TEXTURE_COMPARE_MODE`](crate::gl_enums::GL_TEXTURE_COMPARE_MODE), [`GL_TEXTURE_LOD_BIAS`](crate::gl_enums::GL_TEXTURE_LOD_BIAS),
-/// > [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER), [`GL_TEXTURE_MAG_FILTER`](crate::gl_enums::GL_TEXTURE_MAG_FILTER),
-/// > [`GL_TEXTURE_MIN_LOD`](crate::gl_enums::GL_TEXTURE_MIN_LOD), [`GL_TEXTURE_MAX_LOD`](crate::gl_enums::GL_TEXTURE_MAX_LOD),
-/// > [`GL_TEXTURE_MAX_LEVEL`](crate::gl_enums::GL_TEXTURE_MAX_LEVEL), [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R),
+/// > Sets the swizzles that will be applied to the `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `params` and their effects are similar to those of [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R),
+/// > except that all channels are specified simultaneously. Setting the value
+/// > of [`GL_TEXTURE_SWIZZLE_RGBA`](crate::gl_enums::GL_TEXTURE_SWIZZLE_RGBA)
+/// > is equivalent (assuming no errors are generated) to setting the parameters
+/// > of each of [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R),
 /// > [`GL_TEXTURE_SWIZZLE_G`](crate::gl_enums::GL_TEXTURE_SWIZZLE_G), [`GL_TEXTURE_SWIZZLE_B`](crate::gl_enums::GL_TEXTURE_SWIZZLE_B),
-/// > [`GL_TEXTURE_SWIZZLE_A`](crate::gl_enums::GL_TEXTURE_SWIZZLE_A), [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S),
-/// > [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T), or [`GL_TEXTURE_WRAP_R`](crate::gl_enums::GL_TEXTURE_WRAP_R).
-///
-/// > For the vector commands( [**glTexParameter*v**](crate::context::Context::oxidegl_tex_parameter*v)
-/// > ), `pname` can also be one of [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR)
-/// > or [`GL_TEXTURE_SWIZZLE_RGBA`](crate::gl_enums::GL_TEXTURE_SWIZZLE_RGBA).
-///
-/// `param`
-///
-/// > For the scalar commands, specifies the value of `pname`.
-///
-/// `params`
-///
-/// > For the vector commands, specifies a pointer to an array where the value
-/// > or values of `pname` are stored.
-///
-/// ### Description
-/// [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter) and
-/// [**glTextureParameter**](crate::context::Context::oxidegl_texture_parameter)
-/// assign the value or values in `params` to the texture parameter specified
-/// as `pname`. For [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter),
-/// `target` defines the target texture, either [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D),
-/// [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D),
-/// [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE),
-/// [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY),
-/// [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D), [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP),
-/// [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY),
-/// or [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE). The
-/// following symbols are accepted in `pname`:
-///
-/// [`GL_DEPTH_STENCIL_TEXTURE_MODE`](crate::gl_enums::GL_DEPTH_STENCIL_TEXTURE_MODE)
-///
-/// > Specifies the mode used to read from depth-stencil format textures. `params`
-/// > must be one of [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT)
-/// > or [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX). If the depth
-/// > stencil mode is [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT),
-/// > then reads from depth-stencil format textures will return the depth component
-/// > of the texel in `[inlineq]` [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX)
-/// > then the stencil component is returned in `[inlineq]` [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT).
-///
-/// [`GL_TEXTURE_BASE_LEVEL`](crate::gl_enums::GL_TEXTURE_BASE_LEVEL)
-///
-/// > Specifies the index of the lowest defined mipmap level. This is an integer
-/// > value. The initial value is 0.
-///
-///
-/// [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR)
-///
-/// > The data in `params` specifies four values that define the border values
-/// > that should be used for border texels. If a texel is sampled from the border
-/// > of the texture, the values of [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR)
-/// > are interpreted as an RGBA color to match the texture's internal format
-/// > and substituted for the non-existent texel data. If the texture contains
-/// > depth components, the first component of [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR)
-/// > is interpreted as a depth value. The initial value is `[inlineq]`
-///
-/// > If the values for [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR)
-/// > are specified with [**glTexParameterIiv**](crate::context::Context::oxidegl_tex_parameter_iiv)
-/// > or [**glTexParameterIuiv**](crate::context::Context::oxidegl_tex_parameter_iuiv),
-/// > the values are stored unmodified with an internal data type of integer.
-/// > If specified with [**glTexParameteriv**](crate::context::Context::oxidegl_tex_parameteriv),
-/// > they are converted to floating point with the following equation: `[inlineq]`
-/// > [**glTexParameterfv**](crate::context::Context::oxidegl_tex_parameterfv),
-/// > they are stored unmodified as floating-point values.
-///
-/// [`GL_TEXTURE_COMPARE_FUNC`](crate::gl_enums::GL_TEXTURE_COMPARE_FUNC)
-///
-/// > Specifies the comparison operator used when [`GL_TEXTURE_COMPARE_MODE`](crate::gl_enums::GL_TEXTURE_COMPARE_MODE)
-/// > is set to [`GL_COMPARE_REF_TO_TEXTURE`](crate::gl_enums::GL_COMPARE_REF_TO_TEXTURE).
-/// > Permissible values are:
-/// > |* Texture Comparison Function*                                  |* Computed result*                            |
-/// > |----------------------------------------------------------------|----------------------------------------------|
-/// > | [`GL_LEQUAL`](crate::gl_enums::GL_LEQUAL)                      | `[inlineq]`                                  |
-/// > | [`GL_GEQUAL`](crate::gl_enums::GL_GEQUAL)                      | `[inlineq]`                                  |
-/// > | [`GL_LESS`](crate::gl_enums::GL_LESS)                          | `[inlineq]`                                  |
-/// > | [`GL_GREATER`](crate::gl_enums::GL_GREATER)                    | `[inlineq]`                                  |
-/// > | [`GL_EQUAL`](crate::gl_enums::GL_EQUAL)                        | `[inlineq]`                                  |
-/// > | [`GL_NOTEQUAL`](crate::gl_enums::GL_NOTEQUAL)                  | `[inlineq]`                                  |
-/// > | [`GL_ALWAYS`](crate::gl_enums::GL_ALWAYS)                      | `[inlineq]`                                  |
-/// > | [`GL_NEVER`](crate::gl_enums::GL_NEVER)                        | `[inlineq]`                                  |
-///
-/// > `[inlineq]` `[inlineq]` `[inlineq]`
-///
-/// [`GL_TEXTURE_COMPARE_MODE`](crate::gl_enums::GL_TEXTURE_COMPARE_MODE)
-///
-/// > Specifies the texture comparison mode for currently bound depth textures.
-/// > That is, a texture whose internal format is [`GL_DEPTH_COMPONENT_*`](crate::gl_enums::GL_DEPTH_COMPONENT_*);
-/// > see [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d))
-/// > Permissible values are: [`GL_COMPARE_REF_TO_TEXTURE`](crate::gl_enums::GL_COMPARE_REF_TO_TEXTURE)
-///
-/// >> Specifies that the interpolated and clamped `[inlineq]` [`GL_TEXTURE_COMPARE_FUNC`](crate::gl_enums::GL_TEXTURE_COMPARE_FUNC)
-/// >> for details of how the comparison is evaluated. The result of the comparison
-/// >> is assigned to the red channel.
-///
-/// > [`GL_NONE`](crate::gl_enums::GL_NONE)
-///
-/// >> Specifies that the red channel should be assigned the appropriate value
-/// >> from the currently bound depth texture.
+/// > and [`GL_TEXTURE_SWIZZLE_A`](crate::gl_enums::GL_TEXTURE_SWIZZLE_A) successively.
 ///
 ///
-/// [`GL_TEXTURE_LOD_BIAS`](crate::gl_enums::GL_TEXTURE_LOD_BIAS)
+/// [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S)
 ///
-/// > `params` specifies a fixed bias value that is to be added to the level-of-detail
-/// > parameter for the texture before texture sampling. The specified value
-/// > is added to the shader-supplied bias value (if any) and subsequently clamped
-/// > into the implementation-defined range `[inlineq]` `[inlineq]` [`GL_MAX_TEXTURE_LOD_BIAS`](crate::gl_enums::GL_MAX_TEXTURE_LOD_BIAS).
-/// > The initial value is 0.0.
+/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE),
+/// > [`GL_CLAMP_TO_BORDER`](crate::gl_enums::GL_CLAMP_TO_BORDER), [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT),
+/// > [`GL_REPEAT`](crate::gl_enums::GL_REPEAT), or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE).
+/// > [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE) causes `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [`GL_CLAMP_TO_BORDER`](crate::gl_enums::GL_CLAMP_TO_BORDER)
+/// > evaluates `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE).
+/// > However, in cases where clamping would have occurred in [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE)
+/// > mode, the fetched texel data is substituted with the values specified by
+/// > [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR). [`GL_REPEAT`](crate::gl_enums::GL_REPEAT)
+/// > causes the integer part of the `[inlineq]` [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT)
+/// > causes the `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+/// > `[inlineq]` `[inlineq]` [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE)
+/// > causes the `[inlineq]` [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT)
+/// > for one repetition of the texture, at which point the coordinate to be
+/// > clamped as in [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE). Initially,
+/// > [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S) is set to [`GL_REPEAT`](crate::gl_enums::GL_REPEAT).
 ///
-/// [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER)
 ///
-/// > The texture minifying function is used whenever the level-of-detail function
-/// > used when sampling from the texture determines that the texture should
-/// > be minified. There are six defined minifying functions. Two of them use
-/// > either the nearest texture elements or a weighted average of multiple texture
-/// > elements to compute the texture value. The other four use mipmaps.
+/// [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T)
 ///
-/// > A mipmap is an ordered set of arrays representing the same image at progressively
-/// > lower resolutions. If the texture has dimensions `[inlineq]` `[inlineq]`
-/// > `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
-/// > `[inlineq]` `[inlineq]` [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
-/// > [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d),
-/// > [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d),
-/// > or [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d)
-/// > with the *level* argument indicating the order of the mipmaps. Level 0
-/// > is the original texture; level `[inlineq]` `[inlineq]`
+/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE),
+/// > [`GL_CLAMP_TO_BORDER`](crate::gl_enums::GL_CLAMP_TO_BORDER), [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT),
+/// > [`GL_REPEAT`](crate::gl_enums::GL_REPEAT), or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE).
+/// > See the discussion under [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S).
+/// > Initially, [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T) is
+/// > set to [`GL_REPEAT`](crate::gl_enums::GL_REPEAT).
 ///
-/// > `params` supplies a function for minifying the texture as one of the following:
-/// > [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
 ///
-/// >> Returns the value of the texture element that is nearest (in Manhattan
-/// >> distance) to the specified texture coordinates.
+/// [`GL_TEXTURE_WRAP_R`](crate::gl_enums::GL_TEXTURE_WRAP_R)
 ///
-/// > [`GL_LINEAR`](crate::gl_enums::GL_LINEAR)
+/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE),
+/// > [`GL_CLAMP_TO_BORDER`](crate::gl_enums::GL_CLAMP_TO_BORDER), [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT),
+/// > [`GL_REPEAT`](crate::gl_enums::GL_REPEAT), or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE).
+/// > See the discussion under [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S).
+/// > Initially, [`GL_TEXTURE_WRAP_R`](crate::gl_enums::GL_TEXTURE_WRAP_R) is
+/// > set to [`GL_REPEAT`](crate::gl_enums::GL_REPEAT).
 ///
-/// >> Returns the weighted average of the four texture elements that are closest
-/// >> to the specified texture coordinates. These can include items wrapped or
-/// >> repeated from other parts of a texture, depending on the values of [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S)
-/// >> and [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T), and on the
-/// >> exact mapping.
+/// ### Notes
+/// Suppose that a program attempts to sample from a texture and has set [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER)
+/// to one of the functions that requires a mipmap. If either the dimensions
+/// of the texture images currently defined (with previous calls to [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
+/// [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d),
+/// [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d),
+/// or [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d))
+/// do not follow the proper sequence for mipmaps (described above), or there
+/// are fewer texture images defined than are needed, or the set of texture
+/// images have differing numbers of texture components, then the texture is
+/// considered *incomplete*.
 ///
-/// > [`GL_NEAREST_MIPMAP_NEAREST`](crate::gl_enums::GL_NEAREST_MIPMAP_NEAREST)
+/// Linear filtering accesses the four nearest texture elements only in 2D
+/// textures. In 1D textures, linear filtering accesses the two nearest texture
+/// elements. In 3D textures, linear filtering accesses the eight nearest texture
+/// elements.
 ///
-/// >> Chooses the mipmap that most closely matches the size of the pixel being
-/// >> textured and uses the [`GL_NEAREST`](crate::gl_enums::GL_NEAREST) criterion
-/// >> (the texture element closest to the specified texture coordinates) to produce
-/// >> a texture value.
+/// [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter) specifies
+/// the texture parameters for the active texture unit, specified by calling
+/// [**glActiveTexture**](crate::context::Context::oxidegl_active_texture).
+/// [**glTextureParameter**](crate::context::Context::oxidegl_texture_parameter)
+/// specifies the texture parameters for the texture object with ID `texture`.
 ///
-/// > [`GL_LINEAR_MIPMAP_NEAREST`](crate::gl_enums::GL_LINEAR_MIPMAP_NEAREST)
+/// [`GL_DEPTH_STENCIL_TEXTURE_MODE`](crate::gl_enums::GL_DEPTH_STENCIL_TEXTURE_MODE)
+/// is available only if the GL version is 4.3 or greater.
 ///
-/// >> Chooses the mipmap that most closely matches the size of the pixel being
-/// >> textured and uses the [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) criterion
-/// >> (a weighted average of the four texture elements that are closest to the
-/// >> specified texture coordinates) to produce a texture value.
+/// [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE) is
+/// available only if the GL version is 4.4 or greater.
 ///
-/// > [`GL_NEAREST_MIPMAP_LINEAR`](crate::gl_enums::GL_NEAREST_MIPMAP_LINEAR)
+/// ### Associated Gets
+/// [**glGetTexParameter**](crate::context::Context::oxidegl_get_tex_parameter)
 ///
-/// >> Chooses the two mipmaps that most closely match the size of the pixel being
-/// >> textured and uses the [`GL_NEAREST`](crate::gl_enums::GL_NEAREST) criterion
-/// >> (the texture element closest to the specified texture coordinates) to produce
-/// >> a texture value from each mipmap. The final texture value is a weighted
-/// >> average of those two values.
+/// [**glGetTextureParameter**](crate::context::Context::oxidegl_get_texture_parameter)
 ///
-/// > [`GL_LINEAR_MIPMAP_LINEAR`](crate::gl_enums::GL_LINEAR_MIPMAP_LINEAR)
-///
-/// >> Chooses the two mipmaps that most closely match the size of the pixel being
-/// >> textured and uses the [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) criterion
-/// >> (a weighted average of the texture elements that are closest to the specified
-/// >> texture coordinates) to produce a texture value from each mipmap. The final
-/// >> texture value is a weighted average of those two values.
-///
-///
-/// > As more texture elements are sampled in the minification process, fewer
-/// > aliasing artifacts will be apparent. While the [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
-/// > and [`GL_LINEAR`](crate::gl_enums::GL_LINEAR) minification functions can
-/// > be faster than the other four, they sample only one or multiple texture
-/// > elements to determine the texture value of the pixel being rendered and
-/// > can produce moire patterns or ragged transitions. The initial value of
-/// > [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER) is [`GL_NEAREST_MIPMAP_LINEAR`](crate::gl_enums::GL_NEAREST_MIPMAP_LINEAR).
-///
-///
-/// [`GL_TEXTURE_MAG_FILTER`](crate::gl_enums::GL_TEXTURE_MAG_FILTER)
-///
-/// > The texture magnification function is used whenever the level-of-detail
-/// > function used when sampling from the texture determines that the texture
-/// > should be magified. It sets the texture magnification function to either
-/// > [`GL_NEAREST`](crate::gl_enums::GL_NEAREST) or [`GL_LINEAR`](crate::gl_enums::GL_LINEAR)
-/// > (see below). [`GL_NEAREST`](crate::gl_enums::GL_NEAREST) is generally faster
-/// > than [`GL_LINEAR`](crate::gl_enums::GL_LINEAR), but it can produce textured
-/// > images with sharper edges because the transition between texture elements
-/// > is not as smooth. The initial value of [`GL_TEXTURE_MAG_FILTER`](crate::gl_enums::GL_TEXTURE_MAG_FILTER)
-/// > is [`GL_LINEAR`](crate::gl_enums::GL_LINEAR). [`GL_NEAREST`](crate::gl_enums::GL_NEAREST)
-///
-/// >> Returns the value of the texture element that is nearest (in Manhattan
-/// >> distance) to the specified texture coordinates.
-///
-/// > [`GL_LINEAR`](crate::gl_enums::GL_LINEAR)
-///
-/// >> Returns the weighted average of the texture elements that are closest to
-/// >> the specified texture coordinates. These can include items wrapped or repeated
-/// >> from other parts of a texture, depending on the values of [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S)
-/// >> and [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T), and on the
-/// >> exact mapping.
-///
-///
-///
-/// [`GL_TEXTURE_MIN_LOD`](crate::gl_enums::GL_TEXTURE_MIN_LOD)
-///
-/// > Sets the minimum level-of-detail parameter. This floating-point value limits
-/// > the selection of highest resolution mipmap (lowest mipmap level). The initial
-/// > value is -1000.
-///
-///
-/// [`GL_TEXTURE_MAX_LOD`](crate::gl_enums::GL_TEXTURE_MAX_LOD)
-///
-/// > Sets the maximum level-of-detail parameter. This floating-point value limits
-/// > the selection of the lowest resolution mipmap (highest mipmap level). The
-/// > initial value is 1000.
-///
-///
-/// [`GL_TEXTURE_MAX_LEVEL`](crate::gl_enums::GL_TEXTURE_MAX_LEVEL)
-///
-/// > Sets the index of the highest defined mipmap level. This is an integer
-/// > value. The initial value is 1000.
-///
-///
-/// [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R)
-///
-/// > Sets the swizzle that will be applied to the `[inlineq]` `param` are [`GL_RED`](crate::gl_enums::GL_RED),
-/// > [`GL_GREEN`](crate::gl_enums::GL_GREEN), [`GL_BLUE`](crate::gl_enums::GL_BLUE),
-/// > [`GL_ALPHA`](crate::gl_enums::GL_ALPHA), [`GL_ZERO`](crate::gl_enums::GL_ZERO)
-/// > and [`GL_ONE`](crate::gl_enums::GL_ONE). If [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R)
-/// > is [`GL_RED`](crate::gl_enums::GL_RED), the value for `[inlineq]` [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R)
-/// > is [`GL_GREEN`](crate::gl_enums::GL_GREEN), the value for `[inlineq]` [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R)
-/// > is [`GL_BLUE`](crate::gl_enums::GL_BLUE), the value for `[inlineq]` [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R)
-/// > is [`GL_ALPHA`](crate::gl_enums::GL_ALPHA), the value for `[inlineq]` [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R)
-/// > is [`GL_ZERO`](crate::gl_enums::GL_ZERO), the value for `[inlineq]` `[inlineq]`
-/// > [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R) is [`GL_ONE`](crate::gl_enums::GL_ONE),
-/// > the value for `[inlineq]` `[inlineq]` [`GL_RED`](crate::gl_enums::GL_RED).
-///
-///
-/// [`GL_TEXTURE_SWIZZLE_G`](crate::gl_enums::GL_TEXTURE_SWIZZLE_G)
-///
-/// > Sets the swizzle that will be applied to the `[inlineq]` `param` and their
-/// > effects are similar to those of [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R).
-/// > The initial value is [`GL_GREEN`](crate::gl_enums::GL_GREEN).
-///
-///
-/// [`GL_TEXTURE_SWIZZLE_B`](crate::gl_enums::GL_TEXTURE_SWIZZLE_B)
-///
-/// > Sets the swizzle that will be applied to the `[inlineq]` `param` and their
-/// > effects are similar to those of [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R).
-/// > The initial value is [`GL_BLUE`](crate::gl_enums::GL_BLUE).
-///
-///
-/// [`GL_TEXTURE_SWIZZLE_A`](crate::gl_enums::GL_TEXTURE_SWIZZLE_A)
-///
-/// > Sets the swizzle that will be applied to the `[inlineq]` `param` and their
-/// > effects are similar to those of [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R).
-/// > The initial value is [`GL_ALPHA`](crate::gl_enums::GL_ALPHA).
-///
-///
-/// [`GL_TEXTURE_SWIZZLE_RGBA`](crate::gl_enums::GL_TEXTURE_SWIZZLE_RGBA)
-///
-/// > Sets the swizzles that will be applied to the `[inlineq]` `[inlineq]` `[inlineq]`
-/// > `[inlineq]` `params` and their effects are similar to those of [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R),
-/// > except that all channels are specified simultaneously. Setting the value
-/// > of [`GL_TEXTURE_SWIZZLE_RGBA`](crate::gl_enums::GL_TEXTURE_SWIZZLE_RGBA)
-/// > is equivalent (assuming no errors are generated) to setting the parameters
-/// > of each of [`GL_TEXTURE_SWIZZLE_R`](crate::gl_enums::GL_TEXTURE_SWIZZLE_R),
-/// > [`GL_TEXTURE_SWIZZLE_G`](crate::gl_enums::GL_TEXTURE_SWIZZLE_G), [`GL_TEXTURE_SWIZZLE_B`](crate::gl_enums::GL_TEXTURE_SWIZZLE_B),
-/// > and [`GL_TEXTURE_SWIZZLE_A`](crate::gl_enums::GL_TEXTURE_SWIZZLE_A) successively.
-///
-///
-/// [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S)
-///
-/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE),
-/// > [`GL_CLAMP_TO_BORDER`](crate::gl_enums::GL_CLAMP_TO_BORDER), [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT),
-/// > [`GL_REPEAT`](crate::gl_enums::GL_REPEAT), or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE).
-/// > [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE) causes `[inlineq]`
-/// > `[inlineq]` `[inlineq]` [`GL_CLAMP_TO_BORDER`](crate::gl_enums::GL_CLAMP_TO_BORDER)
-/// > evaluates `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE).
-/// > However, in cases where clamping would have occurred in [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE)
-/// > mode, the fetched texel data is substituted with the values specified by
-/// > [`GL_TEXTURE_BORDER_COLOR`](crate::gl_enums::GL_TEXTURE_BORDER_COLOR). [`GL_REPEAT`](crate::gl_enums::GL_REPEAT)
-/// > causes the integer part of the `[inlineq]` [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT)
-/// > causes the `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
-/// > `[inlineq]` `[inlineq]` [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE)
-/// > causes the `[inlineq]` [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT)
-/// > for one repetition of the texture, at which point the coordinate to be
-/// > clamped as in [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE). Initially,
-/// > [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S) is set to [`GL_REPEAT`](crate::gl_enums::GL_REPEAT).
-///
-///
-/// [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T)
-///
-/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE),
-/// > [`GL_CLAMP_TO_BORDER`](crate::gl_enums::GL_CLAMP_TO_BORDER), [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT),
-/// > [`GL_REPEAT`](crate::gl_enums::GL_REPEAT), or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE).
-/// > See the discussion under [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S).
-/// > Initially, [`GL_TEXTURE_WRAP_T`](crate::gl_enums::GL_TEXTURE_WRAP_T) is
-/// > set to [`GL_REPEAT`](crate::gl_enums::GL_REPEAT).
-///
-///
-/// [`GL_TEXTURE_WRAP_R`](crate::gl_enums::GL_TEXTURE_WRAP_R)
-///
-/// > Sets the wrap parameter for texture coordinate `[inlineq]` [`GL_CLAMP_TO_EDGE`](crate::gl_enums::GL_CLAMP_TO_EDGE),
-/// > [`GL_CLAMP_TO_BORDER`](crate::gl_enums::GL_CLAMP_TO_BORDER), [`GL_MIRRORED_REPEAT`](crate::gl_enums::GL_MIRRORED_REPEAT),
-/// > [`GL_REPEAT`](crate::gl_enums::GL_REPEAT), or [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE).
-/// > See the discussion under [`GL_TEXTURE_WRAP_S`](crate::gl_enums::GL_TEXTURE_WRAP_S).
-/// > Initially, [`GL_TEXTURE_WRAP_R`](crate::gl_enums::GL_TEXTURE_WRAP_R) is
-/// > set to [`GL_REPEAT`](crate::gl_enums::GL_REPEAT).
-///
-/// ### Notes
-/// Suppose that a program attempts to sample from a texture and has set [`GL_TEXTURE_MIN_FILTER`](crate::gl_enums::GL_TEXTURE_MIN_FILTER)
-/// to one of the functions that requires a mipmap. If either the dimensions
-/// of the texture images currently defined (with previous calls to [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
-/// [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d),
-/// [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d),
-/// or [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d))
-/// do not follow the proper sequence for mipmaps (described above), or there
-/// are fewer texture images defined than are needed, or the set of texture
-/// images have differing numbers of texture components, then the texture is
-/// considered *incomplete*.
-///
-/// Linear filtering accesses the four nearest texture elements only in 2D
-/// textures. In 1D textures, linear filtering accesses the two nearest texture
-/// elements. In 3D textures, linear filtering accesses the eight nearest texture
-/// elements.
-///
-/// [**glTexParameter**](crate::context::Context::oxidegl_tex_parameter) specifies
-/// the texture parameters for the active texture unit, specified by calling
-/// [**glActiveTexture**](crate::context::Context::oxidegl_active_texture).
-/// [**glTextureParameter**](crate::context::Context::oxidegl_texture_parameter)
-/// specifies the texture parameters for the texture object with ID `texture`.
-///
-/// [`GL_DEPTH_STENCIL_TEXTURE_MODE`](crate::gl_enums::GL_DEPTH_STENCIL_TEXTURE_MODE)
-/// is available only if the GL version is 4.3 or greater.
-///
-/// [`GL_MIRROR_CLAMP_TO_EDGE`](crate::gl_enums::GL_MIRROR_CLAMP_TO_EDGE) is
-/// available only if the GL version is 4.4 or greater.
-///
-/// ### Associated Gets
-/// [**glGetTexParameter**](crate::context::Context::oxidegl_get_tex_parameter)
-///
-/// [**glGetTextureParameter**](crate::context::Context::oxidegl_get_texture_parameter)
-///
-/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+/// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
 ///
 /// [**glGetTextureLevelParameter**](crate::context::Context::oxidegl_get_texture_level_parameter)
 impl Context {
@@ -10394,475 +9309,81 @@ impl Context {
     }
 }
 /// ### Parameters
-/// `location`
-///
-/// > Specifies the location of the uniform variable to be modified.
-///
-/// `count`
-///
-/// > For the vector( [**glUniform*v**](crate::context::Context::oxidegl_uniform*v))
-/// > commands, specifies the number of elements that are to be modified. This
-/// > should be 1 if the targeted uniform variable is not an array, and 1 or
-/// > more if it is an array.
+/// `target`
 ///
-/// > For the matrix( [**glUniformMatrix***](crate::context::Context::oxidegl_uniform_matrix*))
-/// > commands, specifies the number of matrices that are to be modified. This
-/// > should be 1 if the targeted uniform variable is not an array of matrices,
-/// > and 1 or more if it is an array of matrices.
+/// > Specifies the target to which the buffer object is bound for [**glUnmapBuffer**](crate::context::Context::oxidegl_unmap_buffer),
+/// > which must be one of the buffer binding targets in the following table:
 ///
-/// `transpose`
+/// > | *Buffer Binding Target*                               | *Purpose*      |
+/// > |-------------------------------------------------------|----------------|
+/// > | [`GL_ARRAY_BUFFER`](crate::gl_enums::GL_ARRAY_BUFFER) | Vertex attributes |
+/// > | [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER) | Atomic counter storage |
+/// > | [`GL_COPY_READ_BUFFER`](crate::gl_enums::GL_COPY_READ_BUFFER) | Buffer copy source |
+/// > | [`GL_COPY_WRITE_BUFFER`](crate::gl_enums::GL_COPY_WRITE_BUFFER) | Buffer copy destination |
+/// > | [`GL_DISPATCH_INDIRECT_BUFFER`](crate::gl_enums::GL_DISPATCH_INDIRECT_BUFFER) | Indirect compute dispatch commands |
+/// > | [`GL_DRAW_INDIRECT_BUFFER`](crate::gl_enums::GL_DRAW_INDIRECT_BUFFER) | Indirect command arguments |
+/// > | [`GL_ELEMENT_ARRAY_BUFFER`](crate::gl_enums::GL_ELEMENT_ARRAY_BUFFER) | Vertex array indices |
+/// > | [`GL_PIXEL_PACK_BUFFER`](crate::gl_enums::GL_PIXEL_PACK_BUFFER) | Pixel read target |
+/// > | [`GL_PIXEL_UNPACK_BUFFER`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER) | Texture data source |
+/// > | [`GL_QUERY_BUFFER`](crate::gl_enums::GL_QUERY_BUFFER) | Query result buffer |
+/// > | [`GL_SHADER_STORAGE_BUFFER`](crate::gl_enums::GL_SHADER_STORAGE_BUFFER) | Read-write storage for shaders |
+/// > | [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER) | Texture data buffer |
+/// > | [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER) | Transform feedback buffer |
+/// > | [`GL_UNIFORM_BUFFER`](crate::gl_enums::GL_UNIFORM_BUFFER) | Uniform block storage |
 ///
-/// > For the matrix commands, specifies whether to transpose the matrix as the
-/// > values are loaded into the uniform variable.
+/// `buffer`
 ///
-/// `v0`, `v1`, `v2`, `v3`
+/// > Specifies the name of the buffer object for [**glUnmapNamedBuffer**](crate::context::Context::oxidegl_unmap_named_buffer).
 ///
-/// > For the scalar commands, specifies the new values to be used for the specified
-/// > uniform variable.
+/// ### Description
+/// [**glUnmapBuffer**](crate::context::Context::oxidegl_unmap_buffer) and
+/// [**glUnmapNamedBuffer**](crate::context::Context::oxidegl_unmap_named_buffer)
+/// unmap (release) any mapping of a specified buffer object into the client's
+/// address space (see [**glMapBufferRange**](crate::context::Context::oxidegl_map_buffer_range)
+/// and [**glMapBuffer**](crate::context::Context::oxidegl_map_buffer) ).
 ///
-/// `value`
+/// If a mapping is not unmapped before the corresponding buffer object's data
+/// store is used by the GL, an error will be generated by any GL command that
+/// attempts to dereference the buffer object's data store, unless the buffer
+/// was successfully mapped with [`GL_MAP_PERSISTENT_BIT`](crate::gl_enums::GL_MAP_PERSISTENT_BIT)
+/// (see [**glMapBufferRange**](crate::context::Context::oxidegl_map_buffer_range)
+/// ). When a data store is unmapped, the mapped pointer becomes invalid.
 ///
-/// > For the vector and matrix commands, specifies a pointer to an array of
-/// > `count` values that will be used to update the specified uniform variable.
+/// [**glUnmapBuffer**](crate::context::Context::oxidegl_unmap_buffer) returns
+/// [`GL_TRUE`](crate::gl_enums::GL_TRUE) unless the data store contents have
+/// become corrupt during the time the data store was mapped. This can occur
+/// for system-specific reasons that affect the availability of graphics memory,
+/// such as screen mode changes. In such situations, [`GL_FALSE`](crate::gl_enums::GL_FALSE)
+/// is returned and the data store contents are undefined. An application must
+/// detect this rare condition and reinitialize the data store.
 ///
-/// ### Description
-/// [**glUniform**](crate::context::Context::oxidegl_uniform) modifies the
-/// value of a uniform variable or a uniform variable array. The location of
-/// the uniform variable to be modified is specified by `location`, which should
-/// be a value returned by [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location).
-/// [**glUniform**](crate::context::Context::oxidegl_uniform) operates on the
-/// program object that was made part of current state by calling [**glUseProgram**](crate::context::Context::oxidegl_use_program).
-///
-/// The commands [**glUniform{1|2|3|4}{f|i|ui}**](crate::context::Context::oxidegl_uniform{1|2|3|4}{f|i|ui})
-/// are used to change the value of the uniform variable specified by `location`
-/// using the values passed as arguments. The number specified in the command
-/// should match the number of components in the data type of the specified
-/// uniform variable (e.g., [**1**](crate::context::Context::oxide1) for [**2**](crate::context::Context::oxide2)
-/// for [**f**](crate::context::Context::oxidef) indicates that floating-point
-/// values are being passed; the suffix [**i**](crate::context::Context::oxidei)
-/// indicates that integer values are being passed; the suffix [**ui**](crate::context::Context::oxideui)
-/// indicates that unsigned integer values are being passed, and this type
-/// should also match the data type of the specified uniform variable. The
-/// [**i**](crate::context::Context::oxidei) variants of this function should
-/// be used to provide values for uniform variables defined as [**ui**](crate::context::Context::oxideui)
-/// variants of this function should be used to provide values for uniform
-/// variables defined as [**f**](crate::context::Context::oxidef) variants
-/// should be used to provide values for uniform variables of type [**i**](crate::context::Context::oxidei),
-/// [**ui**](crate::context::Context::oxideui) or [**f**](crate::context::Context::oxidef)
-/// variants may be used to provide values for uniform variables of type
-///
-/// All active uniform variables defined in a program object are initialized
-/// to 0 when the program object is linked successfully. They retain the values
-/// assigned to them by a call to [**glUniform **](crate::context::Context::oxidegl_uniform
-///) until the next successful link operation occurs on the program object,
-/// when they are once again initialized to 0.
-///
-/// The commands [**glUniform{1|2|3|4}{f|i|ui}v**](crate::context::Context::oxidegl_uniform{1|2|3|4}{f|i|ui}v)
-/// can be used to modify a single uniform variable or a uniform variable array.
-/// These commands pass a count and a pointer to the values to be loaded into
-/// a uniform variable or a uniform variable array. A count of 1 should be
-/// used if modifying the value of a single uniform variable, and a count of
-/// 1 or greater can be used to modify an entire array or part of an array.
-/// When loading *n* elements starting at an arbitrary position *m* in a uniform
-/// variable array, elements *m*+ *n*- 1 in the array will be replaced with
-/// the new values. If `m`+ `n`- 1 is larger than the size of the uniform variable
-/// array, values for all array elements beyond the end of the array will be
-/// ignored. The number specified in the name of the command indicates the
-/// number of components for each element in `value`, and it should match the
-/// number of components in the data type of the specified uniform variable
-/// (e.g., [**1**](crate::context::Context::oxide1) for float, int, bool; [**2**](crate::context::Context::oxide2)
-/// for vec2, ivec2, bvec2, etc.). The data type specified in the name of the
-/// command must match the data type for the specified uniform variable as
-/// described previously for [**glUniform{1|2|3|4}{f|i|ui}**](crate::context::Context::oxidegl_uniform{1|2|3|4}{f|i|ui}).
-///
-/// For uniform variable arrays, each element of the array is considered to
-/// be of the type indicated in the name of the command (e.g., [**glUniform3f**](crate::context::Context::oxidegl_uniform3f)
-/// or [**glUniform3fv**](crate::context::Context::oxidegl_uniform3fv) can
-/// be used to load a uniform variable array of type vec3). The number of elements
-/// of the uniform variable array to be modified is specified by `count`
-///
-/// The commands [**glUniformMatrix{2|3|4|2x3|3x2|2x4|4x2|3x4|4x3}fv**](crate::context::Context::oxidegl_uniform_matrix{2|3|4|2x3|3x2|2x4|4x2|3x4|4x3}fv)
-/// are used to modify a matrix or an array of matrices. The numbers in the
-/// command name are interpreted as the dimensionality of the matrix. The number
-/// [**2**](crate::context::Context::oxide2) indicates a 2 × 2 matrix (i.e.,
-/// 4 values), the number [**3**](crate::context::Context::oxide3) indicates
-/// a 3 × 3 matrix (i.e., 9 values), and the number [**4**](crate::context::Context::oxide4)
-/// indicates a 4 × 4 matrix (i.e., 16 values). Non-square matrix dimensionality
-/// is explicit, with the first number representing the number of columns and
-/// the second number representing the number of rows. For example, [**2x4**](crate::context::Context::oxide2x4)
-/// indicates a 2 × 4 matrix with 2 columns and 4 rows (i.e., 8 values). If
-/// `transpose` is [`GL_FALSE`](crate::gl_enums::GL_FALSE), each matrix is
-/// assumed to be supplied in column major order. If `transpose` is [`GL_TRUE`](crate::gl_enums::GL_TRUE),
-/// each matrix is assumed to be supplied in row major order. The `count`
-/// argument indicates the number of matrices to be passed. A count of 1 should
-/// be used if modifying the value of a single matrix, and a count greater
-/// than 1 can be used to modify an array of matrices.
+/// A buffer object's mapped data store is automatically unmapped when the
+/// buffer object is deleted or its data store is recreated with [**glBufferData**](crate::context::Context::oxidegl_buffer_data)
+/// ).
 ///
 /// ### Notes
-/// [**glUniform1i**](crate::context::Context::oxidegl_uniform1i) and [**glUniform1iv**](crate::context::Context::oxidegl_uniform1iv)
-/// are the only two functions that may be used to load uniform variables defined
-/// as sampler types. Loading samplers with any other function will result
-/// in a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION) error.
-///
-/// If `count` is greater than 1 and the indicated uniform variable is not
-/// an array, a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION)
-/// error is generated and the specified uniform variable will remain unchanged.
-///
-/// Other than the preceding exceptions, if the type and size of the uniform
-/// variable as defined in the shader do not match the type and size specified
-/// in the name of the command used to load its value, a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION)
-/// error will be generated and the specified uniform variable will remain
-/// unchanged.
-///
-/// If `location` is a value other than -1 and it does not represent a valid
-/// uniform variable location in the current program object, an error will
-/// be generated, and no changes will be made to the uniform variable storage
-/// of the current program object. If `location` is equal to -1, the data passed
-/// in will be silently ignored and the specified uniform variable will not
-/// be changed.
+/// If an error is generated, [**glUnmapBuffer**](crate::context::Context::oxidegl_unmap_buffer)
+/// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE).
 ///
-/// ### Associated Gets
-/// [**glGet**](crate::context::Context::oxidegl_get) with the argument [`GL_CURRENT_PROGRAM`](crate::gl_enums::GL_CURRENT_PROGRAM)
+/// The [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER)
+/// target is accepted only if the GL version is 4.2 or greater.
 ///
-/// [**glGetActiveUniform**](crate::context::Context::oxidegl_get_active_uniform)
-/// with the handle of a program object and the index of an active uniform
-/// variable
+/// The [`GL_DISPATCH_INDIRECT_BUFFER`](crate::gl_enums::GL_DISPATCH_INDIRECT_BUFFER)
+/// and [`GL_SHADER_STORAGE_BUFFER`](crate::gl_enums::GL_SHADER_STORAGE_BUFFER)
+/// targets are available only if the GL version is 4.3 or greater.
 ///
-/// [**glGetUniform**](crate::context::Context::oxidegl_get_uniform) with the
-/// handle of a program object and the location of a uniform variable
+/// The [`GL_QUERY_BUFFER`](crate::gl_enums::GL_QUERY_BUFFER) target is available
+/// only if the GL version is 4.4 or greater.
 ///
-/// [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location)
-/// with the handle of a program object and the name of a uniform variable
+/// ### Associated Gets
+/// [**glGetBufferParameter**](crate::context::Context::oxidegl_get_buffer_parameter)
+/// with argument [`GL_BUFFER_MAPPED`](crate::gl_enums::GL_BUFFER_MAPPED).
 impl Context {
-    pub fn oxidegl_uniform1f(&mut self, location: GLint, v0: GLfloat) -> GlFallible {
-        panic!("command oxidegl_uniform1f not yet implemented");
+    pub fn oxidegl_unmap_buffer(&mut self, target: BufferTarget) -> GlFallible<GLboolean> {
+        panic!("command oxidegl_unmap_buffer not yet implemented");
     }
-    pub fn oxidegl_uniform2f(&mut self, location: GLint, v0: GLfloat, v1: GLfloat) -> GlFallible {
-        panic!("command oxidegl_uniform2f not yet implemented");
-    }
-    pub fn oxidegl_uniform3f(
-        &mut self,
-        location: GLint,
-        v0: GLfloat,
-        v1: GLfloat,
-        v2: GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform3f not yet implemented");
-    }
-    pub fn oxidegl_uniform4f(
-        &mut self,
-        location: GLint,
-        v0: GLfloat,
-        v1: GLfloat,
-        v2: GLfloat,
-        v3: GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform4f not yet implemented");
-    }
-    pub fn oxidegl_uniform1i(&mut self, location: GLint, v0: GLint) -> GlFallible {
-        panic!("command oxidegl_uniform1i not yet implemented");
-    }
-    pub fn oxidegl_uniform2i(&mut self, location: GLint, v0: GLint, v1: GLint) -> GlFallible {
-        panic!("command oxidegl_uniform2i not yet implemented");
-    }
-    pub fn oxidegl_uniform3i(
-        &mut self,
-        location: GLint,
-        v0: GLint,
-        v1: GLint,
-        v2: GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform3i not yet implemented");
-    }
-    pub fn oxidegl_uniform4i(
-        &mut self,
-        location: GLint,
-        v0: GLint,
-        v1: GLint,
-        v2: GLint,
-        v3: GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform4i not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform1fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform1fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform2fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform2fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform3fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform3fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform4fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform4fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform1iv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform1iv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform2iv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform2iv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform3iv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform3iv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform4iv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform4iv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix2fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix2fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix3fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix3fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix4fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix4fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix2x3fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix2x3fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix3x2fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix3x2fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix2x4fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix2x4fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix4x2fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix4x2fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix3x4fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix3x4fv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix4x3fv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix4x3fv not yet implemented");
-    }
-    pub fn oxidegl_uniform1ui(&mut self, location: GLint, v0: GLuint) -> GlFallible {
-        panic!("command oxidegl_uniform1ui not yet implemented");
-    }
-    pub fn oxidegl_uniform2ui(&mut self, location: GLint, v0: GLuint, v1: GLuint) -> GlFallible {
-        panic!("command oxidegl_uniform2ui not yet implemented");
-    }
-    pub fn oxidegl_uniform3ui(
-        &mut self,
-        location: GLint,
-        v0: GLuint,
-        v1: GLuint,
-        v2: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform3ui not yet implemented");
-    }
-    pub fn oxidegl_uniform4ui(
-        &mut self,
-        location: GLint,
-        v0: GLuint,
-        v1: GLuint,
-        v2: GLuint,
-        v3: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform4ui not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform1uiv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform1uiv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform2uiv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform2uiv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform3uiv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform3uiv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform4uiv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform4uiv not yet implemented");
-    }
-}
-/// ### Parameters
-/// `target`
-///
-/// > Specifies the target to which the buffer object is bound for [**glUnmapBuffer**](crate::context::Context::oxidegl_unmap_buffer),
-/// > which must be one of the buffer binding targets in the following table:
-///
-/// > | *Buffer Binding Target*                               | *Purpose*      |
-/// > |-------------------------------------------------------|----------------|
-/// > | [`GL_ARRAY_BUFFER`](crate::gl_enums::GL_ARRAY_BUFFER) | Vertex attributes |
-/// > | [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER) | Atomic counter storage |
-/// > | [`GL_COPY_READ_BUFFER`](crate::gl_enums::GL_COPY_READ_BUFFER) | Buffer copy source |
-/// > | [`GL_COPY_WRITE_BUFFER`](crate::gl_enums::GL_COPY_WRITE_BUFFER) | Buffer copy destination |
-/// > | [`GL_DISPATCH_INDIRECT_BUFFER`](crate::gl_enums::GL_DISPATCH_INDIRECT_BUFFER) | Indirect compute dispatch commands |
-/// > | [`GL_DRAW_INDIRECT_BUFFER`](crate::gl_enums::GL_DRAW_INDIRECT_BUFFER) | Indirect command arguments |
-/// > | [`GL_ELEMENT_ARRAY_BUFFER`](crate::gl_enums::GL_ELEMENT_ARRAY_BUFFER) | Vertex array indices |
-/// > | [`GL_PIXEL_PACK_BUFFER`](crate::gl_enums::GL_PIXEL_PACK_BUFFER) | Pixel read target |
-/// > | [`GL_PIXEL_UNPACK_BUFFER`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER) | Texture data source |
-/// > | [`GL_QUERY_BUFFER`](crate::gl_enums::GL_QUERY_BUFFER) | Query result buffer |
-/// > | [`GL_SHADER_STORAGE_BUFFER`](crate::gl_enums::GL_SHADER_STORAGE_BUFFER) | Read-write storage for shaders |
-/// > | [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER) | Texture data buffer |
-/// > | [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER) | Transform feedback buffer |
-/// > | [`GL_UNIFORM_BUFFER`](crate::gl_enums::GL_UNIFORM_BUFFER) | Uniform block storage |
-///
-/// `buffer`
-///
-/// > Specifies the name of the buffer object for [**glUnmapNamedBuffer**](crate::context::Context::oxidegl_unmap_named_buffer).
-///
-/// ### Description
-/// [**glUnmapBuffer**](crate::context::Context::oxidegl_unmap_buffer) and
-/// [**glUnmapNamedBuffer**](crate::context::Context::oxidegl_unmap_named_buffer)
-/// unmap (release) any mapping of a specified buffer object into the client's
-/// address space (see [**glMapBufferRange**](crate::context::Context::oxidegl_map_buffer_range)
-/// and [**glMapBuffer**](crate::context::Context::oxidegl_map_buffer) ).
-///
-/// If a mapping is not unmapped before the corresponding buffer object's data
-/// store is used by the GL, an error will be generated by any GL command that
-/// attempts to dereference the buffer object's data store, unless the buffer
-/// was successfully mapped with [`GL_MAP_PERSISTENT_BIT`](crate::gl_enums::GL_MAP_PERSISTENT_BIT)
-/// (see [**glMapBufferRange**](crate::context::Context::oxidegl_map_buffer_range)
-/// ). When a data store is unmapped, the mapped pointer becomes invalid.
-///
-/// [**glUnmapBuffer**](crate::context::Context::oxidegl_unmap_buffer) returns
-/// [`GL_TRUE`](crate::gl_enums::GL_TRUE) unless the data store contents have
-/// become corrupt during the time the data store was mapped. This can occur
-/// for system-specific reasons that affect the availability of graphics memory,
-/// such as screen mode changes. In such situations, [`GL_FALSE`](crate::gl_enums::GL_FALSE)
-/// is returned and the data store contents are undefined. An application must
-/// detect this rare condition and reinitialize the data store.
-///
-/// A buffer object's mapped data store is automatically unmapped when the
-/// buffer object is deleted or its data store is recreated with [**glBufferData**](crate::context::Context::oxidegl_buffer_data)
-/// ).
-///
-/// ### Notes
-/// If an error is generated, [**glUnmapBuffer**](crate::context::Context::oxidegl_unmap_buffer)
-/// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE).
-///
-/// The [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER)
-/// target is accepted only if the GL version is 4.2 or greater.
-///
-/// The [`GL_DISPATCH_INDIRECT_BUFFER`](crate::gl_enums::GL_DISPATCH_INDIRECT_BUFFER)
-/// and [`GL_SHADER_STORAGE_BUFFER`](crate::gl_enums::GL_SHADER_STORAGE_BUFFER)
-/// targets are available only if the GL version is 4.3 or greater.
-///
-/// The [`GL_QUERY_BUFFER`](crate::gl_enums::GL_QUERY_BUFFER) target is available
-/// only if the GL version is 4.4 or greater.
-///
-/// ### Associated Gets
-/// [**glGetBufferParameter**](crate::context::Context::oxidegl_get_buffer_parameter)
-/// with argument [`GL_BUFFER_MAPPED`](crate::gl_enums::GL_BUFFER_MAPPED).
-impl Context {
-    pub fn oxidegl_unmap_buffer(&mut self, target: BufferTarget) -> GlFallible<GLboolean> {
-        panic!("command oxidegl_unmap_buffer not yet implemented");
-    }
-    pub fn oxidegl_unmap_named_buffer(&mut self, buffer: GLuint) -> GlFallible<GLboolean> {
-        panic!("command oxidegl_unmap_named_buffer not yet implemented");
+    pub fn oxidegl_unmap_named_buffer(&mut self, buffer: GLuint) -> GlFallible<GLboolean> {
+        panic!("command oxidegl_unmap_named_buffer not yet implemented");
     }
 }
 /// ### Parameters
@@ -11446,5834 +9967,2796 @@ impl Context {
         panic!("command oxidegl_vertex_attrib_p4ui not yet implemented");
     }
     pub fn oxidegl_vertex_attrib_l1d(&mut self, index: GLuint, x: GLdouble) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_l1d not yet implemented");
-    }
-    pub fn oxidegl_vertex_attrib_l2d(
-        &mut self,
-        index: GLuint,
-        x: GLdouble,
-        y: GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_l2d not yet implemented");
-    }
-    pub fn oxidegl_vertex_attrib_l3d(
-        &mut self,
-        index: GLuint,
-        x: GLdouble,
-        y: GLdouble,
-        z: GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_l3d not yet implemented");
-    }
-    pub fn oxidegl_vertex_attrib_l4d(
-        &mut self,
-        index: GLuint,
-        x: GLdouble,
-        y: GLdouble,
-        z: GLdouble,
-        w: GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_l4d not yet implemented");
-    }
-    pub unsafe fn oxidegl_vertex_attrib_l1dv(
-        &mut self,
-        index: GLuint,
-        v: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_l1dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_vertex_attrib_l2dv(
-        &mut self,
-        index: GLuint,
-        v: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_l2dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_vertex_attrib_l3dv(
-        &mut self,
-        index: GLuint,
-        v: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_l3dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_vertex_attrib_l4dv(
-        &mut self,
-        index: GLuint,
-        v: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_l4dv not yet implemented");
-    }
-}
-/// ### Parameters
-/// `index`
-///
-/// > Specify the first viewport to set.
-///
-/// `x`
-///
-/// `y`
-///
-/// > For [**glViewportIndexedf**](crate::context::Context::oxidegl_viewport_indexedf),
-/// > specifies the lower left corner of the viewport rectangle, in pixels.
-/// > The initial value is (0,0).
-///
-/// `w`
-///
-/// `h`
-///
-/// > For [**glViewportIndexedf**](crate::context::Context::oxidegl_viewport_indexedf),
-/// > specifies the width and height of the viewport. When a GL context is first
-/// > attached to a window, `w` and `h` are set to the dimensions of that window.
-///
-/// `v`
-///
-/// > For [**glViewportIndexedfv**](crate::context::Context::oxidegl_viewport_indexedfv),
-/// > specifies the address of an array containing the viewport parameters.
-///
-/// ### Description
-/// [**glViewportIndexedf**](crate::context::Context::oxidegl_viewport_indexedf)
-/// and [**glViewportIndexedfv**](crate::context::Context::oxidegl_viewport_indexedfv)
-/// specify the parameters for a single viewport. `index` specifies the index
-/// of the viewport to modify. `index` must be less than the value of [`GL_MAX_VIEWPORTS`](crate::gl_enums::GL_MAX_VIEWPORTS).
-/// For [**glViewportIndexedf**](crate::context::Context::oxidegl_viewport_indexedf),
-/// `x`, `y`, `w`, and `h` specify the left, bottom, width and height of the
-/// viewport in pixels, respectively. For [**glViewportIndexedfv**](crate::context::Context::oxidegl_viewport_indexedfv),
-/// `v` contains the address of an array of floating point values specifying
-/// the left( `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
-/// `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
-///
-///
-///
-/// The location of the viewport's bottom left corner, given by( `[inlineq]`
-/// `[inlineq]` `[inlineq]` `[inlineq]` [**glGet**](crate::context::Context::oxidegl_get)
-/// with argument [`GL_VIEWPORT_BOUNDS_RANGE`](crate::gl_enums::GL_VIEWPORT_BOUNDS_RANGE).
-/// Viewport width and height are silently clamped to a range that depends
-/// on the implementation. To query this range, call [**glGet**](crate::context::Context::oxidegl_get)
-/// with argument [`GL_MAX_VIEWPORT_DIMS`](crate::gl_enums::GL_MAX_VIEWPORT_DIMS).
-///
-/// The precision with which the GL interprets the floating point viewport
-/// bounds is implementation-dependent and may be determined by querying the
-/// impementation-defined constant [`GL_VIEWPORT_SUBPIXEL_BITS`](crate::gl_enums::GL_VIEWPORT_SUBPIXEL_BITS).
-///
-/// Calling [**glViewportIndexedfv**](crate::context::Context::oxidegl_viewport_indexedfv)
-/// is equivalent to calling [**glViewportArray**](crate::context::Context::oxidegl_viewport_array)
-/// with `first` set to `index`, `count` set to 1 and `v` passsed directly.
-/// [**glViewportIndexedf**](crate::context::Context::oxidegl_viewport_indexedf)
-/// is equivalent to:
-///
-/// ### Associated Gets
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_VIEWPORT`](crate::gl_enums::GL_VIEWPORT)
-///
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_VIEWPORT_DIMS`](crate::gl_enums::GL_MAX_VIEWPORT_DIMS)
-///
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_VIEWPORT_BOUNDS_RANGE`](crate::gl_enums::GL_VIEWPORT_BOUNDS_RANGE)
-///
-/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_VIEWPORT_SUBPIXEL_BITS`](crate::gl_enums::GL_VIEWPORT_SUBPIXEL_BITS)
-impl Context {
-    pub fn oxidegl_viewport_indexedf(
-        &mut self,
-        index: GLuint,
-        x: GLfloat,
-        y: GLfloat,
-        w: GLfloat,
-        h: GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_viewport_indexedf not yet implemented");
-    }
-    pub unsafe fn oxidegl_viewport_indexedfv(
-        &mut self,
-        index: GLuint,
-        v: *const GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_viewport_indexedfv not yet implemented");
-    }
-}
-impl Context {
-    /// ### Parameters
-    /// `pipeline`
-    ///
-    /// > Specifies the program pipeline object to set the active program object
-    /// > for.
-    ///
-    /// `program`
-    ///
-    /// > Specifies the program object to set as the active program pipeline object
-    /// > `pipeline`.
-    ///
-    /// ### Description
-    /// [**glActiveShaderProgram**](crate::context::Context::oxidegl_active_shader_program)
-    /// sets the linked program named by `program` to be the active program for
-    /// the program pipeline object `pipeline`. The active program in the active
-    /// program pipeline object is the target of calls to [**glUniform**](crate::context::Context::oxidegl_uniform)
-    /// when no program has been made current through a call to [**glUseProgram**](crate::context::Context::oxidegl_use_program).
-    pub fn oxidegl_active_shader_program(
-        &mut self,
-        pipeline: GLuint,
-        program: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_active_shader_program not yet implemented");
-    }
-    /// ### Parameters
-    /// `texture`
-    ///
-    /// > Specifies which texture unit to make active. The number of texture units
-    /// > is implementation dependent, but must be at least 80. `texture` must be
-    /// > one of [`GL_TEXTURE`](crate::gl_enums::GL_TEXTURE) *i*, where *i* ranges
-    /// > from zero to the value of [`GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`](crate::gl_enums::GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS)
-    /// > minus one. The initial value is [`GL_TEXTURE0`](crate::gl_enums::GL_TEXTURE0).
-    ///
-    /// ### Description
-    /// [**glActiveTexture**](crate::context::Context::oxidegl_active_texture)
-    /// selects which texture unit subsequent texture state calls will affect.
-    /// The number of texture units an implementation supports is implementation
-    /// dependent, but must be at least 80.
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_ACTIVE_TEXTURE`](crate::gl_enums::GL_ACTIVE_TEXTURE),
-    /// or [`GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`](crate::gl_enums::GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS).
-    pub fn oxidegl_active_texture(&mut self, texture: TextureUnit) -> GlFallible {
-        panic!("command oxidegl_active_texture not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the handle of the program object in which the association is
-    /// > to be made.
-    ///
-    /// `index`
-    ///
-    /// > Specifies the index of the generic vertex attribute to be bound.
-    ///
-    /// `name`
-    ///
-    /// > Specifies a null terminated string containing the name of the vertex shader
-    /// > attribute variable to which `index` is to be bound.
-    ///
-    /// ### Description
-    /// [**glBindAttribLocation**](crate::context::Context::oxidegl_bind_attrib_location)
-    /// is used to associate a user-defined attribute variable in the program object
-    /// specified by `program` with a generic vertex attribute index. The name
-    /// of the user-defined attribute variable is passed as a null terminated string
-    /// in `name`. The generic vertex attribute index to be bound to this variable
-    /// is specified by `index`. When `program` is made part of current state,
-    /// values provided via the generic vertex attribute `index` will modify the
-    /// value of the user-defined attribute variable specified by `name`.
-    ///
-    /// If `name` refers to a matrix attribute variable, `index` refers to the
-    /// first column of the matrix. Other matrix columns are then automatically
-    /// bound to locations `index+1` for a matrix of type [**mat2**](crate::context::Context::oxidemat2);
-    /// `index+1` and `index+2` for a matrix of type [**mat3**](crate::context::Context::oxidemat3);
-    /// and `index+1`, `index+2`, and `index+3` for a matrix of type [**mat4**](crate::context::Context::oxidemat4).
-    ///
-    /// This command makes it possible for vertex shaders to use descriptive names
-    /// for attribute variables rather than generic variables that are numbered
-    /// from zero to the value of [`GL_MAX_VERTEX_ATTRIBS`](crate::gl_enums::GL_MAX_VERTEX_ATTRIBS)
-    /// minus one. The values sent to each generic attribute index are part of
-    /// current state. If a different program object is made current by calling
-    /// [**glUseProgram**](crate::context::Context::oxidegl_use_program), the generic
-    /// vertex attributes are tracked in such a way that the same values will be
-    /// observed by attributes in the new program object that are also bound to
-    /// `index`.
-    ///
-    /// Attribute variable name-to-generic attribute index bindings for a program
-    /// object can be explicitly assigned at any time by calling [**glBindAttribLocation**](crate::context::Context::oxidegl_bind_attrib_location).
-    /// Attribute bindings do not go into effect until [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// is called. After a program object has been linked successfully, the index
-    /// values for generic attributes remain fixed (and their values can be queried)
-    /// until the next link command occurs.
-    ///
-    /// Any attribute binding that occurs after the program object has been linked
-    /// will not take effect until the next time the program object is linked.
-    ///
-    /// ### Notes
-    /// [**glBindAttribLocation**](crate::context::Context::oxidegl_bind_attrib_location)
-    /// can be called before any vertex shader objects are bound to the specified
-    /// program object. It is also permissible to bind a generic attribute index
-    /// to an attribute variable name that is never used in a vertex shader.
-    ///
-    /// If `name` was bound previously, that information is lost. Thus you cannot
-    /// bind one user-defined attribute variable to multiple indices, but you can
-    /// bind multiple user-defined attribute variables to the same index.
-    ///
-    /// Applications are allowed to bind more than one user-defined attribute variable
-    /// to the same generic vertex attribute index. This is called *aliasing*, and
-    /// it is allowed only if just one of the aliased attributes is active in the
-    /// executable program, or if no path through the shader consumes more than
-    /// one attribute of a set of attributes aliased to the same location. The
-    /// compiler and linker are allowed to assume that no aliasing is done and
-    /// are free to employ optimizations that work only in the absence of aliasing.
-    /// OpenGL implementations are not required to do error checking to detect
-    /// aliasing.
-    ///
-    /// Active attributes that are not explicitly bound will be bound by the linker
-    /// when [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// is called. The locations assigned can be queried by calling [**glGetAttribLocation**](crate::context::Context::oxidegl_get_attrib_location).
-    ///
-    /// OpenGL copies the `name` string when [**glBindAttribLocation**](crate::context::Context::oxidegl_bind_attrib_location)
-    /// is called, so an application may free its copy of the `name` string immediately
-    /// after the function returns.
-    ///
-    /// Generic attribute locations may be specified in the shader source text
-    /// using a [**location**](crate::context::Context::oxidelocation) layout qualifier.
-    /// In this case, the location of the attribute specified in the shader's source
-    /// takes precedence and may be queried by calling [**glGetAttribLocation**](crate::context::Context::oxidegl_get_attrib_location).
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_VERTEX_ATTRIBS`](crate::gl_enums::GL_MAX_VERTEX_ATTRIBS)
-    ///
-    /// [**glGetActiveAttrib**](crate::context::Context::oxidegl_get_active_attrib)
-    /// with argument `program`
-    ///
-    /// [**glGetAttribLocation**](crate::context::Context::oxidegl_get_attrib_location)
-    /// with arguments `program` and `name`
-    ///
-    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
-    pub unsafe fn oxidegl_bind_attrib_location(
-        &mut self,
-        program: GLuint,
-        index: GLuint,
-        name: *const GLchar,
-    ) -> GlFallible {
-        panic!("command oxidegl_bind_attrib_location not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
-    ///
-    /// > The name of the program containing varying out variable whose binding to
-    /// > modify
-    ///
-    /// `colorNumber`
-    ///
-    /// > The color number to bind the user-defined varying out variable to
-    ///
-    /// `name`
-    ///
-    /// > The name of the user-defined varying out variable whose binding to modify
-    ///
-    /// ### Description
-    /// [**glBindFragDataLocation**](crate::context::Context::oxidegl_bind_frag_data_location)
-    /// explicitly specifies the binding of the user-defined varying out variable
-    /// `name` to fragment shader color number `colorNumber` for program `program`.
-    /// If `name` was bound previously, its assigned binding is replaced with
-    /// `colorNumber`. `name` must be a null-terminated string. `colorNumber` must
-    /// be less than [`GL_MAX_DRAW_BUFFERS`](crate::gl_enums::GL_MAX_DRAW_BUFFERS).
-    ///
-    /// The bindings specified by [**glBindFragDataLocation**](crate::context::Context::oxidegl_bind_frag_data_location)
-    /// have no effect until `program` is next linked. Bindings may be specified
-    /// at any time after `program` has been created. Specifically, they may be
-    /// specified before shader objects are attached to the program. Therefore,
-    /// any name may be specified in `name`, including a name that is never used
-    /// as a varying out variable in any fragment shader object. Names beginning
-    /// with [`gl_`](crate::gl_enums::gl_) are reserved by the GL.
-    ///
-    /// In addition to the errors generated by [**glBindFragDataLocation**](crate::context::Context::oxidegl_bind_frag_data_location),
-    /// the program `program` will fail to link if: The number of active outputs
-    /// > is greater than the value [`GL_MAX_DRAW_BUFFERS`](crate::gl_enums::GL_MAX_DRAW_BUFFERS).
-    ///
-    /// > More than one varying out variable is bound to the same color number.
-    ///
-    ///
-    /// ### Notes
-    /// Varying out varyings may have indexed locations assigned explicitly in
-    /// the shader text using a [**glBindFragDataLocation**](crate::context::Context::oxidegl_bind_frag_data_location)
-    /// is ignored.
-    ///
-    /// ### Associated Gets
-    /// [**glGetFragDataLocation**](crate::context::Context::oxidegl_get_frag_data_location)
-    /// with a valid program object and the name of a user-defined varying out
-    /// variable
-    pub unsafe fn oxidegl_bind_frag_data_location(
-        &mut self,
-        program: GLuint,
-        color: GLuint,
-        name: *const GLchar,
-    ) -> GlFallible {
-        panic!("command oxidegl_bind_frag_data_location not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
-    ///
-    /// > The name of the program containing varying out variable whose binding to
-    /// > modify
-    ///
-    /// `colorNumber`
-    ///
-    /// > The color number to bind the user-defined varying out variable to
-    ///
-    /// `index`
-    ///
-    /// > The index of the color input to bind the user-defined varying out variable
-    /// > to
-    ///
-    /// `name`
-    ///
-    /// > The name of the user-defined varying out variable whose binding to modify
-    ///
-    /// ### Description
-    /// [**glBindFragDataLocationIndexed**](crate::context::Context::oxidegl_bind_frag_data_location_indexed)
-    /// specifies that the varying out variable `name` in `program` should be bound
-    /// to fragment color `colorNumber` when the program is next linked. `index`
-    /// may be zero or one to specify that the color be used as either the first
-    /// or second color input to the blend equation, respectively.
-    ///
-    /// The bindings specified by [**glBindFragDataLocationIndexed**](crate::context::Context::oxidegl_bind_frag_data_location_indexed)
-    /// have no effect until `program` is next linked. Bindings may be specified
-    /// at any time after `program` has been created. Specifically, they may be
-    /// specified before shader objects are attached to the program. Therefore,
-    /// any name may be specified in `name`, including a name that is never used
-    /// as a varying out variable in any fragment shader object. Names beginning
-    /// with [`gl_`](crate::gl_enums::gl_) are reserved by the GL.
-    ///
-    /// If `name` was bound previously, its assigned binding is replaced with `colorNumber`
-    /// and `index`. `name` must be a null-terminated string. `index` must be less
-    /// than or equal to one, and `colorNumber` must be less than the value of
-    /// [`GL_MAX_DRAW_BUFFERS`](crate::gl_enums::GL_MAX_DRAW_BUFFERS) if `index`
-    /// is zero, and less than the value of [`GL_MAX_DUAL_SOURCE_DRAW_BUFFERS`](crate::gl_enums::GL_MAX_DUAL_SOURCE_DRAW_BUFFERS)
-    /// if index is greater than or equal to one.
-    ///
-    /// In addition to the errors generated by [**glBindFragDataLocationIndexed**](crate::context::Context::oxidegl_bind_frag_data_location_indexed),
-    /// the program `program` will fail to link if: The number of active outputs
-    /// > is greater than the value [`GL_MAX_DRAW_BUFFERS`](crate::gl_enums::GL_MAX_DRAW_BUFFERS).
-    ///
-    /// > More than one varying out variable is bound to the same color number.
-    ///
-    ///
-    /// ### Notes
-    /// Varying out varyings may have locations assigned explicitly in the shader
-    /// text using a [**glBindFragDataLocation**](crate::context::Context::oxidegl_bind_frag_data_location)
-    /// is ignored.
-    ///
-    /// ### Associated Gets
-    /// [**glGetFragDataLocation**](crate::context::Context::oxidegl_get_frag_data_location)
-    /// with a valid program object and the name of a user-defined varying out
-    /// variable
-    ///
-    /// [**glGetFragDataIndex**](crate::context::Context::oxidegl_get_frag_data_index)
-    /// with a valid program object and the name of a user-defined varying out
-    /// variable
-    pub unsafe fn oxidegl_bind_frag_data_location_indexed(
-        &mut self,
-        program: GLuint,
-        color_number: GLuint,
-        index: GLuint,
-        name: *const GLchar,
-    ) -> GlFallible {
-        panic!("command oxidegl_bind_frag_data_location_indexed not yet implemented");
-    }
-    /// ### Parameters
-    /// `target`
-    ///
-    /// > Specifies the framebuffer target of the binding operation.
-    ///
-    /// `framebuffer`
-    ///
-    /// > Specifies the name of the framebuffer object to bind.
-    ///
-    /// ### Description
-    /// [**glBindFramebuffer**](crate::context::Context::oxidegl_bind_framebuffer)
-    /// binds the framebuffer object with name `framebuffer` to the framebuffer
-    /// target specified by `target`. `target` must be either [`GL_DRAW_FRAMEBUFFER`](crate::gl_enums::GL_DRAW_FRAMEBUFFER),
-    /// [`GL_READ_FRAMEBUFFER`](crate::gl_enums::GL_READ_FRAMEBUFFER) or [`GL_FRAMEBUFFER`](crate::gl_enums::GL_FRAMEBUFFER).
-    /// If a framebuffer object is bound to [`GL_DRAW_FRAMEBUFFER`](crate::gl_enums::GL_DRAW_FRAMEBUFFER)
-    /// or [`GL_READ_FRAMEBUFFER`](crate::gl_enums::GL_READ_FRAMEBUFFER), it becomes
-    /// the target for rendering or readback operations, respectively, until it
-    /// is deleted or another framebuffer is bound to the corresponding bind point.
-    /// Calling [**glBindFramebuffer**](crate::context::Context::oxidegl_bind_framebuffer)
-    /// with `target` set to [`GL_FRAMEBUFFER`](crate::gl_enums::GL_FRAMEBUFFER)
-    /// binds `framebuffer` to both the read and draw framebuffer targets. `framebuffer`
-    /// is the name of a framebuffer object previously returned from a call to
-    /// [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers),
-    /// or zero to break the existing binding of a framebuffer object to `target`.
-    pub fn oxidegl_bind_framebuffer(
-        &mut self,
-        target: FramebufferTarget,
-        framebuffer: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_bind_framebuffer not yet implemented");
-    }
-    /// ### Parameters
-    /// `unit`
-    ///
-    /// > Specifies the index of the image unit to which to bind the texture
-    ///
-    /// `texture`
-    ///
-    /// > Specifies the name of the texture to bind to the image unit.
-    ///
-    /// `level`
-    ///
-    /// > Specifies the level of the texture that is to be bound.
-    ///
-    /// `layered`
-    ///
-    /// > Specifies whether a layered texture binding is to be established.
-    ///
-    /// `layer`
-    ///
-    /// > If `layered` is [`GL_FALSE`](crate::gl_enums::GL_FALSE), specifies the
-    /// > layer of `texture` to be bound to the image unit. Ignored otherwise.
-    ///
-    /// `access`
-    ///
-    /// > Specifies a token indicating the type of access that will be performed
-    /// > on the image.
-    ///
-    /// `format`
-    ///
-    /// > Specifies the format that the elements of the image will be treated as
-    /// > for the purposes of formatted stores.
-    ///
-    /// ### Description
-    /// [**glBindImageTexture**](crate::context::Context::oxidegl_bind_image_texture)
-    /// binds a single level of a texture to an image unit for the purpose of reading
-    /// and writing it from shaders. `unit` specifies the zero-based index of the
-    /// image unit to which to bind the texture level. `texture` specifies the
-    /// name of an existing texture object to bind to the image unit. If `texture`
-    /// is zero, then any existing binding to the image unit is broken. `level`
-    /// specifies the level of the texture to bind to the image unit.
-    ///
-    /// If `texture` is the name of a one-, two-, or three-dimensional array texture,
-    /// a cube map or cube map array texture, or a two-dimensional multisample
-    /// array texture, then it is possible to bind either the entire array, or
-    /// only a single layer of the array to the image unit. In such cases, if `layered`
-    /// is [`GL_TRUE`](crate::gl_enums::GL_TRUE), the entire array is attached
-    /// to the image unit and `layer` is ignored. However, if `layered` is [`GL_FALSE`](crate::gl_enums::GL_FALSE)
-    /// then `layer` specifies the layer of the array to attach to the image unit.
-    ///
-    /// `access` specifies the access types to be performed by shaders and may
-    /// be set to [`GL_READ_ONLY`](crate::gl_enums::GL_READ_ONLY), [`GL_WRITE_ONLY`](crate::gl_enums::GL_WRITE_ONLY),
-    /// or [`GL_READ_WRITE`](crate::gl_enums::GL_READ_WRITE) to indicate read-only,
-    /// write-only or read-write access, respectively. Violation of the access
-    /// type specified in `access` (for example, if a shader writes to an image
-    /// bound with `access` set to [`GL_READ_ONLY`](crate::gl_enums::GL_READ_ONLY))
-    /// will lead to undefined results, possibly including program termination.
-    ///
-    /// `format` specifies the format that is to be used when performing formatted
-    /// stores into the image from shaders. `format` must be compatible with the
-    /// texture's internal format and must be one of the formats listed in the
-    /// following table.
-    ///
-    /// |* Image Unit Format*                             |* Format Qualifier*                             |
-    /// |-------------------------------------------------|------------------------------------------------|
-    /// | [`GL_RGBA32F`](crate::gl_enums::GL_RGBA32F)     |                                                |
-    /// | [`GL_RGBA16F`](crate::gl_enums::GL_RGBA16F)     |                                                |
-    /// | [`GL_RG32F`](crate::gl_enums::GL_RG32F)         |                                                |
-    /// | [`GL_RG16F`](crate::gl_enums::GL_RG16F)         |                                                |
-    /// | [`GL_R11F_G11F_B10F`](crate::gl_enums::GL_R11F_G11F_B10F) |                                      |
-    /// | [`GL_R32F`](crate::gl_enums::GL_R32F)           |                                                |
-    /// | [`GL_R16F`](crate::gl_enums::GL_R16F)           |                                                |
-    /// | [`GL_RGBA32UI`](crate::gl_enums::GL_RGBA32UI)   |                                                |
-    /// | [`GL_RGBA16UI`](crate::gl_enums::GL_RGBA16UI)   |                                                |
-    /// | [`GL_RGB10_A2UI`](crate::gl_enums::GL_RGB10_A2UI) |                                              |
-    /// | [`GL_RGBA8UI`](crate::gl_enums::GL_RGBA8UI)     |                                                |
-    /// | [`GL_RG32UI`](crate::gl_enums::GL_RG32UI)       |                                                |
-    /// | [`GL_RG16UI`](crate::gl_enums::GL_RG16UI)       |                                                |
-    /// | [`GL_RG8UI`](crate::gl_enums::GL_RG8UI)         |                                                |
-    /// | [`GL_R32UI`](crate::gl_enums::GL_R32UI)         |                                                |
-    /// | [`GL_R16UI`](crate::gl_enums::GL_R16UI)         |                                                |
-    /// | [`GL_R8UI`](crate::gl_enums::GL_R8UI)           |                                                |
-    /// | [`GL_RGBA32I`](crate::gl_enums::GL_RGBA32I)     |                                                |
-    /// | [`GL_RGBA16I`](crate::gl_enums::GL_RGBA16I)     |                                                |
-    /// | [`GL_RGBA8I`](crate::gl_enums::GL_RGBA8I)       |                                                |
-    /// | [`GL_RG32I`](crate::gl_enums::GL_RG32I)         |                                                |
-    /// | [`GL_RG16I`](crate::gl_enums::GL_RG16I)         |                                                |
-    /// | [`GL_RG8I`](crate::gl_enums::GL_RG8I)           |                                                |
-    /// | [`GL_R32I`](crate::gl_enums::GL_R32I)           |                                                |
-    /// | [`GL_R16I`](crate::gl_enums::GL_R16I)           |                                                |
-    /// | [`GL_R8I`](crate::gl_enums::GL_R8I)             |                                                |
-    /// | [`GL_RGBA16`](crate::gl_enums::GL_RGBA16)       |                                                |
-    /// | [`GL_RGB10_A2`](crate::gl_enums::GL_RGB10_A2)   |                                                |
-    /// | [`GL_RGBA8`](crate::gl_enums::GL_RGBA8)         |                                                |
-    /// | [`GL_RG16`](crate::gl_enums::GL_RG16)           |                                                |
-    /// | [`GL_RG8`](crate::gl_enums::GL_RG8)             |                                                |
-    /// | [`GL_R16`](crate::gl_enums::GL_R16)             |                                                |
-    /// | [`GL_R8`](crate::gl_enums::GL_R8)               |                                                |
-    /// | [`GL_RGBA16_SNORM`](crate::gl_enums::GL_RGBA16_SNORM) |                                          |
-    /// | [`GL_RGBA8_SNORM`](crate::gl_enums::GL_RGBA8_SNORM) |                                            |
-    /// | [`GL_RG16_SNORM`](crate::gl_enums::GL_RG16_SNORM) |                                              |
-    /// | [`GL_RG8_SNORM`](crate::gl_enums::GL_RG8_SNORM) |                                                |
-    /// | [`GL_R16_SNORM`](crate::gl_enums::GL_R16_SNORM) |                                                |
-    /// | [`GL_R8_SNORM`](crate::gl_enums::GL_R8_SNORM)   |                                                |
-    ///
-    ///
-    /// When a texture is bound to an image unit, the `format` parameter for the
-    /// image unit need not exactly match the texture internal format as long as
-    /// the formats are considered compatible as defined in the OpenGL Specification.
-    /// The matching criterion used for a given texture may be determined by calling
-    /// [**glGetTexParameter**](crate::context::Context::oxidegl_get_tex_parameter)
-    /// with `value` set to [`GL_IMAGE_FORMAT_COMPATIBILITY_TYPE`](crate::gl_enums::GL_IMAGE_FORMAT_COMPATIBILITY_TYPE),
-    /// with return values of [`GL_IMAGE_FORMAT_COMPATIBILITY_BY_SIZE`](crate::gl_enums::GL_IMAGE_FORMAT_COMPATIBILITY_BY_SIZE)
-    /// and [`GL_IMAGE_FORMAT_COMPATIBILITY_BY_CLASS`](crate::gl_enums::GL_IMAGE_FORMAT_COMPATIBILITY_BY_CLASS),
-    /// specifying matches by size and class, respectively.
-    ///
-    /// ### Notes
-    /// The [**glBindImageTexture**](crate::context::Context::oxidegl_bind_image_texture)
-    /// is available only if the GL version is 4.2 or greater.
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_IMAGE_BINDING_NAME`](crate::gl_enums::GL_IMAGE_BINDING_NAME).
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_IMAGE_BINDING_LEVEL`](crate::gl_enums::GL_IMAGE_BINDING_LEVEL).
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_IMAGE_BINDING_LAYERED`](crate::gl_enums::GL_IMAGE_BINDING_LAYERED).
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_IMAGE_BINDING_LAYER`](crate::gl_enums::GL_IMAGE_BINDING_LAYER).
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_IMAGE_BINDING_ACCESS`](crate::gl_enums::GL_IMAGE_BINDING_ACCESS).
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_IMAGE_BINDING_FORMAT`](crate::gl_enums::GL_IMAGE_BINDING_FORMAT).
-    pub fn oxidegl_bind_image_texture(
-        &mut self,
-        unit: GLuint,
-        texture: GLuint,
-        level: GLint,
-        layered: GLboolean,
-        layer: GLint,
-        access: BufferAccess,
-        format: InternalFormat,
-    ) -> GlFallible {
-        panic!("command oxidegl_bind_image_texture not yet implemented");
-    }
-    /// ### Parameters
-    /// `first`
-    ///
-    /// > Specifies the first image unit to which a texture is to be bound.
-    ///
-    /// `count`
-    ///
-    /// > Specifies the number of textures to bind.
-    ///
-    /// `textures`
-    ///
-    /// > Specifies the address of an array of names of existing texture objects.
-    ///
-    /// ### Description
-    /// [**glBindImageTextures**](crate::context::Context::oxidegl_bind_image_textures)
-    /// binds images from an array of existing texture objects to a specified number
-    /// of consecutive image units. `count` specifies the number of texture objects
-    /// whose names are stored in the array `textures`. That number of texture
-    /// names are read from the array and bound to the `count` consecutive texture
-    /// units starting from `first`. If the name zero appears in the `textures`
-    /// array, any existing binding to the image unit is reset. Any non-zero entry
-    /// in `textures` must be the name of an existing texture object. When a non-zero
-    /// entry in `textures` is present, the image at level zero is bound, the binding
-    /// is considered layered, with the first layer set to zero, and the image
-    /// is bound for read-write access. The image unit format parameter is taken
-    /// from the internal format of the image at level zero of the texture object.
-    /// For cube map textures, the internal format of the positive X image of level
-    /// zero is used. If `textures` is [`NULL`](crate::gl_enums::NULL) then it
-    /// is as if an appropriately sized array containing only zeros had been specified.
-    ///
-    /// [**glBindImageTextures**](crate::context::Context::oxidegl_bind_image_textures)
-    /// is equivalent to the following pseudo code:
-    ///
-    /// Each entry in `textures` will be checked individually and if found to be
-    /// invalid, the state for that image unit will not be changed and an error
-    /// will be generated. However, the state for other texture image units referenced
-    /// by the command will still be updated.
-    ///
-    /// ### Notes
-    /// [**glBindImageTextures**](crate::context::Context::oxidegl_bind_image_textures)
-    /// is available only if the GL version is 4.4 or higher.
-    ///
-    /// Note that because [**glBindImageTextures**](crate::context::Context::oxidegl_bind_image_textures)
-    /// cannot create new textures (even if a name passed has been previously generated
-    /// by call to [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
-    /// ), names passed to [**glBindImageTextures**](crate::context::Context::oxidegl_bind_image_textures)
-    /// must have been bound at least once previously via a call to [**glBindTexture**](crate::context::Context::oxidegl_bind_texture).
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_1D`](crate::gl_enums::GL_TEXTURE_BINDING_1D),
-    /// [`GL_TEXTURE_BINDING_2D`](crate::gl_enums::GL_TEXTURE_BINDING_2D), [`GL_TEXTURE_BINDING_3D`](crate::gl_enums::GL_TEXTURE_BINDING_3D),
-    /// [`GL_TEXTURE_BINDING_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_1D_ARRAY),
-    /// [`GL_TEXTURE_BINDING_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_ARRAY),
-    /// [`GL_TEXTURE_BINDING_RECTANGLE`](crate::gl_enums::GL_TEXTURE_BINDING_RECTANGLE),
-    /// [`GL_TEXTURE_BINDING_BUFFER`](crate::gl_enums::GL_TEXTURE_BINDING_BUFFER),
-    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP),
-    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP),
-    /// [`GL_TEXTURE_BINDING_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP_ARRAY),
-    /// [`GL_TEXTURE_BINDING_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE),
-    /// or [`GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY).
-    pub unsafe fn oxidegl_bind_image_textures(
-        &mut self,
-        first: GLuint,
-        count: GLsizei,
-        textures: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_bind_image_textures not yet implemented");
-    }
-    /// ### Parameters
-    /// `pipeline`
-    ///
-    /// > Specifies the name of the pipeline object to bind to the context.
-    ///
-    /// ### Description
-    /// [**glBindProgramPipeline**](crate::context::Context::oxidegl_bind_program_pipeline)
-    /// binds a program pipeline object to the current context. `pipeline` must
-    /// be a name previously returned from a call to [**glGenProgramPipelines**](crate::context::Context::oxidegl_gen_program_pipelines).
-    /// If no program pipeline exists with name `pipeline` then a new pipeline
-    /// object is created with that name and initialized to the default state vector.
-    ///
-    /// When a program pipeline object is bound using [**glBindProgramPipeline**](crate::context::Context::oxidegl_bind_program_pipeline),
-    /// any previous binding is broken and is replaced with a binding to the specified
-    /// pipeline object. If `pipeline` is zero, the previous binding is broken
-    /// and is not replaced, leaving no pipeline object bound. If no current program
-    /// object has been established by [**glUseProgram**](crate::context::Context::oxidegl_use_program),
-    /// the program objects used for each stage and for uniform updates are taken
-    /// from the bound program pipeline object, if any. If there is a current program
-    /// object established by [**glUseProgram**](crate::context::Context::oxidegl_use_program),
-    /// the bound program pipeline object has no effect on rendering or uniform
-    /// updates. When a bound program pipeline object is used for rendering, individual
-    /// shader executables are taken from its program objects.
-    pub fn oxidegl_bind_program_pipeline(&mut self, pipeline: GLuint) -> GlFallible {
-        panic!("command oxidegl_bind_program_pipeline not yet implemented");
-    }
-    /// ### Parameters
-    /// `target`
-    ///
-    /// > Specifies the renderbuffer target of the binding operation. `target` must
-    /// > be [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER).
-    ///
-    /// `renderbuffer`
-    ///
-    /// > Specifies the name of the renderbuffer object to bind.
-    ///
-    /// ### Description
-    /// [**glBindRenderbuffer**](crate::context::Context::oxidegl_bind_renderbuffer)
-    /// binds the renderbuffer object with name `renderbuffer` to the renderbuffer
-    /// target specified by `target`. `target` must be [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER).
-    /// `renderbuffer` is the name of a renderbuffer object previously returned
-    /// from a call to [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers),
-    /// or zero to break the existing binding of a renderbuffer object to `target`.
-    pub fn oxidegl_bind_renderbuffer(
-        &mut self,
-        target: GLenum,
-        renderbuffer: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_bind_renderbuffer not yet implemented");
-    }
-    /// ### Parameters
-    /// `unit`
-    ///
-    /// > Specifies the index of the texture unit to which the sampler is bound.
-    ///
-    /// `sampler`
-    ///
-    /// > Specifies the name of a sampler.
-    ///
-    /// ### Description
-    /// [**glBindSampler**](crate::context::Context::oxidegl_bind_sampler) binds
-    /// `sampler` to the texture unit at index `unit`. `sampler` must be zero or
-    /// the name of a sampler object previously returned from a call to [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers).
-    /// `unit` must be less than the value of [`GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`](crate::gl_enums::GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS).
-    ///
-    /// When a sampler object is bound to a texture unit, its state supersedes
-    /// that of the texture object bound to that texture unit. If the sampler name
-    /// zero is bound to a texture unit, the currently bound texture's sampler
-    /// state becomes active. A single sampler object may be bound to multiple
-    /// texture units simultaneously.
-    ///
-    /// ### Notes
-    /// [**glBindSampler**](crate::context::Context::oxidegl_bind_sampler) is available
-    /// only if the GL version is 3.3 or higher.
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_SAMPLER_BINDING`](crate::gl_enums::GL_SAMPLER_BINDING)
-    pub fn oxidegl_bind_sampler(&mut self, unit: GLuint, sampler: GLuint) -> GlFallible {
-        panic!("command oxidegl_bind_sampler not yet implemented");
-    }
-    /// ### Parameters
-    /// `first`
-    ///
-    /// > Specifies the first sampler unit to which a sampler object is to be bound.
-    ///
-    /// `count`
-    ///
-    /// > Specifies the number of samplers to bind.
-    ///
-    /// `samplers`
-    ///
-    /// > Specifies the address of an array of names of existing sampler objects.
-    ///
-    /// ### Description
-    /// [**glBindSamplers**](crate::context::Context::oxidegl_bind_samplers) binds
-    /// samplers from an array of existing sampler objects to a specified number
-    /// of consecutive sampler units. `count` specifies the number of sampler objects
-    /// whose names are stored in the array `samplers`. That number of sampler
-    /// names is read from the array and bound to the `count` consecutive sampler
-    /// units starting from `first`.
-    ///
-    /// If the name zero appears in the `samplers` array, any existing binding
-    /// to the sampler unit is reset. Any non-zero entry in `samplers` must be
-    /// the name of an existing sampler object. When a non-zero entry in `samplers`
-    /// is present, that sampler object is bound to the corresponding sampler unit.
-    /// If `samplers` is [`NULL`](crate::gl_enums::NULL) then it is as if an appropriately
-    /// sized array containing only zeros had been specified.
-    ///
-    /// [**glBindSamplers**](crate::context::Context::oxidegl_bind_samplers) is
-    /// equivalent to the following pseudo code:
-    ///
-    /// Each entry in `samplers` will be checked individually and if found to be
-    /// invalid, the state for that sampler unit will not be changed and an error
-    /// will be generated. However, the state for other sampler units referenced
-    /// by the command will still be updated.
-    ///
-    /// ### Notes
-    /// [**glBindSamplers**](crate::context::Context::oxidegl_bind_samplers) is
-    /// available only if the GL version is 4.4 or higher.
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_SAMPLER_BINDING`](crate::gl_enums::GL_SAMPLER_BINDING)
-    pub unsafe fn oxidegl_bind_samplers(
-        &mut self,
-        first: GLuint,
-        count: GLsizei,
-        samplers: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_bind_samplers not yet implemented");
-    }
-    /// ### Parameters
-    /// `target`
-    ///
-    /// > Specifies the target to which the texture is bound. Must be one of [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D),
-    /// > [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D), [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D),
-    /// > [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY),
-    /// > [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE), [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP),
-    /// > [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY),
-    /// > [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE)
-    /// > or [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY).
-    ///
-    /// `texture`
-    ///
-    /// > Specifies the name of a texture.
-    ///
-    /// ### Description
-    /// [**glBindTexture**](crate::context::Context::oxidegl_bind_texture) lets
-    /// you create or use a named texture. Calling [**glBindTexture**](crate::context::Context::oxidegl_bind_texture)
-    /// with `target` set to [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D),
-    /// [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D), [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D),
-    /// [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY),
-    /// [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE), [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP),
-    /// [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY),
-    /// [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE)
-    /// or [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY)
-    /// and `texture` set to the name of the new texture binds the texture name
-    /// to the target. When a texture is bound to a target, the previous binding
-    /// for that target is automatically broken.
-    ///
-    /// Texture names are unsigned integers. The value zero is reserved to represent
-    /// the default texture for each texture target. Texture names and the corresponding
-    /// texture contents are local to the shared object space of the current GL
-    /// rendering context; two rendering contexts share texture names only if they
-    /// explicitly enable sharing between contexts through the appropriate GL windows
-    /// interfaces functions.
-    ///
-    /// You must use [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
-    /// to generate a set of new texture names.
-    ///
-    /// When a texture is first bound, it assumes the specified target: A texture
-    /// first bound to [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D) becomes
-    /// one-dimensional texture, a texture first bound to [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D)
-    /// becomes two-dimensional texture, a texture first bound to [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D)
-    /// becomes three-dimensional texture, a texture first bound to [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY)
-    /// becomes one-dimensional array texture, a texture first bound to [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY)
-    /// becomes two-dimensional array texture, a texture first bound to [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE)
-    /// becomes rectangle texture, a texture first bound to [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP)
-    /// becomes a cube-mapped texture, a texture first bound to [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY)
-    /// becomes a cube-mapped array texture, a texture first bound to [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER)
-    /// becomes a buffer texture, a texture first bound to [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE)
-    /// becomes a two-dimensional multisampled texture, and a texture first bound
-    /// to [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY)
-    /// becomes a two-dimensional multisampled array texture. The state of a one-dimensional
-    /// texture immediately after it is first bound is equivalent to the state
-    /// of the default [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D) at GL
-    /// initialization, and similarly for the other texture types.
-    ///
-    /// While a texture is bound, GL operations on the target to which it is bound
-    /// affect the bound texture, and queries of the target to which it is bound
-    /// return state from the bound texture. In effect, the texture targets become
-    /// aliases for the textures currently bound to them, and the texture name
-    /// zero refers to the default textures that were bound to them at initialization.
-    ///
-    /// A texture binding created with [**glBindTexture**](crate::context::Context::oxidegl_bind_texture)
-    /// remains active until a different texture is bound to the same target, or
-    /// until the bound texture is deleted with [**glDeleteTextures**](crate::context::Context::oxidegl_delete_textures).
-    ///
-    /// Once created, a named texture may be re-bound to its same original target
-    /// as often as needed. It is usually much faster to use [**glBindTexture**](crate::context::Context::oxidegl_bind_texture)
-    /// to bind an existing named texture to one of the texture targets than it
-    /// is to reload the texture image using [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
-    /// [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d)
-    /// or another similar function.
-    ///
-    /// ### Notes
-    /// The [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE)
-    /// and [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY)
-    /// targets are available only if the GL version is 3.2 or higher.
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_1D`](crate::gl_enums::GL_TEXTURE_BINDING_1D),
-    /// [`GL_TEXTURE_BINDING_2D`](crate::gl_enums::GL_TEXTURE_BINDING_2D), [`GL_TEXTURE_BINDING_3D`](crate::gl_enums::GL_TEXTURE_BINDING_3D),
-    /// [`GL_TEXTURE_BINDING_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_1D_ARRAY),
-    /// [`GL_TEXTURE_BINDING_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_ARRAY),
-    /// [`GL_TEXTURE_BINDING_RECTANGLE`](crate::gl_enums::GL_TEXTURE_BINDING_RECTANGLE),
-    /// [`GL_TEXTURE_BINDING_BUFFER`](crate::gl_enums::GL_TEXTURE_BINDING_BUFFER),
-    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP),
-    /// [`GL_TEXTURE_BINDING_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP_ARRAY),
-    /// [`GL_TEXTURE_BINDING_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE),
-    /// or [`GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY).
-    pub fn oxidegl_bind_texture(&mut self, target: TextureTarget, texture: GLuint) -> GlFallible {
-        panic!("command oxidegl_bind_texture not yet implemented");
-    }
-    /// ### Parameters
-    /// `first`
-    ///
-    /// > Specifies the first texture unit to which a texture is to be bound.
-    ///
-    /// `count`
-    ///
-    /// > Specifies the number of textures to bind.
-    ///
-    /// `textures`
-    ///
-    /// > Specifies the address of an array of names of existing texture objects.
-    ///
-    /// ### Description
-    /// [**glBindTextures**](crate::context::Context::oxidegl_bind_textures) binds
-    /// an array of existing texture objects to a specified number of consecutive
-    /// texture units. `count` specifies the number of texture objects whose names
-    /// are stored in the array `textures`. That number of texture names are read
-    /// from the array and bound to the `count` consecutive texture units starting
-    /// from `first`. The target, or type of texture is deduced from the texture
-    /// object and each texture is bound to the corresponding target of the texture
-    /// unit. If the name zero appears in the `textures` array, any existing binding
-    /// to any target of the texture unit is reset and the default texture for
-    /// that target is bound in its place. Any non-zero entry in `textures` must
-    /// be the name of an existing texture object. If `textures` is [`NULL`](crate::gl_enums::NULL)
-    /// then it is as if an appropriately sized array containing only zeros had
-    /// been specified.
-    ///
-    /// With the exception that the active texture selector maintains its current
-    /// value, [**glBindTextures**](crate::context::Context::oxidegl_bind_textures)
-    /// is equivalent to the following pseudo code:
-    ///
-    /// Each entry in `textures` will be checked individually and if found to be
-    /// invalid, the state for that texture unit will not be changed and an error
-    /// will be generated. However, the state for other texture units referenced
-    /// by the command will still be updated.
-    ///
-    /// ### Notes
-    /// [**glBindTextures**](crate::context::Context::oxidegl_bind_textures) is
-    /// available only if the GL version is 4.4 or higher.
-    ///
-    /// Note that because [**glBindTextures**](crate::context::Context::oxidegl_bind_textures)
-    /// cannot create new textures (even if a name passed has been previously generated
-    /// by call to [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
-    /// ), names passed to [**glBindTextures**](crate::context::Context::oxidegl_bind_textures)
-    /// must have been bound at least once previously via a call to [**glBindTexture**](crate::context::Context::oxidegl_bind_texture).
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_1D`](crate::gl_enums::GL_TEXTURE_BINDING_1D),
-    /// [`GL_TEXTURE_BINDING_2D`](crate::gl_enums::GL_TEXTURE_BINDING_2D), [`GL_TEXTURE_BINDING_3D`](crate::gl_enums::GL_TEXTURE_BINDING_3D),
-    /// [`GL_TEXTURE_BINDING_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_1D_ARRAY),
-    /// [`GL_TEXTURE_BINDING_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_ARRAY),
-    /// [`GL_TEXTURE_BINDING_RECTANGLE`](crate::gl_enums::GL_TEXTURE_BINDING_RECTANGLE),
-    /// [`GL_TEXTURE_BINDING_BUFFER`](crate::gl_enums::GL_TEXTURE_BINDING_BUFFER),
-    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP),
-    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP),
-    /// [`GL_TEXTURE_BINDING_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP_ARRAY),
-    /// [`GL_TEXTURE_BINDING_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE),
-    /// or [`GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY).
-    pub unsafe fn oxidegl_bind_textures(
-        &mut self,
-        first: GLuint,
-        count: GLsizei,
-        textures: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_bind_textures not yet implemented");
-    }
-    /// ### Parameters
-    /// `unit`
-    ///
-    /// > Specifies the texture unit, to which the texture object should be bound
-    /// > to.
-    ///
-    /// `texture`
-    ///
-    /// > Specifies the name of a texture.
-    ///
-    /// ### Description
-    /// [**glBindTextureUnit**](crate::context::Context::oxidegl_bind_texture_unit)
-    /// binds an existing texture object to the texture unit numbered `unit`.
-    ///
-    /// `texture` must be zero or the name of an existing texture object. When
-    /// `texture` is the name of an existing texture object, that object is bound
-    /// to the target, in the corresponding texture unit, that was specified when
-    /// the object was created. When `texture` is zero, each of the targets enumerated
-    /// at the beginning of this section is reset to its default texture for the
-    /// corresponding texture image unit.
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_1D`](crate::gl_enums::GL_TEXTURE_BINDING_1D),
-    /// [`GL_TEXTURE_BINDING_2D`](crate::gl_enums::GL_TEXTURE_BINDING_2D), [`GL_TEXTURE_BINDING_3D`](crate::gl_enums::GL_TEXTURE_BINDING_3D),
-    /// [`GL_TEXTURE_BINDING_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_1D_ARRAY),
-    /// [`GL_TEXTURE_BINDING_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_ARRAY),
-    /// [`GL_TEXTURE_BINDING_RECTANGLE`](crate::gl_enums::GL_TEXTURE_BINDING_RECTANGLE),
-    /// [`GL_TEXTURE_BINDING_BUFFER`](crate::gl_enums::GL_TEXTURE_BINDING_BUFFER),
-    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP),
-    /// [`GL_TEXTURE_BINDING_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP_ARRAY),
-    /// [`GL_TEXTURE_BINDING_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE)
-    /// or [`GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY).
-    pub fn oxidegl_bind_texture_unit(&mut self, unit: GLuint, texture: GLuint) -> GlFallible {
-        panic!("command oxidegl_bind_texture_unit not yet implemented");
-    }
-    /// ### Parameters
-    /// `target`
-    ///
-    /// > Specifies the target to which to bind the transform feedback object `id`.
-    /// > `target` must be [`GL_TRANSFORM_FEEDBACK`](crate::gl_enums::GL_TRANSFORM_FEEDBACK).
-    ///
-    /// `id`
-    ///
-    /// > Specifies the name of a transform feedback object reserved by [**glGenTransformFeedbacks**](crate::context::Context::oxidegl_gen_transform_feedbacks).
-    ///
-    /// ### Description
-    /// [**glBindTransformFeedback**](crate::context::Context::oxidegl_bind_transform_feedback)
-    /// binds the transform feedback object with name `id` to the current GL state.
-    /// `id` must be a name previously returned from a call to [**glGenTransformFeedbacks**](crate::context::Context::oxidegl_gen_transform_feedbacks).
-    /// If `id` has not previously been bound, a new transform feedback object
-    /// with name `id` and initialized with the default transform state vector
-    /// is created.
-    ///
-    /// In the initial state, a default transform feedback object is bound and
-    /// treated as a transform feedback object with a name of zero. If the name
-    /// zero is subsequently bound, the default transform feedback object is again
-    /// bound to the GL state.
-    ///
-    /// While a transform feedback buffer object is bound, GL operations on the
-    /// target to which it is bound affect the bound transform feedback object,
-    /// and queries of the target to which a transform feedback object is bound
-    /// return state from the bound object. When buffer objects are bound for transform
-    /// feedback, they are attached to the currently bound transform feedback object.
-    /// Buffer objects are used for trans- form feedback only if they are attached
-    /// to the currently bound transform feedback object.
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TRANSFORM_FEEDBACK_BINDING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BINDING)
-    pub fn oxidegl_bind_transform_feedback(&mut self, target: GLenum, id: GLuint) -> GlFallible {
-        panic!("command oxidegl_bind_transform_feedback not yet implemented");
-    }
-    /// ### Parameters
-    /// `red`
-    ///
-    /// `green`
-    ///
-    /// `blue`
-    ///
-    /// `alpha`
-    ///
-    /// > specify the components of [`GL_BLEND_COLOR`](crate::gl_enums::GL_BLEND_COLOR)
-    ///
-    /// ### Description
-    /// The [`GL_BLEND_COLOR`](crate::gl_enums::GL_BLEND_COLOR) may be used to
-    /// calculate the source and destination blending factors. The color components
-    /// are clamped to the range `[inlineq]` [**glBlendFunc**](crate::context::Context::oxidegl_blend_func)
-    /// for a complete description of the blending operations. Initially the [`GL_BLEND_COLOR`](crate::gl_enums::GL_BLEND_COLOR)
-    /// is set to (0, 0, 0, 0).
-    ///
-    /// ### Notes
-    /// The type of the `red`, `green`, `blue`, and `alpha` parameters was changed
-    /// from `GLclampf` to `GLfloat`. This change is transparent to user code and is
-    /// described in detail on the [**removedTypes**](crate::context::Context::oxideremoved_types)
-    /// page.
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with an argument of [`GL_BLEND_COLOR`](crate::gl_enums::GL_BLEND_COLOR)
-    pub fn oxidegl_blend_color(
-        &mut self,
-        red: GLfloat,
-        green: GLfloat,
-        blue: GLfloat,
-        alpha: GLfloat,
-    ) -> GlFallible {
-        panic!("command oxidegl_blend_color not yet implemented");
-    }
-    /// ### Parameters
-    /// `target`
-    ///
-    /// > Target for color clamping. `target` must be [`GL_CLAMP_READ_COLOR`](crate::gl_enums::GL_CLAMP_READ_COLOR).
-    ///
-    /// `clamp`
-    ///
-    /// > Specifies whether to apply color clamping. `clamp` must be [`GL_TRUE`](crate::gl_enums::GL_TRUE)
-    /// > or [`GL_FALSE`](crate::gl_enums::GL_FALSE).
-    ///
-    /// ### Description
-    /// [**glClampColor**](crate::context::Context::oxidegl_clamp_color) controls
-    /// color clamping that is performed during [**glReadPixels**](crate::context::Context::oxidegl_read_pixels).
-    /// `target` must be [`GL_CLAMP_READ_COLOR`](crate::gl_enums::GL_CLAMP_READ_COLOR).
-    /// If `clamp` is [`GL_TRUE`](crate::gl_enums::GL_TRUE), read color clamping
-    /// is enabled; if `clamp` is [`GL_FALSE`](crate::gl_enums::GL_FALSE), read
-    /// color clamping is disabled. If `clamp` is [`GL_FIXED_ONLY`](crate::gl_enums::GL_FIXED_ONLY),
-    /// read color clamping is enabled only if the selected read buffer has fixed
-    /// point components and disabled otherwise.
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_CLAMP_READ_COLOR`](crate::gl_enums::GL_CLAMP_READ_COLOR).
-    pub fn oxidegl_clamp_color(&mut self, target: GLenum, clamp: ClampColorMode) -> GlFallible {
-        panic!("command oxidegl_clamp_color not yet implemented");
-    }
-    /// ### Parameters
-    /// `texture`
-    ///
-    /// > The name of an existing texture object containing the image to be cleared.
-    ///
-    /// `level`
-    ///
-    /// > The level of `texture` containing the region to be cleared.
-    ///
-    /// `format`
-    ///
-    /// > The format of the data whose address in memory is given by `data`.
-    ///
-    /// `type`
-    ///
-    /// > The type of the data whose address in memory is given by `data`.
-    ///
-    /// `data`
-    ///
-    /// > The address in memory of the data to be used to clear the specified region.
-    ///
-    /// ### Description
-    /// [**glClearTexImage**](crate::context::Context::oxidegl_clear_tex_image)
-    /// fills all an image contained in a texture with an application supplied
-    /// value. `texture` must be the name of an existing texture. Further, `texture`
-    /// may not be the name of a buffer texture, nor may its internal format be
-    /// compressed.
-    ///
-    /// `format` and `type` specify the format and type of the source data and
-    /// are interpreted as they are for [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d).
-    /// Textures with a base internal format of [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT),
-    /// [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX), or [`GL_DEPTH_STENCIL`](crate::gl_enums::GL_DEPTH_STENCIL)
-    /// require depth component, stencil, or depth-stencil component data respectively.
-    /// Textures with other base internal formats require RGBA formats. Textures
-    /// with integer internal formats require integer data.
-    ///
-    /// `data` is a pointer to an array of between one and four components of texel
-    /// data that will be used as the source for the constant fill value. The elements
-    /// of data are converted by the GL into the internal format of the texture
-    /// image (that was specified when the level was defined by any of the [**glTexImage***](crate::context::Context::oxidegl_tex_image*),
-    /// [**glTexStorage***](crate::context::Context::oxidegl_tex_storage*) or [**glCopyTexImage***](crate::context::Context::oxidegl_copy_tex_image*)
-    /// commands), and then used to fill the specified range of the destination
-    /// texture level. If `data` is [`NULL`](crate::gl_enums::NULL), then the pointer
-    /// is ignored and the sub-range of the texture image is filled with zeros.
-    /// If texture is a multisample texture, all the samples in a texel are cleared
-    /// to the value specified by data.
-    ///
-    /// ### Notes
-    /// [**glClearTexImage**](crate::context::Context::oxidegl_clear_tex_image)
-    /// is available only if the GL version is 4.4 or greater.
-    ///
-    /// ### Associated Gets
-    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image), [**glGetInternalformat**](crate::context::Context::oxidegl_get_internalformat)
-    pub unsafe fn oxidegl_clear_tex_image(
-        &mut self,
-        texture: GLuint,
-        level: GLint,
-        format: PixelFormat,
-        r#type: PixelType,
-        data: *const GLvoid,
-    ) -> GlFallible {
-        panic!("command oxidegl_clear_tex_image not yet implemented");
-    }
-    /// ### Parameters
-    /// `texture`
-    ///
-    /// > The name of an existing texture object containing the image to be cleared.
-    ///
-    /// `level`
-    ///
-    /// > The level of `texture` containing the region to be cleared.
-    ///
-    /// `xoffset`
-    ///
-    /// > The coordinate of the left edge of the region to be cleared.
-    ///
-    /// `yoffset`
-    ///
-    /// > The coordinate of the lower edge of the region to be cleared.
-    ///
-    /// `zoffset`
-    ///
-    /// > The coordinate of the front of the region to be cleared.
-    ///
-    /// `width`
-    ///
-    /// > The width of the region to be cleared.
-    ///
-    /// `height`
-    ///
-    /// > The height of the region to be cleared.
-    ///
-    /// `depth`
-    ///
-    /// > The depth of the region to be cleared.
-    ///
-    /// `format`
-    ///
-    /// > The format of the data whose address in memory is given by `data`.
-    ///
-    /// `type`
-    ///
-    /// > The type of the data whose address in memory is given by `data`.
-    ///
-    /// `data`
-    ///
-    /// > The address in memory of the data to be used to clear the specified region.
-    ///
-    /// ### Description
-    /// [**glClearTexSubImage**](crate::context::Context::oxidegl_clear_tex_sub_image)
-    /// fills all or part of an image contained in a texture with an application
-    /// supplied value. `texture` must be the name of an existing texture. Further,
-    /// `texture` may not be the name of a buffer texture, nor may its internal
-    /// format be compressed.
-    ///
-    /// Arguments `xoffset`, `yoffset`, and `zoffset` specify the lower left texel
-    /// coordinates of a width-wide by height-high by depth-deep rectangular subregion
-    /// of the texel array.
-    ///
-    /// For one-dimensional array textures, `yoffset` is interpreted as the first
-    /// layer to be cleared and `height` is the number of layers to clear. For
-    /// two-dimensional array textures, `zoffset` is interpreted as the first layer
-    /// to be cleared and `depth` is the number of layers to clear. Cube map textures
-    /// are treated as an array of six slices in the z-dimension, where the value
-    /// of `zoffset` is interpreted as specifying the cube map face for the corresponding
-    /// layer and `depth` is the number of faces to clear. For cube map array textures,
-    /// `zoffset` is the first layer-face to clear, and `depth` is the number of
-    /// layer-faces to clear. Each layer-face is translated into an array layer
-    /// and a cube map face as described in the OpenGL Specification.
-    ///
-    /// Negative values of `xoffset`, `yoffset`, and `zoffset` correspond to the
-    /// coordinates of border texels. Taking `[inlineq]` `[inlineq]` `[inlineq]`
-    /// `[inlineq]` `[inlineq]` `[inlineq]` `width`, `height`, `depth`, and the
-    /// border width, border height, and border depth of the texel array and taking
-    /// `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
-    /// `xoffset`, `yoffset`, `zoffset`, `width`, `height`, and `depth` argument
-    /// values, any of the following relationships generates a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION)
-    /// error:
-    ///
-    /// For texture types that do not have certain dimensions, this command treats
-    /// those dimensions as having a size of 1. For example, to clear a portion
-    /// of a two-dimensional texture, use `zoffset` equal to zero and `depth` equal
-    /// to one.
-    ///
-    /// `format` and `type` specify the format and type of the source data and
-    /// are interpreted as they are for [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d).
-    /// Textures with a base internal format of [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT),
-    /// [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX), or [`GL_DEPTH_STENCIL`](crate::gl_enums::GL_DEPTH_STENCIL)
-    /// require depth component, stencil, or depth-stencil component data respectively.
-    /// Textures with other base internal formats require RGBA formats. Textures
-    /// with integer internal formats require integer data.
-    ///
-    /// `data` is a pointer to an array of between one and four components of texel
-    /// data that will be used as the source for the constant fill value. The elements
-    /// of data are converted by the GL into the internal format of the texture
-    /// image (that was specified when the level was defined by any of the [**glTexImage***](crate::context::Context::oxidegl_tex_image*),
-    /// [**glTexStorage***](crate::context::Context::oxidegl_tex_storage*) or [**glCopyTexImage***](crate::context::Context::oxidegl_copy_tex_image*)
-    /// commands), and then used to fill the specified range of the destination
-    /// texture level. If `data` is [`NULL`](crate::gl_enums::NULL), then the pointer
-    /// is ignored and the sub-range of the texture image is filled with zeros.
-    /// If texture is a multisample texture, all the samples in a texel are cleared
-    /// to the value specified by data.
-    ///
-    /// ### Notes
-    /// [**glClearTexSubImage**](crate::context::Context::oxidegl_clear_tex_sub_image)
-    /// is available only if the GL version is 4.4 or greater.
-    ///
-    /// ### Associated Gets
-    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image), [**glGetInternalformat**](crate::context::Context::oxidegl_get_internalformat)
-    pub unsafe fn oxidegl_clear_tex_sub_image(
-        &mut self,
-        texture: GLuint,
-        level: GLint,
-        xoffset: GLint,
-        yoffset: GLint,
-        zoffset: GLint,
-        width: GLsizei,
-        height: GLsizei,
-        depth: GLsizei,
-        format: PixelFormat,
-        r#type: PixelType,
-        data: *const GLvoid,
-    ) -> GlFallible {
-        panic!("command oxidegl_clear_tex_sub_image not yet implemented");
-    }
-    /// ### Parameters
-    /// `sync`
-    ///
-    /// > The sync object whose status to wait on.
-    ///
-    /// `flags`
-    ///
-    /// > A bitfield controlling the command flushing behavior. `flags` may be [`GL_SYNC_FLUSH_COMMANDS_BIT`](crate::gl_enums::GL_SYNC_FLUSH_COMMANDS_BIT).
-    ///
-    /// `timeout`
-    ///
-    /// > The timeout, specified in nanoseconds, for which the implementation should
-    /// > wait for `sync` to become signaled.
-    ///
-    /// ### Description
-    /// [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
-    /// causes the client to block and wait for the sync object specified by `sync`
-    /// to become signaled. If `sync` is signaled when [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
-    /// is called, [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
-    /// returns immediately, otherwise it will block and wait for up to `timeout`
-    /// nanoseconds for `sync` to become signaled.
-    ///
-    /// The return value is one of four status values: [`GL_ALREADY_SIGNALED`](crate::gl_enums::GL_ALREADY_SIGNALED)
-    /// > indicates that `sync` was signaled at the time that [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
-    /// > was called.
-    ///
-    /// > [`GL_TIMEOUT_EXPIRED`](crate::gl_enums::GL_TIMEOUT_EXPIRED) indicates that
-    /// > at least `timeout` nanoseconds passed and `sync` did not become signaled.
-    ///
-    /// > [`GL_CONDITION_SATISFIED`](crate::gl_enums::GL_CONDITION_SATISFIED) indicates
-    /// > that `sync` was signaled before the timeout expired.
-    ///
-    /// > [`GL_WAIT_FAILED`](crate::gl_enums::GL_WAIT_FAILED) indicates that an error
-    /// > occurred. Additionally, an OpenGL error will be generated.
-    ///
-    ///
-    /// ### Notes
-    /// [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
-    /// is available only if the GL version is 3.2 or greater.
-    pub fn oxidegl_client_wait_sync(
-        &mut self,
-        sync: GLsync,
-        flags: GLbitfield,
-        timeout: GLuint64,
-    ) -> GlFallible<GLenum> {
-        panic!("command oxidegl_client_wait_sync not yet implemented");
-    }
-    /// ### Parameters
-    /// `origin`
-    ///
-    /// > Specifies the clip control origin. Must be one of [`GL_LOWER_LEFT`](crate::gl_enums::GL_LOWER_LEFT)
-    /// > or [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT).
-    ///
-    /// `depth`
-    ///
-    /// > Specifies the clip control depth mode. Must be one of [`GL_NEGATIVE_ONE_TO_ONE`](crate::gl_enums::GL_NEGATIVE_ONE_TO_ONE)
-    /// > or [`GL_ZERO_TO_ONE`](crate::gl_enums::GL_ZERO_TO_ONE).
-    ///
-    /// ### Description
-    /// [**glClipControl**](crate::context::Context::oxidegl_clip_control) controls
-    /// the clipping volume behavior and the clip coordinate to window coordinate
-    /// transformation behavior.
-    ///
-    /// The view volume is defined by $$z_{min} \leq `z_c` \leq `w_c`$$ where $z_{min}
-    ///= -`w_c`$ when `depth` is [`GL_NEGATIVE_ONE_TO_ONE`](crate::gl_enums::GL_NEGATIVE_ONE_TO_ONE),
-    /// and $z_{min}= 0$ when `depth` is [`GL_ZERO_TO_ONE`](crate::gl_enums::GL_ZERO_TO_ONE).
-    ///
-    /// The normalized device coordinate $`y_d`$ is given by $$`y_d`={{ f \times `y_c`
-    ///} \over `w_c` }$$ where $f= 1$ when `origin` is [`GL_LOWER_LEFT`](crate::gl_enums::GL_LOWER_LEFT),
-    /// and $f= -1$ when `origin` is [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT).
-    ///
-    /// The window coordinate $`z_w`$ is given by $$`z_w`= s \times `z_d`+ b$$ where
-    /// $s={{ f- n} \over 2 }$ and $b={ {n+ f} \over 2 }$ when `depth` is [`GL_NEGATIVE_ONE_TO_ONE`](crate::gl_enums::GL_NEGATIVE_ONE_TO_ONE),
-    /// and $s= f- n$ and $b= n$ when `depth` is [`GL_ZERO_TO_ONE`](crate::gl_enums::GL_ZERO_TO_ONE).
-    /// $n$ and $f$ are the near and far depth range values set with [**glDepthRange**](crate::context::Context::oxidegl_depth_range).
-    ///
-    /// Finally, the polygon area computation defined by [**`gl_FrontFacing`**](crate::context::Context::oxidegl__front_facing)
-    /// to determine if a polygon is front- or back-facing has its sign negated
-    /// when `origin` is [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT).
-    ///
-    /// ### Notes
-    /// The default GL clip volume definition is for a `origin` of [`GL_LOWER_LEFT`](crate::gl_enums::GL_LOWER_LEFT)
-    /// and a `depth` of [`GL_NEGATIVE_ONE_TO_ONE`](crate::gl_enums::GL_NEGATIVE_ONE_TO_ONE).
-    ///
-    /// An `origin` of [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT) and a
-    /// `depth` of [`GL_ZERO_TO_ONE`](crate::gl_enums::GL_ZERO_TO_ONE) corresponds
-    /// to Direct3D's clip volume definition.
-    ///
-    /// An `origin` of [`GL_UPPER_LEFT`](crate::gl_enums::GL_UPPER_LEFT) and a
-    /// `depth` of [`GL_NEGATIVE_ONE_TO_ONE`](crate::gl_enums::GL_NEGATIVE_ONE_TO_ONE)
-    /// corresponds to the upper-left origin of the window coordinate system of
-    /// Microsoft Windows and the X Window System.
-    ///
-    /// There is extensive discussion of the uses and further consequences of the
-    /// different clip volume settings in the
-    pub fn oxidegl_clip_control(
-        &mut self,
-        origin: ClipControlOrigin,
-        depth: ClipControlDepth,
-    ) -> GlFallible {
-        panic!("command oxidegl_clip_control not yet implemented");
-    }
-    /// ### Parameters
-    /// `target`
-    ///
-    /// > Specifies the target texture. Must be [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D)
-    /// > or [`GL_PROXY_TEXTURE_1D`](crate::gl_enums::GL_PROXY_TEXTURE_1D).
-    ///
-    /// `level`
-    ///
-    /// > Specifies the level-of-detail number. Level 0 is the base image level.
-    /// > Level *n* is the *n* th mipmap reduction image.
-    ///
-    /// `internalformat`
-    ///
-    /// > Specifies the format of the compressed image data stored at address `data`.
-    ///
-    /// `width`
-    ///
-    /// > Specifies the width of the texture image. All implementations support texture
-    /// > images that are at least 64 texels wide. The height of the 1D texture image
-    /// > is 1.
-    ///
-    /// `border`
-    ///
-    /// > This value must be 0.
-    ///
-    /// `imageSize`
-    ///
-    /// > Specifies the number of unsigned bytes of image data starting at the address
-    /// > specified by `data`.
-    ///
-    /// `data`
-    ///
-    /// > Specifies a pointer to the compressed image data in memory.
-    ///
-    /// ### Description
-    /// Texturing allows elements of an image array to be read by shaders.
-    ///
-    /// [**glCompressedTexImage1D**](crate::context::Context::oxidegl_compressed_tex_image1_d)
-    /// loads a previously defined, and retrieved, compressed one-dimensional texture
-    /// image if `target` is [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D)
-    /// (see [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d)
-    /// ).
-    ///
-    /// If `target` is [`GL_PROXY_TEXTURE_1D`](crate::gl_enums::GL_PROXY_TEXTURE_1D),
-    /// no data is read from `data`, but all of the texture image state is recalculated,
-    /// checked for consistency, and checked against the implementation's capabilities.
-    /// If the implementation cannot handle a texture of the requested texture
-    /// size, it sets all of the image state to 0, but does not generate an error
-    /// (see [**glGetError**](crate::context::Context::oxidegl_get_error) ). To
-    /// query for an entire mipmap array, use an image array level greater than
-    /// or equal to 1.
-    ///
-    /// `internalformat` must be an extension-specified compressed-texture format.
-    /// When a texture is loaded with [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d)
-    /// using a generic compressed texture format (e.g., [`GL_COMPRESSED_RGB`](crate::gl_enums::GL_COMPRESSED_RGB))
-    /// the GL selects from one of its extensions supporting compressed textures.
-    /// In order to load the compressed texture image using [**glCompressedTexImage1D**](crate::context::Context::oxidegl_compressed_tex_image1_d),
-    /// query the compressed texture image's size and format using [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
-    ///
-    /// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER)
-    /// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
-    /// while a texture image is specified, `data` is treated as a byte offset
-    /// into the buffer object's data store.
-    ///
-    /// If the compressed data are arranged into fixed-size blocks of texels, the
-    /// pixel storage modes can be used to select a sub-rectangle from a larger
-    /// containing rectangle. These pixel storage modes operate in the same way
-    /// as they do for [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d).
-    /// In the following description, denote by `[inlineq]` `[inlineq]` `[inlineq]`
-    /// `[inlineq]` [`GL_UNPACK_COMPRESSED_BLOCK_SIZE`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_SIZE),
-    /// [`GL_UNPACK_COMPRESSED_BLOCK_WIDTH`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_WIDTH),
-    /// [`GL_UNPACK_COMPRESSED_BLOCK_HEIGHT`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_HEIGHT),
-    /// and [`GL_UNPACK_COMPRESSED_BLOCK_DEPTH`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_DEPTH),
-    /// respectively. `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
-    ///
-    /// By default the pixel storage modes [`GL_UNPACK_ROW_LENGTH`](crate::gl_enums::GL_UNPACK_ROW_LENGTH),
-    /// [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS), [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS),
-    /// [`GL_UNPACK_IMAGE_HEIGHT`](crate::gl_enums::GL_UNPACK_IMAGE_HEIGHT) and
-    /// [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES) are ignored
-    /// for compressed images. To enable [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS)
-    /// and [`GL_UNPACK_ROW_LENGTH`](crate::gl_enums::GL_UNPACK_ROW_LENGTH), `[inlineq]`
-    /// `[inlineq]` [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS)
-    /// and [`GL_UNPACK_IMAGE_HEIGHT`](crate::gl_enums::GL_UNPACK_IMAGE_HEIGHT),
-    /// `[inlineq]` [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES),
-    /// `[inlineq]`
-    ///
-    /// When selecting a sub-rectangle from a compressed image, the value of [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS)
-    /// > must be a multiple of `[inlineq]`
-    ///
-    ///
-    /// `imageSize` must be equal to:
-    ///
-    /// `[inlineq]`
-    ///
-    /// ### Associated Gets
-    /// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_COMPRESSED`](crate::gl_enums::GL_TEXTURE_COMPRESSED)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_NUM_COMPRESSED_TEXTURE_FORMATS`](crate::gl_enums::GL_NUM_COMPRESSED_TEXTURE_FORMATS)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_COMPRESSED_TEXTURE_FORMATS`](crate::gl_enums::GL_COMPRESSED_TEXTURE_FORMATS)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
-    ///
-    /// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
-    /// with arguments [`GL_TEXTURE_INTERNAL_FORMAT`](crate::gl_enums::GL_TEXTURE_INTERNAL_FORMAT)
-    /// and [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::gl_enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
-    pub unsafe fn oxidegl_compressed_tex_image1_d(
-        &mut self,
-        target: TextureTarget,
-        level: GLint,
-        internalformat: InternalFormat,
-        width: GLsizei,
-        border: GLint,
-        image_size: GLsizei,
-        data: *const GLvoid,
-    ) -> GlFallible {
-        panic!("command oxidegl_compressed_tex_image1_d not yet implemented");
-    }
-    /// ### Parameters
-    /// `target`
-    ///
-    /// > Specifies the target texture. Must be [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D),
-    /// > [`GL_PROXY_TEXTURE_2D`](crate::gl_enums::GL_PROXY_TEXTURE_2D), [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY),
-    /// > [`GL_PROXY_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_PROXY_TEXTURE_1D_ARRAY),
-    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
-    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
-    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
-    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
-    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
-    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z),
-    /// > or [`GL_PROXY_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_PROXY_TEXTURE_CUBE_MAP).
-    ///
-    /// `level`
-    ///
-    /// > Specifies the level-of-detail number. Level 0 is the base image level.
-    /// > Level *n* is the *n* th mipmap reduction image.
-    ///
-    /// `internalformat`
-    ///
-    /// > Specifies the format of the compressed image data stored at address `data`.
-    ///
-    /// `width`
-    ///
-    /// > Specifies the width of the texture image. All implementations support 2D
-    /// > texture and cube map texture images that are at least 16384 texels wide.
-    ///
-    /// `height`
-    ///
-    /// > Specifies the height of the texture image. All implementations support
-    /// > 2D texture and cube map texture images that are at least 16384 texels high.
-    ///
-    /// `border`
-    ///
-    /// > This value must be 0.
-    ///
-    /// `imageSize`
-    ///
-    /// > Specifies the number of unsigned bytes of image data starting at the address
-    /// > specified by `data`.
-    ///
-    /// `data`
-    ///
-    /// > Specifies a pointer to the compressed image data in memory.
-    ///
-    /// ### Description
-    /// Texturing allows elements of an image array to be read by shaders.
-    ///
-    /// [**glCompressedTexImage2D**](crate::context::Context::oxidegl_compressed_tex_image2_d)
-    /// loads a previously defined, and retrieved, compressed two-dimensional texture
-    /// image if `target` is [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D),
-    /// or one of the cube map faces such as [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X).
-    /// (see [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d)
-    /// ).
-    ///
-    /// If `target` is [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY),
-    /// `data` is treated as an array of compressed 1D textures.
-    ///
-    /// If `target` is [`GL_PROXY_TEXTURE_2D`](crate::gl_enums::GL_PROXY_TEXTURE_2D),
-    /// [`GL_PROXY_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_PROXY_TEXTURE_1D_ARRAY)
-    /// or [`GL_PROXY_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_PROXY_TEXTURE_CUBE_MAP),
-    /// no data is read from `data`, but all of the texture image state is recalculated,
-    /// checked for consistency, and checked against the implementation's capabilities.
-    /// If the implementation cannot handle a texture of the requested texture
-    /// size, it sets all of the image state to 0, but does not generate an error
-    /// (see [**glGetError**](crate::context::Context::oxidegl_get_error) ). To
-    /// query for an entire mipmap array, use an image array level greater than
-    /// or equal to 1.
-    ///
-    /// `internalformat` must be a known compressed image format (such as [`GL_RGTC`](crate::gl_enums::GL_RGTC))
-    /// or an extension-specified compressed-texture format. When a texture is
-    /// loaded with [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d)
-    /// using a generic compressed texture format (e.g., [`GL_COMPRESSED_RGB`](crate::gl_enums::GL_COMPRESSED_RGB)
-    /// ), the GL selects from one of its extensions supporting compressed textures.
-    /// In order to load the compressed texture image using [**glCompressedTexImage2D**](crate::context::Context::oxidegl_compressed_tex_image2_d),
-    /// query the compressed texture image's size and format using [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
-    ///
-    /// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER)
-    /// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
-    /// while a texture image is specified, `data` is treated as a byte offset
-    /// into the buffer object's data store.
-    ///
-    /// If the compressed data are arranged into fixed-size blocks of texels, the
-    /// pixel storage modes can be used to select a sub-rectangle from a larger
-    /// containing rectangle. These pixel storage modes operate in the same way
-    /// as they do for [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d).
-    /// In the following description, denote by `[inlineq]` `[inlineq]` `[inlineq]`
-    /// `[inlineq]` [`GL_UNPACK_COMPRESSED_BLOCK_SIZE`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_SIZE),
-    /// [`GL_UNPACK_COMPRESSED_BLOCK_WIDTH`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_WIDTH),
-    /// [`GL_UNPACK_COMPRESSED_BLOCK_HEIGHT`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_HEIGHT),
-    /// and [`GL_UNPACK_COMPRESSED_BLOCK_DEPTH`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_DEPTH),
-    /// respectively. `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
-    ///
-    /// By default the pixel storage modes [`GL_UNPACK_ROW_LENGTH`](crate::gl_enums::GL_UNPACK_ROW_LENGTH),
-    /// [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS), [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS),
-    /// [`GL_UNPACK_IMAGE_HEIGHT`](crate::gl_enums::GL_UNPACK_IMAGE_HEIGHT) and
-    /// [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES) are ignored
-    /// for compressed images. To enable [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS)
-    /// and [`GL_UNPACK_ROW_LENGTH`](crate::gl_enums::GL_UNPACK_ROW_LENGTH), `[inlineq]`
-    /// `[inlineq]` [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS)
-    /// and [`GL_UNPACK_IMAGE_HEIGHT`](crate::gl_enums::GL_UNPACK_IMAGE_HEIGHT),
-    /// `[inlineq]` [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES),
-    /// `[inlineq]`
-    ///
-    /// When selecting a sub-rectangle from a compressed image: The value of [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS)
-    /// > must be a multiple of `[inlineq]`
-    ///
-    /// > the value of [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS)
-    /// > must be a multiple of `[inlineq]`
-    ///
-    ///
-    /// `imageSize` must be equal to:
-    ///
-    /// `[inlineq]`
-    ///
-    /// ### Notes
-    /// The specific compressed internal formats [`GL_COMPRESSED_RGB8_ETC2`](crate::gl_enums::GL_COMPRESSED_RGB8_ETC2),
-    /// [`GL_COMPRESSED_SRGB8_ETC2`](crate::gl_enums::GL_COMPRESSED_SRGB8_ETC2),
-    /// [`GL_COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2`](crate::gl_enums::GL_COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2),
-    /// [`GL_COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2`](crate::gl_enums::GL_COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2),
-    /// [`GL_COMPRESSED_RGBA8_ETC2_EAC`](crate::gl_enums::GL_COMPRESSED_RGBA8_ETC2_EAC),
-    /// [`GL_COMPRESSED_SRGB8_ALPHA8_ETC2_EAC`](crate::gl_enums::GL_COMPRESSED_SRGB8_ALPHA8_ETC2_EAC),
-    /// [`GL_COMPRESSED_R11_EAC`](crate::gl_enums::GL_COMPRESSED_R11_EAC), [`GL_COMPRESSED_SIGNED_R11_EAC`](crate::gl_enums::GL_COMPRESSED_SIGNED_R11_EAC),
-    /// [`GL_COMPRESSED_RG11_EAC`](crate::gl_enums::GL_COMPRESSED_RG11_EAC), and
-    /// [`GL_COMPRESSED_SIGNED_RG11_EAC`](crate::gl_enums::GL_COMPRESSED_SIGNED_RG11_EAC)
-    /// are available only if the GL version is 4.3 or higher.
-    ///
-    /// ### Associated Gets
-    /// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_COMPRESSED`](crate::gl_enums::GL_TEXTURE_COMPRESSED)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
-    ///
-    /// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
-    /// with arguments [`GL_TEXTURE_INTERNAL_FORMAT`](crate::gl_enums::GL_TEXTURE_INTERNAL_FORMAT)
-    /// and [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::gl_enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
-    pub unsafe fn oxidegl_compressed_tex_image2_d(
-        &mut self,
-        target: TextureTarget,
-        level: GLint,
-        internalformat: InternalFormat,
-        width: GLsizei,
-        height: GLsizei,
-        border: GLint,
-        image_size: GLsizei,
-        data: *const GLvoid,
-    ) -> GlFallible {
-        panic!("command oxidegl_compressed_tex_image2_d not yet implemented");
-    }
-    /// ### Parameters
-    /// `target`
-    ///
-    /// > Specifies the target texture. Must be [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D),
-    /// > [`GL_PROXY_TEXTURE_3D`](crate::gl_enums::GL_PROXY_TEXTURE_3D), [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY)
-    /// > or [`GL_PROXY_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_PROXY_TEXTURE_2D_ARRAY).
-    ///
-    /// `level`
-    ///
-    /// > Specifies the level-of-detail number. Level 0 is the base image level.
-    /// > Level *n* is the *n* th mipmap reduction image.
-    ///
-    /// `internalformat`
-    ///
-    /// > Specifies the format of the compressed image data stored at address `data`.
-    ///
-    /// `width`
-    ///
-    /// > Specifies the width of the texture image. All implementations support 3D
-    /// > texture images that are at least 16 texels wide.
-    ///
-    /// `height`
-    ///
-    /// > Specifies the height of the texture image. All implementations support
-    /// > 3D texture images that are at least 16 texels high.
-    ///
-    /// `depth`
-    ///
-    /// > Specifies the depth of the texture image. All implementations support 3D
-    /// > texture images that are at least 16 texels deep.
-    ///
-    /// `border`
-    ///
-    /// > This value must be 0.
-    ///
-    /// `imageSize`
-    ///
-    /// > Specifies the number of unsigned bytes of image data starting at the address
-    /// > specified by `data`.
-    ///
-    /// `data`
-    ///
-    /// > Specifies a pointer to the compressed image data in memory.
-    ///
-    /// ### Description
-    /// Texturing allows elements of an image array to be read by shaders.
-    ///
-    /// [**glCompressedTexImage3D**](crate::context::Context::oxidegl_compressed_tex_image3_d)
-    /// loads a previously defined, and retrieved, compressed three-dimensional
-    /// texture image if `target` is [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D)
-    /// (see [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d)
-    /// ).
-    ///
-    /// If `target` is [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY),
-    /// `data` is treated as an array of compressed 2D textures.
-    ///
-    /// If `target` is [`GL_PROXY_TEXTURE_3D`](crate::gl_enums::GL_PROXY_TEXTURE_3D)
-    /// or [`GL_PROXY_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_PROXY_TEXTURE_2D_ARRAY),
-    /// no data is read from `data`, but all of the texture image state is recalculated,
-    /// checked for consistency, and checked against the implementation's capabilities.
-    /// If the implementation cannot handle a texture of the requested texture
-    /// size, it sets all of the image state to 0, but does not generate an error
-    /// (see [**glGetError**](crate::context::Context::oxidegl_get_error) ). To
-    /// query for an entire mipmap array, use an image array level greater than
-    /// or equal to 1.
-    ///
-    /// `internalformat` must be a known compressed image format (such as [`GL_RGTC`](crate::gl_enums::GL_RGTC))
-    /// or an extension-specified compressed-texture format. When a texture is
-    /// loaded with [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d)
-    /// using a generic compressed texture format (e.g., [`GL_COMPRESSED_RGB`](crate::gl_enums::GL_COMPRESSED_RGB)
-    /// ), the GL selects from one of its extensions supporting compressed textures.
-    /// In order to load the compressed texture image using [**glCompressedTexImage3D**](crate::context::Context::oxidegl_compressed_tex_image3_d),
-    /// query the compressed texture image's size and format using [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
-    ///
-    /// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER)
-    /// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
-    /// while a texture image is specified, `data` is treated as a byte offset
-    /// into the buffer object's data store.
-    ///
-    /// If the compressed data are arranged into fixed-size blocks of texels, the
-    /// pixel storage modes can be used to select a sub-rectangle from a larger
-    /// containing rectangle. These pixel storage modes operate in the same way
-    /// as they do for [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d).
-    /// In the following description, denote by `[inlineq]` `[inlineq]` `[inlineq]`
-    /// `[inlineq]` [`GL_UNPACK_COMPRESSED_BLOCK_SIZE`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_SIZE),
-    /// [`GL_UNPACK_COMPRESSED_BLOCK_WIDTH`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_WIDTH),
-    /// [`GL_UNPACK_COMPRESSED_BLOCK_HEIGHT`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_HEIGHT),
-    /// and [`GL_UNPACK_COMPRESSED_BLOCK_DEPTH`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_DEPTH),
-    /// respectively. `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
-    ///
-    /// By default the pixel storage modes [`GL_UNPACK_ROW_LENGTH`](crate::gl_enums::GL_UNPACK_ROW_LENGTH),
-    /// [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS), [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS),
-    /// [`GL_UNPACK_IMAGE_HEIGHT`](crate::gl_enums::GL_UNPACK_IMAGE_HEIGHT) and
-    /// [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES) are ignored
-    /// for compressed images. To enable [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS)
-    /// and [`GL_UNPACK_ROW_LENGTH`](crate::gl_enums::GL_UNPACK_ROW_LENGTH), `[inlineq]`
-    /// `[inlineq]` [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS)
-    /// and [`GL_UNPACK_IMAGE_HEIGHT`](crate::gl_enums::GL_UNPACK_IMAGE_HEIGHT),
-    /// `[inlineq]` [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES),
-    /// `[inlineq]`
-    ///
-    /// When selecting a sub-rectangle from a compressed image: the value of [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS)
-    /// > must be a multiple of `[inlineq]`
-    ///
-    /// > the value of [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS)
-    /// > must be a multiple of `[inlineq]`
-    ///
-    /// > the value of [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES)
-    /// > must be a multiple of `[inlineq]`
-    ///
-    ///
-    /// `imageSize` must be equal to:
-    ///
-    /// `[inlineq]`
-    ///
-    /// ### Associated Gets
-    /// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_COMPRESSED`](crate::gl_enums::GL_TEXTURE_COMPRESSED)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
-    ///
-    /// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
-    /// with arguments [`GL_TEXTURE_INTERNAL_FORMAT`](crate::gl_enums::GL_TEXTURE_INTERNAL_FORMAT)
-    /// and [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::gl_enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
-    pub unsafe fn oxidegl_compressed_tex_image3_d(
-        &mut self,
-        target: TextureTarget,
-        level: GLint,
-        internalformat: InternalFormat,
-        width: GLsizei,
-        height: GLsizei,
-        depth: GLsizei,
-        border: GLint,
-        image_size: GLsizei,
-        data: *const GLvoid,
-    ) -> GlFallible {
-        panic!("command oxidegl_compressed_tex_image3_d not yet implemented");
-    }
-    /// ### Parameters
-    /// `srcName`
-    ///
-    /// > The name of a texture or renderbuffer object from which to copy.
-    ///
-    /// `srcTarget`
-    ///
-    /// > The target representing the namespace of the source name `srcName`.
-    ///
-    /// `srcLevel`
-    ///
-    /// > The mipmap level to read from the source.
-    ///
-    /// `srcX`
-    ///
-    /// > The X coordinate of the left edge of the souce region to copy.
-    ///
-    /// `srcY`
-    ///
-    /// > The Y coordinate of the top edge of the souce region to copy.
-    ///
-    /// `srcZ`
-    ///
-    /// > The Z coordinate of the near edge of the souce region to copy.
-    ///
-    /// `dstName`
-    ///
-    /// > The name of a texture or renderbuffer object to which to copy.
-    ///
-    /// `dstTarget`
-    ///
-    /// > The target representing the namespace of the destination name `dstName`.
-    ///
-    /// `dstX`
-    ///
-    /// > The X coordinate of the left edge of the destination region.
-    ///
-    /// `dstY`
-    ///
-    /// > The Y coordinate of the top edge of the destination region.
-    ///
-    /// `dstZ`
-    ///
-    /// > The Z coordinate of the near edge of the destination region.
-    ///
-    /// `srcWidth`
-    ///
-    /// > The width of the region to be copied.
-    ///
-    /// `srcHeight`
-    ///
-    /// > The height of the region to be copied.
-    ///
-    /// `srcDepth`
-    ///
-    /// > The depth of the region to be copied.
-    ///
-    /// ### Description
-    /// [**glCopyImageSubData**](crate::context::Context::oxidegl_copy_image_sub_data)
-    /// may be used to copy data from one image (i.e. texture or renderbuffer)
-    /// to another. [**glCopyImageSubData**](crate::context::Context::oxidegl_copy_image_sub_data)
-    /// does not perform general-purpose conversions such as scaling, resizing,
-    /// blending, color-space, or format conversions. It should be considered to
-    /// operate in a manner similar to a CPU memcpy. `CopyImageSubData` can copy
-    /// between images with different internal formats, provided the formats are
-    /// compatible.
-    ///
-    /// [**glCopyImageSubData**](crate::context::Context::oxidegl_copy_image_sub_data)
-    /// also allows copying between certain types of compressed and uncompressed
-    /// internal formats. This copy does not perform on-the-fly compression or
-    /// decompression. When copying from an uncompressed internal format to a compressed
-    /// internal format, each texel of uncompressed data becomes a single block
-    /// of compressed data. When copying from a compressed internal format to an
-    /// uncompressed internal format, a block of compressed data becomes a single
-    /// texel of uncompressed data. The texel size of the uncompressed format must
-    /// be the same size the block size of the compressed formats. Thus it is permitted
-    /// to copy between a 128-bit uncompressed format and a compressed format which
-    /// uses 8-bit 4x4 blocks, or between a 64-bit uncompressed format and a compressed
-    /// format which uses 4-bit 4x4 blocks.
-    ///
-    /// The source object is identified by `srcName` and `srcTarget` and the destination
-    /// object is identified by `dstName` and `dstTarget`. The interpretation of
-    /// the name depends on the value of the corresponding `target` parameter.
-    /// If `target` is [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER), the
-    /// name is interpreted as the name of a renderbuffer object. If the target
-    /// parameter is a texture target, the name is interpreted as a texture object.
-    /// All non-proxy texture targets are accepted, with the exception of [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER)
-    /// and the cubemap face selectors.
-    ///
-    /// `srcLevel` and `dstLevel` identify the source and destination level of
-    /// detail. For textures, this must be a valid level of detail in the texture
-    /// object. For renderbuffers, this value must be zero.
-    ///
-    /// `srcX`, `srcY`, and `srcZ` specify the lower left texel coordinates of
-    /// a `srcWidth` -wide by `srcHeight` -high by `srcDepth` -deep rectangular
-    /// subregion of the source texel array. Similarly, `dstX`, `dstY` and `dstZ`
-    /// specify the coordinates of a subregion of the destination texel array.
-    /// The source and destination subregions must be contained entirely within
-    /// the specified level of the corresponding image objects.
-    ///
-    /// The dimensions are always specified in texels, even for compressed texture
-    /// formats. However, it should be noted that if only one of the source and
-    /// destination textures is compressed then the number of texels touched in
-    /// the compressed image will be a factor of the block size larger than in
-    /// the uncompressed image.
-    ///
-    /// Slices of a [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY),
-    /// [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY)
-    /// [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D) and faces of [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP)
-    /// are all compatible provided they share a compatible internal format, and
-    /// multiple slices or faces may be copied between these objects with a single
-    /// call by specifying the starting slice with `srcZ` and `dstZ`, and the number
-    /// of slices to be copied with `srcDepth`. Cubemap textures always have six
-    /// faces which are selected by a zero-based face index.
-    ///
-    /// For the purposes of `CopyImageSubData`, two internal formats are considered
-    /// compatible if any of the following conditions are met: the formats are
-    /// > the same,
-    ///
-    /// > the formats are considered compatible according to the compatibility rules
-    /// > used for texture views as defined in section 3.9.X. In particular, if both
-    /// > internal formats are listed in the same entry of Table 3.X.2, they are
-    /// > considered compatible, or
-    ///
-    /// > one format is compressed and the other is uncompressed and Table 4.X.1
-    /// > lists the two formats in the same row.
-    ///
-    ///
-    /// |* Texel/ Block Size*                             |* Uncompressed Internal Format*                                   |* Compressed Internal Format(s)*                                   |
-    /// |-------------------------------------------------|------------------------------------------------------------------|-------------------------------------------------------------------|
-    /// | 64-bit                                          | [`GL_RGBA32UI`](crate::gl_enums::GL_RGBA32UI), [`GL_RGBA32I`](crate::gl_enums::GL_RGBA32I), [`GL_RGBA32F`](crate::gl_enums::GL_RGBA32F) | [`GL_COMPRESSED_RGBA_S3TC_DXT3_EXT`](crate::gl_enums::GL_COMPRESSED_RGBA_S3TC_DXT3_EXT), [`GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT`](crate::gl_enums::GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT), [`GL_COMPRESSED_RGBA_S3TC_DXT5_EXT`](crate::gl_enums::GL_COMPRESSED_RGBA_S3TC_DXT5_EXT), [`GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT`](crate::gl_enums::GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT), [`GL_COMPRESSED_RG_RGTC2`](crate::gl_enums::GL_COMPRESSED_RG_RGTC2), [`GL_COMPRESSED_SIGNED_RG_RGTC2`](crate::gl_enums::GL_COMPRESSED_SIGNED_RG_RGTC2), [`GL_COMPRESSED_RGBA_BPTC_UNORM`](crate::gl_enums::GL_COMPRESSED_RGBA_BPTC_UNORM), [`GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM`](crate::gl_enums::GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM), [`GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT`](crate::gl_enums::GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT), [`GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT`](crate::gl_enums::GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT) |
-    /// | 128-bit                                         | [`GL_RGBA16UI`](crate::gl_enums::GL_RGBA16UI), [`GL_RGBA16I`](crate::gl_enums::GL_RGBA16I), [`GL_RGBA16F`](crate::gl_enums::GL_RGBA16F), [`GL_RG32F`](crate::gl_enums::GL_RG32F), [`GL_RG32UI`](crate::gl_enums::GL_RG32UI), [`GL_RG32I`](crate::gl_enums::GL_RG32I), [`GL_RGBA16`](crate::gl_enums::GL_RGBA16), [`GL_RGBA16_SNORM`](crate::gl_enums::GL_RGBA16_SNORM) | [`GL_COMPRESSED_RGB_S3TC_DXT1_EXT`](crate::gl_enums::GL_COMPRESSED_RGB_S3TC_DXT1_EXT), [`GL_COMPRESSED_SRGB_S3TC_DXT1_EXT`](crate::gl_enums::GL_COMPRESSED_SRGB_S3TC_DXT1_EXT), [`GL_COMPRESSED_RGBA_S3TC_DXT1_EXT`](crate::gl_enums::GL_COMPRESSED_RGBA_S3TC_DXT1_EXT), [`GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT`](crate::gl_enums::GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT), [`GL_COMPRESSED_RED_RGTC1`](crate::gl_enums::GL_COMPRESSED_RED_RGTC1), [`GL_COMPRESSED_SIGNED_RED_RGTC1`](crate::gl_enums::GL_COMPRESSED_SIGNED_RED_RGTC1) |
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_COMPUTE_WORK_GROUP_COUNT`](crate::gl_enums::GL_MAX_COMPUTE_WORK_GROUP_COUNT)
-    pub fn oxidegl_copy_image_sub_data(
-        &mut self,
-        src_name: GLuint,
-        src_target: CopyImageSubDataTarget,
-        src_level: GLint,
-        src_x: GLint,
-        src_y: GLint,
-        src_z: GLint,
-        dst_name: GLuint,
-        dst_target: CopyImageSubDataTarget,
-        dst_level: GLint,
-        dst_x: GLint,
-        dst_y: GLint,
-        dst_z: GLint,
-        src_width: GLsizei,
-        src_height: GLsizei,
-        src_depth: GLsizei,
-    ) -> GlFallible {
-        panic!("command oxidegl_copy_image_sub_data not yet implemented");
-    }
-    /// ### Parameters
-    /// `target`
-    ///
-    /// > Specifies the target texture. Must be [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D).
-    ///
-    /// `level`
-    ///
-    /// > Specifies the level-of-detail number. Level 0 is the base image level.
-    /// > Level *n* is the *n* th mipmap reduction image.
-    ///
-    /// `internalformat`
-    ///
-    /// > Specifies the internal format of the texture. Must be one of the following
-    /// > symbolic constants: [`GL_COMPRESSED_RED`](crate::gl_enums::GL_COMPRESSED_RED),
-    /// > [`GL_COMPRESSED_RG`](crate::gl_enums::GL_COMPRESSED_RG), [`GL_COMPRESSED_RGB`](crate::gl_enums::GL_COMPRESSED_RGB),
-    /// > [`GL_COMPRESSED_RGBA`](crate::gl_enums::GL_COMPRESSED_RGBA). [`GL_COMPRESSED_SRGB`](crate::gl_enums::GL_COMPRESSED_SRGB),
-    /// > [`GL_COMPRESSED_SRGB_ALPHA`](crate::gl_enums::GL_COMPRESSED_SRGB_ALPHA).
-    /// > [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT), [`GL_DEPTH_COMPONENT16`](crate::gl_enums::GL_DEPTH_COMPONENT16),
-    /// > [`GL_DEPTH_COMPONENT24`](crate::gl_enums::GL_DEPTH_COMPONENT24), [`GL_DEPTH_COMPONENT32`](crate::gl_enums::GL_DEPTH_COMPONENT32),
-    /// > [`GL_STENCIL_INDEX8`](crate::gl_enums::GL_STENCIL_INDEX8), [`GL_RED`](crate::gl_enums::GL_RED),
-    /// > [`GL_RG`](crate::gl_enums::GL_RG), [`GL_RGB`](crate::gl_enums::GL_RGB),
-    /// > [`GL_R3_G3_B2`](crate::gl_enums::GL_R3_G3_B2), [`GL_RGB4`](crate::gl_enums::GL_RGB4),
-    /// > [`GL_RGB5`](crate::gl_enums::GL_RGB5), [`GL_RGB8`](crate::gl_enums::GL_RGB8),
-    /// > [`GL_RGB10`](crate::gl_enums::GL_RGB10), [`GL_RGB12`](crate::gl_enums::GL_RGB12),
-    /// > [`GL_RGB16`](crate::gl_enums::GL_RGB16), [`GL_RGBA`](crate::gl_enums::GL_RGBA),
-    /// > [`GL_RGBA2`](crate::gl_enums::GL_RGBA2), [`GL_RGBA4`](crate::gl_enums::GL_RGBA4),
-    /// > [`GL_RGB5_A1`](crate::gl_enums::GL_RGB5_A1), [`GL_RGBA8`](crate::gl_enums::GL_RGBA8),
-    /// > [`GL_RGB10_A2`](crate::gl_enums::GL_RGB10_A2), [`GL_RGBA12`](crate::gl_enums::GL_RGBA12),
-    /// > [`GL_RGBA16`](crate::gl_enums::GL_RGBA16), [`GL_SRGB`](crate::gl_enums::GL_SRGB),
-    /// > [`GL_SRGB8`](crate::gl_enums::GL_SRGB8), [`GL_SRGB_ALPHA`](crate::gl_enums::GL_SRGB_ALPHA),
-    /// > or [`GL_SRGB8_ALPHA8`](crate::gl_enums::GL_SRGB8_ALPHA8).
-    ///
-    /// `x`
-    ///
-    /// `y`
-    ///
-    /// > Specify the window coordinates of the left corner of the row of pixels
-    /// > to be copied.
-    ///
-    /// `width`
-    ///
-    /// > Specifies the width of the texture image. The height of the texture image
-    /// > is 1.
-    ///
-    /// `border`
-    ///
-    /// > Must be 0.
-    ///
-    /// ### Description
-    /// [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d)
-    /// defines a one-dimensional texture image with pixels from the current [`GL_READ_BUFFER`](crate::gl_enums::GL_READ_BUFFER).
-    ///
-    /// The screen-aligned pixel row with left corner at `[inlineq]` `[inlineq]`
-    /// `level`. `internalformat` specifies the internal format of the texture
-    /// array.
-    ///
-    /// The pixels in the row are processed exactly as if [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
-    /// had been called, but the process stops just before final conversion. At
-    /// this point all pixel component values are clamped to the range `[inlineq]`
-    ///
-    /// Pixel ordering is such that lower `[inlineq]`
-    ///
-    /// If any of the pixels within the specified row of the current [`GL_READ_BUFFER`](crate::gl_enums::GL_READ_BUFFER)
-    /// are outside the window associated with the current rendering context, then
-    /// the values obtained for those pixels are undefined.
-    ///
-    /// [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d)
-    /// defines a one-dimensional texture image with pixels from the current [`GL_READ_BUFFER`](crate::gl_enums::GL_READ_BUFFER).
-    ///
-    /// When `internalformat` is one of the sRGB types, the GL does not automatically
-    /// convert the source pixels to the sRGB color space. In this case, the [**glPixelMap**](crate::context::Context::oxidegl_pixel_map)
-    /// function can be used to accomplish the conversion.
-    ///
-    /// ### Notes
-    /// 1, 2, 3, and 4 are not accepted values for `internalformat`.
-    ///
-    /// An image with 0 width indicates a NULL texture.
-    ///
-    /// [`GL_STENCIL_INDEX8`](crate::gl_enums::GL_STENCIL_INDEX8) is accepted for
-    /// `internalformat` only if the GL version is 4.4 or higher.
-    ///
-    /// ### Associated Gets
-    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
-    pub fn oxidegl_copy_tex_image1_d(
-        &mut self,
-        target: TextureTarget,
-        level: GLint,
-        internalformat: InternalFormat,
-        x: GLint,
-        y: GLint,
-        width: GLsizei,
-        border: GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_copy_tex_image1_d not yet implemented");
-    }
-    /// ### Parameters
-    /// `target`
-    ///
-    /// > Specifies the target texture. Must be [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D),
-    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
-    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
-    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
-    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
-    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
-    /// > or [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z).
-    ///
-    /// `level`
-    ///
-    /// > Specifies the level-of-detail number. Level 0 is the base image level.
-    /// > Level *n* is the *n* th mipmap reduction image.
-    ///
-    /// `internalformat`
-    ///
-    /// > Specifies the internal format of the texture. Must be one of the following
-    /// > symbolic constants: [`GL_COMPRESSED_RED`](crate::gl_enums::GL_COMPRESSED_RED),
-    /// > [`GL_COMPRESSED_RG`](crate::gl_enums::GL_COMPRESSED_RG), [`GL_COMPRESSED_RGB`](crate::gl_enums::GL_COMPRESSED_RGB),
-    /// > [`GL_COMPRESSED_RGBA`](crate::gl_enums::GL_COMPRESSED_RGBA). [`GL_COMPRESSED_SRGB`](crate::gl_enums::GL_COMPRESSED_SRGB),
-    /// > [`GL_COMPRESSED_SRGB_ALPHA`](crate::gl_enums::GL_COMPRESSED_SRGB_ALPHA).
-    /// > [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT), [`GL_DEPTH_COMPONENT16`](crate::gl_enums::GL_DEPTH_COMPONENT16),
-    /// > [`GL_DEPTH_COMPONENT24`](crate::gl_enums::GL_DEPTH_COMPONENT24), [`GL_DEPTH_COMPONENT32`](crate::gl_enums::GL_DEPTH_COMPONENT32),
-    /// > [`GL_STENCIL_INDEX8`](crate::gl_enums::GL_STENCIL_INDEX8), [`GL_RED`](crate::gl_enums::GL_RED),
-    /// > [`GL_RG`](crate::gl_enums::GL_RG), [`GL_RGB`](crate::gl_enums::GL_RGB),
-    /// > [`GL_R3_G3_B2`](crate::gl_enums::GL_R3_G3_B2), [`GL_RGB4`](crate::gl_enums::GL_RGB4),
-    /// > [`GL_RGB5`](crate::gl_enums::GL_RGB5), [`GL_RGB8`](crate::gl_enums::GL_RGB8),
-    /// > [`GL_RGB10`](crate::gl_enums::GL_RGB10), [`GL_RGB12`](crate::gl_enums::GL_RGB12),
-    /// > [`GL_RGB16`](crate::gl_enums::GL_RGB16), [`GL_RGBA`](crate::gl_enums::GL_RGBA),
-    /// > [`GL_RGBA2`](crate::gl_enums::GL_RGBA2), [`GL_RGBA4`](crate::gl_enums::GL_RGBA4),
-    /// > [`GL_RGB5_A1`](crate::gl_enums::GL_RGB5_A1), [`GL_RGBA8`](crate::gl_enums::GL_RGBA8),
-    /// > [`GL_RGB10_A2`](crate::gl_enums::GL_RGB10_A2), [`GL_RGBA12`](crate::gl_enums::GL_RGBA12),
-    /// > [`GL_RGBA16`](crate::gl_enums::GL_RGBA16), [`GL_SRGB`](crate::gl_enums::GL_SRGB),
-    /// > [`GL_SRGB8`](crate::gl_enums::GL_SRGB8), [`GL_SRGB_ALPHA`](crate::gl_enums::GL_SRGB_ALPHA),
-    /// > or [`GL_SRGB8_ALPHA8`](crate::gl_enums::GL_SRGB8_ALPHA8).
-    ///
-    /// `x`
-    ///
-    /// `y`
-    ///
-    /// > Specify the window coordinates of the lower left corner of the rectangular
-    /// > region of pixels to be copied.
-    ///
-    /// `width`
-    ///
-    /// > Specifies the width of the texture image.
-    ///
-    /// `height`
-    ///
-    /// > Specifies the height of the texture image.
-    ///
-    /// `border`
-    ///
-    /// > Must be 0.
-    ///
-    /// ### Description
-    /// [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d)
-    /// defines a two-dimensional texture image, or cube-map texture image with
-    /// pixels from the current [`GL_READ_BUFFER`](crate::gl_enums::GL_READ_BUFFER).
-    ///
-    /// The screen-aligned pixel rectangle with lower left corner at( `x`, `y`)
-    /// and with a width of `[inlineq]` `[inlineq]` `level`. `internalformat`
-    /// specifies the internal format of the texture array.
-    ///
-    /// The pixels in the rectangle are processed exactly as if [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
-    /// had been called, but the process stops just before final conversion. At
-    /// this point all pixel component values are clamped to the range `[inlineq]`
-    ///
-    /// Pixel ordering is such that lower `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
-    ///
-    /// If any of the pixels within the specified rectangle of the current [`GL_READ_BUFFER`](crate::gl_enums::GL_READ_BUFFER)
-    /// are outside the window associated with the current rendering context, then
-    /// the values obtained for those pixels are undefined.
-    ///
-    /// When `internalformat` is one of the sRGB types, the GL does not automatically
-    /// convert the source pixels to the sRGB color space. In this case, the [**glPixelMap**](crate::context::Context::oxidegl_pixel_map)
-    /// function can be used to accomplish the conversion.
-    ///
-    /// ### Notes
-    /// 1, 2, 3, and 4 are not accepted values for `internalformat`.
-    ///
-    /// An image with height or width of 0 indicates a NULL texture.
-    ///
-    /// [`GL_STENCIL_INDEX8`](crate::gl_enums::GL_STENCIL_INDEX8) is accepted for
-    /// `internalformat` only if the GL version is 4.4 or higher.
-    ///
-    /// ### Associated Gets
-    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
-    pub fn oxidegl_copy_tex_image2_d(
+        panic!("command oxidegl_vertex_attrib_l1d not yet implemented");
+    }
+    pub fn oxidegl_vertex_attrib_l2d(
         &mut self,
-        target: TextureTarget,
-        level: GLint,
-        internalformat: InternalFormat,
-        x: GLint,
-        y: GLint,
-        width: GLsizei,
-        height: GLsizei,
-        border: GLint,
+        index: GLuint,
+        x: GLdouble,
+        y: GLdouble,
     ) -> GlFallible {
-        panic!("command oxidegl_copy_tex_image2_d not yet implemented");
+        panic!("command oxidegl_vertex_attrib_l2d not yet implemented");
     }
-    /// ### Parameters
-    /// `n`
-    ///
-    /// > Number of framebuffer objects to create.
-    ///
-    /// `framebuffers`
-    ///
-    /// > Specifies an array in which names of the new framebuffer objects are stored.
-    ///
-    /// ### Description
-    /// [**glCreateFramebuffers**](crate::context::Context::oxidegl_create_framebuffers)
-    /// returns `n` previously unused framebuffer names in `framebuffers`, each
-    /// representing a new framebuffer object initialized to the default state.
-    pub unsafe fn oxidegl_create_framebuffers(
+    pub fn oxidegl_vertex_attrib_l3d(
         &mut self,
-        n: GLsizei,
-        framebuffers: *mut GLuint,
+        index: GLuint,
+        x: GLdouble,
+        y: GLdouble,
+        z: GLdouble,
     ) -> GlFallible {
-        panic!("command oxidegl_create_framebuffers not yet implemented");
+        panic!("command oxidegl_vertex_attrib_l3d not yet implemented");
     }
-    /// ### Parameters
-    /// `n`
-    ///
-    /// > Number of program pipeline objects to create.
-    ///
-    /// `pipelines`
-    ///
-    /// > Specifies an array in which names of the new program pipeline objects are
-    /// > stored.
-    ///
-    /// ### Description
-    /// [**glCreateProgramPipelines**](crate::context::Context::oxidegl_create_program_pipelines)
-    /// returns `n` previously unused program pipeline names in `pipelines`, each
-    /// representing a new program pipeline object initialized to the default state.
-    pub unsafe fn oxidegl_create_program_pipelines(
+    pub fn oxidegl_vertex_attrib_l4d(
         &mut self,
-        n: GLsizei,
-        pipelines: *mut GLuint,
+        index: GLuint,
+        x: GLdouble,
+        y: GLdouble,
+        z: GLdouble,
+        w: GLdouble,
     ) -> GlFallible {
-        panic!("command oxidegl_create_program_pipelines not yet implemented");
+        panic!("command oxidegl_vertex_attrib_l4d not yet implemented");
     }
-    /// ### Parameters
-    /// `target`
-    ///
-    /// > Specifies the target of each created query object.
-    ///
-    /// `n`
-    ///
-    /// > Number of query objects to create.
-    ///
-    /// `ids`
-    ///
-    /// > Specifies an array in which names of the new query objects are stored.
-    ///
-    /// ### Description
-    /// [**glCreateQueries**](crate::context::Context::oxidegl_create_queries)
-    /// returns `n` previously unused query object names in `ids`, each representing
-    /// a new query object with the specified `target`.
-    ///
-    /// `target` may be one of [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
-    /// [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE),
-    /// [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED), [`GL_TIMESTAMP`](crate::gl_enums::GL_TIMESTAMP),
-    /// [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED) or
-    /// [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN).
-    pub unsafe fn oxidegl_create_queries(
-        &mut self,
-        target: QueryTarget,
-        n: GLsizei,
-        ids: *mut GLuint,
+    pub unsafe fn oxidegl_vertex_attrib_l1dv(
+        &mut self,
+        index: GLuint,
+        v: *const GLdouble,
     ) -> GlFallible {
-        panic!("command oxidegl_create_queries not yet implemented");
+        panic!("command oxidegl_vertex_attrib_l1dv not yet implemented");
     }
-    /// ### Parameters
-    /// `n`
-    ///
-    /// > Number of renderbuffer objects to create.
-    ///
-    /// `renderbuffers`
-    ///
-    /// > Specifies an array in which names of the new renderbuffer objects are stored.
-    ///
-    /// ### Description
-    /// [**glCreateRenderbuffers**](crate::context::Context::oxidegl_create_renderbuffers)
-    /// returns `n` previously unused renderbuffer object names in `renderbuffers`,
-    /// each representing a new renderbuffer object initialized to the default
-    /// state.
-    pub unsafe fn oxidegl_create_renderbuffers(
+    pub unsafe fn oxidegl_vertex_attrib_l2dv(
         &mut self,
-        n: GLsizei,
-        renderbuffers: *mut GLuint,
+        index: GLuint,
+        v: *const GLdouble,
     ) -> GlFallible {
-        panic!("command oxidegl_create_renderbuffers not yet implemented");
+        panic!("command oxidegl_vertex_attrib_l2dv not yet implemented");
     }
-    /// ### Parameters
-    /// `n`
-    ///
-    /// > Number of sampler objects to create.
-    ///
-    /// `samplers`
-    ///
-    /// > Specifies an array in which names of the new sampler objects are stored.
-    ///
-    /// ### Description
-    /// [**glCreateSamplers**](crate::context::Context::oxidegl_create_samplers)
-    /// returns `n` previously unused sampler names in `samplers`, each representing
-    /// a new sampler object initialized to the default state.
-    pub unsafe fn oxidegl_create_samplers(
+    pub unsafe fn oxidegl_vertex_attrib_l3dv(
         &mut self,
-        n: GLsizei,
-        samplers: *mut GLuint,
+        index: GLuint,
+        v: *const GLdouble,
     ) -> GlFallible {
-        panic!("command oxidegl_create_samplers not yet implemented");
+        panic!("command oxidegl_vertex_attrib_l3dv not yet implemented");
+    }
+    pub unsafe fn oxidegl_vertex_attrib_l4dv(
+        &mut self,
+        index: GLuint,
+        v: *const GLdouble,
+    ) -> GlFallible {
+        panic!("command oxidegl_vertex_attrib_l4dv not yet implemented");
+    }
+}
+/// ### Parameters
+/// `index`
+///
+/// > Specify the first viewport to set.
+///
+/// `x`
+///
+/// `y`
+///
+/// > For [**glViewportIndexedf**](crate::context::Context::oxidegl_viewport_indexedf),
+/// > specifies the lower left corner of the viewport rectangle, in pixels.
+/// > The initial value is (0,0).
+///
+/// `w`
+///
+/// `h`
+///
+/// > For [**glViewportIndexedf**](crate::context::Context::oxidegl_viewport_indexedf),
+/// > specifies the width and height of the viewport. When a GL context is first
+/// > attached to a window, `w` and `h` are set to the dimensions of that window.
+///
+/// `v`
+///
+/// > For [**glViewportIndexedfv**](crate::context::Context::oxidegl_viewport_indexedfv),
+/// > specifies the address of an array containing the viewport parameters.
+///
+/// ### Description
+/// [**glViewportIndexedf**](crate::context::Context::oxidegl_viewport_indexedf)
+/// and [**glViewportIndexedfv**](crate::context::Context::oxidegl_viewport_indexedfv)
+/// specify the parameters for a single viewport. `index` specifies the index
+/// of the viewport to modify. `index` must be less than the value of [`GL_MAX_VIEWPORTS`](crate::gl_enums::GL_MAX_VIEWPORTS).
+/// For [**glViewportIndexedf**](crate::context::Context::oxidegl_viewport_indexedf),
+/// `x`, `y`, `w`, and `h` specify the left, bottom, width and height of the
+/// viewport in pixels, respectively. For [**glViewportIndexedfv**](crate::context::Context::oxidegl_viewport_indexedfv),
+/// `v` contains the address of an array of floating point values specifying
+/// the left( `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+/// `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+///
+///
+///
+/// The location of the viewport's bottom left corner, given by( `[inlineq]`
+/// `[inlineq]` `[inlineq]` `[inlineq]` [**glGet**](crate::context::Context::oxidegl_get)
+/// with argument [`GL_VIEWPORT_BOUNDS_RANGE`](crate::gl_enums::GL_VIEWPORT_BOUNDS_RANGE).
+/// Viewport width and height are silently clamped to a range that depends
+/// on the implementation. To query this range, call [**glGet**](crate::context::Context::oxidegl_get)
+/// with argument [`GL_MAX_VIEWPORT_DIMS`](crate::gl_enums::GL_MAX_VIEWPORT_DIMS).
+///
+/// The precision with which the GL interprets the floating point viewport
+/// bounds is implementation-dependent and may be determined by querying the
+/// impementation-defined constant [`GL_VIEWPORT_SUBPIXEL_BITS`](crate::gl_enums::GL_VIEWPORT_SUBPIXEL_BITS).
+///
+/// Calling [**glViewportIndexedfv**](crate::context::Context::oxidegl_viewport_indexedfv)
+/// is equivalent to calling [**glViewportArray**](crate::context::Context::oxidegl_viewport_array)
+/// with `first` set to `index`, `count` set to 1 and `v` passsed directly.
+/// [**glViewportIndexedf**](crate::context::Context::oxidegl_viewport_indexedf)
+/// is equivalent to:
+///
+/// ### Associated Gets
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_VIEWPORT`](crate::gl_enums::GL_VIEWPORT)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_VIEWPORT_DIMS`](crate::gl_enums::GL_MAX_VIEWPORT_DIMS)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_VIEWPORT_BOUNDS_RANGE`](crate::gl_enums::GL_VIEWPORT_BOUNDS_RANGE)
+///
+/// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_VIEWPORT_SUBPIXEL_BITS`](crate::gl_enums::GL_VIEWPORT_SUBPIXEL_BITS)
+impl Context {
+    pub fn oxidegl_viewport_indexedf(
+        &mut self,
+        index: GLuint,
+        x: GLfloat,
+        y: GLfloat,
+        w: GLfloat,
+        h: GLfloat,
+    ) -> GlFallible {
+        panic!("command oxidegl_viewport_indexedf not yet implemented");
+    }
+    pub unsafe fn oxidegl_viewport_indexedfv(
+        &mut self,
+        index: GLuint,
+        v: *const GLfloat,
+    ) -> GlFallible {
+        panic!("command oxidegl_viewport_indexedfv not yet implemented");
     }
+}
+impl Context {
     /// ### Parameters
-    /// `type`
-    ///
-    /// > Specifies the type of shader to create.
-    ///
-    /// `count`
-    ///
-    /// > Specifies the number of source code strings in the array `strings`.
-    ///
-    /// `strings`
+    /// `texture`
     ///
-    /// > Specifies the address of an array of pointers to source code strings from
-    /// > which to create the program object.
+    /// > Specifies which texture unit to make active. The number of texture units
+    /// > is implementation dependent, but must be at least 80. `texture` must be
+    /// > one of [`GL_TEXTURE`](crate::gl_enums::GL_TEXTURE) *i*, where *i* ranges
+    /// > from zero to the value of [`GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`](crate::gl_enums::GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS)
+    /// > minus one. The initial value is [`GL_TEXTURE0`](crate::gl_enums::GL_TEXTURE0).
     ///
     /// ### Description
-    /// [**glCreateShaderProgram**](crate::context::Context::oxidegl_create_shader_program)
-    /// creates a program object containing compiled and linked shaders for a single
-    /// stage specified by `type`. `strings` refers to an array of `count` strings
-    /// from which to create the shader executables.
-    ///
-    /// [**glCreateShaderProgram**](crate::context::Context::oxidegl_create_shader_program)
-    /// is equivalent (assuming no errors are generated) to:
+    /// [**glActiveTexture**](crate::context::Context::oxidegl_active_texture)
+    /// selects which texture unit subsequent texture state calls will affect.
+    /// The number of texture units an implementation supports is implementation
+    /// dependent, but must be at least 80.
     ///
-    /// The program object created by [**glCreateShaderProgram**](crate::context::Context::oxidegl_create_shader_program)
-    /// has its [`GL_PROGRAM_SEPARABLE`](crate::gl_enums::GL_PROGRAM_SEPARABLE)
-    /// status set to [`GL_TRUE`](crate::gl_enums::GL_TRUE).
-    pub unsafe fn oxidegl_create_shader_programv(
-        &mut self,
-        r#type: ShaderType,
-        count: GLsizei,
-        strings: *const *const GLchar,
-    ) -> GlFallible<GLuint> {
-        panic!("command oxidegl_create_shader_programv not yet implemented");
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_ACTIVE_TEXTURE`](crate::gl_enums::GL_ACTIVE_TEXTURE),
+    /// or [`GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`](crate::gl_enums::GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS).
+    pub fn oxidegl_active_texture(&mut self, texture: TextureUnit) -> GlFallible {
+        panic!("command oxidegl_active_texture not yet implemented");
     }
     /// ### Parameters
     /// `target`
     ///
-    /// > Specifies the effective texture target of each created texture.
-    ///
-    /// `n`
-    ///
-    /// > Number of texture objects to create.
+    /// > Specifies the framebuffer target of the binding operation.
     ///
-    /// `textures`
+    /// `framebuffer`
     ///
-    /// > Specifies an array in which names of the new texture objects are stored.
+    /// > Specifies the name of the framebuffer object to bind.
     ///
     /// ### Description
-    /// [**glCreateTextures**](crate::context::Context::oxidegl_create_textures)
-    /// returns `n` previously unused texture names in `textures`, each representing
-    /// a new texture object of the dimensionality and type specified by `target`
-    /// and initialized to the default values for that texture type.
-    ///
-    /// `target` must be one of [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D),
-    /// [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D), [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D),
-    /// [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY),
-    /// [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE), [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP),
-    /// [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY),
-    /// [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE)
-    /// or [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY).
-    pub unsafe fn oxidegl_create_textures(
+    /// [**glBindFramebuffer**](crate::context::Context::oxidegl_bind_framebuffer)
+    /// binds the framebuffer object with name `framebuffer` to the framebuffer
+    /// target specified by `target`. `target` must be either [`GL_DRAW_FRAMEBUFFER`](crate::gl_enums::GL_DRAW_FRAMEBUFFER),
+    /// [`GL_READ_FRAMEBUFFER`](crate::gl_enums::GL_READ_FRAMEBUFFER) or [`GL_FRAMEBUFFER`](crate::gl_enums::GL_FRAMEBUFFER).
+    /// If a framebuffer object is bound to [`GL_DRAW_FRAMEBUFFER`](crate::gl_enums::GL_DRAW_FRAMEBUFFER)
+    /// or [`GL_READ_FRAMEBUFFER`](crate::gl_enums::GL_READ_FRAMEBUFFER), it becomes
+    /// the target for rendering or readback operations, respectively, until it
+    /// is deleted or another framebuffer is bound to the corresponding bind point.
+    /// Calling [**glBindFramebuffer**](crate::context::Context::oxidegl_bind_framebuffer)
+    /// with `target` set to [`GL_FRAMEBUFFER`](crate::gl_enums::GL_FRAMEBUFFER)
+    /// binds `framebuffer` to both the read and draw framebuffer targets. `framebuffer`
+    /// is the name of a framebuffer object previously returned from a call to
+    /// [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers),
+    /// or zero to break the existing binding of a framebuffer object to `target`.
+    pub fn oxidegl_bind_framebuffer(
         &mut self,
-        target: TextureTarget,
-        n: GLsizei,
-        textures: *mut GLuint,
+        target: FramebufferTarget,
+        framebuffer: GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_create_textures not yet implemented");
+        panic!("command oxidegl_bind_framebuffer not yet implemented");
     }
     /// ### Parameters
-    /// `n`
+    /// `target`
     ///
-    /// > Number of transform feedback objects to create.
+    /// > Specifies the renderbuffer target of the binding operation. `target` must
+    /// > be [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER).
     ///
-    /// `ids`
+    /// `renderbuffer`
     ///
-    /// > Specifies an array in which names of the new transform feedback objects
-    /// > are stored.
+    /// > Specifies the name of the renderbuffer object to bind.
     ///
     /// ### Description
-    /// [**glCreateTransformFeedbacks**](crate::context::Context::oxidegl_create_transform_feedbacks)
-    /// returns `n` previously unused transform feedback object names in `ids`,
-    /// each representing a new transform feedback object initialized to the default
-    /// state.
-    pub unsafe fn oxidegl_create_transform_feedbacks(
+    /// [**glBindRenderbuffer**](crate::context::Context::oxidegl_bind_renderbuffer)
+    /// binds the renderbuffer object with name `renderbuffer` to the renderbuffer
+    /// target specified by `target`. `target` must be [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER).
+    /// `renderbuffer` is the name of a renderbuffer object previously returned
+    /// from a call to [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers),
+    /// or zero to break the existing binding of a renderbuffer object to `target`.
+    pub fn oxidegl_bind_renderbuffer(
         &mut self,
-        n: GLsizei,
-        ids: *mut GLuint,
+        target: GLenum,
+        renderbuffer: GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_create_transform_feedbacks not yet implemented");
+        panic!("command oxidegl_bind_renderbuffer not yet implemented");
     }
     /// ### Parameters
-    /// `mode`
+    /// `unit`
     ///
-    /// > Specifies whether front- or back-facing facets are candidates for culling.
-    /// > Symbolic constants [`GL_FRONT`](crate::gl_enums::GL_FRONT), [`GL_BACK`](crate::gl_enums::GL_BACK),
-    /// > and [`GL_FRONT_AND_BACK`](crate::gl_enums::GL_FRONT_AND_BACK) are accepted.
-    /// > The initial value is [`GL_BACK`](crate::gl_enums::GL_BACK).
+    /// > Specifies the index of the texture unit to which the sampler is bound.
+    ///
+    /// `sampler`
+    ///
+    /// > Specifies the name of a sampler.
     ///
     /// ### Description
-    /// [**glCullFace**](crate::context::Context::oxidegl_cull_face) specifies
-    /// whether front- or back-facing facets are culled (as specified by *mode*)
-    /// when facet culling is enabled. Facet culling is initially disabled. To
-    /// enable and disable facet culling, call the [**glEnable**](crate::context::Context::oxidegl_enable)
-    /// and [**glDisable**](crate::context::Context::oxidegl_disable) commands
-    /// with the argument [`GL_CULL_FACE`](crate::gl_enums::GL_CULL_FACE). Facets
-    /// include triangles, quadrilaterals, polygons, and rectangles.
+    /// [**glBindSampler**](crate::context::Context::oxidegl_bind_sampler) binds
+    /// `sampler` to the texture unit at index `unit`. `sampler` must be zero or
+    /// the name of a sampler object previously returned from a call to [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers).
+    /// `unit` must be less than the value of [`GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS`](crate::gl_enums::GL_MAX_COMBINED_TEXTURE_IMAGE_UNITS).
     ///
-    /// [**glFrontFace**](crate::context::Context::oxidegl_front_face) specifies
-    /// which of the clockwise and counterclockwise facets are front-facing and
-    /// back-facing. See [**glFrontFace**](crate::context::Context::oxidegl_front_face).
+    /// When a sampler object is bound to a texture unit, its state supersedes
+    /// that of the texture object bound to that texture unit. If the sampler name
+    /// zero is bound to a texture unit, the currently bound texture's sampler
+    /// state becomes active. A single sampler object may be bound to multiple
+    /// texture units simultaneously.
     ///
     /// ### Notes
-    /// If `mode` is [`GL_FRONT_AND_BACK`](crate::gl_enums::GL_FRONT_AND_BACK),
-    /// no facets are drawn, but other primitives such as points and lines are
-    /// drawn.
+    /// [**glBindSampler**](crate::context::Context::oxidegl_bind_sampler) is available
+    /// only if the GL version is 3.3 or higher.
     ///
     /// ### Associated Gets
-    /// [**glIsEnabled**](crate::context::Context::oxidegl_is_enabled) with argument
-    /// [`GL_CULL_FACE`](crate::gl_enums::GL_CULL_FACE)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_CULL_FACE_MODE`](crate::gl_enums::GL_CULL_FACE_MODE)
-    pub fn oxidegl_cull_face(&mut self, mode: TriangleFace) -> GlFallible {
-        panic!("command oxidegl_cull_face not yet implemented");
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_SAMPLER_BINDING`](crate::gl_enums::GL_SAMPLER_BINDING)
+    pub fn oxidegl_bind_sampler(&mut self, unit: GLuint, sampler: GLuint) -> GlFallible {
+        panic!("command oxidegl_bind_sampler not yet implemented");
     }
     /// ### Parameters
-    /// `n`
+    /// `first`
     ///
-    /// > Specifies the number of framebuffer objects to be deleted.
+    /// > Specifies the first sampler unit to which a sampler object is to be bound.
     ///
-    /// `framebuffers`
+    /// `count`
     ///
-    /// > A pointer to an array containing `n` framebuffer objects to be deleted.
+    /// > Specifies the number of samplers to bind.
     ///
-    /// ### Description
-    /// [**glDeleteFramebuffers**](crate::context::Context::oxidegl_delete_framebuffers)
-    /// deletes the `n` framebuffer objects whose names are stored in the array
-    /// addressed by `framebuffers`. The name zero is reserved by the GL and is
-    /// silently ignored, should it occur in `framebuffers`, as are other unused
-    /// names. Once a framebuffer object is deleted, its name is again unused and
-    /// it has no attachments. If a framebuffer that is currently bound to one
-    /// or more of the targets [`GL_DRAW_FRAMEBUFFER`](crate::gl_enums::GL_DRAW_FRAMEBUFFER)
-    /// or [`GL_READ_FRAMEBUFFER`](crate::gl_enums::GL_READ_FRAMEBUFFER) is deleted,
-    /// it is as though [**glBindFramebuffer**](crate::context::Context::oxidegl_bind_framebuffer)
-    /// had been executed with the corresponding `target` and `framebuffer` zero.
-    pub unsafe fn oxidegl_delete_framebuffers(
-        &mut self,
-        n: GLsizei,
-        framebuffers: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_delete_framebuffers not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
+    /// `samplers`
     ///
-    /// > Specifies the program object to be deleted.
+    /// > Specifies the address of an array of names of existing sampler objects.
     ///
     /// ### Description
-    /// [**glDeleteProgram**](crate::context::Context::oxidegl_delete_program)
-    /// frees the memory and invalidates the name associated with the program object
-    /// specified by `program.` This command effectively undoes the effects of
-    /// a call to [**glCreateProgram**](crate::context::Context::oxidegl_create_program).
-    ///
-    /// If a program object is in use as part of current rendering state, it will
-    /// be flagged for deletion, but it will not be deleted until it is no longer
-    /// part of current state for any rendering context. If a program object to
-    /// be deleted has shader objects attached to it, those shader objects will
-    /// be automatically detached but not deleted unless they have already been
-    /// flagged for deletion by a previous call to [**glDeleteShader**](crate::context::Context::oxidegl_delete_shader).
-    /// A value of 0 for `program` will be silently ignored.
-    ///
-    /// To determine whether a program object has been flagged for deletion, call
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with arguments
-    /// `program` and [`GL_DELETE_STATUS`](crate::gl_enums::GL_DELETE_STATUS).
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_CURRENT_PROGRAM`](crate::gl_enums::GL_CURRENT_PROGRAM)
-    ///
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with arguments
-    /// `program` and [`GL_DELETE_STATUS`](crate::gl_enums::GL_DELETE_STATUS)
-    ///
-    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
-    pub fn oxidegl_delete_program(&mut self, program: GLuint) -> GlFallible {
-        panic!("command oxidegl_delete_program not yet implemented");
-    }
-    /// ### Parameters
-    /// `n`
+    /// [**glBindSamplers**](crate::context::Context::oxidegl_bind_samplers) binds
+    /// samplers from an array of existing sampler objects to a specified number
+    /// of consecutive sampler units. `count` specifies the number of sampler objects
+    /// whose names are stored in the array `samplers`. That number of sampler
+    /// names is read from the array and bound to the `count` consecutive sampler
+    /// units starting from `first`.
     ///
-    /// > Specifies the number of program pipeline objects to delete.
+    /// If the name zero appears in the `samplers` array, any existing binding
+    /// to the sampler unit is reset. Any non-zero entry in `samplers` must be
+    /// the name of an existing sampler object. When a non-zero entry in `samplers`
+    /// is present, that sampler object is bound to the corresponding sampler unit.
+    /// If `samplers` is [`NULL`](crate::gl_enums::NULL) then it is as if an appropriately
+    /// sized array containing only zeros had been specified.
     ///
-    /// `pipelines`
+    /// [**glBindSamplers**](crate::context::Context::oxidegl_bind_samplers) is
+    /// equivalent to the following pseudo code:
     ///
-    /// > Specifies an array of names of program pipeline objects to delete.
+    /// Each entry in `samplers` will be checked individually and if found to be
+    /// invalid, the state for that sampler unit will not be changed and an error
+    /// will be generated. However, the state for other sampler units referenced
+    /// by the command will still be updated.
     ///
-    /// ### Description
-    /// [**glDeleteProgramPipelines**](crate::context::Context::oxidegl_delete_program_pipelines)
-    /// deletes the `n` program pipeline objects whose names are stored in the
-    /// array `pipelines`. Unused names in `pipelines` are ignored, as is the name
-    /// zero. After a program pipeline object is deleted, its name is again unused
-    /// and it has no contents. If program pipeline object that is currently bound
-    /// is deleted, the binding for that object reverts to zero and no program
-    /// pipeline object becomes current.
+    /// ### Notes
+    /// [**glBindSamplers**](crate::context::Context::oxidegl_bind_samplers) is
+    /// available only if the GL version is 4.4 or higher.
     ///
     /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PROGRAM_PIPELINE_BINDING`](crate::gl_enums::GL_PROGRAM_PIPELINE_BINDING)
-    pub unsafe fn oxidegl_delete_program_pipelines(
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_SAMPLER_BINDING`](crate::gl_enums::GL_SAMPLER_BINDING)
+    pub unsafe fn oxidegl_bind_samplers(
         &mut self,
-        n: GLsizei,
-        pipelines: *const GLuint,
+        first: GLuint,
+        count: GLsizei,
+        samplers: *const GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_delete_program_pipelines not yet implemented");
+        panic!("command oxidegl_bind_samplers not yet implemented");
     }
     /// ### Parameters
-    /// `n`
+    /// `target`
     ///
-    /// > Specifies the number of query objects to be deleted.
+    /// > Specifies the target to which the texture is bound. Must be one of [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D),
+    /// > [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D), [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D),
+    /// > [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY),
+    /// > [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE), [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP),
+    /// > [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+    /// > [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE)
+    /// > or [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY).
     ///
-    /// `ids`
+    /// `texture`
     ///
-    /// > Specifies an array of query objects to be deleted.
+    /// > Specifies the name of a texture.
     ///
     /// ### Description
-    /// [**glDeleteQueries**](crate::context::Context::oxidegl_delete_queries)
-    /// deletes `n` query objects named by the elements of the array `ids`. After
-    /// a query object is deleted, it has no contents, and its name is free for
-    /// reuse (for example by [**glGenQueries**](crate::context::Context::oxidegl_gen_queries)
-    /// ).
-    ///
-    /// [**glDeleteQueries**](crate::context::Context::oxidegl_delete_queries)
-    /// silently ignores 0's and names that do not correspond to existing query
-    /// objects.
-    ///
-    /// ### Associated Gets
-    /// [**glIsQuery**](crate::context::Context::oxidegl_is_query)
-    pub unsafe fn oxidegl_delete_queries(&mut self, n: GLsizei, ids: *const GLuint) -> GlFallible {
-        panic!("command oxidegl_delete_queries not yet implemented");
-    }
-    /// ### Parameters
-    /// `n`
-    ///
-    /// > Specifies the number of renderbuffer objects to be deleted.
-    ///
-    /// `renderbuffers`
-    ///
-    /// > A pointer to an array containing `n` renderbuffer objects to be deleted.
+    /// [**glBindTexture**](crate::context::Context::oxidegl_bind_texture) lets
+    /// you create or use a named texture. Calling [**glBindTexture**](crate::context::Context::oxidegl_bind_texture)
+    /// with `target` set to [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D),
+    /// [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D), [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D),
+    /// [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY),
+    /// [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE), [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP),
+    /// [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY),
+    /// [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER), [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE)
+    /// or [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY)
+    /// and `texture` set to the name of the new texture binds the texture name
+    /// to the target. When a texture is bound to a target, the previous binding
+    /// for that target is automatically broken.
     ///
-    /// ### Description
-    /// [**glDeleteRenderbuffers**](crate::context::Context::oxidegl_delete_renderbuffers)
-    /// deletes the `n` renderbuffer objects whose names are stored in the array
-    /// addressed by `renderbuffers`. The name zero is reserved by the GL and is
-    /// silently ignored, should it occur in `renderbuffers`, as are other unused
-    /// names. Once a renderbuffer object is deleted, its name is again unused
-    /// and it has no contents. If a renderbuffer that is currently bound to the
-    /// target [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER) is deleted,
-    /// it is as though [**glBindRenderbuffer**](crate::context::Context::oxidegl_bind_renderbuffer)
-    /// had been executed with a `target` of [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER)
-    /// and a `name` of zero.
+    /// Texture names are unsigned integers. The value zero is reserved to represent
+    /// the default texture for each texture target. Texture names and the corresponding
+    /// texture contents are local to the shared object space of the current GL
+    /// rendering context; two rendering contexts share texture names only if they
+    /// explicitly enable sharing between contexts through the appropriate GL windows
+    /// interfaces functions.
     ///
-    /// If a renderbuffer object is attached to one or more attachment points in
-    /// the currently bound framebuffer, then it as if [**glFramebufferRenderbuffer**](crate::context::Context::oxidegl_framebuffer_renderbuffer)
-    /// had been called, with a `renderbuffer` of zero for each attachment point
-    /// to which this image was attached in the currently bound framebuffer. In
-    /// other words, this renderbuffer object is first detached from all attachment
-    /// ponits in the currently bound framebuffer. Note that the renderbuffer image
-    /// is specifically *not* detached from any non-bound framebuffers.
-    pub unsafe fn oxidegl_delete_renderbuffers(
-        &mut self,
-        n: GLsizei,
-        renderbuffers: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_delete_renderbuffers not yet implemented");
-    }
-    /// ### Parameters
-    /// `n`
+    /// You must use [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
+    /// to generate a set of new texture names.
     ///
-    /// > Specifies the number of sampler objects to be deleted.
+    /// When a texture is first bound, it assumes the specified target: A texture
+    /// first bound to [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D) becomes
+    /// one-dimensional texture, a texture first bound to [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D)
+    /// becomes two-dimensional texture, a texture first bound to [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D)
+    /// becomes three-dimensional texture, a texture first bound to [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY)
+    /// becomes one-dimensional array texture, a texture first bound to [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY)
+    /// becomes two-dimensional array texture, a texture first bound to [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE)
+    /// becomes rectangle texture, a texture first bound to [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP)
+    /// becomes a cube-mapped texture, a texture first bound to [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY)
+    /// becomes a cube-mapped array texture, a texture first bound to [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER)
+    /// becomes a buffer texture, a texture first bound to [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE)
+    /// becomes a two-dimensional multisampled texture, and a texture first bound
+    /// to [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY)
+    /// becomes a two-dimensional multisampled array texture. The state of a one-dimensional
+    /// texture immediately after it is first bound is equivalent to the state
+    /// of the default [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D) at GL
+    /// initialization, and similarly for the other texture types.
     ///
-    /// `samplers`
+    /// While a texture is bound, GL operations on the target to which it is bound
+    /// affect the bound texture, and queries of the target to which it is bound
+    /// return state from the bound texture. In effect, the texture targets become
+    /// aliases for the textures currently bound to them, and the texture name
+    /// zero refers to the default textures that were bound to them at initialization.
     ///
-    /// > Specifies an array of sampler objects to be deleted.
+    /// A texture binding created with [**glBindTexture**](crate::context::Context::oxidegl_bind_texture)
+    /// remains active until a different texture is bound to the same target, or
+    /// until the bound texture is deleted with [**glDeleteTextures**](crate::context::Context::oxidegl_delete_textures).
     ///
-    /// ### Description
-    /// [**glDeleteSamplers**](crate::context::Context::oxidegl_delete_samplers)
-    /// deletes `n` sampler objects named by the elements of the array `samplers`.
-    /// After a sampler object is deleted, its name is again unused. If a sampler
-    /// object that is currently bound to a sampler unit is deleted, it is as though
-    /// [**glBindSampler**](crate::context::Context::oxidegl_bind_sampler) is called
-    /// with unit set to the unit the sampler is bound to and sampler zero. Unused
-    /// names in samplers are silently ignored, as is the reserved name zero.
+    /// Once created, a named texture may be re-bound to its same original target
+    /// as often as needed. It is usually much faster to use [**glBindTexture**](crate::context::Context::oxidegl_bind_texture)
+    /// to bind an existing named texture to one of the texture targets than it
+    /// is to reload the texture image using [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d),
+    /// [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d), [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d)
+    /// or another similar function.
     ///
     /// ### Notes
-    /// [**glDeleteSamplers**](crate::context::Context::oxidegl_delete_samplers)
-    /// is available only if the GL version is 3.3 or higher.
+    /// The [`GL_TEXTURE_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE)
+    /// and [`GL_TEXTURE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_MULTISAMPLE_ARRAY)
+    /// targets are available only if the GL version is 3.2 or higher.
     ///
     /// ### Associated Gets
-    /// [**glIsSampler**](crate::context::Context::oxidegl_is_sampler)
-    pub unsafe fn oxidegl_delete_samplers(
-        &mut self,
-        count: GLsizei,
-        samplers: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_delete_samplers not yet implemented");
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_1D`](crate::gl_enums::GL_TEXTURE_BINDING_1D),
+    /// [`GL_TEXTURE_BINDING_2D`](crate::gl_enums::GL_TEXTURE_BINDING_2D), [`GL_TEXTURE_BINDING_3D`](crate::gl_enums::GL_TEXTURE_BINDING_3D),
+    /// [`GL_TEXTURE_BINDING_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_1D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_RECTANGLE`](crate::gl_enums::GL_TEXTURE_BINDING_RECTANGLE),
+    /// [`GL_TEXTURE_BINDING_BUFFER`](crate::gl_enums::GL_TEXTURE_BINDING_BUFFER),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE),
+    /// or [`GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY).
+    pub fn oxidegl_bind_texture(&mut self, target: TextureTarget, texture: GLuint) -> GlFallible {
+        panic!("command oxidegl_bind_texture not yet implemented");
     }
     /// ### Parameters
-    /// `sync`
-    ///
-    /// > The sync object to be deleted.
-    ///
-    /// ### Description
-    /// [**glDeleteSync**](crate::context::Context::oxidegl_delete_sync) deletes
-    /// the sync object specified by `sync`. If the fence command corresponding
-    /// to the specified sync object has completed, or if no [**glWaitSync**](crate::context::Context::oxidegl_wait_sync)
-    /// or [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
-    /// commands are blocking on `sync`, the object is deleted immediately. Otherwise,
-    /// `sync` is flagged for deletion and will be deleted when it is no longer
-    /// associated with any fence command and is no longer blocking any [**glWaitSync**](crate::context::Context::oxidegl_wait_sync)
-    /// or [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
-    /// command. In either case, after [**glDeleteSync**](crate::context::Context::oxidegl_delete_sync)
-    /// returns, the name `sync` is invalid and can no longer be used to refer
-    /// to the sync object.
+    /// `first`
     ///
-    /// [**glDeleteSync**](crate::context::Context::oxidegl_delete_sync) will silently
-    /// ignore a `sync` value of zero.
+    /// > Specifies the first texture unit to which a texture is to be bound.
     ///
-    /// ### Notes
-    /// [**glSync**](crate::context::Context::oxidegl_sync) is only supported if
-    /// the GL version is 3.2 or greater, or if the
-    pub fn oxidegl_delete_sync(&mut self, sync: GLsync) -> GlFallible {
-        panic!("command oxidegl_delete_sync not yet implemented");
-    }
-    /// ### Parameters
-    /// `n`
+    /// `count`
     ///
-    /// > Specifies the number of textures to be deleted.
+    /// > Specifies the number of textures to bind.
     ///
     /// `textures`
     ///
-    /// > Specifies an array of textures to be deleted.
+    /// > Specifies the address of an array of names of existing texture objects.
     ///
     /// ### Description
-    /// [**glDeleteTextures**](crate::context::Context::oxidegl_delete_textures)
-    /// deletes `n` textures named by the elements of the array `textures`. After
-    /// a texture is deleted, it has no contents or dimensionality, and its name
-    /// is free for reuse (for example by [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
-    /// ). If a texture that is currently bound is deleted, the binding reverts
-    /// to 0 (the default texture).
+    /// [**glBindTextures**](crate::context::Context::oxidegl_bind_textures) binds
+    /// an array of existing texture objects to a specified number of consecutive
+    /// texture units. `count` specifies the number of texture objects whose names
+    /// are stored in the array `textures`. That number of texture names are read
+    /// from the array and bound to the `count` consecutive texture units starting
+    /// from `first`. The target, or type of texture is deduced from the texture
+    /// object and each texture is bound to the corresponding target of the texture
+    /// unit. If the name zero appears in the `textures` array, any existing binding
+    /// to any target of the texture unit is reset and the default texture for
+    /// that target is bound in its place. Any non-zero entry in `textures` must
+    /// be the name of an existing texture object. If `textures` is [`NULL`](crate::gl_enums::NULL)
+    /// then it is as if an appropriately sized array containing only zeros had
+    /// been specified.
+    ///
+    /// With the exception that the active texture selector maintains its current
+    /// value, [**glBindTextures**](crate::context::Context::oxidegl_bind_textures)
+    /// is equivalent to the following pseudo code:
+    ///
+    /// Each entry in `textures` will be checked individually and if found to be
+    /// invalid, the state for that texture unit will not be changed and an error
+    /// will be generated. However, the state for other texture units referenced
+    /// by the command will still be updated.
+    ///
+    /// ### Notes
+    /// [**glBindTextures**](crate::context::Context::oxidegl_bind_textures) is
+    /// available only if the GL version is 4.4 or higher.
     ///
-    /// [**glDeleteTextures**](crate::context::Context::oxidegl_delete_textures)
-    /// silently ignores 0's and names that do not correspond to existing textures.
+    /// Note that because [**glBindTextures**](crate::context::Context::oxidegl_bind_textures)
+    /// cannot create new textures (even if a name passed has been previously generated
+    /// by call to [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
+    /// ), names passed to [**glBindTextures**](crate::context::Context::oxidegl_bind_textures)
+    /// must have been bound at least once previously via a call to [**glBindTexture**](crate::context::Context::oxidegl_bind_texture).
     ///
     /// ### Associated Gets
-    /// [**glIsTexture**](crate::context::Context::oxidegl_is_texture)
-    pub unsafe fn oxidegl_delete_textures(
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_1D`](crate::gl_enums::GL_TEXTURE_BINDING_1D),
+    /// [`GL_TEXTURE_BINDING_2D`](crate::gl_enums::GL_TEXTURE_BINDING_2D), [`GL_TEXTURE_BINDING_3D`](crate::gl_enums::GL_TEXTURE_BINDING_3D),
+    /// [`GL_TEXTURE_BINDING_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_1D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_RECTANGLE`](crate::gl_enums::GL_TEXTURE_BINDING_RECTANGLE),
+    /// [`GL_TEXTURE_BINDING_BUFFER`](crate::gl_enums::GL_TEXTURE_BINDING_BUFFER),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE),
+    /// or [`GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY).
+    pub unsafe fn oxidegl_bind_textures(
         &mut self,
-        n: GLsizei,
+        first: GLuint,
+        count: GLsizei,
         textures: *const GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_delete_textures not yet implemented");
+        panic!("command oxidegl_bind_textures not yet implemented");
     }
     /// ### Parameters
-    /// `n`
+    /// `unit`
     ///
-    /// > Specifies the number of transform feedback objects to delete.
+    /// > Specifies the texture unit, to which the texture object should be bound
+    /// > to.
     ///
-    /// `ids`
+    /// `texture`
     ///
-    /// > Specifies an array of names of transform feedback objects to delete.
+    /// > Specifies the name of a texture.
     ///
     /// ### Description
-    /// [**glDeleteTransformFeedbacks**](crate::context::Context::oxidegl_delete_transform_feedbacks)
-    /// deletes the `n` transform feedback objects whose names are stored in the
-    /// array `ids`. Unused names in `ids` are ignored, as is the name zero. After
-    /// a transform feedback object is deleted, its name is again unused and it
-    /// has no contents. If an active transform feedback object is deleted, its
-    /// name immediately becomes unused, but the underlying object is not deleted
-    /// until it is no longer active.
+    /// [**glBindTextureUnit**](crate::context::Context::oxidegl_bind_texture_unit)
+    /// binds an existing texture object to the texture unit numbered `unit`.
+    ///
+    /// `texture` must be zero or the name of an existing texture object. When
+    /// `texture` is the name of an existing texture object, that object is bound
+    /// to the target, in the corresponding texture unit, that was specified when
+    /// the object was created. When `texture` is zero, each of the targets enumerated
+    /// at the beginning of this section is reset to its default texture for the
+    /// corresponding texture image unit.
     ///
     /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TRANSFORM_FEEDBACK_BINDING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BINDING)
-    pub unsafe fn oxidegl_delete_transform_feedbacks(
-        &mut self,
-        n: GLsizei,
-        ids: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_delete_transform_feedbacks not yet implemented");
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_BINDING_1D`](crate::gl_enums::GL_TEXTURE_BINDING_1D),
+    /// [`GL_TEXTURE_BINDING_2D`](crate::gl_enums::GL_TEXTURE_BINDING_2D), [`GL_TEXTURE_BINDING_3D`](crate::gl_enums::GL_TEXTURE_BINDING_3D),
+    /// [`GL_TEXTURE_BINDING_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_1D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_ARRAY),
+    /// [`GL_TEXTURE_BINDING_RECTANGLE`](crate::gl_enums::GL_TEXTURE_BINDING_RECTANGLE),
+    /// [`GL_TEXTURE_BINDING_BUFFER`](crate::gl_enums::GL_TEXTURE_BINDING_BUFFER),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP),
+    /// [`GL_TEXTURE_BINDING_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_CUBE_MAP_ARRAY),
+    /// [`GL_TEXTURE_BINDING_2D_MULTISAMPLE`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE)
+    /// or [`GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY).
+    pub fn oxidegl_bind_texture_unit(&mut self, unit: GLuint, texture: GLuint) -> GlFallible {
+        panic!("command oxidegl_bind_texture_unit not yet implemented");
     }
     /// ### Parameters
-    /// `func`
-    ///
-    /// > Specifies the depth comparison function. Symbolic constants [`GL_NEVER`](crate::gl_enums::GL_NEVER),
-    /// > [`GL_LESS`](crate::gl_enums::GL_LESS), [`GL_EQUAL`](crate::gl_enums::GL_EQUAL),
-    /// > [`GL_LEQUAL`](crate::gl_enums::GL_LEQUAL), [`GL_GREATER`](crate::gl_enums::GL_GREATER),
-    /// > [`GL_NOTEQUAL`](crate::gl_enums::GL_NOTEQUAL), [`GL_GEQUAL`](crate::gl_enums::GL_GEQUAL),
-    /// > and [`GL_ALWAYS`](crate::gl_enums::GL_ALWAYS) are accepted. The initial
-    /// > value is [`GL_LESS`](crate::gl_enums::GL_LESS).
-    ///
-    /// ### Description
-    /// [**glDepthFunc**](crate::context::Context::oxidegl_depth_func) specifies
-    /// the function used to compare each incoming pixel depth value with the depth
-    /// value present in the depth buffer. The comparison is performed only if
-    /// depth testing is enabled. (See [**glEnable**](crate::context::Context::oxidegl_enable)
-    /// and [**glDisable**](crate::context::Context::oxidegl_disable) of [`GL_DEPTH_TEST`](crate::gl_enums::GL_DEPTH_TEST)
-    /// .)
-    ///
-    /// `func` specifies the conditions under which the pixel will be drawn. The
-    /// comparison functions are as follows:
-    ///
-    /// [`GL_NEVER`](crate::gl_enums::GL_NEVER)
-    ///
-    /// > Never passes.
-    ///
-    /// [`GL_LESS`](crate::gl_enums::GL_LESS)
-    ///
-    /// > Passes if the incoming depth value is less than the stored depth value.
-    ///
-    /// [`GL_EQUAL`](crate::gl_enums::GL_EQUAL)
-    ///
-    /// > Passes if the incoming depth value is equal to the stored depth value.
-    ///
-    /// [`GL_LEQUAL`](crate::gl_enums::GL_LEQUAL)
-    ///
-    /// > Passes if the incoming depth value is less than or equal to the stored
-    /// > depth value.
-    ///
-    /// [`GL_GREATER`](crate::gl_enums::GL_GREATER)
-    ///
-    /// > Passes if the incoming depth value is greater than the stored depth value.
-    ///
-    /// [`GL_NOTEQUAL`](crate::gl_enums::GL_NOTEQUAL)
-    ///
-    /// > Passes if the incoming depth value is not equal to the stored depth value.
+    /// `red`
     ///
-    /// [`GL_GEQUAL`](crate::gl_enums::GL_GEQUAL)
+    /// `green`
     ///
-    /// > Passes if the incoming depth value is greater than or equal to the stored
-    /// > depth value.
+    /// `blue`
     ///
-    /// [`GL_ALWAYS`](crate::gl_enums::GL_ALWAYS)
+    /// `alpha`
     ///
-    /// > Always passes.
+    /// > specify the components of [`GL_BLEND_COLOR`](crate::gl_enums::GL_BLEND_COLOR)
     ///
-    /// The initial value of `func` is [`GL_LESS`](crate::gl_enums::GL_LESS). Initially,
-    /// depth testing is disabled. If depth testing is disabled or if no depth
-    /// buffer exists, it is as if the depth test always passes.
+    /// ### Description
+    /// The [`GL_BLEND_COLOR`](crate::gl_enums::GL_BLEND_COLOR) may be used to
+    /// calculate the source and destination blending factors. The color components
+    /// are clamped to the range `[inlineq]` [**glBlendFunc**](crate::context::Context::oxidegl_blend_func)
+    /// for a complete description of the blending operations. Initially the [`GL_BLEND_COLOR`](crate::gl_enums::GL_BLEND_COLOR)
+    /// is set to (0, 0, 0, 0).
     ///
     /// ### Notes
-    /// Even if the depth buffer exists and the depth mask is non-zero, the depth
-    /// buffer is not updated if the depth test is disabled. In order to unconditionally
-    /// write to the depth buffer, the depth test should be enabled and set to
-    /// [`GL_ALWAYS`](crate::gl_enums::GL_ALWAYS).
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_DEPTH_FUNC`](crate::gl_enums::GL_DEPTH_FUNC)
-    ///
-    /// [**glIsEnabled**](crate::context::Context::oxidegl_is_enabled) with argument
-    /// [`GL_DEPTH_TEST`](crate::gl_enums::GL_DEPTH_TEST)
-    pub fn oxidegl_depth_func(&mut self, func: DepthFunction) -> GlFallible {
-        panic!("command oxidegl_depth_func not yet implemented");
-    }
-    /// ### Parameters
-    /// `flag`
-    ///
-    /// > Specifies whether the depth buffer is enabled for writing. If `flag` is
-    /// > [`GL_FALSE`](crate::gl_enums::GL_FALSE), depth buffer writing is disabled.
-    /// > Otherwise, it is enabled. Initially, depth buffer writing is enabled.
-    ///
-    /// ### Description
-    /// [**glDepthMask**](crate::context::Context::oxidegl_depth_mask) specifies
-    /// whether the depth buffer is enabled for writing. If `flag` is [`GL_FALSE`](crate::gl_enums::GL_FALSE),
-    /// depth buffer writing is disabled. Otherwise, it is enabled. Initially,
-    /// depth buffer writing is enabled.
+    /// The type of the `red`, `green`, `blue`, and `alpha` parameters was changed
+    /// from `GLclampf` to `GLfloat`. This change is transparent to user code and is
+    /// described in detail on the [**removedTypes**](crate::context::Context::oxideremoved_types)
+    /// page.
     ///
     /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_DEPTH_WRITEMASK`](crate::gl_enums::GL_DEPTH_WRITEMASK)
-    ///
-    /// ### Notes
-    /// Even if the depth buffer exists and the depth mask is non-zero, the depth
-    /// buffer is not updated if the depth test is disabled. In order to unconditionally
-    /// write to the depth buffer, the depth test should be enabled and set to
-    /// [`GL_ALWAYS`](crate::gl_enums::GL_ALWAYS) (see [**glDepthFunc**](crate::context::Context::oxidegl_depth_func)
-    /// ).
-    pub fn oxidegl_depth_mask(&mut self, flag: GLboolean) -> GlFallible {
-        panic!("command oxidegl_depth_mask not yet implemented");
+    /// [**glGet**](crate::context::Context::oxidegl_get) with an argument of [`GL_BLEND_COLOR`](crate::gl_enums::GL_BLEND_COLOR)
+    pub fn oxidegl_blend_color(
+        &mut self,
+        red: GLfloat,
+        green: GLfloat,
+        blue: GLfloat,
+        alpha: GLfloat,
+    ) -> GlFallible {
+        panic!("command oxidegl_blend_color not yet implemented");
     }
     /// ### Parameters
-    /// `first`
-    ///
-    /// > Specifies the index of the first viewport whose depth range to update.
-    ///
-    /// `count`
+    /// `target`
     ///
-    /// > Specifies the number of viewports whose depth range to update.
+    /// > Target for color clamping. `target` must be [`GL_CLAMP_READ_COLOR`](crate::gl_enums::GL_CLAMP_READ_COLOR).
     ///
-    /// `v`
+    /// `clamp`
     ///
-    /// > Specifies the address of an array containing the near and far values for
-    /// > the depth range of each modified viewport.
+    /// > Specifies whether to apply color clamping. `clamp` must be [`GL_TRUE`](crate::gl_enums::GL_TRUE)
+    /// > or [`GL_FALSE`](crate::gl_enums::GL_FALSE).
     ///
     /// ### Description
-    /// After clipping and division by *w*, depth coordinates range from `[inlineq]`
-    /// [**glDepthRangeArray**](crate::context::Context::oxidegl_depth_range_array)
-    /// specifies a linear mapping of the normalized depth coordinates in this
-    /// range to window depth coordinates for each viewport in the range \[ `first`,
-    /// `first`+ `count` ). Thus, the values accepted by [**glDepthRangeArray**](crate::context::Context::oxidegl_depth_range_array)
-    /// are both clamped to this range before they are accepted.
-    ///
-    /// The `first` parameter specifies the index of the first viewport whose depth
-    /// range to modify and must be less than the value of [`GL_MAX_VIEWPORTS`](crate::gl_enums::GL_MAX_VIEWPORTS).
-    /// `count` specifies the number of viewports whose depth range to modify.
-    /// `first`+ `count` must be less than or equal to the value of [`GL_MAX_VIEWPORTS`](crate::gl_enums::GL_MAX_VIEWPORTS).
-    /// `v` specifies the address of an array of pairs of double precision floating
-    /// point values representing the near and far values of the depth range for
-    /// each viewport, in that order.
-    ///
-    /// The setting of (0,1) maps the near plane to 0 and the far plane to 1. With
-    /// this mapping, the depth buffer range is fully utilized.
-    ///
-    /// ### Notes
-    /// It is not necessary that the near plane distance be less than the far plane
-    /// distance. Reverse mappings such as `[inlineq]` `[inlineq]`
-    ///
-    /// The type of the `v` parameter was changed from `GLclampd` to `GLdouble`. This
-    /// change is transparent to user code and is described in detail on the [**removedTypes**](crate::context::Context::oxideremoved_types)
-    /// page.
+    /// [**glClampColor**](crate::context::Context::oxidegl_clamp_color) controls
+    /// color clamping that is performed during [**glReadPixels**](crate::context::Context::oxidegl_read_pixels).
+    /// `target` must be [`GL_CLAMP_READ_COLOR`](crate::gl_enums::GL_CLAMP_READ_COLOR).
+    /// If `clamp` is [`GL_TRUE`](crate::gl_enums::GL_TRUE), read color clamping
+    /// is enabled; if `clamp` is [`GL_FALSE`](crate::gl_enums::GL_FALSE), read
+    /// color clamping is disabled. If `clamp` is [`GL_FIXED_ONLY`](crate::gl_enums::GL_FIXED_ONLY),
+    /// read color clamping is enabled only if the selected read buffer has fixed
+    /// point components and disabled otherwise.
     ///
     /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_DEPTH_RANGE`](crate::gl_enums::GL_DEPTH_RANGE)
-    pub unsafe fn oxidegl_depth_range_arrayv(
-        &mut self,
-        first: GLuint,
-        count: GLsizei,
-        v: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_depth_range_arrayv not yet implemented");
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_CLAMP_READ_COLOR`](crate::gl_enums::GL_CLAMP_READ_COLOR).
+    pub fn oxidegl_clamp_color(&mut self, target: GLenum, clamp: ClampColorMode) -> GlFallible {
+        panic!("command oxidegl_clamp_color not yet implemented");
     }
     /// ### Parameters
-    /// `index`
+    /// `texture`
+    ///
+    /// > The name of an existing texture object containing the image to be cleared.
+    ///
+    /// `level`
+    ///
+    /// > The level of `texture` containing the region to be cleared.
+    ///
+    /// `format`
     ///
-    /// > Specifies the index of the viewport whose depth range to update.
+    /// > The format of the data whose address in memory is given by `data`.
     ///
-    /// `nearVal`
+    /// `type`
     ///
-    /// > Specifies the mapping of the near clipping plane to window coordinates.
-    /// > The initial value is 0.
+    /// > The type of the data whose address in memory is given by `data`.
     ///
-    /// `farVal`
+    /// `data`
     ///
-    /// > Specifies the mapping of the far clipping plane to window coordinates.
-    /// > The initial value is 1.
+    /// > The address in memory of the data to be used to clear the specified region.
     ///
     /// ### Description
-    /// After clipping and division by *w*, depth coordinates range from `[inlineq]`
-    /// [**glDepthRangeIndexed**](crate::context::Context::oxidegl_depth_range_indexed)
-    /// specifies a linear mapping of the normalized depth coordinates in this
-    /// range to window depth coordinates for a specified viewport. Thus, the values
-    /// accepted by [**glDepthRangeIndexed**](crate::context::Context::oxidegl_depth_range_indexed)
-    /// are both clamped to this range before they are accepted.
+    /// [**glClearTexImage**](crate::context::Context::oxidegl_clear_tex_image)
+    /// fills all an image contained in a texture with an application supplied
+    /// value. `texture` must be the name of an existing texture. Further, `texture`
+    /// may not be the name of a buffer texture, nor may its internal format be
+    /// compressed.
     ///
-    /// The `index` parameter specifies the index of first viewport whose depth
-    /// range to modify and must be less than the value of [`GL_MAX_VIEWPORTS`](crate::gl_enums::GL_MAX_VIEWPORTS).
-    /// `nearVal` and `farVal` specify near and far values of the depth range for
-    /// the specified viewport, respectively.
+    /// `format` and `type` specify the format and type of the source data and
+    /// are interpreted as they are for [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d).
+    /// Textures with a base internal format of [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT),
+    /// [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX), or [`GL_DEPTH_STENCIL`](crate::gl_enums::GL_DEPTH_STENCIL)
+    /// require depth component, stencil, or depth-stencil component data respectively.
+    /// Textures with other base internal formats require RGBA formats. Textures
+    /// with integer internal formats require integer data.
     ///
-    /// The setting of (0,1) maps the near plane to 0 and the far plane to 1. With
-    /// this mapping, the depth buffer range is fully utilized.
+    /// `data` is a pointer to an array of between one and four components of texel
+    /// data that will be used as the source for the constant fill value. The elements
+    /// of data are converted by the GL into the internal format of the texture
+    /// image (that was specified when the level was defined by any of the [**glTexImage***](crate::context::Context::oxidegl_tex_image*),
+    /// [**glTexStorage***](crate::context::Context::oxidegl_tex_storage*) or [**glCopyTexImage***](crate::context::Context::oxidegl_copy_tex_image*)
+    /// commands), and then used to fill the specified range of the destination
+    /// texture level. If `data` is [`NULL`](crate::gl_enums::NULL), then the pointer
+    /// is ignored and the sub-range of the texture image is filled with zeros.
+    /// If texture is a multisample texture, all the samples in a texel are cleared
+    /// to the value specified by data.
     ///
     /// ### Notes
-    /// It is not necessary that the near plane distance be less than the far plane
-    /// distance. Reverse mappings such as `[inlineq]` `[inlineq]`
-    ///
-    /// The type of the `nearVal` and `farVal` parameters was changed from `GLclampd`
-    /// to `GLdouble`. This change is transparent to user code and is described in
-    /// detail on the [**removedTypes**](crate::context::Context::oxideremoved_types)
-    /// page.
+    /// [**glClearTexImage**](crate::context::Context::oxidegl_clear_tex_image)
+    /// is available only if the GL version is 4.4 or greater.
     ///
     /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_DEPTH_RANGE`](crate::gl_enums::GL_DEPTH_RANGE)
-    pub fn oxidegl_depth_range_indexed(
+    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image), [**glGetInternalformat**](crate::context::Context::oxidegl_get_internalformat)
+    pub unsafe fn oxidegl_clear_tex_image(
         &mut self,
-        index: GLuint,
-        n: GLdouble,
-        f: GLdouble,
+        texture: GLuint,
+        level: GLint,
+        format: PixelFormat,
+        r#type: PixelType,
+        data: *const GLvoid,
     ) -> GlFallible {
-        panic!("command oxidegl_depth_range_indexed not yet implemented");
+        panic!("command oxidegl_clear_tex_image not yet implemented");
     }
     /// ### Parameters
-    /// `num_groups_x`
+    /// `texture`
     ///
-    /// > The number of work groups to be launched in the X dimension.
+    /// > The name of an existing texture object containing the image to be cleared.
     ///
-    /// `num_groups_y`
+    /// `level`
     ///
-    /// > The number of work groups to be launched in the Y dimension.
+    /// > The level of `texture` containing the region to be cleared.
     ///
-    /// `num_groups_z`
+    /// `xoffset`
     ///
-    /// > The number of work groups to be launched in the Z dimension.
+    /// > The coordinate of the left edge of the region to be cleared.
     ///
-    /// ### Description
-    /// [**glDispatchCompute**](crate::context::Context::oxidegl_dispatch_compute)
-    /// launches one or more compute work groups. Each work group is processed
-    /// by the active program object for the compute shader stage. While the individual
-    /// shader invocations within a work group are executed as a unit, work groups
-    /// are executed completely independently and in unspecified order. `num_groups_x`,
-    /// `num_groups_y` and `num_groups_z` specify the number of local work groups
-    /// that will be dispatched in the X, Y and Z dimensions, respectively.
+    /// `yoffset`
     ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_COMPUTE_WORK_GROUP_COUNT`](crate::gl_enums::GL_MAX_COMPUTE_WORK_GROUP_COUNT)
-    pub fn oxidegl_dispatch_compute(
-        &mut self,
-        num_groups_x: GLuint,
-        num_groups_y: GLuint,
-        num_groups_z: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_dispatch_compute not yet implemented");
-    }
-    /// ### Parameters
-    /// `indirect`
+    /// > The coordinate of the lower edge of the region to be cleared.
     ///
-    /// > The offset into the buffer object currently bound to the [`GL_DISPATCH_INDIRECT_BUFFER`](crate::gl_enums::GL_DISPATCH_INDIRECT_BUFFER)
-    /// > buffer target at which the dispatch parameters are stored.
+    /// `zoffset`
     ///
-    /// ### Description
-    /// [**glDispatchComputeIndirect**](crate::context::Context::oxidegl_dispatch_compute_indirect)
-    /// launches one or more compute work groups using parameters stored in the
-    /// buffer object currently bound to the [`GL_DISPATCH_INDIRECT_BUFFER`](crate::gl_enums::GL_DISPATCH_INDIRECT_BUFFER)
-    /// target. Each work group is processed by the active program object for the
-    /// compute shader stage. While the individual shader invocations within a
-    /// work group are executed as a unit, work groups are executed completely
-    /// independently and in unspecified order. `indirect` contains the offset
-    /// into the data store of the buffer object bound to the [`GL_DISPATCH_INDIRECT_BUFFER`](crate::gl_enums::GL_DISPATCH_INDIRECT_BUFFER)
-    /// target at which the parameters are stored.
-    ///
-    /// The parameters addressed by `indirect` are packed a structure, which takes
-    /// the form (in C):
-    ///
-    /// A call to [**glDispatchComputeIndirect**](crate::context::Context::oxidegl_dispatch_compute_indirect)
-    /// is equivalent, assuming no errors are generated, to:
-    ///
-    /// Unlike [**glDispatchCompute**](crate::context::Context::oxidegl_dispatch_compute),
-    /// no error is generated if any of the [`GL_MAX_COMPUTE_WORK_GROUP_COUNT`](crate::gl_enums::GL_MAX_COMPUTE_WORK_GROUP_COUNT)
-    /// for the corresponding dimension. In such circumstances, behavior is undefined
-    /// and may lead to application termination.
+    /// > The coordinate of the front of the region to be cleared.
     ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_COMPUTE_WORK_GROUP_COUNT`](crate::gl_enums::GL_MAX_COMPUTE_WORK_GROUP_COUNT)
-    pub fn oxidegl_dispatch_compute_indirect(&mut self, indirect: GLintptr) -> GlFallible {
-        panic!("command oxidegl_dispatch_compute_indirect not yet implemented");
-    }
-    /// ### Parameters
-    /// `condition`
+    /// `width`
     ///
-    /// > Specifies the condition that must be met to set the sync object's state
-    /// > to signaled. `condition` must be [`GL_SYNC_GPU_COMMANDS_COMPLETE`](crate::gl_enums::GL_SYNC_GPU_COMMANDS_COMPLETE).
+    /// > The width of the region to be cleared.
     ///
-    /// `flags`
+    /// `height`
     ///
-    /// > Specifies a bitwise combination of flags controlling the behavior of the
-    /// > sync object. No flags are presently defined for this operation and `flags`
-    /// > must be zero. `flags` is a placeholder for anticipated future extensions
-    /// > of fence sync object capabilities.
+    /// > The height of the region to be cleared.
     ///
+    /// `depth`
     ///
-    /// ### Description
-    /// [**glFenceSync**](crate::context::Context::oxidegl_fence_sync) creates
-    /// a new fence sync object, inserts a fence command into the GL command stream
-    /// and associates it with that sync object, and returns a non-zero name corresponding
-    /// to the sync object.
+    /// > The depth of the region to be cleared.
     ///
-    /// When the specified `condition` of the sync object is satisfied by the fence
-    /// command, the sync object is signaled by the GL, causing any [**glWaitSync**](crate::context::Context::oxidegl_wait_sync),
-    /// [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
-    /// commands blocking in `sync` to *unblock*. No other state is affected by
-    /// [**glFenceSync**](crate::context::Context::oxidegl_fence_sync) or by the
-    /// execution of the associated fence command.
+    /// `format`
     ///
-    /// `condition` must be [`GL_SYNC_GPU_COMMANDS_COMPLETE`](crate::gl_enums::GL_SYNC_GPU_COMMANDS_COMPLETE).
-    /// This condition is satisfied by completion of the fence command corresponding
-    /// to the sync object and all preceding commands in the same command stream.
-    /// The sync object will not be signaled until all effects from these commands
-    /// on GL client and server state and the framebuffer are fully realized. Note
-    /// that completion of the fence command occurs once the state of the corresponding
-    /// sync object has been changed, but commands waiting on that sync object
-    /// may not be unblocked until after the fence command completes.
+    /// > The format of the data whose address in memory is given by `data`.
     ///
-    /// ### Notes
-    /// [**glFenceSync**](crate::context::Context::oxidegl_fence_sync) is only
-    /// supported if the GL version is 3.2 or greater, or if the
-    pub fn oxidegl_fence_sync(
-        &mut self,
-        condition: GLenum,
-        flags: GLbitfield,
-    ) -> GlFallible<GLsync> {
-        panic!("command oxidegl_fence_sync not yet implemented");
-    }
-    /// ### Description
-    /// [**glFinish**](crate::context::Context::oxidegl_finish) does not return
-    /// until the effects of all previously called GL commands are complete. Such
-    /// effects include all changes to GL state, all changes to connection state,
-    /// and all changes to the frame buffer contents.
+    /// `type`
+    ///
+    /// > The type of the data whose address in memory is given by `data`.
+    ///
+    /// `data`
+    ///
+    /// > The address in memory of the data to be used to clear the specified region.
     ///
-    /// ### Notes
-    /// [**glFinish**](crate::context::Context::oxidegl_finish) requires a round
-    /// trip to the server.
-    pub fn oxidegl_finish(&mut self) -> GlFallible {
-        panic!("command oxidegl_finish not yet implemented");
-    }
     /// ### Description
-    /// Different GL implementations buffer commands in several different locations,
-    /// including network buffers and the graphics accelerator itself. [**glFlush**](crate::context::Context::oxidegl_flush)
-    /// empties all of these buffers, causing all issued commands to be executed
-    /// as quickly as they are accepted by the actual rendering engine. Though
-    /// this execution may not be completed in any particular time period, it does
-    /// complete in finite time.
+    /// [**glClearTexSubImage**](crate::context::Context::oxidegl_clear_tex_sub_image)
+    /// fills all or part of an image contained in a texture with an application
+    /// supplied value. `texture` must be the name of an existing texture. Further,
+    /// `texture` may not be the name of a buffer texture, nor may its internal
+    /// format be compressed.
     ///
-    /// Because any GL program might be executed over a network, or on an accelerator
-    /// that buffers commands, all programs should call [**glFlush**](crate::context::Context::oxidegl_flush)
-    /// whenever they count on having all of their previously issued commands completed.
-    /// For example, call [**glFlush**](crate::context::Context::oxidegl_flush)
-    /// before waiting for user input that depends on the generated image.
+    /// Arguments `xoffset`, `yoffset`, and `zoffset` specify the lower left texel
+    /// coordinates of a width-wide by height-high by depth-deep rectangular subregion
+    /// of the texel array.
     ///
-    /// ### Notes
-    /// [**glFlush**](crate::context::Context::oxidegl_flush) can return at any
-    /// time. It does not wait until the execution of all previously issued GL
-    /// commands is complete.
-    pub fn oxidegl_flush(&mut self) -> GlFallible {
-        panic!("command oxidegl_flush not yet implemented");
-    }
-    /// ### Parameters
-    /// `mode`
+    /// For one-dimensional array textures, `yoffset` is interpreted as the first
+    /// layer to be cleared and `height` is the number of layers to clear. For
+    /// two-dimensional array textures, `zoffset` is interpreted as the first layer
+    /// to be cleared and `depth` is the number of layers to clear. Cube map textures
+    /// are treated as an array of six slices in the z-dimension, where the value
+    /// of `zoffset` is interpreted as specifying the cube map face for the corresponding
+    /// layer and `depth` is the number of faces to clear. For cube map array textures,
+    /// `zoffset` is the first layer-face to clear, and `depth` is the number of
+    /// layer-faces to clear. Each layer-face is translated into an array layer
+    /// and a cube map face as described in the OpenGL Specification.
     ///
-    /// > Specifies the orientation of front-facing polygons. [`GL_CW`](crate::gl_enums::GL_CW)
-    /// > and [`GL_CCW`](crate::gl_enums::GL_CCW) are accepted. The initial value
-    /// > is [`GL_CCW`](crate::gl_enums::GL_CCW).
+    /// Negative values of `xoffset`, `yoffset`, and `zoffset` correspond to the
+    /// coordinates of border texels. Taking `[inlineq]` `[inlineq]` `[inlineq]`
+    /// `[inlineq]` `[inlineq]` `[inlineq]` `width`, `height`, `depth`, and the
+    /// border width, border height, and border depth of the texel array and taking
+    /// `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
+    /// `xoffset`, `yoffset`, `zoffset`, `width`, `height`, and `depth` argument
+    /// values, any of the following relationships generates a [`GL_INVALID_OPERATION`](crate::gl_enums::GL_INVALID_OPERATION)
+    /// error:
     ///
-    /// ### Description
-    /// In a scene composed entirely of opaque closed surfaces, back-facing polygons
-    /// are never visible. Eliminating these invisible polygons has the obvious
-    /// benefit of speeding up the rendering of the image. To enable and disable
-    /// elimination of back-facing polygons, call [**glEnable**](crate::context::Context::oxidegl_enable)
-    /// and [**glDisable**](crate::context::Context::oxidegl_disable) with argument
-    /// [`GL_CULL_FACE`](crate::gl_enums::GL_CULL_FACE).
+    /// For texture types that do not have certain dimensions, this command treats
+    /// those dimensions as having a size of 1. For example, to clear a portion
+    /// of a two-dimensional texture, use `zoffset` equal to zero and `depth` equal
+    /// to one.
     ///
-    /// The projection of a polygon to window coordinates is said to have clockwise
-    /// winding if an imaginary object following the path from its first vertex,
-    /// its second vertex, and so on, to its last vertex, and finally back to its
-    /// first vertex, moves in a clockwise direction about the interior of the
-    /// polygon. The polygon's winding is said to be counterclockwise if the imaginary
-    /// object following the same path moves in a counterclockwise direction about
-    /// the interior of the polygon. [**glFrontFace**](crate::context::Context::oxidegl_front_face)
-    /// specifies whether polygons with clockwise winding in window coordinates,
-    /// or counterclockwise winding in window coordinates, are taken to be front-facing.
-    /// Passing [`GL_CCW`](crate::gl_enums::GL_CCW) to `mode` selects counterclockwise
-    /// polygons as front-facing; [`GL_CW`](crate::gl_enums::GL_CW) selects clockwise
-    /// polygons as front-facing. By default, counterclockwise polygons are taken
-    /// to be front-facing.
+    /// `format` and `type` specify the format and type of the source data and
+    /// are interpreted as they are for [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d).
+    /// Textures with a base internal format of [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT),
+    /// [`GL_STENCIL_INDEX`](crate::gl_enums::GL_STENCIL_INDEX), or [`GL_DEPTH_STENCIL`](crate::gl_enums::GL_DEPTH_STENCIL)
+    /// require depth component, stencil, or depth-stencil component data respectively.
+    /// Textures with other base internal formats require RGBA formats. Textures
+    /// with integer internal formats require integer data.
+    ///
+    /// `data` is a pointer to an array of between one and four components of texel
+    /// data that will be used as the source for the constant fill value. The elements
+    /// of data are converted by the GL into the internal format of the texture
+    /// image (that was specified when the level was defined by any of the [**glTexImage***](crate::context::Context::oxidegl_tex_image*),
+    /// [**glTexStorage***](crate::context::Context::oxidegl_tex_storage*) or [**glCopyTexImage***](crate::context::Context::oxidegl_copy_tex_image*)
+    /// commands), and then used to fill the specified range of the destination
+    /// texture level. If `data` is [`NULL`](crate::gl_enums::NULL), then the pointer
+    /// is ignored and the sub-range of the texture image is filled with zeros.
+    /// If texture is a multisample texture, all the samples in a texel are cleared
+    /// to the value specified by data.
+    ///
+    /// ### Notes
+    /// [**glClearTexSubImage**](crate::context::Context::oxidegl_clear_tex_sub_image)
+    /// is available only if the GL version is 4.4 or greater.
     ///
     /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_FRONT_FACE`](crate::gl_enums::GL_FRONT_FACE)
-    pub fn oxidegl_front_face(&mut self, mode: FrontFaceDirection) -> GlFallible {
-        panic!("command oxidegl_front_face not yet implemented");
+    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image), [**glGetInternalformat**](crate::context::Context::oxidegl_get_internalformat)
+    pub unsafe fn oxidegl_clear_tex_sub_image(
+        &mut self,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: PixelFormat,
+        r#type: PixelType,
+        data: *const GLvoid,
+    ) -> GlFallible {
+        panic!("command oxidegl_clear_tex_sub_image not yet implemented");
     }
     /// ### Parameters
-    /// `n`
+    /// `sync`
     ///
-    /// > Specifies the number of framebuffer object names to generate.
+    /// > The sync object whose status to wait on.
     ///
-    /// `ids`
+    /// `flags`
     ///
-    /// > Specifies an array in which the generated framebuffer object names are
-    /// > stored.
+    /// > A bitfield controlling the command flushing behavior. `flags` may be [`GL_SYNC_FLUSH_COMMANDS_BIT`](crate::gl_enums::GL_SYNC_FLUSH_COMMANDS_BIT).
     ///
-    /// ### Description
-    /// [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers)
-    /// returns `n` framebuffer object names in `ids`. There is no guarantee that
-    /// the names form a contiguous set of integers; however, it is guaranteed
-    /// that none of the returned names was in use immediately before the call
-    /// to [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers).
+    /// `timeout`
     ///
-    /// Framebuffer object names returned by a call to [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers)
-    /// are not returned by subsequent calls, unless they are first deleted with
-    /// [**glDeleteFramebuffers**](crate::context::Context::oxidegl_delete_framebuffers).
+    /// > The timeout, specified in nanoseconds, for which the implementation should
+    /// > wait for `sync` to become signaled.
     ///
-    /// The names returned in `ids` are marked as used, for the purposes of [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers)
-    /// only, but they acquire state and type only when they are first bound.
-    pub unsafe fn oxidegl_gen_framebuffers(
-        &mut self,
-        n: GLsizei,
-        framebuffers: *mut GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_gen_framebuffers not yet implemented");
-    }
-    /// ### Parameters
-    /// `n`
+    /// ### Description
+    /// [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
+    /// causes the client to block and wait for the sync object specified by `sync`
+    /// to become signaled. If `sync` is signaled when [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
+    /// is called, [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
+    /// returns immediately, otherwise it will block and wait for up to `timeout`
+    /// nanoseconds for `sync` to become signaled.
     ///
-    /// > Specifies the number of program pipeline object names to reserve.
+    /// The return value is one of four status values: [`GL_ALREADY_SIGNALED`](crate::gl_enums::GL_ALREADY_SIGNALED)
+    /// > indicates that `sync` was signaled at the time that [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
+    /// > was called.
     ///
-    /// `pipelines`
+    /// > [`GL_TIMEOUT_EXPIRED`](crate::gl_enums::GL_TIMEOUT_EXPIRED) indicates that
+    /// > at least `timeout` nanoseconds passed and `sync` did not become signaled.
     ///
-    /// > Specifies an array of into which the reserved names will be written.
+    /// > [`GL_CONDITION_SATISFIED`](crate::gl_enums::GL_CONDITION_SATISFIED) indicates
+    /// > that `sync` was signaled before the timeout expired.
     ///
-    /// ### Description
-    /// [**glGenProgramPipelines**](crate::context::Context::oxidegl_gen_program_pipelines)
-    /// returns `n` previously unused program pipeline object names in `pipelines`.
-    /// These names are marked as used, for the purposes of [**glGenProgramPipelines**](crate::context::Context::oxidegl_gen_program_pipelines)
-    /// only, but they acquire program pipeline state only when they are first
-    /// bound.
+    /// > [`GL_WAIT_FAILED`](crate::gl_enums::GL_WAIT_FAILED) indicates that an error
+    /// > occurred. Additionally, an OpenGL error will be generated.
     ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PROGRAM_PIPELINE_BINDING`](crate::gl_enums::GL_PROGRAM_PIPELINE_BINDING)
     ///
-    /// [**glIsProgramPipeline**](crate::context::Context::oxidegl_is_program_pipeline)
-    pub unsafe fn oxidegl_gen_program_pipelines(
+    /// ### Notes
+    /// [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
+    /// is available only if the GL version is 3.2 or greater.
+    pub fn oxidegl_client_wait_sync(
         &mut self,
-        n: GLsizei,
-        pipelines: *mut GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_gen_program_pipelines not yet implemented");
+        sync: GLsync,
+        flags: GLbitfield,
+        timeout: GLuint64,
+    ) -> GlFallible<GLenum> {
+        panic!("command oxidegl_client_wait_sync not yet implemented");
     }
     /// ### Parameters
-    /// `n`
-    ///
-    /// > Specifies the number of query object names to be generated.
-    ///
-    /// `ids`
-    ///
-    /// > Specifies an array in which the generated query object names are stored.
+    /// `target`
     ///
-    /// ### Description
-    /// [**glGenQueries**](crate::context::Context::oxidegl_gen_queries) returns
-    /// `n` query object names in `ids`. There is no guarantee that the names form
-    /// a contiguous set of integers; however, it is guaranteed that none of the
-    /// returned names was in use immediately before the call to [**glGenQueries**](crate::context::Context::oxidegl_gen_queries).
+    /// > Specifies the target texture. Must be [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D)
+    /// > or [`GL_PROXY_TEXTURE_1D`](crate::gl_enums::GL_PROXY_TEXTURE_1D).
     ///
-    /// Query object names returned by a call to [**glGenQueries**](crate::context::Context::oxidegl_gen_queries)
-    /// are not returned by subsequent calls, unless they are first deleted with
-    /// [**glDeleteQueries**](crate::context::Context::oxidegl_delete_queries).
+    /// `level`
     ///
-    /// No query objects are associated with the returned query object names until
-    /// they are first used by calling [**glBeginQuery**](crate::context::Context::oxidegl_begin_query).
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level *n* is the *n* th mipmap reduction image.
     ///
-    /// ### Associated Gets
-    /// [**glIsQuery**](crate::context::Context::oxidegl_is_query)
-    pub unsafe fn oxidegl_gen_queries(&mut self, n: GLsizei, ids: *mut GLuint) -> GlFallible {
-        panic!("command oxidegl_gen_queries not yet implemented");
-    }
-    /// ### Parameters
-    /// `n`
+    /// `internalformat`
     ///
-    /// > Specifies the number of renderbuffer object names to generate.
+    /// > Specifies the format of the compressed image data stored at address `data`.
     ///
-    /// `renderbuffers`
+    /// `width`
     ///
-    /// > Specifies an array in which the generated renderbuffer object names are
-    /// > stored.
+    /// > Specifies the width of the texture image. All implementations support texture
+    /// > images that are at least 64 texels wide. The height of the 1D texture image
+    /// > is 1.
     ///
-    /// ### Description
-    /// [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers)
-    /// returns `n` renderbuffer object names in `renderbuffers`. There is no guarantee
-    /// that the names form a contiguous set of integers; however, it is guaranteed
-    /// that none of the returned names was in use immediately before the call
-    /// to [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers).
+    /// `border`
     ///
-    /// Renderbuffer object names returned by a call to [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers)
-    /// are not returned by subsequent calls, unless they are first deleted with
-    /// [**glDeleteRenderbuffers**](crate::context::Context::oxidegl_delete_renderbuffers).
+    /// > This value must be 0.
     ///
-    /// The names returned in `renderbuffers` are marked as used, for the purposes
-    /// of [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers)
-    /// only, but they acquire state and type only when they are first bound.
-    pub unsafe fn oxidegl_gen_renderbuffers(
-        &mut self,
-        n: GLsizei,
-        renderbuffers: *mut GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_gen_renderbuffers not yet implemented");
-    }
-    /// ### Parameters
-    /// `n`
+    /// `imageSize`
     ///
-    /// > Specifies the number of sampler object names to generate.
+    /// > Specifies the number of unsigned bytes of image data starting at the address
+    /// > specified by `data`.
     ///
-    /// `samplers`
+    /// `data`
     ///
-    /// > Specifies an array in which the generated sampler object names are stored.
+    /// > Specifies a pointer to the compressed image data in memory.
     ///
     /// ### Description
-    /// [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers) returns
-    /// `n` sampler object names in `samplers`. There is no guarantee that the
-    /// names form a contiguous set of integers; however, it is guaranteed that
-    /// none of the returned names was in use immediately before the call to [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers).
+    /// Texturing allows elements of an image array to be read by shaders.
     ///
-    /// Sampler object names returned by a call to [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers)
-    /// are not returned by subsequent calls, unless they are first deleted with
-    /// [**glDeleteSamplers**](crate::context::Context::oxidegl_delete_samplers).
+    /// [**glCompressedTexImage1D**](crate::context::Context::oxidegl_compressed_tex_image1_d)
+    /// loads a previously defined, and retrieved, compressed one-dimensional texture
+    /// image if `target` is [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D)
+    /// (see [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d)
+    /// ).
     ///
-    /// The names returned in `samplers` are marked as used, for the purposes of
-    /// [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers) only,
-    /// but they acquire state and type only when they are first bound.
+    /// If `target` is [`GL_PROXY_TEXTURE_1D`](crate::gl_enums::GL_PROXY_TEXTURE_1D),
+    /// no data is read from `data`, but all of the texture image state is recalculated,
+    /// checked for consistency, and checked against the implementation's capabilities.
+    /// If the implementation cannot handle a texture of the requested texture
+    /// size, it sets all of the image state to 0, but does not generate an error
+    /// (see [**glGetError**](crate::context::Context::oxidegl_get_error) ). To
+    /// query for an entire mipmap array, use an image array level greater than
+    /// or equal to 1.
     ///
-    /// ### Notes
-    /// [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers) is available
-    /// only if the GL version is 3.3 or higher.
-    pub unsafe fn oxidegl_gen_samplers(
-        &mut self,
-        count: GLsizei,
-        samplers: *mut GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_gen_samplers not yet implemented");
-    }
-    /// ### Parameters
-    /// `n`
+    /// `internalformat` must be an extension-specified compressed-texture format.
+    /// When a texture is loaded with [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d)
+    /// using a generic compressed texture format (e.g., [`GL_COMPRESSED_RGB`](crate::gl_enums::GL_COMPRESSED_RGB))
+    /// the GL selects from one of its extensions supporting compressed textures.
+    /// In order to load the compressed texture image using [**glCompressedTexImage1D**](crate::context::Context::oxidegl_compressed_tex_image1_d),
+    /// query the compressed texture image's size and format using [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
     ///
-    /// > Specifies the number of texture names to be generated.
+    /// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER)
+    /// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+    /// while a texture image is specified, `data` is treated as a byte offset
+    /// into the buffer object's data store.
     ///
-    /// `textures`
+    /// If the compressed data are arranged into fixed-size blocks of texels, the
+    /// pixel storage modes can be used to select a sub-rectangle from a larger
+    /// containing rectangle. These pixel storage modes operate in the same way
+    /// as they do for [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d).
+    /// In the following description, denote by `[inlineq]` `[inlineq]` `[inlineq]`
+    /// `[inlineq]` [`GL_UNPACK_COMPRESSED_BLOCK_SIZE`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_SIZE),
+    /// [`GL_UNPACK_COMPRESSED_BLOCK_WIDTH`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_WIDTH),
+    /// [`GL_UNPACK_COMPRESSED_BLOCK_HEIGHT`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_HEIGHT),
+    /// and [`GL_UNPACK_COMPRESSED_BLOCK_DEPTH`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_DEPTH),
+    /// respectively. `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
     ///
-    /// > Specifies an array in which the generated texture names are stored.
+    /// By default the pixel storage modes [`GL_UNPACK_ROW_LENGTH`](crate::gl_enums::GL_UNPACK_ROW_LENGTH),
+    /// [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS), [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS),
+    /// [`GL_UNPACK_IMAGE_HEIGHT`](crate::gl_enums::GL_UNPACK_IMAGE_HEIGHT) and
+    /// [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES) are ignored
+    /// for compressed images. To enable [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS)
+    /// and [`GL_UNPACK_ROW_LENGTH`](crate::gl_enums::GL_UNPACK_ROW_LENGTH), `[inlineq]`
+    /// `[inlineq]` [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS)
+    /// and [`GL_UNPACK_IMAGE_HEIGHT`](crate::gl_enums::GL_UNPACK_IMAGE_HEIGHT),
+    /// `[inlineq]` [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES),
+    /// `[inlineq]`
     ///
-    /// ### Description
-    /// [**glGenTextures**](crate::context::Context::oxidegl_gen_textures) returns
-    /// `n` texture names in `textures`. There is no guarantee that the names form
-    /// a contiguous set of integers; however, it is guaranteed that none of the
-    /// returned names was in use immediately before the call to [**glGenTextures**](crate::context::Context::oxidegl_gen_textures).
+    /// When selecting a sub-rectangle from a compressed image, the value of [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS)
+    /// > must be a multiple of `[inlineq]`
     ///
-    /// The generated textures have no dimensionality; they assume the dimensionality
-    /// of the texture target to which they are first bound (see [**glBindTexture**](crate::context::Context::oxidegl_bind_texture)
-    /// ).
     ///
-    /// Texture names returned by a call to [**glGenTextures**](crate::context::Context::oxidegl_gen_textures)
-    /// are not returned by subsequent calls, unless they are first deleted with
-    /// [**glDeleteTextures**](crate::context::Context::oxidegl_delete_textures).
+    /// `imageSize` must be equal to:
     ///
-    /// ### Associated Gets
-    /// [**glIsTexture**](crate::context::Context::oxidegl_is_texture)
-    pub unsafe fn oxidegl_gen_textures(&mut self, n: GLsizei, textures: *mut GLuint) -> GlFallible {
-        panic!("command oxidegl_gen_textures not yet implemented");
-    }
-    /// ### Parameters
-    /// `n`
+    /// `[inlineq]`
     ///
-    /// > Specifies the number of transform feedback object names to reserve.
+    /// ### Associated Gets
+    /// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
     ///
-    /// `ids`
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_COMPRESSED`](crate::gl_enums::GL_TEXTURE_COMPRESSED)
     ///
-    /// > Specifies an array of into which the reserved names will be written.
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_NUM_COMPRESSED_TEXTURE_FORMATS`](crate::gl_enums::GL_NUM_COMPRESSED_TEXTURE_FORMATS)
     ///
-    /// ### Description
-    /// [**glGenTransformFeedbacks**](crate::context::Context::oxidegl_gen_transform_feedbacks)
-    /// returns `n` previously unused transform feedback object names in `ids`.
-    /// These names are marked as used, for the purposes of [**glGenTransformFeedbacks**](crate::context::Context::oxidegl_gen_transform_feedbacks)
-    /// only, but they acquire transform feedback state only when they are first
-    /// bound.
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_COMPRESSED_TEXTURE_FORMATS`](crate::gl_enums::GL_COMPRESSED_TEXTURE_FORMATS)
     ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TRANSFORM_FEEDBACK_BINDING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BINDING)
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
     ///
-    /// [**glIsTransformFeedback**](crate::context::Context::oxidegl_is_transform_feedback)
-    pub unsafe fn oxidegl_gen_transform_feedbacks(
+    /// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+    /// with arguments [`GL_TEXTURE_INTERNAL_FORMAT`](crate::gl_enums::GL_TEXTURE_INTERNAL_FORMAT)
+    /// and [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::gl_enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
+    pub unsafe fn oxidegl_compressed_tex_image1_d(
         &mut self,
-        n: GLsizei,
-        ids: *mut GLuint,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        border: GLint,
+        image_size: GLsizei,
+        data: *const GLvoid,
     ) -> GlFallible {
-        panic!("command oxidegl_gen_transform_feedbacks not yet implemented");
+        panic!("command oxidegl_compressed_tex_image1_d not yet implemented");
     }
     /// ### Parameters
-    /// `program`
-    ///
-    /// > The name of a program object from which to retrieve information.
-    ///
-    /// `bufferIndex`
+    /// `target`
     ///
-    /// > Specifies index of an active atomic counter buffer.
+    /// > Specifies the target texture. Must be [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D),
+    /// > [`GL_PROXY_TEXTURE_2D`](crate::gl_enums::GL_PROXY_TEXTURE_2D), [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY),
+    /// > [`GL_PROXY_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_PROXY_TEXTURE_1D_ARRAY),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z),
+    /// > or [`GL_PROXY_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_PROXY_TEXTURE_CUBE_MAP).
     ///
-    /// `pname`
+    /// `level`
     ///
-    /// > Specifies which parameter of the atomic counter buffer to retrieve.
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level *n* is the *n* th mipmap reduction image.
     ///
-    /// `params`
+    /// `internalformat`
     ///
-    /// > Specifies the address of a variable into which to write the retrieved information.
+    /// > Specifies the format of the compressed image data stored at address `data`.
     ///
-    /// ### Description
-    /// [**glGetActiveAtomicCounterBufferiv**](crate::context::Context::oxidegl_get_active_atomic_counter_bufferiv)
-    /// retrieves information about the set of active atomic counter buffers for
-    /// a program object. `program` is the name of a program object for which the
-    /// command [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// has been issued in the past. It is not necessary for `program` to have
-    /// been linked successfully. The link may have failed because the number of
-    /// active atomic counters exceeded the limits.
+    /// `width`
     ///
-    /// `bufferIndex` specifies the index of an active atomic counter buffer and
-    /// must be in the range zero to the value of [`GL_ACTIVE_ATOMIC_COUNTER_BUFFERS`](crate::gl_enums::GL_ACTIVE_ATOMIC_COUNTER_BUFFERS)
-    /// minus one. The value of [`GL_ACTIVE_ATOMIC_COUNTER_BUFFERS`](crate::gl_enums::GL_ACTIVE_ATOMIC_COUNTER_BUFFERS)
-    /// for `program` indicates the number of active atomic counter buffer and
-    /// can be queried with [**glGetProgram**](crate::context::Context::oxidegl_get_program).
+    /// > Specifies the width of the texture image. All implementations support 2D
+    /// > texture and cube map texture images that are at least 16384 texels wide.
     ///
-    /// If no error occurs, the parameter(s) specified by `pname` are returned
-    /// in `params`. If an error is generated, the contents of `params` are not
-    /// modified.
+    /// `height`
     ///
-    /// If `pname` is [`GL_ATOMIC_COUNTER_BUFFER_BINDING`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_BINDING),
-    /// then the index of the counter buffer binding point associated with the
-    /// active atomic counter buffer `bufferIndex` for `program` is returned.
+    /// > Specifies the height of the texture image. All implementations support
+    /// > 2D texture and cube map texture images that are at least 16384 texels high.
     ///
-    /// If `pname` is [`GL_ATOMIC_COUNTER_BUFFER_DATA_SIZE`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_DATA_SIZE),
-    /// then the implementation-dependent minimum total buffer object size, in
-    /// baseic machine units, required to hold all active atomic counters in the
-    /// atomic counter binding point identified by `bufferIndex` is returned.
-    ///
-    /// If `pname` is [`GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTERS`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTERS),
-    /// then the number of active atomic counters for the atomic counter buffer
-    /// identified by `bufferIndex` is returned.
-    ///
-    /// If `pname` is [`GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTER_INDICES`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTER_INDICES),
-    /// then a list of the active atomic counter indices for the atomic counter
-    /// buffer identified by `bufferIndex` is returned. The number of elements
-    /// that will be written into `params` is the value of [`GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTERS`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_ACTIVE_ATOMIC_COUNTERS)
-    /// for `bufferIndex`.
-    ///
-    /// If `pname` is [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_VERTEX_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_VERTEX_SHADER),
-    /// [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_TESS_CONTROL_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_TESS_CONTROL_SHADER),
-    /// [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_TESS_EVALUATION_SHADER),
-    /// [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_GEOMETRY_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_GEOMETRY_SHADER),
-    /// [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_FRAGMENT_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_FRAGMENT_SHADER),
-    /// [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_COMPUTE_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_COMPUTE_SHADER)
-    /// then a boolean value indicating whether the atomic counter buffer identified
-    /// by `bufferIndex` is referenced by the vertex, tessellation control, tessellation
-    /// evaluation, geometry, fragment or compute processing stages of `program`,
-    /// respectively, is returned.
+    /// `border`
     ///
-    /// ### Notes
-    /// [**glGetActiveAtomicCounterBufferiv**](crate::context::Context::oxidegl_get_active_atomic_counter_bufferiv)
-    /// is available only if the GL version is 4.2 or higher.
+    /// > This value must be 0.
     ///
-    /// [`GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_COMPUTE_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_REFERENCED_BY_COMPUTE_SHADER)
-    /// is available only of the GL version is 4.3 or higher.
-    pub unsafe fn oxidegl_get_active_atomic_counter_bufferiv(
-        &mut self,
-        program: GLuint,
-        buffer_index: GLuint,
-        pname: AtomicCounterBufferPName,
-        params: *mut GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_active_atomic_counter_bufferiv not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
+    /// `imageSize`
     ///
-    /// > Specifies the program object to be queried.
+    /// > Specifies the number of unsigned bytes of image data starting at the address
+    /// > specified by `data`.
     ///
-    /// `index`
+    /// `data`
     ///
-    /// > Specifies the index of the attribute variable to be queried.
+    /// > Specifies a pointer to the compressed image data in memory.
     ///
-    /// `bufSize`
+    /// ### Description
+    /// Texturing allows elements of an image array to be read by shaders.
     ///
-    /// > Specifies the maximum number of characters OpenGL is allowed to write in
-    /// > the character buffer indicated by `name`.
+    /// [**glCompressedTexImage2D**](crate::context::Context::oxidegl_compressed_tex_image2_d)
+    /// loads a previously defined, and retrieved, compressed two-dimensional texture
+    /// image if `target` is [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D),
+    /// or one of the cube map faces such as [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X).
+    /// (see [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d)
+    /// ).
     ///
-    /// `length`
+    /// If `target` is [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY),
+    /// `data` is treated as an array of compressed 1D textures.
     ///
-    /// > Returns the number of characters actually written by OpenGL in the string
-    /// > indicated by `name` (excluding the null terminator) if a value other than
-    /// > [`NULL`](crate::gl_enums::NULL) is passed.
+    /// If `target` is [`GL_PROXY_TEXTURE_2D`](crate::gl_enums::GL_PROXY_TEXTURE_2D),
+    /// [`GL_PROXY_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_PROXY_TEXTURE_1D_ARRAY)
+    /// or [`GL_PROXY_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_PROXY_TEXTURE_CUBE_MAP),
+    /// no data is read from `data`, but all of the texture image state is recalculated,
+    /// checked for consistency, and checked against the implementation's capabilities.
+    /// If the implementation cannot handle a texture of the requested texture
+    /// size, it sets all of the image state to 0, but does not generate an error
+    /// (see [**glGetError**](crate::context::Context::oxidegl_get_error) ). To
+    /// query for an entire mipmap array, use an image array level greater than
+    /// or equal to 1.
     ///
-    /// `size`
+    /// `internalformat` must be a known compressed image format (such as [`GL_RGTC`](crate::gl_enums::GL_RGTC))
+    /// or an extension-specified compressed-texture format. When a texture is
+    /// loaded with [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d)
+    /// using a generic compressed texture format (e.g., [`GL_COMPRESSED_RGB`](crate::gl_enums::GL_COMPRESSED_RGB)
+    /// ), the GL selects from one of its extensions supporting compressed textures.
+    /// In order to load the compressed texture image using [**glCompressedTexImage2D**](crate::context::Context::oxidegl_compressed_tex_image2_d),
+    /// query the compressed texture image's size and format using [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
     ///
-    /// > Returns the size of the attribute variable.
+    /// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER)
+    /// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+    /// while a texture image is specified, `data` is treated as a byte offset
+    /// into the buffer object's data store.
     ///
-    /// `type`
+    /// If the compressed data are arranged into fixed-size blocks of texels, the
+    /// pixel storage modes can be used to select a sub-rectangle from a larger
+    /// containing rectangle. These pixel storage modes operate in the same way
+    /// as they do for [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d).
+    /// In the following description, denote by `[inlineq]` `[inlineq]` `[inlineq]`
+    /// `[inlineq]` [`GL_UNPACK_COMPRESSED_BLOCK_SIZE`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_SIZE),
+    /// [`GL_UNPACK_COMPRESSED_BLOCK_WIDTH`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_WIDTH),
+    /// [`GL_UNPACK_COMPRESSED_BLOCK_HEIGHT`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_HEIGHT),
+    /// and [`GL_UNPACK_COMPRESSED_BLOCK_DEPTH`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_DEPTH),
+    /// respectively. `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
     ///
-    /// > Returns the data type of the attribute variable.
+    /// By default the pixel storage modes [`GL_UNPACK_ROW_LENGTH`](crate::gl_enums::GL_UNPACK_ROW_LENGTH),
+    /// [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS), [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS),
+    /// [`GL_UNPACK_IMAGE_HEIGHT`](crate::gl_enums::GL_UNPACK_IMAGE_HEIGHT) and
+    /// [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES) are ignored
+    /// for compressed images. To enable [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS)
+    /// and [`GL_UNPACK_ROW_LENGTH`](crate::gl_enums::GL_UNPACK_ROW_LENGTH), `[inlineq]`
+    /// `[inlineq]` [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS)
+    /// and [`GL_UNPACK_IMAGE_HEIGHT`](crate::gl_enums::GL_UNPACK_IMAGE_HEIGHT),
+    /// `[inlineq]` [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES),
+    /// `[inlineq]`
     ///
-    /// `name`
+    /// When selecting a sub-rectangle from a compressed image: The value of [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS)
+    /// > must be a multiple of `[inlineq]`
     ///
-    /// > Returns a null terminated string containing the name of the attribute variable.
+    /// > the value of [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS)
+    /// > must be a multiple of `[inlineq]`
     ///
-    /// ### Description
-    /// [**glGetActiveAttrib**](crate::context::Context::oxidegl_get_active_attrib)
-    /// returns information about an active attribute variable in the program object
-    /// specified by `program`. The number of active attributes can be obtained
-    /// by calling [**glGetProgram**](crate::context::Context::oxidegl_get_program)
-    /// with the value [`GL_ACTIVE_ATTRIBUTES`](crate::gl_enums::GL_ACTIVE_ATTRIBUTES).
-    /// A value of 0 for `index` selects the first active attribute variable.
-    /// Permissible values for `index` range from zero to the number of active
-    /// attribute variables minus one.
-    ///
-    /// A vertex shader may use either built-in attribute variables, user-defined
-    /// attribute variables, or both. Built-in attribute variables have a prefix
-    /// of "gl_" and reference conventional OpenGL vertex attribtes (e.g., `gl_Vertex`,
-    /// `gl_Normal`, etc., see the OpenGL Shading Language specification for a
-    /// complete list.) User-defined attribute variables have arbitrary names and
-    /// obtain their values through numbered generic vertex attributes. An attribute
-    /// variable (either built-in or user-defined) is considered active if it is
-    /// determined during the link operation that it may be accessed during program
-    /// execution. Therefore, `program` should have previously been the target
-    /// of a call to [**glLinkProgram**](crate::context::Context::oxidegl_link_program),
-    /// but it is not necessary for it to have been linked successfully.
-    ///
-    /// The size of the character buffer required to store the longest attribute
-    /// variable name in `program` can be obtained by calling [**glGetProgram**](crate::context::Context::oxidegl_get_program)
-    /// with the value [`GL_ACTIVE_ATTRIBUTE_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_ATTRIBUTE_MAX_LENGTH).
-    /// This value should be used to allocate a buffer of sufficient size to store
-    /// the returned attribute name. The size of this character buffer is passed
-    /// in `bufSize`, and a pointer to this character buffer is passed in `name`.
     ///
-    /// [**glGetActiveAttrib**](crate::context::Context::oxidegl_get_active_attrib)
-    /// returns the name of the attribute variable indicated by `index`, storing
-    /// it in the character buffer specified by `name`. The string returned will
-    /// be null terminated. The actual number of characters written into this buffer
-    /// is returned in `length`, and this count does not include the null termination
-    /// character. If the length of the returned string is not required, a value
-    /// of [`NULL`](crate::gl_enums::NULL) can be passed in the `length` argument.
-    ///
-    /// The `type` argument specifies a pointer to a variable into which the attribute
-    /// variable's data type will be written. The symbolic constants [`GL_FLOAT`](crate::gl_enums::GL_FLOAT),
-    /// [`GL_FLOAT_VEC2`](crate::gl_enums::GL_FLOAT_VEC2), [`GL_FLOAT_VEC3`](crate::gl_enums::GL_FLOAT_VEC3),
-    /// [`GL_FLOAT_VEC4`](crate::gl_enums::GL_FLOAT_VEC4), [`GL_FLOAT_MAT2`](crate::gl_enums::GL_FLOAT_MAT2),
-    /// [`GL_FLOAT_MAT3`](crate::gl_enums::GL_FLOAT_MAT3), [`GL_FLOAT_MAT4`](crate::gl_enums::GL_FLOAT_MAT4),
-    /// [`GL_FLOAT_MAT2x3`](crate::gl_enums::GL_FLOAT_MAT2x3), [`GL_FLOAT_MAT2x4`](crate::gl_enums::GL_FLOAT_MAT2x4),
-    /// [`GL_FLOAT_MAT3x2`](crate::gl_enums::GL_FLOAT_MAT3x2), [`GL_FLOAT_MAT3x4`](crate::gl_enums::GL_FLOAT_MAT3x4),
-    /// [`GL_FLOAT_MAT4x2`](crate::gl_enums::GL_FLOAT_MAT4x2), [`GL_FLOAT_MAT4x3`](crate::gl_enums::GL_FLOAT_MAT4x3),
-    /// [`GL_INT`](crate::gl_enums::GL_INT), [`GL_INT_VEC2`](crate::gl_enums::GL_INT_VEC2),
-    /// [`GL_INT_VEC3`](crate::gl_enums::GL_INT_VEC3), [`GL_INT_VEC4`](crate::gl_enums::GL_INT_VEC4),
-    /// [`GL_UNSIGNED_INT`](crate::gl_enums::GL_UNSIGNED_INT), [`GL_UNSIGNED_INT_VEC2`](crate::gl_enums::GL_UNSIGNED_INT_VEC2),
-    /// [`GL_UNSIGNED_INT_VEC3`](crate::gl_enums::GL_UNSIGNED_INT_VEC3), [`GL_UNSIGNED_INT_VEC4`](crate::gl_enums::GL_UNSIGNED_INT_VEC4),
-    /// [`GL_DOUBLE`](crate::gl_enums::GL_DOUBLE), [`GL_DOUBLE_VEC2`](crate::gl_enums::GL_DOUBLE_VEC2),
-    /// [`GL_DOUBLE_VEC3`](crate::gl_enums::GL_DOUBLE_VEC3), [`GL_DOUBLE_VEC4`](crate::gl_enums::GL_DOUBLE_VEC4),
-    /// [`GL_DOUBLE_MAT2`](crate::gl_enums::GL_DOUBLE_MAT2), [`GL_DOUBLE_MAT3`](crate::gl_enums::GL_DOUBLE_MAT3),
-    /// [`GL_DOUBLE_MAT4`](crate::gl_enums::GL_DOUBLE_MAT4), [`GL_DOUBLE_MAT2x3`](crate::gl_enums::GL_DOUBLE_MAT2x3),
-    /// [`GL_DOUBLE_MAT2x4`](crate::gl_enums::GL_DOUBLE_MAT2x4), [`GL_DOUBLE_MAT3x2`](crate::gl_enums::GL_DOUBLE_MAT3x2),
-    /// [`GL_DOUBLE_MAT3x4`](crate::gl_enums::GL_DOUBLE_MAT3x4), [`GL_DOUBLE_MAT4x2`](crate::gl_enums::GL_DOUBLE_MAT4x2),
-    /// or [`GL_DOUBLE_MAT4x3`](crate::gl_enums::GL_DOUBLE_MAT4x3) may be returned.
-    /// The `size` argument will return the size of the attribute, in units of
-    /// the type returned in `type`.
+    /// `imageSize` must be equal to:
     ///
-    /// The list of active attribute variables may include both built-in attribute
-    /// variables (which begin with the prefix "gl_") as well as user-defined attribute
-    /// variable names.
+    /// `[inlineq]`
     ///
-    /// This function will return as much information as it can about the specified
-    /// active attribute variable. If no information is available, `length` will
-    /// be 0, and `name` will be an empty string. This situation could occur if
-    /// this function is called after a link operation that failed. If an error
-    /// occurs, the return values `length`, `size`, `type`, and `name` will be
-    /// unmodified.
+    /// ### Notes
+    /// The specific compressed internal formats [`GL_COMPRESSED_RGB8_ETC2`](crate::gl_enums::GL_COMPRESSED_RGB8_ETC2),
+    /// [`GL_COMPRESSED_SRGB8_ETC2`](crate::gl_enums::GL_COMPRESSED_SRGB8_ETC2),
+    /// [`GL_COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2`](crate::gl_enums::GL_COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2),
+    /// [`GL_COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2`](crate::gl_enums::GL_COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2),
+    /// [`GL_COMPRESSED_RGBA8_ETC2_EAC`](crate::gl_enums::GL_COMPRESSED_RGBA8_ETC2_EAC),
+    /// [`GL_COMPRESSED_SRGB8_ALPHA8_ETC2_EAC`](crate::gl_enums::GL_COMPRESSED_SRGB8_ALPHA8_ETC2_EAC),
+    /// [`GL_COMPRESSED_R11_EAC`](crate::gl_enums::GL_COMPRESSED_R11_EAC), [`GL_COMPRESSED_SIGNED_R11_EAC`](crate::gl_enums::GL_COMPRESSED_SIGNED_R11_EAC),
+    /// [`GL_COMPRESSED_RG11_EAC`](crate::gl_enums::GL_COMPRESSED_RG11_EAC), and
+    /// [`GL_COMPRESSED_SIGNED_RG11_EAC`](crate::gl_enums::GL_COMPRESSED_SIGNED_RG11_EAC)
+    /// are available only if the GL version is 4.3 or higher.
     ///
     /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_VERTEX_ATTRIBS`](crate::gl_enums::GL_MAX_VERTEX_ATTRIBS).
+    /// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
     ///
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
-    /// [`GL_ACTIVE_ATTRIBUTES`](crate::gl_enums::GL_ACTIVE_ATTRIBUTES) or [`GL_ACTIVE_ATTRIBUTE_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_ATTRIBUTE_MAX_LENGTH).
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_COMPRESSED`](crate::gl_enums::GL_TEXTURE_COMPRESSED)
     ///
-    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
-    pub unsafe fn oxidegl_get_active_attrib(
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
+    ///
+    /// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+    /// with arguments [`GL_TEXTURE_INTERNAL_FORMAT`](crate::gl_enums::GL_TEXTURE_INTERNAL_FORMAT)
+    /// and [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::gl_enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
+    pub unsafe fn oxidegl_compressed_tex_image2_d(
         &mut self,
-        program: GLuint,
-        index: GLuint,
-        buf_size: GLsizei,
-        length: *mut GLsizei,
-        size: *mut GLint,
-        r#type: *mut AttributeType,
-        name: *mut GLchar,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        border: GLint,
+        image_size: GLsizei,
+        data: *const GLvoid,
     ) -> GlFallible {
-        panic!("command oxidegl_get_active_attrib not yet implemented");
+        panic!("command oxidegl_compressed_tex_image2_d not yet implemented");
     }
     /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the name of the program containing the subroutine.
-    ///
-    /// `shadertype`
-    ///
-    /// > Specifies the shader stage from which to query the subroutine name.
+    /// `target`
     ///
-    /// `index`
+    /// > Specifies the target texture. Must be [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D),
+    /// > [`GL_PROXY_TEXTURE_3D`](crate::gl_enums::GL_PROXY_TEXTURE_3D), [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY)
+    /// > or [`GL_PROXY_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_PROXY_TEXTURE_2D_ARRAY).
     ///
-    /// > Specifies the index of the shader subroutine uniform.
+    /// `level`
     ///
-    /// `bufSize`
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level *n* is the *n* th mipmap reduction image.
     ///
-    /// > Specifies the size of the buffer whose address is given in `name`.
+    /// `internalformat`
     ///
-    /// `length`
+    /// > Specifies the format of the compressed image data stored at address `data`.
     ///
-    /// > Specifies the address of a variable which is to receive the length of the
-    /// > shader subroutine uniform name.
+    /// `width`
     ///
-    /// `name`
+    /// > Specifies the width of the texture image. All implementations support 3D
+    /// > texture images that are at least 16 texels wide.
     ///
-    /// > Specifies the address of an array into which the name of the shader subroutine
-    /// > uniform will be written.
+    /// `height`
     ///
-    /// ### Description
-    /// [**glGetActiveSubroutineName**](crate::context::Context::oxidegl_get_active_subroutine_name)
-    /// queries the name of an active shader subroutine uniform from the program
-    /// object given in `program`. `index` specifies the index of the shader subroutine
-    /// uniform within the shader stage given by `stage`, and must between zero
-    /// and the value of [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES)
-    /// minus one for the shader stage.
-    ///
-    /// The name of the selected subroutine is returned as a null-terminated string
-    /// in `name`. The actual number of characters written into `name`, not including
-    /// the null-terminator, is returned in `length`. If `length` is [`NULL`](crate::gl_enums::NULL),
-    /// no length is returned. The maximum number of characters that may be written
-    /// into `name`, including the null-terminator, is given in `bufSize`.
+    /// > Specifies the height of the texture image. All implementations support
+    /// > 3D texture images that are at least 16 texels high.
     ///
-    /// ### Associated Gets
-    /// [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage)
-    /// with argument [`GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH)
-    pub unsafe fn oxidegl_get_active_subroutine_name(
-        &mut self,
-        program: GLuint,
-        shadertype: ShaderType,
-        index: GLuint,
-        buf_size: GLsizei,
-        length: *mut GLsizei,
-        name: *mut GLchar,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_active_subroutine_name not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
+    /// `depth`
     ///
-    /// > Specifies the name of the program containing the subroutine.
+    /// > Specifies the depth of the texture image. All implementations support 3D
+    /// > texture images that are at least 16 texels deep.
     ///
-    /// `shadertype`
+    /// `border`
     ///
-    /// > Specifies the shader stage from which to query for the subroutine parameter.
-    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
-    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
-    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
+    /// > This value must be 0.
     ///
-    /// `index`
+    /// `imageSize`
     ///
-    /// > Specifies the index of the shader subroutine uniform.
+    /// > Specifies the number of unsigned bytes of image data starting at the address
+    /// > specified by `data`.
     ///
-    /// `pname`
+    /// `data`
     ///
-    /// > Specifies the parameter of the shader subroutine uniform to query. `pname`
-    /// > must be [`GL_NUM_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_NUM_COMPATIBLE_SUBROUTINES),
-    /// > [`GL_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_COMPATIBLE_SUBROUTINES),
-    /// > [`GL_UNIFORM_SIZE`](crate::gl_enums::GL_UNIFORM_SIZE) or [`GL_UNIFORM_NAME_LENGTH`](crate::gl_enums::GL_UNIFORM_NAME_LENGTH).
+    /// > Specifies a pointer to the compressed image data in memory.
     ///
-    /// `values`
+    /// ### Description
+    /// Texturing allows elements of an image array to be read by shaders.
     ///
-    /// > Specifies the address of a into which the queried value or values will
-    /// > be placed.
+    /// [**glCompressedTexImage3D**](crate::context::Context::oxidegl_compressed_tex_image3_d)
+    /// loads a previously defined, and retrieved, compressed three-dimensional
+    /// texture image if `target` is [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D)
+    /// (see [**glTexImage3D**](crate::context::Context::oxidegl_tex_image3_d)
+    /// ).
     ///
-    /// ### Description
-    /// [**glGetActiveSubroutineUniform**](crate::context::Context::oxidegl_get_active_subroutine_uniform)
-    /// queries a parameter of an active shader subroutine uniform. `program` contains
-    /// the name of the program containing the uniform. `shadertype` specifies
-    /// the stage which the uniform location, given by `index`, is valid. `index`
-    /// must be between zero and the value of [`GL_ACTIVE_SUBROUTINE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORMS)
-    /// minus one for the shader stage.
-    ///
-    /// If `pname` is [`GL_NUM_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_NUM_COMPATIBLE_SUBROUTINES),
-    /// a single integer indicating the number of subroutines that can be assigned
-    /// to the uniform is returned in `values`.
-    ///
-    /// If `pname` is [`GL_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_COMPATIBLE_SUBROUTINES),
-    /// an array of integers is returned in `values`, with each integer specifying
-    /// the index of an active subroutine that can be assigned to the selected
-    /// subroutine uniform. The number of integers returned is the same as the
-    /// value returned for [`GL_NUM_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_NUM_COMPATIBLE_SUBROUTINES).
-    ///
-    /// If `pname` is [`GL_UNIFORM_SIZE`](crate::gl_enums::GL_UNIFORM_SIZE), a
-    /// single integer is returned in `values`. If the selected subroutine uniform
-    /// is an array, the declared size of the array is returned; otherwise, one
-    /// is returned.
+    /// If `target` is [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY),
+    /// `data` is treated as an array of compressed 2D textures.
     ///
-    /// If `pname` is [`GL_UNIFORM_NAME_LENGTH`](crate::gl_enums::GL_UNIFORM_NAME_LENGTH),
-    /// a single integer specifying the length of the subroutine uniform name
-    /// (including the terminating null character) is returned in `values`.
+    /// If `target` is [`GL_PROXY_TEXTURE_3D`](crate::gl_enums::GL_PROXY_TEXTURE_3D)
+    /// or [`GL_PROXY_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_PROXY_TEXTURE_2D_ARRAY),
+    /// no data is read from `data`, but all of the texture image state is recalculated,
+    /// checked for consistency, and checked against the implementation's capabilities.
+    /// If the implementation cannot handle a texture of the requested texture
+    /// size, it sets all of the image state to 0, but does not generate an error
+    /// (see [**glGetError**](crate::context::Context::oxidegl_get_error) ). To
+    /// query for an entire mipmap array, use an image array level greater than
+    /// or equal to 1.
     ///
-    /// ### Associated Gets
-    /// [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage)
-    /// with argument [`GL_ACTIVE_SUBROUTINE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORMS)
-    pub unsafe fn oxidegl_get_active_subroutine_uniformiv(
-        &mut self,
-        program: GLuint,
-        shadertype: ShaderType,
-        index: GLuint,
-        pname: SubroutineParameterName,
-        values: *mut GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_active_subroutine_uniformiv not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
+    /// `internalformat` must be a known compressed image format (such as [`GL_RGTC`](crate::gl_enums::GL_RGTC))
+    /// or an extension-specified compressed-texture format. When a texture is
+    /// loaded with [**glTexImage2D**](crate::context::Context::oxidegl_tex_image2_d)
+    /// using a generic compressed texture format (e.g., [`GL_COMPRESSED_RGB`](crate::gl_enums::GL_COMPRESSED_RGB)
+    /// ), the GL selects from one of its extensions supporting compressed textures.
+    /// In order to load the compressed texture image using [**glCompressedTexImage3D**](crate::context::Context::oxidegl_compressed_tex_image3_d),
+    /// query the compressed texture image's size and format using [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter).
     ///
-    /// > Specifies the name of the program containing the subroutine.
+    /// If a non-zero named buffer object is bound to the [`GL_PIXEL_UNPACK_BUFFER`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER)
+    /// target (see [**glBindBuffer**](crate::context::Context::oxidegl_bind_buffer))
+    /// while a texture image is specified, `data` is treated as a byte offset
+    /// into the buffer object's data store.
     ///
-    /// `shadertype`
+    /// If the compressed data are arranged into fixed-size blocks of texels, the
+    /// pixel storage modes can be used to select a sub-rectangle from a larger
+    /// containing rectangle. These pixel storage modes operate in the same way
+    /// as they do for [**glTexImage1D**](crate::context::Context::oxidegl_tex_image1_d).
+    /// In the following description, denote by `[inlineq]` `[inlineq]` `[inlineq]`
+    /// `[inlineq]` [`GL_UNPACK_COMPRESSED_BLOCK_SIZE`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_SIZE),
+    /// [`GL_UNPACK_COMPRESSED_BLOCK_WIDTH`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_WIDTH),
+    /// [`GL_UNPACK_COMPRESSED_BLOCK_HEIGHT`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_HEIGHT),
+    /// and [`GL_UNPACK_COMPRESSED_BLOCK_DEPTH`](crate::gl_enums::GL_UNPACK_COMPRESSED_BLOCK_DEPTH),
+    /// respectively. `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
     ///
-    /// > Specifies the shader stage from which to query for the subroutine parameter.
-    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
-    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
-    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
+    /// By default the pixel storage modes [`GL_UNPACK_ROW_LENGTH`](crate::gl_enums::GL_UNPACK_ROW_LENGTH),
+    /// [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS), [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS),
+    /// [`GL_UNPACK_IMAGE_HEIGHT`](crate::gl_enums::GL_UNPACK_IMAGE_HEIGHT) and
+    /// [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES) are ignored
+    /// for compressed images. To enable [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS)
+    /// and [`GL_UNPACK_ROW_LENGTH`](crate::gl_enums::GL_UNPACK_ROW_LENGTH), `[inlineq]`
+    /// `[inlineq]` [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS)
+    /// and [`GL_UNPACK_IMAGE_HEIGHT`](crate::gl_enums::GL_UNPACK_IMAGE_HEIGHT),
+    /// `[inlineq]` [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES),
+    /// `[inlineq]`
     ///
-    /// `index`
+    /// When selecting a sub-rectangle from a compressed image: the value of [`GL_UNPACK_SKIP_PIXELS`](crate::gl_enums::GL_UNPACK_SKIP_PIXELS)
+    /// > must be a multiple of `[inlineq]`
     ///
-    /// > Specifies the index of the shader subroutine uniform.
+    /// > the value of [`GL_UNPACK_SKIP_ROWS`](crate::gl_enums::GL_UNPACK_SKIP_ROWS)
+    /// > must be a multiple of `[inlineq]`
     ///
-    /// `bufSize`
+    /// > the value of [`GL_UNPACK_SKIP_IMAGES`](crate::gl_enums::GL_UNPACK_SKIP_IMAGES)
+    /// > must be a multiple of `[inlineq]`
     ///
-    /// > Specifies the size of the buffer whose address is given in `name`.
     ///
-    /// `length`
+    /// `imageSize` must be equal to:
     ///
-    /// > Specifies the address of a variable into which is written the number of
-    /// > characters copied into `name`.
+    /// `[inlineq]`
     ///
-    /// `name`
+    /// ### Associated Gets
+    /// [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
     ///
-    /// > Specifies the address of a buffer that will receive the name of the specified
-    /// > shader subroutine uniform.
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_TEXTURE_COMPRESSED`](crate::gl_enums::GL_TEXTURE_COMPRESSED)
     ///
-    /// ### Description
-    /// [**glGetActiveSubroutineUniformName**](crate::context::Context::oxidegl_get_active_subroutine_uniform_name)
-    /// retrieves the name of an active shader subroutine uniform. `program` contains
-    /// the name of the program containing the uniform. `shadertype` specifies
-    /// the stage for which the uniform location, given by `index`, is valid. `index`
-    /// must be between zero and the value of [`GL_ACTIVE_SUBROUTINE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORMS)
-    /// minus one for the shader stage.
-    ///
-    /// The uniform name is returned as a null-terminated string in `name`. The
-    /// actual number of characters written into `name`, excluding the null terminator
-    /// is returned in `length`. If `length` is [`NULL`](crate::gl_enums::NULL),
-    /// no length is returned. The maximum number of characters that may be written
-    /// into `name`, including the null terminator, is specified by `bufSize`. The
-    /// length of the longest subroutine uniform name in `program` and `shadertype`
-    /// is given by the value of [`GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH),
-    /// which can be queried with [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage).
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PIXEL_UNPACK_BUFFER_BINDING`](crate::gl_enums::GL_PIXEL_UNPACK_BUFFER_BINDING)
     ///
-    /// ### Associated Gets
-    /// [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage)
-    /// with argument [`GL_ACTIVE_SUBROUTINE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORMS)
-    pub unsafe fn oxidegl_get_active_subroutine_uniform_name(
+    /// [**glGetTexLevelParameter**](crate::context::Context::oxidegl_get_tex_level_parameter)
+    /// with arguments [`GL_TEXTURE_INTERNAL_FORMAT`](crate::gl_enums::GL_TEXTURE_INTERNAL_FORMAT)
+    /// and [`GL_TEXTURE_COMPRESSED_IMAGE_SIZE`](crate::gl_enums::GL_TEXTURE_COMPRESSED_IMAGE_SIZE)
+    pub unsafe fn oxidegl_compressed_tex_image3_d(
         &mut self,
-        program: GLuint,
-        shadertype: ShaderType,
-        index: GLuint,
-        buf_size: GLsizei,
-        length: *mut GLsizei,
-        name: *mut GLchar,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        border: GLint,
+        image_size: GLsizei,
+        data: *const GLvoid,
     ) -> GlFallible {
-        panic!("command oxidegl_get_active_subroutine_uniform_name not yet implemented");
+        panic!("command oxidegl_compressed_tex_image3_d not yet implemented");
     }
     /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the program object to be queried.
+    /// `srcName`
     ///
-    /// `index`
+    /// > The name of a texture or renderbuffer object from which to copy.
     ///
-    /// > Specifies the index of the uniform variable to be queried.
+    /// `srcTarget`
     ///
-    /// `bufSize`
+    /// > The target representing the namespace of the source name `srcName`.
     ///
-    /// > Specifies the maximum number of characters OpenGL is allowed to write in
-    /// > the character buffer indicated by `name`.
+    /// `srcLevel`
     ///
-    /// `length`
+    /// > The mipmap level to read from the source.
     ///
-    /// > Returns the number of characters actually written by OpenGL in the string
-    /// > indicated by `name` (excluding the null terminator) if a value other than
-    /// > [`NULL`](crate::gl_enums::NULL) is passed.
+    /// `srcX`
     ///
-    /// `size`
+    /// > The X coordinate of the left edge of the souce region to copy.
     ///
-    /// > Returns the size of the uniform variable.
+    /// `srcY`
     ///
-    /// `type`
+    /// > The Y coordinate of the top edge of the souce region to copy.
     ///
-    /// > Returns the data type of the uniform variable.
+    /// `srcZ`
     ///
-    /// `name`
+    /// > The Z coordinate of the near edge of the souce region to copy.
     ///
-    /// > Returns a null terminated string containing the name of the uniform variable.
+    /// `dstName`
     ///
-    /// ### Description
-    /// [**glGetActiveUniform**](crate::context::Context::oxidegl_get_active_uniform)
-    /// returns information about an active uniform variable in the program object
-    /// specified by `program`. The number of active uniform variables can be obtained
-    /// by calling [**glGetProgram**](crate::context::Context::oxidegl_get_program)
-    /// with the value [`GL_ACTIVE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_UNIFORMS).
-    /// A value of 0 for `index` selects the first active uniform variable. Permissible
-    /// values for `index` range from zero to the number of active uniform variables
-    /// minus one.
+    /// > The name of a texture or renderbuffer object to which to copy.
     ///
-    /// Shaders may use either built-in uniform variables, user-defined uniform
-    /// variables, or both. Built-in uniform variables have a prefix of "gl_" and
-    /// reference existing OpenGL state or values derived from such state (e.g.,
-    /// `gl_DepthRangeParameters`, see the OpenGL Shading Language specification
-    /// for a complete list.) User-defined uniform variables have arbitrary names
-    /// and obtain their values from the application through calls to [**glUniform**](crate::context::Context::oxidegl_uniform).
-    /// A uniform variable (either built-in or user-defined) is considered active
-    /// if it is determined during the link operation that it may be accessed during
-    /// program execution. Therefore, `program` should have previously been the
-    /// target of a call to [**glLinkProgram**](crate::context::Context::oxidegl_link_program),
-    /// but it is not necessary for it to have been linked successfully.
-    ///
-    /// The size of the character buffer required to store the longest uniform
-    /// variable name in `program` can be obtained by calling [**glGetProgram**](crate::context::Context::oxidegl_get_program)
-    /// with the value [`GL_ACTIVE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_UNIFORM_MAX_LENGTH).
-    /// This value should be used to allocate a buffer of sufficient size to store
-    /// the returned uniform variable name. The size of this character buffer is
-    /// passed in `bufSize`, and a pointer to this character buffer is passed in
-    /// `name.`
+    /// `dstTarget`
     ///
-    /// [**glGetActiveUniform**](crate::context::Context::oxidegl_get_active_uniform)
-    /// returns the name of the uniform variable indicated by `index`, storing
-    /// it in the character buffer specified by `name`. The string returned will
-    /// be null terminated. The actual number of characters written into this buffer
-    /// is returned in `length`, and this count does not include the null termination
-    /// character. If the length of the returned string is not required, a value
-    /// of [`NULL`](crate::gl_enums::NULL) can be passed in the `length` argument.
-    ///
-    /// The `type` argument will return a pointer to the uniform variable's data
-    /// type. The symbolic constants returned for uniform types are shown in the
-    /// table below.
-    /// |* Returned Symbolic Contant*                                 |* Shader Uniform Type*                              |
-    /// |-------------------------------------------------------------|----------------------------------------------------|
-    /// | [`GL_FLOAT`](crate::gl_enums::GL_FLOAT)                     | [`float`](crate::gl_enums::float)                  |
-    /// | [`GL_FLOAT_VEC2`](crate::gl_enums::GL_FLOAT_VEC2)           | [`vec2`](crate::gl_enums::vec2)                    |
-    /// | [`GL_FLOAT_VEC3`](crate::gl_enums::GL_FLOAT_VEC3)           | [`vec3`](crate::gl_enums::vec3)                    |
-    /// | [`GL_FLOAT_VEC4`](crate::gl_enums::GL_FLOAT_VEC4)           | [`vec4`](crate::gl_enums::vec4)                    |
-    /// | [`GL_DOUBLE`](crate::gl_enums::GL_DOUBLE)                   | [`double`](crate::gl_enums::double)                |
-    /// | [`GL_DOUBLE_VEC2`](crate::gl_enums::GL_DOUBLE_VEC2)         | [`dvec2`](crate::gl_enums::dvec2)                  |
-    /// | [`GL_DOUBLE_VEC3`](crate::gl_enums::GL_DOUBLE_VEC3)         | [`dvec3`](crate::gl_enums::dvec3)                  |
-    /// | [`GL_DOUBLE_VEC4`](crate::gl_enums::GL_DOUBLE_VEC4)         | [`dvec4`](crate::gl_enums::dvec4)                  |
-    /// | [`GL_INT`](crate::gl_enums::GL_INT)                         | [`int`](crate::gl_enums::int)                      |
-    /// | [`GL_INT_VEC2`](crate::gl_enums::GL_INT_VEC2)               | [`ivec2`](crate::gl_enums::ivec2)                  |
-    /// | [`GL_INT_VEC3`](crate::gl_enums::GL_INT_VEC3)               | [`ivec3`](crate::gl_enums::ivec3)                  |
-    /// | [`GL_INT_VEC4`](crate::gl_enums::GL_INT_VEC4)               | [`ivec4`](crate::gl_enums::ivec4)                  |
-    /// | [`GL_UNSIGNED_INT`](crate::gl_enums::GL_UNSIGNED_INT)       | [`unsigned int`](`crate::gl_enums::unsigned` int)    |
-    /// | [`GL_UNSIGNED_INT_VEC2`](crate::gl_enums::GL_UNSIGNED_INT_VEC2) | [`uvec2`](crate::gl_enums::uvec2)              |
-    /// | [`GL_UNSIGNED_INT_VEC3`](crate::gl_enums::GL_UNSIGNED_INT_VEC3) | [`uvec3`](crate::gl_enums::uvec3)              |
-    /// | [`GL_UNSIGNED_INT_VEC4`](crate::gl_enums::GL_UNSIGNED_INT_VEC4) | [`uvec4`](crate::gl_enums::uvec4)              |
-    /// | [`GL_BOOL`](crate::gl_enums::GL_BOOL)                       | [`bool`](crate::gl_enums::bool)                    |
-    /// | [`GL_BOOL_VEC2`](crate::gl_enums::GL_BOOL_VEC2)             | [`bvec2`](crate::gl_enums::bvec2)                  |
-    /// | [`GL_BOOL_VEC3`](crate::gl_enums::GL_BOOL_VEC3)             | [`bvec3`](crate::gl_enums::bvec3)                  |
-    /// | [`GL_BOOL_VEC4`](crate::gl_enums::GL_BOOL_VEC4)             | [`bvec4`](crate::gl_enums::bvec4)                  |
-    /// | [`GL_FLOAT_MAT2`](crate::gl_enums::GL_FLOAT_MAT2)           | [`mat2`](crate::gl_enums::mat2)                    |
-    /// | [`GL_FLOAT_MAT3`](crate::gl_enums::GL_FLOAT_MAT3)           | [`mat3`](crate::gl_enums::mat3)                    |
-    /// | [`GL_FLOAT_MAT4`](crate::gl_enums::GL_FLOAT_MAT4)           | [`mat4`](crate::gl_enums::mat4)                    |
-    /// | [`GL_FLOAT_MAT2x3`](crate::gl_enums::GL_FLOAT_MAT2x3)       | [`mat2x3`](crate::gl_enums::mat2x3)                |
-    /// | [`GL_FLOAT_MAT2x4`](crate::gl_enums::GL_FLOAT_MAT2x4)       | [`mat2x4`](crate::gl_enums::mat2x4)                |
-    /// | [`GL_FLOAT_MAT3x2`](crate::gl_enums::GL_FLOAT_MAT3x2)       | [`mat3x2`](crate::gl_enums::mat3x2)                |
-    /// | [`GL_FLOAT_MAT3x4`](crate::gl_enums::GL_FLOAT_MAT3x4)       | [`mat3x4`](crate::gl_enums::mat3x4)                |
-    /// | [`GL_FLOAT_MAT4x2`](crate::gl_enums::GL_FLOAT_MAT4x2)       | [`mat4x2`](crate::gl_enums::mat4x2)                |
-    /// | [`GL_FLOAT_MAT4x3`](crate::gl_enums::GL_FLOAT_MAT4x3)       | [`mat4x3`](crate::gl_enums::mat4x3)                |
-    /// | [`GL_DOUBLE_MAT2`](crate::gl_enums::GL_DOUBLE_MAT2)         | [`dmat2`](crate::gl_enums::dmat2)                  |
-    /// | [`GL_DOUBLE_MAT3`](crate::gl_enums::GL_DOUBLE_MAT3)         | [`dmat3`](crate::gl_enums::dmat3)                  |
-    /// | [`GL_DOUBLE_MAT4`](crate::gl_enums::GL_DOUBLE_MAT4)         | [`dmat4`](crate::gl_enums::dmat4)                  |
-    /// | [`GL_DOUBLE_MAT2x3`](crate::gl_enums::GL_DOUBLE_MAT2x3)     | [`dmat2x3`](crate::gl_enums::dmat2x3)              |
-    /// | [`GL_DOUBLE_MAT2x4`](crate::gl_enums::GL_DOUBLE_MAT2x4)     | [`dmat2x4`](crate::gl_enums::dmat2x4)              |
-    /// | [`GL_DOUBLE_MAT3x2`](crate::gl_enums::GL_DOUBLE_MAT3x2)     | [`dmat3x2`](crate::gl_enums::dmat3x2)              |
-    /// | [`GL_DOUBLE_MAT3x4`](crate::gl_enums::GL_DOUBLE_MAT3x4)     | [`dmat3x4`](crate::gl_enums::dmat3x4)              |
-    /// | [`GL_DOUBLE_MAT4x2`](crate::gl_enums::GL_DOUBLE_MAT4x2)     | [`dmat4x2`](crate::gl_enums::dmat4x2)              |
-    /// | [`GL_DOUBLE_MAT4x3`](crate::gl_enums::GL_DOUBLE_MAT4x3)     | [`dmat4x3`](crate::gl_enums::dmat4x3)              |
-    /// | [`GL_SAMPLER_1D`](crate::gl_enums::GL_SAMPLER_1D)           | [`sampler1D`](crate::gl_enums::sampler1D)          |
-    /// | [`GL_SAMPLER_2D`](crate::gl_enums::GL_SAMPLER_2D)           | [`sampler2D`](crate::gl_enums::sampler2D)          |
-    /// | [`GL_SAMPLER_3D`](crate::gl_enums::GL_SAMPLER_3D)           | [`sampler3D`](crate::gl_enums::sampler3D)          |
-    /// | [`GL_SAMPLER_CUBE`](crate::gl_enums::GL_SAMPLER_CUBE)       | [`samplerCube`](crate::gl_enums::samplerCube)      |
-    /// | [`GL_SAMPLER_1D_SHADOW`](crate::gl_enums::GL_SAMPLER_1D_SHADOW) | [`sampler1DShadow`](crate::gl_enums::sampler1DShadow) |
-    /// | [`GL_SAMPLER_2D_SHADOW`](crate::gl_enums::GL_SAMPLER_2D_SHADOW) | [`sampler2DShadow`](crate::gl_enums::sampler2DShadow) |
-    /// | [`GL_SAMPLER_1D_ARRAY`](crate::gl_enums::GL_SAMPLER_1D_ARRAY) | [`sampler1DArray`](crate::gl_enums::sampler1DArray) |
-    /// | [`GL_SAMPLER_2D_ARRAY`](crate::gl_enums::GL_SAMPLER_2D_ARRAY) | [`sampler2DArray`](crate::gl_enums::sampler2DArray) |
-    /// | [`GL_SAMPLER_1D_ARRAY_SHADOW`](crate::gl_enums::GL_SAMPLER_1D_ARRAY_SHADOW) | [`sampler1DArrayShadow`](crate::gl_enums::sampler1DArrayShadow) |
-    /// | [`GL_SAMPLER_2D_ARRAY_SHADOW`](crate::gl_enums::GL_SAMPLER_2D_ARRAY_SHADOW) | [`sampler2DArrayShadow`](crate::gl_enums::sampler2DArrayShadow) |
-    /// | [`GL_SAMPLER_2D_MULTISAMPLE`](crate::gl_enums::GL_SAMPLER_2D_MULTISAMPLE) | [`sampler2DMS`](crate::gl_enums::sampler2DMS) |
-    /// | [`GL_SAMPLER_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_SAMPLER_2D_MULTISAMPLE_ARRAY) | [`sampler2DMSArray`](crate::gl_enums::sampler2DMSArray) |
-    /// | [`GL_SAMPLER_CUBE_SHADOW`](crate::gl_enums::GL_SAMPLER_CUBE_SHADOW) | [`samplerCubeShadow`](crate::gl_enums::samplerCubeShadow) |
-    /// | [`GL_SAMPLER_BUFFER`](crate::gl_enums::GL_SAMPLER_BUFFER)   | [`samplerBuffer`](crate::gl_enums::samplerBuffer)  |
-    /// | [`GL_SAMPLER_2D_RECT`](crate::gl_enums::GL_SAMPLER_2D_RECT) | [`sampler2DRect`](crate::gl_enums::sampler2DRect)  |
-    /// | [`GL_SAMPLER_2D_RECT_SHADOW`](crate::gl_enums::GL_SAMPLER_2D_RECT_SHADOW) | [`sampler2DRectShadow`](crate::gl_enums::sampler2DRectShadow) |
-    /// | [`GL_INT_SAMPLER_1D`](crate::gl_enums::GL_INT_SAMPLER_1D)   | [`isampler1D`](crate::gl_enums::isampler1D)        |
-    /// | [`GL_INT_SAMPLER_2D`](crate::gl_enums::GL_INT_SAMPLER_2D)   | [`isampler2D`](crate::gl_enums::isampler2D)        |
-    /// | [`GL_INT_SAMPLER_3D`](crate::gl_enums::GL_INT_SAMPLER_3D)   | [`isampler3D`](crate::gl_enums::isampler3D)        |
-    /// | [`GL_INT_SAMPLER_CUBE`](crate::gl_enums::GL_INT_SAMPLER_CUBE) | [`isamplerCube`](crate::gl_enums::isamplerCube)  |
-    /// | [`GL_INT_SAMPLER_1D_ARRAY`](crate::gl_enums::GL_INT_SAMPLER_1D_ARRAY) | [`isampler1DArray`](crate::gl_enums::isampler1DArray) |
-    /// | [`GL_INT_SAMPLER_2D_ARRAY`](crate::gl_enums::GL_INT_SAMPLER_2D_ARRAY) | [`isampler2DArray`](crate::gl_enums::isampler2DArray) |
-    /// | [`GL_INT_SAMPLER_2D_MULTISAMPLE`](crate::gl_enums::GL_INT_SAMPLER_2D_MULTISAMPLE) | [`isampler2DMS`](crate::gl_enums::isampler2DMS) |
-    /// | [`GL_INT_SAMPLER_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_INT_SAMPLER_2D_MULTISAMPLE_ARRAY) | [`isampler2DMSArray`](crate::gl_enums::isampler2DMSArray) |
-    /// | [`GL_INT_SAMPLER_BUFFER`](crate::gl_enums::GL_INT_SAMPLER_BUFFER) | [`isamplerBuffer`](crate::gl_enums::isamplerBuffer) |
-    /// | [`GL_INT_SAMPLER_2D_RECT`](crate::gl_enums::GL_INT_SAMPLER_2D_RECT) | [`isampler2DRect`](crate::gl_enums::isampler2DRect) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_1D`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_1D) | [`usampler1D`](crate::gl_enums::usampler1D) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_2D`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D) | [`usampler2D`](crate::gl_enums::usampler2D) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_3D`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_3D) | [`usampler3D`](crate::gl_enums::usampler3D) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_CUBE`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_CUBE) | [`usamplerCube`](crate::gl_enums::usamplerCube) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_1D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_1D_ARRAY) | [`usampler2DArray`](crate::gl_enums::usampler2DArray) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_ARRAY) | [`usampler2DArray`](crate::gl_enums::usampler2DArray) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE) | [`usampler2DMS`](crate::gl_enums::usampler2DMS) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY) | [`usampler2DMSArray`](crate::gl_enums::usampler2DMSArray) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_BUFFER`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_BUFFER) | [`usamplerBuffer`](crate::gl_enums::usamplerBuffer) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_RECT`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_RECT) | [`usampler2DRect`](crate::gl_enums::usampler2DRect) |
-    /// | [`GL_IMAGE_1D`](crate::gl_enums::GL_IMAGE_1D)               | [`image1D`](crate::gl_enums::image1D)              |
-    /// | [`GL_IMAGE_2D`](crate::gl_enums::GL_IMAGE_2D)               | [`image2D`](crate::gl_enums::image2D)              |
-    /// | [`GL_IMAGE_3D`](crate::gl_enums::GL_IMAGE_3D)               | [`image3D`](crate::gl_enums::image3D)              |
-    /// | [`GL_IMAGE_2D_RECT`](crate::gl_enums::GL_IMAGE_2D_RECT)     | [`image2DRect`](crate::gl_enums::image2DRect)      |
-    /// | [`GL_IMAGE_CUBE`](crate::gl_enums::GL_IMAGE_CUBE)           | [`imageCube`](crate::gl_enums::imageCube)          |
-    /// | [`GL_IMAGE_BUFFER`](crate::gl_enums::GL_IMAGE_BUFFER)       | [`imageBuffer`](crate::gl_enums::imageBuffer)      |
-    /// | [`GL_IMAGE_1D_ARRAY`](crate::gl_enums::GL_IMAGE_1D_ARRAY)   | [`image1DArray`](crate::gl_enums::image1DArray)    |
-    /// | [`GL_IMAGE_2D_ARRAY`](crate::gl_enums::GL_IMAGE_2D_ARRAY)   | [`image2DArray`](crate::gl_enums::image2DArray)    |
-    /// | [`GL_IMAGE_2D_MULTISAMPLE`](crate::gl_enums::GL_IMAGE_2D_MULTISAMPLE) | [`image2DMS`](crate::gl_enums::image2DMS) |
-    /// | [`GL_IMAGE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_IMAGE_2D_MULTISAMPLE_ARRAY) | [`image2DMSArray`](crate::gl_enums::image2DMSArray) |
-    /// | [`GL_INT_IMAGE_1D`](crate::gl_enums::GL_INT_IMAGE_1D)       | [`iimage1D`](crate::gl_enums::iimage1D)            |
-    /// | [`GL_INT_IMAGE_2D`](crate::gl_enums::GL_INT_IMAGE_2D)       | [`iimage2D`](crate::gl_enums::iimage2D)            |
-    /// | [`GL_INT_IMAGE_3D`](crate::gl_enums::GL_INT_IMAGE_3D)       | [`iimage3D`](crate::gl_enums::iimage3D)            |
-    /// | [`GL_INT_IMAGE_2D_RECT`](crate::gl_enums::GL_INT_IMAGE_2D_RECT) | [`iimage2DRect`](crate::gl_enums::iimage2DRect) |
-    /// | [`GL_INT_IMAGE_CUBE`](crate::gl_enums::GL_INT_IMAGE_CUBE)   | [`iimageCube`](crate::gl_enums::iimageCube)        |
-    /// | [`GL_INT_IMAGE_BUFFER`](crate::gl_enums::GL_INT_IMAGE_BUFFER) | [`iimageBuffer`](crate::gl_enums::iimageBuffer)  |
-    /// | [`GL_INT_IMAGE_1D_ARRAY`](crate::gl_enums::GL_INT_IMAGE_1D_ARRAY) | [`iimage1DArray`](crate::gl_enums::iimage1DArray) |
-    /// | [`GL_INT_IMAGE_2D_ARRAY`](crate::gl_enums::GL_INT_IMAGE_2D_ARRAY) | [`iimage2DArray`](crate::gl_enums::iimage2DArray) |
-    /// | [`GL_INT_IMAGE_2D_MULTISAMPLE`](crate::gl_enums::GL_INT_IMAGE_2D_MULTISAMPLE) | [`iimage2DMS`](crate::gl_enums::iimage2DMS) |
-    /// | [`GL_INT_IMAGE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_INT_IMAGE_2D_MULTISAMPLE_ARRAY) | [`iimage2DMSArray`](crate::gl_enums::iimage2DMSArray) |
-    /// | [`GL_UNSIGNED_INT_IMAGE_1D`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_1D) | [`uimage1D`](crate::gl_enums::uimage1D) |
-    /// | [`GL_UNSIGNED_INT_IMAGE_2D`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D) | [`uimage2D`](crate::gl_enums::uimage2D) |
-    /// | [`GL_UNSIGNED_INT_IMAGE_3D`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_3D) | [`uimage3D`](crate::gl_enums::uimage3D) |
-    /// | [`GL_UNSIGNED_INT_IMAGE_2D_RECT`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_RECT) | [`uimage2DRect`](crate::gl_enums::uimage2DRect) |
-    /// | [`GL_UNSIGNED_INT_IMAGE_CUBE`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_CUBE) | [`uimageCube`](crate::gl_enums::uimageCube) |
-    /// | [`GL_UNSIGNED_INT_IMAGE_BUFFER`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_BUFFER) | [`uimageBuffer`](crate::gl_enums::uimageBuffer) |
-    /// | [`GL_UNSIGNED_INT_IMAGE_1D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_1D_ARRAY) | [`uimage1DArray`](crate::gl_enums::uimage1DArray) |
-    /// | [`GL_UNSIGNED_INT_IMAGE_2D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_ARRAY) | [`uimage2DArray`](crate::gl_enums::uimage2DArray) |
-    /// | [`GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE) | [`uimage2DMS`](crate::gl_enums::uimage2DMS) |
-    /// | [`GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY) | [`uimage2DMSArray`](crate::gl_enums::uimage2DMSArray) |
-    /// | [`GL_UNSIGNED_INT_ATOMIC_COUNTER`](crate::gl_enums::GL_UNSIGNED_INT_ATOMIC_COUNTER) | [`atomic_uint`](crate::gl_enums::atomic_uint) |
-    ///
-    ///
-    /// If one or more elements of an array are active, the name of the array is
-    /// returned in `name`, the type is returned in `type`, and the `size` parameter
-    /// returns the highest array element index used, plus one, as determined by
-    /// the compiler and/or linker. Only one active uniform variable will be reported
-    /// for a uniform array.
-    ///
-    /// Uniform variables that are declared as structures or arrays of structures
-    /// will not be returned directly by this function. Instead, each of these
-    /// uniform variables will be reduced to its fundamental components containing
-    /// the "." and "\[\]" operators such that each of the names is valid as an
-    /// argument to [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location).
-    /// Each of these reduced uniform variables is counted as one active uniform
-    /// variable and is assigned an index. A valid name cannot be a structure,
-    /// an array of structures, or a subcomponent of a vector or matrix.
-    ///
-    /// The size of the uniform variable will be returned in `size`. Uniform variables
-    /// other than arrays will have a size of 1. Structures and arrays of structures
-    /// will be reduced as described earlier, such that each of the names returned
-    /// will be a data type in the earlier list. If this reduction results in an
-    /// array, the size returned will be as described for uniform arrays; otherwise,
-    /// the size returned will be 1.
-    ///
-    /// The list of active uniform variables may include both built-in uniform
-    /// variables (which begin with the prefix "gl_") as well as user-defined uniform
-    /// variable names.
-    ///
-    /// This function will return as much information as it can about the specified
-    /// active uniform variable. If no information is available, `length` will
-    /// be 0, and `name` will be an empty string. This situation could occur if
-    /// this function is called after a link operation that failed. If an error
-    /// occurs, the return values `length`, `size`, `type`, and `name` will be
-    /// unmodified.
+    /// > The target representing the namespace of the destination name `dstName`.
     ///
-    /// ### Notes
-    /// The double types, [`GL_DOUBLE`](crate::gl_enums::GL_DOUBLE), [`GL_DOUBLE_VEC2`](crate::gl_enums::GL_DOUBLE_VEC2),
-    /// [`GL_DOUBLE_VEC3`](crate::gl_enums::GL_DOUBLE_VEC3), [`GL_DOUBLE_VEC4`](crate::gl_enums::GL_DOUBLE_VEC4),
-    /// [`GL_DOUBLE_MAT2`](crate::gl_enums::GL_DOUBLE_MAT2), [`GL_DOUBLE_MAT3`](crate::gl_enums::GL_DOUBLE_MAT3),
-    /// [`GL_DOUBLE_MAT4`](crate::gl_enums::GL_DOUBLE_MAT4), [`GL_DOUBLE_MAT2x3`](crate::gl_enums::GL_DOUBLE_MAT2x3),
-    /// [`GL_DOUBLE_MAT2x4`](crate::gl_enums::GL_DOUBLE_MAT2x4), [`GL_DOUBLE_MAT3x2`](crate::gl_enums::GL_DOUBLE_MAT3x2),
-    /// [`GL_DOUBLE_MAT3x4`](crate::gl_enums::GL_DOUBLE_MAT3x4), [`GL_DOUBLE_MAT4x2`](crate::gl_enums::GL_DOUBLE_MAT4x2),
-    /// and [`GL_DOUBLE_MAT4x3`](crate::gl_enums::GL_DOUBLE_MAT4x3) are only available
-    /// if the GL version is 4.1 or higher.
+    /// `dstX`
     ///
-    /// The image types, [`GL_IMAGE_1D`](crate::gl_enums::GL_IMAGE_1D), [`GL_IMAGE_2D`](crate::gl_enums::GL_IMAGE_2D),
-    /// [`GL_IMAGE_3D`](crate::gl_enums::GL_IMAGE_3D), [`GL_IMAGE_2D_RECT`](crate::gl_enums::GL_IMAGE_2D_RECT),
-    /// [`GL_IMAGE_CUBE`](crate::gl_enums::GL_IMAGE_CUBE), [`GL_IMAGE_BUFFER`](crate::gl_enums::GL_IMAGE_BUFFER),
-    /// [`GL_IMAGE_1D_ARRAY`](crate::gl_enums::GL_IMAGE_1D_ARRAY), [`GL_IMAGE_2D_ARRAY`](crate::gl_enums::GL_IMAGE_2D_ARRAY),
-    /// [`GL_IMAGE_2D_MULTISAMPLE`](crate::gl_enums::GL_IMAGE_2D_MULTISAMPLE), [`GL_IMAGE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_IMAGE_2D_MULTISAMPLE_ARRAY),
-    /// [`GL_INT_IMAGE_1D`](crate::gl_enums::GL_INT_IMAGE_1D), [`GL_INT_IMAGE_2D`](crate::gl_enums::GL_INT_IMAGE_2D),
-    /// [`GL_INT_IMAGE_3D`](crate::gl_enums::GL_INT_IMAGE_3D), [`GL_INT_IMAGE_2D_RECT`](crate::gl_enums::GL_INT_IMAGE_2D_RECT),
-    /// [`GL_INT_IMAGE_CUBE`](crate::gl_enums::GL_INT_IMAGE_CUBE), [`GL_INT_IMAGE_BUFFER`](crate::gl_enums::GL_INT_IMAGE_BUFFER),
-    /// [`GL_INT_IMAGE_1D_ARRAY`](crate::gl_enums::GL_INT_IMAGE_1D_ARRAY), [`GL_INT_IMAGE_2D_ARRAY`](crate::gl_enums::GL_INT_IMAGE_2D_ARRAY),
-    /// [`GL_INT_IMAGE_2D_MULTISAMPLE`](crate::gl_enums::GL_INT_IMAGE_2D_MULTISAMPLE),
-    /// [`GL_INT_IMAGE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_INT_IMAGE_2D_MULTISAMPLE_ARRAY),
-    /// [`GL_UNSIGNED_INT_IMAGE_1D`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_1D),
-    /// [`GL_UNSIGNED_INT_IMAGE_2D`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D),
-    /// [`GL_UNSIGNED_INT_IMAGE_3D`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_3D),
-    /// [`GL_UNSIGNED_INT_IMAGE_2D_RECT`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_RECT),
-    /// [`GL_UNSIGNED_INT_IMAGE_CUBE`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_CUBE),
-    /// [`GL_UNSIGNED_INT_IMAGE_BUFFER`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_BUFFER),
-    /// [`GL_UNSIGNED_INT_IMAGE_1D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_1D_ARRAY),
-    /// [`GL_UNSIGNED_INT_IMAGE_2D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_ARRAY),
-    /// [`GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE),
-    /// [`GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY),
-    /// and the atomic counter type, [`GL_UNSIGNED_INT_ATOMIC_COUNTER`](crate::gl_enums::GL_UNSIGNED_INT_ATOMIC_COUNTER)
-    /// are only available if the GL version is 4.2 or higher.
+    /// > The X coordinate of the left edge of the destination region.
     ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_VERTEX_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_VERTEX_UNIFORM_COMPONENTS),
-    /// [`GL_MAX_GEOMETRY_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_GEOMETRY_UNIFORM_COMPONENTS),
-    /// [`GL_MAX_TESS_CONTROL_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_TESS_CONTROL_UNIFORM_COMPONENTS),
-    /// [`GL_MAX_TESS_EVALUATION_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_TESS_EVALUATION_UNIFORM_COMPONENTS),
-    /// [`GL_MAX_FRAGMENT_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_FRAGMENT_UNIFORM_COMPONENTS).
+    /// `dstY`
     ///
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
-    /// [`GL_ACTIVE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_UNIFORMS) or [`GL_ACTIVE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_UNIFORM_MAX_LENGTH).
+    /// > The Y coordinate of the top edge of the destination region.
     ///
-    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
-    pub unsafe fn oxidegl_get_active_uniform(
-        &mut self,
-        program: GLuint,
-        index: GLuint,
-        buf_size: GLsizei,
-        length: *mut GLsizei,
-        size: *mut GLint,
-        r#type: *mut UniformType,
-        name: *mut GLchar,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_active_uniform not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
+    /// `dstZ`
     ///
-    /// > Specifies the name of a program containing the uniform block.
+    /// > The Z coordinate of the near edge of the destination region.
     ///
-    /// `uniformBlockIndex`
+    /// `srcWidth`
     ///
-    /// > Specifies the index of the uniform block within `program`.
+    /// > The width of the region to be copied.
     ///
-    /// `pname`
+    /// `srcHeight`
     ///
-    /// > Specifies the name of the parameter to query.
+    /// > The height of the region to be copied.
     ///
-    /// `params`
+    /// `srcDepth`
     ///
-    /// > Specifies the address of a variable to receive the result of the query.
+    /// > The depth of the region to be copied.
     ///
     /// ### Description
-    /// [**glGetActiveUniformBlockiv**](crate::context::Context::oxidegl_get_active_uniform_blockiv)
-    /// retrieves information about an active uniform block within `program`.
+    /// [**glCopyImageSubData**](crate::context::Context::oxidegl_copy_image_sub_data)
+    /// may be used to copy data from one image (i.e. texture or renderbuffer)
+    /// to another. [**glCopyImageSubData**](crate::context::Context::oxidegl_copy_image_sub_data)
+    /// does not perform general-purpose conversions such as scaling, resizing,
+    /// blending, color-space, or format conversions. It should be considered to
+    /// operate in a manner similar to a CPU memcpy. `CopyImageSubData` can copy
+    /// between images with different internal formats, provided the formats are
+    /// compatible.
     ///
-    /// `program` must be the name of a program object for which the command [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// must have been called in the past, although it is not required that [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// must have succeeded. The link could have failed because the number of active
-    /// uniforms exceeded the limit.
+    /// [**glCopyImageSubData**](crate::context::Context::oxidegl_copy_image_sub_data)
+    /// also allows copying between certain types of compressed and uncompressed
+    /// internal formats. This copy does not perform on-the-fly compression or
+    /// decompression. When copying from an uncompressed internal format to a compressed
+    /// internal format, each texel of uncompressed data becomes a single block
+    /// of compressed data. When copying from a compressed internal format to an
+    /// uncompressed internal format, a block of compressed data becomes a single
+    /// texel of uncompressed data. The texel size of the uncompressed format must
+    /// be the same size the block size of the compressed formats. Thus it is permitted
+    /// to copy between a 128-bit uncompressed format and a compressed format which
+    /// uses 8-bit 4x4 blocks, or between a 64-bit uncompressed format and a compressed
+    /// format which uses 4-bit 4x4 blocks.
     ///
-    /// `uniformBlockIndex` is an active uniform block index of `program`, and
-    /// must be less than the value of [`GL_ACTIVE_UNIFORM_BLOCKS`](crate::gl_enums::GL_ACTIVE_UNIFORM_BLOCKS).
+    /// The source object is identified by `srcName` and `srcTarget` and the destination
+    /// object is identified by `dstName` and `dstTarget`. The interpretation of
+    /// the name depends on the value of the corresponding `target` parameter.
+    /// If `target` is [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER), the
+    /// name is interpreted as the name of a renderbuffer object. If the target
+    /// parameter is a texture target, the name is interpreted as a texture object.
+    /// All non-proxy texture targets are accepted, with the exception of [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER)
+    /// and the cubemap face selectors.
     ///
-    /// Upon success, the uniform block parameter(s) specified by `pname` are returned
-    /// in `params`. If an error occurs, nothing will be written to `params`.
+    /// `srcLevel` and `dstLevel` identify the source and destination level of
+    /// detail. For textures, this must be a valid level of detail in the texture
+    /// object. For renderbuffers, this value must be zero.
     ///
-    /// If `pname` is [`GL_UNIFORM_BLOCK_BINDING`](crate::gl_enums::GL_UNIFORM_BLOCK_BINDING),
-    /// then the index of the uniform buffer binding point last selected by the
-    /// uniform block specified by `uniformBlockIndex` for `program` is returned.
-    /// If no uniform block has been previously specified, zero is returned.
+    /// `srcX`, `srcY`, and `srcZ` specify the lower left texel coordinates of
+    /// a `srcWidth` -wide by `srcHeight` -high by `srcDepth` -deep rectangular
+    /// subregion of the source texel array. Similarly, `dstX`, `dstY` and `dstZ`
+    /// specify the coordinates of a subregion of the destination texel array.
+    /// The source and destination subregions must be contained entirely within
+    /// the specified level of the corresponding image objects.
     ///
-    /// If `pname` is [`GL_UNIFORM_BLOCK_DATA_SIZE`](crate::gl_enums::GL_UNIFORM_BLOCK_DATA_SIZE),
-    /// then the implementation-dependent minimum total buffer object size, in
-    /// basic machine units, required to hold all active uniforms in the uniform
-    /// block identified by `uniformBlockIndex` is returned. It is neither guaranteed
-    /// nor expected that a given implementation will arrange uniform values as
-    /// tightly packed in a buffer object. The exception to this is the *std140
-    /// uniform block layout*, which guarantees specific packing behavior and does
-    /// not require the application to query for offsets and strides. In this case
-    /// the minimum size may still be queried, even though it is determined in
-    /// advance based only on the uniform block declaration.
+    /// The dimensions are always specified in texels, even for compressed texture
+    /// formats. However, it should be noted that if only one of the source and
+    /// destination textures is compressed then the number of texels touched in
+    /// the compressed image will be a factor of the block size larger than in
+    /// the uncompressed image.
+    ///
+    /// Slices of a [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY),
+    /// [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY)
+    /// [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D) and faces of [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP)
+    /// are all compatible provided they share a compatible internal format, and
+    /// multiple slices or faces may be copied between these objects with a single
+    /// call by specifying the starting slice with `srcZ` and `dstZ`, and the number
+    /// of slices to be copied with `srcDepth`. Cubemap textures always have six
+    /// faces which are selected by a zero-based face index.
     ///
-    /// If `pname` is [`GL_UNIFORM_BLOCK_NAME_LENGTH`](crate::gl_enums::GL_UNIFORM_BLOCK_NAME_LENGTH),
-    /// then the total length (including the nul terminator) of the name of the
-    /// uniform block identified by `uniformBlockIndex` is returned.
+    /// For the purposes of `CopyImageSubData`, two internal formats are considered
+    /// compatible if any of the following conditions are met: the formats are
+    /// > the same,
     ///
-    /// If `pname` is [`GL_UNIFORM_BLOCK_ACTIVE_UNIFORMS`](crate::gl_enums::GL_UNIFORM_BLOCK_ACTIVE_UNIFORMS),
-    /// then the number of active uniforms in the uniform block identified by
-    /// `uniformBlockIndex` is returned.
+    /// > the formats are considered compatible according to the compatibility rules
+    /// > used for texture views as defined in section 3.9.X. In particular, if both
+    /// > internal formats are listed in the same entry of Table 3.X.2, they are
+    /// > considered compatible, or
     ///
-    /// If `pname` is [`GL_UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES`](crate::gl_enums::GL_UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES),
-    /// then a list of the active uniform indices for the uniform block identified
-    /// by `uniformBlockIndex` is returned. The number of elements that will be
-    /// written to `params` is the value of [`GL_UNIFORM_BLOCK_ACTIVE_UNIFORMS`](crate::gl_enums::GL_UNIFORM_BLOCK_ACTIVE_UNIFORMS)
-    /// for `uniformBlockIndex`.
+    /// > one format is compressed and the other is uncompressed and Table 4.X.1
+    /// > lists the two formats in the same row.
     ///
-    /// If `pname` is [`GL_UNIFORM_BLOCK_REFERENCED_BY_VERTEX_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_VERTEX_SHADER),
-    /// [`GL_UNIFORM_BLOCK_REFERENCED_BY_TESS_CONTROL_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_TESS_CONTROL_SHADER),
-    /// [`GL_UNIFORM_BLOCK_REFERENCED_BY_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_TESS_EVALUATION_SHADER),
-    /// [`GL_UNIFORM_BLOCK_REFERENCED_BY_GEOMETRY_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_GEOMETRY_SHADER),
-    /// [`GL_UNIFORM_BLOCK_REFERENCED_BY_FRAGMENT_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_FRAGMENT_SHADER),
-    /// or [`GL_UNIFORM_BLOCK_REFERENCED_BY_COMPUTE_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_COMPUTE_SHADER)
-    /// then a boolean value indicating whether the uniform block identified by
-    /// `uniformBlockIndex` is referenced by the vertex, tessellation control,
-    /// tessellation evaluation, geometry, fragment or compute programming stages
-    /// of program, respectively, is returned.
     ///
-    /// ### Notes
-    /// [**glGetActiveUniformBlockiv**](crate::context::Context::oxidegl_get_active_uniform_blockiv)
-    /// is available only if the GL version is 3.1 or greater.
+    /// |* Texel/ Block Size*                             |* Uncompressed Internal Format*                                   |* Compressed Internal Format(s)*                                   |
+    /// |-------------------------------------------------|------------------------------------------------------------------|-------------------------------------------------------------------|
+    /// | 64-bit                                          | [`GL_RGBA32UI`](crate::gl_enums::GL_RGBA32UI), [`GL_RGBA32I`](crate::gl_enums::GL_RGBA32I), [`GL_RGBA32F`](crate::gl_enums::GL_RGBA32F) | [`GL_COMPRESSED_RGBA_S3TC_DXT3_EXT`](crate::gl_enums::GL_COMPRESSED_RGBA_S3TC_DXT3_EXT), [`GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT`](crate::gl_enums::GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT), [`GL_COMPRESSED_RGBA_S3TC_DXT5_EXT`](crate::gl_enums::GL_COMPRESSED_RGBA_S3TC_DXT5_EXT), [`GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT`](crate::gl_enums::GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT), [`GL_COMPRESSED_RG_RGTC2`](crate::gl_enums::GL_COMPRESSED_RG_RGTC2), [`GL_COMPRESSED_SIGNED_RG_RGTC2`](crate::gl_enums::GL_COMPRESSED_SIGNED_RG_RGTC2), [`GL_COMPRESSED_RGBA_BPTC_UNORM`](crate::gl_enums::GL_COMPRESSED_RGBA_BPTC_UNORM), [`GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM`](crate::gl_enums::GL_COMPRESSED_SRGB_ALPHA_BPTC_UNORM), [`GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT`](crate::gl_enums::GL_COMPRESSED_RGB_BPTC_SIGNED_FLOAT), [`GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT`](crate::gl_enums::GL_COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT) |
+    /// | 128-bit                                         | [`GL_RGBA16UI`](crate::gl_enums::GL_RGBA16UI), [`GL_RGBA16I`](crate::gl_enums::GL_RGBA16I), [`GL_RGBA16F`](crate::gl_enums::GL_RGBA16F), [`GL_RG32F`](crate::gl_enums::GL_RG32F), [`GL_RG32UI`](crate::gl_enums::GL_RG32UI), [`GL_RG32I`](crate::gl_enums::GL_RG32I), [`GL_RGBA16`](crate::gl_enums::GL_RGBA16), [`GL_RGBA16_SNORM`](crate::gl_enums::GL_RGBA16_SNORM) | [`GL_COMPRESSED_RGB_S3TC_DXT1_EXT`](crate::gl_enums::GL_COMPRESSED_RGB_S3TC_DXT1_EXT), [`GL_COMPRESSED_SRGB_S3TC_DXT1_EXT`](crate::gl_enums::GL_COMPRESSED_SRGB_S3TC_DXT1_EXT), [`GL_COMPRESSED_RGBA_S3TC_DXT1_EXT`](crate::gl_enums::GL_COMPRESSED_RGBA_S3TC_DXT1_EXT), [`GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT`](crate::gl_enums::GL_COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT), [`GL_COMPRESSED_RED_RGTC1`](crate::gl_enums::GL_COMPRESSED_RED_RGTC1), [`GL_COMPRESSED_SIGNED_RED_RGTC1`](crate::gl_enums::GL_COMPRESSED_SIGNED_RED_RGTC1) |
     ///
-    /// [`GL_UNIFORM_BLOCK_REFERENCED_BY_COMPUTE_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_COMPUTE_SHADER)
-    /// is accepted only if the GL version is 4.3 or greater.
-    pub unsafe fn oxidegl_get_active_uniform_blockiv(
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_COMPUTE_WORK_GROUP_COUNT`](crate::gl_enums::GL_MAX_COMPUTE_WORK_GROUP_COUNT)
+    pub fn oxidegl_copy_image_sub_data(
         &mut self,
-        program: GLuint,
-        uniform_block_index: GLuint,
-        pname: UniformBlockPName,
-        params: *mut GLint,
+        src_name: GLuint,
+        src_target: CopyImageSubDataTarget,
+        src_level: GLint,
+        src_x: GLint,
+        src_y: GLint,
+        src_z: GLint,
+        dst_name: GLuint,
+        dst_target: CopyImageSubDataTarget,
+        dst_level: GLint,
+        dst_x: GLint,
+        dst_y: GLint,
+        dst_z: GLint,
+        src_width: GLsizei,
+        src_height: GLsizei,
+        src_depth: GLsizei,
     ) -> GlFallible {
-        panic!("command oxidegl_get_active_uniform_blockiv not yet implemented");
+        panic!("command oxidegl_copy_image_sub_data not yet implemented");
     }
     /// ### Parameters
-    /// `program`
+    /// `target`
     ///
-    /// > Specifies the name of a program containing the uniform block.
+    /// > Specifies the target texture. Must be [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D).
     ///
-    /// `uniformBlockIndex`
+    /// `level`
     ///
-    /// > Specifies the index of the uniform block within `program`.
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level *n* is the *n* th mipmap reduction image.
     ///
-    /// `bufSize`
+    /// `internalformat`
     ///
-    /// > Specifies the size of the buffer addressed by `uniformBlockName`.
+    /// > Specifies the internal format of the texture. Must be one of the following
+    /// > symbolic constants: [`GL_COMPRESSED_RED`](crate::gl_enums::GL_COMPRESSED_RED),
+    /// > [`GL_COMPRESSED_RG`](crate::gl_enums::GL_COMPRESSED_RG), [`GL_COMPRESSED_RGB`](crate::gl_enums::GL_COMPRESSED_RGB),
+    /// > [`GL_COMPRESSED_RGBA`](crate::gl_enums::GL_COMPRESSED_RGBA). [`GL_COMPRESSED_SRGB`](crate::gl_enums::GL_COMPRESSED_SRGB),
+    /// > [`GL_COMPRESSED_SRGB_ALPHA`](crate::gl_enums::GL_COMPRESSED_SRGB_ALPHA).
+    /// > [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT), [`GL_DEPTH_COMPONENT16`](crate::gl_enums::GL_DEPTH_COMPONENT16),
+    /// > [`GL_DEPTH_COMPONENT24`](crate::gl_enums::GL_DEPTH_COMPONENT24), [`GL_DEPTH_COMPONENT32`](crate::gl_enums::GL_DEPTH_COMPONENT32),
+    /// > [`GL_STENCIL_INDEX8`](crate::gl_enums::GL_STENCIL_INDEX8), [`GL_RED`](crate::gl_enums::GL_RED),
+    /// > [`GL_RG`](crate::gl_enums::GL_RG), [`GL_RGB`](crate::gl_enums::GL_RGB),
+    /// > [`GL_R3_G3_B2`](crate::gl_enums::GL_R3_G3_B2), [`GL_RGB4`](crate::gl_enums::GL_RGB4),
+    /// > [`GL_RGB5`](crate::gl_enums::GL_RGB5), [`GL_RGB8`](crate::gl_enums::GL_RGB8),
+    /// > [`GL_RGB10`](crate::gl_enums::GL_RGB10), [`GL_RGB12`](crate::gl_enums::GL_RGB12),
+    /// > [`GL_RGB16`](crate::gl_enums::GL_RGB16), [`GL_RGBA`](crate::gl_enums::GL_RGBA),
+    /// > [`GL_RGBA2`](crate::gl_enums::GL_RGBA2), [`GL_RGBA4`](crate::gl_enums::GL_RGBA4),
+    /// > [`GL_RGB5_A1`](crate::gl_enums::GL_RGB5_A1), [`GL_RGBA8`](crate::gl_enums::GL_RGBA8),
+    /// > [`GL_RGB10_A2`](crate::gl_enums::GL_RGB10_A2), [`GL_RGBA12`](crate::gl_enums::GL_RGBA12),
+    /// > [`GL_RGBA16`](crate::gl_enums::GL_RGBA16), [`GL_SRGB`](crate::gl_enums::GL_SRGB),
+    /// > [`GL_SRGB8`](crate::gl_enums::GL_SRGB8), [`GL_SRGB_ALPHA`](crate::gl_enums::GL_SRGB_ALPHA),
+    /// > or [`GL_SRGB8_ALPHA8`](crate::gl_enums::GL_SRGB8_ALPHA8).
     ///
-    /// `length`
+    /// `x`
     ///
-    /// > Specifies the address of a variable to receive the number of characters
-    /// > that were written to `uniformBlockName`.
+    /// `y`
     ///
-    /// `uniformBlockName`
+    /// > Specify the window coordinates of the left corner of the row of pixels
+    /// > to be copied.
     ///
-    /// > Specifies the address an array of characters to receive the name of the
-    /// > uniform block at `uniformBlockIndex`.
+    /// `width`
+    ///
+    /// > Specifies the width of the texture image. The height of the texture image
+    /// > is 1.
+    ///
+    /// `border`
+    ///
+    /// > Must be 0.
     ///
     /// ### Description
-    /// [**glGetActiveUniformBlockName**](crate::context::Context::oxidegl_get_active_uniform_block_name)
-    /// retrieves the name of the active uniform block at `uniformBlockIndex` within
-    /// `program`.
+    /// [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d)
+    /// defines a one-dimensional texture image with pixels from the current [`GL_READ_BUFFER`](crate::gl_enums::GL_READ_BUFFER).
     ///
-    /// `program` must be the name of a program object for which the command [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// must have been called in the past, although it is not required that [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// must have succeeded. The link could have failed because the number of active
-    /// uniforms exceeded the limit.
+    /// The screen-aligned pixel row with left corner at `[inlineq]` `[inlineq]`
+    /// `level`. `internalformat` specifies the internal format of the texture
+    /// array.
     ///
-    /// `uniformBlockIndex` is an active uniform block index of `program`, and
-    /// must be less than the value of [`GL_ACTIVE_UNIFORM_BLOCKS`](crate::gl_enums::GL_ACTIVE_UNIFORM_BLOCKS).
+    /// The pixels in the row are processed exactly as if [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
+    /// had been called, but the process stops just before final conversion. At
+    /// this point all pixel component values are clamped to the range `[inlineq]`
     ///
-    /// Upon success, the name of the uniform block identified by `unifomBlockIndex`
-    /// is returned into `uniformBlockName`. The name is nul-terminated. The actual
-    /// number of characters written into `uniformBlockName`, excluding the nul
-    /// terminator, is returned in `length`. If `length` is
+    /// Pixel ordering is such that lower `[inlineq]`
     ///
-    /// `bufSize` contains the maximum number of characters (including the nul
-    /// terminator) that will be written into `uniformBlockName`.
+    /// If any of the pixels within the specified row of the current [`GL_READ_BUFFER`](crate::gl_enums::GL_READ_BUFFER)
+    /// are outside the window associated with the current rendering context, then
+    /// the values obtained for those pixels are undefined.
     ///
-    /// If an error occurs, nothing will be written to `uniformBlockName` or `length`.
+    /// [**glCopyTexImage1D**](crate::context::Context::oxidegl_copy_tex_image1_d)
+    /// defines a one-dimensional texture image with pixels from the current [`GL_READ_BUFFER`](crate::gl_enums::GL_READ_BUFFER).
+    ///
+    /// When `internalformat` is one of the sRGB types, the GL does not automatically
+    /// convert the source pixels to the sRGB color space. In this case, the [**glPixelMap**](crate::context::Context::oxidegl_pixel_map)
+    /// function can be used to accomplish the conversion.
     ///
     /// ### Notes
-    /// [**glGetActiveUniformBlockName**](crate::context::Context::oxidegl_get_active_uniform_block_name)
-    /// is available only if the GL version is 3.1 or greater.
-    pub unsafe fn oxidegl_get_active_uniform_block_name(
+    /// 1, 2, 3, and 4 are not accepted values for `internalformat`.
+    ///
+    /// An image with 0 width indicates a NULL texture.
+    ///
+    /// [`GL_STENCIL_INDEX8`](crate::gl_enums::GL_STENCIL_INDEX8) is accepted for
+    /// `internalformat` only if the GL version is 4.4 or higher.
+    ///
+    /// ### Associated Gets
+    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+    pub fn oxidegl_copy_tex_image1_d(
         &mut self,
-        program: GLuint,
-        uniform_block_index: GLuint,
-        buf_size: GLsizei,
-        length: *mut GLsizei,
-        uniform_block_name: *mut GLchar,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        border: GLint,
     ) -> GlFallible {
-        panic!("command oxidegl_get_active_uniform_block_name not yet implemented");
+        panic!("command oxidegl_copy_tex_image1_d not yet implemented");
     }
     /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the program containing the active uniform index `uniformIndex`.
-    ///
-    /// `uniformIndex`
-    ///
-    /// > Specifies the index of the active uniform whose name to query.
-    ///
-    /// `bufSize`
-    ///
-    /// > Specifies the size of the buffer, in units of `uniformName`.
+    /// `target`
     ///
-    /// `length`
+    /// > Specifies the target texture. Must be [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y),
+    /// > [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y),
+    /// > [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z),
+    /// > or [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z).
     ///
-    /// > Specifies the address of a variable that will receive the number of characters
-    /// > that were or would have been written to the buffer addressed by `uniformName`.
+    /// `level`
     ///
-    /// `uniformName`
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level *n* is the *n* th mipmap reduction image.
     ///
-    /// > Specifies the address of a buffer into which the GL will place the name
-    /// > of the active uniform at `uniformIndex` within `program`.
+    /// `internalformat`
     ///
-    /// ### Description
-    /// [**glGetActiveUniformName**](crate::context::Context::oxidegl_get_active_uniform_name)
-    /// returns the name of the active uniform at `uniformIndex` within `program`.
-    /// If `uniformName` is not `bufSize` characters (including a nul-terminator)
-    /// will be written into the array whose address is specified by `uniformName`.
-    /// If `length` is not `uniformName` (not including the nul-terminator) will
-    /// be placed in the variable whose address is specified in `length`. If `length`
-    /// is `program` is given by the value of [`GL_ACTIVE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_UNIFORM_MAX_LENGTH),
-    /// which can be queried with [**glGetProgram**](crate::context::Context::oxidegl_get_program).
+    /// > Specifies the internal format of the texture. Must be one of the following
+    /// > symbolic constants: [`GL_COMPRESSED_RED`](crate::gl_enums::GL_COMPRESSED_RED),
+    /// > [`GL_COMPRESSED_RG`](crate::gl_enums::GL_COMPRESSED_RG), [`GL_COMPRESSED_RGB`](crate::gl_enums::GL_COMPRESSED_RGB),
+    /// > [`GL_COMPRESSED_RGBA`](crate::gl_enums::GL_COMPRESSED_RGBA). [`GL_COMPRESSED_SRGB`](crate::gl_enums::GL_COMPRESSED_SRGB),
+    /// > [`GL_COMPRESSED_SRGB_ALPHA`](crate::gl_enums::GL_COMPRESSED_SRGB_ALPHA).
+    /// > [`GL_DEPTH_COMPONENT`](crate::gl_enums::GL_DEPTH_COMPONENT), [`GL_DEPTH_COMPONENT16`](crate::gl_enums::GL_DEPTH_COMPONENT16),
+    /// > [`GL_DEPTH_COMPONENT24`](crate::gl_enums::GL_DEPTH_COMPONENT24), [`GL_DEPTH_COMPONENT32`](crate::gl_enums::GL_DEPTH_COMPONENT32),
+    /// > [`GL_STENCIL_INDEX8`](crate::gl_enums::GL_STENCIL_INDEX8), [`GL_RED`](crate::gl_enums::GL_RED),
+    /// > [`GL_RG`](crate::gl_enums::GL_RG), [`GL_RGB`](crate::gl_enums::GL_RGB),
+    /// > [`GL_R3_G3_B2`](crate::gl_enums::GL_R3_G3_B2), [`GL_RGB4`](crate::gl_enums::GL_RGB4),
+    /// > [`GL_RGB5`](crate::gl_enums::GL_RGB5), [`GL_RGB8`](crate::gl_enums::GL_RGB8),
+    /// > [`GL_RGB10`](crate::gl_enums::GL_RGB10), [`GL_RGB12`](crate::gl_enums::GL_RGB12),
+    /// > [`GL_RGB16`](crate::gl_enums::GL_RGB16), [`GL_RGBA`](crate::gl_enums::GL_RGBA),
+    /// > [`GL_RGBA2`](crate::gl_enums::GL_RGBA2), [`GL_RGBA4`](crate::gl_enums::GL_RGBA4),
+    /// > [`GL_RGB5_A1`](crate::gl_enums::GL_RGB5_A1), [`GL_RGBA8`](crate::gl_enums::GL_RGBA8),
+    /// > [`GL_RGB10_A2`](crate::gl_enums::GL_RGB10_A2), [`GL_RGBA12`](crate::gl_enums::GL_RGBA12),
+    /// > [`GL_RGBA16`](crate::gl_enums::GL_RGBA16), [`GL_SRGB`](crate::gl_enums::GL_SRGB),
+    /// > [`GL_SRGB8`](crate::gl_enums::GL_SRGB8), [`GL_SRGB_ALPHA`](crate::gl_enums::GL_SRGB_ALPHA),
+    /// > or [`GL_SRGB8_ALPHA8`](crate::gl_enums::GL_SRGB8_ALPHA8).
     ///
-    /// If [**glGetActiveUniformName**](crate::context::Context::oxidegl_get_active_uniform_name)
-    /// is not successful, nothing is written to `length` or `uniformName`.
+    /// `x`
     ///
-    /// `program` must be the name of a program for which the command [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// has been issued in the past. It is not necessary for `program` to have
-    /// been linked successfully. The link could have failed because the number
-    /// of active uniforms exceeded the limit.
+    /// `y`
     ///
-    /// `uniformIndex` must be an active uniform index of the program `program`,
-    /// in the range zero to the value of [`GL_ACTIVE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_UNIFORMS)
-    /// minus one. The value of [`GL_ACTIVE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_UNIFORMS)
-    /// can be queried with [**glGetProgram**](crate::context::Context::oxidegl_get_program).
-    pub unsafe fn oxidegl_get_active_uniform_name(
-        &mut self,
-        program: GLuint,
-        uniform_index: GLuint,
-        buf_size: GLsizei,
-        length: *mut GLsizei,
-        uniform_name: *mut GLchar,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_active_uniform_name not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
+    /// > Specify the window coordinates of the lower left corner of the rectangular
+    /// > region of pixels to be copied.
     ///
-    /// > Specifies the program object to be queried.
+    /// `width`
     ///
-    /// `uniformCount`
+    /// > Specifies the width of the texture image.
     ///
-    /// > Specifies both the number of elements in the array of indices `uniformIndices`
-    /// > and the number of parameters written to `params` upon successful return.
+    /// `height`
     ///
-    /// `uniformIndices`
+    /// > Specifies the height of the texture image.
     ///
-    /// > Specifies the address of an array of `uniformCount` integers containing
-    /// > the indices of uniforms within `program` whose parameter `pname` should
-    /// > be queried.
+    /// `border`
     ///
-    /// `pname`
+    /// > Must be 0.
     ///
-    /// > Specifies the property of each uniform in `uniformIndices` that should
-    /// > be written into the corresponding element of `params`.
+    /// ### Description
+    /// [**glCopyTexImage2D**](crate::context::Context::oxidegl_copy_tex_image2_d)
+    /// defines a two-dimensional texture image, or cube-map texture image with
+    /// pixels from the current [`GL_READ_BUFFER`](crate::gl_enums::GL_READ_BUFFER).
     ///
-    /// `params`
+    /// The screen-aligned pixel rectangle with lower left corner at( `x`, `y`)
+    /// and with a width of `[inlineq]` `[inlineq]` `level`. `internalformat`
+    /// specifies the internal format of the texture array.
     ///
-    /// > Specifies the address of an array of `uniformCount` integers which are
-    /// > to receive the value of `pname` for each uniform in `uniformIndices`.
+    /// The pixels in the rectangle are processed exactly as if [**glReadPixels**](crate::context::Context::oxidegl_read_pixels)
+    /// had been called, but the process stops just before final conversion. At
+    /// this point all pixel component values are clamped to the range `[inlineq]`
     ///
-    /// ### Description
-    /// [**glGetActiveUniformsiv**](crate::context::Context::oxidegl_get_active_uniformsiv)
-    /// queries the value of the parameter named `pname` for each of the uniforms
-    /// within `program` whose indices are specified in the array of `uniformCount`
-    /// unsigned integers `uniformIndices`. Upon success, the value of the parameter
-    /// for each uniform is written into the corresponding entry in the array whose
-    /// address is given in `params`. If an error is generated, nothing is written
-    /// into `params`.
+    /// Pixel ordering is such that lower `[inlineq]` `[inlineq]` `[inlineq]` `[inlineq]`
     ///
-    /// If `pname` is [`GL_UNIFORM_TYPE`](crate::gl_enums::GL_UNIFORM_TYPE), then
-    /// an array identifying the types of uniforms specified by the corresponding
-    /// array of `uniformIndices` is returned. The returned types can be any of
-    /// the values from the following table:
-    /// |* Returned Symbolic Contant*                                 |* Shader Uniform Type*                              |
-    /// |-------------------------------------------------------------|----------------------------------------------------|
-    /// | [`GL_FLOAT`](crate::gl_enums::GL_FLOAT)                     | [`float`](crate::gl_enums::float)                  |
-    /// | [`GL_FLOAT_VEC2`](crate::gl_enums::GL_FLOAT_VEC2)           | [`vec2`](crate::gl_enums::vec2)                    |
-    /// | [`GL_FLOAT_VEC3`](crate::gl_enums::GL_FLOAT_VEC3)           | [`vec3`](crate::gl_enums::vec3)                    |
-    /// | [`GL_FLOAT_VEC4`](crate::gl_enums::GL_FLOAT_VEC4)           | [`vec4`](crate::gl_enums::vec4)                    |
-    /// | [`GL_DOUBLE`](crate::gl_enums::GL_DOUBLE)                   | [`double`](crate::gl_enums::double)                |
-    /// | [`GL_DOUBLE_VEC2`](crate::gl_enums::GL_DOUBLE_VEC2)         | [`dvec2`](crate::gl_enums::dvec2)                  |
-    /// | [`GL_DOUBLE_VEC3`](crate::gl_enums::GL_DOUBLE_VEC3)         | [`dvec3`](crate::gl_enums::dvec3)                  |
-    /// | [`GL_DOUBLE_VEC4`](crate::gl_enums::GL_DOUBLE_VEC4)         | [`dvec4`](crate::gl_enums::dvec4)                  |
-    /// | [`GL_INT`](crate::gl_enums::GL_INT)                         | [`int`](crate::gl_enums::int)                      |
-    /// | [`GL_INT_VEC2`](crate::gl_enums::GL_INT_VEC2)               | [`ivec2`](crate::gl_enums::ivec2)                  |
-    /// | [`GL_INT_VEC3`](crate::gl_enums::GL_INT_VEC3)               | [`ivec3`](crate::gl_enums::ivec3)                  |
-    /// | [`GL_INT_VEC4`](crate::gl_enums::GL_INT_VEC4)               | [`ivec4`](crate::gl_enums::ivec4)                  |
-    /// | [`GL_UNSIGNED_INT`](crate::gl_enums::GL_UNSIGNED_INT)       | [`unsigned int`](`crate::gl_enums::unsigned` int)    |
-    /// | [`GL_UNSIGNED_INT_VEC2`](crate::gl_enums::GL_UNSIGNED_INT_VEC2) | [`uvec2`](crate::gl_enums::uvec2)              |
-    /// | [`GL_UNSIGNED_INT_VEC3`](crate::gl_enums::GL_UNSIGNED_INT_VEC3) | [`uvec3`](crate::gl_enums::uvec3)              |
-    /// | [`GL_UNSIGNED_INT_VEC4`](crate::gl_enums::GL_UNSIGNED_INT_VEC4) | [`uvec4`](crate::gl_enums::uvec4)              |
-    /// | [`GL_BOOL`](crate::gl_enums::GL_BOOL)                       | [`bool`](crate::gl_enums::bool)                    |
-    /// | [`GL_BOOL_VEC2`](crate::gl_enums::GL_BOOL_VEC2)             | [`bvec2`](crate::gl_enums::bvec2)                  |
-    /// | [`GL_BOOL_VEC3`](crate::gl_enums::GL_BOOL_VEC3)             | [`bvec3`](crate::gl_enums::bvec3)                  |
-    /// | [`GL_BOOL_VEC4`](crate::gl_enums::GL_BOOL_VEC4)             | [`bvec4`](crate::gl_enums::bvec4)                  |
-    /// | [`GL_FLOAT_MAT2`](crate::gl_enums::GL_FLOAT_MAT2)           | [`mat2`](crate::gl_enums::mat2)                    |
-    /// | [`GL_FLOAT_MAT3`](crate::gl_enums::GL_FLOAT_MAT3)           | [`mat3`](crate::gl_enums::mat3)                    |
-    /// | [`GL_FLOAT_MAT4`](crate::gl_enums::GL_FLOAT_MAT4)           | [`mat4`](crate::gl_enums::mat4)                    |
-    /// | [`GL_FLOAT_MAT2x3`](crate::gl_enums::GL_FLOAT_MAT2x3)       | [`mat2x3`](crate::gl_enums::mat2x3)                |
-    /// | [`GL_FLOAT_MAT2x4`](crate::gl_enums::GL_FLOAT_MAT2x4)       | [`mat2x4`](crate::gl_enums::mat2x4)                |
-    /// | [`GL_FLOAT_MAT3x2`](crate::gl_enums::GL_FLOAT_MAT3x2)       | [`mat3x2`](crate::gl_enums::mat3x2)                |
-    /// | [`GL_FLOAT_MAT3x4`](crate::gl_enums::GL_FLOAT_MAT3x4)       | [`mat3x4`](crate::gl_enums::mat3x4)                |
-    /// | [`GL_FLOAT_MAT4x2`](crate::gl_enums::GL_FLOAT_MAT4x2)       | [`mat4x2`](crate::gl_enums::mat4x2)                |
-    /// | [`GL_FLOAT_MAT4x3`](crate::gl_enums::GL_FLOAT_MAT4x3)       | [`mat4x3`](crate::gl_enums::mat4x3)                |
-    /// | [`GL_DOUBLE_MAT2`](crate::gl_enums::GL_DOUBLE_MAT2)         | [`dmat2`](crate::gl_enums::dmat2)                  |
-    /// | [`GL_DOUBLE_MAT3`](crate::gl_enums::GL_DOUBLE_MAT3)         | [`dmat3`](crate::gl_enums::dmat3)                  |
-    /// | [`GL_DOUBLE_MAT4`](crate::gl_enums::GL_DOUBLE_MAT4)         | [`dmat4`](crate::gl_enums::dmat4)                  |
-    /// | [`GL_DOUBLE_MAT2x3`](crate::gl_enums::GL_DOUBLE_MAT2x3)     | [`dmat2x3`](crate::gl_enums::dmat2x3)              |
-    /// | [`GL_DOUBLE_MAT2x4`](crate::gl_enums::GL_DOUBLE_MAT2x4)     | [`dmat2x4`](crate::gl_enums::dmat2x4)              |
-    /// | [`GL_DOUBLE_MAT3x2`](crate::gl_enums::GL_DOUBLE_MAT3x2)     | [`dmat3x2`](crate::gl_enums::dmat3x2)              |
-    /// | [`GL_DOUBLE_MAT3x4`](crate::gl_enums::GL_DOUBLE_MAT3x4)     | [`dmat3x4`](crate::gl_enums::dmat3x4)              |
-    /// | [`GL_DOUBLE_MAT4x2`](crate::gl_enums::GL_DOUBLE_MAT4x2)     | [`dmat4x2`](crate::gl_enums::dmat4x2)              |
-    /// | [`GL_DOUBLE_MAT4x3`](crate::gl_enums::GL_DOUBLE_MAT4x3)     | [`dmat4x3`](crate::gl_enums::dmat4x3)              |
-    /// | [`GL_SAMPLER_1D`](crate::gl_enums::GL_SAMPLER_1D)           | [`sampler1D`](crate::gl_enums::sampler1D)          |
-    /// | [`GL_SAMPLER_2D`](crate::gl_enums::GL_SAMPLER_2D)           | [`sampler2D`](crate::gl_enums::sampler2D)          |
-    /// | [`GL_SAMPLER_3D`](crate::gl_enums::GL_SAMPLER_3D)           | [`sampler3D`](crate::gl_enums::sampler3D)          |
-    /// | [`GL_SAMPLER_CUBE`](crate::gl_enums::GL_SAMPLER_CUBE)       | [`samplerCube`](crate::gl_enums::samplerCube)      |
-    /// | [`GL_SAMPLER_1D_SHADOW`](crate::gl_enums::GL_SAMPLER_1D_SHADOW) | [`sampler1DShadow`](crate::gl_enums::sampler1DShadow) |
-    /// | [`GL_SAMPLER_2D_SHADOW`](crate::gl_enums::GL_SAMPLER_2D_SHADOW) | [`sampler2DShadow`](crate::gl_enums::sampler2DShadow) |
-    /// | [`GL_SAMPLER_1D_ARRAY`](crate::gl_enums::GL_SAMPLER_1D_ARRAY) | [`sampler1DArray`](crate::gl_enums::sampler1DArray) |
-    /// | [`GL_SAMPLER_2D_ARRAY`](crate::gl_enums::GL_SAMPLER_2D_ARRAY) | [`sampler2DArray`](crate::gl_enums::sampler2DArray) |
-    /// | [`GL_SAMPLER_1D_ARRAY_SHADOW`](crate::gl_enums::GL_SAMPLER_1D_ARRAY_SHADOW) | [`sampler1DArrayShadow`](crate::gl_enums::sampler1DArrayShadow) |
-    /// | [`GL_SAMPLER_2D_ARRAY_SHADOW`](crate::gl_enums::GL_SAMPLER_2D_ARRAY_SHADOW) | [`sampler2DArrayShadow`](crate::gl_enums::sampler2DArrayShadow) |
-    /// | [`GL_SAMPLER_2D_MULTISAMPLE`](crate::gl_enums::GL_SAMPLER_2D_MULTISAMPLE) | [`sampler2DMS`](crate::gl_enums::sampler2DMS) |
-    /// | [`GL_SAMPLER_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_SAMPLER_2D_MULTISAMPLE_ARRAY) | [`sampler2DMSArray`](crate::gl_enums::sampler2DMSArray) |
-    /// | [`GL_SAMPLER_CUBE_SHADOW`](crate::gl_enums::GL_SAMPLER_CUBE_SHADOW) | [`samplerCubeShadow`](crate::gl_enums::samplerCubeShadow) |
-    /// | [`GL_SAMPLER_BUFFER`](crate::gl_enums::GL_SAMPLER_BUFFER)   | [`samplerBuffer`](crate::gl_enums::samplerBuffer)  |
-    /// | [`GL_SAMPLER_2D_RECT`](crate::gl_enums::GL_SAMPLER_2D_RECT) | [`sampler2DRect`](crate::gl_enums::sampler2DRect)  |
-    /// | [`GL_SAMPLER_2D_RECT_SHADOW`](crate::gl_enums::GL_SAMPLER_2D_RECT_SHADOW) | [`sampler2DRectShadow`](crate::gl_enums::sampler2DRectShadow) |
-    /// | [`GL_INT_SAMPLER_1D`](crate::gl_enums::GL_INT_SAMPLER_1D)   | [`isampler1D`](crate::gl_enums::isampler1D)        |
-    /// | [`GL_INT_SAMPLER_2D`](crate::gl_enums::GL_INT_SAMPLER_2D)   | [`isampler2D`](crate::gl_enums::isampler2D)        |
-    /// | [`GL_INT_SAMPLER_3D`](crate::gl_enums::GL_INT_SAMPLER_3D)   | [`isampler3D`](crate::gl_enums::isampler3D)        |
-    /// | [`GL_INT_SAMPLER_CUBE`](crate::gl_enums::GL_INT_SAMPLER_CUBE) | [`isamplerCube`](crate::gl_enums::isamplerCube)  |
-    /// | [`GL_INT_SAMPLER_1D_ARRAY`](crate::gl_enums::GL_INT_SAMPLER_1D_ARRAY) | [`isampler1DArray`](crate::gl_enums::isampler1DArray) |
-    /// | [`GL_INT_SAMPLER_2D_ARRAY`](crate::gl_enums::GL_INT_SAMPLER_2D_ARRAY) | [`isampler2DArray`](crate::gl_enums::isampler2DArray) |
-    /// | [`GL_INT_SAMPLER_2D_MULTISAMPLE`](crate::gl_enums::GL_INT_SAMPLER_2D_MULTISAMPLE) | [`isampler2DMS`](crate::gl_enums::isampler2DMS) |
-    /// | [`GL_INT_SAMPLER_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_INT_SAMPLER_2D_MULTISAMPLE_ARRAY) | [`isampler2DMSArray`](crate::gl_enums::isampler2DMSArray) |
-    /// | [`GL_INT_SAMPLER_BUFFER`](crate::gl_enums::GL_INT_SAMPLER_BUFFER) | [`isamplerBuffer`](crate::gl_enums::isamplerBuffer) |
-    /// | [`GL_INT_SAMPLER_2D_RECT`](crate::gl_enums::GL_INT_SAMPLER_2D_RECT) | [`isampler2DRect`](crate::gl_enums::isampler2DRect) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_1D`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_1D) | [`usampler1D`](crate::gl_enums::usampler1D) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_2D`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D) | [`usampler2D`](crate::gl_enums::usampler2D) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_3D`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_3D) | [`usampler3D`](crate::gl_enums::usampler3D) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_CUBE`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_CUBE) | [`usamplerCube`](crate::gl_enums::usamplerCube) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_1D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_1D_ARRAY) | [`usampler2DArray`](crate::gl_enums::usampler2DArray) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_ARRAY) | [`usampler2DArray`](crate::gl_enums::usampler2DArray) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE) | [`usampler2DMS`](crate::gl_enums::usampler2DMS) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY) | [`usampler2DMSArray`](crate::gl_enums::usampler2DMSArray) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_BUFFER`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_BUFFER) | [`usamplerBuffer`](crate::gl_enums::usamplerBuffer) |
-    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_RECT`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_RECT) | [`usampler2DRect`](crate::gl_enums::usampler2DRect) |
+    /// If any of the pixels within the specified rectangle of the current [`GL_READ_BUFFER`](crate::gl_enums::GL_READ_BUFFER)
+    /// are outside the window associated with the current rendering context, then
+    /// the values obtained for those pixels are undefined.
     ///
+    /// When `internalformat` is one of the sRGB types, the GL does not automatically
+    /// convert the source pixels to the sRGB color space. In this case, the [**glPixelMap**](crate::context::Context::oxidegl_pixel_map)
+    /// function can be used to accomplish the conversion.
     ///
-    /// If `pname` is [`GL_UNIFORM_SIZE`](crate::gl_enums::GL_UNIFORM_SIZE), then
-    /// an array identifying the size of the uniforms specified by the corresponding
-    /// array of `uniformIndices` is returned. The sizes returned are in units
-    /// of the type returned by a query of [`GL_UNIFORM_TYPE`](crate::gl_enums::GL_UNIFORM_TYPE).
-    /// For active uniforms that are arrays, the size is the number of active
-    /// elements in the array; for all other uniforms, the size is one.
+    /// ### Notes
+    /// 1, 2, 3, and 4 are not accepted values for `internalformat`.
     ///
-    /// If `pname` is [`GL_UNIFORM_NAME_LENGTH`](crate::gl_enums::GL_UNIFORM_NAME_LENGTH),
-    /// then an array identifying the length, including the terminating null character,
-    /// of the uniform name strings specified by the corresponding array of `uniformIndices`
-    /// is returned.
+    /// An image with height or width of 0 indicates a NULL texture.
     ///
-    /// If `pname` is [`GL_UNIFORM_BLOCK_INDEX`](crate::gl_enums::GL_UNIFORM_BLOCK_INDEX),
-    /// then an array identifying the uniform block index of each of the uniforms
-    /// specified by the corresponding array of `uniformIndices` is returned. The
-    /// uniform block index of a uniform associated with the default uniform block
-    /// is -1.
+    /// [`GL_STENCIL_INDEX8`](crate::gl_enums::GL_STENCIL_INDEX8) is accepted for
+    /// `internalformat` only if the GL version is 4.4 or higher.
     ///
-    /// If `pname` is [`GL_UNIFORM_OFFSET`](crate::gl_enums::GL_UNIFORM_OFFSET),
-    /// then an array of uniform buffer offsets is returned. For uniforms in a
-    /// named uniform block, the returned value will be its offset, in basic machine
-    /// units, relative to the beginning of the uniform block in the buffer object
-    /// data store. For atomic counter uniforms, the returned value will be its
-    /// offset relative to the beginning of its active atomic counter buffer. For
-    /// all other uniforms, -1 will be returned.
+    /// ### Associated Gets
+    /// [**glGetTexImage**](crate::context::Context::oxidegl_get_tex_image)
+    pub fn oxidegl_copy_tex_image2_d(
+        &mut self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        border: GLint,
+    ) -> GlFallible {
+        panic!("command oxidegl_copy_tex_image2_d not yet implemented");
+    }
+    /// ### Parameters
+    /// `n`
     ///
-    /// If `pname` is [`GL_UNIFORM_ARRAY_STRIDE`](crate::gl_enums::GL_UNIFORM_ARRAY_STRIDE),
-    /// then an array identifying the stride between elements of each of the uniforms
-    /// specified by the corresponding array of `uniformIndices` is returned. For
-    /// uniforms in named uniform blocks and for uniforms declared as atomic counters,
-    /// the stride is the difference, in basic machine units, of consecutive elements
-    /// in an array, or zero for uniforms not declared as an array. For all other
-    /// uniforms, a stride of -1 will be returned.
+    /// > Number of framebuffer objects to create.
     ///
-    /// If `pname` is [`GL_UNIFORM_MATRIX_STRIDE`](crate::gl_enums::GL_UNIFORM_MATRIX_STRIDE),
-    /// then an array identifying the stride between columns of a column-major
-    /// matrix or rows of a row-major matrix, in basic machine units, of each of
-    /// the uniforms specified by the corresponding array of `uniformIndices` is
-    /// returned. The matrix stride of a uniform associated with the default uniform
-    /// block is -1. Note that this information only makes sense for uniforms that
-    /// are matrices. For uniforms that are not matrices, but are declared in a
-    /// named uniform block, a matrix stride of zero is returned.
+    /// `framebuffers`
     ///
-    /// If `pname` is [`GL_UNIFORM_IS_ROW_MAJOR`](crate::gl_enums::GL_UNIFORM_IS_ROW_MAJOR),
-    /// then an array identifying whether each of the uniforms specified by the
-    /// corresponding array of `uniformIndices` is a row-major matrix or not is
-    /// returned. A value of one indicates a row-major matrix, and a value of zero
-    /// indicates a column-major matrix, a matrix in the default uniform block,
-    /// or a non-matrix.
+    /// > Specifies an array in which names of the new framebuffer objects are stored.
     ///
-    /// If `pname` is [`GL_UNIFORM_ATOMIC_COUNTER_BUFFER_INDEX`](crate::gl_enums::GL_UNIFORM_ATOMIC_COUNTER_BUFFER_INDEX),
-    /// then an array identifying the active atomic counter buffer index of each
-    /// of the uniforms specified by the corresponding array of `uniformIndices`
-    /// is returned. For uniforms other than atomic counters, the returned buffer
-    /// index is -1. The returned indices may be passed to [**glGetActiveAtomicCounterBufferiv**](crate::context::Context::oxidegl_get_active_atomic_counter_bufferiv)
-    /// to query the properties of the associated buffer, and not necessarily the
-    /// binding point specified in the uniform declaration.
+    /// ### Description
+    /// [**glCreateFramebuffers**](crate::context::Context::oxidegl_create_framebuffers)
+    /// returns `n` previously unused framebuffer names in `framebuffers`, each
+    /// representing a new framebuffer object initialized to the default state.
+    pub unsafe fn oxidegl_create_framebuffers(
+        &mut self,
+        n: GLsizei,
+        framebuffers: *mut GLuint,
+    ) -> GlFallible {
+        panic!("command oxidegl_create_framebuffers not yet implemented");
+    }
+    /// ### Parameters
+    /// `n`
     ///
-    /// ### Notes
-    /// The double types, [`GL_DOUBLE`](crate::gl_enums::GL_DOUBLE), [`GL_DOUBLE_VEC2`](crate::gl_enums::GL_DOUBLE_VEC2),
-    /// [`GL_DOUBLE_VEC3`](crate::gl_enums::GL_DOUBLE_VEC3), [`GL_DOUBLE_VEC4`](crate::gl_enums::GL_DOUBLE_VEC4),
-    /// [`GL_DOUBLE_MAT2`](crate::gl_enums::GL_DOUBLE_MAT2), [`GL_DOUBLE_MAT3`](crate::gl_enums::GL_DOUBLE_MAT3),
-    /// [`GL_DOUBLE_MAT4`](crate::gl_enums::GL_DOUBLE_MAT4), [`GL_DOUBLE_MAT2x3`](crate::gl_enums::GL_DOUBLE_MAT2x3),
-    /// [`GL_DOUBLE_MAT2x4`](crate::gl_enums::GL_DOUBLE_MAT2x4), [`GL_DOUBLE_MAT3x2`](crate::gl_enums::GL_DOUBLE_MAT3x2),
-    /// [`GL_DOUBLE_MAT3x4`](crate::gl_enums::GL_DOUBLE_MAT3x4), [`GL_DOUBLE_MAT4x2`](crate::gl_enums::GL_DOUBLE_MAT4x2),
-    /// and [`GL_DOUBLE_MAT4x3`](crate::gl_enums::GL_DOUBLE_MAT4x3) are only available
-    /// if the GL version is 4.1 or higher.
+    /// > Number of renderbuffer objects to create.
     ///
-    /// [`GL_UNIFORM_ATOMIC_COUNTER_BUFFER_INDEX`](crate::gl_enums::GL_UNIFORM_ATOMIC_COUNTER_BUFFER_INDEX)
-    /// is only accepted by `pname` if the GL version is 4.2 or higher.
+    /// `renderbuffers`
     ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_VERTEX_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_VERTEX_UNIFORM_COMPONENTS),
-    /// [`GL_MAX_TESS_CONTROL_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_TESS_CONTROL_UNIFORM_COMPONENTS),
-    /// [`GL_MAX_TESS_EVALUATION_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_TESS_EVALUATION_UNIFORM_COMPONENTS),
-    /// [`GL_MAX_GEOMETRY_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_GEOMETRY_UNIFORM_COMPONENTS),
-    /// [`GL_MAX_FRAGMENT_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_FRAGMENT_UNIFORM_COMPONENTS),
-    /// or [`GL_MAX_COMBINED_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_COMBINED_UNIFORM_COMPONENTS).
+    /// > Specifies an array in which names of the new renderbuffer objects are stored.
     ///
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
-    /// [`GL_ACTIVE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_UNIFORMS) or [`GL_ACTIVE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_UNIFORM_MAX_LENGTH).
+    /// ### Description
+    /// [**glCreateRenderbuffers**](crate::context::Context::oxidegl_create_renderbuffers)
+    /// returns `n` previously unused renderbuffer object names in `renderbuffers`,
+    /// each representing a new renderbuffer object initialized to the default
+    /// state.
+    pub unsafe fn oxidegl_create_renderbuffers(
+        &mut self,
+        n: GLsizei,
+        renderbuffers: *mut GLuint,
+    ) -> GlFallible {
+        panic!("command oxidegl_create_renderbuffers not yet implemented");
+    }
+    /// ### Parameters
+    /// `n`
+    ///
+    /// > Number of sampler objects to create.
+    ///
+    /// `samplers`
+    ///
+    /// > Specifies an array in which names of the new sampler objects are stored.
     ///
-    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
-    pub unsafe fn oxidegl_get_active_uniformsiv(
+    /// ### Description
+    /// [**glCreateSamplers**](crate::context::Context::oxidegl_create_samplers)
+    /// returns `n` previously unused sampler names in `samplers`, each representing
+    /// a new sampler object initialized to the default state.
+    pub unsafe fn oxidegl_create_samplers(
         &mut self,
-        program: GLuint,
-        uniform_count: GLsizei,
-        uniform_indices: *const GLuint,
-        pname: UniformPName,
-        params: *mut GLint,
+        n: GLsizei,
+        samplers: *mut GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_get_active_uniformsiv not yet implemented");
+        panic!("command oxidegl_create_samplers not yet implemented");
     }
     /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the program object to be queried.
+    /// `mode`
     ///
-    /// `maxCount`
+    /// > Specifies whether front- or back-facing facets are candidates for culling.
+    /// > Symbolic constants [`GL_FRONT`](crate::gl_enums::GL_FRONT), [`GL_BACK`](crate::gl_enums::GL_BACK),
+    /// > and [`GL_FRONT_AND_BACK`](crate::gl_enums::GL_FRONT_AND_BACK) are accepted.
+    /// > The initial value is [`GL_BACK`](crate::gl_enums::GL_BACK).
     ///
-    /// > Specifies the size of the array for storing the returned object names.
+    /// ### Description
+    /// [**glCullFace**](crate::context::Context::oxidegl_cull_face) specifies
+    /// whether front- or back-facing facets are culled (as specified by *mode*)
+    /// when facet culling is enabled. Facet culling is initially disabled. To
+    /// enable and disable facet culling, call the [**glEnable**](crate::context::Context::oxidegl_enable)
+    /// and [**glDisable**](crate::context::Context::oxidegl_disable) commands
+    /// with the argument [`GL_CULL_FACE`](crate::gl_enums::GL_CULL_FACE). Facets
+    /// include triangles, quadrilaterals, polygons, and rectangles.
     ///
-    /// `count`
+    /// [**glFrontFace**](crate::context::Context::oxidegl_front_face) specifies
+    /// which of the clockwise and counterclockwise facets are front-facing and
+    /// back-facing. See [**glFrontFace**](crate::context::Context::oxidegl_front_face).
     ///
-    /// > Returns the number of names actually returned in `shaders`.
+    /// ### Notes
+    /// If `mode` is [`GL_FRONT_AND_BACK`](crate::gl_enums::GL_FRONT_AND_BACK),
+    /// no facets are drawn, but other primitives such as points and lines are
+    /// drawn.
     ///
-    /// `shaders`
+    /// ### Associated Gets
+    /// [**glIsEnabled**](crate::context::Context::oxidegl_is_enabled) with argument
+    /// [`GL_CULL_FACE`](crate::gl_enums::GL_CULL_FACE)
     ///
-    /// > Specifies an array that is used to return the names of attached shader
-    /// > objects.
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_CULL_FACE_MODE`](crate::gl_enums::GL_CULL_FACE_MODE)
+    pub fn oxidegl_cull_face(&mut self, mode: TriangleFace) -> GlFallible {
+        panic!("command oxidegl_cull_face not yet implemented");
+    }
+    /// ### Parameters
+    /// `n`
     ///
-    /// ### Description
-    /// [**glGetAttachedShaders**](crate::context::Context::oxidegl_get_attached_shaders)
-    /// returns the names of the shader objects attached to `program`. The names
-    /// of shader objects that are attached to `program` will be returned in `shaders.`
-    /// The actual number of shader names written into `shaders` is returned in
-    /// `count.` If no shader objects are attached to `program`, `count` is set
-    /// to 0. The maximum number of shader names that may be returned in `shaders`
-    /// is specified by `maxCount`.
+    /// > Specifies the number of framebuffer objects to be deleted.
     ///
-    /// If the number of names actually returned is not required (for instance,
-    /// if it has just been obtained by calling [**glGetProgram**](crate::context::Context::oxidegl_get_program)
-    /// ), a value of [`NULL`](crate::gl_enums::NULL) may be passed for count.
-    /// If no shader objects are attached to `program`, a value of 0 will be returned
-    /// in `count`. The actual number of attached shaders can be obtained by calling
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with the
-    /// value [`GL_ATTACHED_SHADERS`](crate::gl_enums::GL_ATTACHED_SHADERS).
+    /// `framebuffers`
     ///
-    /// ### Associated Gets
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
-    /// [`GL_ATTACHED_SHADERS`](crate::gl_enums::GL_ATTACHED_SHADERS)
+    /// > A pointer to an array containing `n` framebuffer objects to be deleted.
     ///
-    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
-    pub unsafe fn oxidegl_get_attached_shaders(
+    /// ### Description
+    /// [**glDeleteFramebuffers**](crate::context::Context::oxidegl_delete_framebuffers)
+    /// deletes the `n` framebuffer objects whose names are stored in the array
+    /// addressed by `framebuffers`. The name zero is reserved by the GL and is
+    /// silently ignored, should it occur in `framebuffers`, as are other unused
+    /// names. Once a framebuffer object is deleted, its name is again unused and
+    /// it has no attachments. If a framebuffer that is currently bound to one
+    /// or more of the targets [`GL_DRAW_FRAMEBUFFER`](crate::gl_enums::GL_DRAW_FRAMEBUFFER)
+    /// or [`GL_READ_FRAMEBUFFER`](crate::gl_enums::GL_READ_FRAMEBUFFER) is deleted,
+    /// it is as though [**glBindFramebuffer**](crate::context::Context::oxidegl_bind_framebuffer)
+    /// had been executed with the corresponding `target` and `framebuffer` zero.
+    pub unsafe fn oxidegl_delete_framebuffers(
         &mut self,
-        program: GLuint,
-        max_count: GLsizei,
-        count: *mut GLsizei,
-        shaders: *mut GLuint,
+        n: GLsizei,
+        framebuffers: *const GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_get_attached_shaders not yet implemented");
+        panic!("command oxidegl_delete_framebuffers not yet implemented");
     }
     /// ### Parameters
     /// `program`
     ///
-    /// > Specifies the program object to be queried.
+    /// > Specifies the program object to be deleted.
     ///
-    /// `name`
+    /// ### Description
+    /// [**glDeleteProgram**](crate::context::Context::oxidegl_delete_program)
+    /// frees the memory and invalidates the name associated with the program object
+    /// specified by `program.` This command effectively undoes the effects of
+    /// a call to [**glCreateProgram**](crate::context::Context::oxidegl_create_program).
     ///
-    /// > Points to a null terminated string containing the name of the attribute
-    /// > variable whose location is to be queried.
+    /// If a program object is in use as part of current rendering state, it will
+    /// be flagged for deletion, but it will not be deleted until it is no longer
+    /// part of current state for any rendering context. If a program object to
+    /// be deleted has shader objects attached to it, those shader objects will
+    /// be automatically detached but not deleted unless they have already been
+    /// flagged for deletion by a previous call to [**glDeleteShader**](crate::context::Context::oxidegl_delete_shader).
+    /// A value of 0 for `program` will be silently ignored.
     ///
-    /// ### Description
-    /// [**glGetAttribLocation**](crate::context::Context::oxidegl_get_attrib_location)
-    /// queries the previously linked program object specified by `program` for
-    /// the attribute variable specified by `name` and returns the index of the
-    /// generic vertex attribute that is bound to that attribute variable. If `name`
-    /// is a matrix attribute variable, the index of the first column of the matrix
-    /// is returned. If the named attribute variable is not an active attribute
-    /// in the specified program object or if `name` starts with the reserved prefix
-    /// "gl_", a value of -1 is returned.
-    ///
-    /// The association between an attribute variable name and a generic attribute
-    /// index can be specified at any time by calling [**glBindAttribLocation**](crate::context::Context::oxidegl_bind_attrib_location).
-    /// Attribute bindings do not go into effect until [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// is called. After a program object has been linked successfully, the index
-    /// values for attribute variables remain fixed until the next link command
-    /// occurs. The attribute values can only be queried after a link if the link
-    /// was successful. [**glGetAttribLocation**](crate::context::Context::oxidegl_get_attrib_location)
-    /// returns the binding that actually went into effect the last time [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// was called for the specified program object. Attribute bindings that have
-    /// been specified since the last link operation are not returned by [**glGetAttribLocation**](crate::context::Context::oxidegl_get_attrib_location).
+    /// To determine whether a program object has been flagged for deletion, call
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with arguments
+    /// `program` and [`GL_DELETE_STATUS`](crate::gl_enums::GL_DELETE_STATUS).
     ///
     /// ### Associated Gets
-    /// [**glGetActiveAttrib**](crate::context::Context::oxidegl_get_active_attrib)
-    /// with argument `program` and the index of an active attribute
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_CURRENT_PROGRAM`](crate::gl_enums::GL_CURRENT_PROGRAM)
+    ///
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with arguments
+    /// `program` and [`GL_DELETE_STATUS`](crate::gl_enums::GL_DELETE_STATUS)
     ///
     /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
-    pub unsafe fn oxidegl_get_attrib_location(
-        &mut self,
-        program: GLuint,
-        name: *const GLchar,
-    ) -> GlFallible<GLint> {
-        panic!("command oxidegl_get_attrib_location not yet implemented");
+    pub fn oxidegl_delete_program(&mut self, program: GLuint) -> GlFallible {
+        panic!("command oxidegl_delete_program not yet implemented");
     }
     /// ### Parameters
-    /// `texture`
+    /// `n`
     ///
-    /// > Specifies the name of the source texture object. Must be [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D),
-    /// > [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D),
-    /// > [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D),
-    /// > [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP), [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY)
-    /// > or [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE). In
-    /// > specific, buffer and multisample textures are not permitted.
+    /// > Specifies the number of renderbuffer objects to be deleted.
     ///
-    /// `level`
+    /// `renderbuffers`
     ///
-    /// > Specifies the level-of-detail number. Level 0 is the base image level.
-    /// > Level $n$ is the $n$th mipmap reduction image.
+    /// > A pointer to an array containing `n` renderbuffer objects to be deleted.
     ///
-    /// `xoffset`
+    /// ### Description
+    /// [**glDeleteRenderbuffers**](crate::context::Context::oxidegl_delete_renderbuffers)
+    /// deletes the `n` renderbuffer objects whose names are stored in the array
+    /// addressed by `renderbuffers`. The name zero is reserved by the GL and is
+    /// silently ignored, should it occur in `renderbuffers`, as are other unused
+    /// names. Once a renderbuffer object is deleted, its name is again unused
+    /// and it has no contents. If a renderbuffer that is currently bound to the
+    /// target [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER) is deleted,
+    /// it is as though [**glBindRenderbuffer**](crate::context::Context::oxidegl_bind_renderbuffer)
+    /// had been executed with a `target` of [`GL_RENDERBUFFER`](crate::gl_enums::GL_RENDERBUFFER)
+    /// and a `name` of zero.
     ///
-    /// > Specifies a texel offset in the x direction within the texture array.
+    /// If a renderbuffer object is attached to one or more attachment points in
+    /// the currently bound framebuffer, then it as if [**glFramebufferRenderbuffer**](crate::context::Context::oxidegl_framebuffer_renderbuffer)
+    /// had been called, with a `renderbuffer` of zero for each attachment point
+    /// to which this image was attached in the currently bound framebuffer. In
+    /// other words, this renderbuffer object is first detached from all attachment
+    /// ponits in the currently bound framebuffer. Note that the renderbuffer image
+    /// is specifically *not* detached from any non-bound framebuffers.
+    pub unsafe fn oxidegl_delete_renderbuffers(
+        &mut self,
+        n: GLsizei,
+        renderbuffers: *const GLuint,
+    ) -> GlFallible {
+        panic!("command oxidegl_delete_renderbuffers not yet implemented");
+    }
+    /// ### Parameters
+    /// `n`
     ///
-    /// `yoffset`
+    /// > Specifies the number of sampler objects to be deleted.
     ///
-    /// > Specifies a texel offset in the y direction within the texture array.
+    /// `samplers`
     ///
-    /// `zoffset`
+    /// > Specifies an array of sampler objects to be deleted.
     ///
-    /// > Specifies a texel offset in the z direction within the texture array.
+    /// ### Description
+    /// [**glDeleteSamplers**](crate::context::Context::oxidegl_delete_samplers)
+    /// deletes `n` sampler objects named by the elements of the array `samplers`.
+    /// After a sampler object is deleted, its name is again unused. If a sampler
+    /// object that is currently bound to a sampler unit is deleted, it is as though
+    /// [**glBindSampler**](crate::context::Context::oxidegl_bind_sampler) is called
+    /// with unit set to the unit the sampler is bound to and sampler zero. Unused
+    /// names in samplers are silently ignored, as is the reserved name zero.
     ///
-    /// `width`
+    /// ### Notes
+    /// [**glDeleteSamplers**](crate::context::Context::oxidegl_delete_samplers)
+    /// is available only if the GL version is 3.3 or higher.
     ///
-    /// > Specifies the width of the texture subimage. Must be a multiple of the
-    /// > compressed block's width, unless the `offset` is zero and the size equals
-    /// > the texture image size.
+    /// ### Associated Gets
+    /// [**glIsSampler**](crate::context::Context::oxidegl_is_sampler)
+    pub unsafe fn oxidegl_delete_samplers(
+        &mut self,
+        count: GLsizei,
+        samplers: *const GLuint,
+    ) -> GlFallible {
+        panic!("command oxidegl_delete_samplers not yet implemented");
+    }
+    /// ### Parameters
+    /// `sync`
     ///
-    /// `height`
+    /// > The sync object to be deleted.
     ///
-    /// > Specifies the height of the texture subimage. Must be a multiple of the
-    /// > compressed block's height, unless the `offset` is zero and the size equals
-    /// > the texture image size.
+    /// ### Description
+    /// [**glDeleteSync**](crate::context::Context::oxidegl_delete_sync) deletes
+    /// the sync object specified by `sync`. If the fence command corresponding
+    /// to the specified sync object has completed, or if no [**glWaitSync**](crate::context::Context::oxidegl_wait_sync)
+    /// or [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
+    /// commands are blocking on `sync`, the object is deleted immediately. Otherwise,
+    /// `sync` is flagged for deletion and will be deleted when it is no longer
+    /// associated with any fence command and is no longer blocking any [**glWaitSync**](crate::context::Context::oxidegl_wait_sync)
+    /// or [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
+    /// command. In either case, after [**glDeleteSync**](crate::context::Context::oxidegl_delete_sync)
+    /// returns, the name `sync` is invalid and can no longer be used to refer
+    /// to the sync object.
     ///
-    /// `depth`
+    /// [**glDeleteSync**](crate::context::Context::oxidegl_delete_sync) will silently
+    /// ignore a `sync` value of zero.
     ///
-    /// > Specifies the depth of the texture subimage. Must be a multiple of the
-    /// > compressed block's depth, unless the `offset` is zero and the size equals
-    /// > the texture image size.
+    /// ### Notes
+    /// [**glSync**](crate::context::Context::oxidegl_sync) is only supported if
+    /// the GL version is 3.2 or greater, or if the
+    pub fn oxidegl_delete_sync(&mut self, sync: GLsync) -> GlFallible {
+        panic!("command oxidegl_delete_sync not yet implemented");
+    }
+    /// ### Parameters
+    /// `func`
     ///
-    /// `bufSize`
+    /// > Specifies the depth comparison function. Symbolic constants [`GL_NEVER`](crate::gl_enums::GL_NEVER),
+    /// > [`GL_LESS`](crate::gl_enums::GL_LESS), [`GL_EQUAL`](crate::gl_enums::GL_EQUAL),
+    /// > [`GL_LEQUAL`](crate::gl_enums::GL_LEQUAL), [`GL_GREATER`](crate::gl_enums::GL_GREATER),
+    /// > [`GL_NOTEQUAL`](crate::gl_enums::GL_NOTEQUAL), [`GL_GEQUAL`](crate::gl_enums::GL_GEQUAL),
+    /// > and [`GL_ALWAYS`](crate::gl_enums::GL_ALWAYS) are accepted. The initial
+    /// > value is [`GL_LESS`](crate::gl_enums::GL_LESS).
     ///
-    /// > Specifies the size of the buffer to receive the retrieved pixel data.
+    /// ### Description
+    /// [**glDepthFunc**](crate::context::Context::oxidegl_depth_func) specifies
+    /// the function used to compare each incoming pixel depth value with the depth
+    /// value present in the depth buffer. The comparison is performed only if
+    /// depth testing is enabled. (See [**glEnable**](crate::context::Context::oxidegl_enable)
+    /// and [**glDisable**](crate::context::Context::oxidegl_disable) of [`GL_DEPTH_TEST`](crate::gl_enums::GL_DEPTH_TEST)
+    /// .)
+    ///
+    /// `func` specifies the conditions under which the pixel will be drawn. The
+    /// comparison functions are as follows:
+    ///
+    /// [`GL_NEVER`](crate::gl_enums::GL_NEVER)
+    ///
+    /// > Never passes.
     ///
-    /// `pixels`
+    /// [`GL_LESS`](crate::gl_enums::GL_LESS)
     ///
-    /// > Returns the texture subimage. Should be a pointer to an array of the type
-    /// > specified by type.
+    /// > Passes if the incoming depth value is less than the stored depth value.
     ///
-    /// ### Description
-    /// [**glGetCompressedTextureSubImage**](crate::context::Context::oxidegl_get_compressed_texture_sub_image)
-    /// can be used to obtain a sub-region of a compressed texture image instead
-    /// of the whole image, as long as the compressed data are arranged into fixed-size
-    /// blocks of texels. `texture` is the name of the texture object, and must
-    /// not be a buffer or multisample texture. The effective `target` is the value
-    /// of [`GL_TEXTURE_TARGET`](crate::gl_enums::GL_TEXTURE_TARGET) for texture.
-    /// `level` and `pixels` have the same meaning as the corresponding arguments
-    /// of [**glCompressedTexSubImage3D**](crate::context::Context::oxidegl_compressed_tex_sub_image3_d).
-    /// `bufSize` indicates the size of the buffer to receive the retrieved pixel
-    /// data.
+    /// [`GL_EQUAL`](crate::gl_enums::GL_EQUAL)
     ///
-    /// For cube map textures, the behavior is as though [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
-    /// were called once for each requested face (selected by `zoffset` and `depth`,
-    /// as described below) with target corresponding to the requested texture
-    /// cube map face as indicated by the table presented below. `pixels` is offset
-    /// appropriately for each successive image.
+    /// > Passes if the incoming depth value is equal to the stored depth value.
     ///
-    /// | Layer number        | Cube Map Face        |
-    /// |---------------------|----------------------|
-    /// | 0                   | [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X) |
-    /// | 1                   | [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X) |
-    /// | 2                   | [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y) |
-    /// | 3                   | [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y) |
-    /// | 4                   | [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z) |
-    /// | 5                   | [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z) |
+    /// [`GL_LEQUAL`](crate::gl_enums::GL_LEQUAL)
     ///
-    /// `xoffset`, `yoffset` and `zoffset` indicate the position of the subregion
-    /// to return. `width`, `height` and `depth` indicate the size of the region
-    /// to return. These arguments have the same meaning as for [**glCompressedTexSubImage3D**](crate::context::Context::oxidegl_compressed_tex_sub_image3_d),
-    /// though there are extra restrictions, described in the errors section below.
+    /// > Passes if the incoming depth value is less than or equal to the stored
+    /// > depth value.
     ///
-    /// The mapping between the `xoffset`, `yoffset`, `zoffset`, `width`, `height`
-    /// and `depth` parameters and the faces, layers, and layer-faces for cube
-    /// map, array, and cube map array textures is the same as for glGetTextureSubImage.
+    /// [`GL_GREATER`](crate::gl_enums::GL_GREATER)
     ///
-    /// The `xoffset`, `yoffset`, `zoffset` offsets and `width`, `height` and `depth`
-    /// sizes must be multiples of the values of [`GL_PACK_COMPRESSED_BLOCK_WIDTH`](crate::gl_enums::GL_PACK_COMPRESSED_BLOCK_WIDTH),
-    /// [`GL_PACK_COMPRESSED_BLOCK_HEIGHT`](crate::gl_enums::GL_PACK_COMPRESSED_BLOCK_HEIGHT),
-    /// and [`GL_PACK_COMPRESSED_BLOCK_DEPTH`](crate::gl_enums::GL_PACK_COMPRESSED_BLOCK_DEPTH)
-    /// respectively, unless `offset` is zero and the corresponding `size` is the
-    /// same as the texture size in that dimension.
+    /// > Passes if the incoming depth value is greater than the stored depth value.
     ///
-    /// Pixel storage modes are treated as for [**glGetCompressedTexSubImage**](crate::context::Context::oxidegl_get_compressed_tex_sub_image).
-    /// The texel at( `xoffset`, `yoffset`, `zoffset`) will be stored at the location
-    /// indicated by `pixels` and the current pixel packing parameters.
-    pub unsafe fn oxidegl_get_compressed_texture_sub_image(
-        &mut self,
-        texture: GLuint,
-        level: GLint,
-        xoffset: GLint,
-        yoffset: GLint,
-        zoffset: GLint,
-        width: GLsizei,
-        height: GLsizei,
-        depth: GLsizei,
-        buf_size: GLsizei,
-        pixels: *mut GLvoid,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_compressed_texture_sub_image not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
+    /// [`GL_NOTEQUAL`](crate::gl_enums::GL_NOTEQUAL)
     ///
-    /// > The name of the program containing varying out variable whose binding to
-    /// > query
+    /// > Passes if the incoming depth value is not equal to the stored depth value.
     ///
-    /// `name`
+    /// [`GL_GEQUAL`](crate::gl_enums::GL_GEQUAL)
     ///
-    /// > The name of the user-defined varying out variable whose index to query
+    /// > Passes if the incoming depth value is greater than or equal to the stored
+    /// > depth value.
     ///
-    /// ### Description
-    /// [**glGetFragDataIndex**](crate::context::Context::oxidegl_get_frag_data_index)
-    /// returns the index of the fragment color to which the variable `name` was
-    /// bound when the program object `program` was last linked. If `name` is not
-    /// a varying out variable of `program`, or if an error occurs, -1 will be
-    /// returned.
+    /// [`GL_ALWAYS`](crate::gl_enums::GL_ALWAYS)
     ///
-    /// ### Notes
-    /// [**glGetFragDataIndex**](crate::context::Context::oxidegl_get_frag_data_index)
-    /// is available only if the GL version is 3.3 or greater.
-    pub unsafe fn oxidegl_get_frag_data_index(
-        &mut self,
-        program: GLuint,
-        name: *const GLchar,
-    ) -> GlFallible<GLint> {
-        panic!("command oxidegl_get_frag_data_index not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
+    /// > Always passes.
     ///
-    /// > The name of the program containing varying out variable whose binding to
-    /// > query
+    /// The initial value of `func` is [`GL_LESS`](crate::gl_enums::GL_LESS). Initially,
+    /// depth testing is disabled. If depth testing is disabled or if no depth
+    /// buffer exists, it is as if the depth test always passes.
     ///
-    /// `name`
+    /// ### Notes
+    /// Even if the depth buffer exists and the depth mask is non-zero, the depth
+    /// buffer is not updated if the depth test is disabled. In order to unconditionally
+    /// write to the depth buffer, the depth test should be enabled and set to
+    /// [`GL_ALWAYS`](crate::gl_enums::GL_ALWAYS).
     ///
-    /// > The name of the user-defined varying out variable whose binding to query
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_DEPTH_FUNC`](crate::gl_enums::GL_DEPTH_FUNC)
     ///
-    /// ### Description
-    /// [**glGetFragDataLocation**](crate::context::Context::oxidegl_get_frag_data_location)
-    /// retrieves the assigned color number binding for the user-defined varying
-    /// out variable `name` for program `program`. `program` must have previously
-    /// been linked. `name` must be a null-terminated string. If `name` is not
-    /// the name of an active user-defined varying out fragment shader variable
-    /// within `program`, -1 will be returned.
-    pub unsafe fn oxidegl_get_frag_data_location(
-        &mut self,
-        program: GLuint,
-        name: *const GLchar,
-    ) -> GlFallible<GLint> {
-        panic!("command oxidegl_get_frag_data_location not yet implemented");
+    /// [**glIsEnabled**](crate::context::Context::oxidegl_is_enabled) with argument
+    /// [`GL_DEPTH_TEST`](crate::gl_enums::GL_DEPTH_TEST)
+    pub fn oxidegl_depth_func(&mut self, func: DepthFunction) -> GlFallible {
+        panic!("command oxidegl_depth_func not yet implemented");
     }
-    /// ### Description
-    /// Certain events can result in a reset of the GL context. Such a reset causes
-    /// all context state to be lost and requires the application to recreate all
-    /// objects in the affected context.
-    ///
-    /// [**glGetGraphicsResetStatus**](crate::context::Context::oxidegl_get_graphics_reset_status)
-    /// can return one of the following constants:
+    /// ### Parameters
+    /// `flag`
     ///
-    /// [`GL_NO_ERROR`](crate::gl_enums::GL_NO_ERROR)
+    /// > Specifies whether the depth buffer is enabled for writing. If `flag` is
+    /// > [`GL_FALSE`](crate::gl_enums::GL_FALSE), depth buffer writing is disabled.
+    /// > Otherwise, it is enabled. Initially, depth buffer writing is enabled.
     ///
-    /// > Indicates that the GL context has not been in a reset state since the last
-    /// > call.
+    /// ### Description
+    /// [**glDepthMask**](crate::context::Context::oxidegl_depth_mask) specifies
+    /// whether the depth buffer is enabled for writing. If `flag` is [`GL_FALSE`](crate::gl_enums::GL_FALSE),
+    /// depth buffer writing is disabled. Otherwise, it is enabled. Initially,
+    /// depth buffer writing is enabled.
     ///
-    /// [`GL_GUILTY_CONTEXT_RESET`](crate::gl_enums::GL_GUILTY_CONTEXT_RESET)
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_DEPTH_WRITEMASK`](crate::gl_enums::GL_DEPTH_WRITEMASK)
     ///
-    /// > Indicates that a reset has been detected that is attributable to the current
-    /// > GL context.
+    /// ### Notes
+    /// Even if the depth buffer exists and the depth mask is non-zero, the depth
+    /// buffer is not updated if the depth test is disabled. In order to unconditionally
+    /// write to the depth buffer, the depth test should be enabled and set to
+    /// [`GL_ALWAYS`](crate::gl_enums::GL_ALWAYS) (see [**glDepthFunc**](crate::context::Context::oxidegl_depth_func)
+    /// ).
+    pub fn oxidegl_depth_mask(&mut self, flag: GLboolean) -> GlFallible {
+        panic!("command oxidegl_depth_mask not yet implemented");
+    }
+    /// ### Parameters
+    /// `condition`
     ///
-    /// [`GL_INNOCENT_CONTEXT_RESET`](crate::gl_enums::GL_INNOCENT_CONTEXT_RESET)
+    /// > Specifies the condition that must be met to set the sync object's state
+    /// > to signaled. `condition` must be [`GL_SYNC_GPU_COMMANDS_COMPLETE`](crate::gl_enums::GL_SYNC_GPU_COMMANDS_COMPLETE).
     ///
-    /// > Indicates a reset has been detected that is not attributable to the current
-    /// > GL context.
+    /// `flags`
     ///
-    /// [`GL_UNKNOWN_CONTEXT_RESET`](crate::gl_enums::GL_UNKNOWN_CONTEXT_RESET)
+    /// > Specifies a bitwise combination of flags controlling the behavior of the
+    /// > sync object. No flags are presently defined for this operation and `flags`
+    /// > must be zero. `flags` is a placeholder for anticipated future extensions
+    /// > of fence sync object capabilities.
     ///
-    /// > Indicates a detected graphics reset whose cause is unknown.
     ///
-    /// If a reset status other than [`GL_NO_ERROR`](crate::gl_enums::GL_NO_ERROR)
-    /// is returned and subsequent calls return [`GL_NO_ERROR`](crate::gl_enums::GL_NO_ERROR),
-    /// the context reset was encountered and completed. If a reset status is
-    /// repeatedly returned, the context may be in the process of resetting.
+    /// ### Description
+    /// [**glFenceSync**](crate::context::Context::oxidegl_fence_sync) creates
+    /// a new fence sync object, inserts a fence command into the GL command stream
+    /// and associates it with that sync object, and returns a non-zero name corresponding
+    /// to the sync object.
     ///
-    /// Reset notification behavior is determined at context creation time, and
-    /// may be queried by calling [**`GetIntegerv`**](crate::context::Context::oxide_get_integerv)
-    /// with the symbolic constant [`GL_RESET_NOTIFICATION_STRATEGY`](crate::gl_enums::GL_RESET_NOTIFICATION_STRATEGY).
+    /// When the specified `condition` of the sync object is satisfied by the fence
+    /// command, the sync object is signaled by the GL, causing any [**glWaitSync**](crate::context::Context::oxidegl_wait_sync),
+    /// [**glClientWaitSync**](crate::context::Context::oxidegl_client_wait_sync)
+    /// commands blocking in `sync` to *unblock*. No other state is affected by
+    /// [**glFenceSync**](crate::context::Context::oxidegl_fence_sync) or by the
+    /// execution of the associated fence command.
     ///
-    /// If the reset notification behavior is [`GL_NO_RESET_NOTIFICATION`](crate::gl_enums::GL_NO_RESET_NOTIFICATION),
-    /// then the implementation will never deliver notification of reset events,
-    /// and [**glGetGraphicsResetStatus**](crate::context::Context::oxidegl_get_graphics_reset_status)
-    /// will always return [`GL_NO_ERROR`](crate::gl_enums::GL_NO_ERROR).
+    /// `condition` must be [`GL_SYNC_GPU_COMMANDS_COMPLETE`](crate::gl_enums::GL_SYNC_GPU_COMMANDS_COMPLETE).
+    /// This condition is satisfied by completion of the fence command corresponding
+    /// to the sync object and all preceding commands in the same command stream.
+    /// The sync object will not be signaled until all effects from these commands
+    /// on GL client and server state and the framebuffer are fully realized. Note
+    /// that completion of the fence command occurs once the state of the corresponding
+    /// sync object has been changed, but commands waiting on that sync object
+    /// may not be unblocked until after the fence command completes.
     ///
-    /// If the behavior is [`GL_LOSE_CONTEXT_ON_RESET`](crate::gl_enums::GL_LOSE_CONTEXT_ON_RESET),
-    /// a graphics reset will result in the loss of all context state, requiring
-    /// the recreation of all associated objects. In this case [**glGetGraphicsResetStatus**](crate::context::Context::oxidegl_get_graphics_reset_status)
-    /// may return any of the values described above.
+    /// ### Notes
+    /// [**glFenceSync**](crate::context::Context::oxidegl_fence_sync) is only
+    /// supported if the GL version is 3.2 or greater, or if the
+    pub fn oxidegl_fence_sync(
+        &mut self,
+        condition: GLenum,
+        flags: GLbitfield,
+    ) -> GlFallible<GLsync> {
+        panic!("command oxidegl_fence_sync not yet implemented");
+    }
+    /// ### Description
+    /// [**glFinish**](crate::context::Context::oxidegl_finish) does not return
+    /// until the effects of all previously called GL commands are complete. Such
+    /// effects include all changes to GL state, all changes to connection state,
+    /// and all changes to the frame buffer contents.
     ///
-    /// If a graphics reset notification occurs in a context, a notification must
-    /// also occur in all other contexts which share objects with that context.
+    /// ### Notes
+    /// [**glFinish**](crate::context::Context::oxidegl_finish) requires a round
+    /// trip to the server.
+    pub fn oxidegl_finish(&mut self) -> GlFallible {
+        panic!("command oxidegl_finish not yet implemented");
+    }
+    /// ### Description
+    /// Different GL implementations buffer commands in several different locations,
+    /// including network buffers and the graphics accelerator itself. [**glFlush**](crate::context::Context::oxidegl_flush)
+    /// empties all of these buffers, causing all issued commands to be executed
+    /// as quickly as they are accepted by the actual rendering engine. Though
+    /// this execution may not be completed in any particular time period, it does
+    /// complete in finite time.
     ///
-    /// After a graphics reset has occurred on a context, subsequent GL commands
-    /// on that context (or any context which shares with that context) will generate
-    /// a [`GL_CONTEXT_LOST`](crate::gl_enums::GL_CONTEXT_LOST) error. Such commands
-    /// will not have side effects (in particular, they will not modify memory
-    /// passed by pointer for query results), and will not block indefinitely or
-    /// cause termination of the application. There are two important exceptions
-    /// to this behavior:
+    /// Because any GL program might be executed over a network, or on an accelerator
+    /// that buffers commands, all programs should call [**glFlush**](crate::context::Context::oxidegl_flush)
+    /// whenever they count on having all of their previously issued commands completed.
+    /// For example, call [**glFlush**](crate::context::Context::oxidegl_flush)
+    /// before waiting for user input that depends on the generated image.
     ///
-    /// > [**glGetError**](crate::context::Context::oxidegl_get_error) and [**glGetGraphicsResetStatus**](crate::context::Context::oxidegl_get_graphics_reset_status)
-    /// > behave normally following a graphics reset, so that the application can
-    /// > determine a reset has occurred, and when it is safe to destroy and re-create
-    /// > the context.
+    /// ### Notes
+    /// [**glFlush**](crate::context::Context::oxidegl_flush) can return at any
+    /// time. It does not wait until the execution of all previously issued GL
+    /// commands is complete.
+    pub fn oxidegl_flush(&mut self) -> GlFallible {
+        panic!("command oxidegl_flush not yet implemented");
+    }
+    /// ### Parameters
+    /// `mode`
     ///
-    /// > Any commands which might cause a polling application to block indefinitely
-    /// > will generate a [`GL_CONTEXT_LOST`](crate::gl_enums::GL_CONTEXT_LOST) error,
-    /// > but will also return a value indicating completion to the application.
-    /// > Such commands include: [**glGetSynciv**](crate::context::Context::oxidegl_get_synciv)
-    /// >> with pname [`GL_SYNC_STATUS`](crate::gl_enums::GL_SYNC_STATUS) ignores
-    /// >> the other parameters and returns [`GL_SIGNALED`](crate::gl_enums::GL_SIGNALED)
-    /// >> in `values`.
+    /// > Specifies the orientation of front-facing polygons. [`GL_CW`](crate::gl_enums::GL_CW)
+    /// > and [`GL_CCW`](crate::gl_enums::GL_CCW) are accepted. The initial value
+    /// > is [`GL_CCW`](crate::gl_enums::GL_CCW).
     ///
-    /// >> [**glGetQueryObjectuiv**](crate::context::Context::oxidegl_get_query_objectuiv)
-    /// >> with pname [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE)
-    /// >> ignores the other parameters and returns [`TRUE`](crate::gl_enums::TRUE)
-    /// >> in `params`.
+    /// ### Description
+    /// In a scene composed entirely of opaque closed surfaces, back-facing polygons
+    /// are never visible. Eliminating these invisible polygons has the obvious
+    /// benefit of speeding up the rendering of the image. To enable and disable
+    /// elimination of back-facing polygons, call [**glEnable**](crate::context::Context::oxidegl_enable)
+    /// and [**glDisable**](crate::context::Context::oxidegl_disable) with argument
+    /// [`GL_CULL_FACE`](crate::gl_enums::GL_CULL_FACE).
     ///
+    /// The projection of a polygon to window coordinates is said to have clockwise
+    /// winding if an imaginary object following the path from its first vertex,
+    /// its second vertex, and so on, to its last vertex, and finally back to its
+    /// first vertex, moves in a clockwise direction about the interior of the
+    /// polygon. The polygon's winding is said to be counterclockwise if the imaginary
+    /// object following the same path moves in a counterclockwise direction about
+    /// the interior of the polygon. [**glFrontFace**](crate::context::Context::oxidegl_front_face)
+    /// specifies whether polygons with clockwise winding in window coordinates,
+    /// or counterclockwise winding in window coordinates, are taken to be front-facing.
+    /// Passing [`GL_CCW`](crate::gl_enums::GL_CCW) to `mode` selects counterclockwise
+    /// polygons as front-facing; [`GL_CW`](crate::gl_enums::GL_CW) selects clockwise
+    /// polygons as front-facing. By default, counterclockwise polygons are taken
+    /// to be front-facing.
     ///
-    pub fn oxidegl_get_graphics_reset_status(&mut self) -> GlFallible<GLenum> {
-        panic!("command oxidegl_get_graphics_reset_status not yet implemented");
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_FRONT_FACE`](crate::gl_enums::GL_FRONT_FACE)
+    pub fn oxidegl_front_face(&mut self, mode: FrontFaceDirection) -> GlFallible {
+        panic!("command oxidegl_front_face not yet implemented");
     }
     /// ### Parameters
-    /// `pname`
-    ///
-    /// > Specifies the sample parameter name. `pname` must be [`GL_SAMPLE_POSITION`](crate::gl_enums::GL_SAMPLE_POSITION).
-    ///
-    /// `index`
+    /// `n`
     ///
-    /// > Specifies the index of the sample whose position to query.
+    /// > Specifies the number of framebuffer object names to generate.
     ///
-    /// `val`
+    /// `ids`
     ///
-    /// > Specifies the address of an array to receive the position of the sample.
+    /// > Specifies an array in which the generated framebuffer object names are
+    /// > stored.
     ///
     /// ### Description
-    /// [**glGetMultisamplefv**](crate::context::Context::oxidegl_get_multisamplefv)
-    /// queries the location of a given sample. `pname` specifies the sample parameter
-    /// to retrieve and must be [`GL_SAMPLE_POSITION`](crate::gl_enums::GL_SAMPLE_POSITION).
-    /// `index` corresponds to the sample for which the location should be returned.
-    /// The sample location is returned as two floating-point values in `val[0]`
-    /// and `val[1]`, each between 0 and 1, corresponding to the `x` and `y` locations
-    /// respectively in the GL pixel space of that sample. (0.5, 0.5) this corresponds
-    /// to the pixel center. `index` must be between zero and the value of [`GL_SAMPLES`](crate::gl_enums::GL_SAMPLES)
-    /// minus one.
+    /// [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers)
+    /// returns `n` framebuffer object names in `ids`. There is no guarantee that
+    /// the names form a contiguous set of integers; however, it is guaranteed
+    /// that none of the returned names was in use immediately before the call
+    /// to [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers).
     ///
-    /// If the multisample mode does not have fixed sample locations, the returned
-    /// values may only reflect the locations of samples within some pixels.
-    pub unsafe fn oxidegl_get_multisamplefv(
+    /// Framebuffer object names returned by a call to [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers)
+    /// are not returned by subsequent calls, unless they are first deleted with
+    /// [**glDeleteFramebuffers**](crate::context::Context::oxidegl_delete_framebuffers).
+    ///
+    /// The names returned in `ids` are marked as used, for the purposes of [**glGenFramebuffers**](crate::context::Context::oxidegl_gen_framebuffers)
+    /// only, but they acquire state and type only when they are first bound.
+    pub unsafe fn oxidegl_gen_framebuffers(
         &mut self,
-        pname: GLenum,
-        index: GLuint,
-        val: *mut GLfloat,
+        n: GLsizei,
+        framebuffers: *mut GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_get_multisamplefv not yet implemented");
+        panic!("command oxidegl_gen_framebuffers not yet implemented");
     }
     /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the name of a program object whose binary representation to retrieve.
-    ///
-    /// `bufSize`
-    ///
-    /// > Specifies the size of the buffer whose address is given by `binary`.
-    ///
-    /// `length`
-    ///
-    /// > Specifies the address of a variable to receive the number of bytes written
-    /// > into `binary`.
-    ///
-    /// `binaryFormat`
+    /// `n`
     ///
-    /// > Specifies the address of a variable to receive a token indicating the format
-    /// > of the binary data returned by the GL.
+    /// > Specifies the number of renderbuffer object names to generate.
     ///
-    /// `binary`
+    /// `renderbuffers`
     ///
-    /// > Specifies the address an array into which the GL will return `program`
-    /// > 's binary representation.
+    /// > Specifies an array in which the generated renderbuffer object names are
+    /// > stored.
     ///
     /// ### Description
-    /// [**glGetProgramBinary**](crate::context::Context::oxidegl_get_program_binary)
-    /// returns a binary representation of the compiled and linked executable for
-    /// `program` into the array of bytes whose address is specified in `binary`.
-    /// The maximum number of bytes that may be written into `binary` is specified
-    /// by `bufSize`. If the program binary is greater in size than `bufSize` bytes,
-    /// then an error is generated, otherwise the actual number of bytes written
-    /// into `binary` is returned in the variable whose address is given by `length`.
-    /// If `length` is [`NULL`](crate::gl_enums::NULL), then no length is returned.
-    ///
-    /// The format of the program binary written into `binary` is returned in the
-    /// variable whose address is given by `binaryFormat`, and may be implementation
-    /// dependent. The binary produced by the GL may subsequently be returned to
-    /// the GL by calling [**glProgramBinary**](crate::context::Context::oxidegl_program_binary),
-    /// with `binaryFormat` and `length` set to the values returned by [**glGetProgramBinary**](crate::context::Context::oxidegl_get_program_binary),
-    /// and passing the returned binary data in the `binary` parameter.
+    /// [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers)
+    /// returns `n` renderbuffer object names in `renderbuffers`. There is no guarantee
+    /// that the names form a contiguous set of integers; however, it is guaranteed
+    /// that none of the returned names was in use immediately before the call
+    /// to [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers).
     ///
-    /// ### Associated Gets
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
-    /// [`GL_PROGRAM_BINARY_LENGTH`](crate::gl_enums::GL_PROGRAM_BINARY_LENGTH)
-    pub unsafe fn oxidegl_get_program_binary(
+    /// Renderbuffer object names returned by a call to [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers)
+    /// are not returned by subsequent calls, unless they are first deleted with
+    /// [**glDeleteRenderbuffers**](crate::context::Context::oxidegl_delete_renderbuffers).
+    ///
+    /// The names returned in `renderbuffers` are marked as used, for the purposes
+    /// of [**glGenRenderbuffers**](crate::context::Context::oxidegl_gen_renderbuffers)
+    /// only, but they acquire state and type only when they are first bound.
+    pub unsafe fn oxidegl_gen_renderbuffers(
         &mut self,
-        program: GLuint,
-        buf_size: GLsizei,
-        length: *mut GLsizei,
-        binary_format: *mut GLenum,
-        binary: *mut GLvoid,
+        n: GLsizei,
+        renderbuffers: *mut GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_get_program_binary not yet implemented");
+        panic!("command oxidegl_gen_renderbuffers not yet implemented");
     }
     /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the program object whose information log is to be queried.
-    ///
-    /// `maxLength`
-    ///
-    /// > Specifies the size of the character buffer for storing the returned information
-    /// > log.
-    ///
-    /// `length`
+    /// `n`
     ///
-    /// > Returns the length of the string returned in `infoLog` (excluding the null
-    /// > terminator).
+    /// > Specifies the number of sampler object names to generate.
     ///
-    /// `infoLog`
+    /// `samplers`
     ///
-    /// > Specifies an array of characters that is used to return the information
-    /// > log.
+    /// > Specifies an array in which the generated sampler object names are stored.
     ///
     /// ### Description
-    /// [**glGetProgramInfoLog**](crate::context::Context::oxidegl_get_program_info_log)
-    /// returns the information log for the specified program object. The information
-    /// log for a program object is modified when the program object is linked
-    /// or validated. The string that is returned will be null terminated.
-    ///
-    /// [**glGetProgramInfoLog**](crate::context::Context::oxidegl_get_program_info_log)
-    /// returns in `infoLog` as much of the information log as it can, up to a
-    /// maximum of `maxLength` characters. The number of characters actually returned,
-    /// excluding the null termination character, is specified by `length`. If
-    /// the length of the returned string is not required, a value of [`NULL`](crate::gl_enums::NULL)
-    /// can be passed in the `length` argument. The size of the buffer required
-    /// to store the returned information log can be obtained by calling [**glGetProgram**](crate::context::Context::oxidegl_get_program)
-    /// with the value [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH).
-    ///
-    /// The information log for a program object is either an empty string, or
-    /// a string containing information about the last link operation, or a string
-    /// containing information about the last validation operation. It may contain
-    /// diagnostic messages, warning messages, and other information. When a program
-    /// object is created, its information log will be a string of length 0.
+    /// [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers) returns
+    /// `n` sampler object names in `samplers`. There is no guarantee that the
+    /// names form a contiguous set of integers; however, it is guaranteed that
+    /// none of the returned names was in use immediately before the call to [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers).
     ///
-    /// ### Notes
-    /// The information log for a program object is the OpenGL implementer's primary
-    /// mechanism for conveying information about linking and validating. Therefore,
-    /// the information log can be helpful to application developers during the
-    /// development process, even when these operations are successful. Application
-    /// developers should not expect different OpenGL implementations to produce
-    /// identical information logs.
+    /// Sampler object names returned by a call to [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers)
+    /// are not returned by subsequent calls, unless they are first deleted with
+    /// [**glDeleteSamplers**](crate::context::Context::oxidegl_delete_samplers).
     ///
-    /// ### Associated Gets
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
-    /// [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH)
+    /// The names returned in `samplers` are marked as used, for the purposes of
+    /// [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers) only,
+    /// but they acquire state and type only when they are first bound.
     ///
-    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
-    pub unsafe fn oxidegl_get_program_info_log(
+    /// ### Notes
+    /// [**glGenSamplers**](crate::context::Context::oxidegl_gen_samplers) is available
+    /// only if the GL version is 3.3 or higher.
+    pub unsafe fn oxidegl_gen_samplers(
         &mut self,
-        program: GLuint,
-        buf_size: GLsizei,
-        length: *mut GLsizei,
-        info_log: *mut GLchar,
+        count: GLsizei,
+        samplers: *mut GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_get_program_info_log not yet implemented");
+        panic!("command oxidegl_gen_samplers not yet implemented");
     }
     /// ### Parameters
     /// `program`
     ///
-    /// > The name of a program object whose interface to query.
+    /// > Specifies the name of a program containing the uniform block.
     ///
-    /// `programInterface`
+    /// `uniformBlockIndex`
     ///
-    /// > A token identifying the interface within `program` to query.
+    /// > Specifies the index of the uniform block within `program`.
     ///
     /// `pname`
     ///
-    /// > The name of the parameter within `programInterface` to query.
+    /// > Specifies the name of the parameter to query.
     ///
     /// `params`
     ///
-    /// > The address of a variable to retrieve the value of `pname` for the program
-    /// > interface.
+    /// > Specifies the address of a variable to receive the result of the query.
     ///
     /// ### Description
-    /// [**glGetProgramInterfaceiv**](crate::context::Context::oxidegl_get_program_interfaceiv)
-    /// queries the property of the interface identifed by `programInterface` in
-    /// `program`, the property name of which is given by `pname`.
-    ///
-    /// `program` must be the name of an existing program object. `programInterface`
-    /// is the name of the interface within `program` to query and must be one
-    /// of the following values:
-    ///
-    /// [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM)
-    ///
-    /// > The query is targeted at the set of active uniforms within `program`.
-    ///
-    /// [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK)
-    ///
-    /// > The query is targeted at the set of active uniform blocks within `program`.
-    ///
-    /// [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER)
-    ///
-    /// > The query is targeted at the set of active atomic counter buffer binding
-    /// > points within `program`.
-    ///
-    /// [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT)
-    ///
-    /// > The query is targeted at the set of active input variables used by the
-    /// > first shader stage of `program`. If `program` contains multiple shader
-    /// > stages then input variables from any stage other than the first will not
-    /// > be enumerated.
-    ///
-    /// [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT)
-    ///
-    /// > The query is targeted at the set of active output variables produced by
-    /// > the last shader stage of `program`. If `program` contains multiple shader
-    /// > stages then output variables from any stage other than the last will not
-    /// > be enumerated.
-    ///
-    /// [`GL_VERTEX_SUBROUTINE`](crate::gl_enums::GL_VERTEX_SUBROUTINE)
-    ///
-    /// [`GL_TESS_CONTROL_SUBROUTINE`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE)
-    ///
-    /// [`GL_TESS_EVALUATION_SUBROUTINE`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE)
-    ///
-    /// [`GL_GEOMETRY_SUBROUTINE`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE)
-    ///
-    /// [`GL_FRAGMENT_SUBROUTINE`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE)
-    ///
-    /// [`GL_COMPUTE_SUBROUTINE`](crate::gl_enums::GL_COMPUTE_SUBROUTINE)
-    ///
-    /// > The query is targeted at the set of active subroutines for the vertex,
-    /// > tessellation control, tessellation evaluation, geometry, fragment and compute
-    /// > shader stages of `program`, respectively.
-    ///
-    /// [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM)
-    ///
-    /// [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM)
-    ///
-    /// [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM)
-    ///
-    /// [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM)
-    ///
-    /// [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM)
-    ///
-    /// [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM)
-    ///
-    /// > The query is targeted at the set of active subroutine uniform variables
-    /// > used by the vertex, tessellation control, tessellation evaluation, geometry,
-    /// > fragment and compute shader stages of `program`, respectively.
-    ///
-    /// [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING)
-    ///
-    /// > The query is targeted at the set of output variables from the last non-fragment
-    /// > stage of `program` that would be captured if transform feedback were active.
-    ///
-    /// [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE)
+    /// [**glGetActiveUniformBlockiv**](crate::context::Context::oxidegl_get_active_uniform_blockiv)
+    /// retrieves information about an active uniform block within `program`.
     ///
-    /// > The query is targeted at the set of active buffer variables used by `program`.
+    /// `program` must be the name of a program object for which the command [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// must have been called in the past, although it is not required that [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// must have succeeded. The link could have failed because the number of active
+    /// uniforms exceeded the limit.
     ///
-    /// [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK)
+    /// `uniformBlockIndex` is an active uniform block index of `program`, and
+    /// must be less than the value of [`GL_ACTIVE_UNIFORM_BLOCKS`](crate::gl_enums::GL_ACTIVE_UNIFORM_BLOCKS).
     ///
-    /// > The query is targeted at the set of active shader storage blocks used by
-    /// > `program`.
+    /// Upon success, the uniform block parameter(s) specified by `pname` are returned
+    /// in `params`. If an error occurs, nothing will be written to `params`.
     ///
-    /// [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER)
+    /// If `pname` is [`GL_UNIFORM_BLOCK_BINDING`](crate::gl_enums::GL_UNIFORM_BLOCK_BINDING),
+    /// then the index of the uniform buffer binding point last selected by the
+    /// uniform block specified by `uniformBlockIndex` for `program` is returned.
+    /// If no uniform block has been previously specified, zero is returned.
     ///
-    /// > The query is targeted at the set of active buffer binding points to which
-    /// > output variables in the [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING)
-    /// > interface are written.
+    /// If `pname` is [`GL_UNIFORM_BLOCK_DATA_SIZE`](crate::gl_enums::GL_UNIFORM_BLOCK_DATA_SIZE),
+    /// then the implementation-dependent minimum total buffer object size, in
+    /// basic machine units, required to hold all active uniforms in the uniform
+    /// block identified by `uniformBlockIndex` is returned. It is neither guaranteed
+    /// nor expected that a given implementation will arrange uniform values as
+    /// tightly packed in a buffer object. The exception to this is the *std140
+    /// uniform block layout*, which guarantees specific packing behavior and does
+    /// not require the application to query for offsets and strides. In this case
+    /// the minimum size may still be queried, even though it is determined in
+    /// advance based only on the uniform block declaration.
     ///
-    /// `pname` identifies the property of `programInterface` to return in `params`.
+    /// If `pname` is [`GL_UNIFORM_BLOCK_NAME_LENGTH`](crate::gl_enums::GL_UNIFORM_BLOCK_NAME_LENGTH),
+    /// then the total length (including the nul terminator) of the name of the
+    /// uniform block identified by `uniformBlockIndex` is returned.
     ///
-    /// If `pname` is [`GL_ACTIVE_RESOURCES`](crate::gl_enums::GL_ACTIVE_RESOURCES),
-    /// the value returned is the number of resources in the active resource list
-    /// for `programInterface`. If the list of active resources for `programInterface`
-    /// is empty, zero is returned.
+    /// If `pname` is [`GL_UNIFORM_BLOCK_ACTIVE_UNIFORMS`](crate::gl_enums::GL_UNIFORM_BLOCK_ACTIVE_UNIFORMS),
+    /// then the number of active uniforms in the uniform block identified by
+    /// `uniformBlockIndex` is returned.
     ///
-    /// If `pname` is [`GL_MAX_NAME_LENGTH`](crate::gl_enums::GL_MAX_NAME_LENGTH),
-    /// the value returned is the length of the longest active name string for
-    /// an active resource in `programInterface`. This length includes an extra
-    /// character for the null terminator. If the list of active resources for
-    /// `programInterface` is empty, zero is returned. It is an error to specify
-    /// [`GL_MAX_NAME_LENGTH`](crate::gl_enums::GL_MAX_NAME_LENGTH) when `programInterface`
-    /// is [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER),
-    /// as active atomic counter buffer resources are not assigned name strings.
+    /// If `pname` is [`GL_UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES`](crate::gl_enums::GL_UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES),
+    /// then a list of the active uniform indices for the uniform block identified
+    /// by `uniformBlockIndex` is returned. The number of elements that will be
+    /// written to `params` is the value of [`GL_UNIFORM_BLOCK_ACTIVE_UNIFORMS`](crate::gl_enums::GL_UNIFORM_BLOCK_ACTIVE_UNIFORMS)
+    /// for `uniformBlockIndex`.
     ///
-    /// If `pname` is [`GL_MAX_NUM_ACTIVE_VARIABLES`](crate::gl_enums::GL_MAX_NUM_ACTIVE_VARIABLES),
-    /// the value returned is the number of active variables belonging to the
-    /// interface block or atomic counter buffer resource in `programInterface`
-    /// with the most active variables. If the list of active resources for `programInterface`
-    /// is empty, zero is returned. When `pname` is [`GL_MAX_NUM_ACTIVE_VARIABLES`](crate::gl_enums::GL_MAX_NUM_ACTIVE_VARIABLES),
-    /// `programInterface` must be [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK),
-    /// [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER),
-    /// or [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK).
+    /// If `pname` is [`GL_UNIFORM_BLOCK_REFERENCED_BY_VERTEX_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_VERTEX_SHADER),
+    /// [`GL_UNIFORM_BLOCK_REFERENCED_BY_TESS_CONTROL_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_TESS_CONTROL_SHADER),
+    /// [`GL_UNIFORM_BLOCK_REFERENCED_BY_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_TESS_EVALUATION_SHADER),
+    /// [`GL_UNIFORM_BLOCK_REFERENCED_BY_GEOMETRY_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_GEOMETRY_SHADER),
+    /// [`GL_UNIFORM_BLOCK_REFERENCED_BY_FRAGMENT_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_FRAGMENT_SHADER),
+    /// or [`GL_UNIFORM_BLOCK_REFERENCED_BY_COMPUTE_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_COMPUTE_SHADER)
+    /// then a boolean value indicating whether the uniform block identified by
+    /// `uniformBlockIndex` is referenced by the vertex, tessellation control,
+    /// tessellation evaluation, geometry, fragment or compute programming stages
+    /// of program, respectively, is returned.
     ///
-    /// If `pname` is [`GL_MAX_NUM_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_MAX_NUM_COMPATIBLE_SUBROUTINES),
-    /// the value returned is the number of compatible subroutines belonging to
-    /// the active subroutine uniform in `programInterface` with the most compatible
-    /// subroutines. If the list of active resources for `programInterface` is
-    /// empty, zero is returned. When `pname` is [`GL_MAX_NUM_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_MAX_NUM_COMPATIBLE_SUBROUTINES),
-    /// `programInterface` must be one of [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM),
-    /// [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM),
-    /// [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM),
-    /// [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM),
-    /// [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM),
-    /// or [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM).
+    /// ### Notes
+    /// [**glGetActiveUniformBlockiv**](crate::context::Context::oxidegl_get_active_uniform_blockiv)
+    /// is available only if the GL version is 3.1 or greater.
     ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_LABEL_LENGTH`](crate::gl_enums::GL_MAX_LABEL_LENGTH).
-    pub unsafe fn oxidegl_get_program_interfaceiv(
+    /// [`GL_UNIFORM_BLOCK_REFERENCED_BY_COMPUTE_SHADER`](crate::gl_enums::GL_UNIFORM_BLOCK_REFERENCED_BY_COMPUTE_SHADER)
+    /// is accepted only if the GL version is 4.3 or greater.
+    pub unsafe fn oxidegl_get_active_uniform_blockiv(
         &mut self,
         program: GLuint,
-        program_interface: ProgramInterface,
-        pname: ProgramInterfacePName,
-        params: *mut GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_program_interfaceiv not yet implemented");
-    }
-    /// ### Parameters
-    /// `pipeline`
-    ///
-    /// > Specifies the name of a program pipeline object whose parameter retrieve.
-    ///
-    /// `pname`
-    ///
-    /// > Specifies the name of the parameter to retrieve.
-    ///
-    /// `params`
-    ///
-    /// > Specifies the address of a variable into which will be written the value
-    /// > or values of `pname` for `pipeline`.
-    ///
-    /// ### Description
-    /// [**glGetProgramPipelineiv**](crate::context::Context::oxidegl_get_program_pipelineiv)
-    /// retrieves the value of a property of the program pipeline object `pipeline`.
-    /// `pname` specifies the name of the parameter whose value to retrieve. The
-    /// value of the parameter is written to the variable whose address is given
-    /// by `params`.
-    ///
-    /// If `pname` is [`GL_ACTIVE_PROGRAM`](crate::gl_enums::GL_ACTIVE_PROGRAM),
-    /// the name of the active program object of the program pipeline object is
-    /// returned in `params`.
-    ///
-    /// If `pname` is [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER), the
-    /// name of the current program object for the vertex shader type of the program
-    /// pipeline object is returned in `params`.
-    ///
-    /// If `pname` is [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER),
-    /// the name of the current program object for the tessellation control shader
-    /// type of the program pipeline object is returned in `params`.
-    ///
-    /// If `pname` is [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
-    /// the name of the current program object for the tessellation evaluation
-    /// shader type of the program pipeline object is returned in `params`.
-    ///
-    /// If `pname` is [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER),
-    /// the name of the current program object for the geometry shader type of
-    /// the program pipeline object is returned in `params`.
-    ///
-    /// If `pname` is [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER),
-    /// the name of the current program object for the fragment shader type of
-    /// the program pipeline object is returned in `params`.
-    ///
-    /// If `pname` is [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH),
-    /// the length of the info log, including the null terminator, is returned
-    /// in `params`. If there is no info log, zero is returned.
-    pub unsafe fn oxidegl_get_program_pipelineiv(
-        &mut self,
-        pipeline: GLuint,
-        pname: PipelineParameterName,
+        uniform_block_index: GLuint,
+        pname: UniformBlockPName,
         params: *mut GLint,
     ) -> GlFallible {
-        panic!("command oxidegl_get_program_pipelineiv not yet implemented");
-    }
-    /// ### Parameters
-    /// `pipeline`
-    ///
-    /// > Specifies the name of a program pipeline object from which to retrieve
-    /// > the info log.
-    ///
-    /// `bufSize`
-    ///
-    /// > Specifies the maximum number of characters, including the null terminator,
-    /// > that may be written into `infoLog`.
-    ///
-    /// `length`
-    ///
-    /// > Specifies the address of a variable into which will be written the number
-    /// > of characters written into `infoLog`.
-    ///
-    /// `infoLog`
-    ///
-    /// > Specifies the address of an array of characters into which will be written
-    /// > the info log for `pipeline`.
-    ///
-    /// ### Description
-    /// [**glGetProgramPipelineInfoLog**](crate::context::Context::oxidegl_get_program_pipeline_info_log)
-    /// retrieves the info log for the program pipeline object `pipeline`. The
-    /// info log, including its null terminator, is written into the array of characters
-    /// whose address is given by `infoLog`. The maximum number of characters that
-    /// may be written into `infoLog` is given by `bufSize`, and the actual number
-    /// of characters written into `infoLog` is returned in the integer whose address
-    /// is given by `length`. If `length` is [`NULL`](crate::gl_enums::NULL), no
-    /// length is returned.
-    ///
-    /// The actual length of the info log for the program pipeline may be determined
-    /// by calling [**glGetProgramPipeline**](crate::context::Context::oxidegl_get_program_pipeline)
-    /// with `pname` set to [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH).
-    ///
-    /// ### Associated Gets
-    /// [**glGetProgramPipeline**](crate::context::Context::oxidegl_get_program_pipeline)
-    /// with parameter [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH).
-    pub unsafe fn oxidegl_get_program_pipeline_info_log(
-        &mut self,
-        pipeline: GLuint,
-        buf_size: GLsizei,
-        length: *mut GLsizei,
-        info_log: *mut GLchar,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_program_pipeline_info_log not yet implemented");
+        panic!("command oxidegl_get_active_uniform_blockiv not yet implemented");
     }
     /// ### Parameters
     /// `program`
     ///
-    /// > The name of a program object whose resources to query.
+    /// > Specifies the name of a program containing the uniform block.
     ///
-    /// `programInterface`
+    /// `uniformBlockIndex`
     ///
-    /// > A token identifying the interface within `program` containing the resource
-    /// > named `name`.
+    /// > Specifies the index of the uniform block within `program`.
     ///
-    /// ### Description
-    /// [**glGetProgramResourceiv**](crate::context::Context::oxidegl_get_program_resourceiv)
-    /// returns values for multiple properties of a single active resource with
-    /// an index of `index` in the interface `programInterface` of program object
-    /// `program`. For each resource, values for `propCount` properties specified
-    /// by the array `props` are returned. `propCount` may not be zero. An error
-    /// is generated if any value in `props` is not one of the properties described
-    /// immediately belowor if any value in `props` is not allowed for `programInterface`.
-    /// The set of allowed `programInterface` values for each property can be
-    /// found in the following table:
-    ///
-    /// | Property      | Supported Interfaces                              |
-    /// |---------------|---------------------------------------------------|
-    /// | [`GL_NAME_LENGTH`](crate::gl_enums::GL_NAME_LENGTH) | Any except [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER) and [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER) |
-    /// | [`GL_TYPE`](crate::gl_enums::GL_TYPE) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT), [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
-    /// | [`GL_ARRAY_SIZE`](crate::gl_enums::GL_ARRAY_SIZE) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT, VERTEX_SUBROUTINE_UNIFORM`](`crate::gl_enums::GL_PROGRAM_OUTPUT`, `VERTEX_SUBROUTINE_UNIFORM`), [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM), [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM), [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM), [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM), [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM), [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING) |
-    /// | [`GL_OFFSET`](crate::gl_enums::GL_OFFSET) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING) |
-    /// | [`GL_BLOCK_INDEX`](crate::gl_enums::GL_BLOCK_INDEX) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
-    /// | [`GL_ARRAY_STRIDE`](crate::gl_enums::GL_ARRAY_STRIDE) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
-    /// | [`GL_MATRIX_STRIDE`](crate::gl_enums::GL_MATRIX_STRIDE) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
-    /// | [`GL_IS_ROW_MAJOR`](crate::gl_enums::GL_IS_ROW_MAJOR) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
-    /// | [`GL_ATOMIC_COUNTER_BUFFER_INDEX`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_INDEX) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM) |
-    /// | [`GL_TEXTURE_BUFFER`](crate::gl_enums::GL_TEXTURE_BUFFER) | *none* |
-    /// | [`GL_BUFFER_BINDING`](crate::gl_enums::GL_BUFFER_BINDING) | [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER) |
-    /// | [`GL_BUFFER_DATA_SIZE`](crate::gl_enums::GL_BUFFER_DATA_SIZE) | [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK) |
-    /// | [`GL_NUM_ACTIVE_VARIABLES`](crate::gl_enums::GL_NUM_ACTIVE_VARIABLES) | [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER) |
-    /// | [`GL_ACTIVE_VARIABLES`](crate::gl_enums::GL_ACTIVE_VARIABLES) | [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_BUFFER`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER) |
-    /// | [`GL_REFERENCED_BY_VERTEX_SHADER`](crate::gl_enums::GL_REFERENCED_BY_VERTEX_SHADER) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_SHADER), [`GL_BUFFER`](crate::gl_enums::GL_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
-    /// | [`GL_REFERENCED_BY_TESS_CONTROL_SHADER`](crate::gl_enums::GL_REFERENCED_BY_TESS_CONTROL_SHADER) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_SHADER), [`GL_BUFFER`](crate::gl_enums::GL_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
-    /// | [`GL_REFERENCED_BY_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_REFERENCED_BY_TESS_EVALUATION_SHADER) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_SHADER), [`GL_BUFFER`](crate::gl_enums::GL_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
-    /// | [`GL_REFERENCED_BY_GEOMETRY_SHADER`](crate::gl_enums::GL_REFERENCED_BY_GEOMETRY_SHADER) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_SHADER), [`GL_BUFFER`](crate::gl_enums::GL_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
-    /// | [`GL_REFERENCED_BY_FRAGMENT_SHADER`](crate::gl_enums::GL_REFERENCED_BY_FRAGMENT_SHADER) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_SHADER), [`GL_BUFFER`](crate::gl_enums::GL_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
-    /// | [`GL_REFERENCED_BY_COMPUTE_SHADER`](crate::gl_enums::GL_REFERENCED_BY_COMPUTE_SHADER) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK), [`GL_ATOMIC_COUNTER_SHADER`](crate::gl_enums::GL_ATOMIC_COUNTER_SHADER), [`GL_BUFFER`](crate::gl_enums::GL_BUFFER), [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK), [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
-    /// | [`GL_NUM_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_NUM_COMPATIBLE_SUBROUTINES) | [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM), [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM), [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM), [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM), [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM), [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM) |
-    /// | [`GL_COMPATIBLE_SUBROUTINES`](crate::gl_enums::GL_COMPATIBLE_SUBROUTINES) | [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM), [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM), [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM), [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM), [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM), [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM) |
-    /// | [`GL_TOP_LEVEL_ARRAY_SIZE`](crate::gl_enums::GL_TOP_LEVEL_ARRAY_SIZE) | [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
-    /// | [`GL_TOP_LEVEL_ARRAY_STRIDE`](crate::gl_enums::GL_TOP_LEVEL_ARRAY_STRIDE) | [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE) |
-    /// | [`GL_LOCATION`](crate::gl_enums::GL_LOCATION) | [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT), [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM), [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM), [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM), [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM), [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM), [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM) |
-    /// | [`GL_LOCATION_INDEX`](crate::gl_enums::GL_LOCATION_INDEX) | [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
-    /// | [`GL_IS_PER_PATCH`](crate::gl_enums::GL_IS_PER_PATCH) | [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
-    /// | [`GL_LOCATION_COMPONENT`](crate::gl_enums::GL_LOCATION_COMPONENT) | [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT), [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT) |
-    /// | [`GL_TRANSFORM_FEEDBACK_BUFFER_INDEX`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_INDEX) | [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING) |
-    /// | [`GL_TRANSFORM_FEEDBACK_BUFFER_STRIDE`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_STRIDE) | [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER) |
-    ///
-    /// For the property [`GL_NAME_LENGTH`](crate::gl_enums::GL_NAME_LENGTH), a
-    /// single integer identifying the length of the name string associated with
-    /// an active variable, interface block, or subroutine is written to `params`.
-    /// The name length includes a terminating null character.
-    ///
-    /// For the property [`GL_TYPE`](crate::gl_enums::GL_TYPE), a single integer
-    /// identifying the type of an active variable is written to `params`. The
-    /// integer returned is one of the values found in table 2.16.
-    ///
-    /// For the property [`GL_ARRAY_SIZE`](crate::gl_enums::GL_ARRAY_SIZE), a single
-    /// integer identifying the number of active array elements of an active variable
-    /// is written to `params`. The array size returned is in units of the type
-    /// associated with the property [`GL_TYPE`](crate::gl_enums::GL_TYPE). For
-    /// active variables not corresponding to an array of basic types, the value
-    /// zero is written to `params`.
-    ///
-    /// For the property [`GL_BLOCK_INDEX`](crate::gl_enums::GL_BLOCK_INDEX), a
-    /// single integer identifying the index of the active interface block containing
-    /// an active variable is written to `params`. If the variable is not the member
-    /// of an interface block, the value -1 is written to `params`.
-    ///
-    /// For the property [`GL_OFFSET`](crate::gl_enums::GL_OFFSET), a single integer
-    /// identifying the offset of an active variable is written to `params`. For
-    /// variables in the [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM) and [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE)
-    /// interfaces that are backed by a buffer object, the value written is the
-    /// offset of that variable relative to the base of the buffer range holding
-    /// its value. For variables in the [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING)
-    /// interface, the value written is the offset in the transform feedback buffer
-    /// storage assigned to each vertex captured in transform feedback mode where
-    /// the value of the variable will be stored. Such offsets are specified via
-    /// the [**glTransformFeedbackVaryings**](crate::context::Context::oxidegl_transform_feedback_varyings).
-    /// Offsets are expressed in basic machine units. For all variables not recorded
-    /// in transform feedback mode, including the special names `params`.
-    ///
-    /// For the property [`GL_ARRAY_STRIDE`](crate::gl_enums::GL_ARRAY_STRIDE),
-    /// a single integer identifying the stride between array elements in an active
-    /// variable is written to `params`. For active variables declared as an array
-    /// of basic types, the value written is the difference, in basic machine units,
-    /// between the offsets of consecutive elements in an array. For active variables
-    /// not declared as an array of basic types, zero is written to `params`. For
-    /// active variables not backed by a buffer object, -1 is written to `params`,
-    /// regardless of the variable type.
-    ///
-    /// For the property [`GL_MATRIX_STRIDE`](crate::gl_enums::GL_MATRIX_STRIDE),
-    /// a single integer identifying the stride between columns of a column-major
-    /// matrix or rows of a row-major matrix is written to `params`. For active
-    /// variables declared a single matrix or array of matrices, the value written
-    /// is the difference, in basic machine units, between the offsets of consecutive
-    /// columns or rows in each matrix. For active variables not declared as a
-    /// matrix or array of matrices, zero is written to `params`. For active variables
-    /// not backed by a buffer object, -1 is written to `params`, regardless of
-    /// the variable type.
-    ///
-    /// For the property [`GL_IS_ROW_MAJOR`](crate::gl_enums::GL_IS_ROW_MAJOR),
-    /// a single integer identifying whether an active variable is a row-major
-    /// matrix is written to `params`. For active variables backed by a buffer
-    /// object, declared as a single matrix or array of matrices, and stored in
-    /// row-major order, one is written to `params`. For all other active variables,
-    /// zero is written to `params`.
-    ///
-    /// For the property [`GL_ATOMIC_COUNTER_BUFFER_INDEX`](crate::gl_enums::GL_ATOMIC_COUNTER_BUFFER_INDEX),
-    /// a single integer identifying the index of the active atomic counter buffer
-    /// containing an active variable is written to `params`. If the variable is
-    /// not an atomic counter uniform, the value -1 is written to `params`.
-    ///
-    /// For the property [`GL_BUFFER_BINDING`](crate::gl_enums::GL_BUFFER_BINDING),
-    /// to index of the buffer binding point associated with the active uniform
-    /// block, shader storage block, atomic counter buffer or transform feedback
-    /// buffer is written to `params`.
-    ///
-    /// For the property [`GL_BUFFER_DATA_SIZE`](crate::gl_enums::GL_BUFFER_DATA_SIZE),
-    /// then the implementation-dependent minimum total buffer object size, in
-    /// basic machine units, required to hold all active variables associated with
-    /// an active uniform block, shader storage block, or atomic counter buffer
-    /// is written to `params`. If the final member of an active shader storage
-    /// block is array with no declared size, the minimum buffer size is computed
-    /// assuming the array was declared as an array with one element.
-    ///
-    /// For the property [`GL_NUM_ACTIVE_VARIABLES`](crate::gl_enums::GL_NUM_ACTIVE_VARIABLES),
-    /// the number of active variables associated with an active uniform block,
-    /// shader storage block, atomic counter buffer or transform feedback buffer
-    /// is written to `params`.
-    ///
-    /// For the property [`GL_ACTIVE_VARIABLES`](crate::gl_enums::GL_ACTIVE_VARIABLES),
-    /// an array of active variable indices associated with an active uniform
-    /// block, shader storage block, atomic counter buffer or transform feedback
-    /// buffer is written to `params`. The number of values written to `params`
-    /// for an active resource is given by the value of the property [`GL_NUM_ACTIVE_VARIABLES`](crate::gl_enums::GL_NUM_ACTIVE_VARIABLES)
-    /// for the resource.
-    ///
-    /// For the properties [`GL_REFERENCED_BY_VERTEX_SHADER`](crate::gl_enums::GL_REFERENCED_BY_VERTEX_SHADER),
-    /// [`GL_REFERENCED_BY_TESS_CONTROL_SHADER`](crate::gl_enums::GL_REFERENCED_BY_TESS_CONTROL_SHADER),
-    /// [`GL_REFERENCED_BY_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_REFERENCED_BY_TESS_EVALUATION_SHADER),
-    /// [`GL_REFERENCED_BY_GEOMETRY_SHADER`](crate::gl_enums::GL_REFERENCED_BY_GEOMETRY_SHADER),
-    /// [`GL_REFERENCED_BY_FRAGMENT_SHADER`](crate::gl_enums::GL_REFERENCED_BY_FRAGMENT_SHADER),
-    /// and [`GL_REFERENCED_BY_COMPUTE_SHADER`](crate::gl_enums::GL_REFERENCED_BY_COMPUTE_SHADER),
-    /// a single integer is written to `params`, identifying whether the active
-    /// resource is referenced by the vertex, tessellation control, tessellation
-    /// evaluation, geometry, or fragment shaders, respectively, in the program
-    /// object. The value one is written to `params` if an active variable is referenced
-    /// by the corresponding shader, or if an active uniform block, shader storage
-    /// block, or atomic counter buffer contains at least one variable referenced
-    /// by the corresponding shader. Otherwise, the value zero is written to `params`.
-    ///
-    /// For the property [`GL_TOP_LEVEL_ARRAY_SIZE`](crate::gl_enums::GL_TOP_LEVEL_ARRAY_SIZE),
-    /// a single integer identifying the number of active array elements of the
-    /// top-level shader storage block member containing to the active variable
-    /// is written to `params`. If the top-level block member is not declared as
-    /// an array, the value one is written to `params`. If the top-level block
-    /// member is an array with no declared size, the value zero is written to
-    /// `params`.
-    ///
-    /// For the property [`GL_TOP_LEVEL_ARRAY_STRIDE`](crate::gl_enums::GL_TOP_LEVEL_ARRAY_STRIDE),
-    /// a single integer identifying the stride between array elements of the
-    /// top-level shader storage block member containing the active variable is
-    /// written to `params`. For top-level block members declared as arrays, the
-    /// value written is the difference, in basic machine units, between the offsets
-    /// of the active variable for consecutive elements in the top-level array.
-    /// For top-level block members not declared as an array, zero is written to
-    /// `params`.
-    ///
-    /// For the property [`GL_LOCATION`](crate::gl_enums::GL_LOCATION), a single
-    /// integer identifying the assigned location for an active uniform, input,
-    /// output, or subroutine uniform variable is written to `params`. For input,
-    /// output, or uniform variables with locations specified by a layout qualifier,
-    /// the specified location is used. For vertex shader input or fragment shader
-    /// output variables without a layout qualifier, the location assigned when
-    /// a program is linked is written to `params`. For all other input and output
-    /// variables, the value -1 is written to `params`. For uniforms in uniform
-    /// blocks, the value -1 is written to `params`.
-    ///
-    /// For the property [`GL_LOCATION_INDEX`](crate::gl_enums::GL_LOCATION_INDEX),
-    /// a single integer identifying the fragment color index of an active fragment
-    /// shader output variable is written to `params`. If the active variable is
-    /// an output for a non-fragment shader, the value -1 will be written to `params`.
-    ///
-    /// For the property [`GL_IS_PER_PATCH`](crate::gl_enums::GL_IS_PER_PATCH),
-    /// a single integer identifying whether the input or output is a per-patch
-    /// attribute. If the active variable is a per-patch attribute (declared with
-    /// the `params`; otherwise, the value zero is written to `params`.
-    ///
-    /// For the property [`GL_LOCATION_COMPONENT`](crate::gl_enums::GL_LOCATION_COMPONENT),
-    /// a single integer indicating the first component of the location assigned
-    /// to an active input or output variable is written to `params`. For input
-    /// and output variables with a component specified by a
-    ///
-    /// For the property [`GL_TRANSFORM_FEEDBACK_BUFFER_INDEX`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_INDEX),
-    /// a single integer identifying the index of the active transform feedback
-    /// buffer associated with an active variable is written to `params`. For variables
-    /// corresponding to the special names `params`.
-    ///
-    /// For the property [`GL_TRANSFORM_FEEDBACK_BUFFER_STRIDE`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER_STRIDE),
-    /// a single integer identifying the stride, in basic machine units, between
-    /// consecutive vertices written to the transform feedback buffer is written
-    /// to `params`.
-    pub unsafe fn oxidegl_get_program_resourceiv(
-        &mut self,
-        program: GLuint,
-        program_interface: ProgramInterface,
-        index: GLuint,
-        prop_count: GLsizei,
-        props: *const ProgramResourceProperty,
-        count: GLsizei,
-        length: *mut GLsizei,
-        params: *mut GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_program_resourceiv not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
+    /// `bufSize`
     ///
-    /// > The name of a program object whose resources to query.
+    /// > Specifies the size of the buffer addressed by `uniformBlockName`.
     ///
-    /// `programInterface`
+    /// `length`
     ///
-    /// > A token identifying the interface within `program` containing the resource
-    /// > named `name`.
+    /// > Specifies the address of a variable to receive the number of characters
+    /// > that were written to `uniformBlockName`.
     ///
-    /// `name`
+    /// `uniformBlockName`
     ///
-    /// > The name of the resource to query the index of.
+    /// > Specifies the address an array of characters to receive the name of the
+    /// > uniform block at `uniformBlockIndex`.
     ///
     /// ### Description
-    /// [**glGetProgramResourceIndex**](crate::context::Context::oxidegl_get_program_resource_index)
-    /// returns the unsigned integer index assigned to a resource named `name`
-    /// in the interface type `programInterface` of program object `program`.
-    ///
-    /// `program` must be the name of an existing program object. `programInterface`
-    /// is the name of the interface within `program` which contains the resource
-    /// named `name` and must be one of the following values:
+    /// [**glGetActiveUniformBlockName**](crate::context::Context::oxidegl_get_active_uniform_block_name)
+    /// retrieves the name of the active uniform block at `uniformBlockIndex` within
+    /// `program`.
     ///
-    /// [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM)
+    /// `program` must be the name of a program object for which the command [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// must have been called in the past, although it is not required that [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// must have succeeded. The link could have failed because the number of active
+    /// uniforms exceeded the limit.
     ///
-    /// > The query is targeted at the set of active uniforms within `program`.
+    /// `uniformBlockIndex` is an active uniform block index of `program`, and
+    /// must be less than the value of [`GL_ACTIVE_UNIFORM_BLOCKS`](crate::gl_enums::GL_ACTIVE_UNIFORM_BLOCKS).
     ///
-    /// [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK)
+    /// Upon success, the name of the uniform block identified by `unifomBlockIndex`
+    /// is returned into `uniformBlockName`. The name is nul-terminated. The actual
+    /// number of characters written into `uniformBlockName`, excluding the nul
+    /// terminator, is returned in `length`. If `length` is
     ///
-    /// > The query is targeted at the set of active uniform blocks within `program`.
+    /// `bufSize` contains the maximum number of characters (including the nul
+    /// terminator) that will be written into `uniformBlockName`.
     ///
-    /// [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT)
+    /// If an error occurs, nothing will be written to `uniformBlockName` or `length`.
     ///
-    /// > The query is targeted at the set of active input variables used by the
-    /// > first shader stage of `program`. If `program` contains multiple shader
-    /// > stages then input variables from any stage other than the first will not
-    /// > be enumerated.
+    /// ### Notes
+    /// [**glGetActiveUniformBlockName**](crate::context::Context::oxidegl_get_active_uniform_block_name)
+    /// is available only if the GL version is 3.1 or greater.
+    pub unsafe fn oxidegl_get_active_uniform_block_name(
+        &mut self,
+        program: GLuint,
+        uniform_block_index: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        uniform_block_name: *mut GLchar,
+    ) -> GlFallible {
+        panic!("command oxidegl_get_active_uniform_block_name not yet implemented");
+    }
+    /// ### Parameters
+    /// `program`
     ///
-    /// [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT)
+    /// > Specifies the program containing the active uniform index `uniformIndex`.
     ///
-    /// > The query is targeted at the set of active output variables produced by
-    /// > the last shader stage of `program`. If `program` contains multiple shader
-    /// > stages then output variables from any stage other than the last will not
-    /// > be enumerated.
+    /// `uniformIndex`
     ///
-    /// [`GL_VERTEX_SUBROUTINE`](crate::gl_enums::GL_VERTEX_SUBROUTINE)
+    /// > Specifies the index of the active uniform whose name to query.
     ///
-    /// [`GL_TESS_CONTROL_SUBROUTINE`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE)
+    /// `bufSize`
     ///
-    /// [`GL_TESS_EVALUATION_SUBROUTINE`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE)
+    /// > Specifies the size of the buffer, in units of `uniformName`.
     ///
-    /// [`GL_GEOMETRY_SUBROUTINE`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE)
+    /// `length`
     ///
-    /// [`GL_FRAGMENT_SUBROUTINE`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE)
+    /// > Specifies the address of a variable that will receive the number of characters
+    /// > that were or would have been written to the buffer addressed by `uniformName`.
     ///
-    /// [`GL_COMPUTE_SUBROUTINE`](crate::gl_enums::GL_COMPUTE_SUBROUTINE)
+    /// `uniformName`
     ///
-    /// > The query is targeted at the set of active subroutines for the vertex,
-    /// > tessellation control, tessellation evaluation, geometry, fragment and compute
-    /// > shader stages of `program`, respectively.
+    /// > Specifies the address of a buffer into which the GL will place the name
+    /// > of the active uniform at `uniformIndex` within `program`.
     ///
-    /// [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM)
+    /// ### Description
+    /// [**glGetActiveUniformName**](crate::context::Context::oxidegl_get_active_uniform_name)
+    /// returns the name of the active uniform at `uniformIndex` within `program`.
+    /// If `uniformName` is not `bufSize` characters (including a nul-terminator)
+    /// will be written into the array whose address is specified by `uniformName`.
+    /// If `length` is not `uniformName` (not including the nul-terminator) will
+    /// be placed in the variable whose address is specified in `length`. If `length`
+    /// is `program` is given by the value of [`GL_ACTIVE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_UNIFORM_MAX_LENGTH),
+    /// which can be queried with [**glGetProgram**](crate::context::Context::oxidegl_get_program).
     ///
-    /// [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM)
+    /// If [**glGetActiveUniformName**](crate::context::Context::oxidegl_get_active_uniform_name)
+    /// is not successful, nothing is written to `length` or `uniformName`.
     ///
-    /// [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM)
+    /// `program` must be the name of a program for which the command [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// has been issued in the past. It is not necessary for `program` to have
+    /// been linked successfully. The link could have failed because the number
+    /// of active uniforms exceeded the limit.
     ///
-    /// [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM)
+    /// `uniformIndex` must be an active uniform index of the program `program`,
+    /// in the range zero to the value of [`GL_ACTIVE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_UNIFORMS)
+    /// minus one. The value of [`GL_ACTIVE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_UNIFORMS)
+    /// can be queried with [**glGetProgram**](crate::context::Context::oxidegl_get_program).
+    pub unsafe fn oxidegl_get_active_uniform_name(
+        &mut self,
+        program: GLuint,
+        uniform_index: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        uniform_name: *mut GLchar,
+    ) -> GlFallible {
+        panic!("command oxidegl_get_active_uniform_name not yet implemented");
+    }
+    /// ### Parameters
+    /// `program`
     ///
-    /// [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM)
+    /// > Specifies the program object to be queried.
     ///
-    /// [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM)
+    /// `uniformCount`
     ///
-    /// > The query is targeted at the set of active subroutine uniform variables
-    /// > used by the vertex, tessellation control, tessellation evaluation, geometry,
-    /// > fragment and compute shader stages of `program`, respectively.
+    /// > Specifies both the number of elements in the array of indices `uniformIndices`
+    /// > and the number of parameters written to `params` upon successful return.
     ///
-    /// [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING)
+    /// `uniformIndices`
     ///
-    /// > The query is targeted at the set of output variables from the last non-fragment
-    /// > stage of `program` that would be captured if transform feedback were active.
+    /// > Specifies the address of an array of `uniformCount` integers containing
+    /// > the indices of uniforms within `program` whose parameter `pname` should
+    /// > be queried.
     ///
-    /// [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER)
+    /// `pname`
     ///
-    /// > The query is targeted at the set of active buffer binding points to which
-    /// > output variables in the [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING)
-    /// > interface are written.
+    /// > Specifies the property of each uniform in `uniformIndices` that should
+    /// > be written into the corresponding element of `params`.
     ///
-    /// [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE)
+    /// `params`
     ///
-    /// > The query is targeted at the set of active buffer variables used by `program`.
+    /// > Specifies the address of an array of `uniformCount` integers which are
+    /// > to receive the value of `pname` for each uniform in `uniformIndices`.
     ///
-    /// [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK)
+    /// ### Description
+    /// [**glGetActiveUniformsiv**](crate::context::Context::oxidegl_get_active_uniformsiv)
+    /// queries the value of the parameter named `pname` for each of the uniforms
+    /// within `program` whose indices are specified in the array of `uniformCount`
+    /// unsigned integers `uniformIndices`. Upon success, the value of the parameter
+    /// for each uniform is written into the corresponding entry in the array whose
+    /// address is given in `params`. If an error is generated, nothing is written
+    /// into `params`.
     ///
-    /// > The query is targeted at the set of active shader storage blocks used by
-    /// > `program`.
+    /// If `pname` is [`GL_UNIFORM_TYPE`](crate::gl_enums::GL_UNIFORM_TYPE), then
+    /// an array identifying the types of uniforms specified by the corresponding
+    /// array of `uniformIndices` is returned. The returned types can be any of
+    /// the values from the following table:
+    /// |* Returned Symbolic Contant*                                 |* Shader Uniform Type*                              |
+    /// |-------------------------------------------------------------|----------------------------------------------------|
+    /// | [`GL_FLOAT`](crate::gl_enums::GL_FLOAT)                     | [`float`](crate::gl_enums::float)                  |
+    /// | [`GL_FLOAT_VEC2`](crate::gl_enums::GL_FLOAT_VEC2)           | [`vec2`](crate::gl_enums::vec2)                    |
+    /// | [`GL_FLOAT_VEC3`](crate::gl_enums::GL_FLOAT_VEC3)           | [`vec3`](crate::gl_enums::vec3)                    |
+    /// | [`GL_FLOAT_VEC4`](crate::gl_enums::GL_FLOAT_VEC4)           | [`vec4`](crate::gl_enums::vec4)                    |
+    /// | [`GL_DOUBLE`](crate::gl_enums::GL_DOUBLE)                   | [`double`](crate::gl_enums::double)                |
+    /// | [`GL_DOUBLE_VEC2`](crate::gl_enums::GL_DOUBLE_VEC2)         | [`dvec2`](crate::gl_enums::dvec2)                  |
+    /// | [`GL_DOUBLE_VEC3`](crate::gl_enums::GL_DOUBLE_VEC3)         | [`dvec3`](crate::gl_enums::dvec3)                  |
+    /// | [`GL_DOUBLE_VEC4`](crate::gl_enums::GL_DOUBLE_VEC4)         | [`dvec4`](crate::gl_enums::dvec4)                  |
+    /// | [`GL_INT`](crate::gl_enums::GL_INT)                         | [`int`](crate::gl_enums::int)                      |
+    /// | [`GL_INT_VEC2`](crate::gl_enums::GL_INT_VEC2)               | [`ivec2`](crate::gl_enums::ivec2)                  |
+    /// | [`GL_INT_VEC3`](crate::gl_enums::GL_INT_VEC3)               | [`ivec3`](crate::gl_enums::ivec3)                  |
+    /// | [`GL_INT_VEC4`](crate::gl_enums::GL_INT_VEC4)               | [`ivec4`](crate::gl_enums::ivec4)                  |
+    /// | [`GL_UNSIGNED_INT`](crate::gl_enums::GL_UNSIGNED_INT)       | [`unsigned int`](`crate::gl_enums::unsigned` int)    |
+    /// | [`GL_UNSIGNED_INT_VEC2`](crate::gl_enums::GL_UNSIGNED_INT_VEC2) | [`uvec2`](crate::gl_enums::uvec2)              |
+    /// | [`GL_UNSIGNED_INT_VEC3`](crate::gl_enums::GL_UNSIGNED_INT_VEC3) | [`uvec3`](crate::gl_enums::uvec3)              |
+    /// | [`GL_UNSIGNED_INT_VEC4`](crate::gl_enums::GL_UNSIGNED_INT_VEC4) | [`uvec4`](crate::gl_enums::uvec4)              |
+    /// | [`GL_BOOL`](crate::gl_enums::GL_BOOL)                       | [`bool`](crate::gl_enums::bool)                    |
+    /// | [`GL_BOOL_VEC2`](crate::gl_enums::GL_BOOL_VEC2)             | [`bvec2`](crate::gl_enums::bvec2)                  |
+    /// | [`GL_BOOL_VEC3`](crate::gl_enums::GL_BOOL_VEC3)             | [`bvec3`](crate::gl_enums::bvec3)                  |
+    /// | [`GL_BOOL_VEC4`](crate::gl_enums::GL_BOOL_VEC4)             | [`bvec4`](crate::gl_enums::bvec4)                  |
+    /// | [`GL_FLOAT_MAT2`](crate::gl_enums::GL_FLOAT_MAT2)           | [`mat2`](crate::gl_enums::mat2)                    |
+    /// | [`GL_FLOAT_MAT3`](crate::gl_enums::GL_FLOAT_MAT3)           | [`mat3`](crate::gl_enums::mat3)                    |
+    /// | [`GL_FLOAT_MAT4`](crate::gl_enums::GL_FLOAT_MAT4)           | [`mat4`](crate::gl_enums::mat4)                    |
+    /// | [`GL_FLOAT_MAT2x3`](crate::gl_enums::GL_FLOAT_MAT2x3)       | [`mat2x3`](crate::gl_enums::mat2x3)                |
+    /// | [`GL_FLOAT_MAT2x4`](crate::gl_enums::GL_FLOAT_MAT2x4)       | [`mat2x4`](crate::gl_enums::mat2x4)                |
+    /// | [`GL_FLOAT_MAT3x2`](crate::gl_enums::GL_FLOAT_MAT3x2)       | [`mat3x2`](crate::gl_enums::mat3x2)                |
+    /// | [`GL_FLOAT_MAT3x4`](crate::gl_enums::GL_FLOAT_MAT3x4)       | [`mat3x4`](crate::gl_enums::mat3x4)                |
+    /// | [`GL_FLOAT_MAT4x2`](crate::gl_enums::GL_FLOAT_MAT4x2)       | [`mat4x2`](crate::gl_enums::mat4x2)                |
+    /// | [`GL_FLOAT_MAT4x3`](crate::gl_enums::GL_FLOAT_MAT4x3)       | [`mat4x3`](crate::gl_enums::mat4x3)                |
+    /// | [`GL_DOUBLE_MAT2`](crate::gl_enums::GL_DOUBLE_MAT2)         | [`dmat2`](crate::gl_enums::dmat2)                  |
+    /// | [`GL_DOUBLE_MAT3`](crate::gl_enums::GL_DOUBLE_MAT3)         | [`dmat3`](crate::gl_enums::dmat3)                  |
+    /// | [`GL_DOUBLE_MAT4`](crate::gl_enums::GL_DOUBLE_MAT4)         | [`dmat4`](crate::gl_enums::dmat4)                  |
+    /// | [`GL_DOUBLE_MAT2x3`](crate::gl_enums::GL_DOUBLE_MAT2x3)     | [`dmat2x3`](crate::gl_enums::dmat2x3)              |
+    /// | [`GL_DOUBLE_MAT2x4`](crate::gl_enums::GL_DOUBLE_MAT2x4)     | [`dmat2x4`](crate::gl_enums::dmat2x4)              |
+    /// | [`GL_DOUBLE_MAT3x2`](crate::gl_enums::GL_DOUBLE_MAT3x2)     | [`dmat3x2`](crate::gl_enums::dmat3x2)              |
+    /// | [`GL_DOUBLE_MAT3x4`](crate::gl_enums::GL_DOUBLE_MAT3x4)     | [`dmat3x4`](crate::gl_enums::dmat3x4)              |
+    /// | [`GL_DOUBLE_MAT4x2`](crate::gl_enums::GL_DOUBLE_MAT4x2)     | [`dmat4x2`](crate::gl_enums::dmat4x2)              |
+    /// | [`GL_DOUBLE_MAT4x3`](crate::gl_enums::GL_DOUBLE_MAT4x3)     | [`dmat4x3`](crate::gl_enums::dmat4x3)              |
+    /// | [`GL_SAMPLER_1D`](crate::gl_enums::GL_SAMPLER_1D)           | [`sampler1D`](crate::gl_enums::sampler1D)          |
+    /// | [`GL_SAMPLER_2D`](crate::gl_enums::GL_SAMPLER_2D)           | [`sampler2D`](crate::gl_enums::sampler2D)          |
+    /// | [`GL_SAMPLER_3D`](crate::gl_enums::GL_SAMPLER_3D)           | [`sampler3D`](crate::gl_enums::sampler3D)          |
+    /// | [`GL_SAMPLER_CUBE`](crate::gl_enums::GL_SAMPLER_CUBE)       | [`samplerCube`](crate::gl_enums::samplerCube)      |
+    /// | [`GL_SAMPLER_1D_SHADOW`](crate::gl_enums::GL_SAMPLER_1D_SHADOW) | [`sampler1DShadow`](crate::gl_enums::sampler1DShadow) |
+    /// | [`GL_SAMPLER_2D_SHADOW`](crate::gl_enums::GL_SAMPLER_2D_SHADOW) | [`sampler2DShadow`](crate::gl_enums::sampler2DShadow) |
+    /// | [`GL_SAMPLER_1D_ARRAY`](crate::gl_enums::GL_SAMPLER_1D_ARRAY) | [`sampler1DArray`](crate::gl_enums::sampler1DArray) |
+    /// | [`GL_SAMPLER_2D_ARRAY`](crate::gl_enums::GL_SAMPLER_2D_ARRAY) | [`sampler2DArray`](crate::gl_enums::sampler2DArray) |
+    /// | [`GL_SAMPLER_1D_ARRAY_SHADOW`](crate::gl_enums::GL_SAMPLER_1D_ARRAY_SHADOW) | [`sampler1DArrayShadow`](crate::gl_enums::sampler1DArrayShadow) |
+    /// | [`GL_SAMPLER_2D_ARRAY_SHADOW`](crate::gl_enums::GL_SAMPLER_2D_ARRAY_SHADOW) | [`sampler2DArrayShadow`](crate::gl_enums::sampler2DArrayShadow) |
+    /// | [`GL_SAMPLER_2D_MULTISAMPLE`](crate::gl_enums::GL_SAMPLER_2D_MULTISAMPLE) | [`sampler2DMS`](crate::gl_enums::sampler2DMS) |
+    /// | [`GL_SAMPLER_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_SAMPLER_2D_MULTISAMPLE_ARRAY) | [`sampler2DMSArray`](crate::gl_enums::sampler2DMSArray) |
+    /// | [`GL_SAMPLER_CUBE_SHADOW`](crate::gl_enums::GL_SAMPLER_CUBE_SHADOW) | [`samplerCubeShadow`](crate::gl_enums::samplerCubeShadow) |
+    /// | [`GL_SAMPLER_BUFFER`](crate::gl_enums::GL_SAMPLER_BUFFER)   | [`samplerBuffer`](crate::gl_enums::samplerBuffer)  |
+    /// | [`GL_SAMPLER_2D_RECT`](crate::gl_enums::GL_SAMPLER_2D_RECT) | [`sampler2DRect`](crate::gl_enums::sampler2DRect)  |
+    /// | [`GL_SAMPLER_2D_RECT_SHADOW`](crate::gl_enums::GL_SAMPLER_2D_RECT_SHADOW) | [`sampler2DRectShadow`](crate::gl_enums::sampler2DRectShadow) |
+    /// | [`GL_INT_SAMPLER_1D`](crate::gl_enums::GL_INT_SAMPLER_1D)   | [`isampler1D`](crate::gl_enums::isampler1D)        |
+    /// | [`GL_INT_SAMPLER_2D`](crate::gl_enums::GL_INT_SAMPLER_2D)   | [`isampler2D`](crate::gl_enums::isampler2D)        |
+    /// | [`GL_INT_SAMPLER_3D`](crate::gl_enums::GL_INT_SAMPLER_3D)   | [`isampler3D`](crate::gl_enums::isampler3D)        |
+    /// | [`GL_INT_SAMPLER_CUBE`](crate::gl_enums::GL_INT_SAMPLER_CUBE) | [`isamplerCube`](crate::gl_enums::isamplerCube)  |
+    /// | [`GL_INT_SAMPLER_1D_ARRAY`](crate::gl_enums::GL_INT_SAMPLER_1D_ARRAY) | [`isampler1DArray`](crate::gl_enums::isampler1DArray) |
+    /// | [`GL_INT_SAMPLER_2D_ARRAY`](crate::gl_enums::GL_INT_SAMPLER_2D_ARRAY) | [`isampler2DArray`](crate::gl_enums::isampler2DArray) |
+    /// | [`GL_INT_SAMPLER_2D_MULTISAMPLE`](crate::gl_enums::GL_INT_SAMPLER_2D_MULTISAMPLE) | [`isampler2DMS`](crate::gl_enums::isampler2DMS) |
+    /// | [`GL_INT_SAMPLER_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_INT_SAMPLER_2D_MULTISAMPLE_ARRAY) | [`isampler2DMSArray`](crate::gl_enums::isampler2DMSArray) |
+    /// | [`GL_INT_SAMPLER_BUFFER`](crate::gl_enums::GL_INT_SAMPLER_BUFFER) | [`isamplerBuffer`](crate::gl_enums::isamplerBuffer) |
+    /// | [`GL_INT_SAMPLER_2D_RECT`](crate::gl_enums::GL_INT_SAMPLER_2D_RECT) | [`isampler2DRect`](crate::gl_enums::isampler2DRect) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_1D`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_1D) | [`usampler1D`](crate::gl_enums::usampler1D) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_2D`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D) | [`usampler2D`](crate::gl_enums::usampler2D) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_3D`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_3D) | [`usampler3D`](crate::gl_enums::usampler3D) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_CUBE`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_CUBE) | [`usamplerCube`](crate::gl_enums::usamplerCube) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_1D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_1D_ARRAY) | [`usampler2DArray`](crate::gl_enums::usampler2DArray) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_ARRAY) | [`usampler2DArray`](crate::gl_enums::usampler2DArray) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE) | [`usampler2DMS`](crate::gl_enums::usampler2DMS) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY) | [`usampler2DMSArray`](crate::gl_enums::usampler2DMSArray) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_BUFFER`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_BUFFER) | [`usamplerBuffer`](crate::gl_enums::usamplerBuffer) |
+    /// | [`GL_UNSIGNED_INT_SAMPLER_2D_RECT`](crate::gl_enums::GL_UNSIGNED_INT_SAMPLER_2D_RECT) | [`usampler2DRect`](crate::gl_enums::usampler2DRect) |
     ///
-    /// If `name` exactly matches the name string of one of the active resources
-    /// for `programInterface`, the index of the matched resource is returned.
-    /// Additionally, if `name` would exactly match the name string of an active
-    /// resource if "\[0\]" were appended to `name`, the index of the matched resource
-    /// is returned. Otherwise, `name` is considered not to be the name of an active
-    /// resource, and [`GL_INVALID_INDEX`](crate::gl_enums::GL_INVALID_INDEX) is
-    /// returned.
     ///
-    /// For the interface [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING),
-    /// the value [`GL_INVALID_INDEX`](crate::gl_enums::GL_INVALID_INDEX) should
-    /// be returned when querying the index assigned to the special names
-    pub unsafe fn oxidegl_get_program_resource_index(
-        &mut self,
-        program: GLuint,
-        program_interface: ProgramInterface,
-        name: *const GLchar,
-    ) -> GlFallible<GLuint> {
-        panic!("command oxidegl_get_program_resource_index not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
+    /// If `pname` is [`GL_UNIFORM_SIZE`](crate::gl_enums::GL_UNIFORM_SIZE), then
+    /// an array identifying the size of the uniforms specified by the corresponding
+    /// array of `uniformIndices` is returned. The sizes returned are in units
+    /// of the type returned by a query of [`GL_UNIFORM_TYPE`](crate::gl_enums::GL_UNIFORM_TYPE).
+    /// For active uniforms that are arrays, the size is the number of active
+    /// elements in the array; for all other uniforms, the size is one.
     ///
-    /// > The name of a program object whose resources to query.
+    /// If `pname` is [`GL_UNIFORM_NAME_LENGTH`](crate::gl_enums::GL_UNIFORM_NAME_LENGTH),
+    /// then an array identifying the length, including the terminating null character,
+    /// of the uniform name strings specified by the corresponding array of `uniformIndices`
+    /// is returned.
     ///
-    /// `programInterface`
+    /// If `pname` is [`GL_UNIFORM_BLOCK_INDEX`](crate::gl_enums::GL_UNIFORM_BLOCK_INDEX),
+    /// then an array identifying the uniform block index of each of the uniforms
+    /// specified by the corresponding array of `uniformIndices` is returned. The
+    /// uniform block index of a uniform associated with the default uniform block
+    /// is -1.
     ///
-    /// > A token identifying the interface within `program` containing the resource
-    /// > named `name`.
+    /// If `pname` is [`GL_UNIFORM_OFFSET`](crate::gl_enums::GL_UNIFORM_OFFSET),
+    /// then an array of uniform buffer offsets is returned. For uniforms in a
+    /// named uniform block, the returned value will be its offset, in basic machine
+    /// units, relative to the beginning of the uniform block in the buffer object
+    /// data store. For atomic counter uniforms, the returned value will be its
+    /// offset relative to the beginning of its active atomic counter buffer. For
+    /// all other uniforms, -1 will be returned.
     ///
-    /// `name`
+    /// If `pname` is [`GL_UNIFORM_ARRAY_STRIDE`](crate::gl_enums::GL_UNIFORM_ARRAY_STRIDE),
+    /// then an array identifying the stride between elements of each of the uniforms
+    /// specified by the corresponding array of `uniformIndices` is returned. For
+    /// uniforms in named uniform blocks and for uniforms declared as atomic counters,
+    /// the stride is the difference, in basic machine units, of consecutive elements
+    /// in an array, or zero for uniforms not declared as an array. For all other
+    /// uniforms, a stride of -1 will be returned.
     ///
-    /// > The name of the resource to query the location of.
+    /// If `pname` is [`GL_UNIFORM_MATRIX_STRIDE`](crate::gl_enums::GL_UNIFORM_MATRIX_STRIDE),
+    /// then an array identifying the stride between columns of a column-major
+    /// matrix or rows of a row-major matrix, in basic machine units, of each of
+    /// the uniforms specified by the corresponding array of `uniformIndices` is
+    /// returned. The matrix stride of a uniform associated with the default uniform
+    /// block is -1. Note that this information only makes sense for uniforms that
+    /// are matrices. For uniforms that are not matrices, but are declared in a
+    /// named uniform block, a matrix stride of zero is returned.
     ///
-    /// ### Description
-    /// [**glGetProgramResourceLocation**](crate::context::Context::oxidegl_get_program_resource_location)
-    /// returns the location assigned to the variable named `name` in interface
-    /// `programInterface` of program object `program`. `program` must be the name
-    /// of a program that has been linked successfully. `programInterface` must
-    /// be one of [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM), [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT),
-    /// [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT), [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM),
-    /// [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM),
-    /// [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM),
-    /// [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM),
-    /// [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM),
-    /// [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM),
-    /// or [`GL_TRANSFORM_FEEDBACK_BUFFER`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_BUFFER).
-    ///
-    /// The value -1 will be returned if an error occurs, if `name` does not identify
-    /// an active variable on `programInterface`, or if `name` identifies an active
-    /// variable that does not have a valid location assigned, as described above.
-    /// The locations returned by these commands are the same locations returned
-    /// when querying the [`GL_LOCATION`](crate::gl_enums::GL_LOCATION) and [`GL_LOCATION_INDEX`](crate::gl_enums::GL_LOCATION_INDEX)
-    /// resource properties.
-    ///
-    /// A string provided to [**glGetProgramResourceLocation**](crate::context::Context::oxidegl_get_program_resource_location)
-    /// is considered to match an active variable if:
-    ///
-    /// > the string exactly matches the name of the active variable
-    ///
-    /// > if the string identifies the base name of an active array, where the string
-    /// > would exactly match the name of the variable if the suffix "\[0\]" were
-    /// > appended to the string
-    ///
-    /// > if the string identifies an active element of the array, where the string
-    /// > ends with the concatenation of the "\[" character, an integer *with no
-    /// > "+" sign, extra leading zeroes, or whitespace* identifying an array element,
-    /// > and the "\]" character, the integer is less than the number of active elements
-    /// > of the array variable, and where the string would exactly match the enumerated
-    /// > name of the array if the decimal integer were replaced with zero.
-    ///
-    /// Any other string is considered not to identify an active variable. If the
-    /// string specifies an element of an array variable, [**glGetProgramResourceLocation**](crate::context::Context::oxidegl_get_program_resource_location)
-    /// returns the location assigned to that element. If it specifies the base
-    /// name of an array, it identifies the resources associated with the first
-    /// element of the array.
-    pub unsafe fn oxidegl_get_program_resource_location(
-        &mut self,
-        program: GLuint,
-        program_interface: ProgramInterface,
-        name: *const GLchar,
-    ) -> GlFallible<GLint> {
-        panic!("command oxidegl_get_program_resource_location not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
+    /// If `pname` is [`GL_UNIFORM_IS_ROW_MAJOR`](crate::gl_enums::GL_UNIFORM_IS_ROW_MAJOR),
+    /// then an array identifying whether each of the uniforms specified by the
+    /// corresponding array of `uniformIndices` is a row-major matrix or not is
+    /// returned. A value of one indicates a row-major matrix, and a value of zero
+    /// indicates a column-major matrix, a matrix in the default uniform block,
+    /// or a non-matrix.
     ///
-    /// > The name of a program object whose resources to query.
+    /// If `pname` is [`GL_UNIFORM_ATOMIC_COUNTER_BUFFER_INDEX`](crate::gl_enums::GL_UNIFORM_ATOMIC_COUNTER_BUFFER_INDEX),
+    /// then an array identifying the active atomic counter buffer index of each
+    /// of the uniforms specified by the corresponding array of `uniformIndices`
+    /// is returned. For uniforms other than atomic counters, the returned buffer
+    /// index is -1. The returned indices may be passed to [**glGetActiveAtomicCounterBufferiv**](crate::context::Context::oxidegl_get_active_atomic_counter_bufferiv)
+    /// to query the properties of the associated buffer, and not necessarily the
+    /// binding point specified in the uniform declaration.
     ///
-    /// `programInterface`
+    /// ### Notes
+    /// The double types, [`GL_DOUBLE`](crate::gl_enums::GL_DOUBLE), [`GL_DOUBLE_VEC2`](crate::gl_enums::GL_DOUBLE_VEC2),
+    /// [`GL_DOUBLE_VEC3`](crate::gl_enums::GL_DOUBLE_VEC3), [`GL_DOUBLE_VEC4`](crate::gl_enums::GL_DOUBLE_VEC4),
+    /// [`GL_DOUBLE_MAT2`](crate::gl_enums::GL_DOUBLE_MAT2), [`GL_DOUBLE_MAT3`](crate::gl_enums::GL_DOUBLE_MAT3),
+    /// [`GL_DOUBLE_MAT4`](crate::gl_enums::GL_DOUBLE_MAT4), [`GL_DOUBLE_MAT2x3`](crate::gl_enums::GL_DOUBLE_MAT2x3),
+    /// [`GL_DOUBLE_MAT2x4`](crate::gl_enums::GL_DOUBLE_MAT2x4), [`GL_DOUBLE_MAT3x2`](crate::gl_enums::GL_DOUBLE_MAT3x2),
+    /// [`GL_DOUBLE_MAT3x4`](crate::gl_enums::GL_DOUBLE_MAT3x4), [`GL_DOUBLE_MAT4x2`](crate::gl_enums::GL_DOUBLE_MAT4x2),
+    /// and [`GL_DOUBLE_MAT4x3`](crate::gl_enums::GL_DOUBLE_MAT4x3) are only available
+    /// if the GL version is 4.1 or higher.
     ///
-    /// > A token identifying the interface within `program` containing the resource
-    /// > named `name`.
+    /// [`GL_UNIFORM_ATOMIC_COUNTER_BUFFER_INDEX`](crate::gl_enums::GL_UNIFORM_ATOMIC_COUNTER_BUFFER_INDEX)
+    /// is only accepted by `pname` if the GL version is 4.2 or higher.
     ///
-    /// `name`
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_MAX_VERTEX_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_VERTEX_UNIFORM_COMPONENTS),
+    /// [`GL_MAX_TESS_CONTROL_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_TESS_CONTROL_UNIFORM_COMPONENTS),
+    /// [`GL_MAX_TESS_EVALUATION_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_TESS_EVALUATION_UNIFORM_COMPONENTS),
+    /// [`GL_MAX_GEOMETRY_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_GEOMETRY_UNIFORM_COMPONENTS),
+    /// [`GL_MAX_FRAGMENT_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_FRAGMENT_UNIFORM_COMPONENTS),
+    /// or [`GL_MAX_COMBINED_UNIFORM_COMPONENTS`](crate::gl_enums::GL_MAX_COMBINED_UNIFORM_COMPONENTS).
     ///
-    /// > The name of the resource to query the location of.
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
+    /// [`GL_ACTIVE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_UNIFORMS) or [`GL_ACTIVE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_UNIFORM_MAX_LENGTH).
     ///
-    /// ### Description
-    /// [**glGetProgramResourceLocationIndex**](crate::context::Context::oxidegl_get_program_resource_location_index)
-    /// returns the fragment color index assigned to the variable named `name`
-    /// in interface `programInterface` of program object `program`. `program`
-    /// must be the name of a program that has been linked successfully. `programInterface`
-    /// must be [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT).
-    ///
-    /// The value -1 will be returned if an error occurs, if `name` does not identify
-    /// an active variable on `programInterface`, or if `name` identifies an active
-    /// variable that does not have a valid location assigned, as described above.
-    /// The locations returned by these commands are the same locations returned
-    /// when querying the [`GL_LOCATION`](crate::gl_enums::GL_LOCATION) and [`GL_LOCATION_INDEX`](crate::gl_enums::GL_LOCATION_INDEX)
-    /// resource properties.
-    ///
-    /// A string provided to [**glGetProgramResourceLocationIndex**](crate::context::Context::oxidegl_get_program_resource_location_index)
-    /// is considered to match an active variable if:
-    ///
-    /// > the string exactly matches the name of the active variable
-    ///
-    /// > if the string identifies the base name of an active array, where the string
-    /// > would exactly match the name of the variable if the suffix "\[0\]" were
-    /// > appended to the string
-    ///
-    /// > if the string identifies an active element of the array, where the string
-    /// > ends with the concatenation of the "\[" character, an integer *with no
-    /// > "+" sign, extra leading zeroes, or whitespace* identifying an array element,
-    /// > and the "\]" character, the integer is less than the number of active elements
-    /// > of the array variable, and where the string would exactly match the enumerated
-    /// > name of the array if the decimal integer were replaced with zero.
-    ///
-    /// Any other string is considered not to identify an active variable. If the
-    /// string specifies an element of an array variable, [**glGetProgramResourceLocation**](crate::context::Context::oxidegl_get_program_resource_location)
-    /// returns the location assigned to that element. If it specifies the base
-    /// name of an array, it identifies the resources associated with the first
-    /// element of the array.
-    pub unsafe fn oxidegl_get_program_resource_location_index(
+    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
+    pub unsafe fn oxidegl_get_active_uniformsiv(
         &mut self,
         program: GLuint,
-        program_interface: ProgramInterface,
-        name: *const GLchar,
-    ) -> GlFallible<GLint> {
-        panic!("command oxidegl_get_program_resource_location_index not yet implemented");
+        uniform_count: GLsizei,
+        uniform_indices: *const GLuint,
+        pname: UniformPName,
+        params: *mut GLint,
+    ) -> GlFallible {
+        panic!("command oxidegl_get_active_uniformsiv not yet implemented");
     }
     /// ### Parameters
     /// `program`
     ///
-    /// > The name of a program object whose resources to query.
-    ///
-    /// `programInterface`
-    ///
-    /// > A token identifying the interface within `program` containing the indexed
-    /// > resource.
-    ///
-    /// `index`
-    ///
-    /// > The index of the resource within `programInterface` of `program`.
+    /// > Specifies the program object to be queried.
     ///
-    /// `bufSize`
+    /// `maxCount`
     ///
-    /// > The size of the character array whose address is given by `name`.
+    /// > Specifies the size of the array for storing the returned object names.
     ///
-    /// `length`
+    /// `count`
     ///
-    /// > The address of a variable which will receive the length of the resource
-    /// > name.
+    /// > Returns the number of names actually returned in `shaders`.
     ///
-    /// `name`
+    /// `shaders`
     ///
-    /// > The address of a character array into which will be written the name of
-    /// > the resource.
+    /// > Specifies an array that is used to return the names of attached shader
+    /// > objects.
     ///
     /// ### Description
-    /// [**glGetProgramResourceName**](crate::context::Context::oxidegl_get_program_resource_name)
-    /// retrieves the name string assigned to the single active resource with an
-    /// index of `index` in the interface `programInterface` of program object
-    /// `program`. `index` must be less than the number of entries in the active
-    /// resource list for `programInterface`.
-    ///
-    /// `program` must be the name of an existing program object. `programInterface`
-    /// is the name of the interface within `program` which contains the resource
-    /// and must be one of the following values:
+    /// [**glGetAttachedShaders**](crate::context::Context::oxidegl_get_attached_shaders)
+    /// returns the names of the shader objects attached to `program`. The names
+    /// of shader objects that are attached to `program` will be returned in `shaders.`
+    /// The actual number of shader names written into `shaders` is returned in
+    /// `count.` If no shader objects are attached to `program`, `count` is set
+    /// to 0. The maximum number of shader names that may be returned in `shaders`
+    /// is specified by `maxCount`.
     ///
-    /// [`GL_UNIFORM`](crate::gl_enums::GL_UNIFORM)
+    /// If the number of names actually returned is not required (for instance,
+    /// if it has just been obtained by calling [**glGetProgram**](crate::context::Context::oxidegl_get_program)
+    /// ), a value of [`NULL`](crate::gl_enums::NULL) may be passed for count.
+    /// If no shader objects are attached to `program`, a value of 0 will be returned
+    /// in `count`. The actual number of attached shaders can be obtained by calling
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with the
+    /// value [`GL_ATTACHED_SHADERS`](crate::gl_enums::GL_ATTACHED_SHADERS).
     ///
-    /// > The query is targeted at the set of active uniforms within `program`.
+    /// ### Associated Gets
+    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
+    /// [`GL_ATTACHED_SHADERS`](crate::gl_enums::GL_ATTACHED_SHADERS)
     ///
-    /// [`GL_UNIFORM_BLOCK`](crate::gl_enums::GL_UNIFORM_BLOCK)
+    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
+    pub unsafe fn oxidegl_get_attached_shaders(
+        &mut self,
+        program: GLuint,
+        max_count: GLsizei,
+        count: *mut GLsizei,
+        shaders: *mut GLuint,
+    ) -> GlFallible {
+        panic!("command oxidegl_get_attached_shaders not yet implemented");
+    }
+    /// ### Parameters
+    /// `texture`
     ///
-    /// > The query is targeted at the set of active uniform blocks within `program`.
+    /// > Specifies the name of the source texture object. Must be [`GL_TEXTURE_1D`](crate::gl_enums::GL_TEXTURE_1D),
+    /// > [`GL_TEXTURE_1D_ARRAY`](crate::gl_enums::GL_TEXTURE_1D_ARRAY), [`GL_TEXTURE_2D`](crate::gl_enums::GL_TEXTURE_2D),
+    /// > [`GL_TEXTURE_2D_ARRAY`](crate::gl_enums::GL_TEXTURE_2D_ARRAY), [`GL_TEXTURE_3D`](crate::gl_enums::GL_TEXTURE_3D),
+    /// > [`GL_TEXTURE_CUBE_MAP`](crate::gl_enums::GL_TEXTURE_CUBE_MAP), [`GL_TEXTURE_CUBE_MAP_ARRAY`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_ARRAY)
+    /// > or [`GL_TEXTURE_RECTANGLE`](crate::gl_enums::GL_TEXTURE_RECTANGLE). In
+    /// > specific, buffer and multisample textures are not permitted.
     ///
-    /// [`GL_PROGRAM_INPUT`](crate::gl_enums::GL_PROGRAM_INPUT)
+    /// `level`
     ///
-    /// > The query is targeted at the set of active input variables used by the
-    /// > first shader stage of `program`. If `program` contains multiple shader
-    /// > stages then input variables from any stage other than the first will not
-    /// > be enumerated.
+    /// > Specifies the level-of-detail number. Level 0 is the base image level.
+    /// > Level $n$ is the $n$th mipmap reduction image.
     ///
-    /// [`GL_PROGRAM_OUTPUT`](crate::gl_enums::GL_PROGRAM_OUTPUT)
+    /// `xoffset`
     ///
-    /// > The query is targeted at the set of active output variables produced by
-    /// > the last shader stage of `program`. If `program` contains multiple shader
-    /// > stages then output variables from any stage other than the last will not
-    /// > be enumerated.
+    /// > Specifies a texel offset in the x direction within the texture array.
     ///
-    /// [`GL_VERTEX_SUBROUTINE`](crate::gl_enums::GL_VERTEX_SUBROUTINE)
+    /// `yoffset`
     ///
-    /// [`GL_TESS_CONTROL_SUBROUTINE`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE)
+    /// > Specifies a texel offset in the y direction within the texture array.
     ///
-    /// [`GL_TESS_EVALUATION_SUBROUTINE`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE)
+    /// `zoffset`
     ///
-    /// [`GL_GEOMETRY_SUBROUTINE`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE)
+    /// > Specifies a texel offset in the z direction within the texture array.
     ///
-    /// [`GL_FRAGMENT_SUBROUTINE`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE)
+    /// `width`
     ///
-    /// [`GL_COMPUTE_SUBROUTINE`](crate::gl_enums::GL_COMPUTE_SUBROUTINE)
+    /// > Specifies the width of the texture subimage. Must be a multiple of the
+    /// > compressed block's width, unless the `offset` is zero and the size equals
+    /// > the texture image size.
     ///
-    /// > The query is targeted at the set of active subroutines for the vertex,
-    /// > tessellation control, tessellation evaluation, geometry, fragment and compute
-    /// > shader stages of `program`, respectively.
+    /// `height`
     ///
-    /// [`GL_VERTEX_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_VERTEX_SUBROUTINE_UNIFORM)
+    /// > Specifies the height of the texture subimage. Must be a multiple of the
+    /// > compressed block's height, unless the `offset` is zero and the size equals
+    /// > the texture image size.
     ///
-    /// [`GL_TESS_CONTROL_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_CONTROL_SUBROUTINE_UNIFORM)
+    /// `depth`
     ///
-    /// [`GL_TESS_EVALUATION_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_TESS_EVALUATION_SUBROUTINE_UNIFORM)
+    /// > Specifies the depth of the texture subimage. Must be a multiple of the
+    /// > compressed block's depth, unless the `offset` is zero and the size equals
+    /// > the texture image size.
     ///
-    /// [`GL_GEOMETRY_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_GEOMETRY_SUBROUTINE_UNIFORM)
+    /// `bufSize`
     ///
-    /// [`GL_FRAGMENT_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_FRAGMENT_SUBROUTINE_UNIFORM)
+    /// > Specifies the size of the buffer to receive the retrieved pixel data.
     ///
-    /// [`GL_COMPUTE_SUBROUTINE_UNIFORM`](crate::gl_enums::GL_COMPUTE_SUBROUTINE_UNIFORM)
+    /// `pixels`
     ///
-    /// > The query is targeted at the set of active subroutine uniform variables
-    /// > used by the vertex, tessellation control, tessellation evaluation, geometry,
-    /// > fragment and compute shader stages of `program`, respectively.
+    /// > Returns the texture subimage. Should be a pointer to an array of the type
+    /// > specified by type.
     ///
-    /// [`GL_TRANSFORM_FEEDBACK_VARYING`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING)
+    /// ### Description
+    /// [**glGetCompressedTextureSubImage**](crate::context::Context::oxidegl_get_compressed_texture_sub_image)
+    /// can be used to obtain a sub-region of a compressed texture image instead
+    /// of the whole image, as long as the compressed data are arranged into fixed-size
+    /// blocks of texels. `texture` is the name of the texture object, and must
+    /// not be a buffer or multisample texture. The effective `target` is the value
+    /// of [`GL_TEXTURE_TARGET`](crate::gl_enums::GL_TEXTURE_TARGET) for texture.
+    /// `level` and `pixels` have the same meaning as the corresponding arguments
+    /// of [**glCompressedTexSubImage3D**](crate::context::Context::oxidegl_compressed_tex_sub_image3_d).
+    /// `bufSize` indicates the size of the buffer to receive the retrieved pixel
+    /// data.
     ///
-    /// > The query is targeted at the set of output variables from the last non-fragment
-    /// > stage of `program` that would be captured if transform feedback were active.
+    /// For cube map textures, the behavior is as though [**glGetCompressedTexImage**](crate::context::Context::oxidegl_get_compressed_tex_image)
+    /// were called once for each requested face (selected by `zoffset` and `depth`,
+    /// as described below) with target corresponding to the requested texture
+    /// cube map face as indicated by the table presented below. `pixels` is offset
+    /// appropriately for each successive image.
     ///
-    /// [`GL_BUFFER_VARIABLE`](crate::gl_enums::GL_BUFFER_VARIABLE)
+    /// | Layer number        | Cube Map Face        |
+    /// |---------------------|----------------------|
+    /// | 0                   | [`GL_TEXTURE_CUBE_MAP_POSITIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_X) |
+    /// | 1                   | [`GL_TEXTURE_CUBE_MAP_NEGATIVE_X`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_X) |
+    /// | 2                   | [`GL_TEXTURE_CUBE_MAP_POSITIVE_Y`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Y) |
+    /// | 3                   | [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Y) |
+    /// | 4                   | [`GL_TEXTURE_CUBE_MAP_POSITIVE_Z`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_POSITIVE_Z) |
+    /// | 5                   | [`GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`](crate::gl_enums::GL_TEXTURE_CUBE_MAP_NEGATIVE_Z) |
     ///
-    /// > The query is targeted at the set of active buffer variables used by `program`.
+    /// `xoffset`, `yoffset` and `zoffset` indicate the position of the subregion
+    /// to return. `width`, `height` and `depth` indicate the size of the region
+    /// to return. These arguments have the same meaning as for [**glCompressedTexSubImage3D**](crate::context::Context::oxidegl_compressed_tex_sub_image3_d),
+    /// though there are extra restrictions, described in the errors section below.
     ///
-    /// [`GL_SHADER_STORAGE_BLOCK`](crate::gl_enums::GL_SHADER_STORAGE_BLOCK)
+    /// The mapping between the `xoffset`, `yoffset`, `zoffset`, `width`, `height`
+    /// and `depth` parameters and the faces, layers, and layer-faces for cube
+    /// map, array, and cube map array textures is the same as for glGetTextureSubImage.
     ///
-    /// > The query is targeted at the set of active shader storage blocks used by
-    /// > `program`.
+    /// The `xoffset`, `yoffset`, `zoffset` offsets and `width`, `height` and `depth`
+    /// sizes must be multiples of the values of [`GL_PACK_COMPRESSED_BLOCK_WIDTH`](crate::gl_enums::GL_PACK_COMPRESSED_BLOCK_WIDTH),
+    /// [`GL_PACK_COMPRESSED_BLOCK_HEIGHT`](crate::gl_enums::GL_PACK_COMPRESSED_BLOCK_HEIGHT),
+    /// and [`GL_PACK_COMPRESSED_BLOCK_DEPTH`](crate::gl_enums::GL_PACK_COMPRESSED_BLOCK_DEPTH)
+    /// respectively, unless `offset` is zero and the corresponding `size` is the
+    /// same as the texture size in that dimension.
     ///
-    /// The name string assigned to the active resource identified by `index` is
-    /// returned as a null-terminated string in the character array whose address
-    /// is given in `name`. The actual number of characters written into `name`,
-    /// excluding the null terminator, is returned in `length`. If `length` is
-    /// NULL, no length is returned. The maximum number of characters that may
-    /// be written into `name`, including the null terminator, is specified by
-    /// `bufSize`. If the length of the name string *including the null terminator*
-    /// is greater than `bufSize`, the first `bufSize` -1 characters of the name
-    /// string will be written to `name`, followed by a null terminator. If `bufSize`
-    /// is zero, no error will be generated but no characters will be written to
-    /// `name`. The length of the longest name string for `programInterface` >,
-    /// including a null terminator, can be queried by calling [**glGetProgramInterface**](crate::context::Context::oxidegl_get_program_interface)
-    /// with a `pname` of [`GL_MAX_NAME_LENGTH`](crate::gl_enums::GL_MAX_NAME_LENGTH).
-    pub unsafe fn oxidegl_get_program_resource_name(
+    /// Pixel storage modes are treated as for [**glGetCompressedTexSubImage**](crate::context::Context::oxidegl_get_compressed_tex_sub_image).
+    /// The texel at( `xoffset`, `yoffset`, `zoffset`) will be stored at the location
+    /// indicated by `pixels` and the current pixel packing parameters.
+    pub unsafe fn oxidegl_get_compressed_texture_sub_image(
         &mut self,
-        program: GLuint,
-        program_interface: ProgramInterface,
-        index: GLuint,
+        texture: GLuint,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
         buf_size: GLsizei,
-        length: *mut GLsizei,
-        name: *mut GLchar,
+        pixels: *mut GLvoid,
     ) -> GlFallible {
-        panic!("command oxidegl_get_program_resource_name not yet implemented");
+        panic!("command oxidegl_get_compressed_texture_sub_image not yet implemented");
     }
-    /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the name of the program containing shader stage.
-    ///
-    /// `shadertype`
-    ///
-    /// > Specifies the shader stage from which to query for the subroutine parameter.
-    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
-    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
-    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
-    ///
-    /// `pname`
-    ///
-    /// > Specifies the parameter of the shader to query. `pname` must be [`GL_ACTIVE_SUBROUTINE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORMS),
-    /// > [`GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS),
-    /// > [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES), [`GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH),
-    /// > or [`GL_ACTIVE_SUBROUTINE_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_MAX_LENGTH).
-    ///
-    /// `values`
-    ///
-    /// > Specifies the address of a variable into which the queried value or values
-    /// > will be placed.
-    ///
     /// ### Description
-    /// [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage)
-    /// queries a parameter of a shader stage attached to a program object. `program`
-    /// contains the name of the program to which the shader is attached. `shadertype`
-    /// specifies the stage from which to query the parameter. `pname` specifies
-    /// which parameter should be queried. The value or values of the parameter
-    /// to be queried is returned in the variable whose address is given in `values`.
-    ///
-    /// If `pname` is [`GL_ACTIVE_SUBROUTINE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORMS),
-    /// the number of active subroutine variables in the stage is returned in
-    /// `values`.
-    ///
-    /// If `pname` is [`GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS),
-    /// the number of active subroutine variable locations in the stage is returned
-    /// in `values`.
-    ///
-    /// If `pname` is [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES),
-    /// the number of active subroutines in the stage is returned in `values`.
-    ///
-    /// If `pname` is [`GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_MAX_LENGTH),
-    /// the length of the longest subroutine uniform for the stage is returned
-    /// in `values`.
-    ///
-    /// If `pname` is [`GL_ACTIVE_SUBROUTINE_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_MAX_LENGTH),
-    /// the length of the longest subroutine name for the stage is returned in
-    /// `values`. The returned name length includes space for the null-terminator.
-    ///
-    /// If there is no shader present of type `shadertype`, the returned value
-    /// will be consistent with a shader containing no subroutines or subroutine
-    /// uniforms.
-    pub unsafe fn oxidegl_get_program_stageiv(
-        &mut self,
-        program: GLuint,
-        shadertype: ShaderType,
-        pname: ProgramStagePName,
-        values: *mut GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_program_stageiv not yet implemented");
-    }
-    /// ### Parameters
-    /// `target`
+    /// Certain events can result in a reset of the GL context. Such a reset causes
+    /// all context state to be lost and requires the application to recreate all
+    /// objects in the affected context.
     ///
-    /// > Specifies a query object target. Must be [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
-    /// > [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE)
-    /// > [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED), [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
-    /// > [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED), or [`GL_TIMESTAMP`](crate::gl_enums::GL_TIMESTAMP).
+    /// [**glGetGraphicsResetStatus**](crate::context::Context::oxidegl_get_graphics_reset_status)
+    /// can return one of the following constants:
     ///
-    /// `index`
+    /// [`GL_NO_ERROR`](crate::gl_enums::GL_NO_ERROR)
     ///
-    /// > Specifies the index of the query object target.
+    /// > Indicates that the GL context has not been in a reset state since the last
+    /// > call.
     ///
-    /// `pname`
+    /// [`GL_GUILTY_CONTEXT_RESET`](crate::gl_enums::GL_GUILTY_CONTEXT_RESET)
     ///
-    /// > Specifies the symbolic name of a query object target parameter. Accepted
-    /// > values are [`GL_CURRENT_QUERY`](crate::gl_enums::GL_CURRENT_QUERY) or [`GL_QUERY_COUNTER_BITS`](crate::gl_enums::GL_QUERY_COUNTER_BITS).
+    /// > Indicates that a reset has been detected that is attributable to the current
+    /// > GL context.
     ///
-    /// `params`
+    /// [`GL_INNOCENT_CONTEXT_RESET`](crate::gl_enums::GL_INNOCENT_CONTEXT_RESET)
     ///
-    /// > Returns the requested data.
+    /// > Indicates a reset has been detected that is not attributable to the current
+    /// > GL context.
     ///
-    /// ### Description
-    /// [**glGetQueryIndexediv**](crate::context::Context::oxidegl_get_query_indexediv)
-    /// returns in `params` a selected parameter of the indexed query object target
-    /// specified by `target` and `index`. `index` specifies the index of the query
-    /// object target and must be between zero and a target-specific maxiumum.
-    ///
-    /// `pname` names a specific query object target parameter. When `pname` is
-    /// [`GL_CURRENT_QUERY`](crate::gl_enums::GL_CURRENT_QUERY), the name of the
-    /// currently active query for the specified `index` of `target`, or zero if
-    /// no query is active, will be placed in `params`. If `pname` is [`GL_QUERY_COUNTER_BITS`](crate::gl_enums::GL_QUERY_COUNTER_BITS),
-    /// the implementation-dependent number of bits used to hold the result of
-    /// queries for `target` is returned in `params`.
+    /// [`GL_UNKNOWN_CONTEXT_RESET`](crate::gl_enums::GL_UNKNOWN_CONTEXT_RESET)
     ///
-    /// ### Notes
-    /// The target [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE)
-    /// is available only if the GL version is 4.3 or greater.
+    /// > Indicates a detected graphics reset whose cause is unknown.
     ///
-    /// If an error is generated, no change is made to the contents of `params`.
+    /// If a reset status other than [`GL_NO_ERROR`](crate::gl_enums::GL_NO_ERROR)
+    /// is returned and subsequent calls return [`GL_NO_ERROR`](crate::gl_enums::GL_NO_ERROR),
+    /// the context reset was encountered and completed. If a reset status is
+    /// repeatedly returned, the context may be in the process of resetting.
     ///
-    /// Calling [**glGetQueryiv**](crate::context::Context::oxidegl_get_queryiv)
-    /// is equivalent to calling [**glGetQueryIndexediv**](crate::context::Context::oxidegl_get_query_indexediv)
-    /// with `index` set to zero.
-    pub unsafe fn oxidegl_get_query_indexediv(
-        &mut self,
-        target: QueryTarget,
-        index: GLuint,
-        pname: QueryParameterName,
-        params: *mut GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_query_indexediv not yet implemented");
-    }
-    /// ### Parameters
-    /// `target`
+    /// Reset notification behavior is determined at context creation time, and
+    /// may be queried by calling [**`GetIntegerv`**](crate::context::Context::oxide_get_integerv)
+    /// with the symbolic constant [`GL_RESET_NOTIFICATION_STRATEGY`](crate::gl_enums::GL_RESET_NOTIFICATION_STRATEGY).
     ///
-    /// > Specifies a query object target. Must be [`GL_SAMPLES_PASSED`](crate::gl_enums::GL_SAMPLES_PASSED),
-    /// > [`GL_ANY_SAMPLES_PASSED`](crate::gl_enums::GL_ANY_SAMPLES_PASSED), [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE)
-    /// > [`GL_PRIMITIVES_GENERATED`](crate::gl_enums::GL_PRIMITIVES_GENERATED), [`GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN),
-    /// > [`GL_TIME_ELAPSED`](crate::gl_enums::GL_TIME_ELAPSED), or [`GL_TIMESTAMP`](crate::gl_enums::GL_TIMESTAMP).
+    /// If the reset notification behavior is [`GL_NO_RESET_NOTIFICATION`](crate::gl_enums::GL_NO_RESET_NOTIFICATION),
+    /// then the implementation will never deliver notification of reset events,
+    /// and [**glGetGraphicsResetStatus**](crate::context::Context::oxidegl_get_graphics_reset_status)
+    /// will always return [`GL_NO_ERROR`](crate::gl_enums::GL_NO_ERROR).
     ///
-    /// `pname`
+    /// If the behavior is [`GL_LOSE_CONTEXT_ON_RESET`](crate::gl_enums::GL_LOSE_CONTEXT_ON_RESET),
+    /// a graphics reset will result in the loss of all context state, requiring
+    /// the recreation of all associated objects. In this case [**glGetGraphicsResetStatus**](crate::context::Context::oxidegl_get_graphics_reset_status)
+    /// may return any of the values described above.
     ///
-    /// > Specifies the symbolic name of a query object target parameter. Accepted
-    /// > values are [`GL_CURRENT_QUERY`](crate::gl_enums::GL_CURRENT_QUERY) or [`GL_QUERY_COUNTER_BITS`](crate::gl_enums::GL_QUERY_COUNTER_BITS).
+    /// If a graphics reset notification occurs in a context, a notification must
+    /// also occur in all other contexts which share objects with that context.
     ///
-    /// `params`
+    /// After a graphics reset has occurred on a context, subsequent GL commands
+    /// on that context (or any context which shares with that context) will generate
+    /// a [`GL_CONTEXT_LOST`](crate::gl_enums::GL_CONTEXT_LOST) error. Such commands
+    /// will not have side effects (in particular, they will not modify memory
+    /// passed by pointer for query results), and will not block indefinitely or
+    /// cause termination of the application. There are two important exceptions
+    /// to this behavior:
     ///
-    /// > Returns the requested data.
+    /// > [**glGetError**](crate::context::Context::oxidegl_get_error) and [**glGetGraphicsResetStatus**](crate::context::Context::oxidegl_get_graphics_reset_status)
+    /// > behave normally following a graphics reset, so that the application can
+    /// > determine a reset has occurred, and when it is safe to destroy and re-create
+    /// > the context.
     ///
-    /// ### Description
-    /// [**glGetQueryiv**](crate::context::Context::oxidegl_get_queryiv) returns
-    /// in `params` a selected parameter of the query object target specified by
-    /// `target`.
+    /// > Any commands which might cause a polling application to block indefinitely
+    /// > will generate a [`GL_CONTEXT_LOST`](crate::gl_enums::GL_CONTEXT_LOST) error,
+    /// > but will also return a value indicating completion to the application.
+    /// > Such commands include: [**glGetSynciv**](crate::context::Context::oxidegl_get_synciv)
+    /// >> with pname [`GL_SYNC_STATUS`](crate::gl_enums::GL_SYNC_STATUS) ignores
+    /// >> the other parameters and returns [`GL_SIGNALED`](crate::gl_enums::GL_SIGNALED)
+    /// >> in `values`.
     ///
-    /// `pname` names a specific query object target parameter. When `pname` is
-    /// [`GL_CURRENT_QUERY`](crate::gl_enums::GL_CURRENT_QUERY), the name of the
-    /// currently active query for `target`, or zero if no query is active, will
-    /// be placed in `params`. If `pname` is [`GL_QUERY_COUNTER_BITS`](crate::gl_enums::GL_QUERY_COUNTER_BITS),
-    /// the implementation-dependent number of bits used to hold the result of
-    /// queries for `target` is returned in `params`.
+    /// >> [**glGetQueryObjectuiv**](crate::context::Context::oxidegl_get_query_objectuiv)
+    /// >> with pname [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE)
+    /// >> ignores the other parameters and returns [`TRUE`](crate::gl_enums::TRUE)
+    /// >> in `params`.
     ///
-    /// ### Notes
-    /// The target [`GL_ANY_SAMPLES_PASSED_CONSERVATIVE`](crate::gl_enums::GL_ANY_SAMPLES_PASSED_CONSERVATIVE)
-    /// is available only if the GL version is 4.3 or greater.
     ///
-    /// If an error is generated, no change is made to the contents of `params`.
-    pub unsafe fn oxidegl_get_queryiv(
-        &mut self,
-        target: QueryTarget,
-        pname: QueryParameterName,
-        params: *mut GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_queryiv not yet implemented");
+    pub fn oxidegl_get_graphics_reset_status(&mut self) -> GlFallible<GLenum> {
+        panic!("command oxidegl_get_graphics_reset_status not yet implemented");
     }
     /// ### Parameters
-    /// `shader`
-    ///
-    /// > Specifies the shader object whose information log is to be queried.
-    ///
-    /// `maxLength`
+    /// `pname`
     ///
-    /// > Specifies the size of the character buffer for storing the returned information
-    /// > log.
+    /// > Specifies the sample parameter name. `pname` must be [`GL_SAMPLE_POSITION`](crate::gl_enums::GL_SAMPLE_POSITION).
     ///
-    /// `length`
+    /// `index`
     ///
-    /// > Returns the length of the string returned in `infoLog` (excluding the null
-    /// > terminator).
+    /// > Specifies the index of the sample whose position to query.
     ///
-    /// `infoLog`
+    /// `val`
     ///
-    /// > Specifies an array of characters that is used to return the information
-    /// > log.
+    /// > Specifies the address of an array to receive the position of the sample.
     ///
     /// ### Description
-    /// [**glGetShaderInfoLog**](crate::context::Context::oxidegl_get_shader_info_log)
-    /// returns the information log for the specified shader object. The information
-    /// log for a shader object is modified when the shader is compiled. The string
-    /// that is returned will be null terminated.
-    ///
-    /// [**glGetShaderInfoLog**](crate::context::Context::oxidegl_get_shader_info_log)
-    /// returns in `infoLog` as much of the information log as it can, up to a
-    /// maximum of `maxLength` characters. The number of characters actually returned,
-    /// excluding the null termination character, is specified by `length`. If
-    /// the length of the returned string is not required, a value of [`NULL`](crate::gl_enums::NULL)
-    /// can be passed in the `length` argument. The size of the buffer required
-    /// to store the returned information log can be obtained by calling [**glGetShader**](crate::context::Context::oxidegl_get_shader)
-    /// with the value [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH).
-    ///
-    /// The information log for a shader object is a string that may contain diagnostic
-    /// messages, warning messages, and other information about the last compile
-    /// operation. When a shader object is created, its information log will be
-    /// a string of length 0.
-    ///
-    /// ### Notes
-    /// The information log for a shader object is the OpenGL implementer's primary
-    /// mechanism for conveying information about the compilation process. Therefore,
-    /// the information log can be helpful to application developers during the
-    /// development process, even when compilation is successful. Application developers
-    /// should not expect different OpenGL implementations to produce identical
-    /// information logs.
-    ///
-    /// ### Associated Gets
-    /// [**glGetShader**](crate::context::Context::oxidegl_get_shader) with argument
-    /// [`GL_INFO_LOG_LENGTH`](crate::gl_enums::GL_INFO_LOG_LENGTH)
+    /// [**glGetMultisamplefv**](crate::context::Context::oxidegl_get_multisamplefv)
+    /// queries the location of a given sample. `pname` specifies the sample parameter
+    /// to retrieve and must be [`GL_SAMPLE_POSITION`](crate::gl_enums::GL_SAMPLE_POSITION).
+    /// `index` corresponds to the sample for which the location should be returned.
+    /// The sample location is returned as two floating-point values in `val[0]`
+    /// and `val[1]`, each between 0 and 1, corresponding to the `x` and `y` locations
+    /// respectively in the GL pixel space of that sample. (0.5, 0.5) this corresponds
+    /// to the pixel center. `index` must be between zero and the value of [`GL_SAMPLES`](crate::gl_enums::GL_SAMPLES)
+    /// minus one.
     ///
-    /// [**glIsShader**](crate::context::Context::oxidegl_is_shader)
-    pub unsafe fn oxidegl_get_shader_info_log(
+    /// If the multisample mode does not have fixed sample locations, the returned
+    /// values may only reflect the locations of samples within some pixels.
+    pub unsafe fn oxidegl_get_multisamplefv(
         &mut self,
-        shader: GLuint,
-        buf_size: GLsizei,
-        length: *mut GLsizei,
-        info_log: *mut GLchar,
+        pname: GLenum,
+        index: GLuint,
+        val: *mut GLfloat,
     ) -> GlFallible {
-        panic!("command oxidegl_get_shader_info_log not yet implemented");
+        panic!("command oxidegl_get_multisamplefv not yet implemented");
     }
     /// ### Parameters
     /// `shaderType`
@@ -17326,132 +12809,6 @@ impl Context {
         panic!("command oxidegl_get_shader_precision_format not yet implemented");
     }
     /// ### Parameters
-    /// `shader`
-    ///
-    /// > Specifies the shader object to be queried.
-    ///
-    /// `bufSize`
-    ///
-    /// > Specifies the size of the character buffer for storing the returned source
-    /// > code string.
-    ///
-    /// `length`
-    ///
-    /// > Returns the length of the string returned in `source` (excluding the null
-    /// > terminator).
-    ///
-    /// `source`
-    ///
-    /// > Specifies an array of characters that is used to return the source code
-    /// > string.
-    ///
-    /// ### Description
-    /// [**glGetShaderSource**](crate::context::Context::oxidegl_get_shader_source)
-    /// returns the concatenation of the source code strings from the shader object
-    /// specified by `shader`. The source code strings for a shader object are
-    /// the result of a previous call to [**glShaderSource**](crate::context::Context::oxidegl_shader_source).
-    /// The string returned by the function will be null terminated.
-    ///
-    /// [**glGetShaderSource**](crate::context::Context::oxidegl_get_shader_source)
-    /// returns in `source` as much of the source code string as it can, up to
-    /// a maximum of `bufSize` characters. The number of characters actually returned,
-    /// excluding the null termination character, is specified by `length`. If
-    /// the length of the returned string is not required, a value of [`NULL`](crate::gl_enums::NULL)
-    /// can be passed in the `length` argument. The size of the buffer required
-    /// to store the returned source code string can be obtained by calling [**glGetShader**](crate::context::Context::oxidegl_get_shader)
-    /// with the value [`GL_SHADER_SOURCE_LENGTH`](crate::gl_enums::GL_SHADER_SOURCE_LENGTH).
-    ///
-    /// ### Associated Gets
-    /// [**glGetShader**](crate::context::Context::oxidegl_get_shader) with argument
-    /// [`GL_SHADER_SOURCE_LENGTH`](crate::gl_enums::GL_SHADER_SOURCE_LENGTH)
-    ///
-    /// [**glIsShader**](crate::context::Context::oxidegl_is_shader)
-    pub unsafe fn oxidegl_get_shader_source(
-        &mut self,
-        shader: GLuint,
-        buf_size: GLsizei,
-        length: *mut GLsizei,
-        source: *mut GLchar,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_shader_source not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the name of the program containing shader stage.
-    ///
-    /// `shadertype`
-    ///
-    /// > Specifies the shader stage from which to query for subroutine uniform index.
-    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
-    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
-    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
-    ///
-    /// `name`
-    ///
-    /// > Specifies the name of the subroutine uniform whose index to query.
-    ///
-    /// ### Description
-    /// [**glGetSubroutineIndex**](crate::context::Context::oxidegl_get_subroutine_index)
-    /// returns the index of a subroutine uniform within a shader stage attached
-    /// to a program object. `program` contains the name of the program to which
-    /// the shader is attached. `shadertype` specifies the stage from which to
-    /// query shader subroutine index. `name` contains the null-terminated name
-    /// of the subroutine uniform whose name to query.
-    ///
-    /// If `name` is not the name of a subroutine uniform in the shader stage,
-    /// [`GL_INVALID_INDEX`](crate::gl_enums::GL_INVALID_INDEX) is returned, but
-    /// no error is generated. If `name` is the name of a subroutine uniform in
-    /// the shader stage, a value between zero and the value of [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES)
-    /// minus one will be returned. Subroutine indices are assigned using consecutive
-    /// integers in the range from zero to the value of [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES)
-    /// minus one for the shader stage.
-    pub unsafe fn oxidegl_get_subroutine_index(
-        &mut self,
-        program: GLuint,
-        shadertype: ShaderType,
-        name: *const GLchar,
-    ) -> GlFallible<GLuint> {
-        panic!("command oxidegl_get_subroutine_index not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the name of the program containing shader stage.
-    ///
-    /// `shadertype`
-    ///
-    /// > Specifies the shader stage from which to query for subroutine uniform index.
-    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
-    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
-    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
-    ///
-    /// `name`
-    ///
-    /// > Specifies the name of the subroutine uniform whose index to query.
-    ///
-    /// ### Description
-    /// [**glGetSubroutineUniformLocation**](crate::context::Context::oxidegl_get_subroutine_uniform_location)
-    /// returns the location of the subroutine uniform variable `name` in the shader
-    /// stage of type `shadertype` attached to `program`, with behavior otherwise
-    /// identical to [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location).
-    ///
-    /// If `name` is not the name of a subroutine uniform in the shader stage,
-    /// -1 is returned, but no error is generated. If `name` is the name of a subroutine
-    /// uniform in the shader stage, a value between zero and the value of [`GL_ACTIVE_SUBROUTINE_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_LOCATIONS)
-    /// minus one will be returned. Subroutine locations are assigned using consecutive
-    /// integers in the range from zero to the value of [`GL_ACTIVE_SUBROUTINE_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_LOCATIONS)
-    /// minus one for the shader stage. For active subroutine uniforms declared
-    /// as arrays, the declared array elements are assigned consecutive locations.
-    pub unsafe fn oxidegl_get_subroutine_uniform_location(
-        &mut self,
-        program: GLuint,
-        shadertype: ShaderType,
-        name: *const GLchar,
-    ) -> GlFallible<GLint> {
-        panic!("command oxidegl_get_subroutine_uniform_location not yet implemented");
-    }
-    /// ### Parameters
     /// `sync`
     ///
     /// > Specifies the sync object whose properties to query.
@@ -17657,84 +13014,6 @@ impl Context {
     /// ### Parameters
     /// `program`
     ///
-    /// > The name of the target program object.
-    ///
-    /// `index`
-    ///
-    /// > The index of the varying variable whose information to retrieve.
-    ///
-    /// `bufSize`
-    ///
-    /// > The maximum number of characters, including the null terminator, that may
-    /// > be written into `name`.
-    ///
-    /// `length`
-    ///
-    /// > The address of a variable which will receive the number of characters written
-    /// > into `name`, excluding the null-terminator. If `length` is [`NULL`](crate::gl_enums::NULL)
-    /// > no length is returned.
-    ///
-    /// `size`
-    ///
-    /// > The address of a variable that will receive the size of the varying.
-    ///
-    /// `type`
-    ///
-    /// > The address of a variable that will receive the type of the varying.
-    ///
-    /// `name`
-    ///
-    /// > The address of a buffer into which will be written the name of the varying.
-    ///
-    /// ### Description
-    /// Information about the set of varying variables in a linked program that
-    /// will be captured during transform feedback may be retrieved by calling
-    /// [**glGetTransformFeedbackVarying**](crate::context::Context::oxidegl_get_transform_feedback_varying).
-    /// [**glGetTransformFeedbackVarying**](crate::context::Context::oxidegl_get_transform_feedback_varying)
-    /// provides information about the varying variable selected by `index`. An
-    /// `index` of 0 selects the first varying variable specified in the `varyings`
-    /// array passed to [**glTransformFeedbackVaryings**](crate::context::Context::oxidegl_transform_feedback_varyings),
-    /// and an `index` of the value of [`GL_TRANSFORM_FEEDBACK_VARYINGS`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYINGS)
-    /// minus one selects the last such variable.
-    ///
-    /// The name of the selected varying is returned as a null-terminated string
-    /// in `name`. The actual number of characters written into `name`, excluding
-    /// the null terminator, is returned in `length`. If `length` is NULL, no length
-    /// is returned. The maximum number of characters that may be written into
-    /// `name`, including the null terminator, is specified by `bufSize`.
-    ///
-    /// The length of the longest varying name in program is given by [`GL_TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH),
-    /// which can be queried with [**glGetProgram**](crate::context::Context::oxidegl_get_program).
-    ///
-    /// For the selected varying variable, its type is returned into `type`. The
-    /// size of the varying is returned into `size`. The value in `size` is in
-    /// units of the type returned in `type`. The type returned can be any of the
-    /// scalar, vector, or matrix attribute types returned by [**glGetActiveAttrib**](crate::context::Context::oxidegl_get_active_attrib).
-    /// If an error occurred, the return parameters `length`, `size`, `type` and
-    /// `name` will be unmodified. This command will return as much information
-    /// about the varying variables as possible. If no information is available,
-    /// `length` will be set to zero and `name` will be an empty string. This situation
-    /// could arise if [**glGetTransformFeedbackVarying**](crate::context::Context::oxidegl_get_transform_feedback_varying)
-    /// is called after a failed link.
-    ///
-    /// ### Associated Gets
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
-    /// [`GL_TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH`](crate::gl_enums::GL_TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH).
-    pub unsafe fn oxidegl_get_transform_feedback_varying(
-        &mut self,
-        program: GLuint,
-        index: GLuint,
-        buf_size: GLsizei,
-        length: *mut GLsizei,
-        size: *mut GLsizei,
-        r#type: *mut AttributeType,
-        name: *mut GLchar,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_transform_feedback_varying not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
-    ///
     /// > Specifies the name of a program containing the uniform block.
     ///
     /// `uniformBlockName`
@@ -17825,100 +13104,6 @@ impl Context {
         panic!("command oxidegl_get_uniform_indices not yet implemented");
     }
     /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the program object to be queried.
-    ///
-    /// `name`
-    ///
-    /// > Points to a null terminated string containing the name of the uniform variable
-    /// > whose location is to be queried.
-    ///
-    /// ### Description
-    /// [**glGetUniformLocation **](crate::context::Context::oxidegl_get_uniform_location
-    ///) returns an integer that represents the location of a specific uniform
-    /// variable within a program object. `name` must be a null terminated string
-    /// that contains no white space. `name` must be an active uniform variable
-    /// name in `program` that is not a structure, an array of structures, or a
-    /// subcomponent of a vector or a matrix. This function returns -1 if `name`
-    /// does not correspond to an active uniform variable in `program`, if `name`
-    /// starts with the reserved prefix "gl_", or if `name` is associated with
-    /// an atomic counter or a named uniform block.
-    ///
-    /// Uniform variables that are structures or arrays of structures may be queried
-    /// by calling [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location)
-    /// for each field within the structure. The array element operator "\[\]"
-    /// and the structure field operator "." may be used in `name` in order to
-    /// select elements within an array or fields within a structure. The result
-    /// of using these operators is not allowed to be another structure, an array
-    /// of structures, or a subcomponent of a vector or a matrix. Except if the
-    /// last part of `name` indicates a uniform variable array, the location of
-    /// the first element of an array can be retrieved by using the name of the
-    /// array, or by using the name appended by "\[0\]".
-    ///
-    /// The actual locations assigned to uniform variables are not known until
-    /// the program object is linked successfully. After linking has occurred,
-    /// the command [**glGetUniformLocation**](crate::context::Context::oxidegl_get_uniform_location)
-    /// can be used to obtain the location of a uniform variable. This location
-    /// value can then be passed to [**glUniform**](crate::context::Context::oxidegl_uniform)
-    /// to set the value of the uniform variable or to [**glGetUniform**](crate::context::Context::oxidegl_get_uniform)
-    /// in order to query the current value of the uniform variable. After a program
-    /// object has been linked successfully, the index values for uniform variables
-    /// remain fixed until the next link command occurs. Uniform variable locations
-    /// and values can only be queried after a link if the link was successful.
-    ///
-    /// ### Associated Gets
-    /// [**glGetActiveUniform**](crate::context::Context::oxidegl_get_active_uniform)
-    /// with arguments `program` and the index of an active uniform variable
-    ///
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with arguments
-    /// `program` and [`GL_ACTIVE_UNIFORMS`](crate::gl_enums::GL_ACTIVE_UNIFORMS)
-    /// or [`GL_ACTIVE_UNIFORM_MAX_LENGTH`](crate::gl_enums::GL_ACTIVE_UNIFORM_MAX_LENGTH)
-    ///
-    /// [**glGetUniform**](crate::context::Context::oxidegl_get_uniform) with arguments
-    /// `program` and the name of a uniform variable
-    ///
-    /// [**glIsProgram**](crate::context::Context::oxidegl_is_program)
-    pub unsafe fn oxidegl_get_uniform_location(
-        &mut self,
-        program: GLuint,
-        name: *const GLchar,
-    ) -> GlFallible<GLint> {
-        panic!("command oxidegl_get_uniform_location not yet implemented");
-    }
-    /// ### Parameters
-    /// `shadertype`
-    ///
-    /// > Specifies the shader stage from which to query for subroutine uniform index.
-    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
-    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
-    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
-    ///
-    /// `location`
-    ///
-    /// > Specifies the location of the subroutine uniform.
-    ///
-    /// `values`
-    ///
-    /// > Specifies the address of a variable to receive the value or values of the
-    /// > subroutine uniform.
-    ///
-    /// ### Description
-    /// [**glGetUniformSubroutine**](crate::context::Context::oxidegl_get_uniform_subroutine)
-    /// retrieves the value of the subroutine uniform at location `location` for
-    /// shader stage `shadertype` of the current program. `location` must be less
-    /// than the value of [`GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS)
-    /// for the shader currently in use at shader stage `shadertype`. The value
-    /// of the subroutine uniform is returned in `values`.
-    pub unsafe fn oxidegl_get_uniform_subroutineuiv(
-        &mut self,
-        shadertype: ShaderType,
-        location: GLint,
-        params: *mut GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_get_uniform_subroutineuiv not yet implemented");
-    }
-    /// ### Parameters
     /// `vaobj`
     ///
     /// > specifies the name of the vertex array object to use for the query.
@@ -18293,42 +13478,6 @@ impl Context {
         panic!("command oxidegl_is_program not yet implemented");
     }
     /// ### Parameters
-    /// `pipeline`
-    ///
-    /// > Specifies a value that may be the name of a program pipeline object.
-    ///
-    /// ### Description
-    /// [**glIsProgramPipeline**](crate::context::Context::oxidegl_is_program_pipeline)
-    /// returns [`GL_TRUE`](crate::gl_enums::GL_TRUE) if `pipeline` is currently
-    /// the name of a program pipeline object. If `pipeline` is zero, or if [`pipeline`](crate::gl_enums::pipeline)
-    /// is not the name of a program pipeline object, or if an error occurs, [**glIsProgramPipeline**](crate::context::Context::oxidegl_is_program_pipeline)
-    /// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE). If `pipeline` is a name
-    /// returned by [**glGenProgramPipelines**](crate::context::Context::oxidegl_gen_program_pipelines),
-    /// but that has not yet been bound through a call to [**glBindProgramPipeline**](crate::context::Context::oxidegl_bind_program_pipeline),
-    /// then the name is not a program pipeline object and [**glIsProgramPipeline**](crate::context::Context::oxidegl_is_program_pipeline)
-    /// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE).
-    pub fn oxidegl_is_program_pipeline(&mut self, pipeline: GLuint) -> GlFallible<GLboolean> {
-        panic!("command oxidegl_is_program_pipeline not yet implemented");
-    }
-    /// ### Parameters
-    /// `id`
-    ///
-    /// > Specifies a value that may be the name of a query object.
-    ///
-    /// ### Description
-    /// [**glIsQuery**](crate::context::Context::oxidegl_is_query) returns [`GL_TRUE`](crate::gl_enums::GL_TRUE)
-    /// if `id` is currently the name of a query object. If `id` is zero, or is
-    /// a non-zero value that is not currently the name of a query object, or if
-    /// an error occurs, [**glIsQuery**](crate::context::Context::oxidegl_is_query)
-    /// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE).
-    ///
-    /// A name returned by [**glGenQueries**](crate::context::Context::oxidegl_gen_queries),
-    /// but not yet associated with a query object by calling [**glBeginQuery**](crate::context::Context::oxidegl_begin_query),
-    /// is not the name of a query object.
-    pub fn oxidegl_is_query(&mut self, id: GLuint) -> GlFallible<GLboolean> {
-        panic!("command oxidegl_is_query not yet implemented");
-    }
-    /// ### Parameters
     /// `renderbuffer`
     ///
     /// > Specifies a value that may be the name of a renderbuffer object.
@@ -18423,43 +13572,6 @@ impl Context {
         panic!("command oxidegl_is_sync not yet implemented");
     }
     /// ### Parameters
-    /// `texture`
-    ///
-    /// > Specifies a value that may be the name of a texture.
-    ///
-    /// ### Description
-    /// [**glIsTexture**](crate::context::Context::oxidegl_is_texture) returns
-    /// [`GL_TRUE`](crate::gl_enums::GL_TRUE) if `texture` is currently the name
-    /// of a texture. If `texture` is zero, or is a non-zero value that is not
-    /// currently the name of a texture, or if an error occurs, [**glIsTexture**](crate::context::Context::oxidegl_is_texture)
-    /// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE).
-    ///
-    /// A name returned by [**glGenTextures**](crate::context::Context::oxidegl_gen_textures),
-    /// but not yet associated with a texture by calling [**glBindTexture**](crate::context::Context::oxidegl_bind_texture),
-    /// is not the name of a texture.
-    pub fn oxidegl_is_texture(&mut self, texture: GLuint) -> GlFallible<GLboolean> {
-        panic!("command oxidegl_is_texture not yet implemented");
-    }
-    /// ### Parameters
-    /// `id`
-    ///
-    /// > Specifies a value that may be the name of a transform feedback object.
-    ///
-    /// ### Description
-    /// [**glIsTransformFeedback**](crate::context::Context::oxidegl_is_transform_feedback)
-    /// returns [`GL_TRUE`](crate::gl_enums::GL_TRUE) if `id` is currently the
-    /// name of a transform feedback object. If `id` is zero, or if [`id`](crate::gl_enums::id)
-    /// is not the name of a transform feedback object, or if an error occurs,
-    /// [**glIsTransformFeedback**](crate::context::Context::oxidegl_is_transform_feedback)
-    /// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE). If `id` is a name returned
-    /// by [**glGenTransformFeedbacks**](crate::context::Context::oxidegl_gen_transform_feedbacks),
-    /// but that has not yet been bound through a call to [**glBindTransformFeedback**](crate::context::Context::oxidegl_bind_transform_feedback),
-    /// then the name is not a transform feedback object and [**glIsTransformFeedback**](crate::context::Context::oxidegl_is_transform_feedback)
-    /// returns [`GL_FALSE`](crate::gl_enums::GL_FALSE).
-    pub fn oxidegl_is_transform_feedback(&mut self, id: GLuint) -> GlFallible<GLboolean> {
-        panic!("command oxidegl_is_transform_feedback not yet implemented");
-    }
-    /// ### Parameters
     /// `width`
     ///
     /// > Specifies the width of rasterized lines. The initial value is 1.
@@ -18963,49 +14075,6 @@ impl Context {
     ) -> GlFallible {
         panic!("command oxidegl_multi_draw_elements_indirect not yet implemented");
     }
-    /// ### Description
-    /// [**glPauseTransformFeedback**](crate::context::Context::oxidegl_pause_transform_feedback)
-    /// pauses transform feedback operations on the currently active transform
-    /// feedback object. When transform feedback operations are paused, transform
-    /// feedback is still considered active and changing most transform feedback
-    /// state related to the object results in an error. However, a new transform
-    /// feedback object may be bound while transform feedback is paused.
-    pub fn oxidegl_pause_transform_feedback(&mut self) -> GlFallible {
-        panic!("command oxidegl_pause_transform_feedback not yet implemented");
-    }
-    /// ### Parameters
-    /// `size`
-    ///
-    /// > Specifies the diameter of rasterized points. The initial value is 1.
-    ///
-    /// ### Description
-    /// [**glPointSize**](crate::context::Context::oxidegl_point_size) specifies
-    /// the rasterized diameter of points. If point size mode is disabled (see
-    /// [**glEnable**](crate::context::Context::oxidegl_enable) with parameter
-    /// [`GL_PROGRAM_POINT_SIZE`](crate::gl_enums::GL_PROGRAM_POINT_SIZE) ), this
-    /// value will be used to rasterize points. Otherwise, the value written to
-    /// the shading language built-in variable
-    ///
-    /// ### Notes
-    /// The point size specified by [**glPointSize**](crate::context::Context::oxidegl_point_size)
-    /// is always returned when [`GL_POINT_SIZE`](crate::gl_enums::GL_POINT_SIZE)
-    /// is queried. Clamping and rounding for points have no effect on the specified
-    /// value.
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_POINT_SIZE_RANGE`](crate::gl_enums::GL_POINT_SIZE_RANGE)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_POINT_SIZE_GRANULARITY`](crate::gl_enums::GL_POINT_SIZE_GRANULARITY)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_POINT_SIZE`](crate::gl_enums::GL_POINT_SIZE)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_POINT_FADE_THRESHOLD_SIZE`](crate::gl_enums::GL_POINT_FADE_THRESHOLD_SIZE)
-    ///
-    /// [**glIsEnabled**](crate::context::Context::oxidegl_is_enabled) with argument
-    /// [`GL_PROGRAM_POINT_SIZE`](crate::gl_enums::GL_PROGRAM_POINT_SIZE)
-    pub fn oxidegl_point_size(&mut self, size: GLfloat) -> GlFallible {
-        panic!("command oxidegl_point_size not yet implemented");
-    }
     /// ### Parameters
     /// `face`
     ///
@@ -19116,131 +14185,13 @@ impl Context {
     /// [**glDrawElementsInstancedBaseVertex**](crate::context::Context::oxidegl_draw_elements_instanced_base_vertex)
     /// or [**glMultiDrawElementsBaseVertex**](crate::context::Context::oxidegl_multi_draw_elements_base_vertex)
     /// is used, the primitive restart comparison occurs before the basevertex
-    /// offset is added to the array index.
-    ///
-    /// ### Notes
-    /// [**glPrimitiveRestartIndex**](crate::context::Context::oxidegl_primitive_restart_index)
-    /// is available only if the GL version is 3.1 or greater.
-    pub fn oxidegl_primitive_restart_index(&mut self, index: GLuint) -> GlFallible {
-        panic!("command oxidegl_primitive_restart_index not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the name of a program object into which to load a program binary.
-    ///
-    /// `binaryFormat`
-    ///
-    /// > Specifies the format of the binary data in binary.
-    ///
-    /// `binary`
-    ///
-    /// > Specifies the address an array containing the binary to be loaded into
-    /// > `program`.
-    ///
-    /// `length`
-    ///
-    /// > Specifies the number of bytes contained in `binary`.
-    ///
-    /// ### Description
-    /// [**glProgramBinary**](crate::context::Context::oxidegl_program_binary)
-    /// loads a program object with a program binary previously returned from [**glGetProgramBinary**](crate::context::Context::oxidegl_get_program_binary).
-    /// `binaryFormat` and `binary` must be those returned by a previous call to
-    /// [**glGetProgramBinary**](crate::context::Context::oxidegl_get_program_binary),
-    /// and `length` must be the length returned by [**glGetProgramBinary**](crate::context::Context::oxidegl_get_program_binary),
-    /// or by [**glGetProgram**](crate::context::Context::oxidegl_get_program)
-    /// when called with `pname` set to [`GL_PROGRAM_BINARY_LENGTH`](crate::gl_enums::GL_PROGRAM_BINARY_LENGTH).
-    /// If these conditions are not met, loading the program binary will fail
-    /// and `program` 's [`GL_LINK_STATUS`](crate::gl_enums::GL_LINK_STATUS) will
-    /// be set to [`GL_FALSE`](crate::gl_enums::GL_FALSE).
-    ///
-    /// A program object's program binary is replaced by calls to [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// or [**glProgramBinary**](crate::context::Context::oxidegl_program_binary).
-    /// When linking success or failure is concerned, [**glProgramBinary**](crate::context::Context::oxidegl_program_binary)
-    /// can be considered to perform an implicit linking operation. [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// and [**glProgramBinary**](crate::context::Context::oxidegl_program_binary)
-    /// both set the program object's [`GL_LINK_STATUS`](crate::gl_enums::GL_LINK_STATUS)
-    /// to [`GL_TRUE`](crate::gl_enums::GL_TRUE) or [`GL_FALSE`](crate::gl_enums::GL_FALSE).
-    ///
-    /// A successful call to [**glProgramBinary**](crate::context::Context::oxidegl_program_binary)
-    /// will reset all uniform variables to their initial values. The initial value
-    /// is either the value of the variable's initializer as specified in the original
-    /// shader source, or zero if no initializer was present. Additionally, all
-    /// vertex shader input and fragment shader output assignments that were in
-    /// effect when the program was linked before saving are restored with [**glProgramBinary**](crate::context::Context::oxidegl_program_binary)
-    /// is called.
-    ///
-    /// ### Notes
-    /// A program binary may fail to load if the implementation determines that
-    /// there has been a change in hardware or software configuration from when
-    /// the program binary was produced such as having been compiled with an incompatible
-    /// or outdated version of the compiler.
-    ///
-    /// ### Associated Gets
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program) with argument
-    /// [`GL_PROGRAM_BINARY_LENGTH`](crate::gl_enums::GL_PROGRAM_BINARY_LENGTH)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_NUM_PROGRAM_BINARY_FORMATS`](crate::gl_enums::GL_NUM_PROGRAM_BINARY_FORMATS)
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_PROGRAM_BINARY_FORMATS`](crate::gl_enums::GL_PROGRAM_BINARY_FORMATS)
-    pub unsafe fn oxidegl_program_binary(
-        &mut self,
-        program: GLuint,
-        binary_format: GLenum,
-        binary: *const GLvoid,
-        length: GLsizei,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_binary not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
-    ///
-    /// > Specifies the name of a program object whose parameter to modify.
-    ///
-    /// `pname`
-    ///
-    /// > Specifies the name of the parameter to modify.
-    ///
-    /// `value`
-    ///
-    /// > Specifies the new value of the parameter specified by `pname` for `program`.
-    ///
-    /// ### Description
-    /// [**glProgramParameter**](crate::context::Context::oxidegl_program_parameter)
-    /// specifies a new value for the parameter nameed by `pname` for the program
-    /// object `program`.
-    ///
-    /// If `pname` is [`GL_PROGRAM_BINARY_RETRIEVABLE_HINT`](crate::gl_enums::GL_PROGRAM_BINARY_RETRIEVABLE_HINT),
-    /// `value` should be [`GL_FALSE`](crate::gl_enums::GL_FALSE) or [`GL_TRUE`](crate::gl_enums::GL_TRUE)
-    /// to indicate to the implementation the intention of the application to retrieve
-    /// the program's binary representation with [**glGetProgramBinary**](crate::context::Context::oxidegl_get_program_binary).
-    /// The implementation may use this information to store information that
-    /// may be useful for a future query of the program's binary. It is recommended
-    /// to set [`GL_PROGRAM_BINARY_RETRIEVABLE_HINT`](crate::gl_enums::GL_PROGRAM_BINARY_RETRIEVABLE_HINT)
-    /// for the program to [`GL_TRUE`](crate::gl_enums::GL_TRUE) before calling
-    /// [**glLinkProgram**](crate::context::Context::oxidegl_link_program), and
-    /// using the program at run-time if the binary is to be retrieved later.
-    ///
-    /// If `pname` is [`GL_PROGRAM_SEPARABLE`](crate::gl_enums::GL_PROGRAM_SEPARABLE),
-    /// `value` must be [`GL_TRUE`](crate::gl_enums::GL_TRUE) or [`GL_FALSE`](crate::gl_enums::GL_FALSE)
-    /// and indicates whether `program` can be bound to individual pipeline stages
-    /// via [**glUseProgramStages**](crate::context::Context::oxidegl_use_program_stages).
-    /// A program's [`GL_PROGRAM_SEPARABLE`](crate::gl_enums::GL_PROGRAM_SEPARABLE)
-    /// parameter must be set to [`GL_TRUE`](crate::gl_enums::GL_TRUE) *before*
-    /// [**glLinkProgram**](crate::context::Context::oxidegl_link_program) is called
-    /// in order for it to be usable with a program pipeline object. The initial
-    /// state of [`GL_PROGRAM_SEPARABLE`](crate::gl_enums::GL_PROGRAM_SEPARABLE)
-    /// is [`GL_FALSE`](crate::gl_enums::GL_FALSE).
+    /// offset is added to the array index.
     ///
-    /// ### Associated Gets
-    /// [**glGetProgram**](crate::context::Context::oxidegl_get_program).
-    pub fn oxidegl_program_parameteri(
-        &mut self,
-        program: GLuint,
-        pname: ProgramParameterPName,
-        value: GLint,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_parameteri not yet implemented");
+    /// ### Notes
+    /// [**glPrimitiveRestartIndex**](crate::context::Context::oxidegl_primitive_restart_index)
+    /// is available only if the GL version is 3.1 or greater.
+    pub fn oxidegl_primitive_restart_index(&mut self, index: GLuint) -> GlFallible {
+        panic!("command oxidegl_primitive_restart_index not yet implemented");
     }
     /// ### Parameters
     /// `provokeMode`
@@ -19319,16 +14270,6 @@ impl Context {
     pub fn oxidegl_release_shader_compiler(&mut self) -> GlFallible {
         panic!("command oxidegl_release_shader_compiler not yet implemented");
     }
-    /// ### Description
-    /// [**glResumeTransformFeedback**](crate::context::Context::oxidegl_resume_transform_feedback)
-    /// resumes transform feedback operations on the currently active transform
-    /// feedback object. When transform feedback operations are paused, transform
-    /// feedback is still considered active and changing most transform feedback
-    /// state related to the object results in an error. However, a new transform
-    /// feedback object may be bound while transform feedback is paused.
-    pub fn oxidegl_resume_transform_feedback(&mut self) -> GlFallible {
-        panic!("command oxidegl_resume_transform_feedback not yet implemented");
-    }
     /// ### Parameters
     /// `value`
     ///
@@ -19410,60 +14351,6 @@ impl Context {
         panic!("command oxidegl_sample_maski not yet implemented");
     }
     /// ### Parameters
-    /// `count`
-    ///
-    /// > Specifies the number of shader object handles contained in `shaders`.
-    ///
-    /// `shaders`
-    ///
-    /// > Specifies the address of an array of shader handles into which to load
-    /// > pre-compiled shader binaries.
-    ///
-    /// `binaryFormat`
-    ///
-    /// > Specifies the format of the shader binaries contained in `binary`.
-    ///
-    /// `binary`
-    ///
-    /// > Specifies the address of an array of bytes containing pre-compiled binary
-    /// > shader code.
-    ///
-    /// `length`
-    ///
-    /// > Specifies the length of the array whose address is given in `binary`.
-    ///
-    /// ### Description
-    /// [**glShaderBinary**](crate::context::Context::oxidegl_shader_binary) loads
-    /// pre-compiled shader binary code into the `count` shader objects whose handles
-    /// are given in `shaders`. `binary` points to `length` bytes of binary shader
-    /// code stored in client memory. `binaryFormat` specifies the format of the
-    /// pre-compiled code.
-    ///
-    /// The binary image contained in `binary` will be decoded according to the
-    /// extension specification defining the specified `binaryFormat` token. OpenGL
-    /// does not define any specific binary formats, but it does provide a mechanism
-    /// to obtain token vaues for such formats provided by such extensions.
-    ///
-    /// Depending on the types of the shader objects in `shaders`, [**glShaderBinary**](crate::context::Context::oxidegl_shader_binary)
-    /// will individually load binary vertex or fragment shaders, or load an executable
-    /// binary that contains an optimized pair of vertex and fragment shaders stored
-    /// in the same binary.
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with parameter [`GL_NUM_SHADER_BINARY_FORMATS`](crate::gl_enums::GL_NUM_SHADER_BINARY_FORMATS).
-    ///
-    /// [**glGet**](crate::context::Context::oxidegl_get) with parameter [`GL_SHADER_BINARY_FORMATS`](crate::gl_enums::GL_SHADER_BINARY_FORMATS).
-    pub unsafe fn oxidegl_shader_binary(
-        &mut self,
-        count: GLsizei,
-        shaders: *const GLuint,
-        binary_format: GLenum,
-        binary: *const GLvoid,
-        length: GLsizei,
-    ) -> GlFallible {
-        panic!("command oxidegl_shader_binary not yet implemented");
-    }
-    /// ### Parameters
     /// `program`
     ///
     /// > The name of the program containing the block whose binding to change.
@@ -21330,785 +16217,195 @@ impl Context {
         internalformat: SizedInternalFormat,
         minlevel: GLuint,
         numlevels: GLuint,
-        minlayer: GLuint,
-        numlayers: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_texture_view not yet implemented");
-    }
-    /// ### Parameters
-    /// `xfb`
-    ///
-    /// > Name of the transform feedback buffer object.
-    ///
-    /// `index`
-    ///
-    /// > Index of the binding point within `xfb`.
-    ///
-    /// `buffer`
-    ///
-    /// > Name of the buffer object to bind to the specified binding point.
-    ///
-    /// ### Description
-    /// [**glTransformFeedbackBufferBase**](crate::context::Context::oxidegl_transform_feedback_buffer_base)
-    /// binds the buffer object `buffer` to the binding point at index `index`
-    /// of the transform feedback object `xfb`.
-    ///
-    /// ### Notes
-    /// Calling [**glTransformFeedbackBufferBase**](crate::context::Context::oxidegl_transform_feedback_buffer_base)
-    /// is equivalent to calling [**glTransformFeedbackBufferRange**](crate::context::Context::oxidegl_transform_feedback_buffer_range)
-    /// with `offset` zero and `size` equal to the size of `buffer`.
-    pub fn oxidegl_transform_feedback_buffer_base(
-        &mut self,
-        xfb: GLuint,
-        index: GLuint,
-        buffer: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_transform_feedback_buffer_base not yet implemented");
-    }
-    /// ### Parameters
-    /// `xfb`
-    ///
-    /// > Name of the transform feedback buffer object.
-    ///
-    /// `index`
-    ///
-    /// > Index of the binding point within `xfb`.
-    ///
-    /// `buffer`
-    ///
-    /// > Name of the buffer object to bind to the specified binding point.
-    ///
-    /// `offset`
-    ///
-    /// > The starting offset in basic machine units into the buffer object.
-    ///
-    /// `size`
-    ///
-    /// > The amount of data in basic machine units that can be read from or written
-    /// > to the buffer object while used as an indexed target.
-    ///
-    /// ### Description
-    /// [**glTransformFeedbackBufferRange**](crate::context::Context::oxidegl_transform_feedback_buffer_range)
-    /// binds a range of the buffer object `buffer` represented by `offset` and
-    /// `size` to the binding point at index `index` of the transform feedback
-    /// object `xfb`.
-    ///
-    /// `offset` specifies the offset in basic machine units into the buffer object
-    /// `buffer` and `size` specifies the amount of data that can be read from
-    /// the buffer object while used as an indexed target.
-    ///
-    /// ### Notes
-    pub fn oxidegl_transform_feedback_buffer_range(
-        &mut self,
-        xfb: GLuint,
-        index: GLuint,
-        buffer: GLuint,
-        offset: GLintptr,
-        size: GLsizeiptr,
-    ) -> GlFallible {
-        panic!("command oxidegl_transform_feedback_buffer_range not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
-    ///
-    /// > The name of the target program object.
-    ///
-    /// `count`
-    ///
-    /// > The number of varying variables used for transform feedback.
-    ///
-    /// `varyings`
-    ///
-    /// > An array of `count` zero-terminated strings specifying the names of the
-    /// > varying variables to use for transform feedback.
-    ///
-    /// `bufferMode`
-    ///
-    /// > Identifies the mode used to capture the varying variables when transform
-    /// > feedback is active. `bufferMode` must be [`GL_INTERLEAVED_ATTRIBS`](crate::gl_enums::GL_INTERLEAVED_ATTRIBS)
-    /// > or [`GL_SEPARATE_ATTRIBS`](crate::gl_enums::GL_SEPARATE_ATTRIBS).
-    ///
-    /// ### Description
-    /// The names of the vertex or geometry shader outputs to be recorded in transform
-    /// feedback mode are specified using [**glTransformFeedbackVaryings**](crate::context::Context::oxidegl_transform_feedback_varyings).
-    /// When a geometry shader is active, transform feedback records the values
-    /// of selected geometry shader output variables from the emitted vertices.
-    /// Otherwise, the values of the selected vertex shader outputs are recorded.
-    ///
-    /// The state set by [**glTranformFeedbackVaryings**](crate::context::Context::oxidegl_tranform_feedback_varyings)
-    /// is stored and takes effect next time [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// is called on `program`. When [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// is called, `program` is linked so that the values of the specified varying
-    /// variables for the vertices of each primitive generated by the GL are written
-    /// to a single buffer object if `bufferMode` is [`GL_INTERLEAVED_ATTRIBS`](crate::gl_enums::GL_INTERLEAVED_ATTRIBS)
-    /// or multiple buffer objects if `bufferMode` is [`GL_SEPARATE_ATTRIBS`](crate::gl_enums::GL_SEPARATE_ATTRIBS).
-    ///
-    /// [**glTransformFeedbackVaryings**](crate::context::Context::oxidegl_transform_feedback_varyings)
-    /// can also special identifiers if `bufferMode` is [`GL_INTERLEAVED_ATTRIBS`](crate::gl_enums::GL_INTERLEAVED_ATTRIBS):
-    ///
-    /// Subsequent variables in `varyings` will be assigned to the next buffer
-    /// binding point.
-    ///
-    /// Where# may be 1, 2, 3, or 4. The variable is treated as having this number
-    /// of components, but the contents of the memory are preserved under feedback
-    /// operations.
-    ///
-    /// In addition to the errors generated by [**glTransformFeedbackVaryings**](crate::context::Context::oxidegl_transform_feedback_varyings),
-    /// the program `program` will fail to link if: The count specified by [**glTransformFeedbackVaryings**](crate::context::Context::oxidegl_transform_feedback_varyings)
-    /// > is non-zero, but the program object has no vertex or geometry shader.
-    ///
-    /// > Any variable name specified in the `varyings` array is not declared as
-    /// > an output in the vertex shader (or the geometry shader, if active), or
-    /// > is not one of the special identifiers listed above.
-    ///
-    /// > Special identifiers appear in a `varyings` array where `bufferMode` is
-    /// > not [`GL_INTERLEAVED_ATTRIBS`](crate::gl_enums::GL_INTERLEAVED_ATTRIBS).
-    ///
-    /// > Any two entries in the `varyings` array, which are not one of the special
-    /// > varyings above, specify the same varying variable.
-    ///
-    /// > Discounting any special identifiers, the total number of components to
-    /// > capture in any varying variable in `varyings` is greater than the constant
-    /// > [`GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_COMPONENTS`](crate::gl_enums::GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_COMPONENTS)
-    /// > and the buffer mode is [`GL_SEPARATE_ATTRIBS`](crate::gl_enums::GL_SEPARATE_ATTRIBS).
-    ///
-    /// > The total number of components to capture is greater than the constant
-    /// > [`GL_MAX_TRANSFORM_FEEDBACK_INTERLEAVED_COMPONENTS`](crate::gl_enums::GL_MAX_TRANSFORM_FEEDBACK_INTERLEAVED_COMPONENTS)
-    /// > and the buffer mode is [`GL_INTERLEAVED_ATTRIBS`](crate::gl_enums::GL_INTERLEAVED_ATTRIBS).
-    ///
-    ///
-    /// ### Notes
-    /// [**glGetTransformFeedbackVarying**](crate::context::Context::oxidegl_get_transform_feedback_varying)
-    /// is available only if the GL version is 3.0 or greater.
-    ///
-    /// ### Associated Gets
-    /// [**glGetTransformFeedbackVarying**](crate::context::Context::oxidegl_get_transform_feedback_varying)
-    pub unsafe fn oxidegl_transform_feedback_varyings(
-        &mut self,
-        program: GLuint,
-        count: GLsizei,
-        varyings: *const *const GLchar,
-        buffer_mode: TransformFeedbackBufferMode,
-    ) -> GlFallible {
-        panic!("command oxidegl_transform_feedback_varyings not yet implemented");
-    }
-    /// ### Parameters
-    /// `program`
-    ///
-    /// > The name of a program object containing the active uniform block whose
-    /// > binding to assign.
-    ///
-    /// `uniformBlockIndex`
-    ///
-    /// > The index of the active uniform block within `program` whose binding to
-    /// > assign.
-    ///
-    /// `uniformBlockBinding`
-    ///
-    /// > Specifies the binding point to which to bind the uniform block with index
-    /// > `uniformBlockIndex` within `program`.
-    ///
-    /// ### Description
-    /// Binding points for active uniform blocks are assigned using [**glUniformBlockBinding**](crate::context::Context::oxidegl_uniform_block_binding).
-    /// Each of a program's active uniform blocks has a corresponding uniform
-    /// buffer binding point. `program` is the name of a program object for which
-    /// the command [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
-    /// has been issued in the past.
-    ///
-    /// If successful, [**glUniformBlockBinding**](crate::context::Context::oxidegl_uniform_block_binding)
-    /// specifies that `program` will use the data store of the buffer object bound
-    /// to the binding point `uniformBlockBinding` to extract the values of the
-    /// uniforms in the uniform block identified by `uniformBlockIndex`.
-    ///
-    /// When a program object is linked or re-linked, the uniform buffer object
-    /// binding point assigned to each of its active uniform blocks is reset to
-    /// zero.
-    ///
-    /// ### Notes
-    /// [**glUniformBlockBinding**](crate::context::Context::oxidegl_uniform_block_binding)
-    /// is available only if the GL version is 3.1 or greater.
-    ///
-    /// ### Associated Gets
-    /// [**glGetActiveUniformBlock**](crate::context::Context::oxidegl_get_active_uniform_block)
-    /// with argument [`GL_UNIFORM_BLOCK_BINDING`](crate::gl_enums::GL_UNIFORM_BLOCK_BINDING)
-    pub fn oxidegl_uniform_block_binding(
-        &mut self,
-        program: GLuint,
-        uniform_block_index: GLuint,
-        uniform_block_binding: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_block_binding not yet implemented");
-    }
-    /// ### Parameters
-    /// `shadertype`
-    ///
-    /// > Specifies the shader stage from which to query for subroutine uniform index.
-    /// > `shadertype` must be one of [`GL_VERTEX_SHADER`](crate::gl_enums::GL_VERTEX_SHADER),
-    /// > [`GL_TESS_CONTROL_SHADER`](crate::gl_enums::GL_TESS_CONTROL_SHADER), [`GL_TESS_EVALUATION_SHADER`](crate::gl_enums::GL_TESS_EVALUATION_SHADER),
-    /// > [`GL_GEOMETRY_SHADER`](crate::gl_enums::GL_GEOMETRY_SHADER) or [`GL_FRAGMENT_SHADER`](crate::gl_enums::GL_FRAGMENT_SHADER).
-    ///
-    /// `count`
-    ///
-    /// > Specifies the number of uniform indices stored in `indices`.
-    ///
-    /// `indices`
-    ///
-    /// > Specifies the address of an array holding the indices to load into the
-    /// > shader subroutine variables.
-    ///
-    /// ### Description
-    /// [**glUniformSubroutines**](crate::context::Context::oxidegl_uniform_subroutines)
-    /// loads all active subroutine uniforms for shader stage `shadertype` of the
-    /// current program with subroutine indices from `indices`, storing `indices[i]`
-    /// into the uniform at location `i`. `count` must be equal to the value of
-    /// [`GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS)
-    /// for the program currently in use at shader stage `shadertype`. Furthermore,
-    /// all values in `indices` must be less than the value of [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES)
-    /// for the shader stage.
-    ///
-    /// ### Associated Gets
-    /// [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage)
-    /// with argument [`GL_ACTIVE_SUBROUTINES`](crate::gl_enums::GL_ACTIVE_SUBROUTINES)
-    ///
-    /// [**glGetProgramStage**](crate::context::Context::oxidegl_get_program_stage)
-    /// with argument [`GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS`](crate::gl_enums::GL_ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS)
-    pub unsafe fn oxidegl_uniform_subroutinesuiv(
-        &mut self,
-        shadertype: ShaderType,
-        count: GLsizei,
-        indices: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_subroutinesuiv not yet implemented");
-    }
-    /// ### Parameters
-    /// `pipeline`
-    ///
-    /// > Specifies the program pipeline object to which to bind stages from `program`.
-    ///
-    /// `stages`
-    ///
-    /// > Specifies a set of program stages to bind to the program pipeline object.
-    ///
-    /// `program`
-    ///
-    /// > Specifies the program object containing the shader executables to use in
-    /// > `pipeline`.
-    ///
-    /// ### Description
-    /// [**glUseProgramStages**](crate::context::Context::oxidegl_use_program_stages)
-    /// binds executables from a program object associated with a specified set
-    /// of shader stages to the program pipeline object given by `pipeline`. `pipeline`
-    /// specifies the program pipeline object to which to bind the executables.
-    /// `stages` contains a logical combination of bits indicating the shader stages
-    /// to use within `program` with the program pipeline object `pipeline`. `stages`
-    /// must be a logical combination of [`GL_VERTEX_SHADER_BIT`](crate::gl_enums::GL_VERTEX_SHADER_BIT),
-    /// [`GL_TESS_CONTROL_SHADER_BIT`](crate::gl_enums::GL_TESS_CONTROL_SHADER_BIT),
-    /// [`GL_TESS_EVALUATION_SHADER_BIT`](crate::gl_enums::GL_TESS_EVALUATION_SHADER_BIT),
-    /// [`GL_GEOMETRY_SHADER_BIT`](crate::gl_enums::GL_GEOMETRY_SHADER_BIT), [`GL_FRAGMENT_SHADER_BIT`](crate::gl_enums::GL_FRAGMENT_SHADER_BIT)
-    /// and [`GL_COMPUTE_SHADER_BIT`](crate::gl_enums::GL_COMPUTE_SHADER_BIT). Additionally,
-    /// the special value [`GL_ALL_SHADER_BITS`](crate::gl_enums::GL_ALL_SHADER_BITS)
-    /// may be specified to indicate that all executables contained in `program`
-    /// should be installed in `pipeline`.
-    ///
-    /// If `program` refers to a program object with a valid shader attached for
-    /// an indicated shader stage, [**glUseProgramStages**](crate::context::Context::oxidegl_use_program_stages)
-    /// installs the executable code for that stage in the indicated program pipeline
-    /// object `pipeline`. If `program` is zero, or refers to a program object
-    /// with no valid shader executable for a given stage, it is as if the pipeline
-    /// object has no programmable stage configured for the indicated shader stages.
-    /// If `stages` contains bits other than those listed above, and is not equal
-    /// to [`GL_ALL_SHADER_BITS`](crate::gl_enums::GL_ALL_SHADER_BITS), an error
-    /// is generated.
-    ///
-    /// ### Notes
-    /// The [`GL_COMPUTE_SHADER_BIT`](crate::gl_enums::GL_COMPUTE_SHADER_BIT) bit
-    /// is available only if the GL version is 4.3 or greater.
-    pub fn oxidegl_use_program_stages(
-        &mut self,
-        pipeline: GLuint,
-        stages: UseProgramStageMask,
-        program: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_use_program_stages not yet implemented");
-    }
-    /// ### Parameters
-    /// `pipeline`
-    ///
-    /// > Specifies the name of a program pipeline object to validate.
-    ///
-    /// ### Description
-    /// [**glValidateProgramPipeline**](crate::context::Context::oxidegl_validate_program_pipeline)
-    /// instructs the implementation to validate the shader executables contained
-    /// in `pipeline` against the current GL state. The implementation may use
-    /// this as an opportunity to perform any internal shader modifications that
-    /// may be required to ensure correct operation of the installed shaders given
-    /// the current GL state.
-    ///
-    /// After a program pipeline has been validated, its validation status is set
-    /// to [`GL_TRUE`](crate::gl_enums::GL_TRUE). The validation status of a program
-    /// pipeline object may be queried by calling [**glGetProgramPipeline**](crate::context::Context::oxidegl_get_program_pipeline)
-    /// with parameter [`GL_VALIDATE_STATUS`](crate::gl_enums::GL_VALIDATE_STATUS).
-    ///
-    /// If `pipeline` is a name previously returned from a call to [**glGenProgramPipelines**](crate::context::Context::oxidegl_gen_program_pipelines)
-    /// but that has not yet been bound by a call to [**glBindProgramPipeline**](crate::context::Context::oxidegl_bind_program_pipeline),
-    /// a new program pipeline object is created with name `pipeline` and the
-    /// default state vector.
-    ///
-    /// ### Associated Gets
-    /// [**glGetProgramPipeline**](crate::context::Context::oxidegl_get_program_pipeline)
-    /// with parameter [`GL_VALIDATE_STATUS`](crate::gl_enums::GL_VALIDATE_STATUS).
-    pub fn oxidegl_validate_program_pipeline(&mut self, pipeline: GLuint) -> GlFallible {
-        panic!("command oxidegl_validate_program_pipeline not yet implemented");
-    }
-    /// ### Parameters
-    /// `vaobj`
-    ///
-    /// > Specifies the name of the vertex array object.
-    ///
-    /// `buffer`
-    ///
-    /// > Specifies the name of the buffer object to use for the element array buffer
-    /// > binding.
-    ///
-    /// ### Description
-    /// [**glVertexArrayElementBuffer**](crate::context::Context::oxidegl_vertex_array_element_buffer)
-    /// binds a buffer object with id `buffer` to the element array buffer bind
-    /// point of a vertex array object with id `vaobj`. If `buffer` is zero, any
-    /// existing element array buffer binding to `vaobj` is removed.
-    ///
-    /// ### Associated Gets
-    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_ELEMENT_ARRAY_BUFFER_BINDING`](crate::gl_enums::GL_ELEMENT_ARRAY_BUFFER_BINDING).
-    ///
-    /// [**glGetVertexArrayiv**](crate::context::Context::oxidegl_get_vertex_arrayiv)
-    /// with argument [`GL_ELEMENT_ARRAY_BUFFER_BINDING`](crate::gl_enums::GL_ELEMENT_ARRAY_BUFFER_BINDING).
-    pub fn oxidegl_vertex_array_element_buffer(
-        &mut self,
-        vaobj: GLuint,
-        buffer: GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_vertex_array_element_buffer not yet implemented");
-    }
-    /// ### Parameters
-    /// `index`
-    ///
-    /// > Specify the index of the generic vertex attribute.
-    ///
-    /// `divisor`
-    ///
-    /// > Specify the number of instances that will pass between updates of the generic
-    /// > attribute at slot `index`.
-    ///
-    /// ### Description
-    /// [**glVertexAttribDivisor**](crate::context::Context::oxidegl_vertex_attrib_divisor)
-    /// modifies the rate at which generic vertex attributes advance when rendering
-    /// multiple instances of primitives in a single draw call. If `divisor` is
-    /// zero, the attribute at slot `index` advances once per vertex. If `divisor`
-    /// is non-zero, the attribute advances once per `divisor` instances of the
-    /// set(s) of vertices being rendered. An attribute is referred to as instanced
-    /// if its [`GL_VERTEX_ATTRIB_ARRAY_DIVISOR`](crate::gl_enums::GL_VERTEX_ATTRIB_ARRAY_DIVISOR)
-    /// value is non-zero.
-    ///
-    /// `index` must be less than the value of [`GL_MAX_VERTEX_ATTRIBS`](crate::gl_enums::GL_MAX_VERTEX_ATTRIBS).
-    ///
-    /// ### Notes
-    /// [**glVertexAttribDivisor**](crate::context::Context::oxidegl_vertex_attrib_divisor)
-    /// is available only if the GL version is 3.3 or higher.
-    pub fn oxidegl_vertex_attrib_divisor(&mut self, index: GLuint, divisor: GLuint) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_divisor not yet implemented");
+        minlayer: GLuint,
+        numlayers: GLuint,
+    ) -> GlFallible {
+        panic!("command oxidegl_texture_view not yet implemented");
     }
     /// ### Parameters
-    /// `sync`
+    /// `program`
     ///
-    /// > Specifies the sync object whose status to wait on.
+    /// > The name of a program object containing the active uniform block whose
+    /// > binding to assign.
     ///
-    /// `flags`
+    /// `uniformBlockIndex`
     ///
-    /// > A bitfield controlling the command flushing behavior. `flags` may be zero.
+    /// > The index of the active uniform block within `program` whose binding to
+    /// > assign.
     ///
-    /// `timeout`
+    /// `uniformBlockBinding`
     ///
-    /// > Specifies the timeout that the server should wait before continuing. `timeout`
-    /// > must be [`GL_TIMEOUT_IGNORED`](crate::gl_enums::GL_TIMEOUT_IGNORED).
+    /// > Specifies the binding point to which to bind the uniform block with index
+    /// > `uniformBlockIndex` within `program`.
     ///
     /// ### Description
-    /// [**glWaitSync**](crate::context::Context::oxidegl_wait_sync) causes the
-    /// GL server to block and wait until `sync` becomes signaled. `sync` is the
-    /// name of an existing sync object upon which to wait. `flags` and `timeout`
-    /// are currently not used and must be set to zero and the special value [`GL_TIMEOUT_IGNORED`](crate::gl_enums::GL_TIMEOUT_IGNORED),
-    /// respectively `flags` and `timeout` are placeholders for anticipated future
-    /// extensions of sync object capabilities. They must have these reserved values
-    /// in order that existing code calling [**glWaitSync**](crate::context::Context::oxidegl_wait_sync)
-    /// operate properly in the presence of such extensions.
+    /// Binding points for active uniform blocks are assigned using [**glUniformBlockBinding**](crate::context::Context::oxidegl_uniform_block_binding).
+    /// Each of a program's active uniform blocks has a corresponding uniform
+    /// buffer binding point. `program` is the name of a program object for which
+    /// the command [**glLinkProgram**](crate::context::Context::oxidegl_link_program)
+    /// has been issued in the past.
     ///
-    /// [**glWaitSync**](crate::context::Context::oxidegl_wait_sync) will always
-    /// wait no longer than an implementation-dependent timeout. The duration of
-    /// this timeout in nanoseconds may be queried by calling [**glGet**](crate::context::Context::oxidegl_get)
-    /// with the parameter [`GL_MAX_SERVER_WAIT_TIMEOUT`](crate::gl_enums::GL_MAX_SERVER_WAIT_TIMEOUT).
-    /// There is currently no way to determine whether [**glWaitSync**](crate::context::Context::oxidegl_wait_sync)
-    /// unblocked because the timeout expired or because the sync object being
-    /// waited on was signaled.
+    /// If successful, [**glUniformBlockBinding**](crate::context::Context::oxidegl_uniform_block_binding)
+    /// specifies that `program` will use the data store of the buffer object bound
+    /// to the binding point `uniformBlockBinding` to extract the values of the
+    /// uniforms in the uniform block identified by `uniformBlockIndex`.
     ///
-    /// If an error occurs, [**glWaitSync**](crate::context::Context::oxidegl_wait_sync)
-    /// does not cause the GL server to block.
+    /// When a program object is linked or re-linked, the uniform buffer object
+    /// binding point assigned to each of its active uniform blocks is reset to
+    /// zero.
     ///
     /// ### Notes
-    /// [**glWaitSync**](crate::context::Context::oxidegl_wait_sync) is available
-    /// only if the GL version is 3.2 or higher.
-    pub fn oxidegl_wait_sync(
-        &mut self,
-        sync: GLsync,
-        flags: GLbitfield,
-        timeout: GLuint64,
-    ) -> GlFallible {
-        panic!("command oxidegl_wait_sync not yet implemented");
-    }
-    pub unsafe fn oxidegl_vertex_attrib_p1uiv(
-        &mut self,
-        index: GLuint,
-        r#type: VertexAttribPointerType,
-        normalized: GLboolean,
-        value: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_p1uiv not yet implemented");
-    }
-    pub unsafe fn oxidegl_vertex_attrib_p2uiv(
-        &mut self,
-        index: GLuint,
-        r#type: VertexAttribPointerType,
-        normalized: GLboolean,
-        value: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_p2uiv not yet implemented");
-    }
-    pub unsafe fn oxidegl_vertex_attrib_p3uiv(
-        &mut self,
-        index: GLuint,
-        r#type: VertexAttribPointerType,
-        normalized: GLboolean,
-        value: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_p3uiv not yet implemented");
-    }
-    pub unsafe fn oxidegl_vertex_attrib_p4uiv(
-        &mut self,
-        index: GLuint,
-        r#type: VertexAttribPointerType,
-        normalized: GLboolean,
-        value: *const GLuint,
-    ) -> GlFallible {
-        panic!("command oxidegl_vertex_attrib_p4uiv not yet implemented");
-    }
-    pub fn oxidegl_uniform1d(&mut self, location: GLint, x: GLdouble) -> GlFallible {
-        panic!("command oxidegl_uniform1d not yet implemented");
-    }
-    pub fn oxidegl_uniform2d(&mut self, location: GLint, x: GLdouble, y: GLdouble) -> GlFallible {
-        panic!("command oxidegl_uniform2d not yet implemented");
-    }
-    pub fn oxidegl_uniform3d(
-        &mut self,
-        location: GLint,
-        x: GLdouble,
-        y: GLdouble,
-        z: GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform3d not yet implemented");
-    }
-    pub fn oxidegl_uniform4d(
-        &mut self,
-        location: GLint,
-        x: GLdouble,
-        y: GLdouble,
-        z: GLdouble,
-        w: GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform4d not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform1dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform1dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform2dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform2dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform3dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform3dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform4dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform4dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix2dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix2dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix3dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix3dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix4dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix4dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix2x3dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix2x3dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix2x4dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix2x4dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix3x2dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix3x2dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix3x4dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix3x4dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix4x2dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix4x2dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_uniform_matrix4x3dv(
-        &mut self,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_uniform_matrix4x3dv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform1d(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform1d not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform1dv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform1dv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform2d(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLdouble,
-        v1: GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform2d not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform2dv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform2dv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform3d(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLdouble,
-        v1: GLdouble,
-        v2: GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform3d not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform3dv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform3dv not yet implemented");
-    }
-    pub fn oxidegl_program_uniform4d(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        v0: GLdouble,
-        v1: GLdouble,
-        v2: GLdouble,
-        v3: GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform4d not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform4dv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform4dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform_matrix2dv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix2dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform_matrix3dv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix3dv not yet implemented");
-    }
-    pub unsafe fn oxidegl_program_uniform_matrix4dv(
+    /// [**glUniformBlockBinding**](crate::context::Context::oxidegl_uniform_block_binding)
+    /// is available only if the GL version is 3.1 or greater.
+    ///
+    /// ### Associated Gets
+    /// [**glGetActiveUniformBlock**](crate::context::Context::oxidegl_get_active_uniform_block)
+    /// with argument [`GL_UNIFORM_BLOCK_BINDING`](crate::gl_enums::GL_UNIFORM_BLOCK_BINDING)
+    pub fn oxidegl_uniform_block_binding(
         &mut self,
         program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
+        uniform_block_index: GLuint,
+        uniform_block_binding: GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix4dv not yet implemented");
+        panic!("command oxidegl_uniform_block_binding not yet implemented");
     }
-    pub unsafe fn oxidegl_program_uniform_matrix2x3dv(
+    /// ### Parameters
+    /// `vaobj`
+    ///
+    /// > Specifies the name of the vertex array object.
+    ///
+    /// `buffer`
+    ///
+    /// > Specifies the name of the buffer object to use for the element array buffer
+    /// > binding.
+    ///
+    /// ### Description
+    /// [**glVertexArrayElementBuffer**](crate::context::Context::oxidegl_vertex_array_element_buffer)
+    /// binds a buffer object with id `buffer` to the element array buffer bind
+    /// point of a vertex array object with id `vaobj`. If `buffer` is zero, any
+    /// existing element array buffer binding to `vaobj` is removed.
+    ///
+    /// ### Associated Gets
+    /// [**glGet**](crate::context::Context::oxidegl_get) with argument [`GL_ELEMENT_ARRAY_BUFFER_BINDING`](crate::gl_enums::GL_ELEMENT_ARRAY_BUFFER_BINDING).
+    ///
+    /// [**glGetVertexArrayiv**](crate::context::Context::oxidegl_get_vertex_arrayiv)
+    /// with argument [`GL_ELEMENT_ARRAY_BUFFER_BINDING`](crate::gl_enums::GL_ELEMENT_ARRAY_BUFFER_BINDING).
+    pub fn oxidegl_vertex_array_element_buffer(
         &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
+        vaobj: GLuint,
+        buffer: GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix2x3dv not yet implemented");
+        panic!("command oxidegl_vertex_array_element_buffer not yet implemented");
     }
-    pub unsafe fn oxidegl_program_uniform_matrix3x2dv(
-        &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
-    ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix3x2dv not yet implemented");
+    /// ### Parameters
+    /// `index`
+    ///
+    /// > Specify the index of the generic vertex attribute.
+    ///
+    /// `divisor`
+    ///
+    /// > Specify the number of instances that will pass between updates of the generic
+    /// > attribute at slot `index`.
+    ///
+    /// ### Description
+    /// [**glVertexAttribDivisor**](crate::context::Context::oxidegl_vertex_attrib_divisor)
+    /// modifies the rate at which generic vertex attributes advance when rendering
+    /// multiple instances of primitives in a single draw call. If `divisor` is
+    /// zero, the attribute at slot `index` advances once per vertex. If `divisor`
+    /// is non-zero, the attribute advances once per `divisor` instances of the
+    /// set(s) of vertices being rendered. An attribute is referred to as instanced
+    /// if its [`GL_VERTEX_ATTRIB_ARRAY_DIVISOR`](crate::gl_enums::GL_VERTEX_ATTRIB_ARRAY_DIVISOR)
+    /// value is non-zero.
+    ///
+    /// `index` must be less than the value of [`GL_MAX_VERTEX_ATTRIBS`](crate::gl_enums::GL_MAX_VERTEX_ATTRIBS).
+    ///
+    /// ### Notes
+    /// [**glVertexAttribDivisor**](crate::context::Context::oxidegl_vertex_attrib_divisor)
+    /// is available only if the GL version is 3.3 or higher.
+    pub fn oxidegl_vertex_attrib_divisor(&mut self, index: GLuint, divisor: GLuint) -> GlFallible {
+        panic!("command oxidegl_vertex_attrib_divisor not yet implemented");
     }
-    pub unsafe fn oxidegl_program_uniform_matrix2x4dv(
+    /// ### Parameters
+    /// `sync`
+    ///
+    /// > Specifies the sync object whose status to wait on.
+    ///
+    /// `flags`
+    ///
+    /// > A bitfield controlling the command flushing behavior. `flags` may be zero.
+    ///
+    /// `timeout`
+    ///
+    /// > Specifies the timeout that the server should wait before continuing. `timeout`
+    /// > must be [`GL_TIMEOUT_IGNORED`](crate::gl_enums::GL_TIMEOUT_IGNORED).
+    ///
+    /// ### Description
+    /// [**glWaitSync**](crate::context::Context::oxidegl_wait_sync) causes the
+    /// GL server to block and wait until `sync` becomes signaled. `sync` is the
+    /// name of an existing sync object upon which to wait. `flags` and `timeout`
+    /// are currently not used and must be set to zero and the special value [`GL_TIMEOUT_IGNORED`](crate::gl_enums::GL_TIMEOUT_IGNORED),
+    /// respectively `flags` and `timeout` are placeholders for anticipated future
+    /// extensions of sync object capabilities. They must have these reserved values
+    /// in order that existing code calling [**glWaitSync**](crate::context::Context::oxidegl_wait_sync)
+    /// operate properly in the presence of such extensions.
+    ///
+    /// [**glWaitSync**](crate::context::Context::oxidegl_wait_sync) will always
+    /// wait no longer than an implementation-dependent timeout. The duration of
+    /// this timeout in nanoseconds may be queried by calling [**glGet**](crate::context::Context::oxidegl_get)
+    /// with the parameter [`GL_MAX_SERVER_WAIT_TIMEOUT`](crate::gl_enums::GL_MAX_SERVER_WAIT_TIMEOUT).
+    /// There is currently no way to determine whether [**glWaitSync**](crate::context::Context::oxidegl_wait_sync)
+    /// unblocked because the timeout expired or because the sync object being
+    /// waited on was signaled.
+    ///
+    /// If an error occurs, [**glWaitSync**](crate::context::Context::oxidegl_wait_sync)
+    /// does not cause the GL server to block.
+    ///
+    /// ### Notes
+    /// [**glWaitSync**](crate::context::Context::oxidegl_wait_sync) is available
+    /// only if the GL version is 3.2 or higher.
+    pub fn oxidegl_wait_sync(
         &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
+        sync: GLsync,
+        flags: GLbitfield,
+        timeout: GLuint64,
     ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix2x4dv not yet implemented");
+        panic!("command oxidegl_wait_sync not yet implemented");
     }
-    pub unsafe fn oxidegl_program_uniform_matrix4x2dv(
+    pub unsafe fn oxidegl_vertex_attrib_p1uiv(
         &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
+        index: GLuint,
+        r#type: VertexAttribPointerType,
+        normalized: GLboolean,
+        value: *const GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix4x2dv not yet implemented");
+        panic!("command oxidegl_vertex_attrib_p1uiv not yet implemented");
     }
-    pub unsafe fn oxidegl_program_uniform_matrix3x4dv(
+    pub unsafe fn oxidegl_vertex_attrib_p2uiv(
         &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
+        index: GLuint,
+        r#type: VertexAttribPointerType,
+        normalized: GLboolean,
+        value: *const GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix3x4dv not yet implemented");
+        panic!("command oxidegl_vertex_attrib_p2uiv not yet implemented");
     }
-    pub unsafe fn oxidegl_program_uniform_matrix4x3dv(
+    pub unsafe fn oxidegl_vertex_attrib_p3uiv(
         &mut self,
-        program: GLuint,
-        location: GLint,
-        count: GLsizei,
-        transpose: GLboolean,
-        value: *const GLdouble,
+        index: GLuint,
+        r#type: VertexAttribPointerType,
+        normalized: GLboolean,
+        value: *const GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_program_uniform_matrix4x3dv not yet implemented");
+        panic!("command oxidegl_vertex_attrib_p3uiv not yet implemented");
     }
-    pub unsafe fn oxidegl_specialize_shader(
+    pub unsafe fn oxidegl_vertex_attrib_p4uiv(
         &mut self,
-        shader: GLuint,
-        p_entry_point: *const GLchar,
-        num_specialization_constants: GLuint,
-        p_constant_index: *const GLuint,
-        p_constant_value: *const GLuint,
+        index: GLuint,
+        r#type: VertexAttribPointerType,
+        normalized: GLboolean,
+        value: *const GLuint,
     ) -> GlFallible {
-        panic!("command oxidegl_specialize_shader not yet implemented");
+        panic!("command oxidegl_vertex_attrib_p4uiv not yet implemented");
     }
     pub unsafe fn oxidegl_multi_draw_arrays_indirect_count(
         &mut self,
@@ -22140,3 +16437,131 @@ impl Context {
         panic!("command oxidegl_polygon_offset_clamp not yet implemented");
     }
 }
+/// ### Parameters
+/// `id`
+///
+/// > Specifies the name of a query object.
+///
+/// `buffer`
+///
+/// > Specifies the name of a buffer object.
+///
+/// `pname`
+///
+/// > Specifies the symbolic name of a query object parameter. Accepted values
+/// > are [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT), [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE),
+/// > [`GL_QUERY_RESULT_NO_WAIT`](crate::gl_enums::GL_QUERY_RESULT_NO_WAIT), or
+/// > [`GL_QUERY_TARGET`](crate::gl_enums::GL_QUERY_TARGET).
+///
+/// `params`
+///
+/// > If a buffer is bound to the [`GL_QUERY_RESULT_BUFFER`](crate::gl_enums::GL_QUERY_RESULT_BUFFER)
+/// > target, then `params` is treated as an offset to a location within that
+/// > buffer's data store to receive the result of the query. If no buffer is
+/// > bound to [`GL_QUERY_RESULT_BUFFER`](crate::gl_enums::GL_QUERY_RESULT_BUFFER),
+/// > then `params` is treated as an address in client memory of a variable
+/// > to receive the resulting data.
+///
+/// `offset`
+///
+/// > Specifies the byte offset into `buffer` 's data store where the queried
+/// > result will be written.
+///
+/// ### Description
+/// These commands return a selected parameter of the query object specified
+/// by `id`. [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// returns in `params` a selected parameter of the query object specified
+/// by `id`. [**glGetQueryBufferObject**](crate::context::Context::oxidegl_get_query_buffer_object)
+/// returns in `buffer` a selected parameter of the query object specified
+/// by `id`, by writing it to `buffer` 's data store at the byte offset specified
+/// by `offset`.
+///
+/// `pname` names a specific query object parameter. `pname` can be as follows:
+///
+/// [`GL_QUERY_RESULT`](crate::gl_enums::GL_QUERY_RESULT)
+///
+/// > `params` or `buffer` returns the value of the query object's passed samples
+/// > counter. The initial value is 0.
+///
+/// [`GL_QUERY_RESULT_NO_WAIT`](crate::gl_enums::GL_QUERY_RESULT_NO_WAIT)
+///
+/// > If the result of the query is available (that is, a query of [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE)
+/// > would return non-zero), then `params` or `buffer` returns the value of
+/// > the query object's passed samples counter, otherwise, the data referred
+/// > to by `params` or `buffer` is not modified. The initial value is 0.
+///
+/// [`GL_QUERY_RESULT_AVAILABLE`](crate::gl_enums::GL_QUERY_RESULT_AVAILABLE)
+///
+/// > `params` or `buffer` returns whether the passed samples counter is immediately
+/// > available. If a delay would occur waiting for the query result, [`GL_FALSE`](crate::gl_enums::GL_FALSE)
+/// > is returned. Otherwise, [`GL_TRUE`](crate::gl_enums::GL_TRUE) is returned,
+/// > which also indicates that the results of all previous queries are available
+/// > as well.
+///
+/// [`GL_QUERY_TARGET`](crate::gl_enums::GL_QUERY_TARGET)
+///
+/// > `params` or `buffer` returns the query object's target.
+///
+/// ### Notes
+/// If an error is generated, no change is made to the contents of `params`
+/// or `buffer`.
+///
+/// [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// and [**glGetQueryBufferObject**](crate::context::Context::oxidegl_get_query_buffer_object)
+/// implicitly flush the GL pipeline so that any incomplete rendering delimited
+/// by the occlusion query completes in finite time.
+///
+/// If multiple queries are issued using the same query object `id` before
+/// calling [**glGetQueryObject**](crate::context::Context::oxidegl_get_query_object)
+/// or [**glGetQueryBufferObject**](crate::context::Context::oxidegl_get_query_buffer_object),
+/// the results of the most recent query will be returned. In this case, when
+/// issuing a new query, the results of the previous query are discarded.
+///
+/// [**glGetQueryObjecti64v**](crate::context::Context::oxidegl_get_query_objecti64v)
+/// and [**glGetQueryObjectui64v**](crate::context::Context::oxidegl_get_query_objectui64v)
+/// are available only if the GL version is 3.3 or greater.
+///
+/// [`GL_QUERY_RESULT_NO_WAIT`](crate::gl_enums::GL_QUERY_RESULT_NO_WAIT) is
+/// accepted for `pname` only if the GL version is 4.4 or greater.
+///
+/// The [`GL_QUERY_RESULT_BUFFER`](crate::gl_enums::GL_QUERY_RESULT_BUFFER)
+/// target is available only if the GL version is 4.4 or higher. On earlier
+/// versions of the GL, `params` is always an address in client memory.
+impl Context {
+    pub fn oxidegl_get_query_buffer_objecti64v(
+        &mut self,
+        id: GLuint,
+        buffer: GLuint,
+        pname: QueryObjectParameterName,
+        offset: GLintptr,
+    ) -> GlFallible {
+        panic!("command oxidegl_get_query_buffer_objecti64v not yet implemented");
+    }
+    pub fn oxidegl_get_query_buffer_objectiv(
+        &mut self,
+        id: GLuint,
+        buffer: GLuint,
+        pname: QueryObjectParameterName,
+        offset: GLintptr,
+    ) -> GlFallible {
+        panic!("command oxidegl_get_query_buffer_objectiv not yet implemented");
+    }
+    pub fn oxidegl_get_query_buffer_objectui64v(
+        &mut self,
+        id: GLuint,
+        buffer: GLuint,
+        pname: QueryObjectParameterName,
+        offset: GLintptr,
+    ) -> GlFallible {
+        panic!("command oxidegl_get_query_buffer_objectui64v not yet implemented");
+    }
+    pub fn oxidegl_get_query_buffer_objectuiv(
+        &mut self,
+        id: GLuint,
+        buffer: GLuint,
+        pname: QueryObjectParameterName,
+        offset: GLintptr,
+    ) -> GlFallible {
+        panic!("command oxidegl_get_query_buffer_objectuiv not yet implemented");
+    }
+}
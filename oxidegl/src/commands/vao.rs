@@ -157,8 +157,15 @@ impl Context {
             size as u32,
             r#type,
             relativeoffset,
-            IntegralCastBehavior::Cast,
-        )
+            if normalized {
+                IntegralCastBehavior::Normalize
+            } else {
+                IntegralCastBehavior::Cast
+            },
+        )?;
+        // the stage that fetches the attribute may have to convert it differently
+        self.new_pipeline();
+        Ok(())
     }
     #[allow(clippy::cast_sign_loss)]
     pub fn oxidegl_vertex_attrib_i_format(
@@ -176,7 +183,9 @@ impl Context {
             unsafe { core::mem::transmute::<VertexAttribIType, VertexAttribType>(r#type) },
             relativeoffset,
             IntegralCastBehavior::Native,
-        )
+        )?;
+        self.new_pipeline();
+        Ok(())
     }
     #[allow(clippy::cast_sign_loss)]
     pub fn oxidegl_vertex_array_attrib_format(
@@ -193,8 +202,15 @@ impl Context {
             size as u32,
             r#type,
             relativeoffset,
-            IntegralCastBehavior::Cast,
-        )
+            if normalized {
+                IntegralCastBehavior::Normalize
+            } else {
+                IntegralCastBehavior::Cast
+            },
+        )?;
+        // the stage that fetches the attribute may have to convert it differently
+        self.new_pipeline();
+        Ok(())
     }
     #[allow(clippy::cast_sign_loss)]
     pub fn oxidegl_vertex_array_attrib_i_format(
@@ -213,7 +229,9 @@ impl Context {
             unsafe { core::mem::transmute::<VertexAttribIType, VertexAttribType>(r#type) },
             relativeoffset,
             IntegralCastBehavior::Native,
-        )
+        )?;
+        self.new_pipeline();
+        Ok(())
     }
     pub fn oxidegl_vertex_array_attrib_l_format(
         &mut self,
@@ -552,6 +570,7 @@ impl Context {
         run_if_changed!(self.gl_state.vao_binding;= name => {
                 self.remap_buffers();
                 self.update_encoder();
+                self.new_pipeline();
             }
         );
         Ok(())
@@ -645,25 +664,33 @@ impl Context {
 impl Context {
     pub fn oxidegl_disable_vertex_attrib_array(&mut self, index: GLuint) -> GlFallible {
         self.get_vao(CurrentBinding)?
-            .set_attrib_enabled(index, false)
+            .set_attrib_enabled(index, false)?;
+        self.new_pipeline();
+        Ok(())
     }
     pub fn oxidegl_enable_vertex_attrib_array(&mut self, index: GLuint) -> GlFallible {
         self.get_vao(CurrentBinding)?
-            .set_attrib_enabled(index, true)
+            .set_attrib_enabled(index, true)?;
+        self.new_pipeline();
+        Ok(())
     }
     pub fn oxidegl_disable_vertex_array_attrib(
         &mut self,
         vaobj: GLuint,
         index: GLuint,
     ) -> GlFallible {
-        self.get_vao(vaobj)?.set_attrib_enabled(index, false)
+        self.get_vao(vaobj)?.set_attrib_enabled(index, false)?;
+        self.new_pipeline();
+        Ok(())
     }
     pub fn oxidegl_enable_vertex_array_attrib(
         &mut self,
         vaobj: GLuint,
         index: GLuint,
     ) -> GlFallible {
-        self.get_vao(vaobj)?.set_attrib_enabled(index, true)
+        self.get_vao(vaobj)?.set_attrib_enabled(index, true)?;
+        self.new_pipeline();
+        Ok(())
     }
 }
 
@@ -984,6 +1011,8 @@ impl Context {
             pointer,
             integer_behavior,
             array_buffer,
-        )
+        )?;
+        self.new_pipeline();
+        Ok(())
     }
 }
@@ -0,0 +1,199 @@
+//! Worker threads that shader compilation and program linking are offloaded to, as exposed by
+//! `GL_KHR_parallel_shader_compile`
+//!
+//! Jobs return a [`Pending`] handle. `GL_COMPLETION_STATUS_KHR` queries poll it, anything else that needs the result
+//! blocks on it.
+//!
+//! Jobs report compile and link errors as part of their result. `OxideGL` is built with `panic = "abort"`, so a job that
+//! panics takes down the whole process like a panic on the application thread would, and is never observed by its
+//! handle.
+
+use std::{
+    fmt::Debug,
+    sync::{
+        Arc, Condvar, Mutex, MutexGuard, PoisonError,
+        mpsc::{self, Receiver, Sender},
+    },
+    thread,
+};
+
+use crate::debug::{gl_debug, mark_worker_thread};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+enum Message {
+    Job(Job),
+    /// Sent to shrink the pool, the worker that receives it exits
+    Exit,
+}
+
+/// Result of a job submitted to a [`CompilePool`], which may still be running
+pub(crate) struct Pending<T> {
+    shared: Arc<(Mutex<Option<T>>, Condvar)>,
+}
+impl<T> Clone for Pending<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+impl<T> Debug for Pending<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pending")
+            .field("complete", &self.is_complete())
+            .finish()
+    }
+}
+impl<T> Pending<T> {
+    fn new() -> Self {
+        Self {
+            shared: Arc::new((Mutex::new(None), Condvar::new())),
+        }
+    }
+    fn lock(&self) -> MutexGuard<'_, Option<T>> {
+        // the value is only ever written in one go, so a poisoned lock can't hold a partial result
+        self.shared.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    fn complete(&self, value: T) {
+        *self.lock() = Some(value);
+        self.shared.1.notify_all();
+    }
+    fn wait_guard(&self) -> MutexGuard<'_, Option<T>> {
+        let guard = self.lock();
+        self.shared
+            .1
+            .wait_while(guard, |v| v.is_none())
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+    /// Whether the job has finished, without blocking
+    pub(crate) fn is_complete(&self) -> bool {
+        self.lock().is_some()
+    }
+    /// Block until the job has finished and take its result
+    pub(crate) fn wait(self) -> T {
+        self.wait_guard().take().expect("result was already taken")
+    }
+    /// Block until the job has finished and clone its result, leaving it in place for other handles
+    pub(crate) fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.wait_guard()
+            .as_ref()
+            .expect("result was already taken")
+            .clone()
+    }
+}
+
+/// A pool of worker threads, which grows on demand up to a maximum set by `glMaxShaderCompilerThreadsKHR`
+pub(crate) struct CompilePool {
+    /// maximum number of worker threads. Jobs are run synchronously on the submitting thread when this is 0
+    max_threads: u32,
+    /// number of worker threads that are alive and haven't been sent an exit message
+    threads: u32,
+    sender: Sender<Message>,
+    receiver: Arc<Mutex<Receiver<Message>>>,
+}
+impl Debug for CompilePool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompilePool")
+            .field("max_threads", &self.max_threads)
+            .field("threads", &self.threads)
+            .finish_non_exhaustive()
+    }
+}
+impl Default for CompilePool {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            max_threads: Self::default_max_threads(),
+            threads: 0,
+            sender,
+            receiver: Arc::new(Mutex::new(receiver)),
+        }
+    }
+}
+impl CompilePool {
+    /// Number of worker threads used when the application doesn't ask for a specific number
+    fn default_max_threads() -> u32 {
+        thread::available_parallelism().map_or(1, |n| u32::try_from(n.get()).unwrap_or(u32::MAX))
+    }
+    #[inline]
+    pub(crate) fn max_threads(&self) -> u32 {
+        self.max_threads
+    }
+    /// Set the maximum number of worker threads. `u32::MAX` selects the default, 0 makes all jobs run synchronously
+    pub(crate) fn set_max_threads(&mut self, count: u32) {
+        self.max_threads = if count == u32::MAX {
+            Self::default_max_threads()
+        } else {
+            count
+        };
+        gl_debug!(src: ShaderCompiler, "set maximum shader compiler thread count to {}", self.max_threads);
+        // workers exit once they are done with the jobs queued before the exit message
+        while self.threads > self.max_threads {
+            let _ = self.sender.send(Message::Exit);
+            self.threads -= 1;
+        }
+    }
+    /// Run a job on the pool
+    pub(crate) fn submit<T: Send + 'static>(
+        &mut self,
+        job: impl FnOnce() -> T + Send + 'static,
+    ) -> Pending<T> {
+        let pending = Pending::new();
+        if self.max_threads == 0 {
+            pending.complete(job());
+            return pending;
+        }
+        let handle = pending.clone();
+        let job = Box::new(move || handle.complete(job()));
+        if self.threads < self.max_threads {
+            self.spawn_worker();
+        }
+        self.sender
+            .send(Message::Job(job))
+            .expect("compile pool workers hold the receiver until the pool is dropped");
+        pending
+    }
+    fn spawn_worker(&mut self) {
+        let receiver = Arc::clone(&self.receiver);
+        self.threads += 1;
+        thread::Builder::new()
+            .name("oxidegl-shader-compiler".into())
+            .spawn(move || {
+                mark_worker_thread();
+                loop {
+                    // release the lock before running the job so other workers can pick up the next one
+                    let message = receiver
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .recv();
+                    match message {
+                        Ok(Message::Job(job)) => job(),
+                        // the pool was shrunk or dropped
+                        Ok(Message::Exit) | Err(_) => break,
+                    }
+                }
+            })
+            .expect("failed to spawn shader compiler thread");
+    }
+}
+
+#[test]
+fn compile_pool_jobs() {
+    let mut pool = CompilePool::default();
+    pool.set_max_threads(2);
+    let (tx, rx) = mpsc::channel::<()>();
+    let blocked = pool.submit(move || rx.recv().is_ok());
+    let other = pool.submit(|| 2 + 2);
+    // the second job runs on another worker while the first one is blocked
+    assert_eq!(other.get(), 4);
+    assert!(!blocked.is_complete());
+    tx.send(()).unwrap();
+    assert!(blocked.wait());
+
+    pool.set_max_threads(0);
+    assert!(pool.submit(|| 1).is_complete());
+}
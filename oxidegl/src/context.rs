@@ -1,7 +1,13 @@
-use crate::{gl_enums::ErrorCode, render::Renderer};
+#[cfg(target_vendor = "apple")]
+use crate::render::{PixelFormat, metal::MetalBackend};
+use crate::{
+    gl_enums::ErrorCode,
+    render::{Renderer, headless::HeadlessBackend, software::SoftwareBackend},
+};
+#[cfg(target_vendor = "apple")]
 use objc2::rc::Retained;
+#[cfg(target_vendor = "apple")]
 use objc2_app_kit::NSView;
-use objc2_metal::MTLPixelFormat;
 use state::GlState;
 
 pub(crate) mod state;
@@ -13,16 +19,63 @@ pub struct Context {
 }
 
 impl Context {
+    #[cfg(target_vendor = "apple")]
     #[must_use]
     pub fn new() -> Self {
         Self {
             gl_state: GlState::default(),
-            renderer: Renderer::new(MTLPixelFormat::BGRA8Unorm_sRGB, None, None),
+            renderer: Renderer::new(Box::new(MetalBackend::new(
+                PixelFormat::BGRA8Unorm_sRGB.into(),
+                None,
+                None,
+            ))),
         }
     }
+    /// Creates a context that is not backed by a GPU or a view. Rendering work is recorded instead of executed,
+    /// and can be inspected with [`Context::recorded_commands`]
+    #[must_use]
+    pub fn new_headless(width: u32, height: u32) -> Self {
+        let mut ctx = Self {
+            gl_state: GlState::default(),
+            renderer: Renderer::new(Box::new(HeadlessBackend::new(width, height))),
+        };
+        ctx.gl_state.viewport.width = width;
+        ctx.gl_state.viewport.height = height;
+
+        ctx.gl_state.scissor_box.width = width;
+        ctx.gl_state.scissor_box.height = height;
+        ctx
+    }
+    /// Creates a context that renders on the CPU with the reference software rasterizer, without a GPU or a view.
+    /// The rendered image can be read back with [`Context::color_buffer`]
+    #[must_use]
+    pub fn new_software(width: u32, height: u32) -> Self {
+        let mut ctx = Self {
+            gl_state: GlState::default(),
+            renderer: Renderer::new(Box::new(SoftwareBackend::new(width, height))),
+        };
+        ctx.gl_state.viewport.width = width;
+        ctx.gl_state.viewport.height = height;
+
+        ctx.gl_state.scissor_box.width = width;
+        ctx.gl_state.scissor_box.height = height;
+        ctx
+    }
     pub fn set_error(&mut self, error: ErrorCode) {
         self.gl_state.error = error;
     }
+    /// The GL state of this context, for inspection and reporting
+    #[must_use]
+    pub fn gl_state(&self) -> &impl std::fmt::Debug {
+        &self.gl_state
+    }
+    /// Whether a buffer is bound to `GL_ELEMENT_ARRAY_BUFFER`, in which case the indices of indexed draws are read from
+    /// it rather than from client memory
+    #[must_use]
+    pub fn element_array_buffer_bound(&self) -> bool {
+        self.gl_state.buffer_bindings.element_array.is_some()
+    }
+    #[cfg(target_vendor = "apple")]
     pub fn set_view(&mut self, view: &Retained<NSView>) {
         let backing_scale_factor = view.window().map_or(1.0, |w| w.backingScaleFactor());
         self.renderer.set_view(view, backing_scale_factor);
@@ -36,6 +89,7 @@ impl Context {
     }
 }
 
+#[cfg(target_vendor = "apple")]
 impl Default for Context {
     fn default() -> Self {
         Self::new()
@@ -1,13 +1,13 @@
 use std::{fmt::Debug, ops::Deref, ptr};
 
 use ahash::HashSet;
-use objc2_metal::{MTLBlendFactor, MTLBlendOperation};
 
 use crate::{
     gl_enums::{
-        BlendEquationModeEXT, BlendingFactor, ClearBufferMask, DepthFunction, ErrorCode,
-        GL_CONTEXT_CORE_PROFILE_BIT, GL_CONTEXT_FLAG_FORWARD_COMPATIBLE_BIT,
-        GL_CONTEXT_FLAG_NO_ERROR_BIT, StencilFunction, StencilOp, TriangleFace,
+        BlendEquationModeEXT, BlendingFactor, ClearBufferMask, ClipControlDepth, ClipControlOrigin,
+        DepthFunction, ErrorCode, GL_CONTEXT_CORE_PROFILE_BIT,
+        GL_CONTEXT_FLAG_FORWARD_COMPATIBLE_BIT, GL_CONTEXT_FLAG_NO_ERROR_BIT, StencilFunction,
+        StencilOp, TriangleFace,
     },
     gl_types::GLenum,
     util::bitflag_bits,
@@ -15,11 +15,20 @@ use crate::{
 
 use crate::{
     commands::buffer::Buffer,
+    compile_pool::CompilePool,
+    context::Context,
     debug::DebugState,
     framebuffer::{DrawBuffers, Framebuffer, MAX_COLOR_ATTACHMENTS},
     gl_object::{NamedObjectList, ObjectName},
     program::Program,
+    program_pipeline::{PIPELINE_STAGES, ProgramPipeline},
+    query::{ActiveQuery, Query},
     shader::Shader,
+    texture::{
+        Texture,
+        image::{ImageUnit, MAX_IMAGE_UNITS},
+    },
+    transform_feedback::{FeedbackState, TransformFeedback},
     vao::Vao,
 };
 
@@ -51,6 +60,15 @@ pub(crate) struct GlState {
     pub(crate) program_deletion_queue: HashSet<ObjectName<Program>>,
     /// Current program to render with
     pub(crate) program_binding: Option<ObjectName<Program>>,
+    /// List of program pipeline object states
+    pub(crate) program_pipeline_list: NamedObjectList<ProgramPipeline>,
+    /// The program pipeline to render with if there is no current program
+    pub(crate) program_pipeline_binding: Option<ObjectName<ProgramPipeline>>,
+    /// Subroutines selected for the subroutine uniform locations of each stage, by location and in the order of
+    /// [`PIPELINE_STAGES`]. Empty for stages whose locations have not been set since the program was made current
+    pub(crate) subroutine_selections: [Vec<u32>; PIPELINE_STAGES.len()],
+    /// Worker threads that shaders are compiled and programs are linked on
+    pub(crate) compile_pool: CompilePool,
 
     /// List of framebuffer object states
     pub(crate) framebuffer_list: NamedObjectList<Framebuffer>,
@@ -62,6 +80,10 @@ pub(crate) struct GlState {
     //TODO: these should be arrays in order to support viewport arrays
     pub(crate) scissor_box: PixelAlignedRect,
     pub(crate) viewport: PixelAlignedRect,
+    /// Mapping of normalized device depth to window depth set by `glDepthRange`
+    pub(crate) depth_range: DepthRange,
+    /// Origin and depth mode of the clip volume set by `glClipControl`
+    pub(crate) clip_control: ClipControl,
 
     pub(crate) clear_values: ClearState,
     pub(crate) stencil: StencilState,
@@ -71,6 +93,28 @@ pub(crate) struct GlState {
 
     pub(crate) depth_func: DepthFunction,
 
+    /// Patch parameters set by `glPatchParameter`
+    pub(crate) patch: PatchState,
+    /// Point rasterization parameters set by `glPointSize` and `glPointParameter`
+    pub(crate) points: PointState,
+
+    /// List of transform feedback object states
+    pub(crate) transform_feedback_list: NamedObjectList<TransformFeedback>,
+    /// The current transform feedback object (None: default transform feedback object)
+    pub(crate) transform_feedback_binding: Option<ObjectName<TransformFeedback>>,
+    /// State of the default transform feedback object
+    pub(crate) default_transform_feedback: FeedbackState,
+
+    /// List of texture objects
+    pub(crate) texture_list: NamedObjectList<Texture>,
+    /// Textures bound to the image units
+    pub(crate) image_units: [ImageUnit; MAX_IMAGE_UNITS as usize],
+
+    /// List of query object states
+    pub(crate) query_list: NamedObjectList<Query>,
+    /// Queries that are currently counting
+    pub(crate) active_queries: Vec<ActiveQuery>,
+
     /// storage for the debug state associated with this context (if it is not the current context). If this context is
     /// current, you'll need to use [`with_debug_state`](super::debug::with_debug_state) or
     /// [`with_debug_state_mut`](super::debug::with_debug_state_mut) to interact with the current debug state
@@ -118,13 +162,50 @@ impl Default for ClearState {
         }
     }
 }
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PatchState {
+    /// Number of vertices that make up each patch primitive
+    pub(crate) vertices: u32,
+    /// Tessellation levels used when no tessellation control shader is present
+    pub(crate) default_outer_level: [f32; 4],
+    pub(crate) default_inner_level: [f32; 2],
+}
+impl Default for PatchState {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            vertices: 3,
+            default_outer_level: [1.0; 4],
+            default_inner_level: [1.0; 2],
+        }
+    }
+}
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PointState {
+    /// Diameter of rasterized points, unless the program writes it (`GL_PROGRAM_POINT_SIZE`)
+    pub(crate) size: f32,
+    /// Size below which multisampled points are faded out instead of shrunk
+    pub(crate) fade_threshold_size: f32,
+    /// Origin of `gl_PointCoord` within a point, which shares its values with the clip control origin
+    pub(crate) sprite_coord_origin: ClipControlOrigin,
+}
+impl Default for PointState {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            size: 1.0,
+            fade_threshold_size: 1.0,
+            sprite_coord_origin: ClipControlOrigin::UpperLeft,
+        }
+    }
+}
 #[derive(Clone, Copy, Debug, Default)]
 pub(crate) struct StencilState {
     pub(crate) front: StencilFaceState,
     pub(crate) back: StencilFaceState,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct StencilFaceState {
     /// Comparison function used to decide whether to discard the fragment or not
     pub(crate) func: StencilFunction,
@@ -150,7 +231,7 @@ impl Default for StencilFaceState {
         }
     }
 }
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DrawbufferBlendState {
     pub(crate) blend_enabled: bool,
     pub(crate) src_rgb: BlendingFactor,
@@ -180,57 +261,6 @@ pub struct BlendState {
     pub(crate) drawbuffer_states: [DrawbufferBlendState; MAX_COLOR_ATTACHMENTS as usize],
 }
 
-impl From<BlendingFactor> for MTLBlendFactor {
-    #[inline]
-    fn from(value: BlendingFactor) -> Self {
-        #[allow(clippy::enum_glob_use)]
-        use BlendingFactor::*;
-
-        match value {
-            // constants
-            Zero => Self::Zero,
-            One => Self::One,
-
-            // source color/alpha
-            SrcColor => Self::SourceColor,
-            OneMinusSrcColor => Self::OneMinusSourceColor,
-            SrcAlpha => Self::SourceAlpha,
-            OneMinusSrcAlpha => Self::OneMinusSourceAlpha,
-            SrcAlphaSaturate => Self::SourceAlphaSaturated,
-
-            // destination color/alpha
-            DstColor => Self::DestinationColor,
-            OneMinusDstColor => Self::OneMinusDestinationColor,
-            DstAlpha => Self::DestinationAlpha,
-            OneMinusDstAlpha => Self::OneMinusDestinationAlpha,
-
-            // constant color/alpha
-            ConstantColor => Self::BlendColor,
-            OneMinusConstantColor => Self::OneMinusBlendColor,
-            ConstantAlpha => Self::BlendAlpha,
-            OneMinusConstantAlpha => Self::OneMinusBlendAlpha,
-
-            // source #2 color/alpha (for dual-source blending)
-            Src1Alpha => Self::Source1Alpha,
-            Src1Color => Self::Source1Color,
-            OneMinusSrc1Color => Self::OneMinusSource1Color,
-            OneMinusSrc1Alpha => Self::OneMinusSource1Alpha,
-        }
-    }
-}
-impl From<BlendEquationModeEXT> for MTLBlendOperation {
-    #[inline]
-    fn from(value: BlendEquationModeEXT) -> Self {
-        match value {
-            BlendEquationModeEXT::FuncAdd => Self::Add,
-            BlendEquationModeEXT::FuncReverseSubtract => Self::ReverseSubtract,
-            BlendEquationModeEXT::FuncSubtract => Self::Subtract,
-            BlendEquationModeEXT::Min => Self::Min,
-            BlendEquationModeEXT::Max => Self::Max,
-        }
-    }
-}
-
 bitflag_bits! {
     #[derive(Debug, Copy, Clone, Eq, PartialEq)]
     #[repr(transparent)]
@@ -238,7 +268,8 @@ bitflag_bits! {
 
         // don't include indexed caps
         // BLEND: 0,
-        // SCISSOR_TEST: 19,
+        // the scissor test is indexed by viewport, but there is only a single viewport
+        SCISSOR_TEST: 19,
 
         MULTISAMPLE: 1,
         SAMPLE_ALPHA_TO_COVERAGE: 2,
@@ -284,6 +315,14 @@ bitflag_bits! {
         PROGRAM_POINT_SIZE: 43,
         PRIMITIVE_RESTART: 44,
         PRIMITIVE_RESTART_FIXED_INDEX: 45,
+        CLIP_DISTANCE0: 46,
+        CLIP_DISTANCE1: 47,
+        CLIP_DISTANCE2: 48,
+        CLIP_DISTANCE3: 49,
+        CLIP_DISTANCE4: 50,
+        CLIP_DISTANCE5: 51,
+        CLIP_DISTANCE6: 52,
+        CLIP_DISTANCE7: 53,
     }
 
 }
@@ -301,6 +340,12 @@ impl Capabilities {
     pub(crate) fn enable(&mut self, cap: Self) {
         *self = self.union(cap);
     }
+    /// Mask of the enabled user clip planes, with the bit of `GL_CLIP_DISTANCE0` first
+    #[inline]
+    #[expect(clippy::cast_possible_truncation, reason = "there are 8 clip planes")]
+    pub(crate) fn clip_distances(self) -> u8 {
+        (self.bits() >> Self::CLIP_DISTANCE0.bits().trailing_zeros()) as u8
+    }
     #[inline]
     pub(crate) fn set_to(&mut self, to: bool, cap: Self) -> bool {
         let mut r = self.intersects(cap);
@@ -319,6 +364,48 @@ impl Default for Capabilities {
         Self::empty()
     }
 }
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DepthRange {
+    /// Window depth of the near plane of the clip volume
+    pub(crate) near: f64,
+    /// Window depth of the far plane of the clip volume
+    pub(crate) far: f64,
+}
+impl DepthRange {
+    /// A depth range with both values clamped to [0, 1]
+    #[inline]
+    pub(crate) fn new(near: f64, far: f64) -> Self {
+        Self {
+            near: near.clamp(0.0, 1.0),
+            far: far.clamp(0.0, 1.0),
+        }
+    }
+}
+impl Default for DepthRange {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            near: 0.0,
+            far: 1.0,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClipControl {
+    /// Whether clip space y points up (lower left origin) or down (upper left origin) in window coordinates
+    pub(crate) origin: ClipControlOrigin,
+    /// Whether the clip volume spans -w to w or 0 to w in z
+    pub(crate) depth: ClipControlDepth,
+}
+impl Default for ClipControl {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            origin: ClipControlOrigin::LowerLeft,
+            depth: ClipControlDepth::NegativeOneToOne,
+        }
+    }
+}
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 #[repr(C)]
 pub(crate) struct PixelAlignedRect {
@@ -355,6 +442,12 @@ pub struct BufferBindings {
     pub(crate) array: Option<ObjectName<Buffer>>,
     /// Atomic counter storage
     pub(crate) atomic_counter: [Option<ObjectName<Buffer>>; MAX_ATOMIC_COUNTER_BUFFER_BINDINGS],
+    /// Offset and size in bytes of the ranges of the atomic counter storage bindings, `None` if the whole buffer is bound
+    pub(crate) atomic_counter_ranges: [Option<(usize, usize)>; MAX_ATOMIC_COUNTER_BUFFER_BINDINGS],
+    /// Generic (non-indexed) bindings of the indexed binding targets, which are set by all binding commands
+    pub(crate) generic_atomic_counter: Option<ObjectName<Buffer>>,
+    pub(crate) generic_shader_storage: Option<ObjectName<Buffer>>,
+    pub(crate) generic_uniform: Option<ObjectName<Buffer>>,
     /// Buffer copy source
     pub(crate) copy_read: Option<ObjectName<Buffer>>,
     /// Buffer copy destination
@@ -377,9 +470,8 @@ pub struct BufferBindings {
     pub(crate) shader_storage: [Option<ObjectName<Buffer>>; MAX_SHADER_STORAGE_BUFFER_BINDINGS],
     /// Texture data buffer
     pub(crate) texture: Option<ObjectName<Buffer>>,
-    /// Transform feedback result buffers
-    pub(crate) transform_feedback:
-        [Option<ObjectName<Buffer>>; MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS],
+    /// Transform feedback result buffer (the indexed bindings are part of the current transform feedback object)
+    pub(crate) transform_feedback: Option<ObjectName<Buffer>>,
     /// Uniform storage buffers
     pub(crate) uniform: [Option<ObjectName<Buffer>>; MAX_UNIFORM_BUFFER_BINDINGS],
 }
@@ -431,7 +523,6 @@ impl From<[bool; 4]> for ColorWriteMask {
 
 #[derive(Debug, Clone, Copy)]
 pub struct Characteristics {
-    pub(crate) point_size: f32,
     pub(crate) point_size_range: [f32; 2],
     pub(crate) point_size_granularity: f32,
     pub(crate) line_width: f32,
@@ -441,13 +532,18 @@ pub struct Characteristics {
 }
 impl Default for Characteristics {
     fn default() -> Self {
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "there are far fewer than u32::MAX extensions"
+        )]
+        let num_extensions = Context::EXTENSIONS.len() as u32;
         Self {
-            point_size_range: [1.0, 1.0],
+            // the largest point size Metal supports
+            point_size_range: [1.0, 511.0],
             point_size_granularity: 0.0001,
             context_flags: GL_CONTEXT_FLAG_FORWARD_COMPATIBLE_BIT | GL_CONTEXT_FLAG_NO_ERROR_BIT,
             context_profile_mask: GL_CONTEXT_CORE_PROFILE_BIT,
-            num_extensions: 1,
-            point_size: 1.0,
+            num_extensions,
             line_width: 1.0,
         }
     }
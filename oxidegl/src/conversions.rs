@@ -4,7 +4,7 @@ use crate::{
     debug::gl_err,
     error::{GlError, GlFallible},
     gl_object::{NamedObject, ObjectName},
-    gl_types::{GLenum, GLsizei},
+    gl_types::{GLchar, GLenum, GLsizei},
     util::trimmed_type_name,
 };
 
@@ -22,6 +22,47 @@ macro_rules! sizei {
     };
 }
 pub(crate) use sizei;
+
+/// Write a string to a client buffer of `buf_size` bytes, following the conventions shared by the GL commands that return
+/// strings: the string is truncated to leave room for a nul terminator, and the number of bytes written (not counting the
+/// terminator) is stored to `length` if it isn't null
+/// # Safety
+/// `buf` must either be null or valid for writes of `buf_size` bytes, and `length` must either be null or valid for a write
+pub(crate) unsafe fn write_gl_string(
+    s: &str,
+    buf_size: u32,
+    length: *mut GLsizei,
+    buf: *mut GLchar,
+) {
+    let written = if buf.is_null() || buf_size == 0 {
+        0
+    } else {
+        let written = s.len().min(buf_size as usize - 1);
+        // Safety: caller ensures buf is valid for writes of buf_size bytes, of which we write at most buf_size
+        unsafe {
+            ptr::copy_nonoverlapping(s.as_ptr().cast::<GLchar>(), buf, written);
+            buf.add(written).write(0);
+        }
+        written
+    };
+    if !length.is_null() {
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            reason = "written is less than buf_size, which came from a GLsizei"
+        )]
+        // Safety: checked for null, otherwise caller ensures length is valid for a write
+        unsafe {
+            length.write(written as GLsizei);
+        }
+    }
+}
+/// Length of a string as reported by the `*_LENGTH` queries of the commands that return it: the length including the nul
+/// terminator, or 0 for an empty string
+#[inline]
+pub(crate) fn gl_string_len(s: &str) -> usize {
+    if s.is_empty() { 0 } else { s.len() + 1 }
+}
 /// Trait defined for all custom bitfield and enum types which allows them to be unsafely created
 /// from an underlying `GLenum` (u32) value with checks on debug builds
 pub(crate) trait GlEnumGroup: Sized {
@@ -1,7 +1,5 @@
 use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
 use log::{Level, Record, RecordBuilder, logger};
-use objc2::AllocAnyThread;
-use objc2_foundation::NSString;
 use std::{
     any,
     cell::Cell,
@@ -10,8 +8,7 @@ use std::{
     fmt::Arguments,
     mem::{self, MaybeUninit},
     pin::Pin,
-    ptr::{self, NonNull},
-    slice,
+    ptr, slice,
 };
 //TODO fallible implementations for these commands
 use crate::{
@@ -27,6 +24,14 @@ use super::{
 thread_local! {
     // We store the debug logging infrastructure for the current context in a separate thread local to avoid passing it in by-reference every log call (which cannot be avoided/worked around with macros)
     static DEBUG_STATE: Cell<Option<DebugState>> = const { Cell::new(None) };
+    // Whether this thread is a worker thread owned by OxideGL, which never has a current context
+    static WORKER_THREAD: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Mark the current thread as a worker thread. Messages logged on it are forwarded to the logger only, without
+/// complaining about the missing context
+pub(crate) fn mark_worker_thread() {
+    WORKER_THREAD.set(true);
 }
 
 #[inline]
@@ -120,6 +125,33 @@ impl DisabledMessages {
         }
     }
 }
+/// Copies a message passed to a debug command into an owned string. A negative `length` means
+/// that `message` is nul-terminated
+///
+/// # Safety
+/// If `length` is negative, `message` must point to a valid nul-terminated C string, otherwise it
+/// must point to at least `length` bytes
+unsafe fn message_string(length: i32, message: *const c_char) -> Box<CStr> {
+    match length {
+        // Safety: caller ensures that if length is negative, message points to a valid nul-terminated C-string
+        ..0 => unsafe { CStr::from_ptr(message) }.into(),
+        0 => c"".into(),
+        1.. => {
+            #[expect(clippy::cast_sign_loss, reason = "sign loss is avoided via match arm")]
+            let mut bytes =
+            // Safety: caller ensures that if length is positive, it represents the length of message
+                unsafe { slice::from_raw_parts(message.cast::<u8>(), length as usize) }
+                    .to_vec();
+            // message is not guaranteed to be nul-terminated afaict, rust's CStr(ing) (and the GL client) expects this
+            if *bytes.last().unwrap() != 0 {
+                bytes.push(0);
+            }
+            CString::from_vec_with_nul(bytes)
+                .unwrap()
+                .into_boxed_c_str()
+        }
+    }
+}
 impl DebugState {
     #[inline]
     fn should_log(&self, meta: &DebugMessageMeta) -> bool {
@@ -226,25 +258,8 @@ impl DebugState {
         length: i32,
         message: *const c_char,
     ) {
-        let msg: Box<CStr> = match length {
-            // Safety: caller ensures that if length is negative, message points to a valid nul-terminated C-string
-            ..0 => unsafe { CStr::from_ptr(message) }.into(),
-            0 => c"".into(),
-            1.. => {
-                #[expect(clippy::cast_sign_loss, reason = "sign loss is avoided via match arm")]
-                let mut bytes =
-                // Safety: caller ensures that if length is positive, it represents the length of message
-                    unsafe { slice::from_raw_parts(message.cast::<u8>(), length as usize) }
-                        .to_vec();
-                // message is not guaranteed to be nul-terminated afaict, rust's CStr(ing) (and the GL client) expects this
-                if *bytes.last().unwrap() != 0 {
-                    bytes.push(0);
-                }
-                CString::from_vec_with_nul(bytes)
-                    .unwrap()
-                    .into_boxed_c_str()
-            }
-        };
+        // Safety: caller ensures message and length describe a valid string
+        let msg = unsafe { message_string(length, message) };
         let mut new_group = self.debug_groups.last().unwrap().clone();
         new_group.id = id;
         new_group.message = msg;
@@ -306,6 +321,21 @@ impl DebugState {
             v.message.to_str().expect("message wasn't valid UTF-8!")
         ));
     }
+    /// glDebugMessageInsert impl
+    pub(crate) unsafe fn insert_message(
+        &mut self,
+        meta: DebugMessageMeta,
+        length: i32,
+        message: *const c_char,
+    ) {
+        if !self.should_log(&meta) {
+            return;
+        }
+        // Safety: caller ensures message and length describe a valid string
+        let msg = unsafe { message_string(length, message) };
+        // the message came from the application, so it only goes back to the GL client
+        self.log_to_gl_client(msg, meta);
+    }
     /// __NOT PART OF PUBLIC API__
     #[doc(hidden)]
     pub fn log_impl(id: u32, gl_src: DebugSource, gl_ty: DebugType, rec: &Record) {
@@ -316,9 +346,11 @@ impl DebugState {
             id,
         };
         let Some(s) = DEBUG_STATE.take() else {
-            log::warn!(
-                "OxideGL tried to log the following message, but logging context wasn't set:"
-            );
+            if !WORKER_THREAD.get() {
+                log::warn!(
+                    "OxideGL tried to log the following message, but logging context wasn't set:"
+                );
+            }
             logger().log(rec);
             return;
         };
@@ -505,16 +537,7 @@ impl DebugState {
         T::set_debug_label(
             ctx,
             name,
-            // Safety: null_terminated_c_string points to an initialized, nul-terminated C string (as upheld by the CStr type)
-            label.as_deref().map(|c| unsafe {
-                let alloc = NSString::alloc();
-                NSString::initWithCString_encoding(
-                    alloc,
-                    NonNull::new_unchecked(c.as_ptr().cast_mut()),
-                    NSString::defaultCStringEncoding(),
-                )
-                .expect("failed to create NSString")
-            }),
+            label.as_deref().map(CStr::to_string_lossy).as_deref(),
         );
         if let Some(label) = label {
             map_for_type.insert(name.to_raw(), label);
@@ -118,7 +118,7 @@ impl MetalProperties {
             },
         }
     }
-    fn get_texture_caps(&self, format: MTLPixelFormat) -> Option<TextureCaps> {
+    pub(crate) fn get_texture_caps(&self, format: MTLPixelFormat) -> Option<TextureCaps> {
         use MTLPixelFormat as MF;
 
         let device_families = self.families;
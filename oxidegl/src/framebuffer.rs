@@ -1,9 +1,9 @@
+#[cfg(target_vendor = "apple")]
 use objc2_metal::MTLTexture;
 
-use crate::{
-    gl_enums::{DrawBufferMode, TextureTarget},
-    util::ProtoObjRef,
-};
+use crate::gl_enums::{DrawBufferMode, TextureTarget};
+#[cfg(target_vendor = "apple")]
+use crate::util::ProtoObjRef;
 
 use super::gl_object::{LateInit, NamedObject, ObjectName};
 
@@ -33,6 +33,7 @@ impl NamedObject for Framebuffer {
     const LATE_INIT_FUNC: fn(ObjectName<Self>) -> Self = Self::new_default;
 }
 /// GL object wrapping an internal drawable
+#[cfg(target_vendor = "apple")]
 pub(crate) struct RenderBuffer {
     pub(crate) name: ObjectName<Self>,
     pub(crate) drawable: InternalDrawable,
@@ -50,6 +51,7 @@ pub(crate) enum ClearValue {
     Stencil(i32),
 }
 pub trait AttachableTexture {}
+#[cfg(target_vendor = "apple")]
 impl AttachableTexture for RenderBuffer {}
 
 // TODO uncomment when Texture is impled :3
@@ -94,12 +96,14 @@ impl DrawBuffers {
     }
 }
 /// A drawable for usage in rendering
+#[cfg(target_vendor = "apple")]
 #[derive(Debug, Clone)]
 pub(crate) struct InternalDrawable {
     // TODO might not need this field (dims are tracked by the texture object)
     pub(crate) dimensions: (u32, u32),
     pub(crate) tex: ProtoObjRef<dyn MTLTexture>,
 }
+#[cfg(target_vendor = "apple")]
 impl InternalDrawable {
     pub(crate) fn new(color: ProtoObjRef<dyn MTLTexture>, dimensions: (u32, u32)) -> Self {
         Self {
@@ -1369,6 +1369,8 @@ pub const GL_TEXTURE_MAX_ANISOTROPY: GLenum = 34046;
 pub const GL_MAX_TEXTURE_MAX_ANISOTROPY: GLenum = 34047;
 pub const GL_TRANSFORM_FEEDBACK_OVERFLOW: GLenum = 33516;
 pub const GL_TRANSFORM_FEEDBACK_STREAM_OVERFLOW: GLenum = 33517;
+pub const GL_MAX_SHADER_COMPILER_THREADS_KHR: GLenum = 37296;
+pub const GL_COMPLETION_STATUS_KHR: GLenum = 37297;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ::strum_macros::FromRepr)]
 #[repr(u32)]
 pub enum CopyBufferSubDataTarget {
@@ -2615,6 +2617,30 @@ pub enum GetPName {
     TextureBindingCubeMap = GL_TEXTURE_BINDING_CUBE_MAP,
     TextureBindingRectangle = GL_TEXTURE_BINDING_RECTANGLE,
     ParameterBufferBinding = GL_PARAMETER_BUFFER_BINDING,
+    MaxShaderCompilerThreads = GL_MAX_SHADER_COMPILER_THREADS_KHR,
+    PatchVertices = GL_PATCH_VERTICES,
+    PatchDefaultInnerLevel = GL_PATCH_DEFAULT_INNER_LEVEL,
+    PatchDefaultOuterLevel = GL_PATCH_DEFAULT_OUTER_LEVEL,
+    MaxPatchVertices = GL_MAX_PATCH_VERTICES,
+    MaxTessGenLevel = GL_MAX_TESS_GEN_LEVEL,
+    MaxTessControlInputComponents = GL_MAX_TESS_CONTROL_INPUT_COMPONENTS,
+    MaxTessControlOutputComponents = GL_MAX_TESS_CONTROL_OUTPUT_COMPONENTS,
+    MaxTessPatchComponents = GL_MAX_TESS_PATCH_COMPONENTS,
+    MaxTessControlTotalOutputComponents = GL_MAX_TESS_CONTROL_TOTAL_OUTPUT_COMPONENTS,
+    MaxTessEvaluationInputComponents = GL_MAX_TESS_EVALUATION_INPUT_COMPONENTS,
+    MaxTessEvaluationOutputComponents = GL_MAX_TESS_EVALUATION_OUTPUT_COMPONENTS,
+    TransformFeedbackBinding = GL_TRANSFORM_FEEDBACK_BINDING,
+    TransformFeedbackBufferPaused = GL_TRANSFORM_FEEDBACK_BUFFER_PAUSED,
+    TransformFeedbackBufferActive = GL_TRANSFORM_FEEDBACK_BUFFER_ACTIVE,
+    MaxTransformFeedbackInterleavedComponents = GL_MAX_TRANSFORM_FEEDBACK_INTERLEAVED_COMPONENTS,
+    MaxTransformFeedbackSeparateAttribs = GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_ATTRIBS,
+    MaxTransformFeedbackSeparateComponents = GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_COMPONENTS,
+    AtomicCounterBufferStart = GL_ATOMIC_COUNTER_BUFFER_START,
+    AtomicCounterBufferSize = GL_ATOMIC_COUNTER_BUFFER_SIZE,
+    MaxAtomicCounterBufferBindings = GL_MAX_ATOMIC_COUNTER_BUFFER_BINDINGS,
+    ClipOrigin = GL_CLIP_ORIGIN,
+    ClipDepthMode = GL_CLIP_DEPTH_MODE,
+    PointSpriteCoordOrigin = GL_POINT_SPRITE_COORD_ORIGIN,
 }
 impl GlEnumGroup for GetPName {
     unsafe fn from_enum_noerr(val: u32) -> Self {
@@ -3627,6 +3653,7 @@ pub enum UniformType {
     DoubleMat3x4 = GL_DOUBLE_MAT3x4,
     DoubleMat4x2 = GL_DOUBLE_MAT4x2,
     DoubleMat4x3 = GL_DOUBLE_MAT4x3,
+    UnsignedIntAtomicCounter = GL_UNSIGNED_INT_ATOMIC_COUNTER,
 }
 impl GlEnumGroup for UniformType {
     unsafe fn from_enum_noerr(val: u32) -> Self {
@@ -4281,6 +4308,8 @@ pub enum ShaderParameterName {
     CompileStatus = GL_COMPILE_STATUS,
     InfoLogLength = GL_INFO_LOG_LENGTH,
     ShaderSourceLength = GL_SHADER_SOURCE_LENGTH,
+    SpirVBinary = GL_SPIR_V_BINARY,
+    CompletionStatus = GL_COMPLETION_STATUS_KHR,
 }
 impl GlEnumGroup for ShaderParameterName {
     unsafe fn from_enum_noerr(val: u32) -> Self {
@@ -4689,6 +4718,7 @@ pub enum ProgramInterface {
     ComputeSubroutineUniform = GL_COMPUTE_SUBROUTINE_UNIFORM,
     TransformFeedbackVarying = GL_TRANSFORM_FEEDBACK_VARYING,
     TransformFeedbackBuffer = GL_TRANSFORM_FEEDBACK_BUFFER,
+    AtomicCounterBuffer = GL_ATOMIC_COUNTER_BUFFER,
 }
 impl GlEnumGroup for ProgramInterface {
     unsafe fn from_enum_noerr(val: u32) -> Self {
@@ -5220,6 +5250,7 @@ pub enum ProgramProperty {
     ProgramBinaryLength = GL_PROGRAM_BINARY_LENGTH,
     ActiveAtomicCounterBuffers = GL_ACTIVE_ATOMIC_COUNTER_BUFFERS,
     ComputeWorkGroupSize = GL_COMPUTE_WORK_GROUP_SIZE,
+    CompletionStatus = GL_COMPLETION_STATUS_KHR,
 }
 impl GlEnumGroup for ProgramProperty {
     unsafe fn from_enum_noerr(val: u32) -> Self {
@@ -1,8 +1,5 @@
 use std::{cell::UnsafeCell, fmt::Debug, marker::PhantomData, num::NonZeroU32};
 
-use objc2::rc::Retained;
-use objc2_foundation::NSString;
-
 use crate::{
     conversions::{GlDstType, SrcType},
     debug::gl_err,
@@ -21,12 +18,7 @@ use super::{
 /// * an opt-in helper method to generically set the underlying API's debug label
 pub(crate) trait NamedObject: Sized + 'static {
     #[expect(unused_variables)]
-    fn set_debug_label(
-        ctx: &mut Context,
-        name: ObjectName<Self>,
-        label: Option<Retained<NSString>>,
-    ) {
-    }
+    fn set_debug_label(ctx: &mut Context, name: ObjectName<Self>, label: Option<&str>) {}
 
     type LateInitType: GetLateInitTypes<Obj = Self> + GetCellType<Obj = Self>;
 
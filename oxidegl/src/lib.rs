@@ -25,18 +25,22 @@ use log::info;
 pub mod commands;
 pub mod context;
 
+pub(crate) mod compile_pool;
 pub mod debug;
 pub mod error;
 pub(crate) mod framebuffer;
 pub(crate) mod pixel;
 pub(crate) mod program;
+pub(crate) mod program_pipeline;
+pub(crate) mod query;
 pub(crate) mod shader;
 pub(crate) mod texture;
+pub(crate) mod transform_feedback;
 pub(crate) mod vao;
 
 pub(crate) mod gl_object;
 
-pub(crate) mod render;
+pub mod render;
 
 #[allow(
     clippy::cast_lossless,
@@ -47,6 +51,7 @@ pub(crate) mod render;
 )]
 pub mod conversions;
 
+#[cfg(target_vendor = "apple")]
 mod device_properties;
 
 #[allow(non_upper_case_globals, unused)]
@@ -56,6 +61,9 @@ pub mod gl_types;
 
 pub mod util;
 
+#[cfg(test)]
+pub(crate) mod test_util;
+
 /// Prints the oxidegl version to stdout and enables some env vars in configurations with debug assertions
 /// # Safety
 /// Should be called as early in the program execution as possible, as it mutates env vars in a potentially racy way
@@ -7,15 +7,15 @@ use super::{
 use crate::{
     gl_enums::InternalFormat,
     pixel::{ChannelSource, ChannelType},
+    render::PixelFormat,
 };
 use half::f16;
-use objc2_metal::MTLPixelFormat;
 
 //meow
 
 pub(crate) trait InternalFormatInfo {
-    /// The underlying [`MTLPixelFormat`] used to represent this [`InternalFormat`].
-    fn mtl_tex_format() -> MTLPixelFormat;
+    /// The underlying [`PixelFormat`] used to represent this [`InternalFormat`].
+    fn mtl_tex_format() -> PixelFormat;
     fn copyable() -> bool;
     fn view_class() -> Option<TextureViewClass>;
     fn id() -> InternalFormat;
@@ -116,8 +116,8 @@ macro_rules! decl_internal_formats {
                     }
                 }
                 impl InternalFormatInfo for $format {
-                    fn mtl_tex_format() -> MTLPixelFormat {
-                        MTLPixelFormat::$mtl_format
+                    fn mtl_tex_format() -> PixelFormat {
+                        PixelFormat::$mtl_format
                     }
                     fn copyable() -> bool {
                         decl_internal_formats! { present: $(v $_mark)? }
@@ -146,7 +146,7 @@ macro_rules! decl_internal_formats {
 
         )+
         impl InternalFormat {
-            fn mtl_tex_format(self) -> MTLPixelFormat {
+            fn mtl_tex_format(self) -> PixelFormat {
                 match self {
                     $(
                         $(
@@ -1,28 +1,64 @@
-use std::{mem, ptr::NonNull};
+use std::{borrow::Cow, mem, sync::Arc};
 
-use ahash::{HashSet, HashSetExt};
+use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
 use glslang::Compiler as GlslLangCompiler;
-use objc2::{rc::Retained, AllocAnyThread};
 //use naga::back::msl::{Options, PipelineOptions};
 use crate::{
-
-        debug::{gl_debug, gl_trace, with_debug_state},
-        shader::ShaderInternal,
-    gl_enums::ShaderType, util::{NoDebug, ProtoObjRef},
+    compile_pool::{CompilePool, Pending},
+    context::state::MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS,
+    debug::{gl_debug, gl_trace, gl_warn, with_debug_state},
+    gl_enums::{ShaderType, TransformFeedbackBufferMode},
+    program_pipeline::PIPELINE_STAGES,
+    render::{
+        ATOMIC_COUNTER_BUFFER_INDEX, DEFAULT_UNIFORM_BLOCK_INDEX, GEOMETRY_INPUT_INDEX,
+        GEOMETRY_OUTPUT_INDEX, StageCompiler, StageFunction, TESSELLATION_FACTOR_INDEX,
+    },
+    shader::{GlslCompilation, ShaderInternal, SpecializedSpirv, parse_glsl},
 };
-use objc2_foundation::NSString;
-use objc2_metal::{MTLDevice, MTLFunction, MTLLibrary};
+use attributes::{AttributeConversion, FetchStages};
+use cache::{with_shader_cache, CacheKeyBuilder, CachedStage};
+use default_block::{DefaultBlock, UniformTable};
+use feedback::FeedbackLayout;
+use geometry::{GeometryLayout, StageVariable, VariableKind};
+use interface::{LinkedProgramResources, ProgramInterfaces};
+use locations::LocationBindings;
+use spirv::SpirvModule;
 use spirv_cross2::{
-    compile::{msl::CompilerOptions, CompiledArtifact},
-    reflect::ResourceIter,
+    compile::msl::{BindTarget, CompilerOptions, ResourceBinding},
+    spirv::StorageClass,
     targets::Msl,
-    Compiler, Module, SpirvCrossError,
+    Compiler, Module,
 };
+use subroutines::StageSubroutines;
+use tessellation::{TessellationInfo, TessellationLayout};
 
 use super::{
     gl_object::{NamedObject, NamedObjectList, NoLateInit, ObjectName},
     shader::Shader,
 };
+
+pub(crate) mod atomic_counters;
+pub(crate) mod attributes;
+pub(crate) mod binary;
+pub(crate) mod cache;
+pub(crate) mod clip_control;
+pub(crate) mod compute;
+pub(crate) mod default_block;
+pub(crate) mod feedback;
+pub(crate) mod geometry;
+pub(crate) mod images;
+pub(crate) mod interface;
+pub(crate) mod locations;
+pub(crate) mod points;
+pub(crate) mod spirv;
+pub(crate) mod subroutines;
+pub(crate) mod tessellation;
+
+/// Version of the Metal shading language that stages are translated to
+const MSL_VERSION: (u32, u32) = (2, 1);
+/// Descriptor set that SPIRV-Cross emits as discrete resources instead of an argument buffer
+const DISCRETE_DESCRIPTOR_SET: u32 = 3;
+
 #[derive(Debug)]
 pub enum ProgramStageBinding {
     Unbound,
@@ -92,18 +128,87 @@ impl ProgramStageBinding {
         };
         b
     }
-    pub(crate) fn is_empty(&self) -> bool {
-        matches!(self, Self::Unbound)
+    /// Snapshot the shaders bound to this stage, so that it can be linked on the compile pool
+    fn snapshot(&self, shader_list: &NamedObjectList<Shader>) -> Option<StageShaders> {
+        // sort the shaders so that the entry point name and the key of the stage don't depend on hash set order
+        let mut shaders = match self {
+            ProgramStageBinding::Unbound => return None,
+            ProgramStageBinding::Spirv(name) => vec![shader_list.get(*name)],
+            ProgramStageBinding::Glsl(hash_set) => {
+                // dont need to recheck shader name validity here, we can just panic on failiure
+                hash_set.iter().map(|&name| shader_list.get(name)).collect()
+            }
+        };
+        shaders.sort_unstable_by_key(|s| s.name.to_raw());
+        let sources = match &shaders[0].internal {
+            ShaderInternal::Spirv(internal) => {
+                StageSources::Spirv(internal.specialized.clone().filter(|_| internal.compiled))
+            }
+            ShaderInternal::Glsl(_) => StageSources::Glsl(
+                shaders
+                    .iter()
+                    .map(|s| match &s.internal {
+                        ShaderInternal::Glsl(internal) => internal.compilation.clone(),
+                        ShaderInternal::Spirv(_) => unreachable!(),
+                    })
+                    .collect(),
+            ),
+        };
+        Some(StageShaders {
+            stage: shaders[0].stage,
+            sources,
+        })
     }
 }
+/// The shaders attached to a stage of a program at the time it was linked
+#[derive(Debug)]
+struct StageShaders {
+    stage: ShaderType,
+    sources: StageSources,
+}
+#[derive(Debug)]
+enum StageSources {
+    /// The specialized module of the attached SPIR-V shader, if it is compiled
+    Spirv(Option<SpecializedSpirv>),
+    /// The latest compilation of each attached GLSL shader, in the same order as the names
+    Glsl(Vec<Option<Pending<Arc<GlslCompilation>>>>),
+}
+/// The shaders attached to each stage of a program at the time it was linked
+#[derive(Debug)]
+struct ProgramStages {
+    vertex: Option<StageShaders>,
+    tess_control: Option<StageShaders>,
+    tess_evaluation: Option<StageShaders>,
+    geometry: Option<StageShaders>,
+    fragment: Option<StageShaders>,
+    compute: Option<StageShaders>,
+}
+/// Outputs of the vertex stage to capture with transform feedback, as specified by `glTransformFeedbackVaryings`
+#[derive(Debug, Clone)]
+pub(crate) struct FeedbackVaryings {
+    pub(crate) names: Vec<String>,
+    pub(crate) mode: TransformFeedbackBufferMode,
+}
 #[derive(Debug)]
 pub struct Program {
     pub(crate) name: ObjectName<Self>,
     pub(crate) refcount: u32,
     pub(crate) vertex_shaders: ProgramStageBinding,
+    pub(crate) tess_control_shaders: ProgramStageBinding,
+    pub(crate) tess_evaluation_shaders: ProgramStageBinding,
+    pub(crate) geometry_shaders: ProgramStageBinding,
     pub(crate) fragment_shaders: ProgramStageBinding,
     pub(crate) compute_shaders: ProgramStageBinding,
+    /// Varyings captured with transform feedback by the next link
+    pub(crate) feedback_varyings: FeedbackVaryings,
+    /// Locations the next link binds vertex attributes and fragment outputs to by name
+    pub(crate) location_bindings: LocationBindings,
+    /// Whether the next link makes a program whose stages can be used in a program pipeline, as set by
+    /// `glProgramParameteri` with `GL_PROGRAM_SEPARABLE`
+    pub(crate) separable: bool,
     pub(crate) latest_linkage: Option<LinkedProgram>,
+    /// Result of the last link, if it hasn't been moved into `latest_linkage` yet
+    pub(crate) pending_link: Option<Pending<Result<LinkedProgram, Box<str>>>>,
     pub(crate) info_log: String,
 }
 impl Program {
@@ -113,9 +218,19 @@ impl Program {
             name,
             refcount: 0,
             vertex_shaders: ProgramStageBinding::Unbound,
+            tess_control_shaders: ProgramStageBinding::Unbound,
+            tess_evaluation_shaders: ProgramStageBinding::Unbound,
+            geometry_shaders: ProgramStageBinding::Unbound,
             fragment_shaders: ProgramStageBinding::Unbound,
             compute_shaders: ProgramStageBinding::Unbound,
+            feedback_varyings: FeedbackVaryings {
+                names: Vec::new(),
+                mode: TransformFeedbackBufferMode::InterleavedAttribs,
+            },
+            location_bindings: LocationBindings::default(),
+            separable: false,
             latest_linkage: None,
+            pending_link: None,
             info_log: String::new(),
         }
     }
@@ -133,14 +248,17 @@ impl Program {
     #[inline]
     pub(crate) fn attached_shader_count(&self) -> u32 {
         self.vertex_shaders.shader_count()
+            + self.tess_control_shaders.shader_count()
+            + self.tess_evaluation_shaders.shader_count()
+            + self.geometry_shaders.shader_count()
             + self.fragment_shaders.shader_count()
             + self.compute_shaders.shader_count()
     }
     #[inline]
     pub(crate) fn debug_log_str(&mut self, msg: &str) {
         gl_debug!("{msg}");
-        self.info_log.push('\n');
         self.info_log.push_str(msg);
+        self.info_log.push('\n');
     }
     #[inline]
     pub(crate) fn attach_shader(&mut self, shader: &mut Shader) {
@@ -153,8 +271,10 @@ impl Program {
         match stage {
             crate::gl_enums::ShaderType::FragmentShader => &mut self.fragment_shaders,
             crate::gl_enums::ShaderType::VertexShader => &mut self.vertex_shaders,
+            crate::gl_enums::ShaderType::GeometryShader => &mut self.geometry_shaders,
             crate::gl_enums::ShaderType::ComputeShader => &mut self.compute_shaders,
-            _ => unreachable!(),
+            crate::gl_enums::ShaderType::TessControlShader => &mut self.tess_control_shaders,
+            crate::gl_enums::ShaderType::TessEvaluationShader => &mut self.tess_evaluation_shaders,
         }
     }
     /// Detaches shader. Returns whether the shader can be safely removed from the shader list if it is queued for deletion
@@ -170,172 +290,616 @@ impl Program {
             false
         }
     }
+    /// Link a stage of a program. `producer` is the stage that feeds a tessellation evaluation stage, which it is
+    /// generated from, and `bindings` are the locations that the vertex attributes or fragment outputs of a vertex or
+    /// fragment stage are bound to
     #[inline]
     fn link_stage(
-        shader_list: &NamedObjectList<Shader>,
-        device: &ProtoObjRef<dyn MTLDevice>,
-        binding: &mut ProgramStageBinding,
-        glslang_compiler: &GlslLangCompiler,
-        label: Option<&Retained<NSString>>,
+        shaders: StageShaders,
+        producer: Option<&LinkedStage>,
+        bindings: Option<&LocationBindings>,
+        stage_compiler: &dyn StageCompiler,
+        label: Option<&str>,
     ) -> Result<LinkedStage, Box<str>> {
         macro_rules! err_ret {
             ($e:expr) => {
                 return Err($e.to_string().into_boxed_str())
             };
         }
-        let mut used_shaders = Vec::with_capacity(1);
-        let mut stage = None;
-        let mut stage_spirv = match binding {
-            ProgramStageBinding::Unbound => unreachable!(),
-            ProgramStageBinding::Spirv(_) => todo!(),
-            ProgramStageBinding::Glsl(hash_set) => {
-                let mut program = glslang_compiler.create_program();
-                // dont need to recheck shader name validity here, we can just panic on failiure
-                for shader in hash_set.iter().copied().map(|name| shader_list.get(name)) {
-                    used_shaders.push(shader.name.to_raw().to_string().into_boxed_str());
-                    let ShaderInternal::Glsl(internal) = &shader.internal else {
-                        unreachable!()
-                    };
-                    stage = Some(shader.stage);
-                    let Some(glslang_shader) = &internal.latest_shader else {
-                        err_ret!("Tried to link a program with a shader that did not compile!");
-                    };
-                    program.add_shader(glslang_shader);
+        let StageShaders { stage, sources } = shaders;
+
+        let mut key = CacheKeyBuilder::new(stage);
+        key.u32(MSL_VERSION.0)
+            .u32(MSL_VERSION.1)
+            .u32(DISCRETE_DESCRIPTOR_SET)
+            .u32(DEFAULT_UNIFORM_BLOCK_INDEX);
+        if let Some(producer) = producer {
+            key.words(&producer.spirv);
+        }
+        // name of the entry point in the SPIR-V module of this stage
+        let mut source_entry = "main";
+        // wait for the attached shaders to finish compiling
+        let mut compilations = Vec::new();
+        match &sources {
+            StageSources::Spirv(None) => {
+                err_ret!("Tried to link a program with a SPIR-V shader that was not specialized!");
+            }
+            StageSources::Spirv(Some(specialized)) => {
+                source_entry = &*specialized.entry_point;
+                key.str(source_entry).words(&specialized.words);
+            }
+            StageSources::Glsl(pending) => {
+                for compilation in pending {
+                    match compilation.as_ref().map(Pending::get) {
+                        Some(c) if c.success => {
+                            key.str(&c.source);
+                            compilations.push(c);
+                        }
+                        _ => {
+                            err_ret!("Tried to link a program with a shader that did not compile!")
+                        }
+                    }
                 }
+            }
+        }
+        // SPIR-V shaders have to qualify the locations of all of their inputs and outputs
+        let bindings = match (bindings, &sources) {
+            (Some(bindings), StageSources::Glsl(_)) => Some(bindings.stage(stage)),
+            _ => None,
+        };
+        for &(name, location, index) in bindings.iter().flatten() {
+            key.str(name).u32(location).u32(index);
+        }
+        let key = key.finish();
+        // the entry point is named after the key rather than the attached shaders, so that cached MSL can be reused by
+        // any program with the same stage
+        let entry_name = format!("{stage:?}_{key}_main");
+        // subroutine indices and uniform locations are per stage, so only one shader of a stage may declare them
+        let mut declaring = compilations.iter().filter(|c| !c.subroutines.is_empty());
+        let subroutines = declaring
+            .next()
+            .map(|c| c.subroutines.clone())
+            .unwrap_or_default();
+        if declaring.next().is_some() {
+            err_ret!(format!(
+                "Tried to link a program with more than one {stage:?} that declares subroutines"
+            ));
+        }
+
+        let cached = with_shader_cache(|cache| cache.get(key)).flatten();
+        let (original_spirv, cached_msl) = match cached {
+            Some(cached) => {
+                gl_debug!(src: ShaderCompiler, "shader cache hit for {entry_name}");
+                (cached.spirv, Some(cached.msl))
+            }
+            None => {
+                let spirv = match &sources {
+                    StageSources::Spirv(specialized) => {
+                        specialized.as_ref().expect("checked above").words.clone()
+                    }
+                    StageSources::Glsl(_) => {
+                        let lone_spirv = match compilations.as_slice() {
+                            [c] => c.spirv.as_deref(),
+                            _ => None,
+                        };
+                        // a lone shader was already linked to SPIR-V by its compile job
+                        let spirv = if let Some(spirv) = lone_spirv {
+                            Cow::Borrowed(spirv)
+                        } else {
+                            // glslang shaders can't be kept around with their compilations, so stages made of
+                            // several shaders are parsed again from the validated sources and linked together
+                            let glslang_compiler = GlslLangCompiler::acquire()
+                                .expect("failed to acquire glslang instance");
+                            let parsed = compilations
+                                .iter()
+                                .map(|c| parse_glsl(glslang_compiler, stage, &c.source))
+                                .collect::<Result<Vec<_>, _>>()?;
+                            let mut program = glslang_compiler.create_program();
+                            for shader in &parsed {
+                                program.add_shader(shader);
+                            }
+                            Cow::Owned(
+                                program
+                                    .compile(stage.to_glslang_stage())
+                                    .map_err(|e| e.to_string())?,
+                            )
+                        };
+                        match &bindings {
+                            Some(bindings) => locations::assign_locations(&spirv, stage, bindings)?,
+                            None => spirv.into_owned().into_boxed_slice(),
+                        }
+                    }
+                };
+                (spirv, None)
+            }
+        };
+        let (spirv, default_block) = default_block::rewrite_default_block(&original_spirv)?;
+        let resources = LinkedProgramResources::reflect(&SpirvModule::parse(&spirv)?, stage)?;
+        let hit = cached_msl.is_some();
+        let msl_src = match cached_msl {
+            Some(msl) => msl,
+            // Metal has no geometry stage, geometry shaders are translated to the kernel that emulates them
+            None if stage == ShaderType::GeometryShader => {
+                let kernel = geometry::geometry_kernel(&spirv)?;
+                Self::translate_stage(&kernel, source_entry, &entry_name, false)?.into_boxed_str()
+            }
+            // nor a tessellation control stage, which is emulated by a kernel as well
+            None if stage == ShaderType::TessControlShader => {
+                let kernel = tessellation::control_kernel(&spirv)?;
+                Self::translate_stage(&kernel, source_entry, &entry_name, false)?.into_boxed_str()
+            }
+            None if stage == ShaderType::TessEvaluationShader => {
+                let control =
+                    producer.ok_or("tessellation evaluation stage has no control stage")?;
+                let control = SpirvModule::parse(&control.spirv)?;
+                TessellationInfo::parse(&control, &SpirvModule::parse(&spirv)?)?;
+                let generated =
+                    tessellation::evaluation_stage(&spirv, &TessellationLayout::new(&control)?)?;
+                Self::translate_stage(&generated, source_entry, &entry_name, false)?
+                    .into_boxed_str()
+            }
+            None => {
+                Self::translate_stage(&spirv, source_entry, &entry_name, false)?.into_boxed_str()
+            }
+        };
+        gl_trace!(src: ShaderCompiler, "transformed metal sources for stage:\n{msl_src}");
 
-                let spirv_src = program
-                    .compile(
-                        stage
-                            .expect("stage should have been set")
-                            .to_glslang_stage(),
-                    )
-                    .map_err(|e| e.to_string())?;
-                let module = Module::from_words(&spirv_src);
-                Compiler::<Msl>::new(module).map_err(|e| e.to_string())?
+        let function = stage_compiler.new_stage_function(&msl_src, &entry_name, label)?;
+        // only cache stages that the backend accepted
+        if !hit {
+            let entry = CachedStage {
+                spirv: original_spirv.clone(),
+                msl: msl_src.clone(),
+            };
+            if let Some(Err(e)) = with_shader_cache(|cache| cache.insert(key, &entry)) {
+                gl_warn!(src: ShaderCompiler, "failed to write shader cache entry: {e}");
             }
+        }
+        Ok(LinkedStage {
+            entry_name: entry_name.into_boxed_str(),
+            function,
+            original_spirv,
+            spirv,
+            default_block,
+            resources,
+            subroutines,
+            msl_src,
+        })
+    }
+    /// Link the geometry stage of a program, and generate the stages that emulate it together with the vertex stage
+    fn link_geometry(
+        vertex: Option<&LinkedStage>,
+        shaders: StageShaders,
+        stage_compiler: &dyn StageCompiler,
+        label: Option<&str>,
+    ) -> Result<LinkedGeometry, Box<str>> {
+        let vertex =
+            vertex.ok_or("Tried to link a program with a geometry shader but no vertex shader!")?;
+        let stage = Self::link_stage(shaders, None, None, stage_compiler, label)?;
+        let layout = GeometryLayout::new(&SpirvModule::parse(&stage.spirv)?)?;
+        let vertex_kernel = Self::generate_stage(
+            &geometry::vertex_capture(&vertex.spirv, &layout.inputs)?,
+            &format!("{}_vertex_capture", stage.entry_name),
+            true,
+            stage_compiler,
+            label,
+        )?;
+        let passthrough = Self::generate_stage(
+            &geometry::passthrough(&stage.spirv, &layout)?,
+            &format!("{}_passthrough", stage.entry_name),
+            false,
+            stage_compiler,
+            label,
+        )?;
+        Ok(LinkedGeometry {
+            stage,
+            vertex_kernel,
+            passthrough,
+            layout,
+        })
+    }
+    /// Link the compute stage of a program, and read the work group size it declares
+    fn link_compute(
+        shaders: StageShaders,
+        stage_compiler: &dyn StageCompiler,
+        label: Option<&str>,
+    ) -> Result<LinkedCompute, Box<str>> {
+        let stage = Self::link_stage(shaders, None, None, stage_compiler, label)?;
+        let work_group_size = compute::work_group_size(&SpirvModule::parse(&stage.spirv)?)?;
+        Ok(LinkedCompute {
+            stage,
+            work_group_size,
+        })
+    }
+    /// Link the tessellation stages of a program, and generate the vertex stage kernel that feeds them
+    fn link_tessellation(
+        vertex: Option<&LinkedStage>,
+        control: StageShaders,
+        evaluation: StageShaders,
+        stage_compiler: &dyn StageCompiler,
+        label: Option<&str>,
+    ) -> Result<LinkedTessellation, Box<str>> {
+        let vertex = vertex
+            .ok_or("Tried to link a program with tessellation shaders but no vertex shader!")?;
+        let control = Self::link_stage(control, None, None, stage_compiler, label)?;
+        let evaluation = Self::link_stage(evaluation, Some(&control), None, stage_compiler, label)?;
+        let control_module = SpirvModule::parse(&control.spirv)?;
+        let layout = TessellationLayout::new(&control_module)?;
+        let info =
+            TessellationInfo::parse(&control_module, &SpirvModule::parse(&evaluation.spirv)?)?;
+        let vertex_kernel = Self::generate_stage(
+            &geometry::vertex_capture(&vertex.spirv, &layout.vertices)?,
+            &format!("{}_vertex_capture", control.entry_name),
+            true,
+            stage_compiler,
+            label,
+        )?;
+        Ok(LinkedTessellation {
+            control,
+            evaluation,
+            vertex_kernel,
+            layout,
+            info,
+        })
+    }
+    /// Lay out the varyings of the vertex stage that are captured with transform feedback, and generate the kernels
+    /// that capture them
+    fn link_feedback(
+        vertex: Option<&LinkedStage>,
+        varyings: &FeedbackVaryings,
+        stage_compiler: &dyn StageCompiler,
+        label: Option<&str>,
+    ) -> Result<LinkedFeedback, Box<str>> {
+        let vertex = vertex.ok_or(
+            "Tried to link a program with transform feedback varyings but no vertex shader!",
+        )?;
+        let layout = FeedbackLayout::new(
+            &SpirvModule::parse(&vertex.spirv)?,
+            &varyings.names,
+            varyings.mode,
+        )?;
+        let vertex_kernel = Self::generate_stage(
+            &geometry::vertex_capture(&vertex.spirv, &layout.records)?,
+            &format!("{}_feedback_capture", vertex.entry_name),
+            true,
+            stage_compiler,
+            label,
+        )?;
+        let kernel = Self::generate_stage(
+            &feedback::feedback_kernel(&vertex.spirv, &layout)?,
+            &format!("{}_feedback_kernel", vertex.entry_name),
+            false,
+            stage_compiler,
+            label,
+        )?;
+        Ok(LinkedFeedback {
+            varyings: varyings.clone(),
+            vertex_kernel,
+            kernel,
+            layout,
+        })
+    }
+    /// Translate a module generated at link time and create its entry point
+    fn generate_stage(
+        spirv: &[u32],
+        entry_name: &str,
+        vertex_kernel: bool,
+        stage_compiler: &dyn StageCompiler,
+        label: Option<&str>,
+    ) -> Result<GeneratedStage, Box<str>> {
+        let source_entry = SpirvModule::parse(spirv)?
+            .entry_point_name()
+            .ok_or("module has no entry point")?;
+        let msl_src = Self::translate_stage(spirv, &source_entry, entry_name, vertex_kernel)?;
+        gl_trace!(src: ShaderCompiler, "generated metal sources for {entry_name}:\n{msl_src}");
+        let function = stage_compiler.new_stage_function(&msl_src, entry_name, label)?;
+        Ok(GeneratedStage {
+            entry_name: entry_name.into(),
+            function,
+            msl_src: msl_src.into_boxed_str(),
+            spirv: spirv.into(),
+        })
+    }
+    /// Translate a rewritten SPIR-V module to MSL with SPIRV-Cross, renaming its entry point to `entry_name`. Vertex
+    /// stages are translated to compute kernels fed by a stage input descriptor if `vertex_kernel` is set
+    fn translate_stage(
+        spirv: &[u32],
+        source_entry: &str,
+        entry_name: &str,
+        vertex_kernel: bool,
+    ) -> Result<String, Box<str>> {
+        // the stages that feed the rasterizer remap the position and clip distances they write to the clip volume of
+        // Metal, unlike vertex kernels, whose outputs are read by the next stage. Fragment stages remap the point
+        // coordinate they read
+        let remapped = if vertex_kernel {
+            None
+        } else if let Some(remapped) = clip_control::remap_clip_outputs(spirv)? {
+            Some(remapped)
+        } else {
+            points::remap_point_coord(spirv)?
         };
+        let mut stage_module = SpirvModule::parse(remapped.as_deref().unwrap_or(spirv))?;
+        let counters = atomic_counters::atomic_counters(&stage_module)?;
+        let counter_buffers = atomic_counters::lower_atomic_counters(&mut stage_module, &counters)?;
+        let image_vars = images::image_variables(&stage_module)?;
+        let spirv = images::bind_images(&stage_module, &image_vars);
+        let module = Module::from_words(&spirv);
+        let mut stage_spirv = Compiler::<Msl>::new(module).map_err(|e| e.to_string())?;
         stage_spirv
-            .add_discrete_descriptor_set(3)
+            .add_discrete_descriptor_set(DISCRETE_DESCRIPTOR_SET)
             .map_err(|e| e.to_string().into_boxed_str())?;
 
-        let entry_name = format!("{:?}_{}_main", stage.unwrap(), used_shaders.join("_"));
-
         let model = stage_spirv
             .execution_model()
             .expect("failed to get execution model");
         let previous_entry_cleansed = stage_spirv
-            .cleansed_entry_point_name("main", model)
+            .cleansed_entry_point_name(source_entry, model)
             .expect("failed to cleanse entry point name")
             .expect("cleansed entry point was null");
         stage_spirv
-            .rename_entry_point(previous_entry_cleansed, entry_name.clone(), model)
+            .rename_entry_point(previous_entry_cleansed, entry_name.to_owned(), model)
             .expect("failed to rename spirv entry point");
+        // the emulated default uniform block is bound to the argument table index reserved for it
+        stage_spirv
+            .add_resource_binding(
+                model,
+                ResourceBinding::PushConstantBuffer,
+                &BindTarget {
+                    buffer: DEFAULT_UNIFORM_BLOCK_INDEX,
+                    texture: 0,
+                    sampler: 0,
+                    count: None,
+                },
+            )
+            .map_err(|e| e.to_string())?;
+        // as are the buffers of the emulated geometry and tessellation stages, the blocks that atomic counters were
+        // lowered to, and the transform feedback buffers (which are only bound to the kernel that captures them, and
+        // take up the first argument table indices)
+        #[expect(clippy::cast_possible_truncation, reason = "const checked")]
+        let feedback_buffers = (0..MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS as u32)
+            .map(|i| (feedback::BUFFER_BINDING + i, i));
+        let counter_buffers = (0..counter_buffers).map(|i| {
+            (
+                atomic_counters::ATOMIC_COUNTER_BINDING + i,
+                ATOMIC_COUNTER_BUFFER_INDEX + i,
+            )
+        });
+        for (binding, buffer) in [
+            (geometry::INPUT_BINDING, GEOMETRY_INPUT_INDEX),
+            (geometry::OUTPUT_BINDING, GEOMETRY_OUTPUT_INDEX),
+            (tessellation::FACTOR_BINDING, TESSELLATION_FACTOR_INDEX),
+        ]
+        .into_iter()
+        .chain(counter_buffers)
+        .chain(feedback_buffers)
+        {
+            stage_spirv
+                .add_resource_binding(
+                    model,
+                    ResourceBinding::Qualified {
+                        set: DISCRETE_DESCRIPTOR_SET,
+                        binding,
+                    },
+                    &BindTarget {
+                        buffer,
+                        texture: 0,
+                        sampler: 0,
+                        count: None,
+                    },
+                )
+                .map_err(|e| e.to_string())?;
+        }
+        // image uniforms are bound to the textures of the image units they refer to
+        for image in image_vars {
+            stage_spirv
+                .add_resource_binding(
+                    model,
+                    ResourceBinding::Qualified {
+                        set: DISCRETE_DESCRIPTOR_SET,
+                        binding: images::IMAGE_BINDING + image.index,
+                    },
+                    &BindTarget {
+                        buffer: 0,
+                        texture: image.index,
+                        sampler: 0,
+                        count: None,
+                    },
+                )
+                .map_err(|e| e.to_string())?;
+        }
 
         let mut opts = CompilerOptions::default();
-        opts.version = (2, 1).into();
+        opts.version = MSL_VERSION.into();
         opts.argument_buffers = true;
-        let artifact = stage_spirv.compile(&opts).map_err(|e| e.to_string())?;
-
-        let msl_src = format!("{artifact}");
-        gl_trace!(src: ShaderCompiler, "transformed metal sources for stage:\n{msl_src}");
-
-        let lib = device
-            .newLibraryWithSource_options_error(&NSString::from_str(&msl_src), None)
-            .map_err(|e| e.to_string())?;
-        if let Some(label) = label {
-            lib.setLabel(Some(label));
+        if vertex_kernel {
+            opts.vertex_for_tessellation = true;
+            // the generated vertex stage has no outputs, so the buffers SPIRV-Cross reserves for them are aliased to
+            // the ones of the geometry stage instead of taking up more argument table indices
+            opts.shader_output_buffer_index = GEOMETRY_OUTPUT_INDEX;
+            opts.indirect_params_buffer_index = GEOMETRY_INPUT_INDEX;
         }
-        // TODO: coalesce ungrouped (named) uniforms into a single uniform block with a hashmap for by-identifier uniform lookup
-        Ok(LinkedStage {
-            function: lib
-                .newFunctionWithName(&NSString::from_str(&entry_name))
-                .unwrap(),
-            lib,
-            resources: LinkedProgramResources::get_from_compiler(&artifact)
-                .expect("failed to get resource bindings during program linkage!"),
-            artifact: artifact.into(),
-        })
+        // GL tessellation coordinates have their origin in the lower left corner of the domain, unlike Metal ones
+        opts.tess_domain_origin_lower_left = true;
+        let artifact = stage_spirv.compile(&opts).map_err(|e| e.to_string())?;
+        Ok(format!("{artifact}"))
     }
-    //TODO async shader compilation
+    /// Start linking this program on the compile pool, with the shaders that are currently attached to it
     #[inline]
     pub(crate) fn link(
         &mut self,
-        shader_list: &mut NamedObjectList<Shader>,
-        device: &ProtoObjRef<dyn MTLDevice>,
+        shader_list: &NamedObjectList<Shader>,
+        pool: &mut CompilePool,
+        stage_compiler: Arc<dyn StageCompiler>,
     ) {
         //TODO errors
         self.latest_linkage = None;
+        self.info_log.clear();
         gl_debug!(src: ShaderCompiler, "attempting to link {:?}", self.name);
-        let glslang_compiler =
-            GlslLangCompiler::acquire().expect("failed to acquire glslang instance");
+        let label = self.label();
+        let stages = ProgramStages {
+            vertex: self.vertex_shaders.snapshot(shader_list),
+            tess_control: self.tess_control_shaders.snapshot(shader_list),
+            tess_evaluation: self.tess_evaluation_shaders.snapshot(shader_list),
+            geometry: self.geometry_shaders.snapshot(shader_list),
+            fragment: self.fragment_shaders.snapshot(shader_list),
+            compute: self.compute_shaders.snapshot(shader_list),
+        };
+        let varyings = self.feedback_varyings.clone();
+        let bindings = self.location_bindings.clone();
+        let separable = self.separable;
+        self.pending_link = Some(pool.submit(move || {
+            Self::link_program(
+                stages,
+                &varyings,
+                &bindings,
+                separable,
+                stage_compiler.as_ref(),
+                label.as_deref(),
+            )
+        }));
+    }
+    /// Debug label of this program, which the entry points of its stages are labeled with
+    fn label(&self) -> Option<String> {
+        with_debug_state(|state| state.get_label(self.name))
+            .flatten()
+            .map(|c| c.to_string_lossy().into_owned())
+    }
+    fn link_program(
+        stages: ProgramStages,
+        varyings: &FeedbackVaryings,
+        bindings: &LocationBindings,
+        separable: bool,
+        stage_compiler: &dyn StageCompiler,
+        label: Option<&str>,
+    ) -> Result<LinkedProgram, Box<str>> {
         let mut new_linkage = LinkedProgram {
+            separable,
+            uniforms: UniformTable::default(),
+            interfaces: ProgramInterfaces::default(),
             fragment: None,
             vertex: None,
+            tessellation: None,
+            geometry: None,
             compute: None,
+            feedback: None,
+            fetch_stages: HashMap::new(),
         };
-        let label = with_debug_state(|state| state.get_label(self.name))
-            .flatten()
-            .as_deref()
-            .map(|c| 
-                // Safety: 
-                unsafe {
-                let alloc = NSString::alloc();
-                NSString::initWithCString_encoding(
-                    alloc,
-                    NonNull::new_unchecked(c.as_ptr().cast_mut()),
-                    NSString::defaultCStringEncoding(),
-                )
-                .expect("failed to create NSString")
-            });
-        if !self.vertex_shaders.is_empty() {
+        if let Some(shaders) = stages.vertex {
             gl_trace!(src: ShaderCompiler, "linking vertex shaders");
-            match Self::link_stage(
-                shader_list,
-                device,
-                &mut self.vertex_shaders,
-                glslang_compiler,
-                label.as_ref(),
-            ) {
-                Ok(v) => new_linkage.vertex = Some(v),
-                Err(s) => {
-                    self.debug_log_str(&s);
-                    return;
+            new_linkage.vertex = Some(Self::link_stage(
+                shaders,
+                None,
+                Some(bindings),
+                stage_compiler,
+                label,
+            )?);
+        }
+        match (stages.tess_control, stages.tess_evaluation) {
+            (Some(control), Some(evaluation)) => {
+                if stages.geometry.is_some() {
+                    return Err(
+                        "Tried to link a program with both tessellation and geometry shaders, which is not supported!"
+                            .into(),
+                    );
                 }
+                gl_trace!(src: ShaderCompiler, "linking tessellation shaders");
+                new_linkage.tessellation = Some(Self::link_tessellation(
+                    new_linkage.vertex.as_ref(),
+                    control,
+                    evaluation,
+                    stage_compiler,
+                    label,
+                )?);
             }
+            (Some(_), None) => {
+                return Err(
+                    "Tried to link a program with a tessellation control shader but no tessellation evaluation shader!"
+                        .into(),
+                );
+            }
+            (None, Some(_)) => {
+                return Err(
+                    "Tried to link a program with a tessellation evaluation shader but no tessellation control shader, which is not supported!"
+                        .into(),
+                );
+            }
+            (None, None) => {}
         }
-        if !self.fragment_shaders.is_empty() {
-            gl_trace!(src: ShaderCompiler, "linking fragment shaders");
-            match Self::link_stage(
-                shader_list,
-                device,
-                &mut self.fragment_shaders,
-                glslang_compiler,
-                label.as_ref(),
-            ) {
-                Ok(v) => new_linkage.fragment = Some(v),
-                Err(s) => {
-                    self.debug_log_str(&s);
-                    return;
-                }
+        if let Some(shaders) = stages.geometry {
+            gl_trace!(src: ShaderCompiler, "linking geometry shaders");
+            new_linkage.geometry = Some(Self::link_geometry(
+                new_linkage.vertex.as_ref(),
+                shaders,
+                stage_compiler,
+                label,
+            )?);
+        }
+        if !varyings.names.is_empty() {
+            if new_linkage.geometry.is_some() || new_linkage.tessellation.is_some() {
+                return Err(
+                    "Tried to link a program that captures the outputs of geometry or tessellation shaders with transform feedback, which is not supported!"
+                        .into(),
+                );
             }
+            gl_trace!(src: ShaderCompiler, "linking transform feedback varyings");
+            new_linkage.feedback = Some(Self::link_feedback(
+                new_linkage.vertex.as_ref(),
+                varyings,
+                stage_compiler,
+                label,
+            )?);
+        }
+        if let Some(shaders) = stages.fragment {
+            gl_trace!(src: ShaderCompiler, "linking fragment shaders");
+            new_linkage.fragment = Some(Self::link_stage(
+                shaders,
+                None,
+                Some(bindings),
+                stage_compiler,
+                label,
+            )?);
         }
-        if !self.compute_shaders.is_empty() {
+        if let Some(shaders) = stages.compute {
+            if new_linkage.vertex.is_some() || new_linkage.fragment.is_some() {
+                return Err(
+                    "Tried to link a program with both a compute shader and shaders of other stages!".into(),
+                );
+            }
             gl_trace!(src: ShaderCompiler, "linking compute shaders");
-            match Self::link_stage(
-                shader_list,
-                device,
-                &mut self.compute_shaders,
-                glslang_compiler,
-                label.as_ref(),
-            ) {
-                Ok(v) => new_linkage.vertex = Some(v),
-                Err(s) => {
-                    self.debug_log_str(&s);
-                    return;
-                }
+            new_linkage.compute = Some(Self::link_compute(shaders, stage_compiler, label)?);
+        }
+        new_linkage.build_interfaces()?;
+        Ok(new_linkage)
+    }
+    /// Wait for the last link of this program to finish, and make its result current
+    pub(crate) fn finish_link(&mut self) {
+        let Some(pending) = self.pending_link.take() else {
+            return;
+        };
+        match pending.wait() {
+            Ok(linkage) => {
+                gl_debug!(src: ShaderCompiler, "linked {:?}", self.name);
+                self.latest_linkage = Some(linkage);
             }
+            Err(s) => self.debug_log_str(&s),
         }
-        self.latest_linkage = Some(new_linkage);
+    }
+    /// Whether the last link of this program has finished, as queried by `GL_COMPLETION_STATUS_KHR`
+    #[inline]
+    pub(crate) fn link_complete(&self) -> bool {
+        self.pending_link.as_ref().is_none_or(Pending::is_complete)
+    }
+    /// The current linkage of this program, waiting for a running link to finish
+    #[inline]
+    pub(crate) fn linkage(&mut self) -> Option<&LinkedProgram> {
+        self.finish_link();
+        self.latest_linkage.as_ref()
+    }
+    /// The current linkage of this program, waiting for a running link to finish
+    #[inline]
+    pub(crate) fn linkage_mut(&mut self) -> Option<&mut LinkedProgram> {
+        self.finish_link();
+        self.latest_linkage.as_mut()
     }
 }
 
@@ -345,84 +909,170 @@ impl NamedObject for Program {
 
 #[derive(Debug)]
 pub struct LinkedProgram {
+    /// whether the program was separable when it was linked
+    pub(crate) separable: bool,
+    /// active uniforms of the default block of each stage
+    pub(crate) uniforms: UniformTable,
+    /// active resources of the program, as exposed by the program interface queries
+    pub(crate) interfaces: ProgramInterfaces,
     pub(crate) fragment: Option<LinkedStage>,
     pub(crate) vertex: Option<LinkedStage>,
-    pub(crate) compute: Option<LinkedStage>,
+    pub(crate) tessellation: Option<LinkedTessellation>,
+    pub(crate) geometry: Option<LinkedGeometry>,
+    pub(crate) compute: Option<LinkedCompute>,
+    /// the stages generated to capture transform feedback varyings, if any were specified
+    pub(crate) feedback: Option<LinkedFeedback>,
+    /// variants of the stages that fetch vertex attributes, generated for the attribute conversions they were drawn
+    /// with (see [`attributes`])
+    pub(crate) fetch_stages: HashMap<Box<[AttributeConversion]>, FetchStages>,
 }
-#[inline]
-fn to_resource_vec(
-    iter: ResourceIter<'_>,
-    compiler: &Compiler<Msl>,
-) -> Result<Vec<ProgramResource>, SpirvCrossError> {
-    let mut vec = Vec::with_capacity(iter.len());
-    for v in iter {
-        vec.push(ProgramResource {
-            name: v.name.to_string().into_boxed_str(),
-            binding: compiler
-                .decoration(v.id, spirv_cross2::spirv::Decoration::Binding)?
-                .map(|v| v.as_literal().expect("failed to convert literal")),
-            location: compiler
-                .decoration(v.id, spirv_cross2::spirv::Decoration::Location)?
-                .map(|v| v.as_literal().expect("failed to convert literal")),
-        });
+impl LinkedProgram {
+    /// Build the uniform table and the program interfaces from the linked stages
+    fn build_interfaces(&mut self) -> Result<(), Box<str>> {
+        let stages = PIPELINE_STAGES
+            .into_iter()
+            .filter_map(|ty| Some((ty, self.stage(ty)?)))
+            .collect::<Vec<_>>();
+        let default_blocks = stages
+            .iter()
+            .map(|&(ty, stage)| (ty, &stage.default_block))
+            .collect::<Vec<_>>();
+        let uniforms = UniformTable::build(&default_blocks)?;
+        let resources = stages
+            .iter()
+            .map(|&(ty, stage)| (ty, &stage.resources))
+            .collect::<Vec<_>>();
+        let mut interfaces = ProgramInterfaces::build(&uniforms, &resources);
+        interfaces.subroutines = stages
+            .iter()
+            .filter(|(_, stage)| !stage.subroutines.is_empty())
+            .map(|&(ty, stage)| (ty, stage.subroutines.clone()))
+            .collect();
+        if let Some(feedback) = &self.feedback {
+            interfaces
+                .transform_feedback_varyings
+                .clone_from(&feedback.layout.varyings);
+            interfaces
+                .transform_feedback_buffers
+                .clone_from(&feedback.layout.buffers);
+        }
+        self.interfaces = interfaces;
+        self.uniforms = uniforms;
+        Ok(())
+    }
+    /// The linked stage of the given type, if the program has one
+    #[inline]
+    pub(crate) fn stage(&self, stage: ShaderType) -> Option<&LinkedStage> {
+        match stage {
+            ShaderType::FragmentShader => self.fragment.as_ref(),
+            ShaderType::VertexShader => self.vertex.as_ref(),
+            ShaderType::ComputeShader => self.compute.as_ref().map(|c| &c.stage),
+            ShaderType::GeometryShader => self.geometry.as_ref().map(|g| &g.stage),
+            ShaderType::TessControlShader => self.tessellation.as_ref().map(|t| &t.control),
+            ShaderType::TessEvaluationShader => self.tessellation.as_ref().map(|t| &t.evaluation),
+        }
     }
-    Ok(vec)
 }
 #[derive(Debug)]
-pub struct LinkedProgramResources {
-    pub(crate) uniform_buffers: Vec<ProgramResource>,
-    pub(crate) shader_storage_buffers: Vec<ProgramResource>,
-    pub(crate) atomic_counter_buffers: Vec<ProgramResource>,
-    pub(crate) stage_inputs: Vec<ProgramResource>,
-    pub(crate) plain_uniforms: Vec<ProgramResource>,
+pub struct LinkedStage {
+    /// name of the entry point for this stage
+    pub(crate) entry_name: Box<str>,
+    /// the entry point for this stage, as created by the rendering backend
+    pub(crate) function: StageFunction,
+    /// the MSL source that was compiled to the entry point given above
+    pub(crate) msl_src: Box<str>,
+    /// the SPIR-V module of the stage before any link-time rewrites (but with the locations of its vertex attributes or
+    /// fragment outputs assigned), as stored in program binaries
+    pub(crate) original_spirv: Box<[u32]>,
+    /// the SPIR-V module the artifact above was compiled from, after the default uniform block was rewritten
+    pub(crate) spirv: Box<[u32]>,
+    /// the emulated default uniform block of this stage
+    pub(crate) default_block: DefaultBlock,
+    /// Resources
+    pub(crate) resources: LinkedProgramResources,
+    /// the subroutines and subroutine uniforms of this stage, whose selectors are part of the default block
+    pub(crate) subroutines: StageSubroutines,
 }
-impl LinkedProgramResources {
-    //TODO XFBs
-    fn get_from_compiler(spirvc: &Compiler<Msl>) -> Result<Self, SpirvCrossError> {
-        let value = spirvc.shader_resources()?;
-        let uniform_buffers = to_resource_vec(
-            value.resources_for_type(spirv_cross2::reflect::ResourceType::UniformBuffer)?,
-            spirvc,
-        )?;
-        let shader_storage_buffers = to_resource_vec(
-            value.resources_for_type(spirv_cross2::reflect::ResourceType::StorageBuffer)?,
-            spirvc,
-        )?;
-        let atomic_counter_buffers = to_resource_vec(
-            value.resources_for_type(spirv_cross2::reflect::ResourceType::AtomicCounter)?,
-            spirvc,
-        )?;
-        let stage_inputs = to_resource_vec(
-            value.resources_for_type(spirv_cross2::reflect::ResourceType::StageInput)?,
-            spirvc,
-        )?;
-        let plain_uniforms = to_resource_vec(
-            value.resources_for_type(spirv_cross2::reflect::ResourceType::GlPlainUniform)?,
-            spirvc,
-        )?;
-        Ok(Self {
-            uniform_buffers,
-            shader_storage_buffers,
-            atomic_counter_buffers,
-            stage_inputs,
-            plain_uniforms,
-        })
+impl LinkedStage {
+    /// Check that every user-defined input of `consumer`, a stage that was linked separately from this one, is written
+    /// by an output of this stage with the same type. Metal can't build a render pipeline whose fragment function
+    /// reads inputs its vertex function doesn't write
+    pub(crate) fn match_interface(&self, consumer: &LinkedStage) -> Result<(), Box<str>> {
+        let producer = SpirvModule::parse(&self.spirv)?;
+        let consumer_module = SpirvModule::parse(&consumer.spirv)?;
+        let outputs = StageVariable::collect(&producer, StorageClass::Output, false);
+        for input in StageVariable::collect(&consumer_module, StorageClass::Input, false) {
+            let VariableKind::User(key) = &input.kind else {
+                continue;
+            };
+            let Some(output) = outputs
+                .iter()
+                .find(|o| matches!(&o.kind, VariableKind::User(k) if k == key))
+            else {
+                return Err(format!(
+                    "input {key:?} of {} is not written by {}",
+                    consumer.entry_name, self.entry_name
+                )
+                .into());
+            };
+            if !producer.same_type(output.ty, &consumer_module, input.ty) {
+                return Err(format!(
+                    "type of input {key:?} of {} does not match the output of {}",
+                    consumer.entry_name, self.entry_name
+                )
+                .into());
+            }
+        }
+        Ok(())
     }
 }
+/// A linked geometry stage, and the stages generated to emulate it (see [`geometry`])
 #[derive(Debug)]
-pub struct ProgramResource {
-    pub(crate) name: Box<str>,
-    pub(crate) binding: Option<u32>,
-    pub(crate) location: Option<u32>,
+pub struct LinkedGeometry {
+    /// the geometry stage, whose function is the compute kernel that runs it
+    pub(crate) stage: LinkedStage,
+    /// the vertex stage of the program, translated to a compute kernel that writes its outputs to the input buffer
+    pub(crate) vertex_kernel: GeneratedStage,
+    /// the vertex stage of the draw of the primitives emitted by the geometry stage
+    pub(crate) passthrough: GeneratedStage,
+    pub(crate) layout: GeometryLayout,
 }
+/// A linked compute stage (see [`compute`])
 #[derive(Debug)]
-pub struct LinkedStage {
-    /// the entry point for this stage
-    pub(crate) function: ProtoObjRef<dyn MTLFunction>,
-    /// a retained reference to the metal library that contains the entry point function for this stage
-    pub(crate) lib: ProtoObjRef<dyn MTLLibrary>,
-    /// the `spirv_cross` artifact/module that was compiled to the metal lib given above
-    pub(crate) artifact: NoDebug<CompiledArtifact<Msl>>,
-    /// Resources
-    pub(crate) resources: LinkedProgramResources,
+pub struct LinkedCompute {
+    pub(crate) stage: LinkedStage,
+    /// number of invocations of each dimension of a work group, as declared by the stage
+    pub(crate) work_group_size: [u32; 3],
+}
+/// The linked tessellation stages of a program, and the stage generated to feed them (see [`tessellation`])
+#[derive(Debug)]
+pub struct LinkedTessellation {
+    /// the tessellation control stage, whose function is the compute kernel that runs it
+    pub(crate) control: LinkedStage,
+    /// the tessellation evaluation stage, whose function is the post-tessellation vertex function that runs it
+    pub(crate) evaluation: LinkedStage,
+    /// the vertex stage of the program, translated to a compute kernel that writes its outputs to the input buffer
+    pub(crate) vertex_kernel: GeneratedStage,
+    pub(crate) layout: TessellationLayout,
+    pub(crate) info: TessellationInfo,
+}
+/// The stages generated to capture the transform feedback varyings of a program (see [`feedback`])
+#[derive(Debug)]
+pub struct LinkedFeedback {
+    /// the varyings the program was linked with
+    pub(crate) varyings: FeedbackVaryings,
+    /// the vertex stage of the program, translated to a compute kernel that writes its outputs to the input buffer
+    pub(crate) vertex_kernel: GeneratedStage,
+    /// the kernel that copies the captured varyings to the transform feedback buffers
+    pub(crate) kernel: GeneratedStage,
+    pub(crate) layout: FeedbackLayout,
+}
+/// A stage generated at link time, that has no GL-visible resources of its own
+#[derive(Debug)]
+pub struct GeneratedStage {
+    pub(crate) entry_name: Box<str>,
+    pub(crate) function: StageFunction,
+    pub(crate) msl_src: Box<str>,
+    /// the generated SPIR-V module the artifact above was compiled from
+    pub(crate) spirv: Box<[u32]>,
 }
@@ -0,0 +1,324 @@
+//! Atomic counters. Metal has nothing like the atomic counter storage class, so the `atomic_uint` uniforms of a stage are
+//! lowered to atomic operations on buffer blocks instead: the counters of each atomic counter buffer binding point that
+//! the stage uses become the members of a block in the discrete descriptor set (at the offsets given by their `offset`
+//! layout qualifiers), and the range bound to that binding point is bound as the block before every draw or dispatch
+
+use ahash::{HashMap, HashMapExt};
+use spirv_cross2::spirv::{Capability, Decoration, Op, StorageClass};
+
+use super::{
+    DISCRETE_DESCRIPTOR_SET,
+    default_block::UniformKind,
+    images,
+    spirv::{Instruction, SpirvModule, Type},
+};
+use crate::texture::image::MAX_IMAGE_UNITS;
+
+/// Maximum number of atomic counter buffer binding points that a single stage can use
+pub(crate) const MAX_STAGE_ATOMIC_COUNTER_BUFFERS: u32 = 4;
+/// Binding of the block of the first atomic counter buffer of a stage in the discrete descriptor set, after the image
+/// uniforms. The blocks of the other buffers follow it, in binding point order
+pub(crate) const ATOMIC_COUNTER_BINDING: u32 = images::IMAGE_BINDING + MAX_IMAGE_UNITS;
+/// Size of a counter, which is also the stride of arrays of counters
+pub(crate) const COUNTER_SIZE: u32 = 4;
+
+/// An atomic counter of a module
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AtomicCounter {
+    pub(crate) var: u32,
+    /// Type of the counter (an unsigned integer or an array of them)
+    pub(crate) ty: u32,
+    /// Binding point of the atomic counter buffer holding the counter
+    pub(crate) binding: u32,
+    /// Offset of the (first element of the) counter in the buffer
+    pub(crate) offset: u32,
+    /// Number of array elements, `None` if the counter is not an array
+    pub(crate) len: Option<u32>,
+}
+impl AtomicCounter {
+    /// Offset of the end of the last element of the counter in the buffer
+    #[inline]
+    pub(crate) fn end(&self) -> u32 {
+        self.offset + self.len.unwrap_or(1) * COUNTER_SIZE
+    }
+}
+
+/// The atomic counters of a module, in declaration order
+pub(crate) fn atomic_counters(module: &SpirvModule) -> Result<Vec<AtomicCounter>, Box<str>> {
+    let mut counters = Vec::new();
+    for inst in &module.instructions {
+        let o = &inst.operands;
+        match inst.op() {
+            Some(Op::Function) => break,
+            Some(Op::Variable) if o[2] == StorageClass::AtomicCounter as u32 => {}
+            _ => continue,
+        }
+        let Some(&Type::Pointer(_, ty)) = module.types.get(&o[0]) else {
+            continue;
+        };
+        let len = match module.types.get(&ty) {
+            Some(&Type::Array(_, len)) => Some(module.constant_value(len)?),
+            _ => None,
+        };
+        counters.push(AtomicCounter {
+            var: o[1],
+            ty,
+            binding: module
+                .decoration(o[1], Decoration::Binding)
+                .map_or(0, |d| d[0]),
+            offset: module
+                .decoration(o[1], Decoration::Offset)
+                .map_or(0, |d| d[0]),
+            len,
+        });
+    }
+    Ok(counters)
+}
+
+/// Group the atomic counters of a stage by the binding point of their buffer, in ascending binding point order, with
+/// the counters of each buffer sorted by offset. The buffer at each position is bound to the block binding and argument
+/// table index at that position past the first ones reserved for atomic counter buffers
+pub(crate) fn counter_buffers(
+    counters: &[AtomicCounter],
+) -> Result<Vec<(u32, Vec<AtomicCounter>)>, Box<str>> {
+    let mut buffers: Vec<(u32, Vec<AtomicCounter>)> = Vec::new();
+    for counter in counters {
+        match buffers.iter_mut().find(|(b, _)| *b == counter.binding) {
+            Some((_, members)) => members.push(*counter),
+            None => buffers.push((counter.binding, vec![*counter])),
+        }
+    }
+    if buffers.len() > MAX_STAGE_ATOMIC_COUNTER_BUFFERS as usize {
+        return Err(format!(
+            "stage uses {} atomic counter buffers, but at most {MAX_STAGE_ATOMIC_COUNTER_BUFFERS} are supported",
+            buffers.len()
+        )
+        .into());
+    }
+    buffers.sort_unstable_by_key(|(b, _)| *b);
+    for (binding, members) in &mut buffers {
+        members.sort_unstable_by_key(|c| c.offset);
+        for pair in members.windows(2) {
+            if pair[1].offset < pair[0].end() {
+                return Err(format!(
+                    "atomic counters at offsets {} and {} of binding {binding} overlap",
+                    pair[0].offset, pair[1].offset
+                )
+                .into());
+            }
+        }
+    }
+    Ok(buffers)
+}
+
+/// Operands of an instruction that may be a pointer to an atomic counter
+fn pointer_operands(inst: &Instruction) -> core::ops::Range<usize> {
+    match inst.op() {
+        Some(
+            Op::AccessChain
+            | Op::InBoundsAccessChain
+            | Op::PtrAccessChain
+            | Op::CopyObject
+            | Op::AtomicLoad
+            | Op::AtomicExchange
+            | Op::AtomicCompareExchange
+            | Op::AtomicCompareExchangeWeak
+            | Op::AtomicIIncrement
+            | Op::AtomicIDecrement
+            | Op::AtomicIAdd
+            | Op::AtomicISub
+            | Op::AtomicSMin
+            | Op::AtomicUMin
+            | Op::AtomicSMax
+            | Op::AtomicUMax
+            | Op::AtomicAnd
+            | Op::AtomicOr
+            | Op::AtomicXor,
+        ) => 2..3,
+        Some(Op::AtomicStore) => 0..1,
+        Some(Op::FunctionCall) => 3..inst.operands.len(),
+        _ => 0..0,
+    }
+}
+
+/// Lower the atomic counters of a module to members of buffer blocks, bound to the bindings after
+/// [`ATOMIC_COUNTER_BINDING`] in the discrete descriptor set. Functions access each counter through a pointer to its
+/// member, which replaces every use of the counter variable. Returns the number of blocks
+pub(crate) fn lower_atomic_counters(
+    module: &mut SpirvModule,
+    counters: &[AtomicCounter],
+) -> Result<u32, Box<str>> {
+    if counters.is_empty() {
+        return Ok(0);
+    }
+    let buffers = counter_buffers(counters)?;
+    let uint = module
+        .types
+        .iter()
+        .find(|(_, t)| **t == Type::Scalar(UniformKind::UnsignedInt, 32))
+        .map(|(&id, _)| id)
+        .ok_or("module declares atomic counters without an unsigned integer type")?;
+
+    let mut decorations = Vec::new();
+    let mut globals = Vec::new();
+    // counter variable -> (pointer type, block variable, member index constant)
+    let mut members = HashMap::new();
+    let mut constants = Vec::new();
+    // arrays of counters that the stride of the array members of the blocks is given to
+    let mut strided = Vec::new();
+    for (slot, (_, buffer_counters)) in (0..).zip(&buffers) {
+        let block = module.new_id();
+        let block_ptr = module.new_id();
+        let block_var = module.new_id();
+        decorations.push(Instruction::new(
+            Op::Decorate,
+            vec![block, Decoration::BufferBlock as u32],
+        ));
+        // member indices are shared by the blocks
+        while constants.len() < buffer_counters.len() {
+            let index = module.new_id();
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "checked by counter_buffers"
+            )]
+            let member = constants.len() as u32;
+            globals.push(Instruction::new(Op::Constant, vec![uint, index, member]));
+            constants.push(index);
+        }
+        for (member, counter) in (0..).zip(buffer_counters) {
+            decorations.push(Instruction::new(
+                Op::MemberDecorate,
+                vec![block, member, Decoration::Offset as u32, counter.offset],
+            ));
+            if counter.len.is_some()
+                && module
+                    .decoration(counter.ty, Decoration::ArrayStride)
+                    .is_none()
+                && !strided.contains(&counter.ty)
+            {
+                strided.push(counter.ty);
+                decorations.push(Instruction::new(
+                    Op::Decorate,
+                    vec![counter.ty, Decoration::ArrayStride as u32, COUNTER_SIZE],
+                ));
+            }
+            let index = constants[member as usize];
+            let ptr = module
+                .instructions
+                .iter()
+                .find(|i| i.op() == Some(Op::Variable) && i.operands[1] == counter.var)
+                .map(|i| i.operands[0])
+                .expect("atomic counter variable should be declared");
+            members.insert(counter.var, (ptr, block_var, index));
+        }
+        decorations.extend([
+            Instruction::new(
+                Op::Decorate,
+                vec![
+                    block_var,
+                    Decoration::DescriptorSet as u32,
+                    DISCRETE_DESCRIPTOR_SET,
+                ],
+            ),
+            Instruction::new(
+                Op::Decorate,
+                vec![
+                    block_var,
+                    Decoration::Binding as u32,
+                    ATOMIC_COUNTER_BINDING + slot,
+                ],
+            ),
+        ]);
+        globals.extend([
+            Instruction::new(
+                Op::TypeStruct,
+                [block]
+                    .into_iter()
+                    .chain(buffer_counters.iter().map(|c| c.ty))
+                    .collect(),
+            ),
+            Instruction::new(
+                Op::TypePointer,
+                vec![block_ptr, StorageClass::Uniform as u32, block],
+            ),
+            Instruction::new(
+                Op::Variable,
+                vec![block_ptr, block_var, StorageClass::Uniform as u32],
+            ),
+        ]);
+    }
+
+    let first_type = module
+        .instructions
+        .iter()
+        .position(|i| (Op::TypeVoid as u32..=Op::TypeForwardPointer as u32).contains(&i.opcode));
+    let instructions = core::mem::take(&mut module.instructions);
+    let mut out = Vec::with_capacity(instructions.len() + decorations.len() + globals.len());
+    // pointers to the members of the function being rewritten, and the access chains that create them, which are
+    // inserted after the variables of its first block
+    let mut replaced = HashMap::new();
+    let mut pending = Vec::new();
+    let mut after_variables = false;
+    for (idx, mut inst) in instructions.iter().cloned().enumerate() {
+        if Some(idx) == first_type {
+            out.append(&mut decorations);
+        }
+        let op = inst.op();
+        let o = &mut inst.operands;
+        match op {
+            Some(Op::Capability) if o[0] == Capability::AtomicStorage as u32 => continue,
+            // the counter variables and everything attached to them are replaced by the blocks
+            Some(Op::Name | Op::Decorate) if members.contains_key(&o[0]) => continue,
+            Some(Op::Variable) if members.contains_key(&o[1]) => continue,
+            Some(Op::TypePointer) if o[1] == StorageClass::AtomicCounter as u32 => {
+                o[1] = StorageClass::Uniform as u32;
+                module.types.insert(o[0], Type::Pointer(o[1], o[2]));
+            }
+            Some(Op::Function) => {
+                // the blocks are declared before the first function, after the types of their members
+                out.append(&mut globals);
+                let mut used = Vec::new();
+                for body in instructions[idx..]
+                    .iter()
+                    .take_while(|i| i.op() != Some(Op::FunctionEnd))
+                {
+                    for operand in &body.operands[pointer_operands(body)] {
+                        if members.contains_key(operand) && !used.contains(operand) {
+                            used.push(*operand);
+                        }
+                    }
+                }
+                for var in used {
+                    let (ptr, block_var, index) = members[&var];
+                    let id = module.new_id();
+                    pending.push(Instruction::new(
+                        Op::AccessChain,
+                        vec![ptr, id, block_var, index],
+                    ));
+                    replaced.insert(var, id);
+                }
+            }
+            Some(Op::Label) if !pending.is_empty() => after_variables = true,
+            Some(Op::Variable) => {}
+            Some(Op::FunctionEnd) => replaced.clear(),
+            _ if after_variables => {
+                out.append(&mut pending);
+                after_variables = false;
+            }
+            _ => {}
+        }
+        let pointers = pointer_operands(&inst);
+        for operand in &mut inst.operands[pointers] {
+            if let Some(&id) = replaced.get(operand) {
+                *operand = id;
+            }
+        }
+        out.push(inst);
+    }
+    module.instructions = out;
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "checked by counter_buffers"
+    )]
+    Ok(buffers.len() as u32)
+}
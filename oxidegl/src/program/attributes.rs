@@ -0,0 +1,217 @@
+//! Conversion of vertex attributes the vertex fetch can't present to the vertex stage the way the GL client asked for.
+//! Metal only converts integers to floats when they are normalized and at most 16 bits wide, and only has a BGRA
+//! variant of the 8 bit normalized format. Every other conversion happens in a generated prologue of the stage that
+//! fetches the attributes:
+//! 1. The float input of the stage is replaced by an input of the integral type the attribute is fetched as, and the
+//!    original input becomes a private variable.
+//! 2. The generated entry point loads the fetched value, converts (and normalizes) it to float, swaps its first and
+//!    third components if it is stored in BGRA order, and stores it to the private variable before calling the
+//!    original entry point.
+
+use spirv_cross2::spirv::{Decoration, ExecutionModel, Op, StorageClass};
+
+use super::{
+    GeneratedStage, LinkedProgram, Program, default_block::UniformKind, geometry::Generator,
+    spirv::Type,
+};
+use crate::{
+    render::StageCompiler,
+    vao::{AttributeFormatWithConversion, IntegralCastBehavior},
+};
+
+/// Conversion of the vertex attribute at a location
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AttributeConversion {
+    pub location: u32,
+    pub format: AttributeFormatWithConversion,
+}
+impl AttributeConversion {
+    /// The conversion an attribute with the given format needs, if it needs one
+    #[inline]
+    pub(crate) fn new(location: u32, format: AttributeFormatWithConversion) -> Option<Self> {
+        (format.conversion != IntegralCastBehavior::Native || format.bgra_shuffle)
+            .then_some(Self { location, format })
+    }
+}
+
+/// Variants of the stages of a program that fetch vertex attributes, which convert them for a set of conversions
+#[derive(Debug)]
+pub struct FetchStages {
+    /// the vertex stage, or the vertex kernel of the geometry or tessellation pre-pass if the program has one of them
+    pub(crate) vertex: GeneratedStage,
+    /// the vertex kernel of the transform feedback pre-pass, if the program captures any varyings
+    pub(crate) feedback: Option<GeneratedStage>,
+}
+
+impl LinkedProgram {
+    /// Generate the variants of the stages that fetch vertex attributes for a set of conversions, unless they were
+    /// already generated
+    pub(crate) fn generate_fetch_stages(
+        &mut self,
+        conversions: &[AttributeConversion],
+        stage_compiler: &dyn StageCompiler,
+    ) -> Result<(), Box<str>> {
+        if self.fetch_stages.contains_key(conversions) {
+            return Ok(());
+        }
+        let generate = |spirv: &[u32], entry_name: &str, vertex_kernel: bool| {
+            Program::generate_stage(
+                &convert_attributes(spirv, conversions)?,
+                &format!("{entry_name}_converted"),
+                vertex_kernel,
+                stage_compiler,
+                None,
+            )
+        };
+        let kernel = match (&self.geometry, &self.tessellation) {
+            (Some(g), _) => Some(&g.vertex_kernel),
+            (None, Some(t)) => Some(&t.vertex_kernel),
+            (None, None) => None,
+        };
+        let vertex = match kernel {
+            Some(kernel) => generate(&kernel.spirv, &kernel.entry_name, true)?,
+            None => {
+                let vertex = self.vertex.as_ref().ok_or(
+                    "tried to convert the vertex attributes of a program without a vertex stage",
+                )?;
+                generate(&vertex.spirv, &vertex.entry_name, false)?
+            }
+        };
+        let feedback = self
+            .feedback
+            .as_ref()
+            .map(|f| generate(&f.vertex_kernel.spirv, &f.vertex_kernel.entry_name, true))
+            .transpose()?;
+        self.fetch_stages
+            .insert(conversions.into(), FetchStages { vertex, feedback });
+        Ok(())
+    }
+}
+
+/// Generate a variant of the stage in `spirv` that converts the attributes it fetches as described by `conversions`.
+/// Conversions of locations the stage doesn't read, or reads as integers, are ignored
+pub(crate) fn convert_attributes(
+    spirv: &[u32],
+    conversions: &[AttributeConversion],
+) -> Result<Box<[u32]>, Box<str>> {
+    let mut g = Generator::new(spirv)?;
+    let entry_point = g
+        .module
+        .instructions
+        .iter()
+        .find(|i| i.op() == Some(Op::EntryPoint))
+        .expect("checked in Generator::new");
+    let model =
+        ExecutionModel::from_u32(entry_point.operands[0]).ok_or("unknown execution model")?;
+    let modes = g
+        .module
+        .instructions
+        .iter()
+        .filter(|i| i.op() == Some(Op::ExecutionMode) && i.operands[0] == g.main)
+        .map(|i| i.operands[1..].to_vec())
+        .collect::<Vec<_>>();
+    let mut interface = g.interface();
+
+    let float = g.find_or_declare(Type::Scalar(UniformKind::Float, 32));
+    let mut converted = Vec::new();
+    for conversion in conversions {
+        let Some((var, ty)) = interface.iter().find_map(|&v| {
+            let inst = g
+                .module
+                .instructions
+                .iter()
+                .find(|i| i.op() == Some(Op::Variable) && i.operands[1] == v)?;
+            let Some(&Type::Pointer(storage, ty)) = g.module.types.get(&inst.operands[0]) else {
+                return None;
+            };
+            (storage == StorageClass::Input as u32
+                && g.module.location(v) == Some(conversion.location))
+            .then_some((v, ty))
+        }) else {
+            continue;
+        };
+        let components = match g.module.types.get(&ty) {
+            Some(&Type::Scalar(UniformKind::Float, 32)) => 1,
+            Some(&Type::Vector(component, n)) if component == float => n,
+            _ => continue,
+        };
+        let format = conversion.format;
+        let fetched_components = if format.bgra_shuffle { 4 } else { components };
+        let fetched_component = match format.conversion {
+            IntegralCastBehavior::Native => float,
+            _ if format.signed() => g.find_or_declare(Type::Scalar(UniformKind::Int, 32)),
+            _ => g.uint_type(),
+        };
+        let vector = |g: &mut Generator, component| {
+            if fetched_components == 1 {
+                component
+            } else {
+                g.find_or_declare(Type::Vector(component, fetched_components))
+            }
+        };
+        let fetched_ty = vector(&mut g, fetched_component);
+        let float_ty = vector(&mut g, float);
+        let fetched = g.variable(
+            StorageClass::Input,
+            fetched_ty,
+            &format!("oxidegl_attribute_{}", conversion.location),
+        );
+        g.decorate(fetched, Decoration::Location, &[conversion.location]);
+        g.privatize_variable(var);
+        for v in &mut interface {
+            if *v == var {
+                *v = fetched;
+            }
+        }
+        converted.push((var, fetched, format, (fetched_ty, float_ty), components, ty));
+    }
+    if converted.is_empty() {
+        return Ok(spirv.into());
+    }
+
+    let main = g.main;
+    let entry = g.begin_function("oxidegl_convert_attributes");
+    for (var, fetched, format, (fetched_ty, float_ty), components, ty) in converted {
+        let mut value = g.op(Op::Load, fetched_ty, &[fetched]);
+        match format.conversion {
+            IntegralCastBehavior::Native => {}
+            conversion => {
+                let op = if format.signed() {
+                    Op::ConvertSToF
+                } else {
+                    Op::ConvertUToF
+                };
+                value = g.op(op, float_ty, &[value]);
+                if conversion == IntegralCastBehavior::Normalize {
+                    // signed values are normalized by the largest positive value, so the most negative one maps
+                    // slightly below -1 and should be clamped. The only formats normalized here are 32 bits wide,
+                    // and 2^31 - 1 isn't representable as an f32, so the scale rounds to 2^-31 and the result is
+                    // exactly -1 already
+                    #[expect(clippy::cast_possible_truncation, reason = "rounding is intended")]
+                    let scale = g.float(
+                        (2f64.powi(i32::from(format.normalization_const)) - 1.0).recip() as f32,
+                    );
+                    value = if float_ty == float {
+                        g.op(Op::FMul, float_ty, &[value, scale])
+                    } else {
+                        g.op(Op::VectorTimesScalar, float_ty, &[value, scale])
+                    };
+                }
+            }
+        }
+        if format.bgra_shuffle {
+            value = if components == 1 {
+                g.op(Op::CompositeExtract, ty, &[value, 2])
+            } else {
+                let mut operands = vec![value, value];
+                operands.extend(&[2, 1, 0, 3][..components as usize]);
+                g.op(Op::VectorShuffle, ty, &operands)
+            };
+        }
+        g.push(Op::Store, vec![var, value]);
+    }
+    g.call(main);
+    g.end_function();
+    let modes = modes.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    Ok(g.finish(model, entry, &interface, &modes))
+}
@@ -0,0 +1,451 @@
+//! Program binaries, as returned by `glGetProgramBinary` and loaded by `glProgramBinary`
+//!
+//! A binary holds the translated stages of a linked program: the SPIR-V module, MSL source, entry point name and
+//! subroutines of each linked stage, and the first three of the stages generated to emulate geometry and tessellation
+//! stages and transform feedback.
+//! Loading one only has to create the entry points of the stages from their MSL, skipping glslang and SPIRV-Cross
+//! entirely. Like a hit in the [`cache`](super::cache), the default uniform blocks, program interfaces and emulation
+//! layouts are reflected from the stored SPIR-V again instead of being stored themselves, which is cheap and keeps them
+//! from ever disagreeing with the stages.
+//!
+//! Every binary starts with the version and commit hash of the `OxideGL` build that created it, and is rejected by any
+//! other build, since the translation of stages and the layouts derived from them can change between builds.
+
+use std::sync::Arc;
+
+use ahash::{HashMap, HashMapExt};
+
+use super::{
+    FeedbackVaryings, GeneratedStage, LinkedCompute, LinkedFeedback, LinkedGeometry, LinkedProgram,
+    LinkedStage, LinkedTessellation, Program, compute,
+    default_block::{self, UniformTable},
+    feedback::FeedbackLayout,
+    geometry::GeometryLayout,
+    interface::{LinkedProgramResources, ProgramInterfaces},
+    spirv::SpirvModule,
+    subroutines::{StageSubroutines, SubroutineUniform},
+    tessellation::{TessellationInfo, TessellationLayout},
+};
+use crate::{
+    compile_pool::CompilePool,
+    debug::gl_debug,
+    gl_enums::{ShaderType, TransformFeedbackBufferMode},
+    gl_types::GLenum,
+    render::StageCompiler,
+};
+
+/// The `binaryFormat` of `OxideGL` program binaries, the only one enumerated by `GL_PROGRAM_BINARY_FORMATS`
+pub(crate) const PROGRAM_BINARY_FORMAT: GLenum = u32::from_be_bytes(*b"OXGL");
+/// Magic bytes at the start of every program binary, bumped whenever the binary format changes
+const BINARY_MAGIC: &[u8; 8] = b"OXGLPB02";
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+const COMMIT_HASH: &str = env!("OXIDEGL_COMMIT_HASH");
+
+/// Appends length-prefixed values to a program binary
+#[derive(Debug)]
+struct BinaryWriter(Vec<u8>);
+impl BinaryWriter {
+    fn u32(&mut self, v: u32) -> &mut Self {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        self
+    }
+    fn flag(&mut self, v: bool) -> &mut Self {
+        self.u32(u32::from(v))
+    }
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "modules and sources are smaller than 4GiB"
+    )]
+    fn str(&mut self, s: &str) -> &mut Self {
+        self.u32(s.len() as u32);
+        self.0.extend_from_slice(s.as_bytes());
+        self
+    }
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "modules and sources are smaller than 4GiB"
+    )]
+    fn words(&mut self, words: &[u32]) -> &mut Self {
+        self.u32(words.len() as u32);
+        self.0.extend(words.iter().flat_map(|w| w.to_le_bytes()));
+        self
+    }
+    fn stage(&mut self, stage: &LinkedStage) -> &mut Self {
+        self.str(&stage.entry_name)
+            .words(&stage.original_spirv)
+            .str(&stage.msl_src)
+            .subroutines(&stage.subroutines)
+    }
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "more than u32::MAX subroutines cannot exist at once"
+    )]
+    fn subroutines(&mut self, subroutines: &StageSubroutines) -> &mut Self {
+        self.u32(subroutines.functions.len() as u32);
+        for function in &subroutines.functions {
+            self.str(function);
+        }
+        self.u32(subroutines.uniforms.len() as u32);
+        for uniform in &subroutines.uniforms {
+            self.str(&uniform.name)
+                .u32(uniform.len)
+                .flag(uniform.is_array)
+                .u32(uniform.location)
+                .words(&uniform.compatible);
+        }
+        self
+    }
+    fn generated(&mut self, stage: &GeneratedStage) -> &mut Self {
+        self.str(&stage.entry_name)
+            .words(&stage.spirv)
+            .str(&stage.msl_src)
+    }
+}
+
+/// Reads the values written by a [`BinaryWriter`] back, failing if the binary ends early
+#[derive(Debug)]
+struct BinaryReader<'a>(&'a [u8]);
+impl<'a> BinaryReader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], Box<str>> {
+        let (head, tail) = self
+            .0
+            .split_at_checked(n)
+            .ok_or("program binary is truncated")?;
+        self.0 = tail;
+        Ok(head)
+    }
+    fn u32(&mut self) -> Result<u32, Box<str>> {
+        Ok(u32::from_le_bytes(
+            self.take(4)?.try_into().expect("took 4 bytes"),
+        ))
+    }
+    fn flag(&mut self) -> Result<bool, Box<str>> {
+        match self.u32()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err("program binary is corrupt".into()),
+        }
+    }
+    fn str(&mut self) -> Result<&'a str, Box<str>> {
+        let len = self.u32()? as usize;
+        std::str::from_utf8(self.take(len)?).map_err(|_| "program binary is corrupt".into())
+    }
+    fn words(&mut self) -> Result<Box<[u32]>, Box<str>> {
+        let len = self.u32()? as usize;
+        Ok(self
+            .take(len.checked_mul(4).ok_or("program binary is corrupt")?)?
+            .chunks_exact(4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect())
+    }
+    /// Read a linked stage and create its entry point, reflecting its default uniform block and resources again
+    fn stage(
+        &mut self,
+        stage: ShaderType,
+        stage_compiler: &dyn StageCompiler,
+        label: Option<&str>,
+    ) -> Result<LinkedStage, Box<str>> {
+        let entry_name = self.str()?;
+        let original_spirv = self.words()?;
+        let msl_src = self.str()?;
+        let subroutines = self.subroutines()?;
+        let (spirv, default_block) = default_block::rewrite_default_block(&original_spirv)?;
+        let resources = LinkedProgramResources::reflect(&SpirvModule::parse(&spirv)?, stage)?;
+        let function = stage_compiler.new_stage_function(msl_src, entry_name, label)?;
+        Ok(LinkedStage {
+            entry_name: entry_name.into(),
+            function,
+            msl_src: msl_src.into(),
+            original_spirv,
+            spirv,
+            default_block,
+            resources,
+            subroutines,
+        })
+    }
+    fn subroutines(&mut self) -> Result<StageSubroutines, Box<str>> {
+        let functions = (0..self.u32()?)
+            .map(|_| self.str().map(Box::from))
+            .collect::<Result<_, _>>()?;
+        let uniforms = (0..self.u32()?)
+            .map(|_| {
+                Ok(SubroutineUniform {
+                    name: self.str()?.into(),
+                    len: self.u32()?,
+                    is_array: self.flag()?,
+                    location: self.u32()?,
+                    compatible: self.words()?.into(),
+                })
+            })
+            .collect::<Result<_, Box<str>>>()?;
+        Ok(StageSubroutines {
+            functions,
+            uniforms,
+        })
+    }
+    /// Read a generated stage and create its entry point
+    fn generated(
+        &mut self,
+        stage_compiler: &dyn StageCompiler,
+        label: Option<&str>,
+    ) -> Result<GeneratedStage, Box<str>> {
+        let entry_name = self.str()?;
+        let spirv = self.words()?;
+        let msl_src = self.str()?;
+        let function = stage_compiler.new_stage_function(msl_src, entry_name, label)?;
+        Ok(GeneratedStage {
+            entry_name: entry_name.into(),
+            function,
+            msl_src: msl_src.into(),
+            spirv,
+        })
+    }
+    /// Read a value that is only present if it is preceded by a set flag
+    fn opt<T>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<T, Box<str>>,
+    ) -> Result<Option<T>, Box<str>> {
+        self.flag()?.then(|| read(self)).transpose()
+    }
+}
+
+impl LinkedProgram {
+    /// Serialize this program into a binary that can be loaded with [`Program::load_binary`]
+    pub(crate) fn binary(&self) -> Vec<u8> {
+        let mut w = BinaryWriter(BINARY_MAGIC.to_vec());
+        w.str(VERSION).str(COMMIT_HASH).flag(self.separable);
+        w.flag(self.vertex.is_some());
+        if let Some(vertex) = &self.vertex {
+            w.stage(vertex);
+        }
+        w.flag(self.tessellation.is_some());
+        if let Some(t) = &self.tessellation {
+            w.stage(&t.control)
+                .stage(&t.evaluation)
+                .generated(&t.vertex_kernel);
+        }
+        w.flag(self.geometry.is_some());
+        if let Some(g) = &self.geometry {
+            w.stage(&g.stage)
+                .generated(&g.vertex_kernel)
+                .generated(&g.passthrough);
+        }
+        w.flag(self.feedback.is_some());
+        if let Some(f) = &self.feedback {
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "there are less than u32::MAX varyings"
+            )]
+            let count = f.varyings.names.len() as u32;
+            w.u32(f.varyings.mode as u32).u32(count);
+            for name in &f.varyings.names {
+                w.str(name);
+            }
+            w.generated(&f.vertex_kernel).generated(&f.kernel);
+        }
+        w.flag(self.fragment.is_some());
+        if let Some(fragment) = &self.fragment {
+            w.stage(fragment);
+        }
+        w.flag(self.compute.is_some());
+        if let Some(compute) = &self.compute {
+            w.stage(&compute.stage);
+        }
+        w.0
+    }
+}
+
+impl Program {
+    /// Start loading a program binary into this program on the compile pool, replacing its executable like a link
+    /// does. The binary must have the [`PROGRAM_BINARY_FORMAT`] format
+    pub(crate) fn load_binary(
+        &mut self,
+        binary: Box<[u8]>,
+        pool: &mut CompilePool,
+        stage_compiler: Arc<dyn StageCompiler>,
+    ) {
+        self.latest_linkage = None;
+        self.info_log.clear();
+        gl_debug!(src: ShaderCompiler, "attempting to load a program binary into {:?}", self.name);
+        let label = self.label();
+        self.pending_link =
+            Some(pool.submit(move || {
+                Self::load_linked(&binary, stage_compiler.as_ref(), label.as_deref())
+            }));
+    }
+    fn load_linked(
+        binary: &[u8],
+        stage_compiler: &dyn StageCompiler,
+        label: Option<&str>,
+    ) -> Result<LinkedProgram, Box<str>> {
+        let mut r = BinaryReader(binary);
+        if r.take(BINARY_MAGIC.len()).ok() != Some(BINARY_MAGIC) {
+            return Err("not an OxideGL program binary".into());
+        }
+        let (version, commit_hash) = (r.str()?, r.str()?);
+        if (version, commit_hash) != (VERSION, COMMIT_HASH) {
+            return Err(format!(
+                "program binary was created by OxideGL {version} ({}), but this is OxideGL {VERSION} ({})",
+                commit_hash.trim(),
+                COMMIT_HASH.trim()
+            )
+            .into());
+        }
+        let separable = r.flag()?;
+        let vertex = r.opt(|r| r.stage(ShaderType::VertexShader, stage_compiler, label))?;
+        let tessellation = r.opt(|r| {
+            let control = r.stage(ShaderType::TessControlShader, stage_compiler, label)?;
+            let evaluation = r.stage(ShaderType::TessEvaluationShader, stage_compiler, label)?;
+            let vertex_kernel = r.generated(stage_compiler, label)?;
+            let control_module = SpirvModule::parse(&control.spirv)?;
+            let layout = TessellationLayout::new(&control_module)?;
+            let info =
+                TessellationInfo::parse(&control_module, &SpirvModule::parse(&evaluation.spirv)?)?;
+            Ok(LinkedTessellation {
+                control,
+                evaluation,
+                vertex_kernel,
+                layout,
+                info,
+            })
+        })?;
+        let geometry = r.opt(|r| {
+            let stage = r.stage(ShaderType::GeometryShader, stage_compiler, label)?;
+            let layout = GeometryLayout::new(&SpirvModule::parse(&stage.spirv)?)?;
+            Ok(LinkedGeometry {
+                stage,
+                vertex_kernel: r.generated(stage_compiler, label)?,
+                passthrough: r.generated(stage_compiler, label)?,
+                layout,
+            })
+        })?;
+        let feedback = r.opt(|r| {
+            let mode = TransformFeedbackBufferMode::from_repr(r.u32()?)
+                .ok_or("program binary is corrupt")?;
+            let count = r.u32()?;
+            let names = (0..count)
+                .map(|_| r.str().map(str::to_owned))
+                .collect::<Result<Vec<_>, _>>()?;
+            let vertex = vertex
+                .as_ref()
+                .ok_or("program binary captures varyings but has no vertex stage")?;
+            let layout = FeedbackLayout::new(&SpirvModule::parse(&vertex.spirv)?, &names, mode)?;
+            Ok(LinkedFeedback {
+                varyings: FeedbackVaryings { names, mode },
+                vertex_kernel: r.generated(stage_compiler, label)?,
+                kernel: r.generated(stage_compiler, label)?,
+                layout,
+            })
+        })?;
+        let fragment = r.opt(|r| r.stage(ShaderType::FragmentShader, stage_compiler, label))?;
+        let compute = r.opt(|r| {
+            let stage = r.stage(ShaderType::ComputeShader, stage_compiler, label)?;
+            let work_group_size = compute::work_group_size(&SpirvModule::parse(&stage.spirv)?)?;
+            Ok(LinkedCompute {
+                stage,
+                work_group_size,
+            })
+        })?;
+        if !r.0.is_empty() {
+            return Err("program binary is corrupt".into());
+        }
+        let mut linkage = LinkedProgram {
+            separable,
+            uniforms: UniformTable::default(),
+            interfaces: ProgramInterfaces::default(),
+            fragment,
+            vertex,
+            tessellation,
+            geometry,
+            compute,
+            feedback,
+            fetch_stages: HashMap::new(),
+        };
+        linkage.build_interfaces()?;
+        Ok(linkage)
+    }
+}
+
+#[test]
+fn program_binary_roundtrip() {
+    use crate::{context::Context, gl_enums::ProgramProperty, test_util::get_programiv};
+
+    let mut ctx = Context::new_software(4, 4);
+    let src = std::ffi::CString::new(include_str!(
+        "../../../tests/HelloTriangle/shader_triangle.vert"
+    ))
+    .unwrap();
+    let ptr = src.as_ptr();
+    // Safety: strings points to a single nul-terminated string
+    let program =
+        unsafe { ctx.oxidegl_create_shader_programv(ShaderType::VertexShader, 1, &raw const ptr) }
+            .unwrap();
+    assert_eq!(
+        get_programiv(&mut ctx, program, ProgramProperty::LinkStatus),
+        1
+    );
+    let len = get_programiv(&mut ctx, program, ProgramProperty::ProgramBinaryLength);
+    let mut binary = vec![0u8; len as usize];
+    let (mut written, mut format) = (0, 0);
+    // a buffer that is too small is an error
+    // Safety: pointers are valid for the duration of the calls, binary holds len bytes
+    unsafe {
+        assert!(
+            ctx.oxidegl_get_program_binary(
+                program,
+                len - 1,
+                &raw mut written,
+                &raw mut format,
+                binary.as_mut_ptr().cast(),
+            )
+            .is_err()
+        );
+        ctx.oxidegl_get_program_binary(
+            program,
+            len,
+            &raw mut written,
+            &raw mut format,
+            binary.as_mut_ptr().cast(),
+        )
+        .unwrap();
+    }
+    assert_eq!((written, format), (len, PROGRAM_BINARY_FORMAT));
+
+    let load = |ctx: &mut Context, bytes: &[u8], format| {
+        let program = ctx.oxidegl_create_program();
+        #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let len = bytes.len() as i32;
+        // Safety: binary points to len bytes
+        unsafe { ctx.oxidegl_program_binary(program, format, bytes.as_ptr().cast(), len) }
+            .map(|()| program)
+    };
+    let loaded = load(&mut ctx, &binary, PROGRAM_BINARY_FORMAT).unwrap();
+    assert_eq!(
+        get_programiv(&mut ctx, loaded, ProgramProperty::LinkStatus),
+        1
+    );
+    for pname in [
+        ProgramProperty::ActiveAttributes,
+        ProgramProperty::ProgramBinaryLength,
+    ] {
+        assert_eq!(
+            get_programiv(&mut ctx, loaded, pname),
+            get_programiv(&mut ctx, program, pname)
+        );
+    }
+
+    // binaries of other formats, or that are damaged or were made by another build, fail to load
+    assert!(load(&mut ctx, &binary, 0).is_err());
+    let truncated = load(&mut ctx, &binary[..binary.len() - 1], PROGRAM_BINARY_FORMAT).unwrap();
+    assert_eq!(
+        get_programiv(&mut ctx, truncated, ProgramProperty::LinkStatus),
+        0
+    );
+    let mut stale = binary.clone();
+    stale[BINARY_MAGIC.len() + 4] ^= 1;
+    let stale = load(&mut ctx, &stale, PROGRAM_BINARY_FORMAT).unwrap();
+    assert_eq!(
+        get_programiv(&mut ctx, stale, ProgramProperty::LinkStatus),
+        0
+    );
+}
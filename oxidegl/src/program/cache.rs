@@ -0,0 +1,364 @@
+//! Persistent on-disk cache of translated program stages
+//!
+//! Linking a stage runs glslang, SPIRV-Cross and finally the Metal compiler, which adds up to seconds for programs with
+//! large shaders. The SPIR-V and MSL produced for each stage are stored in a content-addressed cache in the user cache
+//! directory, so that linking an identical stage again (in this process or a later one) only has to run the Metal compiler.
+//!
+//! The cache is configured with environment variables:
+//! * `OXIDEGL_SHADER_CACHE`: directory to store the cache in, or `0` to disable it. Defaults to `oxidegl/shaders` in the
+//!   user cache directory
+//! * `OXIDEGL_SHADER_CACHE_SIZE`: maximum size of the cache in MiB, defaults to 256
+//!
+//! Entries are stored in a subdirectory named after a hash of [`Context::VERSION_INFO`], which is marked as belonging to
+//! the cache by a marker file. The subdirectories of other versions are removed when the cache is opened, since their
+//! entries can never be hit again. Anything else in the cache directory is left alone.
+
+use std::{
+    env, fmt,
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    sync::{LazyLock, Mutex},
+    time::SystemTime,
+};
+
+use crate::{
+    context::Context,
+    debug::{gl_debug, gl_warn},
+    gl_enums::ShaderType,
+};
+
+/// Magic bytes at the start of every cache entry, bumped whenever the entry format changes
+const ENTRY_MAGIC: &[u8; 8] = b"OXGLSC01";
+const DEFAULT_SIZE_LIMIT_MIB: u64 = 256;
+/// Prefix of the name of the subdirectory of each version, followed by the hash of its version info
+const VERSION_DIR_PREFIX: &str = "oxidegl-";
+/// File that marks a directory as the subdirectory of a version, so that pruning stale versions never touches
+/// directories the cache did not create
+const VERSION_DIR_MARKER: &str = "OXIDEGL_SHADER_CACHE";
+
+/// Key of a cache entry: a 128 bit FNV-1a hash of everything that affects the translation of a stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct CacheKey(u128);
+
+impl fmt::Display for CacheKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:032x}", self.0)
+    }
+}
+
+/// Builds a [`CacheKey`] from the inputs of the translation of a stage. Every input is length-prefixed, so that the
+/// boundaries between inputs contribute to the key
+#[derive(Debug, Clone)]
+pub(crate) struct CacheKeyBuilder(u128);
+
+impl CacheKeyBuilder {
+    const OFFSET_BASIS: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+    const PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+
+    pub(crate) fn new(stage: ShaderType) -> Self {
+        let mut builder = Self(Self::OFFSET_BASIS);
+        builder.str(Context::VERSION_INFO).u32(stage.into());
+        builder
+    }
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= u128::from(b);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+    pub(crate) fn u32(&mut self, v: u32) -> &mut Self {
+        self.write(&v.to_le_bytes());
+        self
+    }
+    pub(crate) fn bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.write(&(bytes.len() as u64).to_le_bytes());
+        self.write(bytes);
+        self
+    }
+    pub(crate) fn str(&mut self, s: &str) -> &mut Self {
+        self.bytes(s.as_bytes())
+    }
+    pub(crate) fn words(&mut self, words: &[u32]) -> &mut Self {
+        self.write(&(words.len() as u64).to_le_bytes());
+        for w in words {
+            self.write(&w.to_le_bytes());
+        }
+        self
+    }
+    pub(crate) fn finish(&self) -> CacheKey {
+        CacheKey(self.0)
+    }
+}
+
+/// The translation of a stage, as stored in the cache
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CachedStage {
+    /// the SPIR-V module of the stage, before any link-time rewrites
+    pub(crate) spirv: Box<[u32]>,
+    /// the MSL source the rewritten module was translated to
+    pub(crate) msl: Box<str>,
+}
+
+impl CachedStage {
+    fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(16 + self.spirv.len() * 4 + self.msl.len());
+        out.extend_from_slice(ENTRY_MAGIC);
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "modules and sources are smaller than 4GiB"
+        )]
+        {
+            out.extend_from_slice(&(self.spirv.len() as u32).to_le_bytes());
+            out.extend(self.spirv.iter().flat_map(|w| w.to_le_bytes()));
+            out.extend_from_slice(&(self.msl.len() as u32).to_le_bytes());
+        }
+        out.extend_from_slice(self.msl.as_bytes());
+        out
+    }
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        fn take<'a>(bytes: &mut &'a [u8], n: usize) -> Option<&'a [u8]> {
+            let (head, tail) = bytes.split_at_checked(n)?;
+            *bytes = tail;
+            Some(head)
+        }
+        fn take_len(bytes: &mut &[u8]) -> Option<usize> {
+            Some(u32::from_le_bytes(take(bytes, 4)?.try_into().ok()?) as usize)
+        }
+        let mut bytes = bytes;
+        if take(&mut bytes, ENTRY_MAGIC.len())? != ENTRY_MAGIC {
+            return None;
+        }
+        let word_count = take_len(&mut bytes)?;
+        let spirv = take(&mut bytes, word_count.checked_mul(4)?)?
+            .chunks_exact(4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+        let msl_len = take_len(&mut bytes)?;
+        let msl = std::str::from_utf8(take(&mut bytes, msl_len)?).ok()?.into();
+        bytes.is_empty().then_some(Self { spirv, msl })
+    }
+}
+
+/// A directory of cache entries, evicted least recently used first once their total size exceeds a limit
+#[derive(Debug)]
+pub(crate) struct ShaderCache {
+    dir: PathBuf,
+    size_limit: u64,
+    /// total size of the entries in the directory
+    size: u64,
+}
+
+impl ShaderCache {
+    /// Open the cache for this version of `OxideGL` in `root`, removing the caches of other versions
+    pub(crate) fn open(root: &Path, size_limit: u64) -> io::Result<Self> {
+        let version_dir = format!(
+            "{VERSION_DIR_PREFIX}{}",
+            CacheKeyBuilder(CacheKeyBuilder::OFFSET_BASIS)
+                .str(Context::VERSION_INFO)
+                .finish()
+        );
+        fs::create_dir_all(root.join(&version_dir))?;
+        File::create(root.join(&version_dir).join(VERSION_DIR_MARKER))?;
+        for entry in fs::read_dir(root)? {
+            let entry = entry?;
+            if entry.file_name() != *version_dir && is_version_dir(&entry)? {
+                gl_debug!(src: ShaderCompiler, "removing stale shader cache {}", entry.path().display());
+                fs::remove_dir_all(entry.path())?;
+            }
+        }
+        let mut cache = Self {
+            dir: root.join(version_dir),
+            size_limit,
+            size: 0,
+        };
+        cache.size = cache.entries()?.iter().map(|e| e.1).sum();
+        Ok(cache)
+    }
+    fn entry_path(&self, key: CacheKey) -> PathBuf {
+        self.dir.join(format!("{key}.bin"))
+    }
+    /// Path, size and last use of every entry in the cache
+    fn entries(&self) -> io::Result<Vec<(PathBuf, u64, SystemTime)>> {
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().is_some_and(|e| e == "bin") {
+                let meta = entry.metadata()?;
+                entries.push((path, meta.len(), meta.modified()?));
+            }
+        }
+        Ok(entries)
+    }
+    /// Look up an entry, marking it as recently used
+    pub(crate) fn get(&mut self, key: CacheKey) -> Option<CachedStage> {
+        let path = self.entry_path(key);
+        let mut file = OpenOptions::new().read(true).write(true).open(&path).ok()?;
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes).ok()?;
+        let Some(stage) = CachedStage::decode(&bytes) else {
+            gl_warn!(src: ShaderCompiler, "removing corrupt shader cache entry {}", path.display());
+            drop(file);
+            if fs::remove_file(&path).is_ok() {
+                self.size = self.size.saturating_sub(bytes.len() as u64);
+            }
+            return None;
+        };
+        // the modification time doubles as the time of last use for eviction
+        let _ = file.set_modified(SystemTime::now());
+        Some(stage)
+    }
+    /// Store an entry, evicting the least recently used entries if the cache grows past its size limit
+    pub(crate) fn insert(&mut self, key: CacheKey, stage: &CachedStage) -> io::Result<()> {
+        let bytes = stage.encode();
+        let path = self.entry_path(key);
+        if let Ok(meta) = fs::metadata(&path) {
+            self.size = self.size.saturating_sub(meta.len());
+        }
+        // write to a temporary file first so that other processes never observe a partially written entry
+        let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+        File::create(&tmp)?.write_all(&bytes)?;
+        fs::rename(&tmp, &path)?;
+        self.size += bytes.len() as u64;
+        if self.size > self.size_limit {
+            self.evict()?;
+        }
+        Ok(())
+    }
+    fn evict(&mut self) -> io::Result<()> {
+        let mut entries = self.entries()?;
+        self.size = entries.iter().map(|e| e.1).sum();
+        entries.sort_unstable_by_key(|e| e.2);
+        for (path, len, _) in entries {
+            if self.size <= self.size_limit {
+                break;
+            }
+            gl_debug!(src: ShaderCompiler, "evicting shader cache entry {}", path.display());
+            fs::remove_file(path)?;
+            self.size -= len;
+        }
+        Ok(())
+    }
+}
+
+/// Whether a directory entry is the subdirectory of some version of the cache, as created by [`ShaderCache::open`]
+fn is_version_dir(entry: &fs::DirEntry) -> io::Result<bool> {
+    let name = entry.file_name();
+    let is_hash = name
+        .to_str()
+        .and_then(|n| n.strip_prefix(VERSION_DIR_PREFIX))
+        .is_some_and(|hash| hash.len() == 32 && hash.bytes().all(|b| b.is_ascii_hexdigit()));
+    Ok(is_hash && entry.file_type()?.is_dir() && entry.path().join(VERSION_DIR_MARKER).is_file())
+}
+
+/// Open the cache configured by the environment, if any
+fn open_from_env() -> Option<Mutex<ShaderCache>> {
+    // keep unit tests from touching the user cache directory
+    if cfg!(test) {
+        return None;
+    }
+    let root = match env::var_os("OXIDEGL_SHADER_CACHE") {
+        Some(v) if v == "0" => return None,
+        Some(v) => PathBuf::from(v),
+        None => {
+            let base = if cfg!(target_os = "macos") {
+                PathBuf::from(env::var_os("HOME")?).join("Library/Caches")
+            } else if let Some(xdg) = env::var_os("XDG_CACHE_HOME") {
+                PathBuf::from(xdg)
+            } else {
+                PathBuf::from(env::var_os("HOME")?).join(".cache")
+            };
+            base.join("oxidegl/shaders")
+        }
+    };
+    let size_limit = env::var("OXIDEGL_SHADER_CACHE_SIZE")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_SIZE_LIMIT_MIB)
+        .saturating_mul(1024 * 1024);
+    match ShaderCache::open(&root, size_limit) {
+        Ok(cache) => {
+            gl_debug!(src: ShaderCompiler, "opened shader cache at {}", root.display());
+            Some(Mutex::new(cache))
+        }
+        Err(e) => {
+            gl_warn!(src: ShaderCompiler, "failed to open shader cache at {}: {e}", root.display());
+            None
+        }
+    }
+}
+
+static SHADER_CACHE: LazyLock<Option<Mutex<ShaderCache>>> = LazyLock::new(open_from_env);
+
+/// Run a closure with the process-wide shader cache, returning `None` if the cache is disabled
+pub(crate) fn with_shader_cache<T>(f: impl FnOnce(&mut ShaderCache) -> T) -> Option<T> {
+    let cache = SHADER_CACHE.as_ref()?;
+    // a panic while the cache was locked can at worst have left a stale size behind
+    let mut cache = cache
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    Some(f(&mut cache))
+}
+
+#[test]
+fn shader_cache_roundtrip_and_eviction() {
+    let root = env::temp_dir().join(format!("oxidegl-shader-cache-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    // only directories that look like the subdirectory of a version and are marked as such are pruned
+    let stale_version = root.join(format!("{VERSION_DIR_PREFIX}{:032x}", 1));
+    let unmarked = root.join(format!("{VERSION_DIR_PREFIX}{:032x}", 2));
+    let unrelated = root.join("unrelated");
+    for dir in [&stale_version, &unmarked, &unrelated] {
+        fs::create_dir_all(dir).unwrap();
+    }
+    File::create(stale_version.join(VERSION_DIR_MARKER)).unwrap();
+    File::create(unrelated.join(VERSION_DIR_MARKER)).unwrap();
+
+    let stage = |msl: &str| CachedStage {
+        spirv: vec![0x0723_0203, 0x0001_0000, 0, 8, 0].into_boxed_slice(),
+        msl: msl.into(),
+    };
+    let key = |src: &str| {
+        CacheKeyBuilder::new(ShaderType::VertexShader)
+            .str(src)
+            .finish()
+    };
+    assert_ne!(
+        key("a"),
+        CacheKeyBuilder::new(ShaderType::FragmentShader)
+            .str("a")
+            .finish()
+    );
+
+    let entry_size = stage("0123456789").encode().len() as u64;
+    let mut cache = ShaderCache::open(&root, entry_size * 2).unwrap();
+    assert!(!stale_version.exists());
+    assert!(unmarked.exists() && unrelated.exists());
+    assert_eq!(cache.get(key("a")), None);
+    cache.insert(key("a"), &stage("aaaaaaaaaa")).unwrap();
+    assert_eq!(cache.get(key("a")), Some(stage("aaaaaaaaaa")));
+
+    // the cache persists across opens
+    let mut cache = ShaderCache::open(&root, entry_size * 2).unwrap();
+    assert_eq!(cache.size, entry_size);
+    cache.insert(key("b"), &stage("bbbbbbbbbb")).unwrap();
+    // make sure "a" is the least recently used entry regardless of the file system timestamp resolution
+    File::options()
+        .write(true)
+        .open(cache.entry_path(key("a")))
+        .unwrap()
+        .set_modified(SystemTime::UNIX_EPOCH)
+        .unwrap();
+    cache.insert(key("c"), &stage("cccccccccc")).unwrap();
+    assert_eq!(cache.get(key("a")), None);
+    assert_eq!(cache.get(key("b")), Some(stage("bbbbbbbbbb")));
+    assert_eq!(cache.get(key("c")), Some(stage("cccccccccc")));
+
+    // corrupt entries are dropped
+    fs::write(cache.entry_path(key("b")), b"garbage").unwrap();
+    assert_eq!(cache.get(key("b")), None);
+    assert!(!cache.entry_path(key("b")).exists());
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,151 @@
+//! Clip control and user clip distances. Metal clips z to [0, w] and maps normalized device depth to the depth range as
+//! is, while the default GL clip volume spans [-w, w] in z. Metal also clips against every `[[clip_distance]]` a stage
+//! writes, while GL ignores the planes that aren't enabled. The stages that feed the rasterizer remap the outputs they
+//! write in a generated epilogue instead:
+//! 1. The generated entry point calls the original one, and loads the position it wrote.
+//! 2. Unless the [`ZERO_TO_ONE_CONSTANT`] function constant is set (`GL_ZERO_TO_ONE`), z is remapped to (z + w) / 2.
+//! 3. If the [`UPPER_LEFT_CONSTANT`] function constant is set (`GL_UPPER_LEFT`), y is negated.
+//! 4. Each element of `gl_ClipDistance` is replaced by 0, which never clips, unless the function constant at
+//!    [`CLIP_DISTANCE_CONSTANT`] plus its index is set (`GL_CLIP_DISTANCEi`).
+//! 5. The point size is written (see [`points`](super::points)).
+//!
+//! The constants default to the GL default clip volume with every plane disabled, so the Metal backend only
+//! specializes the vertex function of a pipeline when `glClipControl`, `glEnable` or `glPointSize` changed them.
+
+use spirv_cross2::spirv::{BuiltIn, ExecutionModel, Op, StorageClass};
+
+use super::{
+    default_block::UniformKind,
+    geometry::{Generator, StageVariable, VariableKind},
+    points,
+    spirv::Type,
+};
+
+/// Number of user clip planes, `GL_MAX_CLIP_DISTANCES`
+pub(crate) const MAX_CLIP_DISTANCES: u32 = 8;
+
+/// Function constant index of the flag that is set when the clip volume spans [0, w] in z. Metal function constant
+/// indices are 16 bits wide, these are the last ones so they don't collide with the specialization constants of a stage
+pub(crate) const ZERO_TO_ONE_CONSTANT: u32 = 0xfffe;
+/// Function constant index of the flag that is set when the clip volume has an upper left origin
+pub(crate) const UPPER_LEFT_CONSTANT: u32 = 0xffff;
+/// Function constant index of the flag that is set when the first clip plane is enabled, the flags of the other planes
+/// follow it
+pub(crate) const CLIP_DISTANCE_CONSTANT: u32 = ZERO_TO_ONE_CONSTANT - MAX_CLIP_DISTANCES;
+
+/// Output variable of the stage in `g` that is or contains `builtin`, with the type of the builtin and the index of the
+/// block member it is, if it's part of a block
+pub(super) fn builtin_output(
+    g: &Generator,
+    interface: &[u32],
+    builtin: BuiltIn,
+) -> Option<(u32, u32, Option<u32>)> {
+    StageVariable::collect(&g.module, StorageClass::Output, false)
+        .into_iter()
+        .filter(|v| interface.contains(&v.var))
+        .find_map(|v| match v.kind {
+            VariableKind::BuiltIn(b) if b == builtin => Some((v.var, v.ty, None)),
+            VariableKind::Block(members) => {
+                let member = members.iter().position(|&b| b == Some(builtin))?;
+                let Some(Type::Struct(types)) = g.module.types.get(&v.ty) else {
+                    return None;
+                };
+                #[expect(clippy::cast_possible_truncation, reason = "SPIR-V ids are 32 bits")]
+                Some((v.var, types[member], Some(member as u32)))
+            }
+            _ => None,
+        })
+}
+
+/// Generate the variant of the stage in `spirv` that remaps the position and clip distances it writes to the clip
+/// volume of Metal, and writes the point size. Returns `None` for stages that don't feed the rasterizer
+pub(crate) fn remap_clip_outputs(spirv: &[u32]) -> Result<Option<Box<[u32]>>, Box<str>> {
+    let mut g = Generator::new(spirv)?;
+    let entry_point = g
+        .module
+        .instructions
+        .iter()
+        .find(|i| i.op() == Some(Op::EntryPoint))
+        .expect("checked in Generator::new");
+    let execution_model =
+        ExecutionModel::from_u32(entry_point.operands[0]).ok_or("unknown execution model")?;
+    if !matches!(
+        execution_model,
+        ExecutionModel::Vertex | ExecutionModel::TessellationEvaluation
+    ) {
+        return Ok(None);
+    }
+    let mut interface = g.interface();
+    let position = builtin_output(&g, &interface, BuiltIn::Position);
+    let clip_distance = match builtin_output(&g, &interface, BuiltIn::ClipDistance) {
+        Some((var, ty, member)) => {
+            let Some(&Type::Array(_, length)) = g.module.types.get(&ty) else {
+                return Err("gl_ClipDistance is not an array".into());
+            };
+            Some((var, g.module.constant_value(length)?, member))
+        }
+        None => None,
+    };
+    let modes = g
+        .module
+        .instructions
+        .iter()
+        .filter(|i| i.op() == Some(Op::ExecutionMode) && i.operands[0] == g.main)
+        .map(|i| i.operands[1..].to_vec())
+        .collect::<Vec<_>>();
+
+    let float = g.find_or_declare(Type::Scalar(UniformKind::Float, 32));
+    let main = g.main;
+    let entry = g.begin_function("oxidegl_clip_control");
+    g.call(main);
+    if let Some((var, vec4, member)) = position {
+        let zero_to_one = g.spec_flag(ZERO_TO_ONE_CONSTANT, "oxidegl_clip_zero_to_one");
+        let upper_left = g.spec_flag(UPPER_LEFT_CONSTANT, "oxidegl_clip_upper_left");
+        let half = g.float(0.5);
+        let pointer = match member {
+            Some(member) => {
+                let pointer = g.pointer_type(StorageClass::Output, vec4);
+                let index = g.uint(member);
+                g.op(Op::AccessChain, pointer, &[var, index])
+            }
+            None => var,
+        };
+        let position = g.op(Op::Load, vec4, &[pointer]);
+        let y = g.op(Op::CompositeExtract, float, &[position, 1]);
+        let z = g.op(Op::CompositeExtract, float, &[position, 2]);
+        let w = g.op(Op::CompositeExtract, float, &[position, 3]);
+        let sum = g.op(Op::FAdd, float, &[z, w]);
+        let remapped = g.op(Op::FMul, float, &[sum, half]);
+        let z = g.op(Op::Select, float, &[zero_to_one, z, remapped]);
+        let flipped = g.op(Op::FNegate, float, &[y]);
+        let y = g.op(Op::Select, float, &[upper_left, flipped, y]);
+        let position = g.op(Op::CompositeInsert, vec4, &[z, position, 2]);
+        let position = g.op(Op::CompositeInsert, vec4, &[y, position, 1]);
+        g.push(Op::Store, vec![pointer, position]);
+    }
+    if let Some((var, length, member)) = clip_distance {
+        let pointer = g.pointer_type(StorageClass::Output, float);
+        let zero = g.float(0.0);
+        for plane in 0..length.min(MAX_CLIP_DISTANCES) {
+            let enabled = g.spec_flag(
+                CLIP_DISTANCE_CONSTANT + plane,
+                &format!("oxidegl_clip_distance_{plane}"),
+            );
+            let index = g.uint(plane);
+            let element = match member {
+                Some(member) => {
+                    let member = g.uint(member);
+                    g.op(Op::AccessChain, pointer, &[var, member, index])
+                }
+                None => g.op(Op::AccessChain, pointer, &[var, index]),
+            };
+            let distance = g.op(Op::Load, float, &[element]);
+            let distance = g.op(Op::Select, float, &[enabled, distance, zero]);
+            g.push(Op::Store, vec![element, distance]);
+        }
+    }
+    points::write_point_size(&mut g, &mut interface);
+    g.end_function();
+    let modes = modes.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    Ok(Some(g.finish(execution_model, entry, &interface, &modes)))
+}
@@ -0,0 +1,79 @@
+//! Work group sizes of compute stages. Metal takes the number of threads per threadgroup as an argument of each
+//! dispatch rather than as part of the kernel, so the size a compute shader declares is read from its SPIR-V when the
+//! program is linked and passed to every dispatch of it.
+
+use spirv_cross2::spirv::{BuiltIn, Decoration, ExecutionMode, Op};
+
+use super::spirv::SpirvModule;
+
+/// Maximum number of work groups of each dimension of a dispatch
+pub(crate) const MAX_COMPUTE_WORK_GROUP_COUNT: [u32; 3] = [65535; 3];
+/// Maximum size of each dimension of a work group
+pub(crate) const MAX_COMPUTE_WORK_GROUP_SIZE: [u32; 3] = [1024, 1024, 64];
+/// Maximum number of invocations of a work group (`maxTotalThreadsPerThreadgroup` of Metal)
+pub(crate) const MAX_COMPUTE_WORK_GROUP_INVOCATIONS: u32 = 1024;
+
+/// Work group size a compute stage declares with its `local_size_{x,y,z}` (or `local_size_{x,y,z}_id`) layout
+/// qualifiers
+pub(crate) fn work_group_size(module: &SpirvModule) -> Result<[u32; 3], Box<str>> {
+    let constants = |ids: &[u32]| -> Result<[u32; 3], Box<str>> {
+        let mut dims = [0; 3];
+        for (dim, &id) in dims.iter_mut().zip(ids) {
+            *dim = module
+                .constant_value(id)
+                .map_err(|_| "work group size is not a constant")?;
+        }
+        Ok(dims)
+    };
+    let mut size = None;
+    for inst in &module.instructions {
+        if inst.op() != Some(Op::ExecutionMode) {
+            continue;
+        }
+        match ExecutionMode::from_u32(inst.operands[1]) {
+            Some(ExecutionMode::LocalSize) => {
+                size = Some([inst.operands[2], inst.operands[3], inst.operands[4]]);
+            }
+            Some(ExecutionMode::LocalSizeId) => size = Some(constants(&inst.operands[2..5])?),
+            _ => {}
+        }
+    }
+    // a constant decorated with the WorkgroupSize builtin takes precedence over the execution mode, and is how
+    // specialization constants end up in the size
+    let builtin = module.constants.iter().find(|&(&id, _)| {
+        module
+            .decoration(id, Decoration::BuiltIn)
+            .is_some_and(|o| o[0] == BuiltIn::WorkgroupSize as u32)
+    });
+    if let Some((_, composite)) = builtin {
+        let components = composite
+            .operands
+            .get(2..5)
+            .ok_or("work group size constant does not have 3 components")?;
+        size = Some(constants(components)?);
+    }
+    let size = size.ok_or("compute shader does not declare a work group size")?;
+
+    if size.contains(&0) {
+        return Err("work group size must be greater than 0 in every dimension".into());
+    }
+    for ((dim, max), axis) in size
+        .iter()
+        .zip(MAX_COMPUTE_WORK_GROUP_SIZE)
+        .zip(["x", "y", "z"])
+    {
+        if *dim > max {
+            return Err(
+                format!("work group size {axis} of {dim} exceeds the maximum of {max}").into(),
+            );
+        }
+    }
+    let invocations = size.iter().product::<u32>();
+    if invocations > MAX_COMPUTE_WORK_GROUP_INVOCATIONS {
+        return Err(format!(
+            "work groups of {invocations} invocations exceed the maximum of {MAX_COMPUTE_WORK_GROUP_INVOCATIONS}"
+        )
+        .into());
+    }
+    Ok(size)
+}
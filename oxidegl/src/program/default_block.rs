@@ -0,0 +1,945 @@
+//! Emulation of the default uniform block. Metal has no counterpart to loose (non-block) uniforms, so at link time the
+//! loose uniforms of each stage are moved into a generated push constant block. The contents of that block are kept in
+//! host memory, written by the `glUniform*` commands and handed to the stage with `set{Vertex,Fragment}Bytes` at draw time.
+
+use ahash::{HashMap, HashMapExt, HashSet};
+use spirv_cross2::spirv::{Decoration, Op, StorageClass};
+
+use super::{
+    LinkedProgram,
+    spirv::{Instruction, SpirvModule, Type, string_words},
+    subroutines::SELECTOR_PREFIX,
+};
+use crate::{
+    gl_enums::{ShaderType, UniformType},
+    shader::spirv_string,
+};
+
+/// Kind of the components of a uniform, or of the values passed to a `glUniform*` command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UniformKind {
+    Float,
+    Int,
+    UnsignedInt,
+    Bool,
+    Double,
+    /// Samplers and other opaque types, whose value is the index of the unit they refer to
+    Opaque,
+}
+
+/// Component kind, number of columns and number of rows of each non-opaque uniform type. Vectors have a single column
+const SHAPES: &[(UniformType, UniformKind, u8, u8)] = {
+    use UniformKind as K;
+    use UniformType as U;
+    &[
+        (U::Float, K::Float, 1, 1),
+        (U::FloatVec2, K::Float, 1, 2),
+        (U::FloatVec3, K::Float, 1, 3),
+        (U::FloatVec4, K::Float, 1, 4),
+        (U::Int, K::Int, 1, 1),
+        (U::IntVec2, K::Int, 1, 2),
+        (U::IntVec3, K::Int, 1, 3),
+        (U::IntVec4, K::Int, 1, 4),
+        (U::UnsignedInt, K::UnsignedInt, 1, 1),
+        (U::UnsignedIntVec2, K::UnsignedInt, 1, 2),
+        (U::UnsignedIntVec3, K::UnsignedInt, 1, 3),
+        (U::UnsignedIntVec4, K::UnsignedInt, 1, 4),
+        (U::Bool, K::Bool, 1, 1),
+        (U::BoolVec2, K::Bool, 1, 2),
+        (U::BoolVec3, K::Bool, 1, 3),
+        (U::BoolVec4, K::Bool, 1, 4),
+        (U::FloatMat2, K::Float, 2, 2),
+        (U::FloatMat2x3, K::Float, 2, 3),
+        (U::FloatMat2x4, K::Float, 2, 4),
+        (U::FloatMat3x2, K::Float, 3, 2),
+        (U::FloatMat3, K::Float, 3, 3),
+        (U::FloatMat3x4, K::Float, 3, 4),
+        (U::FloatMat4x2, K::Float, 4, 2),
+        (U::FloatMat4x3, K::Float, 4, 3),
+        (U::FloatMat4, K::Float, 4, 4),
+        (U::Double, K::Double, 1, 1),
+        (U::DoubleVec2, K::Double, 1, 2),
+        (U::DoubleVec3, K::Double, 1, 3),
+        (U::DoubleVec4, K::Double, 1, 4),
+        (U::DoubleMat2, K::Double, 2, 2),
+        (U::DoubleMat2x3, K::Double, 2, 3),
+        (U::DoubleMat2x4, K::Double, 2, 4),
+        (U::DoubleMat3x2, K::Double, 3, 2),
+        (U::DoubleMat3, K::Double, 3, 3),
+        (U::DoubleMat3x4, K::Double, 3, 4),
+        (U::DoubleMat4x2, K::Double, 4, 2),
+        (U::DoubleMat4x3, K::Double, 4, 3),
+        (U::DoubleMat4, K::Double, 4, 4),
+    ]
+};
+
+/// Sampled component kind, SPIR-V image dimensionality, arrayed, multisampled and shadow flags of each sampler type
+#[rustfmt::skip]
+pub(crate) const SAMPLERS: &[(UniformType, UniformKind, u32, bool, bool, bool)] = {
+    use UniformKind as K;
+    use UniformType as U;
+    &[
+        (U::Sampler1D, K::Float, 0, false, false, false),
+        (U::Sampler2D, K::Float, 1, false, false, false),
+        (U::Sampler3D, K::Float, 2, false, false, false),
+        (U::SamplerCube, K::Float, 3, false, false, false),
+        (U::Sampler2DRect, K::Float, 4, false, false, false),
+        (U::SamplerBuffer, K::Float, 5, false, false, false),
+        (U::Sampler1DArray, K::Float, 0, true, false, false),
+        (U::Sampler2DArray, K::Float, 1, true, false, false),
+        (U::SamplerCubeMapArray, K::Float, 3, true, false, false),
+        (U::Sampler2DMultisample, K::Float, 1, false, true, false),
+        (U::Sampler2DMultisampleArray, K::Float, 1, true, true, false),
+        (U::Sampler1DShadow, K::Float, 0, false, false, true),
+        (U::Sampler2DShadow, K::Float, 1, false, false, true),
+        (U::SamplerCubeShadow, K::Float, 3, false, false, true),
+        (U::Sampler2DRectShadow, K::Float, 4, false, false, true),
+        (U::Sampler1DArrayShadow, K::Float, 0, true, false, true),
+        (U::Sampler2DArrayShadow, K::Float, 1, true, false, true),
+        (U::SamplerCubeMapArrayShadow, K::Float, 3, true, false, true),
+        (U::IntSampler1D, K::Int, 0, false, false, false),
+        (U::IntSampler2D, K::Int, 1, false, false, false),
+        (U::IntSampler3D, K::Int, 2, false, false, false),
+        (U::IntSamplerCube, K::Int, 3, false, false, false),
+        (U::IntSampler2DRect, K::Int, 4, false, false, false),
+        (U::IntSamplerBuffer, K::Int, 5, false, false, false),
+        (U::IntSampler1DArray, K::Int, 0, true, false, false),
+        (U::IntSampler2DArray, K::Int, 1, true, false, false),
+        (U::IntSamplerCubeMapArray, K::Int, 3, true, false, false),
+        (U::IntSampler2DMultisample, K::Int, 1, false, true, false),
+        (U::IntSampler2DMultisampleArray, K::Int, 1, true, true, false),
+        (U::UnsignedIntSampler1D, K::UnsignedInt, 0, false, false, false),
+        (U::UnsignedIntSampler2D, K::UnsignedInt, 1, false, false, false),
+        (U::UnsignedIntSampler3D, K::UnsignedInt, 2, false, false, false),
+        (U::UnsignedIntSamplerCube, K::UnsignedInt, 3, false, false, false),
+        (U::UnsignedIntSampler2DRect, K::UnsignedInt, 4, false, false, false),
+        (U::UnsignedIntSamplerBuffer, K::UnsignedInt, 5, false, false, false),
+        (U::UnsignedIntSampler1DArray, K::UnsignedInt, 0, true, false, false),
+        (U::UnsignedIntSampler2DArray, K::UnsignedInt, 1, true, false, false),
+        (U::UnsignedIntSamplerCubeMapArray, K::UnsignedInt, 3, true, false, false),
+        (U::UnsignedIntSampler2DMultisample, K::UnsignedInt, 1, false, true, false),
+        (U::UnsignedIntSampler2DMultisampleArray, K::UnsignedInt, 1, true, true, false),
+    ]
+};
+
+impl UniformType {
+    /// Component kind, number of columns and number of rows of this type. Vectors have a single column, and
+    /// opaque types are treated as a single opaque component
+    pub(crate) fn shape(self) -> (UniformKind, u8, u8) {
+        SHAPES
+            .iter()
+            .find(|s| s.0 == self)
+            .map_or((UniformKind::Opaque, 1, 1), |&(_, kind, columns, rows)| {
+                (kind, columns, rows)
+            })
+    }
+    pub(crate) fn from_shape(kind: UniformKind, columns: u32, rows: u32) -> Option<Self> {
+        SHAPES
+            .iter()
+            .find(|s| (s.1, u32::from(s.2), u32::from(s.3)) == (kind, columns, rows))
+            .map(|s| s.0)
+    }
+    /// Number of locations a stage input or output of this type takes up
+    pub(crate) fn locations(self) -> u32 {
+        let (kind, columns, rows) = self.shape();
+        let wide = kind == UniformKind::Double && rows > 2;
+        u32::from(columns) * if wide { 2 } else { 1 }
+    }
+}
+
+/// A uniform in the default block of a linked stage. Uniforms of struct type (and arrays of structs) are split into
+/// one member per field of basic type, named the way GL names them (e.g. `lights[1].color`)
+#[derive(Debug, Clone)]
+pub(crate) struct BlockMember {
+    pub(crate) name: Box<str>,
+    pub(crate) ty: UniformType,
+    /// Number of array elements, 1 if this member is not an array
+    pub(crate) len: u32,
+    pub(crate) is_array: bool,
+    /// Location assigned to this member in the shader source, if any
+    pub(crate) location: Option<u32>,
+    /// Where this member is stored in the block. `None` for opaque uniforms, which are not part of the block
+    pub(crate) layout: Option<MemberLayout>,
+}
+/// Placement of a uniform inside of a generated default block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct MemberLayout {
+    pub(crate) offset: u32,
+    pub(crate) array_stride: u32,
+    /// Distance between the columns of a matrix, unused for scalars and vectors
+    pub(crate) matrix_stride: u32,
+}
+
+/// The emulated default uniform block of a linked stage
+#[derive(Debug, Default)]
+pub struct DefaultBlock {
+    pub(crate) members: Vec<BlockMember>,
+    /// Current contents of the block. Empty if the stage has no non-opaque loose uniforms
+    pub(crate) data: Vec<u8>,
+}
+
+/// A uniform in the default block of a linked program
+#[derive(Debug)]
+pub struct ActiveUniform {
+    pub(crate) name: Box<str>,
+    pub(crate) ty: UniformType,
+    pub(crate) len: u32,
+    pub(crate) is_array: bool,
+    /// Location of the first array element
+    pub(crate) location: u32,
+    /// Where this uniform is stored in the default block of each stage that uses it
+    pub(crate) slots: Vec<(ShaderType, MemberLayout)>,
+    /// Stages that declare this uniform
+    pub(crate) stages: Vec<ShaderType>,
+    /// Unit of each array element, for opaque uniforms
+    pub(crate) units: Box<[u32]>,
+}
+/// The active uniforms in the default block of a linked program, and the locations assigned to them
+#[derive(Debug, Default)]
+pub struct UniformTable {
+    pub(crate) uniforms: Vec<ActiveUniform>,
+    /// Index of the uniform and array element referred to by each location
+    pub(crate) locations: Vec<Option<(usize, u32)>>,
+}
+impl UniformTable {
+    /// Merge the default blocks of the stages of a program, assigning a location to each uniform that wasn't given one
+    /// in the shader source
+    pub(crate) fn build(stages: &[(ShaderType, &DefaultBlock)]) -> Result<Self, Box<str>> {
+        let mut uniforms: Vec<ActiveUniform> = Vec::new();
+        let mut explicit_locations: Vec<Option<u32>> = Vec::new();
+        for &(stage, block) in stages {
+            // subroutine selectors are set by glUniformSubroutinesuiv, not glUniform*
+            for member in block
+                .members
+                .iter()
+                .filter(|m| !m.name.starts_with(SELECTOR_PREFIX))
+            {
+                let idx = if let Some(idx) = uniforms.iter().position(|u| u.name == member.name) {
+                    let u = &uniforms[idx];
+                    if (u.ty, u.len) != (member.ty, member.len) {
+                        return Err(format!(
+                            "uniform {} is declared with different types in different stages",
+                            member.name
+                        )
+                        .into());
+                    }
+                    match (explicit_locations[idx], member.location) {
+                        (Some(a), Some(b)) if a != b => {
+                            return Err(format!(
+                                "uniform {} is given different locations in different stages",
+                                member.name
+                            )
+                            .into());
+                        }
+                        (None, l) => explicit_locations[idx] = l,
+                        _ => {}
+                    }
+                    idx
+                } else {
+                    uniforms.push(ActiveUniform {
+                        name: member.name.clone(),
+                        ty: member.ty,
+                        len: member.len,
+                        is_array: member.is_array,
+                        location: 0,
+                        slots: Vec::new(),
+                        stages: Vec::new(),
+                        units: vec![0; member.len as usize].into_boxed_slice(),
+                    });
+                    explicit_locations.push(member.location);
+                    uniforms.len() - 1
+                };
+                if !uniforms[idx].stages.contains(&stage) {
+                    uniforms[idx].stages.push(stage);
+                }
+                if let Some(layout) = member.layout {
+                    uniforms[idx].slots.push((stage, layout));
+                }
+            }
+        }
+
+        let mut locations: Vec<Option<(usize, u32)>> = Vec::new();
+        let place = |locations: &mut Vec<Option<(usize, u32)>>,
+                     idx: usize,
+                     start: u32,
+                     u: &mut ActiveUniform| {
+            let end = (start + u.len) as usize;
+            if locations.len() < end {
+                locations.resize(end, None);
+            }
+            u.location = start;
+            for (element, l) in (0..u.len).zip(&mut locations[start as usize..end]) {
+                *l = Some((idx, element));
+            }
+        };
+        // uniforms with explicit locations are placed first, the rest go in the lowest free range of locations
+        for idx in 0..uniforms.len() {
+            let Some(start) = explicit_locations[idx] else {
+                continue;
+            };
+            let range = start as usize..(start + uniforms[idx].len) as usize;
+            if let Some(&(other, _)) = locations.get(range).into_iter().flatten().flatten().next() {
+                return Err(format!(
+                    "the locations of uniforms {} and {} overlap",
+                    uniforms[idx].name, uniforms[other].name
+                )
+                .into());
+            }
+            place(&mut locations, idx, start, &mut uniforms[idx]);
+        }
+        for (idx, u) in uniforms.iter_mut().enumerate() {
+            if explicit_locations[idx].is_some() {
+                continue;
+            }
+            let mut start = 0;
+            while locations
+                .iter()
+                .skip(start as usize)
+                .take(u.len as usize)
+                .any(Option::is_some)
+            {
+                start += 1;
+            }
+            place(&mut locations, idx, start, u);
+        }
+        Ok(Self {
+            uniforms,
+            locations,
+        })
+    }
+    /// Index of the uniform and array element referred to by a location
+    #[inline]
+    pub(crate) fn get(&self, location: u32) -> Option<(usize, u32)> {
+        self.locations.get(location as usize).copied().flatten()
+    }
+    /// Location of a uniform (or of an element of a uniform array) by name
+    pub(crate) fn location(&self, name: &str) -> Option<u32> {
+        let (base, element) = match name.strip_suffix(']').and_then(|n| n.rsplit_once('[')) {
+            Some((base, idx)) => (base, Some(idx.parse::<u32>().ok()?)),
+            None => (name, None),
+        };
+        if let Some(u) = self.uniforms.iter().find(|u| &*u.name == name) {
+            return Some(u.location);
+        }
+        let u = self
+            .uniforms
+            .iter()
+            .find(|u| u.is_array && &*u.name == base)?;
+        let element = element?;
+        (element < u.len).then_some(u.location + element)
+    }
+}
+
+impl LinkedProgram {
+    /// Store the values of consecutive elements of a uniform, starting at element `first`. `words` holds the components
+    /// of each element in column-major order, already converted to the component kind of the uniform
+    pub(crate) fn write_uniform(&mut self, uniform: usize, first: u32, words: &[u32]) {
+        let Self {
+            uniforms,
+            vertex,
+            tessellation,
+            geometry,
+            fragment,
+            compute,
+            ..
+        } = self;
+        let u = &mut uniforms.uniforms[uniform];
+        let (kind, columns, rows) = u.ty.shape();
+        if kind == UniformKind::Opaque {
+            for (unit, &word) in u.units[first as usize..].iter_mut().zip(words) {
+                *unit = word;
+            }
+            return;
+        }
+        let element_words = usize::from(columns) * usize::from(rows);
+        for &(stage, layout) in &u.slots {
+            let linked = match stage {
+                ShaderType::VertexShader => vertex.as_mut(),
+                ShaderType::TessControlShader => tessellation.as_mut().map(|t| &mut t.control),
+                ShaderType::TessEvaluationShader => {
+                    tessellation.as_mut().map(|t| &mut t.evaluation)
+                }
+                ShaderType::GeometryShader => geometry.as_mut().map(|g| &mut g.stage),
+                ShaderType::FragmentShader => fragment.as_mut(),
+                ShaderType::ComputeShader => compute.as_mut().map(|c| &mut c.stage),
+            }
+            .expect("uniform is stored in a stage that was not linked");
+            let data = &mut linked.default_block.data;
+            for (element, values) in (first..).zip(words.chunks_exact(element_words)) {
+                let base = (layout.offset + element * layout.array_stride) as usize;
+                for (column, values) in values.chunks_exact(usize::from(rows)).enumerate() {
+                    let column_base = base + column * layout.matrix_stride as usize;
+                    for (row, word) in values.iter().enumerate() {
+                        let at = column_base + 4 * row;
+                        data[at..at + 4].copy_from_slice(&word.to_le_bytes());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Name given to the generated push constant block
+const BLOCK_NAME: &str = "gl_DefaultUniformBlock";
+
+/// State of the rewrite of a single module
+#[derive(Debug)]
+struct Rewriter {
+    module: SpirvModule,
+
+    /// Size and alignment of the types laid out so far
+    layouts: HashMap<u32, (u32, u32)>,
+    array_strides: HashMap<u32, u32>,
+    struct_offsets: HashMap<u32, Vec<u32>>,
+
+    /// Decorations to add in front of the first type declaration
+    new_decorations: Vec<Instruction>,
+    /// Scalar and vector types to add in front of the first type declaration
+    new_types: Vec<Instruction>,
+    /// Declarations to add in front of the first function
+    new_globals: Vec<Instruction>,
+    /// Debug names to add after the last existing one
+    new_names: Vec<Instruction>,
+}
+
+impl Rewriter {
+    fn new(words: &[u32]) -> Result<Self, Box<str>> {
+        Ok(Self {
+            module: SpirvModule::parse(words)?,
+            layouts: HashMap::new(),
+            array_strides: HashMap::new(),
+            struct_offsets: HashMap::new(),
+            new_decorations: Vec::new(),
+            new_types: Vec::new(),
+            new_globals: Vec::new(),
+            new_names: Vec::new(),
+        })
+    }
+    /// Find the id of a type, declaring it if the module doesn't already
+    fn find_or_declare(&mut self, ty: Type) -> u32 {
+        if let Some((&id, _)) = self.module.types.iter().find(|(_, t)| **t == ty) {
+            return id;
+        }
+        let id = self.module.new_id();
+        self.new_types.push(match ty {
+            Type::Scalar(kind, width) => Instruction::new(
+                Op::TypeInt,
+                vec![id, width, u32::from(kind == UniformKind::Int)],
+            ),
+            Type::Vector(component, n) => Instruction::new(Op::TypeVector, vec![id, component, n]),
+            _ => unreachable!("only integer scalar and vector types are declared by the rewrite"),
+        });
+        self.module.types.insert(id, ty);
+        id
+    }
+    /// The block-compatible counterpart of a basic type (booleans are stored as unsigned integers)
+    fn storage_type(&mut self, ty: u32) -> u32 {
+        match self.module.types[&ty] {
+            Type::Bool => self.find_or_declare(Type::Scalar(UniformKind::UnsignedInt, 32)),
+            Type::Vector(component, n) if self.module.types[&component] == Type::Bool => {
+                let uint = self.find_or_declare(Type::Scalar(UniformKind::UnsignedInt, 32));
+                self.find_or_declare(Type::Vector(uint, n))
+            }
+            _ => ty,
+        }
+    }
+    /// Decorate a member of a block or struct with its offset, and its matrix layout if it holds matrices
+    fn decorate_member(
+        &mut self,
+        ty: u32,
+        member: u32,
+        member_ty: u32,
+        offset: u32,
+    ) -> Result<(), Box<str>> {
+        self.new_decorations.push(Instruction::new(
+            Op::MemberDecorate,
+            vec![ty, member, Decoration::Offset as u32, offset],
+        ));
+        if let Some(column) = self.module.matrix_column(member_ty) {
+            let (stride, _) = self.layout(column)?;
+            self.new_decorations.push(Instruction::new(
+                Op::MemberDecorate,
+                vec![ty, member, Decoration::ColMajor as u32],
+            ));
+            self.new_decorations.push(Instruction::new(
+                Op::MemberDecorate,
+                vec![ty, member, Decoration::MatrixStride as u32, stride],
+            ));
+        }
+        Ok(())
+    }
+    /// Size and alignment of a type when stored in the default block, decorating it with its layout. Types are laid out
+    /// the way Metal lays out the equivalent MSL types, so that SPIRV-Cross doesn't have to emit any padding or repacking
+    fn layout(&mut self, ty: u32) -> Result<(u32, u32), Box<str>> {
+        if let Some(&layout) = self.layouts.get(&ty) {
+            return Ok(layout);
+        }
+        let layout = match self.module.types.get(&ty).cloned() {
+            Some(Type::Bool | Type::Scalar(_, 32)) => (4, 4),
+            Some(Type::Scalar(_, width)) => {
+                return Err(format!("{width} bit uniforms are not supported").into());
+            }
+            Some(Type::Vector(component, n)) => {
+                let (size, _) = self.layout(component)?;
+                // 3 component vectors are padded to the size of 4 component vectors in MSL
+                let size = size * if n == 2 { 2 } else { 4 };
+                (size, size)
+            }
+            Some(Type::Matrix(column, n)) => {
+                let (size, align) = self.layout(column)?;
+                (size * n, align)
+            }
+            Some(Type::Array(element, len)) => {
+                if self.module.is_bool(element) {
+                    return Err("arrays of boolean uniforms are not supported".into());
+                }
+                let (stride, align) = self.layout(element)?;
+                self.new_decorations.push(Instruction::new(
+                    Op::Decorate,
+                    vec![ty, Decoration::ArrayStride as u32, stride],
+                ));
+                self.array_strides.insert(ty, stride);
+                (stride * self.module.constant_value(len)?, align)
+            }
+            Some(Type::Struct(members)) => {
+                let mut offsets = Vec::with_capacity(members.len());
+                let (mut size, mut align) = (0u32, 4);
+                for (member, member_ty) in (0..).zip(members) {
+                    if self.module.is_bool(member_ty) {
+                        return Err("boolean members of uniform structs are not supported".into());
+                    }
+                    let (member_size, member_align) = self.layout(member_ty)?;
+                    let offset = size.next_multiple_of(member_align);
+                    self.decorate_member(ty, member, member_ty, offset)?;
+                    offsets.push(offset);
+                    size = offset + member_size;
+                    align = align.max(member_align);
+                }
+                self.struct_offsets.insert(ty, offsets);
+                (size.next_multiple_of(align), align)
+            }
+            _ => return Err("unsupported uniform type".into()),
+        };
+        self.layouts.insert(ty, layout);
+        Ok(layout)
+    }
+    /// Split a uniform into members of basic type, the way GL enumerates active uniforms
+    fn flatten(
+        &self,
+        name: &str,
+        ty: u32,
+        offset: Option<u32>,
+        location: &mut Option<u32>,
+        members: &mut Vec<BlockMember>,
+    ) -> Result<(), Box<str>> {
+        let mut push = |ty, len, is_array, layout| {
+            members.push(BlockMember {
+                name: name.into(),
+                ty,
+                len,
+                is_array,
+                location: *location,
+                layout,
+            });
+            if let Some(l) = location {
+                *l += len;
+            }
+        };
+        let member_layout = |ty, array_stride| {
+            offset.map(|offset| MemberLayout {
+                offset,
+                array_stride,
+                matrix_stride: self
+                    .module
+                    .matrix_column(ty)
+                    .and_then(|c| self.layouts.get(&c))
+                    .map_or(0, |l| l.0),
+            })
+        };
+        if let Some(basic) = self
+            .module
+            .basic_type(ty)
+            .or_else(|| self.module.sampler_type(ty))
+        {
+            push(basic, 1, false, member_layout(ty, 0));
+            return Ok(());
+        }
+        match self.module.types.get(&ty) {
+            Some(&Type::Array(element, len)) => {
+                let len = self.module.constant_value(len)?;
+                let stride = self.array_strides.get(&ty).copied().unwrap_or(0);
+                if let Some(basic) = self
+                    .module
+                    .basic_type(element)
+                    .or_else(|| self.module.sampler_type(element))
+                {
+                    push(basic, len, true, member_layout(ty, stride));
+                } else {
+                    for i in 0..len {
+                        self.flatten(
+                            &format!("{name}[{i}]"),
+                            element,
+                            offset.map(|o| o + i * stride),
+                            location,
+                            members,
+                        )?;
+                    }
+                }
+            }
+            Some(Type::Struct(struct_members)) => {
+                for (i, &member_ty) in (0..).zip(struct_members) {
+                    let member_name = self
+                        .module
+                        .member_names
+                        .get(&(ty, i))
+                        .map_or_else(|| format!("_m{i}"), Clone::clone);
+                    let member_offset = offset.map(|o| o + self.struct_offsets[&ty][i as usize]);
+                    self.flatten(
+                        &format!("{name}.{member_name}"),
+                        member_ty,
+                        member_offset,
+                        location,
+                        members,
+                    )?;
+                }
+            }
+            // opaque uniforms that can't be set with glUniform (e.g. bare samplers) are left out
+            _ => {}
+        }
+        Ok(())
+    }
+    /// Write the value of a constant (i.e. the initializer of a uniform) into the contents of the block
+    fn write_constant(&self, data: &mut [u8], ty: u32, id: u32, offset: u32) {
+        let Some(constant) = self.module.constants.get(&id) else {
+            return;
+        };
+        let o = &constant.operands;
+        match constant.op() {
+            Some(Op::Constant | Op::SpecConstant) => {
+                let at = offset as usize;
+                data[at..at + 4].copy_from_slice(&o[2].to_le_bytes());
+            }
+            Some(Op::ConstantTrue | Op::SpecConstantTrue) => {
+                let at = offset as usize;
+                data[at..at + 4].copy_from_slice(&1u32.to_le_bytes());
+            }
+            Some(Op::ConstantComposite | Op::SpecConstantComposite) => {
+                for (i, &constituent) in (0..).zip(&o[2..]) {
+                    let (constituent_ty, constituent_offset) = match self.module.types[&ty] {
+                        Type::Vector(component, _) => (component, 4 * i),
+                        Type::Matrix(column, _) => (column, i * self.layouts[&column].0),
+                        Type::Array(element, _) => (element, i * self.array_strides[&ty]),
+                        Type::Struct(ref members) => {
+                            (members[i as usize], self.struct_offsets[&ty][i as usize])
+                        }
+                        _ => return,
+                    };
+                    self.write_constant(
+                        data,
+                        constituent_ty,
+                        constituent,
+                        offset + constituent_offset,
+                    );
+                }
+            }
+            // false and null constants are already zero
+            _ => {}
+        }
+    }
+}
+
+/// Indices of the operands of an instruction that may refer to a pointer to a uniform
+fn pointer_operands(inst: &Instruction) -> std::ops::Range<usize> {
+    match inst.op() {
+        Some(Op::Load | Op::AccessChain | Op::InBoundsAccessChain | Op::PtrAccessChain) => 2..3,
+        Some(Op::CopyMemory) => 1..2,
+        Some(Op::FunctionCall) => 3..inst.operands.len(),
+        _ => 0..0,
+    }
+}
+
+/// Move the loose uniforms of a SPIR-V module into a generated push constant block, and describe the default uniform
+/// block of the module. Loose uniforms of opaque type (i.e. samplers) are left alone, but are still described.
+pub(crate) fn rewrite_default_block(words: &[u32]) -> Result<(Box<[u32]>, DefaultBlock), Box<str>> {
+    let mut rw = Rewriter::new(words)?;
+
+    // loose uniforms and their pointer types
+    let mut uniforms = Vec::new();
+    let mut opaque_uniforms = Vec::new();
+    for inst in &rw.module.instructions {
+        if inst.op() == Some(Op::FunctionEnd) {
+            break;
+        }
+        let o = &inst.operands;
+        if inst.op() == Some(Op::Variable) && o[2] == StorageClass::UniformConstant as u32 {
+            let Some(&Type::Pointer(_, pointee)) = rw.module.types.get(&o[0]) else {
+                continue;
+            };
+            if rw.module.is_opaque(pointee) {
+                opaque_uniforms.push((o[1], pointee));
+            } else {
+                uniforms.push((o[1], o[0], pointee, o.get(3).copied()));
+            }
+        }
+    }
+    let mut block = DefaultBlock::default();
+    for &(var, pointee) in &opaque_uniforms {
+        let name = rw
+            .module
+            .names
+            .get(&var)
+            .cloned()
+            .unwrap_or_else(|| format!("_{var}"));
+        let mut location = rw.module.location(var);
+        rw.flatten(&name, pointee, None, &mut location, &mut block.members)?;
+    }
+    if uniforms.is_empty() {
+        return Ok((words.into(), block));
+    }
+
+    // lay out the block
+    let block_ty = rw.module.new_id();
+    let mut member_types = Vec::with_capacity(uniforms.len());
+    let mut offsets = Vec::with_capacity(uniforms.len());
+    let mut size = 0u32;
+    for (member, &(var, _, pointee, _)) in (0..).zip(&uniforms) {
+        let (member_size, member_align) = rw.layout(pointee)?;
+        let offset = size.next_multiple_of(member_align);
+        rw.decorate_member(block_ty, member, pointee, offset)?;
+        size = offset + member_size;
+
+        let name = rw
+            .module
+            .names
+            .get(&var)
+            .cloned()
+            .unwrap_or_else(|| format!("_{var}"));
+        let mut location = rw.module.location(var);
+        rw.flatten(
+            &name,
+            pointee,
+            Some(offset),
+            &mut location,
+            &mut block.members,
+        )?;
+        let mut member_name = vec![block_ty, member];
+        member_name.extend(string_words(&name));
+        rw.new_names
+            .push(Instruction::new(Op::MemberName, member_name));
+
+        member_types.push(rw.storage_type(pointee));
+        offsets.push(offset);
+    }
+    block.data = vec![0; size.next_multiple_of(16) as usize];
+    for (&(_, _, pointee, initializer), &offset) in uniforms.iter().zip(&offsets) {
+        if let Some(initializer) = initializer {
+            rw.write_constant(&mut block.data, pointee, initializer, offset);
+        }
+    }
+
+    // declare the block, and a constant holding the index of each of its members
+    let block_ptr = rw.module.new_id();
+    let block_var = rw.module.new_id();
+    let int = rw.find_or_declare(Type::Scalar(UniformKind::Int, 32));
+    rw.new_decorations.push(Instruction::new(
+        Op::Decorate,
+        vec![block_ty, Decoration::Block as u32],
+    ));
+    let mut block_name = vec![block_ty];
+    block_name.extend(string_words(BLOCK_NAME));
+    rw.new_names.push(Instruction::new(Op::Name, block_name));
+    let mut struct_operands = vec![block_ty];
+    struct_operands.extend(&member_types);
+    rw.new_globals
+        .push(Instruction::new(Op::TypeStruct, struct_operands));
+    rw.new_globals.push(Instruction::new(
+        Op::TypePointer,
+        vec![block_ptr, StorageClass::PushConstant as u32, block_ty],
+    ));
+    rw.new_globals.push(Instruction::new(
+        Op::Variable,
+        vec![block_ptr, block_var, StorageClass::PushConstant as u32],
+    ));
+    let mut member_indices = HashMap::new();
+    for (member, &(var, ..)) in (0..).zip(&uniforms) {
+        let id = rw.module.new_id();
+        rw.new_globals
+            .push(Instruction::new(Op::Constant, vec![int, id, member]));
+        member_indices.insert(var, id);
+    }
+
+    // pointers to loose uniforms now point into the block. Pointers to booleans become pointers to unsigned integers,
+    // so loads through them need to be converted back
+    let mut bool_pointers = HashMap::new();
+    let pointer_types = rw
+        .module
+        .types
+        .iter()
+        .filter_map(|(&id, ty)| match *ty {
+            Type::Pointer(storage, pointee)
+                if storage == StorageClass::UniformConstant as u32
+                    && !rw.module.is_opaque(pointee) =>
+            {
+                Some((id, pointee))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let mut retyped = HashMap::new();
+    for (id, pointee) in pointer_types {
+        let storage_ty = rw.storage_type(pointee);
+        if storage_ty != pointee {
+            bool_pointers.insert(id, (pointee, storage_ty));
+        }
+        retyped.insert(id, storage_ty);
+    }
+    // booleans are compared against null to convert them back after loading
+    let mut null_constants = HashMap::new();
+    for &(_, storage_ty) in bool_pointers.values() {
+        if let std::collections::hash_map::Entry::Vacant(e) = null_constants.entry(storage_ty) {
+            let id = rw.module.new_id();
+            e.insert(id);
+            rw.new_globals
+                .push(Instruction::new(Op::ConstantNull, vec![storage_ty, id]));
+        }
+    }
+
+    // result type of every pointer that may refer to a uniform, and the uniforms used by each function
+    let removed = uniforms.iter().map(|u| u.0).collect::<HashSet<_>>();
+    let mut pointer_result_types = HashMap::new();
+    let mut function_uniforms: HashMap<usize, Vec<u32>> = HashMap::new();
+    let mut function = 0;
+    for (idx, inst) in rw.module.instructions.iter().enumerate() {
+        let o = &inst.operands;
+        match inst.op() {
+            Some(Op::Function) => function = idx,
+            Some(
+                Op::AccessChain
+                | Op::InBoundsAccessChain
+                | Op::PtrAccessChain
+                | Op::FunctionParameter
+                | Op::Variable,
+            ) => {
+                pointer_result_types.insert(o[1], o[0]);
+            }
+            _ => {}
+        }
+        for &operand in &o[pointer_operands(inst)] {
+            if removed.contains(&operand) {
+                let used = function_uniforms.entry(function).or_default();
+                if !used.contains(&operand) {
+                    used.push(operand);
+                }
+            }
+        }
+    }
+
+    let first_type =
+        rw.module.instructions.iter().position(|i| {
+            (Op::TypeVoid as u32..=Op::TypeForwardPointer as u32).contains(&i.opcode)
+        });
+    let first_function = rw
+        .module
+        .instructions
+        .iter()
+        .position(|i| i.op() == Some(Op::Function));
+    let last_name = rw
+        .module
+        .instructions
+        .iter()
+        .rposition(|i| matches!(i.op(), Some(Op::Name | Op::MemberName)));
+
+    let instructions = std::mem::take(&mut rw.module.instructions);
+    let mut out = Vec::with_capacity(instructions.len() + 4 * uniforms.len());
+    // pointer types that now point to a type declared by the rewrite, and have to be declared after it
+    let mut moved_pointers = Vec::new();
+    // uniform -> pointer to its block member in the current function
+    let mut renames = HashMap::new();
+    // access chains creating those pointers, inserted once the variables of the function's first block are declared
+    let mut pending_chains = Vec::new();
+    let mut awaiting_label = false;
+    for (idx, mut inst) in instructions.into_iter().enumerate() {
+        if Some(idx) == last_name.map(|i| i + 1) {
+            out.append(&mut rw.new_names);
+        }
+        if Some(idx) == first_type {
+            out.append(&mut rw.new_decorations);
+        }
+        if Some(idx) == first_function {
+            out.append(&mut rw.new_types);
+            out.append(&mut moved_pointers);
+            out.append(&mut rw.new_globals);
+        }
+        if !pending_chains.is_empty()
+            && !awaiting_label
+            && !matches!(
+                inst.op(),
+                Some(Op::Variable | Op::Line | Op::NoLine | Op::Label)
+            )
+        {
+            out.append(&mut pending_chains);
+        }
+        for i in pointer_operands(&inst) {
+            if let Some(&new) = renames.get(&inst.operands[i]) {
+                inst.operands[i] = new;
+            }
+        }
+        let op = inst.op();
+        let o = &mut inst.operands;
+        match op {
+            Some(Op::Name | Op::Decorate) if removed.contains(&o[0]) => continue,
+            Some(Op::Variable) if removed.contains(&o[1]) => continue,
+            Some(Op::EntryPoint) => {
+                let (_, name_len) = spirv_string(&o[2..]);
+                let interface = o.split_off(2 + name_len);
+                o.extend(interface.into_iter().filter(|id| !removed.contains(id)));
+            }
+            Some(Op::TypePointer) => {
+                if let Some(&storage_ty) = retyped.get(&o[0]) {
+                    o[1] = StorageClass::PushConstant as u32;
+                    o[2] = storage_ty;
+                    if bool_pointers.contains_key(&o[0]) {
+                        moved_pointers.push(inst);
+                        continue;
+                    }
+                }
+            }
+            Some(Op::Function) => {
+                renames.clear();
+                pending_chains.clear();
+                for &var in function_uniforms.get(&idx).into_iter().flatten() {
+                    let ptr_ty = uniforms.iter().find(|u| u.0 == var).unwrap().1;
+                    let chain = rw.module.new_id();
+                    renames.insert(var, chain);
+                    pointer_result_types.insert(chain, ptr_ty);
+                    pending_chains.push(Instruction::new(
+                        Op::AccessChain,
+                        vec![ptr_ty, chain, block_var, member_indices[&var]],
+                    ));
+                }
+                awaiting_label = !pending_chains.is_empty();
+            }
+            Some(Op::Label) => awaiting_label = false,
+            Some(Op::Load) => {
+                let bool_pointer = pointer_result_types
+                    .get(&o[2])
+                    .and_then(|ty| bool_pointers.get(ty));
+                if let Some(&(bool_ty, storage_ty)) = bool_pointer {
+                    let loaded = rw.module.new_id();
+                    let result = o[1];
+                    o[0] = storage_ty;
+                    o[1] = loaded;
+                    out.push(inst);
+                    out.push(Instruction::new(
+                        Op::INotEqual,
+                        vec![bool_ty, result, loaded, null_constants[&storage_ty]],
+                    ));
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        out.push(inst);
+    }
+    Ok((rw.module.words(&out), block))
+}
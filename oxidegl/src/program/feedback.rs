@@ -0,0 +1,412 @@
+//! Emulation of transform feedback. Metal can't capture the outputs of a vertex function, so while transform feedback
+//! is active, each draw runs a compute pre-pass before the regular draw:
+//! 1. The vertex stage runs as a compute kernel over the vertices used by the draw, writing its outputs into records
+//!    in the input buffer, like it does in the geometry pre-pass (see [`geometry`](super::geometry)).
+//! 2. A generated kernel with a thread per captured vertex copies the captured components of its record into the bound
+//!    transform feedback buffers. Vertices are captured in the order of the primitives they are assembled into, so
+//!    strips, loops and fans are written out as lists of independent primitives, as required by the GL.
+//!
+//! Captured varyings are packed into the buffers in the order they were given to `glTransformFeedbackVaryings`. In
+//! interleaved mode they are written to the same buffer until `gl_NextBuffer` moves on to the next one, and
+//! `gl_SkipComponents1-4` leave gaps in between. In separate mode each varying is written to its own buffer.
+
+use spirv_cross2::spirv::{BuiltIn, Decoration, ExecutionMode, ExecutionModel, Op, StorageClass};
+
+use super::{
+    geometry::{
+        BUILTIN_SLOTS, Generator, IN_PRIMITIVES_START, IN_RECORDS_START, INPUT_BINDING, Leaf,
+        RecordLayout, StageVariable, VariableKind, assemble_input, input_buffer,
+    },
+    interface::{BlockLayout, InterfaceBlock, InterfaceVariable},
+    spirv::{SpirvModule, Type},
+};
+use crate::{
+    context::state::MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS,
+    gl_enums::{PrimitiveType, ShaderType, TransformFeedbackBufferMode, UniformType},
+    render::PrepassDraw,
+};
+
+/// Binding of the first transform feedback buffer in the discrete descriptor set. Buffer `i` is bound to the binding
+/// after it
+pub(crate) const BUFFER_BINDING: u32 = 3;
+
+/// Value of `GL_MAX_TRANSFORM_FEEDBACK_INTERLEAVED_COMPONENTS`
+pub(crate) const MAX_INTERLEAVED_COMPONENTS: u32 = 64;
+/// Value of `GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_ATTRIBS`
+pub(crate) const MAX_SEPARATE_ATTRIBS: u32 = 4;
+/// Value of `GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_COMPONENTS`
+pub(crate) const MAX_SEPARATE_COMPONENTS: u32 = 4;
+
+/// Name of a builtin output that can be captured
+fn builtin_name(builtin: BuiltIn) -> Option<&'static str> {
+    match builtin {
+        BuiltIn::Position => Some("gl_Position"),
+        BuiltIn::PointSize => Some("gl_PointSize"),
+        _ => None,
+    }
+}
+
+/// Number of vertices in each primitive captured in the given primitive mode
+pub(crate) fn primitive_vertices(primitive_mode: PrimitiveType) -> u32 {
+    match primitive_mode {
+        PrimitiveType::Points => 1,
+        PrimitiveType::Lines => 2,
+        PrimitiveType::Triangles => 3,
+        _ => unreachable!("not a transform feedback primitive mode"),
+    }
+}
+
+/// Whether primitives of type `mode` can be drawn while capturing `primitive_mode` primitives
+pub(crate) fn captures(primitive_mode: PrimitiveType, mode: PrimitiveType) -> bool {
+    use PrimitiveType as P;
+    match primitive_mode {
+        P::Points => mode == P::Points,
+        P::Lines => matches!(
+            mode,
+            P::Lines | P::LineStrip | P::LineLoop | P::LinesAdjacency | P::LineStripAdjacency
+        ),
+        P::Triangles => matches!(
+            mode,
+            P::Triangles
+                | P::TriangleStrip
+                | P::TriangleFan
+                | P::TrianglesAdjacency
+                | P::TriangleStripAdjacency
+        ),
+        _ => false,
+    }
+}
+
+/// Split `count` vertices drawn as primitives of type `mode` into the independent primitives that are captured, which
+/// leave out the adjacent vertices of adjacency primitives
+pub(crate) fn assemble(mode: PrimitiveType, count: u32) -> Vec<u32> {
+    use PrimitiveType as P;
+    let vertices = assemble_input(mode, count);
+    match mode {
+        P::LinesAdjacency | P::LineStripAdjacency => vertices
+            .chunks_exact(4)
+            .flat_map(|p| [p[1], p[2]])
+            .collect(),
+        P::TrianglesAdjacency | P::TriangleStripAdjacency => vertices
+            .chunks_exact(6)
+            .flat_map(|p| [p[0], p[2], p[4]])
+            .collect(),
+        _ => vertices,
+    }
+}
+
+/// Number of primitives `count` vertices drawn as primitives of type `mode` make up, as counted by
+/// `GL_PRIMITIVES_GENERATED` queries
+pub(crate) fn primitive_count(mode: PrimitiveType, count: u32) -> u32 {
+    use PrimitiveType as P;
+    let vertices = match mode {
+        P::Points => 1,
+        P::Lines | P::LineStrip | P::LineLoop | P::LinesAdjacency | P::LineStripAdjacency => 2,
+        _ => 3,
+    };
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "draws have less than u32::MAX vertices"
+    )]
+    let primitives = assemble(mode, count).len() as u32 / vertices;
+    primitives
+}
+
+/// Placement of the captured varyings of a vertex stage in its records and in the transform feedback buffers
+#[derive(Debug, Clone)]
+pub struct FeedbackLayout {
+    pub(crate) mode: TransformFeedbackBufferMode,
+    /// Layout of the records the vertex stage writes its outputs to
+    pub(crate) records: RecordLayout,
+    /// Captured words, as the word in the record, the buffer and the word in the captured vertex of the buffer
+    copies: Vec<(u32, u32, u32)>,
+    /// Size of a captured vertex in each buffer, in words
+    pub(crate) strides: Vec<u32>,
+    /// The captured varyings, as exposed by the `GL_TRANSFORM_FEEDBACK_VARYING` interface
+    pub(crate) varyings: Vec<InterfaceVariable>,
+    /// The buffers written to, as exposed by the `GL_TRANSFORM_FEEDBACK_BUFFER` interface
+    pub(crate) buffers: Vec<InterfaceBlock>,
+}
+impl FeedbackLayout {
+    /// Lay out the varyings of a vertex stage that are named by `names`
+    pub(crate) fn new(
+        module: &SpirvModule,
+        names: &[String],
+        mode: TransformFeedbackBufferMode,
+    ) -> Result<Self, Box<str>> {
+        let separate = mode == TransformFeedbackBufferMode::SeparateAttribs;
+        if separate && names.len() > MAX_SEPARATE_ATTRIBS as usize {
+            return Err(format!(
+                "{} separate transform feedback varyings exceed GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_ATTRIBS ({MAX_SEPARATE_ATTRIBS})",
+                names.len()
+            )
+            .into());
+        }
+        let outputs = StageVariable::collect(module, StorageClass::Output, false);
+        let records = RecordLayout::new(module, &outputs, BUILTIN_SLOTS)?;
+        let mut layout = Self {
+            mode,
+            records,
+            copies: Vec::new(),
+            strides: vec![0],
+            varyings: Vec::new(),
+            buffers: Vec::new(),
+        };
+        // buffer written to and the next word of the captured vertex in it
+        let (mut buffer, mut offset) = (0, 0);
+        for (i, name) in (0..).zip(names) {
+            if separate {
+                (buffer, offset) = (i, 0);
+                layout.strides.resize(i as usize + 1, 0);
+            } else if name == "gl_NextBuffer" {
+                (buffer, offset) = (buffer + 1, 0);
+                if buffer as usize >= MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS {
+                    return Err(
+                        "transform feedback varyings use more than GL_MAX_TRANSFORM_FEEDBACK_BUFFERS buffers".into(),
+                    );
+                }
+                layout.strides.push(0);
+                continue;
+            } else if let Some(n) = name.strip_prefix("gl_SkipComponents") {
+                offset += match n.parse::<u32>() {
+                    Ok(n @ 1..=4) => n,
+                    _ => return Err(format!("invalid transform feedback varying {name}").into()),
+                };
+                layout.strides[buffer as usize] = offset;
+                continue;
+            }
+            if layout.varyings.iter().any(|v| *v.name == **name) {
+                return Err(format!("transform feedback varying {name} is captured twice").into());
+            }
+            let (ty, len, leaves) = find_varying(module, &outputs, &layout.records, name)?
+                .ok_or_else(|| {
+                    format!(
+                        "transform feedback varying {name} is not an output of the vertex stage"
+                    )
+                })?;
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "varyings have less than u32::MAX components"
+            )]
+            let components = leaves.len() as u32;
+            if separate && components > MAX_SEPARATE_COMPONENTS {
+                return Err(format!(
+                    "transform feedback varying {name} has more than GL_MAX_TRANSFORM_FEEDBACK_SEPARATE_COMPONENTS ({MAX_SEPARATE_COMPONENTS}) components"
+                )
+                .into());
+            }
+            layout.varyings.push(InterfaceVariable {
+                name: name.as_str().into(),
+                ty,
+                len,
+                is_array: false,
+                location: None,
+                location_index: None,
+                component: None,
+                block: Some(buffer),
+                layout: Some(BlockLayout {
+                    offset: offset * 4,
+                    array_stride: 0,
+                    matrix_stride: 0,
+                    row_major: false,
+                    top_level_size: 1,
+                    top_level_stride: 0,
+                }),
+                stages: vec![ShaderType::VertexShader],
+            });
+            for leaf in leaves {
+                layout.copies.push((leaf.word, buffer, offset));
+                offset += 1;
+            }
+            layout.strides[buffer as usize] = offset;
+        }
+        let components = layout.strides.iter().sum::<u32>();
+        if !separate && components > MAX_INTERLEAVED_COMPONENTS {
+            return Err(format!(
+                "{components} interleaved transform feedback components exceed GL_MAX_TRANSFORM_FEEDBACK_INTERLEAVED_COMPONENTS ({MAX_INTERLEAVED_COMPONENTS})"
+            )
+            .into());
+        }
+        layout.buffers = (0..)
+            .zip(&layout.strides)
+            .map(|(binding, stride)| InterfaceBlock {
+                name: "".into(),
+                binding: Some(binding),
+                data_size: stride * 4,
+                members: Vec::new(),
+                stages: vec![ShaderType::VertexShader],
+            })
+            .collect();
+        Ok(layout)
+    }
+    /// Start of the input buffer of a draw that captures `vertices` vertices, and its size in words. The record of each
+    /// captured vertex takes the place of the vertices of the input primitives
+    pub(crate) fn input_buffer(&self, draw: &PrepassDraw, vertices: u32) -> (Vec<u32>, usize) {
+        // the primitives of each instance are captured after the ones of the previous instance
+        let captured = (0..draw.instance_count)
+            .flat_map(|instance| {
+                draw.primitives
+                    .iter()
+                    .map(move |&v| v + instance * draw.vertex_span)
+            })
+            .take(vertices as usize)
+            .collect();
+        input_buffer(
+            &PrepassDraw {
+                primitives: captured,
+                ..draw.clone()
+            },
+            &self.records,
+        )
+    }
+}
+
+/// Resolve a captured varying by name, returning its type, array length and the components it captures
+fn find_varying(
+    module: &SpirvModule,
+    outputs: &[StageVariable],
+    records: &RecordLayout,
+    name: &str,
+) -> Result<Option<(UniformType, u32, Vec<Leaf>)>, Box<str>> {
+    let (base, element) = match name.strip_suffix(']').and_then(|n| n.rsplit_once('[')) {
+        Some((base, element)) => match element.parse::<u32>() {
+            Ok(element) => (base, Some(element)),
+            Err(_) => return Ok(None),
+        },
+        None => (name, None),
+    };
+    for v in outputs {
+        // access chain indices of the varying in the output variable, and its type
+        let member = |ty: u32, m: u32| match module.types.get(&ty) {
+            Some(Type::Struct(members)) => (vec![m], members[m as usize]),
+            _ => unreachable!("blocks are structs"),
+        };
+        let (mut path, mut ty) = match &v.kind {
+            VariableKind::User(_) if module.decoration(v.ty, Decoration::Block).is_some() => {
+                let block = module.names.get(&v.ty).map_or("", String::as_str);
+                let Some(Type::Struct(members)) = module.types.get(&v.ty) else {
+                    continue;
+                };
+                let Some(m) = (0..).take(members.len()).find(|&m| {
+                    module.member_names.get(&(v.ty, m)).is_some_and(|n| {
+                        base.strip_prefix(block)
+                            .and_then(|b| b.strip_prefix('.'))
+                            .is_some_and(|b| b == n)
+                    })
+                }) else {
+                    continue;
+                };
+                member(v.ty, m)
+            }
+            VariableKind::User(_) if module.names.get(&v.var).is_some_and(|n| n == base) => {
+                (Vec::new(), v.ty)
+            }
+            &VariableKind::BuiltIn(b) if builtin_name(b) == Some(base) => (Vec::new(), v.ty),
+            VariableKind::Block(builtins) => {
+                let Some(m) = (0..)
+                    .zip(builtins)
+                    .find(|(_, b)| b.and_then(builtin_name) == Some(base))
+                    .map(|(m, _)| m)
+                else {
+                    continue;
+                };
+                member(v.ty, m)
+            }
+            _ => continue,
+        };
+        let mut len = 1;
+        match (module.types.get(&ty), element) {
+            (Some(&Type::Array(element_ty, array_len)), Some(element)) => {
+                if element >= module.constant_value(array_len)? {
+                    return Ok(None);
+                }
+                path.push(element);
+                ty = element_ty;
+            }
+            (Some(&Type::Array(element_ty, array_len)), None) => {
+                len = module.constant_value(array_len)?;
+                ty = element_ty;
+            }
+            (_, Some(_)) => return Ok(None),
+            (_, None) => {}
+        }
+        let ty = module.basic_type(ty).ok_or_else(|| {
+            format!("transform feedback varying {name} does not have a basic type")
+        })?;
+        let leaves = v
+            .leaves(module, records)?
+            .into_iter()
+            .filter(|l| l.path.starts_with(&path))
+            .collect::<Vec<_>>();
+        if leaves.is_empty() {
+            return Err(format!("transform feedback varying {name} can't be captured").into());
+        }
+        return Ok(Some((ty, len, leaves)));
+    }
+    Ok(None)
+}
+
+/// Generate the compute kernel that copies the captured components of the record of one captured vertex per thread
+/// into the transform feedback buffers
+pub(crate) fn feedback_kernel(
+    vertex_spirv: &[u32],
+    layout: &FeedbackLayout,
+) -> Result<Box<[u32]>, Box<str>> {
+    let mut g = Generator::new(vertex_spirv)?;
+    // the vertex stage itself already ran in the vertex kernel, the kernel only keeps its module around
+    g.privatize(&[StorageClass::Input, StorageClass::Output]);
+
+    let uint = g.uint_type();
+    let uvec3 = g.find_or_declare(Type::Vector(uint, 3));
+    let global_id = g.builtin_input(BuiltIn::GlobalInvocationId, uvec3, "gl_GlobalInvocationID");
+    let entry = g.begin_function("oxidegl_feedback_kernel");
+    let id = g.op(Op::Load, uvec3, &[global_id]);
+    let vertex = g.op(Op::CompositeExtract, uint, &[id, 0]);
+    let primitives_start = g.uint(IN_PRIMITIVES_START);
+    let index = g.uint_op(Op::IAdd, vertex, primitives_start);
+    let record = g.load_word(INPUT_BINDING, index);
+    let base = g.record(
+        INPUT_BINDING,
+        IN_RECORDS_START,
+        record,
+        layout.records.stride,
+    );
+    // start of the captured vertex in each buffer
+    let mut vertex_starts = vec![None; layout.strides.len()];
+    for &(word, buffer, offset) in &layout.copies {
+        let word = g.uint(word);
+        let src = g.uint_op(Op::IAdd, base, word);
+        let value = g.load_word(INPUT_BINDING, src);
+        let start = if let Some(start) = vertex_starts[buffer as usize] {
+            start
+        } else {
+            let stride = g.uint(layout.strides[buffer as usize]);
+            let start = g.uint_op(Op::IMul, vertex, stride);
+            vertex_starts[buffer as usize] = Some(start);
+            start
+        };
+        let offset = g.uint(offset);
+        let dst = g.uint_op(Op::IAdd, start, offset);
+        g.store_word(BUFFER_BINDING + buffer, dst, value);
+    }
+    g.end_function();
+    Ok(g.finish(
+        ExecutionModel::GLCompute,
+        entry,
+        &[global_id],
+        &[&[ExecutionMode::LocalSize as u32, 1, 1, 1]],
+    ))
+}
+
+#[test]
+fn feedback_assembly() {
+    use PrimitiveType as P;
+    assert_eq!(assemble(P::LineLoop, 3), [0, 1, 1, 2, 2, 0]);
+    assert_eq!(assemble(P::TriangleFan, 4), [0, 1, 2, 0, 2, 3]);
+    assert_eq!(assemble(P::LineStripAdjacency, 5), [1, 2, 2, 3]);
+    assert_eq!(assemble(P::TrianglesAdjacency, 6), [0, 2, 4]);
+    assert_eq!(assemble(P::TriangleStripAdjacency, 8), [0, 2, 4, 4, 2, 6]);
+    assert_eq!(primitive_count(P::LineLoop, 3), 3);
+    assert_eq!(primitive_count(P::TriangleStrip, 5), 3);
+    assert_eq!(primitive_count(P::Points, 7), 7);
+}
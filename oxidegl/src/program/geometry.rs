@@ -0,0 +1,1487 @@
+//! Emulation of geometry shaders. Metal has no geometry stage, so a draw through a geometry stage is split into a
+//! compute pre-pass and a regular draw:
+//! 1. The vertex stage runs as a compute kernel over the vertices used by the draw, writing its outputs into records
+//!    in the input buffer.
+//! 2. The geometry stage runs as a compute kernel with a thread per input primitive, instance and geometry shader
+//!    invocation. Its inputs are loaded from the records of the vertices of its primitive, and `EmitStreamVertex`
+//!    appends a record holding the current outputs to the output buffer. Vertices emitted to stream 0 are assembled
+//!    into lists of points, lines or triangles in the index region of the output buffer.
+//! 3. A generated pass-through vertex stage reads the records back out of the output buffer, and the assembled
+//!    primitives are drawn with an indirect indexed draw whose arguments live at the start of the output buffer.
+//!
+//! Records are made up of 16 byte slots. The first one holds `gl_Position`, the second one `gl_PointSize`, `gl_Layer`,
+//! `gl_ViewportIndex` and the stream the vertex was emitted to. The user-defined interface variables of the geometry
+//! stage follow, and are matched with those of the vertex stage by location, or by name if they don't have one.
+
+use ahash::{HashMap, HashMapExt};
+use spirv_cross2::spirv::{
+    BuiltIn, Decoration, ExecutionMode, ExecutionModel, MemorySemantics, Op, Scope, StorageClass,
+};
+
+use super::{
+    DISCRETE_DESCRIPTOR_SET,
+    default_block::UniformKind,
+    spirv::{Instruction, SpirvModule, Type, string_words},
+};
+use crate::{gl_enums::PrimitiveType, render::PrepassDraw, shader::spirv_string};
+
+/// Bindings of the input and output buffers in the discrete descriptor set
+pub(crate) const INPUT_BINDING: u32 = 0;
+pub(crate) const OUTPUT_BINDING: u32 = 1;
+
+/// Words of the header of the input buffer. The vertices of each input primitive (relative to the vertex base) follow
+/// it, then the records written by the vertex stage
+pub(super) const IN_VERTEX_BASE: u32 = 0;
+pub(super) const IN_BASE_INSTANCE: u32 = 1;
+pub(super) const IN_VERTEX_SPAN: u32 = 2;
+pub(super) const IN_RECORDS_START: u32 = 3;
+pub(super) const IN_PRIMITIVES_START: u32 = 4;
+
+/// Words of the header of the output buffer. It starts with the arguments of the indirect indexed draw of the assembled
+/// primitives, whose index count is incremented as they are assembled. The indices follow it, then the records of the
+/// emitted vertices
+const OUT_INDEX_COUNT: u32 = 0;
+const OUT_VERTEX_COUNT: u32 = 5;
+const OUT_VERTEX_CAPACITY: u32 = 6;
+const OUT_RECORDS_START: u32 = 7;
+const OUT_INDICES_START: u32 = 8;
+
+/// Words in a slot of a record
+pub(super) const SLOT_WORDS: u32 = 4;
+/// Slots at the start of each record that are taken up by builtins
+pub(super) const BUILTIN_SLOTS: u32 = 2;
+const POINT_SIZE_WORD: u32 = 4;
+const STREAM_WORD: u32 = 7;
+
+/// Value of `GL_MAX_GEOMETRY_OUTPUT_VERTICES`
+const MAX_OUTPUT_VERTICES: u32 = 256;
+/// Value of `GL_MAX_GEOMETRY_SHADER_INVOCATIONS`
+const MAX_INVOCATIONS: u32 = 32;
+/// Value of `GL_MAX_VERTEX_STREAMS`
+pub(crate) const MAX_VERTEX_STREAMS: u32 = 4;
+/// Value of `GL_MAX_GEOMETRY_INPUT_COMPONENTS`
+pub(crate) const MAX_INPUT_COMPONENTS: u32 = 64;
+/// Value of `GL_MAX_GEOMETRY_OUTPUT_COMPONENTS`
+pub(crate) const MAX_OUTPUT_COMPONENTS: u32 = 128;
+
+/// Word a builtin is stored at in a record, if it is passed between stages
+fn builtin_word(builtin: BuiltIn) -> Option<u32> {
+    match builtin {
+        BuiltIn::Position => Some(0),
+        BuiltIn::PointSize => Some(POINT_SIZE_WORD),
+        BuiltIn::Layer => Some(5),
+        BuiltIn::ViewportIndex => Some(6),
+        // tessellation levels are only stored in the records of patches, which hold no other builtins
+        BuiltIn::TessLevelOuter => Some(0),
+        BuiltIn::TessLevelInner => Some(4 * SLOT_WORDS),
+        _ => None,
+    }
+}
+
+/// Execution modes of a geometry stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeometryInfo {
+    /// `POINTS`, `LINES`, `LINES_ADJACENCY`, `TRIANGLES` or `TRIANGLES_ADJACENCY`
+    pub(crate) input: PrimitiveType,
+    /// `POINTS`, `LINE_STRIP` or `TRIANGLE_STRIP`
+    pub(crate) output: PrimitiveType,
+    pub(crate) max_vertices: u32,
+    pub(crate) invocations: u32,
+}
+impl GeometryInfo {
+    fn parse(module: &SpirvModule) -> Result<Self, Box<str>> {
+        let (mut input, mut output, mut max_vertices, mut invocations) = (None, None, None, 1);
+        for inst in &module.instructions {
+            if inst.op() != Some(Op::ExecutionMode) {
+                continue;
+            }
+            let o = &inst.operands;
+            match ExecutionMode::from_u32(o[1]) {
+                Some(ExecutionMode::InputPoints) => input = Some(PrimitiveType::Points),
+                Some(ExecutionMode::InputLines) => input = Some(PrimitiveType::Lines),
+                Some(ExecutionMode::InputLinesAdjacency) => {
+                    input = Some(PrimitiveType::LinesAdjacency);
+                }
+                Some(ExecutionMode::Triangles) => input = Some(PrimitiveType::Triangles),
+                Some(ExecutionMode::InputTrianglesAdjacency) => {
+                    input = Some(PrimitiveType::TrianglesAdjacency);
+                }
+                Some(ExecutionMode::OutputPoints) => output = Some(PrimitiveType::Points),
+                Some(ExecutionMode::OutputLineStrip) => output = Some(PrimitiveType::LineStrip),
+                Some(ExecutionMode::OutputTriangleStrip) => {
+                    output = Some(PrimitiveType::TriangleStrip);
+                }
+                Some(ExecutionMode::OutputVertices) => max_vertices = Some(o[2]),
+                Some(ExecutionMode::Invocations) => invocations = o[2],
+                _ => {}
+            }
+        }
+        let info = Self {
+            input: input.ok_or("geometry shader does not declare an input primitive type")?,
+            output: output.ok_or("geometry shader does not declare an output primitive type")?,
+            max_vertices: max_vertices.ok_or("geometry shader does not declare max_vertices")?,
+            invocations,
+        };
+        if info.max_vertices > MAX_OUTPUT_VERTICES {
+            return Err(format!(
+                "geometry shader max_vertices of {} exceeds GL_MAX_GEOMETRY_OUTPUT_VERTICES ({MAX_OUTPUT_VERTICES})",
+                info.max_vertices
+            )
+            .into());
+        }
+        if !(1..=MAX_INVOCATIONS).contains(&info.invocations) {
+            return Err(format!(
+                "geometry shader invocation count of {} exceeds GL_MAX_GEOMETRY_SHADER_INVOCATIONS ({MAX_INVOCATIONS})",
+                info.invocations
+            )
+            .into());
+        }
+        Ok(info)
+    }
+    /// Number of vertices in each input primitive
+    pub(crate) fn input_vertices(&self) -> u32 {
+        match self.input {
+            PrimitiveType::Points => 1,
+            PrimitiveType::Lines => 2,
+            PrimitiveType::Triangles => 3,
+            PrimitiveType::LinesAdjacency => 4,
+            PrimitiveType::TrianglesAdjacency => 6,
+            _ => unreachable!("not a geometry shader input primitive type"),
+        }
+    }
+    /// Type of the primitives the output strips are assembled into
+    pub(crate) fn output_list(&self) -> PrimitiveType {
+        match self.output {
+            PrimitiveType::LineStrip => PrimitiveType::Lines,
+            PrimitiveType::TriangleStrip => PrimitiveType::Triangles,
+            _ => PrimitiveType::Points,
+        }
+    }
+    /// Number of vertices in each assembled output primitive
+    fn output_vertices(&self) -> u32 {
+        match self.output {
+            PrimitiveType::LineStrip => 2,
+            PrimitiveType::TriangleStrip => 3,
+            _ => 1,
+        }
+    }
+    /// Whether primitives of the given type can be drawn through this geometry stage
+    pub(crate) fn accepts(&self, mode: PrimitiveType) -> bool {
+        use PrimitiveType as P;
+        match self.input {
+            P::Points => mode == P::Points,
+            P::Lines => matches!(mode, P::Lines | P::LineStrip | P::LineLoop),
+            P::LinesAdjacency => matches!(mode, P::LinesAdjacency | P::LineStripAdjacency),
+            P::Triangles => matches!(mode, P::Triangles | P::TriangleStrip | P::TriangleFan),
+            P::TrianglesAdjacency => {
+                matches!(mode, P::TrianglesAdjacency | P::TriangleStripAdjacency)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Split `count` vertices drawn as primitives of type `mode` into the input primitives of a geometry stage, returning
+/// the vertices of each primitive in the order the geometry stage sees them
+pub(crate) fn assemble_input(mode: PrimitiveType, count: u32) -> Vec<u32> {
+    use PrimitiveType as P;
+    let mut out = Vec::new();
+    match mode {
+        P::Points => out.extend(0..count),
+        P::Lines => out.extend(0..count / 2 * 2),
+        P::LineStrip | P::LineLoop => {
+            for i in 1..count {
+                out.extend([i - 1, i]);
+            }
+            if mode == P::LineLoop && count > 2 {
+                out.extend([count - 1, 0]);
+            }
+        }
+        P::LinesAdjacency => out.extend(0..count / 4 * 4),
+        P::LineStripAdjacency => {
+            for i in 3..count {
+                out.extend([i - 3, i - 2, i - 1, i]);
+            }
+        }
+        P::Triangles => out.extend(0..count / 3 * 3),
+        P::TriangleStrip => {
+            // every other triangle has its first two vertices swapped to keep the winding order of the strip
+            for i in 2..count {
+                if i % 2 == 0 {
+                    out.extend([i - 2, i - 1, i]);
+                } else {
+                    out.extend([i - 1, i - 2, i]);
+                }
+            }
+        }
+        P::TriangleFan => {
+            for i in 2..count {
+                out.extend([0, i - 1, i]);
+            }
+        }
+        P::TrianglesAdjacency => out.extend(0..count / 6 * 6),
+        P::TriangleStripAdjacency => {
+            // see table 10.1 of the GL 4.6 core specification
+            let triangles = if count >= 6 { (count - 4) / 2 } else { 0 };
+            for i in 0..triangles {
+                let (a, b) = (2 * i, 2 * i + 2);
+                let (vertices, adjacent) = if triangles == 1 {
+                    ([0, 2, 4], [1, 5, 3])
+                } else if i == 0 {
+                    ([0, 2, 4], [1, 6, 3])
+                } else if i == triangles - 1 {
+                    if i % 2 == 1 {
+                        ([b, a, a + 4], [a - 2, a + 3, a + 5])
+                    } else {
+                        ([a, b, a + 4], [a - 2, a + 5, a + 3])
+                    }
+                } else if i % 2 == 1 {
+                    ([b, a, a + 4], [a - 2, a + 3, a + 6])
+                } else {
+                    ([a, b, a + 4], [a - 2, a + 6, a + 3])
+                };
+                for (v, adj) in vertices.into_iter().zip(adjacent) {
+                    out.extend([v, adj]);
+                }
+            }
+        }
+        P::Patches | P::Quads => unreachable!("not a geometry shader input primitive type"),
+    }
+    out
+}
+
+/// Identifies an interface variable across stages
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum InterfaceKey {
+    Location(u32),
+    Name(String),
+}
+
+#[derive(Debug)]
+pub(super) enum VariableKind {
+    User(InterfaceKey),
+    BuiltIn(BuiltIn),
+    /// A block of builtins like `gl_PerVertex`, with the builtin of each member
+    Block(Vec<Option<BuiltIn>>),
+}
+
+/// An input or output variable of a stage
+#[derive(Debug)]
+pub(super) struct StageVariable {
+    pub(super) var: u32,
+    /// Type of the variable, or of its elements if it was collected as arrayed and is per-vertex
+    pub(super) ty: u32,
+    pub(super) kind: VariableKind,
+    /// Whether the variable is per-patch rather than per-vertex, which only happens in tessellation stages
+    pub(super) patch: bool,
+}
+
+/// A scalar component of an interface variable, and where it is stored in a record
+#[derive(Debug)]
+pub(super) struct Leaf {
+    /// Access chain indices of the component in the variable
+    pub(super) path: Vec<u32>,
+    pub(super) word: u32,
+    pub(super) ty: u32,
+}
+
+impl StageVariable {
+    /// The input or output variables of a module. Per-vertex inputs of geometry and tessellation stages, and per-vertex
+    /// outputs of tessellation control stages are `arrayed`
+    pub(super) fn collect(module: &SpirvModule, storage: StorageClass, arrayed: bool) -> Vec<Self> {
+        let mut vars = Vec::new();
+        for inst in &module.instructions {
+            match inst.op() {
+                Some(Op::Function) => break,
+                Some(Op::Variable) if inst.operands[2] == storage as u32 => {}
+                _ => continue,
+            }
+            let var = inst.operands[1];
+            let Some(&Type::Pointer(_, mut ty)) = module.types.get(&inst.operands[0]) else {
+                continue;
+            };
+            let builtin = module
+                .decoration(var, Decoration::BuiltIn)
+                .and_then(|b| BuiltIn::from_u32(b[0]));
+            let patch = module.decoration(var, Decoration::Patch).is_some()
+                || matches!(
+                    builtin,
+                    Some(BuiltIn::TessLevelOuter | BuiltIn::TessLevelInner)
+                );
+            if let (true, false, Some(&Type::Array(element, _))) =
+                (arrayed, patch, module.types.get(&ty))
+            {
+                ty = element;
+            }
+            let kind = if let Some(builtin) = module.decoration(var, Decoration::BuiltIn) {
+                let Some(builtin) = BuiltIn::from_u32(builtin[0]) else {
+                    continue;
+                };
+                VariableKind::BuiltIn(builtin)
+            } else if let Some(Type::Struct(members)) = module.types.get(&ty).filter(|_| {
+                module
+                    .member_decoration(ty, 0, Decoration::BuiltIn)
+                    .is_some()
+            }) {
+                VariableKind::Block(
+                    (0..)
+                        .zip(members)
+                        .map(|(m, _)| {
+                            module
+                                .member_decoration(ty, m, Decoration::BuiltIn)
+                                .and_then(|b| BuiltIn::from_u32(b[0]))
+                        })
+                        .collect(),
+                )
+            } else {
+                // blocks are matched by their block name, which is the name of their type
+                let name = match module.types.get(&ty) {
+                    Some(Type::Struct(_)) => module.names.get(&ty),
+                    _ => module.names.get(&var),
+                };
+                VariableKind::User(module.location(var).map_or_else(
+                    || InterfaceKey::Name(name.cloned().unwrap_or_default()),
+                    InterfaceKey::Location,
+                ))
+            };
+            vars.push(Self {
+                var,
+                ty,
+                kind,
+                patch,
+            });
+        }
+        vars
+    }
+    /// Components of this variable that are stored in records laid out by `layout`
+    pub(super) fn leaves(
+        &self,
+        module: &SpirvModule,
+        layout: &RecordLayout,
+    ) -> Result<Vec<Leaf>, Box<str>> {
+        let mut out = Vec::new();
+        match &self.kind {
+            VariableKind::User(key) => {
+                if let Some(slot) = layout.slot(key) {
+                    collect_leaves(
+                        module,
+                        self.ty,
+                        &mut Vec::new(),
+                        slot * SLOT_WORDS,
+                        &mut out,
+                    )?;
+                }
+            }
+            &VariableKind::BuiltIn(builtin) => {
+                if let Some(word) = builtin_word(builtin) {
+                    collect_leaves(module, self.ty, &mut Vec::new(), word, &mut out)?;
+                }
+            }
+            VariableKind::Block(builtins) => {
+                let Some(Type::Struct(members)) = module.types.get(&self.ty) else {
+                    unreachable!("builtin blocks are structs")
+                };
+                for ((member, &ty), builtin) in (0..).zip(members).zip(builtins) {
+                    if let Some(word) = builtin.and_then(builtin_word) {
+                        collect_leaves(module, ty, &mut vec![member], word, &mut out)?;
+                    }
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Number of record slots a value of the given type takes up
+fn slot_count(module: &SpirvModule, ty: u32) -> Result<u32, Box<str>> {
+    Ok(match module.types.get(&ty) {
+        Some(Type::Scalar(_, 32)) => 1,
+        Some(&Type::Vector(component, _)) => slot_count(module, component)?,
+        Some(&Type::Matrix(column, n)) => slot_count(module, column)? * n,
+        Some(&Type::Array(element, len)) => {
+            slot_count(module, element)? * module.constant_value(len)?
+        }
+        Some(Type::Struct(members)) => members
+            .iter()
+            .map(|&m| slot_count(module, m))
+            .sum::<Result<u32, _>>()?,
+        _ => return Err("unsupported type of an emulated stage interface variable".into()),
+    })
+}
+fn collect_leaves(
+    module: &SpirvModule,
+    ty: u32,
+    path: &mut Vec<u32>,
+    word: u32,
+    out: &mut Vec<Leaf>,
+) -> Result<(), Box<str>> {
+    let mut nested = |ty, index, word, out: &mut Vec<Leaf>| {
+        path.push(index);
+        let result = collect_leaves(module, ty, path, word, out);
+        path.pop();
+        result
+    };
+    match module.types.get(&ty) {
+        Some(Type::Scalar(_, 32)) => out.push(Leaf {
+            path: path.clone(),
+            word,
+            ty,
+        }),
+        Some(&Type::Vector(component, n)) => {
+            for i in 0..n {
+                nested(component, i, word + i, out)?;
+            }
+        }
+        Some(&Type::Matrix(column, n)) => {
+            for i in 0..n {
+                nested(column, i, word + i * SLOT_WORDS, out)?;
+            }
+        }
+        Some(&Type::Array(element, len)) => {
+            let stride = slot_count(module, element)? * SLOT_WORDS;
+            for i in 0..module.constant_value(len)? {
+                nested(element, i, word + i * stride, out)?;
+            }
+        }
+        Some(Type::Struct(members)) => {
+            let mut offset = word;
+            for (i, &member) in (0..).zip(members) {
+                nested(member, i, offset, out)?;
+                offset += slot_count(module, member)? * SLOT_WORDS;
+            }
+        }
+        _ => return Err("unsupported type of an emulated stage interface variable".into()),
+    }
+    Ok(())
+}
+
+/// Placement of the user-defined interface variables of a stage in records
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RecordLayout {
+    slots: Vec<(InterfaceKey, u32)>,
+    /// Size of a record in words
+    pub(crate) stride: u32,
+}
+impl RecordLayout {
+    /// Lay out the user-defined variables among `vars` after `builtin_slots` slots
+    pub(super) fn new(
+        module: &SpirvModule,
+        vars: &[StageVariable],
+        builtin_slots: u32,
+    ) -> Result<Self, Box<str>> {
+        let mut slots = Vec::new();
+        let mut next = builtin_slots;
+        for v in vars {
+            if let VariableKind::User(key) = &v.kind {
+                slots.push((key.clone(), next));
+                next += slot_count(module, v.ty)?;
+            }
+        }
+        Ok(Self {
+            slots,
+            stride: next * SLOT_WORDS,
+        })
+    }
+    fn slot(&self, key: &InterfaceKey) -> Option<u32> {
+        self.slots.iter().find(|(k, _)| k == key).map(|&(_, s)| s)
+    }
+}
+
+/// Execution modes of a geometry stage, and the layouts of the records it reads and writes
+#[derive(Debug, Clone)]
+pub struct GeometryLayout {
+    pub(crate) info: GeometryInfo,
+    pub(crate) inputs: RecordLayout,
+    pub(crate) outputs: RecordLayout,
+}
+impl GeometryLayout {
+    pub(crate) fn new(module: &SpirvModule) -> Result<Self, Box<str>> {
+        Ok(Self {
+            info: GeometryInfo::parse(module)?,
+            inputs: RecordLayout::new(
+                module,
+                &StageVariable::collect(module, StorageClass::Input, true),
+                BUILTIN_SLOTS,
+            )?,
+            outputs: RecordLayout::new(
+                module,
+                &StageVariable::collect(module, StorageClass::Output, false),
+                BUILTIN_SLOTS,
+            )?,
+        })
+    }
+    /// Initial contents of the input and output buffers of a draw, and their sizes in words
+    pub(crate) fn buffers(&self, draw: &PrepassDraw) -> GeometryBuffers {
+        let (input, input_len) = input_buffer(draw, &self.inputs);
+
+        // the upper bound of the number of emitted vertices, each of which completes at most one output primitive
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "draws have less than u32::MAX vertices"
+        )]
+        let primitives = draw.primitives.len() as u32 / self.info.input_vertices();
+        let capacity =
+            primitives * draw.instance_count * self.info.invocations * self.info.max_vertices;
+        let records_start = OUT_INDICES_START + capacity * self.info.output_vertices();
+        let mut output = [0; OUT_INDICES_START as usize];
+        // instance count of the indirect draw
+        output[1] = 1;
+        output[OUT_VERTEX_CAPACITY as usize] = capacity;
+        output[OUT_RECORDS_START as usize] = records_start;
+        GeometryBuffers {
+            input,
+            input_len,
+            output_len: (records_start + capacity * self.outputs.stride) as usize,
+            output,
+            dispatch: primitives * draw.instance_count * self.info.invocations,
+        }
+    }
+}
+
+/// Start of the input buffer of a pre-pass up to the records written by the vertex stage, and its size in words
+pub(super) fn input_buffer(draw: &PrepassDraw, records: &RecordLayout) -> (Vec<u32>, usize) {
+    let mut input = vec![0; IN_PRIMITIVES_START as usize];
+    input[IN_VERTEX_BASE as usize] = draw.vertex_base;
+    input[IN_BASE_INSTANCE as usize] = draw.base_instance;
+    input[IN_VERTEX_SPAN as usize] = draw.vertex_span;
+    input.extend_from_slice(&draw.primitives);
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "draws have less than u32::MAX vertices"
+    )]
+    let records_start = input.len() as u32;
+    input[IN_RECORDS_START as usize] = records_start;
+    let len = input.len() + (draw.vertex_span * draw.instance_count * records.stride) as usize;
+    (input, len)
+}
+
+/// Contents of the buffers of a draw through an emulated geometry stage, as built by [`GeometryLayout::buffers`]
+#[derive(Debug)]
+pub(crate) struct GeometryBuffers {
+    /// Start of the input buffer, up to the vertex records
+    pub(crate) input: Vec<u32>,
+    pub(crate) input_len: usize,
+    /// Header of the output buffer, the rest of it is written by the geometry kernel
+    pub(crate) output: [u32; OUT_INDICES_START as usize],
+    pub(crate) output_len: usize,
+    /// Number of geometry kernel threads to dispatch
+    pub(crate) dispatch: u32,
+}
+impl GeometryBuffers {
+    /// Byte offset of the indices of the assembled primitives in the output buffer
+    pub(crate) const INDEX_OFFSET: usize = OUT_INDICES_START as usize * 4;
+}
+
+/// Generates the stages that emulate geometry and tessellation stages from the SPIR-V module of an existing stage
+pub(super) struct Generator {
+    pub(super) module: SpirvModule,
+    /// Function of the entry point of the module
+    pub(super) main: u32,
+    /// void type, and the type of functions that return void without taking parameters
+    pub(super) void_function: (u32, u32),
+    /// Decorations to insert in front of the first type declaration
+    new_decorations: Vec<Instruction>,
+    /// Types, constants and variables to insert in front of the first function
+    new_globals: Vec<Instruction>,
+    /// Debug names to insert after the last existing one
+    new_names: Vec<Instruction>,
+    /// Functions to append to the module
+    new_functions: Vec<Instruction>,
+    /// Body of the function being generated
+    body: Vec<Instruction>,
+    /// uint constants, by value
+    constants: HashMap<u32, u32>,
+    /// Pointer type of the block of words the buffers of the pre-pass are declared as
+    block_pointer: Option<u32>,
+    /// Variables of the buffers of the pre-pass, by binding
+    buffers: HashMap<u32, u32>,
+}
+impl Generator {
+    pub(super) fn new(spirv: &[u32]) -> Result<Self, Box<str>> {
+        let module = SpirvModule::parse(spirv)?;
+        let main = module
+            .instructions
+            .iter()
+            .find(|i| i.op() == Some(Op::EntryPoint))
+            .ok_or("module has no entry point")?
+            .operands[1];
+        let function = module
+            .instructions
+            .iter()
+            .find(|i| i.op() == Some(Op::Function) && i.operands[1] == main)
+            .ok_or("entry point function is missing")?;
+        let void_function = (function.operands[0], function.operands[3]);
+        Ok(Self {
+            module,
+            main,
+            void_function,
+            new_decorations: Vec::new(),
+            new_globals: Vec::new(),
+            new_names: Vec::new(),
+            new_functions: Vec::new(),
+            body: Vec::new(),
+            constants: HashMap::new(),
+            block_pointer: None,
+            buffers: HashMap::new(),
+        })
+    }
+    pub(super) fn find_or_declare(&mut self, ty: Type) -> u32 {
+        if let Some((&id, _)) = self.module.types.iter().find(|(_, t)| **t == ty) {
+            return id;
+        }
+        let id = self.module.new_id();
+        self.new_globals.push(match ty {
+            Type::Bool => Instruction::new(Op::TypeBool, vec![id]),
+            Type::Scalar(UniformKind::Float, width) => {
+                Instruction::new(Op::TypeFloat, vec![id, width])
+            }
+            Type::Scalar(kind, width) => Instruction::new(
+                Op::TypeInt,
+                vec![id, width, u32::from(kind == UniformKind::Int)],
+            ),
+            Type::Vector(component, n) => Instruction::new(Op::TypeVector, vec![id, component, n]),
+            Type::Pointer(storage, pointee) => {
+                Instruction::new(Op::TypePointer, vec![id, storage, pointee])
+            }
+            _ => {
+                unreachable!("only scalar, vector and pointer types are declared by the generator")
+            }
+        });
+        self.module.types.insert(id, ty);
+        id
+    }
+    pub(super) fn uint_type(&mut self) -> u32 {
+        self.find_or_declare(Type::Scalar(UniformKind::UnsignedInt, 32))
+    }
+    pub(super) fn pointer_type(&mut self, storage: StorageClass, pointee: u32) -> u32 {
+        self.find_or_declare(Type::Pointer(storage as u32, pointee))
+    }
+    pub(super) fn uint(&mut self, value: u32) -> u32 {
+        if let Some(&id) = self.constants.get(&value) {
+            return id;
+        }
+        let ty = self.uint_type();
+        let id = self.module.new_id();
+        self.new_globals
+            .push(Instruction::new(Op::Constant, vec![ty, id, value]));
+        self.constants.insert(value, id);
+        id
+    }
+    pub(super) fn float(&mut self, value: f32) -> u32 {
+        let ty = self.find_or_declare(Type::Scalar(UniformKind::Float, 32));
+        let id = self.module.new_id();
+        self.new_globals.push(Instruction::new(
+            Op::Constant,
+            vec![ty, id, value.to_bits()],
+        ));
+        id
+    }
+    /// Boolean specialization constant with the given `SpecId`, which is false unless the stage is specialized
+    pub(super) fn spec_flag(&mut self, spec_id: u32, name: &str) -> u32 {
+        let ty = self.find_or_declare(Type::Bool);
+        let id = self.module.new_id();
+        self.new_globals
+            .push(Instruction::new(Op::SpecConstantFalse, vec![ty, id]));
+        self.decorate(id, Decoration::SpecId, &[spec_id]);
+        self.name(id, name);
+        id
+    }
+    /// Float specialization constant with the given `SpecId`, which is `default` unless the stage is specialized
+    pub(super) fn spec_float(&mut self, spec_id: u32, default: f32, name: &str) -> u32 {
+        let ty = self.find_or_declare(Type::Scalar(UniformKind::Float, 32));
+        let id = self.module.new_id();
+        self.new_globals.push(Instruction::new(
+            Op::SpecConstant,
+            vec![ty, id, default.to_bits()],
+        ));
+        self.decorate(id, Decoration::SpecId, &[spec_id]);
+        self.name(id, name);
+        id
+    }
+    pub(super) fn decorate(&mut self, id: u32, decoration: Decoration, operands: &[u32]) {
+        let mut all = vec![id, decoration as u32];
+        all.extend_from_slice(operands);
+        self.new_decorations
+            .push(Instruction::new(Op::Decorate, all));
+    }
+    pub(super) fn name(&mut self, id: u32, name: &str) {
+        let mut operands = vec![id];
+        operands.extend(string_words(name));
+        self.new_names.push(Instruction::new(Op::Name, operands));
+    }
+    pub(super) fn variable(&mut self, storage: StorageClass, ty: u32, name: &str) -> u32 {
+        let pointer = self.pointer_type(storage, ty);
+        let id = self.module.new_id();
+        self.new_globals.push(Instruction::new(
+            Op::Variable,
+            vec![pointer, id, storage as u32],
+        ));
+        self.name(id, name);
+        id
+    }
+    /// The input variable decorated with a builtin, declaring it if the module doesn't already have one
+    pub(super) fn builtin_input(&mut self, builtin: BuiltIn, ty: u32, name: &str) -> u32 {
+        let existing = self.module.instructions.iter().find(|i| {
+            i.op() == Some(Op::Variable)
+                && i.operands[2] == StorageClass::Input as u32
+                && self
+                    .module
+                    .decoration(i.operands[1], Decoration::BuiltIn)
+                    .is_some_and(|b| b[0] == builtin as u32)
+        });
+        if let Some(var) = existing {
+            return var.operands[1];
+        }
+        let var = self.variable(StorageClass::Input, ty, name);
+        self.new_decorations.push(Instruction::new(
+            Op::Decorate,
+            vec![var, Decoration::BuiltIn as u32, builtin as u32],
+        ));
+        var
+    }
+    /// Variable of the buffer of the pre-pass with the given binding
+    pub(super) fn buffer(&mut self, binding: u32) -> u32 {
+        if let Some(&var) = self.buffers.get(&binding) {
+            return var;
+        }
+        let pointer = if let Some(pointer) = self.block_pointer {
+            pointer
+        } else {
+            let uint = self.uint_type();
+            let (array, block, pointer) = (
+                self.module.new_id(),
+                self.module.new_id(),
+                self.module.new_id(),
+            );
+            self.new_decorations.extend([
+                Instruction::new(Op::Decorate, vec![array, Decoration::ArrayStride as u32, 4]),
+                Instruction::new(Op::Decorate, vec![block, Decoration::BufferBlock as u32]),
+                Instruction::new(
+                    Op::MemberDecorate,
+                    vec![block, 0, Decoration::Offset as u32, 0],
+                ),
+            ]);
+            self.new_globals.extend([
+                Instruction::new(Op::TypeRuntimeArray, vec![array, uint]),
+                Instruction::new(Op::TypeStruct, vec![block, array]),
+                Instruction::new(
+                    Op::TypePointer,
+                    vec![pointer, StorageClass::Uniform as u32, block],
+                ),
+            ]);
+            self.name(block, "oxidegl_words");
+            self.block_pointer = Some(pointer);
+            pointer
+        };
+        let var = self.module.new_id();
+        self.new_globals.push(Instruction::new(
+            Op::Variable,
+            vec![pointer, var, StorageClass::Uniform as u32],
+        ));
+        self.new_decorations.extend([
+            Instruction::new(
+                Op::Decorate,
+                vec![
+                    var,
+                    Decoration::DescriptorSet as u32,
+                    DISCRETE_DESCRIPTOR_SET,
+                ],
+            ),
+            Instruction::new(Op::Decorate, vec![var, Decoration::Binding as u32, binding]),
+        ]);
+        self.name(var, &format!("oxidegl_buffer_{binding}"));
+        self.buffers.insert(binding, var);
+        var
+    }
+
+    /// Append an instruction with a result to the function being generated, returning the result id
+    pub(super) fn op(&mut self, op: Op, ty: u32, operands: &[u32]) -> u32 {
+        let id = self.module.new_id();
+        let mut all = vec![ty, id];
+        all.extend_from_slice(operands);
+        self.body.push(Instruction::new(op, all));
+        id
+    }
+    pub(super) fn push(&mut self, op: Op, operands: Vec<u32>) {
+        self.body.push(Instruction::new(op, operands));
+    }
+    pub(super) fn uint_op(&mut self, op: Op, a: u32, b: u32) -> u32 {
+        let uint = self.uint_type();
+        self.op(op, uint, &[a, b])
+    }
+    /// Pointer to a word of a buffer of the pre-pass
+    pub(super) fn word_pointer(&mut self, binding: u32, index: u32) -> u32 {
+        let var = self.buffer(binding);
+        let uint = self.uint_type();
+        let pointer = self.pointer_type(StorageClass::Uniform, uint);
+        let zero = self.uint(0);
+        self.op(Op::AccessChain, pointer, &[var, zero, index])
+    }
+    pub(super) fn load_word(&mut self, binding: u32, index: u32) -> u32 {
+        let pointer = self.word_pointer(binding, index);
+        let uint = self.uint_type();
+        self.op(Op::Load, uint, &[pointer])
+    }
+    pub(super) fn store_word(&mut self, binding: u32, index: u32, value: u32) {
+        let pointer = self.word_pointer(binding, index);
+        self.push(Op::Store, vec![pointer, value]);
+    }
+    pub(super) fn header(&mut self, binding: u32, word: u32) -> u32 {
+        let index = self.uint(word);
+        self.load_word(binding, index)
+    }
+    /// Atomically add to a header word, returning its previous value
+    pub(super) fn atomic_add(&mut self, binding: u32, word: u32, value: u32) -> u32 {
+        let index = self.uint(word);
+        let pointer = self.word_pointer(binding, index);
+        let scope = self.uint(Scope::Device as u32);
+        let semantics = self.uint(MemorySemantics::empty().bits());
+        let value = self.uint(value);
+        let uint = self.uint_type();
+        self.op(Op::AtomicIAdd, uint, &[pointer, scope, semantics, value])
+    }
+    /// Word offset of record `index`, from a header word holding the start of the records
+    pub(super) fn record(&mut self, binding: u32, start_word: u32, index: u32, stride: u32) -> u32 {
+        let start = self.header(binding, start_word);
+        let stride = self.uint(stride);
+        let offset = self.uint_op(Op::IMul, index, stride);
+        self.uint_op(Op::IAdd, start, offset)
+    }
+    /// Reinterpret a value as a uint, or the other way around
+    pub(super) fn bitcast(&mut self, value: u32, from: u32, to: u32) -> u32 {
+        if from == to {
+            value
+        } else {
+            self.op(Op::Bitcast, to, &[value])
+        }
+    }
+    /// Pointer to a component of a variable, or of element `prefix` of it. `prefix` holds the ids of the indices
+    pub(super) fn leaf_pointer(
+        &mut self,
+        var: u32,
+        storage: StorageClass,
+        prefix: &[u32],
+        leaf: &Leaf,
+    ) -> u32 {
+        if prefix.is_empty() && leaf.path.is_empty() {
+            return var;
+        }
+        let pointer = self.pointer_type(storage, leaf.ty);
+        let mut operands = vec![var];
+        operands.extend_from_slice(prefix);
+        for &index in &leaf.path {
+            operands.push(self.uint(index));
+        }
+        self.op(Op::AccessChain, pointer, &operands)
+    }
+    /// Store the components of a variable (or of element `prefix` of it) into the record starting at word `base` of a
+    /// buffer
+    pub(super) fn store_leaves(
+        &mut self,
+        binding: u32,
+        base: u32,
+        var: u32,
+        storage: StorageClass,
+        prefix: &[u32],
+        leaves: &[Leaf],
+    ) {
+        let uint = self.uint_type();
+        for leaf in leaves {
+            let pointer = self.leaf_pointer(var, storage, prefix, leaf);
+            let value = self.op(Op::Load, leaf.ty, &[pointer]);
+            let bits = self.bitcast(value, leaf.ty, uint);
+            let offset = self.uint(leaf.word);
+            let index = self.uint_op(Op::IAdd, base, offset);
+            self.store_word(binding, index, bits);
+        }
+    }
+    /// Load the components of a variable (or of element `prefix` of it) from the record starting at word `base` of a
+    /// buffer
+    pub(super) fn load_leaves(
+        &mut self,
+        binding: u32,
+        base: u32,
+        var: u32,
+        storage: StorageClass,
+        prefix: &[u32],
+        leaves: &[Leaf],
+    ) {
+        let uint = self.uint_type();
+        for leaf in leaves {
+            let offset = self.uint(leaf.word);
+            let index = self.uint_op(Op::IAdd, base, offset);
+            let bits = self.load_word(binding, index);
+            let value = self.bitcast(bits, uint, leaf.ty);
+            let pointer = self.leaf_pointer(var, storage, prefix, leaf);
+            self.push(Op::Store, vec![pointer, value]);
+        }
+    }
+    /// Generate a structured `if` around the code generated by `then`
+    pub(super) fn if_then(&mut self, condition: u32, then: impl FnOnce(&mut Self)) {
+        let (then_label, merge) = (self.module.new_id(), self.module.new_id());
+        self.push(Op::SelectionMerge, vec![merge, 0]);
+        self.push(Op::BranchConditional, vec![condition, then_label, merge]);
+        self.push(Op::Label, vec![then_label]);
+        then(self);
+        self.push(Op::Branch, vec![merge]);
+        self.push(Op::Label, vec![merge]);
+    }
+    /// Generate a structured loop that runs the code generated by `body` with each value from 0 up to the id `count`,
+    /// using the private variable `counter`
+    pub(super) fn for_range(
+        &mut self,
+        counter: u32,
+        count: u32,
+        body: impl FnOnce(&mut Self, u32),
+    ) {
+        let [header, condition, body_label, continue_label, merge] =
+            [(); 5].map(|()| self.module.new_id());
+        let bool_type = self.find_or_declare(Type::Bool);
+        let uint = self.uint_type();
+        let zero = self.uint(0);
+        self.push(Op::Store, vec![counter, zero]);
+        self.push(Op::Branch, vec![header]);
+        self.push(Op::Label, vec![header]);
+        self.push(Op::LoopMerge, vec![merge, continue_label, 0]);
+        self.push(Op::Branch, vec![condition]);
+        self.push(Op::Label, vec![condition]);
+        let index = self.op(Op::Load, uint, &[counter]);
+        let in_range = self.op(Op::ULessThan, bool_type, &[index, count]);
+        self.push(Op::BranchConditional, vec![in_range, body_label, merge]);
+        self.push(Op::Label, vec![body_label]);
+        body(self, index);
+        self.push(Op::Branch, vec![continue_label]);
+        self.push(Op::Label, vec![continue_label]);
+        let one = self.uint(1);
+        let next = self.uint_op(Op::IAdd, index, one);
+        self.push(Op::Store, vec![counter, next]);
+        self.push(Op::Branch, vec![header]);
+        self.push(Op::Label, vec![merge]);
+    }
+    /// Start generating a function that returns nothing and takes no parameters
+    pub(super) fn begin_function(&mut self, name: &str) -> u32 {
+        let (void, function_type) = self.void_function;
+        let id = self.module.new_id();
+        let label = self.module.new_id();
+        self.push(Op::Function, vec![void, id, 0, function_type]);
+        self.push(Op::Label, vec![label]);
+        self.name(id, name);
+        id
+    }
+    pub(super) fn end_function(&mut self) {
+        self.push(Op::Return, Vec::new());
+        self.push(Op::FunctionEnd, Vec::new());
+        self.new_functions.append(&mut self.body);
+    }
+    pub(super) fn call(&mut self, function: u32) {
+        let void = self.void_function.0;
+        self.op(Op::FunctionCall, void, &[function]);
+    }
+
+    /// Turn all variables of the given storage classes into private variables, stripping the decorations that only
+    /// apply to interface variables. Returns the converted variables
+    pub(super) fn privatize(&mut self, classes: &[StorageClass]) -> Vec<u32> {
+        self.retarget(classes, StorageClass::Private)
+    }
+    /// Move all variables of the given storage classes to the `target` storage class, like [`Generator::privatize`]
+    pub(super) fn retarget(&mut self, classes: &[StorageClass], target: StorageClass) -> Vec<u32> {
+        let classes = classes.iter().map(|&c| c as u32).collect::<Vec<_>>();
+        let target = target as u32;
+        let mut vars = Vec::new();
+        let mut blocks = Vec::new();
+        for inst in &mut self.module.instructions {
+            let op = inst.op();
+            let o = &mut inst.operands;
+            match op {
+                Some(Op::TypePointer) if classes.contains(&o[1]) => {
+                    o[1] = target;
+                    self.module.types.insert(o[0], Type::Pointer(target, o[2]));
+                }
+                Some(Op::Variable) if classes.contains(&o[2]) => {
+                    o[2] = target;
+                    vars.push(o[1]);
+                    if let Some(&Type::Pointer(_, mut pointee)) = self.module.types.get(&o[0]) {
+                        if let Some(&Type::Array(element, _)) = self.module.types.get(&pointee) {
+                            pointee = element;
+                        }
+                        blocks.push(pointee);
+                    }
+                }
+                Some(Op::Function) => break,
+                _ => {}
+            }
+        }
+        self.module.instructions.retain(|inst| {
+            let o = &inst.operands;
+            match inst.op() {
+                Some(Op::Decorate) => {
+                    !vars.contains(&o[0])
+                        && !(blocks.contains(&o[0]) && o[1] == Decoration::Block as u32)
+                }
+                Some(Op::MemberDecorate) => {
+                    !(blocks.contains(&o[0]) && o[2] == Decoration::BuiltIn as u32)
+                }
+                _ => true,
+            }
+        });
+        vars
+    }
+    /// Turn a single variable into a private variable, like [`Generator::privatize`]. The variable is redeclared
+    /// with the generated globals, and pointers derived from it are retyped to point into the private storage class
+    pub(super) fn privatize_variable(&mut self, var: u32) {
+        let private = StorageClass::Private as u32;
+        let mut derived = vec![var];
+        let mut retyped = Vec::new();
+        for (idx, inst) in self.module.instructions.iter().enumerate() {
+            let o = &inst.operands;
+            match inst.op() {
+                Some(Op::AccessChain | Op::InBoundsAccessChain) if derived.contains(&o[2]) => {
+                    derived.push(o[1]);
+                }
+                Some(Op::Variable) if o[1] == var => {}
+                _ => continue,
+            }
+            if let Some(&Type::Pointer(_, pointee)) = self.module.types.get(&o[0]) {
+                retyped.push((idx, pointee));
+            }
+        }
+        for (idx, pointee) in retyped {
+            let pointer = self.find_or_declare(Type::Pointer(private, pointee));
+            self.module.instructions[idx].operands[0] = pointer;
+        }
+        let Some(idx) = self
+            .module
+            .instructions
+            .iter()
+            .position(|i| i.op() == Some(Op::Variable) && i.operands[1] == var)
+        else {
+            return;
+        };
+        // the private pointer type may be a generated one, which is declared after the existing variables
+        let mut inst = self.module.instructions.remove(idx);
+        inst.operands[2] = private;
+        self.new_globals.push(inst);
+        self.module
+            .instructions
+            .retain(|i| !(i.op() == Some(Op::Decorate) && i.operands[0] == var));
+    }
+    /// Interface variables of the entry point
+    pub(super) fn interface(&self) -> Vec<u32> {
+        let entry = self
+            .module
+            .instructions
+            .iter()
+            .find(|i| i.op() == Some(Op::EntryPoint))
+            .expect("checked in new");
+        let (_, name_len) = spirv_string(&entry.operands[2..]);
+        entry.operands[2 + name_len..].to_vec()
+    }
+    /// Stream each vertex emission instruction emits to, and whether it ends a primitive instead
+    pub(super) fn emission(&self, inst: &Instruction) -> Result<Option<(u32, bool)>, Box<str>> {
+        let stream = |id| {
+            self.module
+                .constant_value(id)
+                .map_err(|_| Box::from("vertex stream is not a constant"))
+        };
+        Ok(Some(match inst.op() {
+            Some(Op::EmitVertex) => (0, false),
+            Some(Op::EndPrimitive) => (0, true),
+            Some(Op::EmitStreamVertex) => (stream(inst.operands[0])?, false),
+            Some(Op::EndStreamPrimitive) => (stream(inst.operands[0])?, true),
+            _ => return Ok(None),
+        }))
+    }
+    /// Assemble the generated module, with `entry` as the function of its entry point
+    pub(super) fn finish(
+        mut self,
+        model: ExecutionModel,
+        entry: u32,
+        interface: &[u32],
+        modes: &[&[u32]],
+    ) -> Box<[u32]> {
+        let first_type = self.module.instructions.iter().position(|i| {
+            (Op::TypeVoid as u32..=Op::TypeForwardPointer as u32).contains(&i.opcode)
+        });
+        let first_function = self
+            .module
+            .instructions
+            .iter()
+            .position(|i| i.op() == Some(Op::Function));
+        let last_name = self
+            .module
+            .instructions
+            .iter()
+            .rposition(|i| matches!(i.op(), Some(Op::Name | Op::MemberName)));
+        let instructions = std::mem::take(&mut self.module.instructions);
+        let mut out = Vec::with_capacity(instructions.len());
+        for (idx, mut inst) in instructions.into_iter().enumerate() {
+            if last_name.is_some_and(|n| n + 1 == idx) {
+                out.append(&mut self.new_names);
+            }
+            if first_type == Some(idx) {
+                out.append(&mut self.new_decorations);
+            }
+            if first_function == Some(idx) {
+                out.append(&mut self.new_globals);
+            }
+            match inst.op() {
+                Some(Op::EntryPoint) => {
+                    let (_, name_len) = spirv_string(&inst.operands[2..]);
+                    inst.operands.truncate(2 + name_len);
+                    inst.operands[0] = model as u32;
+                    inst.operands[1] = entry;
+                    inst.operands.extend_from_slice(interface);
+                    out.push(inst);
+                    for mode in modes {
+                        let mut operands = vec![entry];
+                        operands.extend_from_slice(mode);
+                        out.push(Instruction::new(Op::ExecutionMode, operands));
+                    }
+                    continue;
+                }
+                // the execution modes of the original stage don't apply to the generated one
+                Some(Op::ExecutionMode) => continue,
+                _ => {}
+            }
+            out.push(inst);
+        }
+        out.append(&mut self.new_functions);
+        self.module.words(&out)
+    }
+}
+
+/// Generate the vertex stage that runs as the first kernel of the pre-pass, writing the outputs of `vertex_spirv` the
+/// next stage reads into the records of the input buffer, laid out by `records`
+pub(crate) fn vertex_capture(
+    vertex_spirv: &[u32],
+    records: &RecordLayout,
+) -> Result<Box<[u32]>, Box<str>> {
+    let mut g = Generator::new(vertex_spirv)?;
+    let outputs = StageVariable::collect(&g.module, StorageClass::Output, false)
+        .into_iter()
+        .map(|v| Ok((v.var, v.leaves(&g.module, records)?)))
+        .collect::<Result<Vec<_>, Box<str>>>()?;
+    let mut interface = g.interface();
+    let privatized = g.privatize(&[StorageClass::Output]);
+    interface.retain(|v| !privatized.contains(v));
+
+    let int = g.find_or_declare(Type::Scalar(UniformKind::Int, 32));
+    let uint = g.uint_type();
+    let vertex_index = g.builtin_input(BuiltIn::VertexIndex, int, "gl_VertexIndex");
+    let instance_index = g.builtin_input(BuiltIn::InstanceIndex, int, "gl_InstanceIndex");
+    for var in [vertex_index, instance_index] {
+        if !interface.contains(&var) {
+            interface.push(var);
+        }
+    }
+
+    let main = g.main;
+    let entry = g.begin_function("oxidegl_vertex_capture");
+    g.call(main);
+    let vertex = g.op(Op::Load, int, &[vertex_index]);
+    let vertex = g.bitcast(vertex, int, uint);
+    let instance = g.op(Op::Load, int, &[instance_index]);
+    let instance = g.bitcast(instance, int, uint);
+    let vertex_base = g.header(INPUT_BINDING, IN_VERTEX_BASE);
+    let base_instance = g.header(INPUT_BINDING, IN_BASE_INSTANCE);
+    let span = g.header(INPUT_BINDING, IN_VERTEX_SPAN);
+    let vertex = g.uint_op(Op::ISub, vertex, vertex_base);
+    let instance = g.uint_op(Op::ISub, instance, base_instance);
+    let instance = g.uint_op(Op::IMul, instance, span);
+    let index = g.uint_op(Op::IAdd, vertex, instance);
+    let base = g.record(INPUT_BINDING, IN_RECORDS_START, index, records.stride);
+    for (var, leaves) in &outputs {
+        g.store_leaves(
+            INPUT_BINDING,
+            base,
+            *var,
+            StorageClass::Private,
+            &[],
+            leaves,
+        );
+    }
+    g.end_function();
+    Ok(g.finish(ExecutionModel::Vertex, entry, &interface, &[]))
+}
+
+/// Generate the compute kernel that runs a geometry stage for one input primitive, instance and invocation per thread
+pub(crate) fn geometry_kernel(spirv: &[u32]) -> Result<Box<[u32]>, Box<str>> {
+    let mut g = Generator::new(spirv)?;
+    let layout = GeometryLayout::new(&g.module)?;
+    let info = layout.info;
+    let inputs = StageVariable::collect(&g.module, StorageClass::Input, true);
+    let input_leaves = inputs
+        .iter()
+        .map(|v| Ok((v.var, v.leaves(&g.module, &layout.inputs)?)))
+        .collect::<Result<Vec<_>, Box<str>>>()?;
+    let builtin_input = |builtin| {
+        inputs
+            .iter()
+            .find(|v| matches!(v.kind, VariableKind::BuiltIn(b) if b == builtin))
+            .map(|v| (v.var, v.ty))
+    };
+    let (primitive_id, invocation_id) = (
+        builtin_input(BuiltIn::PrimitiveId),
+        builtin_input(BuiltIn::InvocationId),
+    );
+    let outputs = StageVariable::collect(&g.module, StorageClass::Output, false)
+        .into_iter()
+        .map(|v| Ok((v.var, v.leaves(&g.module, &layout.outputs)?)))
+        .collect::<Result<Vec<_>, Box<str>>>()?;
+    let mut streams = Vec::new();
+    for inst in &g.module.instructions {
+        if let Some((stream, _)) = g.emission(inst)? {
+            if stream >= MAX_VERTEX_STREAMS {
+                return Err(format!(
+                    "vertex stream {stream} exceeds GL_MAX_VERTEX_STREAMS ({MAX_VERTEX_STREAMS})"
+                )
+                .into());
+            }
+            if !streams.contains(&stream) {
+                streams.push(stream);
+            }
+        }
+    }
+    g.privatize(&[StorageClass::Input, StorageClass::Output]);
+
+    let uint = g.uint_type();
+    let uvec3 = g.find_or_declare(Type::Vector(uint, 3));
+    let global_id = g.builtin_input(BuiltIn::GlobalInvocationId, uvec3, "gl_GlobalInvocationID");
+    // length of the current output strip, and its last two vertices
+    let strip = [
+        g.variable(StorageClass::Private, uint, "oxidegl_strip_length"),
+        g.variable(StorageClass::Private, uint, "oxidegl_strip_vertex_0"),
+        g.variable(StorageClass::Private, uint, "oxidegl_strip_vertex_1"),
+    ];
+    let mut functions = HashMap::new();
+    for &stream in &streams {
+        let emit = emit_function(&mut g, stream, info, &outputs, strip, layout.outputs.stride);
+        let end = g.begin_function(&format!("oxidegl_end_primitive_{stream}"));
+        if stream == 0 {
+            let zero = g.uint(0);
+            g.push(Op::Store, vec![strip[0], zero]);
+        }
+        g.end_function();
+        functions.insert(stream, (emit, end));
+    }
+    let void = g.void_function.0;
+    for idx in 0..g.module.instructions.len() {
+        let Some((stream, end)) = g.emission(&g.module.instructions[idx])? else {
+            continue;
+        };
+        let (emit_function, end_function) = functions[&stream];
+        let id = g.module.new_id();
+        g.module.instructions[idx] = Instruction::new(
+            Op::FunctionCall,
+            vec![void, id, if end { end_function } else { emit_function }],
+        );
+    }
+
+    let main = g.main;
+    let entry = g.begin_function("oxidegl_geometry_kernel");
+    let id = g.op(Op::Load, uvec3, &[global_id]);
+    let [primitive, instance, invocation] =
+        [0, 1, 2].map(|i| g.op(Op::CompositeExtract, uint, &[id, i]));
+    let span = g.header(INPUT_BINDING, IN_VERTEX_SPAN);
+    let instance_offset = g.uint_op(Op::IMul, instance, span);
+    let vertex_count = g.uint(info.input_vertices());
+    let first = g.uint_op(Op::IMul, primitive, vertex_count);
+    let primitives_start = g.uint(IN_PRIMITIVES_START);
+    let first = g.uint_op(Op::IAdd, first, primitives_start);
+    for k in 0..info.input_vertices() {
+        let k_constant = g.uint(k);
+        let index = g.uint_op(Op::IAdd, first, k_constant);
+        let vertex = g.load_word(INPUT_BINDING, index);
+        let index = g.uint_op(Op::IAdd, vertex, instance_offset);
+        let base = g.record(INPUT_BINDING, IN_RECORDS_START, index, layout.inputs.stride);
+        for (var, leaves) in &input_leaves {
+            g.load_leaves(
+                INPUT_BINDING,
+                base,
+                *var,
+                StorageClass::Private,
+                &[k_constant],
+                leaves,
+            );
+        }
+    }
+    for (builtin, value) in [(primitive_id, primitive), (invocation_id, invocation)] {
+        if let Some((var, ty)) = builtin {
+            let value = g.bitcast(value, uint, ty);
+            g.push(Op::Store, vec![var, value]);
+        }
+    }
+    let zero = g.uint(0);
+    g.push(Op::Store, vec![strip[0], zero]);
+    g.call(main);
+    g.end_function();
+    Ok(g.finish(
+        ExecutionModel::GLCompute,
+        entry,
+        &[global_id],
+        &[&[ExecutionMode::LocalSize as u32, 1, 1, 1]],
+    ))
+}
+
+/// Generate the function that replaces `EmitStreamVertex(stream)`. It appends a record holding the current outputs
+/// to the output buffer, and assembles the primitive completed by a vertex emitted to stream 0 (if any)
+fn emit_function(
+    g: &mut Generator,
+    stream: u32,
+    info: GeometryInfo,
+    outputs: &[(u32, Vec<Leaf>)],
+    [length, previous_0, previous_1]: [u32; 3],
+    stride: u32,
+) -> u32 {
+    let function = g.begin_function(&format!("oxidegl_emit_vertex_{stream}"));
+    let bool_type = g.find_or_declare(Type::Bool);
+    let uint = g.uint_type();
+    let vertex = g.atomic_add(OUTPUT_BINDING, OUT_VERTEX_COUNT, 1);
+    let capacity = g.header(OUTPUT_BINDING, OUT_VERTEX_CAPACITY);
+    let fits = g.op(Op::ULessThan, bool_type, &[vertex, capacity]);
+    g.if_then(fits, |g| {
+        let base = g.record(OUTPUT_BINDING, OUT_RECORDS_START, vertex, stride);
+        for (var, leaves) in outputs {
+            g.store_leaves(
+                OUTPUT_BINDING,
+                base,
+                *var,
+                StorageClass::Private,
+                &[],
+                leaves,
+            );
+        }
+        let stream_word = g.uint(STREAM_WORD);
+        let index = g.uint_op(Op::IAdd, base, stream_word);
+        let stream_constant = g.uint(stream);
+        g.store_word(OUTPUT_BINDING, index, stream_constant);
+        // only the primitives of stream 0 are rasterized
+        if stream != 0 {
+            return;
+        }
+        let store_primitive = |g: &mut Generator, vertices: &[u32]| {
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "primitives have at most 3 vertices"
+            )]
+            let first = g.atomic_add(OUTPUT_BINDING, OUT_INDEX_COUNT, vertices.len() as u32);
+            for (i, &v) in (OUT_INDICES_START..).zip(vertices) {
+                let offset = g.uint(i);
+                let index = g.uint_op(Op::IAdd, first, offset);
+                g.store_word(OUTPUT_BINDING, index, v);
+            }
+        };
+        if info.output == PrimitiveType::Points {
+            store_primitive(g, &[vertex]);
+            return;
+        }
+        let strip_length = g.op(Op::Load, uint, &[length]);
+        let v0 = g.op(Op::Load, uint, &[previous_0]);
+        let v1 = g.op(Op::Load, uint, &[previous_1]);
+        let needed = g.uint(info.output_vertices() - 1);
+        let complete = g.op(Op::UGreaterThanEqual, bool_type, &[strip_length, needed]);
+        g.if_then(complete, |g| {
+            if info.output == PrimitiveType::LineStrip {
+                store_primitive(g, &[v1, vertex]);
+            } else {
+                // every other triangle has its first two vertices swapped to keep the winding order of the strip
+                let one = g.uint(1);
+                let zero = g.uint(0);
+                let parity = g.uint_op(Op::BitwiseAnd, strip_length, one);
+                let odd = g.op(Op::INotEqual, bool_type, &[parity, zero]);
+                let first = g.op(Op::Select, uint, &[odd, v1, v0]);
+                let second = g.op(Op::Select, uint, &[odd, v0, v1]);
+                store_primitive(g, &[first, second, vertex]);
+            }
+        });
+        let one = g.uint(1);
+        let strip_length = g.uint_op(Op::IAdd, strip_length, one);
+        g.push(Op::Store, vec![length, strip_length]);
+        g.push(Op::Store, vec![previous_0, v1]);
+        g.push(Op::Store, vec![previous_1, vertex]);
+    });
+    g.end_function();
+    function
+}
+
+/// Generate the vertex stage of the draw of the assembled primitives, which loads the outputs of the geometry stage
+/// from the records of the output buffer
+pub(crate) fn passthrough(spirv: &[u32], layout: &GeometryLayout) -> Result<Box<[u32]>, Box<str>> {
+    let mut g = Generator::new(spirv)?;
+    let mut outputs = Vec::new();
+    for v in StageVariable::collect(&g.module, StorageClass::Output, false) {
+        let mut leaves = v.leaves(&g.module, &layout.outputs)?;
+        // the point size is undefined unless the geometry stage emits points
+        if layout.info.output != PrimitiveType::Points {
+            leaves.retain(|l| l.word != POINT_SIZE_WORD);
+        }
+        if !leaves.is_empty() {
+            outputs.push((v.var, leaves));
+        }
+    }
+    g.privatize(&[StorageClass::Input]);
+    // the original functions are kept around but never called, and can't emit vertices in a vertex stage
+    let emissions = g
+        .module
+        .instructions
+        .iter()
+        .map(|i| g.emission(i).map(|e| e.is_some()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut emissions = emissions.into_iter();
+    g.module
+        .instructions
+        .retain(|_| !emissions.next().unwrap_or(false));
+
+    let int = g.find_or_declare(Type::Scalar(UniformKind::Int, 32));
+    let uint = g.uint_type();
+    let vertex_index = g.builtin_input(BuiltIn::VertexIndex, int, "gl_VertexIndex");
+    let entry = g.begin_function("oxidegl_geometry_passthrough");
+    let vertex = g.op(Op::Load, int, &[vertex_index]);
+    let vertex = g.bitcast(vertex, int, uint);
+    let base = g.record(
+        OUTPUT_BINDING,
+        OUT_RECORDS_START,
+        vertex,
+        layout.outputs.stride,
+    );
+    for (var, leaves) in &outputs {
+        g.load_leaves(
+            OUTPUT_BINDING,
+            base,
+            *var,
+            StorageClass::Output,
+            &[],
+            leaves,
+        );
+    }
+    g.end_function();
+    let interface = std::iter::once(vertex_index)
+        .chain(outputs.iter().map(|&(var, _)| var))
+        .collect::<Vec<_>>();
+    Ok(g.finish(ExecutionModel::Vertex, entry, &interface, &[]))
+}
+
+#[test]
+fn geometry_input_assembly() {
+    use PrimitiveType as P;
+    assert_eq!(assemble_input(P::Points, 3), [0, 1, 2]);
+    assert_eq!(assemble_input(P::Lines, 5), [0, 1, 2, 3]);
+    assert_eq!(assemble_input(P::LineLoop, 3), [0, 1, 1, 2, 2, 0]);
+    assert_eq!(
+        assemble_input(P::TriangleStrip, 5),
+        [0, 1, 2, 2, 1, 3, 2, 3, 4]
+    );
+    assert_eq!(assemble_input(P::TriangleFan, 4), [0, 1, 2, 0, 2, 3]);
+    assert_eq!(
+        assemble_input(P::LineStripAdjacency, 5),
+        [0, 1, 2, 3, 1, 2, 3, 4]
+    );
+    assert_eq!(
+        assemble_input(P::TriangleStripAdjacency, 6),
+        [0, 1, 2, 5, 4, 3]
+    );
+    assert_eq!(
+        assemble_input(P::TriangleStripAdjacency, 8),
+        [0, 1, 2, 6, 4, 3, 4, 0, 2, 5, 6, 7]
+    );
+}
@@ -0,0 +1,153 @@
+//! Image uniforms. SPIRV-Cross would place them in the argument buffer of their descriptor set along with the samplers,
+//! so they are moved to the discrete descriptor set instead, where each element of an image uniform becomes a texture
+//! argument of the stage that is bound to the texture of the image unit it refers to
+
+use ahash::{HashMap, HashMapExt};
+use spirv_cross2::spirv::{Decoration, Op, StorageClass};
+
+use super::{
+    DISCRETE_DESCRIPTOR_SET, feedback,
+    spirv::{Instruction, SpirvModule, Type},
+};
+use crate::{
+    context::state::MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS, gl_enums::BufferAccess,
+    texture::image::MAX_IMAGE_UNITS,
+};
+
+/// Binding of the first image uniform in the discrete descriptor set, after the transform feedback buffers. The other
+/// image uniforms of a stage are bound to the bindings after it, in declaration order
+#[expect(clippy::cast_possible_truncation, reason = "const checked")]
+pub(crate) const IMAGE_BINDING: u32 =
+    feedback::BUFFER_BINDING + MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS as u32;
+
+/// An image uniform of a module
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ImageVariable {
+    pub(crate) var: u32,
+    /// Number of array elements, 1 if the uniform is not an array
+    pub(crate) len: u32,
+    /// Texture argument table index of the first element. The elements of the image uniforms of a stage take up
+    /// consecutive indices in declaration order
+    pub(crate) index: u32,
+}
+
+/// The image uniforms of a module, in declaration order
+pub(crate) fn image_variables(module: &SpirvModule) -> Result<Vec<ImageVariable>, Box<str>> {
+    let mut images = Vec::new();
+    let mut index = 0;
+    for inst in &module.instructions {
+        let o = &inst.operands;
+        match inst.op() {
+            Some(Op::Function) => break,
+            Some(Op::Variable) if o[2] == StorageClass::UniformConstant as u32 => {}
+            _ => continue,
+        }
+        let Some(&Type::Pointer(_, pointee)) = module.types.get(&o[0]) else {
+            continue;
+        };
+        let (element, len) = match module.types.get(&pointee) {
+            Some(&Type::Array(element, len)) => (element, module.constant_value(len)?),
+            _ => (pointee, 1),
+        };
+        if !matches!(
+            module.types.get(&element),
+            Some(Type::Image { storage: true, .. })
+        ) {
+            continue;
+        }
+        images.push(ImageVariable {
+            var: o[1],
+            len,
+            index,
+        });
+        index += len;
+    }
+    if index > MAX_IMAGE_UNITS {
+        return Err(format!(
+            "stage uses {index} image uniforms, but at most {MAX_IMAGE_UNITS} are supported"
+        )
+        .into());
+    }
+    Ok(images)
+}
+
+/// Accesses a stage performs on an image uniform, as declared by its `readonly` and `writeonly` qualifiers
+pub(crate) fn access(module: &SpirvModule, var: u32) -> BufferAccess {
+    if module.decoration(var, Decoration::NonWritable).is_some() {
+        BufferAccess::ReadOnly
+    } else if module.decoration(var, Decoration::NonReadable).is_some() {
+        BufferAccess::WriteOnly
+    } else {
+        BufferAccess::ReadWrite
+    }
+}
+
+/// Image uniforms that a module performs atomic operations on, which take a pointer to a texel of the image (or of an
+/// element of an array of images)
+pub(crate) fn atomic_images(module: &SpirvModule) -> Vec<u32> {
+    // pointers to elements of arrays of images -> the array they point into
+    let mut elements = HashMap::new();
+    let mut atomic = Vec::new();
+    for inst in &module.instructions {
+        let o = &inst.operands;
+        match inst.op() {
+            Some(Op::AccessChain | Op::InBoundsAccessChain) => {
+                elements.insert(o[1], elements.get(&o[2]).copied().unwrap_or(o[2]));
+            }
+            Some(Op::ImageTexelPointer) => {
+                let var = elements.get(&o[2]).copied().unwrap_or(o[2]);
+                if !atomic.contains(&var) {
+                    atomic.push(var);
+                }
+            }
+            _ => {}
+        }
+    }
+    atomic
+}
+
+/// Move the image uniforms of a module to the discrete descriptor set, at [`IMAGE_BINDING`] plus the texture index of
+/// their first element
+pub(crate) fn bind_images(module: &SpirvModule, images: &[ImageVariable]) -> Box<[u32]> {
+    let first_type = module
+        .instructions
+        .iter()
+        .position(|i| (Op::TypeVoid as u32..=Op::TypeForwardPointer as u32).contains(&i.opcode));
+    let mut decorations = Vec::with_capacity(2 * images.len());
+    for image in images {
+        decorations.extend([
+            Instruction::new(
+                Op::Decorate,
+                vec![
+                    image.var,
+                    Decoration::DescriptorSet as u32,
+                    DISCRETE_DESCRIPTOR_SET,
+                ],
+            ),
+            Instruction::new(
+                Op::Decorate,
+                vec![
+                    image.var,
+                    Decoration::Binding as u32,
+                    IMAGE_BINDING + image.index,
+                ],
+            ),
+        ]);
+    }
+    let mut out = Vec::with_capacity(module.instructions.len() + decorations.len());
+    for (idx, inst) in module.instructions.iter().enumerate() {
+        if Some(idx) == first_type {
+            out.append(&mut decorations);
+        }
+        // the descriptor set and binding given in the source are replaced
+        if inst.op() == Some(Op::Decorate)
+            && images.iter().any(|i| i.var == inst.operands[0])
+            && [Decoration::DescriptorSet as u32, Decoration::Binding as u32]
+                .contains(&inst.operands[1])
+        {
+            continue;
+        }
+        out.push(inst.clone());
+    }
+    module.words(&out)
+}
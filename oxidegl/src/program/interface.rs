@@ -0,0 +1,851 @@
+//! Reflection of the resources used by linked stages, and of the program interfaces (in the sense of
+//! `glGetProgramResource*`) of a linked program
+
+use spirv_cross2::spirv::{Decoration, Op, StorageClass};
+
+use super::{
+    atomic_counters::{self, COUNTER_SIZE},
+    default_block::UniformTable,
+    images,
+    spirv::{SpirvModule, Type},
+    subroutines::{NO_SUBROUTINES, StageSubroutines, SubroutineUniform},
+};
+use crate::{
+    gl_enums::{BufferAccess, ProgramInterface, ShaderType, UniformType},
+    shader::spirv_string,
+};
+
+/// A resource that is only identified by its name, binding and location
+#[derive(Debug)]
+pub struct ProgramResource {
+    pub(crate) name: Box<str>,
+    pub(crate) binding: Option<u32>,
+    pub(crate) location: Option<u32>,
+}
+
+/// An image uniform of a stage
+#[derive(Debug)]
+pub struct ImageResource {
+    pub(crate) name: Box<str>,
+    /// Image unit of the first array element, from the `binding` layout qualifier
+    pub(crate) binding: u32,
+    /// Number of array elements, 1 if the uniform is not an array
+    pub(crate) len: u32,
+    /// Texture argument table index of the first array element
+    pub(crate) index: u32,
+    /// Accesses the stage performs on the image
+    pub(crate) access: BufferAccess,
+    /// Whether the stage performs atomic operations on the image
+    pub(crate) atomic: bool,
+}
+
+/// Placement of a variable inside of a uniform or shader storage block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct BlockLayout {
+    pub(crate) offset: u32,
+    pub(crate) array_stride: u32,
+    pub(crate) matrix_stride: u32,
+    pub(crate) row_major: bool,
+    /// Number of elements (0 if runtime sized) and stride of the top-level block member this variable belongs to, if
+    /// that member is an array. 1 and 0 otherwise
+    pub(crate) top_level_size: u32,
+    pub(crate) top_level_stride: u32,
+}
+
+/// A variable of basic type (i.e. a scalar, vector, matrix or opaque type, or an array of those) that is part of a
+/// program interface
+#[derive(Debug, Clone)]
+pub struct InterfaceVariable {
+    /// Name of the variable, without the `[0]` suffix GL gives to arrays
+    pub(crate) name: Box<str>,
+    pub(crate) ty: UniformType,
+    /// Number of array elements. 1 if this variable is not an array, 0 if it is a runtime sized array
+    pub(crate) len: u32,
+    pub(crate) is_array: bool,
+    pub(crate) location: Option<u32>,
+    /// Location index of a fragment shader output
+    pub(crate) location_index: Option<u32>,
+    pub(crate) component: Option<u32>,
+    /// Index of the block holding this variable in the interface of the program, once linked
+    pub(crate) block: Option<u32>,
+    pub(crate) layout: Option<BlockLayout>,
+    /// Stages that reference this variable
+    pub(crate) stages: Vec<ShaderType>,
+}
+impl InterfaceVariable {
+    /// Name of this variable as reported by GL
+    pub(crate) fn gl_name(&self) -> String {
+        if self.is_array {
+            format!("{}[0]", self.name)
+        } else {
+            self.name.to_string()
+        }
+    }
+    /// Number of locations taken up by each array element of this variable
+    pub(crate) fn element_locations(&self) -> u32 {
+        self.ty.locations()
+    }
+    /// Whether this variable is an atomic counter, whose block is an atomic counter buffer rather than a uniform block
+    pub(crate) fn is_atomic_counter(&self) -> bool {
+        self.ty == UniformType::UnsignedIntAtomicCounter
+    }
+}
+
+/// A uniform or shader storage block, or a buffer that transform feedback varyings or atomic counters are in
+#[derive(Debug, Clone)]
+pub struct InterfaceBlock {
+    /// Name of the block, including the array index for elements of block arrays
+    pub(crate) name: Box<str>,
+    pub(crate) binding: Option<u32>,
+    /// Minimum size of a buffer backing this block
+    pub(crate) data_size: u32,
+    pub(crate) members: Vec<InterfaceVariable>,
+    /// Stages that reference this block
+    pub(crate) stages: Vec<ShaderType>,
+}
+
+/// The resources used by a linked stage
+#[derive(Debug)]
+pub struct LinkedProgramResources {
+    pub(crate) uniform_buffers: Vec<InterfaceBlock>,
+    pub(crate) shader_storage_buffers: Vec<InterfaceBlock>,
+    /// Atomic counter buffers in binding point order, which is the order of the blocks their counters are lowered to
+    pub(crate) atomic_counter_buffers: Vec<InterfaceBlock>,
+    pub(crate) stage_inputs: Vec<InterfaceVariable>,
+    pub(crate) stage_outputs: Vec<InterfaceVariable>,
+    /// Loose uniforms left out of the emulated default block
+    pub(crate) plain_uniforms: Vec<ProgramResource>,
+    pub(crate) images: Vec<ImageResource>,
+}
+
+impl LinkedProgramResources {
+    pub(crate) fn reflect(module: &SpirvModule, stage: ShaderType) -> Result<Self, Box<str>> {
+        let mut resources = Self {
+            uniform_buffers: Vec::new(),
+            shader_storage_buffers: Vec::new(),
+            atomic_counter_buffers: Vec::new(),
+            stage_inputs: Vec::new(),
+            stage_outputs: Vec::new(),
+            plain_uniforms: Vec::new(),
+            images: Vec::new(),
+        };
+        let image_vars = images::image_variables(module)?;
+        let atomic_images = images::atomic_images(module);
+        // only the inputs and outputs that the entry point statically uses are active
+        let interface = module
+            .instructions
+            .iter()
+            .find(|i| i.op() == Some(Op::EntryPoint))
+            .map(|i| {
+                let (_, name_len) = spirv_string(&i.operands[2..]);
+                &i.operands[2 + name_len..]
+            })
+            .unwrap_or_default();
+        for inst in &module.instructions {
+            match inst.op() {
+                Some(Op::Function) => break,
+                Some(Op::Variable) => {}
+                _ => continue,
+            }
+            let o = &inst.operands;
+            let (var, storage) = (o[1], o[2]);
+            let Some(&Type::Pointer(_, pointee)) = module.types.get(&o[0]) else {
+                continue;
+            };
+            let name = module.names.get(&var).map_or("", String::as_str);
+            let binding = module.decoration(var, Decoration::Binding).map(|o| o[0]);
+            if storage == StorageClass::Uniform as u32
+                || storage == StorageClass::StorageBuffer as u32
+            {
+                let (block_ty, count) = match module.types.get(&pointee) {
+                    Some(&Type::Array(element, len)) => {
+                        (element, Some(module.constant_value(len)?))
+                    }
+                    _ => (pointee, None),
+                };
+                let is_storage = storage == StorageClass::StorageBuffer as u32
+                    || module
+                        .decoration(block_ty, Decoration::BufferBlock)
+                        .is_some();
+                if !is_storage && module.decoration(block_ty, Decoration::Block).is_none() {
+                    continue;
+                }
+                let block_name = module.names.get(&block_ty).map_or("", String::as_str);
+                // members of named block instances are qualified with the name of the block, not of the instance
+                let prefix = if name.is_empty() {
+                    String::new()
+                } else {
+                    format!("{block_name}.")
+                };
+                let mut members = Vec::new();
+                module.flatten_block(block_ty, &prefix, is_storage, stage, &mut members)?;
+                let data_size = module.size_of(block_ty, 0, false)?;
+                let list = if is_storage {
+                    &mut resources.shader_storage_buffers
+                } else {
+                    &mut resources.uniform_buffers
+                };
+                // each element of an array of blocks is a separate block as far as GL is concerned
+                let elements = count.map_or_else(
+                    || vec![(block_name.to_owned(), binding)],
+                    |count| {
+                        (0..count)
+                            .map(|i| (format!("{block_name}[{i}]"), binding.map(|b| b + i)))
+                            .collect()
+                    },
+                );
+                for (name, binding) in elements {
+                    list.push(InterfaceBlock {
+                        name: name.into(),
+                        binding,
+                        data_size,
+                        members: members.clone(),
+                        stages: vec![stage],
+                    });
+                }
+            } else if storage == StorageClass::Input as u32
+                || storage == StorageClass::Output as u32
+            {
+                if name.is_empty() || !interface.contains(&var) || module.is_builtin_block(pointee)
+                {
+                    continue;
+                }
+                let (element, len, is_array) = match module.types.get(&pointee) {
+                    Some(&Type::Array(element, len)) => {
+                        (element, module.constant_value(len)?, true)
+                    }
+                    _ => (pointee, 1, false),
+                };
+                let Some(ty) = module.basic_type(element) else {
+                    continue;
+                };
+                let variable = InterfaceVariable {
+                    name: name.into(),
+                    ty,
+                    len,
+                    is_array,
+                    location: module.location(var),
+                    location_index: module.decoration(var, Decoration::Index).map(|o| o[0]),
+                    component: module.decoration(var, Decoration::Component).map(|o| o[0]),
+                    block: None,
+                    layout: None,
+                    stages: vec![stage],
+                };
+                if storage == StorageClass::Input as u32 {
+                    resources.stage_inputs.push(variable);
+                } else {
+                    resources.stage_outputs.push(variable);
+                }
+            } else if let Some(image) = image_vars.iter().find(|i| i.var == var) {
+                resources.images.push(ImageResource {
+                    name: name.into(),
+                    binding: binding.unwrap_or(0),
+                    len: image.len,
+                    index: image.index,
+                    access: images::access(module, var),
+                    atomic: atomic_images.contains(&var),
+                });
+            } else if storage == StorageClass::UniformConstant as u32 {
+                resources.plain_uniforms.push(ProgramResource {
+                    name: name.into(),
+                    binding,
+                    location: module.location(var),
+                });
+            }
+        }
+        let counters = atomic_counters::atomic_counters(module)?;
+        for (binding, counters) in atomic_counters::counter_buffers(&counters)? {
+            resources.atomic_counter_buffers.push(InterfaceBlock {
+                name: "".into(),
+                binding: Some(binding),
+                data_size: counters
+                    .last()
+                    .map_or(0, atomic_counters::AtomicCounter::end),
+                members: counters
+                    .iter()
+                    .map(|c| InterfaceVariable {
+                        name: module.names.get(&c.var).map_or("", |n| n).into(),
+                        ty: UniformType::UnsignedIntAtomicCounter,
+                        len: c.len.unwrap_or(1),
+                        is_array: c.len.is_some(),
+                        location: None,
+                        location_index: None,
+                        component: None,
+                        block: None,
+                        layout: Some(BlockLayout {
+                            offset: c.offset,
+                            array_stride: if c.len.is_some() { COUNTER_SIZE } else { 0 },
+                            matrix_stride: 0,
+                            row_major: false,
+                            top_level_size: 1,
+                            top_level_stride: 0,
+                        }),
+                        stages: vec![stage],
+                    })
+                    .collect(),
+                stages: vec![stage],
+            });
+        }
+        Ok(resources)
+    }
+}
+
+impl SpirvModule {
+    /// Whether a type is a block of built-in variables (i.e. `gl_PerVertex`), or an array of those
+    fn is_builtin_block(&self, ty: u32) -> bool {
+        match self.types.get(&ty) {
+            Some(&Type::Array(element, _) | &Type::RuntimeArray(element)) => {
+                self.is_builtin_block(element)
+            }
+            Some(Type::Struct(members)) => (0..)
+                .zip(members)
+                .any(|(i, _)| self.member_decoration(ty, i, Decoration::BuiltIn).is_some()),
+            _ => false,
+        }
+    }
+    fn array_stride(&self, ty: u32) -> u32 {
+        self.decoration(ty, Decoration::ArrayStride)
+            .map_or(0, |o| o[0])
+    }
+    /// Size of a type stored in a uniform or shader storage block, given the layout of the matrices it holds
+    fn size_of(&self, ty: u32, matrix_stride: u32, row_major: bool) -> Result<u32, Box<str>> {
+        Ok(match self.types.get(&ty) {
+            Some(Type::Bool) => 4,
+            Some(&Type::Scalar(_, width)) => width / 8,
+            Some(&Type::Vector(component, n)) => n * self.size_of(component, 0, false)?,
+            Some(&Type::Matrix(column, columns)) => {
+                let Some(&Type::Vector(_, rows)) = self.types.get(&column) else {
+                    return Err("malformed matrix type".into());
+                };
+                matrix_stride * if row_major { rows } else { columns }
+            }
+            Some(&Type::Array(_, len)) => self.constant_value(len)? * self.array_stride(ty),
+            Some(Type::Struct(members)) => {
+                let mut size = 0;
+                for (i, &member) in (0..).zip(members) {
+                    let (offset, matrix_stride, row_major) = self.member_layout(ty, i);
+                    size = size.max(offset + self.size_of(member, matrix_stride, row_major)?);
+                }
+                size
+            }
+            // runtime sized arrays don't count towards the minimum size of a block
+            _ => 0,
+        })
+    }
+    /// Offset, matrix stride and majorness of a structure member
+    fn member_layout(&self, ty: u32, member: u32) -> (u32, u32, bool) {
+        (
+            self.member_decoration(ty, member, Decoration::Offset)
+                .map_or(0, |o| o[0]),
+            self.member_decoration(ty, member, Decoration::MatrixStride)
+                .map_or(0, |o| o[0]),
+            self.member_decoration(ty, member, Decoration::RowMajor)
+                .is_some(),
+        )
+    }
+    /// Enumerate the members of a block the way GL does, splitting structures and arrays of structures into their
+    /// members of basic type
+    fn flatten_block(
+        &self,
+        block_ty: u32,
+        prefix: &str,
+        is_storage: bool,
+        stage: ShaderType,
+        members: &mut Vec<InterfaceVariable>,
+    ) -> Result<(), Box<str>> {
+        let Some(Type::Struct(block_members)) = self.types.get(&block_ty) else {
+            return Err("block is not a structure".into());
+        };
+        for (i, &member_ty) in (0..).zip(block_members) {
+            let member_name = self
+                .member_names
+                .get(&(block_ty, i))
+                .map_or_else(|| format!("_m{i}"), Clone::clone);
+            let (offset, matrix_stride, row_major) = self.member_layout(block_ty, i);
+            let (top_level_size, top_level_stride) = match self.types.get(&member_ty) {
+                Some(&Type::Array(_, len)) => {
+                    (self.constant_value(len)?, self.array_stride(member_ty))
+                }
+                Some(Type::RuntimeArray(_)) => (0, self.array_stride(member_ty)),
+                _ => (1, 0),
+            };
+            let layout = BlockLayout {
+                offset,
+                array_stride: 0,
+                matrix_stride,
+                row_major,
+                top_level_size,
+                top_level_stride,
+            };
+            // only the first element of top-level arrays of structures in shader storage blocks is enumerated
+            self.flatten_member(
+                &format!("{prefix}{member_name}"),
+                member_ty,
+                layout,
+                is_storage,
+                stage,
+                members,
+            )?;
+        }
+        Ok(())
+    }
+    fn flatten_member(
+        &self,
+        name: &str,
+        ty: u32,
+        layout: BlockLayout,
+        first_only: bool,
+        stage: ShaderType,
+        members: &mut Vec<InterfaceVariable>,
+    ) -> Result<(), Box<str>> {
+        let mut push = |ty, len, is_array, layout| {
+            members.push(InterfaceVariable {
+                name: name.into(),
+                ty,
+                len,
+                is_array,
+                location: None,
+                location_index: None,
+                component: None,
+                block: None,
+                layout: Some(layout),
+                stages: vec![stage],
+            });
+        };
+        if let Some(basic) = self.basic_type(ty) {
+            push(basic, 1, false, layout);
+            return Ok(());
+        }
+        let array = match self.types.get(&ty) {
+            Some(&Type::Array(element, len)) => Some((element, self.constant_value(len)?)),
+            Some(&Type::RuntimeArray(element)) => Some((element, 0)),
+            _ => None,
+        };
+        if let Some((element, len)) = array {
+            let array_stride = self.array_stride(ty);
+            if let Some(basic) = self.basic_type(element) {
+                push(
+                    basic,
+                    len,
+                    true,
+                    BlockLayout {
+                        array_stride,
+                        ..layout
+                    },
+                );
+                return Ok(());
+            }
+            let enumerated = if first_only || len == 0 { 1 } else { len };
+            for i in 0..enumerated {
+                self.flatten_member(
+                    &format!("{name}[{i}]"),
+                    element,
+                    BlockLayout {
+                        offset: layout.offset + i * array_stride,
+                        ..layout
+                    },
+                    false,
+                    stage,
+                    members,
+                )?;
+            }
+            return Ok(());
+        }
+        match self.types.get(&ty) {
+            Some(Type::Struct(struct_members)) => {
+                for (i, &member_ty) in (0..).zip(struct_members) {
+                    let member_name = self
+                        .member_names
+                        .get(&(ty, i))
+                        .map_or_else(|| format!("_m{i}"), Clone::clone);
+                    let (offset, matrix_stride, row_major) = self.member_layout(ty, i);
+                    self.flatten_member(
+                        &format!("{name}.{member_name}"),
+                        member_ty,
+                        BlockLayout {
+                            offset: layout.offset + offset,
+                            matrix_stride,
+                            row_major,
+                            ..layout
+                        },
+                        false,
+                        stage,
+                        members,
+                    )?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// The active resources of a linked program, as enumerated by `glGetProgramResource*` and the older introspection
+/// commands. Indices into these lists are the resource indices exposed to the application
+#[derive(Debug, Default)]
+pub struct ProgramInterfaces {
+    /// Uniforms of the default block, in the order of the uniform table, followed by the members of uniform blocks and
+    /// then the atomic counters
+    pub(crate) uniforms: Vec<InterfaceVariable>,
+    pub(crate) uniform_blocks: Vec<InterfaceBlock>,
+    /// Buffers of the atomic counters among the uniforms, which are their blocks
+    pub(crate) atomic_counter_buffers: Vec<InterfaceBlock>,
+    pub(crate) buffer_variables: Vec<InterfaceVariable>,
+    pub(crate) shader_storage_blocks: Vec<InterfaceBlock>,
+    /// Inputs of the first stage of the program
+    pub(crate) inputs: Vec<InterfaceVariable>,
+    /// Outputs of the last stage of the program
+    pub(crate) outputs: Vec<InterfaceVariable>,
+    /// Varyings captured with transform feedback, whose blocks are the buffers they are written to
+    pub(crate) transform_feedback_varyings: Vec<InterfaceVariable>,
+    pub(crate) transform_feedback_buffers: Vec<InterfaceBlock>,
+    /// Subroutines and subroutine uniforms of the stages that declare any, which make up the subroutine interfaces
+    pub(crate) subroutines: Vec<(ShaderType, StageSubroutines)>,
+}
+
+impl ProgramInterfaces {
+    /// Build the interfaces of a program from the uniform table and the resources of its stages, which must be given
+    /// in pipeline order
+    pub(crate) fn build(
+        table: &UniformTable,
+        stages: &[(ShaderType, &LinkedProgramResources)],
+    ) -> Self {
+        let mut interfaces = Self {
+            uniforms: table
+                .uniforms
+                .iter()
+                .map(|u| InterfaceVariable {
+                    name: u.name.clone(),
+                    ty: u.ty,
+                    len: u.len,
+                    is_array: u.is_array,
+                    location: Some(u.location),
+                    location_index: None,
+                    component: None,
+                    block: None,
+                    layout: None,
+                    stages: u.stages.clone(),
+                })
+                .collect(),
+            ..Default::default()
+        };
+        for &(_, resources) in stages {
+            merge_blocks(
+                &mut interfaces.uniform_blocks,
+                &mut interfaces.uniforms,
+                &resources.uniform_buffers,
+            );
+            merge_blocks(
+                &mut interfaces.shader_storage_blocks,
+                &mut interfaces.buffer_variables,
+                &resources.shader_storage_buffers,
+            );
+        }
+        for &(_, resources) in stages {
+            merge_atomic_counter_buffers(
+                &mut interfaces.atomic_counter_buffers,
+                &mut interfaces.uniforms,
+                &resources.atomic_counter_buffers,
+            );
+        }
+        if let Some((_, first)) = stages.first() {
+            interfaces.inputs.clone_from(&first.stage_inputs);
+        }
+        if let Some((_, last)) = stages.last() {
+            interfaces.outputs.clone_from(&last.stage_outputs);
+        }
+        interfaces
+    }
+}
+
+/// A resource in one of the interfaces of a program
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Resource<'a> {
+    Variable(&'a InterfaceVariable),
+    Block(&'a InterfaceBlock),
+    /// A subroutine, identified by its name
+    Subroutine(&'a str),
+    SubroutineUniform(&'a SubroutineUniform),
+}
+impl<'a> Resource<'a> {
+    /// Name of this resource as reported by GL
+    pub(crate) fn gl_name(self) -> String {
+        match self {
+            Resource::Variable(v) => v.gl_name(),
+            Resource::Block(b) => b.name.to_string(),
+            Resource::Subroutine(name) => name.to_owned(),
+            Resource::SubroutineUniform(u) => u.gl_name(),
+        }
+    }
+    /// Stages that reference this resource
+    pub(crate) fn stages(self) -> &'a [ShaderType] {
+        match self {
+            Resource::Variable(v) => &v.stages,
+            Resource::Block(b) => &b.stages,
+            // subroutine interfaces belong to a single stage, and have no stage references
+            Resource::Subroutine(_) | Resource::SubroutineUniform(_) => &[],
+        }
+    }
+}
+
+impl ProgramInterfaces {
+    /// Number of active resources in an interface
+    pub(crate) fn resource_count(&self, interface: ProgramInterface) -> usize {
+        match interface {
+            ProgramInterface::Uniform => self.uniforms.len(),
+            ProgramInterface::UniformBlock => self.uniform_blocks.len(),
+            ProgramInterface::AtomicCounterBuffer => self.atomic_counter_buffers.len(),
+            ProgramInterface::ProgramInput => self.inputs.len(),
+            ProgramInterface::ProgramOutput => self.outputs.len(),
+            ProgramInterface::BufferVariable => self.buffer_variables.len(),
+            ProgramInterface::ShaderStorageBlock => self.shader_storage_blocks.len(),
+            ProgramInterface::TransformFeedbackVarying => self.transform_feedback_varyings.len(),
+            ProgramInterface::TransformFeedbackBuffer => self.transform_feedback_buffers.len(),
+            _ => match subroutine_interface(interface) {
+                Some((stage, false)) => self.stage_subroutines(stage).functions.len(),
+                Some((stage, true)) => self.stage_subroutines(stage).uniforms.len(),
+                None => 0,
+            },
+        }
+    }
+    /// Active resource of an interface by index
+    pub(crate) fn resource(
+        &self,
+        interface: ProgramInterface,
+        index: usize,
+    ) -> Option<Resource<'_>> {
+        let variables = match interface {
+            ProgramInterface::Uniform => &self.uniforms,
+            ProgramInterface::ProgramInput => &self.inputs,
+            ProgramInterface::ProgramOutput => &self.outputs,
+            ProgramInterface::BufferVariable => &self.buffer_variables,
+            ProgramInterface::TransformFeedbackVarying => &self.transform_feedback_varyings,
+            ProgramInterface::UniformBlock => {
+                return self.uniform_blocks.get(index).map(Resource::Block);
+            }
+            ProgramInterface::ShaderStorageBlock => {
+                return self.shader_storage_blocks.get(index).map(Resource::Block);
+            }
+            ProgramInterface::AtomicCounterBuffer => {
+                return self.atomic_counter_buffers.get(index).map(Resource::Block);
+            }
+            ProgramInterface::TransformFeedbackBuffer => {
+                return self
+                    .transform_feedback_buffers
+                    .get(index)
+                    .map(Resource::Block);
+            }
+            _ => {
+                let (stage, uniforms) = subroutine_interface(interface)?;
+                let subroutines = self.stage_subroutines(stage);
+                return if uniforms {
+                    subroutines
+                        .uniforms
+                        .get(index)
+                        .map(Resource::SubroutineUniform)
+                } else {
+                    subroutines
+                        .functions
+                        .get(index)
+                        .map(|f| Resource::Subroutine(f))
+                };
+            }
+        };
+        variables.get(index).map(Resource::Variable)
+    }
+    /// Index of an active resource of an interface by name. Names of arrays match with or without the `[0]` suffix
+    pub(crate) fn resource_index(&self, interface: ProgramInterface, name: &str) -> Option<usize> {
+        (0..self.resource_count(interface)).find(|&i| {
+            let resource_name = self
+                .resource(interface, i)
+                .expect("resource index should be in bounds")
+                .gl_name();
+            resource_name == name
+                || resource_name
+                    .strip_suffix("[0]")
+                    .is_some_and(|base| base == name)
+        })
+    }
+    /// Members of the block at `index` in an interface of blocks, as indices into the matching interface of variables
+    pub(crate) fn block_members(&self, interface: ProgramInterface, index: usize) -> Vec<usize> {
+        let variables = match interface {
+            ProgramInterface::UniformBlock | ProgramInterface::AtomicCounterBuffer => {
+                &self.uniforms
+            }
+            ProgramInterface::ShaderStorageBlock => &self.buffer_variables,
+            ProgramInterface::TransformFeedbackBuffer => &self.transform_feedback_varyings,
+            _ => return Vec::new(),
+        };
+        // atomic counters share the uniform interface with the members of uniform blocks
+        let counters = interface == ProgramInterface::AtomicCounterBuffer;
+        variables
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| {
+                v.block.is_some_and(|b| b as usize == index) && v.is_atomic_counter() == counters
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+    /// Input or output of the program by name, along with the array element the name refers to
+    pub(crate) fn find_variable(
+        &self,
+        interface: ProgramInterface,
+        name: &str,
+    ) -> Option<(&InterfaceVariable, u32)> {
+        let variables = match interface {
+            ProgramInterface::ProgramInput => &self.inputs,
+            ProgramInterface::ProgramOutput => &self.outputs,
+            _ => return None,
+        };
+        if let Some(v) = variables.iter().find(|v| &*v.name == name) {
+            return Some((v, 0));
+        }
+        let (base, element) = name.strip_suffix(']')?.rsplit_once('[')?;
+        let element = element.parse::<u32>().ok()?;
+        variables
+            .iter()
+            .find(|v| v.is_array && &*v.name == base && element < v.len)
+            .map(|v| (v, element))
+    }
+    /// Location of an input or output of the program by name, which may refer to an element of an array
+    pub(crate) fn variable_location(&self, interface: ProgramInterface, name: &str) -> Option<u32> {
+        let (v, element) = self.find_variable(interface, name)?;
+        Some(v.location? + element * v.element_locations())
+    }
+    /// Vertex attributes of the program, i.e. its inputs if its first stage is a vertex shader
+    pub(crate) fn attributes(&self) -> &[InterfaceVariable] {
+        if self
+            .inputs
+            .first()
+            .is_some_and(|v| v.stages.contains(&ShaderType::VertexShader))
+        {
+            &self.inputs
+        } else {
+            &[]
+        }
+    }
+    /// Subroutines and subroutine uniforms of a stage of the program, which has none if it has no such stage
+    pub(crate) fn stage_subroutines(&self, stage: ShaderType) -> &StageSubroutines {
+        self.subroutines
+            .iter()
+            .find(|(s, _)| *s == stage)
+            .map_or(&NO_SUBROUTINES, |(_, s)| s)
+    }
+    /// Length of the longest name in an interface, including the nul terminator
+    pub(crate) fn max_name_length(&self, interface: ProgramInterface) -> usize {
+        (0..self.resource_count(interface))
+            .filter_map(|i| self.resource(interface, i))
+            .map(|r| r.gl_name().len() + 1)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// The stage of a subroutine interface, and whether it is an interface of subroutine uniforms
+pub(crate) fn subroutine_interface(interface: ProgramInterface) -> Option<(ShaderType, bool)> {
+    use ProgramInterface as I;
+    Some(match interface {
+        I::VertexSubroutine => (ShaderType::VertexShader, false),
+        I::TessControlSubroutine => (ShaderType::TessControlShader, false),
+        I::TessEvaluationSubroutine => (ShaderType::TessEvaluationShader, false),
+        I::GeometrySubroutine => (ShaderType::GeometryShader, false),
+        I::FragmentSubroutine => (ShaderType::FragmentShader, false),
+        I::ComputeSubroutine => (ShaderType::ComputeShader, false),
+        I::VertexSubroutineUniform => (ShaderType::VertexShader, true),
+        I::TessControlSubroutineUniform => (ShaderType::TessControlShader, true),
+        I::TessEvaluationSubroutineUniform => (ShaderType::TessEvaluationShader, true),
+        I::GeometrySubroutineUniform => (ShaderType::GeometryShader, true),
+        I::FragmentSubroutineUniform => (ShaderType::FragmentShader, true),
+        I::ComputeSubroutineUniform => (ShaderType::ComputeShader, true),
+        _ => return None,
+    })
+}
+
+/// Add the blocks used by a stage to the blocks of a program. Blocks declared by several stages are merged, and the
+/// members of new blocks are appended to the variables of the program
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "more than u32::MAX blocks cannot exist at once"
+)]
+fn merge_blocks(
+    program_blocks: &mut Vec<InterfaceBlock>,
+    variables: &mut Vec<InterfaceVariable>,
+    stage_blocks: &[InterfaceBlock],
+) {
+    for block in stage_blocks {
+        if let Some(idx) = program_blocks.iter().position(|b| b.name == block.name) {
+            for &stage in &block.stages {
+                if !program_blocks[idx].stages.contains(&stage) {
+                    program_blocks[idx].stages.push(stage);
+                }
+            }
+            for v in variables.iter_mut().filter(|v| v.block == Some(idx as u32)) {
+                for &stage in &block.stages {
+                    if !v.stages.contains(&stage) {
+                        v.stages.push(stage);
+                    }
+                }
+            }
+            continue;
+        }
+        let idx = program_blocks.len() as u32;
+        variables.extend(block.members.iter().map(|m| InterfaceVariable {
+            block: Some(idx),
+            ..m.clone()
+        }));
+        program_blocks.push(block.clone());
+    }
+}
+
+/// Add the atomic counter buffers used by a stage to the ones of a program, and their counters to its uniforms. Buffers
+/// are identified by their binding point and counters by their name, so the ones declared by several stages are merged
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "more than u32::MAX buffers cannot exist at once"
+)]
+fn merge_atomic_counter_buffers(
+    program_buffers: &mut Vec<InterfaceBlock>,
+    uniforms: &mut Vec<InterfaceVariable>,
+    stage_buffers: &[InterfaceBlock],
+) {
+    for buffer in stage_buffers {
+        let idx = match program_buffers
+            .iter()
+            .position(|b| b.binding == buffer.binding)
+        {
+            Some(idx) => {
+                let program_buffer = &mut program_buffers[idx];
+                program_buffer.data_size = program_buffer.data_size.max(buffer.data_size);
+                for &stage in &buffer.stages {
+                    if !program_buffer.stages.contains(&stage) {
+                        program_buffer.stages.push(stage);
+                    }
+                }
+                idx as u32
+            }
+            None => {
+                program_buffers.push(buffer.clone());
+                program_buffers.len() as u32 - 1
+            }
+        };
+        for counter in &buffer.members {
+            match uniforms
+                .iter_mut()
+                .find(|u| u.is_atomic_counter() && u.block == Some(idx) && u.name == counter.name)
+            {
+                Some(uniform) => {
+                    for &stage in &counter.stages {
+                        if !uniform.stages.contains(&stage) {
+                            uniform.stages.push(stage);
+                        }
+                    }
+                }
+                None => uniforms.push(InterfaceVariable {
+                    block: Some(idx),
+                    ..counter.clone()
+                }),
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Assignment of locations to the vertex attributes and fragment outputs of a program, as done by `glLinkProgram`.
+//! Variables with a `location` layout qualifier keep their location, variables bound by name with
+//! `glBindAttribLocation` or `glBindFragDataLocation(Indexed)` get the location they were bound to, and the remaining
+//! ones get the lowest free range of locations they fit in, in the order they were declared in. The inputs and
+//! outputs between stages don't need locations, since stages are matched by name when they have none.
+
+use core::ops::Range;
+
+use ahash::HashMap;
+use spirv_cross2::spirv::{Decoration, Op, StorageClass};
+
+use super::spirv::{Instruction, SpirvModule, Type};
+use crate::{
+    framebuffer::MAX_COLOR_ATTACHMENTS, gl_enums::ShaderType, shader::spirv_string,
+    vao::MAX_VERTEX_ATTRIBUTES,
+};
+
+/// Number of color numbers that fragment outputs of index 1 can be bound to for dual-source blending
+pub(crate) const MAX_DUAL_SOURCE_DRAW_BUFFERS: u32 = 1;
+
+/// Locations that the next link of a program binds its vertex attributes and fragment outputs to by name
+#[derive(Debug, Clone, Default)]
+pub struct LocationBindings {
+    /// Generic vertex attribute of each vertex shader input, as bound by `glBindAttribLocation`
+    pub(crate) attributes: HashMap<String, u32>,
+    /// Color number and index of each fragment shader output, as bound by `glBindFragDataLocationIndexed`
+    pub(crate) frag_data: HashMap<String, (u32, u32)>,
+}
+impl LocationBindings {
+    /// Name, location and index of the bindings of the variables of a stage, sorted by name
+    pub(crate) fn stage(&self, stage: ShaderType) -> Vec<(&str, u32, u32)> {
+        let mut bindings = match stage {
+            ShaderType::VertexShader => self
+                .attributes
+                .iter()
+                .map(|(name, &location)| (name.as_str(), location, 0))
+                .collect(),
+            ShaderType::FragmentShader => self
+                .frag_data
+                .iter()
+                .map(|(name, &(location, index))| (name.as_str(), location, index))
+                .collect(),
+            _ => Vec::new(),
+        };
+        bindings.sort_unstable();
+        bindings
+    }
+}
+
+/// Number of locations a variable of a type takes up
+fn location_count(module: &SpirvModule, ty: u32) -> Result<u32, Box<str>> {
+    match module.types.get(&ty) {
+        Some(&Type::Array(element, len)) => {
+            Ok(module.constant_value(len)? * location_count(module, element)?)
+        }
+        _ => Ok(module
+            .basic_type(ty)
+            .ok_or("interface variable has no basic type")?
+            .locations()),
+    }
+}
+
+/// Assign locations to the inputs of a vertex stage or the outputs of a fragment stage that don't have one, given the
+/// `bindings` of the stage by name (as returned by [`LocationBindings::stage`])
+pub(crate) fn assign_locations(
+    spirv: &[u32],
+    stage: ShaderType,
+    bindings: &[(&str, u32, u32)],
+) -> Result<Box<[u32]>, Box<str>> {
+    #[expect(clippy::cast_possible_truncation, reason = "const checked")]
+    let (storage, max, what) = match stage {
+        ShaderType::VertexShader => (
+            StorageClass::Input,
+            MAX_VERTEX_ATTRIBUTES as u32,
+            "vertex attributes",
+        ),
+        ShaderType::FragmentShader => (
+            StorageClass::Output,
+            MAX_COLOR_ATTACHMENTS,
+            "fragment outputs",
+        ),
+        _ => return Ok(spirv.into()),
+    };
+    let mut module = SpirvModule::parse(spirv)?;
+    // only the variables that the entry point statically uses are active
+    let interface = module
+        .instructions
+        .iter()
+        .find(|i| i.op() == Some(Op::EntryPoint))
+        .map(|i| {
+            let (_, name_len) = spirv_string(&i.operands[2..]);
+            &i.operands[2 + name_len..]
+        })
+        .unwrap_or_default();
+
+    // locations taken up by variables of index 0, which are the only ones automatically assigned variables can alias
+    let mut used = Vec::<Range<u32>>::new();
+    let mut unassigned = Vec::new();
+    for inst in &module.instructions {
+        match inst.op() {
+            Some(Op::Function) => break,
+            Some(Op::Variable) if inst.operands[2] == storage as u32 => {}
+            _ => continue,
+        }
+        let var = inst.operands[1];
+        let Some(&Type::Pointer(_, pointee)) = module.types.get(&inst.operands[0]) else {
+            continue;
+        };
+        if !interface.contains(&var) || module.decoration(var, Decoration::BuiltIn).is_some() {
+            continue;
+        }
+        let count = location_count(&module, pointee)?;
+        let index = module
+            .decoration(var, Decoration::Index)
+            .map_or(0, |o| o[0]);
+        match module.location(var) {
+            Some(location) if index == 0 => used.push(location..location + count),
+            Some(_) => {}
+            None => unassigned.push((var, count)),
+        }
+    }
+    let mut assigned = Vec::new();
+    let mut automatic = Vec::new();
+    for (var, count) in unassigned {
+        let name = module.names.get(&var).map_or("", String::as_str);
+        match bindings.iter().find(|&&(n, ..)| n == name) {
+            Some(&(_, location, index)) => {
+                if index == 0 {
+                    used.push(location..location + count);
+                }
+                assigned.push((var, location, index));
+            }
+            None => automatic.push((var, count)),
+        }
+    }
+    for (var, count) in automatic {
+        // the lowest free range starts at 0 or right after a used one
+        let location = std::iter::once(0)
+            .chain(used.iter().map(|r| r.end))
+            .filter(|&l| used.iter().all(|r| l + count <= r.start || r.end <= l))
+            .min()
+            .expect("the range after the last used one is free");
+        used.push(location..location + count);
+        assigned.push((var, location, 0));
+    }
+    if let Some(end) = used.iter().map(|r| r.end).max().filter(|&end| end > max) {
+        return Err(format!("{what} take up {end} locations, but only {max} are available").into());
+    }
+    if assigned.is_empty() {
+        return Ok(spirv.into());
+    }
+
+    let mut decorations = Vec::new();
+    for (var, location, index) in assigned {
+        decorations.push(Instruction::new(
+            Op::Decorate,
+            vec![var, Decoration::Location as u32, location],
+        ));
+        if index != 0 {
+            decorations.push(Instruction::new(
+                Op::Decorate,
+                vec![var, Decoration::Index as u32, index],
+            ));
+        }
+    }
+    // decorations go right before the first type declaration
+    let first_type = module
+        .instructions
+        .iter()
+        .position(|i| (Op::TypeVoid as u32..=Op::TypeForwardPointer as u32).contains(&i.opcode))
+        .ok_or("module declares no types")?;
+    let mut instructions = core::mem::take(&mut module.instructions);
+    instructions.splice(first_type..first_type, decorations);
+    Ok(module.words(&instructions))
+}
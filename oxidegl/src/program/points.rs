@@ -0,0 +1,116 @@
+//! Point rasterization state. Metal reads the diameter of points from the `[[point_size]]` output of the vertex
+//! function, which GL only uses with `GL_PROGRAM_POINT_SIZE` enabled, and its `[[point_coord]]` always has an upper
+//! left origin. Like the clip volume (see [`clip_control`](super::clip_control)), both are handled by function
+//! constants read by generated code:
+//! * The epilogue of the stages that feed the rasterizer writes the [`POINT_SIZE_CONSTANT`] function constant
+//!   (`glPointSize`) to the point size output, declaring one if the stage doesn't have it, unless the
+//!   [`PROGRAM_POINT_SIZE_CONSTANT`] function constant is set.
+//! * The prologue of fragment stages that read `gl_PointCoord` flips it vertically if the
+//!   [`SPRITE_LOWER_LEFT_CONSTANT`] function constant is set (`GL_POINT_SPRITE_COORD_ORIGIN` is `GL_LOWER_LEFT`).
+
+use spirv_cross2::spirv::{BuiltIn, Decoration, ExecutionModel, Op, StorageClass};
+
+use super::{
+    clip_control::{self, builtin_output},
+    default_block::UniformKind,
+    geometry::{Generator, StageVariable, VariableKind},
+    spirv::Type,
+};
+
+/// Function constant index of the flag that is set when the stage writes the point size (`GL_PROGRAM_POINT_SIZE`)
+pub(crate) const PROGRAM_POINT_SIZE_CONSTANT: u32 = clip_control::CLIP_DISTANCE_CONSTANT - 1;
+/// Function constant index of the point size used when the stage doesn't write it, which defaults to 1
+pub(crate) const POINT_SIZE_CONSTANT: u32 = PROGRAM_POINT_SIZE_CONSTANT - 1;
+/// Function constant index of the flag that is set when `gl_PointCoord` has a lower left origin
+pub(crate) const SPRITE_LOWER_LEFT_CONSTANT: u32 = POINT_SIZE_CONSTANT - 1;
+
+/// Write the point size to the point size output of the stage in `g` from the generated function being built, adding
+/// the output to `interface` if it has to be declared
+pub(super) fn write_point_size(g: &mut Generator, interface: &mut Vec<u32>) {
+    let float = g.find_or_declare(Type::Scalar(UniformKind::Float, 32));
+    let size = g.spec_float(POINT_SIZE_CONSTANT, 1.0, "oxidegl_point_size");
+    let (pointer, size) =
+        if let Some((var, _, member)) = builtin_output(g, interface, BuiltIn::PointSize) {
+            let program_point_size =
+                g.spec_flag(PROGRAM_POINT_SIZE_CONSTANT, "oxidegl_program_point_size");
+            let pointer = match member {
+                Some(member) => {
+                    let pointer = g.pointer_type(StorageClass::Output, float);
+                    let index = g.uint(member);
+                    g.op(Op::AccessChain, pointer, &[var, index])
+                }
+                None => var,
+            };
+            let written = g.op(Op::Load, float, &[pointer]);
+            let size = g.op(Op::Select, float, &[program_point_size, written, size]);
+            (pointer, size)
+        } else {
+            let var = g.variable(StorageClass::Output, float, "oxidegl_PointSize");
+            g.decorate(var, Decoration::BuiltIn, &[BuiltIn::PointSize as u32]);
+            interface.push(var);
+            (var, size)
+        };
+    g.push(Op::Store, vec![pointer, size]);
+}
+
+/// Generate the variant of the fragment stage in `spirv` that flips the `gl_PointCoord` it reads to the point sprite
+/// origin. Returns `None` for other stages and fragment stages that don't read it
+pub(crate) fn remap_point_coord(spirv: &[u32]) -> Result<Option<Box<[u32]>>, Box<str>> {
+    let mut g = Generator::new(spirv)?;
+    let entry_point = g
+        .module
+        .instructions
+        .iter()
+        .find(|i| i.op() == Some(Op::EntryPoint))
+        .expect("checked in Generator::new");
+    if entry_point.operands[0] != ExecutionModel::Fragment as u32 {
+        return Ok(None);
+    }
+    let mut interface = g.interface();
+    let Some(point_coord) = StageVariable::collect(&g.module, StorageClass::Input, false)
+        .into_iter()
+        .find(|v| {
+            interface.contains(&v.var)
+                && matches!(v.kind, VariableKind::BuiltIn(BuiltIn::PointCoord))
+        })
+    else {
+        return Ok(None);
+    };
+    let modes = g
+        .module
+        .instructions
+        .iter()
+        .filter(|i| i.op() == Some(Op::ExecutionMode) && i.operands[0] == g.main)
+        .map(|i| i.operands[1..].to_vec())
+        .collect::<Vec<_>>();
+
+    // the stage reads the flipped coordinate from a private copy of its input
+    let vec2 = point_coord.ty;
+    g.privatize_variable(point_coord.var);
+    interface.retain(|&var| var != point_coord.var);
+    let input = g.builtin_input(BuiltIn::PointCoord, vec2, "oxidegl_PointCoord");
+    interface.push(input);
+    let float = g.find_or_declare(Type::Scalar(UniformKind::Float, 32));
+    let lower_left = g.spec_flag(
+        SPRITE_LOWER_LEFT_CONSTANT,
+        "oxidegl_point_sprite_lower_left",
+    );
+    let one = g.float(1.0);
+    let main = g.main;
+    let entry = g.begin_function("oxidegl_point_coord");
+    let coord = g.op(Op::Load, vec2, &[input]);
+    let t = g.op(Op::CompositeExtract, float, &[coord, 1]);
+    let flipped = g.op(Op::FSub, float, &[one, t]);
+    let t = g.op(Op::Select, float, &[lower_left, flipped, t]);
+    let coord = g.op(Op::CompositeInsert, vec2, &[t, coord, 1]);
+    g.push(Op::Store, vec![point_coord.var, coord]);
+    g.call(main);
+    g.end_function();
+    let modes = modes.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    Ok(Some(g.finish(
+        ExecutionModel::Fragment,
+        entry,
+        &interface,
+        &modes,
+    )))
+}
@@ -0,0 +1,339 @@
+//! A minimal SPIR-V module representation, shared by the link-time rewrites and the reflection of linked stages
+
+use ahash::{HashMap, HashMapExt};
+use spirv_cross2::spirv::{Decoration, Op};
+
+use super::default_block::{SAMPLERS, UniformKind};
+use crate::{
+    gl_enums::UniformType,
+    shader::{SPIRV_MAGIC, spirv_string},
+};
+
+/// A SPIR-V instruction
+#[derive(Debug, Clone)]
+pub(crate) struct Instruction {
+    pub(crate) opcode: u32,
+    pub(crate) operands: Vec<u32>,
+}
+impl Instruction {
+    #[inline]
+    pub(crate) fn new(op: Op, operands: Vec<u32>) -> Self {
+        Self {
+            opcode: op as u32,
+            operands,
+        }
+    }
+    #[inline]
+    pub(crate) fn op(&self) -> Option<Op> {
+        Op::from_u32(self.opcode)
+    }
+}
+/// Encode a string as a SPIR-V literal
+pub(crate) fn string_words(s: &str) -> Vec<u32> {
+    let mut bytes = s.as_bytes().to_vec();
+    bytes.resize(bytes.len() / 4 * 4 + 4, 0);
+    bytes
+        .chunks_exact(4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+/// The types declared by a module that are relevant to GL
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Type {
+    Bool,
+    /// Numeric scalar of the given kind and bit width
+    Scalar(UniformKind, u32),
+    Vector(u32, u32),
+    Matrix(u32, u32),
+    /// Element type and the id of the constant holding the length
+    Array(u32, u32),
+    RuntimeArray(u32),
+    Struct(Vec<u32>),
+    Pointer(u32, u32),
+    Image {
+        sampled_type: u32,
+        dim: u32,
+        shadow: bool,
+        arrayed: bool,
+        multisampled: bool,
+        /// Whether the image is used without a sampler, i.e. declared by an image uniform
+        storage: bool,
+    },
+    SampledImage(u32),
+    /// Samplers, functions and every other type that can't be part of a uniform block
+    Opaque,
+}
+
+/// A parsed SPIR-V module
+#[derive(Debug)]
+pub(crate) struct SpirvModule {
+    pub(crate) header: [u32; 5],
+    pub(crate) instructions: Vec<Instruction>,
+    pub(crate) types: HashMap<u32, Type>,
+    /// Operands of each constant declaration, by id
+    pub(crate) constants: HashMap<u32, Instruction>,
+    pub(crate) names: HashMap<u32, String>,
+    pub(crate) member_names: HashMap<(u32, u32), String>,
+    /// Literal operands of each decoration, by target id and decoration
+    pub(crate) decorations: HashMap<(u32, u32), Vec<u32>>,
+    /// Literal operands of each member decoration, by structure type, member index and decoration
+    pub(crate) member_decorations: HashMap<(u32, u32, u32), Vec<u32>>,
+}
+
+impl SpirvModule {
+    pub(crate) fn parse(words: &[u32]) -> Result<Self, Box<str>> {
+        if words.len() < 5 || words[0] != SPIRV_MAGIC {
+            return Err("not a SPIR-V module".into());
+        }
+        let mut instructions = Vec::new();
+        let mut idx = 5;
+        while idx < words.len() {
+            let word_count = (words[idx] >> 16) as usize;
+            if word_count == 0 || idx + word_count > words.len() {
+                return Err("malformed SPIR-V instruction".into());
+            }
+            instructions.push(Instruction {
+                opcode: words[idx] & 0xffff,
+                operands: words[idx + 1..idx + word_count].to_vec(),
+            });
+            idx += word_count;
+        }
+        let mut module = Self {
+            header: words[..5].try_into().unwrap(),
+            instructions: Vec::new(),
+            types: HashMap::new(),
+            constants: HashMap::new(),
+            names: HashMap::new(),
+            member_names: HashMap::new(),
+            decorations: HashMap::new(),
+            member_decorations: HashMap::new(),
+        };
+        for inst in &instructions {
+            let o = &inst.operands;
+            let ty = match inst.op() {
+                Some(Op::Name) => {
+                    module.names.insert(o[0], spirv_string(&o[1..]).0);
+                    continue;
+                }
+                Some(Op::MemberName) => {
+                    module
+                        .member_names
+                        .insert((o[0], o[1]), spirv_string(&o[2..]).0);
+                    continue;
+                }
+                Some(Op::Decorate) => {
+                    module.decorations.insert((o[0], o[1]), o[2..].to_vec());
+                    continue;
+                }
+                Some(Op::MemberDecorate) => {
+                    module
+                        .member_decorations
+                        .insert((o[0], o[1], o[2]), o[3..].to_vec());
+                    continue;
+                }
+                Some(
+                    Op::Constant
+                    | Op::ConstantTrue
+                    | Op::ConstantFalse
+                    | Op::ConstantComposite
+                    | Op::ConstantNull
+                    | Op::SpecConstant
+                    | Op::SpecConstantTrue
+                    | Op::SpecConstantFalse
+                    | Op::SpecConstantComposite,
+                ) => {
+                    module.constants.insert(o[1], inst.clone());
+                    continue;
+                }
+                Some(Op::TypeBool) => Type::Bool,
+                Some(Op::TypeInt) => Type::Scalar(
+                    if o[2] == 1 {
+                        UniformKind::Int
+                    } else {
+                        UniformKind::UnsignedInt
+                    },
+                    o[1],
+                ),
+                Some(Op::TypeFloat) => Type::Scalar(
+                    if o[1] == 64 {
+                        UniformKind::Double
+                    } else {
+                        UniformKind::Float
+                    },
+                    o[1],
+                ),
+                Some(Op::TypeVector) => Type::Vector(o[1], o[2]),
+                Some(Op::TypeMatrix) => Type::Matrix(o[1], o[2]),
+                Some(Op::TypeArray) => Type::Array(o[1], o[2]),
+                Some(Op::TypeRuntimeArray) => Type::RuntimeArray(o[1]),
+                Some(Op::TypeStruct) => Type::Struct(o[1..].to_vec()),
+                Some(Op::TypePointer) => Type::Pointer(o[1], o[2]),
+                Some(Op::TypeImage) => Type::Image {
+                    sampled_type: o[1],
+                    dim: o[2],
+                    shadow: o[3] == 1,
+                    arrayed: o[4] == 1,
+                    multisampled: o[5] == 1,
+                    storage: o[6] == 2,
+                },
+                Some(Op::TypeSampledImage) => Type::SampledImage(o[1]),
+                Some(
+                    Op::TypeVoid
+                    | Op::TypeSampler
+                    | Op::TypeFunction
+                    | Op::TypeOpaque
+                    | Op::TypeForwardPointer,
+                ) => Type::Opaque,
+                _ => continue,
+            };
+            module.types.insert(o[0], ty);
+        }
+        module.instructions = instructions;
+        Ok(module)
+    }
+    #[inline]
+    pub(crate) fn new_id(&mut self) -> u32 {
+        let id = self.header[3];
+        self.header[3] += 1;
+        id
+    }
+    /// Assemble a list of instructions into a module with the header of this one
+    pub(crate) fn words(&self, instructions: &[Instruction]) -> Box<[u32]> {
+        let mut words = self.header.to_vec();
+        for inst in instructions {
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "instructions are shorter than u16::MAX words"
+            )]
+            words.push(((inst.operands.len() as u32 + 1) << 16) | inst.opcode);
+            words.extend_from_slice(&inst.operands);
+        }
+        words.into_boxed_slice()
+    }
+    /// Name of the first entry point of the module
+    pub(crate) fn entry_point_name(&self) -> Option<String> {
+        self.instructions
+            .iter()
+            .find(|i| i.op() == Some(Op::EntryPoint))
+            .map(|i| spirv_string(&i.operands[2..]).0)
+    }
+    /// Literal operands of a decoration of an id, if it is decorated with it
+    #[inline]
+    pub(crate) fn decoration(&self, id: u32, decoration: Decoration) -> Option<&[u32]> {
+        self.decorations
+            .get(&(id, decoration as u32))
+            .map(Vec::as_slice)
+    }
+    /// Literal operands of a decoration of a structure member, if it is decorated with it
+    #[inline]
+    pub(crate) fn member_decoration(
+        &self,
+        ty: u32,
+        member: u32,
+        decoration: Decoration,
+    ) -> Option<&[u32]> {
+        self.member_decorations
+            .get(&(ty, member, decoration as u32))
+            .map(Vec::as_slice)
+    }
+    /// Location an id is decorated with
+    #[inline]
+    pub(crate) fn location(&self, id: u32) -> Option<u32> {
+        self.decoration(id, Decoration::Location).map(|o| o[0])
+    }
+    pub(crate) fn is_bool(&self, ty: u32) -> bool {
+        match self.types.get(&ty) {
+            Some(Type::Bool) => true,
+            Some(&Type::Vector(component, _)) => self.is_bool(component),
+            _ => false,
+        }
+    }
+    /// Whether a type can not be stored in a uniform block
+    pub(crate) fn is_opaque(&self, ty: u32) -> bool {
+        match self.types.get(&ty) {
+            Some(&Type::Array(element, _)) => self.is_opaque(element),
+            Some(Type::Struct(members)) => members.iter().any(|&m| self.is_opaque(m)),
+            Some(
+                Type::Image { .. }
+                | Type::SampledImage(_)
+                | Type::Opaque
+                | Type::Pointer(..)
+                | Type::RuntimeArray(_),
+            )
+            | None => true,
+            Some(_) => false,
+        }
+    }
+    pub(crate) fn constant_value(&self, id: u32) -> Result<u32, Box<str>> {
+        self.constants
+            .get(&id)
+            .filter(|c| matches!(c.op(), Some(Op::Constant | Op::SpecConstant)))
+            .map(|c| c.operands[2])
+            .ok_or_else(|| "array length is not a constant".into())
+    }
+    /// Whether a type of this module is the same as a type of `other`, as an output of a stage and the input of the
+    /// next stage it is matched to have to be
+    pub(crate) fn same_type(&self, ty: u32, other: &SpirvModule, other_ty: u32) -> bool {
+        match (self.types.get(&ty), other.types.get(&other_ty)) {
+            (Some(Type::Bool), Some(Type::Bool)) => true,
+            (Some(a @ Type::Scalar(..)), Some(b @ Type::Scalar(..))) => a == b,
+            (Some(&Type::Vector(a, n)), Some(&Type::Vector(b, m)))
+            | (Some(&Type::Matrix(a, n)), Some(&Type::Matrix(b, m))) => {
+                n == m && self.same_type(a, other, b)
+            }
+            (Some(&Type::Array(a, n)), Some(&Type::Array(b, m))) => {
+                self.constant_value(n).ok() == other.constant_value(m).ok()
+                    && self.same_type(a, other, b)
+            }
+            (Some(Type::Struct(a)), Some(Type::Struct(b))) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(&a, &b)| self.same_type(a, other, b))
+            }
+            _ => false,
+        }
+    }
+    /// Column type of a matrix type, or of the matrices in an array type
+    pub(crate) fn matrix_column(&self, ty: u32) -> Option<u32> {
+        match self.types.get(&ty)? {
+            &Type::Matrix(column, _) => Some(column),
+            &Type::Array(element, _) | &Type::RuntimeArray(element) => self.matrix_column(element),
+            _ => None,
+        }
+    }
+    /// GL type of a scalar, vector or matrix type
+    pub(crate) fn basic_type(&self, ty: u32) -> Option<UniformType> {
+        let (kind, columns, rows) = match *self.types.get(&ty)? {
+            Type::Bool => (UniformKind::Bool, 1, 1),
+            Type::Scalar(kind, _) => (kind, 1, 1),
+            Type::Vector(component, n) => (self.basic_type(component)?.shape().0, 1, n),
+            Type::Matrix(column, n) => {
+                let (kind, _, rows) = self.basic_type(column)?.shape();
+                (kind, n, u32::from(rows))
+            }
+            _ => return None,
+        };
+        UniformType::from_shape(kind, columns, rows)
+    }
+    /// GL type of a sampler type
+    pub(crate) fn sampler_type(&self, ty: u32) -> Option<UniformType> {
+        let &Type::SampledImage(image) = self.types.get(&ty)? else {
+            return None;
+        };
+        let &Type::Image {
+            sampled_type,
+            dim,
+            shadow,
+            arrayed,
+            multisampled,
+            ..
+        } = self.types.get(&image)?
+        else {
+            return None;
+        };
+        let kind = self.basic_type(sampled_type)?.shape().0;
+        SAMPLERS
+            .iter()
+            .find(|s| (s.1, s.2, s.3, s.4, s.5) == (kind, dim, arrayed, multisampled, shadow))
+            .map(|s| s.0)
+    }
+}
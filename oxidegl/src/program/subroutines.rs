@@ -0,0 +1,756 @@
+//! Emulation of shader subroutines, which neither SPIR-V for OpenGL nor Metal have. Subroutines are lowered in the
+//! GLSL source of a shader before it is compiled:
+//! 1. Subroutine type declarations are removed, and the `subroutine(...)` qualifiers of the functions implementing
+//!    them are stripped, which leaves plain functions.
+//! 2. Each subroutine uniform is replaced by a `uint` uniform of the default block (its selector), which holds the
+//!    index of the subroutine to call, and calls through the subroutine uniform become calls of a generated dispatch
+//!    function that switches on the selector.
+//! 3. At draw time, the subroutines selected with `glUniformSubroutinesuiv` are written to the selectors in the
+//!    default block of each stage before it is uploaded.
+//!
+//! Lowering never adds or removes lines before the dispatch functions, which are appended to the end of the source,
+//! so compiler messages still point at the lines the application wrote.
+
+use core::{fmt::Write, ops::Range};
+
+use super::default_block::DefaultBlock;
+use crate::{context::state::GlState, gl_enums::ShaderType, program_pipeline::ProgramPipeline};
+
+/// Prefix of the name of the uniform that selects the subroutine called through a subroutine uniform
+pub(crate) const SELECTOR_PREFIX: &str = "oxidegl_subroutine_";
+/// Prefix of the name of the function that calls the selected subroutine of a subroutine uniform
+const DISPATCH_PREFIX: &str = "oxidegl_dispatch_";
+/// Qualifiers that may precede the type of a function parameter
+const PARAMETER_QUALIFIERS: &[&str] = &[
+    "const",
+    "in",
+    "out",
+    "inout",
+    "precise",
+    "lowp",
+    "mediump",
+    "highp",
+    "coherent",
+    "volatile",
+    "restrict",
+    "readonly",
+    "writeonly",
+];
+
+/// A subroutine uniform of a stage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubroutineUniform {
+    pub(crate) name: Box<str>,
+    /// Number of array elements, 1 if this uniform is not an array
+    pub(crate) len: u32,
+    pub(crate) is_array: bool,
+    /// Location of the first element, the other elements have consecutive locations
+    pub(crate) location: u32,
+    /// Indices of the subroutines that can be selected for this uniform, in ascending order
+    pub(crate) compatible: Vec<u32>,
+}
+impl SubroutineUniform {
+    /// Name of this uniform as reported by GL
+    pub(crate) fn gl_name(&self) -> String {
+        if self.is_array {
+            format!("{}[0]", self.name)
+        } else {
+            self.name.to_string()
+        }
+    }
+}
+
+/// The subroutines and subroutine uniforms of a stage
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StageSubroutines {
+    /// Names of the subroutines, by index
+    pub(crate) functions: Vec<Box<str>>,
+    pub(crate) uniforms: Vec<SubroutineUniform>,
+}
+/// The subroutines of a stage that declares none
+pub(crate) static NO_SUBROUTINES: StageSubroutines = StageSubroutines {
+    functions: Vec::new(),
+    uniforms: Vec::new(),
+};
+impl StageSubroutines {
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.functions.is_empty() && self.uniforms.is_empty()
+    }
+    /// Number of subroutine uniform locations, i.e. one more than the highest location used
+    pub(crate) fn location_count(&self) -> u32 {
+        self.uniforms
+            .iter()
+            .map(|u| u.location + u.len)
+            .max()
+            .unwrap_or(0)
+    }
+    /// The subroutine uniform a location belongs to, along with the array element at the location
+    pub(crate) fn uniform_at(&self, location: u32) -> Option<(&SubroutineUniform, u32)> {
+        self.uniforms
+            .iter()
+            .find(|u| (u.location..u.location + u.len).contains(&location))
+            .map(|u| (u, location - u.location))
+    }
+    /// Index of a subroutine by name
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "more than u32::MAX subroutines cannot exist at once"
+    )]
+    pub(crate) fn index(&self, name: &str) -> Option<u32> {
+        self.functions
+            .iter()
+            .position(|f| &**f == name)
+            .map(|i| i as u32)
+    }
+    /// Location of a subroutine uniform by name, which may refer to an element of an array
+    pub(crate) fn location(&self, name: &str) -> Option<u32> {
+        if let Some(u) = self.uniforms.iter().find(|u| &*u.name == name) {
+            return Some(u.location);
+        }
+        let (base, element) = name.strip_suffix(']')?.rsplit_once('[')?;
+        let element = element.parse::<u32>().ok()?;
+        self.uniforms
+            .iter()
+            .find(|u| u.is_array && &*u.name == base && element < u.len)
+            .map(|u| u.location + element)
+    }
+    /// Index of the subroutine called through a location: the one selected for it, or the first compatible subroutine
+    /// if none was selected since the program was made current
+    #[inline]
+    pub(crate) fn selected(&self, selections: &[u32], location: u32) -> Option<u32> {
+        let (uniform, _) = self.uniform_at(location)?;
+        selections
+            .get(location as usize)
+            .or(uniform.compatible.first())
+            .copied()
+    }
+    /// Write the index of the subroutine called through each location to the selectors in the contents of the default
+    /// block of the stage
+    pub(crate) fn write_selectors(
+        &self,
+        block: &DefaultBlock,
+        data: &mut [u8],
+        selections: &[u32],
+    ) {
+        for uniform in &self.uniforms {
+            let selector = format!("{SELECTOR_PREFIX}{}", uniform.name);
+            let Some(layout) = block
+                .members
+                .iter()
+                .find(|m| *m.name == selector)
+                .and_then(|m| m.layout)
+            else {
+                continue;
+            };
+            for element in 0..uniform.len {
+                let index = self
+                    .selected(selections, uniform.location + element)
+                    .expect("location belongs to this uniform");
+                let offset = (layout.offset + element * layout.array_stride) as usize;
+                data[offset..offset + 4].copy_from_slice(&index.to_le_bytes());
+            }
+        }
+    }
+}
+
+impl GlState {
+    /// Subroutines selected for the subroutine uniform locations of a stage with `glUniformSubroutinesuiv`, empty if
+    /// none were selected since the program of the stage was made current
+    #[inline]
+    pub(crate) fn subroutine_selections(&self, stage: ShaderType) -> &[u32] {
+        &self.subroutine_selections[ProgramPipeline::stage_index(stage)]
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    text: &'a str,
+    start: usize,
+}
+impl Token<'_> {
+    #[inline]
+    fn end(&self) -> usize {
+        self.start + self.text.len()
+    }
+    #[inline]
+    fn is_identifier(&self) -> bool {
+        self.text
+            .bytes()
+            .next()
+            .is_some_and(|b| b.is_ascii_alphabetic() || b == b'_')
+    }
+}
+
+/// Split GLSL source into identifiers, numbers and punctuation, skipping comments and preprocessor directives
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let mut line_start = true;
+    while i < bytes.len() {
+        let rest = &source[i..];
+        let c = bytes[i];
+        if c == b'\n' {
+            line_start = true;
+            i += 1;
+        } else if c.is_ascii_whitespace() {
+            i += 1;
+        } else if c == b'#' && line_start {
+            // directives end at the first newline that doesn't follow a backslash
+            while i < bytes.len() && bytes[i] != b'\n' {
+                i += if bytes[i..].starts_with(b"\\\n") {
+                    2
+                } else {
+                    1
+                };
+            }
+        } else if rest.starts_with("//") {
+            i += rest.find('\n').unwrap_or(rest.len());
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            i += comment.find("*/").map_or(rest.len(), |end| end + 4);
+        } else {
+            line_start = false;
+            let len = if c.is_ascii_alphanumeric() || c == b'_' {
+                rest.bytes()
+                    .position(|b| !(b.is_ascii_alphanumeric() || b == b'_'))
+                    .unwrap_or(rest.len())
+            } else {
+                rest.chars().next().map_or(1, char::len_utf8)
+            };
+            tokens.push(Token {
+                text: &rest[..len],
+                start: i,
+            });
+            i += len;
+        }
+    }
+    tokens
+}
+
+/// Index of the token that closes the parenthesis or bracket opened at `open`
+fn matching(tokens: &[Token<'_>], open: usize) -> Result<usize, Box<str>> {
+    let mut depth = 0usize;
+    for (i, t) in tokens.iter().enumerate().skip(open) {
+        match t.text {
+            "(" | "[" => depth += 1,
+            ")" | "]" => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    Err("unbalanced parentheses".into())
+}
+
+/// Split the tokens in between a pair of parentheses at their top-level commas
+fn split_list<'a, 'b>(tokens: &'b [Token<'a>]) -> Vec<&'b [Token<'a>]> {
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, t) in tokens.iter().enumerate() {
+        match t.text {
+            "(" | "[" => depth += 1,
+            ")" | "]" => depth = depth.saturating_sub(1),
+            "," if depth == 0 => {
+                items.push(&tokens[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < tokens.len() {
+        items.push(&tokens[start..]);
+    }
+    items
+}
+
+/// The parts of a layout qualifier that are relevant to subroutines
+struct LayoutQualifier {
+    /// Index of the `layout` token
+    start: usize,
+    index: Option<u32>,
+    location: Option<u32>,
+}
+/// Parse a layout qualifier that ends right before the token at `i`
+fn layout_before(tokens: &[Token<'_>], i: usize) -> Result<Option<LayoutQualifier>, Box<str>> {
+    if i == 0 || tokens[i - 1].text != ")" {
+        return Ok(None);
+    }
+    let Some(open) = (0..i - 1)
+        .rev()
+        .find(|&j| tokens[j].text == "(" && matching(tokens, j).is_ok_and(|close| close == i - 1))
+    else {
+        return Ok(None);
+    };
+    if open == 0 || tokens[open - 1].text != "layout" {
+        return Ok(None);
+    }
+    let (mut index, mut location) = (None, None);
+    for qualifier in split_list(&tokens[open + 1..i - 1]) {
+        if let [name, eq, value] = qualifier
+            && eq.text == "="
+        {
+            let value = value
+                .text
+                .parse::<u32>()
+                .map_err(|_| format!("expected an integer value for {}", name.text))?;
+            match name.text {
+                "index" => index = Some(value),
+                "location" => location = Some(value),
+                _ => {}
+            }
+        }
+    }
+    Ok(Some(LayoutQualifier {
+        start: open - 1,
+        index,
+        location,
+    }))
+}
+
+/// Text that replaces removed source, which keeps its newlines so the following lines keep their numbers
+fn removed(source: &str) -> String {
+    source.chars().filter(|&c| c == '\n').collect()
+}
+
+/// A subroutine type declared by a shader
+struct SubroutineType<'a> {
+    name: &'a str,
+    return_type: &'a str,
+    /// Parameters, with their names replaced by the ones the dispatch functions use
+    parameters: Vec<String>,
+}
+/// A function that implements subroutine types
+struct SubroutineFunction<'a> {
+    name: &'a str,
+    types: Vec<&'a str>,
+    index: Option<u32>,
+}
+/// A subroutine uniform, as declared
+struct DeclaredUniform<'a> {
+    name: &'a str,
+    ty: &'a str,
+    len: u32,
+    is_array: bool,
+    location: Option<u32>,
+    /// Source range of the declaration
+    declaration: Range<usize>,
+}
+
+/// Rename a parameter of a subroutine type to the name of the `n`th argument of a dispatch function, adding a name if
+/// it has none
+fn rename_parameter(source: &str, parameter: &[Token<'_>], n: usize) -> String {
+    let new_name = format!("oxidegl_arg_{n}");
+    let text = &source[parameter[0].start..parameter[parameter.len() - 1].end()];
+    // the name is the last identifier outside of array brackets that isn't a qualifier or the type
+    let mut depth = 0usize;
+    let identifiers = parameter
+        .iter()
+        .filter(|t| {
+            match t.text {
+                "[" => depth += 1,
+                "]" => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            depth == 0 && t.is_identifier() && !PARAMETER_QUALIFIERS.contains(&t.text)
+        })
+        .collect::<Vec<_>>();
+    match identifiers[..] {
+        [.., _, name] => {
+            let offset = name.start - parameter[0].start;
+            format!(
+                "{}{new_name}{}",
+                &text[..offset],
+                &text[offset + name.text.len()..]
+            )
+        }
+        _ => format!("{text} {new_name}"),
+    }
+}
+
+/// Assign the lowest free values to the items without an explicit one, so that each covers `len` consecutive values
+fn assign(explicit: &[(Option<u32>, u32)], what: &str) -> Result<Vec<u32>, Box<str>> {
+    let mut used: Vec<Range<u32>> = Vec::new();
+    for &(value, len) in explicit {
+        let Some(value) = value else {
+            continue;
+        };
+        let range = value..value + len;
+        if used
+            .iter()
+            .any(|r| r.start < range.end && range.start < r.end)
+        {
+            return Err(format!("{what} {value} is assigned more than once").into());
+        }
+        used.push(range);
+    }
+    let mut values = Vec::with_capacity(explicit.len());
+    for &(value, len) in explicit {
+        let value = value.unwrap_or_else(|| {
+            // the lowest free range starts at 0 or right after a used one
+            let value = std::iter::once(0)
+                .chain(used.iter().map(|r| r.end))
+                .filter(|&v| used.iter().all(|r| v + len <= r.start || r.end <= v))
+                .min()
+                .expect("the range after the last used one is free");
+            used.push(value..value + len);
+            value
+        });
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Lower the subroutines of the GLSL source of a shader to plain functions, selector uniforms and dispatch functions
+/// as described in the [module docs](self), returning the lowered source and the subroutines it declares
+pub(crate) fn lower_subroutines(source: &str) -> Result<(String, StageSubroutines), Box<str>> {
+    let tokens = tokenize(source);
+    if !tokens.iter().any(|t| t.text == "subroutine") {
+        return Ok((source.to_owned(), StageSubroutines::default()));
+    }
+    let t = &tokens;
+    let token = |i: usize| {
+        t.get(i)
+            .copied()
+            .ok_or_else(|| Box::<str>::from("unexpected end of a subroutine declaration"))
+    };
+    let expect = |i: usize, text: &str| -> Result<(), Box<str>> {
+        let found = token(i)?.text;
+        if found == text {
+            Ok(())
+        } else {
+            Err(format!("expected `{text}` in a subroutine declaration, found `{found}`").into())
+        }
+    };
+
+    let mut types: Vec<SubroutineType<'_>> = Vec::new();
+    let mut functions: Vec<SubroutineFunction<'_>> = Vec::new();
+    let mut uniforms: Vec<DeclaredUniform<'_>> = Vec::new();
+    // replaced source ranges, in order
+    let mut edits: Vec<(Range<usize>, String)> = Vec::new();
+    let mut i = 0;
+    while i < t.len() {
+        if t[i].text != "subroutine" {
+            i += 1;
+            continue;
+        }
+        let (start, index, location) = match layout_before(t, i)? {
+            Some(layout) => (t[layout.start].start, layout.index, layout.location),
+            None => (t[i].start, None, None),
+        };
+        match token(i + 1)?.text {
+            // subroutine uniform <type> <name>[<len>];
+            "uniform" => {
+                let (ty, name) = (token(i + 2)?.text, token(i + 3)?.text);
+                let (len, is_array, end) = if token(i + 4)?.text == "[" {
+                    let len = token(i + 5)?.text.parse::<u32>().map_err(|_| {
+                        format!("subroutine uniform array {name} must have a constant length")
+                    })?;
+                    expect(i + 6, "]")?;
+                    (len, true, i + 7)
+                } else {
+                    (1, false, i + 4)
+                };
+                expect(end, ";")?;
+                uniforms.push(DeclaredUniform {
+                    name,
+                    ty,
+                    len,
+                    is_array,
+                    location,
+                    declaration: start..t[end].end(),
+                });
+                i = end + 1;
+            }
+            // subroutine(<types>) <return type> <name>(<parameters>) { ... }
+            "(" => {
+                let close = matching(t, i + 1)?;
+                let implemented = split_list(&t[i + 2..close])
+                    .into_iter()
+                    .map(|ty| match ty {
+                        [ty] if ty.is_identifier() => Ok(ty.text),
+                        _ => Err("expected a list of subroutine types"),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let open = (close + 1..t.len())
+                    .find(|&j| t[j].text == "(")
+                    .ok_or("expected a function after a subroutine qualifier")?;
+                let name = t[open - 1].text;
+                if let Some(f) = functions.iter_mut().find(|f| f.name == name) {
+                    // a prototype followed by the definition
+                    f.index = f.index.or(index);
+                } else {
+                    functions.push(SubroutineFunction {
+                        name,
+                        types: implemented,
+                        index,
+                    });
+                }
+                edits.push((
+                    start..t[close].end(),
+                    removed(&source[start..t[close].end()]),
+                ));
+                i = close + 1;
+            }
+            // subroutine <return type> <name>(<parameters>);
+            _ => {
+                let open = (i + 1..t.len())
+                    .find(|&j| t[j].text == "(")
+                    .ok_or("expected parameters of a subroutine type")?;
+                if open < i + 3 {
+                    return Err("expected the return type and name of a subroutine type".into());
+                }
+                let close = matching(t, open)?;
+                expect(close + 1, ";")?;
+                let parameters = match &t[open + 1..close] {
+                    [] => Vec::new(),
+                    [void] if void.text == "void" => Vec::new(),
+                    parameters => split_list(parameters)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(n, p)| rename_parameter(source, p, n))
+                        .collect(),
+                };
+                types.push(SubroutineType {
+                    name: t[open - 1].text,
+                    return_type: &source[t[i + 1].start..t[open - 2].end()],
+                    parameters,
+                });
+                let end = t[close + 1].end();
+                edits.push((start..end, removed(&source[start..end])));
+                i = close + 2;
+            }
+        }
+    }
+
+    for f in &functions {
+        if let Some(ty) = f
+            .types
+            .iter()
+            .find(|&&ty| types.iter().all(|declared| declared.name != ty))
+        {
+            return Err(format!(
+                "subroutine {} implements undeclared subroutine type {ty}",
+                f.name
+            )
+            .into());
+        }
+    }
+    let indices = assign(
+        &functions.iter().map(|f| (f.index, 1)).collect::<Vec<_>>(),
+        "subroutine index",
+    )?;
+    if let Some((f, index)) = functions
+        .iter()
+        .zip(&indices)
+        .find(|&(_, &index)| index as usize >= functions.len())
+    {
+        return Err(format!(
+            "subroutine {} has index {index}, but indices that leave unused indices in between are not supported",
+            f.name
+        )
+        .into());
+    }
+    let locations = assign(
+        &uniforms
+            .iter()
+            .map(|u| (u.location, u.len))
+            .collect::<Vec<_>>(),
+        "subroutine uniform location",
+    )?;
+
+    let mut subroutines = StageSubroutines {
+        functions: vec![Box::from(""); functions.len()],
+        uniforms: Vec::new(),
+    };
+    for (f, &index) in functions.iter().zip(&indices) {
+        subroutines.functions[index as usize] = f.name.into();
+    }
+    let mut dispatch = String::new();
+    for (u, location) in uniforms.iter().zip(locations) {
+        let ty = types.iter().find(|t| t.name == u.ty).ok_or_else(|| {
+            format!(
+                "subroutine uniform {} has undeclared subroutine type {}",
+                u.name, u.ty
+            )
+        })?;
+        let mut compatible = functions
+            .iter()
+            .zip(&indices)
+            .filter(|(f, _)| f.types.contains(&u.ty))
+            .map(|(_, &index)| index)
+            .collect::<Vec<_>>();
+        compatible.sort_unstable();
+        if compatible.is_empty() {
+            return Err(format!(
+                "subroutine uniform {} has subroutine type {}, which no subroutine implements",
+                u.name, u.ty
+            )
+            .into());
+        }
+
+        let (name, ret) = (u.name, ty.return_type);
+        let mut parameters = ty.parameters.clone();
+        let (array, element) = if u.is_array {
+            parameters.insert(0, "int oxidegl_element".to_owned());
+            (format!("[{}]", u.len), "[oxidegl_element]")
+        } else {
+            (String::new(), "")
+        };
+        let parameters = parameters.join(", ");
+        let prototype = format!("{ret} {DISPATCH_PREFIX}{name}({parameters})");
+        edits.push((
+            u.declaration.clone(),
+            format!(
+                "uniform uint {SELECTOR_PREFIX}{name}{array}; {prototype};{}",
+                removed(&source[u.declaration.clone()])
+            ),
+        ));
+        let arguments = (0..ty.parameters.len())
+            .map(|n| format!("oxidegl_arg_{n}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            dispatch,
+            "{prototype} {{\n    switch ({SELECTOR_PREFIX}{name}{element}) {{"
+        )
+        .unwrap();
+        for (n, &index) in compatible.iter().enumerate() {
+            let label = if n + 1 == compatible.len() {
+                "default".to_owned()
+            } else {
+                format!("case {index}u")
+            };
+            let function = &subroutines.functions[index as usize];
+            if ret == "void" {
+                writeln!(dispatch, "    {label}: {function}({arguments}); return;").unwrap();
+            } else {
+                writeln!(dispatch, "    {label}: return {function}({arguments});").unwrap();
+            }
+        }
+        dispatch.push_str("    }\n}\n");
+
+        subroutines.uniforms.push(SubroutineUniform {
+            name: name.into(),
+            len: u.len,
+            is_array: u.is_array,
+            location,
+            compatible,
+        });
+    }
+
+    // calls through subroutine uniforms call their dispatch functions instead
+    for (i, call) in t.iter().enumerate() {
+        let Some(u) = uniforms.iter().find(|u| u.name == call.text) else {
+            continue;
+        };
+        if uniforms.iter().any(|u| u.declaration.contains(&call.start))
+            || (i > 0 && t[i - 1].text == ".")
+        {
+            continue;
+        }
+        let function = format!("{DISPATCH_PREFIX}{}", u.name);
+        match t.get(i + 1).map(|next| next.text) {
+            Some("(") => edits.push((call.start..call.end(), function)),
+            // u[element](arguments) calls dispatch(element, arguments)
+            Some("[") => {
+                let close = matching(t, i + 1)?;
+                if t.get(close + 1).map(|next| next.text) != Some("(") {
+                    continue;
+                }
+                let no_arguments = t.get(close + 2).map(|next| next.text) == Some(")");
+                edits.push((call.start..t[i + 1].end(), format!("{function}(")));
+                edits.push((
+                    t[close].start..t[close + 1].end(),
+                    if no_arguments { "" } else { ", " }.to_owned(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    edits.sort_by_key(|(range, _)| range.start);
+    let mut lowered = String::with_capacity(source.len() + dispatch.len());
+    let mut copied = 0;
+    for (range, replacement) in edits {
+        lowered.push_str(&source[copied..range.start]);
+        lowered.push_str(&replacement);
+        copied = range.end;
+    }
+    lowered.push_str(&source[copied..]);
+    if !lowered.ends_with('\n') {
+        lowered.push('\n');
+    }
+    lowered.push_str(&dispatch);
+    Ok((lowered, subroutines))
+}
+
+#[test]
+fn subroutine_lowering() {
+    let source = "#version 430 core
+subroutine vec4 Shade(vec3 normal, float);
+subroutine vec4 Other(vec3 n, float i);
+layout(index = 1) subroutine(Shade) vec4 flat_shade(vec3 n, float intensity) { return vec4(intensity); }
+subroutine(Shade, Other) vec4 /* Shade */ lit(vec3 n, float i) { return vec4(n * i, 1.0); }
+subroutine uniform Shade shade;
+layout(location = 2) subroutine uniform Shade shades[2];
+void main() {
+    // shade(vec3(1.0), 0.5);
+    vec4 a = shade(vec3(1.0), 0.5) + shades[1](vec3(0.0), 1.0);
+}
+";
+    let (lowered, subroutines) = lower_subroutines(source).unwrap();
+    assert_eq!(
+        subroutines,
+        StageSubroutines {
+            functions: vec!["lit".into(), "flat_shade".into()],
+            uniforms: vec![
+                SubroutineUniform {
+                    name: "shade".into(),
+                    len: 1,
+                    is_array: false,
+                    location: 0,
+                    compatible: vec![0, 1],
+                },
+                SubroutineUniform {
+                    name: "shades".into(),
+                    len: 2,
+                    is_array: true,
+                    location: 2,
+                    compatible: vec![0, 1],
+                },
+            ],
+        }
+    );
+    assert_eq!(subroutines.location_count(), 4);
+    assert_eq!(subroutines.location("shades[1]"), Some(3));
+    assert_eq!(subroutines.uniform_at(1), None);
+    assert!(!lowered.contains("subroutine("));
+    assert!(lowered.contains("uniform uint oxidegl_subroutine_shades[2];"));
+    assert!(lowered.contains(
+        "oxidegl_dispatch_shade(vec3(1.0), 0.5) + oxidegl_dispatch_shades(1, vec3(0.0), 1.0)"
+    ));
+    // comments are left alone
+    assert!(lowered.contains("// shade(vec3(1.0), 0.5);"));
+    assert!(lowered.contains(
+        "vec4 oxidegl_dispatch_shades(int oxidegl_element, vec3 oxidegl_arg_0, float oxidegl_arg_1) {"
+    ));
+    assert!(lowered.contains("case 0u: return lit(oxidegl_arg_0, oxidegl_arg_1);"));
+    assert!(lowered.contains("default: return flat_shade(oxidegl_arg_0, oxidegl_arg_1);"));
+    // lines of the original source keep their numbers
+    let lines = source.lines().count();
+    assert_eq!(
+        lowered.lines().nth(lines - 2),
+        Some(
+            "    vec4 a = oxidegl_dispatch_shade(vec3(1.0), 0.5) + oxidegl_dispatch_shades(1, vec3(0.0), 1.0);"
+        )
+    );
+
+    assert!(lower_subroutines("subroutine void Unused(); subroutine uniform Unused u;").is_err());
+    let plain = "void main() {}";
+    assert_eq!(lower_subroutines(plain).unwrap().0, plain);
+}
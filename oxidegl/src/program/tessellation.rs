@@ -0,0 +1,570 @@
+//! Emulation of tessellation control stages, and translation of tessellation evaluation stages to Metal
+//! post-tessellation vertex functions. Metal runs its fixed-function tessellator on patches whose tessellation factors
+//! are read from a buffer, and has no programmable stage in front of it, so a draw of patches is split into a compute
+//! pre-pass and a patch draw:
+//! 1. The vertex stage runs as a compute kernel over the vertices used by the draw, like in the pre-pass of an emulated
+//!    geometry stage (see [`geometry`](super::geometry)), writing its outputs into records in the input buffer.
+//! 2. The tessellation control stage runs as a compute kernel with a threadgroup per patch and instance, and a thread
+//!    per output control point. Its outputs live in threadgroup memory, so the invocations of a patch can read each
+//!    other's outputs after a barrier. Once they return, each invocation writes its control point to a record in the
+//!    output buffer, and the first one writes the per-patch outputs and the tessellation levels to the record of the
+//!    patch. The levels are also written to the tessellation factor buffer, in the half precision layout Metal expects.
+//! 3. The tessellation evaluation stage is translated to a post-tessellation vertex function, which loads its inputs
+//!    from the records of its patch.
+//!
+//! Control point records are laid out like the records of an emulated geometry stage. Patch records hold the outer
+//! tessellation levels in their first 4 slots and the inner ones in the 2 slots after that, followed by the
+//! user-defined per-patch outputs of the control stage.
+
+use spirv_cross2::spirv::{
+    BuiltIn, ExecutionMode, ExecutionModel, MemorySemantics, Op, Scope, StorageClass,
+};
+
+use super::{
+    default_block::UniformKind,
+    geometry::{
+        self, BUILTIN_SLOTS, Generator, IN_PRIMITIVES_START, IN_RECORDS_START, IN_VERTEX_SPAN,
+        INPUT_BINDING, OUTPUT_BINDING, RecordLayout, StageVariable, VariableKind,
+    },
+    spirv::{SpirvModule, Type},
+};
+use crate::{gl_enums::PrimitiveType, render::PrepassDraw, shader::spirv_string};
+
+/// Binding of the tessellation factor buffer in the discrete descriptor set
+pub(crate) const FACTOR_BINDING: u32 = 2;
+
+/// Words of the header of the output buffer. The control point records follow it, then the patch records
+const OUT_PATCH_COUNT: u32 = 0;
+const OUT_PATCH_VERTICES: u32 = 1;
+const OUT_FACTOR_WORDS: u32 = 2;
+const OUT_CONTROL_RECORDS_START: u32 = 3;
+const OUT_PATCH_RECORDS_START: u32 = 4;
+const OUT_HEADER_WORDS: u32 = 5;
+
+/// Slots at the start of each patch record that are taken up by the tessellation levels
+const PATCH_BUILTIN_SLOTS: u32 = 6;
+
+/// `GLSL.std.450` instruction that packs the components of a vec2 into a uint as halves
+const PACK_HALF_2X16: u32 = 58;
+
+/// Value of `GL_MAX_PATCH_VERTICES`
+pub(crate) const MAX_PATCH_VERTICES: u32 = 32;
+/// Value of `GL_MAX_TESS_GEN_LEVEL`, the largest tessellation factor Metal supports
+pub(crate) const MAX_TESS_GEN_LEVEL: u32 = 64;
+/// Value of `GL_MAX_TESS_CONTROL_INPUT_COMPONENTS`
+pub(crate) const MAX_CONTROL_INPUT_COMPONENTS: u32 = 128;
+/// Value of `GL_MAX_TESS_CONTROL_OUTPUT_COMPONENTS`
+pub(crate) const MAX_CONTROL_OUTPUT_COMPONENTS: u32 = 128;
+/// Value of `GL_MAX_TESS_CONTROL_TOTAL_OUTPUT_COMPONENTS`
+pub(crate) const MAX_CONTROL_TOTAL_OUTPUT_COMPONENTS: u32 = 4096;
+/// Value of `GL_MAX_TESS_PATCH_COMPONENTS`
+pub(crate) const MAX_PATCH_COMPONENTS: u32 = 120;
+/// Value of `GL_MAX_TESS_EVALUATION_INPUT_COMPONENTS`
+pub(crate) const MAX_EVALUATION_INPUT_COMPONENTS: u32 = 128;
+/// Value of `GL_MAX_TESS_EVALUATION_OUTPUT_COMPONENTS`
+pub(crate) const MAX_EVALUATION_OUTPUT_COMPONENTS: u32 = 128;
+
+/// Spacing of the vertices generated along the edges of a patch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TessellationSpacing {
+    Equal,
+    FractionalEven,
+    FractionalOdd,
+}
+
+/// Execution modes of a tessellation evaluation stage that configure the tessellator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TessellationInfo {
+    /// `TRIANGLES` or `QUADS`
+    pub(crate) domain: PrimitiveType,
+    pub(crate) spacing: TessellationSpacing,
+    /// Whether the generated triangles are in clockwise order in the (lower left origin) GL domain
+    pub(crate) clockwise: bool,
+}
+impl TessellationInfo {
+    /// Parse the execution modes of the tessellation stages of a program, which may be declared by either of them
+    pub(crate) fn parse(control: &SpirvModule, evaluation: &SpirvModule) -> Result<Self, Box<str>> {
+        let (mut domain, mut spacing, mut clockwise) = (None, TessellationSpacing::Equal, false);
+        for inst in control.instructions.iter().chain(&evaluation.instructions) {
+            if inst.op() != Some(Op::ExecutionMode) {
+                continue;
+            }
+            match ExecutionMode::from_u32(inst.operands[1]) {
+                Some(ExecutionMode::Triangles) => domain = Some(PrimitiveType::Triangles),
+                Some(ExecutionMode::Quads) => domain = Some(PrimitiveType::Quads),
+                Some(ExecutionMode::Isolines) => {
+                    return Err("isoline tessellation is not supported by Metal".into());
+                }
+                Some(ExecutionMode::PointMode) => {
+                    return Err("point mode tessellation is not supported by Metal".into());
+                }
+                Some(ExecutionMode::SpacingEqual) => spacing = TessellationSpacing::Equal,
+                Some(ExecutionMode::SpacingFractionalEven) => {
+                    spacing = TessellationSpacing::FractionalEven;
+                }
+                Some(ExecutionMode::SpacingFractionalOdd) => {
+                    spacing = TessellationSpacing::FractionalOdd;
+                }
+                Some(ExecutionMode::VertexOrderCw) => clockwise = true,
+                Some(ExecutionMode::VertexOrderCcw) => clockwise = false,
+                _ => {}
+            }
+        }
+        Ok(Self {
+            domain: domain
+                .ok_or("tessellation evaluation shader does not declare a primitive mode")?,
+            spacing,
+            clockwise,
+        })
+    }
+    /// Number of words the tessellation factors of a patch take up in the factor buffer
+    pub(crate) fn factor_words(&self) -> u32 {
+        // MTLTriangleTessellationFactorsHalf and MTLQuadTessellationFactorsHalf
+        if self.domain == PrimitiveType::Triangles {
+            2
+        } else {
+            3
+        }
+    }
+}
+
+/// Split `count` vertices into patches of `patch_vertices` vertices, dropping the incomplete patch at the end
+pub(crate) fn assemble_patches(count: u32, patch_vertices: u32) -> Vec<u32> {
+    (0..count / patch_vertices * patch_vertices).collect()
+}
+
+/// Number of output control points of a tessellation control stage
+fn output_vertices(module: &SpirvModule) -> Result<u32, Box<str>> {
+    let vertices = module
+        .instructions
+        .iter()
+        .find(|i| {
+            i.op() == Some(Op::ExecutionMode)
+                && i.operands[1] == ExecutionMode::OutputVertices as u32
+        })
+        .map(|i| i.operands[2])
+        .ok_or("tessellation control shader does not declare its output vertex count")?;
+    if !(1..=MAX_PATCH_VERTICES).contains(&vertices) {
+        return Err(format!(
+            "tessellation control shader output vertex count of {vertices} exceeds GL_MAX_PATCH_VERTICES ({MAX_PATCH_VERTICES})"
+        )
+        .into());
+    }
+    Ok(vertices)
+}
+
+/// Layouts of the records a tessellation control stage reads and writes
+#[derive(Debug, Clone)]
+pub struct TessellationLayout {
+    /// Number of control points in each patch written by the control stage
+    pub(crate) output_vertices: u32,
+    /// Records of the vertices of the draw, as written by the vertex stage
+    pub(crate) vertices: RecordLayout,
+    pub(crate) control_points: RecordLayout,
+    pub(crate) patches: RecordLayout,
+}
+impl TessellationLayout {
+    pub(crate) fn new(control: &SpirvModule) -> Result<Self, Box<str>> {
+        let (patch_outputs, vertex_outputs): (Vec<_>, Vec<_>) =
+            StageVariable::collect(control, StorageClass::Output, true)
+                .into_iter()
+                .partition(|v| v.patch);
+        Ok(Self {
+            output_vertices: output_vertices(control)?,
+            vertices: RecordLayout::new(
+                control,
+                &StageVariable::collect(control, StorageClass::Input, true),
+                BUILTIN_SLOTS,
+            )?,
+            control_points: RecordLayout::new(control, &vertex_outputs, BUILTIN_SLOTS)?,
+            patches: RecordLayout::new(control, &patch_outputs, PATCH_BUILTIN_SLOTS)?,
+        })
+    }
+    /// Initial contents of the buffers of a draw of patches of `patch_vertices` vertices, and their sizes in words
+    pub(crate) fn buffers(
+        &self,
+        draw: &PrepassDraw,
+        info: TessellationInfo,
+        patch_vertices: u32,
+    ) -> TessellationBuffers {
+        let (input, input_len) = geometry::input_buffer(draw, &self.vertices);
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "draws have less than u32::MAX vertices"
+        )]
+        let patches = draw.primitives.len() as u32 / patch_vertices;
+        let total = patches * draw.instance_count;
+        let patch_records =
+            OUT_HEADER_WORDS + total * self.output_vertices * self.control_points.stride;
+        let mut output = [0; OUT_HEADER_WORDS as usize];
+        output[OUT_PATCH_COUNT as usize] = patches;
+        output[OUT_PATCH_VERTICES as usize] = patch_vertices;
+        output[OUT_FACTOR_WORDS as usize] = info.factor_words();
+        output[OUT_CONTROL_RECORDS_START as usize] = OUT_HEADER_WORDS;
+        output[OUT_PATCH_RECORDS_START as usize] = patch_records;
+        TessellationBuffers {
+            input,
+            input_len,
+            output,
+            output_len: (patch_records + total * self.patches.stride) as usize,
+            factors_len: (total * info.factor_words()) as usize,
+            patches,
+        }
+    }
+}
+
+/// Contents of the buffers of a draw through emulated tessellation stages, as built by
+/// [`TessellationLayout::buffers`]
+#[derive(Debug)]
+pub(crate) struct TessellationBuffers {
+    /// Start of the input buffer, up to the vertex records
+    pub(crate) input: Vec<u32>,
+    pub(crate) input_len: usize,
+    /// Header of the output buffer, the rest of it is written by the control kernel
+    pub(crate) output: [u32; OUT_HEADER_WORDS as usize],
+    pub(crate) output_len: usize,
+    /// Size of the tessellation factor buffer in words
+    pub(crate) factors_len: usize,
+    /// Number of patches of each instance
+    pub(crate) patches: u32,
+}
+
+/// Id of the `GLSL.std.450` extended instruction set import of a module
+fn glsl_std_450(module: &SpirvModule) -> Result<u32, Box<str>> {
+    module
+        .instructions
+        .iter()
+        .find(|i| {
+            i.op() == Some(Op::ExtInstImport) && spirv_string(&i.operands[1..]).0 == "GLSL.std.450"
+        })
+        .map(|i| i.operands[0])
+        .ok_or_else(|| "tessellation control shader does not import GLSL.std.450".into())
+}
+
+/// The builtin variable among `vars`, and its type
+fn builtin_variable(vars: &[StageVariable], builtin: BuiltIn) -> Option<(u32, u32)> {
+    vars.iter()
+        .find(|v| matches!(v.kind, VariableKind::BuiltIn(b) if b == builtin))
+        .map(|v| (v.var, v.ty))
+}
+
+/// Generate the compute kernel that runs a tessellation control stage, with a threadgroup per patch and instance and
+/// a thread per output control point
+pub(crate) fn control_kernel(spirv: &[u32]) -> Result<Box<[u32]>, Box<str>> {
+    let mut g = Generator::new(spirv)?;
+    let layout = TessellationLayout::new(&g.module)?;
+    let glsl = glsl_std_450(&g.module)?;
+    let inputs = StageVariable::collect(&g.module, StorageClass::Input, true);
+    let input_leaves = inputs
+        .iter()
+        .map(|v| Ok((v.var, v.leaves(&g.module, &layout.vertices)?)))
+        .collect::<Result<Vec<_>, Box<str>>>()?;
+    let outputs = StageVariable::collect(&g.module, StorageClass::Output, true);
+    let (mut vertex_outputs, mut patch_outputs) = (Vec::new(), Vec::new());
+    for v in &outputs {
+        if v.patch {
+            patch_outputs.push((v.var, v.leaves(&g.module, &layout.patches)?));
+        } else {
+            vertex_outputs.push((v.var, v.leaves(&g.module, &layout.control_points)?));
+        }
+    }
+    let levels = [
+        builtin_variable(&outputs, BuiltIn::TessLevelOuter),
+        builtin_variable(&outputs, BuiltIn::TessLevelInner),
+    ];
+    let builtins = [
+        BuiltIn::InvocationId,
+        BuiltIn::PrimitiveId,
+        BuiltIn::PatchVertices,
+    ]
+    .map(|b| builtin_variable(&inputs, b));
+    g.privatize(&[StorageClass::Input]);
+    g.retarget(&[StorageClass::Output], StorageClass::Workgroup);
+    // barriers only let the invocations of a patch read each other's outputs if they make threadgroup memory visible
+    let workgroup = g.uint(Scope::Workgroup as u32);
+    let semantics =
+        g.uint((MemorySemantics::ACQUIRE_RELEASE | MemorySemantics::WORKGROUP_MEMORY).bits());
+    for inst in &mut g.module.instructions {
+        if inst.op() == Some(Op::ControlBarrier) {
+            inst.operands[1] = workgroup;
+            inst.operands[2] = semantics;
+        }
+    }
+
+    let uint = g.uint_type();
+    let uvec3 = g.find_or_declare(Type::Vector(uint, 3));
+    let local_id = g.builtin_input(BuiltIn::LocalInvocationId, uvec3, "gl_LocalInvocationID");
+    let group_id = g.builtin_input(BuiltIn::WorkgroupId, uvec3, "gl_WorkGroupID");
+    let counter = g.variable(StorageClass::Private, uint, "oxidegl_patch_vertex");
+
+    let main = g.main;
+    let entry = g.begin_function("oxidegl_control_kernel");
+    let local = g.op(Op::Load, uvec3, &[local_id]);
+    let invocation = g.op(Op::CompositeExtract, uint, &[local, 0]);
+    let group = g.op(Op::Load, uvec3, &[group_id]);
+    let [patch, instance] = [0, 1].map(|i| g.op(Op::CompositeExtract, uint, &[group, i]));
+    let span = g.header(INPUT_BINDING, IN_VERTEX_SPAN);
+    let instance_offset = g.uint_op(Op::IMul, instance, span);
+    let patch_vertices = g.header(OUTPUT_BINDING, OUT_PATCH_VERTICES);
+    let first = g.uint_op(Op::IMul, patch, patch_vertices);
+    let primitives_start = g.uint(IN_PRIMITIVES_START);
+    let first = g.uint_op(Op::IAdd, first, primitives_start);
+    g.for_range(counter, patch_vertices, |g, k| {
+        let index = g.uint_op(Op::IAdd, first, k);
+        let vertex = g.load_word(INPUT_BINDING, index);
+        let index = g.uint_op(Op::IAdd, vertex, instance_offset);
+        let base = g.record(
+            INPUT_BINDING,
+            IN_RECORDS_START,
+            index,
+            layout.vertices.stride,
+        );
+        for (var, leaves) in &input_leaves {
+            g.load_leaves(
+                INPUT_BINDING,
+                base,
+                *var,
+                StorageClass::Private,
+                &[k],
+                leaves,
+            );
+        }
+    });
+    for (builtin, value) in builtins
+        .into_iter()
+        .zip([invocation, patch, patch_vertices])
+    {
+        if let Some((var, ty)) = builtin {
+            let value = g.bitcast(value, uint, ty);
+            g.push(Op::Store, vec![var, value]);
+        }
+    }
+    g.call(main);
+    // wait for all invocations of the patch to write their outputs
+    g.push(Op::ControlBarrier, vec![workgroup, workgroup, semantics]);
+
+    let patches = g.header(OUTPUT_BINDING, OUT_PATCH_COUNT);
+    let patch_index = g.uint_op(Op::IMul, instance, patches);
+    let patch_index = g.uint_op(Op::IAdd, patch_index, patch);
+    let vertices = g.uint(layout.output_vertices);
+    let control_index = g.uint_op(Op::IMul, patch_index, vertices);
+    let control_index = g.uint_op(Op::IAdd, control_index, invocation);
+    let base = g.record(
+        OUTPUT_BINDING,
+        OUT_CONTROL_RECORDS_START,
+        control_index,
+        layout.control_points.stride,
+    );
+    for (var, leaves) in &vertex_outputs {
+        g.store_leaves(
+            OUTPUT_BINDING,
+            base,
+            *var,
+            StorageClass::Workgroup,
+            &[invocation],
+            leaves,
+        );
+    }
+    let bool_type = g.find_or_declare(Type::Bool);
+    let zero = g.uint(0);
+    let first_invocation = g.op(Op::IEqual, bool_type, &[invocation, zero]);
+    g.if_then(first_invocation, |g| {
+        let base = g.record(
+            OUTPUT_BINDING,
+            OUT_PATCH_RECORDS_START,
+            patch_index,
+            layout.patches.stride,
+        );
+        for (var, leaves) in &patch_outputs {
+            g.store_leaves(
+                OUTPUT_BINDING,
+                base,
+                *var,
+                StorageClass::Workgroup,
+                &[],
+                leaves,
+            );
+        }
+        store_factors(g, glsl, patch_index, levels);
+    });
+    g.end_function();
+    Ok(g.finish(
+        ExecutionModel::GLCompute,
+        entry,
+        &[local_id, group_id],
+        &[&[
+            ExecutionMode::LocalSize as u32,
+            layout.output_vertices,
+            1,
+            1,
+        ]],
+    ))
+}
+
+/// Write the tessellation levels of a patch to the factor buffer, as a `MTLTriangleTessellationFactorsHalf` or a
+/// `MTLQuadTessellationFactorsHalf` depending on the factor word count in the header of the output buffer
+fn store_factors(
+    g: &mut Generator,
+    glsl: u32,
+    patch: u32,
+    [outer, inner]: [Option<(u32, u32)>; 2],
+) {
+    let bool_type = g.find_or_declare(Type::Bool);
+    let uint = g.uint_type();
+    let float = g.find_or_declare(Type::Scalar(UniformKind::Float, 32));
+    let vec2 = g.find_or_declare(Type::Vector(float, 2));
+    let float_pointer = g.pointer_type(StorageClass::Workgroup, float);
+    // levels the control stage doesn't write are 0, which culls the patch
+    let level = |g: &mut Generator, var: Option<(u32, u32)>, i: u32| match var {
+        Some((var, _)) => {
+            let index = g.uint(i);
+            let pointer = g.op(Op::AccessChain, float_pointer, &[var, index]);
+            g.op(Op::Load, float, &[pointer])
+        }
+        None => {
+            let zero = g.uint(0);
+            g.bitcast(zero, uint, float)
+        }
+    };
+    let outer = [0, 1, 2, 3].map(|i| level(g, outer, i));
+    let inner = [0, 1].map(|i| level(g, inner, i));
+    let pack = |g: &mut Generator, a: u32, b: u32| {
+        let v = g.op(Op::CompositeConstruct, vec2, &[a, b]);
+        g.op(Op::ExtInst, uint, &[glsl, PACK_HALF_2X16, v])
+    };
+    let words = g.header(OUTPUT_BINDING, OUT_FACTOR_WORDS);
+    let base = g.uint_op(Op::IMul, patch, words);
+    let edges = pack(g, outer[0], outer[1]);
+    g.store_word(FACTOR_BINDING, base, edges);
+    let two = g.uint(2);
+    let triangles = g.op(Op::IEqual, bool_type, &[words, two]);
+    let triangle_word = pack(g, outer[2], inner[0]);
+    let quad_word = pack(g, outer[2], outer[3]);
+    let second = g.op(Op::Select, uint, &[triangles, triangle_word, quad_word]);
+    let one = g.uint(1);
+    let index = g.uint_op(Op::IAdd, base, one);
+    g.store_word(FACTOR_BINDING, index, second);
+    let quads = g.op(Op::LogicalNot, bool_type, &[triangles]);
+    g.if_then(quads, |g| {
+        let inside = pack(g, inner[0], inner[1]);
+        let index = g.uint_op(Op::IAdd, base, two);
+        g.store_word(FACTOR_BINDING, index, inside);
+    });
+}
+
+/// Generate the post-tessellation vertex stage that runs a tessellation evaluation stage, loading its inputs from the
+/// records written by the control kernel generated from the control stage `layout` was built from
+pub(crate) fn evaluation_stage(
+    spirv: &[u32],
+    layout: &TessellationLayout,
+) -> Result<Box<[u32]>, Box<str>> {
+    let mut g = Generator::new(spirv)?;
+    let inputs = StageVariable::collect(&g.module, StorageClass::Input, true);
+    let (mut vertex_inputs, mut patch_inputs) = (Vec::new(), Vec::new());
+    for v in &inputs {
+        if v.patch {
+            patch_inputs.push((v.var, v.leaves(&g.module, &layout.patches)?));
+        } else {
+            vertex_inputs.push((v.var, v.leaves(&g.module, &layout.control_points)?));
+        }
+    }
+    let [tess_coord, primitive_id, patch_vertices] = [
+        BuiltIn::TessCoord,
+        BuiltIn::PrimitiveId,
+        BuiltIn::PatchVertices,
+    ]
+    .map(|b| builtin_variable(&inputs, b));
+    // the execution modes configure the tessellator, so they carry over to the generated stage
+    let modes = g
+        .module
+        .instructions
+        .iter()
+        .filter(|i| i.op() == Some(Op::ExecutionMode))
+        .map(|i| i.operands[1..].to_vec())
+        .collect::<Vec<_>>();
+    let mut interface = g.interface();
+    let privatized = g.privatize(&[StorageClass::Input]);
+    interface.retain(|v| !privatized.contains(v));
+
+    let int = g.find_or_declare(Type::Scalar(UniformKind::Int, 32));
+    let uint = g.uint_type();
+    let float = g.find_or_declare(Type::Scalar(UniformKind::Float, 32));
+    let vec3 = g.find_or_declare(Type::Vector(float, 3));
+    let coord_input = g.builtin_input(BuiltIn::TessCoord, vec3, "gl_TessCoord");
+    let patch_input = g.builtin_input(BuiltIn::PrimitiveId, int, "gl_PrimitiveID");
+    interface.extend([coord_input, patch_input]);
+    let counter = g.variable(StorageClass::Private, uint, "oxidegl_patch_vertex");
+
+    let main = g.main;
+    let entry = g.begin_function("oxidegl_evaluation_main");
+    // patches of all instances are drawn as a single instance, in the order the control kernel wrote them
+    let patch = g.op(Op::Load, int, &[patch_input]);
+    let patch = g.bitcast(patch, int, uint);
+    if let Some((var, _)) = tess_coord {
+        let coord = g.op(Op::Load, vec3, &[coord_input]);
+        g.push(Op::Store, vec![var, coord]);
+    }
+    if let Some((var, ty)) = primitive_id {
+        let patches = g.header(OUTPUT_BINDING, OUT_PATCH_COUNT);
+        let id = g.uint_op(Op::UMod, patch, patches);
+        let id = g.bitcast(id, uint, ty);
+        g.push(Op::Store, vec![var, id]);
+    }
+    let vertices = g.uint(layout.output_vertices);
+    if let Some((var, ty)) = patch_vertices {
+        let count = g.bitcast(vertices, uint, ty);
+        g.push(Op::Store, vec![var, count]);
+    }
+    let first = g.uint_op(Op::IMul, patch, vertices);
+    g.for_range(counter, vertices, |g, k| {
+        let index = g.uint_op(Op::IAdd, first, k);
+        let base = g.record(
+            OUTPUT_BINDING,
+            OUT_CONTROL_RECORDS_START,
+            index,
+            layout.control_points.stride,
+        );
+        for (var, leaves) in &vertex_inputs {
+            g.load_leaves(
+                OUTPUT_BINDING,
+                base,
+                *var,
+                StorageClass::Private,
+                &[k],
+                leaves,
+            );
+        }
+    });
+    let base = g.record(
+        OUTPUT_BINDING,
+        OUT_PATCH_RECORDS_START,
+        patch,
+        layout.patches.stride,
+    );
+    for (var, leaves) in &patch_inputs {
+        g.load_leaves(
+            OUTPUT_BINDING,
+            base,
+            *var,
+            StorageClass::Private,
+            &[],
+            leaves,
+        );
+    }
+    g.call(main);
+    g.end_function();
+    let modes = modes.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    Ok(g.finish(
+        ExecutionModel::TessellationEvaluation,
+        entry,
+        &interface,
+        &modes,
+    ))
+}
+
+#[test]
+fn tessellation_patch_assembly() {
+    assert_eq!(assemble_patches(7, 3), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(assemble_patches(2, 3), [0; 0]);
+    assert_eq!(assemble_patches(4, 4), [0, 1, 2, 3]);
+}
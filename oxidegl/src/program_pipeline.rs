@@ -0,0 +1,227 @@
+use crate::{
+    context::state::GlState,
+    debug::gl_err,
+    error::{GlError, GlFallible},
+    gl_enums::{ShaderType, UseProgramStageMask},
+    gl_object::{LateInit, NamedObject, NamedObjectList, ObjectName},
+    program::{LinkedProgram, Program},
+};
+
+/// The stages a program pipeline has a program for, in the order they run in
+pub(crate) const PIPELINE_STAGES: [ShaderType; 6] = [
+    ShaderType::VertexShader,
+    ShaderType::TessControlShader,
+    ShaderType::TessEvaluationShader,
+    ShaderType::GeometryShader,
+    ShaderType::FragmentShader,
+    ShaderType::ComputeShader,
+];
+/// The bit of `glUseProgramStages`' stage mask that selects each of [`PIPELINE_STAGES`]
+const STAGE_BITS: [UseProgramStageMask; 6] = [
+    UseProgramStageMask::VERTEX_SHADER_BIT,
+    UseProgramStageMask::TESS_CONTROL_SHADER_BIT,
+    UseProgramStageMask::TESS_EVALUATION_SHADER_BIT,
+    UseProgramStageMask::GEOMETRY_SHADER_BIT,
+    UseProgramStageMask::FRAGMENT_SHADER_BIT,
+    UseProgramStageMask::COMPUTE_SHADER_BIT,
+];
+
+#[derive(Debug)]
+pub struct ProgramPipeline {
+    pub(crate) name: ObjectName<Self>,
+    /// Program whose executable runs each stage, in the order of [`PIPELINE_STAGES`]
+    pub(crate) stages: [Option<ObjectName<Program>>; PIPELINE_STAGES.len()],
+    /// Program that the `glUniform*` commands modify while this pipeline is current, as set by `glActiveShaderProgram`
+    pub(crate) active_program: Option<ObjectName<Program>>,
+    /// Result of the last validation of this pipeline, until its stages or their programs change
+    pub(crate) validation: Option<Result<(), Box<str>>>,
+    pub(crate) info_log: String,
+}
+impl ProgramPipeline {
+    pub(crate) fn new_default(name: ObjectName<Self>) -> Self {
+        Self {
+            name,
+            stages: [None; PIPELINE_STAGES.len()],
+            active_program: None,
+            validation: None,
+            info_log: String::new(),
+        }
+    }
+    #[inline]
+    pub(crate) fn stage_index(stage: ShaderType) -> usize {
+        PIPELINE_STAGES
+            .iter()
+            .position(|&s| s == stage)
+            .expect("every shader type is a pipeline stage")
+    }
+    /// Program whose executable runs `stage` in this pipeline
+    #[inline]
+    pub(crate) fn stage(&self, stage: ShaderType) -> Option<ObjectName<Program>> {
+        self.stages[Self::stage_index(stage)]
+    }
+    /// Use the executables of `program` for the stages selected by `mask`. Selected stages that `program` has no
+    /// executable for (or all of them, if there is no program) are left without one
+    pub(crate) fn use_stages(
+        &mut self,
+        mask: UseProgramStageMask,
+        program: Option<(ObjectName<Program>, &LinkedProgram)>,
+    ) {
+        for ((slot, stage), bit) in self.stages.iter_mut().zip(PIPELINE_STAGES).zip(STAGE_BITS) {
+            if mask.contains(bit) {
+                *slot = program
+                    .filter(|(_, linkage)| linkage.stage(stage).is_some())
+                    .map(|(name, _)| name);
+            }
+        }
+        self.validation = None;
+    }
+    /// Check whether the executables of this pipeline can be used together, reusing the result of the last check if
+    /// nothing changed since
+    pub(crate) fn validate(
+        &mut self,
+        programs: &mut NamedObjectList<Program>,
+    ) -> Result<(), Box<str>> {
+        if let Some(result) = &self.validation {
+            return result.clone();
+        }
+        let result = self.check(programs);
+        self.validation = Some(result.clone());
+        result
+    }
+    fn check(&self, programs: &mut NamedObjectList<Program>) -> Result<(), Box<str>> {
+        for &name in self.stages.iter().flatten() {
+            let Some(program) = programs.get_opt_mut(name) else {
+                return Err(format!("{name:?} was deleted").into());
+            };
+            program.finish_link();
+        }
+        let linkage =
+            |name: ObjectName<Program>| {
+                programs.get(name).latest_linkage.as_ref().ok_or_else(|| {
+                    format!("{name:?} has no executable, since its last link failed")
+                })
+            };
+        for (i, &name) in self.stages.iter().enumerate() {
+            let Some(name) = name else {
+                continue;
+            };
+            let linked = linkage(name)?;
+            if !linked.separable {
+                return Err(format!("{name:?} was relinked without being separable").into());
+            }
+            // a program has to be used for exactly the stages it has an executable for...
+            for (&stage, &slot) in PIPELINE_STAGES.iter().zip(&self.stages) {
+                match (linked.stage(stage).is_some(), slot == Some(name)) {
+                    (true, false) => {
+                        return Err(format!(
+                            "{name:?} is used for some of its stages, but not for {stage:?}"
+                        )
+                        .into());
+                    }
+                    (false, true) => {
+                        return Err(format!(
+                            "{name:?} was relinked without a {stage:?} executable"
+                        )
+                        .into());
+                    }
+                    _ => {}
+                }
+            }
+            // ...and no other program can run a stage in between two of them
+            let last = self
+                .stages
+                .iter()
+                .rposition(|&s| s == Some(name))
+                .unwrap_or(i);
+            if let Some(other) = self.stages[i..last].iter().flatten().find(|&&s| s != name) {
+                return Err(format!(
+                    "{other:?} is used for a stage in between two stages of {name:?}"
+                )
+                .into());
+            }
+        }
+        let vertex = self.stage(ShaderType::VertexShader);
+        let fragment = self.stage(ShaderType::FragmentShader);
+        if vertex.is_none()
+            && [
+                ShaderType::TessControlShader,
+                ShaderType::TessEvaluationShader,
+                ShaderType::GeometryShader,
+            ]
+            .into_iter()
+            .any(|s| self.stage(s).is_some())
+        {
+            return Err(
+                "the pipeline has tessellation or geometry stages but no vertex stage".into(),
+            );
+        }
+        // the stages of different programs were not matched when they were linked
+        if let (Some(vertex), Some(fragment)) = (vertex, fragment.filter(|&f| Some(f) != vertex)) {
+            let vertex = linkage(vertex)?;
+            let producer = [
+                ShaderType::GeometryShader,
+                ShaderType::TessEvaluationShader,
+                ShaderType::VertexShader,
+            ]
+            .into_iter()
+            .find_map(|s| vertex.stage(s))
+            .expect("checked above");
+            let consumer = linkage(fragment)?
+                .stage(ShaderType::FragmentShader)
+                .expect("checked above");
+            producer.match_interface(consumer)?;
+        }
+        Ok(())
+    }
+}
+impl NamedObject for ProgramPipeline {
+    type LateInitType = LateInit<Self>;
+    const LATE_INIT_FUNC: fn(ObjectName<Self>) -> Self = Self::new_default;
+}
+
+impl GlState {
+    /// Program whose executable runs `stage`: the current program if there is one, otherwise the program of that
+    /// stage in the current program pipeline
+    #[inline]
+    pub(crate) fn stage_program(&self, stage: ShaderType) -> Option<ObjectName<Program>> {
+        match (self.program_binding, self.program_pipeline_binding) {
+            (Some(program), _) => Some(program),
+            (None, Some(pipeline)) => self.program_pipeline_list.get(pipeline).stage(stage),
+            (None, None) => None,
+        }
+    }
+    /// Programs whose executables are currently used for rendering
+    pub(crate) fn current_programs(&self) -> Vec<ObjectName<Program>> {
+        let mut programs = PIPELINE_STAGES
+            .iter()
+            .filter_map(|&s| self.stage_program(s))
+            .collect::<Vec<_>>();
+        programs.dedup();
+        programs
+    }
+    /// Program that the `glUniform*` commands modify: the current program if there is one, otherwise the active
+    /// program of the current program pipeline
+    #[inline]
+    pub(crate) fn uniform_program(&self) -> Option<ObjectName<Program>> {
+        match (self.program_binding, self.program_pipeline_binding) {
+            (Some(program), _) => Some(program),
+            (None, Some(pipeline)) => self.program_pipeline_list.get(pipeline).active_program,
+            (None, None) => None,
+        }
+    }
+    /// Check that the current program pipeline can be drawn with, if it is used for rendering
+    pub(crate) fn validate_current_pipeline(&mut self) -> GlFallible {
+        let (None, Some(pipeline)) = (self.program_binding, self.program_pipeline_binding) else {
+            return Ok(());
+        };
+        if let Err(e) = self
+            .program_pipeline_list
+            .get_mut(pipeline)
+            .validate(&mut self.program_list)
+        {
+            gl_err!(src: Api, ty: Error, "tried to draw with invalid program pipeline {pipeline:?}: {e}");
+            return Err(GlError::InvalidOperation.e());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{
+    context::state::GlState,
+    gl_enums::QueryTarget,
+    gl_object::{LateInit, NamedObject, ObjectName},
+};
+
+#[derive(Debug)]
+pub struct Query {
+    pub(crate) name: ObjectName<Self>,
+    /// Target the query was created for or first begun on (None: the query hasn't been begun yet)
+    pub(crate) target: Option<QueryTarget>,
+    /// Result of the last time the query was begun, which stays available until the query is begun again
+    pub(crate) result: u64,
+}
+impl Query {
+    pub(crate) fn new_default(name: ObjectName<Self>) -> Self {
+        Self {
+            name,
+            target: None,
+            result: 0,
+        }
+    }
+    pub(crate) fn new_with_target(name: ObjectName<Self>, target: QueryTarget) -> Self {
+        Self {
+            target: Some(target),
+            ..Self::new_default(name)
+        }
+    }
+}
+impl NamedObject for Query {
+    type LateInitType = LateInit<Self>;
+    const LATE_INIT_FUNC: fn(ObjectName<Self>) -> Self = Self::new_default;
+}
+
+/// A query between its `glBeginQuery` and `glEndQuery`
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ActiveQuery {
+    pub(crate) target: QueryTarget,
+    /// Vertex stream (or other index) the query was begun on with `glBeginQueryIndexed`
+    pub(crate) index: u32,
+    pub(crate) query: ObjectName<Query>,
+}
+
+impl GlState {
+    /// Add `count` to the results of the active queries of `target` on index `index`
+    pub(crate) fn count_queries(&mut self, target: QueryTarget, index: u32, count: u64) {
+        for active in &self.active_queries {
+            if active.target == target && active.index == index {
+                let query = self.query_list.get_mut(active.query);
+                query.result = match target {
+                    // boolean queries only record whether anything was counted
+                    QueryTarget::TransformFeedbackOverflow => u64::from(query.result + count > 0),
+                    _ => query.result + count,
+                };
+            }
+        }
+    }
+}
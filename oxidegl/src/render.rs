@@ -1,117 +1,502 @@
-use std::mem;
+use std::{any::Any, borrow::Cow, ffi::c_void, fmt::Debug, ptr::NonNull, sync::Arc};
 
 use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
-use log::{info, trace};
+#[cfg(target_vendor = "apple")]
 use objc2::rc::Retained;
+#[cfg(target_vendor = "apple")]
 use objc2_app_kit::NSView;
-use objc2_foundation::{NSCopying, NSString, ns_string};
-use objc2_metal::{
-    MTLBlitCommandEncoder, MTLColorWriteMask, MTLCommandBuffer, MTLCommandBufferDescriptor,
-    MTLCommandBufferErrorOption, MTLCommandEncoder, MTLCommandQueue, MTLCompareFunction,
-    MTLCreateSystemDefaultDevice, MTLCullMode, MTLDepthStencilDescriptor, MTLDevice,
-    MTLPixelFormat, MTLRenderCommandEncoder, MTLRenderPassColorAttachmentDescriptor,
-    MTLRenderPassDepthAttachmentDescriptor, MTLRenderPassDescriptor,
-    MTLRenderPassStencilAttachmentDescriptor, MTLRenderPipelineColorAttachmentDescriptor,
-    MTLRenderPipelineDescriptor, MTLRenderPipelineState, MTLStencilDescriptor, MTLStencilOperation,
-    MTLStorageMode, MTLTexture, MTLTextureDescriptor, MTLTextureUsage,
-    MTLVertexAttributeDescriptor, MTLVertexBufferLayoutDescriptor, MTLVertexDescriptor,
-    MTLViewport,
-};
-use objc2_quartz_core::{CAMetalDrawable, CAMetalLayer, kCAFilterNearest};
+#[cfg(target_vendor = "apple")]
+use objc2_foundation::NSString;
+#[cfg(target_vendor = "apple")]
+use objc2_metal::{MTLBuffer, MTLFunction, MTLLibrary, MTLResource};
 
+#[cfg(target_vendor = "apple")]
+use crate::util::ProtoObjRef;
 use crate::{
     commands::buffer::Buffer,
     context::{
         Context,
-        state::{Capabilities, ColorWriteMask, DrawbufferBlendState, GlState, StencilFaceState},
+        state::{Capabilities, ClipControl, DrawbufferBlendState, GlState, StencilFaceState},
     },
-    debug::{gl_debug, gl_trace},
-    device_properties::MetalProperties,
-    framebuffer::InternalDrawable,
+    debug::{gl_trace, gl_warn},
+    framebuffer::MAX_COLOR_ATTACHMENTS,
     gl_enums::{
-        DepthFunction, DrawBufferMode, ShaderType, StencilFunction, StencilOp, TriangleFace,
+        BufferAccess, ClipControlOrigin, DepthFunction, DrawBufferMode, DrawElementsType,
+        InternalFormat, MemoryBarrierMask, PrimitiveType, QueryTarget, ShaderType, TriangleFace,
     },
     gl_object::{NamedObject, ObjectName},
-    program::LinkedStage,
-    util::{ProtoObjRef, bitflag_bits},
+    program::{
+        LinkedCompute, LinkedFeedback, LinkedGeometry, LinkedProgram, LinkedStage,
+        LinkedTessellation,
+        atomic_counters::MAX_STAGE_ATOMIC_COUNTER_BUFFERS,
+        attributes::{AttributeConversion, FetchStages},
+        feedback, geometry,
+        tessellation::{self, TessellationInfo},
+    },
+    texture::image,
+    util::bitflag_bits,
 };
 
-#[derive(Debug)]
-pub struct Renderer {
-    /// Tracks invalidation (caused by GL state changes) of the various pieces of Metal rendering state
-    pub(crate) dirty_state: Dirty,
-
-    /// the `NSView` this context is associated with
-    view: Option<Retained<NSView>>,
-
-    /// Metal device
-    pub(crate) device: ProtoObjRef<dyn MTLDevice>,
-
-    /// Device and Metal version properties
-    pub(crate) props: MetalProperties,
-
-    /// Metal command queue
-    pub(crate) queue: ProtoObjRef<dyn MTLCommandQueue>,
-
-    /// Metal layer for rendering
-    pub(crate) layer: Retained<CAMetalLayer>,
+mod formats;
+pub mod headless;
+#[cfg(target_vendor = "apple")]
+pub(crate) mod metal;
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_wrap
+)]
+pub(crate) mod software;
+
+pub use formats::{PixelFormat, VertexFormat};
+use headless::RecordedCommand;
+
+/// Interface between the GL state translation done by [`Renderer`] and the API that actually carries out the resulting work.
+/// Every operation is described in terms of plain data derived from the GL state, backends are responsible for lowering it
+/// to their own objects.
+pub(crate) trait RenderBackend: Debug + Any {
+    /// "guesstimate" of the dimensions of the default framebuffer
+    fn target_defaultfb_dims(&mut self) -> (u32, u32);
 
-    /// Current drawable
-    // TODO: see if it's really necessary to have this as a field as opposed to generating it on-the-fly
-    pub(crate) drawable: Option<ProtoObjRef<dyn CAMetalDrawable>>,
+    /// Pixel format of the default framebuffer
+    fn pixel_format(&self) -> PixelFormat;
+    /// Depth buffer format of the default framebuffer
+    fn depth_format(&self) -> Option<PixelFormat>;
+
+    /// Allocate `size` bytes of backing storage for a buffer object, initializing it from `data` if present
+    /// # Safety
+    /// if `data` is `Some`, it must point to at least `size` initialized bytes
+    unsafe fn new_buffer_storage(
+        &self,
+        size: usize,
+        data: Option<NonNull<c_void>>,
+    ) -> BufferStorage;
+    /// Handle that creates the entry points of linked shader stages, which is sent to the compile pool threads
+    fn stage_compiler(&self) -> Arc<dyn StageCompiler>;
+
+    /// Whether this backend currently has a render encoder open
+    fn has_render_encoder(&self) -> bool;
+    /// End the current render or compute encoder (if any) and begin a new render encoder targeting the given attachments
+    fn new_render_encoder(&mut self, state: &GlState, desc: &RenderPassDescriptor);
+    /// End the current render or compute encoder (if any)
+    fn end_encoding(&mut self);
+    /// Update the dynamic state of the current render encoder
+    fn update_render_encoder(&mut self, encoder_state: &EncoderState);
+    /// Build a new render pipeline and make it the current pipeline
+    fn new_render_pipeline(&mut self, state: &GlState, desc: &RenderPipelineDescriptor);
+    /// Attach the current render pipeline to the current render encoder
+    fn bind_render_pipeline(&mut self);
+    /// Bind a buffer to an index of a shader stage's argument table. The arguments of the compute stage are bound to
+    /// the current compute encoder, the ones of the other stages to the current render encoder
+    fn bind_buffer(&mut self, state: &GlState, arg: &BufferArgument);
+    /// Copy a small amount of data into an index of a shader stage's argument table of the current render encoder (or
+    /// compute encoder, for the compute stage)
+    fn set_bytes(&mut self, stage: ShaderType, index: u32, bytes: &[u8]);
+    /// Bind a level of a texture to an index of a shader stage's texture argument table, for loads, stores and atomic
+    /// operations on it by an image uniform
+    fn bind_image(&mut self, state: &GlState, arg: &ImageArgument);
+    /// Encode a draw command into the current render encoder
+    fn draw(&mut self, state: &GlState, call: &DrawCall);
+    /// Encode a draw whose primitives go through the emulated geometry stage of the current program (see
+    /// [`geometry`]): the pre-pass that runs the vertex and geometry stages, then the draw of the emitted primitives
+    fn draw_geometry(&mut self, state: &GlState, draw: &PrepassDraw);
+    /// Encode a draw of patches through the tessellation stages of the current program (see [`tessellation`]): the
+    /// pre-pass that runs the vertex and tessellation control stages, then the draw of the tessellated patches
+    fn draw_tessellation(&mut self, state: &GlState, draw: &PrepassDraw);
+    /// Encode the capture of the transform feedback varyings of the vertices of a draw (see [`feedback`]): the
+    /// pre-pass that runs the vertex stage, then the kernel that writes the captured vertices to the bound buffers
+    fn draw_feedback(&mut self, state: &GlState, draw: &FeedbackDraw);
+    /// Whether this backend currently has a compute encoder open
+    fn has_compute_encoder(&self) -> bool;
+    /// End the current render or compute encoder (if any) and begin a new compute encoder
+    fn new_compute_encoder(&mut self);
+    /// Build a new compute pipeline and make it the current compute pipeline
+    fn new_compute_pipeline(&mut self, state: &GlState, desc: &ComputePipelineDescriptor);
+    /// Attach the current compute pipeline to the current compute encoder
+    fn bind_compute_pipeline(&mut self);
+    /// Encode a dispatch of the current compute pipeline into the current compute encoder
+    fn dispatch(&mut self, state: &GlState, dispatch: &ComputeDispatch);
+    /// Make the shader writes encoded so far visible to the operations selected by `barriers` that are encoded after
+    /// this
+    fn memory_barrier(&mut self, barriers: MemoryBarrierMask);
+    /// Submit all of the work encoded since the last call to `present`, and present the default framebuffer
+    fn present(&mut self);
+
+    fn push_debug_group(&mut self, name: &str);
+    fn pop_debug_group(&mut self);
+
+    /// The list of commands this backend has recorded, if it records them
+    fn recorded_commands(&self) -> Option<&[RecordedCommand]> {
+        None
+    }
+    /// The dimensions and contents of the default framebuffer's color buffer, if it is stored in host memory
+    fn color_buffer(&self) -> Option<((u32, u32), &[[f32; 4]])> {
+        None
+    }
+}
 
-    /// Command buffer for this frame's rendering/compute commands
-    pub(crate) command_buffer: Option<ProtoObjRef<dyn MTLCommandBuffer>>,
+/// Backing storage of a buffer object, as allocated by a [`RenderBackend`]
+#[derive(Debug)]
+pub(crate) enum BufferStorage {
+    #[cfg(target_vendor = "apple")]
+    Metal(ProtoObjRef<dyn MTLBuffer>),
+    Host(Box<[u8]>),
+}
+impl BufferStorage {
+    #[inline]
+    #[cfg_attr(not(target_vendor = "apple"), expect(unused_variables))]
+    pub(crate) fn set_label(&self, label: Option<&str>) {
+        match self {
+            #[cfg(target_vendor = "apple")]
+            BufferStorage::Metal(buf) => buf.setLabel(label.map(NSString::from_str).as_deref()),
+            BufferStorage::Host(_) => {}
+        }
+    }
+    /// Contents of this storage, as seen from the host
+    pub(crate) fn contents(&self) -> &[u8] {
+        match self {
+            #[cfg(target_vendor = "apple")]
+            BufferStorage::Metal(buf) => {
+                // Safety: buffers are allocated with shared storage, so their contents are host accessible for as
+                // long as the buffer is alive
+                unsafe { std::slice::from_raw_parts(buf.contents().as_ptr().cast(), buf.length()) }
+            }
+            BufferStorage::Host(b) => b,
+        }
+    }
+}
+/// Creates the entry points of linked shader stages for a [`RenderBackend`]. Unlike the backend itself, this is used from
+/// the compile pool threads
+pub(crate) trait StageCompiler: Debug + Send + Sync {
+    /// Create the entry point of a linked shader stage from its translated MSL source
+    fn new_stage_function(
+        &self,
+        msl_src: &str,
+        entry_name: &str,
+        label: Option<&str>,
+    ) -> Result<StageFunction, Box<str>>;
+}
+/// Stage compiler for backends that do not execute shaders on the GPU
+#[derive(Debug)]
+pub(crate) struct HostStageCompiler;
+impl StageCompiler for HostStageCompiler {
+    fn new_stage_function(
+        &self,
+        _msl_src: &str,
+        _entry_name: &str,
+        _label: Option<&str>,
+    ) -> Result<StageFunction, Box<str>> {
+        Ok(StageFunction::Host)
+    }
+}
 
-    /// Command buffer used to realize buffer to buffer copies
-    /// This is separate from the primary command buffer because
-    /// we need to realize private to shared/shared to private buffer copies which
-    /// happen before the user calls mapBuffer, which can happen
-    /// at any time, and we don't want to submit partial render commands by accident
-    pub(crate) blit_command_buffer: Option<ProtoObjRef<dyn MTLCommandBuffer>>,
+/// Entry point of a linked shader stage, as created by a [`StageCompiler`]
+#[derive(Debug)]
+pub(crate) enum StageFunction {
+    #[cfg(target_vendor = "apple")]
+    Metal {
+        /// the entry point for this stage
+        function: ProtoObjRef<dyn MTLFunction>,
+        /// a retained reference to the metal library that contains the entry point function for this stage
+        lib: ProtoObjRef<dyn MTLLibrary>,
+    },
+    /// Stage function for backends that do not execute shaders
+    Host,
+}
+// Safety: Metal functions and libraries are immutable after creation and may be used from any thread
+#[cfg(target_vendor = "apple")]
+unsafe impl Send for StageFunction {}
+// Safety: see above
+#[cfg(target_vendor = "apple")]
+unsafe impl Sync for StageFunction {}
+
+/// Attachments of a new render pass
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderPassDescriptor {
+    /// Default framebuffer draw buffer backing each color attachment
+    pub color_attachments: [Option<DrawBufferMode>; MAX_COLOR_ATTACHMENTS as usize],
+    pub depth_attachment: bool,
+    pub stencil_attachment: bool,
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorAttachmentDescriptor {
+    pub pixel_format: PixelFormat,
+    pub blend: DrawbufferBlendState,
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderPipelineDescriptor {
+    /// Name of the entry point of the vertex stage
+    pub vertex_function: Box<str>,
+    /// Name of the entry point of the fragment stage
+    pub fragment_function: Box<str>,
+    pub color_attachments: [Option<ColorAttachmentDescriptor>; MAX_COLOR_ATTACHMENTS as usize],
+    pub depth_attachment_format: Option<PixelFormat>,
+    /// Layout of the vertex attributes. With a geometry or tessellation stage, this describes the input of the vertex
+    /// kernel of its pre-pass instead of the input of the vertex function
+    pub vertex_descriptor: VertexDescriptor,
+    /// Type of the primitives assembled from the output of the geometry stage, if the program has one. The vertex
+    /// function is then the generated pass-through stage that reads its output
+    pub geometry_output: Option<PrimitiveType>,
+    /// Configuration of the tessellator, if the program has tessellation stages. The vertex function is then the
+    /// post-tessellation vertex function that runs the evaluation stage
+    pub tessellation: Option<TessellationInfo>,
+    /// Conversions of the vertex attributes the stage that fetches them has to do. If there are any, that stage is
+    /// replaced by its variant from [`Renderer::fetch_stages`]
+    pub attribute_conversions: Box<[AttributeConversion]>,
+    /// Clip volume the vertex function remaps the positions it writes from (see [`crate::program::clip_control`])
+    pub clip_control: ClipControl,
+    /// Mask of the enabled user clip planes, the vertex function doesn't clip against the others (see
+    /// [`crate::program::clip_control`])
+    pub clip_distances: u8,
+    /// Diameter of rasterized points, or `None` if the vertex function writes it (see [`crate::program::points`])
+    pub point_size: Option<f32>,
+    /// Origin of the point coordinates read by the fragment function
+    pub point_sprite_coord_origin: ClipControlOrigin,
+}
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VertexDescriptor {
+    pub layouts: Vec<VertexBufferLayout>,
+    pub attributes: Vec<VertexAttributeDescriptor>,
+}
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VertexBufferLayout {
+    /// Vertex stage argument table index of the buffer this layout describes
+    pub buffer_index: u32,
+    pub stride: u16,
+}
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VertexAttributeDescriptor {
+    pub attribute_index: u32,
+    /// Vertex stage argument table index of the buffer this attribute is sourced from
+    pub buffer_index: u32,
+    pub format: VertexFormat,
+    pub offset: u16,
+}
+/// A compute pipeline for the compute stage of the current program
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComputePipelineDescriptor {
+    /// Name of the entry point of the compute stage
+    pub function: Box<str>,
+    /// Number of threads of each dimension of a threadgroup, which is the work group size declared by the stage
+    pub threads_per_threadgroup: [u32; 3],
+}
+/// Number of threadgroups launched by a compute dispatch
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComputeDispatch {
+    Direct {
+        groups: [u32; 3],
+    },
+    /// Read from the three `u32`s at `offset` in a buffer when the dispatch runs
+    Indirect {
+        /// GL name of the buffer
+        buffer: u32,
+        offset: usize,
+    },
+}
+/// Dynamic state of a render encoder
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncoderState {
+    /// Depth compare function and depth write enable, if the depth test is enabled
+    pub depth: Option<(DepthFunction, bool)>,
+    /// Front and back stencil face states and write masks, if the stencil test is enabled
+    pub stencil: Option<[(StencilFaceState, u32); 2]>,
+    /// Which faces to cull, if face culling is enabled
+    pub cull_mode: Option<TriangleFace>,
+    pub blend_color: [f32; 4],
+    /// x, y, width, height
+    pub viewport: [u32; 4],
+    /// x, y, width, height of the scissor box, if the scissor test is enabled
+    pub scissor: Option<[u32; 4]>,
+    /// Window depth of the near and far planes of the clip volume
+    pub depth_range: [f64; 2],
+    /// Origin of the clip volume. Positions are flipped vertically with an upper left origin, which flips the winding
+    /// of front facing triangles as well
+    pub clip_origin: ClipControlOrigin,
+}
+/// A buffer bound to an index of a shader stage's argument table
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BufferArgument {
+    pub stage: ShaderType,
+    /// GL name of the bound buffer
+    pub buffer: u32,
+    pub offset: usize,
+    pub index: u32,
+}
+/// The texture of an image unit bound to an index of a shader stage's texture argument table
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImageArgument {
+    pub stage: ShaderType,
+    /// GL name of the bound texture
+    pub texture: u32,
+    pub index: u32,
+    pub level: u32,
+    /// Layer of the level to bind, or `None` to bind all of its layers
+    pub layer: Option<u32>,
+    /// Format the texels of the texture are interpreted as
+    pub format: InternalFormat,
+    /// Whether the stage writes to the image
+    pub write: bool,
+    /// Whether the stage performs atomic operations on the image
+    pub atomic: bool,
+}
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DrawCall {
+    Arrays {
+        mode: PrimitiveType,
+        first: u32,
+        count: u32,
+        instance_count: u32,
+        base_instance: u32,
+    },
+    Elements {
+        mode: PrimitiveType,
+        count: u32,
+        index_type: DrawElementsType,
+        /// GL name of the element array buffer
+        index_buffer: u32,
+        index_offset: usize,
+        instance_count: u32,
+        base_vertex: i32,
+        base_instance: u32,
+    },
+}
+impl DrawCall {
+    /// Vertex ids of the vertices of this draw in order, with the base vertex of indexed draws applied
+    pub(crate) fn vertex_ids(&self, state: &GlState) -> Vec<u32> {
+        match *self {
+            DrawCall::Arrays { first, count, .. } => (first..first + count).collect(),
+            DrawCall::Elements {
+                count,
+                index_type,
+                index_buffer,
+                index_offset,
+                base_vertex,
+                ..
+            } => {
+                let name = ObjectName::from_raw(index_buffer);
+                let Some(allocation) = state.buffer_list.get(name).allocation.as_ref() else {
+                    panic!("tried to use {name:?} before its storage was allocated");
+                };
+                let bytes = &allocation.storage.contents()[index_offset..];
+                (0..count as usize)
+                    .map(|i| {
+                        let idx = match index_type {
+                            DrawElementsType::UnsignedByte => u32::from(bytes[i]),
+                            DrawElementsType::UnsignedShort => {
+                                u32::from(u16::from_le_bytes([bytes[2 * i], bytes[2 * i + 1]]))
+                            }
+                            DrawElementsType::UnsignedInt => {
+                                u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap())
+                            }
+                        };
+                        idx.wrapping_add_signed(base_vertex)
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+/// A draw through a stage that is emulated in a compute pre-pass, with its vertices split into the input primitives
+/// (or patches) of that stage
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrepassDraw {
+    /// Lowest vertex id used by the draw
+    pub vertex_base: u32,
+    /// Number of vertex ids from the lowest to the highest one used by the draw, which the vertex stage runs for
+    pub vertex_span: u32,
+    pub instance_count: u32,
+    pub base_instance: u32,
+    /// Vertices of each input primitive, relative to `vertex_base`
+    pub primitives: Vec<u32>,
+}
+impl PrepassDraw {
+    /// Split the vertices of `call` into primitives with `assemble`, which returns the indices of the vertices of each
+    /// primitive given the primitive mode and vertex count of the draw
+    fn new(
+        state: &GlState,
+        call: &DrawCall,
+        assemble: impl FnOnce(PrimitiveType, u32) -> Vec<u32>,
+    ) -> Self {
+        let (mode, instance_count, base_instance) = match *call {
+            DrawCall::Arrays {
+                mode,
+                instance_count,
+                base_instance,
+                ..
+            }
+            | DrawCall::Elements {
+                mode,
+                instance_count,
+                base_instance,
+                ..
+            } => (mode, instance_count, base_instance),
+        };
+        let ids = call.vertex_ids(state);
+        let vertex_base = ids.iter().copied().min().unwrap_or(0);
+        let vertex_span = ids.iter().max().map_or(0, |&max| max - vertex_base + 1);
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "draws have less than u32::MAX vertices"
+        )]
+        let primitives = assemble(mode, ids.len() as u32)
+            .into_iter()
+            .map(|i| ids[i as usize] - vertex_base)
+            .collect();
+        Self {
+            vertex_base,
+            vertex_span,
+            instance_count,
+            base_instance,
+            primitives,
+        }
+    }
+}
 
-    /// Current encoder for blit commands. We only really need one encoder instance, so
-    /// the procedure is to commit this encoder and immediately submit the `blit_command_buffer`
-    /// upon a buffer copy flush
-    pub(crate) blit_encoder: Option<ProtoObjRef<dyn MTLBlitCommandEncoder>>,
+/// Capture of the transform feedback varyings of the first `vertices` vertices of a draw
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedbackDraw {
+    /// The draw, split into the independent primitives that are captured
+    pub draw: PrepassDraw,
+    /// GL name of the buffer that is written to and the offset of the first vertex written, for each buffer of the
+    /// transform feedback layout of the program
+    pub buffers: Vec<(u32, usize)>,
+    /// Number of vertices captured, which is always a multiple of the vertices of a captured primitive
+    pub vertices: u32,
+}
 
-    /// Current encoder for render commands
-    pub(crate) render_encoder: Option<ProtoObjRef<dyn MTLRenderCommandEncoder>>,
+#[derive(Debug)]
+pub struct Renderer {
+    /// Tracks invalidation (caused by GL state changes) of the various pieces of backend rendering state
+    pub(crate) dirty_state: Dirty,
 
-    /// Current render pipeline state
-    pub(crate) render_pipeline_state: Option<ProtoObjRef<dyn MTLRenderPipelineState>>,
+    /// Backend that executes the rendering work
+    pub(crate) backend: Box<dyn RenderBackend>,
 
-    /// Mapping from buffer name to metal vertex shader argument index
-    pub(crate) vertex_buffer_map: ResourceMap<Buffer, MTL_MAX_ARGUMENT_BINDINGS>,
+    /// Mapping from buffer name to metal vertex shader argument index (the last index is reserved for the default uniform block,
+    /// the three before it for the buffers of the emulated geometry and tessellation stages, and the ones before those
+    /// for the atomic counter buffers of the stage)
+    pub(crate) vertex_buffer_map: ResourceMap<Buffer, BUFFER_MAP_ENTRIES>,
 
     /// Mapping from metal vertex argument table index to vertex descriptor buffer offset
     pub(crate) vertex_buffer_offsets: HashMap<ObjectName<Buffer>, usize>,
 
-    /// Mapping from buffer name to metal fragment shader argument index
-    pub(crate) fragment_buffer_map: ResourceMap<Buffer, MTL_MAX_ARGUMENT_BINDINGS>,
-
-    /// List of internal drawables that back the various bitplanes in the default framebuffer
-    pub(crate) internal_drawables: InternalDrawables,
+    /// Mapping from buffer name to metal geometry kernel argument index (reserves the same indices as the vertex map)
+    pub(crate) geometry_buffer_map: ResourceMap<Buffer, BUFFER_MAP_ENTRIES>,
 
-    /// Pixel format of the default framebuffer
-    pub(crate) pixel_format: MTLPixelFormat,
+    /// Mapping from buffer name to metal tessellation control kernel argument index (reserves the same indices as the
+    /// vertex map)
+    pub(crate) tess_control_buffer_map: ResourceMap<Buffer, BUFFER_MAP_ENTRIES>,
 
-    /// Depth buffer format of the default framebuffer
-    pub(crate) depth_format: Option<MTLPixelFormat>,
+    /// Mapping from buffer name to metal post-tessellation vertex function argument index (reserves the same indices as
+    /// the vertex map)
+    pub(crate) tess_evaluation_buffer_map: ResourceMap<Buffer, BUFFER_MAP_ENTRIES>,
 
-    /// Stencil buffer format of the default framebuffer
-    pub(crate) stencil_format: Option<MTLPixelFormat>,
+    /// Mapping from buffer name to metal fragment shader argument index (reserves the same indices as the vertex map)
+    pub(crate) fragment_buffer_map: ResourceMap<Buffer, BUFFER_MAP_ENTRIES>,
 
-    pub(crate) debug_group_stack: Vec<Retained<NSString>>,
-}
-#[derive(Default, Debug, Clone)]
-pub struct InternalDrawables {
-    front_left: Option<InternalDrawable>,
-    front_right: Option<InternalDrawable>,
-    back_left: Option<InternalDrawable>,
-    back_right: Option<InternalDrawable>,
-    depth: Option<InternalDrawable>,
-    stencil: Option<InternalDrawable>,
+    /// Mapping from buffer name to metal compute kernel argument index (reserves the same indices as the vertex map)
+    pub(crate) compute_buffer_map: ResourceMap<Buffer, BUFFER_MAP_ENTRIES>,
 }
 bitflag_bits! {
     #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -125,6 +510,11 @@ bitflag_bits! {
         NEW_RENDER_PIPELINE: 2,
         /// Update buffer maps (e.g. when a new VAO or program is bound)
         REMAP_BUFFERS: 3,
+        /// Upload the contents of the default uniform blocks of the current program, and bind the textures of the image
+        /// units its image uniforms refer to and the atomic counter buffers its atomic counters are in
+        UPLOAD_UNIFORMS: 4,
+        /// Create a fresh compute pipeline state for the compute stage of the current program
+        NEW_COMPUTE_PIPELINE: 5,
     }
 }
 impl Dirty {
@@ -165,8 +555,32 @@ impl Context {
             .dirty_state
             .set_bits(Dirty::NEW_RENDER_PIPELINE);
     }
+    #[inline]
+    pub(crate) fn upload_uniforms(&mut self) {
+        self.renderer.dirty_state.set_bits(Dirty::UPLOAD_UNIFORMS);
+    }
+    #[inline]
+    pub(crate) fn new_compute_pipeline(&mut self) {
+        self.renderer
+            .dirty_state
+            .set_bits(Dirty::NEW_COMPUTE_PIPELINE);
+    }
 }
 const MTL_MAX_ARGUMENT_BINDINGS: usize = 31;
+/// Argument table index the emulated default uniform block of each stage is bound to
+#[expect(clippy::cast_possible_truncation, reason = "const checked")]
+pub(crate) const DEFAULT_UNIFORM_BLOCK_INDEX: u32 = MTL_MAX_ARGUMENT_BINDINGS as u32 - 1;
+/// Argument table indices of the input and output buffers of the emulated geometry stage
+pub(crate) const GEOMETRY_INPUT_INDEX: u32 = DEFAULT_UNIFORM_BLOCK_INDEX - 1;
+pub(crate) const GEOMETRY_OUTPUT_INDEX: u32 = DEFAULT_UNIFORM_BLOCK_INDEX - 2;
+/// Argument table index of the tessellation factor buffer written by the emulated tessellation control stage
+pub(crate) const TESSELLATION_FACTOR_INDEX: u32 = DEFAULT_UNIFORM_BLOCK_INDEX - 3;
+/// Argument table index of the first atomic counter buffer of each stage. The other atomic counter buffers of the stage
+/// are bound to the indices after it, in binding point order
+pub(crate) const ATOMIC_COUNTER_BUFFER_INDEX: u32 =
+    TESSELLATION_FACTOR_INDEX - MAX_STAGE_ATOMIC_COUNTER_BUFFERS;
+/// Number of argument table indices left for the buffers that are bound by GL commands
+const BUFFER_MAP_ENTRIES: usize = ATOMIC_COUNTER_BUFFER_INDEX as usize;
 
 /// Utility that maps currently active object names to their location in the relevant Metal shader parameter table
 #[derive(Debug)]
@@ -231,176 +645,215 @@ impl<const MAX_ENTRIES: usize, T: NamedObject + 'static> ResourceMap<T, MAX_ENTR
     }
 }
 
-#[allow(clippy::undocumented_unsafe_blocks)]
 impl Renderer {
-    pub(crate) fn set_view(&mut self, view: &Retained<NSView>, backing_scale_factor: f64) {
-        self.view = Some(view.clone());
-        self.layer.setFrame(view.frame());
-        self.layer.setContentsScale(backing_scale_factor);
-        // Tell the OS this view is layer-backed
-        view.setWantsLayer(true);
-        // set the backing layer
-        unsafe { view.setLayer(Some(&self.layer)) };
-
-        trace!("injected layer {:?} into NSView", &self.layer);
-    }
-    pub(crate) fn swap_buffers(&mut self, state: &mut GlState) {
-        self.update_state(state, false);
-
-        self.end_encoding();
-
-        if let Some(drawable) = self.drawable.take() {
-            self.current_command_buffer()
-                .presentDrawable(drawable.as_ref());
-            drop(drawable);
-        }
-        self.current_command_buffer().commit();
-        self.command_buffer = None;
-    }
-    pub(crate) fn push_debug_group(&mut self, group_name: &NSString) {
-        // Try to push the group at encoder granularity if we can first
-        if let Some(ref enc) = self.render_encoder {
-            enc.pushDebugGroup(group_name);
-        }
-        self.current_command_buffer().pushDebugGroup(group_name);
-        self.debug_group_stack.push(group_name.copy());
-    }
-    pub(crate) fn pop_debug_group(&mut self) {
-        if let Some(ref enc) = self.render_encoder {
-            enc.popDebugGroup();
-        }
-        self.current_command_buffer().popDebugGroup();
-        let _ = self.debug_group_stack.pop();
-    }
-    pub(crate) fn new(
-        pixel_format: MTLPixelFormat,
-        depth_format: Option<MTLPixelFormat>,
-        stencil_format: Option<MTLPixelFormat>,
-    ) -> Self {
-        let device = MTLCreateSystemDefaultDevice().unwrap();
-
-        let layer;
-
-        unsafe {
-            layer = CAMetalLayer::new();
-            layer.setPixelFormat(pixel_format);
-            layer.setDevice(Some(&device));
-            layer.setFramebufferOnly(false);
-        };
-        layer.setMagnificationFilter(unsafe { kCAFilterNearest });
-
-        // use `info` instead of `gl_info` because gl logging state might not be initialized yet
-        info!("Metal device: {}", device.name());
-        let queue = device
-            .newCommandQueue()
-            .expect("failed to create command queue");
-        queue.setLabel(Some(ns_string!("OxideGL command queue")));
-
-        let props = MetalProperties::new(&device);
+    pub(crate) fn new(backend: Box<dyn RenderBackend>) -> Self {
         Self {
             dirty_state: Dirty::all(),
-
-            view: None,
-            device,
-            props,
-            queue,
-            layer,
-            drawable: None,
-            command_buffer: None,
-            blit_command_buffer: None,
-            blit_encoder: None,
-
-            internal_drawables: InternalDrawables::default(),
-
-            render_encoder: None,
-            render_pipeline_state: None,
+            backend,
 
             vertex_buffer_map: ResourceMap::new(),
             vertex_buffer_offsets: HashMap::new(),
 
-            fragment_buffer_map: ResourceMap::new(),
+            geometry_buffer_map: ResourceMap::new(),
 
-            pixel_format,
-            depth_format,
-            stencil_format,
+            tess_control_buffer_map: ResourceMap::new(),
+            tess_evaluation_buffer_map: ResourceMap::new(),
 
-            debug_group_stack: Vec::new(),
+            fragment_buffer_map: ResourceMap::new(),
+
+            compute_buffer_map: ResourceMap::new(),
         }
     }
+    /// Attach the Metal backend of this renderer to the given view
+    #[cfg(target_vendor = "apple")]
+    pub(crate) fn set_view(&mut self, view: &Retained<NSView>, backing_scale_factor: f64) {
+        let backend: &mut dyn Any = self.backend.as_mut();
+        backend
+            .downcast_mut::<metal::MetalBackend>()
+            .expect("tried to attach a context that is not backed by Metal to a view")
+            .set_view(view, backing_scale_factor);
+    }
     #[inline]
-    fn new_command_buffer(
-        queue: &ProtoObjRef<dyn MTLCommandQueue>,
-        label: Option<&'static NSString>,
-    ) -> ProtoObjRef<dyn MTLCommandBuffer> {
-        let buf;
+    pub(crate) fn target_defaultfb_dims(&mut self) -> (u32, u32) {
+        self.backend.target_defaultfb_dims()
+    }
+    pub(crate) fn swap_buffers(&mut self, state: &mut GlState) {
+        self.update_state(state, false);
 
-        // use extra metal debugging/validation when debug assertions are enabled
-        #[cfg(debug_assertions)]
-        unsafe {
-            let desc = MTLCommandBufferDescriptor::new();
-            desc.setErrorOptions(MTLCommandBufferErrorOption::EncoderExecutionStatus);
-            buf = queue
-                .commandBufferWithDescriptor(&desc)
-                .expect("failed to create command buffer");
+        self.backend.end_encoding();
+        self.backend.present();
+    }
+    /// Bring the backend state up to date with the GL state, then encode a draw command
+    pub(crate) fn draw(&mut self, state: &mut GlState, call: &DrawCall) {
+        self.update_state(state, true);
+        Self::count_primitives(state, call);
+        if state.transform_feedback().capturing() {
+            self.capture_feedback(state, call);
         }
-        #[cfg(not(debug_assertions))]
-        unsafe {
-            buf = queue
-                .commandBuffer()
-                .expect("failed to create command buffer")
+        if state.caps.is_any_enabled(Capabilities::RASTERIZER_DISCARD) {
+            return;
         }
-        if let Some(v) = label {
-            buf.setLabel(Some(v));
+        if Self::linked_tessellation(state).is_some() {
+            let patch_vertices = state.patch.vertices;
+            let draw = PrepassDraw::new(state, call, |_, count| {
+                tessellation::assemble_patches(count, patch_vertices)
+            });
+            self.backend.draw_tessellation(state, &draw);
+        } else if Self::linked_geometry(state).is_some() {
+            self.backend.draw_geometry(
+                state,
+                &PrepassDraw::new(state, call, geometry::assemble_input),
+            );
+        } else {
+            self.backend.draw(state, call);
         }
-        buf
     }
-    #[inline]
-    fn current_command_buffer(&mut self) -> &ProtoObjRef<dyn MTLCommandBuffer> {
-        self.command_buffer.get_or_insert_with(|| {
-            let cb = Self::new_command_buffer(
-                &self.queue,
-                Some(ns_string!("OxideGL render command buffer")),
-            );
-            for group in &self.debug_group_stack {
-                cb.pushDebugGroup(group);
+    /// Count the primitives of a draw for the active `GL_PRIMITIVES_GENERATED` queries
+    fn count_primitives(state: &mut GlState, call: &DrawCall) {
+        //TODO: count the primitives emitted by geometry and tessellation stages, which are only known to the pre-pass
+        if Self::linked_geometry(state).is_some() || Self::linked_tessellation(state).is_some() {
+            return;
+        }
+        let (mode, count, instance_count) = match *call {
+            DrawCall::Arrays {
+                mode,
+                count,
+                instance_count,
+                ..
             }
-            cb
-        })
+            | DrawCall::Elements {
+                mode,
+                count,
+                instance_count,
+                ..
+            } => (mode, count, instance_count),
+        };
+        let primitives = feedback::primitive_count(mode, count);
+        state.count_queries(
+            QueryTarget::PrimitivesGenerated,
+            0,
+            u64::from(primitives) * u64::from(instance_count),
+        );
     }
-    #[inline]
-    #[track_caller]
-    pub(crate) fn current_render_encoder(&mut self) -> &ProtoObjRef<dyn MTLRenderCommandEncoder> {
-        self.render_encoder
-            .as_ref()
-            .expect("render command encoder should have been created!")
+    /// Capture the varyings of as many primitives of a draw as fit in the remaining space of the transform feedback
+    /// buffers, and count them for the active transform feedback queries
+    fn capture_feedback(&mut self, state: &mut GlState, call: &DrawCall) {
+        let xfb = state.transform_feedback();
+        let active = xfb
+            .active
+            .expect("tried to capture transform feedback without beginning it");
+        let layout = &Self::linked_feedback(state)
+            .expect("program with active transform feedback had no captured varyings")
+            .layout;
+        let draw = PrepassDraw::new(state, call, feedback::assemble);
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "draws have less than u32::MAX vertices"
+        )]
+        let total = draw.primitives.len() as u32 * draw.instance_count;
+        let mut vertices = total;
+        let mut buffers = Vec::with_capacity(layout.strides.len());
+        for (i, &stride) in layout.strides.iter().enumerate() {
+            let stride = stride as usize * 4;
+            let written = active.vertices as usize * stride;
+            // a buffer that was deleted while bound has no space left
+            let (name, offset, size) = match (xfb.buffers[i], xfb.range(state, i)) {
+                (Some(name), Some((offset, size))) => (name.to_raw(), offset, size),
+                _ => (0, 0, 0),
+            };
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "limited by the vertex count of the draw"
+            )]
+            let fit = (size.saturating_sub(written) / stride).min(vertices as usize) as u32;
+            vertices = fit;
+            buffers.push((name, offset + written));
+        }
+        // primitives are never partially written
+        let primitive_vertices = feedback::primitive_vertices(active.primitive_mode);
+        vertices -= vertices % primitive_vertices;
+        if vertices > 0 {
+            self.backend.draw_feedback(
+                state,
+                &FeedbackDraw {
+                    draw,
+                    buffers,
+                    vertices,
+                },
+            );
+        }
+        if let Some(active) = state.transform_feedback_mut().active.as_mut() {
+            active.vertices += vertices;
+        }
+        state.count_queries(
+            QueryTarget::TransformFeedbackPrimitivesWritten,
+            0,
+            u64::from(vertices / primitive_vertices),
+        );
+        if vertices < total {
+            state.count_queries(QueryTarget::TransformFeedbackOverflow, 0, 1);
+        }
     }
-    #[inline]
-    pub(crate) fn end_encoding(&mut self) {
-        if let Some(enc) = &self.render_encoder {
-            enc.endEncoding();
+    /// Bring the compute state of the backend up to date with the GL state, then encode a dispatch of the compute stage
+    /// of the current program. Compute encoders take turns with render encoders on the same command buffer, so
+    /// dispatches stay ordered with the draws around them
+    pub(crate) fn dispatch_compute(&mut self, state: &mut GlState, dispatch: &ComputeDispatch) {
+        for name in state.current_programs() {
+            state.program_list.get_mut(name).finish_link();
+        }
+        let compute =
+            Self::linked_compute(state).expect("tried to dispatch without a linked compute stage");
+
+        // opening a compute encoder ends the render encoder, which the next draw replaces with a new one
+        let new_encoder = !self.backend.has_compute_encoder();
+        if new_encoder {
+            gl_trace!("generating new compute command encoder");
+            self.backend.new_compute_encoder();
+        }
+        let new_pipeline = self.dirty_state.any_set(Dirty::NEW_COMPUTE_PIPELINE);
+        if new_pipeline {
+            gl_trace!("generating new compute pipeline state");
+            self.backend.new_compute_pipeline(
+                state,
+                &ComputePipelineDescriptor {
+                    function: compute.stage.entry_name.clone(),
+                    threads_per_threadgroup: compute.work_group_size,
+                },
+            );
+            self.dirty_state.unset(Dirty::NEW_COMPUTE_PIPELINE);
         }
-        self.render_encoder = None;
+        if new_encoder || new_pipeline {
+            self.backend.bind_compute_pipeline();
+        }
+        // the buffers and uniforms of the compute stage are bound at every dispatch, like the render stages bind
+        // theirs on every new render encoder
+        self.compute_buffer_map
+            .build(&Self::stage_pinned_buffers(state, &compute.stage), &[]);
+        for (&buf, &binding) in &self.compute_buffer_map.inner {
+            gl_trace!("binding {buf:?} to compute argument table index {binding}");
+            self.backend.bind_buffer(
+                state,
+                &BufferArgument {
+                    stage: ShaderType::ComputeShader,
+                    buffer: buf.to_raw(),
+                    offset: 0,
+                    index: binding,
+                },
+            );
+        }
+        self.upload_default_block(state, ShaderType::ComputeShader);
+        self.bind_images(state, ShaderType::ComputeShader);
+        self.bind_atomic_counter_buffers(state, ShaderType::ComputeShader);
+        self.backend.dispatch(state, dispatch);
     }
-    //TODO: use onresized or something for updating drawable size instead of effectively polling every frame
-    #[inline]
-    #[track_caller]
-    pub(crate) fn current_drawable(&mut self) -> &ProtoObjRef<dyn CAMetalDrawable> {
-        self.drawable.get_or_insert_with(|| {
-            let view = self
-                .view
-                .as_ref()
-                .expect("Can't get metal drawable before attaching Context to a view");
-            let maybe_new_size = unsafe { view.convertSizeToBacking(view.frame().size) };
-            if maybe_new_size != unsafe { self.layer.drawableSize() } {
-                unsafe {
-                    self.layer.setDrawableSize(maybe_new_size);
-                };
-                self.dirty_state |= Dirty::NEW_RENDER_ENCODER;
-            }
-
-            unsafe { self.layer.nextDrawable() }
-                .expect("Failed to get next drawable from CAMetalLayer")
-        })
+    pub(crate) fn memory_barrier(&mut self, barriers: MemoryBarrierMask) {
+        self.backend.memory_barrier(barriers);
+    }
+    pub(crate) fn push_debug_group(&mut self, group_name: &str) {
+        self.backend.push_debug_group(group_name);
+    }
+    pub(crate) fn pop_debug_group(&mut self) {
+        self.backend.pop_debug_group();
     }
 
     /// Core function of OpenGL state machine emulation. "steps" the state forward,
@@ -408,10 +861,14 @@ impl Renderer {
     pub(crate) fn update_state(&mut self, state: &mut GlState, is_draw_command: bool) {
         // if we don't currently have an encoder, make sure we make a new one
         // (in case there are no state changes that mark it dirty over the course of a frame)
-        if self.render_encoder.is_none() {
+        if !self.backend.has_render_encoder() {
             self.dirty_state
                 .set_bits(Dirty::NEW_RENDER_ENCODER | Dirty::UPDATE_RENDER_ENCODER);
         }
+        // the first draw after a link has to wait for it to finish on the compile pool
+        for name in state.current_programs() {
+            state.program_list.get_mut(name).finish_link();
+        }
         // all of the operations to be carried out this step
         let all_dirty = self.dirty_state;
 
@@ -420,13 +877,15 @@ impl Renderer {
             if is_draw_command {
                 panic!("tried to call a draw command without a VAO bound")
             } else {
-                self.end_encoding();
-                self.render_encoder = Some(self.build_render_encoder(state));
+                let desc = Self::render_pass_descriptor(state);
+                self.backend.new_render_encoder(state, &desc);
+                // the new encoder doesn't have the uniforms of the current program
+                self.dirty_state.set_bits(Dirty::UPLOAD_UNIFORMS);
                 return;
             }
         }
 
-        // need to materialize a new render encoder
+        // need to rebuild the argument table mappings
         if all_dirty.any_set(Dirty::REMAP_BUFFERS) {
             self.remap_buffer_arguments(state);
         }
@@ -434,36 +893,60 @@ impl Renderer {
         // need to materialize a new render encoder
         if all_dirty.any_set(Dirty::NEW_RENDER_ENCODER) {
             gl_trace!("generating new render command encoder");
-            self.end_encoding();
-            self.render_encoder = Some(self.build_render_encoder(state));
+            let desc = Self::render_pass_descriptor(state);
+            self.backend.new_render_encoder(state, &desc);
             self.dirty_state.unset(Dirty::NEW_RENDER_ENCODER);
+            self.dirty_state.set_bits(Dirty::UPLOAD_UNIFORMS);
         }
         // need to update the dynamic state of the current encoder (it's either been invalidated or is blank (i.e. the encoder is brand new))
         if all_dirty.any_set(Dirty::UPDATE_RENDER_ENCODER) {
-            self.update_encoder(state);
+            self.bind_buffers(state);
+            self.backend
+                .update_render_encoder(&Self::encoder_state(state));
             self.dirty_state.unset(Dirty::UPDATE_RENDER_ENCODER);
         }
+        // a program with only a compute stage can be current while commands like glClear update the render encoder,
+        // but there is no render pipeline to build for it
+        if Self::linked_stage(state, ShaderType::VertexShader).is_none() {
+            assert!(
+                !is_draw_command,
+                "tried to call a draw command without a vertex stage"
+            );
+            return;
+        }
         // need to regenerate pipeline
         if all_dirty.any_set(Dirty::NEW_RENDER_PIPELINE) {
             gl_trace!("generating new render pipeline state");
-            self.render_pipeline_state = Some(self.build_render_pipeline_state(state));
+            self.generate_fetch_stages(state);
+            let desc = self.render_pipeline_descriptor(state);
+            self.backend.new_render_pipeline(state, &desc);
             self.dirty_state.unset(Dirty::NEW_RENDER_PIPELINE);
         }
         // If the encoder or pipeline is new, we need to attach the pipeline to the encoder
         if all_dirty.any_set(Dirty::NEW_RENDER_PIPELINE | Dirty::NEW_RENDER_ENCODER) {
-            let ps = self.render_pipeline_state.as_ref().unwrap();
-            let enc = self.render_encoder.as_ref().unwrap();
-            enc.setRenderPipelineState(ps);
+            self.backend.bind_render_pipeline();
+        }
+        // uniforms are only needed by draws, and may still change before the next one
+        if is_draw_command && self.dirty_state.any_set(Dirty::UPLOAD_UNIFORMS) {
+            self.upload_default_blocks(state);
+            self.dirty_state.unset(Dirty::UPLOAD_UNIFORMS);
+        }
+    }
+    //preconditions: view set on context
+    pub(crate) fn render_pass_descriptor(state: &GlState) -> RenderPassDescriptor {
+        if state.framebuffer_binding.is_some() {
+            // user-defined FBO
+            todo!()
+        }
+        // default FBO
+        RenderPassDescriptor {
+            color_attachments: state.default_draw_buffers.modes,
+            depth_attachment: state.caps.is_any_enabled(Capabilities::DEPTH_TEST),
+            stencil_attachment: state.caps.is_any_enabled(Capabilities::STENCIL_TEST),
         }
     }
     //preconditions: buffer maps built, renderable program present
-    pub(crate) fn build_render_pipeline_state(
-        &mut self,
-        state: &mut GlState,
-    ) -> ProtoObjRef<dyn MTLRenderPipelineState> {
-        let desc = MTLRenderPipelineDescriptor::new();
-        #[cfg(debug_assertions)]
-        desc.setLabel(Some(ns_string!("OxideGL render pipeline")));
+    pub(crate) fn render_pipeline_descriptor(&self, state: &GlState) -> RenderPipelineDescriptor {
         let (Some(f), Some(v)) = (
             Self::linked_stage(state, ShaderType::FragmentShader),
             Self::linked_stage(state, ShaderType::VertexShader),
@@ -472,355 +955,345 @@ impl Renderer {
                 "Tried to build a render pipeline while missing a linked vertex or fragment shader stage"
             );
         };
-        let attachments = desc.colorAttachments();
+        let mut color_attachments = [const { None }; MAX_COLOR_ATTACHMENTS as usize];
+        let mut depth_attachment_format = None;
         if state.framebuffer_binding.is_some() {
             todo!()
         } else {
             for (i, mode) in state.default_draw_buffers.modes.iter().enumerate() {
                 if mode.is_some() {
-                    let attachment_desc =
-                        unsafe { MTLRenderPipelineColorAttachmentDescriptor::new() };
-                    attachment_desc.setPixelFormat(self.pixel_format);
-
-                    // Apply blend state if present
-                    state.blend.drawbuffer_states[i].apply_to_mtl_desc(&attachment_desc);
-                    unsafe { attachments.setObject_atIndexedSubscript(Some(&attachment_desc), i) };
+                    color_attachments[i] = Some(ColorAttachmentDescriptor {
+                        pixel_format: self.backend.pixel_format(),
+                        blend: state.blend.drawbuffer_states[i],
+                    });
                 }
             }
             if state.caps.is_any_enabled(Capabilities::DEPTH_TEST) {
-                desc.setDepthAttachmentPixelFormat(
-                    self.depth_format
+                depth_attachment_format = Some(
+                    self.backend.depth_format()
                     .expect("Tried to use depth test on the default framebuffer without specifying a depth format during context creation")
                 );
             }
             //TODO depth/stencil attachment formats
         }
-        desc.setVertexFunction(Some(&v.function));
-        desc.setFragmentFunction(Some(&f.function));
         //TODO: primitive topology real
-        // unsafe { desc.setInputPrimitiveTopology(MTLPrimitiveTopologyClass::Triangle) };
-        let v_desc = self.build_vertex_descriptor(state);
-        desc.setVertexDescriptor(Some(&v_desc));
-        self.device
-            .newRenderPipelineStateWithDescriptor_error(&desc)
-            .expect("failed to create pipeline state")
+        let geometry = Self::linked_geometry(state);
+        let tessellation = Self::linked_tessellation(state);
+        let attribute_conversions = Self::attribute_conversions(state);
+        let vertex_function = match (geometry, tessellation) {
+            (Some(g), _) => &g.passthrough.entry_name,
+            (None, Some(t)) => &t.evaluation.entry_name,
+            (None, None) => Self::fetch_stages(state, &attribute_conversions)
+                .map_or(&v.entry_name, |s| &s.vertex.entry_name),
+        };
+        RenderPipelineDescriptor {
+            vertex_function: vertex_function.clone(),
+            fragment_function: f.entry_name.clone(),
+            color_attachments,
+            depth_attachment_format,
+            vertex_descriptor: self.vertex_descriptor(state),
+            geometry_output: geometry.map(|g| g.layout.info.output_list()),
+            tessellation: tessellation.map(|t| t.info),
+            attribute_conversions,
+            clip_control: state.clip_control,
+            clip_distances: state.caps.clip_distances(),
+            point_size: (!state.caps.is_any_enabled(Capabilities::PROGRAM_POINT_SIZE))
+                .then_some(state.points.size),
+            point_sprite_coord_origin: state.points.sprite_coord_origin,
+        }
         // TODO clear state, depth test config, scissor box
     }
     // precondition: buffers mapped
-    fn update_encoder(&mut self, state: &mut GlState) {
-        fn stencil_descriptor_for_stencil_state(
-            state: &StencilFaceState,
-            writemask: u32,
-        ) -> Retained<MTLStencilDescriptor> {
-            let desc = unsafe { MTLStencilDescriptor::new() };
-            desc.setStencilCompareFunction(state.func.into());
-            desc.setStencilFailureOperation(state.fail_action.into());
-
-            desc.setDepthFailureOperation(state.depth_fail_action.into());
-            desc.setDepthStencilPassOperation(state.depth_pass_action.into());
-
-            desc.setWriteMask(writemask);
-            desc.setReadMask(state.mask);
-            desc
+    pub(crate) fn encoder_state(state: &GlState) -> EncoderState {
+        EncoderState {
+            depth: state
+                .caps
+                .is_any_enabled(Capabilities::DEPTH_TEST)
+                .then_some((state.depth_func, state.writemasks.depth)),
+            stencil: state
+                .caps
+                .is_any_enabled(Capabilities::STENCIL_TEST)
+                .then_some([
+                    (state.stencil.front, state.writemasks.stencil_front),
+                    (state.stencil.back, state.writemasks.stencil_back),
+                ]),
+            cull_mode: state
+                .caps
+                .is_any_enabled(Capabilities::CULL_FACE)
+                .then_some(state.cull_face_mode),
+            // we *could* set this only when blending is actually enabled, but that's done on a per-attachment basis anyways (and
+            // this call is quite cheap (just sets a similar variable somewhere within the encoder state)
+            blend_color: state.blend.blend_color,
+            viewport: *state.viewport,
+            scissor: state
+                .caps
+                .is_any_enabled(Capabilities::SCISSOR_TEST)
+                .then_some(*state.scissor_box),
+            depth_range: [state.depth_range.near, state.depth_range.far],
+            clip_origin: state.clip_control.origin,
         }
-        self.bind_buffers_to_render_encoder(state);
-        let enc;
-        if state
-            .caps
-            .is_any_enabled(Capabilities::DEPTH_TEST | Capabilities::STENCIL_TEST)
-        {
-            let desc = unsafe { MTLDepthStencilDescriptor::new() };
-            if state.caps.is_any_enabled(Capabilities::DEPTH_TEST) {
-                desc.setDepthCompareFunction(state.depth_func.into());
-                desc.setDepthWriteEnabled(state.writemasks.depth);
-            }
-            if state.caps.is_any_enabled(Capabilities::STENCIL_TEST) {
-                let front = stencil_descriptor_for_stencil_state(
-                    &state.stencil.front,
-                    state.writemasks.stencil_front,
-                );
-                desc.setFrontFaceStencil(Some(&front));
-                let back = stencil_descriptor_for_stencil_state(
-                    &state.stencil.back,
-                    state.writemasks.stencil_back,
+    }
+    fn bind_buffers(&mut self, state: &GlState) {
+        // the evaluation stage comes after the vertex stage, as its arguments replace the ones of the vertex stage on
+        // the vertex function of the render encoder (which the vertex stage only needs in the pre-pass)
+        for (stage, map) in [
+            (ShaderType::VertexShader, &self.vertex_buffer_map),
+            (ShaderType::GeometryShader, &self.geometry_buffer_map),
+            (ShaderType::TessControlShader, &self.tess_control_buffer_map),
+            (
+                ShaderType::TessEvaluationShader,
+                &self.tess_evaluation_buffer_map,
+            ),
+            (ShaderType::FragmentShader, &self.fragment_buffer_map),
+        ] {
+            for (&buf, &binding) in &map.inner {
+                gl_trace!("binding {buf:?} to {stage:?} argument table index {binding}");
+                self.backend.bind_buffer(
+                    state,
+                    &BufferArgument {
+                        stage,
+                        buffer: buf.to_raw(),
+                        offset: self.vertex_buffer_offsets.get(&buf).copied().unwrap_or(0),
+                        index: binding,
+                    },
                 );
-                desc.setBackFaceStencil(Some(&back));
             }
-            let ds_state = self
-                .device
-                .newDepthStencilStateWithDescriptor(&desc)
-                .expect("failed to create MTLDepthStencilState");
-            enc = self.current_render_encoder();
-            enc.setDepthStencilState(Some(&ds_state));
-        } else {
-            enc = self.current_render_encoder();
         }
-        if state.caps.is_any_enabled(Capabilities::CULL_FACE) {
-            enc.setCullMode(state.cull_face_mode.into());
+    }
+    fn upload_default_blocks(&mut self, state: &GlState) {
+        for stage in [
+            ShaderType::VertexShader,
+            ShaderType::GeometryShader,
+            ShaderType::TessControlShader,
+            ShaderType::TessEvaluationShader,
+            ShaderType::FragmentShader,
+        ] {
+            self.upload_default_block(state, stage);
+            self.bind_images(state, stage);
+            self.bind_atomic_counter_buffers(state, stage);
         }
-
-        // we *could* set this only when blending is actually enabled, but that's done on a per-attachment basis anyways (and
-        // this call is quite cheap (just sets a similar variable somewhere within the encoder state)
-        let blend_col = state.blend.blend_color;
-        enc.setBlendColorRed_green_blue_alpha(
-            blend_col[0],
-            blend_col[1],
-            blend_col[2],
-            blend_col[3],
-        );
-
-        // TODO scissor test
-
-        enc.setViewport(MTLViewport {
-            originX: state.viewport.x.into(),
-            originY: state.viewport.y.into(),
-            width: state.viewport.width.into(),
-            height: state.viewport.height.into(),
-            // TODO: depth range
-            znear: 0.0,
-            zfar: 1.0,
-        });
     }
-    fn bind_buffers_to_render_encoder(&mut self, state: &mut GlState) {
-        let enc = self.render_encoder.as_ref().unwrap();
-        for (&buf, &binding) in &self.vertex_buffer_map.inner {
-            let buf_obj = state.buffer_list.get(buf);
-            gl_trace!("binding {buf:?} to metal argument table index {binding}");
-            if let Some(alloc) = buf_obj.allocation.as_ref() {
-                unsafe {
-                    enc.setVertexBuffer_offset_atIndex(
-                        Some(&alloc.mtl),
-                        *self.vertex_buffer_offsets.get(&buf).unwrap(),
-                        binding as usize,
-                    );
-                };
-            }
+    /// Upload the contents of the default uniform block of a stage of the current program, if it has the stage
+    fn upload_default_block(&mut self, state: &GlState, stage: ShaderType) {
+        let Some(linked) = Self::linked_stage(state, stage) else {
+            return;
+        };
+        let mut data = Cow::Borrowed(linked.default_block.data.as_slice());
+        if !linked.subroutines.uniforms.is_empty() {
+            linked.subroutines.write_selectors(
+                &linked.default_block,
+                data.to_mut(),
+                state.subroutine_selections(stage),
+            );
         }
-        for (&buf, &binding) in &self.fragment_buffer_map.inner {
-            let buf_obj = state.buffer_list.get(buf);
-            gl_trace!("binding {buf:?} to metal argument table index {binding}");
-            if let Some(alloc) = buf_obj.allocation.as_ref() {
-                unsafe {
-                    enc.setFragmentBuffer_offset_atIndex(
-                        Some(&alloc.mtl),
-                        *self.vertex_buffer_offsets.get(&buf).unwrap(),
-                        binding as usize,
-                    );
-                };
-            }
+        if !data.is_empty() {
+            gl_trace!(
+                "uploading {} bytes of default block uniforms to {stage:?}",
+                data.len()
+            );
+            self.backend
+                .set_bytes(stage, DEFAULT_UNIFORM_BLOCK_INDEX, &data);
         }
     }
-    #[expect(
-        clippy::cast_possible_truncation,
-        clippy::cast_sign_loss,
-        reason = "we hope this works"
-    )]
-    #[inline]
-    // "guesstimate," it can still get out of sync with the actual size of the current drawable
-    pub(crate) fn target_defaultfb_dims(&mut self) -> (u32, u32) {
-        // reproduce the calculation done by the CAMetalLayer when generating the next drawable size.
-        // (from https://developer.apple.com/documentation/quartzcore/cametallayer/1478174-drawablesize)
-        // We don't read [Layer drawableSize] directly because it proxies a field on the actual drawable
-        // and is 0 if the layer hasn't materialized a drawable yet.
-        let size = self.layer.bounds().size;
-        let scale = self.layer.contentsScale();
-        let size = (size.width * scale, size.height * scale);
-        debug_assert!(
-            (size.0 - size.0.floor()) == 0.0
-                && (size.1 - size.1.floor()) == 0.0
-                && size.0 > 0.0
-                && size.1 > 0.0,
-            "bad size ({size:?})"
-        );
-        (size.0 as u32, size.1 as u32)
-    }
-    #[expect(clippy::cast_possible_truncation, reason = "we hope this works")]
-    //preconditions: view set on context
-    pub(crate) fn build_render_encoder(
-        &mut self,
-        state: &mut GlState,
-    ) -> ProtoObjRef<dyn MTLRenderCommandEncoder> {
-        let desc = unsafe { MTLRenderPassDescriptor::new() };
-
-        if state.framebuffer_binding.is_some() {
-            // user-defined FBO
-            todo!()
-        } else {
-            // default FBO
-            let mut iter = state
-                .default_draw_buffers
-                .modes
-                .iter()
-                .copied()
-                .enumerate()
-                .filter_map(|(idx, v)| v.map(|v| (idx, v)))
-                .peekable();
-            //FIXME this expect contradicts the spec, should be an early return of some kind
-            let &(_, first) = iter.peek().expect("No draw buffer set");
-            let ca_drawable_tex = unsafe { self.current_drawable().texture() };
-
-            // Use the current drawable size as the targeted size for rendering. If the drawable size changes, a new
-            // render encoder will be created, which will inherit the new size from the new drawawable
-            let dims = (
-                ca_drawable_tex.width() as u32,
-                ca_drawable_tex.height() as u32,
-            );
-
-            if state.caps.is_any_enabled(Capabilities::DEPTH_TEST) {
-                let a_desc = unsafe { MTLRenderPassDepthAttachmentDescriptor::new() };
-                a_desc.setTexture(Some(&self.get_internal_depthbuffer(dims).tex));
-
-                desc.setDepthAttachment(Some(&a_desc));
-            }
-            if state.caps.is_any_enabled(Capabilities::STENCIL_TEST) {
-                let a_desc = unsafe { MTLRenderPassStencilAttachmentDescriptor::new() };
-                a_desc.setTexture(Some(&self.get_internal_stencilbuffer(dims).tex));
-                desc.setStencilAttachment(Some(&a_desc));
-            }
-
-            let drawbuffer = self.get_internal_drawbuffer(first, dims);
-            for (idx, buf) in iter {
-                let a_desc = MTLRenderPassColorAttachmentDescriptor::new();
-                // set attachment texture
-                if buf == DrawBufferMode::FrontLeft {
-                    // Replace the texture with the current drawable
-                    debug_assert_eq!(
-                        (
-                            ca_drawable_tex.width() as u32,
-                            ca_drawable_tex.height() as u32
-                        ),
-                        drawbuffer.dimensions,
-                        "Metal drawable had different dimensions than associated drawbuffer!"
+    /// Bind the textures of the image units that the image uniforms of a stage of the current program refer to
+    fn bind_images(&mut self, state: &GlState, stage: ShaderType) {
+        let Some(linked) = Self::linked_stage(state, stage) else {
+            return;
+        };
+        for image in &linked.resources.images {
+            for i in 0..image.len {
+                let Some(unit) = state.image_units.get((image.binding + i) as usize) else {
+                    gl_warn!(
+                        "{stage:?} image uniform {} refers to image unit {}, which does not exist",
+                        image.name,
+                        image.binding + i
                     );
-                    a_desc.setTexture(Some(&ca_drawable_tex));
-                } else {
-                    a_desc.setTexture(Some(&drawbuffer.tex));
-                }
-
-                // FIXME need to do load action real
-                // a_desc.setLoadAction(MTLLoadAction::Clear);
-                // a_desc.setClearColor(MTLClearColor {
-                //     red: 1.0,
-                //     green: 0.0,
-                //     blue: 0.0,
-                //     alpha: 0.5,
-                // });
-                unsafe {
-                    desc.colorAttachments()
-                        .setObject_atIndexedSubscript(Some(&a_desc), idx);
+                    continue;
                 };
+                // accesses to images without a texture are undefined, so they are left unbound
+                let Some(texture) = unit.texture else {
+                    continue;
+                };
+                if !image::access_allows(unit.access, image.access) {
+                    gl_warn!(
+                        "{stage:?} image uniform {} performs {:?} accesses, but its image unit was bound for {:?} access",
+                        image.name,
+                        image.access,
+                        unit.access
+                    );
+                    continue;
+                }
+                let compatible = state
+                    .texture_list
+                    .get(texture)
+                    .format()
+                    .is_none_or(|format| image::formats_compatible(format, unit.format));
+                if !compatible {
+                    gl_warn!(
+                        "{texture:?} can't be interpreted as {:?} by {stage:?} image uniform {}",
+                        unit.format,
+                        image.name
+                    );
+                    continue;
+                }
+                gl_trace!(
+                    "binding {texture:?} to {stage:?} texture argument table index {}",
+                    image.index + i
+                );
+                self.backend.bind_image(
+                    state,
+                    &ImageArgument {
+                        stage,
+                        texture: texture.to_raw(),
+                        index: image.index + i,
+                        level: unit.level,
+                        layer: (!unit.layered).then_some(unit.layer),
+                        format: unit.format,
+                        write: image.access != BufferAccess::ReadOnly,
+                        atomic: image.atomic,
+                    },
+                );
             }
-            desc.setRenderTargetWidth(dims.0 as usize);
-            desc.setRenderTargetHeight(dims.1 as usize);
         }
-        let enc = self
-            .current_command_buffer()
-            .renderCommandEncoderWithDescriptor(&desc)
-            .expect("failed to create new render command encoder");
-        #[cfg(debug_assertions)]
-        enc.setLabel(Some(ns_string!("OxideGL render encoder")));
-        for group in &self.debug_group_stack {
-            enc.pushDebugGroup(group);
+    }
+    /// Bind the atomic counter buffer ranges that the atomic counters of a stage of the current program are in to the
+    /// argument table indices of the blocks they were lowered to
+    fn bind_atomic_counter_buffers(&mut self, state: &GlState, stage: ShaderType) {
+        let Some(linked) = Self::linked_stage(state, stage) else {
+            return;
+        };
+        for (index, acb) in
+            (ATOMIC_COUNTER_BUFFER_INDEX..).zip(&linked.resources.atomic_counter_buffers)
+        {
+            let binding = acb.binding.unwrap_or(0) as usize;
+            let bindings = &state.buffer_bindings;
+            // accesses to counters without a buffer are undefined, so they are left unbound
+            let Some(Some(buffer)) = bindings.atomic_counter.get(binding) else {
+                gl_warn!(
+                    "{stage:?} uses the atomic counters at binding {binding}, but no buffer is bound to it"
+                );
+                continue;
+            };
+            let buffer = *buffer;
+            let (offset, size) = bindings.atomic_counter_ranges[binding]
+                .unwrap_or((0, state.buffer_list.get(buffer).size));
+            if size < acb.data_size as usize {
+                gl_warn!(
+                    "{stage:?} uses {} bytes of atomic counters at binding {binding}, but only {size} bytes of {buffer:?} are bound to it",
+                    acb.data_size
+                );
+            }
+            gl_trace!(
+                "binding {buffer:?} to {stage:?} argument table index {index} for its atomic counters"
+            );
+            self.backend.bind_buffer(
+                state,
+                &BufferArgument {
+                    stage,
+                    buffer: buffer.to_raw(),
+                    offset,
+                    index,
+                },
+            );
         }
-        enc
     }
+    /// The linkage of the program whose executable runs `stage`, if there is one
     #[inline]
-    fn check_and_resize_drawable<'a>(
-        device: &ProtoObjRef<dyn MTLDevice>,
-        dims: (u32, u32),
-        pixel_format: MTLPixelFormat,
-        gpu_private: bool,
-        r: &'a mut Option<InternalDrawable>,
-    ) -> &'a InternalDrawable {
-        if r.as_ref().is_none_or(|v| v.dimensions != dims) {
-            // Need a new internal drawable
-            let new_tex = Self::new_drawbuffer_size_format(device, dims, pixel_format, gpu_private);
-            let mut replacement = Some(InternalDrawable::new(new_tex, dims));
-            mem::swap(r, &mut replacement);
-            drop(replacement);
-        }
-        // must be Some due to code above
-        r.as_ref().unwrap()
+    fn stage_linkage(state: &GlState, stage: ShaderType) -> Option<&LinkedProgram> {
+        let program = state.program_list.get(state.stage_program(stage)?);
+        Some(
+            program
+                .latest_linkage
+                .as_ref()
+                .expect("Program should have been linked"),
+        )
     }
-    pub(crate) fn get_internal_drawbuffer(
-        &mut self,
-        target: DrawBufferMode,
-        dims: (u32, u32),
-    ) -> &InternalDrawable {
-        gl_trace!("getting internal (default FB) drawbuffer for {target:?}");
-        let r = match target {
-            DrawBufferMode::FrontLeft => &mut self.internal_drawables.front_left,
-            DrawBufferMode::FrontRight => &mut self.internal_drawables.front_right,
-            DrawBufferMode::BackLeft => &mut self.internal_drawables.back_left,
-            DrawBufferMode::BackRight => &mut self.internal_drawables.back_right,
-            _ => todo!("oxidegl does not support aliased draw buffer modes"),
-        };
-        Self::check_and_resize_drawable(&self.device, dims, self.pixel_format, false, r)
+    #[inline]
+    pub(crate) fn linked_stage(state: &GlState, shader_type: ShaderType) -> Option<&LinkedStage> {
+        Self::stage_linkage(state, shader_type)?.stage(shader_type)
     }
-    // precondition: user specifies depth format for defaultfb
-    pub(crate) fn get_internal_depthbuffer(&mut self, dims: (u32, u32)) -> &InternalDrawable {
-        Self::check_and_resize_drawable(
-            &self.device,
-            dims,
-            self.depth_format.expect("tried to generate a depth buffer for the default framebuffer, but no depth format was specified at context creation!"),
-            true,
-            &mut self.internal_drawables.depth,
-        )
+    /// The geometry stage of the current program and the stages generated to emulate it, if it has one
+    #[inline]
+    pub(crate) fn linked_geometry(state: &GlState) -> Option<&LinkedGeometry> {
+        Self::stage_linkage(state, ShaderType::GeometryShader)?
+            .geometry
+            .as_ref()
     }
-    // precondition: user specifies stencil format for defaultfb
-    pub(crate) fn get_internal_stencilbuffer(&mut self, dims: (u32, u32)) -> &InternalDrawable {
-        Self::check_and_resize_drawable(
-            &self.device,
-            dims,
-            self.depth_format.expect("tried to generate a stencil buffer for the default framebuffer, but no stencil format was specified at context creation!"),
-            true,
-            &mut self.internal_drawables.stencil,
-        )
+    /// The tessellation stages of the current program and the stage generated to feed them, if it has them
+    #[inline]
+    pub(crate) fn linked_tessellation(state: &GlState) -> Option<&LinkedTessellation> {
+        Self::stage_linkage(state, ShaderType::TessControlShader)?
+            .tessellation
+            .as_ref()
     }
-    pub(crate) fn new_drawbuffer_size_format(
-        device: &ProtoObjRef<dyn MTLDevice>,
-        size: (u32, u32),
-        format: MTLPixelFormat,
-        gpu_private: bool,
-    ) -> ProtoObjRef<dyn MTLTexture> {
-        gl_debug!(
-            "creating new {}x{} {format:?} drawable texture",
-            size.0,
-            size.1
-        );
-        let desc = unsafe { MTLTextureDescriptor::new() };
-        if gpu_private {
-            desc.setStorageMode(MTLStorageMode::Private);
-            desc.setAllowGPUOptimizedContents(true);
-        }
-        unsafe { desc.setWidth(size.0 as usize) };
-        unsafe { desc.setHeight(size.1 as usize) };
-        desc.setPixelFormat(format);
-        desc.setUsage(MTLTextureUsage::RenderTarget);
-        device
-            .newTextureWithDescriptor(&desc)
-            .expect("failed to create drawable texture")
+    /// The compute stage of the current program and its work group size, if it has one
+    #[inline]
+    pub(crate) fn linked_compute(state: &GlState) -> Option<&LinkedCompute> {
+        Self::stage_linkage(state, ShaderType::ComputeShader)?
+            .compute
+            .as_ref()
     }
+
+    /// The stages generated to capture the transform feedback varyings of the current program, if it captures any
     #[inline]
-    pub(crate) fn linked_stage(state: &GlState, shader_type: ShaderType) -> Option<&LinkedStage> {
-        //TODO: handle program pipelines
-        let program = state
-            .program_list
-            .get(state.program_binding.expect("No program bound"));
-        let link = program
-            .latest_linkage
+    pub(crate) fn linked_feedback(state: &GlState) -> Option<&LinkedFeedback> {
+        Self::stage_linkage(state, ShaderType::VertexShader)?
+            .feedback
             .as_ref()
+    }
+    /// The variants of the stages of the current program that fetch vertex attributes, which convert them as described
+    /// by `conversions`. None if there are no conversions to do
+    #[inline]
+    pub(crate) fn fetch_stages<'a>(
+        state: &'a GlState,
+        conversions: &[AttributeConversion],
+    ) -> Option<&'a FetchStages> {
+        if conversions.is_empty() {
+            return None;
+        }
+        let stages = Self::stage_linkage(state, ShaderType::VertexShader)
+            .expect("No program bound")
+            .fetch_stages
+            .get(conversions)
+            .expect("stages that fetch vertex attributes should have been generated");
+        Some(stages)
+    }
+    /// Conversions of the enabled vertex attributes of the current VAO that Metal's vertex fetch can't do
+    pub(crate) fn attribute_conversions(state: &GlState) -> Box<[AttributeConversion]> {
+        let vao = state.vao_list.get(state.vao_binding.unwrap());
+        vao.attribs
+            .iter()
+            .enumerate()
+            .filter(|(_, attr)| attr.enabled)
+            .filter_map(|(idx, attr)| {
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    reason = "idx < MAX_VERTEX_ATTRIBUTES"
+                )]
+                let location = idx as u32;
+                AttributeConversion::new(location, attr.get_mtl_layout())
+            })
+            .collect()
+    }
+    /// Generate the variants of the stages of the current program that convert the vertex attributes of the current
+    /// VAO, if it has any that need to be converted
+    fn generate_fetch_stages(&self, state: &mut GlState) {
+        let conversions = Self::attribute_conversions(state);
+        if conversions.is_empty() {
+            return;
+        }
+        let stage_compiler = self.backend.stage_compiler();
+        let program = state.program_list.get_mut(
+            state
+                .stage_program(ShaderType::VertexShader)
+                .expect("No program bound"),
+        );
+        let linkage = program
+            .linkage_mut()
             .expect("Program should have been linked");
-        match shader_type {
-            ShaderType::FragmentShader => &link.fragment,
-            ShaderType::VertexShader => &link.vertex,
-            ShaderType::ComputeShader => &link.compute,
-
-            ShaderType::TessEvaluationShader => todo!(),
-            ShaderType::TessControlShader => todo!(),
-            ShaderType::GeometryShader => todo!(),
+        if let Err(e) = linkage.generate_fetch_stages(&conversions, stage_compiler.as_ref()) {
+            panic!("failed to generate vertex attribute conversions: {e}");
         }
-        .as_ref()
     }
 
     /// precondition: has program
@@ -843,6 +1316,27 @@ impl Renderer {
             self.vertex_buffer_map
                 .build(&pinned_buffers, &vertex_buffers);
         }
+        match Self::linked_stage(state, ShaderType::GeometryShader) {
+            Some(geometry) => self
+                .geometry_buffer_map
+                .build(&Self::stage_pinned_buffers(state, geometry), &[]),
+            None => self.geometry_buffer_map.build(&[], &[]),
+        }
+        for (stage, map) in [
+            (
+                ShaderType::TessControlShader,
+                &mut self.tess_control_buffer_map,
+            ),
+            (
+                ShaderType::TessEvaluationShader,
+                &mut self.tess_evaluation_buffer_map,
+            ),
+        ] {
+            match Self::linked_stage(state, stage) {
+                Some(linked) => map.build(&Self::stage_pinned_buffers(state, linked), &[]),
+                None => map.build(&[], &[]),
+            }
+        }
         if let Some(frag) = Self::linked_stage(state, ShaderType::FragmentShader) {
             self.fragment_buffer_map
                 .build(&Self::stage_pinned_buffers(state, frag), &[]);
@@ -881,39 +1375,19 @@ impl Renderer {
             #[allow(clippy::cast_possible_truncation)]
             v.push((name, binding as u8));
         }
-        for acb in &shader_stage.resources.atomic_counter_buffers {
-            let binding = acb
-                .binding
-                .expect("atomic counter buffer declaration missing binding attribute");
-            let Some(name) = state.buffer_bindings.shader_storage[binding as usize] else {
-                panic!(
-                    "Shader requested atomic counter buffer at binding {binding} but no buffer was bound at that index"
-                );
-            };
-            #[allow(clippy::cast_possible_truncation)]
-            v.push((name, binding as u8));
-        }
         v
     }
     /// precondition: Buffer maps built, VAO present
-    pub(crate) fn build_vertex_descriptor(
-        &mut self,
-        state: &mut GlState,
-    ) -> Retained<MTLVertexDescriptor> {
-        gl_trace!("generating Metal vertex descriptor from GL VAO state");
+    pub(crate) fn vertex_descriptor(&self, state: &GlState) -> VertexDescriptor {
+        gl_trace!("generating vertex descriptor from GL VAO state");
         let vao = state.vao_list.get(state.vao_binding.unwrap());
-        let mtl_vertex_desc = unsafe { MTLVertexDescriptor::new() };
+        let mut desc = VertexDescriptor::default();
         for bdg in &vao.buffer_bindings {
             if let Some(buf) = bdg.buf {
-                let buffer_argument_index = self.vertex_buffer_map.get(buf).unwrap();
-                let desc = MTLVertexBufferLayoutDescriptor::new();
-                unsafe { desc.setStride(bdg.stride.into()) };
-
-                unsafe {
-                    mtl_vertex_desc
-                        .layouts()
-                        .setObject_atIndexedSubscript(Some(&desc), buffer_argument_index as usize);
-                };
+                desc.layouts.push(VertexBufferLayout {
+                    buffer_index: self.vertex_buffer_map.get(buf).unwrap(),
+                    stride: bdg.stride,
+                });
             }
         }
         for (idx, attr) in vao.attribs.iter().enumerate() {
@@ -922,26 +1396,22 @@ impl Renderer {
             }
             let attr_binding = vao.buffer_bindings[attr.buffer_idx as usize];
 
-            let mtl_attrib_desc = MTLVertexAttributeDescriptor::new();
-
-            // Get the index of the corresponding buffer object in the vertex shader argument table
-            let buffer_argument_index = self
-                .vertex_buffer_map
-                .get(attr_binding.buf.expect("Buffer for attribute not bound"))
-                .unwrap();
-
-            unsafe { mtl_attrib_desc.setBufferIndex(buffer_argument_index as usize) };
-            mtl_attrib_desc.setFormat(attr.get_mtl_layout().to_vertex_format());
-            unsafe { mtl_attrib_desc.setOffset(attr.relative_offset as usize) };
-
-            unsafe {
-                mtl_vertex_desc
-                    .attributes()
-                    .setObject_atIndexedSubscript(Some(&mtl_attrib_desc), idx);
-            };
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "idx < MAX_VERTEX_ATTRIBUTES"
+            )]
+            desc.attributes.push(VertexAttributeDescriptor {
+                attribute_index: idx as u32,
+                // Get the index of the corresponding buffer object in the vertex shader argument table
+                buffer_index: self
+                    .vertex_buffer_map
+                    .get(attr_binding.buf.expect("Buffer for attribute not bound"))
+                    .unwrap(),
+                format: attr.get_mtl_layout().to_vertex_format(),
+                offset: attr.relative_offset,
+            });
         }
-
-        mtl_vertex_desc
+        desc
     }
 }
 impl Context {
@@ -952,74 +1422,16 @@ impl Context {
         } = &mut *self;
         platform.swap_buffers(state);
     }
-}
-impl From<DepthFunction> for MTLCompareFunction {
-    fn from(value: DepthFunction) -> Self {
-        // 1:1 correspondance between depthfunc and MTLCompareFunction after an offsetting subtraction
-        Self((value as u32 - DepthFunction::Never as u32) as usize)
+    /// Returns the list of commands recorded by this context's rendering backend, if it records them
+    /// (i.e. if it was created with [`Context::new_headless`])
+    #[must_use]
+    pub fn recorded_commands(&self) -> Option<&[RecordedCommand]> {
+        self.renderer.backend.recorded_commands()
     }
-}
-impl From<StencilFunction> for MTLCompareFunction {
-    fn from(value: StencilFunction) -> Self {
-        // 1:1 correspondance between stencilfunc and MTLCompareFunction after an offsetting subtraction
-        Self((value as u32 - StencilFunction::Never as u32) as usize)
-    }
-}
-
-impl From<StencilOp> for MTLStencilOperation {
-    #[inline]
-    fn from(value: StencilOp) -> Self {
-        match value {
-            StencilOp::Zero => Self::Zero,
-            StencilOp::Invert => Self::Invert,
-            StencilOp::Keep => Self::Keep,
-            StencilOp::Replace => Self::Replace,
-            StencilOp::Incr => Self::IncrementClamp,
-            StencilOp::Decr => Self::DecrementClamp,
-            StencilOp::IncrWrap => Self::IncrementWrap,
-            StencilOp::DecrWrap => Self::DecrementWrap,
-        }
-    }
-}
-
-impl DrawbufferBlendState {
-    #[inline]
-    fn apply_to_mtl_desc(&self, desc: &Retained<MTLRenderPipelineColorAttachmentDescriptor>) {
-        if !self.blend_enabled {
-            return;
-        }
-        desc.setBlendingEnabled(true);
-
-        desc.setSourceRGBBlendFactor(self.src_rgb.into());
-        desc.setSourceAlphaBlendFactor(self.src_alpha.into());
-
-        desc.setDestinationRGBBlendFactor(self.dst_rgb.into());
-        desc.setDestinationAlphaBlendFactor(self.dst_alpha.into());
-
-        desc.setRgbBlendOperation(self.eq_rgb.into());
-        desc.setAlphaBlendOperation(self.eq_alpha.into());
-    }
-}
-impl From<ColorWriteMask> for MTLColorWriteMask {
-    #[inline]
-    fn from(value: ColorWriteMask) -> Self {
-        let arr: [bool; 4] = value.into();
-        // See: MTLRenderPipeline.h L#54
-        let val = usize::from(arr[3])
-            | (usize::from(arr[2]) << 1)
-            | (usize::from(arr[1]) << 2)
-            | (usize::from(arr[0]) << 3);
-        Self(val)
-    }
-}
-impl From<TriangleFace> for MTLCullMode {
-    #[inline]
-    fn from(value: TriangleFace) -> Self {
-        match value {
-            TriangleFace::Front => Self::Front,
-            //FIXME this is incorrect. This should cull all faces but not lines. This will be a fun™ behavior to emulate
-            TriangleFace::FrontAndBack => Self::None,
-            TriangleFace::Back => Self::Back,
-        }
+    /// Returns the dimensions and contents of the default framebuffer's color buffer if it is stored in host memory
+    /// (i.e. if this context was created with [`Context::new_software`]). Rows are ordered bottom to top
+    #[must_use]
+    pub fn color_buffer(&self) -> Option<((u32, u32), &[[f32; 4]])> {
+        self.renderer.backend.color_buffer()
     }
 }
@@ -0,0 +1,118 @@
+//! Pixel and vertex formats used to describe render targets and vertex fetch to a [`super::RenderBackend`].
+//! The values of these match the corresponding Metal enums, so the Metal backend can pass them through unchanged
+
+/// Format of the texels of a render target or texture, with the same values as `MTLPixelFormat`
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PixelFormat(pub usize);
+#[allow(non_upper_case_globals)]
+impl PixelFormat {
+    pub const R8Unorm: Self = Self(10);
+    pub const R8Snorm: Self = Self(12);
+    pub const R8Uint: Self = Self(13);
+    pub const R8Sint: Self = Self(14);
+    pub const R16Unorm: Self = Self(20);
+    pub const R16Snorm: Self = Self(22);
+    pub const R16Uint: Self = Self(23);
+    pub const R16Sint: Self = Self(24);
+    pub const R16Float: Self = Self(25);
+    pub const RG8Unorm: Self = Self(30);
+    pub const RG8Snorm: Self = Self(32);
+    pub const RG8Uint: Self = Self(33);
+    pub const RG8Sint: Self = Self(34);
+    pub const A1BGR5Unorm: Self = Self(41);
+    pub const ABGR4Unorm: Self = Self(42);
+    pub const R32Uint: Self = Self(53);
+    pub const R32Sint: Self = Self(54);
+    pub const R32Float: Self = Self(55);
+    pub const RG16Unorm: Self = Self(60);
+    pub const RG16Snorm: Self = Self(62);
+    pub const RG16Uint: Self = Self(63);
+    pub const RG16Sint: Self = Self(64);
+    pub const RG16Float: Self = Self(65);
+    pub const RGBA8Unorm: Self = Self(70);
+    pub const RGBA8Unorm_sRGB: Self = Self(71);
+    pub const RGBA8Snorm: Self = Self(72);
+    pub const RGBA8Uint: Self = Self(73);
+    pub const RGBA8Sint: Self = Self(74);
+    pub const BGRA8Unorm_sRGB: Self = Self(81);
+    pub const RGB10A2Unorm: Self = Self(90);
+    pub const RGB10A2Uint: Self = Self(91);
+    pub const RG11B10Float: Self = Self(92);
+    pub const RG32Uint: Self = Self(103);
+    pub const RG32Sint: Self = Self(104);
+    pub const RG32Float: Self = Self(105);
+    pub const RGBA16Unorm: Self = Self(110);
+    pub const RGBA16Snorm: Self = Self(112);
+    pub const RGBA16Uint: Self = Self(113);
+    pub const RGBA16Sint: Self = Self(114);
+    pub const RGBA16Float: Self = Self(115);
+    pub const RGBA32Uint: Self = Self(123);
+    pub const RGBA32Sint: Self = Self(124);
+    pub const RGBA32Float: Self = Self(125);
+    pub const Depth32Float: Self = Self(252);
+    pub const Stencil8: Self = Self(253);
+    pub const Depth24Unorm_Stencil8: Self = Self(255);
+    pub const Depth32Float_Stencil8: Self = Self(260);
+}
+
+/// In-memory layout of a vertex attribute, with the same values as `MTLVertexFormat`
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VertexFormat(pub usize);
+#[allow(non_upper_case_globals)]
+impl VertexFormat {
+    pub const UChar2: Self = Self(1);
+    pub const UChar3: Self = Self(2);
+    pub const UChar4: Self = Self(3);
+    pub const Char2: Self = Self(4);
+    pub const Char3: Self = Self(5);
+    pub const Char4: Self = Self(6);
+    pub const UChar2Normalized: Self = Self(7);
+    pub const UChar3Normalized: Self = Self(8);
+    pub const UChar4Normalized: Self = Self(9);
+    pub const Char2Normalized: Self = Self(10);
+    pub const Char3Normalized: Self = Self(11);
+    pub const Char4Normalized: Self = Self(12);
+    pub const UShort2: Self = Self(13);
+    pub const UShort3: Self = Self(14);
+    pub const UShort4: Self = Self(15);
+    pub const Short2: Self = Self(16);
+    pub const Short3: Self = Self(17);
+    pub const Short4: Self = Self(18);
+    pub const UShort2Normalized: Self = Self(19);
+    pub const UShort3Normalized: Self = Self(20);
+    pub const UShort4Normalized: Self = Self(21);
+    pub const Short2Normalized: Self = Self(22);
+    pub const Short3Normalized: Self = Self(23);
+    pub const Short4Normalized: Self = Self(24);
+    pub const Half2: Self = Self(25);
+    pub const Half3: Self = Self(26);
+    pub const Half4: Self = Self(27);
+    pub const Float: Self = Self(28);
+    pub const Float2: Self = Self(29);
+    pub const Float3: Self = Self(30);
+    pub const Float4: Self = Self(31);
+    pub const Int: Self = Self(32);
+    pub const Int2: Self = Self(33);
+    pub const Int3: Self = Self(34);
+    pub const Int4: Self = Self(35);
+    pub const UInt: Self = Self(36);
+    pub const UInt2: Self = Self(37);
+    pub const UInt3: Self = Self(38);
+    pub const UInt4: Self = Self(39);
+    pub const Int1010102Normalized: Self = Self(40);
+    pub const UInt1010102Normalized: Self = Self(41);
+    pub const UChar4Normalized_BGRA: Self = Self(42);
+    pub const UChar: Self = Self(45);
+    pub const Char: Self = Self(46);
+    pub const UCharNormalized: Self = Self(47);
+    pub const CharNormalized: Self = Self(48);
+    pub const UShort: Self = Self(49);
+    pub const Short: Self = Self(50);
+    pub const UShortNormalized: Self = Self(51);
+    pub const ShortNormalized: Self = Self(52);
+    pub const Half: Self = Self(53);
+    pub const FloatRG11B10: Self = Self(54);
+    pub const FloatRGB9E5: Self = Self(55);
+}
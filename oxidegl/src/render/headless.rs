@@ -0,0 +1,195 @@
+use std::{ffi::c_void, ptr::NonNull, sync::Arc};
+
+use super::{
+    BufferArgument, BufferStorage, ComputeDispatch, ComputePipelineDescriptor, DrawCall,
+    EncoderState, FeedbackDraw, HostStageCompiler, ImageArgument, PixelFormat, PrepassDraw,
+    RenderBackend, RenderPassDescriptor, RenderPipelineDescriptor, StageCompiler,
+};
+use crate::{
+    context::state::GlState,
+    debug::gl_trace,
+    gl_enums::{MemoryBarrierMask, ShaderType},
+};
+
+/// A single operation carried out by a rendering backend, as recorded by the headless backend
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecordedCommand {
+    NewRenderEncoder(RenderPassDescriptor),
+    EndEncoding,
+    UpdateRenderEncoder(EncoderState),
+    NewRenderPipeline(RenderPipelineDescriptor),
+    BindRenderPipeline,
+    BindBuffer(BufferArgument),
+    SetBytes {
+        stage: ShaderType,
+        index: u32,
+        bytes: Box<[u8]>,
+    },
+    BindImage(ImageArgument),
+    Draw(DrawCall),
+    DrawGeometry(PrepassDraw),
+    DrawTessellation(PrepassDraw),
+    DrawFeedback(FeedbackDraw),
+    NewComputeEncoder,
+    NewComputePipeline(ComputePipelineDescriptor),
+    BindComputePipeline,
+    Dispatch(ComputeDispatch),
+    MemoryBarrier(MemoryBarrierMask),
+    Present,
+    PushDebugGroup(Box<str>),
+    PopDebugGroup,
+}
+
+/// Rendering backend that does not render anything. Instead, it records the list of operations
+/// it was asked to perform, so that GL state translation can be inspected without a GPU or a window
+#[derive(Debug)]
+pub(crate) struct HeadlessBackend {
+    /// Dimensions of the (imaginary) default framebuffer
+    dims: (u32, u32),
+    has_encoder: bool,
+    has_compute_encoder: bool,
+    commands: Vec<RecordedCommand>,
+}
+impl HeadlessBackend {
+    pub(crate) fn new(width: u32, height: u32) -> Self {
+        Self {
+            dims: (width, height),
+            has_encoder: false,
+            has_compute_encoder: false,
+            commands: Vec::new(),
+        }
+    }
+    #[inline]
+    fn record(&mut self, cmd: RecordedCommand) {
+        gl_trace!("headless backend recorded {cmd:?}");
+        self.commands.push(cmd);
+    }
+}
+
+impl RenderBackend for HeadlessBackend {
+    #[inline]
+    fn target_defaultfb_dims(&mut self) -> (u32, u32) {
+        self.dims
+    }
+    #[inline]
+    fn pixel_format(&self) -> PixelFormat {
+        PixelFormat::BGRA8Unorm_sRGB
+    }
+    #[inline]
+    fn depth_format(&self) -> Option<PixelFormat> {
+        Some(PixelFormat::Depth32Float)
+    }
+    unsafe fn new_buffer_storage(
+        &self,
+        size: usize,
+        data: Option<NonNull<c_void>>,
+    ) -> BufferStorage {
+        let mut storage = vec![0; size].into_boxed_slice();
+        if let Some(ptr) = data {
+            // Safety: caller ensures that ptr points to at least `size` initialized bytes
+            storage
+                .copy_from_slice(unsafe { std::slice::from_raw_parts(ptr.as_ptr().cast(), size) });
+        }
+        BufferStorage::Host(storage)
+    }
+    fn stage_compiler(&self) -> Arc<dyn StageCompiler> {
+        Arc::new(HostStageCompiler)
+    }
+    #[inline]
+    fn has_render_encoder(&self) -> bool {
+        self.has_encoder
+    }
+    fn new_render_encoder(&mut self, _state: &GlState, desc: &RenderPassDescriptor) {
+        self.end_encoding();
+        self.has_encoder = true;
+        self.record(RecordedCommand::NewRenderEncoder(desc.clone()));
+    }
+    fn end_encoding(&mut self) {
+        if self.has_encoder || self.has_compute_encoder {
+            self.record(RecordedCommand::EndEncoding);
+        }
+        self.has_encoder = false;
+        self.has_compute_encoder = false;
+    }
+    fn update_render_encoder(&mut self, encoder_state: &EncoderState) {
+        self.record(RecordedCommand::UpdateRenderEncoder(encoder_state.clone()));
+    }
+    fn new_render_pipeline(&mut self, _state: &GlState, desc: &RenderPipelineDescriptor) {
+        self.record(RecordedCommand::NewRenderPipeline(desc.clone()));
+    }
+    fn bind_render_pipeline(&mut self) {
+        self.record(RecordedCommand::BindRenderPipeline);
+    }
+    fn bind_buffer(&mut self, _state: &GlState, arg: &BufferArgument) {
+        self.record(RecordedCommand::BindBuffer(*arg));
+    }
+    fn set_bytes(&mut self, stage: ShaderType, index: u32, bytes: &[u8]) {
+        self.record(RecordedCommand::SetBytes {
+            stage,
+            index,
+            bytes: bytes.into(),
+        });
+    }
+    fn bind_image(&mut self, _state: &GlState, arg: &ImageArgument) {
+        self.record(RecordedCommand::BindImage(*arg));
+    }
+    fn draw(&mut self, _state: &GlState, call: &DrawCall) {
+        self.record(RecordedCommand::Draw(*call));
+    }
+    fn draw_geometry(&mut self, _state: &GlState, draw: &PrepassDraw) {
+        self.record(RecordedCommand::DrawGeometry(draw.clone()));
+    }
+    fn draw_tessellation(&mut self, _state: &GlState, draw: &PrepassDraw) {
+        self.record(RecordedCommand::DrawTessellation(draw.clone()));
+    }
+    fn draw_feedback(&mut self, _state: &GlState, draw: &FeedbackDraw) {
+        self.record(RecordedCommand::DrawFeedback(draw.clone()));
+    }
+    #[inline]
+    fn has_compute_encoder(&self) -> bool {
+        self.has_compute_encoder
+    }
+    fn new_compute_encoder(&mut self) {
+        self.end_encoding();
+        self.has_compute_encoder = true;
+        self.record(RecordedCommand::NewComputeEncoder);
+    }
+    fn new_compute_pipeline(&mut self, _state: &GlState, desc: &ComputePipelineDescriptor) {
+        self.record(RecordedCommand::NewComputePipeline(desc.clone()));
+    }
+    fn bind_compute_pipeline(&mut self) {
+        self.record(RecordedCommand::BindComputePipeline);
+    }
+    fn dispatch(&mut self, _state: &GlState, dispatch: &ComputeDispatch) {
+        self.record(RecordedCommand::Dispatch(*dispatch));
+    }
+    fn memory_barrier(&mut self, barriers: MemoryBarrierMask) {
+        self.record(RecordedCommand::MemoryBarrier(barriers));
+    }
+    fn present(&mut self) {
+        self.record(RecordedCommand::Present);
+    }
+    fn push_debug_group(&mut self, name: &str) {
+        self.record(RecordedCommand::PushDebugGroup(name.into()));
+    }
+    fn pop_debug_group(&mut self) {
+        self.record(RecordedCommand::PopDebugGroup);
+    }
+    fn recorded_commands(&self) -> Option<&[RecordedCommand]> {
+        Some(&self.commands)
+    }
+}
+
+#[test]
+fn headless_swap_buffers() {
+    use crate::context::Context;
+
+    let mut ctx = Context::new_headless(64, 64);
+    ctx.swap_buffers();
+    let cmds = ctx.recorded_commands().unwrap();
+    assert!(matches!(cmds[0], RecordedCommand::NewRenderEncoder(_)));
+    assert_eq!(
+        &cmds[1..],
+        &[RecordedCommand::EndEncoding, RecordedCommand::Present]
+    );
+}
@@ -0,0 +1,1887 @@
+use std::{ffi::c_void, mem, ptr::NonNull, sync::Arc};
+
+use ahash::{HashMap, HashMapExt};
+use log::{info, trace};
+use objc2::rc::Retained;
+use objc2_app_kit::NSView;
+use objc2_foundation::{NSCopying, NSRange, NSString, ns_string};
+use objc2_metal::{
+    MTLAttributeDescriptor, MTLAttributeFormat, MTLBarrierScope, MTLBlendFactor, MTLBlendOperation,
+    MTLBlitCommandEncoder, MTLBuffer, MTLBufferLayoutDescriptor, MTLColorWriteMask,
+    MTLCommandBuffer, MTLCommandBufferDescriptor, MTLCommandBufferErrorOption, MTLCommandEncoder,
+    MTLCommandQueue, MTLCompareFunction, MTLComputeCommandEncoder, MTLComputePipelineDescriptor,
+    MTLComputePipelineState, MTLCreateSystemDefaultDevice, MTLCullMode, MTLDataType,
+    MTLDepthStencilDescriptor, MTLDevice, MTLFunction, MTLFunctionConstantValues, MTLIndexType,
+    MTLLibrary, MTLOrigin, MTLPipelineOption, MTLPixelFormat, MTLPrimitiveTopologyClass,
+    MTLPrimitiveType, MTLRegion, MTLRenderCommandEncoder, MTLRenderPassColorAttachmentDescriptor,
+    MTLRenderPassDepthAttachmentDescriptor, MTLRenderPassDescriptor,
+    MTLRenderPassStencilAttachmentDescriptor, MTLRenderPipelineColorAttachmentDescriptor,
+    MTLRenderPipelineDescriptor, MTLRenderPipelineState, MTLResourceOptions, MTLSamplerAddressMode,
+    MTLSamplerBorderColor, MTLSamplerMinMagFilter, MTLSamplerMipFilter, MTLScissorRect, MTLSize,
+    MTLStageInputOutputDescriptor, MTLStencilDescriptor, MTLStencilOperation, MTLStepFunction,
+    MTLStorageMode, MTLTessellationControlPointIndexType, MTLTessellationFactorFormat,
+    MTLTessellationFactorStepFunction, MTLTessellationPartitionMode, MTLTexture,
+    MTLTextureDescriptor, MTLTextureSwizzle, MTLTextureType, MTLTextureUsage,
+    MTLVertexAttributeDescriptor, MTLVertexBufferLayoutDescriptor, MTLVertexDescriptor,
+    MTLVertexFormat, MTLViewport, MTLWinding,
+};
+use objc2_quartz_core::{CAMetalDrawable, CAMetalLayer, kCAFilterNearest};
+
+use super::{
+    BufferArgument, BufferStorage, ComputeDispatch, ComputePipelineDescriptor, DrawCall,
+    EncoderState, FeedbackDraw, GEOMETRY_INPUT_INDEX, GEOMETRY_OUTPUT_INDEX, ImageArgument,
+    PixelFormat, PrepassDraw, RenderBackend, RenderPassDescriptor, RenderPipelineDescriptor,
+    Renderer, StageCompiler, StageFunction, TESSELLATION_FACTOR_INDEX, VertexDescriptor,
+    VertexFormat,
+};
+use crate::{
+    context::state::{
+        ClipControl, ColorWriteMask, DrawbufferBlendState, GlState, StencilFaceState,
+    },
+    debug::{gl_debug, gl_trace, gl_warn},
+    device_properties::{MetalProperties, TextureCaps},
+    framebuffer::InternalDrawable,
+    gl_enums::{
+        BlendEquationModeEXT, BlendingFactor, ClipControlDepth, ClipControlOrigin, DepthFunction,
+        DrawBufferMode, DrawElementsType, MemoryBarrierMask, PrimitiveType, ShaderType,
+        StencilFunction, StencilOp, TextureMagFilter, TextureMinFilter, TextureSwizzle,
+        TextureTarget, TextureWrapMode, TriangleFace,
+    },
+    gl_object::ObjectName,
+    program::{
+        GeneratedStage, clip_control,
+        geometry::{GeometryBuffers, assemble_input},
+        points,
+        tessellation::{MAX_TESS_GEN_LEVEL, TessellationSpacing},
+    },
+    texture::{BorderColor, image},
+    util::ProtoObjRef,
+};
+
+/// Rendering backend that translates GL rendering work to Metal commands
+#[derive(Debug)]
+pub(crate) struct MetalBackend {
+    /// the `NSView` this context is associated with
+    view: Option<Retained<NSView>>,
+
+    /// Metal device
+    pub(crate) device: ProtoObjRef<dyn MTLDevice>,
+
+    /// Device and Metal version properties
+    pub(crate) props: MetalProperties,
+
+    /// Metal command queue
+    pub(crate) queue: ProtoObjRef<dyn MTLCommandQueue>,
+
+    /// Metal layer for rendering
+    pub(crate) layer: Retained<CAMetalLayer>,
+
+    /// Current drawable
+    // TODO: see if it's really necessary to have this as a field as opposed to generating it on-the-fly
+    pub(crate) drawable: Option<ProtoObjRef<dyn CAMetalDrawable>>,
+
+    /// Command buffer for this frame's rendering/compute commands
+    pub(crate) command_buffer: Option<ProtoObjRef<dyn MTLCommandBuffer>>,
+
+    /// Command buffer used to realize buffer to buffer copies
+    /// This is separate from the primary command buffer because
+    /// we need to realize private to shared/shared to private buffer copies which
+    /// happen before the user calls mapBuffer, which can happen
+    /// at any time, and we don't want to submit partial render commands by accident
+    pub(crate) blit_command_buffer: Option<ProtoObjRef<dyn MTLCommandBuffer>>,
+
+    /// Current encoder for blit commands. We only really need one encoder instance, so
+    /// the procedure is to commit this encoder and immediately submit the `blit_command_buffer`
+    /// upon a buffer copy flush
+    pub(crate) blit_encoder: Option<ProtoObjRef<dyn MTLBlitCommandEncoder>>,
+
+    /// Current encoder for render commands
+    pub(crate) render_encoder: Option<ProtoObjRef<dyn MTLRenderCommandEncoder>>,
+
+    /// Current render pipeline state
+    pub(crate) render_pipeline_state: Option<ProtoObjRef<dyn MTLRenderPipelineState>>,
+
+    /// Current encoder for compute dispatches. Only one of it and the render encoder is open at a time
+    pub(crate) compute_encoder: Option<ProtoObjRef<dyn MTLComputeCommandEncoder>>,
+
+    /// Current compute pipeline state, and the threadgroup size its dispatches use
+    pub(crate) compute_pipeline_state: Option<(ProtoObjRef<dyn MTLComputePipelineState>, MTLSize)>,
+
+    /// Compute pipelines of the pre-pass that emulates the geometry or tessellation control stage of the current render
+    /// pipeline, if it has one
+    pub(crate) prepass_pipelines: Option<PrepassPipelines>,
+
+    /// Compute pipelines of the pre-pass that captures the transform feedback varyings of the current render pipeline,
+    /// if its program captures any
+    pub(crate) feedback_pipelines: Option<PrepassPipelines>,
+
+    /// Buffers and bytes bound to the stages that run in the pre-pass, which are bound again on its compute encoder
+    pub(crate) prepass_arguments: HashMap<(ShaderType, u32), PrepassArgument>,
+
+    /// Textures bound to the stages that run in the pre-pass, by stage and texture argument table index
+    pub(crate) prepass_textures: HashMap<(ShaderType, u32), ProtoObjRef<dyn MTLTexture>>,
+
+    /// List of internal drawables that back the various bitplanes in the default framebuffer
+    pub(crate) internal_drawables: InternalDrawables,
+
+    /// Pixel format of the default framebuffer
+    pub(crate) pixel_format: MTLPixelFormat,
+
+    /// Depth buffer format of the default framebuffer
+    pub(crate) depth_format: Option<MTLPixelFormat>,
+
+    /// Stencil buffer format of the default framebuffer
+    pub(crate) stencil_format: Option<MTLPixelFormat>,
+
+    pub(crate) debug_group_stack: Vec<Retained<NSString>>,
+}
+/// Creates Metal libraries for linked shader stages on the compile pool threads
+#[derive(Debug)]
+pub(crate) struct MetalStageCompiler {
+    device: ProtoObjRef<dyn MTLDevice>,
+}
+// Safety: Metal devices are thread safe
+unsafe impl Send for MetalStageCompiler {}
+// Safety: see above
+unsafe impl Sync for MetalStageCompiler {}
+impl StageCompiler for MetalStageCompiler {
+    fn new_stage_function(
+        &self,
+        msl_src: &str,
+        entry_name: &str,
+        label: Option<&str>,
+    ) -> Result<StageFunction, Box<str>> {
+        let lib = self
+            .device
+            .newLibraryWithSource_options_error(&NSString::from_str(msl_src), None)
+            .map_err(|e| e.to_string())?;
+        if let Some(label) = label {
+            lib.setLabel(Some(&NSString::from_str(label)));
+        }
+        Ok(StageFunction::Metal {
+            function: lib
+                .newFunctionWithName(&NSString::from_str(entry_name))
+                .unwrap(),
+            lib,
+        })
+    }
+}
+/// Compute pipelines of the pre-pass of a draw through an emulated geometry or tessellation control stage, or of the
+/// capture of transform feedback varyings
+#[derive(Debug)]
+pub(crate) struct PrepassPipelines {
+    vertex_kernel: ProtoObjRef<dyn MTLComputePipelineState>,
+    /// kernel of the emulated stage, or the kernel that writes the captured varyings
+    stage_kernel: ProtoObjRef<dyn MTLComputePipelineState>,
+}
+/// An argument of a stage that runs in the pre-pass
+#[derive(Debug, Clone)]
+pub(crate) enum PrepassArgument {
+    Buffer { name: u32, offset: usize },
+    Bytes(Box<[u8]>),
+}
+#[derive(Default, Debug, Clone)]
+pub struct InternalDrawables {
+    front_left: Option<InternalDrawable>,
+    front_right: Option<InternalDrawable>,
+    back_left: Option<InternalDrawable>,
+    back_right: Option<InternalDrawable>,
+    depth: Option<InternalDrawable>,
+    stencil: Option<InternalDrawable>,
+}
+
+#[allow(clippy::undocumented_unsafe_blocks)]
+impl MetalBackend {
+    pub(crate) fn new(
+        pixel_format: MTLPixelFormat,
+        depth_format: Option<MTLPixelFormat>,
+        stencil_format: Option<MTLPixelFormat>,
+    ) -> Self {
+        let device = MTLCreateSystemDefaultDevice().unwrap();
+
+        let layer;
+
+        unsafe {
+            layer = CAMetalLayer::new();
+            layer.setPixelFormat(pixel_format);
+            layer.setDevice(Some(&device));
+            layer.setFramebufferOnly(false);
+        };
+        layer.setMagnificationFilter(unsafe { kCAFilterNearest });
+
+        // use `info` instead of `gl_info` because gl logging state might not be initialized yet
+        info!("Metal device: {}", device.name());
+        let queue = device
+            .newCommandQueue()
+            .expect("failed to create command queue");
+        queue.setLabel(Some(ns_string!("OxideGL command queue")));
+
+        let props = MetalProperties::new(&device);
+        Self {
+            view: None,
+            device,
+            props,
+            queue,
+            layer,
+            drawable: None,
+            command_buffer: None,
+            blit_command_buffer: None,
+            blit_encoder: None,
+
+            internal_drawables: InternalDrawables::default(),
+
+            render_encoder: None,
+            render_pipeline_state: None,
+
+            compute_encoder: None,
+            compute_pipeline_state: None,
+
+            prepass_pipelines: None,
+            feedback_pipelines: None,
+            prepass_arguments: HashMap::new(),
+            prepass_textures: HashMap::new(),
+
+            pixel_format,
+            depth_format,
+            stencil_format,
+
+            debug_group_stack: Vec::new(),
+        }
+    }
+    /// Attach this backend to the given view
+    pub(crate) fn set_view(&mut self, view: &Retained<NSView>, backing_scale_factor: f64) {
+        self.view = Some(view.clone());
+        self.layer.setFrame(view.frame());
+        self.layer.setContentsScale(backing_scale_factor);
+        // Tell the OS this view is layer-backed
+        view.setWantsLayer(true);
+        // set the backing layer
+        unsafe { view.setLayer(Some(&self.layer)) };
+
+        trace!("injected layer {:?} into NSView", &self.layer);
+    }
+    #[inline]
+    fn new_command_buffer(
+        queue: &ProtoObjRef<dyn MTLCommandQueue>,
+        label: Option<&'static NSString>,
+    ) -> ProtoObjRef<dyn MTLCommandBuffer> {
+        let buf;
+
+        // use extra metal debugging/validation when debug assertions are enabled
+        #[cfg(debug_assertions)]
+        unsafe {
+            let desc = MTLCommandBufferDescriptor::new();
+            desc.setErrorOptions(MTLCommandBufferErrorOption::EncoderExecutionStatus);
+            buf = queue
+                .commandBufferWithDescriptor(&desc)
+                .expect("failed to create command buffer");
+        }
+        #[cfg(not(debug_assertions))]
+        unsafe {
+            buf = queue
+                .commandBuffer()
+                .expect("failed to create command buffer")
+        }
+        if let Some(v) = label {
+            buf.setLabel(Some(v));
+        }
+        buf
+    }
+    #[inline]
+    fn current_command_buffer(&mut self) -> &ProtoObjRef<dyn MTLCommandBuffer> {
+        self.command_buffer.get_or_insert_with(|| {
+            let cb = Self::new_command_buffer(
+                &self.queue,
+                Some(ns_string!("OxideGL render command buffer")),
+            );
+            for group in &self.debug_group_stack {
+                cb.pushDebugGroup(group);
+            }
+            cb
+        })
+    }
+    #[inline]
+    #[track_caller]
+    pub(crate) fn current_render_encoder(&self) -> &ProtoObjRef<dyn MTLRenderCommandEncoder> {
+        self.render_encoder
+            .as_ref()
+            .expect("render command encoder should have been created!")
+    }
+    #[inline]
+    #[track_caller]
+    pub(crate) fn current_compute_encoder(&self) -> &ProtoObjRef<dyn MTLComputeCommandEncoder> {
+        self.compute_encoder
+            .as_ref()
+            .expect("compute command encoder should have been created!")
+    }
+    //TODO: use onresized or something for updating drawable size instead of effectively polling every frame
+    #[inline]
+    #[track_caller]
+    pub(crate) fn current_drawable(&mut self) -> &ProtoObjRef<dyn CAMetalDrawable> {
+        self.drawable.get_or_insert_with(|| {
+            let view = self
+                .view
+                .as_ref()
+                .expect("Can't get metal drawable before attaching Context to a view");
+            let maybe_new_size = unsafe { view.convertSizeToBacking(view.frame().size) };
+            if maybe_new_size != unsafe { self.layer.drawableSize() } {
+                // the drawable size is inherited by the next render encoder, which is created right after this
+                unsafe {
+                    self.layer.setDrawableSize(maybe_new_size);
+                };
+            }
+
+            unsafe { self.layer.nextDrawable() }
+                .expect("Failed to get next drawable from CAMetalLayer")
+        })
+    }
+    #[expect(clippy::cast_possible_truncation, reason = "we hope this works")]
+    //preconditions: view set on context
+    fn build_render_encoder(
+        &mut self,
+        pass: &RenderPassDescriptor,
+    ) -> ProtoObjRef<dyn MTLRenderCommandEncoder> {
+        let desc = unsafe { MTLRenderPassDescriptor::new() };
+
+        let mut iter = pass
+            .color_attachments
+            .iter()
+            .copied()
+            .enumerate()
+            .filter_map(|(idx, v)| v.map(|v| (idx, v)))
+            .peekable();
+        //FIXME this expect contradicts the spec, should be an early return of some kind
+        let &(_, first) = iter.peek().expect("No draw buffer set");
+        let ca_drawable_tex = unsafe { self.current_drawable().texture() };
+
+        // Use the current drawable size as the targeted size for rendering. If the drawable size changes, a new
+        // render encoder will be created, which will inherit the new size from the new drawawable
+        let dims = (
+            ca_drawable_tex.width() as u32,
+            ca_drawable_tex.height() as u32,
+        );
+
+        if pass.depth_attachment {
+            let a_desc = unsafe { MTLRenderPassDepthAttachmentDescriptor::new() };
+            a_desc.setTexture(Some(&self.get_internal_depthbuffer(dims).tex));
+
+            desc.setDepthAttachment(Some(&a_desc));
+        }
+        if pass.stencil_attachment {
+            let a_desc = unsafe { MTLRenderPassStencilAttachmentDescriptor::new() };
+            a_desc.setTexture(Some(&self.get_internal_stencilbuffer(dims).tex));
+            desc.setStencilAttachment(Some(&a_desc));
+        }
+
+        let drawbuffer = self.get_internal_drawbuffer(first, dims);
+        for (idx, buf) in iter {
+            let a_desc = MTLRenderPassColorAttachmentDescriptor::new();
+            // set attachment texture
+            if buf == DrawBufferMode::FrontLeft {
+                // Replace the texture with the current drawable
+                debug_assert_eq!(
+                    (
+                        ca_drawable_tex.width() as u32,
+                        ca_drawable_tex.height() as u32
+                    ),
+                    drawbuffer.dimensions,
+                    "Metal drawable had different dimensions than associated drawbuffer!"
+                );
+                a_desc.setTexture(Some(&ca_drawable_tex));
+            } else {
+                a_desc.setTexture(Some(&drawbuffer.tex));
+            }
+
+            // FIXME need to do load action real
+            // a_desc.setLoadAction(MTLLoadAction::Clear);
+            // a_desc.setClearColor(MTLClearColor {
+            //     red: 1.0,
+            //     green: 0.0,
+            //     blue: 0.0,
+            //     alpha: 0.5,
+            // });
+            unsafe {
+                desc.colorAttachments()
+                    .setObject_atIndexedSubscript(Some(&a_desc), idx);
+            };
+        }
+        desc.setRenderTargetWidth(dims.0 as usize);
+        desc.setRenderTargetHeight(dims.1 as usize);
+
+        let enc = self
+            .current_command_buffer()
+            .renderCommandEncoderWithDescriptor(&desc)
+            .expect("failed to create new render command encoder");
+        #[cfg(debug_assertions)]
+        enc.setLabel(Some(ns_string!("OxideGL render encoder")));
+        for group in &self.debug_group_stack {
+            enc.pushDebugGroup(group);
+        }
+        enc
+    }
+    #[inline]
+    fn check_and_resize_drawable<'a>(
+        device: &ProtoObjRef<dyn MTLDevice>,
+        dims: (u32, u32),
+        pixel_format: MTLPixelFormat,
+        gpu_private: bool,
+        r: &'a mut Option<InternalDrawable>,
+    ) -> &'a InternalDrawable {
+        if r.as_ref().is_none_or(|v| v.dimensions != dims) {
+            // Need a new internal drawable
+            let new_tex = Self::new_drawbuffer_size_format(device, dims, pixel_format, gpu_private);
+            let mut replacement = Some(InternalDrawable::new(new_tex, dims));
+            mem::swap(r, &mut replacement);
+            drop(replacement);
+        }
+        // must be Some due to code above
+        r.as_ref().unwrap()
+    }
+    pub(crate) fn get_internal_drawbuffer(
+        &mut self,
+        target: DrawBufferMode,
+        dims: (u32, u32),
+    ) -> &InternalDrawable {
+        gl_trace!("getting internal (default FB) drawbuffer for {target:?}");
+        let r = match target {
+            DrawBufferMode::FrontLeft => &mut self.internal_drawables.front_left,
+            DrawBufferMode::FrontRight => &mut self.internal_drawables.front_right,
+            DrawBufferMode::BackLeft => &mut self.internal_drawables.back_left,
+            DrawBufferMode::BackRight => &mut self.internal_drawables.back_right,
+            _ => todo!("oxidegl does not support aliased draw buffer modes"),
+        };
+        Self::check_and_resize_drawable(&self.device, dims, self.pixel_format, false, r)
+    }
+    // precondition: user specifies depth format for defaultfb
+    pub(crate) fn get_internal_depthbuffer(&mut self, dims: (u32, u32)) -> &InternalDrawable {
+        Self::check_and_resize_drawable(
+            &self.device,
+            dims,
+            self.depth_format.expect("tried to generate a depth buffer for the default framebuffer, but no depth format was specified at context creation!"),
+            true,
+            &mut self.internal_drawables.depth,
+        )
+    }
+    // precondition: user specifies stencil format for defaultfb
+    pub(crate) fn get_internal_stencilbuffer(&mut self, dims: (u32, u32)) -> &InternalDrawable {
+        Self::check_and_resize_drawable(
+            &self.device,
+            dims,
+            self.depth_format.expect("tried to generate a stencil buffer for the default framebuffer, but no stencil format was specified at context creation!"),
+            true,
+            &mut self.internal_drawables.stencil,
+        )
+    }
+    pub(crate) fn new_drawbuffer_size_format(
+        device: &ProtoObjRef<dyn MTLDevice>,
+        size: (u32, u32),
+        format: MTLPixelFormat,
+        gpu_private: bool,
+    ) -> ProtoObjRef<dyn MTLTexture> {
+        gl_debug!(
+            "creating new {}x{} {format:?} drawable texture",
+            size.0,
+            size.1
+        );
+        let desc = unsafe { MTLTextureDescriptor::new() };
+        if gpu_private {
+            desc.setStorageMode(MTLStorageMode::Private);
+            desc.setAllowGPUOptimizedContents(true);
+        }
+        unsafe { desc.setWidth(size.0 as usize) };
+        unsafe { desc.setHeight(size.1 as usize) };
+        desc.setPixelFormat(format);
+        desc.setUsage(MTLTextureUsage::RenderTarget);
+        device
+            .newTextureWithDescriptor(&desc)
+            .expect("failed to create drawable texture")
+    }
+    fn build_vertex_descriptor(desc: &VertexDescriptor) -> Retained<MTLVertexDescriptor> {
+        let mtl_vertex_desc = unsafe { MTLVertexDescriptor::new() };
+        for layout in &desc.layouts {
+            let layout_desc = MTLVertexBufferLayoutDescriptor::new();
+            unsafe { layout_desc.setStride(layout.stride.into()) };
+
+            unsafe {
+                mtl_vertex_desc
+                    .layouts()
+                    .setObject_atIndexedSubscript(Some(&layout_desc), layout.buffer_index as usize);
+            };
+        }
+        for attr in &desc.attributes {
+            let mtl_attrib_desc = MTLVertexAttributeDescriptor::new();
+
+            unsafe { mtl_attrib_desc.setBufferIndex(attr.buffer_index as usize) };
+            mtl_attrib_desc.setFormat(attr.format.into());
+            unsafe { mtl_attrib_desc.setOffset(attr.offset as usize) };
+
+            unsafe {
+                mtl_vertex_desc.attributes().setObject_atIndexedSubscript(
+                    Some(&mtl_attrib_desc),
+                    attr.attribute_index as usize,
+                );
+            };
+        }
+        mtl_vertex_desc
+    }
+    /// Equivalent of [`MetalBackend::build_vertex_descriptor`] for the vertex kernel of the geometry pre-pass, which
+    /// has a thread per vertex along x and per instance along y
+    fn build_stage_input_descriptor(
+        desc: &VertexDescriptor,
+    ) -> Retained<MTLStageInputOutputDescriptor> {
+        let stage_in = unsafe { MTLStageInputOutputDescriptor::new() };
+        for layout in &desc.layouts {
+            let layout_desc = unsafe { MTLBufferLayoutDescriptor::new() };
+            unsafe { layout_desc.setStride(layout.stride.into()) };
+            layout_desc.setStepFunction(MTLStepFunction::ThreadPositionInGridX);
+
+            unsafe {
+                stage_in
+                    .layouts()
+                    .setObject_atIndexedSubscript(Some(&layout_desc), layout.buffer_index as usize);
+            };
+        }
+        for attr in &desc.attributes {
+            let attrib_desc = unsafe { MTLAttributeDescriptor::new() };
+
+            unsafe { attrib_desc.setBufferIndex(attr.buffer_index as usize) };
+            // attribute formats share their values with vertex formats
+            attrib_desc.setFormat(MTLAttributeFormat(attr.format.0));
+            unsafe { attrib_desc.setOffset(attr.offset as usize) };
+
+            unsafe {
+                stage_in.attributes().setObject_atIndexedSubscript(
+                    Some(&attrib_desc),
+                    attr.attribute_index as usize,
+                );
+            };
+        }
+        stage_in
+    }
+    /// Build the pipelines of the pre-pass that runs the vertex stage and `stage_kernel`
+    fn build_prepass_pipelines(
+        &self,
+        vertex_kernel: &GeneratedStage,
+        stage_kernel: &ProtoObjRef<dyn MTLFunction>,
+        vertex_desc: &VertexDescriptor,
+    ) -> PrepassPipelines {
+        let desc = MTLComputePipelineDescriptor::new();
+        #[cfg(debug_assertions)]
+        desc.setLabel(Some(ns_string!("OxideGL pre-pass vertex kernel")));
+        desc.setComputeFunction(Some(Self::generated_function(vertex_kernel)));
+        desc.setStageInputDescriptor(Some(&Self::build_stage_input_descriptor(vertex_desc)));
+        let vertex_kernel = unsafe {
+            self.device
+                .newComputePipelineStateWithDescriptor_options_reflection_error(
+                    &desc,
+                    MTLPipelineOption::None,
+                    None,
+                )
+        }
+        .expect("failed to create pre-pass vertex kernel pipeline state");
+        let stage_kernel = self
+            .device
+            .newComputePipelineStateWithFunction_error(stage_kernel)
+            .expect("failed to create pre-pass stage kernel pipeline state");
+        PrepassPipelines {
+            vertex_kernel,
+            stage_kernel,
+        }
+    }
+    /// Allocate a buffer of `len` words for a pre-pass, starting with `header`
+    fn new_prepass_buffer(&self, header: &[u32], len: usize) -> ProtoObjRef<dyn MTLBuffer> {
+        let buf = self
+            .device
+            .newBufferWithLength_options(len * 4, MTLResourceOptions::StorageModeShared)
+            .expect("Metal Buffer allocation failiure");
+        // Safety: the buffer has shared storage and is at least as long as the header
+        unsafe {
+            buf.contents()
+                .cast::<u32>()
+                .as_ptr()
+                .copy_from_nonoverlapping(header.as_ptr(), header.len());
+        }
+        buf
+    }
+    /// Encode and commit the pre-pass of a draw: the vertex kernel over the vertices of the draw, then the stage kernel
+    /// of `pipelines` with `groups` threadgroups of `threads` threads. The arguments of `stage` (if the stage kernel
+    /// emulates one) and `buffers` are bound to both kernels, `stage_buffers` (buffer, offset and index) only to the
+    /// stage kernel
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "internal helper shared by pre-pass draws"
+    )]
+    fn encode_prepass(
+        &self,
+        state: &GlState,
+        pipelines: &PrepassPipelines,
+        draw: &PrepassDraw,
+        stage: Option<ShaderType>,
+        buffers: &[(&ProtoObjRef<dyn MTLBuffer>, u32)],
+        stage_buffers: &[(&ProtoObjRef<dyn MTLBuffer>, usize, u32)],
+        groups: MTLSize,
+        threads: MTLSize,
+        label: &'static NSString,
+    ) {
+        // the pre-pass is committed right away, so it runs before the render command buffer that reads its output
+        let cb = Self::new_command_buffer(&self.queue, Some(label));
+        let enc = cb
+            .computeCommandEncoder()
+            .expect("failed to create compute command encoder");
+        let vertices = MTLSize {
+            width: draw.vertex_span as usize,
+            height: draw.instance_count as usize,
+            depth: 1,
+        };
+        for (stage, pipeline) in [
+            (Some(ShaderType::VertexShader), &pipelines.vertex_kernel),
+            (stage, &pipelines.stage_kernel),
+        ] {
+            enc.setComputePipelineState(pipeline);
+            if let Some(stage) = stage {
+                self.bind_prepass_arguments(state, &enc, stage);
+            }
+            for &(buffer, index) in buffers {
+                unsafe { enc.setBuffer_offset_atIndex(Some(buffer), 0, index as usize) };
+            }
+            // dispatches of a compute encoder run one after the other, so the stage kernel sees the vertex records
+            if stage == Some(ShaderType::VertexShader) {
+                enc.setStageInRegion(MTLRegion {
+                    origin: MTLOrigin {
+                        x: draw.vertex_base as usize,
+                        y: draw.base_instance as usize,
+                        z: 0,
+                    },
+                    size: vertices,
+                });
+                let width = pipeline.threadExecutionWidth();
+                enc.dispatchThreads_threadsPerThreadgroup(
+                    vertices,
+                    MTLSize {
+                        width,
+                        height: 1,
+                        depth: 1,
+                    },
+                );
+            } else {
+                for &(buffer, offset, index) in stage_buffers {
+                    unsafe { enc.setBuffer_offset_atIndex(Some(buffer), offset, index as usize) };
+                }
+                enc.dispatchThreadgroups_threadsPerThreadgroup(groups, threads);
+            }
+        }
+        enc.endEncoding();
+        cb.commit();
+    }
+    /// Bind the arguments bound to `stage` on the render encoder to a compute encoder of the pre-pass
+    fn bind_prepass_arguments(
+        &self,
+        state: &GlState,
+        enc: &ProtoObjRef<dyn MTLComputeCommandEncoder>,
+        stage: ShaderType,
+    ) {
+        for (&(arg_stage, index), arg) in &self.prepass_arguments {
+            if arg_stage != stage {
+                continue;
+            }
+            match arg {
+                &PrepassArgument::Buffer { name, offset } => {
+                    if let Some(buf) = Self::metal_buffer(state, name) {
+                        unsafe { enc.setBuffer_offset_atIndex(Some(buf), offset, index as usize) };
+                    }
+                }
+                PrepassArgument::Bytes(bytes) => unsafe {
+                    enc.setBytes_length_atIndex(
+                        NonNull::from(&**bytes).cast(),
+                        bytes.len(),
+                        index as usize,
+                    );
+                },
+            }
+        }
+        for (&(arg_stage, index), texture) in &self.prepass_textures {
+            if arg_stage == stage {
+                unsafe { enc.setTexture_atIndex(Some(texture), index as usize) };
+            }
+        }
+    }
+    /// A texture that exposes the level, layers and format of `texture` an image argument binds, which is `texture`
+    /// itself if it binds all of it
+    fn image_view(
+        texture: &ProtoObjRef<dyn MTLTexture>,
+        arg: &ImageArgument,
+        pixel_format: MTLPixelFormat,
+    ) -> Option<ProtoObjRef<dyn MTLTexture>> {
+        let ty = texture.textureType();
+        let slices = match ty {
+            MTLTextureType::TypeCube => 6,
+            MTLTextureType::TypeCubeArray => 6 * texture.arrayLength(),
+            _ => texture.arrayLength(),
+        };
+        // a single layer of an array or cube texture is bound as a texture of the non-arrayed type
+        let (ty, slices) = match (arg.layer, ty) {
+            (Some(layer), MTLTextureType::Type1DArray) => {
+                (MTLTextureType::Type1D, layer as usize..layer as usize + 1)
+            }
+            (
+                Some(layer),
+                MTLTextureType::Type2DArray
+                | MTLTextureType::TypeCube
+                | MTLTextureType::TypeCubeArray,
+            ) => (MTLTextureType::Type2D, layer as usize..layer as usize + 1),
+            _ => (ty, 0..slices),
+        };
+        if ty == texture.textureType()
+            && pixel_format == texture.pixelFormat()
+            && arg.level == 0
+            && texture.mipmapLevelCount() == 1
+        {
+            return Some(texture.clone());
+        }
+        unsafe {
+            texture.newTextureViewWithPixelFormat_textureType_levels_slices(
+                pixel_format,
+                ty,
+                NSRange::new(arg.level as usize, 1),
+                NSRange::new(slices.start, slices.len()),
+            )
+        }
+    }
+    #[track_caller]
+    fn metal_buffer(state: &GlState, name: u32) -> Option<&ProtoObjRef<dyn MTLBuffer>> {
+        let buf = state.buffer_list.get(ObjectName::from_raw(name));
+        match &buf.allocation.as_ref()?.storage {
+            BufferStorage::Metal(b) => Some(b),
+            BufferStorage::Host(_) => unreachable!("Metal backend encountered host buffer storage"),
+        }
+    }
+    /// Draw `call` from 32 bit indices assembled on the host, for the primitive types (line loops, triangle fans and the
+    /// adjacency types) and index types (8 bit indices) that Metal can't draw directly. Without a geometry stage the
+    /// adjacent vertices of adjacency primitives are never seen by a shader, so they are dropped
+    fn draw_lowered(&mut self, state: &GlState, call: &DrawCall) {
+        use PrimitiveType as P;
+        let (DrawCall::Arrays {
+            mode,
+            instance_count,
+            base_instance,
+            ..
+        }
+        | DrawCall::Elements {
+            mode,
+            instance_count,
+            base_instance,
+            ..
+        }) = *call;
+        // vertices of each assembled primitive, and which of them make up the list primitive that gets drawn
+        let (primitive, stride, kept): (_, _, &[usize]) = match mode {
+            P::Points => (MTLPrimitiveType::Point, 1, &[0]),
+            P::Lines | P::LineStrip | P::LineLoop => (MTLPrimitiveType::Line, 2, &[0, 1]),
+            P::LinesAdjacency | P::LineStripAdjacency => (MTLPrimitiveType::Line, 4, &[1, 2]),
+            P::Triangles | P::TriangleStrip | P::TriangleFan => {
+                (MTLPrimitiveType::Triangle, 3, &[0, 1, 2])
+            }
+            P::TrianglesAdjacency | P::TriangleStripAdjacency => {
+                (MTLPrimitiveType::Triangle, 6, &[0, 2, 4])
+            }
+            P::Patches | P::Quads => {
+                unreachable!(
+                    "{mode:?} primitives are either rejected or drawn through tessellation"
+                )
+            }
+        };
+        let ids = call.vertex_ids(state);
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "draws have less than 2^32 vertices"
+        )]
+        let indices: Vec<u32> = assemble_input(mode, ids.len() as u32)
+            .chunks_exact(stride)
+            .flat_map(|p| kept.iter().map(move |&v| p[v]))
+            .map(|v| ids[v as usize])
+            .collect();
+        if indices.is_empty() {
+            return;
+        }
+        let index_buffer = self.new_prepass_buffer(&indices, indices.len());
+        unsafe {
+            self.current_render_encoder().drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset_instanceCount_baseVertex_baseInstance(
+                primitive,
+                indices.len(),
+                MTLIndexType::UInt32,
+                &index_buffer,
+                0,
+                instance_count as usize,
+                0,
+                base_instance as usize,
+            );
+        }
+    }
+    #[track_caller]
+    fn metal_function(state: &GlState, stage: ShaderType) -> &ProtoObjRef<dyn MTLFunction> {
+        let Some(StageFunction::Metal { function, .. }) =
+            Renderer::linked_stage(state, stage).map(|s| &s.function)
+        else {
+            panic!("{stage:?} stage was missing or not linked by the Metal backend");
+        };
+        function
+    }
+    /// Specialize the vertex function of a render pipeline for its clip volume, enabled clip planes and point size. The
+    /// function is used as is with the default GL clip volume, no enabled planes and a point size of 1, which the
+    /// function constants of the generated epilogue default to (see [`clip_control`] and [`points`])
+    fn vertex_function(
+        stage: &StageFunction,
+        desc: &RenderPipelineDescriptor,
+    ) -> ProtoObjRef<dyn MTLFunction> {
+        let StageFunction::Metal { function, lib } = stage else {
+            panic!("vertex function was not compiled by the Metal backend");
+        };
+        let clip = desc.clip_control;
+        if clip == ClipControl::default()
+            && desc.clip_distances == 0
+            && desc.point_size == Some(1.0)
+        {
+            return function.clone();
+        }
+        let planes = (0..clip_control::MAX_CLIP_DISTANCES).map(|plane| {
+            (
+                clip_control::CLIP_DISTANCE_CONSTANT + plane,
+                desc.clip_distances & (1 << plane) != 0,
+            )
+        });
+        let flags = [
+            (
+                clip_control::ZERO_TO_ONE_CONSTANT,
+                clip.depth == ClipControlDepth::ZeroToOne,
+            ),
+            (
+                clip_control::UPPER_LEFT_CONSTANT,
+                clip.origin == ClipControlOrigin::UpperLeft,
+            ),
+            (
+                points::PROGRAM_POINT_SIZE_CONSTANT,
+                desc.point_size.is_none(),
+            ),
+        ]
+        .into_iter()
+        .chain(planes)
+        .collect::<Vec<_>>();
+        let size = [(points::POINT_SIZE_CONSTANT, desc.point_size.unwrap_or(1.0))];
+        Self::specialize(function, lib, &flags, &size)
+    }
+    /// Specialize the fragment function of a render pipeline for its point sprite origin, which the function constant
+    /// of the generated prologue defaults to the GL default of (see [`points`])
+    fn fragment_function(
+        state: &GlState,
+        desc: &RenderPipelineDescriptor,
+    ) -> ProtoObjRef<dyn MTLFunction> {
+        let Some(StageFunction::Metal { function, lib }) =
+            Renderer::linked_stage(state, ShaderType::FragmentShader).map(|s| &s.function)
+        else {
+            panic!("fragment stage was missing or not linked by the Metal backend");
+        };
+        if desc.point_sprite_coord_origin == ClipControlOrigin::UpperLeft {
+            return function.clone();
+        }
+        Self::specialize(
+            function,
+            lib,
+            &[(points::SPRITE_LOWER_LEFT_CONSTANT, true)],
+            &[],
+        )
+    }
+    /// Create the variant of `function` with the given bool and float function constant values
+    fn specialize(
+        function: &ProtoObjRef<dyn MTLFunction>,
+        lib: &ProtoObjRef<dyn MTLLibrary>,
+        flags: &[(u32, bool)],
+        floats: &[(u32, f32)],
+    ) -> ProtoObjRef<dyn MTLFunction> {
+        let values = MTLFunctionConstantValues::new();
+        // Safety: the constants are declared with the types of the values, which are copied before this returns
+        unsafe {
+            for (index, value) in flags {
+                values.setConstantValue_type_atIndex(
+                    NonNull::from(value).cast(),
+                    MTLDataType::Bool,
+                    *index as usize,
+                );
+            }
+            for (index, value) in floats {
+                values.setConstantValue_type_atIndex(
+                    NonNull::from(value).cast(),
+                    MTLDataType::Float,
+                    *index as usize,
+                );
+            }
+        }
+        lib.newFunctionWithName_constantValues_error(&function.name(), &values)
+            .expect("failed to specialize a function of a render pipeline")
+    }
+    #[track_caller]
+    fn generated_function(stage: &GeneratedStage) -> &ProtoObjRef<dyn MTLFunction> {
+        let StageFunction::Metal { function, .. } = &stage.function else {
+            panic!(
+                "generated stage {} was not compiled by the Metal backend",
+                stage.entry_name
+            );
+        };
+        function
+    }
+}
+
+#[allow(clippy::undocumented_unsafe_blocks)]
+impl RenderBackend for MetalBackend {
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "we hope this works"
+    )]
+    #[inline]
+    // "guesstimate," it can still get out of sync with the actual size of the current drawable
+    fn target_defaultfb_dims(&mut self) -> (u32, u32) {
+        // reproduce the calculation done by the CAMetalLayer when generating the next drawable size.
+        // (from https://developer.apple.com/documentation/quartzcore/cametallayer/1478174-drawablesize)
+        // We don't read [Layer drawableSize] directly because it proxies a field on the actual drawable
+        // and is 0 if the layer hasn't materialized a drawable yet.
+        let size = self.layer.bounds().size;
+        let scale = self.layer.contentsScale();
+        let size = (size.width * scale, size.height * scale);
+        debug_assert!(
+            (size.0 - size.0.floor()) == 0.0
+                && (size.1 - size.1.floor()) == 0.0
+                && size.0 > 0.0
+                && size.1 > 0.0,
+            "bad size ({size:?})"
+        );
+        (size.0 as u32, size.1 as u32)
+    }
+    #[inline]
+    fn pixel_format(&self) -> PixelFormat {
+        self.pixel_format.into()
+    }
+    #[inline]
+    fn depth_format(&self) -> Option<PixelFormat> {
+        self.depth_format.map(Into::into)
+    }
+    unsafe fn new_buffer_storage(
+        &self,
+        size: usize,
+        data: Option<NonNull<c_void>>,
+    ) -> BufferStorage {
+        // TODO: lower-coherence storage modes (StorageModeManaged or single-upload StorageModePrivate).
+        // Shared backing buffers are going to annihilate perf with larger buffers
+        let options = MTLResourceOptions::StorageModeShared;
+        let buffer = if let Some(ptr) = data {
+            // Safety: caller ensures pointer validity, and that the slice implicitly formed by (data, size) is correctly initialized
+            unsafe {
+                self.device
+                    .newBufferWithBytes_length_options(ptr, size, options)
+            }
+        } else {
+            self.device.newBufferWithLength_options(size, options)
+        };
+        BufferStorage::Metal(buffer.expect("Metal Buffer allocation failiure"))
+    }
+    fn stage_compiler(&self) -> Arc<dyn StageCompiler> {
+        Arc::new(MetalStageCompiler {
+            device: self.device.clone(),
+        })
+    }
+    #[inline]
+    fn has_render_encoder(&self) -> bool {
+        self.render_encoder.is_some()
+    }
+    fn new_render_encoder(&mut self, state: &GlState, desc: &RenderPassDescriptor) {
+        debug_assert!(
+            state.framebuffer_binding.is_none(),
+            "Metal backend does not support user-defined framebuffers yet"
+        );
+        self.end_encoding();
+        self.render_encoder = Some(self.build_render_encoder(desc));
+    }
+    #[inline]
+    fn end_encoding(&mut self) {
+        if let Some(enc) = &self.render_encoder {
+            enc.endEncoding();
+        }
+        self.render_encoder = None;
+        if let Some(enc) = &self.compute_encoder {
+            enc.endEncoding();
+        }
+        self.compute_encoder = None;
+    }
+    fn update_render_encoder(&mut self, encoder_state: &EncoderState) {
+        fn stencil_descriptor_for_stencil_state(
+            state: &StencilFaceState,
+            writemask: u32,
+        ) -> Retained<MTLStencilDescriptor> {
+            let desc = unsafe { MTLStencilDescriptor::new() };
+            desc.setStencilCompareFunction(state.func.into());
+            desc.setStencilFailureOperation(state.fail_action.into());
+
+            desc.setDepthFailureOperation(state.depth_fail_action.into());
+            desc.setDepthStencilPassOperation(state.depth_pass_action.into());
+
+            desc.setWriteMask(writemask);
+            desc.setReadMask(state.mask);
+            desc
+        }
+        // the render target is always the drawable, and Metal requires the scissor rect to lie within it
+        let (target_width, target_height) = self.target_defaultfb_dims();
+        let enc = self.current_render_encoder();
+        if encoder_state.depth.is_some() || encoder_state.stencil.is_some() {
+            let desc = unsafe { MTLDepthStencilDescriptor::new() };
+            if let Some((func, write)) = encoder_state.depth {
+                desc.setDepthCompareFunction(func.into());
+                desc.setDepthWriteEnabled(write);
+            }
+            if let Some([(front, front_mask), (back, back_mask)]) = &encoder_state.stencil {
+                let front = stencil_descriptor_for_stencil_state(front, *front_mask);
+                desc.setFrontFaceStencil(Some(&front));
+                let back = stencil_descriptor_for_stencil_state(back, *back_mask);
+                desc.setBackFaceStencil(Some(&back));
+            }
+            let ds_state = self
+                .device
+                .newDepthStencilStateWithDescriptor(&desc)
+                .expect("failed to create MTLDepthStencilState");
+            enc.setDepthStencilState(Some(&ds_state));
+        }
+        if let Some(face) = encoder_state.cull_mode {
+            enc.setCullMode(face.into());
+        }
+
+        let blend_col = encoder_state.blend_color;
+        enc.setBlendColorRed_green_blue_alpha(
+            blend_col[0],
+            blend_col[1],
+            blend_col[2],
+            blend_col[3],
+        );
+
+        // GL front faces wind counterclockwise with y pointing up, so they wind clockwise in Metal window coordinates,
+        // unless the vertex function flipped them vertically for an upper left origin
+        enc.setFrontFacingWinding(match encoder_state.clip_origin {
+            ClipControlOrigin::LowerLeft => MTLWinding::Clockwise,
+            ClipControlOrigin::UpperLeft => MTLWinding::CounterClockwise,
+        });
+
+        let [x, y, width, height] = encoder_state.viewport;
+        let [znear, zfar] = encoder_state.depth_range;
+        enc.setViewport(MTLViewport {
+            originX: x.into(),
+            originY: y.into(),
+            width: width.into(),
+            height: height.into(),
+            znear,
+            zfar,
+        });
+
+        let [x, y, width, height] =
+            encoder_state
+                .scissor
+                .unwrap_or([0, 0, target_width, target_height]);
+        let (x, y) = (x.min(target_width), y.min(target_height));
+        enc.setScissorRect(MTLScissorRect {
+            x: x as usize,
+            y: y as usize,
+            width: width.min(target_width - x) as usize,
+            height: height.min(target_height - y) as usize,
+        });
+    }
+    fn new_render_pipeline(&mut self, state: &GlState, desc: &RenderPipelineDescriptor) {
+        let mtl_desc = MTLRenderPipelineDescriptor::new();
+        #[cfg(debug_assertions)]
+        mtl_desc.setLabel(Some(ns_string!("OxideGL render pipeline")));
+
+        let attachments = mtl_desc.colorAttachments();
+        for (i, attachment) in desc.color_attachments.iter().enumerate() {
+            if let Some(attachment) = attachment {
+                let attachment_desc = unsafe { MTLRenderPipelineColorAttachmentDescriptor::new() };
+                attachment_desc.setPixelFormat(attachment.pixel_format.into());
+
+                // Apply blend state if present
+                attachment.blend.apply_to_mtl_desc(&attachment_desc);
+                unsafe { attachments.setObject_atIndexedSubscript(Some(&attachment_desc), i) };
+            }
+        }
+        if let Some(depth_format) = desc.depth_attachment_format {
+            mtl_desc.setDepthAttachmentPixelFormat(depth_format.into());
+        }
+        mtl_desc.setFragmentFunction(Some(&Self::fragment_function(state, desc)));
+        // the stages that fetch vertex attributes are replaced by variants that convert them, if they need it
+        let fetch_stages = Renderer::fetch_stages(state, &desc.attribute_conversions);
+        if let (Some(tessellation), Some(info)) =
+            (Renderer::linked_tessellation(state), desc.tessellation)
+        {
+            // vertex attributes are fetched by the pre-pass, the evaluation stage only reads the records of the patches
+            mtl_desc.setVertexFunction(Some(&Self::vertex_function(
+                &tessellation.evaluation.function,
+                desc,
+            )));
+            let partition = match info.spacing {
+                TessellationSpacing::Equal => MTLTessellationPartitionMode::Integer,
+                TessellationSpacing::FractionalEven => MTLTessellationPartitionMode::FractionalEven,
+                TessellationSpacing::FractionalOdd => MTLTessellationPartitionMode::FractionalOdd,
+            };
+            // the tessellation coordinates are flipped to the lower left origin of GL, which mirrors the domain
+            let winding = if info.clockwise {
+                MTLWinding::CounterClockwise
+            } else {
+                MTLWinding::Clockwise
+            };
+            unsafe {
+                mtl_desc.setMaxTessellationFactor(MAX_TESS_GEN_LEVEL as usize);
+                mtl_desc.setTessellationPartitionMode(partition);
+                mtl_desc.setTessellationOutputWindingOrder(winding);
+                mtl_desc
+                    .setTessellationFactorStepFunction(MTLTessellationFactorStepFunction::PerPatch);
+                mtl_desc.setTessellationFactorFormat(MTLTessellationFactorFormat::Half);
+                mtl_desc.setTessellationControlPointIndexType(
+                    MTLTessellationControlPointIndexType::None,
+                );
+            }
+            self.prepass_pipelines = Some(self.build_prepass_pipelines(
+                fetch_stages.map_or(&tessellation.vertex_kernel, |s| &s.vertex),
+                Self::metal_function(state, ShaderType::TessControlShader),
+                &desc.vertex_descriptor,
+            ));
+        } else if let Some(geometry) = Renderer::linked_geometry(state) {
+            // vertex attributes are fetched by the pre-pass, the pass-through stage only reads the emitted vertices
+            mtl_desc.setVertexFunction(Some(&Self::vertex_function(
+                &geometry.passthrough.function,
+                desc,
+            )));
+            let topology = match desc.geometry_output {
+                Some(PrimitiveType::Points) => MTLPrimitiveTopologyClass::Point,
+                Some(PrimitiveType::Lines) => MTLPrimitiveTopologyClass::Line,
+                _ => MTLPrimitiveTopologyClass::Triangle,
+            };
+            unsafe { mtl_desc.setInputPrimitiveTopology(topology) };
+            self.prepass_pipelines = Some(self.build_prepass_pipelines(
+                fetch_stages.map_or(&geometry.vertex_kernel, |s| &s.vertex),
+                Self::metal_function(state, ShaderType::GeometryShader),
+                &desc.vertex_descriptor,
+            ));
+        } else {
+            let function = match fetch_stages {
+                Some(stages) => &stages.vertex.function,
+                None => {
+                    &Renderer::linked_stage(state, ShaderType::VertexShader)
+                        .expect("tried to build a render pipeline without a vertex stage")
+                        .function
+                }
+            };
+            mtl_desc.setVertexFunction(Some(&Self::vertex_function(function, desc)));
+            //TODO: primitive topology real
+            // unsafe { desc.setInputPrimitiveTopology(MTLPrimitiveTopologyClass::Triangle) };
+            let v_desc = Self::build_vertex_descriptor(&desc.vertex_descriptor);
+            mtl_desc.setVertexDescriptor(Some(&v_desc));
+            self.prepass_pipelines = None;
+        }
+        // programs with transform feedback varyings can't have geometry or tessellation stages
+        self.feedback_pipelines = Renderer::linked_feedback(state).map(|feedback| {
+            self.build_prepass_pipelines(
+                fetch_stages
+                    .and_then(|s| s.feedback.as_ref())
+                    .unwrap_or(&feedback.vertex_kernel),
+                Self::generated_function(&feedback.kernel),
+                &desc.vertex_descriptor,
+            )
+        });
+        self.render_pipeline_state = Some(
+            self.device
+                .newRenderPipelineStateWithDescriptor_error(&mtl_desc)
+                .expect("failed to create pipeline state"),
+        );
+    }
+    fn bind_render_pipeline(&mut self) {
+        let ps = self.render_pipeline_state.as_ref().unwrap();
+        self.current_render_encoder().setRenderPipelineState(ps);
+    }
+    fn bind_buffer(&mut self, state: &GlState, arg: &BufferArgument) {
+        if arg.stage == ShaderType::ComputeShader {
+            if let Some(buf) = Self::metal_buffer(state, arg.buffer) {
+                unsafe {
+                    self.current_compute_encoder().setBuffer_offset_atIndex(
+                        Some(buf),
+                        arg.offset,
+                        arg.index as usize,
+                    );
+                }
+            }
+            return;
+        }
+        if matches!(
+            arg.stage,
+            ShaderType::VertexShader | ShaderType::GeometryShader | ShaderType::TessControlShader
+        ) {
+            self.prepass_arguments.insert(
+                (arg.stage, arg.index),
+                PrepassArgument::Buffer {
+                    name: arg.buffer,
+                    offset: arg.offset,
+                },
+            );
+        }
+        let enc = self.current_render_encoder();
+        if let Some(buf) = Self::metal_buffer(state, arg.buffer) {
+            match arg.stage {
+                // the geometry and tessellation control stages only run in the pre-pass
+                ShaderType::GeometryShader | ShaderType::TessControlShader => {}
+                ShaderType::VertexShader | ShaderType::TessEvaluationShader => unsafe {
+                    enc.setVertexBuffer_offset_atIndex(Some(buf), arg.offset, arg.index as usize);
+                },
+                ShaderType::FragmentShader => unsafe {
+                    enc.setFragmentBuffer_offset_atIndex(Some(buf), arg.offset, arg.index as usize);
+                },
+                s => unreachable!("tried to bind a buffer to the render encoder for {s:?}"),
+            }
+        }
+    }
+    fn set_bytes(&mut self, stage: ShaderType, index: u32, bytes: &[u8]) {
+        if stage == ShaderType::ComputeShader {
+            unsafe {
+                self.current_compute_encoder().setBytes_length_atIndex(
+                    NonNull::from(bytes).cast(),
+                    bytes.len(),
+                    index as usize,
+                );
+            }
+            return;
+        }
+        if matches!(
+            stage,
+            ShaderType::VertexShader | ShaderType::GeometryShader | ShaderType::TessControlShader
+        ) {
+            self.prepass_arguments
+                .insert((stage, index), PrepassArgument::Bytes(bytes.into()));
+        }
+        let enc = self.current_render_encoder();
+        let ptr = NonNull::from(bytes).cast();
+        match stage {
+            ShaderType::GeometryShader | ShaderType::TessControlShader => {}
+            ShaderType::VertexShader | ShaderType::TessEvaluationShader => unsafe {
+                enc.setVertexBytes_length_atIndex(ptr, bytes.len(), index as usize);
+            },
+            ShaderType::FragmentShader => unsafe {
+                enc.setFragmentBytes_length_atIndex(ptr, bytes.len(), index as usize);
+            },
+            s => unreachable!("tried to set bytes on the render encoder for {s:?}"),
+        }
+    }
+    fn bind_image(&mut self, state: &GlState, arg: &ImageArgument) {
+        let Some(texture) = state
+            .texture_list
+            .get(ObjectName::from_raw(arg.texture))
+            .mtl_texture()
+        else {
+            gl_warn!("texture {} of an image unit has no storage", arg.texture);
+            return;
+        };
+        let pixel_format = image::image_format(arg.format)
+            .expect("image unit had an invalid format")
+            .0
+            .into();
+        let caps = self
+            .props
+            .get_texture_caps(pixel_format)
+            .unwrap_or(TextureCaps::NONE);
+        if arg.write && !caps.contains(TextureCaps::WRITE) {
+            gl_warn!("{:?} images can't be written to on this device", arg.format);
+            return;
+        }
+        if arg.atomic && !caps.contains(TextureCaps::ATOMIC) {
+            gl_warn!(
+                "{:?} images don't support atomic operations on this device",
+                arg.format
+            );
+            return;
+        }
+        let Some(view) = Self::image_view(texture, arg, pixel_format) else {
+            gl_warn!(
+                "failed to create a view of texture {} for an image unit",
+                arg.texture
+            );
+            return;
+        };
+        let index = arg.index as usize;
+        match arg.stage {
+            ShaderType::ComputeShader => unsafe {
+                self.current_compute_encoder()
+                    .setTexture_atIndex(Some(&view), index);
+            },
+            ShaderType::VertexShader
+            | ShaderType::GeometryShader
+            | ShaderType::TessControlShader => {
+                let enc = self.current_render_encoder();
+                // the geometry and tessellation control stages only run in the pre-pass
+                if arg.stage == ShaderType::VertexShader {
+                    unsafe { enc.setVertexTexture_atIndex(Some(&view), index) };
+                }
+                self.prepass_textures.insert((arg.stage, arg.index), view);
+            }
+            ShaderType::TessEvaluationShader => unsafe {
+                self.current_render_encoder()
+                    .setVertexTexture_atIndex(Some(&view), index);
+            },
+            ShaderType::FragmentShader => unsafe {
+                self.current_render_encoder()
+                    .setFragmentTexture_atIndex(Some(&view), index);
+            },
+        }
+    }
+    fn draw(&mut self, state: &GlState, call: &DrawCall) {
+        match *call {
+            DrawCall::Arrays {
+                mode,
+                first,
+                count,
+                instance_count,
+                base_instance,
+            } => {
+                let Some(primitive) = native_primitive_type(mode) else {
+                    return self.draw_lowered(state, call);
+                };
+                unsafe {
+                    self.current_render_encoder()
+                        .drawPrimitives_vertexStart_vertexCount_instanceCount_baseInstance(
+                            primitive,
+                            first as usize,
+                            count as usize,
+                            instance_count as usize,
+                            base_instance as usize,
+                        );
+                }
+            }
+            DrawCall::Elements {
+                mode,
+                count,
+                index_type,
+                index_buffer,
+                index_offset,
+                instance_count,
+                base_vertex,
+                base_instance,
+            } => {
+                let (Some(primitive), Some(index_type)) =
+                    (native_primitive_type(mode), native_index_type(index_type))
+                else {
+                    return self.draw_lowered(state, call);
+                };
+                let index_buffer = Self::metal_buffer(state, index_buffer)
+                    .expect("element array buffer did not have any storage");
+                unsafe {
+                    self.current_render_encoder().drawIndexedPrimitives_indexCount_indexType_indexBuffer_indexBufferOffset_instanceCount_baseVertex_baseInstance(
+                        primitive,
+                        count as usize,
+                        index_type,
+                        index_buffer,
+                        index_offset,
+                        instance_count as usize,
+                        base_vertex as isize,
+                        base_instance as usize,
+                    );
+                };
+            }
+        }
+    }
+    fn draw_geometry(&mut self, state: &GlState, draw: &PrepassDraw) {
+        let geometry = Renderer::linked_geometry(state)
+            .expect("tried to draw through a geometry stage without one");
+        let buffers = geometry.layout.buffers(draw);
+        let input = self.new_prepass_buffer(&buffers.input, buffers.input_len);
+        let output = self.new_prepass_buffer(&buffers.output, buffers.output_len);
+        let one = MTLSize {
+            width: 1,
+            height: 1,
+            depth: 1,
+        };
+        self.encode_prepass(
+            state,
+            self.prepass_pipelines
+                .as_ref()
+                .expect("render pipeline was built without a pre-pass"),
+            draw,
+            Some(ShaderType::GeometryShader),
+            &[
+                (&input, GEOMETRY_INPUT_INDEX),
+                (&output, GEOMETRY_OUTPUT_INDEX),
+            ],
+            &[],
+            MTLSize {
+                width: buffers.dispatch as usize,
+                ..one
+            },
+            one,
+            ns_string!("OxideGL geometry pre-pass"),
+        );
+
+        let enc = self.current_render_encoder();
+        unsafe {
+            enc.setVertexBuffer_offset_atIndex(Some(&output), 0, GEOMETRY_OUTPUT_INDEX as usize);
+            enc.drawIndexedPrimitives_indexType_indexBuffer_indexBufferOffset_indirectBuffer_indirectBufferOffset(
+                native_primitive_type(geometry.layout.info.output_list())
+                    .expect("geometry stage output lists are always drawable by Metal"),
+                MTLIndexType::UInt32,
+                &output,
+                GeometryBuffers::INDEX_OFFSET,
+                &output,
+                0,
+            );
+        }
+    }
+    fn draw_tessellation(&mut self, state: &GlState, draw: &PrepassDraw) {
+        let tessellation = Renderer::linked_tessellation(state)
+            .expect("tried to draw through tessellation stages without them");
+        let layout = &tessellation.layout;
+        let buffers = layout.buffers(draw, tessellation.info, state.patch.vertices);
+        if buffers.patches == 0 {
+            return;
+        }
+        let input = self.new_prepass_buffer(&buffers.input, buffers.input_len);
+        let output = self.new_prepass_buffer(&buffers.output, buffers.output_len);
+        let factors = self.new_prepass_buffer(&[], buffers.factors_len);
+        self.encode_prepass(
+            state,
+            self.prepass_pipelines
+                .as_ref()
+                .expect("render pipeline was built without a pre-pass"),
+            draw,
+            Some(ShaderType::TessControlShader),
+            &[
+                (&input, GEOMETRY_INPUT_INDEX),
+                (&output, GEOMETRY_OUTPUT_INDEX),
+                (&factors, TESSELLATION_FACTOR_INDEX),
+            ],
+            &[],
+            MTLSize {
+                width: buffers.patches as usize,
+                height: draw.instance_count as usize,
+                depth: 1,
+            },
+            MTLSize {
+                width: layout.output_vertices as usize,
+                height: 1,
+                depth: 1,
+            },
+            ns_string!("OxideGL tessellation pre-pass"),
+        );
+
+        // the patches of all instances are drawn as one instance, in the order the control kernel wrote them
+        let enc = self.current_render_encoder();
+        unsafe {
+            enc.setVertexBuffer_offset_atIndex(Some(&output), 0, GEOMETRY_OUTPUT_INDEX as usize);
+            enc.setTessellationFactorBuffer_offset_instanceStride(Some(&factors), 0, 0);
+            enc.drawPatches_patchStart_patchCount_patchIndexBuffer_patchIndexBufferOffset_instanceCount_baseInstance(
+                layout.output_vertices as usize,
+                0,
+                (buffers.patches * draw.instance_count) as usize,
+                None,
+                0,
+                1,
+                0,
+            );
+        }
+    }
+    fn draw_feedback(&mut self, state: &GlState, draw: &FeedbackDraw) {
+        let feedback = Renderer::linked_feedback(state)
+            .expect("tried to capture transform feedback without captured varyings");
+        let (input, input_len) = feedback.layout.input_buffer(&draw.draw, draw.vertices);
+        let input = self.new_prepass_buffer(&input, input_len);
+        // transform feedback buffer i is bound to index i of the feedback kernel
+        let stage_buffers: Vec<_> = draw
+            .buffers
+            .iter()
+            .zip(0..)
+            .map(|(&(name, offset), index)| {
+                let buffer = Self::metal_buffer(state, name)
+                    .expect("transform feedback buffer did not have any storage");
+                (buffer, offset, index)
+            })
+            .collect();
+        let one = MTLSize {
+            width: 1,
+            height: 1,
+            depth: 1,
+        };
+        self.encode_prepass(
+            state,
+            self.feedback_pipelines
+                .as_ref()
+                .expect("render pipeline was built without a transform feedback pre-pass"),
+            &draw.draw,
+            None,
+            &[(&input, GEOMETRY_INPUT_INDEX)],
+            &stage_buffers,
+            MTLSize {
+                width: draw.vertices as usize,
+                ..one
+            },
+            one,
+            ns_string!("OxideGL transform feedback pre-pass"),
+        );
+    }
+    #[inline]
+    fn has_compute_encoder(&self) -> bool {
+        self.compute_encoder.is_some()
+    }
+    fn new_compute_encoder(&mut self) {
+        self.end_encoding();
+        // created on the render command buffer, so dispatches are ordered with the draws encoded around them
+        let enc = self
+            .current_command_buffer()
+            .computeCommandEncoder()
+            .expect("failed to create new compute command encoder");
+        #[cfg(debug_assertions)]
+        enc.setLabel(Some(ns_string!("OxideGL compute encoder")));
+        for group in &self.debug_group_stack {
+            enc.pushDebugGroup(group);
+        }
+        self.compute_encoder = Some(enc);
+    }
+    fn new_compute_pipeline(&mut self, state: &GlState, desc: &ComputePipelineDescriptor) {
+        let pipeline = self
+            .device
+            .newComputePipelineStateWithFunction_error(Self::metal_function(
+                state,
+                ShaderType::ComputeShader,
+            ))
+            .expect("failed to create compute pipeline state");
+        let [width, height, depth] = desc.threads_per_threadgroup.map(|d| d as usize);
+        // the work group size is checked against the GL limit at link time, but a kernel that uses a lot of registers
+        // or threadgroup memory can have an even lower one
+        assert!(
+            width * height * depth <= pipeline.maxTotalThreadsPerThreadgroup(),
+            "work group size of {} exceeds the {} threads per threadgroup Metal supports for it",
+            desc.function,
+            pipeline.maxTotalThreadsPerThreadgroup()
+        );
+        self.compute_pipeline_state = Some((
+            pipeline,
+            MTLSize {
+                width,
+                height,
+                depth,
+            },
+        ));
+    }
+    fn bind_compute_pipeline(&mut self) {
+        let (ps, _) = self.compute_pipeline_state.as_ref().unwrap();
+        self.current_compute_encoder().setComputePipelineState(ps);
+    }
+    fn dispatch(&mut self, state: &GlState, dispatch: &ComputeDispatch) {
+        let (_, threads) = self
+            .compute_pipeline_state
+            .as_ref()
+            .expect("compute pipeline state should have been created!");
+        let enc = self.current_compute_encoder();
+        match *dispatch {
+            ComputeDispatch::Direct {
+                groups: [width, height, depth],
+            } => enc.dispatchThreadgroups_threadsPerThreadgroup(
+                MTLSize {
+                    width: width as usize,
+                    height: height as usize,
+                    depth: depth as usize,
+                },
+                *threads,
+            ),
+            ComputeDispatch::Indirect { buffer, offset } => {
+                let buf = Self::metal_buffer(state, buffer)
+                    .expect("indirect dispatch buffer did not have any storage");
+                unsafe {
+                    enc.dispatchThreadgroupsWithIndirectBuffer_indirectBufferOffset_threadsPerThreadgroup(
+                        buf, offset, *threads,
+                    );
+                }
+            }
+        }
+    }
+    fn memory_barrier(&mut self, barriers: MemoryBarrierMask) {
+        // Metal tracks hazards of buffers and textures between encoders, and dispatches of a serial compute encoder
+        // run one after the other, so this only makes the writes of a dispatch visible to the next one sooner
+        let Some(enc) = &self.compute_encoder else {
+            return;
+        };
+        let mut scope = MTLBarrierScope::Buffers;
+        if barriers.intersects(
+            MemoryBarrierMask::SHADER_IMAGE_ACCESS_BARRIER_BIT
+                | MemoryBarrierMask::TEXTURE_FETCH_BARRIER_BIT,
+        ) {
+            scope |= MTLBarrierScope::Textures;
+        }
+        enc.memoryBarrierWithScope(scope);
+    }
+    fn present(&mut self) {
+        if let Some(drawable) = self.drawable.take() {
+            self.current_command_buffer()
+                .presentDrawable(drawable.as_ref());
+            drop(drawable);
+        }
+        self.current_command_buffer().commit();
+        self.command_buffer = None;
+    }
+    fn push_debug_group(&mut self, name: &str) {
+        let group_name = NSString::from_str(name);
+        // Try to push the group at encoder granularity if we can first
+        if let Some(ref enc) = self.render_encoder {
+            enc.pushDebugGroup(&group_name);
+        }
+        if let Some(ref enc) = self.compute_encoder {
+            enc.pushDebugGroup(&group_name);
+        }
+        self.current_command_buffer().pushDebugGroup(&group_name);
+        self.debug_group_stack.push(group_name.copy());
+    }
+    fn pop_debug_group(&mut self) {
+        if let Some(ref enc) = self.render_encoder {
+            enc.popDebugGroup();
+        }
+        if let Some(ref enc) = self.compute_encoder {
+            enc.popDebugGroup();
+        }
+        self.current_command_buffer().popDebugGroup();
+        let _ = self.debug_group_stack.pop();
+    }
+}
+
+impl From<DepthFunction> for MTLCompareFunction {
+    fn from(value: DepthFunction) -> Self {
+        // 1:1 correspondance between depthfunc and MTLCompareFunction after an offsetting subtraction
+        Self((value as u32 - DepthFunction::Never as u32) as usize)
+    }
+}
+impl From<StencilFunction> for MTLCompareFunction {
+    fn from(value: StencilFunction) -> Self {
+        // 1:1 correspondance between stencilfunc and MTLCompareFunction after an offsetting subtraction
+        Self((value as u32 - StencilFunction::Never as u32) as usize)
+    }
+}
+
+impl From<StencilOp> for MTLStencilOperation {
+    #[inline]
+    fn from(value: StencilOp) -> Self {
+        match value {
+            StencilOp::Zero => Self::Zero,
+            StencilOp::Invert => Self::Invert,
+            StencilOp::Keep => Self::Keep,
+            StencilOp::Replace => Self::Replace,
+            StencilOp::Incr => Self::IncrementClamp,
+            StencilOp::Decr => Self::DecrementClamp,
+            StencilOp::IncrWrap => Self::IncrementWrap,
+            StencilOp::DecrWrap => Self::DecrementWrap,
+        }
+    }
+}
+
+impl DrawbufferBlendState {
+    #[inline]
+    fn apply_to_mtl_desc(&self, desc: &Retained<MTLRenderPipelineColorAttachmentDescriptor>) {
+        if !self.blend_enabled {
+            return;
+        }
+        desc.setBlendingEnabled(true);
+
+        desc.setSourceRGBBlendFactor(self.src_rgb.into());
+        desc.setSourceAlphaBlendFactor(self.src_alpha.into());
+
+        desc.setDestinationRGBBlendFactor(self.dst_rgb.into());
+        desc.setDestinationAlphaBlendFactor(self.dst_alpha.into());
+
+        desc.setRgbBlendOperation(self.eq_rgb.into());
+        desc.setAlphaBlendOperation(self.eq_alpha.into());
+    }
+}
+impl From<BlendingFactor> for MTLBlendFactor {
+    #[inline]
+    fn from(value: BlendingFactor) -> Self {
+        #[allow(clippy::enum_glob_use)]
+        use BlendingFactor::*;
+
+        match value {
+            // constants
+            Zero => Self::Zero,
+            One => Self::One,
+
+            // source color/alpha
+            SrcColor => Self::SourceColor,
+            OneMinusSrcColor => Self::OneMinusSourceColor,
+            SrcAlpha => Self::SourceAlpha,
+            OneMinusSrcAlpha => Self::OneMinusSourceAlpha,
+            SrcAlphaSaturate => Self::SourceAlphaSaturated,
+
+            // destination color/alpha
+            DstColor => Self::DestinationColor,
+            OneMinusDstColor => Self::OneMinusDestinationColor,
+            DstAlpha => Self::DestinationAlpha,
+            OneMinusDstAlpha => Self::OneMinusDestinationAlpha,
+
+            // constant color/alpha
+            ConstantColor => Self::BlendColor,
+            OneMinusConstantColor => Self::OneMinusBlendColor,
+            ConstantAlpha => Self::BlendAlpha,
+            OneMinusConstantAlpha => Self::OneMinusBlendAlpha,
+
+            // source #2 color/alpha (for dual-source blending)
+            Src1Alpha => Self::Source1Alpha,
+            Src1Color => Self::Source1Color,
+            OneMinusSrc1Color => Self::OneMinusSource1Color,
+            OneMinusSrc1Alpha => Self::OneMinusSource1Alpha,
+        }
+    }
+}
+impl From<BlendEquationModeEXT> for MTLBlendOperation {
+    #[inline]
+    fn from(value: BlendEquationModeEXT) -> Self {
+        match value {
+            BlendEquationModeEXT::FuncAdd => Self::Add,
+            BlendEquationModeEXT::FuncReverseSubtract => Self::ReverseSubtract,
+            BlendEquationModeEXT::FuncSubtract => Self::Subtract,
+            BlendEquationModeEXT::Min => Self::Min,
+            BlendEquationModeEXT::Max => Self::Max,
+        }
+    }
+}
+impl From<ColorWriteMask> for MTLColorWriteMask {
+    #[inline]
+    fn from(value: ColorWriteMask) -> Self {
+        let arr: [bool; 4] = value.into();
+        // See: MTLRenderPipeline.h L#54
+        let val = usize::from(arr[3])
+            | (usize::from(arr[2]) << 1)
+            | (usize::from(arr[1]) << 2)
+            | (usize::from(arr[0]) << 3);
+        Self(val)
+    }
+}
+impl From<TriangleFace> for MTLCullMode {
+    #[inline]
+    fn from(value: TriangleFace) -> Self {
+        match value {
+            TriangleFace::Front => Self::Front,
+            //FIXME this is incorrect. This should cull all faces but not lines. This will be a fun™ behavior to emulate
+            TriangleFace::FrontAndBack => Self::None,
+            TriangleFace::Back => Self::Back,
+        }
+    }
+}
+/// Metal primitive type that draws primitives of type `mode` directly, if there is one
+fn native_primitive_type(mode: PrimitiveType) -> Option<MTLPrimitiveType> {
+    Some(match mode {
+        PrimitiveType::Points => MTLPrimitiveType::Point,
+        PrimitiveType::Lines => MTLPrimitiveType::Line,
+        PrimitiveType::LineStrip => MTLPrimitiveType::LineStrip,
+        PrimitiveType::Triangles => MTLPrimitiveType::Triangle,
+        PrimitiveType::TriangleStrip => MTLPrimitiveType::TriangleStrip,
+        _ => return None,
+    })
+}
+/// Metal index type that reads indices of type `ty` directly, if there is one
+fn native_index_type(ty: DrawElementsType) -> Option<MTLIndexType> {
+    match ty {
+        DrawElementsType::UnsignedShort => Some(MTLIndexType::UInt16),
+        DrawElementsType::UnsignedInt => Some(MTLIndexType::UInt32),
+        DrawElementsType::UnsignedByte => None,
+    }
+}
+impl From<TextureTarget> for MTLTextureType {
+    fn from(value: TextureTarget) -> Self {
+        match value {
+            TextureTarget::Texture1D | TextureTarget::ProxyTexture1D => Self::Type1D,
+            TextureTarget::Texture1DArray | TextureTarget::ProxyTexture1DArray => Self::Type1DArray,
+            TextureTarget::Renderbuffer
+            | TextureTarget::TextureRectangle
+            | TextureTarget::ProxyTextureRectangle
+            | TextureTarget::Texture2D
+            | TextureTarget::ProxyTexture2D => Self::Type2D,
+
+            TextureTarget::Texture2DArray | TextureTarget::ProxyTexture2DArray => Self::Type2DArray,
+            TextureTarget::Texture2DMultisample | TextureTarget::ProxyTexture2DMultisample => {
+                Self::Type2DMultisample
+            }
+            TextureTarget::Texture2DMultisampleArray
+            | TextureTarget::ProxyTexture2DMultisampleArray => Self::Type2DMultisampleArray,
+            TextureTarget::TextureCubeMap | TextureTarget::ProxyTextureCubeMap => Self::TypeCube,
+            TextureTarget::TextureCubeMapArray | TextureTarget::ProxyTextureCubeMapArray => {
+                Self::TypeCubeArray
+            }
+
+            TextureTarget::TextureBuffer => Self::TypeTextureBuffer,
+            TextureTarget::Texture3D | TextureTarget::ProxyTexture3D => Self::Type3D,
+            _ => {
+                panic!("invalid texture target")
+            }
+        }
+    }
+}
+impl From<TextureSwizzle> for MTLTextureSwizzle {
+    fn from(value: TextureSwizzle) -> Self {
+        match value {
+            TextureSwizzle::Zero => MTLTextureSwizzle::Zero,
+            TextureSwizzle::One => MTLTextureSwizzle::One,
+            TextureSwizzle::Red => MTLTextureSwizzle::Red,
+            TextureSwizzle::Green => MTLTextureSwizzle::Green,
+            TextureSwizzle::Blue => MTLTextureSwizzle::Blue,
+            TextureSwizzle::Alpha => MTLTextureSwizzle::Alpha,
+        }
+    }
+}
+impl From<TextureWrapMode> for MTLSamplerAddressMode {
+    fn from(value: TextureWrapMode) -> Self {
+        match value {
+            TextureWrapMode::Repeat => MTLSamplerAddressMode::Repeat,
+            TextureWrapMode::MirroredRepeat => MTLSamplerAddressMode::MirrorRepeat,
+            TextureWrapMode::ClampToEdge => MTLSamplerAddressMode::ClampToEdge,
+            TextureWrapMode::ClampToBorder => MTLSamplerAddressMode::ClampToBorderColor,
+        }
+    }
+}
+impl From<TextureMagFilter> for MTLSamplerMinMagFilter {
+    fn from(value: TextureMagFilter) -> Self {
+        match value {
+            TextureMagFilter::Nearest => Self::Nearest,
+            TextureMagFilter::Linear => Self::Linear,
+        }
+    }
+}
+impl From<TextureMinFilter> for (MTLSamplerMinMagFilter, MTLSamplerMipFilter) {
+    fn from(value: TextureMinFilter) -> Self {
+        use objc2_metal::{MTLSamplerMinMagFilter as MinMag, MTLSamplerMipFilter as Mip};
+        match value {
+            TextureMinFilter::Nearest => (MinMag::Nearest, Mip::NotMipmapped),
+            TextureMinFilter::Linear => (MinMag::Linear, Mip::NotMipmapped),
+            TextureMinFilter::NearestMipmapNearest => (MinMag::Nearest, Mip::Nearest),
+            TextureMinFilter::LinearMipmapNearest => (MinMag::Linear, Mip::Nearest),
+            TextureMinFilter::NearestMipmapLinear => (MinMag::Nearest, Mip::Linear),
+            TextureMinFilter::LinearMipmapLinear => (MinMag::Linear, Mip::Linear),
+        }
+    }
+}
+impl From<BorderColor> for MTLSamplerBorderColor {
+    fn from(value: BorderColor) -> Self {
+        match value {
+            BorderColor::TransparentBlack => Self::TransparentBlack,
+            BorderColor::OpaqueBlack => Self::OpaqueBlack,
+            BorderColor::OpaqueWhite => Self::OpaqueWhite,
+        }
+    }
+}
+impl From<PixelFormat> for MTLPixelFormat {
+    #[inline]
+    fn from(value: PixelFormat) -> Self {
+        Self(value.0)
+    }
+}
+impl From<MTLPixelFormat> for PixelFormat {
+    #[inline]
+    fn from(value: MTLPixelFormat) -> Self {
+        Self(value.0)
+    }
+}
+impl From<VertexFormat> for MTLVertexFormat {
+    #[inline]
+    fn from(value: VertexFormat) -> Self {
+        Self(value.0)
+    }
+}
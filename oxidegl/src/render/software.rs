@@ -0,0 +1,1648 @@
+use std::{ffi::c_void, mem, ptr::NonNull, sync::Arc};
+
+use ahash::{HashMap, HashMapExt};
+use spirv::{Discarded, Emitted, Invocation, Module, Value};
+use spirv_cross2::spirv::{BuiltIn, StorageClass};
+
+use super::{
+    BufferArgument, BufferStorage, ComputeDispatch, ComputePipelineDescriptor, DrawCall,
+    EncoderState, FeedbackDraw, HostStageCompiler, ImageArgument, PixelFormat, PrepassDraw,
+    RenderBackend, RenderPassDescriptor, RenderPipelineDescriptor, Renderer, StageCompiler,
+    VertexFormat,
+};
+use crate::{
+    commands::buffer::Buffer,
+    context::state::{ClipControl, DrawbufferBlendState, GlState, StencilFaceState},
+    debug::gl_trace,
+    gl_enums::{
+        BlendEquationModeEXT, BlendingFactor, ClearBufferMask, ClipControlDepth, ClipControlOrigin,
+        DepthFunction, MemoryBarrierMask, PrimitiveType, ShaderType, StencilFunction, StencilOp,
+        TriangleFace,
+    },
+    gl_object::ObjectName,
+    program::{clip_control::MAX_CLIP_DISTANCES, geometry::GeometryInfo},
+};
+
+#[allow(clippy::many_single_char_names, clippy::similar_names)]
+mod spirv;
+
+/// Reference rendering backend that rasterizes on the CPU, executing shaders with a SPIR-V interpreter.
+/// It is slow and only implements a subset of the GL, but it does not need a GPU and its output is deterministic,
+/// which makes it useful as a reference to compare the output of the Metal backend against.
+///
+/// The default framebuffer consists of a single RGBA float color buffer plus a depth and stencil buffer.
+/// Rows are stored bottom to top, like the GL window coordinate system.
+#[derive(Debug)]
+pub(crate) struct SoftwareBackend {
+    dims: (u32, u32),
+    color: Vec<[f32; 4]>,
+    depth: Vec<f32>,
+    stencil: Vec<u8>,
+    has_encoder: bool,
+    encoder_state: Option<EncoderState>,
+    pipeline: Option<Pipeline>,
+    /// Buffer name and offset bound to each vertex stage argument table index
+    vertex_buffers: HashMap<u32, (u32, usize)>,
+    /// Contents of the push constant blocks (i.e. the emulated default uniform blocks) of the vertex, geometry and
+    /// fragment stages
+    push_constants: [Vec<u8>; 3],
+}
+#[derive(Debug)]
+struct Pipeline {
+    desc: RenderPipelineDescriptor,
+    vertex: Module,
+    geometry: Option<GeometryPipeline>,
+    fragment: Module,
+    /// outputs of the last stage before rasterization linked to fragment stage inputs
+    varyings: Vec<Varying>,
+    /// fragment stage output variables and the color attachment they are written to
+    outputs: Vec<(u32, u32)>,
+}
+/// The geometry stage of a pipeline. Unlike the Metal backend, this runs the geometry stage directly instead of
+/// through the generated kernels that emulate it
+#[derive(Debug)]
+struct GeometryPipeline {
+    module: Module,
+    info: GeometryInfo,
+    /// vertex stage outputs linked to geometry stage inputs
+    inputs: Vec<Varying>,
+}
+/// An output of a stage linked to an input of the following stage
+#[derive(Debug, Clone, Copy)]
+struct Varying {
+    output_var: u32,
+    input_var: u32,
+    flat: bool,
+}
+/// Outputs of a vertex or geometry stage invocation for a single vertex
+#[derive(Debug, Clone)]
+struct VertexOutputs {
+    /// clip space position
+    position: [f32; 4],
+    /// values of the outputs linked to the next stage
+    varyings: Vec<Value>,
+    /// distances to the enabled user clip planes
+    clip_distances: Vec<f32>,
+    /// diameter of the point the vertex is rasterized as, if it is part of a point primitive
+    point_size: f32,
+}
+/// Output of the last stage before rasterization for a single vertex, with the position in window coordinates
+#[derive(Debug, Clone)]
+struct ShadedVertex {
+    /// x, y in window coordinates, depth before the depth range is applied and 1/w
+    window: [f32; 4],
+    /// clip space w coordinate
+    clip_w: f32,
+    varyings: Vec<Value>,
+    /// Distances to the enabled user clip planes
+    clip_distances: Vec<f32>,
+    point_size: f32,
+}
+impl ShadedVertex {
+    fn new(outputs: VertexOutputs, viewport: [u32; 4], clip_control: ClipControl) -> Self {
+        let [x, y, z, w] = outputs.position;
+        let [vx, vy, vw, vh] = viewport.map(|v| v as f32);
+        let y = match clip_control.origin {
+            ClipControlOrigin::LowerLeft => y / w,
+            ClipControlOrigin::UpperLeft => -y / w,
+        };
+        let z = match clip_control.depth {
+            ClipControlDepth::NegativeOneToOne => (z / w + 1.0) / 2.0,
+            ClipControlDepth::ZeroToOne => z / w,
+        };
+        Self {
+            window: [
+                vx + (x / w + 1.0) * vw / 2.0,
+                vy + (y + 1.0) * vh / 2.0,
+                z,
+                1.0 / w,
+            ],
+            clip_w: w,
+            varyings: outputs.varyings,
+            clip_distances: outputs.clip_distances,
+            point_size: outputs.point_size,
+        }
+    }
+}
+
+impl SoftwareBackend {
+    pub(crate) fn new(width: u32, height: u32) -> Self {
+        let len = width as usize * height as usize;
+        Self {
+            dims: (width, height),
+            color: vec![[0.0; 4]; len],
+            depth: vec![1.0; len],
+            stencil: vec![0; len],
+            has_encoder: false,
+            encoder_state: None,
+            pipeline: None,
+            vertex_buffers: HashMap::new(),
+            push_constants: [Vec::new(), Vec::new(), Vec::new()],
+        }
+    }
+    fn host_buffer(state: &GlState, name: ObjectName<Buffer>) -> &[u8] {
+        let buf = state.buffer_list.get(name);
+        match buf.allocation.as_ref().map(|a| &a.storage) {
+            Some(BufferStorage::Host(b)) => b,
+            #[cfg(target_vendor = "apple")]
+            Some(BufferStorage::Metal(_)) => {
+                unreachable!("software backend encountered Metal buffer storage")
+            }
+            None => panic!("tried to use {name:?} before its storage was allocated"),
+        }
+    }
+    /// Resolve the backing memory of the uniform, shader storage and push constant blocks used by a module
+    fn block_buffers<'a>(
+        state: &'a GlState,
+        module: &Module,
+        push_constants: &'a [u8],
+    ) -> HashMap<u32, &'a [u8]> {
+        let mut buffers = HashMap::new();
+        for var in module.variables(StorageClass::PushConstant) {
+            buffers.insert(var, push_constants);
+        }
+        for var in module
+            .variables(StorageClass::Uniform)
+            .chain(module.variables(StorageClass::StorageBuffer))
+        {
+            let binding = module
+                .binding(var)
+                .expect("buffer block declaration missing binding attribute")
+                as usize;
+            let bound = if module.is_storage_block(var) {
+                state.buffer_bindings.shader_storage[binding]
+            } else {
+                state.buffer_bindings.uniform[binding]
+            };
+            let Some(name) = bound else {
+                panic!(
+                    "Shader requested a buffer at binding {binding} but no buffer was bound at that index"
+                );
+            };
+            buffers.insert(var, Self::host_buffer(state, name));
+        }
+        buffers
+    }
+    /// Link the outputs of a stage to the inputs of the following stage, by location or by name if the variables have
+    /// no location
+    fn link_varyings(producer: &Module, consumer: &Module) -> Vec<Varying> {
+        let outputs = producer
+            .variables(StorageClass::Output)
+            .filter(|&v| !producer.is_builtin(v))
+            .collect::<Vec<_>>();
+        consumer
+            .variables(StorageClass::Input)
+            .filter(|&v| !consumer.is_builtin(v))
+            .map(|input_var| {
+                let output_var = match consumer.location(input_var) {
+                    Some(loc) => outputs
+                        .iter()
+                        .copied()
+                        .find(|&o| producer.location(o) == Some(loc)),
+                    None => outputs.iter().copied().find(|&o| {
+                        producer.location(o).is_none()
+                            && producer.name(o) == consumer.name(input_var)
+                    }),
+                };
+                Varying {
+                    output_var: output_var.unwrap_or_else(|| {
+                        panic!(
+                            "stage input {:?} has no matching output in the previous stage",
+                            consumer.name(input_var)
+                        )
+                    }),
+                    input_var,
+                    flat: consumer.is_flat(input_var),
+                }
+            })
+            .collect()
+    }
+    #[inline]
+    fn idx(&self, x: u32, y: u32) -> usize {
+        y as usize * self.dims.0 as usize + x as usize
+    }
+
+    /// Run the vertex stage for a single vertex, returning its outputs with the ones linked to the next stage by
+    /// `varyings`
+    fn shade_vertex(
+        &self,
+        state: &GlState,
+        pipeline: &Pipeline,
+        varyings: &[Varying],
+        buffers: &HashMap<u32, &[u8]>,
+        vertex_id: u32,
+        instance_id: u32,
+    ) -> VertexOutputs {
+        let module = &pipeline.vertex;
+        let mut inv = Invocation::new(module, buffers.clone());
+        for (builtin, value) in [
+            (BuiltIn::VertexId, vertex_id),
+            (BuiltIn::VertexIndex, vertex_id),
+            (BuiltIn::InstanceId, instance_id),
+            (BuiltIn::InstanceIndex, instance_id),
+        ] {
+            if let Some((var, member)) = module.builtin(StorageClass::Input, builtin) {
+                inv.write_variable(var, member, Value::Scalar(value));
+            }
+        }
+        for var in module.variables(StorageClass::Input) {
+            let ty = module.pointee_type(var);
+            let Some(location) = module.location(var) else {
+                continue;
+            };
+            let components =
+                self.fetch_attribute(state, pipeline, location, vertex_id, module.is_integral(ty));
+            inv.write_variable(var, None, module.value_from_components(ty, components));
+        }
+        inv.run().expect("vertex shader invocations cannot discard");
+
+        let (pos_var, member) = module
+            .builtin(StorageClass::Output, BuiltIn::Position)
+            .expect("vertex shader does not write gl_Position");
+        let clip_distances = module
+            .builtin(StorageClass::Output, BuiltIn::ClipDistance)
+            .map(|(var, member)| {
+                enabled_clip_distances(inv.read_variable(var, member), pipeline.desc.clip_distances)
+            })
+            .unwrap_or_default();
+        let point_size = pipeline.desc.point_size.unwrap_or_else(|| {
+            module
+                .builtin(StorageClass::Output, BuiltIn::PointSize)
+                .map_or(1.0, |(var, member)| inv.read_variable(var, member).f32())
+        });
+        VertexOutputs {
+            position: inv.read_variable(pos_var, member).to_vec4(),
+            varyings: varyings
+                .iter()
+                .map(|v| inv.read_variable(v.output_var, None).clone())
+                .collect(),
+            clip_distances,
+            point_size,
+        }
+    }
+    /// Run the geometry stage for a single input primitive, returning the vertices it emitted to each strip of
+    /// stream 0 (the only stream that is rasterized)
+    fn shade_primitive(
+        geometry: &GeometryPipeline,
+        varyings: &[Varying],
+        desc: &RenderPipelineDescriptor,
+        buffers: &HashMap<u32, &[u8]>,
+        inputs: &[VertexOutputs],
+        primitive_id: u32,
+        invocation_id: u32,
+    ) -> Vec<Vec<VertexOutputs>> {
+        let module = &geometry.module;
+        let mut inv = Invocation::new(module, buffers.clone());
+        if let Some((var, member)) = module.arrayed_builtin(StorageClass::Input, BuiltIn::Position)
+        {
+            for (k, input) in (0..).zip(inputs) {
+                let path = [k].into_iter().chain(member).collect::<Vec<_>>();
+                inv.write_variable_path(var, &path, Value::from_f32s(&input.position));
+            }
+        }
+        for (k, input) in (0..).zip(inputs) {
+            for (varying, value) in geometry.inputs.iter().zip(&input.varyings) {
+                inv.write_variable_path(varying.input_var, &[k], value.clone());
+            }
+        }
+        for (builtin, value) in [
+            (BuiltIn::PrimitiveId, primitive_id),
+            (BuiltIn::InvocationId, invocation_id),
+        ] {
+            if let Some((var, member)) = module.builtin(StorageClass::Input, builtin) {
+                inv.write_variable(var, member, Value::Scalar(value));
+            }
+        }
+        inv.run()
+            .expect("geometry shader invocations cannot discard");
+
+        // gl_Layer and gl_ViewportIndex are ignored, the default framebuffer has a single layer and viewport
+        let (pos_var, member) = module
+            .builtin(StorageClass::Output, BuiltIn::Position)
+            .expect("geometry shader does not write gl_Position");
+        let clip_var = module.builtin(StorageClass::Output, BuiltIn::ClipDistance);
+        let point_size_var = module.builtin(StorageClass::Output, BuiltIn::PointSize);
+        let mut strips = vec![Vec::new()];
+        for emitted in inv.emitted {
+            match emitted {
+                Emitted::Vertex { stream: 0, outputs } => {
+                    let position = outputs[&pos_var].at_path(member.as_slice()).to_vec4();
+                    let varyings = varyings
+                        .iter()
+                        .map(|v| outputs[&v.output_var].clone())
+                        .collect();
+                    let clip_distances = clip_var
+                        .map(|(var, member)| {
+                            enabled_clip_distances(
+                                outputs[&var].at_path(member.as_slice()),
+                                desc.clip_distances,
+                            )
+                        })
+                        .unwrap_or_default();
+                    let point_size = desc.point_size.unwrap_or_else(|| {
+                        point_size_var.map_or(1.0, |(var, member)| {
+                            outputs[&var].at_path(member.as_slice()).f32()
+                        })
+                    });
+                    strips.last_mut().unwrap().push(VertexOutputs {
+                        position,
+                        varyings,
+                        clip_distances,
+                        point_size,
+                    });
+                }
+                Emitted::EndPrimitive { stream: 0 } => strips.push(Vec::new()),
+                Emitted::Vertex { .. } | Emitted::EndPrimitive { .. } => {}
+            }
+        }
+        strips
+    }
+    /// Fetch the value of a vertex attribute as raw 32 bit components, converted to the representation expected by the shader
+    fn fetch_attribute(
+        &self,
+        state: &GlState,
+        pipeline: &Pipeline,
+        location: u32,
+        vertex_id: u32,
+        integral: bool,
+    ) -> [u32; 4] {
+        let vd = &pipeline.desc.vertex_descriptor;
+        let mut values = [0.0, 0.0, 0.0, 1.0];
+        if let Some(attr) = vd.attributes.iter().find(|a| a.attribute_index == location) {
+            let layout = vd
+                .layouts
+                .iter()
+                .find(|l| l.buffer_index == attr.buffer_index)
+                .expect("vertex attribute references a buffer without a layout");
+            let &(name, offset) = self
+                .vertex_buffers
+                .get(&attr.buffer_index)
+                .expect("vertex attribute references an unbound buffer");
+            let bytes = Self::host_buffer(state, ObjectName::from_raw(name));
+            let start =
+                offset + usize::from(layout.stride) * vertex_id as usize + usize::from(attr.offset);
+            decode_vertex_format(attr.format, &bytes[start..], &mut values);
+        }
+        values.map(|v| {
+            if integral {
+                (v as i64 as i32).cast_unsigned()
+            } else {
+                (v as f32).to_bits()
+            }
+        })
+    }
+
+    /// Rasterize a triangle, running the fragment stage for each covered pixel
+    fn rasterize_triangle(
+        &mut self,
+        state: &GlState,
+        pipeline: &Pipeline,
+        buffers: &HashMap<u32, &[u8]>,
+        verts: [&ShadedVertex; 3],
+    ) {
+        // primitives that cross the w = 0 plane would need to be clipped
+        if verts.iter().any(|v| v.clip_w <= 0.0) {
+            gl_trace!("software backend dropped a triangle that needs clipping");
+            return;
+        }
+        let [p0, p1, p2] = verts.map(|v| v.window);
+        let edge = |a: [f32; 4], b: [f32; 4], x: f32, y: f32| {
+            (b[0] - a[0]) * (y - a[1]) - (b[1] - a[1]) * (x - a[0])
+        };
+        let area = edge(p0, p1, p2[0], p2[1]);
+        if area == 0.0 {
+            return;
+        }
+        // counterclockwise triangles are front facing, and the area is negated with an upper left origin
+        let upper_left = self
+            .encoder_state
+            .as_ref()
+            .is_some_and(|s| s.clip_origin == ClipControlOrigin::UpperLeft);
+        let front_facing = (area > 0.0) != upper_left;
+        let culled = match self.encoder_state.as_ref().and_then(|s| s.cull_mode) {
+            Some(TriangleFace::Front) => front_facing,
+            Some(TriangleFace::Back) => !front_facing,
+            Some(TriangleFace::FrontAndBack) => true,
+            None => false,
+        };
+        if culled {
+            return;
+        }
+        let (w, h) = self.dims;
+        let min_x = p0[0].min(p1[0]).min(p2[0]).floor().max(0.0) as u32;
+        let min_y = p0[1].min(p1[1]).min(p2[1]).floor().max(0.0) as u32;
+        let max_x = (p0[0].max(p1[0]).max(p2[0]).ceil() as u32).min(w);
+        let max_y = (p0[1].max(p1[1]).max(p2[1]).ceil() as u32).min(h);
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let (cx, cy) = (x as f32 + 0.5, y as f32 + 0.5);
+                let b = [
+                    edge(p1, p2, cx, cy) / area,
+                    edge(p2, p0, cx, cy) / area,
+                    edge(p0, p1, cx, cy) / area,
+                ];
+                if b.iter().any(|&b| b < 0.0) {
+                    continue;
+                }
+                self.shade_fragment(
+                    state,
+                    pipeline,
+                    buffers,
+                    (x, y),
+                    &verts,
+                    b,
+                    front_facing,
+                    None,
+                );
+            }
+        }
+    }
+    /// Rasterize a line segment, running the fragment stage for each covered pixel
+    fn rasterize_line(
+        &mut self,
+        state: &GlState,
+        pipeline: &Pipeline,
+        buffers: &HashMap<u32, &[u8]>,
+        verts: [&ShadedVertex; 2],
+    ) {
+        if verts.iter().any(|v| v.clip_w <= 0.0) {
+            gl_trace!("software backend dropped a line that needs clipping");
+            return;
+        }
+        let [p0, p1] = verts.map(|v| v.window);
+        let steps = (p1[0] - p0[0])
+            .abs()
+            .max((p1[1] - p0[1]).abs())
+            .ceil()
+            .max(1.0) as u32;
+        for step in 0..steps {
+            let t = (step as f32 + 0.5) / steps as f32;
+            let x = p0[0] + (p1[0] - p0[0]) * t;
+            let y = p0[1] + (p1[1] - p0[1]) * t;
+            if x < 0.0 || y < 0.0 || x >= self.dims.0 as f32 || y >= self.dims.1 as f32 {
+                continue;
+            }
+            let all = [verts[0], verts[1], verts[1]];
+            self.shade_fragment(
+                state,
+                pipeline,
+                buffers,
+                (x as u32, y as u32),
+                &all,
+                [1.0 - t, t, 0.0],
+                true,
+                None,
+            );
+        }
+    }
+    /// Rasterize the primitives of the given type made of `verts`
+    fn rasterize(
+        &mut self,
+        state: &GlState,
+        pipeline: &Pipeline,
+        buffers: &HashMap<u32, &[u8]>,
+        mode: PrimitiveType,
+        verts: &[ShadedVertex],
+    ) {
+        let fb = buffers;
+        match mode {
+            PrimitiveType::Points => {
+                for v in verts {
+                    self.rasterize_point(state, pipeline, fb, v);
+                }
+            }
+            PrimitiveType::Lines => {
+                for l in verts.chunks_exact(2) {
+                    self.rasterize_line(state, pipeline, fb, [&l[0], &l[1]]);
+                }
+            }
+            PrimitiveType::LineStrip | PrimitiveType::LineLoop => {
+                for l in verts.windows(2) {
+                    self.rasterize_line(state, pipeline, fb, [&l[0], &l[1]]);
+                }
+                if mode == PrimitiveType::LineLoop && verts.len() > 2 {
+                    let l = [&verts[verts.len() - 1], &verts[0]];
+                    self.rasterize_line(state, pipeline, fb, l);
+                }
+            }
+            PrimitiveType::Triangles => {
+                for t in verts.chunks_exact(3) {
+                    self.rasterize_triangle(state, pipeline, fb, [&t[0], &t[1], &t[2]]);
+                }
+            }
+            PrimitiveType::TriangleStrip => {
+                for (i, t) in verts.windows(3).enumerate() {
+                    // keep the winding order consistent
+                    let tri = if i % 2 == 0 {
+                        [&t[0], &t[1], &t[2]]
+                    } else {
+                        [&t[1], &t[0], &t[2]]
+                    };
+                    self.rasterize_triangle(state, pipeline, fb, tri);
+                }
+            }
+            PrimitiveType::TriangleFan => {
+                if let Some((first, rest)) = verts.split_first() {
+                    for t in rest.windows(2) {
+                        self.rasterize_triangle(state, pipeline, fb, [first, &t[0], &t[1]]);
+                    }
+                }
+            }
+            mode => panic!("software backend does not support drawing {mode:?}"),
+        }
+    }
+    fn rasterize_point(
+        &mut self,
+        state: &GlState,
+        pipeline: &Pipeline,
+        buffers: &HashMap<u32, &[u8]>,
+        vert: &ShadedVertex,
+    ) {
+        if vert.clip_w <= 0.0 {
+            return;
+        }
+        let [x, y, ..] = vert.window;
+        let [min_size, max_size] = state.characteristics.point_size_range;
+        let size = vert.point_size.clamp(min_size, max_size);
+        // the point covers the fragments whose centers are in the square of its size centered on it
+        let (w, h) = self.dims;
+        let min_x = (x - size / 2.0 - 0.5).ceil().max(0.0) as u32;
+        let min_y = (y - size / 2.0 - 0.5).ceil().max(0.0) as u32;
+        let max_x = ((x + size / 2.0 - 0.5).ceil().max(0.0) as u32).min(w);
+        let max_y = ((y + size / 2.0 - 0.5).ceil().max(0.0) as u32).min(h);
+        let upper_left = pipeline.desc.point_sprite_coord_origin == ClipControlOrigin::UpperLeft;
+        for fy in min_y..max_y {
+            for fx in min_x..max_x {
+                let s = 0.5 + (fx as f32 + 0.5 - x) / size;
+                let t = (fy as f32 + 0.5 - y) / size;
+                let t = if upper_left { 0.5 - t } else { 0.5 + t };
+                self.shade_fragment(
+                    state,
+                    pipeline,
+                    buffers,
+                    (fx, fy),
+                    &[vert, vert, vert],
+                    [1.0, 0.0, 0.0],
+                    true,
+                    Some([s, t]),
+                );
+            }
+        }
+    }
+
+    /// Run the fragment stage and per-fragment operations for a single pixel. `bary` are the screen-space barycentric
+    /// coordinates of the pixel center relative to `verts`; the last vertex is the provoking vertex. `point_coord` is
+    /// the coordinate of the pixel center within the point being rasterized, if any
+    #[expect(clippy::too_many_arguments)]
+    fn shade_fragment(
+        &mut self,
+        state: &GlState,
+        pipeline: &Pipeline,
+        buffers: &HashMap<u32, &[u8]>,
+        (x, y): (u32, u32),
+        verts: &[&ShadedVertex; 3],
+        bary: [f32; 3],
+        front_facing: bool,
+        point_coord: Option<[f32; 2]>,
+    ) {
+        let scissor = self
+            .encoder_state
+            .as_ref()
+            .expect("software backend tried to draw without encoder state")
+            .scissor;
+        if scissor.is_some_and(|[sx, sy, sw, sh]| {
+            !(sx..sx.saturating_add(sw)).contains(&x) || !(sy..sy.saturating_add(sh)).contains(&y)
+        }) {
+            return;
+        }
+        let z: f32 = (0..3).map(|i| bary[i] * verts[i].window[2]).sum();
+        // no clipping is done, so approximate near/far plane clipping by dropping fragments outside the clip volume
+        if !(0.0..=1.0).contains(&z) {
+            return;
+        }
+        let [near, far] = self
+            .encoder_state
+            .as_ref()
+            .expect("software backend tried to draw without encoder state")
+            .depth_range
+            .map(|d| d as f32);
+        let z = near + z * (far - near);
+        let inv_w: f32 = (0..3).map(|i| bary[i] * verts[i].window[3]).sum();
+        let persp = [0, 1, 2].map(|i| bary[i] * verts[i].window[3] / inv_w);
+        // clip distances are linear in clip space, so they're clipped against per fragment like perspective correct
+        // varyings
+        for plane in 0..verts[0].clip_distances.len() {
+            let distance: f32 = (0..3)
+                .map(|i| persp[i] * verts[i].clip_distances[plane])
+                .sum();
+            if distance < 0.0 {
+                return;
+            }
+        }
+
+        let module = &pipeline.fragment;
+        let mut inv = Invocation::new(module, buffers.clone());
+        for (i, varying) in pipeline.varyings.iter().enumerate() {
+            let value = if varying.flat {
+                verts[2].varyings[i].clone()
+            } else {
+                interpolate([0, 1, 2].map(|v| &verts[v].varyings[i]), persp)
+            };
+            inv.write_variable(varying.input_var, None, value);
+        }
+        if let Some((var, member)) = module.builtin(StorageClass::Input, BuiltIn::FragCoord) {
+            let coord = Value::from_f32s(&[x as f32 + 0.5, y as f32 + 0.5, z, inv_w]);
+            inv.write_variable(var, member, coord);
+        }
+        if let Some((var, member)) = module.builtin(StorageClass::Input, BuiltIn::FrontFacing) {
+            inv.write_variable(var, member, Value::from_bool(front_facing));
+        }
+        if let Some((var, member)) = module.builtin(StorageClass::Input, BuiltIn::PointCoord) {
+            let coord = point_coord.unwrap_or([0.5, 0.5]);
+            inv.write_variable(var, member, Value::from_f32s(&coord));
+        }
+        if inv.run() == Err(Discarded) {
+            return;
+        }
+        let z = module
+            .builtin(StorageClass::Output, BuiltIn::FragDepth)
+            .map_or(z, |(var, member)| inv.read_variable(var, member).f32());
+
+        let idx = self.idx(x, y);
+        let encoder_state = self
+            .encoder_state
+            .as_ref()
+            .expect("software backend tried to draw without encoder state");
+        if let Some(stencil) = &encoder_state.stencil {
+            let (face, writemask) = &stencil[usize::from(!front_facing)];
+            let stored = u32::from(self.stencil[idx]);
+            if !stencil_compare(face.func, face.reference & face.mask, stored & face.mask) {
+                self.stencil[idx] = stencil_op(face.fail_action, *face, stored, *writemask);
+                return;
+            }
+        }
+        if let Some((func, write)) = encoder_state.depth {
+            if !depth_compare(func, z, self.depth[idx]) {
+                if let Some(stencil) = &encoder_state.stencil {
+                    let (face, writemask) = &stencil[usize::from(!front_facing)];
+                    let stored = u32::from(self.stencil[idx]);
+                    self.stencil[idx] =
+                        stencil_op(face.depth_fail_action, *face, stored, *writemask);
+                }
+                return;
+            }
+            if write {
+                self.depth[idx] = z;
+            }
+        }
+        if let Some(stencil) = &encoder_state.stencil {
+            let (face, writemask) = &stencil[usize::from(!front_facing)];
+            let stored = u32::from(self.stencil[idx]);
+            self.stencil[idx] = stencil_op(face.depth_pass_action, *face, stored, *writemask);
+        }
+
+        // the default framebuffer only has a single color buffer
+        for &(var, location) in &pipeline.outputs {
+            if location != 0 {
+                continue;
+            }
+            let Some(attachment) = &pipeline.desc.color_attachments[0] else {
+                continue;
+            };
+            let src = inv
+                .read_variable(var, None)
+                .to_vec4()
+                .map(|c| c.clamp(0.0, 1.0));
+            let dst = self.color[idx];
+            let blended = if attachment.blend.blend_enabled {
+                blend(attachment.blend, src, dst, encoder_state.blend_color)
+            } else {
+                src
+            };
+            let mask: [bool; 4] = state.writemasks.color[0].into();
+            for ((dst, src), write) in self.color[idx].iter_mut().zip(blended).zip(mask) {
+                if write {
+                    *dst = src.clamp(0.0, 1.0);
+                }
+            }
+        }
+    }
+}
+
+impl RenderBackend for SoftwareBackend {
+    #[inline]
+    fn target_defaultfb_dims(&mut self) -> (u32, u32) {
+        self.dims
+    }
+    #[inline]
+    fn pixel_format(&self) -> PixelFormat {
+        PixelFormat::RGBA32Float
+    }
+    #[inline]
+    fn depth_format(&self) -> Option<PixelFormat> {
+        Some(PixelFormat::Depth32Float_Stencil8)
+    }
+    unsafe fn new_buffer_storage(
+        &self,
+        size: usize,
+        data: Option<NonNull<c_void>>,
+    ) -> BufferStorage {
+        let mut storage = vec![0; size].into_boxed_slice();
+        if let Some(ptr) = data {
+            // Safety: caller ensures that ptr points to at least `size` initialized bytes
+            storage
+                .copy_from_slice(unsafe { std::slice::from_raw_parts(ptr.as_ptr().cast(), size) });
+        }
+        BufferStorage::Host(storage)
+    }
+    fn stage_compiler(&self) -> Arc<dyn StageCompiler> {
+        // stages are executed from their SPIR-V, which is parsed when building the pipeline
+        Arc::new(HostStageCompiler)
+    }
+    #[inline]
+    fn has_render_encoder(&self) -> bool {
+        self.has_encoder
+    }
+    fn new_render_encoder(&mut self, state: &GlState, desc: &RenderPassDescriptor) {
+        debug_assert!(
+            state.framebuffer_binding.is_none(),
+            "software backend does not support user-defined framebuffers yet"
+        );
+        self.end_encoding();
+        self.has_encoder = true;
+        // like a Metal clear load action, the clear is applied at the start of the pass
+        let clear = &state.clear_values;
+        if clear.mask.contains(ClearBufferMask::COLOR_BUFFER_BIT)
+            && desc.color_attachments[0].is_some()
+        {
+            let mask: [bool; 4] = state.writemasks.color[0].into();
+            for px in &mut self.color {
+                for ((dst, src), write) in px.iter_mut().zip(clear.color).zip(mask) {
+                    if write {
+                        *dst = src.clamp(0.0, 1.0);
+                    }
+                }
+            }
+        }
+        if clear.mask.contains(ClearBufferMask::DEPTH_BUFFER_BIT) && state.writemasks.depth {
+            self.depth.fill(clear.depth.clamp(0.0, 1.0));
+        }
+        if clear.mask.contains(ClearBufferMask::STENCIL_BUFFER_BIT) {
+            let mask = state.writemasks.stencil_front;
+            for s in &mut self.stencil {
+                *s = ((u32::from(*s) & !mask) | (clear.stencil & mask)) as u8;
+            }
+        }
+    }
+    fn end_encoding(&mut self) {
+        self.has_encoder = false;
+    }
+    fn update_render_encoder(&mut self, encoder_state: &EncoderState) {
+        self.encoder_state = Some(encoder_state.clone());
+    }
+    fn new_render_pipeline(&mut self, state: &GlState, desc: &RenderPipelineDescriptor) {
+        let parse = |stage| {
+            let linked = Renderer::linked_stage(state, stage)
+                .unwrap_or_else(|| panic!("tried to build a pipeline without a {stage:?}"));
+            Module::new(&linked.spirv)
+                .unwrap_or_else(|e| panic!("software backend failed to load {stage:?} SPIR-V: {e}"))
+        };
+        let geometry = Renderer::linked_geometry(state);
+        let vertex = match Renderer::fetch_stages(state, &desc.attribute_conversions) {
+            // with a geometry stage, the variant is the vertex kernel of the pre-pass this backend doesn't run
+            Some(_) if geometry.is_some() => panic!(
+                "software backend does not support vertex attribute conversions with a geometry stage"
+            ),
+            Some(stages) => Module::new(&stages.vertex.spirv).unwrap_or_else(|e| {
+                panic!("software backend failed to load converted vertex SPIR-V: {e}")
+            }),
+            None => parse(ShaderType::VertexShader),
+        };
+        let fragment = parse(ShaderType::FragmentShader);
+        let geometry = geometry.map(|g| {
+            let module = parse(ShaderType::GeometryShader);
+            GeometryPipeline {
+                inputs: Self::link_varyings(&vertex, &module),
+                module,
+                info: g.layout.info,
+            }
+        });
+        let varyings =
+            Self::link_varyings(geometry.as_ref().map_or(&vertex, |g| &g.module), &fragment);
+        let outputs = fragment
+            .variables(StorageClass::Output)
+            .filter(|&v| !fragment.is_builtin(v))
+            .map(|v| (v, fragment.location(v).unwrap_or(0)))
+            .collect();
+        self.pipeline = Some(Pipeline {
+            desc: desc.clone(),
+            vertex,
+            geometry,
+            fragment,
+            varyings,
+            outputs,
+        });
+    }
+    fn bind_render_pipeline(&mut self) {}
+    fn bind_buffer(&mut self, _state: &GlState, arg: &BufferArgument) {
+        // uniform and storage blocks are resolved from the GL binding points when drawing
+        if arg.stage == ShaderType::VertexShader {
+            self.vertex_buffers
+                .insert(arg.index, (arg.buffer, arg.offset));
+        }
+    }
+    fn set_bytes(&mut self, stage: ShaderType, _index: u32, bytes: &[u8]) {
+        // the default uniform block is the only user of this, so there is a single block per stage
+        let idx = match stage {
+            ShaderType::VertexShader => 0,
+            ShaderType::GeometryShader => 1,
+            ShaderType::FragmentShader => 2,
+            // tessellation stages are not supported, see `draw_tessellation`
+            ShaderType::TessControlShader | ShaderType::TessEvaluationShader => return,
+            s => unreachable!("tried to set bytes for {s:?} on the render encoder"),
+        };
+        self.push_constants[idx] = bytes.to_vec();
+    }
+    fn bind_image(&mut self, _state: &GlState, _arg: &ImageArgument) {
+        panic!("software backend does not support image uniforms");
+    }
+    fn draw(&mut self, state: &GlState, call: &DrawCall) {
+        let pipeline = self
+            .pipeline
+            .take()
+            .expect("software backend tried to draw without a pipeline");
+        let viewport = self
+            .encoder_state
+            .as_ref()
+            .expect("software backend tried to draw without encoder state")
+            .viewport;
+        let [
+            vertex_push_constants,
+            geometry_push_constants,
+            fragment_push_constants,
+        ] = mem::take(&mut self.push_constants);
+        let vertex_buffers = Self::block_buffers(state, &pipeline.vertex, &vertex_push_constants);
+        let fragment_buffers =
+            Self::block_buffers(state, &pipeline.fragment, &fragment_push_constants);
+
+        // instanced vertex attributes are not supported yet, so the base instance has no effect
+        let (mode, instance_count) = match *call {
+            DrawCall::Arrays {
+                mode,
+                instance_count,
+                ..
+            }
+            | DrawCall::Elements {
+                mode,
+                instance_count,
+                ..
+            } => (mode, instance_count),
+        };
+        let ids = call.vertex_ids(state);
+        gl_trace!(
+            "software backend drawing {} vertices ({instance_count} instances)",
+            ids.len()
+        );
+
+        for instance in 0..instance_count {
+            let mut cache = HashMap::new();
+            let verts = ids
+                .iter()
+                .map(|&id| {
+                    cache
+                        .entry(id)
+                        .or_insert_with(|| {
+                            let vertex = self.shade_vertex(
+                                state,
+                                &pipeline,
+                                &pipeline.varyings,
+                                &vertex_buffers,
+                                id,
+                                instance,
+                            );
+                            ShadedVertex::new(vertex, viewport, pipeline.desc.clip_control)
+                        })
+                        .clone()
+                })
+                .collect::<Vec<_>>();
+            self.rasterize(state, &pipeline, &fragment_buffers, mode, &verts);
+        }
+        self.push_constants = [
+            vertex_push_constants,
+            geometry_push_constants,
+            fragment_push_constants,
+        ];
+        self.pipeline = Some(pipeline);
+    }
+    fn draw_geometry(&mut self, state: &GlState, draw: &PrepassDraw) {
+        let pipeline = self
+            .pipeline
+            .take()
+            .expect("software backend tried to draw without a pipeline");
+        let geometry = pipeline
+            .geometry
+            .as_ref()
+            .expect("software backend tried to draw through a pipeline without a geometry stage");
+        let viewport = self
+            .encoder_state
+            .as_ref()
+            .expect("software backend tried to draw without encoder state")
+            .viewport;
+        let [
+            vertex_push_constants,
+            geometry_push_constants,
+            fragment_push_constants,
+        ] = mem::take(&mut self.push_constants);
+        let vertex_buffers = Self::block_buffers(state, &pipeline.vertex, &vertex_push_constants);
+        let geometry_buffers =
+            Self::block_buffers(state, &geometry.module, &geometry_push_constants);
+        let fragment_buffers =
+            Self::block_buffers(state, &pipeline.fragment, &fragment_push_constants);
+        let info = &geometry.info;
+        gl_trace!(
+            "software backend drawing {} {:?} primitives through a geometry stage ({} instances)",
+            draw.primitives.len() / info.input_vertices() as usize,
+            info.input,
+            draw.instance_count
+        );
+
+        for instance in 0..draw.instance_count {
+            let mut cache = HashMap::new();
+            let primitives = draw.primitives.chunks_exact(info.input_vertices() as usize);
+            for (primitive_id, primitive) in (0..).zip(primitives) {
+                let inputs = primitive
+                    .iter()
+                    .map(|&v| {
+                        cache
+                            .entry(v)
+                            .or_insert_with(|| {
+                                self.shade_vertex(
+                                    state,
+                                    &pipeline,
+                                    &geometry.inputs,
+                                    &vertex_buffers,
+                                    draw.vertex_base + v,
+                                    instance,
+                                )
+                            })
+                            .clone()
+                    })
+                    .collect::<Vec<_>>();
+                for invocation in 0..info.invocations {
+                    let strips = Self::shade_primitive(
+                        geometry,
+                        &pipeline.varyings,
+                        &pipeline.desc,
+                        &geometry_buffers,
+                        &inputs,
+                        primitive_id,
+                        invocation,
+                    );
+                    for strip in strips {
+                        let verts = strip
+                            .into_iter()
+                            .map(|vertex| {
+                                ShadedVertex::new(vertex, viewport, pipeline.desc.clip_control)
+                            })
+                            .collect::<Vec<_>>();
+                        self.rasterize(state, &pipeline, &fragment_buffers, info.output, &verts);
+                    }
+                }
+            }
+        }
+        self.push_constants = [
+            vertex_push_constants,
+            geometry_push_constants,
+            fragment_push_constants,
+        ];
+        self.pipeline = Some(pipeline);
+    }
+    fn draw_tessellation(&mut self, _state: &GlState, _draw: &PrepassDraw) {
+        panic!("software backend does not support tessellation stages");
+    }
+    fn draw_feedback(&mut self, _state: &GlState, _draw: &FeedbackDraw) {
+        panic!("software backend does not support transform feedback");
+    }
+    fn has_compute_encoder(&self) -> bool {
+        false
+    }
+    fn new_compute_encoder(&mut self) {
+        self.end_encoding();
+    }
+    fn new_compute_pipeline(&mut self, _state: &GlState, _desc: &ComputePipelineDescriptor) {
+        panic!("software backend does not support compute shaders");
+    }
+    fn bind_compute_pipeline(&mut self) {}
+    fn dispatch(&mut self, _state: &GlState, _dispatch: &ComputeDispatch) {
+        panic!("software backend does not support compute shaders");
+    }
+    // draws run to completion when they are encoded
+    fn memory_barrier(&mut self, _barriers: MemoryBarrierMask) {}
+    fn present(&mut self) {}
+    fn push_debug_group(&mut self, _name: &str) {}
+    fn pop_debug_group(&mut self) {}
+    fn color_buffer(&self) -> Option<((u32, u32), &[[f32; 4]])> {
+        Some((self.dims, &self.color))
+    }
+}
+
+/// Decode the components of a single vertex attribute from `bytes` into `out`
+fn decode_vertex_format(format: VertexFormat, bytes: &[u8], out: &mut [f64; 4]) {
+    #[derive(Clone, Copy)]
+    enum Kind {
+        Float,
+        Half,
+        Signed(usize),
+        Unsigned(usize),
+    }
+    macro_rules! formats {
+        ($($kind:expr, $norm:literal => $($fmt:ident $n:literal),+;)+) => {
+            &[$($((VertexFormat::$fmt, $kind, $norm, $n)),+),+]
+        };
+    }
+    const FORMATS: &[(VertexFormat, Kind, bool, usize)] = formats! {
+        Kind::Float, false => Float 1, Float2 2, Float3 3, Float4 4;
+        Kind::Half, false => Half 1, Half2 2, Half3 3, Half4 4;
+        Kind::Signed(4), false => Int 1, Int2 2, Int3 3, Int4 4;
+        Kind::Unsigned(4), false => UInt 1, UInt2 2, UInt3 3, UInt4 4;
+        Kind::Signed(2), false => Short 1, Short2 2, Short3 3, Short4 4;
+        Kind::Unsigned(2), false => UShort 1, UShort2 2, UShort3 3, UShort4 4;
+        Kind::Signed(1), false => Char 1, Char2 2, Char3 3, Char4 4;
+        Kind::Unsigned(1), false => UChar 1, UChar2 2, UChar3 3, UChar4 4;
+        Kind::Signed(2), true => ShortNormalized 1, Short2Normalized 2, Short3Normalized 3, Short4Normalized 4;
+        Kind::Unsigned(2), true => UShortNormalized 1, UShort2Normalized 2, UShort3Normalized 3, UShort4Normalized 4;
+        Kind::Signed(1), true => CharNormalized 1, Char2Normalized 2, Char3Normalized 3, Char4Normalized 4;
+        Kind::Unsigned(1), true => UCharNormalized 1, UChar2Normalized 2, UChar3Normalized 3, UChar4Normalized 4;
+    };
+    let Some(&(_, kind, normalized, n)) = FORMATS.iter().find(|f| f.0 == format) else {
+        panic!("software backend does not support vertex format {format:?}");
+    };
+    for (i, o) in out.iter_mut().take(n).enumerate() {
+        let read = |size: usize| {
+            let mut word = [0; 8];
+            word[..size].copy_from_slice(&bytes[i * size..(i + 1) * size]);
+            u64::from_le_bytes(word)
+        };
+        *o = match kind {
+            Kind::Float => f64::from(f32::from_bits(read(4) as u32)),
+            Kind::Half => f64::from(half::f16::from_bits(read(2) as u16).to_f32()),
+            Kind::Unsigned(size) => {
+                let v = read(size) as f64;
+                if normalized {
+                    v / ((1u64 << (8 * size)) - 1) as f64
+                } else {
+                    v
+                }
+            }
+            Kind::Signed(size) => {
+                // sign extend
+                let shift = 64 - 8 * size;
+                let v = ((read(size) << shift).cast_signed() >> shift) as f64;
+                if normalized {
+                    (v / ((1u64 << (8 * size - 1)) - 1) as f64).max(-1.0)
+                } else {
+                    v
+                }
+            }
+        };
+    }
+}
+
+/// Elements of the `gl_ClipDistance` output in `value` whose planes are enabled in `mask`
+fn enabled_clip_distances(value: &Value, mask: u8) -> Vec<f32> {
+    (0..MAX_CLIP_DISTANCES)
+        .zip(value.components())
+        .filter(|(plane, _)| mask & (1 << plane) != 0)
+        .map(|(_, distance)| distance.f32())
+        .collect()
+}
+
+/// Perspective-correct interpolation of a vertex stage output
+fn interpolate(values: [&Value; 3], weights: [f32; 3]) -> Value {
+    match values[0] {
+        Value::Composite(c) => Value::Composite(
+            (0..c.len())
+                .map(|i| interpolate(values.map(|v| &v.components()[i]), weights))
+                .collect(),
+        ),
+        _ => Value::from_f32(values.iter().zip(weights).map(|(v, w)| v.f32() * w).sum()),
+    }
+}
+
+fn depth_compare(func: DepthFunction, incoming: f32, stored: f32) -> bool {
+    match func {
+        DepthFunction::Never => false,
+        DepthFunction::Less => incoming < stored,
+        DepthFunction::Equal => incoming == stored,
+        DepthFunction::Lequal => incoming <= stored,
+        DepthFunction::Greater => incoming > stored,
+        DepthFunction::Notequal => incoming != stored,
+        DepthFunction::Gequal => incoming >= stored,
+        DepthFunction::Always => true,
+    }
+}
+fn stencil_compare(func: StencilFunction, reference: u32, stored: u32) -> bool {
+    match func {
+        StencilFunction::Never => false,
+        StencilFunction::Less => reference < stored,
+        StencilFunction::Equal => reference == stored,
+        StencilFunction::Lequal => reference <= stored,
+        StencilFunction::Greater => reference > stored,
+        StencilFunction::Notequal => reference != stored,
+        StencilFunction::Gequal => reference >= stored,
+        StencilFunction::Always => true,
+    }
+}
+/// Apply a stencil operation to an 8 bit stencil value, respecting the stencil write mask
+fn stencil_op(op: StencilOp, face: StencilFaceState, stored: u32, writemask: u32) -> u8 {
+    let new = match op {
+        StencilOp::Keep => stored,
+        StencilOp::Zero => 0,
+        StencilOp::Replace => face.reference,
+        StencilOp::Incr => (stored + 1).min(0xff),
+        StencilOp::Decr => stored.saturating_sub(1),
+        StencilOp::IncrWrap => stored.wrapping_add(1),
+        StencilOp::DecrWrap => stored.wrapping_sub(1),
+        StencilOp::Invert => !stored,
+    };
+    ((stored & !writemask) | (new & writemask)) as u8
+}
+fn blend(
+    state: DrawbufferBlendState,
+    src: [f32; 4],
+    dst: [f32; 4],
+    constant: [f32; 4],
+) -> [f32; 4] {
+    let factor = |f: BlendingFactor| -> [f32; 4] {
+        match f {
+            BlendingFactor::Zero => [0.0; 4],
+            BlendingFactor::One => [1.0; 4],
+            BlendingFactor::SrcColor => src,
+            BlendingFactor::OneMinusSrcColor => src.map(|c| 1.0 - c),
+            BlendingFactor::SrcAlpha => [src[3]; 4],
+            BlendingFactor::OneMinusSrcAlpha => [1.0 - src[3]; 4],
+            BlendingFactor::DstAlpha => [dst[3]; 4],
+            BlendingFactor::OneMinusDstAlpha => [1.0 - dst[3]; 4],
+            BlendingFactor::DstColor => dst,
+            BlendingFactor::OneMinusDstColor => dst.map(|c| 1.0 - c),
+            BlendingFactor::SrcAlphaSaturate => {
+                let f = src[3].min(1.0 - dst[3]);
+                [f, f, f, 1.0]
+            }
+            BlendingFactor::ConstantColor => constant,
+            BlendingFactor::OneMinusConstantColor => constant.map(|c| 1.0 - c),
+            BlendingFactor::ConstantAlpha => [constant[3]; 4],
+            BlendingFactor::OneMinusConstantAlpha => [1.0 - constant[3]; 4],
+            BlendingFactor::Src1Alpha
+            | BlendingFactor::Src1Color
+            | BlendingFactor::OneMinusSrc1Color
+            | BlendingFactor::OneMinusSrc1Alpha => {
+                panic!("software backend does not support dual source blending")
+            }
+        }
+    };
+    let equation = |eq: BlendEquationModeEXT, s: f32, sf: f32, d: f32, df: f32| match eq {
+        BlendEquationModeEXT::FuncAdd => s * sf + d * df,
+        BlendEquationModeEXT::FuncSubtract => s * sf - d * df,
+        BlendEquationModeEXT::FuncReverseSubtract => d * df - s * sf,
+        BlendEquationModeEXT::Min => s.min(d),
+        BlendEquationModeEXT::Max => s.max(d),
+    };
+    let (src_rgb, dst_rgb) = (factor(state.src_rgb), factor(state.dst_rgb));
+    let (src_a, dst_a) = (factor(state.src_alpha)[3], factor(state.dst_alpha)[3]);
+    let mut out: [f32; 4] =
+        core::array::from_fn(|c| equation(state.eq_rgb, src[c], src_rgb[c], dst[c], dst_rgb[c]));
+    out[3] = equation(state.eq_alpha, src[3], src_a, dst[3], dst_a);
+    out
+}
+
+#[test]
+fn software_hello_triangle() {
+    use crate::{
+        gl_enums::VertexAttribType,
+        test_util::{SCENE_SIZE, Scene},
+    };
+
+    let mut scene = Scene::with_program(&[
+        (
+            ShaderType::VertexShader,
+            include_str!("../../../tests/HelloTriangle/shader_triangle.vert"),
+        ),
+        (
+            ShaderType::FragmentShader,
+            include_str!("../../../tests/HelloTriangle/shader_triangle.frag"),
+        ),
+    ]);
+    // position, color
+    let vertices: [f32; 18] = [
+        -1.0, -1.0, 0.0, 1.0, 0.0, 0.0, //
+        1.0, -1.0, 0.0, 0.0, 1.0, 0.0, //
+        0.0, 1.0, 0.0, 0.0, 0.0, 1.0,
+    ];
+    scene.vertices(
+        &vertices,
+        &[
+            (3, VertexAttribType::Float, false),
+            (3, VertexAttribType::Float, false),
+        ],
+    );
+    scene.ctx.oxidegl_clear_color(0.25, 0.25, 0.25, 1.0);
+    scene.draw(PrimitiveType::Triangles, 3);
+
+    // the corners are outside of the triangle
+    assert_eq!(scene.pixel(0, SCENE_SIZE - 1), [0.25, 0.25, 0.25, 1.0]);
+    // the center is covered by an even-ish mix of the vertex colors
+    let center = scene.pixel(SCENE_SIZE / 2, SCENE_SIZE / 2);
+    assert!(
+        center[..3].iter().all(|&c| c > 0.1 && c < 0.9),
+        "{center:?}"
+    );
+    assert_eq!(center[3], 1.0);
+    // bottom left pixel is mostly red
+    let bottom_left = scene.pixel(1, 0);
+    assert!(bottom_left[0] > 0.8, "{bottom_left:?}");
+}
+
+#[test]
+fn software_geometry_shader() {
+    use crate::test_util::{SCENE_SIZE, Scene};
+
+    let mut scene = Scene::with_program(&[
+        (
+            ShaderType::VertexShader,
+            "#version 460
+            out vec4 vertexColor;
+            void main() {
+                gl_Position = vec4(gl_VertexID == 0 ? -0.5 : 0.5, 0.0, 0.0, 1.0);
+                vertexColor = gl_VertexID == 0 ? vec4(1.0, 0.0, 0.0, 1.0) : vec4(0.0, 0.0, 1.0, 1.0);
+            }",
+        ),
+        // expands each point into a triangle around it
+        (
+            ShaderType::GeometryShader,
+            "#version 460
+            layout(points) in;
+            layout(triangle_strip, max_vertices = 3) out;
+            in vec4 vertexColor[];
+            out vec4 geometryColor;
+            void main() {
+                vec4 p = gl_in[0].gl_Position;
+                geometryColor = vertexColor[0];
+                gl_Position = p + vec4(-0.25, -0.25, 0.0, 0.0);
+                EmitVertex();
+                gl_Position = p + vec4(0.25, -0.25, 0.0, 0.0);
+                EmitVertex();
+                gl_Position = p + vec4(0.0, 0.25, 0.0, 0.0);
+                EmitVertex();
+                EndPrimitive();
+            }",
+        ),
+        (
+            ShaderType::FragmentShader,
+            "#version 460
+            in vec4 geometryColor;
+            out vec4 FragColor;
+            void main() {
+                FragColor = geometryColor;
+            }",
+        ),
+    ]);
+    // lines can't be fed to a geometry shader that takes points
+    assert!(
+        scene
+            .ctx
+            .oxidegl_draw_arrays(PrimitiveType::Lines, 0, 2)
+            .is_err()
+    );
+    scene.draw(PrimitiveType::Points, 2);
+
+    // each point became a triangle in the color of its vertex
+    let (left, right) = (
+        scene.pixel(SCENE_SIZE / 4, SCENE_SIZE / 2),
+        scene.pixel(3 * SCENE_SIZE / 4, SCENE_SIZE / 2),
+    );
+    assert!(left[0] > 0.99 && left[2] < 0.01, "{left:?}");
+    assert!(right[0] < 0.01 && right[2] > 0.99, "{right:?}");
+    // nothing was drawn between them
+    assert_eq!(
+        scene.pixel(SCENE_SIZE / 2, SCENE_SIZE / 2),
+        [0.0, 0.0, 0.0, 1.0]
+    );
+}
+
+#[test]
+fn software_converted_attributes() {
+    use crate::{
+        gl_enums::VertexAttribType,
+        test_util::{SCENE_SIZE, Scene},
+    };
+
+    let mut scene = Scene::with_program(&[
+        (
+            ShaderType::VertexShader,
+            include_str!("../../../tests/HelloTriangle/shader_triangle.vert"),
+        ),
+        (
+            ShaderType::FragmentShader,
+            include_str!("../../../tests/HelloTriangle/shader_triangle.frag"),
+        ),
+    ]);
+    // integer positions that are cast to float, and colors that are normalized
+    let (one, half) = (i32::MAX, i32::MAX / 2);
+    let vertices: [i32; 18] = [
+        -1, -1, 0, one, half, 0, //
+        1, -1, 0, one, half, 0, //
+        0, 1, 0, one, half, 0,
+    ];
+    scene.vertices(
+        &vertices,
+        &[
+            (3, VertexAttribType::Int, false),
+            (3, VertexAttribType::Int, true),
+        ],
+    );
+    scene.draw(PrimitiveType::Triangles, 3);
+
+    // the positions were cast, so the triangle covers the same pixels as with float positions
+    assert_eq!(scene.pixel(0, SCENE_SIZE - 1), [0.0, 0.0, 0.0, 1.0]);
+    let center = scene.pixel(SCENE_SIZE / 2, SCENE_SIZE / 2);
+    assert!((center[0] - 1.0).abs() < 0.01, "{center:?}");
+    assert!((center[1] - 0.5).abs() < 0.01, "{center:?}");
+    assert_eq!(center[2], 0.0);
+}
+
+#[test]
+fn software_program_pipeline() {
+    use crate::{
+        context::Context,
+        gl_enums::{PipelineParameterName, UseProgramStageMask, VertexAttribType},
+        test_util::{SCENE_SIZE, Scene},
+    };
+
+    let mut scene = Scene::new();
+    let create = |ctx: &mut Context, ty, src: &str| {
+        let src = std::ffi::CString::new(src).unwrap();
+        let ptr = src.as_ptr();
+        // Safety: strings points to a single nul-terminated string
+        unsafe { ctx.oxidegl_create_shader_programv(ty, 1, &raw const ptr) }.unwrap()
+    };
+    let ctx = &mut scene.ctx;
+    let vert = create(
+        ctx,
+        ShaderType::VertexShader,
+        include_str!("../../../tests/HelloTriangle/shader_triangle.vert"),
+    );
+    let frag = create(
+        ctx,
+        ShaderType::FragmentShader,
+        include_str!("../../../tests/HelloTriangle/shader_triangle.frag"),
+    );
+    // reads an input the vertex program doesn't write
+    let mismatched = create(
+        ctx,
+        ShaderType::FragmentShader,
+        "#version 460
+        in vec4 otherColor;
+        out vec4 FragColor;
+        void main() { FragColor = otherColor; }",
+    );
+    let mut pipeline = 0;
+    // Safety: pointer is valid for the duration of the call
+    unsafe { ctx.oxidegl_gen_program_pipelines(1, &raw mut pipeline) }.unwrap();
+    ctx.oxidegl_bind_program_pipeline(pipeline).unwrap();
+    ctx.oxidegl_use_program_stages(pipeline, UseProgramStageMask::VERTEX_SHADER_BIT, vert)
+        .unwrap();
+    ctx.oxidegl_use_program_stages(pipeline, UseProgramStageMask::FRAGMENT_SHADER_BIT, frag)
+        .unwrap();
+    let mut value = 0;
+    // Safety: params points to a single GLint
+    unsafe {
+        ctx.oxidegl_get_program_pipelineiv(
+            pipeline,
+            PipelineParameterName::FragmentShader,
+            &raw mut value,
+        )
+    }
+    .unwrap();
+    assert_eq!(value, frag as i32);
+
+    let vertices: [f32; 18] = [
+        -1.0, -1.0, 0.0, 1.0, 0.0, 0.0, //
+        1.0, -1.0, 0.0, 0.0, 1.0, 0.0, //
+        0.0, 1.0, 0.0, 0.0, 0.0, 1.0,
+    ];
+    scene.vertices(
+        &vertices,
+        &[
+            (3, VertexAttribType::Float, false),
+            (3, VertexAttribType::Float, false),
+        ],
+    );
+    scene.draw(PrimitiveType::Triangles, 3);
+    assert_eq!(scene.pixel(0, SCENE_SIZE - 1), [0.0, 0.0, 0.0, 1.0]);
+    let bottom_left = scene.pixel(1, 0);
+    assert!(bottom_left[0] > 0.8, "{bottom_left:?}");
+
+    // stages of separately linked programs have to match
+    let ctx = &mut scene.ctx;
+    ctx.oxidegl_use_program_stages(
+        pipeline,
+        UseProgramStageMask::FRAGMENT_SHADER_BIT,
+        mismatched,
+    )
+    .unwrap();
+    assert!(
+        ctx.oxidegl_draw_arrays(PrimitiveType::Triangles, 0, 3)
+            .is_err()
+    );
+    ctx.oxidegl_validate_program_pipeline(pipeline).unwrap();
+    // Safety: params points to a single GLint
+    unsafe {
+        ctx.oxidegl_get_program_pipelineiv(
+            pipeline,
+            PipelineParameterName::InfoLogLength,
+            &raw mut value,
+        )
+    }
+    .unwrap();
+    assert!(value > 0);
+}
+
+#[test]
+fn software_clip_control() {
+    use crate::{
+        gl_enums::{EnableCap, GL_UPPER_LEFT, GetPName},
+        test_util::{SCENE_SIZE, Scene},
+    };
+
+    // a triangle at z = 0 that covers the bottom rows of the viewport
+    let mut scene = Scene::with_program(&[
+        (
+            ShaderType::VertexShader,
+            "#version 460
+            void main() {
+                gl_Position = vec4(gl_VertexID == 1 ? 3.0 : -1.0, gl_VertexID == 2 ? 0.0 : -1.0, 0.0, 1.0);
+            }",
+        ),
+        (
+            ShaderType::FragmentShader,
+            "#version 460
+            out vec4 FragColor;
+            void main() {
+                FragColor = vec4(1.0, 0.0, 0.0, 1.0);
+            }",
+        ),
+    ]);
+    scene.ctx.oxidegl_enable(EnableCap::DepthTest);
+    scene.ctx.oxidegl_clear_depth(0.5);
+    // red of the bottom and top left pixels
+    let draw = |scene: &mut Scene| {
+        scene.draw(PrimitiveType::Triangles, 3);
+        (scene.pixel(1, 1)[0], scene.pixel(1, SCENE_SIZE - 2)[0])
+    };
+    // z = 0 maps to the cleared depth, which fails the depth test
+    assert_eq!(draw(&mut scene), (0.0, 0.0));
+    // but not once the depth range is moved closer
+    scene.ctx.oxidegl_depth_range(0.0, 0.2).unwrap();
+    assert_eq!(draw(&mut scene), (1.0, 0.0));
+
+    // z = 0 is the near plane of a [0, 1] clip volume, and an upper left origin flips the triangle to the top rows
+    scene.ctx.oxidegl_depth_range(0.0, 1.0).unwrap();
+    scene
+        .ctx
+        .oxidegl_clip_control(ClipControlOrigin::UpperLeft, ClipControlDepth::ZeroToOne)
+        .unwrap();
+    assert_eq!(draw(&mut scene), (0.0, 1.0));
+
+    let ctx = &mut scene.ctx;
+    let mut origin = 0;
+    let mut range = [0.0; 2];
+    // Safety: data points to enough values for each query
+    unsafe {
+        ctx.oxidegl_get_integerv(GetPName::ClipOrigin, &raw mut origin);
+        ctx.oxidegl_get_doublev(GetPName::DepthRange, range.as_mut_ptr());
+    }
+    assert_eq!(origin, GL_UPPER_LEFT as i32);
+    assert_eq!(range, [0.0, 1.0]);
+    // the depth range of viewports other than the first can't be set
+    assert!(ctx.oxidegl_depth_range_indexed(1, 0.0, 1.0).is_err());
+}
+
+#[test]
+fn software_clip_distances() {
+    use crate::{
+        gl_enums::{EnableCap, GetPName},
+        test_util::{SCENE_SIZE, Scene},
+    };
+
+    // a triangle that covers the viewport, clipped against the left half by the first plane and against the bottom
+    // half by the second
+    let mut scene = Scene::with_program(&[
+        (
+            ShaderType::VertexShader,
+            "#version 460
+            out float gl_ClipDistance[2];
+            void main() {
+                vec2 p = vec2(gl_VertexID == 1 ? 3.0 : -1.0, gl_VertexID == 2 ? 3.0 : -1.0);
+                gl_Position = vec4(p, 0.0, 1.0);
+                gl_ClipDistance[0] = p.x;
+                gl_ClipDistance[1] = p.y;
+            }",
+        ),
+        (
+            ShaderType::FragmentShader,
+            "#version 460
+            out vec4 FragColor;
+            void main() {
+                FragColor = vec4(1.0, 0.0, 0.0, 1.0);
+            }",
+        ),
+    ]);
+    // red of the bottom left, bottom right, top left and top right pixels
+    let draw = |scene: &mut Scene| {
+        scene.draw(PrimitiveType::Triangles, 3);
+        let edge = SCENE_SIZE - 2;
+        [(1, 1), (edge, 1), (1, edge), (edge, edge)].map(|(x, y)| scene.pixel(x, y)[0])
+    };
+    // the distances written to disabled planes are ignored
+    assert_eq!(draw(&mut scene), [1.0; 4]);
+    scene.ctx.oxidegl_enable(EnableCap::ClipDistance0);
+    assert_eq!(draw(&mut scene), [0.0, 1.0, 0.0, 1.0]);
+    scene.ctx.oxidegl_enable(EnableCap::ClipDistance1);
+    assert_eq!(draw(&mut scene), [0.0, 0.0, 0.0, 1.0]);
+    scene.ctx.oxidegl_disable(EnableCap::ClipDistance0);
+    assert_eq!(draw(&mut scene), [0.0, 0.0, 1.0, 1.0]);
+
+    let mut max = 0;
+    // Safety: data points to a single value
+    unsafe {
+        scene
+            .ctx
+            .oxidegl_get_integerv(GetPName::MaxClipDistances, &raw mut max);
+    }
+    assert_eq!(max, 8);
+}
+
+#[test]
+fn software_points() {
+    use crate::{
+        gl_enums::{EnableCap, GL_LOWER_LEFT, GL_POINT_SPRITE_COORD_ORIGIN, GetPName},
+        test_util::Scene,
+    };
+
+    // a point at the center of the viewport, whose size is only used with GL_PROGRAM_POINT_SIZE
+    let mut scene = Scene::with_program(&[
+        (
+            ShaderType::VertexShader,
+            "#version 460
+            void main() {
+                gl_Position = vec4(0.0, 0.0, 0.0, 1.0);
+                gl_PointSize = 8.0;
+            }",
+        ),
+        (
+            ShaderType::FragmentShader,
+            "#version 460
+            out vec4 FragColor;
+            void main() {
+                FragColor = vec4(gl_PointCoord, 0.0, 1.0);
+            }",
+        ),
+    ]);
+    scene.ctx.oxidegl_clear_color(0.0, 0.0, 0.0, 0.0);
+    // point coordinates of the top left pixel of a 4 pixel point, and of the pixel left of it
+    let draw = |scene: &mut Scene| {
+        scene.draw(PrimitiveType::Points, 1);
+        let coord = |x, y| {
+            let [s, t, _, a] = scene.pixel(x, y);
+            (a != 0.0).then_some([s, t])
+        };
+        (coord(14, 17), coord(13, 16))
+    };
+    scene.ctx.oxidegl_point_size(4.0).unwrap();
+    assert_eq!(draw(&mut scene), (Some([0.125, 0.125]), None));
+    scene
+        .ctx
+        .oxidegl_point_parameteri(GL_POINT_SPRITE_COORD_ORIGIN, GL_LOWER_LEFT as i32)
+        .unwrap();
+    assert_eq!(draw(&mut scene), (Some([0.125, 0.875]), None));
+    // the size written by the vertex shader covers the pixel left of the smaller point
+    scene.ctx.oxidegl_enable(EnableCap::ProgramPointSize);
+    assert_eq!(draw(&mut scene).1, Some([0.1875, 0.5625]));
+
+    let ctx = &mut scene.ctx;
+    let mut size = 0.0;
+    let mut origin = 0;
+    // Safety: data points to a single value for each query
+    unsafe {
+        ctx.oxidegl_get_floatv(GetPName::PointSize, &raw mut size);
+        ctx.oxidegl_get_integerv(GetPName::PointSpriteCoordOrigin, &raw mut origin);
+    }
+    assert_eq!(size, 4.0);
+    assert_eq!(origin, GL_LOWER_LEFT as i32);
+    assert!(ctx.oxidegl_point_size(0.0).is_err());
+    assert!(
+        ctx.oxidegl_point_parameterf(GL_POINT_SPRITE_COORD_ORIGIN, 0.0)
+            .is_err()
+    );
+}
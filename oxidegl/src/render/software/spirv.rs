@@ -0,0 +1,1325 @@
+//! Minimal SPIR-V interpreter, used by the software backend to execute linked shader stages on the CPU.
+//! Only the subset of SPIR-V that glslang emits for (untextured) GLSL shaders is supported, and every value is assumed
+//! to be 32 bits wide.
+
+use ahash::{HashMap, HashMapExt};
+use spirv_cross2::spirv::{BuiltIn, Decoration, GLOp, Op, StorageClass};
+
+/// Error returned when the invocation executed a `discard`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Discarded;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) enum Value {
+    #[default]
+    Undef,
+    /// A scalar of any (32 bit) type, stored as its raw bits. Booleans are stored as 0 or 1
+    Scalar(u32),
+    /// A vector, matrix (composite of column vectors), array or struct
+    Composite(Box<[Value]>),
+    Pointer(Pointer),
+}
+/// A vertex or the end of a primitive emitted by a geometry stage invocation
+#[derive(Debug, Clone)]
+pub(crate) enum Emitted {
+    /// Contents of the output variables at the time the vertex was emitted, by variable id
+    Vertex {
+        stream: u32,
+        outputs: HashMap<u32, Value>,
+    },
+    EndPrimitive {
+        stream: u32,
+    },
+}
+/// Pointer to (a part of) the contents of a variable
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Pointer {
+    var: u32,
+    path: Vec<u32>,
+}
+impl Value {
+    #[inline]
+    pub(crate) fn from_f32(v: f32) -> Self {
+        Self::Scalar(v.to_bits())
+    }
+    #[inline]
+    pub(crate) fn from_bool(v: bool) -> Self {
+        Self::Scalar(u32::from(v))
+    }
+    #[inline]
+    pub(crate) fn from_f32s(v: &[f32]) -> Self {
+        Self::Composite(v.iter().copied().map(Self::from_f32).collect())
+    }
+    #[inline]
+    #[track_caller]
+    pub(crate) fn bits(&self) -> u32 {
+        match self {
+            Self::Scalar(v) => *v,
+            // reading undefined values is allowed, the result is just garbage
+            Self::Undef => 0,
+            v => panic!("expected a scalar value, got {v:?}"),
+        }
+    }
+    #[inline]
+    #[track_caller]
+    pub(crate) fn f32(&self) -> f32 {
+        f32::from_bits(self.bits())
+    }
+    #[inline]
+    #[track_caller]
+    fn bool(&self) -> bool {
+        self.bits() != 0
+    }
+    #[inline]
+    #[track_caller]
+    pub(crate) fn components(&self) -> &[Value] {
+        match self {
+            Self::Composite(c) => c,
+            Self::Scalar(_) | Self::Undef => core::slice::from_ref(self),
+            v => panic!("expected a composite value, got {v:?}"),
+        }
+    }
+    /// Returns the components of this (vector or scalar) value as floats
+    pub(crate) fn to_vec4(&self) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        for (o, v) in out.iter_mut().zip(self.components()) {
+            *o = v.f32();
+        }
+        out
+    }
+    #[track_caller]
+    fn pointer(&self) -> &Pointer {
+        let Self::Pointer(p) = self else {
+            panic!("expected a pointer, got {self:?}")
+        };
+        p
+    }
+    pub(crate) fn at_path(&self, path: &[u32]) -> &Value {
+        path.iter()
+            .fold(self, |v, &idx| &v.components()[idx as usize])
+    }
+    fn at_path_mut(&mut self, path: &[u32]) -> &mut Value {
+        let mut v = self;
+        for &idx in path {
+            let Self::Composite(c) = v else {
+                panic!("tried to index into a non-composite value")
+            };
+            v = &mut c[idx as usize];
+        }
+        v
+    }
+}
+
+fn map1(a: &Value, f: &impl Fn(u32) -> u32) -> Value {
+    match a {
+        Value::Composite(c) => Value::Composite(c.iter().map(|v| map1(v, f)).collect()),
+        v => Value::Scalar(f(v.bits())),
+    }
+}
+/// Applies `f` componentwise. If only one of the values is a composite, the other one is broadcast to every component
+fn map2(a: &Value, b: &Value, f: &impl Fn(u32, u32) -> u32) -> Value {
+    match (a, b) {
+        (Value::Composite(a), Value::Composite(b)) => {
+            Value::Composite(a.iter().zip(b.iter()).map(|(a, b)| map2(a, b, f)).collect())
+        }
+        (Value::Composite(a), b) => Value::Composite(a.iter().map(|a| map2(a, b, f)).collect()),
+        (a, Value::Composite(b)) => Value::Composite(b.iter().map(|b| map2(a, b, f)).collect()),
+        (a, b) => Value::Scalar(f(a.bits(), b.bits())),
+    }
+}
+fn map3(a: &Value, b: &Value, c: &Value, f: &impl Fn(u32, u32, u32) -> u32) -> Value {
+    match a {
+        Value::Composite(ac) => Value::Composite(
+            ac.iter()
+                .enumerate()
+                .map(|(i, a)| {
+                    let pick = |v: &Value| match v {
+                        Value::Composite(c) => c[i].clone(),
+                        v => v.clone(),
+                    };
+                    map3(a, &pick(b), &pick(c), f)
+                })
+                .collect(),
+        ),
+        a => Value::Scalar(f(a.bits(), b.bits(), c.bits())),
+    }
+}
+macro_rules! f1 {
+    ($a:expr, |$x:ident| $body:expr) => {
+        map1($a, &|$x| {
+            let $x = f32::from_bits($x);
+            f32::to_bits($body)
+        })
+    };
+}
+macro_rules! f2 {
+    ($a:expr, $b:expr, |$x:ident, $y:ident| $body:expr) => {
+        map2($a, $b, &|$x, $y| {
+            let ($x, $y) = (f32::from_bits($x), f32::from_bits($y));
+            f32::to_bits($body)
+        })
+    };
+}
+macro_rules! f3 {
+    ($a:expr, $b:expr, $c:expr, |$x:ident, $y:ident, $z:ident| $body:expr) => {
+        map3($a, $b, $c, &|$x, $y, $z| {
+            let ($x, $y, $z) = (f32::from_bits($x), f32::from_bits($y), f32::from_bits($z));
+            f32::to_bits($body)
+        })
+    };
+}
+macro_rules! i1 {
+    ($a:expr, |$x:ident| $body:expr) => {
+        map1($a, &|$x| {
+            let $x = $x.cast_signed();
+            i32::cast_unsigned($body)
+        })
+    };
+}
+macro_rules! i2 {
+    ($a:expr, $b:expr, |$x:ident, $y:ident| $body:expr) => {
+        map2($a, $b, &|$x, $y| {
+            let ($x, $y) = ($x.cast_signed(), $y.cast_signed());
+            i32::cast_unsigned($body)
+        })
+    };
+}
+macro_rules! cmp {
+    ($a:expr, $b:expr, $conv:expr, |$x:ident, $y:ident| $body:expr) => {
+        map2($a, $b, &|$x, $y| {
+            let ($x, $y) = ($conv($x), $conv($y));
+            u32::from($body)
+        })
+    };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Type {
+    Void,
+    Bool,
+    Int,
+    Float,
+    Vector(u32, u32),
+    Matrix(u32, u32),
+    Array(u32, u32),
+    RuntimeArray(u32),
+    Struct(Box<[u32]>),
+    Pointer(u32),
+    /// Functions, images, samplers and other types that don't have a value representation
+    Opaque,
+}
+
+#[derive(Debug)]
+struct Instruction {
+    op: Op,
+    operands: Box<[u32]>,
+}
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Variable {
+    pub(crate) storage: StorageClass,
+    /// type of the contents of this variable
+    pointee: u32,
+    initializer: Option<u32>,
+}
+#[derive(Debug)]
+struct Function {
+    params: Vec<u32>,
+    /// index of the first instruction of the function body
+    start: usize,
+}
+#[derive(Debug, Default, Clone)]
+struct Decorations {
+    location: Option<u32>,
+    builtin: Option<BuiltIn>,
+    binding: Option<u32>,
+    offset: Option<u32>,
+    array_stride: Option<u32>,
+    matrix_stride: Option<u32>,
+    flat: bool,
+    buffer_block: bool,
+}
+impl Decorations {
+    fn apply(&mut self, decoration: Decoration, args: &[u32]) {
+        let arg = || args.first().copied();
+        match decoration {
+            Decoration::Location => self.location = arg(),
+            Decoration::BuiltIn => self.builtin = arg().and_then(BuiltIn::from_u32),
+            Decoration::Binding => self.binding = arg(),
+            Decoration::Offset => self.offset = arg(),
+            Decoration::ArrayStride => self.array_stride = arg(),
+            Decoration::MatrixStride => self.matrix_stride = arg(),
+            Decoration::Flat => self.flat = true,
+            Decoration::BufferBlock => self.buffer_block = true,
+            _ => {}
+        }
+    }
+}
+
+/// A parsed SPIR-V module
+#[derive(Debug)]
+pub(crate) struct Module {
+    instructions: Vec<Instruction>,
+    types: Vec<Option<Type>>,
+    /// Values of constants and pointers to global variables, by id
+    constants: Vec<Option<Value>>,
+    variables: Vec<Option<Variable>>,
+    decorations: Vec<Decorations>,
+    member_decorations: HashMap<(u32, u32), Decorations>,
+    names: Vec<Option<Box<str>>>,
+    functions: HashMap<u32, Function>,
+    /// instruction index of each label
+    labels: Vec<usize>,
+    glsl_std: Option<u32>,
+    entry_point: u32,
+    /// Upper bound of ids in this module
+    bound: usize,
+}
+
+fn parse_string(words: &[u32]) -> (Box<str>, usize) {
+    let bytes = words
+        .iter()
+        .flat_map(|w| w.to_le_bytes())
+        .take_while(|&b| b != 0)
+        .collect::<Vec<_>>();
+    let len = bytes.len() / 4 + 1;
+    (String::from_utf8_lossy(&bytes).into(), len)
+}
+
+impl Module {
+    pub(crate) fn new(words: &[u32]) -> Result<Self, Box<str>> {
+        if words.len() < 5 || words[0] != 0x0723_0203 {
+            return Err("not a SPIR-V module".into());
+        }
+        let bound = words[3] as usize;
+        let mut module = Self {
+            instructions: Vec::new(),
+            types: vec![None; bound],
+            constants: vec![None; bound],
+            variables: vec![None; bound],
+            decorations: vec![Decorations::default(); bound],
+            member_decorations: HashMap::new(),
+            names: vec![None; bound],
+            functions: HashMap::new(),
+            labels: vec![0; bound],
+            glsl_std: None,
+            entry_point: 0,
+            bound,
+        };
+        let mut idx = 5;
+        let mut current_function = None;
+        while idx < words.len() {
+            let word_count = (words[idx] >> 16) as usize;
+            let opcode = words[idx] & 0xffff;
+            if word_count == 0 || idx + word_count > words.len() {
+                return Err("malformed SPIR-V instruction".into());
+            }
+            let op = Op::from_u32(opcode).ok_or("unknown SPIR-V opcode")?;
+            let operands: Box<[u32]> = words[idx + 1..idx + word_count].into();
+            idx += word_count;
+            module.parse_instruction(op, &operands, &mut current_function)?;
+            module.instructions.push(Instruction { op, operands });
+        }
+        if module.entry_point == 0 {
+            return Err("SPIR-V module has no entry point".into());
+        }
+        Ok(module)
+    }
+    fn parse_instruction(
+        &mut self,
+        op: Op,
+        o: &[u32],
+        current_function: &mut Option<u32>,
+    ) -> Result<(), Box<str>> {
+        let pos = self.instructions.len();
+        match op {
+            Op::EntryPoint => self.entry_point = o[1],
+            Op::ExtInstImport => {
+                if &*parse_string(&o[1..]).0 == "GLSL.std.450" {
+                    self.glsl_std = Some(o[0]);
+                }
+            }
+            Op::Name => self.names[o[0] as usize] = Some(parse_string(&o[1..]).0),
+            Op::Decorate => {
+                if let Some(d) = Decoration::from_u32(o[1]) {
+                    self.decorations[o[0] as usize].apply(d, &o[2..]);
+                }
+            }
+            Op::MemberDecorate => {
+                if let Some(d) = Decoration::from_u32(o[2]) {
+                    self.member_decorations
+                        .entry((o[0], o[1]))
+                        .or_default()
+                        .apply(d, &o[3..]);
+                }
+            }
+            Op::TypeVoid => self.types[o[0] as usize] = Some(Type::Void),
+            Op::TypeBool => self.types[o[0] as usize] = Some(Type::Bool),
+            Op::TypeInt => {
+                if o[1] != 32 {
+                    return Err("only 32 bit integers are supported".into());
+                }
+                self.types[o[0] as usize] = Some(Type::Int);
+            }
+            Op::TypeFloat => {
+                if o[1] != 32 {
+                    return Err("only 32 bit floats are supported".into());
+                }
+                self.types[o[0] as usize] = Some(Type::Float);
+            }
+            Op::TypeVector => self.types[o[0] as usize] = Some(Type::Vector(o[1], o[2])),
+            Op::TypeMatrix => self.types[o[0] as usize] = Some(Type::Matrix(o[1], o[2])),
+            Op::TypeArray => {
+                let len = self.constants[o[2] as usize]
+                    .as_ref()
+                    .ok_or("array length must be a constant")?
+                    .bits();
+                self.types[o[0] as usize] = Some(Type::Array(o[1], len));
+            }
+            Op::TypeRuntimeArray => self.types[o[0] as usize] = Some(Type::RuntimeArray(o[1])),
+            Op::TypeStruct => self.types[o[0] as usize] = Some(Type::Struct(o[1..].into())),
+            Op::TypePointer => self.types[o[0] as usize] = Some(Type::Pointer(o[2])),
+            Op::TypeFunction
+            | Op::TypeImage
+            | Op::TypeSampler
+            | Op::TypeSampledImage
+            | Op::TypeForwardPointer => self.types[o[0] as usize] = Some(Type::Opaque),
+            Op::Constant | Op::SpecConstant => {
+                self.constants[o[1] as usize] = Some(Value::Scalar(o[2]));
+            }
+            Op::ConstantTrue | Op::SpecConstantTrue => {
+                self.constants[o[1] as usize] = Some(Value::from_bool(true));
+            }
+            Op::ConstantFalse | Op::SpecConstantFalse => {
+                self.constants[o[1] as usize] = Some(Value::from_bool(false));
+            }
+            Op::ConstantComposite | Op::SpecConstantComposite => {
+                let v = o[2..]
+                    .iter()
+                    .map(|&c| self.constants[c as usize].clone().unwrap_or_default())
+                    .collect();
+                self.constants[o[1] as usize] = Some(Value::Composite(v));
+            }
+            Op::ConstantNull => self.constants[o[1] as usize] = Some(self.zero(o[0])),
+            Op::Undef if current_function.is_none() => {
+                self.constants[o[1] as usize] = Some(Value::Undef);
+            }
+            Op::Variable if current_function.is_none() => {
+                let Some(Type::Pointer(pointee)) = self.types[o[0] as usize] else {
+                    return Err("variable must have a pointer type".into());
+                };
+                let storage = StorageClass::from_u32(o[2]).ok_or("unknown storage class")?;
+                self.variables[o[1] as usize] = Some(Variable {
+                    storage,
+                    pointee,
+                    initializer: o.get(3).copied(),
+                });
+                self.constants[o[1] as usize] = Some(Value::Pointer(Pointer {
+                    var: o[1],
+                    path: Vec::new(),
+                }));
+            }
+            Op::Function => {
+                *current_function = Some(o[1]);
+                self.functions.insert(
+                    o[1],
+                    Function {
+                        params: Vec::new(),
+                        start: pos + 1,
+                    },
+                );
+            }
+            Op::FunctionParameter => {
+                let f = current_function.ok_or("function parameter outside of function")?;
+                let f = self.functions.get_mut(&f).unwrap();
+                f.params.push(o[1]);
+                f.start = pos + 1;
+            }
+            Op::FunctionEnd => *current_function = None,
+            Op::Label => self.labels[o[0] as usize] = pos,
+            _ => {}
+        }
+        Ok(())
+    }
+    #[track_caller]
+    fn ty(&self, id: u32) -> &Type {
+        self.types[id as usize]
+            .as_ref()
+            .expect("referenced an undefined SPIR-V type")
+    }
+    /// Zero value of the given type
+    fn zero(&self, ty: u32) -> Value {
+        match self.ty(ty) {
+            Type::Bool | Type::Int | Type::Float => Value::Scalar(0),
+            &Type::Vector(e, n) | &Type::Matrix(e, n) | &Type::Array(e, n) => {
+                Value::Composite((0..n).map(|_| self.zero(e)).collect())
+            }
+            Type::Struct(members) => {
+                Value::Composite(members.iter().map(|&m| self.zero(m)).collect())
+            }
+            Type::RuntimeArray(_) => Value::Composite(Box::new([])),
+            Type::Void | Type::Pointer(_) | Type::Opaque => Value::Undef,
+        }
+    }
+    /// Builds a value of the given (scalar or vector) type from up to 4 components
+    pub(crate) fn value_from_components(&self, ty: u32, components: [u32; 4]) -> Value {
+        match self.ty(ty) {
+            Type::Bool | Type::Int | Type::Float => Value::Scalar(components[0]),
+            &Type::Vector(_, n) => Value::Composite(
+                components[..n as usize]
+                    .iter()
+                    .copied()
+                    .map(Value::Scalar)
+                    .collect(),
+            ),
+            _ => self.zero(ty),
+        }
+    }
+    /// Returns whether values of the given type contain integers
+    pub(crate) fn is_integral(&self, ty: u32) -> bool {
+        match self.ty(ty) {
+            Type::Int | Type::Bool => true,
+            &Type::Vector(e, _) | &Type::Matrix(e, _) | &Type::Array(e, _) => self.is_integral(e),
+            Type::Struct(members) => members.iter().any(|&m| self.is_integral(m)),
+            _ => false,
+        }
+    }
+    /// Iterate over the global variables of this module with the given storage class
+    pub(crate) fn variables(&self, storage: StorageClass) -> impl Iterator<Item = u32> + '_ {
+        self.variables
+            .iter()
+            .enumerate()
+            .filter(move |(_, v)| v.is_some_and(|v| v.storage == storage))
+            .map(|(id, _)| id as u32)
+    }
+    #[inline]
+    pub(crate) fn variable(&self, var: u32) -> Variable {
+        self.variables[var as usize].expect("not a variable")
+    }
+    #[inline]
+    pub(crate) fn pointee_type(&self, var: u32) -> u32 {
+        self.variable(var).pointee
+    }
+    #[inline]
+    pub(crate) fn location(&self, var: u32) -> Option<u32> {
+        self.decorations[var as usize].location
+    }
+    #[inline]
+    pub(crate) fn binding(&self, var: u32) -> Option<u32> {
+        self.decorations[var as usize].binding
+    }
+    #[inline]
+    pub(crate) fn name(&self, id: u32) -> Option<&str> {
+        self.names[id as usize].as_deref()
+    }
+    /// Returns whether the given variable is a shader storage block (as opposed to a uniform block)
+    pub(crate) fn is_storage_block(&self, var: u32) -> bool {
+        let Variable {
+            storage, pointee, ..
+        } = self.variable(var);
+        storage == StorageClass::StorageBuffer
+            || self.decorations[pointee as usize].buffer_block
+            || matches!(self.ty(pointee), &Type::Array(e, _) if self.decorations[e as usize].buffer_block)
+    }
+    /// Returns whether the given interface variable should not be interpolated
+    pub(crate) fn is_flat(&self, var: u32) -> bool {
+        self.decorations[var as usize].flat || self.is_integral(self.pointee_type(var))
+    }
+    /// Find the variable (and struct member, if the builtin is a member of an interface block) that is decorated with the given builtin
+    pub(crate) fn builtin(
+        &self,
+        storage: StorageClass,
+        builtin: BuiltIn,
+    ) -> Option<(u32, Option<u32>)> {
+        self.variables(storage).find_map(|var| {
+            if self.decorations[var as usize].builtin == Some(builtin) {
+                return Some((var, None));
+            }
+            let ty = self.pointee_type(var);
+            let Type::Struct(members) = self.ty(ty) else {
+                return None;
+            };
+            (0..members.len() as u32)
+                .find(|&m| {
+                    self.member_decorations
+                        .get(&(ty, m))
+                        .is_some_and(|d| d.builtin == Some(builtin))
+                })
+                .map(|m| (var, Some(m)))
+        })
+    }
+    /// Like [`Module::builtin`], but for per-vertex inputs of geometry stages, which are arrays of (blocks of) builtins.
+    /// The member is relative to an element of the array
+    pub(crate) fn arrayed_builtin(
+        &self,
+        storage: StorageClass,
+        builtin: BuiltIn,
+    ) -> Option<(u32, Option<u32>)> {
+        self.variables(storage).find_map(|var| {
+            if self.decorations[var as usize].builtin == Some(builtin) {
+                return Some((var, None));
+            }
+            let &Type::Array(ty, _) = self.ty(self.pointee_type(var)) else {
+                return None;
+            };
+            let Type::Struct(members) = self.ty(ty) else {
+                return None;
+            };
+            (0..members.len() as u32)
+                .find(|&m| {
+                    self.member_decorations
+                        .get(&(ty, m))
+                        .is_some_and(|d| d.builtin == Some(builtin))
+                })
+                .map(|m| (var, Some(m)))
+        })
+    }
+    /// Returns whether the given interface variable is (or is a block, or array of blocks, containing) a builtin
+    pub(crate) fn is_builtin(&self, var: u32) -> bool {
+        if self.decorations[var as usize].builtin.is_some() {
+            return true;
+        }
+        let mut ty = self.pointee_type(var);
+        if let &Type::Array(e, _) = self.ty(ty) {
+            ty = e;
+        }
+        matches!(self.ty(ty), Type::Struct(members) if (0..members.len() as u32).any(|m| {
+            self.member_decorations.get(&(ty, m)).is_some_and(|d| d.builtin.is_some())
+        }))
+    }
+    fn member_decoration(&self, ty: u32, member: u32) -> Option<&Decorations> {
+        self.member_decorations.get(&(ty, member))
+    }
+
+    /// Reads a value of the given type from buffer memory laid out according to the offset and stride decorations in this module
+    fn read_buffer(
+        &self,
+        ty: u32,
+        bytes: &[u8],
+        offset: usize,
+        matrix_stride: Option<u32>,
+    ) -> Value {
+        match self.ty(ty) {
+            Type::Bool | Type::Int | Type::Float => {
+                let mut word = [0; 4];
+                if let Some(b) = bytes.get(offset..offset + 4) {
+                    word.copy_from_slice(b);
+                }
+                let v = u32::from_le_bytes(word);
+                Value::Scalar(if *self.ty(ty) == Type::Bool {
+                    u32::from(v != 0)
+                } else {
+                    v
+                })
+            }
+            &Type::Vector(e, n) => Value::Composite(
+                (0..n as usize)
+                    .map(|i| self.read_buffer(e, bytes, offset + 4 * i, None))
+                    .collect(),
+            ),
+            &Type::Matrix(col, n) => {
+                let stride = matrix_stride.unwrap_or(16) as usize;
+                Value::Composite(
+                    (0..n as usize)
+                        .map(|i| self.read_buffer(col, bytes, offset + stride * i, None))
+                        .collect(),
+                )
+            }
+            &Type::Array(e, n) => {
+                let stride = self.array_stride(ty);
+                Value::Composite(
+                    (0..n as usize)
+                        .map(|i| self.read_buffer(e, bytes, offset + stride * i, matrix_stride))
+                        .collect(),
+                )
+            }
+            &Type::RuntimeArray(e) => {
+                let stride = self.array_stride(ty);
+                let n = bytes.len().saturating_sub(offset) / stride.max(1);
+                Value::Composite(
+                    (0..n)
+                        .map(|i| self.read_buffer(e, bytes, offset + stride * i, matrix_stride))
+                        .collect(),
+                )
+            }
+            Type::Struct(members) => Value::Composite(
+                members
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &m)| {
+                        let d = self.member_decoration(ty, i as u32);
+                        self.read_buffer(
+                            m,
+                            bytes,
+                            offset + d.and_then(|d| d.offset).unwrap_or(0) as usize,
+                            d.and_then(|d| d.matrix_stride),
+                        )
+                    })
+                    .collect(),
+            ),
+            Type::Void | Type::Pointer(_) | Type::Opaque => Value::Undef,
+        }
+    }
+    fn array_stride(&self, ty: u32) -> usize {
+        self.decorations[ty as usize]
+            .array_stride
+            .expect("buffer-backed array type is missing an ArrayStride decoration")
+            as usize
+    }
+}
+
+/// State of a single shader invocation
+#[derive(Debug)]
+pub(crate) struct Invocation<'m, 'b> {
+    module: &'m Module,
+    /// Results of instructions, by id
+    values: Vec<Value>,
+    /// Contents of (non buffer-backed) variables, by id
+    memory: Vec<Value>,
+    /// Backing memory of buffer-backed variables
+    buffers: HashMap<u32, &'b [u8]>,
+    /// Vertices and primitive ends emitted so far, if this is a geometry stage invocation
+    pub(crate) emitted: Vec<Emitted>,
+}
+impl<'m, 'b> Invocation<'m, 'b> {
+    pub(crate) fn new(module: &'m Module, buffers: HashMap<u32, &'b [u8]>) -> Self {
+        let mut memory = vec![Value::Undef; module.bound];
+        for (id, var) in module.variables.iter().enumerate() {
+            if let Some(var) = var {
+                memory[id] = match var.initializer {
+                    Some(init) => module.constants[init as usize].clone().unwrap_or_default(),
+                    None => module.zero(var.pointee),
+                };
+            }
+        }
+        Self {
+            module,
+            values: vec![Value::Undef; module.bound],
+            memory,
+            buffers,
+            emitted: Vec::new(),
+        }
+    }
+    /// Sets the contents of a (non buffer-backed) variable, or of one of its members
+    pub(crate) fn write_variable(&mut self, var: u32, member: Option<u32>, value: Value) {
+        let dst = &mut self.memory[var as usize];
+        *dst.at_path_mut(member.as_slice()) = value;
+    }
+    /// Sets a part of the contents of a (non buffer-backed) variable, given by a path of member and element indices
+    pub(crate) fn write_variable_path(&mut self, var: u32, path: &[u32], value: Value) {
+        *self.memory[var as usize].at_path_mut(path) = value;
+    }
+    /// Reads the contents of a (non buffer-backed) variable, or of one of its members
+    pub(crate) fn read_variable(&self, var: u32, member: Option<u32>) -> &Value {
+        self.memory[var as usize].at_path(member.as_slice())
+    }
+    /// Run the entry point of the module
+    pub(crate) fn run(&mut self) -> Result<(), Discarded> {
+        self.call(self.module.entry_point, &[]).map(|_| ())
+    }
+    #[inline]
+    fn get(&self, id: u32) -> &Value {
+        match &self.module.constants[id as usize] {
+            Some(c) => c,
+            None => &self.values[id as usize],
+        }
+    }
+    fn load(&self, ptr: &Pointer) -> Value {
+        let Some(&bytes) = self.buffers.get(&ptr.var) else {
+            return self.memory[ptr.var as usize].at_path(&ptr.path).clone();
+        };
+        let module = self.module;
+        let mut ty = module.pointee_type(ptr.var);
+        let mut offset = 0;
+        let mut matrix_stride = None;
+        for &idx in &ptr.path {
+            match module.ty(ty) {
+                Type::Struct(members) => {
+                    let d = module.member_decoration(ty, idx);
+                    offset += d.and_then(|d| d.offset).unwrap_or(0) as usize;
+                    matrix_stride = d.and_then(|d| d.matrix_stride);
+                    ty = members[idx as usize];
+                }
+                &Type::Array(e, _) | &Type::RuntimeArray(e) => {
+                    offset += idx as usize * module.array_stride(ty);
+                    ty = e;
+                }
+                &Type::Matrix(col, _) => {
+                    offset += idx as usize * matrix_stride.unwrap_or(16) as usize;
+                    matrix_stride = None;
+                    ty = col;
+                }
+                &Type::Vector(e, _) => {
+                    offset += idx as usize * 4;
+                    ty = e;
+                }
+                t => panic!("tried to index into {t:?}"),
+            }
+        }
+        module.read_buffer(ty, bytes, offset, matrix_stride)
+    }
+    fn store(&mut self, ptr: &Pointer, value: Value) {
+        assert!(
+            !self.buffers.contains_key(&ptr.var),
+            "the software renderer does not support writing to buffer-backed variables"
+        );
+        *self.memory[ptr.var as usize].at_path_mut(&ptr.path) = value;
+    }
+    fn call(&mut self, func: u32, args: &[Value]) -> Result<Option<Value>, Discarded> {
+        let module = self.module;
+        let f = &module.functions[&func];
+        for (&param, arg) in f.params.iter().zip(args) {
+            self.values[param as usize] = arg.clone();
+        }
+        let mut pc = f.start;
+        let mut prev_block = 0;
+        let mut cur_block = 0;
+        loop {
+            let Instruction { op, operands: o } = &module.instructions[pc];
+            pc += 1;
+            match *op {
+                Op::Label => {
+                    prev_block = cur_block;
+                    cur_block = o[0];
+                }
+                Op::Branch => pc = module.labels[o[0] as usize],
+                Op::BranchConditional => {
+                    let target = if self.get(o[0]).bool() { o[1] } else { o[2] };
+                    pc = module.labels[target as usize];
+                }
+                Op::Switch => {
+                    let selector = self.get(o[0]).bits();
+                    let target = o[2..]
+                        .chunks_exact(2)
+                        .find(|c| c[0] == selector)
+                        .map_or(o[1], |c| c[1]);
+                    pc = module.labels[target as usize];
+                }
+                Op::Phi => {
+                    let v = o[2..]
+                        .chunks_exact(2)
+                        .find(|c| c[1] == prev_block)
+                        .map(|c| self.get(c[0]).clone())
+                        .unwrap_or_default();
+                    self.values[o[1] as usize] = v;
+                }
+                Op::Return | Op::FunctionEnd => return Ok(None),
+                Op::ReturnValue => return Ok(Some(self.get(o[0]).clone())),
+                Op::Kill | Op::TerminateInvocation | Op::DemoteToHelperInvocation => {
+                    return Err(Discarded);
+                }
+                Op::Unreachable => panic!("executed OpUnreachable"),
+                Op::Variable => {
+                    let pointee = module.variables[o[1] as usize]
+                        .map(|v| v.pointee)
+                        .or_else(|| match module.ty(o[0]) {
+                            &Type::Pointer(p) => Some(p),
+                            _ => None,
+                        })
+                        .expect("variable must have a pointer type");
+                    self.memory[o[1] as usize] = match o.get(3) {
+                        Some(&init) => self.get(init).clone(),
+                        None => module.zero(pointee),
+                    };
+                    self.values[o[1] as usize] = Value::Pointer(Pointer {
+                        var: o[1],
+                        path: Vec::new(),
+                    });
+                }
+                Op::FunctionCall => {
+                    let args = o[3..]
+                        .iter()
+                        .map(|&a| self.get(a).clone())
+                        .collect::<Vec<_>>();
+                    let ret = self.call(o[2], &args)?;
+                    self.values[o[1] as usize] = ret.unwrap_or_default();
+                }
+                Op::Store => {
+                    let ptr = self.get(o[0]).pointer().clone();
+                    let v = self.get(o[1]).clone();
+                    self.store(&ptr, v);
+                }
+                Op::CopyMemory => {
+                    let v = self.load(self.get(o[1]).pointer());
+                    let ptr = self.get(o[0]).pointer().clone();
+                    self.store(&ptr, v);
+                }
+                Op::EmitVertex | Op::EmitStreamVertex => {
+                    let stream = o.first().map_or(0, |&s| self.get(s).bits());
+                    let outputs = module
+                        .variables(StorageClass::Output)
+                        .map(|v| (v, self.memory[v as usize].clone()))
+                        .collect();
+                    self.emitted.push(Emitted::Vertex { stream, outputs });
+                }
+                Op::EndPrimitive | Op::EndStreamPrimitive => {
+                    let stream = o.first().map_or(0, |&s| self.get(s).bits());
+                    self.emitted.push(Emitted::EndPrimitive { stream });
+                }
+                // instructions without a result
+                Op::SelectionMerge
+                | Op::LoopMerge
+                | Op::Line
+                | Op::NoLine
+                | Op::Nop
+                | Op::ControlBarrier
+                | Op::MemoryBarrier => {}
+                op => {
+                    let v = self.exec(op, o[0], &o[2..]);
+                    self.values[o[1] as usize] = v;
+                }
+            }
+        }
+    }
+    /// Execute an instruction that has a result type and id. `ty` is the result type and `o` are the
+    /// remaining operands
+    fn exec(&self, op: Op, ty: u32, o: &[u32]) -> Value {
+        let module = self.module;
+        let a = || self.get(o[0]);
+        let b = || self.get(o[1]);
+        match op {
+            Op::Undef => Value::Undef,
+            Op::Load => self.load(a().pointer()),
+            Op::AccessChain | Op::InBoundsAccessChain => {
+                let mut ptr = a().pointer().clone();
+                ptr.path
+                    .extend(o[1..].iter().map(|&idx| self.get(idx).bits()));
+                Value::Pointer(ptr)
+            }
+            Op::ArrayLength => {
+                let ptr = a().pointer();
+                let struct_ty = module.pointee_type(ptr.var);
+                let Type::Struct(members) = module.ty(struct_ty) else {
+                    panic!("OpArrayLength on a non-struct");
+                };
+                let offset = module
+                    .member_decoration(struct_ty, o[1])
+                    .and_then(|d| d.offset)
+                    .unwrap_or(0) as usize;
+                let stride = module.array_stride(members[o[1] as usize]);
+                let len = self.buffers.get(&ptr.var).map_or(0, |b| b.len());
+                Value::Scalar((len.saturating_sub(offset) / stride.max(1)) as u32)
+            }
+            Op::CopyObject
+            | Op::CopyLogical
+            | Op::Bitcast
+            | Op::UConvert
+            | Op::SConvert
+            | Op::FConvert => a().clone(),
+            Op::CompositeConstruct => {
+                // vector construction may be passed vectors, which must be flattened
+                let flatten = matches!(module.ty(ty), Type::Vector(..));
+                let mut out = Vec::new();
+                for &id in o {
+                    let v = self.get(id);
+                    match v {
+                        Value::Composite(c) if flatten => out.extend(c.iter().cloned()),
+                        v => out.push(v.clone()),
+                    }
+                }
+                Value::Composite(out.into())
+            }
+            Op::CompositeExtract => a().at_path(&o[1..]).clone(),
+            Op::CompositeInsert => {
+                let mut v = b().clone();
+                *v.at_path_mut(&o[2..]) = a().clone();
+                v
+            }
+            Op::VectorExtractDynamic => a().components()[b().bits() as usize].clone(),
+            Op::VectorInsertDynamic => {
+                let mut v = a().clone();
+                *v.at_path_mut(&[self.get(o[2]).bits()]) = b().clone();
+                v
+            }
+            Op::VectorShuffle => {
+                let (v1, v2) = (a().components(), b().components());
+                Value::Composite(
+                    o[2..]
+                        .iter()
+                        .map(|&c| match c {
+                            0xffff_ffff => Value::Undef,
+                            c if (c as usize) < v1.len() => v1[c as usize].clone(),
+                            c => v2[c as usize - v1.len()].clone(),
+                        })
+                        .collect(),
+                )
+            }
+            Op::Transpose => transpose(a()),
+            Op::Select => {
+                let cond = a();
+                match cond {
+                    Value::Composite(c) => Value::Composite(
+                        c.iter()
+                            .enumerate()
+                            .map(|(i, c)| {
+                                if c.bool() {
+                                    b().components()[i].clone()
+                                } else {
+                                    self.get(o[2]).components()[i].clone()
+                                }
+                            })
+                            .collect(),
+                    ),
+                    c if c.bool() => b().clone(),
+                    _ => self.get(o[2]).clone(),
+                }
+            }
+
+            Op::FNegate => f1!(a(), |x| -x),
+            Op::SNegate => i1!(a(), |x| x.wrapping_neg()),
+            Op::Not => map1(a(), &|x| !x),
+            Op::LogicalNot => map1(a(), &|x| u32::from(x == 0)),
+            Op::FAdd => f2!(a(), b(), |x, y| x + y),
+            Op::FSub => f2!(a(), b(), |x, y| x - y),
+            Op::FMul | Op::VectorTimesScalar | Op::MatrixTimesScalar => {
+                f2!(a(), b(), |x, y| x * y)
+            }
+            Op::FDiv => f2!(a(), b(), |x, y| x / y),
+            Op::FRem => f2!(a(), b(), |x, y| x % y),
+            Op::FMod => f2!(a(), b(), |x, y| x - y * (x / y).floor()),
+            Op::IAdd => map2(a(), b(), &u32::wrapping_add),
+            Op::ISub => map2(a(), b(), &u32::wrapping_sub),
+            Op::IMul => map2(a(), b(), &u32::wrapping_mul),
+            Op::UDiv => map2(a(), b(), &|x, y| x.checked_div(y).unwrap_or(0)),
+            Op::SDiv => i2!(a(), b(), |x, y| x.checked_div(y).unwrap_or(0)),
+            Op::UMod => map2(a(), b(), &|x, y| x.checked_rem(y).unwrap_or(0)),
+            Op::SRem => i2!(a(), b(), |x, y| x.checked_rem(y).unwrap_or(0)),
+            Op::SMod => i2!(a(), b(), |x, y| x.checked_rem_euclid(y).unwrap_or(0)),
+            Op::ShiftLeftLogical => map2(a(), b(), &|x, y| x.wrapping_shl(y)),
+            Op::ShiftRightLogical => map2(a(), b(), &|x, y| x.wrapping_shr(y)),
+            Op::ShiftRightArithmetic => i2!(a(), b(), |x, y| x.wrapping_shr(y.cast_unsigned())),
+            Op::BitwiseAnd | Op::LogicalAnd => map2(a(), b(), &|x, y| x & y),
+            Op::BitwiseOr | Op::LogicalOr => map2(a(), b(), &|x, y| x | y),
+            Op::BitwiseXor => map2(a(), b(), &|x, y| x ^ y),
+            Op::LogicalEqual | Op::IEqual => map2(a(), b(), &|x, y| u32::from(x == y)),
+            Op::LogicalNotEqual | Op::INotEqual => map2(a(), b(), &|x, y| u32::from(x != y)),
+            Op::UGreaterThan => map2(a(), b(), &|x, y| u32::from(x > y)),
+            Op::UGreaterThanEqual => map2(a(), b(), &|x, y| u32::from(x >= y)),
+            Op::ULessThan => map2(a(), b(), &|x, y| u32::from(x < y)),
+            Op::ULessThanEqual => map2(a(), b(), &|x, y| u32::from(x <= y)),
+            Op::SGreaterThan => cmp!(a(), b(), u32::cast_signed, |x, y| x > y),
+            Op::SGreaterThanEqual => cmp!(a(), b(), u32::cast_signed, |x, y| x >= y),
+            Op::SLessThan => cmp!(a(), b(), u32::cast_signed, |x, y| x < y),
+            Op::SLessThanEqual => cmp!(a(), b(), u32::cast_signed, |x, y| x <= y),
+            Op::FOrdEqual => cmp!(a(), b(), f32::from_bits, |x, y| x == y),
+            Op::FUnordEqual => cmp!(a(), b(), f32::from_bits, |x, y| x == y
+                || x.is_nan()
+                || y.is_nan()),
+            Op::FOrdNotEqual => cmp!(a(), b(), f32::from_bits, |x, y| x != y
+                && !x.is_nan()
+                && !y.is_nan()),
+            Op::FUnordNotEqual => cmp!(a(), b(), f32::from_bits, |x, y| x != y),
+            Op::FOrdLessThan => cmp!(a(), b(), f32::from_bits, |x, y| x < y),
+            Op::FUnordLessThan => cmp!(a(), b(), f32::from_bits, |x, y| x < y
+                || x.is_nan()
+                || y.is_nan()),
+            Op::FOrdGreaterThan => cmp!(a(), b(), f32::from_bits, |x, y| x > y),
+            Op::FUnordGreaterThan => cmp!(a(), b(), f32::from_bits, |x, y| x > y
+                || x.is_nan()
+                || y.is_nan()),
+            Op::FOrdLessThanEqual => cmp!(a(), b(), f32::from_bits, |x, y| x <= y),
+            Op::FUnordLessThanEqual => cmp!(a(), b(), f32::from_bits, |x, y| x <= y
+                || x.is_nan()
+                || y.is_nan()),
+            Op::FOrdGreaterThanEqual => cmp!(a(), b(), f32::from_bits, |x, y| x >= y),
+            Op::FUnordGreaterThanEqual => cmp!(a(), b(), f32::from_bits, |x, y| x >= y
+                || x.is_nan()
+                || y.is_nan()),
+            Op::IsNan => map1(a(), &|x| u32::from(f32::from_bits(x).is_nan())),
+            Op::IsInf => map1(a(), &|x| u32::from(f32::from_bits(x).is_infinite())),
+            Op::Any => Value::from_bool(a().components().iter().any(Value::bool)),
+            Op::All => Value::from_bool(a().components().iter().all(Value::bool)),
+
+            Op::ConvertFToU => map1(a(), &|x| f32::from_bits(x) as u32),
+            Op::ConvertFToS => map1(a(), &|x| (f32::from_bits(x) as i32).cast_unsigned()),
+            Op::ConvertUToF => map1(a(), &|x| (x as f32).to_bits()),
+            Op::ConvertSToF => map1(a(), &|x| (x.cast_signed() as f32).to_bits()),
+            Op::QuantizeToF16 => f1!(a(), |x| half::f16::from_f32(x).to_f32()),
+
+            Op::Dot => Value::from_f32(dot(a(), b())),
+            Op::MatrixTimesVector => mat_mul_vec(a(), b()),
+            Op::VectorTimesMatrix => Value::Composite(
+                b().components()
+                    .iter()
+                    .map(|col| Value::from_f32(dot(a(), col)))
+                    .collect(),
+            ),
+            Op::MatrixTimesMatrix => Value::Composite(
+                b().components()
+                    .iter()
+                    .map(|col| mat_mul_vec(a(), col))
+                    .collect(),
+            ),
+            Op::OuterProduct => Value::Composite(
+                b().components()
+                    .iter()
+                    .map(|s| f2!(a(), s, |x, y| x * y))
+                    .collect(),
+            ),
+
+            // fragments are shaded individually, so derivatives are always zero
+            Op::DPdx
+            | Op::DPdy
+            | Op::Fwidth
+            | Op::DPdxFine
+            | Op::DPdyFine
+            | Op::FwidthFine
+            | Op::DPdxCoarse
+            | Op::DPdyCoarse
+            | Op::FwidthCoarse => f1!(a(), |_x| 0.0),
+
+            Op::ExtInst => {
+                assert_eq!(
+                    Some(o[0]),
+                    module.glsl_std,
+                    "only the GLSL.std.450 extended instruction set is supported"
+                );
+                let glop = GLOp::from_u32(o[1]).expect("unknown GLSL.std.450 instruction");
+                self.exec_glsl(glop, &o[2..])
+            }
+            op => panic!("the software renderer does not support {op:?}"),
+        }
+    }
+    fn exec_glsl(&self, op: GLOp, o: &[u32]) -> Value {
+        let a = || self.get(o[0]);
+        let b = || self.get(o[1]);
+        let c = || self.get(o[2]);
+        match op {
+            GLOp::Round => f1!(a(), |x| x.round()),
+            GLOp::RoundEven => f1!(a(), |x| x.round_ties_even()),
+            GLOp::Trunc => f1!(a(), |x| x.trunc()),
+            GLOp::FAbs => f1!(a(), |x| x.abs()),
+            GLOp::SAbs => i1!(a(), |x| x.wrapping_abs()),
+            GLOp::FSign => f1!(a(), |x| if x == 0.0 { 0.0 } else { x.signum() }),
+            GLOp::SSign => i1!(a(), |x| x.signum()),
+            GLOp::Floor => f1!(a(), |x| x.floor()),
+            GLOp::Ceil => f1!(a(), |x| x.ceil()),
+            GLOp::Fract => f1!(a(), |x| x - x.floor()),
+            GLOp::Radians => f1!(a(), |x| x.to_radians()),
+            GLOp::Degrees => f1!(a(), |x| x.to_degrees()),
+            GLOp::Sin => f1!(a(), |x| x.sin()),
+            GLOp::Cos => f1!(a(), |x| x.cos()),
+            GLOp::Tan => f1!(a(), |x| x.tan()),
+            GLOp::Asin => f1!(a(), |x| x.asin()),
+            GLOp::Acos => f1!(a(), |x| x.acos()),
+            GLOp::Atan => f1!(a(), |x| x.atan()),
+            GLOp::Sinh => f1!(a(), |x| x.sinh()),
+            GLOp::Cosh => f1!(a(), |x| x.cosh()),
+            GLOp::Tanh => f1!(a(), |x| x.tanh()),
+            GLOp::Asinh => f1!(a(), |x| x.asinh()),
+            GLOp::Acosh => f1!(a(), |x| x.acosh()),
+            GLOp::Atanh => f1!(a(), |x| x.atanh()),
+            GLOp::Atan2 => f2!(a(), b(), |y, x| y.atan2(x)),
+            GLOp::Pow => f2!(a(), b(), |x, y| x.powf(y)),
+            GLOp::Exp => f1!(a(), |x| x.exp()),
+            GLOp::Log => f1!(a(), |x| x.ln()),
+            GLOp::Exp2 => f1!(a(), |x| x.exp2()),
+            GLOp::Log2 => f1!(a(), |x| x.log2()),
+            GLOp::Sqrt => f1!(a(), |x| x.sqrt()),
+            GLOp::InverseSqrt => f1!(a(), |x| x.sqrt().recip()),
+            GLOp::Determinant => Value::from_f32(determinant(&to_rows(a()))),
+            GLOp::MatrixInverse => inverse(a()),
+            GLOp::FMin | GLOp::NMin => f2!(a(), b(), |x, y| x.min(y)),
+            GLOp::FMax | GLOp::NMax => f2!(a(), b(), |x, y| x.max(y)),
+            GLOp::UMin => map2(a(), b(), &u32::min),
+            GLOp::UMax => map2(a(), b(), &u32::max),
+            GLOp::SMin => i2!(a(), b(), |x, y| x.min(y)),
+            GLOp::SMax => i2!(a(), b(), |x, y| x.max(y)),
+            GLOp::FClamp | GLOp::NClamp => f3!(a(), b(), c(), |x, lo, hi| x.max(lo).min(hi)),
+            GLOp::UClamp => map3(a(), b(), c(), &|x, lo, hi| x.max(lo).min(hi)),
+            GLOp::SClamp => map3(a(), b(), c(), &|x, lo, hi| {
+                x.cast_signed()
+                    .max(lo.cast_signed())
+                    .min(hi.cast_signed())
+                    .cast_unsigned()
+            }),
+            GLOp::FMix => f3!(a(), b(), c(), |x, y, t| x * (1.0 - t) + y * t),
+            GLOp::Step => f2!(a(), b(), |edge, x| if x < edge { 0.0 } else { 1.0 }),
+            GLOp::SmoothStep => f3!(a(), b(), c(), |e0, e1, x| {
+                let t = ((x - e0) / (e1 - e0)).clamp(0.0, 1.0);
+                t * t * (3.0 - 2.0 * t)
+            }),
+            GLOp::Fma => f3!(a(), b(), c(), |x, y, z| x.mul_add(y, z)),
+            GLOp::Length => Value::from_f32(dot(a(), a()).sqrt()),
+            GLOp::Distance => {
+                let d = f2!(a(), b(), |x, y| x - y);
+                Value::from_f32(dot(&d, &d).sqrt())
+            }
+            GLOp::Cross => {
+                let (x, y) = (a().to_vec4(), b().to_vec4());
+                Value::from_f32s(&[
+                    x[1] * y[2] - y[1] * x[2],
+                    x[2] * y[0] - y[2] * x[0],
+                    x[0] * y[1] - y[0] * x[1],
+                ])
+            }
+            GLOp::Normalize => {
+                let len = dot(a(), a()).sqrt();
+                f1!(a(), |x| x / len)
+            }
+            GLOp::FaceForward => {
+                // faceforward(N, I, Nref)
+                if dot(c(), b()) < 0.0 {
+                    a().clone()
+                } else {
+                    f1!(a(), |x| -x)
+                }
+            }
+            GLOp::Reflect => {
+                let d = 2.0 * dot(b(), a());
+                f2!(a(), b(), |i, n| i - d * n)
+            }
+            GLOp::Refract => {
+                let eta = c().f32();
+                let ni = dot(b(), a());
+                let k = 1.0 - eta * eta * (1.0 - ni * ni);
+                if k < 0.0 {
+                    f1!(a(), |_x| 0.0)
+                } else {
+                    f2!(a(), b(), |i, n| eta * i - (eta * ni + k.sqrt()) * n)
+                }
+            }
+            GLOp::FindILsb => map1(a(), &|x| {
+                if x == 0 { u32::MAX } else { x.trailing_zeros() }
+            }),
+            GLOp::FindUMsb => map1(a(), &|x| {
+                if x == 0 {
+                    u32::MAX
+                } else {
+                    31 - x.leading_zeros()
+                }
+            }),
+            GLOp::FindSMsb => map1(a(), &|x| {
+                let x = if x.cast_signed() < 0 { !x } else { x };
+                if x == 0 {
+                    u32::MAX
+                } else {
+                    31 - x.leading_zeros()
+                }
+            }),
+            GLOp::PackHalf2x16 => {
+                let v = a().to_vec4();
+                Value::Scalar(
+                    u32::from(half::f16::from_f32(v[0]).to_bits())
+                        | (u32::from(half::f16::from_f32(v[1]).to_bits()) << 16),
+                )
+            }
+            GLOp::UnpackHalf2x16 => {
+                let v = a().bits();
+                Value::from_f32s(&[
+                    half::f16::from_bits(v as u16).to_f32(),
+                    half::f16::from_bits((v >> 16) as u16).to_f32(),
+                ])
+            }
+            GLOp::PackUnorm4x8 => {
+                let v = a().to_vec4();
+                Value::Scalar(v.iter().enumerate().fold(0, |acc, (i, c)| {
+                    let c = (c.clamp(0.0, 1.0) * 255.0).round() as u32;
+                    acc | (c << (8 * i))
+                }))
+            }
+            GLOp::UnpackUnorm4x8 => {
+                let v = a().bits();
+                Value::from_f32s(&core::array::from_fn::<_, 4, _>(|i| {
+                    f32::from((v >> (8 * i)) as u8) / 255.0
+                }))
+            }
+            op => panic!("the software renderer does not support GLSL.std.450 {op:?}"),
+        }
+    }
+}
+
+fn dot(a: &Value, b: &Value) -> f32 {
+    a.components()
+        .iter()
+        .zip(b.components())
+        .map(|(a, b)| a.f32() * b.f32())
+        .sum()
+}
+fn mat_mul_vec(m: &Value, v: &Value) -> Value {
+    let cols = m.components();
+    let rows = cols[0].components().len();
+    Value::Composite(
+        (0..rows)
+            .map(|r| {
+                Value::from_f32(
+                    cols.iter()
+                        .zip(v.components())
+                        .map(|(col, s)| col.components()[r].f32() * s.f32())
+                        .sum(),
+                )
+            })
+            .collect(),
+    )
+}
+fn transpose(m: &Value) -> Value {
+    let cols = m.components();
+    let rows = cols[0].components().len();
+    Value::Composite(
+        (0..rows)
+            .map(|r| Value::Composite(cols.iter().map(|col| col.components()[r].clone()).collect()))
+            .collect(),
+    )
+}
+/// Converts a (column-major) matrix value into a list of rows
+fn to_rows(m: &Value) -> Vec<Vec<f32>> {
+    let cols = m.components();
+    let n = cols[0].components().len();
+    (0..n)
+        .map(|r| cols.iter().map(|c| c.components()[r].f32()).collect())
+        .collect()
+}
+fn determinant(m: &[Vec<f32>]) -> f32 {
+    match m.len() {
+        1 => m[0][0],
+        2 => m[0][0] * m[1][1] - m[0][1] * m[1][0],
+        n => (0..n)
+            .map(|c| {
+                let minor = m[1..]
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .enumerate()
+                            .filter(|&(i, _)| i != c)
+                            .map(|(_, v)| *v)
+                            .collect()
+                    })
+                    .collect::<Vec<_>>();
+                let sign = if c % 2 == 0 { 1.0 } else { -1.0 };
+                sign * m[0][c] * determinant(&minor)
+            })
+            .sum(),
+    }
+}
+fn inverse(m: &Value) -> Value {
+    // Gauss-Jordan elimination on [M | I]
+    let mut rows = to_rows(m);
+    let n = rows.len();
+    for (i, row) in rows.iter_mut().enumerate() {
+        row.extend((0..n).map(|j| if i == j { 1.0 } else { 0.0 }));
+    }
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&a, &b| rows[a][col].abs().total_cmp(&rows[b][col].abs()))
+            .unwrap();
+        rows.swap(col, pivot);
+        let p = rows[col][col];
+        for v in &mut rows[col] {
+            *v /= p;
+        }
+        let pivot_row = rows[col].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != col {
+                let factor = row[col];
+                for (v, p) in row.iter_mut().zip(&pivot_row) {
+                    *v -= factor * p;
+                }
+            }
+        }
+    }
+    // back to column-major
+    Value::Composite(
+        (0..n)
+            .map(|c| Value::from_f32s(&rows.iter().map(|row| row[n + c]).collect::<Vec<_>>()))
+            .collect(),
+    )
+}
@@ -1,6 +1,11 @@
-use std::{fmt::Debug, mem};
+use std::{
+    borrow::Cow,
+    fmt::{Debug, Write},
+    sync::Arc,
+};
 
-use crate::{gl_enums::ShaderType, util::NoDebug};
+use crate::gl_enums::ShaderType;
+use ahash::{HashMap, HashMapExt};
 use glslang::{
     Compiler as GlslangCompiler, CompilerOptions, Shader as GlslLangShader, ShaderInput,
     ShaderMessage, ShaderSource, ShaderStage, SourceLanguage, Target,
@@ -10,12 +15,19 @@ use glslang::{
 //     valid::{Capabilities, ModuleInfo, ValidationFlags, Validator},
 //     ShaderStage, WithSpan,
 // };
-use spirv_cross2::Module;
+use spirv_cross2::spirv::{Decoration, ExecutionModel, Op};
 
 use super::{
+    compile_pool::{CompilePool, Pending},
+    conversions::gl_string_len,
     debug::gl_debug,
     gl_object::{NamedObject, NoLateInit, ObjectName},
+    program::subroutines::{StageSubroutines, lower_subroutines},
 };
+
+mod version;
+use version::translate_version;
+
 //TODO: write more debug logging to compiler_log
 #[derive(Debug)]
 pub struct Shader {
@@ -28,12 +40,51 @@ pub struct Shader {
 #[derive(Debug, Default)]
 pub struct GlslShaderInternal {
     pub(crate) source: String,
-    pub(crate) latest_shader: Option<NoDebug<GlslLangShader<'static>>>,
+    /// The latest compilation of this shader, which may still be running on the compile pool
+    pub(crate) compilation: Option<Pending<Arc<GlslCompilation>>>,
 }
+/// Result of compiling a GLSL shader
 #[derive(Debug)]
+pub(crate) struct GlslCompilation {
+    /// The source that was compiled, which may differ from the source of the shader if it was changed after compiling.
+    /// It is already translated to GLSL 4.50 core if it was written for another version, and its subroutines are
+    /// lowered
+    pub(crate) source: Box<str>,
+    /// The subroutines and subroutine uniforms declared by the source
+    pub(crate) subroutines: StageSubroutines,
+    /// SPIR-V of the source linked on its own, if it compiled successfully and doesn't need other shaders of its stage
+    /// to link. Programs with only this shader attached to the stage use it instead of parsing the source again
+    pub(crate) spirv: Option<Box<[u32]>>,
+    /// Whether the source compiled successfully
+    pub(crate) success: bool,
+    /// The info log of the compilation
+    pub(crate) log: String,
+}
+#[derive(Debug, Default)]
 pub struct SpirvShaderInternal {
     pub(crate) source: Vec<u32>,
-    pub(crate) latest_module: Option<NoDebug<Module<'static>>>,
+    /// The result of the latest successful specialization of this shader, if any
+    pub(crate) specialized: Option<SpecializedSpirv>,
+    /// Whether this shader was specialized successfully and not passed to glCompileShader since
+    pub(crate) compiled: bool,
+}
+#[derive(Debug, Clone)]
+pub struct SpecializedSpirv {
+    /// Name of the entry point selected by glSpecializeShader
+    pub(crate) entry_point: Box<str>,
+    /// The SPIR-V module of this shader, reduced to the selected entry point and with its specialization constants
+    /// replaced by the values passed to glSpecializeShader
+    pub(crate) words: Box<[u32]>,
+}
+/// Reasons glSpecializeShader can fail
+#[derive(Debug)]
+pub(crate) enum SpecializationError {
+    /// the requested entry point does not exist for the stage of the shader
+    NoSuchEntryPoint,
+    /// the given specialization constant ID is not used by the module
+    NoSuchConstant(u32),
+    /// the module could not be parsed
+    Malformed(&'static str),
 }
 #[derive(Debug)]
 pub(crate) enum ShaderInternal {
@@ -44,22 +95,44 @@ impl ShaderInternal {
     pub(crate) fn is_spirv(&self) -> bool {
         matches!(self, ShaderInternal::Spirv(_))
     }
+    /// Value of `GL_SHADER_SOURCE_LENGTH` for this shader
     //4gb shader is not real, 4gb shader cannot hurt you
     #[expect(clippy::cast_possible_truncation)]
     pub(crate) fn source_len(&self) -> u32 {
         (match self {
-            ShaderInternal::Glsl(internal) => internal.source.len(),
-            // 4 byte words
-            ShaderInternal::Spirv(internal) => internal.source.len() * 4,
+            ShaderInternal::Glsl(internal) => gl_string_len(&internal.source),
+            // SPIR-V shaders have no source strings
+            ShaderInternal::Spirv(_) => 0,
         }) as u32
     }
-    /// Returns whether the last attempted compilation of this internal shader succeeded
+    /// Source strings of this shader, as returned by `glGetShaderSource`
+    pub(crate) fn source_str(&self) -> &str {
+        match self {
+            ShaderInternal::Glsl(internal) => &internal.source,
+            ShaderInternal::Spirv(_) => "",
+        }
+    }
+    /// Returns whether the last attempted compilation of this internal shader succeeded, waiting for it to finish if it
+    /// is still running
     pub(crate) fn compile_status(&self) -> bool {
         match self {
-            ShaderInternal::Glsl(glsl_shader_internal) => {
-                glsl_shader_internal.latest_shader.is_some()
-            }
-            ShaderInternal::Spirv(_spirv_shader_internal) => todo!(),
+            ShaderInternal::Glsl(glsl_shader_internal) => glsl_shader_internal
+                .compilation
+                .as_ref()
+                .is_some_and(|c| c.get().success),
+            ShaderInternal::Spirv(spirv_shader_internal) => spirv_shader_internal.compiled,
+        }
+    }
+    /// Returns whether the last compilation of this internal shader has finished, as queried by
+    /// `GL_COMPLETION_STATUS_KHR`
+    pub(crate) fn completion_status(&self) -> bool {
+        match self {
+            ShaderInternal::Glsl(glsl_shader_internal) => glsl_shader_internal
+                .compilation
+                .as_ref()
+                .is_none_or(Pending::is_complete),
+            // SPIR-V shaders are specialized synchronously
+            ShaderInternal::Spirv(_) => true,
         }
     }
 }
@@ -80,52 +153,120 @@ impl NamedObject for Shader {
 }
 
 impl Shader {
-    pub(crate) fn compile(&mut self) {
+    /// Start compiling this shader on the compile pool
+    pub(crate) fn compile(&mut self, pool: &mut CompilePool) {
+        self.compiler_log.clear();
         match &mut self.internal {
             ShaderInternal::Glsl(glsl_shader_internal) => {
-                // Clear the previous compilation attempt
-                glsl_shader_internal.latest_shader = None;
-                let source = ShaderSource::from(mem::take(&mut glsl_shader_internal.source));
-                let comp = GlslangCompiler::acquire().expect("failed to acquire Glslang compiler");
-
-                let opts = CompilerOptions {
-                    source_language: SourceLanguage::GLSL,
-                    target: Target::OpenGL {
-                        version: glslang::OpenGlVersion::OpenGL4_5,
-                        spirv_version: Some(glslang::SpirvVersion::SPIRV1_0),
-                    },
-                    version_profile: None,
-                    messages: ShaderMessage::RELAXED_ERRORS
-                        | ShaderMessage::ENHANCED
-                        | ShaderMessage::DEBUG_INFO
-                        | ShaderMessage::ONLY_PREPROCESSOR
-                        // VULKAN_RULES_RELAXED
-                        | ShaderMessage::from_bits_retain(1 << 2),
-                };
-
-                let input = match ShaderInput::new(
-                    &source,
-                    self.stage.to_glslang_stage(),
-                    &opts,
-                    None,
-                    None,
-                ) {
-                    Ok(input) => input,
-                    Err(err) => {
-                        self.write_to_compiler_log(&err.to_string());
-                        return;
-                    }
-                };
-                let shader = match comp.create_shader(input) {
-                    Ok(shader) => shader,
-                    Err(e) => {
-                        self.write_to_compiler_log(&e.to_string());
-                        return;
-                    }
-                };
-                glsl_shader_internal.latest_shader = Some(shader.into());
+                let source = glsl_shader_internal.source.clone();
+                let (name, stage) = (self.name.to_raw(), self.stage);
+                glsl_shader_internal.compilation = Some(pool.submit(move || {
+                    let compiler =
+                        GlslangCompiler::acquire().expect("failed to acquire Glslang compiler");
+                    let mut log = String::new();
+                    // glslang only accepts recent core profile shaders when targeting SPIR-V, and doesn't support
+                    // subroutines, so other versions are translated and subroutines are lowered beforehand
+                    let lowered = translate_version(&source, stage).and_then(|(translated, warnings)| {
+                        log.extend(warnings.iter().map(|w| format!("WARNING: {w}\n")));
+                        lower_subroutines(&translated)
+                    });
+                    let (source, subroutines, spirv, success) = match lowered {
+                        Ok((lowered, subroutines)) => match parse_glsl(compiler, stage, &lowered) {
+                            Ok(parsed) => {
+                                // glslang shaders aren't Send and are modified by linking, so they can't be kept for
+                                // the link. Shaders that link on their own (the usual single shader per stage) are
+                                // turned into SPIR-V here instead, others are parsed again with the rest of their stage
+                                let mut program = compiler.create_program();
+                                program.add_shader(&parsed);
+                                let spirv = program
+                                    .compile(stage.to_glslang_stage())
+                                    .ok()
+                                    .map(Vec::into_boxed_slice);
+                                (lowered, subroutines, spirv, true)
+                            }
+                            Err(e) => {
+                                log.push_str(&e);
+                                log.push('\n');
+                                (lowered, subroutines, None, false)
+                            }
+                        },
+                        Err(e) => {
+                            writeln!(log, "ERROR: {e}").unwrap();
+                            (source, StageSubroutines::default(), None, false)
+                        }
+                    };
+                    gl_debug!(src: ShaderCompiler, "compiled shader {name} (success: {success}) {log}");
+                    Arc::new(GlslCompilation {
+                        source: source.into_boxed_str(),
+                        subroutines,
+                        spirv,
+                        success,
+                        log,
+                    })
+                }));
+            }
+            ShaderInternal::Spirv(spirv_shader_internal) => {
+                // SPIR-V shaders are compiled by glSpecializeShader, glCompileShader just fails. Their specialization
+                // is kept so they can't be specialized again without reloading their binary
+                spirv_shader_internal.compiled = false;
+                self.write_to_compiler_log(
+                    "glCompileShader cannot compile SPIR-V shaders, use glSpecializeShader instead",
+                );
+            }
+        }
+    }
+    /// The info log of this shader, waiting for a running compilation to finish
+    pub(crate) fn info_log(&self) -> Cow<'_, str> {
+        match &self.internal {
+            ShaderInternal::Glsl(GlslShaderInternal {
+                compilation: Some(compilation),
+                ..
+            }) => Cow::Owned(compilation.get().log.clone()),
+            _ => Cow::Borrowed(&self.compiler_log),
+        }
+    }
+    /// Replace the contents of this shader with a SPIR-V module, as done by glShaderBinary
+    pub(crate) fn load_spirv(&mut self, words: Vec<u32>) {
+        gl_debug!(src: ShaderCompiler, "loaded {} word SPIR-V module into {:?}", words.len(), self.name);
+        self.internal = ShaderInternal::Spirv(SpirvShaderInternal {
+            source: words,
+            specialized: None,
+            compiled: false,
+        });
+    }
+    /// Select the entry point of a SPIR-V shader and set the values of its specialization constants, as done by
+    /// glSpecializeShader
+    pub(crate) fn specialize(
+        &mut self,
+        entry_point: &str,
+        constants: &[(u32, u32)],
+    ) -> Result<(), SpecializationError> {
+        self.compiler_log.clear();
+        let ShaderInternal::Spirv(internal) = &mut self.internal else {
+            unreachable!("tried to specialize a GLSL shader")
+        };
+        let words = specialize_spirv(
+            &internal.source,
+            self.stage.to_spirv_execution_model(),
+            entry_point,
+            constants,
+        );
+        match words {
+            Ok(words) => {
+                gl_debug!(src: ShaderCompiler, "specialized {:?} with entry point {entry_point} and {} constant(s)", self.name, constants.len());
+                internal.specialized = Some(SpecializedSpirv {
+                    entry_point: entry_point.into(),
+                    words,
+                });
+                internal.compiled = true;
+                Ok(())
+            }
+            Err(e) => {
+                internal.specialized = None;
+                internal.compiled = false;
+                self.write_to_compiler_log(&format!("failed to specialize SPIR-V module: {e:?}"));
+                Err(e)
             }
-            ShaderInternal::Spirv(_spirv_shader_internal) => todo!(),
         }
     }
     pub(crate) fn write_to_compiler_log(&mut self, info: &str) {
@@ -151,19 +292,158 @@ impl Shader {
     }
 }
 
+/// Magic number at the start of every SPIR-V module
+pub(crate) const SPIRV_MAGIC: u32 = 0x0723_0203;
+
+/// Decode a SPIR-V literal string, returning it along with the number of words it occupies
+pub(crate) fn spirv_string(words: &[u32]) -> (String, usize) {
+    let bytes = words
+        .iter()
+        .flat_map(|w| w.to_le_bytes())
+        .take_while(|&b| b != 0)
+        .collect::<Vec<_>>();
+    let len = bytes.len() / 4 + 1;
+    (String::from_utf8_lossy(&bytes).into_owned(), len)
+}
+/// Split a SPIR-V module (after its header) into (opcode, operands) pairs
+fn spirv_instructions(words: &[u32]) -> Result<Vec<(u32, &[u32])>, SpecializationError> {
+    if words.len() < 5 || words[0] != SPIRV_MAGIC {
+        return Err(SpecializationError::Malformed("not a SPIR-V module"));
+    }
+    let mut instructions = Vec::new();
+    let mut idx = 5;
+    while idx < words.len() {
+        let word_count = (words[idx] >> 16) as usize;
+        if word_count == 0 || idx + word_count > words.len() {
+            return Err(SpecializationError::Malformed(
+                "malformed SPIR-V instruction",
+            ));
+        }
+        instructions.push((words[idx] & 0xffff, &words[idx + 1..idx + word_count]));
+        idx += word_count;
+    }
+    Ok(instructions)
+}
+/// Produce a copy of `words` that only contains the entry point `entry_point` for the execution model `model`, and
+/// where the default values of the specialization constants with the given IDs are replaced with the given values
+fn specialize_spirv(
+    words: &[u32],
+    model: ExecutionModel,
+    entry_point: &str,
+    constants: &[(u32, u32)],
+) -> Result<Box<[u32]>, SpecializationError> {
+    let instructions = spirv_instructions(words)?;
+    let mut entry_id = None;
+    // result ID -> SpecId
+    let mut spec_ids = HashMap::new();
+    for &(opcode, o) in &instructions {
+        if opcode == Op::EntryPoint as u32
+            && o.len() >= 3
+            && o[0] == model as u32
+            && spirv_string(&o[2..]).0 == entry_point
+        {
+            entry_id = Some(o[1]);
+        } else if opcode == Op::Decorate as u32 && o.len() >= 3 && o[1] == Decoration::SpecId as u32
+        {
+            spec_ids.insert(o[0], o[2]);
+        }
+    }
+    let entry_id = entry_id.ok_or(SpecializationError::NoSuchEntryPoint)?;
+    if let Some(&(id, _)) = constants
+        .iter()
+        .find(|(id, _)| !spec_ids.values().any(|v| v == id))
+    {
+        return Err(SpecializationError::NoSuchConstant(id));
+    }
+    let mut out = words[..5].to_vec();
+    for (mut opcode, o) in instructions {
+        let is_entry_point = opcode == Op::EntryPoint as u32;
+        let is_execution_mode =
+            opcode == Op::ExecutionMode as u32 || opcode == Op::ExecutionModeId as u32;
+        // drop every other entry point, along with its execution modes
+        if (is_entry_point && o.get(1) != Some(&entry_id))
+            || (is_execution_mode && o.first() != Some(&entry_id))
+        {
+            continue;
+        }
+        let mut o = o.to_vec();
+        let value = o
+            .get(1)
+            .and_then(|result| spec_ids.get(result))
+            .and_then(|spec_id| constants.iter().rev().find(|(id, _)| id == spec_id))
+            .map(|&(_, value)| value);
+        if let Some(value) = value {
+            if opcode == Op::SpecConstant as u32 && o.len() >= 3 {
+                o[2] = value;
+            } else if opcode == Op::SpecConstantTrue as u32
+                || opcode == Op::SpecConstantFalse as u32
+            {
+                opcode = if value == 0 {
+                    Op::SpecConstantFalse as u32
+                } else {
+                    Op::SpecConstantTrue as u32
+                };
+            }
+        }
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "instruction word counts fit in 16 bits"
+        )]
+        out.push(((o.len() as u32 + 1) << 16) | opcode);
+        out.extend_from_slice(&o);
+    }
+    Ok(out.into_boxed_slice())
+}
+
 // TODO correctly detect device capabilities
 
+/// Parse and validate a GLSL shader with glslang
+pub(crate) fn parse_glsl(
+    compiler: &'static GlslangCompiler,
+    stage: ShaderType,
+    source: &str,
+) -> Result<GlslLangShader<'static>, String> {
+    let source = ShaderSource::from(source.to_owned());
+    let opts = CompilerOptions {
+        source_language: SourceLanguage::GLSL,
+        target: Target::OpenGL {
+            version: glslang::OpenGlVersion::OpenGL4_5,
+            spirv_version: Some(glslang::SpirvVersion::SPIRV1_0),
+        },
+        version_profile: None,
+        messages: ShaderMessage::RELAXED_ERRORS
+            | ShaderMessage::ENHANCED
+            | ShaderMessage::DEBUG_INFO
+            | ShaderMessage::ONLY_PREPROCESSOR
+            // VULKAN_RULES_RELAXED
+            | ShaderMessage::from_bits_retain(1 << 2),
+    };
+    let input = ShaderInput::new(&source, stage.to_glslang_stage(), &opts, None, None)
+        .map_err(|e| e.to_string())?;
+    compiler.create_shader(input).map_err(|e| e.to_string())
+}
+
 impl ShaderType {
     #[must_use]
     pub fn to_glslang_stage(self) -> ShaderStage {
         match self {
             ShaderType::FragmentShader => ShaderStage::Fragment,
             ShaderType::VertexShader => ShaderStage::Vertex,
-            //TODO: geometry and tesselation shader emulation :)
             ShaderType::GeometryShader => ShaderStage::Geometry,
             ShaderType::TessEvaluationShader => ShaderStage::TesselationEvaluation,
             ShaderType::TessControlShader => ShaderStage::TesselationControl,
             ShaderType::ComputeShader => ShaderStage::Compute,
         }
     }
+    #[must_use]
+    pub fn to_spirv_execution_model(self) -> ExecutionModel {
+        match self {
+            ShaderType::FragmentShader => ExecutionModel::Fragment,
+            ShaderType::VertexShader => ExecutionModel::Vertex,
+            ShaderType::GeometryShader => ExecutionModel::Geometry,
+            ShaderType::TessEvaluationShader => ExecutionModel::TessellationEvaluation,
+            ShaderType::TessControlShader => ExecutionModel::TessellationControl,
+            ShaderType::ComputeShader => ExecutionModel::GLCompute,
+        }
+    }
 }
@@ -0,0 +1,571 @@
+//! Translation of GLSL written for older versions, the compatibility profile or OpenGL ES to GLSL 4.50 core, since
+//! glslang only accepts desktop core profile shaders of version 3.30 or later when targeting SPIR-V for OpenGL. Those
+//! are compiled as they are, every other shader is rewritten before it is compiled:
+//! 1. Its `#version` directive (or the lack of one, which means version 1.10) is replaced by `#version 450 core`.
+//! 2. Identifiers that became keywords after the version the shader was written for are renamed.
+//! 3. If the shader was written for a version that has them (GLSL 1.10 to 1.30, GLSL ES 1.00 or the compatibility
+//!    profile), `attribute` and `varying` become `in` and `out`, and the built-in variables, uniforms and functions
+//!    that were removed from the core profile are replaced by declarations of ordinary variables and functions:
+//!    - `gl_FragColor` and `gl_FragData` become outputs at location 0
+//!    - built-in vertex attributes like `gl_Vertex` become inputs at the locations of the generic attributes they alias
+//!    - built-in varyings like `gl_TexCoord` become outputs of the vertex stage and inputs of the fragment stage
+//!    - built-in uniforms like `gl_ModelViewProjectionMatrix` become uniforms of the default block, which the
+//!      application has to set itself since there is no fixed function state to source them from
+//!    - `ftransform` and the texture lookup functions like `texture2D` become functions that call their core
+//!      counterparts
+//!
+//! Precision qualifiers of GLSL ES shaders are accepted by desktop GLSL, but have no effect there.
+//!
+//! The declarations are inserted after the new `#version` directive, followed by a `#line` directive so compiler
+//! messages still point at the lines the application wrote. Anything that doesn't change the meaning of the shader but
+//! may surprise the application is reported as a warning in the info log of the shader.
+
+use core::fmt::Write;
+
+use ahash::{HashMap, HashMapExt};
+
+use crate::{framebuffer::MAX_COLOR_ATTACHMENTS, gl_enums::ShaderType};
+
+/// Prefix of the names of the declarations that replace removed built-ins, and of renamed identifiers
+const PREFIX: &str = "oxidegl_";
+/// Number of texture coordinate sets of the compatibility profile, i.e. `gl_MaxTextureCoords`
+const TEXTURE_COORDS: u32 = 8;
+/// Supported versions of desktop GLSL
+const DESKTOP_VERSIONS: &[u32] = &[
+    110, 120, 130, 140, 150, 330, 400, 410, 420, 430, 440, 450, 460,
+];
+/// Supported versions of GLSL ES, along with the desktop version whose keywords they roughly have
+const ES_VERSIONS: &[(u32, u32)] = &[(100, 120), (300, 330), (310, 430), (320, 450)];
+/// Keywords of GLSL 4.50 that used to be valid identifiers, along with the desktop version that introduced them
+const KEYWORDS: &[(&str, u32)] = &[
+    ("uint", 130),
+    ("flat", 130),
+    ("smooth", 130),
+    ("noperspective", 130),
+    ("layout", 140),
+    ("sample", 400),
+    ("patch", 400),
+    ("subroutine", 400),
+    ("precise", 400),
+    ("coherent", 420),
+    ("volatile", 420),
+    ("restrict", 420),
+    ("readonly", 420),
+    ("writeonly", 420),
+    ("atomic_uint", 420),
+    ("buffer", 430),
+    ("shared", 430),
+];
+/// Built-in vertex attributes of the compatibility profile, along with their types and the generic attributes they
+/// alias. `gl_MultiTexCoord0` to `gl_MultiTexCoord7` alias attributes 8 to 15
+const VERTEX_ATTRIBUTES: &[(&str, &str, u32)] = &[
+    ("gl_Vertex", "vec4", 0),
+    ("gl_Normal", "vec3", 2),
+    ("gl_Color", "vec4", 3),
+    ("gl_SecondaryColor", "vec4", 4),
+    ("gl_FogCoord", "float", 5),
+];
+/// Built-in varyings of the compatibility profile: name in the vertex stage, name in the fragment stage, type and
+/// array length (0 if they are not arrays)
+const VARYINGS: &[(&str, &str, &str, u32)] = &[
+    ("gl_FrontColor", "gl_Color", "vec4", 0),
+    ("gl_FrontSecondaryColor", "gl_SecondaryColor", "vec4", 0),
+    ("gl_TexCoord", "gl_TexCoord", "vec4", TEXTURE_COORDS),
+    ("gl_FogFragCoord", "gl_FogFragCoord", "float", 0),
+];
+/// Texture lookup functions removed from the core profile, along with their coordinate types (separated by spaces)
+const TEXTURE_FUNCTIONS: &[(&str, &str)] = &[
+    ("texture1D", "float"),
+    ("texture1DProj", "vec2 vec4"),
+    ("texture1DLod", "float"),
+    ("texture1DProjLod", "vec2 vec4"),
+    ("texture2D", "vec2"),
+    ("texture2DProj", "vec3 vec4"),
+    ("texture2DLod", "vec2"),
+    ("texture2DProjLod", "vec3 vec4"),
+    ("texture3D", "vec3"),
+    ("texture3DProj", "vec4"),
+    ("texture3DLod", "vec3"),
+    ("texture3DProjLod", "vec4"),
+    ("textureCube", "vec3"),
+    ("textureCubeLod", "vec3"),
+    ("shadow1D", "vec3"),
+    ("shadow1DProj", "vec4"),
+    ("shadow1DLod", "vec3"),
+    ("shadow1DProjLod", "vec4"),
+    ("shadow2D", "vec3"),
+    ("shadow2DProj", "vec4"),
+    ("shadow2DLod", "vec3"),
+    ("shadow2DProjLod", "vec4"),
+];
+
+/// Profile of a GLSL version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Profile {
+    Core,
+    Compatibility,
+    Es,
+}
+/// Version and profile of a GLSL shader, as declared by its `#version` directive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct GlslVersion {
+    pub(crate) number: u32,
+    pub(crate) profile: Profile,
+}
+impl GlslVersion {
+    /// Version of shaders without a `#version` directive
+    const DEFAULT: Self = Self {
+        number: 110,
+        profile: Profile::Compatibility,
+    };
+    /// Parse the arguments of a `#version` directive
+    fn parse(arguments: &str) -> Result<Self, Box<str>> {
+        let mut words = arguments.split_whitespace();
+        let number = words
+            .next()
+            .and_then(|n| n.parse().ok())
+            .ok_or_else(|| format!("malformed #version directive: {arguments}"))?;
+        let profile = match (words.next(), words.next()) {
+            (None, _) => None,
+            (Some("core"), None) => Some(Profile::Core),
+            (Some("compatibility"), None) => Some(Profile::Compatibility),
+            (Some("es"), None) => Some(Profile::Es),
+            _ => return Err(format!("malformed #version directive: {arguments}").into()),
+        };
+        let es = ES_VERSIONS.iter().any(|&(v, _)| v == number);
+        let profile = match profile {
+            // the profile of GLSL ES 1.00 is implied
+            None if number == 100 => Profile::Es,
+            // desktop GLSL before 1.40 has no profiles, and has everything the compatibility profile keeps
+            None if number < 140 => Profile::Compatibility,
+            None => Profile::Core,
+            Some(Profile::Es) if es => Profile::Es,
+            Some(profile @ (Profile::Core | Profile::Compatibility)) if number >= 150 => profile,
+            Some(profile) => {
+                return Err(
+                    format!("GLSL version {number} doesn't have a {profile:?} profile").into(),
+                );
+            }
+        };
+        if profile != Profile::Es && !DESKTOP_VERSIONS.contains(&number) {
+            return Err(format!("unsupported GLSL version {number}").into());
+        }
+        Ok(Self { number, profile })
+    }
+    /// Whether shaders of this version can be compiled without being translated
+    fn is_native(self) -> bool {
+        self.profile == Profile::Core && self.number >= 330
+    }
+    /// Whether this version has the built-ins and storage qualifiers that were removed from the core profile
+    fn is_legacy(self) -> bool {
+        match self.profile {
+            Profile::Compatibility => true,
+            Profile::Es => self.number == 100,
+            Profile::Core => false,
+        }
+    }
+    /// The desktop version whose keywords this version has
+    fn keyword_version(self) -> u32 {
+        ES_VERSIONS
+            .iter()
+            .find(|&&(v, _)| v == self.number && self.profile == Profile::Es)
+            .map_or(self.number, |&(_, desktop)| desktop)
+    }
+}
+impl core::fmt::Display for GlslVersion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.profile {
+            Profile::Core => write!(f, "{} core", self.number),
+            Profile::Compatibility if self.number < 140 => write!(f, "{}", self.number),
+            Profile::Compatibility => write!(f, "{} compatibility", self.number),
+            Profile::Es => write!(f, "{} es", self.number),
+        }
+    }
+}
+
+/// Byte range of the `#version` directive of a source (including the newline that ends it), and its arguments.
+/// The directive may only be preceded by comments and whitespace
+fn version_directive(source: &str) -> Option<(core::ops::Range<usize>, &str)> {
+    let mut i = 0;
+    loop {
+        let rest = &source[i..];
+        let trimmed = rest.trim_start();
+        i += rest.len() - trimmed.len();
+        if let Some(comment) = trimmed.strip_prefix("//") {
+            i += 2 + comment.find('\n')?;
+        } else if let Some(comment) = trimmed.strip_prefix("/*") {
+            i += 4 + comment.find("*/")?;
+        } else {
+            let directive = trimmed.strip_prefix('#')?.trim_start_matches([' ', '\t']);
+            let arguments = directive.strip_prefix("version")?;
+            let line_len = trimmed.find('\n').map_or(trimmed.len(), |end| end + 1);
+            let arguments = &arguments[..arguments.len() - (trimmed.len() - line_len)];
+            return Some((i..i + line_len, arguments.trim()));
+        }
+    }
+}
+
+/// Byte offsets and text of the identifiers of a source, outside of comments
+fn identifiers(source: &str) -> Vec<(usize, &str)> {
+    let bytes = source.as_bytes();
+    let mut identifiers = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &source[i..];
+        let c = bytes[i];
+        if rest.starts_with("//") {
+            i += rest.find('\n').unwrap_or(rest.len());
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            i += comment.find("*/").map_or(rest.len(), |end| end + 4);
+        } else if c.is_ascii_alphanumeric() || c == b'_' {
+            let len = rest
+                .bytes()
+                .position(|b| !(b.is_ascii_alphanumeric() || b == b'_'))
+                .unwrap_or(rest.len());
+            // numbers (and their suffixes) aren't identifiers
+            if !c.is_ascii_digit() {
+                identifiers.push((i, &rest[..len]));
+            }
+            i += len;
+        } else {
+            i += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    identifiers
+}
+
+/// How an identifier of a source is translated
+#[derive(Default)]
+struct Translation {
+    replacement: String,
+    /// Declarations the replacement refers to
+    declarations: Vec<String>,
+    warning: Option<String>,
+}
+impl Translation {
+    fn new(replacement: impl Into<String>) -> Self {
+        Self {
+            replacement: replacement.into(),
+            ..Self::default()
+        }
+    }
+    fn declare(mut self, declaration: String) -> Self {
+        self.declarations.push(declaration);
+        self
+    }
+    fn warn(mut self, warning: String) -> Self {
+        self.warning = Some(warning);
+        self
+    }
+    /// Replace a built-in by a declaration of the same name with the prefix
+    fn builtin(name: &str, declaration: impl FnOnce(&str) -> String) -> Self {
+        let replacement = format!("{PREFIX}{}", name.trim_start_matches("gl_"));
+        let declaration = declaration(&replacement);
+        Self::new(replacement).declare(declaration)
+    }
+}
+
+/// Translation of a built-in vertex attribute of the compatibility profile
+fn vertex_attribute(name: &str) -> Option<Translation> {
+    let (ty, location) = VERTEX_ATTRIBUTES
+        .iter()
+        .find(|&&(n, ..)| n == name)
+        .map(|&(_, ty, location)| (ty, location))
+        .or_else(|| {
+            let set = name.strip_prefix("gl_MultiTexCoord")?.parse::<u32>().ok()?;
+            (set < TEXTURE_COORDS).then_some(("vec4", 8 + set))
+        })?;
+    Some(
+        Translation::builtin(name, |n| {
+            format!("layout(location = {location}) in {ty} {n};")
+        })
+        .warn(format!(
+            "{name} is read from generic vertex attribute {location}"
+        )),
+    )
+}
+
+/// Translation of a built-in uniform of the compatibility profile
+fn uniform(name: &str) -> Option<Translation> {
+    let matrix = name.strip_prefix("gl_")?;
+    let (ty, len) = if matrix == "NormalMatrix" {
+        ("mat3", 0)
+    } else {
+        let base = ["", "Inverse", "Transpose", "InverseTranspose"]
+            .iter()
+            .find_map(|suffix| matrix.strip_suffix(suffix)?.strip_suffix("Matrix"))?;
+        match base {
+            "ModelView" | "Projection" | "ModelViewProjection" => ("mat4", 0),
+            "Texture" => ("mat4", TEXTURE_COORDS),
+            _ => return None,
+        }
+    };
+    let translation = Translation::builtin(name, |n| {
+        if len == 0 {
+            format!("uniform {ty} {n};")
+        } else {
+            format!("uniform {ty} {n}[{len}];")
+        }
+    });
+    let warning = format!(
+        "there is no fixed function state to source {name} from, it is the uniform {} instead",
+        translation.replacement
+    );
+    Some(translation.warn(warning))
+}
+
+/// Translation of a texture lookup function removed from the core profile
+fn texture_function(name: &str, stage: ShaderType) -> Option<Translation> {
+    let &(_, coordinates) = TEXTURE_FUNCTIONS.iter().find(|&&(n, _)| n == name)?;
+    // the name is made of the kind of sampler, the dimensionality and suffixes for the variants
+    let (shadow, variant) = match name.strip_prefix("shadow") {
+        Some(variant) => (true, variant),
+        None => (false, name.strip_prefix("texture")?),
+    };
+    let lod = variant.ends_with("Lod");
+    let projective = variant.contains("Proj");
+    let dimensionality = variant.trim_end_matches("Lod").trim_end_matches("Proj");
+    let sampler = format!(
+        "sampler{dimensionality}{}",
+        if shadow { "Shadow" } else { "" }
+    );
+    let function = format!(
+        "texture{}{}",
+        if projective { "Proj" } else { "" },
+        if lod { "Lod" } else { "" }
+    );
+    // lookups with a shadow sampler returned the comparison result in every component
+    let (open, close) = if shadow { ("vec4(", ")") } else { ("", "") };
+    let replacement = format!("{PREFIX}{name}");
+    let mut declaration = String::new();
+    for coordinate in coordinates.split(' ') {
+        let mut overload = |parameter: &str, argument: &str| {
+            writeln!(
+                declaration,
+                "vec4 {replacement}({sampler} s, {coordinate} p{parameter}) {{ return {open}{function}(s, p{argument}){close}; }}"
+            )
+            .unwrap();
+        };
+        if lod {
+            overload(", float lod", ", lod");
+        } else {
+            overload("", "");
+            // only fragment shaders have implicit derivatives to bias
+            if stage == ShaderType::FragmentShader {
+                overload(", float bias", ", bias");
+            }
+        }
+    }
+    declaration.pop();
+    Some(Translation::new(replacement).declare(declaration))
+}
+
+/// How an identifier of a shader of a stage and version is translated, if it is
+fn translate_identifier(
+    name: &str,
+    stage: ShaderType,
+    version: GlslVersion,
+) -> Option<Translation> {
+    if KEYWORDS
+        .iter()
+        .any(|&(k, since)| k == name && version.keyword_version() < since)
+    {
+        let replacement = format!("{PREFIX}{name}");
+        let warning = format!("{name} is a keyword in GLSL 4.50, it was renamed to {replacement}");
+        return Some(Translation::new(replacement).warn(warning));
+    }
+    if !version.is_legacy() {
+        return None;
+    }
+    let varying = |vertex: bool| {
+        VARYINGS
+            .iter()
+            .find(|v| (if vertex { v.0 } else { v.1 }) == name)
+            .map(|&(vertex_name, _, ty, len)| {
+                let storage = if vertex { "out" } else { "in" };
+                // both stages use the name of the vertex stage, so the varyings match
+                Translation::builtin(vertex_name, |n| {
+                    if len == 0 {
+                        format!("{storage} {ty} {n};")
+                    } else {
+                        format!("{storage} {ty} {n}[{len}];")
+                    }
+                })
+            })
+    };
+    match (stage, name) {
+        (ShaderType::VertexShader, "attribute") | (ShaderType::FragmentShader, "varying") => {
+            Some(Translation::new("in"))
+        }
+        (ShaderType::VertexShader, "varying") => Some(Translation::new("out")),
+        (ShaderType::FragmentShader, "gl_FragColor") => Some(Translation::builtin(name, |n| {
+            format!("layout(location = 0) out vec4 {n};")
+        })),
+        (ShaderType::FragmentShader, "gl_FragData") => Some(Translation::builtin(name, |n| {
+            format!("layout(location = 0) out vec4 {n}[{MAX_COLOR_ATTACHMENTS}];")
+        })),
+        (ShaderType::VertexShader, "ftransform") => {
+            let [matrix, vertex] = [
+                uniform("gl_ModelViewProjectionMatrix"),
+                vertex_attribute("gl_Vertex"),
+            ]
+            .map(|t| t.expect("ftransform's inputs are built-ins"));
+            let mut translation = Translation::new(format!("{PREFIX}ftransform"));
+            translation.declarations = [matrix.declarations, vertex.declarations].concat();
+            let declaration = format!(
+                "vec4 {}() {{ return {} * {}; }}",
+                translation.replacement, matrix.replacement, vertex.replacement
+            );
+            let warning = format!(
+                "ftransform() is computed from the uniform {} and generic vertex attribute 0",
+                matrix.replacement
+            );
+            Some(translation.declare(declaration).warn(warning))
+        }
+        (ShaderType::VertexShader, _) => vertex_attribute(name)
+            .or_else(|| varying(true))
+            .or_else(|| uniform(name))
+            .or_else(|| texture_function(name, stage)),
+        (ShaderType::FragmentShader, _) => varying(false)
+            .or_else(|| uniform(name))
+            .or_else(|| texture_function(name, stage)),
+        _ => uniform(name).or_else(|| texture_function(name, stage)),
+    }
+}
+
+/// Translate the source of a shader of a stage to GLSL 4.50 core if it was written for another version or profile,
+/// returning the translated source and the warnings to add to the info log of the shader
+pub(crate) fn translate_version(
+    source: &str,
+    stage: ShaderType,
+) -> Result<(String, Vec<String>), Box<str>> {
+    let directive = version_directive(source);
+    let version = match &directive {
+        Some((_, arguments)) => GlslVersion::parse(arguments)?,
+        None => GlslVersion::DEFAULT,
+    };
+    if version.is_native() {
+        return Ok((source.to_owned(), Vec::new()));
+    }
+    let mut warnings = vec![match directive {
+        Some(_) => format!("GLSL {version} is compiled as GLSL 450 core"),
+        None => format!("no #version directive, GLSL {version} is compiled as GLSL 450 core"),
+    }];
+    let (prefix, body) = directive
+        .as_ref()
+        .map_or((0, 0), |(range, _)| (range.start, range.end));
+
+    let mut declarations = Vec::new();
+    let mut replacements = Vec::new();
+    // replacement of each identifier, by name
+    let mut translated = HashMap::<&str, Option<String>>::new();
+    let mut reduced_precision = false;
+    for (start, name) in identifiers(&source[body..]) {
+        reduced_precision |= matches!(name, "lowp" | "mediump");
+        // declarations and warnings are added in the order the identifiers first appear in
+        let replacement = translated.entry(name).or_insert_with(|| {
+            translate_identifier(name, stage, version).map(|t| {
+                for declaration in t.declarations {
+                    if !declarations.contains(&declaration) {
+                        declarations.push(declaration);
+                    }
+                }
+                warnings.extend(t.warning);
+                t.replacement
+            })
+        });
+        if let Some(replacement) = replacement {
+            replacements.push((body + start, name.len(), replacement.clone()));
+        }
+    }
+    if reduced_precision && version.profile == Profile::Es {
+        warnings.push(
+            "precision qualifiers have no effect, every computation is done at full precision"
+                .to_owned(),
+        );
+    }
+
+    let mut out = String::with_capacity(source.len() + 64 * declarations.len());
+    out.push_str(&source[..prefix]);
+    out.push_str("#version 450 core\n");
+    for declaration in &declarations {
+        out.push_str(declaration);
+        out.push('\n');
+    }
+    // the line after the directive keeps its number
+    let line = source[..body].matches('\n').count() + 1;
+    writeln!(out, "#line {line}").unwrap();
+    let mut copied = body;
+    for (start, len, replacement) in replacements {
+        out.push_str(&source[copied..start]);
+        out.push_str(&replacement);
+        copied = start + len;
+    }
+    out.push_str(&source[copied..]);
+    Ok((out, warnings))
+}
+
+#[test]
+fn version_translation() {
+    let (source, warnings) =
+        translate_version("#version 450\nvoid main() {}", ShaderType::VertexShader).unwrap();
+    assert_eq!(source, "#version 450\nvoid main() {}");
+    assert!(warnings.is_empty());
+
+    let vertex = "// legacy\n#version 120\nattribute vec2 uv;\nvarying vec2 v_uv;\nvoid main() {\n    v_uv = uv;\n    \
+                  gl_TexCoord[0] = gl_MultiTexCoord0;\n    gl_Position = ftransform();\n}\n";
+    let (source, warnings) = translate_version(vertex, ShaderType::VertexShader).unwrap();
+    assert!(source.starts_with("// legacy\n#version 450 core\n"));
+    assert!(source.contains("#line 3\nin vec2 uv;\nout vec2 v_uv;\n"));
+    assert!(source.contains("out vec4 oxidegl_TexCoord[8];"));
+    assert!(source.contains("layout(location = 8) in vec4 oxidegl_MultiTexCoord0;"));
+    assert!(source.contains("oxidegl_TexCoord[0] = oxidegl_MultiTexCoord0;"));
+    assert!(source.contains("uniform mat4 oxidegl_ModelViewProjectionMatrix;"));
+    assert!(source.contains("layout(location = 0) in vec4 oxidegl_Vertex;"));
+    assert!(source.contains("gl_Position = oxidegl_ftransform();"));
+    // the helper is declared after the declarations it uses
+    assert!(
+        source.find("oxidegl_Vertex;").unwrap() < source.find("vec4 oxidegl_ftransform()").unwrap()
+    );
+    assert!(warnings.iter().any(|w| w.contains("gl_MultiTexCoord0")));
+
+    let fragment = "uniform sampler2D texture;\nuniform sampler2DShadow shadow;\nvarying vec2 sample;\n\
+                    void main() {\n    gl_FragColor = texture2D(texture, sample) * shadow2D(shadow, vec3(0.5)).r;\n}";
+    let (source, warnings) = translate_version(fragment, ShaderType::FragmentShader).unwrap();
+    assert!(source.starts_with("#version 450 core\n"));
+    assert!(source.contains("#line 1\nuniform sampler2D texture;"));
+    assert!(source.contains("in vec2 oxidegl_sample;"));
+    assert!(source.contains(
+        "oxidegl_FragColor = oxidegl_texture2D(texture, oxidegl_sample) * oxidegl_shadow2D(shadow, vec3(0.5)).r;"
+    ));
+    assert!(source.contains("layout(location = 0) out vec4 oxidegl_FragColor;"));
+    assert!(source.contains(
+        "vec4 oxidegl_texture2D(sampler2D s, vec2 p, float bias) { return texture(s, p, bias); }"
+    ));
+    assert!(source.contains(
+        "vec4 oxidegl_shadow2D(sampler2DShadow s, vec3 p) { return vec4(texture(s, p)); }"
+    ));
+    assert!(warnings[0].starts_with("no #version directive"));
+    assert!(warnings.iter().any(|w| w.contains("oxidegl_sample")));
+
+    let es = "#version 300 es\nprecision mediump float;\nout vec4 color;\nvoid main() { color = vec4(1.0); }";
+    let (source, warnings) = translate_version(es, ShaderType::FragmentShader).unwrap();
+    assert_eq!(
+        source,
+        "#version 450 core\n#line 2\nprecision mediump float;\nout vec4 color;\nvoid main() { color = vec4(1.0); }"
+    );
+    assert_eq!(warnings.len(), 2);
+
+    for bad in [
+        "#version 300\n",
+        "#version 120 core\n",
+        "#version 450 es\n",
+        "#version 200\n",
+        "#version\n",
+    ] {
+        assert!(
+            translate_version(bad, ShaderType::VertexShader).is_err(),
+            "{bad}"
+        );
+    }
+}
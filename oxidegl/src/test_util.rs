@@ -0,0 +1,119 @@
+//! Helpers shared by the tests that drive a [`Context`] through GL commands
+
+use crate::{
+    context::Context,
+    gl_enums::{
+        BufferStorageMask, ClearBufferMask, PrimitiveType, ProgramProperty, ShaderType,
+        VertexAttribType,
+    },
+    gl_types::{GLint, GLsizei, GLuint},
+};
+
+/// Create a shader of type `ty` and compile `src` into it. Compile errors are left in the info log of the shader
+pub(crate) fn compile(ctx: &mut Context, ty: ShaderType, src: &str) -> GLuint {
+    let shader = ctx.oxidegl_create_shader(ty);
+    let ptr = src.as_ptr().cast();
+    let len = GLint::try_from(src.len()).unwrap();
+    // Safety: pointers are valid for the duration of the call
+    unsafe { ctx.oxidegl_shader_source(shader, 1, &raw const ptr, &raw const len) }.unwrap();
+    ctx.oxidegl_compile_shader(shader).unwrap();
+    shader
+}
+
+/// Create a program and link `shaders` into it. Link errors are left in the info log of the program
+pub(crate) fn link(ctx: &mut Context, shaders: &[GLuint]) -> GLuint {
+    let program = ctx.oxidegl_create_program();
+    for &shader in shaders {
+        ctx.oxidegl_attach_shader(program, shader).unwrap();
+    }
+    ctx.oxidegl_link_program(program).unwrap();
+    program
+}
+
+/// Value of the single integer parameter `pname` of `program`
+pub(crate) fn get_programiv(ctx: &mut Context, program: GLuint, pname: ProgramProperty) -> GLint {
+    let mut value = 0;
+    // Safety: params points to a single GLint
+    unsafe { ctx.oxidegl_get_programiv(program, pname, &raw mut value) }.unwrap();
+    value
+}
+
+/// Width and height of the default framebuffer of a [`Scene`]
+pub(crate) const SCENE_SIZE: u32 = 32;
+
+/// A software rendered context with a vertex array bound, for tests that draw something and check the resulting
+/// pixels
+pub(crate) struct Scene {
+    pub(crate) ctx: Context,
+    vao: GLuint,
+}
+
+impl Scene {
+    /// Create a scene without a program, that is cleared to opaque black
+    pub(crate) fn new() -> Self {
+        let mut ctx = Context::new_software(SCENE_SIZE, SCENE_SIZE);
+        let mut vao = 0;
+        // Safety: pointer is valid for the duration of the call
+        unsafe { ctx.oxidegl_create_vertex_arrays(1, &raw mut vao) };
+        ctx.oxidegl_bind_vertex_array(vao).unwrap();
+        ctx.oxidegl_clear_color(0.0, 0.0, 0.0, 1.0);
+        Self { ctx, vao }
+    }
+    /// Create a scene that draws with a program linked from `shaders`
+    pub(crate) fn with_program(shaders: &[(ShaderType, &str)]) -> Self {
+        let mut scene = Self::new();
+        let shaders = shaders
+            .iter()
+            .map(|&(ty, src)| compile(&mut scene.ctx, ty, src))
+            .collect::<Vec<_>>();
+        let program = link(&mut scene.ctx, &shaders);
+        scene.ctx.oxidegl_use_program(program).unwrap();
+        scene
+    }
+    /// Source the vertex attributes from `vertices`, which interleaves one attribute per entry of `attribs` given as
+    /// its component count, type and whether it's normalized. All components are the size of `T`
+    pub(crate) fn vertices<T>(
+        &mut self,
+        vertices: &[T],
+        attribs: &[(GLint, VertexAttribType, bool)],
+    ) {
+        let ctx = &mut self.ctx;
+        let mut vbo = 0;
+        // Safety: pointers are valid for the duration of the calls
+        unsafe {
+            ctx.oxidegl_create_buffers(1, &raw mut vbo);
+            ctx.oxidegl_named_buffer_storage(
+                vbo,
+                isize::try_from(size_of_val(vertices)).unwrap(),
+                vertices.as_ptr().cast(),
+                BufferStorageMask::empty(),
+            )
+            .unwrap();
+        }
+        let component = u32::try_from(size_of::<T>()).unwrap();
+        let mut offset = 0;
+        for (attrib, &(size, ty, normalized)) in (0..).zip(attribs) {
+            ctx.oxidegl_enable_vertex_array_attrib(self.vao, attrib)
+                .unwrap();
+            ctx.oxidegl_vertex_array_attrib_format(self.vao, attrib, size, ty, normalized, offset)
+                .unwrap();
+            ctx.oxidegl_vertex_array_attrib_binding(self.vao, attrib, 0)
+                .unwrap();
+            offset += size.unsigned_abs() * component;
+        }
+        let stride = GLsizei::try_from(offset).unwrap();
+        ctx.oxidegl_vertex_array_vertex_buffer(self.vao, 0, vbo, 0, stride)
+            .unwrap();
+    }
+    /// Clear the color and depth buffers, then draw `count` vertices as `mode`
+    pub(crate) fn draw(&mut self, mode: PrimitiveType, count: GLsizei) {
+        self.ctx
+            .oxidegl_clear(ClearBufferMask::COLOR_BUFFER_BIT | ClearBufferMask::DEPTH_BUFFER_BIT);
+        self.ctx.oxidegl_draw_arrays(mode, 0, count).unwrap();
+    }
+    /// Color of the pixel at `x`, `y` of the default framebuffer, counting rows from the bottom
+    pub(crate) fn pixel(&self, x: u32, y: u32) -> [f32; 4] {
+        let ((width, _), color) = self.ctx.color_buffer().unwrap();
+        color[(y * width + x) as usize]
+    }
+}
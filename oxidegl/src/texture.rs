@@ -1,27 +1,31 @@
-use std::{cell::Cell, fmt::Debug, num::NonZeroU32, ptr, slice};
+#[cfg(target_vendor = "apple")]
+use std::{cell::Cell, fmt::Debug};
+use std::{num::NonZeroU32, ptr, slice};
 
+#[cfg(target_vendor = "apple")]
 use objc2::rc::Retained;
-use objc2_metal::{
-    MTLSamplerAddressMode, MTLSamplerBorderColor, MTLSamplerDescriptor, MTLSamplerMinMagFilter,
-    MTLSamplerMipFilter, MTLTexture, MTLTextureSwizzle, MTLTextureType,
-};
+#[cfg(target_vendor = "apple")]
+use objc2_metal::{MTLSamplerDescriptor, MTLTexture};
 
+#[cfg(target_vendor = "apple")]
+use crate::util::ProtoObjRef;
 use crate::{
     conversions::{GLenumExt, SrcType},
     error::GlError,
     gl_enums::{
         DepthFunction, GL_COMPARE_REF_TO_TEXTURE, GL_NONE, InternalFormat, SamplerParameter,
-        TextureMagFilter, TextureMinFilter, TextureSwizzle, TextureTarget, TextureWrapMode,
+        TextureMagFilter, TextureMinFilter, TextureTarget, TextureWrapMode,
     },
-    util::ProtoObjRef,
 };
 
 use super::{
     debug::gl_err,
     error::{GlFallible, gl_assert},
-    gl_object::ObjectName,
+    gl_object::{NamedObject, NoLateInit, ObjectName},
 };
 
+pub(crate) mod image;
+
 /// * named: name is reserved, object is considered uninitialized
 /// * bound: object is initialized to default state, has no storage
 /// * complete: TODO
@@ -35,6 +39,7 @@ pub struct Texture {
 /// Represents a realized texture's storage
 #[derive(Debug)]
 pub struct RealizedTexture {
+    #[cfg(target_vendor = "apple")]
     mtl_tex: Option<ProtoObjRef<dyn MTLTexture>>,
     format: InternalFormat,
     width: u32,
@@ -42,8 +47,11 @@ pub struct RealizedTexture {
     depth: Option<NonZeroU32>,
     array_length: Option<NonZeroU32>,
 }
+impl NamedObject for Texture {
+    type LateInitType = NoLateInit<Self>;
+}
 impl Texture {
-    fn new_named(name: ObjectName<Self>, target: TextureTarget) -> Self {
+    pub(crate) fn new_named(name: ObjectName<Self>, target: TextureTarget) -> Self {
         Self {
             name,
             target,
@@ -52,35 +60,14 @@ impl Texture {
         }
     }
     fn make_immutable_storage(&mut self, levels: u32) {}
-}
-impl From<TextureTarget> for MTLTextureType {
-    fn from(value: TextureTarget) -> Self {
-        match value {
-            TextureTarget::Texture1D | TextureTarget::ProxyTexture1D => Self::Type1D,
-            TextureTarget::Texture1DArray | TextureTarget::ProxyTexture1DArray => Self::Type1DArray,
-            TextureTarget::Renderbuffer
-            | TextureTarget::TextureRectangle
-            | TextureTarget::ProxyTextureRectangle
-            | TextureTarget::Texture2D
-            | TextureTarget::ProxyTexture2D => Self::Type2D,
-
-            TextureTarget::Texture2DArray | TextureTarget::ProxyTexture2DArray => Self::Type2DArray,
-            TextureTarget::Texture2DMultisample | TextureTarget::ProxyTexture2DMultisample => {
-                Self::Type2DMultisample
-            }
-            TextureTarget::Texture2DMultisampleArray
-            | TextureTarget::ProxyTexture2DMultisampleArray => Self::Type2DMultisampleArray,
-            TextureTarget::TextureCubeMap | TextureTarget::ProxyTextureCubeMap => Self::TypeCube,
-            TextureTarget::TextureCubeMapArray | TextureTarget::ProxyTextureCubeMapArray => {
-                Self::TypeCubeArray
-            }
-
-            TextureTarget::TextureBuffer => Self::TypeTextureBuffer,
-            TextureTarget::Texture3D | TextureTarget::ProxyTexture3D => Self::Type3D,
-            _ => {
-                panic!("invalid texture target")
-            }
-        }
+    /// Internal format of the storage of this texture, if it has any
+    pub(crate) fn format(&self) -> Option<InternalFormat> {
+        self.realized.as_ref().map(|r| r.format)
+    }
+    /// Metal texture backing the storage of this texture, if it has been created
+    #[cfg(target_vendor = "apple")]
+    pub(crate) fn mtl_texture(&self) -> Option<&ProtoObjRef<dyn MTLTexture>> {
+        self.realized.as_ref()?.mtl_tex.as_ref()
     }
 }
 
@@ -121,7 +108,7 @@ impl Anisotropy {
 /// Note: must call [`SamplerParams::mark_dirty`] after modifying values in this struct
 pub struct SamplerParams {
     /// Border color for border wrap mode
-    pub(crate) border_color: MTLSamplerBorderColor,
+    pub(crate) border_color: BorderColor,
     /// Depth comparison mode if depth comparison is enabled
     pub(crate) compare_ref_to_texture: bool,
     pub(crate) compare_func: DepthFunction,
@@ -137,8 +124,16 @@ pub struct SamplerParams {
     pub(crate) wrap_mode_s: TextureWrapMode,
     pub(crate) wrap_mode_t: TextureWrapMode,
     pub(crate) wrap_mode_r: TextureWrapMode,
+    #[cfg(target_vendor = "apple")]
     descriptor_cache: CloneOptionCell<Retained<MTLSamplerDescriptor>>,
 }
+/// Border colors that can be sampled from without a custom border color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BorderColor {
+    TransparentBlack,
+    OpaqueBlack,
+    OpaqueWhite,
+}
 
 trait ToBorderColor {
     fn to_float(self) -> f64;
@@ -223,16 +218,17 @@ impl SamplerParams {
     fn set_border_color(&mut self, vals: [f64; 4]) {
         self.border_color = match vals {
             [0.0, 0.0, 0.0, v] => match v {
-                1.0 => MTLSamplerBorderColor::OpaqueBlack,
-                _ => MTLSamplerBorderColor::TransparentBlack,
+                1.0 => BorderColor::OpaqueBlack,
+                _ => BorderColor::TransparentBlack,
             },
-            [1.0, 1.0, 1.0, 1.0] => MTLSamplerBorderColor::OpaqueWhite,
+            [1.0, 1.0, 1.0, 1.0] => BorderColor::OpaqueWhite,
             _ => {
                 gl_err!(ty: Error, "unsupported texture border color used, defaulting to opaque white");
-                MTLSamplerBorderColor::OpaqueWhite
+                BorderColor::OpaqueWhite
             }
         };
     }
+    #[cfg(target_vendor = "apple")]
     fn sampler_desc(&self) -> Retained<MTLSamplerDescriptor> {
         if let Some(d) = self.descriptor_cache.clone_out() {
             return d;
@@ -241,7 +237,7 @@ impl SamplerParams {
         if [self.wrap_mode_r, self.wrap_mode_s, self.wrap_mode_t]
             .contains(&TextureWrapMode::ClampToBorder)
         {
-            desc.setBorderColor(self.border_color);
+            desc.setBorderColor(self.border_color.into());
         }
         if self.compare_ref_to_texture {
             desc.setCompareFunction(self.compare_func.into());
@@ -260,13 +256,14 @@ impl SamplerParams {
         desc
     }
     fn mark_dirty(&self) {
+        #[cfg(target_vendor = "apple")]
         self.descriptor_cache.set(None);
     }
 }
 impl Default for SamplerParams {
     fn default() -> Self {
         Self {
-            border_color: MTLSamplerBorderColor::TransparentBlack,
+            border_color: BorderColor::TransparentBlack,
             mag_filter: TextureMagFilter::Linear,
             min_filter: TextureMinFilter::NearestMipmapLinear,
             lod_bias: 0.0,
@@ -276,59 +273,19 @@ impl Default for SamplerParams {
             wrap_mode_s: TextureWrapMode::Repeat,
             wrap_mode_t: TextureWrapMode::Repeat,
             wrap_mode_r: TextureWrapMode::Repeat,
+            #[cfg(target_vendor = "apple")]
             descriptor_cache: CloneOptionCell::new(None),
             compare_ref_to_texture: false,
             compare_func: DepthFunction::Lequal,
         }
     }
 }
-impl From<TextureSwizzle> for MTLTextureSwizzle {
-    fn from(value: TextureSwizzle) -> Self {
-        match value {
-            TextureSwizzle::Zero => MTLTextureSwizzle::Zero,
-            TextureSwizzle::One => MTLTextureSwizzle::One,
-            TextureSwizzle::Red => MTLTextureSwizzle::Red,
-            TextureSwizzle::Green => MTLTextureSwizzle::Green,
-            TextureSwizzle::Blue => MTLTextureSwizzle::Blue,
-            TextureSwizzle::Alpha => MTLTextureSwizzle::Alpha,
-        }
-    }
-}
-impl From<TextureWrapMode> for MTLSamplerAddressMode {
-    fn from(value: TextureWrapMode) -> Self {
-        match value {
-            TextureWrapMode::Repeat => MTLSamplerAddressMode::Repeat,
-            TextureWrapMode::MirroredRepeat => MTLSamplerAddressMode::MirrorRepeat,
-            TextureWrapMode::ClampToEdge => MTLSamplerAddressMode::ClampToEdge,
-            TextureWrapMode::ClampToBorder => MTLSamplerAddressMode::ClampToBorderColor,
-        }
-    }
-}
-impl From<TextureMagFilter> for MTLSamplerMinMagFilter {
-    fn from(value: TextureMagFilter) -> Self {
-        match value {
-            TextureMagFilter::Nearest => Self::Nearest,
-            TextureMagFilter::Linear => Self::Linear,
-        }
-    }
-}
-impl From<TextureMinFilter> for (MTLSamplerMinMagFilter, MTLSamplerMipFilter) {
-    fn from(value: TextureMinFilter) -> Self {
-        use objc2_metal::{MTLSamplerMinMagFilter as MinMag, MTLSamplerMipFilter as Mip};
-        match value {
-            TextureMinFilter::Nearest => (MinMag::Nearest, Mip::NotMipmapped),
-            TextureMinFilter::Linear => (MinMag::Linear, Mip::NotMipmapped),
-            TextureMinFilter::NearestMipmapNearest => (MinMag::Nearest, Mip::Nearest),
-            TextureMinFilter::LinearMipmapNearest => (MinMag::Linear, Mip::Nearest),
-            TextureMinFilter::NearestMipmapLinear => (MinMag::Nearest, Mip::Linear),
-            TextureMinFilter::LinearMipmapLinear => (MinMag::Linear, Mip::Linear),
-        }
-    }
-}
 
+#[cfg(target_vendor = "apple")]
 struct CloneOptionCell<T> {
     inner: Cell<Option<T>>,
 }
+#[cfg(target_vendor = "apple")]
 impl<T: Clone> Clone for CloneOptionCell<T> {
     fn clone(&self) -> Self {
         let v = self.inner.take();
@@ -338,6 +295,7 @@ impl<T: Clone> Clone for CloneOptionCell<T> {
         }
     }
 }
+#[cfg(target_vendor = "apple")]
 impl<T: Clone + Debug> Debug for CloneOptionCell<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CloneOptionCell")
@@ -345,6 +303,7 @@ impl<T: Clone + Debug> Debug for CloneOptionCell<T> {
             .finish()
     }
 }
+#[cfg(target_vendor = "apple")]
 impl<T> CloneOptionCell<T> {
     fn clone_out(&self) -> Option<T>
     where
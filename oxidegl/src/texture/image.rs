@@ -0,0 +1,153 @@
+//! Image units, which bind single levels of textures for `imageLoad`, `imageStore` and `imageAtomic*` in shaders. Metal
+//! has no image units: the image uniforms of each stage are translated to read/write texture arguments, which are bound
+//! to the texture of the unit each uniform refers to before every draw or dispatch
+
+use super::Texture;
+use crate::{
+    gl_enums::{BufferAccess, InternalFormat},
+    gl_object::ObjectName,
+    render::PixelFormat,
+};
+
+/// Number of image units, which is also the maximum number of image uniforms of each stage
+pub(crate) const MAX_IMAGE_UNITS: u32 = 8;
+
+/// The binding of an image unit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ImageUnit {
+    pub(crate) texture: Option<ObjectName<Texture>>,
+    pub(crate) level: u32,
+    /// Whether every layer of the level is bound, rather than only `layer`
+    pub(crate) layered: bool,
+    pub(crate) layer: u32,
+    pub(crate) access: BufferAccess,
+    /// Format the texels of the image are interpreted as, which may differ from the internal format of the texture
+    pub(crate) format: InternalFormat,
+}
+impl Default for ImageUnit {
+    fn default() -> Self {
+        Self {
+            texture: None,
+            level: 0,
+            layered: false,
+            layer: 0,
+            access: BufferAccess::ReadOnly,
+            format: InternalFormat::R8,
+        }
+    }
+}
+
+/// Metal pixel format and texel size in bytes of each format an image unit can interpret a texture as
+#[rustfmt::skip]
+const IMAGE_FORMATS: &[(InternalFormat, PixelFormat, u32)] = {
+    use InternalFormat as F;
+    use PixelFormat as P;
+    &[
+        (F::Rgba32f, P::RGBA32Float, 16),
+        (F::Rgba16f, P::RGBA16Float, 8),
+        (F::Rg32f, P::RG32Float, 8),
+        (F::Rg16f, P::RG16Float, 4),
+        (F::R11fG11fB10f, P::RG11B10Float, 4),
+        (F::R32f, P::R32Float, 4),
+        (F::R16f, P::R16Float, 2),
+        (F::Rgba32ui, P::RGBA32Uint, 16),
+        (F::Rgba16ui, P::RGBA16Uint, 8),
+        (F::Rgb10A2ui, P::RGB10A2Uint, 4),
+        (F::Rgba8ui, P::RGBA8Uint, 4),
+        (F::Rg32ui, P::RG32Uint, 8),
+        (F::Rg16ui, P::RG16Uint, 4),
+        (F::Rg8ui, P::RG8Uint, 2),
+        (F::R32ui, P::R32Uint, 4),
+        (F::R16ui, P::R16Uint, 2),
+        (F::R8ui, P::R8Uint, 1),
+        (F::Rgba32i, P::RGBA32Sint, 16),
+        (F::Rgba16i, P::RGBA16Sint, 8),
+        (F::Rgba8i, P::RGBA8Sint, 4),
+        (F::Rg32i, P::RG32Sint, 8),
+        (F::Rg16i, P::RG16Sint, 4),
+        (F::Rg8i, P::RG8Sint, 2),
+        (F::R32i, P::R32Sint, 4),
+        (F::R16i, P::R16Sint, 2),
+        (F::R8i, P::R8Sint, 1),
+        (F::Rgba16, P::RGBA16Unorm, 8),
+        (F::Rgb10A2, P::RGB10A2Unorm, 4),
+        (F::Rgba8, P::RGBA8Unorm, 4),
+        (F::Rg16, P::RG16Unorm, 4),
+        (F::Rg8, P::RG8Unorm, 2),
+        (F::R16, P::R16Unorm, 2),
+        (F::R8, P::R8Unorm, 1),
+        (F::Rgba16Snorm, P::RGBA16Snorm, 8),
+        (F::Rgba8Snorm, P::RGBA8Snorm, 4),
+        (F::Rg16Snorm, P::RG16Snorm, 4),
+        (F::Rg8Snorm, P::RG8Snorm, 2),
+        (F::R16Snorm, P::R16Snorm, 2),
+        (F::R8Snorm, P::R8Snorm, 1),
+    ]
+};
+
+/// Metal pixel format and texel size of an image unit format, if `format` is one
+pub(crate) fn image_format(format: InternalFormat) -> Option<(PixelFormat, u32)> {
+    IMAGE_FORMATS
+        .iter()
+        .find(|f| f.0 == format)
+        .map(|&(_, pixel_format, size)| (pixel_format, size))
+}
+
+/// Whether a texture of internal format `texture` can be bound to an image unit of format `unit`. Every format an image
+/// unit can have matches by size, so a texture can be reinterpreted as any of these formats with the same texel size
+pub(crate) fn formats_compatible(texture: InternalFormat, unit: InternalFormat) -> bool {
+    match (image_format(texture), image_format(unit)) {
+        (Some((_, a)), Some((_, b))) => a == b,
+        _ => false,
+    }
+}
+
+/// Whether a shader that accesses an image with `shader` access can use an image unit bound with `unit` access
+pub(crate) fn access_allows(unit: BufferAccess, shader: BufferAccess) -> bool {
+    unit == BufferAccess::ReadWrite || unit == shader
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{access_allows, formats_compatible};
+    use crate::gl_enums::{BufferAccess, InternalFormat};
+
+    #[test]
+    fn formats_match_by_size() {
+        assert!(formats_compatible(
+            InternalFormat::Rgba8,
+            InternalFormat::R32ui
+        ));
+        assert!(formats_compatible(
+            InternalFormat::Rg16f,
+            InternalFormat::Rgba8Snorm
+        ));
+        assert!(!formats_compatible(
+            InternalFormat::Rgba8,
+            InternalFormat::Rg32f
+        ));
+        assert!(!formats_compatible(
+            InternalFormat::Rgb8,
+            InternalFormat::Rgb8
+        ));
+    }
+    #[test]
+    fn access_must_cover_shader() {
+        assert!(access_allows(
+            BufferAccess::ReadWrite,
+            BufferAccess::WriteOnly
+        ));
+        assert!(access_allows(
+            BufferAccess::ReadOnly,
+            BufferAccess::ReadOnly
+        ));
+        assert!(!access_allows(
+            BufferAccess::ReadOnly,
+            BufferAccess::ReadWrite
+        ));
+        assert!(!access_allows(
+            BufferAccess::WriteOnly,
+            BufferAccess::ReadOnly
+        ));
+    }
+}
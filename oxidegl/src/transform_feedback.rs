@@ -0,0 +1,84 @@
+use crate::{
+    commands::buffer::Buffer,
+    context::state::{GlState, MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS},
+    gl_enums::PrimitiveType,
+    gl_object::{LateInit, NamedObject, ObjectName},
+    program::Program,
+};
+
+#[derive(Debug)]
+pub struct TransformFeedback {
+    pub(crate) name: ObjectName<Self>,
+    pub(crate) state: FeedbackState,
+}
+impl TransformFeedback {
+    pub(crate) fn new_default(name: ObjectName<Self>) -> Self {
+        Self {
+            name,
+            state: FeedbackState::default(),
+        }
+    }
+}
+impl NamedObject for TransformFeedback {
+    type LateInitType = LateInit<Self>;
+    const LATE_INIT_FUNC: fn(ObjectName<Self>) -> Self = Self::new_default;
+}
+
+/// State vector of a transform feedback object, which is also kept for the default transform feedback object (name 0)
+#[derive(Debug, Default, Clone)]
+pub(crate) struct FeedbackState {
+    /// Buffers bound to the indexed `GL_TRANSFORM_FEEDBACK_BUFFER` bindings
+    pub(crate) buffers: [Option<ObjectName<Buffer>>; MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS],
+    /// Offset and size in bytes of the range of each binding, if it was bound with `glBindBufferRange`
+    pub(crate) ranges: [Option<(usize, usize)>; MAX_TRANSFORM_FEEDBACK_BUFFER_BINDINGS],
+    /// The capture started by `glBeginTransformFeedback`, if there is one
+    pub(crate) active: Option<ActiveFeedback>,
+    /// Number of vertices written by the last capture that was ended, which `glDrawTransformFeedback` draws
+    pub(crate) captured_vertices: Option<u32>,
+}
+impl FeedbackState {
+    /// Whether vertices are currently being captured into the buffers of this object
+    #[inline]
+    pub(crate) fn capturing(&self) -> bool {
+        self.active.as_ref().is_some_and(|a| !a.paused)
+    }
+    /// Offset and size in bytes of the region of buffer binding `index` that is written to
+    pub(crate) fn range(&self, state: &GlState, index: usize) -> Option<(usize, usize)> {
+        let buffer = state.buffer_list.get_opt(self.buffers[index]?)?;
+        Some(
+            self.ranges[index]
+                // the range may extend past the end of the buffer, which limits it instead
+                .map_or((0, buffer.size), |(offset, size)| {
+                    (offset, size.min(buffer.size.saturating_sub(offset)))
+                }),
+        )
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ActiveFeedback {
+    /// Type of the primitives that are captured
+    pub(crate) primitive_mode: PrimitiveType,
+    /// Program whose varyings are captured, which can't change until the capture ends
+    pub(crate) program: ObjectName<Program>,
+    pub(crate) paused: bool,
+    /// Number of vertices written to the buffers so far
+    pub(crate) vertices: u32,
+}
+
+impl GlState {
+    /// State of the currently bound transform feedback object
+    #[inline]
+    pub(crate) fn transform_feedback(&self) -> &FeedbackState {
+        match self.transform_feedback_binding {
+            Some(name) => &self.transform_feedback_list.get(name).state,
+            None => &self.default_transform_feedback,
+        }
+    }
+    #[inline]
+    pub(crate) fn transform_feedback_mut(&mut self) -> &mut FeedbackState {
+        match self.transform_feedback_binding {
+            Some(name) => &mut self.transform_feedback_list.get_mut(name).state,
+            None => &mut self.default_transform_feedback,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+#[cfg(target_vendor = "apple")]
 pub type ProtoObjRef<T> = Retained<ProtocolObject<T>>;
 use std::fmt::Debug;
 #[must_use]
@@ -123,6 +124,7 @@ macro_rules! run_if_changed {
         }
     };
 }
+#[cfg(target_vendor = "apple")]
 use objc2::{rc::Retained, runtime::ProtocolObject};
 pub(crate) use run_if_changed;
 
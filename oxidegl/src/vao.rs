@@ -1,13 +1,12 @@
 use std::{array, num::NonZeroU32};
 
-use objc2_metal::MTLVertexFormat;
-
 use crate::{
     conversions::sizei,
     debug::gl_debug,
     error::gl_assert,
-    gl_enums::VertexAttribType,
+    gl_enums::{GL_BGRA, VertexAttribType},
     gl_types::{GLintptr, GLsizei, GLuint, GLvoid},
+    render::VertexFormat,
 };
 
 use super::{
@@ -19,6 +18,19 @@ use super::{
 pub const MAX_VERTEX_ATTRIBUTES: usize = 32;
 pub const MAX_VERTEX_ATTRIB_BUFFER_BINDINGS: usize = 16;
 pub const MAX_VERTEX_ATTRIBUTE_STRIDE: u16 = 2048;
+/// Value of [`VertexAttrib::components`] for attributes of size `GL_BGRA`, which have 4 components in BGRA order
+pub(crate) const BGRA_COMPONENTS: u8 = 0;
+
+/// Number of components of an attribute of the given size, as stored in [`VertexAttrib::components`]
+#[inline]
+fn attrib_components(size: GLuint) -> GlFallible<u8> {
+    match size {
+        #[allow(clippy::cast_possible_truncation, reason = "size is at most 4")]
+        1..=4 => Ok(size as u8),
+        GL_BGRA => Ok(BGRA_COMPONENTS),
+        _ => Err(GlError::InvalidValue.e()),
+    }
+}
 
 #[derive(Debug)]
 pub struct Vao {
@@ -62,13 +74,9 @@ impl Vao {
             relative_offset <= u32::from(MAX_VERTEX_ATTRIBUTE_STRIDE),
             InvalidValue
         );
-        gl_assert!(
-            num_components <= u32::from(MAX_VERTEX_ATTRIBUTE_STRIDE),
-            InvalidValue
-        );
-        // Caller ensures num_components is in-bounds
-        attrib.components = num_components as u8;
+        attrib.components = attrib_components(num_components)?;
         attrib.component_type = r#type;
+        attrib.integral_cast = integer_behavior;
 
         attrib.relative_offset = relative_offset as u16;
         attrib.validate()?;
@@ -158,9 +166,9 @@ impl Vao {
         let attr_ref = self.get_attrib_mut(index)?;
         let mut attrib = *attr_ref;
 
-        gl_assert!(num_components <= 4, InvalidValue);
-        attrib.components = num_components as u8;
+        attrib.components = attrib_components(num_components)?;
         attrib.component_type = ty;
+        attrib.integral_cast = integer_behavior;
 
         attrib.relative_offset = 0;
 
@@ -213,40 +221,36 @@ impl VertexAttrib {
         let components = self.components;
         let component_type = self.component_type;
         let normalize = self.integral_cast == IntegralCastBehavior::Normalize;
+        let bgra = components == BGRA_COMPONENTS;
+        let packed = matches!(
+            component_type,
+            VertexAttribType::UnsignedInt2101010Rev | VertexAttribType::Int2101010Rev
+        );
         gl_assert!(
-            !((components == 0 || components > 4)
-                && !matches!(
-                    component_type,
-                    VertexAttribType::UnsignedInt2101010Rev | VertexAttribType::Int2101010Rev
-                )),
-            InvalidValue,
-            "UB: attribute size BGRA is not supported for non-BGR10A2 formats!"
+            !bgra || packed || component_type == VertexAttribType::UnsignedByte,
+            InvalidOperation,
+            "attribute size GL_BGRA is only supported with GL_UNSIGNED_BYTE and BGR10A2 formats"
         );
         gl_assert!(
-            !(matches!(
-                component_type,
-                VertexAttribType::UnsignedInt2101010Rev | VertexAttribType::Int2101010Rev
-            ) && components < 4
-                || components > 4),
-            InvalidValue,
-            "UB: attribute size {components} is not supported with BGR10A2 formats!"
+            !packed || bgra || components == 4,
+            InvalidOperation,
+            "attribute size {components} is not supported with BGR10A2 formats"
         );
         gl_assert!(
             !(component_type == VertexAttribType::UnsignedInt10F11F11FRev && components != 3),
-            InvalidValue,
-            "UB: attribute size for attribute with format RG11FB10F must be 3, got {components}"
+            InvalidOperation,
+            "attribute size for attribute with format RG11FB10F must be 3, got {components}"
         );
         gl_assert!(
-            !((components == 0 || components > 4) && !normalize),
-            InvalidValue,
-            "UB: size is GL_BGRA but normalize is false"
+            !bgra || normalize,
+            InvalidOperation,
+            "attribute size is GL_BGRA but normalize is false"
         );
         gl_assert!(
             self.relative_offset < MAX_VERTEX_ATTRIBUTE_STRIDE,
             InvalidValue,
             "UB: relative offset greater than maximum stride"
         );
-        assert!(!normalize);
         Ok(())
     }
     pub(crate) const fn new_default(idx: u8) -> Self {
@@ -284,7 +288,12 @@ impl VertexAttrib {
         if component_size == 0 {
             return 4;
         }
-        let contiguous_size = component_size * self.components as usize;
+        let components = if self.components == BGRA_COMPONENTS {
+            4
+        } else {
+            self.components as usize
+        };
+        let contiguous_size = component_size * components;
         let align = contiguous_size.next_power_of_two().max(16);
 
         // Max allowable stride is 2048 (<u16::MAX)
@@ -335,10 +344,10 @@ macro_rules! generate_attr_match_branch {
         AttributeFormatWithConversion {
             mtl_format: {
                     match $size {
-                    1 => concat_idents::concat_idents!(name = $base, $($suffix)* { MTLVertexFormat::name }),
-                    2 => concat_idents::concat_idents!(name = $base, 2, $($suffix)* { MTLVertexFormat::name }),
-                    3 => concat_idents::concat_idents!(name = $base, 3, $($suffix)* { MTLVertexFormat::name }),
-                    4 => concat_idents::concat_idents!(name = $base, 4, $($suffix)* { MTLVertexFormat::name }),
+                    1 => concat_idents::concat_idents!(name = $base, $($suffix)* { VertexFormat::name }),
+                    2 => concat_idents::concat_idents!(name = $base, 2, $($suffix)* { VertexFormat::name }),
+                    3 => concat_idents::concat_idents!(name = $base, 3, $($suffix)* { VertexFormat::name }),
+                    4 => concat_idents::concat_idents!(name = $base, 4, $($suffix)* { VertexFormat::name }),
                     #[allow(unused_unsafe)]
                     // Safety: Caller ensures the number of components is in bounds
                     _ => unreachable!("UB: invalid vertex attribute size!")
@@ -359,8 +368,18 @@ fn gl_attribute_to_mtl(
     behavior: IntegralCastBehavior,
 ) -> AttributeFormatWithConversion {
     let normalize = behavior == IntegralCastBehavior::Normalize;
-    let bgra_shuffle = !(1..=4).contains(&num_components);
-    // All MTLVertexFormat values are in-bounds for u32
+    let bgra_shuffle = num_components == BGRA_COMPONENTS;
+    // Metal fetches BGRA bytes natively, only the packed formats need their components swapped in the shader
+    if bgra_shuffle && ty == VertexAttribType::UnsignedByte {
+        #[allow(clippy::cast_possible_truncation)]
+        return AttributeFormatWithConversion {
+            mtl_format: VertexFormat::UChar4Normalized_BGRA.0 as u32,
+            normalization_const: 0,
+            conversion: IntegralCastBehavior::Native,
+            bgra_shuffle: false,
+        };
+    }
+    // All VertexFormat values are in-bounds for u32
     #[allow(clippy::cast_possible_truncation)]
     match ty {
         VertexAttribType::Byte => generate_attr_match_branch!(
@@ -421,20 +440,20 @@ fn gl_attribute_to_mtl(
             IntegralCastBehavior::Native,
         ),
         VertexAttribType::UnsignedInt2101010Rev => AttributeFormatWithConversion {
-            mtl_format: MTLVertexFormat::UInt1010102Normalized.0 as u32,
+            mtl_format: VertexFormat::UInt1010102Normalized.0 as u32,
             normalization_const: 0,
             conversion: IntegralCastBehavior::Native,
             bgra_shuffle,
         },
         VertexAttribType::Int2101010Rev => AttributeFormatWithConversion {
-            mtl_format: MTLVertexFormat::Int1010102Normalized.0 as u32,
+            mtl_format: VertexFormat::Int1010102Normalized.0 as u32,
             normalization_const: 0,
             conversion: IntegralCastBehavior::Native,
             bgra_shuffle,
         },
 
         VertexAttribType::UnsignedInt10F11F11FRev => AttributeFormatWithConversion {
-            mtl_format: MTLVertexFormat::FloatRG11B10.0 as u32,
+            mtl_format: VertexFormat::FloatRG11B10.0 as u32,
             normalization_const: 0,
             conversion: IntegralCastBehavior::Native,
             bgra_shuffle: false,
@@ -448,7 +467,7 @@ fn gl_attribute_to_mtl(
     }
 }
 /// Describes conversion code that must be run on this vertex attribute before usage in the vertex shader
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IntegralCastBehavior {
     /// This vertex attribute's in-memory representation is integral but GL client requests normalization to float.
     Normalize,
@@ -457,18 +476,25 @@ pub enum IntegralCastBehavior {
     /// This vertex attribute's in-memory representation is equivalent to the representation requested by the GL client
     Native,
 }
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct AttributeFormatWithConversion {
-    /// [`MTLVertexFormat`] truncated to 32 bits
+    /// [`VertexFormat`] truncated to 32 bits
     pub(crate) mtl_format: u32,
     /// Values should be divided or multiplied by `2^normalization_const` when normalizing
     pub(crate) normalization_const: u8,
     /// Type of conversion code that must be added to the vertex shader
     pub(crate) conversion: IntegralCastBehavior,
+    /// The first and third components should be swapped, since they're stored in BGRA order
     pub(crate) bgra_shuffle: bool,
 }
 impl AttributeFormatWithConversion {
-    pub(crate) fn to_vertex_format(self) -> MTLVertexFormat {
-        MTLVertexFormat(self.mtl_format as usize)
+    pub(crate) fn to_vertex_format(self) -> VertexFormat {
+        VertexFormat(self.mtl_format as usize)
+    }
+    /// Whether the in-memory representation of an integral attribute is signed (signed types have one bit less of
+    /// magnitude to normalize by)
+    #[inline]
+    pub(crate) fn signed(self) -> bool {
+        self.normalization_const % 2 == 1
     }
 }
@@ -53,3 +53,14 @@ pub fn with_ctx_mut<
         }
     }
 }
+/// Run `f` with a shared reference to the current context, without recording errors. Returns `None` if there is no
+/// current context, or if it is in use by a GL command (e.g. when called from a debug callback)
+#[inline]
+pub fn with_ctx<Ret>(f: impl FnOnce(&Context) -> Ret) -> Option<Ret> {
+    // take the context pointer for the duration of the call, like `with_ctx_mut`
+    let ptr = CTX.take()?;
+    // Safety: we are the exclusive accessor of ptr due to its thread locality and the fact that we called `take` on it previously
+    let ret = f(unsafe { ptr.as_ref() });
+    CTX.set(Some(ptr));
+    Some(ret)
+}
@@ -13,6 +13,7 @@ unsafe extern "C" fn glActiveShaderProgram(pipeline: GLuint, program: GLuint) {
         pipeline,
         program
     );
+    crate::trace::trace_call!(glActiveShaderProgram, pipeline, program);
     with_ctx_mut(|mut state| state.oxidegl_active_shader_program(pipeline, program))
 }
 #[unsafe(no_mangle)]
@@ -21,6 +22,7 @@ unsafe extern "C" fn glActiveTexture(texture: GLenum) {
         "glActiveTexture called, parameters: texture: {:?} ",
         texture
     );
+    crate::trace::trace_call!(glActiveTexture, texture);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_active_texture(texture.try_into_enum()?))
     })
@@ -32,6 +34,7 @@ unsafe extern "C" fn glAttachShader(program: GLuint, shader: GLuint) {
         program,
         shader
     );
+    crate::trace::trace_call!(glAttachShader, program, shader);
     with_ctx_mut(|mut state| state.oxidegl_attach_shader(program, shader))
 }
 #[unsafe(no_mangle)]
@@ -41,6 +44,7 @@ unsafe extern "C" fn glBeginConditionalRender(id: GLuint, mode: GLenum) {
         id,
         mode
     );
+    crate::trace::trace_call!(glBeginConditionalRender, id, mode);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_begin_conditional_render(id, mode.try_into_enum()?))
     })
@@ -48,6 +52,7 @@ unsafe extern "C" fn glBeginConditionalRender(id: GLuint, mode: GLenum) {
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glEndConditionalRender() {
     ::log::trace!("glEndConditionalRender called, parameters:  ",);
+    crate::trace::trace_call!(glEndConditionalRender,);
     with_ctx_mut(|mut state| state.oxidegl_end_conditional_render())
 }
 #[unsafe(no_mangle)]
@@ -57,6 +62,7 @@ unsafe extern "C" fn glBeginQuery(target: GLenum, id: GLuint) {
         target,
         id
     );
+    crate::trace::trace_call!(glBeginQuery, target, id);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_begin_query(target.try_into_enum()?, id))
     })
@@ -64,6 +70,7 @@ unsafe extern "C" fn glBeginQuery(target: GLenum, id: GLuint) {
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glEndQuery(target: GLenum) {
     ::log::trace!("glEndQuery called, parameters: target: {:?} ", target);
+    crate::trace::trace_call!(glEndQuery, target);
     with_ctx_mut(|mut state| GlResult::normalize(state.oxidegl_end_query(target.try_into_enum()?)))
 }
 #[unsafe(no_mangle)]
@@ -74,6 +81,7 @@ unsafe extern "C" fn glBeginQueryIndexed(target: GLenum, index: GLuint, id: GLui
         index,
         id
     );
+    crate::trace::trace_call!(glBeginQueryIndexed, target, index, id);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_begin_query_indexed(target.try_into_enum()?, index, id))
     })
@@ -85,6 +93,7 @@ unsafe extern "C" fn glEndQueryIndexed(target: GLenum, index: GLuint) {
         target,
         index
     );
+    crate::trace::trace_call!(glEndQueryIndexed, target, index);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_end_query_indexed(target.try_into_enum()?, index))
     })
@@ -95,6 +104,7 @@ unsafe extern "C" fn glBeginTransformFeedback(primitiveMode: GLenum) {
         "glBeginTransformFeedback called, parameters: primitiveMode: {:?} ",
         primitiveMode
     );
+    crate::trace::trace_call!(glBeginTransformFeedback, primitiveMode);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_begin_transform_feedback(primitiveMode.try_into_enum()?))
     })
@@ -102,6 +112,7 @@ unsafe extern "C" fn glBeginTransformFeedback(primitiveMode: GLenum) {
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glEndTransformFeedback() {
     ::log::trace!("glEndTransformFeedback called, parameters:  ",);
+    crate::trace::trace_call!(glEndTransformFeedback,);
     with_ctx_mut(|mut state| state.oxidegl_end_transform_feedback())
 }
 #[unsafe(no_mangle)]
@@ -112,6 +123,7 @@ unsafe extern "C" fn glBindAttribLocation(program: GLuint, index: GLuint, name:
         index,
         name
     );
+    crate::trace::trace_call!(glBindAttribLocation, program, index, name);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_bind_attrib_location(program, index, name) })
 }
 #[unsafe(no_mangle)]
@@ -121,6 +133,7 @@ unsafe extern "C" fn glBindBuffer(target: GLenum, buffer: GLuint) {
         target,
         buffer
     );
+    crate::trace::trace_call!(glBindBuffer, target, buffer);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_bind_buffer(target.try_into_enum()?, buffer))
     })
@@ -133,6 +146,7 @@ unsafe extern "C" fn glBindBufferBase(target: GLenum, index: GLuint, buffer: GLu
         index,
         buffer
     );
+    crate::trace::trace_call!(glBindBufferBase, target, index, buffer);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_bind_buffer_base(target.try_into_enum()?, index, buffer))
     })
@@ -153,6 +167,7 @@ unsafe extern "C" fn glBindBufferRange(
         offset,
         size
     );
+    crate::trace::trace_call!(glBindBufferRange, target, index, buffer, offset, size);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_bind_buffer_range(
             target.try_into_enum()?,
@@ -177,6 +192,7 @@ unsafe extern "C" fn glBindBuffersBase(
         count,
         buffers
     );
+    crate::trace::trace_call!(glBindBuffersBase, target, first, count, buffers);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_bind_buffers_base(target.try_into_enum()?, first, count, buffers)
@@ -201,6 +217,15 @@ unsafe extern "C" fn glBindBuffersRange(
         offsets,
         sizes
     );
+    crate::trace::trace_call!(
+        glBindBuffersRange,
+        target,
+        first,
+        count,
+        buffers,
+        offsets,
+        sizes
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_bind_buffers_range(
@@ -222,6 +247,7 @@ unsafe extern "C" fn glBindFragDataLocation(program: GLuint, color: GLuint, name
         color,
         name
     );
+    crate::trace::trace_call!(glBindFragDataLocation, program, color, name);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_bind_frag_data_location(program, color, name) })
 }
 #[unsafe(no_mangle)]
@@ -238,6 +264,13 @@ unsafe extern "C" fn glBindFragDataLocationIndexed(
         index,
         name
     );
+    crate::trace::trace_call!(
+        glBindFragDataLocationIndexed,
+        program,
+        colorNumber,
+        index,
+        name
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_bind_frag_data_location_indexed(program, colorNumber, index, name)
     })
@@ -249,6 +282,7 @@ unsafe extern "C" fn glBindFramebuffer(target: GLenum, framebuffer: GLuint) {
         target,
         framebuffer
     );
+    crate::trace::trace_call!(glBindFramebuffer, target, framebuffer);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_bind_framebuffer(target.try_into_enum()?, framebuffer))
     })
@@ -273,6 +307,16 @@ unsafe extern "C" fn glBindImageTexture(
         access,
         format
     );
+    crate::trace::trace_call!(
+        glBindImageTexture,
+        unit,
+        texture,
+        level,
+        layered,
+        layer,
+        access,
+        format
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_bind_image_texture(
             unit,
@@ -293,6 +337,7 @@ unsafe extern "C" fn glBindImageTextures(first: GLuint, count: GLsizei, textures
         count,
         textures
     );
+    crate::trace::trace_call!(glBindImageTextures, first, count, textures);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_bind_image_textures(first, count, textures) })
 }
 #[unsafe(no_mangle)]
@@ -301,6 +346,7 @@ unsafe extern "C" fn glBindProgramPipeline(pipeline: GLuint) {
         "glBindProgramPipeline called, parameters: pipeline: {:?} ",
         pipeline
     );
+    crate::trace::trace_call!(glBindProgramPipeline, pipeline);
     with_ctx_mut(|mut state| state.oxidegl_bind_program_pipeline(pipeline))
 }
 #[unsafe(no_mangle)]
@@ -310,6 +356,7 @@ unsafe extern "C" fn glBindRenderbuffer(target: GLenum, renderbuffer: GLuint) {
         target,
         renderbuffer
     );
+    crate::trace::trace_call!(glBindRenderbuffer, target, renderbuffer);
     with_ctx_mut(|mut state| state.oxidegl_bind_renderbuffer(target, renderbuffer))
 }
 #[unsafe(no_mangle)]
@@ -319,6 +366,7 @@ unsafe extern "C" fn glBindSampler(unit: GLuint, sampler: GLuint) {
         unit,
         sampler
     );
+    crate::trace::trace_call!(glBindSampler, unit, sampler);
     with_ctx_mut(|mut state| state.oxidegl_bind_sampler(unit, sampler))
 }
 #[unsafe(no_mangle)]
@@ -329,6 +377,7 @@ unsafe extern "C" fn glBindSamplers(first: GLuint, count: GLsizei, samplers: *co
         count,
         samplers
     );
+    crate::trace::trace_call!(glBindSamplers, first, count, samplers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_bind_samplers(first, count, samplers) })
 }
 #[unsafe(no_mangle)]
@@ -338,6 +387,7 @@ unsafe extern "C" fn glBindTexture(target: GLenum, texture: GLuint) {
         target,
         texture
     );
+    crate::trace::trace_call!(glBindTexture, target, texture);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_bind_texture(target.try_into_enum()?, texture))
     })
@@ -350,6 +400,7 @@ unsafe extern "C" fn glBindTextures(first: GLuint, count: GLsizei, textures: *co
         count,
         textures
     );
+    crate::trace::trace_call!(glBindTextures, first, count, textures);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_bind_textures(first, count, textures) })
 }
 #[unsafe(no_mangle)]
@@ -359,6 +410,7 @@ unsafe extern "C" fn glBindTextureUnit(unit: GLuint, texture: GLuint) {
         unit,
         texture
     );
+    crate::trace::trace_call!(glBindTextureUnit, unit, texture);
     with_ctx_mut(|mut state| state.oxidegl_bind_texture_unit(unit, texture))
 }
 #[unsafe(no_mangle)]
@@ -368,11 +420,13 @@ unsafe extern "C" fn glBindTransformFeedback(target: GLenum, id: GLuint) {
         target,
         id
     );
+    crate::trace::trace_call!(glBindTransformFeedback, target, id);
     with_ctx_mut(|mut state| state.oxidegl_bind_transform_feedback(target, id))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glBindVertexArray(array: GLuint) {
     ::log::trace!("glBindVertexArray called, parameters: array: {:?} ", array);
+    crate::trace::trace_call!(glBindVertexArray, array);
     with_ctx_mut(|mut state| state.oxidegl_bind_vertex_array(array))
 }
 #[unsafe(no_mangle)]
@@ -389,6 +443,7 @@ unsafe extern "C" fn glBindVertexBuffer(
         offset,
         stride
     );
+    crate::trace::trace_call!(glBindVertexBuffer, bindingindex, buffer, offset, stride);
     with_ctx_mut(|mut state| state.oxidegl_bind_vertex_buffer(bindingindex, buffer, offset, stride))
 }
 #[unsafe(no_mangle)]
@@ -407,6 +462,14 @@ unsafe extern "C" fn glVertexArrayVertexBuffer(
         offset,
         stride
     );
+    crate::trace::trace_call!(
+        glVertexArrayVertexBuffer,
+        vaobj,
+        bindingindex,
+        buffer,
+        offset,
+        stride
+    );
     with_ctx_mut(|mut state| {
         state.oxidegl_vertex_array_vertex_buffer(vaobj, bindingindex, buffer, offset, stride)
     })
@@ -427,6 +490,7 @@ unsafe extern "C" fn glBindVertexBuffers(
         offsets,
         strides
     );
+    crate::trace::trace_call!(glBindVertexBuffers, first, count, buffers, offsets, strides);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_bind_vertex_buffers(first, count, buffers, offsets, strides)
     })
@@ -449,6 +513,15 @@ unsafe extern "C" fn glVertexArrayVertexBuffers(
         offsets,
         strides
     );
+    crate::trace::trace_call!(
+        glVertexArrayVertexBuffers,
+        vaobj,
+        first,
+        count,
+        buffers,
+        offsets,
+        strides
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_vertex_array_vertex_buffers(vaobj, first, count, buffers, offsets, strides)
     })
@@ -462,11 +535,13 @@ unsafe extern "C" fn glBlendColor(red: GLfloat, green: GLfloat, blue: GLfloat, a
         blue,
         alpha
     );
+    crate::trace::trace_call!(glBlendColor, red, green, blue, alpha);
     with_ctx_mut(|mut state| state.oxidegl_blend_color(red, green, blue, alpha))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glBlendEquation(mode: GLenum) {
     ::log::trace!("glBlendEquation called, parameters: mode: {:?} ", mode);
+    crate::trace::trace_call!(glBlendEquation, mode);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_blend_equation(mode.try_into_enum()?))
     })
@@ -478,6 +553,7 @@ unsafe extern "C" fn glBlendEquationi(buf: GLuint, mode: GLenum) {
         buf,
         mode
     );
+    crate::trace::trace_call!(glBlendEquationi, buf, mode);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_blend_equationi(buf, mode.try_into_enum()?))
     })
@@ -489,6 +565,7 @@ unsafe extern "C" fn glBlendEquationSeparate(modeRGB: GLenum, modeAlpha: GLenum)
         modeRGB,
         modeAlpha
     );
+    crate::trace::trace_call!(glBlendEquationSeparate, modeRGB, modeAlpha);
     with_ctx_mut(|mut state| {
         GlResult::normalize(
             state.oxidegl_blend_equation_separate(
@@ -506,6 +583,7 @@ unsafe extern "C" fn glBlendEquationSeparatei(buf: GLuint, modeRGB: GLenum, mode
         modeRGB,
         modeAlpha
     );
+    crate::trace::trace_call!(glBlendEquationSeparatei, buf, modeRGB, modeAlpha);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_blend_equation_separatei(
             buf,
@@ -521,6 +599,7 @@ unsafe extern "C" fn glBlendFunc(sfactor: GLenum, dfactor: GLenum) {
         sfactor,
         dfactor
     );
+    crate::trace::trace_call!(glBlendFunc, sfactor, dfactor);
     with_ctx_mut(|mut state| {
         GlResult::normalize(
             state.oxidegl_blend_func(sfactor.try_into_enum()?, dfactor.try_into_enum()?),
@@ -535,6 +614,7 @@ unsafe extern "C" fn glBlendFunci(buf: GLuint, src: GLenum, dst: GLenum) {
         src,
         dst
     );
+    crate::trace::trace_call!(glBlendFunci, buf, src, dst);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_blend_funci(
             buf,
@@ -557,6 +637,13 @@ unsafe extern "C" fn glBlendFuncSeparate(
         sfactorAlpha,
         dfactorAlpha
     );
+    crate::trace::trace_call!(
+        glBlendFuncSeparate,
+        sfactorRGB,
+        dfactorRGB,
+        sfactorAlpha,
+        dfactorAlpha
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_blend_func_separate(
             sfactorRGB.try_into_enum()?,
@@ -582,6 +669,14 @@ unsafe extern "C" fn glBlendFuncSeparatei(
         srcAlpha,
         dstAlpha
     );
+    crate::trace::trace_call!(
+        glBlendFuncSeparatei,
+        buf,
+        srcRGB,
+        dstRGB,
+        srcAlpha,
+        dstAlpha
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_blend_func_separatei(
             buf,
@@ -618,6 +713,19 @@ unsafe extern "C" fn glBlitFramebuffer(
         mask,
         filter
     );
+    crate::trace::trace_call!(
+        glBlitFramebuffer,
+        srcX0,
+        srcY0,
+        srcX1,
+        srcY1,
+        dstX0,
+        dstY0,
+        dstX1,
+        dstY1,
+        mask,
+        filter
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_blit_framebuffer(
             srcX0,
@@ -663,6 +771,21 @@ unsafe extern "C" fn glBlitNamedFramebuffer(
         mask,
         filter
     );
+    crate::trace::trace_call!(
+        glBlitNamedFramebuffer,
+        readFramebuffer,
+        drawFramebuffer,
+        srcX0,
+        srcY0,
+        srcX1,
+        srcY1,
+        dstX0,
+        dstY0,
+        dstX1,
+        dstY1,
+        mask,
+        filter
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_blit_named_framebuffer(
             readFramebuffer,
@@ -694,6 +817,7 @@ unsafe extern "C" fn glBufferData(
         data,
         usage
     );
+    crate::trace::trace_call!(glBufferData, target, size, data, usage);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_buffer_data(target.try_into_enum()?, size, data, usage.try_into_enum()?)
@@ -714,6 +838,7 @@ unsafe extern "C" fn glNamedBufferData(
         data,
         usage
     );
+    crate::trace::trace_call!(glNamedBufferData, buffer, size, data, usage);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_named_buffer_data(buffer, size, data, usage.try_into_enum()?)
@@ -734,6 +859,7 @@ unsafe extern "C" fn glBufferStorage(
         data,
         flags
     );
+    crate::trace::trace_call!(glBufferStorage, target, size, data, flags);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_buffer_storage(
@@ -759,6 +885,7 @@ unsafe extern "C" fn glNamedBufferStorage(
         data,
         flags
     );
+    crate::trace::trace_call!(glNamedBufferStorage, buffer, size, data, flags);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_named_buffer_storage(buffer, size, data, flags.try_into_enum()?)
@@ -779,6 +906,7 @@ unsafe extern "C" fn glBufferSubData(
         size,
         data
     );
+    crate::trace::trace_call!(glBufferSubData, target, offset, size, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_buffer_sub_data(target.try_into_enum()?, offset, size, data)
@@ -799,6 +927,7 @@ unsafe extern "C" fn glNamedBufferSubData(
         size,
         data
     );
+    crate::trace::trace_call!(glNamedBufferSubData, buffer, offset, size, data);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_named_buffer_sub_data(buffer, offset, size, data)
     })
@@ -809,6 +938,7 @@ unsafe extern "C" fn glCheckFramebufferStatus(target: GLenum) -> GLenum {
         "glCheckFramebufferStatus called, parameters: target: {:?} ",
         target
     );
+    crate::trace::trace_call!(glCheckFramebufferStatus, target);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_check_framebuffer_status(target.try_into_enum()?))
     })
@@ -820,6 +950,7 @@ unsafe extern "C" fn glCheckNamedFramebufferStatus(framebuffer: GLuint, target:
         framebuffer,
         target
     );
+    crate::trace::trace_call!(glCheckNamedFramebufferStatus, framebuffer, target);
     with_ctx_mut(|mut state| {
         GlResult::normalize(
             state.oxidegl_check_named_framebuffer_status(framebuffer, target.try_into_enum()?),
@@ -833,6 +964,7 @@ unsafe extern "C" fn glClampColor(target: GLenum, clamp: GLenum) {
         target,
         clamp
     );
+    crate::trace::trace_call!(glClampColor, target, clamp);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_clamp_color(target, clamp.try_into_enum()?))
     })
@@ -840,6 +972,7 @@ unsafe extern "C" fn glClampColor(target: GLenum, clamp: GLenum) {
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glClear(mask: GLenum) {
     ::log::trace!("glClear called, parameters: mask: {:?} ", mask);
+    crate::trace::trace_call!(glClear, mask);
     with_ctx_mut(|mut state| GlResult::normalize(state.oxidegl_clear(mask.try_into_enum()?)))
 }
 #[unsafe(no_mangle)]
@@ -850,6 +983,7 @@ unsafe extern "C" fn glClearBufferiv(buffer: GLenum, drawbuffer: GLint, value: *
         drawbuffer,
         value
     );
+    crate::trace::trace_call!(glClearBufferiv, buffer, drawbuffer, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_clear_bufferiv(buffer.try_into_enum()?, drawbuffer, value)
@@ -864,6 +998,7 @@ unsafe extern "C" fn glClearBufferuiv(buffer: GLenum, drawbuffer: GLint, value:
         drawbuffer,
         value
     );
+    crate::trace::trace_call!(glClearBufferuiv, buffer, drawbuffer, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_clear_bufferuiv(buffer.try_into_enum()?, drawbuffer, value)
@@ -878,6 +1013,7 @@ unsafe extern "C" fn glClearBufferfv(buffer: GLenum, drawbuffer: GLint, value: *
         drawbuffer,
         value
     );
+    crate::trace::trace_call!(glClearBufferfv, buffer, drawbuffer, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_clear_bufferfv(buffer.try_into_enum()?, drawbuffer, value)
@@ -898,6 +1034,7 @@ unsafe extern "C" fn glClearBufferfi(
         depth,
         stencil
     );
+    crate::trace::trace_call!(glClearBufferfi, buffer, drawbuffer, depth, stencil);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_clear_bufferfi(
             buffer.try_into_enum()?,
@@ -921,6 +1058,13 @@ unsafe extern "C" fn glClearNamedFramebufferiv(
         drawbuffer,
         value
     );
+    crate::trace::trace_call!(
+        glClearNamedFramebufferiv,
+        framebuffer,
+        buffer,
+        drawbuffer,
+        value
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_clear_named_framebufferiv(
@@ -946,6 +1090,13 @@ unsafe extern "C" fn glClearNamedFramebufferuiv(
         drawbuffer,
         value
     );
+    crate::trace::trace_call!(
+        glClearNamedFramebufferuiv,
+        framebuffer,
+        buffer,
+        drawbuffer,
+        value
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_clear_named_framebufferuiv(
@@ -971,6 +1122,13 @@ unsafe extern "C" fn glClearNamedFramebufferfv(
         drawbuffer,
         value
     );
+    crate::trace::trace_call!(
+        glClearNamedFramebufferfv,
+        framebuffer,
+        buffer,
+        drawbuffer,
+        value
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_clear_named_framebufferfv(
@@ -998,6 +1156,14 @@ unsafe extern "C" fn glClearNamedFramebufferfi(
         depth,
         stencil
     );
+    crate::trace::trace_call!(
+        glClearNamedFramebufferfi,
+        framebuffer,
+        buffer,
+        drawbuffer,
+        depth,
+        stencil
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_clear_named_framebufferfi(
             framebuffer,
@@ -1024,6 +1190,14 @@ unsafe extern "C" fn glClearBufferData(
         r#type,
         data
     );
+    crate::trace::trace_call!(
+        glClearBufferData,
+        target,
+        internalformat,
+        format,
+        r#type,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_clear_buffer_data(
@@ -1052,6 +1226,14 @@ unsafe extern "C" fn glClearNamedBufferData(
         r#type,
         data
     );
+    crate::trace::trace_call!(
+        glClearNamedBufferData,
+        buffer,
+        internalformat,
+        format,
+        r#type,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_clear_named_buffer_data(
@@ -1084,6 +1266,16 @@ unsafe extern "C" fn glClearBufferSubData(
         r#type,
         data
     );
+    crate::trace::trace_call!(
+        glClearBufferSubData,
+        target,
+        internalformat,
+        offset,
+        size,
+        format,
+        r#type,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_clear_buffer_sub_data(
@@ -1118,6 +1310,16 @@ unsafe extern "C" fn glClearNamedBufferSubData(
         r#type,
         data
     );
+    crate::trace::trace_call!(
+        glClearNamedBufferSubData,
+        buffer,
+        internalformat,
+        offset,
+        size,
+        format,
+        r#type,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_clear_named_buffer_sub_data(
@@ -1141,21 +1343,25 @@ unsafe extern "C" fn glClearColor(red: GLfloat, green: GLfloat, blue: GLfloat, a
         blue,
         alpha
     );
+    crate::trace::trace_call!(glClearColor, red, green, blue, alpha);
     with_ctx_mut(|mut state| state.oxidegl_clear_color(red, green, blue, alpha))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glClearDepth(depth: GLdouble) {
     ::log::trace!("glClearDepth called, parameters: depth: {:?} ", depth);
+    crate::trace::trace_call!(glClearDepth, depth);
     with_ctx_mut(|mut state| state.oxidegl_clear_depth(depth))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glClearDepthf(d: GLfloat) {
     ::log::trace!("glClearDepthf called, parameters: d: {:?} ", d);
+    crate::trace::trace_call!(glClearDepthf, d);
     with_ctx_mut(|mut state| state.oxidegl_clear_depthf(d))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glClearStencil(s: GLint) {
     ::log::trace!("glClearStencil called, parameters: s: {:?} ", s);
+    crate::trace::trace_call!(glClearStencil, s);
     with_ctx_mut(|mut state| state.oxidegl_clear_stencil(s))
 }
 #[unsafe(no_mangle)]
@@ -1174,6 +1380,7 @@ unsafe extern "C" fn glClearTexImage(
         r#type,
         data
     );
+    crate::trace::trace_call!(glClearTexImage, texture, level, format, r#type, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_clear_tex_image(
@@ -1214,6 +1421,20 @@ unsafe extern "C" fn glClearTexSubImage(
         r#type,
         data
     );
+    crate::trace::trace_call!(
+        glClearTexSubImage,
+        texture,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        width,
+        height,
+        depth,
+        format,
+        r#type,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_clear_tex_sub_image(
@@ -1244,6 +1465,7 @@ unsafe extern "C" fn glClientWaitSync(
         flags,
         timeout
     );
+    crate::trace::trace_call!(glClientWaitSync, sync, flags, timeout);
     with_ctx_mut(|mut state| state.oxidegl_client_wait_sync(sync, flags, timeout))
 }
 #[unsafe(no_mangle)]
@@ -1253,6 +1475,7 @@ unsafe extern "C" fn glClipControl(origin: GLenum, depth: GLenum) {
         origin,
         depth
     );
+    crate::trace::trace_call!(glClipControl, origin, depth);
     with_ctx_mut(|mut state| {
         GlResult::normalize(
             state.oxidegl_clip_control(origin.try_into_enum()?, depth.try_into_enum()?),
@@ -1273,6 +1496,7 @@ unsafe extern "C" fn glColorMask(
         blue,
         alpha
     );
+    crate::trace::trace_call!(glColorMask, red, green, blue, alpha);
     with_ctx_mut(|mut state| state.oxidegl_color_mask(red, green, blue, alpha))
 }
 #[unsafe(no_mangle)]
@@ -1291,11 +1515,13 @@ unsafe extern "C" fn glColorMaski(
         b,
         a
     );
+    crate::trace::trace_call!(glColorMaski, index, r, g, b, a);
     with_ctx_mut(|mut state| state.oxidegl_color_maski(index, r, g, b, a))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glCompileShader(shader: GLuint) {
     ::log::trace!("glCompileShader called, parameters: shader: {:?} ", shader);
+    crate::trace::trace_call!(glCompileShader, shader);
     with_ctx_mut(|mut state| state.oxidegl_compile_shader(shader))
 }
 #[unsafe(no_mangle)]
@@ -1318,6 +1544,16 @@ unsafe extern "C" fn glCompressedTexImage1D(
         imageSize,
         data
     );
+    crate::trace::trace_call!(
+        glCompressedTexImage1D,
+        target,
+        level,
+        internalformat,
+        width,
+        border,
+        imageSize,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_compressed_tex_image1_d(
@@ -1354,6 +1590,17 @@ unsafe extern "C" fn glCompressedTexImage2D(
         imageSize,
         data
     );
+    crate::trace::trace_call!(
+        glCompressedTexImage2D,
+        target,
+        level,
+        internalformat,
+        width,
+        height,
+        border,
+        imageSize,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_compressed_tex_image2_d(
@@ -1393,6 +1640,18 @@ unsafe extern "C" fn glCompressedTexImage3D(
         imageSize,
         data
     );
+    crate::trace::trace_call!(
+        glCompressedTexImage3D,
+        target,
+        level,
+        internalformat,
+        width,
+        height,
+        depth,
+        border,
+        imageSize,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_compressed_tex_image3_d(
@@ -1429,6 +1688,16 @@ unsafe extern "C" fn glCompressedTexSubImage1D(
         imageSize,
         data
     );
+    crate::trace::trace_call!(
+        glCompressedTexSubImage1D,
+        target,
+        level,
+        xoffset,
+        width,
+        format,
+        imageSize,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_compressed_tex_sub_image1_d(
@@ -1463,6 +1732,16 @@ unsafe extern "C" fn glCompressedTextureSubImage1D(
         imageSize,
         data
     );
+    crate::trace::trace_call!(
+        glCompressedTextureSubImage1D,
+        texture,
+        level,
+        xoffset,
+        width,
+        format,
+        imageSize,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_compressed_texture_sub_image1_d(
@@ -1501,6 +1780,18 @@ unsafe extern "C" fn glCompressedTexSubImage2D(
         imageSize,
         data
     );
+    crate::trace::trace_call!(
+        glCompressedTexSubImage2D,
+        target,
+        level,
+        xoffset,
+        yoffset,
+        width,
+        height,
+        format,
+        imageSize,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_compressed_tex_sub_image2_d(
@@ -1541,6 +1832,18 @@ unsafe extern "C" fn glCompressedTextureSubImage2D(
         imageSize,
         data
     );
+    crate::trace::trace_call!(
+        glCompressedTextureSubImage2D,
+        texture,
+        level,
+        xoffset,
+        yoffset,
+        width,
+        height,
+        format,
+        imageSize,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_compressed_texture_sub_image2_d(
@@ -1585,6 +1888,20 @@ unsafe extern "C" fn glCompressedTexSubImage3D(
         imageSize,
         data
     );
+    crate::trace::trace_call!(
+        glCompressedTexSubImage3D,
+        target,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        width,
+        height,
+        depth,
+        format,
+        imageSize,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_compressed_tex_sub_image3_d(
@@ -1631,6 +1948,20 @@ unsafe extern "C" fn glCompressedTextureSubImage3D(
         imageSize,
         data
     );
+    crate::trace::trace_call!(
+        glCompressedTextureSubImage3D,
+        texture,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        width,
+        height,
+        depth,
+        format,
+        imageSize,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_compressed_texture_sub_image3_d(
@@ -1665,6 +1996,14 @@ unsafe extern "C" fn glCopyBufferSubData(
         writeOffset,
         size
     );
+    crate::trace::trace_call!(
+        glCopyBufferSubData,
+        readTarget,
+        writeTarget,
+        readOffset,
+        writeOffset,
+        size
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_copy_buffer_sub_data(
             readTarget.try_into_enum()?,
@@ -1691,6 +2030,14 @@ unsafe extern "C" fn glCopyNamedBufferSubData(
         writeOffset,
         size
     );
+    crate::trace::trace_call!(
+        glCopyNamedBufferSubData,
+        readBuffer,
+        writeBuffer,
+        readOffset,
+        writeOffset,
+        size
+    );
     with_ctx_mut(|mut state| {
         state.oxidegl_copy_named_buffer_sub_data(
             readBuffer,
@@ -1737,6 +2084,24 @@ unsafe extern "C" fn glCopyImageSubData(
         srcHeight,
         srcDepth
     );
+    crate::trace::trace_call!(
+        glCopyImageSubData,
+        srcName,
+        srcTarget,
+        srcLevel,
+        srcX,
+        srcY,
+        srcZ,
+        dstName,
+        dstTarget,
+        dstLevel,
+        dstX,
+        dstY,
+        dstZ,
+        srcWidth,
+        srcHeight,
+        srcDepth
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_copy_image_sub_data(
             srcName,
@@ -1777,6 +2142,16 @@ unsafe extern "C" fn glCopyTexImage1D(
         width,
         border
     );
+    crate::trace::trace_call!(
+        glCopyTexImage1D,
+        target,
+        level,
+        internalformat,
+        x,
+        y,
+        width,
+        border
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_copy_tex_image1_d(
             target.try_into_enum()?,
@@ -1811,6 +2186,17 @@ unsafe extern "C" fn glCopyTexImage2D(
         height,
         border
     );
+    crate::trace::trace_call!(
+        glCopyTexImage2D,
+        target,
+        level,
+        internalformat,
+        x,
+        y,
+        width,
+        height,
+        border
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_copy_tex_image2_d(
             target.try_into_enum()?,
@@ -1842,6 +2228,7 @@ unsafe extern "C" fn glCopyTexSubImage1D(
         y,
         width
     );
+    crate::trace::trace_call!(glCopyTexSubImage1D, target, level, xoffset, x, y, width);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_copy_tex_sub_image1_d(
             target.try_into_enum()?,
@@ -1871,6 +2258,15 @@ unsafe extern "C" fn glCopyTextureSubImage1D(
         y,
         width
     );
+    crate::trace::trace_call!(
+        glCopyTextureSubImage1D,
+        texture,
+        level,
+        xoffset,
+        x,
+        y,
+        width
+    );
     with_ctx_mut(|mut state| {
         state.oxidegl_copy_texture_sub_image1_d(texture, level, xoffset, x, y, width)
     })
@@ -1897,6 +2293,17 @@ unsafe extern "C" fn glCopyTexSubImage2D(
         width,
         height
     );
+    crate::trace::trace_call!(
+        glCopyTexSubImage2D,
+        target,
+        level,
+        xoffset,
+        yoffset,
+        x,
+        y,
+        width,
+        height
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_copy_tex_sub_image2_d(
             target.try_into_enum()?,
@@ -1932,6 +2339,17 @@ unsafe extern "C" fn glCopyTextureSubImage2D(
         width,
         height
     );
+    crate::trace::trace_call!(
+        glCopyTextureSubImage2D,
+        texture,
+        level,
+        xoffset,
+        yoffset,
+        x,
+        y,
+        width,
+        height
+    );
     with_ctx_mut(|mut state| {
         state.oxidegl_copy_texture_sub_image2_d(
             texture, level, xoffset, yoffset, x, y, width, height,
@@ -1962,6 +2380,18 @@ unsafe extern "C" fn glCopyTexSubImage3D(
         width,
         height
     );
+    crate::trace::trace_call!(
+        glCopyTexSubImage3D,
+        target,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        x,
+        y,
+        width,
+        height
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_copy_tex_sub_image3_d(
             target.try_into_enum()?,
@@ -2000,6 +2430,18 @@ unsafe extern "C" fn glCopyTextureSubImage3D(
         width,
         height
     );
+    crate::trace::trace_call!(
+        glCopyTextureSubImage3D,
+        texture,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        x,
+        y,
+        width,
+        height
+    );
     with_ctx_mut(|mut state| {
         state.oxidegl_copy_texture_sub_image3_d(
             texture, level, xoffset, yoffset, zoffset, x, y, width, height,
@@ -2013,6 +2455,7 @@ unsafe extern "C" fn glCreateBuffers(n: GLsizei, buffers: *mut GLuint) {
         n,
         buffers
     );
+    crate::trace::trace_call!(glCreateBuffers, n, buffers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_create_buffers(n, buffers) })
 }
 #[unsafe(no_mangle)]
@@ -2022,11 +2465,13 @@ unsafe extern "C" fn glCreateFramebuffers(n: GLsizei, framebuffers: *mut GLuint)
         n,
         framebuffers
     );
+    crate::trace::trace_call!(glCreateFramebuffers, n, framebuffers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_create_framebuffers(n, framebuffers) })
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glCreateProgram() -> GLuint {
     ::log::trace!("glCreateProgram called, parameters:  ",);
+    crate::trace::trace_call!(glCreateProgram,);
     with_ctx_mut(|mut state| state.oxidegl_create_program())
 }
 #[unsafe(no_mangle)]
@@ -2036,6 +2481,7 @@ unsafe extern "C" fn glCreateProgramPipelines(n: GLsizei, pipelines: *mut GLuint
         n,
         pipelines
     );
+    crate::trace::trace_call!(glCreateProgramPipelines, n, pipelines);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_create_program_pipelines(n, pipelines) })
 }
 #[unsafe(no_mangle)]
@@ -2046,6 +2492,7 @@ unsafe extern "C" fn glCreateQueries(target: GLenum, n: GLsizei, ids: *mut GLuin
         n,
         ids
     );
+    crate::trace::trace_call!(glCreateQueries, target, n, ids);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_create_queries(target.try_into_enum()?, n, ids)
@@ -2059,6 +2506,7 @@ unsafe extern "C" fn glCreateRenderbuffers(n: GLsizei, renderbuffers: *mut GLuin
         n,
         renderbuffers
     );
+    crate::trace::trace_call!(glCreateRenderbuffers, n, renderbuffers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_create_renderbuffers(n, renderbuffers) })
 }
 #[unsafe(no_mangle)]
@@ -2068,11 +2516,13 @@ unsafe extern "C" fn glCreateSamplers(n: GLsizei, samplers: *mut GLuint) {
         n,
         samplers
     );
+    crate::trace::trace_call!(glCreateSamplers, n, samplers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_create_samplers(n, samplers) })
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glCreateShader(r#type: GLenum) -> GLuint {
     ::log::trace!("glCreateShader called, parameters: r#type: {:?} ", r#type);
+    crate::trace::trace_call!(glCreateShader, r#type);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_create_shader(r#type.try_into_enum()?))
     })
@@ -2089,6 +2539,7 @@ unsafe extern "C" fn glCreateShaderProgramv(
         count,
         strings
     );
+    crate::trace::trace_call!(glCreateShaderProgramv, r#type, count, strings);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_create_shader_programv(r#type.try_into_enum()?, count, strings)
@@ -2103,6 +2554,7 @@ unsafe extern "C" fn glCreateTextures(target: GLenum, n: GLsizei, textures: *mut
         n,
         textures
     );
+    crate::trace::trace_call!(glCreateTextures, target, n, textures);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_create_textures(target.try_into_enum()?, n, textures)
@@ -2116,6 +2568,7 @@ unsafe extern "C" fn glCreateTransformFeedbacks(n: GLsizei, ids: *mut GLuint) {
         n,
         ids
     );
+    crate::trace::trace_call!(glCreateTransformFeedbacks, n, ids);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_create_transform_feedbacks(n, ids) })
 }
 #[unsafe(no_mangle)]
@@ -2125,11 +2578,13 @@ unsafe extern "C" fn glCreateVertexArrays(n: GLsizei, arrays: *mut GLuint) {
         n,
         arrays
     );
+    crate::trace::trace_call!(glCreateVertexArrays, n, arrays);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_create_vertex_arrays(n, arrays) })
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glCullFace(mode: GLenum) {
     ::log::trace!("glCullFace called, parameters: mode: {:?} ", mode);
+    crate::trace::trace_call!(glCullFace, mode);
     with_ctx_mut(|mut state| GlResult::normalize(state.oxidegl_cull_face(mode.try_into_enum()?)))
 }
 #[unsafe(no_mangle)]
@@ -2139,6 +2594,7 @@ unsafe extern "C" fn glDebugMessageCallback(callback: GLDEBUGPROC, userParam: *c
         callback,
         userParam
     );
+    crate::trace::trace_call!(glDebugMessageCallback, callback, userParam);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_debug_message_callback(callback, userParam) })
 }
 #[unsafe(no_mangle)]
@@ -2159,6 +2615,15 @@ unsafe extern "C" fn glDebugMessageControl(
         ids,
         enabled
     );
+    crate::trace::trace_call!(
+        glDebugMessageControl,
+        source,
+        r#type,
+        severity,
+        count,
+        ids,
+        enabled
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_debug_message_control(
@@ -2190,6 +2655,15 @@ unsafe extern "C" fn glDebugMessageInsert(
         length,
         buf
     );
+    crate::trace::trace_call!(
+        glDebugMessageInsert,
+        source,
+        r#type,
+        id,
+        severity,
+        length,
+        buf
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_debug_message_insert(
@@ -2210,6 +2684,7 @@ unsafe extern "C" fn glDeleteBuffers(n: GLsizei, buffers: *const GLuint) {
         n,
         buffers
     );
+    crate::trace::trace_call!(glDeleteBuffers, n, buffers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_delete_buffers(n, buffers) })
 }
 #[unsafe(no_mangle)]
@@ -2219,6 +2694,7 @@ unsafe extern "C" fn glDeleteFramebuffers(n: GLsizei, framebuffers: *const GLuin
         n,
         framebuffers
     );
+    crate::trace::trace_call!(glDeleteFramebuffers, n, framebuffers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_delete_framebuffers(n, framebuffers) })
 }
 #[unsafe(no_mangle)]
@@ -2227,6 +2703,7 @@ unsafe extern "C" fn glDeleteProgram(program: GLuint) {
         "glDeleteProgram called, parameters: program: {:?} ",
         program
     );
+    crate::trace::trace_call!(glDeleteProgram, program);
     with_ctx_mut(|mut state| state.oxidegl_delete_program(program))
 }
 #[unsafe(no_mangle)]
@@ -2236,6 +2713,7 @@ unsafe extern "C" fn glDeleteProgramPipelines(n: GLsizei, pipelines: *const GLui
         n,
         pipelines
     );
+    crate::trace::trace_call!(glDeleteProgramPipelines, n, pipelines);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_delete_program_pipelines(n, pipelines) })
 }
 #[unsafe(no_mangle)]
@@ -2245,6 +2723,7 @@ unsafe extern "C" fn glDeleteQueries(n: GLsizei, ids: *const GLuint) {
         n,
         ids
     );
+    crate::trace::trace_call!(glDeleteQueries, n, ids);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_delete_queries(n, ids) })
 }
 #[unsafe(no_mangle)]
@@ -2254,6 +2733,7 @@ unsafe extern "C" fn glDeleteRenderbuffers(n: GLsizei, renderbuffers: *const GLu
         n,
         renderbuffers
     );
+    crate::trace::trace_call!(glDeleteRenderbuffers, n, renderbuffers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_delete_renderbuffers(n, renderbuffers) })
 }
 #[unsafe(no_mangle)]
@@ -2263,16 +2743,19 @@ unsafe extern "C" fn glDeleteSamplers(count: GLsizei, samplers: *const GLuint) {
         count,
         samplers
     );
+    crate::trace::trace_call!(glDeleteSamplers, count, samplers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_delete_samplers(count, samplers) })
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glDeleteShader(shader: GLuint) {
     ::log::trace!("glDeleteShader called, parameters: shader: {:?} ", shader);
+    crate::trace::trace_call!(glDeleteShader, shader);
     with_ctx_mut(|mut state| state.oxidegl_delete_shader(shader))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glDeleteSync(sync: GLsync) {
     ::log::trace!("glDeleteSync called, parameters: sync: {:?} ", sync);
+    crate::trace::trace_call!(glDeleteSync, sync);
     with_ctx_mut(|mut state| state.oxidegl_delete_sync(sync))
 }
 #[unsafe(no_mangle)]
@@ -2282,6 +2765,7 @@ unsafe extern "C" fn glDeleteTextures(n: GLsizei, textures: *const GLuint) {
         n,
         textures
     );
+    crate::trace::trace_call!(glDeleteTextures, n, textures);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_delete_textures(n, textures) })
 }
 #[unsafe(no_mangle)]
@@ -2291,6 +2775,7 @@ unsafe extern "C" fn glDeleteTransformFeedbacks(n: GLsizei, ids: *const GLuint)
         n,
         ids
     );
+    crate::trace::trace_call!(glDeleteTransformFeedbacks, n, ids);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_delete_transform_feedbacks(n, ids) })
 }
 #[unsafe(no_mangle)]
@@ -2300,26 +2785,31 @@ unsafe extern "C" fn glDeleteVertexArrays(n: GLsizei, arrays: *const GLuint) {
         n,
         arrays
     );
+    crate::trace::trace_call!(glDeleteVertexArrays, n, arrays);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_delete_vertex_arrays(n, arrays) })
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glDepthFunc(func: GLenum) {
     ::log::trace!("glDepthFunc called, parameters: func: {:?} ", func);
+    crate::trace::trace_call!(glDepthFunc, func);
     with_ctx_mut(|mut state| GlResult::normalize(state.oxidegl_depth_func(func.try_into_enum()?)))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glDepthMask(flag: GLboolean) {
     ::log::trace!("glDepthMask called, parameters: flag: {:?} ", flag);
+    crate::trace::trace_call!(glDepthMask, flag);
     with_ctx_mut(|mut state| state.oxidegl_depth_mask(flag))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glDepthRange(n: GLdouble, f: GLdouble) {
     ::log::trace!("glDepthRange called, parameters: n: {:?}, f: {:?} ", n, f);
+    crate::trace::trace_call!(glDepthRange, n, f);
     with_ctx_mut(|mut state| state.oxidegl_depth_range(n, f))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glDepthRangef(n: GLfloat, f: GLfloat) {
     ::log::trace!("glDepthRangef called, parameters: n: {:?}, f: {:?} ", n, f);
+    crate::trace::trace_call!(glDepthRangef, n, f);
     with_ctx_mut(|mut state| state.oxidegl_depth_rangef(n, f))
 }
 #[unsafe(no_mangle)]
@@ -2330,6 +2820,7 @@ unsafe extern "C" fn glDepthRangeArrayv(first: GLuint, count: GLsizei, v: *const
         count,
         v
     );
+    crate::trace::trace_call!(glDepthRangeArrayv, first, count, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_depth_range_arrayv(first, count, v) })
 }
 #[unsafe(no_mangle)]
@@ -2340,6 +2831,7 @@ unsafe extern "C" fn glDepthRangeIndexed(index: GLuint, n: GLdouble, f: GLdouble
         n,
         f
     );
+    crate::trace::trace_call!(glDepthRangeIndexed, index, n, f);
     with_ctx_mut(|mut state| state.oxidegl_depth_range_indexed(index, n, f))
 }
 #[unsafe(no_mangle)]
@@ -2349,6 +2841,7 @@ unsafe extern "C" fn glDetachShader(program: GLuint, shader: GLuint) {
         program,
         shader
     );
+    crate::trace::trace_call!(glDetachShader, program, shader);
     with_ctx_mut(|mut state| state.oxidegl_detach_shader(program, shader))
 }
 #[unsafe(no_mangle)]
@@ -2363,6 +2856,7 @@ unsafe extern "C" fn glDispatchCompute(
         num_groups_y,
         num_groups_z
     );
+    crate::trace::trace_call!(glDispatchCompute, num_groups_x, num_groups_y, num_groups_z);
     with_ctx_mut(|mut state| {
         state.oxidegl_dispatch_compute(num_groups_x, num_groups_y, num_groups_z)
     })
@@ -2373,6 +2867,7 @@ unsafe extern "C" fn glDispatchComputeIndirect(indirect: GLintptr) {
         "glDispatchComputeIndirect called, parameters: indirect: {:?} ",
         indirect
     );
+    crate::trace::trace_call!(glDispatchComputeIndirect, indirect);
     with_ctx_mut(|mut state| state.oxidegl_dispatch_compute_indirect(indirect))
 }
 #[unsafe(no_mangle)]
@@ -2383,6 +2878,7 @@ unsafe extern "C" fn glDrawArrays(mode: GLenum, first: GLint, count: GLsizei) {
         first,
         count
     );
+    crate::trace::trace_call!(glDrawArrays, mode, first, count);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_draw_arrays(mode.try_into_enum()?, first, count))
     })
@@ -2394,6 +2890,7 @@ unsafe extern "C" fn glDrawArraysIndirect(mode: GLenum, indirect: *const GLvoid)
         mode,
         indirect
     );
+    crate::trace::trace_call!(glDrawArraysIndirect, mode, indirect);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_draw_arrays_indirect(mode.try_into_enum()?, indirect)
@@ -2414,6 +2911,7 @@ unsafe extern "C" fn glDrawArraysInstanced(
         count,
         instancecount
     );
+    crate::trace::trace_call!(glDrawArraysInstanced, mode, first, count, instancecount);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_draw_arrays_instanced(
             mode.try_into_enum()?,
@@ -2439,6 +2937,14 @@ unsafe extern "C" fn glDrawArraysInstancedBaseInstance(
         instancecount,
         baseinstance
     );
+    crate::trace::trace_call!(
+        glDrawArraysInstancedBaseInstance,
+        mode,
+        first,
+        count,
+        instancecount,
+        baseinstance
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_draw_arrays_instanced_base_instance(
             mode.try_into_enum()?,
@@ -2452,6 +2958,7 @@ unsafe extern "C" fn glDrawArraysInstancedBaseInstance(
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glDrawBuffer(buf: GLenum) {
     ::log::trace!("glDrawBuffer called, parameters: buf: {:?} ", buf);
+    crate::trace::trace_call!(glDrawBuffer, buf);
     with_ctx_mut(|mut state| GlResult::normalize(state.oxidegl_draw_buffer(buf.try_into_enum()?)))
 }
 #[unsafe(no_mangle)]
@@ -2461,6 +2968,7 @@ unsafe extern "C" fn glNamedFramebufferDrawBuffer(framebuffer: GLuint, buf: GLen
         framebuffer,
         buf
     );
+    crate::trace::trace_call!(glNamedFramebufferDrawBuffer, framebuffer, buf);
     with_ctx_mut(|mut state| {
         GlResult::normalize(
             state.oxidegl_named_framebuffer_draw_buffer(framebuffer, buf.try_into_enum()?),
@@ -2474,6 +2982,7 @@ unsafe extern "C" fn glDrawBuffers(n: GLsizei, bufs: *const GLenum) {
         n,
         bufs
     );
+    crate::trace::trace_call!(glDrawBuffers, n, bufs);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_draw_buffers(n, bufs.cast()) })
 }
 #[unsafe(no_mangle)]
@@ -2488,6 +2997,7 @@ unsafe extern "C" fn glNamedFramebufferDrawBuffers(
         n,
         bufs
     );
+    crate::trace::trace_call!(glNamedFramebufferDrawBuffers, framebuffer, n, bufs);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_named_framebuffer_draw_buffers(framebuffer, n, bufs.cast())
     })
@@ -2506,6 +3016,7 @@ unsafe extern "C" fn glDrawElements(
         r#type,
         indices
     );
+    crate::trace::trace_call!(glDrawElements, mode, count, r#type, indices);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_draw_elements(
@@ -2533,6 +3044,14 @@ unsafe extern "C" fn glDrawElementsBaseVertex(
         indices,
         basevertex
     );
+    crate::trace::trace_call!(
+        glDrawElementsBaseVertex,
+        mode,
+        count,
+        r#type,
+        indices,
+        basevertex
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_draw_elements_base_vertex(
@@ -2553,6 +3072,7 @@ unsafe extern "C" fn glDrawElementsIndirect(mode: GLenum, r#type: GLenum, indire
         r#type,
         indirect
     );
+    crate::trace::trace_call!(glDrawElementsIndirect, mode, r#type, indirect);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_draw_elements_indirect(
@@ -2579,6 +3099,14 @@ unsafe extern "C" fn glDrawElementsInstanced(
         indices,
         instancecount
     );
+    crate::trace::trace_call!(
+        glDrawElementsInstanced,
+        mode,
+        count,
+        r#type,
+        indices,
+        instancecount
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_draw_elements_instanced(
@@ -2609,6 +3137,15 @@ unsafe extern "C" fn glDrawElementsInstancedBaseInstance(
         instancecount,
         baseinstance
     );
+    crate::trace::trace_call!(
+        glDrawElementsInstancedBaseInstance,
+        mode,
+        count,
+        r#type,
+        indices,
+        instancecount,
+        baseinstance
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_draw_elements_instanced_base_instance(
@@ -2640,6 +3177,15 @@ unsafe extern "C" fn glDrawElementsInstancedBaseVertex(
         instancecount,
         basevertex
     );
+    crate::trace::trace_call!(
+        glDrawElementsInstancedBaseVertex,
+        mode,
+        count,
+        r#type,
+        indices,
+        instancecount,
+        basevertex
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_draw_elements_instanced_base_vertex(
@@ -2673,6 +3219,16 @@ unsafe extern "C" fn glDrawElementsInstancedBaseVertexBaseInstance(
         basevertex,
         baseinstance
     );
+    crate::trace::trace_call!(
+        glDrawElementsInstancedBaseVertexBaseInstance,
+        mode,
+        count,
+        r#type,
+        indices,
+        instancecount,
+        basevertex,
+        baseinstance
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_draw_elements_instanced_base_vertex_base_instance(
@@ -2705,6 +3261,15 @@ unsafe extern "C" fn glDrawRangeElements(
         r#type,
         indices
     );
+    crate::trace::trace_call!(
+        glDrawRangeElements,
+        mode,
+        start,
+        end,
+        count,
+        r#type,
+        indices
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_draw_range_elements(
@@ -2738,6 +3303,16 @@ unsafe extern "C" fn glDrawRangeElementsBaseVertex(
         indices,
         basevertex
     );
+    crate::trace::trace_call!(
+        glDrawRangeElementsBaseVertex,
+        mode,
+        start,
+        end,
+        count,
+        r#type,
+        indices,
+        basevertex
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_draw_range_elements_base_vertex(
@@ -2759,6 +3334,7 @@ unsafe extern "C" fn glDrawTransformFeedback(mode: GLenum, id: GLuint) {
         mode,
         id
     );
+    crate::trace::trace_call!(glDrawTransformFeedback, mode, id);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_draw_transform_feedback(mode.try_into_enum()?, id))
     })
@@ -2775,6 +3351,7 @@ unsafe extern "C" fn glDrawTransformFeedbackInstanced(
         id,
         instancecount
     );
+    crate::trace::trace_call!(glDrawTransformFeedbackInstanced, mode, id, instancecount);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_draw_transform_feedback_instanced(
             mode.try_into_enum()?,
@@ -2791,6 +3368,7 @@ unsafe extern "C" fn glDrawTransformFeedbackStream(mode: GLenum, id: GLuint, str
         id,
         stream
     );
+    crate::trace::trace_call!(glDrawTransformFeedbackStream, mode, id, stream);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_draw_transform_feedback_stream(
             mode.try_into_enum()?,
@@ -2813,6 +3391,13 @@ unsafe extern "C" fn glDrawTransformFeedbackStreamInstanced(
         stream,
         instancecount
     );
+    crate::trace::trace_call!(
+        glDrawTransformFeedbackStreamInstanced,
+        mode,
+        id,
+        stream,
+        instancecount
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_draw_transform_feedback_stream_instanced(
             mode.try_into_enum()?,
@@ -2825,11 +3410,13 @@ unsafe extern "C" fn glDrawTransformFeedbackStreamInstanced(
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glDisable(cap: GLenum) {
     ::log::trace!("glDisable called, parameters: cap: {:?} ", cap);
+    crate::trace::trace_call!(glDisable, cap);
     with_ctx_mut(|mut state| GlResult::normalize(state.oxidegl_disable(cap.try_into_enum()?)))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glEnable(cap: GLenum) {
     ::log::trace!("glEnable called, parameters: cap: {:?} ", cap);
+    crate::trace::trace_call!(glEnable, cap);
     with_ctx_mut(|mut state| GlResult::normalize(state.oxidegl_enable(cap.try_into_enum()?)))
 }
 #[unsafe(no_mangle)]
@@ -2839,6 +3426,7 @@ unsafe extern "C" fn glEnablei(target: GLenum, index: GLuint) {
         target,
         index
     );
+    crate::trace::trace_call!(glEnablei, target, index);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_enablei(target.try_into_enum()?, index))
     })
@@ -2850,6 +3438,7 @@ unsafe extern "C" fn glDisablei(target: GLenum, index: GLuint) {
         target,
         index
     );
+    crate::trace::trace_call!(glDisablei, target, index);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_disablei(target.try_into_enum()?, index))
     })
@@ -2860,6 +3449,7 @@ unsafe extern "C" fn glDisableVertexAttribArray(index: GLuint) {
         "glDisableVertexAttribArray called, parameters: index: {:?} ",
         index
     );
+    crate::trace::trace_call!(glDisableVertexAttribArray, index);
     with_ctx_mut(|mut state| state.oxidegl_disable_vertex_attrib_array(index))
 }
 #[unsafe(no_mangle)]
@@ -2868,6 +3458,7 @@ unsafe extern "C" fn glEnableVertexAttribArray(index: GLuint) {
         "glEnableVertexAttribArray called, parameters: index: {:?} ",
         index
     );
+    crate::trace::trace_call!(glEnableVertexAttribArray, index);
     with_ctx_mut(|mut state| state.oxidegl_enable_vertex_attrib_array(index))
 }
 #[unsafe(no_mangle)]
@@ -2877,6 +3468,7 @@ unsafe extern "C" fn glDisableVertexArrayAttrib(vaobj: GLuint, index: GLuint) {
         vaobj,
         index
     );
+    crate::trace::trace_call!(glDisableVertexArrayAttrib, vaobj, index);
     with_ctx_mut(|mut state| state.oxidegl_disable_vertex_array_attrib(vaobj, index))
 }
 #[unsafe(no_mangle)]
@@ -2886,6 +3478,7 @@ unsafe extern "C" fn glEnableVertexArrayAttrib(vaobj: GLuint, index: GLuint) {
         vaobj,
         index
     );
+    crate::trace::trace_call!(glEnableVertexArrayAttrib, vaobj, index);
     with_ctx_mut(|mut state| state.oxidegl_enable_vertex_array_attrib(vaobj, index))
 }
 #[unsafe(no_mangle)]
@@ -2895,16 +3488,19 @@ unsafe extern "C" fn glFenceSync(condition: GLenum, flags: GLbitfield) -> GLsync
         condition,
         flags
     );
+    crate::trace::trace_call!(glFenceSync, condition, flags);
     with_ctx_mut(|mut state| state.oxidegl_fence_sync(condition, flags))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glFinish() {
     ::log::trace!("glFinish called, parameters:  ",);
+    crate::trace::trace_call!(glFinish,);
     with_ctx_mut(|mut state| state.oxidegl_finish())
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glFlush() {
     ::log::trace!("glFlush called, parameters:  ",);
+    crate::trace::trace_call!(glFlush,);
     with_ctx_mut(|mut state| state.oxidegl_flush())
 }
 #[unsafe(no_mangle)]
@@ -2919,6 +3515,7 @@ unsafe extern "C" fn glFlushMappedBufferRange(
         offset,
         length
     );
+    crate::trace::trace_call!(glFlushMappedBufferRange, target, offset, length);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_flush_mapped_buffer_range(
             target.try_into_enum()?,
@@ -2939,6 +3536,7 @@ unsafe extern "C" fn glFlushMappedNamedBufferRange(
         offset,
         length
     );
+    crate::trace::trace_call!(glFlushMappedNamedBufferRange, buffer, offset, length);
     with_ctx_mut(|mut state| state.oxidegl_flush_mapped_named_buffer_range(buffer, offset, length))
 }
 #[unsafe(no_mangle)]
@@ -2949,6 +3547,7 @@ unsafe extern "C" fn glFramebufferParameteri(target: GLenum, pname: GLenum, para
         pname,
         param
     );
+    crate::trace::trace_call!(glFramebufferParameteri, target, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_framebuffer_parameteri(
             target.try_into_enum()?,
@@ -2969,6 +3568,7 @@ unsafe extern "C" fn glNamedFramebufferParameteri(
         pname,
         param
     );
+    crate::trace::trace_call!(glNamedFramebufferParameteri, framebuffer, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_named_framebuffer_parameteri(
             framebuffer,
@@ -2991,6 +3591,13 @@ unsafe extern "C" fn glFramebufferRenderbuffer(
         renderbuffertarget,
         renderbuffer
     );
+    crate::trace::trace_call!(
+        glFramebufferRenderbuffer,
+        target,
+        attachment,
+        renderbuffertarget,
+        renderbuffer
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_framebuffer_renderbuffer(
             target.try_into_enum()?,
@@ -3014,6 +3621,13 @@ unsafe extern "C" fn glNamedFramebufferRenderbuffer(
         renderbuffertarget,
         renderbuffer
     );
+    crate::trace::trace_call!(
+        glNamedFramebufferRenderbuffer,
+        framebuffer,
+        attachment,
+        renderbuffertarget,
+        renderbuffer
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_named_framebuffer_renderbuffer(
             framebuffer,
@@ -3039,6 +3653,14 @@ unsafe extern "C" fn glFramebufferTexture1D(
         texture,
         level
     );
+    crate::trace::trace_call!(
+        glFramebufferTexture1D,
+        target,
+        attachment,
+        textarget,
+        texture,
+        level
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_framebuffer_texture1_d(
             target.try_into_enum()?,
@@ -3065,6 +3687,14 @@ unsafe extern "C" fn glFramebufferTexture2D(
         texture,
         level
     );
+    crate::trace::trace_call!(
+        glFramebufferTexture2D,
+        target,
+        attachment,
+        textarget,
+        texture,
+        level
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_framebuffer_texture2_d(
             target.try_into_enum()?,
@@ -3093,6 +3723,15 @@ unsafe extern "C" fn glFramebufferTexture3D(
         level,
         zoffset
     );
+    crate::trace::trace_call!(
+        glFramebufferTexture3D,
+        target,
+        attachment,
+        textarget,
+        texture,
+        level,
+        zoffset
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_framebuffer_texture3_d(
             target.try_into_enum()?,
@@ -3118,6 +3757,7 @@ unsafe extern "C" fn glFramebufferTexture(
         texture,
         level
     );
+    crate::trace::trace_call!(glFramebufferTexture, target, attachment, texture, level);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_framebuffer_texture(
             target.try_into_enum()?,
@@ -3141,6 +3781,13 @@ unsafe extern "C" fn glNamedFramebufferTexture(
         texture,
         level
     );
+    crate::trace::trace_call!(
+        glNamedFramebufferTexture,
+        framebuffer,
+        attachment,
+        texture,
+        level
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_named_framebuffer_texture(
             framebuffer,
@@ -3166,6 +3813,14 @@ unsafe extern "C" fn glFramebufferTextureLayer(
         level,
         layer
     );
+    crate::trace::trace_call!(
+        glFramebufferTextureLayer,
+        target,
+        attachment,
+        texture,
+        level,
+        layer
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_framebuffer_texture_layer(
             target.try_into_enum()?,
@@ -3192,6 +3847,14 @@ unsafe extern "C" fn glNamedFramebufferTextureLayer(
         level,
         layer
     );
+    crate::trace::trace_call!(
+        glNamedFramebufferTextureLayer,
+        framebuffer,
+        attachment,
+        texture,
+        level,
+        layer
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_named_framebuffer_texture_layer(
             framebuffer,
@@ -3205,6 +3868,7 @@ unsafe extern "C" fn glNamedFramebufferTextureLayer(
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glFrontFace(mode: GLenum) {
     ::log::trace!("glFrontFace called, parameters: mode: {:?} ", mode);
+    crate::trace::trace_call!(glFrontFace, mode);
     with_ctx_mut(|mut state| GlResult::normalize(state.oxidegl_front_face(mode.try_into_enum()?)))
 }
 #[unsafe(no_mangle)]
@@ -3214,11 +3878,13 @@ unsafe extern "C" fn glGenBuffers(n: GLsizei, buffers: *mut GLuint) {
         n,
         buffers
     );
+    crate::trace::trace_call!(glGenBuffers, n, buffers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_gen_buffers(n, buffers) })
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glGenerateMipmap(target: GLenum) {
     ::log::trace!("glGenerateMipmap called, parameters: target: {:?} ", target);
+    crate::trace::trace_call!(glGenerateMipmap, target);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_generate_mipmap(target.try_into_enum()?))
     })
@@ -3229,6 +3895,7 @@ unsafe extern "C" fn glGenerateTextureMipmap(texture: GLuint) {
         "glGenerateTextureMipmap called, parameters: texture: {:?} ",
         texture
     );
+    crate::trace::trace_call!(glGenerateTextureMipmap, texture);
     with_ctx_mut(|mut state| state.oxidegl_generate_texture_mipmap(texture))
 }
 #[unsafe(no_mangle)]
@@ -3238,6 +3905,7 @@ unsafe extern "C" fn glGenFramebuffers(n: GLsizei, framebuffers: *mut GLuint) {
         n,
         framebuffers
     );
+    crate::trace::trace_call!(glGenFramebuffers, n, framebuffers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_gen_framebuffers(n, framebuffers) })
 }
 #[unsafe(no_mangle)]
@@ -3247,6 +3915,7 @@ unsafe extern "C" fn glGenProgramPipelines(n: GLsizei, pipelines: *mut GLuint) {
         n,
         pipelines
     );
+    crate::trace::trace_call!(glGenProgramPipelines, n, pipelines);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_gen_program_pipelines(n, pipelines) })
 }
 #[unsafe(no_mangle)]
@@ -3256,6 +3925,7 @@ unsafe extern "C" fn glGenQueries(n: GLsizei, ids: *mut GLuint) {
         n,
         ids
     );
+    crate::trace::trace_call!(glGenQueries, n, ids);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_gen_queries(n, ids) })
 }
 #[unsafe(no_mangle)]
@@ -3265,6 +3935,7 @@ unsafe extern "C" fn glGenRenderbuffers(n: GLsizei, renderbuffers: *mut GLuint)
         n,
         renderbuffers
     );
+    crate::trace::trace_call!(glGenRenderbuffers, n, renderbuffers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_gen_renderbuffers(n, renderbuffers) })
 }
 #[unsafe(no_mangle)]
@@ -3274,6 +3945,7 @@ unsafe extern "C" fn glGenSamplers(count: GLsizei, samplers: *mut GLuint) {
         count,
         samplers
     );
+    crate::trace::trace_call!(glGenSamplers, count, samplers);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_gen_samplers(count, samplers) })
 }
 #[unsafe(no_mangle)]
@@ -3283,6 +3955,7 @@ unsafe extern "C" fn glGenTextures(n: GLsizei, textures: *mut GLuint) {
         n,
         textures
     );
+    crate::trace::trace_call!(glGenTextures, n, textures);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_gen_textures(n, textures) })
 }
 #[unsafe(no_mangle)]
@@ -3292,6 +3965,7 @@ unsafe extern "C" fn glGenTransformFeedbacks(n: GLsizei, ids: *mut GLuint) {
         n,
         ids
     );
+    crate::trace::trace_call!(glGenTransformFeedbacks, n, ids);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_gen_transform_feedbacks(n, ids) })
 }
 #[unsafe(no_mangle)]
@@ -3301,6 +3975,7 @@ unsafe extern "C" fn glGenVertexArrays(n: GLsizei, arrays: *mut GLuint) {
         n,
         arrays
     );
+    crate::trace::trace_call!(glGenVertexArrays, n, arrays);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_gen_vertex_arrays(n, arrays) })
 }
 #[unsafe(no_mangle)]
@@ -3310,6 +3985,7 @@ unsafe extern "C" fn glGetBooleanv(pname: GLenum, data: *mut GLboolean) {
         pname,
         data
     );
+    crate::trace::trace_call!(glGetBooleanv, pname, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe { state.oxidegl_get_booleanv(pname.try_into_enum()?, data) })
     })
@@ -3321,6 +3997,7 @@ unsafe extern "C" fn glGetDoublev(pname: GLenum, data: *mut GLdouble) {
         pname,
         data
     );
+    crate::trace::trace_call!(glGetDoublev, pname, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe { state.oxidegl_get_doublev(pname.try_into_enum()?, data) })
     })
@@ -3332,6 +4009,7 @@ unsafe extern "C" fn glGetFloatv(pname: GLenum, data: *mut GLfloat) {
         pname,
         data
     );
+    crate::trace::trace_call!(glGetFloatv, pname, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe { state.oxidegl_get_floatv(pname.try_into_enum()?, data) })
     })
@@ -3343,6 +4021,7 @@ unsafe extern "C" fn glGetIntegerv(pname: GLenum, data: *mut GLint) {
         pname,
         data
     );
+    crate::trace::trace_call!(glGetIntegerv, pname, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe { state.oxidegl_get_integerv(pname.try_into_enum()?, data) })
     })
@@ -3355,6 +4034,7 @@ unsafe extern "C" fn glGetBooleani_v(target: GLenum, index: GLuint, data: *mut G
         index,
         data
     );
+    crate::trace::trace_call!(glGetBooleani_v, target, index, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_booleani_v(target.try_into_enum()?, index, data)
@@ -3369,6 +4049,7 @@ unsafe extern "C" fn glGetIntegeri_v(target: GLenum, index: GLuint, data: *mut G
         index,
         data
     );
+    crate::trace::trace_call!(glGetIntegeri_v, target, index, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_integeri_v(target.try_into_enum()?, index, data)
@@ -3382,6 +4063,7 @@ unsafe extern "C" fn glGetInteger64v(pname: GLenum, data: *mut GLint64) {
         pname,
         data
     );
+    crate::trace::trace_call!(glGetInteger64v, pname, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe { state.oxidegl_get_integer64v(pname.try_into_enum()?, data) })
     })
@@ -3394,6 +4076,7 @@ unsafe extern "C" fn glGetInteger64i_v(target: GLenum, index: GLuint, data: *mut
         index,
         data
     );
+    crate::trace::trace_call!(glGetInteger64i_v, target, index, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_integer64i_v(target.try_into_enum()?, index, data)
@@ -3408,6 +4091,7 @@ unsafe extern "C" fn glGetFloati_v(target: GLenum, index: GLuint, data: *mut GLf
         index,
         data
     );
+    crate::trace::trace_call!(glGetFloati_v, target, index, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_floati_v(target.try_into_enum()?, index, data)
@@ -3422,6 +4106,7 @@ unsafe extern "C" fn glGetDoublei_v(target: GLenum, index: GLuint, data: *mut GL
         index,
         data
     );
+    crate::trace::trace_call!(glGetDoublei_v, target, index, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_doublei_v(target.try_into_enum()?, index, data)
@@ -3442,6 +4127,13 @@ unsafe extern "C" fn glGetActiveAtomicCounterBufferiv(
         pname,
         params
     );
+    crate::trace::trace_call!(
+        glGetActiveAtomicCounterBufferiv,
+        program,
+        bufferIndex,
+        pname,
+        params
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_active_atomic_counter_bufferiv(
@@ -3473,6 +4165,16 @@ unsafe extern "C" fn glGetActiveAttrib(
         r#type,
         name
     );
+    crate::trace::trace_call!(
+        glGetActiveAttrib,
+        program,
+        index,
+        bufSize,
+        length,
+        size,
+        r#type,
+        name
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_active_attrib(program, index, bufSize, length, size, r#type.cast(), name)
     })
@@ -3495,6 +4197,15 @@ unsafe extern "C" fn glGetActiveSubroutineName(
         length,
         name
     );
+    crate::trace::trace_call!(
+        glGetActiveSubroutineName,
+        program,
+        shadertype,
+        index,
+        bufSize,
+        length,
+        name
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_active_subroutine_name(
@@ -3524,6 +4235,14 @@ unsafe extern "C" fn glGetActiveSubroutineUniformiv(
         pname,
         values
     );
+    crate::trace::trace_call!(
+        glGetActiveSubroutineUniformiv,
+        program,
+        shadertype,
+        index,
+        pname,
+        values
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_active_subroutine_uniformiv(
@@ -3554,6 +4273,15 @@ unsafe extern "C" fn glGetActiveSubroutineUniformName(
         length,
         name
     );
+    crate::trace::trace_call!(
+        glGetActiveSubroutineUniformName,
+        program,
+        shadertype,
+        index,
+        bufSize,
+        length,
+        name
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_active_subroutine_uniform_name(
@@ -3587,6 +4315,16 @@ unsafe extern "C" fn glGetActiveUniform(
         r#type,
         name
     );
+    crate::trace::trace_call!(
+        glGetActiveUniform,
+        program,
+        index,
+        bufSize,
+        length,
+        size,
+        r#type,
+        name
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_active_uniform(program, index, bufSize, length, size, r#type.cast(), name)
     })
@@ -3605,6 +4343,13 @@ unsafe extern "C" fn glGetActiveUniformBlockiv(
         pname,
         params
     );
+    crate::trace::trace_call!(
+        glGetActiveUniformBlockiv,
+        program,
+        uniformBlockIndex,
+        pname,
+        params
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_active_uniform_blockiv(
@@ -3632,6 +4377,14 @@ unsafe extern "C" fn glGetActiveUniformBlockName(
         length,
         uniformBlockName
     );
+    crate::trace::trace_call!(
+        glGetActiveUniformBlockName,
+        program,
+        uniformBlockIndex,
+        bufSize,
+        length,
+        uniformBlockName
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_active_uniform_block_name(
             program,
@@ -3658,6 +4411,14 @@ unsafe extern "C" fn glGetActiveUniformName(
         length,
         uniformName
     );
+    crate::trace::trace_call!(
+        glGetActiveUniformName,
+        program,
+        uniformIndex,
+        bufSize,
+        length,
+        uniformName
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_active_uniform_name(program, uniformIndex, bufSize, length, uniformName)
     })
@@ -3678,6 +4439,14 @@ unsafe extern "C" fn glGetActiveUniformsiv(
         pname,
         params
     );
+    crate::trace::trace_call!(
+        glGetActiveUniformsiv,
+        program,
+        uniformCount,
+        uniformIndices,
+        pname,
+        params
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_active_uniformsiv(
@@ -3704,6 +4473,7 @@ unsafe extern "C" fn glGetAttachedShaders(
         count,
         shaders
     );
+    crate::trace::trace_call!(glGetAttachedShaders, program, maxCount, count, shaders);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_attached_shaders(program, maxCount, count, shaders)
     })
@@ -3715,6 +4485,7 @@ unsafe extern "C" fn glGetAttribLocation(program: GLuint, name: *const GLchar) -
         program,
         name
     );
+    crate::trace::trace_call!(glGetAttribLocation, program, name);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_get_attrib_location(program, name) })
 }
 #[unsafe(no_mangle)]
@@ -3725,6 +4496,7 @@ unsafe extern "C" fn glGetBufferParameteriv(target: GLenum, pname: GLenum, param
         pname,
         params
     );
+    crate::trace::trace_call!(glGetBufferParameteriv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_buffer_parameteriv(
@@ -3743,6 +4515,7 @@ unsafe extern "C" fn glGetBufferParameteri64v(target: GLenum, pname: GLenum, par
         pname,
         params
     );
+    crate::trace::trace_call!(glGetBufferParameteri64v, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_buffer_parameteri64v(
@@ -3765,6 +4538,7 @@ unsafe extern "C" fn glGetNamedBufferParameteriv(
         pname,
         params
     );
+    crate::trace::trace_call!(glGetNamedBufferParameteriv, buffer, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_named_buffer_parameteriv(buffer, pname.try_into_enum()?, params)
@@ -3783,6 +4557,7 @@ unsafe extern "C" fn glGetNamedBufferParameteri64v(
         pname,
         params
     );
+    crate::trace::trace_call!(glGetNamedBufferParameteri64v, buffer, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_named_buffer_parameteri64v(buffer, pname.try_into_enum()?, params)
@@ -3797,6 +4572,7 @@ unsafe extern "C" fn glGetBufferPointerv(target: GLenum, pname: GLenum, params:
         pname,
         params
     );
+    crate::trace::trace_call!(glGetBufferPointerv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_buffer_pointerv(target.try_into_enum()?, pname, params)
@@ -3815,6 +4591,7 @@ unsafe extern "C" fn glGetNamedBufferPointerv(
         pname,
         params
     );
+    crate::trace::trace_call!(glGetNamedBufferPointerv, buffer, pname, params);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_named_buffer_pointerv(buffer, pname, params)
     })
@@ -3833,6 +4610,7 @@ unsafe extern "C" fn glGetBufferSubData(
         size,
         data
     );
+    crate::trace::trace_call!(glGetBufferSubData, target, offset, size, data);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_buffer_sub_data(target.try_into_enum()?, offset, size, data)
@@ -3853,6 +4631,7 @@ unsafe extern "C" fn glGetNamedBufferSubData(
         size,
         data
     );
+    crate::trace::trace_call!(glGetNamedBufferSubData, buffer, offset, size, data);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_named_buffer_sub_data(buffer, offset, size, data)
     })
@@ -3865,6 +4644,7 @@ unsafe extern "C" fn glGetCompressedTexImage(target: GLenum, level: GLint, img:
         level,
         img
     );
+    crate::trace::trace_call!(glGetCompressedTexImage, target, level, img);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_compressed_tex_image(target.try_into_enum()?, level, img)
@@ -3885,6 +4665,7 @@ unsafe extern "C" fn glGetCompressedTextureImage(
         bufSize,
         pixels
     );
+    crate::trace::trace_call!(glGetCompressedTextureImage, texture, level, bufSize, pixels);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_compressed_texture_image(texture, level, bufSize, pixels)
     })
@@ -3903,6 +4684,7 @@ unsafe extern "C" fn glGetnCompressedTexImage(
         bufSize,
         pixels
     );
+    crate::trace::trace_call!(glGetnCompressedTexImage, target, lod, bufSize, pixels);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_getn_compressed_tex_image(target.try_into_enum()?, lod, bufSize, pixels)
@@ -3935,6 +4717,19 @@ unsafe extern "C" fn glGetCompressedTextureSubImage(
         bufSize,
         pixels
     );
+    crate::trace::trace_call!(
+        glGetCompressedTextureSubImage,
+        texture,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        width,
+        height,
+        depth,
+        bufSize,
+        pixels
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_compressed_texture_sub_image(
             texture, level, xoffset, yoffset, zoffset, width, height, depth, bufSize, pixels,
@@ -3963,6 +4758,17 @@ unsafe extern "C" fn glGetDebugMessageLog(
         lengths,
         messageLog
     );
+    crate::trace::trace_call!(
+        glGetDebugMessageLog,
+        count,
+        bufSize,
+        sources,
+        types,
+        ids,
+        severities,
+        lengths,
+        messageLog
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_debug_message_log(
             count,
@@ -3979,6 +4785,7 @@ unsafe extern "C" fn glGetDebugMessageLog(
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glGetError() -> GLenum {
     ::log::trace!("glGetError called, parameters:  ",);
+    crate::trace::trace_call!(glGetError,);
     with_ctx_mut(|mut state| state.oxidegl_get_error())
 }
 #[unsafe(no_mangle)]
@@ -3988,6 +4795,7 @@ unsafe extern "C" fn glGetFragDataIndex(program: GLuint, name: *const GLchar) ->
         program,
         name
     );
+    crate::trace::trace_call!(glGetFragDataIndex, program, name);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_get_frag_data_index(program, name) })
 }
 #[unsafe(no_mangle)]
@@ -3997,6 +4805,7 @@ unsafe extern "C" fn glGetFragDataLocation(program: GLuint, name: *const GLchar)
         program,
         name
     );
+    crate::trace::trace_call!(glGetFragDataLocation, program, name);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_get_frag_data_location(program, name) })
 }
 #[unsafe(no_mangle)]
@@ -4013,6 +4822,13 @@ unsafe extern "C" fn glGetFramebufferAttachmentParameteriv(
         pname,
         params
     );
+    crate::trace::trace_call!(
+        glGetFramebufferAttachmentParameteriv,
+        target,
+        attachment,
+        pname,
+        params
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_framebuffer_attachment_parameteriv(
@@ -4038,6 +4854,13 @@ unsafe extern "C" fn glGetNamedFramebufferAttachmentParameteriv(
         pname,
         params
     );
+    crate::trace::trace_call!(
+        glGetNamedFramebufferAttachmentParameteriv,
+        framebuffer,
+        attachment,
+        pname,
+        params
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_named_framebuffer_attachment_parameteriv(
@@ -4061,6 +4884,7 @@ unsafe extern "C" fn glGetFramebufferParameteriv(
         pname,
         params
     );
+    crate::trace::trace_call!(glGetFramebufferParameteriv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_framebuffer_parameteriv(
@@ -4083,6 +4907,7 @@ unsafe extern "C" fn glGetNamedFramebufferParameteriv(
         pname,
         param
     );
+    crate::trace::trace_call!(glGetNamedFramebufferParameteriv, framebuffer, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_named_framebuffer_parameteriv(
@@ -4096,6 +4921,7 @@ unsafe extern "C" fn glGetNamedFramebufferParameteriv(
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glGetGraphicsResetStatus() -> GLenum {
     ::log::trace!("glGetGraphicsResetStatus called, parameters:  ",);
+    crate::trace::trace_call!(glGetGraphicsResetStatus,);
     with_ctx_mut(|mut state| state.oxidegl_get_graphics_reset_status())
 }
 #[unsafe(no_mangle)]
@@ -4114,6 +4940,14 @@ unsafe extern "C" fn glGetInternalformativ(
         count,
         params
     );
+    crate::trace::trace_call!(
+        glGetInternalformativ,
+        target,
+        internalformat,
+        pname,
+        count,
+        params
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_internalformativ(
@@ -4142,6 +4976,14 @@ unsafe extern "C" fn glGetInternalformati64v(
         count,
         params
     );
+    crate::trace::trace_call!(
+        glGetInternalformati64v,
+        target,
+        internalformat,
+        pname,
+        count,
+        params
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_internalformati64v(
@@ -4162,6 +5004,7 @@ unsafe extern "C" fn glGetMultisamplefv(pname: GLenum, index: GLuint, val: *mut
         index,
         val
     );
+    crate::trace::trace_call!(glGetMultisamplefv, pname, index, val);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_get_multisamplefv(pname, index, val) })
 }
 #[unsafe(no_mangle)]
@@ -4180,6 +5023,7 @@ unsafe extern "C" fn glGetObjectLabel(
         length,
         label
     );
+    crate::trace::trace_call!(glGetObjectLabel, identifier, name, bufSize, length, label);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_object_label(
@@ -4206,6 +5050,7 @@ unsafe extern "C" fn glGetObjectPtrLabel(
         length,
         label
     );
+    crate::trace::trace_call!(glGetObjectPtrLabel, ptr, bufSize, length, label);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_object_ptr_label(ptr, bufSize, length, label)
     })
@@ -4217,6 +5062,7 @@ unsafe extern "C" fn glGetPointerv(pname: GLenum, params: *mut *mut GLvoid) {
         pname,
         params
     );
+    crate::trace::trace_call!(glGetPointerv, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe { state.oxidegl_get_pointerv(pname.try_into_enum()?, params) })
     })
@@ -4229,6 +5075,7 @@ unsafe extern "C" fn glGetProgramiv(program: GLuint, pname: GLenum, params: *mut
         pname,
         params
     );
+    crate::trace::trace_call!(glGetProgramiv, program, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_programiv(program, pname.try_into_enum()?, params)
@@ -4251,6 +5098,14 @@ unsafe extern "C" fn glGetProgramBinary(
         binaryFormat,
         binary
     );
+    crate::trace::trace_call!(
+        glGetProgramBinary,
+        program,
+        bufSize,
+        length,
+        binaryFormat,
+        binary
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_program_binary(program, bufSize, length, binaryFormat, binary)
     })
@@ -4269,6 +5124,7 @@ unsafe extern "C" fn glGetProgramInfoLog(
         length,
         infoLog
     );
+    crate::trace::trace_call!(glGetProgramInfoLog, program, bufSize, length, infoLog);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_program_info_log(program, bufSize, length, infoLog)
     })
@@ -4287,6 +5143,13 @@ unsafe extern "C" fn glGetProgramInterfaceiv(
         pname,
         params
     );
+    crate::trace::trace_call!(
+        glGetProgramInterfaceiv,
+        program,
+        programInterface,
+        pname,
+        params
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_program_interfaceiv(
@@ -4306,6 +5169,7 @@ unsafe extern "C" fn glGetProgramPipelineiv(pipeline: GLuint, pname: GLenum, par
         pname,
         params
     );
+    crate::trace::trace_call!(glGetProgramPipelineiv, pipeline, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_program_pipelineiv(pipeline, pname.try_into_enum()?, params)
@@ -4326,6 +5190,13 @@ unsafe extern "C" fn glGetProgramPipelineInfoLog(
         length,
         infoLog
     );
+    crate::trace::trace_call!(
+        glGetProgramPipelineInfoLog,
+        pipeline,
+        bufSize,
+        length,
+        infoLog
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_program_pipeline_info_log(pipeline, bufSize, length, infoLog)
     })
@@ -4352,6 +5223,17 @@ unsafe extern "C" fn glGetProgramResourceiv(
         length,
         params
     );
+    crate::trace::trace_call!(
+        glGetProgramResourceiv,
+        program,
+        programInterface,
+        index,
+        propCount,
+        props,
+        count,
+        length,
+        params
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_program_resourceiv(
@@ -4379,6 +5261,7 @@ unsafe extern "C" fn glGetProgramResourceIndex(
         programInterface,
         name
     );
+    crate::trace::trace_call!(glGetProgramResourceIndex, program, programInterface, name);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_program_resource_index(
@@ -4401,6 +5284,12 @@ unsafe extern "C" fn glGetProgramResourceLocation(
         programInterface,
         name
     );
+    crate::trace::trace_call!(
+        glGetProgramResourceLocation,
+        program,
+        programInterface,
+        name
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_program_resource_location(
@@ -4423,6 +5312,12 @@ unsafe extern "C" fn glGetProgramResourceLocationIndex(
         programInterface,
         name
     );
+    crate::trace::trace_call!(
+        glGetProgramResourceLocationIndex,
+        program,
+        programInterface,
+        name
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_program_resource_location_index(
@@ -4451,6 +5346,15 @@ unsafe extern "C" fn glGetProgramResourceName(
         length,
         name
     );
+    crate::trace::trace_call!(
+        glGetProgramResourceName,
+        program,
+        programInterface,
+        index,
+        bufSize,
+        length,
+        name
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_program_resource_name(
@@ -4478,6 +5382,7 @@ unsafe extern "C" fn glGetProgramStageiv(
         pname,
         values
     );
+    crate::trace::trace_call!(glGetProgramStageiv, program, shadertype, pname, values);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_program_stageiv(
@@ -4503,6 +5408,7 @@ unsafe extern "C" fn glGetQueryIndexediv(
         pname,
         params
     );
+    crate::trace::trace_call!(glGetQueryIndexediv, target, index, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_query_indexediv(
@@ -4522,6 +5428,7 @@ unsafe extern "C" fn glGetQueryiv(target: GLenum, pname: GLenum, params: *mut GL
         pname,
         params
     );
+    crate::trace::trace_call!(glGetQueryiv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_queryiv(target.try_into_enum()?, pname.try_into_enum()?, params)
@@ -4536,6 +5443,7 @@ unsafe extern "C" fn glGetQueryObjectiv(id: GLuint, pname: GLenum, params: *mut
         pname,
         params
     );
+    crate::trace::trace_call!(glGetQueryObjectiv, id, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_query_objectiv(id, pname.try_into_enum()?, params)
@@ -4550,6 +5458,7 @@ unsafe extern "C" fn glGetQueryObjectuiv(id: GLuint, pname: GLenum, params: *mut
         pname,
         params
     );
+    crate::trace::trace_call!(glGetQueryObjectuiv, id, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_query_objectuiv(id, pname.try_into_enum()?, params)
@@ -4564,6 +5473,7 @@ unsafe extern "C" fn glGetQueryObjecti64v(id: GLuint, pname: GLenum, params: *mu
         pname,
         params
     );
+    crate::trace::trace_call!(glGetQueryObjecti64v, id, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_query_objecti64v(id, pname.try_into_enum()?, params)
@@ -4578,6 +5488,7 @@ unsafe extern "C" fn glGetQueryObjectui64v(id: GLuint, pname: GLenum, params: *m
         pname,
         params
     );
+    crate::trace::trace_call!(glGetQueryObjectui64v, id, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_query_objectui64v(id, pname.try_into_enum()?, params)
@@ -4598,6 +5509,7 @@ unsafe extern "C" fn glGetQueryBufferObjecti64v(
         pname,
         offset
     );
+    crate::trace::trace_call!(glGetQueryBufferObjecti64v, id, buffer, pname, offset);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_get_query_buffer_objecti64v(
             id,
@@ -4621,6 +5533,7 @@ unsafe extern "C" fn glGetQueryBufferObjectiv(
         pname,
         offset
     );
+    crate::trace::trace_call!(glGetQueryBufferObjectiv, id, buffer, pname, offset);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_get_query_buffer_objectiv(
             id,
@@ -4644,6 +5557,7 @@ unsafe extern "C" fn glGetQueryBufferObjectui64v(
         pname,
         offset
     );
+    crate::trace::trace_call!(glGetQueryBufferObjectui64v, id, buffer, pname, offset);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_get_query_buffer_objectui64v(
             id,
@@ -4667,6 +5581,7 @@ unsafe extern "C" fn glGetQueryBufferObjectuiv(
         pname,
         offset
     );
+    crate::trace::trace_call!(glGetQueryBufferObjectuiv, id, buffer, pname, offset);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_get_query_buffer_objectuiv(
             id,
@@ -4688,6 +5603,7 @@ unsafe extern "C" fn glGetRenderbufferParameteriv(
         pname,
         params
     );
+    crate::trace::trace_call!(glGetRenderbufferParameteriv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_renderbuffer_parameteriv(target, pname.try_into_enum()?, params)
@@ -4706,6 +5622,12 @@ unsafe extern "C" fn glGetNamedRenderbufferParameteriv(
         pname,
         params
     );
+    crate::trace::trace_call!(
+        glGetNamedRenderbufferParameteriv,
+        renderbuffer,
+        pname,
+        params
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_named_renderbuffer_parameteriv(
@@ -4724,6 +5646,7 @@ unsafe extern "C" fn glGetSamplerParameteriv(sampler: GLuint, pname: GLenum, par
         pname,
         params
     );
+    crate::trace::trace_call!(glGetSamplerParameteriv, sampler, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_sampler_parameteriv(sampler, pname.try_into_enum()?, params)
@@ -4738,6 +5661,7 @@ unsafe extern "C" fn glGetSamplerParameterIiv(sampler: GLuint, pname: GLenum, pa
         pname,
         params
     );
+    crate::trace::trace_call!(glGetSamplerParameterIiv, sampler, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_sampler_parameter_iiv(sampler, pname.try_into_enum()?, params)
@@ -4752,6 +5676,7 @@ unsafe extern "C" fn glGetSamplerParameterfv(sampler: GLuint, pname: GLenum, par
         pname,
         params
     );
+    crate::trace::trace_call!(glGetSamplerParameterfv, sampler, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_sampler_parameterfv(sampler, pname.try_into_enum()?, params)
@@ -4770,6 +5695,7 @@ unsafe extern "C" fn glGetSamplerParameterIuiv(
         pname,
         params
     );
+    crate::trace::trace_call!(glGetSamplerParameterIuiv, sampler, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_sampler_parameter_iuiv(sampler, pname.try_into_enum()?, params)
@@ -4784,6 +5710,7 @@ unsafe extern "C" fn glGetShaderiv(shader: GLuint, pname: GLenum, params: *mut G
         pname,
         params
     );
+    crate::trace::trace_call!(glGetShaderiv, shader, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_shaderiv(shader, pname.try_into_enum()?, params)
@@ -4804,6 +5731,7 @@ unsafe extern "C" fn glGetShaderInfoLog(
         length,
         infoLog
     );
+    crate::trace::trace_call!(glGetShaderInfoLog, shader, bufSize, length, infoLog);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_shader_info_log(shader, bufSize, length, infoLog)
     })
@@ -4822,6 +5750,13 @@ unsafe extern "C" fn glGetShaderPrecisionFormat(
         range,
         precision
     );
+    crate::trace::trace_call!(
+        glGetShaderPrecisionFormat,
+        shadertype,
+        precisiontype,
+        range,
+        precision
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_shader_precision_format(
@@ -4847,6 +5782,7 @@ unsafe extern "C" fn glGetShaderSource(
         length,
         source
     );
+    crate::trace::trace_call!(glGetShaderSource, shader, bufSize, length, source);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_shader_source(shader, bufSize, length, source)
     })
@@ -4854,6 +5790,7 @@ unsafe extern "C" fn glGetShaderSource(
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glGetString(name: GLenum) -> *const GLubyte {
     ::log::trace!("glGetString called, parameters: name: {:?} ", name);
+    crate::trace::trace_call!(glGetString, name);
     with_ctx_mut(|mut state| GlResult::normalize(state.oxidegl_get_string(name.try_into_enum()?)))
 }
 #[unsafe(no_mangle)]
@@ -4863,6 +5800,7 @@ unsafe extern "C" fn glGetStringi(name: GLenum, index: GLuint) -> *const GLubyte
         name,
         index
     );
+    crate::trace::trace_call!(glGetStringi, name, index);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_get_stringi(name.try_into_enum()?, index))
     })
@@ -4879,6 +5817,7 @@ unsafe extern "C" fn glGetSubroutineIndex(
         shadertype,
         name
     );
+    crate::trace::trace_call!(glGetSubroutineIndex, program, shadertype, name);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_subroutine_index(program, shadertype.try_into_enum()?, name)
@@ -4897,6 +5836,7 @@ unsafe extern "C" fn glGetSubroutineUniformLocation(
         shadertype,
         name
     );
+    crate::trace::trace_call!(glGetSubroutineUniformLocation, program, shadertype, name);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_subroutine_uniform_location(
@@ -4923,6 +5863,7 @@ unsafe extern "C" fn glGetSynciv(
         length,
         values
     );
+    crate::trace::trace_call!(glGetSynciv, sync, pname, count, length, values);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_synciv(sync, pname.try_into_enum()?, count, length, values)
@@ -4945,6 +5886,7 @@ unsafe extern "C" fn glGetTexImage(
         r#type,
         pixels
     );
+    crate::trace::trace_call!(glGetTexImage, target, level, format, r#type, pixels);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_tex_image(
@@ -4975,6 +5917,15 @@ unsafe extern "C" fn glGetTextureImage(
         bufSize,
         pixels
     );
+    crate::trace::trace_call!(
+        glGetTextureImage,
+        texture,
+        level,
+        format,
+        r#type,
+        bufSize,
+        pixels
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_texture_image(
@@ -5006,6 +5957,15 @@ unsafe extern "C" fn glGetnTexImage(
         bufSize,
         pixels
     );
+    crate::trace::trace_call!(
+        glGetnTexImage,
+        target,
+        level,
+        format,
+        r#type,
+        bufSize,
+        pixels
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_getn_tex_image(
@@ -5033,6 +5993,7 @@ unsafe extern "C" fn glGetTexLevelParameterfv(
         pname,
         params
     );
+    crate::trace::trace_call!(glGetTexLevelParameterfv, target, level, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_tex_level_parameterfv(
@@ -5058,6 +6019,7 @@ unsafe extern "C" fn glGetTexLevelParameteriv(
         pname,
         params
     );
+    crate::trace::trace_call!(glGetTexLevelParameteriv, target, level, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_tex_level_parameteriv(
@@ -5083,6 +6045,7 @@ unsafe extern "C" fn glGetTextureLevelParameterfv(
         pname,
         params
     );
+    crate::trace::trace_call!(glGetTextureLevelParameterfv, texture, level, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_texture_level_parameterfv(
@@ -5108,6 +6071,7 @@ unsafe extern "C" fn glGetTextureLevelParameteriv(
         pname,
         params
     );
+    crate::trace::trace_call!(glGetTextureLevelParameteriv, texture, level, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_texture_level_parameteriv(
@@ -5127,6 +6091,7 @@ unsafe extern "C" fn glGetTexParameterfv(target: GLenum, pname: GLenum, params:
         pname,
         params
     );
+    crate::trace::trace_call!(glGetTexParameterfv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_tex_parameterfv(
@@ -5145,6 +6110,7 @@ unsafe extern "C" fn glGetTexParameteriv(target: GLenum, pname: GLenum, params:
         pname,
         params
     );
+    crate::trace::trace_call!(glGetTexParameteriv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_tex_parameteriv(
@@ -5163,6 +6129,7 @@ unsafe extern "C" fn glGetTexParameterIiv(target: GLenum, pname: GLenum, params:
         pname,
         params
     );
+    crate::trace::trace_call!(glGetTexParameterIiv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_tex_parameter_iiv(
@@ -5181,6 +6148,7 @@ unsafe extern "C" fn glGetTexParameterIuiv(target: GLenum, pname: GLenum, params
         pname,
         params
     );
+    crate::trace::trace_call!(glGetTexParameterIuiv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_tex_parameter_iuiv(
@@ -5199,6 +6167,7 @@ unsafe extern "C" fn glGetTextureParameterfv(texture: GLuint, pname: GLenum, par
         pname,
         params
     );
+    crate::trace::trace_call!(glGetTextureParameterfv, texture, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_texture_parameterfv(texture, pname.try_into_enum()?, params)
@@ -5213,6 +6182,7 @@ unsafe extern "C" fn glGetTextureParameterIiv(texture: GLuint, pname: GLenum, pa
         pname,
         params
     );
+    crate::trace::trace_call!(glGetTextureParameterIiv, texture, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_texture_parameter_iiv(texture, pname.try_into_enum()?, params)
@@ -5231,6 +6201,7 @@ unsafe extern "C" fn glGetTextureParameterIuiv(
         pname,
         params
     );
+    crate::trace::trace_call!(glGetTextureParameterIuiv, texture, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_texture_parameter_iuiv(texture, pname.try_into_enum()?, params)
@@ -5245,6 +6216,7 @@ unsafe extern "C" fn glGetTextureParameteriv(texture: GLuint, pname: GLenum, par
         pname,
         params
     );
+    crate::trace::trace_call!(glGetTextureParameteriv, texture, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_texture_parameteriv(texture, pname.try_into_enum()?, params)
@@ -5281,6 +6253,21 @@ unsafe extern "C" fn glGetTextureSubImage(
         bufSize,
         pixels
     );
+    crate::trace::trace_call!(
+        glGetTextureSubImage,
+        texture,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        width,
+        height,
+        depth,
+        format,
+        r#type,
+        bufSize,
+        pixels
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_texture_sub_image(
@@ -5308,6 +6295,7 @@ unsafe extern "C" fn glGetTransformFeedbackiv(xfb: GLuint, pname: GLenum, param:
         pname,
         param
     );
+    crate::trace::trace_call!(glGetTransformFeedbackiv, xfb, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_transform_feedbackiv(xfb, pname.try_into_enum()?, param)
@@ -5328,6 +6316,7 @@ unsafe extern "C" fn glGetTransformFeedbacki_v(
         index,
         param
     );
+    crate::trace::trace_call!(glGetTransformFeedbacki_v, xfb, pname, index, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_transform_feedbacki_v(xfb, pname.try_into_enum()?, index, param)
@@ -5348,6 +6337,7 @@ unsafe extern "C" fn glGetTransformFeedbacki64_v(
         index,
         param
     );
+    crate::trace::trace_call!(glGetTransformFeedbacki64_v, xfb, pname, index, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_transform_feedbacki64_v(xfb, pname.try_into_enum()?, index, param)
@@ -5374,6 +6364,16 @@ unsafe extern "C" fn glGetTransformFeedbackVarying(
         r#type,
         name
     );
+    crate::trace::trace_call!(
+        glGetTransformFeedbackVarying,
+        program,
+        index,
+        bufSize,
+        length,
+        size,
+        r#type,
+        name
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_transform_feedback_varying(
             program,
@@ -5394,6 +6394,7 @@ unsafe extern "C" fn glGetUniformfv(program: GLuint, location: GLint, params: *m
         location,
         params
     );
+    crate::trace::trace_call!(glGetUniformfv, program, location, params);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_get_uniformfv(program, location, params) })
 }
 #[unsafe(no_mangle)]
@@ -5404,6 +6405,7 @@ unsafe extern "C" fn glGetUniformiv(program: GLuint, location: GLint, params: *m
         location,
         params
     );
+    crate::trace::trace_call!(glGetUniformiv, program, location, params);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_get_uniformiv(program, location, params) })
 }
 #[unsafe(no_mangle)]
@@ -5414,6 +6416,7 @@ unsafe extern "C" fn glGetUniformuiv(program: GLuint, location: GLint, params: *
         location,
         params
     );
+    crate::trace::trace_call!(glGetUniformuiv, program, location, params);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_get_uniformuiv(program, location, params) })
 }
 #[unsafe(no_mangle)]
@@ -5424,6 +6427,7 @@ unsafe extern "C" fn glGetUniformdv(program: GLuint, location: GLint, params: *m
         location,
         params
     );
+    crate::trace::trace_call!(glGetUniformdv, program, location, params);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_get_uniformdv(program, location, params) })
 }
 #[unsafe(no_mangle)]
@@ -5440,6 +6444,7 @@ unsafe extern "C" fn glGetnUniformdv(
         bufSize,
         params
     );
+    crate::trace::trace_call!(glGetnUniformdv, program, location, bufSize, params);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_getn_uniformdv(program, location, bufSize, params)
     })
@@ -5458,6 +6463,7 @@ unsafe extern "C" fn glGetnUniformfv(
         bufSize,
         params
     );
+    crate::trace::trace_call!(glGetnUniformfv, program, location, bufSize, params);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_getn_uniformfv(program, location, bufSize, params)
     })
@@ -5476,6 +6482,7 @@ unsafe extern "C" fn glGetnUniformiv(
         bufSize,
         params
     );
+    crate::trace::trace_call!(glGetnUniformiv, program, location, bufSize, params);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_getn_uniformiv(program, location, bufSize, params)
     })
@@ -5494,6 +6501,7 @@ unsafe extern "C" fn glGetnUniformuiv(
         bufSize,
         params
     );
+    crate::trace::trace_call!(glGetnUniformuiv, program, location, bufSize, params);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_getn_uniformuiv(program, location, bufSize, params)
     })
@@ -5508,6 +6516,7 @@ unsafe extern "C" fn glGetUniformBlockIndex(
         program,
         uniformBlockName
     );
+    crate::trace::trace_call!(glGetUniformBlockIndex, program, uniformBlockName);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_uniform_block_index(program, uniformBlockName)
     })
@@ -5526,6 +6535,13 @@ unsafe extern "C" fn glGetUniformIndices(
         uniformNames,
         uniformIndices
     );
+    crate::trace::trace_call!(
+        glGetUniformIndices,
+        program,
+        uniformCount,
+        uniformNames,
+        uniformIndices
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_uniform_indices(program, uniformCount, uniformNames, uniformIndices)
     })
@@ -5537,6 +6553,7 @@ unsafe extern "C" fn glGetUniformLocation(program: GLuint, name: *const GLchar)
         program,
         name
     );
+    crate::trace::trace_call!(glGetUniformLocation, program, name);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_get_uniform_location(program, name) })
 }
 #[unsafe(no_mangle)]
@@ -5551,6 +6568,7 @@ unsafe extern "C" fn glGetUniformSubroutineuiv(
         location,
         params
     );
+    crate::trace::trace_call!(glGetUniformSubroutineuiv, shadertype, location, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_uniform_subroutineuiv(shadertype.try_into_enum()?, location, params)
@@ -5571,6 +6589,7 @@ unsafe extern "C" fn glGetVertexArrayIndexediv(
         pname,
         param
     );
+    crate::trace::trace_call!(glGetVertexArrayIndexediv, vaobj, index, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_vertex_array_indexediv(vaobj, index, pname.try_into_enum()?, param)
@@ -5591,6 +6610,7 @@ unsafe extern "C" fn glGetVertexArrayIndexed64iv(
         pname,
         param
     );
+    crate::trace::trace_call!(glGetVertexArrayIndexed64iv, vaobj, index, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_vertex_array_indexed64iv(vaobj, index, pname.try_into_enum()?, param)
@@ -5605,6 +6625,7 @@ unsafe extern "C" fn glGetVertexArrayiv(vaobj: GLuint, pname: GLenum, param: *mu
         pname,
         param
     );
+    crate::trace::trace_call!(glGetVertexArrayiv, vaobj, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_vertex_arrayiv(vaobj, pname.try_into_enum()?, param)
@@ -5619,6 +6640,7 @@ unsafe extern "C" fn glGetVertexAttribdv(index: GLuint, pname: GLenum, params: *
         pname,
         params
     );
+    crate::trace::trace_call!(glGetVertexAttribdv, index, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_vertex_attribdv(index, pname.try_into_enum()?, params)
@@ -5633,6 +6655,7 @@ unsafe extern "C" fn glGetVertexAttribfv(index: GLuint, pname: GLenum, params: *
         pname,
         params
     );
+    crate::trace::trace_call!(glGetVertexAttribfv, index, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_vertex_attribfv(index, pname.try_into_enum()?, params)
@@ -5647,6 +6670,7 @@ unsafe extern "C" fn glGetVertexAttribiv(index: GLuint, pname: GLenum, params: *
         pname,
         params
     );
+    crate::trace::trace_call!(glGetVertexAttribiv, index, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_vertex_attribiv(index, pname.try_into_enum()?, params)
@@ -5661,6 +6685,7 @@ unsafe extern "C" fn glGetVertexAttribIiv(index: GLuint, pname: GLenum, params:
         pname,
         params
     );
+    crate::trace::trace_call!(glGetVertexAttribIiv, index, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_vertex_attrib_iiv(index, pname.try_into_enum()?, params)
@@ -5675,6 +6700,7 @@ unsafe extern "C" fn glGetVertexAttribIuiv(index: GLuint, pname: GLenum, params:
         pname,
         params
     );
+    crate::trace::trace_call!(glGetVertexAttribIuiv, index, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_vertex_attrib_iuiv(index, pname.try_into_enum()?, params)
@@ -5689,6 +6715,7 @@ unsafe extern "C" fn glGetVertexAttribLdv(index: GLuint, pname: GLenum, params:
         pname,
         params
     );
+    crate::trace::trace_call!(glGetVertexAttribLdv, index, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_get_vertex_attrib_ldv(index, pname.try_into_enum()?, params)
@@ -5707,6 +6734,7 @@ unsafe extern "C" fn glGetVertexAttribPointerv(
         pname,
         pointer
     );
+    crate::trace::trace_call!(glGetVertexAttribPointerv, index, pname, pointer);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_get_vertex_attrib_pointerv(index, pname, pointer)
     })
@@ -5718,6 +6746,7 @@ unsafe extern "C" fn glHint(target: GLenum, mode: GLenum) {
         target,
         mode
     );
+    crate::trace::trace_call!(glHint, target, mode);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_hint(target.try_into_enum()?, mode.try_into_enum()?))
     })
@@ -5728,6 +6757,7 @@ unsafe extern "C" fn glInvalidateBufferData(buffer: GLuint) {
         "glInvalidateBufferData called, parameters: buffer: {:?} ",
         buffer
     );
+    crate::trace::trace_call!(glInvalidateBufferData, buffer);
     with_ctx_mut(|mut state| state.oxidegl_invalidate_buffer_data(buffer))
 }
 #[unsafe(no_mangle)]
@@ -5742,6 +6772,7 @@ unsafe extern "C" fn glInvalidateBufferSubData(
         offset,
         length
     );
+    crate::trace::trace_call!(glInvalidateBufferSubData, buffer, offset, length);
     with_ctx_mut(|mut state| state.oxidegl_invalidate_buffer_sub_data(buffer, offset, length))
 }
 #[unsafe(no_mangle)]
@@ -5756,6 +6787,7 @@ unsafe extern "C" fn glInvalidateFramebuffer(
         numAttachments,
         attachments
     );
+    crate::trace::trace_call!(glInvalidateFramebuffer, target, numAttachments, attachments);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_invalidate_framebuffer(
@@ -5778,6 +6810,12 @@ unsafe extern "C" fn glInvalidateNamedFramebufferData(
         numAttachments,
         attachments
     );
+    crate::trace::trace_call!(
+        glInvalidateNamedFramebufferData,
+        framebuffer,
+        numAttachments,
+        attachments
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_invalidate_named_framebuffer_data(
             framebuffer,
@@ -5806,6 +6844,16 @@ unsafe extern "C" fn glInvalidateSubFramebuffer(
         width,
         height
     );
+    crate::trace::trace_call!(
+        glInvalidateSubFramebuffer,
+        target,
+        numAttachments,
+        attachments,
+        x,
+        y,
+        width,
+        height
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_invalidate_sub_framebuffer(
@@ -5840,6 +6888,16 @@ unsafe extern "C" fn glInvalidateNamedFramebufferSubData(
         width,
         height
     );
+    crate::trace::trace_call!(
+        glInvalidateNamedFramebufferSubData,
+        framebuffer,
+        numAttachments,
+        attachments,
+        x,
+        y,
+        width,
+        height
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_invalidate_named_framebuffer_sub_data(
             framebuffer,
@@ -5859,6 +6917,7 @@ unsafe extern "C" fn glInvalidateTexImage(texture: GLuint, level: GLint) {
         texture,
         level
     );
+    crate::trace::trace_call!(glInvalidateTexImage, texture, level);
     with_ctx_mut(|mut state| state.oxidegl_invalidate_tex_image(texture, level))
 }
 #[unsafe(no_mangle)]
@@ -5883,6 +6942,17 @@ unsafe extern "C" fn glInvalidateTexSubImage(
         height,
         depth
     );
+    crate::trace::trace_call!(
+        glInvalidateTexSubImage,
+        texture,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        width,
+        height,
+        depth
+    );
     with_ctx_mut(|mut state| {
         state.oxidegl_invalidate_tex_sub_image(
             texture, level, xoffset, yoffset, zoffset, width, height, depth,
@@ -5892,11 +6962,13 @@ unsafe extern "C" fn glInvalidateTexSubImage(
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glIsBuffer(buffer: GLuint) -> GLboolean {
     ::log::trace!("glIsBuffer called, parameters: buffer: {:?} ", buffer);
+    crate::trace::trace_call!(glIsBuffer, buffer);
     with_ctx_mut(|mut state| state.oxidegl_is_buffer(buffer))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glIsEnabled(cap: GLenum) -> GLboolean {
     ::log::trace!("glIsEnabled called, parameters: cap: {:?} ", cap);
+    crate::trace::trace_call!(glIsEnabled, cap);
     with_ctx_mut(|mut state| GlResult::normalize(state.oxidegl_is_enabled(cap.try_into_enum()?)))
 }
 #[unsafe(no_mangle)]
@@ -5906,6 +6978,7 @@ unsafe extern "C" fn glIsEnabledi(target: GLenum, index: GLuint) -> GLboolean {
         target,
         index
     );
+    crate::trace::trace_call!(glIsEnabledi, target, index);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_is_enabledi(target.try_into_enum()?, index))
     })
@@ -5916,11 +6989,13 @@ unsafe extern "C" fn glIsFramebuffer(framebuffer: GLuint) -> GLboolean {
         "glIsFramebuffer called, parameters: framebuffer: {:?} ",
         framebuffer
     );
+    crate::trace::trace_call!(glIsFramebuffer, framebuffer);
     with_ctx_mut(|mut state| state.oxidegl_is_framebuffer(framebuffer))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glIsProgram(program: GLuint) -> GLboolean {
     ::log::trace!("glIsProgram called, parameters: program: {:?} ", program);
+    crate::trace::trace_call!(glIsProgram, program);
     with_ctx_mut(|mut state| state.oxidegl_is_program(program))
 }
 #[unsafe(no_mangle)]
@@ -5929,11 +7004,13 @@ unsafe extern "C" fn glIsProgramPipeline(pipeline: GLuint) -> GLboolean {
         "glIsProgramPipeline called, parameters: pipeline: {:?} ",
         pipeline
     );
+    crate::trace::trace_call!(glIsProgramPipeline, pipeline);
     with_ctx_mut(|mut state| state.oxidegl_is_program_pipeline(pipeline))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glIsQuery(id: GLuint) -> GLboolean {
     ::log::trace!("glIsQuery called, parameters: id: {:?} ", id);
+    crate::trace::trace_call!(glIsQuery, id);
     with_ctx_mut(|mut state| state.oxidegl_is_query(id))
 }
 #[unsafe(no_mangle)]
@@ -5942,51 +7019,61 @@ unsafe extern "C" fn glIsRenderbuffer(renderbuffer: GLuint) -> GLboolean {
         "glIsRenderbuffer called, parameters: renderbuffer: {:?} ",
         renderbuffer
     );
+    crate::trace::trace_call!(glIsRenderbuffer, renderbuffer);
     with_ctx_mut(|mut state| state.oxidegl_is_renderbuffer(renderbuffer))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glIsSampler(sampler: GLuint) -> GLboolean {
     ::log::trace!("glIsSampler called, parameters: sampler: {:?} ", sampler);
+    crate::trace::trace_call!(glIsSampler, sampler);
     with_ctx_mut(|mut state| state.oxidegl_is_sampler(sampler))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glIsShader(shader: GLuint) -> GLboolean {
     ::log::trace!("glIsShader called, parameters: shader: {:?} ", shader);
+    crate::trace::trace_call!(glIsShader, shader);
     with_ctx_mut(|mut state| state.oxidegl_is_shader(shader))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glIsSync(sync: GLsync) -> GLboolean {
     ::log::trace!("glIsSync called, parameters: sync: {:?} ", sync);
+    crate::trace::trace_call!(glIsSync, sync);
     with_ctx_mut(|mut state| state.oxidegl_is_sync(sync))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glIsTexture(texture: GLuint) -> GLboolean {
     ::log::trace!("glIsTexture called, parameters: texture: {:?} ", texture);
+    crate::trace::trace_call!(glIsTexture, texture);
     with_ctx_mut(|mut state| state.oxidegl_is_texture(texture))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glIsTransformFeedback(id: GLuint) -> GLboolean {
     ::log::trace!("glIsTransformFeedback called, parameters: id: {:?} ", id);
+    crate::trace::trace_call!(glIsTransformFeedback, id);
     with_ctx_mut(|mut state| state.oxidegl_is_transform_feedback(id))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glIsVertexArray(array: GLuint) -> GLboolean {
     ::log::trace!("glIsVertexArray called, parameters: array: {:?} ", array);
+    crate::trace::trace_call!(glIsVertexArray, array);
     with_ctx_mut(|mut state| state.oxidegl_is_vertex_array(array))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glLineWidth(width: GLfloat) {
     ::log::trace!("glLineWidth called, parameters: width: {:?} ", width);
+    crate::trace::trace_call!(glLineWidth, width);
     with_ctx_mut(|mut state| state.oxidegl_line_width(width))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glLinkProgram(program: GLuint) {
     ::log::trace!("glLinkProgram called, parameters: program: {:?} ", program);
+    crate::trace::trace_call!(glLinkProgram, program);
     with_ctx_mut(|mut state| state.oxidegl_link_program(program))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glLogicOp(opcode: GLenum) {
     ::log::trace!("glLogicOp called, parameters: opcode: {:?} ", opcode);
+    crate::trace::trace_call!(glLogicOp, opcode);
     with_ctx_mut(|mut state| GlResult::normalize(state.oxidegl_logic_op(opcode.try_into_enum()?)))
 }
 #[unsafe(no_mangle)]
@@ -5996,6 +7083,7 @@ unsafe extern "C" fn glMapBuffer(target: GLenum, access: GLenum) -> *mut GLvoid
         target,
         access
     );
+    crate::trace::trace_call!(glMapBuffer, target, access);
     with_ctx_mut(|mut state| {
         GlResult::normalize(
             state.oxidegl_map_buffer(target.try_into_enum()?, access.try_into_enum()?),
@@ -6009,6 +7097,7 @@ unsafe extern "C" fn glMapNamedBuffer(buffer: GLuint, access: GLenum) -> *mut GL
         buffer,
         access
     );
+    crate::trace::trace_call!(glMapNamedBuffer, buffer, access);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_map_named_buffer(buffer, access.try_into_enum()?))
     })
@@ -6027,6 +7116,7 @@ unsafe extern "C" fn glMapBufferRange(
         length,
         access
     );
+    crate::trace::trace_call!(glMapBufferRange, target, offset, length, access);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_map_buffer_range(
             target.try_into_enum()?,
@@ -6050,6 +7140,7 @@ unsafe extern "C" fn glMapNamedBufferRange(
         length,
         access
     );
+    crate::trace::trace_call!(glMapNamedBufferRange, buffer, offset, length, access);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_map_named_buffer_range(
             buffer,
@@ -6065,6 +7156,7 @@ unsafe extern "C" fn glMemoryBarrier(barriers: GLenum) {
         "glMemoryBarrier called, parameters: barriers: {:?} ",
         barriers
     );
+    crate::trace::trace_call!(glMemoryBarrier, barriers);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_memory_barrier(barriers.try_into_enum()?))
     })
@@ -6075,6 +7167,7 @@ unsafe extern "C" fn glMemoryBarrierByRegion(barriers: GLenum) {
         "glMemoryBarrierByRegion called, parameters: barriers: {:?} ",
         barriers
     );
+    crate::trace::trace_call!(glMemoryBarrierByRegion, barriers);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_memory_barrier_by_region(barriers.try_into_enum()?))
     })
@@ -6082,6 +7175,7 @@ unsafe extern "C" fn glMemoryBarrierByRegion(barriers: GLenum) {
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glMinSampleShading(value: GLfloat) {
     ::log::trace!("glMinSampleShading called, parameters: value: {:?} ", value);
+    crate::trace::trace_call!(glMinSampleShading, value);
     with_ctx_mut(|mut state| state.oxidegl_min_sample_shading(value))
 }
 #[unsafe(no_mangle)]
@@ -6098,6 +7192,7 @@ unsafe extern "C" fn glMultiDrawArrays(
         count,
         drawcount
     );
+    crate::trace::trace_call!(glMultiDrawArrays, mode, first, count, drawcount);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_multi_draw_arrays(mode.try_into_enum()?, first, count, drawcount)
@@ -6118,6 +7213,7 @@ unsafe extern "C" fn glMultiDrawArraysIndirect(
         drawcount,
         stride
     );
+    crate::trace::trace_call!(glMultiDrawArraysIndirect, mode, indirect, drawcount, stride);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_multi_draw_arrays_indirect(
@@ -6145,6 +7241,7 @@ unsafe extern "C" fn glMultiDrawElements(
         indices,
         drawcount
     );
+    crate::trace::trace_call!(glMultiDrawElements, mode, count, r#type, indices, drawcount);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_multi_draw_elements(
@@ -6175,6 +7272,15 @@ unsafe extern "C" fn glMultiDrawElementsBaseVertex(
         drawcount,
         basevertex
     );
+    crate::trace::trace_call!(
+        glMultiDrawElementsBaseVertex,
+        mode,
+        count,
+        r#type,
+        indices,
+        drawcount,
+        basevertex
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_multi_draw_elements_base_vertex(
@@ -6204,6 +7310,14 @@ unsafe extern "C" fn glMultiDrawElementsIndirect(
         drawcount,
         stride
     );
+    crate::trace::trace_call!(
+        glMultiDrawElementsIndirect,
+        mode,
+        r#type,
+        indirect,
+        drawcount,
+        stride
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_multi_draw_elements_indirect(
@@ -6230,6 +7344,7 @@ unsafe extern "C" fn glObjectLabel(
         length,
         label
     );
+    crate::trace::trace_call!(glObjectLabel, identifier, name, length, label);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_object_label(identifier.try_into_enum()?, name, length, label)
@@ -6244,6 +7359,7 @@ unsafe extern "C" fn glObjectPtrLabel(ptr: *const GLvoid, length: GLsizei, label
         length,
         label
     );
+    crate::trace::trace_call!(glObjectPtrLabel, ptr, length, label);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_object_ptr_label(ptr, length, label) })
 }
 #[unsafe(no_mangle)]
@@ -6253,6 +7369,7 @@ unsafe extern "C" fn glPatchParameteri(pname: GLenum, value: GLint) {
         pname,
         value
     );
+    crate::trace::trace_call!(glPatchParameteri, pname, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_patch_parameteri(pname.try_into_enum()?, value))
     })
@@ -6264,6 +7381,7 @@ unsafe extern "C" fn glPatchParameterfv(pname: GLenum, values: *const GLfloat) {
         pname,
         values
     );
+    crate::trace::trace_call!(glPatchParameterfv, pname, values);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_patch_parameterfv(pname.try_into_enum()?, values)
@@ -6273,6 +7391,7 @@ unsafe extern "C" fn glPatchParameterfv(pname: GLenum, values: *const GLfloat) {
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glPauseTransformFeedback() {
     ::log::trace!("glPauseTransformFeedback called, parameters:  ",);
+    crate::trace::trace_call!(glPauseTransformFeedback,);
     with_ctx_mut(|mut state| state.oxidegl_pause_transform_feedback())
 }
 #[unsafe(no_mangle)]
@@ -6282,6 +7401,7 @@ unsafe extern "C" fn glPixelStoref(pname: GLenum, param: GLfloat) {
         pname,
         param
     );
+    crate::trace::trace_call!(glPixelStoref, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_pixel_storef(pname.try_into_enum()?, param))
     })
@@ -6293,6 +7413,7 @@ unsafe extern "C" fn glPixelStorei(pname: GLenum, param: GLint) {
         pname,
         param
     );
+    crate::trace::trace_call!(glPixelStorei, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_pixel_storei(pname.try_into_enum()?, param))
     })
@@ -6304,6 +7425,7 @@ unsafe extern "C" fn glPointParameterf(pname: GLenum, param: GLfloat) {
         pname,
         param
     );
+    crate::trace::trace_call!(glPointParameterf, pname, param);
     with_ctx_mut(|mut state| state.oxidegl_point_parameterf(pname, param))
 }
 #[unsafe(no_mangle)]
@@ -6313,6 +7435,7 @@ unsafe extern "C" fn glPointParameterfv(pname: GLenum, params: *const GLfloat) {
         pname,
         params
     );
+    crate::trace::trace_call!(glPointParameterfv, pname, params);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_point_parameterfv(pname, params) })
 }
 #[unsafe(no_mangle)]
@@ -6322,6 +7445,7 @@ unsafe extern "C" fn glPointParameteri(pname: GLenum, param: GLint) {
         pname,
         param
     );
+    crate::trace::trace_call!(glPointParameteri, pname, param);
     with_ctx_mut(|mut state| state.oxidegl_point_parameteri(pname, param))
 }
 #[unsafe(no_mangle)]
@@ -6331,11 +7455,13 @@ unsafe extern "C" fn glPointParameteriv(pname: GLenum, params: *const GLint) {
         pname,
         params
     );
+    crate::trace::trace_call!(glPointParameteriv, pname, params);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_point_parameteriv(pname, params) })
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glPointSize(size: GLfloat) {
     ::log::trace!("glPointSize called, parameters: size: {:?} ", size);
+    crate::trace::trace_call!(glPointSize, size);
     with_ctx_mut(|mut state| state.oxidegl_point_size(size))
 }
 #[unsafe(no_mangle)]
@@ -6345,6 +7471,7 @@ unsafe extern "C" fn glPolygonMode(face: GLenum, mode: GLenum) {
         face,
         mode
     );
+    crate::trace::trace_call!(glPolygonMode, face, mode);
     with_ctx_mut(|mut state| {
         GlResult::normalize(
             state.oxidegl_polygon_mode(face.try_into_enum()?, mode.try_into_enum()?),
@@ -6358,11 +7485,13 @@ unsafe extern "C" fn glPolygonOffset(factor: GLfloat, units: GLfloat) {
         factor,
         units
     );
+    crate::trace::trace_call!(glPolygonOffset, factor, units);
     with_ctx_mut(|mut state| state.oxidegl_polygon_offset(factor, units))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glPopDebugGroup() {
     ::log::trace!("glPopDebugGroup called, parameters:  ",);
+    crate::trace::trace_call!(glPopDebugGroup,);
     with_ctx_mut(|mut state| state.oxidegl_pop_debug_group())
 }
 #[unsafe(no_mangle)]
@@ -6371,6 +7500,7 @@ unsafe extern "C" fn glPrimitiveRestartIndex(index: GLuint) {
         "glPrimitiveRestartIndex called, parameters: index: {:?} ",
         index
     );
+    crate::trace::trace_call!(glPrimitiveRestartIndex, index);
     with_ctx_mut(|mut state| state.oxidegl_primitive_restart_index(index))
 }
 #[unsafe(no_mangle)]
@@ -6387,6 +7517,7 @@ unsafe extern "C" fn glProgramBinary(
         binary,
         length
     );
+    crate::trace::trace_call!(glProgramBinary, program, binaryFormat, binary, length);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_binary(program, binaryFormat, binary, length)
     })
@@ -6399,6 +7530,7 @@ unsafe extern "C" fn glProgramParameteri(program: GLuint, pname: GLenum, value:
         pname,
         value
     );
+    crate::trace::trace_call!(glProgramParameteri, program, pname, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_program_parameteri(
             program,
@@ -6415,6 +7547,7 @@ unsafe extern "C" fn glProgramUniform1i(program: GLuint, location: GLint, v0: GL
         location,
         v0
     );
+    crate::trace::trace_call!(glProgramUniform1i, program, location, v0);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform1i(program, location, v0))
 }
 #[unsafe(no_mangle)]
@@ -6431,6 +7564,7 @@ unsafe extern "C" fn glProgramUniform1iv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform1iv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform1iv(program, location, count, value)
     })
@@ -6443,6 +7577,7 @@ unsafe extern "C" fn glProgramUniform1f(program: GLuint, location: GLint, v0: GL
         location,
         v0
     );
+    crate::trace::trace_call!(glProgramUniform1f, program, location, v0);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform1f(program, location, v0))
 }
 #[unsafe(no_mangle)]
@@ -6459,6 +7594,7 @@ unsafe extern "C" fn glProgramUniform1fv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform1fv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform1fv(program, location, count, value)
     })
@@ -6471,6 +7607,7 @@ unsafe extern "C" fn glProgramUniform1ui(program: GLuint, location: GLint, v0: G
         location,
         v0
     );
+    crate::trace::trace_call!(glProgramUniform1ui, program, location, v0);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform1ui(program, location, v0))
 }
 #[unsafe(no_mangle)]
@@ -6487,6 +7624,7 @@ unsafe extern "C" fn glProgramUniform1uiv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform1uiv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform1uiv(program, location, count, value)
     })
@@ -6500,6 +7638,7 @@ unsafe extern "C" fn glProgramUniform2i(program: GLuint, location: GLint, v0: GL
         v0,
         v1
     );
+    crate::trace::trace_call!(glProgramUniform2i, program, location, v0, v1);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform2i(program, location, v0, v1))
 }
 #[unsafe(no_mangle)]
@@ -6516,6 +7655,7 @@ unsafe extern "C" fn glProgramUniform2iv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform2iv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform2iv(program, location, count, value)
     })
@@ -6534,6 +7674,7 @@ unsafe extern "C" fn glProgramUniform2f(
         v0,
         v1
     );
+    crate::trace::trace_call!(glProgramUniform2f, program, location, v0, v1);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform2f(program, location, v0, v1))
 }
 #[unsafe(no_mangle)]
@@ -6550,6 +7691,7 @@ unsafe extern "C" fn glProgramUniform2fv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform2fv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform2fv(program, location, count, value)
     })
@@ -6563,6 +7705,7 @@ unsafe extern "C" fn glProgramUniform2ui(program: GLuint, location: GLint, v0: G
         v0,
         v1
     );
+    crate::trace::trace_call!(glProgramUniform2ui, program, location, v0, v1);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform2ui(program, location, v0, v1))
 }
 #[unsafe(no_mangle)]
@@ -6579,6 +7722,7 @@ unsafe extern "C" fn glProgramUniform2uiv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform2uiv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform2uiv(program, location, count, value)
     })
@@ -6599,6 +7743,7 @@ unsafe extern "C" fn glProgramUniform3i(
         v1,
         v2
     );
+    crate::trace::trace_call!(glProgramUniform3i, program, location, v0, v1, v2);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform3i(program, location, v0, v1, v2))
 }
 #[unsafe(no_mangle)]
@@ -6615,6 +7760,7 @@ unsafe extern "C" fn glProgramUniform3iv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform3iv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform3iv(program, location, count, value)
     })
@@ -6635,6 +7781,7 @@ unsafe extern "C" fn glProgramUniform3f(
         v1,
         v2
     );
+    crate::trace::trace_call!(glProgramUniform3f, program, location, v0, v1, v2);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform3f(program, location, v0, v1, v2))
 }
 #[unsafe(no_mangle)]
@@ -6651,6 +7798,7 @@ unsafe extern "C" fn glProgramUniform3fv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform3fv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform3fv(program, location, count, value)
     })
@@ -6671,6 +7819,7 @@ unsafe extern "C" fn glProgramUniform3ui(
         v1,
         v2
     );
+    crate::trace::trace_call!(glProgramUniform3ui, program, location, v0, v1, v2);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform3ui(program, location, v0, v1, v2))
 }
 #[unsafe(no_mangle)]
@@ -6687,6 +7836,7 @@ unsafe extern "C" fn glProgramUniform3uiv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform3uiv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform3uiv(program, location, count, value)
     })
@@ -6709,6 +7859,7 @@ unsafe extern "C" fn glProgramUniform4i(
         v2,
         v3
     );
+    crate::trace::trace_call!(glProgramUniform4i, program, location, v0, v1, v2, v3);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform4i(program, location, v0, v1, v2, v3))
 }
 #[unsafe(no_mangle)]
@@ -6725,6 +7876,7 @@ unsafe extern "C" fn glProgramUniform4iv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform4iv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform4iv(program, location, count, value)
     })
@@ -6747,6 +7899,7 @@ unsafe extern "C" fn glProgramUniform4f(
         v2,
         v3
     );
+    crate::trace::trace_call!(glProgramUniform4f, program, location, v0, v1, v2, v3);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform4f(program, location, v0, v1, v2, v3))
 }
 #[unsafe(no_mangle)]
@@ -6763,6 +7916,7 @@ unsafe extern "C" fn glProgramUniform4fv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform4fv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform4fv(program, location, count, value)
     })
@@ -6785,6 +7939,7 @@ unsafe extern "C" fn glProgramUniform4ui(
         v2,
         v3
     );
+    crate::trace::trace_call!(glProgramUniform4ui, program, location, v0, v1, v2, v3);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform4ui(program, location, v0, v1, v2, v3))
 }
 #[unsafe(no_mangle)]
@@ -6801,6 +7956,7 @@ unsafe extern "C" fn glProgramUniform4uiv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform4uiv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform4uiv(program, location, count, value)
     })
@@ -6821,6 +7977,14 @@ unsafe extern "C" fn glProgramUniformMatrix2fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix2fv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix2fv(program, location, count, transpose, value)
     })
@@ -6841,6 +8005,14 @@ unsafe extern "C" fn glProgramUniformMatrix3fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix3fv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix3fv(program, location, count, transpose, value)
     })
@@ -6861,6 +8033,14 @@ unsafe extern "C" fn glProgramUniformMatrix4fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix4fv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix4fv(program, location, count, transpose, value)
     })
@@ -6881,6 +8061,14 @@ unsafe extern "C" fn glProgramUniformMatrix2x3fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix2x3fv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix2x3fv(program, location, count, transpose, value)
     })
@@ -6901,6 +8089,14 @@ unsafe extern "C" fn glProgramUniformMatrix3x2fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix3x2fv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix3x2fv(program, location, count, transpose, value)
     })
@@ -6921,6 +8117,14 @@ unsafe extern "C" fn glProgramUniformMatrix2x4fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix2x4fv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix2x4fv(program, location, count, transpose, value)
     })
@@ -6941,6 +8145,14 @@ unsafe extern "C" fn glProgramUniformMatrix4x2fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix4x2fv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix4x2fv(program, location, count, transpose, value)
     })
@@ -6961,6 +8173,14 @@ unsafe extern "C" fn glProgramUniformMatrix3x4fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix3x4fv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix3x4fv(program, location, count, transpose, value)
     })
@@ -6981,6 +8201,14 @@ unsafe extern "C" fn glProgramUniformMatrix4x3fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix4x3fv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix4x3fv(program, location, count, transpose, value)
     })
@@ -6988,6 +8216,7 @@ unsafe extern "C" fn glProgramUniformMatrix4x3fv(
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glProvokingVertex(mode: GLenum) {
     ::log::trace!("glProvokingVertex called, parameters: mode: {:?} ", mode);
+    crate::trace::trace_call!(glProvokingVertex, mode);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_provoking_vertex(mode.try_into_enum()?))
     })
@@ -7006,6 +8235,7 @@ unsafe extern "C" fn glPushDebugGroup(
         length,
         message
     );
+    crate::trace::trace_call!(glPushDebugGroup, source, id, length, message);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_push_debug_group(source.try_into_enum()?, id, length, message)
@@ -7019,11 +8249,13 @@ unsafe extern "C" fn glQueryCounter(id: GLuint, target: GLenum) {
         id,
         target
     );
+    crate::trace::trace_call!(glQueryCounter, id, target);
     with_ctx_mut(|mut state| state.oxidegl_query_counter(id, target))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glReadBuffer(src: GLenum) {
     ::log::trace!("glReadBuffer called, parameters: src: {:?} ", src);
+    crate::trace::trace_call!(glReadBuffer, src);
     with_ctx_mut(|mut state| GlResult::normalize(state.oxidegl_read_buffer(src.try_into_enum()?)))
 }
 #[unsafe(no_mangle)]
@@ -7033,6 +8265,7 @@ unsafe extern "C" fn glNamedFramebufferReadBuffer(framebuffer: GLuint, src: GLen
         framebuffer,
         src
     );
+    crate::trace::trace_call!(glNamedFramebufferReadBuffer, framebuffer, src);
     with_ctx_mut(|mut state| {
         GlResult::normalize(
             state.oxidegl_named_framebuffer_read_buffer(framebuffer, src.try_into_enum()?),
@@ -7059,6 +8292,7 @@ unsafe extern "C" fn glReadPixels(
         r#type,
         pixels
     );
+    crate::trace::trace_call!(glReadPixels, x, y, width, height, format, r#type, pixels);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_read_pixels(
@@ -7095,6 +8329,17 @@ unsafe extern "C" fn glReadnPixels(
         bufSize,
         data
     );
+    crate::trace::trace_call!(
+        glReadnPixels,
+        x,
+        y,
+        width,
+        height,
+        format,
+        r#type,
+        bufSize,
+        data
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_readn_pixels(
@@ -7113,6 +8358,7 @@ unsafe extern "C" fn glReadnPixels(
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glReleaseShaderCompiler() {
     ::log::trace!("glReleaseShaderCompiler called, parameters:  ",);
+    crate::trace::trace_call!(glReleaseShaderCompiler,);
     with_ctx_mut(|mut state| state.oxidegl_release_shader_compiler())
 }
 #[unsafe(no_mangle)]
@@ -7129,6 +8375,7 @@ unsafe extern "C" fn glRenderbufferStorage(
         width,
         height
     );
+    crate::trace::trace_call!(glRenderbufferStorage, target, internalformat, width, height);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_renderbuffer_storage(
             target,
@@ -7152,6 +8399,13 @@ unsafe extern "C" fn glNamedRenderbufferStorage(
         width,
         height
     );
+    crate::trace::trace_call!(
+        glNamedRenderbufferStorage,
+        renderbuffer,
+        internalformat,
+        width,
+        height
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_named_renderbuffer_storage(
             renderbuffer,
@@ -7177,6 +8431,14 @@ unsafe extern "C" fn glRenderbufferStorageMultisample(
         width,
         height
     );
+    crate::trace::trace_call!(
+        glRenderbufferStorageMultisample,
+        target,
+        samples,
+        internalformat,
+        width,
+        height
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_renderbuffer_storage_multisample(
             target,
@@ -7203,6 +8465,14 @@ unsafe extern "C" fn glNamedRenderbufferStorageMultisample(
         width,
         height
     );
+    crate::trace::trace_call!(
+        glNamedRenderbufferStorageMultisample,
+        renderbuffer,
+        samples,
+        internalformat,
+        width,
+        height
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_named_renderbuffer_storage_multisample(
             renderbuffer,
@@ -7216,6 +8486,7 @@ unsafe extern "C" fn glNamedRenderbufferStorageMultisample(
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glResumeTransformFeedback() {
     ::log::trace!("glResumeTransformFeedback called, parameters:  ",);
+    crate::trace::trace_call!(glResumeTransformFeedback,);
     with_ctx_mut(|mut state| state.oxidegl_resume_transform_feedback())
 }
 #[unsafe(no_mangle)]
@@ -7225,6 +8496,7 @@ unsafe extern "C" fn glSampleCoverage(value: GLfloat, invert: GLboolean) {
         value,
         invert
     );
+    crate::trace::trace_call!(glSampleCoverage, value, invert);
     with_ctx_mut(|mut state| state.oxidegl_sample_coverage(value, invert))
 }
 #[unsafe(no_mangle)]
@@ -7234,6 +8506,7 @@ unsafe extern "C" fn glSampleMaski(maskNumber: GLuint, mask: GLbitfield) {
         maskNumber,
         mask
     );
+    crate::trace::trace_call!(glSampleMaski, maskNumber, mask);
     with_ctx_mut(|mut state| state.oxidegl_sample_maski(maskNumber, mask))
 }
 #[unsafe(no_mangle)]
@@ -7244,6 +8517,7 @@ unsafe extern "C" fn glSamplerParameteri(sampler: GLuint, pname: GLenum, param:
         pname,
         param
     );
+    crate::trace::trace_call!(glSamplerParameteri, sampler, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_sampler_parameteri(
             sampler,
@@ -7260,6 +8534,7 @@ unsafe extern "C" fn glSamplerParameteriv(sampler: GLuint, pname: GLenum, param:
         pname,
         param
     );
+    crate::trace::trace_call!(glSamplerParameteriv, sampler, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_sampler_parameteriv(sampler, pname.try_into_enum()?, param)
@@ -7274,6 +8549,7 @@ unsafe extern "C" fn glSamplerParameterf(sampler: GLuint, pname: GLenum, param:
         pname,
         param
     );
+    crate::trace::trace_call!(glSamplerParameterf, sampler, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_sampler_parameterf(
             sampler,
@@ -7290,6 +8566,7 @@ unsafe extern "C" fn glSamplerParameterfv(sampler: GLuint, pname: GLenum, param:
         pname,
         param
     );
+    crate::trace::trace_call!(glSamplerParameterfv, sampler, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_sampler_parameterfv(sampler, pname.try_into_enum()?, param)
@@ -7304,6 +8581,7 @@ unsafe extern "C" fn glSamplerParameterIiv(sampler: GLuint, pname: GLenum, param
         pname,
         param
     );
+    crate::trace::trace_call!(glSamplerParameterIiv, sampler, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_sampler_parameter_iiv(sampler, pname.try_into_enum()?, param)
@@ -7318,6 +8596,7 @@ unsafe extern "C" fn glSamplerParameterIuiv(sampler: GLuint, pname: GLenum, para
         pname,
         param
     );
+    crate::trace::trace_call!(glSamplerParameterIuiv, sampler, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_sampler_parameter_iuiv(sampler, pname.try_into_enum()?, param)
@@ -7333,6 +8612,7 @@ unsafe extern "C" fn glScissor(x: GLint, y: GLint, width: GLsizei, height: GLsiz
         width,
         height
     );
+    crate::trace::trace_call!(glScissor, x, y, width, height);
     with_ctx_mut(|mut state| state.oxidegl_scissor(x, y, width, height))
 }
 #[unsafe(no_mangle)]
@@ -7343,6 +8623,7 @@ unsafe extern "C" fn glScissorArrayv(first: GLuint, count: GLsizei, v: *const GL
         count,
         v
     );
+    crate::trace::trace_call!(glScissorArrayv, first, count, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_scissor_arrayv(first, count, v) })
 }
 #[unsafe(no_mangle)]
@@ -7361,6 +8642,7 @@ unsafe extern "C" fn glScissorIndexed(
         width,
         height
     );
+    crate::trace::trace_call!(glScissorIndexed, index, left, bottom, width, height);
     with_ctx_mut(|mut state| state.oxidegl_scissor_indexed(index, left, bottom, width, height))
 }
 #[unsafe(no_mangle)]
@@ -7370,6 +8652,7 @@ unsafe extern "C" fn glScissorIndexedv(index: GLuint, v: *const GLint) {
         index,
         v
     );
+    crate::trace::trace_call!(glScissorIndexedv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_scissor_indexedv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -7388,6 +8671,7 @@ unsafe extern "C" fn glShaderBinary(
         binary,
         length
     );
+    crate::trace::trace_call!(glShaderBinary, count, shaders, binaryFormat, binary, length);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_shader_binary(count, shaders, binaryFormat, binary, length)
     })
@@ -7406,6 +8690,7 @@ unsafe extern "C" fn glShaderSource(
         string,
         length
     );
+    crate::trace::trace_call!(glShaderSource, shader, count, string, length);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_shader_source(shader, count, string, length) })
 }
 #[unsafe(no_mangle)]
@@ -7420,6 +8705,12 @@ unsafe extern "C" fn glShaderStorageBlockBinding(
         storageBlockIndex,
         storageBlockBinding
     );
+    crate::trace::trace_call!(
+        glShaderStorageBlockBinding,
+        program,
+        storageBlockIndex,
+        storageBlockBinding
+    );
     with_ctx_mut(|mut state| {
         state.oxidegl_shader_storage_block_binding(program, storageBlockIndex, storageBlockBinding)
     })
@@ -7432,6 +8723,7 @@ unsafe extern "C" fn glStencilFunc(func: GLenum, r#ref: GLint, mask: GLuint) {
         r#ref,
         mask
     );
+    crate::trace::trace_call!(glStencilFunc, func, r#ref, mask);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_stencil_func(func.try_into_enum()?, r#ref, mask))
     })
@@ -7445,6 +8737,7 @@ unsafe extern "C" fn glStencilFuncSeparate(face: GLenum, func: GLenum, r#ref: GL
         r#ref,
         mask
     );
+    crate::trace::trace_call!(glStencilFuncSeparate, face, func, r#ref, mask);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_stencil_func_separate(
             face.try_into_enum()?,
@@ -7457,6 +8750,7 @@ unsafe extern "C" fn glStencilFuncSeparate(face: GLenum, func: GLenum, r#ref: GL
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glStencilMask(mask: GLuint) {
     ::log::trace!("glStencilMask called, parameters: mask: {:?} ", mask);
+    crate::trace::trace_call!(glStencilMask, mask);
     with_ctx_mut(|mut state| state.oxidegl_stencil_mask(mask))
 }
 #[unsafe(no_mangle)]
@@ -7466,6 +8760,7 @@ unsafe extern "C" fn glStencilMaskSeparate(face: GLenum, mask: GLuint) {
         face,
         mask
     );
+    crate::trace::trace_call!(glStencilMaskSeparate, face, mask);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_stencil_mask_separate(face.try_into_enum()?, mask))
     })
@@ -7478,6 +8773,7 @@ unsafe extern "C" fn glStencilOp(fail: GLenum, zfail: GLenum, zpass: GLenum) {
         zfail,
         zpass
     );
+    crate::trace::trace_call!(glStencilOp, fail, zfail, zpass);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_stencil_op(
             fail.try_into_enum()?,
@@ -7500,6 +8796,7 @@ unsafe extern "C" fn glStencilOpSeparate(
         dpfail,
         dppass
     );
+    crate::trace::trace_call!(glStencilOpSeparate, face, sfail, dpfail, dppass);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_stencil_op_separate(
             face.try_into_enum()?,
@@ -7517,6 +8814,7 @@ unsafe extern "C" fn glTexBuffer(target: GLenum, internalformat: GLenum, buffer:
         internalformat,
         buffer
     );
+    crate::trace::trace_call!(glTexBuffer, target, internalformat, buffer);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_tex_buffer(
             target.try_into_enum()?,
@@ -7533,6 +8831,7 @@ unsafe extern "C" fn glTextureBuffer(texture: GLuint, internalformat: GLenum, bu
         internalformat,
         buffer
     );
+    crate::trace::trace_call!(glTextureBuffer, texture, internalformat, buffer);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_texture_buffer(
             texture,
@@ -7557,6 +8856,14 @@ unsafe extern "C" fn glTexBufferRange(
         offset,
         size
     );
+    crate::trace::trace_call!(
+        glTexBufferRange,
+        target,
+        internalformat,
+        buffer,
+        offset,
+        size
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_tex_buffer_range(
             target.try_into_enum()?,
@@ -7583,6 +8890,14 @@ unsafe extern "C" fn glTextureBufferRange(
         offset,
         size
     );
+    crate::trace::trace_call!(
+        glTextureBufferRange,
+        texture,
+        internalformat,
+        buffer,
+        offset,
+        size
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_texture_buffer_range(
             texture,
@@ -7615,6 +8930,17 @@ unsafe extern "C" fn glTexImage1D(
         r#type,
         pixels
     );
+    crate::trace::trace_call!(
+        glTexImage1D,
+        target,
+        level,
+        internalformat,
+        width,
+        border,
+        format,
+        r#type,
+        pixels
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_tex_image1_d(
@@ -7654,6 +8980,18 @@ unsafe extern "C" fn glTexImage2D(
         r#type,
         pixels
     );
+    crate::trace::trace_call!(
+        glTexImage2D,
+        target,
+        level,
+        internalformat,
+        width,
+        height,
+        border,
+        format,
+        r#type,
+        pixels
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_tex_image2_d(
@@ -7688,6 +9026,15 @@ unsafe extern "C" fn glTexImage2DMultisample(
         height,
         fixedsamplelocations
     );
+    crate::trace::trace_call!(
+        glTexImage2DMultisample,
+        target,
+        samples,
+        internalformat,
+        width,
+        height,
+        fixedsamplelocations
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_tex_image2_d_multisample(
             target.try_into_enum()?,
@@ -7725,6 +9072,19 @@ unsafe extern "C" fn glTexImage3D(
         r#type,
         pixels
     );
+    crate::trace::trace_call!(
+        glTexImage3D,
+        target,
+        level,
+        internalformat,
+        width,
+        height,
+        depth,
+        border,
+        format,
+        r#type,
+        pixels
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_tex_image3_d(
@@ -7762,6 +9122,16 @@ unsafe extern "C" fn glTexImage3DMultisample(
         depth,
         fixedsamplelocations
     );
+    crate::trace::trace_call!(
+        glTexImage3DMultisample,
+        target,
+        samples,
+        internalformat,
+        width,
+        height,
+        depth,
+        fixedsamplelocations
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_tex_image3_d_multisample(
             target.try_into_enum()?,
@@ -7782,6 +9152,7 @@ unsafe extern "C" fn glTexParameterf(target: GLenum, pname: GLenum, param: GLflo
         pname,
         param
     );
+    crate::trace::trace_call!(glTexParameterf, target, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_tex_parameterf(
             target.try_into_enum()?,
@@ -7798,6 +9169,7 @@ unsafe extern "C" fn glTexParameterfv(target: GLenum, pname: GLenum, params: *co
         pname,
         params
     );
+    crate::trace::trace_call!(glTexParameterfv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_tex_parameterfv(target.try_into_enum()?, pname.try_into_enum()?, params)
@@ -7812,6 +9184,7 @@ unsafe extern "C" fn glTexParameteri(target: GLenum, pname: GLenum, param: GLint
         pname,
         param
     );
+    crate::trace::trace_call!(glTexParameteri, target, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_tex_parameteri(
             target.try_into_enum()?,
@@ -7828,6 +9201,7 @@ unsafe extern "C" fn glTexParameteriv(target: GLenum, pname: GLenum, params: *co
         pname,
         params
     );
+    crate::trace::trace_call!(glTexParameteriv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_tex_parameteriv(target.try_into_enum()?, pname.try_into_enum()?, params)
@@ -7842,6 +9216,7 @@ unsafe extern "C" fn glTexParameterIiv(target: GLenum, pname: GLenum, params: *c
         pname,
         params
     );
+    crate::trace::trace_call!(glTexParameterIiv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_tex_parameter_iiv(target.try_into_enum()?, pname.try_into_enum()?, params)
@@ -7856,6 +9231,7 @@ unsafe extern "C" fn glTexParameterIuiv(target: GLenum, pname: GLenum, params: *
         pname,
         params
     );
+    crate::trace::trace_call!(glTexParameterIuiv, target, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_tex_parameter_iuiv(
@@ -7874,6 +9250,7 @@ unsafe extern "C" fn glTextureParameterf(texture: GLuint, pname: GLenum, param:
         pname,
         param
     );
+    crate::trace::trace_call!(glTextureParameterf, texture, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_texture_parameterf(
             texture,
@@ -7890,6 +9267,7 @@ unsafe extern "C" fn glTextureParameterfv(texture: GLuint, pname: GLenum, param:
         pname,
         param
     );
+    crate::trace::trace_call!(glTextureParameterfv, texture, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_texture_parameterfv(texture, pname.try_into_enum()?, param)
@@ -7904,6 +9282,7 @@ unsafe extern "C" fn glTextureParameteri(texture: GLuint, pname: GLenum, param:
         pname,
         param
     );
+    crate::trace::trace_call!(glTextureParameteri, texture, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_texture_parameteri(
             texture,
@@ -7920,6 +9299,7 @@ unsafe extern "C" fn glTextureParameterIiv(texture: GLuint, pname: GLenum, param
         pname,
         params
     );
+    crate::trace::trace_call!(glTextureParameterIiv, texture, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_texture_parameter_iiv(texture, pname.try_into_enum()?, params)
@@ -7934,6 +9314,7 @@ unsafe extern "C" fn glTextureParameterIuiv(texture: GLuint, pname: GLenum, para
         pname,
         params
     );
+    crate::trace::trace_call!(glTextureParameterIuiv, texture, pname, params);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_texture_parameter_iuiv(texture, pname.try_into_enum()?, params)
@@ -7948,6 +9329,7 @@ unsafe extern "C" fn glTextureParameteriv(texture: GLuint, pname: GLenum, param:
         pname,
         param
     );
+    crate::trace::trace_call!(glTextureParameteriv, texture, pname, param);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_texture_parameteriv(texture, pname.try_into_enum()?, param)
@@ -7968,6 +9350,7 @@ unsafe extern "C" fn glTexStorage1D(
         internalformat,
         width
     );
+    crate::trace::trace_call!(glTexStorage1D, target, levels, internalformat, width);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_tex_storage1_d(
             target.try_into_enum()?,
@@ -7991,6 +9374,7 @@ unsafe extern "C" fn glTextureStorage1D(
         internalformat,
         width
     );
+    crate::trace::trace_call!(glTextureStorage1D, texture, levels, internalformat, width);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_texture_storage1_d(
             texture,
@@ -8016,6 +9400,14 @@ unsafe extern "C" fn glTexStorage2D(
         width,
         height
     );
+    crate::trace::trace_call!(
+        glTexStorage2D,
+        target,
+        levels,
+        internalformat,
+        width,
+        height
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_tex_storage2_d(
             target.try_into_enum()?,
@@ -8042,6 +9434,14 @@ unsafe extern "C" fn glTextureStorage2D(
         width,
         height
     );
+    crate::trace::trace_call!(
+        glTextureStorage2D,
+        texture,
+        levels,
+        internalformat,
+        width,
+        height
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_texture_storage2_d(
             texture,
@@ -8070,6 +9470,15 @@ unsafe extern "C" fn glTexStorage2DMultisample(
         height,
         fixedsamplelocations
     );
+    crate::trace::trace_call!(
+        glTexStorage2DMultisample,
+        target,
+        samples,
+        internalformat,
+        width,
+        height,
+        fixedsamplelocations
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_tex_storage2_d_multisample(
             target.try_into_enum()?,
@@ -8099,6 +9508,15 @@ unsafe extern "C" fn glTextureStorage2DMultisample(
         height,
         fixedsamplelocations
     );
+    crate::trace::trace_call!(
+        glTextureStorage2DMultisample,
+        texture,
+        samples,
+        internalformat,
+        width,
+        height,
+        fixedsamplelocations
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_texture_storage2_d_multisample(
             texture,
@@ -8128,6 +9546,15 @@ unsafe extern "C" fn glTexStorage3D(
         height,
         depth
     );
+    crate::trace::trace_call!(
+        glTexStorage3D,
+        target,
+        levels,
+        internalformat,
+        width,
+        height,
+        depth
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_tex_storage3_d(
             target.try_into_enum()?,
@@ -8157,6 +9584,15 @@ unsafe extern "C" fn glTextureStorage3D(
         height,
         depth
     );
+    crate::trace::trace_call!(
+        glTextureStorage3D,
+        texture,
+        levels,
+        internalformat,
+        width,
+        height,
+        depth
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_texture_storage3_d(
             texture,
@@ -8188,6 +9624,16 @@ unsafe extern "C" fn glTexStorage3DMultisample(
         depth,
         fixedsamplelocations
     );
+    crate::trace::trace_call!(
+        glTexStorage3DMultisample,
+        target,
+        samples,
+        internalformat,
+        width,
+        height,
+        depth,
+        fixedsamplelocations
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_tex_storage3_d_multisample(
             target.try_into_enum()?,
@@ -8220,6 +9666,16 @@ unsafe extern "C" fn glTextureStorage3DMultisample(
         depth,
         fixedsamplelocations
     );
+    crate::trace::trace_call!(
+        glTextureStorage3DMultisample,
+        texture,
+        samples,
+        internalformat,
+        width,
+        height,
+        depth,
+        fixedsamplelocations
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_texture_storage3_d_multisample(
             texture,
@@ -8252,6 +9708,16 @@ unsafe extern "C" fn glTexSubImage1D(
         r#type,
         pixels
     );
+    crate::trace::trace_call!(
+        glTexSubImage1D,
+        target,
+        level,
+        xoffset,
+        width,
+        format,
+        r#type,
+        pixels
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_tex_sub_image1_d(
@@ -8286,6 +9752,16 @@ unsafe extern "C" fn glTextureSubImage1D(
         r#type,
         pixels
     );
+    crate::trace::trace_call!(
+        glTextureSubImage1D,
+        texture,
+        level,
+        xoffset,
+        width,
+        format,
+        r#type,
+        pixels
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_texture_sub_image1_d(
@@ -8324,6 +9800,18 @@ unsafe extern "C" fn glTexSubImage2D(
         r#type,
         pixels
     );
+    crate::trace::trace_call!(
+        glTexSubImage2D,
+        target,
+        level,
+        xoffset,
+        yoffset,
+        width,
+        height,
+        format,
+        r#type,
+        pixels
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_tex_sub_image2_d(
@@ -8364,6 +9852,18 @@ unsafe extern "C" fn glTextureSubImage2D(
         r#type,
         pixels
     );
+    crate::trace::trace_call!(
+        glTextureSubImage2D,
+        texture,
+        level,
+        xoffset,
+        yoffset,
+        width,
+        height,
+        format,
+        r#type,
+        pixels
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_texture_sub_image2_d(
@@ -8408,6 +9908,20 @@ unsafe extern "C" fn glTexSubImage3D(
         r#type,
         pixels
     );
+    crate::trace::trace_call!(
+        glTexSubImage3D,
+        target,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        width,
+        height,
+        depth,
+        format,
+        r#type,
+        pixels
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_tex_sub_image3_d(
@@ -8454,6 +9968,20 @@ unsafe extern "C" fn glTextureSubImage3D(
         r#type,
         pixels
     );
+    crate::trace::trace_call!(
+        glTextureSubImage3D,
+        texture,
+        level,
+        xoffset,
+        yoffset,
+        zoffset,
+        width,
+        height,
+        depth,
+        format,
+        r#type,
+        pixels
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_texture_sub_image3_d(
@@ -8475,6 +10003,7 @@ unsafe extern "C" fn glTextureSubImage3D(
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glTextureBarrier() {
     ::log::trace!("glTextureBarrier called, parameters:  ",);
+    crate::trace::trace_call!(glTextureBarrier,);
     with_ctx_mut(|mut state| state.oxidegl_texture_barrier())
 }
 #[unsafe(no_mangle)]
@@ -8499,6 +10028,17 @@ unsafe extern "C" fn glTextureView(
         minlayer,
         numlayers
     );
+    crate::trace::trace_call!(
+        glTextureView,
+        texture,
+        target,
+        origtexture,
+        internalformat,
+        minlevel,
+        numlevels,
+        minlayer,
+        numlayers
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_texture_view(
             texture,
@@ -8520,6 +10060,7 @@ unsafe extern "C" fn glTransformFeedbackBufferBase(xfb: GLuint, index: GLuint, b
         index,
         buffer
     );
+    crate::trace::trace_call!(glTransformFeedbackBufferBase, xfb, index, buffer);
     with_ctx_mut(|mut state| state.oxidegl_transform_feedback_buffer_base(xfb, index, buffer))
 }
 #[unsafe(no_mangle)]
@@ -8538,6 +10079,14 @@ unsafe extern "C" fn glTransformFeedbackBufferRange(
         offset,
         size
     );
+    crate::trace::trace_call!(
+        glTransformFeedbackBufferRange,
+        xfb,
+        index,
+        buffer,
+        offset,
+        size
+    );
     with_ctx_mut(|mut state| {
         state.oxidegl_transform_feedback_buffer_range(xfb, index, buffer, offset, size)
     })
@@ -8556,6 +10105,13 @@ unsafe extern "C" fn glTransformFeedbackVaryings(
         varyings,
         bufferMode
     );
+    crate::trace::trace_call!(
+        glTransformFeedbackVaryings,
+        program,
+        count,
+        varyings,
+        bufferMode
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_transform_feedback_varyings(
@@ -8574,6 +10130,7 @@ unsafe extern "C" fn glUniform1f(location: GLint, v0: GLfloat) {
         location,
         v0
     );
+    crate::trace::trace_call!(glUniform1f, location, v0);
     with_ctx_mut(|mut state| state.oxidegl_uniform1f(location, v0))
 }
 #[unsafe(no_mangle)]
@@ -8584,6 +10141,7 @@ unsafe extern "C" fn glUniform2f(location: GLint, v0: GLfloat, v1: GLfloat) {
         v0,
         v1
     );
+    crate::trace::trace_call!(glUniform2f, location, v0, v1);
     with_ctx_mut(|mut state| state.oxidegl_uniform2f(location, v0, v1))
 }
 #[unsafe(no_mangle)]
@@ -8595,6 +10153,7 @@ unsafe extern "C" fn glUniform3f(location: GLint, v0: GLfloat, v1: GLfloat, v2:
         v1,
         v2
     );
+    crate::trace::trace_call!(glUniform3f, location, v0, v1, v2);
     with_ctx_mut(|mut state| state.oxidegl_uniform3f(location, v0, v1, v2))
 }
 #[unsafe(no_mangle)]
@@ -8613,6 +10172,7 @@ unsafe extern "C" fn glUniform4f(
         v2,
         v3
     );
+    crate::trace::trace_call!(glUniform4f, location, v0, v1, v2, v3);
     with_ctx_mut(|mut state| state.oxidegl_uniform4f(location, v0, v1, v2, v3))
 }
 #[unsafe(no_mangle)]
@@ -8622,6 +10182,7 @@ unsafe extern "C" fn glUniform1i(location: GLint, v0: GLint) {
         location,
         v0
     );
+    crate::trace::trace_call!(glUniform1i, location, v0);
     with_ctx_mut(|mut state| state.oxidegl_uniform1i(location, v0))
 }
 #[unsafe(no_mangle)]
@@ -8632,6 +10193,7 @@ unsafe extern "C" fn glUniform2i(location: GLint, v0: GLint, v1: GLint) {
         v0,
         v1
     );
+    crate::trace::trace_call!(glUniform2i, location, v0, v1);
     with_ctx_mut(|mut state| state.oxidegl_uniform2i(location, v0, v1))
 }
 #[unsafe(no_mangle)]
@@ -8643,6 +10205,7 @@ unsafe extern "C" fn glUniform3i(location: GLint, v0: GLint, v1: GLint, v2: GLin
         v1,
         v2
     );
+    crate::trace::trace_call!(glUniform3i, location, v0, v1, v2);
     with_ctx_mut(|mut state| state.oxidegl_uniform3i(location, v0, v1, v2))
 }
 #[unsafe(no_mangle)]
@@ -8655,6 +10218,7 @@ unsafe extern "C" fn glUniform4i(location: GLint, v0: GLint, v1: GLint, v2: GLin
         v2,
         v3
     );
+    crate::trace::trace_call!(glUniform4i, location, v0, v1, v2, v3);
     with_ctx_mut(|mut state| state.oxidegl_uniform4i(location, v0, v1, v2, v3))
 }
 #[unsafe(no_mangle)]
@@ -8665,6 +10229,7 @@ unsafe extern "C" fn glUniform1fv(location: GLint, count: GLsizei, value: *const
         count,
         value
     );
+    crate::trace::trace_call!(glUniform1fv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform1fv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -8675,6 +10240,7 @@ unsafe extern "C" fn glUniform2fv(location: GLint, count: GLsizei, value: *const
         count,
         value
     );
+    crate::trace::trace_call!(glUniform2fv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform2fv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -8685,6 +10251,7 @@ unsafe extern "C" fn glUniform3fv(location: GLint, count: GLsizei, value: *const
         count,
         value
     );
+    crate::trace::trace_call!(glUniform3fv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform3fv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -8695,6 +10262,7 @@ unsafe extern "C" fn glUniform4fv(location: GLint, count: GLsizei, value: *const
         count,
         value
     );
+    crate::trace::trace_call!(glUniform4fv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform4fv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -8705,6 +10273,7 @@ unsafe extern "C" fn glUniform1iv(location: GLint, count: GLsizei, value: *const
         count,
         value
     );
+    crate::trace::trace_call!(glUniform1iv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform1iv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -8715,6 +10284,7 @@ unsafe extern "C" fn glUniform2iv(location: GLint, count: GLsizei, value: *const
         count,
         value
     );
+    crate::trace::trace_call!(glUniform2iv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform2iv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -8725,6 +10295,7 @@ unsafe extern "C" fn glUniform3iv(location: GLint, count: GLsizei, value: *const
         count,
         value
     );
+    crate::trace::trace_call!(glUniform3iv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform3iv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -8735,6 +10306,7 @@ unsafe extern "C" fn glUniform4iv(location: GLint, count: GLsizei, value: *const
         count,
         value
     );
+    crate::trace::trace_call!(glUniform4iv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform4iv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -8751,6 +10323,7 @@ unsafe extern "C" fn glUniformMatrix2fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix2fv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix2fv(location, count, transpose, value)
     })
@@ -8769,6 +10342,7 @@ unsafe extern "C" fn glUniformMatrix3fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix3fv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix3fv(location, count, transpose, value)
     })
@@ -8787,6 +10361,7 @@ unsafe extern "C" fn glUniformMatrix4fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix4fv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix4fv(location, count, transpose, value)
     })
@@ -8805,6 +10380,7 @@ unsafe extern "C" fn glUniformMatrix2x3fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix2x3fv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix2x3fv(location, count, transpose, value)
     })
@@ -8823,6 +10399,7 @@ unsafe extern "C" fn glUniformMatrix3x2fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix3x2fv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix3x2fv(location, count, transpose, value)
     })
@@ -8841,6 +10418,7 @@ unsafe extern "C" fn glUniformMatrix2x4fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix2x4fv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix2x4fv(location, count, transpose, value)
     })
@@ -8859,6 +10437,7 @@ unsafe extern "C" fn glUniformMatrix4x2fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix4x2fv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix4x2fv(location, count, transpose, value)
     })
@@ -8877,6 +10456,7 @@ unsafe extern "C" fn glUniformMatrix3x4fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix3x4fv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix3x4fv(location, count, transpose, value)
     })
@@ -8895,6 +10475,7 @@ unsafe extern "C" fn glUniformMatrix4x3fv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix4x3fv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix4x3fv(location, count, transpose, value)
     })
@@ -8906,6 +10487,7 @@ unsafe extern "C" fn glUniform1ui(location: GLint, v0: GLuint) {
         location,
         v0
     );
+    crate::trace::trace_call!(glUniform1ui, location, v0);
     with_ctx_mut(|mut state| state.oxidegl_uniform1ui(location, v0))
 }
 #[unsafe(no_mangle)]
@@ -8916,6 +10498,7 @@ unsafe extern "C" fn glUniform2ui(location: GLint, v0: GLuint, v1: GLuint) {
         v0,
         v1
     );
+    crate::trace::trace_call!(glUniform2ui, location, v0, v1);
     with_ctx_mut(|mut state| state.oxidegl_uniform2ui(location, v0, v1))
 }
 #[unsafe(no_mangle)]
@@ -8927,6 +10510,7 @@ unsafe extern "C" fn glUniform3ui(location: GLint, v0: GLuint, v1: GLuint, v2: G
         v1,
         v2
     );
+    crate::trace::trace_call!(glUniform3ui, location, v0, v1, v2);
     with_ctx_mut(|mut state| state.oxidegl_uniform3ui(location, v0, v1, v2))
 }
 #[unsafe(no_mangle)]
@@ -8939,6 +10523,7 @@ unsafe extern "C" fn glUniform4ui(location: GLint, v0: GLuint, v1: GLuint, v2: G
         v2,
         v3
     );
+    crate::trace::trace_call!(glUniform4ui, location, v0, v1, v2, v3);
     with_ctx_mut(|mut state| state.oxidegl_uniform4ui(location, v0, v1, v2, v3))
 }
 #[unsafe(no_mangle)]
@@ -8949,6 +10534,7 @@ unsafe extern "C" fn glUniform1uiv(location: GLint, count: GLsizei, value: *cons
         count,
         value
     );
+    crate::trace::trace_call!(glUniform1uiv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform1uiv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -8959,6 +10545,7 @@ unsafe extern "C" fn glUniform2uiv(location: GLint, count: GLsizei, value: *cons
         count,
         value
     );
+    crate::trace::trace_call!(glUniform2uiv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform2uiv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -8969,6 +10556,7 @@ unsafe extern "C" fn glUniform3uiv(location: GLint, count: GLsizei, value: *cons
         count,
         value
     );
+    crate::trace::trace_call!(glUniform3uiv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform3uiv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -8979,6 +10567,7 @@ unsafe extern "C" fn glUniform4uiv(location: GLint, count: GLsizei, value: *cons
         count,
         value
     );
+    crate::trace::trace_call!(glUniform4uiv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform4uiv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -8993,6 +10582,12 @@ unsafe extern "C" fn glUniformBlockBinding(
         uniformBlockIndex,
         uniformBlockBinding
     );
+    crate::trace::trace_call!(
+        glUniformBlockBinding,
+        program,
+        uniformBlockIndex,
+        uniformBlockBinding
+    );
     with_ctx_mut(|mut state| {
         state.oxidegl_uniform_block_binding(program, uniformBlockIndex, uniformBlockBinding)
     })
@@ -9009,6 +10604,7 @@ unsafe extern "C" fn glUniformSubroutinesuiv(
         count,
         indices
     );
+    crate::trace::trace_call!(glUniformSubroutinesuiv, shadertype, count, indices);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_uniform_subroutinesuiv(shadertype.try_into_enum()?, count, indices)
@@ -9018,6 +10614,7 @@ unsafe extern "C" fn glUniformSubroutinesuiv(
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glUnmapBuffer(target: GLenum) -> GLboolean {
     ::log::trace!("glUnmapBuffer called, parameters: target: {:?} ", target);
+    crate::trace::trace_call!(glUnmapBuffer, target);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_unmap_buffer(target.try_into_enum()?))
     })
@@ -9028,11 +10625,13 @@ unsafe extern "C" fn glUnmapNamedBuffer(buffer: GLuint) -> GLboolean {
         "glUnmapNamedBuffer called, parameters: buffer: {:?} ",
         buffer
     );
+    crate::trace::trace_call!(glUnmapNamedBuffer, buffer);
     with_ctx_mut(|mut state| state.oxidegl_unmap_named_buffer(buffer))
 }
 #[unsafe(no_mangle)]
 unsafe extern "C" fn glUseProgram(program: GLuint) {
     ::log::trace!("glUseProgram called, parameters: program: {:?} ", program);
+    crate::trace::trace_call!(glUseProgram, program);
     with_ctx_mut(|mut state| state.oxidegl_use_program(program))
 }
 #[unsafe(no_mangle)]
@@ -9043,6 +10642,7 @@ unsafe extern "C" fn glUseProgramStages(pipeline: GLuint, stages: GLenum, progra
         stages,
         program
     );
+    crate::trace::trace_call!(glUseProgramStages, pipeline, stages, program);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_use_program_stages(
             pipeline,
@@ -9057,6 +10657,7 @@ unsafe extern "C" fn glValidateProgram(program: GLuint) {
         "glValidateProgram called, parameters: program: {:?} ",
         program
     );
+    crate::trace::trace_call!(glValidateProgram, program);
     with_ctx_mut(|mut state| state.oxidegl_validate_program(program))
 }
 #[unsafe(no_mangle)]
@@ -9065,6 +10666,7 @@ unsafe extern "C" fn glValidateProgramPipeline(pipeline: GLuint) {
         "glValidateProgramPipeline called, parameters: pipeline: {:?} ",
         pipeline
     );
+    crate::trace::trace_call!(glValidateProgramPipeline, pipeline);
     with_ctx_mut(|mut state| state.oxidegl_validate_program_pipeline(pipeline))
 }
 #[unsafe(no_mangle)]
@@ -9074,6 +10676,7 @@ unsafe extern "C" fn glVertexArrayElementBuffer(vaobj: GLuint, buffer: GLuint) {
         vaobj,
         buffer
     );
+    crate::trace::trace_call!(glVertexArrayElementBuffer, vaobj, buffer);
     with_ctx_mut(|mut state| state.oxidegl_vertex_array_element_buffer(vaobj, buffer))
 }
 #[unsafe(no_mangle)]
@@ -9083,6 +10686,7 @@ unsafe extern "C" fn glVertexAttrib1d(index: GLuint, x: GLdouble) {
         index,
         x
     );
+    crate::trace::trace_call!(glVertexAttrib1d, index, x);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib1d(index, x))
 }
 #[unsafe(no_mangle)]
@@ -9092,6 +10696,7 @@ unsafe extern "C" fn glVertexAttrib1dv(index: GLuint, v: *const GLdouble) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib1dv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib1dv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9101,6 +10706,7 @@ unsafe extern "C" fn glVertexAttrib1f(index: GLuint, x: GLfloat) {
         index,
         x
     );
+    crate::trace::trace_call!(glVertexAttrib1f, index, x);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib1f(index, x))
 }
 #[unsafe(no_mangle)]
@@ -9110,6 +10716,7 @@ unsafe extern "C" fn glVertexAttrib1fv(index: GLuint, v: *const GLfloat) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib1fv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib1fv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9119,6 +10726,7 @@ unsafe extern "C" fn glVertexAttrib1s(index: GLuint, x: GLshort) {
         index,
         x
     );
+    crate::trace::trace_call!(glVertexAttrib1s, index, x);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib1s(index, x))
 }
 #[unsafe(no_mangle)]
@@ -9128,6 +10736,7 @@ unsafe extern "C" fn glVertexAttrib1sv(index: GLuint, v: *const GLshort) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib1sv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib1sv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9138,6 +10747,7 @@ unsafe extern "C" fn glVertexAttrib2d(index: GLuint, x: GLdouble, y: GLdouble) {
         x,
         y
     );
+    crate::trace::trace_call!(glVertexAttrib2d, index, x, y);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib2d(index, x, y))
 }
 #[unsafe(no_mangle)]
@@ -9147,6 +10757,7 @@ unsafe extern "C" fn glVertexAttrib2dv(index: GLuint, v: *const GLdouble) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib2dv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib2dv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9157,6 +10768,7 @@ unsafe extern "C" fn glVertexAttrib2f(index: GLuint, x: GLfloat, y: GLfloat) {
         x,
         y
     );
+    crate::trace::trace_call!(glVertexAttrib2f, index, x, y);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib2f(index, x, y))
 }
 #[unsafe(no_mangle)]
@@ -9166,6 +10778,7 @@ unsafe extern "C" fn glVertexAttrib2fv(index: GLuint, v: *const GLfloat) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib2fv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib2fv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9176,6 +10789,7 @@ unsafe extern "C" fn glVertexAttrib2s(index: GLuint, x: GLshort, y: GLshort) {
         x,
         y
     );
+    crate::trace::trace_call!(glVertexAttrib2s, index, x, y);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib2s(index, x, y))
 }
 #[unsafe(no_mangle)]
@@ -9185,6 +10799,7 @@ unsafe extern "C" fn glVertexAttrib2sv(index: GLuint, v: *const GLshort) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib2sv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib2sv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9196,6 +10811,7 @@ unsafe extern "C" fn glVertexAttrib3d(index: GLuint, x: GLdouble, y: GLdouble, z
         y,
         z
     );
+    crate::trace::trace_call!(glVertexAttrib3d, index, x, y, z);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib3d(index, x, y, z))
 }
 #[unsafe(no_mangle)]
@@ -9205,6 +10821,7 @@ unsafe extern "C" fn glVertexAttrib3dv(index: GLuint, v: *const GLdouble) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib3dv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib3dv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9216,6 +10833,7 @@ unsafe extern "C" fn glVertexAttrib3f(index: GLuint, x: GLfloat, y: GLfloat, z:
         y,
         z
     );
+    crate::trace::trace_call!(glVertexAttrib3f, index, x, y, z);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib3f(index, x, y, z))
 }
 #[unsafe(no_mangle)]
@@ -9225,6 +10843,7 @@ unsafe extern "C" fn glVertexAttrib3fv(index: GLuint, v: *const GLfloat) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib3fv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib3fv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9236,6 +10855,7 @@ unsafe extern "C" fn glVertexAttrib3s(index: GLuint, x: GLshort, y: GLshort, z:
         y,
         z
     );
+    crate::trace::trace_call!(glVertexAttrib3s, index, x, y, z);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib3s(index, x, y, z))
 }
 #[unsafe(no_mangle)]
@@ -9245,6 +10865,7 @@ unsafe extern "C" fn glVertexAttrib3sv(index: GLuint, v: *const GLshort) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib3sv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib3sv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9254,6 +10875,7 @@ unsafe extern "C" fn glVertexAttrib4Nbv(index: GLuint, v: *const GLbyte) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4Nbv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4_nbv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9263,6 +10885,7 @@ unsafe extern "C" fn glVertexAttrib4Niv(index: GLuint, v: *const GLint) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4Niv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4_niv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9272,6 +10895,7 @@ unsafe extern "C" fn glVertexAttrib4Nsv(index: GLuint, v: *const GLshort) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4Nsv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4_nsv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9290,6 +10914,7 @@ unsafe extern "C" fn glVertexAttrib4Nub(
         z,
         w
     );
+    crate::trace::trace_call!(glVertexAttrib4Nub, index, x, y, z, w);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib4_nub(index, x, y, z, w))
 }
 #[unsafe(no_mangle)]
@@ -9299,6 +10924,7 @@ unsafe extern "C" fn glVertexAttrib4Nubv(index: GLuint, v: *const GLubyte) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4Nubv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4_nubv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9308,6 +10934,7 @@ unsafe extern "C" fn glVertexAttrib4Nuiv(index: GLuint, v: *const GLuint) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4Nuiv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4_nuiv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9317,6 +10944,7 @@ unsafe extern "C" fn glVertexAttrib4Nusv(index: GLuint, v: *const GLushort) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4Nusv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4_nusv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9326,6 +10954,7 @@ unsafe extern "C" fn glVertexAttrib4bv(index: GLuint, v: *const GLbyte) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4bv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4bv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9344,6 +10973,7 @@ unsafe extern "C" fn glVertexAttrib4d(
         z,
         w
     );
+    crate::trace::trace_call!(glVertexAttrib4d, index, x, y, z, w);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib4d(index, x, y, z, w))
 }
 #[unsafe(no_mangle)]
@@ -9353,6 +10983,7 @@ unsafe extern "C" fn glVertexAttrib4dv(index: GLuint, v: *const GLdouble) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4dv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4dv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9371,6 +11002,7 @@ unsafe extern "C" fn glVertexAttrib4f(
         z,
         w
     );
+    crate::trace::trace_call!(glVertexAttrib4f, index, x, y, z, w);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib4f(index, x, y, z, w))
 }
 #[unsafe(no_mangle)]
@@ -9380,6 +11012,7 @@ unsafe extern "C" fn glVertexAttrib4fv(index: GLuint, v: *const GLfloat) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4fv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4fv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9389,6 +11022,7 @@ unsafe extern "C" fn glVertexAttrib4iv(index: GLuint, v: *const GLint) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4iv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4iv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9407,6 +11041,7 @@ unsafe extern "C" fn glVertexAttrib4s(
         z,
         w
     );
+    crate::trace::trace_call!(glVertexAttrib4s, index, x, y, z, w);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib4s(index, x, y, z, w))
 }
 #[unsafe(no_mangle)]
@@ -9416,6 +11051,7 @@ unsafe extern "C" fn glVertexAttrib4sv(index: GLuint, v: *const GLshort) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4sv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4sv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9425,6 +11061,7 @@ unsafe extern "C" fn glVertexAttrib4ubv(index: GLuint, v: *const GLubyte) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4ubv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4ubv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9434,6 +11071,7 @@ unsafe extern "C" fn glVertexAttrib4uiv(index: GLuint, v: *const GLuint) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4uiv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4uiv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9443,6 +11081,7 @@ unsafe extern "C" fn glVertexAttrib4usv(index: GLuint, v: *const GLushort) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttrib4usv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib4usv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9452,6 +11091,7 @@ unsafe extern "C" fn glVertexAttribI1i(index: GLuint, x: GLint) {
         index,
         x
     );
+    crate::trace::trace_call!(glVertexAttribI1i, index, x);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_i1i(index, x))
 }
 #[unsafe(no_mangle)]
@@ -9462,6 +11102,7 @@ unsafe extern "C" fn glVertexAttribI2i(index: GLuint, x: GLint, y: GLint) {
         x,
         y
     );
+    crate::trace::trace_call!(glVertexAttribI2i, index, x, y);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_i2i(index, x, y))
 }
 #[unsafe(no_mangle)]
@@ -9473,6 +11114,7 @@ unsafe extern "C" fn glVertexAttribI3i(index: GLuint, x: GLint, y: GLint, z: GLi
         y,
         z
     );
+    crate::trace::trace_call!(glVertexAttribI3i, index, x, y, z);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_i3i(index, x, y, z))
 }
 #[unsafe(no_mangle)]
@@ -9485,6 +11127,7 @@ unsafe extern "C" fn glVertexAttribI4i(index: GLuint, x: GLint, y: GLint, z: GLi
         z,
         w
     );
+    crate::trace::trace_call!(glVertexAttribI4i, index, x, y, z, w);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_i4i(index, x, y, z, w))
 }
 #[unsafe(no_mangle)]
@@ -9494,6 +11137,7 @@ unsafe extern "C" fn glVertexAttribI1ui(index: GLuint, x: GLuint) {
         index,
         x
     );
+    crate::trace::trace_call!(glVertexAttribI1ui, index, x);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_i1ui(index, x))
 }
 #[unsafe(no_mangle)]
@@ -9504,6 +11148,7 @@ unsafe extern "C" fn glVertexAttribI2ui(index: GLuint, x: GLuint, y: GLuint) {
         x,
         y
     );
+    crate::trace::trace_call!(glVertexAttribI2ui, index, x, y);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_i2ui(index, x, y))
 }
 #[unsafe(no_mangle)]
@@ -9515,6 +11160,7 @@ unsafe extern "C" fn glVertexAttribI3ui(index: GLuint, x: GLuint, y: GLuint, z:
         y,
         z
     );
+    crate::trace::trace_call!(glVertexAttribI3ui, index, x, y, z);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_i3ui(index, x, y, z))
 }
 #[unsafe(no_mangle)]
@@ -9527,6 +11173,7 @@ unsafe extern "C" fn glVertexAttribI4ui(index: GLuint, x: GLuint, y: GLuint, z:
         z,
         w
     );
+    crate::trace::trace_call!(glVertexAttribI4ui, index, x, y, z, w);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_i4ui(index, x, y, z, w))
 }
 #[unsafe(no_mangle)]
@@ -9536,6 +11183,7 @@ unsafe extern "C" fn glVertexAttribI1iv(index: GLuint, v: *const GLint) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribI1iv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_i1iv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9545,6 +11193,7 @@ unsafe extern "C" fn glVertexAttribI2iv(index: GLuint, v: *const GLint) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribI2iv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_i2iv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9554,6 +11203,7 @@ unsafe extern "C" fn glVertexAttribI3iv(index: GLuint, v: *const GLint) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribI3iv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_i3iv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9563,6 +11213,7 @@ unsafe extern "C" fn glVertexAttribI4iv(index: GLuint, v: *const GLint) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribI4iv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_i4iv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9572,6 +11223,7 @@ unsafe extern "C" fn glVertexAttribI1uiv(index: GLuint, v: *const GLuint) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribI1uiv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_i1uiv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9581,6 +11233,7 @@ unsafe extern "C" fn glVertexAttribI2uiv(index: GLuint, v: *const GLuint) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribI2uiv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_i2uiv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9590,6 +11243,7 @@ unsafe extern "C" fn glVertexAttribI3uiv(index: GLuint, v: *const GLuint) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribI3uiv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_i3uiv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9599,6 +11253,7 @@ unsafe extern "C" fn glVertexAttribI4uiv(index: GLuint, v: *const GLuint) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribI4uiv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_i4uiv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9608,6 +11263,7 @@ unsafe extern "C" fn glVertexAttribI4bv(index: GLuint, v: *const GLbyte) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribI4bv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_i4bv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9617,6 +11273,7 @@ unsafe extern "C" fn glVertexAttribI4sv(index: GLuint, v: *const GLshort) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribI4sv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_i4sv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9626,6 +11283,7 @@ unsafe extern "C" fn glVertexAttribI4ubv(index: GLuint, v: *const GLubyte) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribI4ubv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_i4ubv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9635,6 +11293,7 @@ unsafe extern "C" fn glVertexAttribI4usv(index: GLuint, v: *const GLushort) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribI4usv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_i4usv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9651,6 +11310,7 @@ unsafe extern "C" fn glVertexAttribP1ui(
         normalized,
         value
     );
+    crate::trace::trace_call!(glVertexAttribP1ui, index, r#type, normalized, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_vertex_attrib_p1ui(
             index,
@@ -9674,6 +11334,7 @@ unsafe extern "C" fn glVertexAttribP2ui(
         normalized,
         value
     );
+    crate::trace::trace_call!(glVertexAttribP2ui, index, r#type, normalized, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_vertex_attrib_p2ui(
             index,
@@ -9697,6 +11358,7 @@ unsafe extern "C" fn glVertexAttribP3ui(
         normalized,
         value
     );
+    crate::trace::trace_call!(glVertexAttribP3ui, index, r#type, normalized, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_vertex_attrib_p3ui(
             index,
@@ -9720,6 +11382,7 @@ unsafe extern "C" fn glVertexAttribP4ui(
         normalized,
         value
     );
+    crate::trace::trace_call!(glVertexAttribP4ui, index, r#type, normalized, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_vertex_attrib_p4ui(
             index,
@@ -9736,6 +11399,7 @@ unsafe extern "C" fn glVertexAttribL1d(index: GLuint, x: GLdouble) {
         index,
         x
     );
+    crate::trace::trace_call!(glVertexAttribL1d, index, x);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_l1d(index, x))
 }
 #[unsafe(no_mangle)]
@@ -9746,6 +11410,7 @@ unsafe extern "C" fn glVertexAttribL2d(index: GLuint, x: GLdouble, y: GLdouble)
         x,
         y
     );
+    crate::trace::trace_call!(glVertexAttribL2d, index, x, y);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_l2d(index, x, y))
 }
 #[unsafe(no_mangle)]
@@ -9757,6 +11422,7 @@ unsafe extern "C" fn glVertexAttribL3d(index: GLuint, x: GLdouble, y: GLdouble,
         y,
         z
     );
+    crate::trace::trace_call!(glVertexAttribL3d, index, x, y, z);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_l3d(index, x, y, z))
 }
 #[unsafe(no_mangle)]
@@ -9775,6 +11441,7 @@ unsafe extern "C" fn glVertexAttribL4d(
         z,
         w
     );
+    crate::trace::trace_call!(glVertexAttribL4d, index, x, y, z, w);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_l4d(index, x, y, z, w))
 }
 #[unsafe(no_mangle)]
@@ -9784,6 +11451,7 @@ unsafe extern "C" fn glVertexAttribL1dv(index: GLuint, v: *const GLdouble) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribL1dv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_l1dv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9793,6 +11461,7 @@ unsafe extern "C" fn glVertexAttribL2dv(index: GLuint, v: *const GLdouble) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribL2dv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_l2dv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9802,6 +11471,7 @@ unsafe extern "C" fn glVertexAttribL3dv(index: GLuint, v: *const GLdouble) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribL3dv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_l3dv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9811,6 +11481,7 @@ unsafe extern "C" fn glVertexAttribL4dv(index: GLuint, v: *const GLdouble) {
         index,
         v
     );
+    crate::trace::trace_call!(glVertexAttribL4dv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_vertex_attrib_l4dv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -9820,6 +11491,7 @@ unsafe extern "C" fn glVertexAttribBinding(attribindex: GLuint, bindingindex: GL
         attribindex,
         bindingindex
     );
+    crate::trace::trace_call!(glVertexAttribBinding, attribindex, bindingindex);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_binding(attribindex, bindingindex))
 }
 #[unsafe(no_mangle)]
@@ -9834,6 +11506,7 @@ unsafe extern "C" fn glVertexArrayAttribBinding(
         attribindex,
         bindingindex
     );
+    crate::trace::trace_call!(glVertexArrayAttribBinding, vaobj, attribindex, bindingindex);
     with_ctx_mut(|mut state| {
         state.oxidegl_vertex_array_attrib_binding(vaobj, attribindex, bindingindex)
     })
@@ -9845,6 +11518,7 @@ unsafe extern "C" fn glVertexAttribDivisor(index: GLuint, divisor: GLuint) {
         index,
         divisor
     );
+    crate::trace::trace_call!(glVertexAttribDivisor, index, divisor);
     with_ctx_mut(|mut state| state.oxidegl_vertex_attrib_divisor(index, divisor))
 }
 #[unsafe(no_mangle)]
@@ -9863,6 +11537,14 @@ unsafe extern "C" fn glVertexAttribFormat(
         normalized,
         relativeoffset
     );
+    crate::trace::trace_call!(
+        glVertexAttribFormat,
+        attribindex,
+        size,
+        r#type,
+        normalized,
+        relativeoffset
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_vertex_attrib_format(
             attribindex,
@@ -9887,6 +11569,13 @@ unsafe extern "C" fn glVertexAttribIFormat(
         r#type,
         relativeoffset
     );
+    crate::trace::trace_call!(
+        glVertexAttribIFormat,
+        attribindex,
+        size,
+        r#type,
+        relativeoffset
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_vertex_attrib_i_format(
             attribindex,
@@ -9910,6 +11599,13 @@ unsafe extern "C" fn glVertexAttribLFormat(
         r#type,
         relativeoffset
     );
+    crate::trace::trace_call!(
+        glVertexAttribLFormat,
+        attribindex,
+        size,
+        r#type,
+        relativeoffset
+    );
     with_ctx_mut(|mut state| {
         state.oxidegl_vertex_attrib_l_format(attribindex, size, r#type, relativeoffset)
     })
@@ -9932,6 +11628,15 @@ unsafe extern "C" fn glVertexArrayAttribFormat(
         normalized,
         relativeoffset
     );
+    crate::trace::trace_call!(
+        glVertexArrayAttribFormat,
+        vaobj,
+        attribindex,
+        size,
+        r#type,
+        normalized,
+        relativeoffset
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_vertex_array_attrib_format(
             vaobj,
@@ -9959,6 +11664,14 @@ unsafe extern "C" fn glVertexArrayAttribIFormat(
         r#type,
         relativeoffset
     );
+    crate::trace::trace_call!(
+        glVertexArrayAttribIFormat,
+        vaobj,
+        attribindex,
+        size,
+        r#type,
+        relativeoffset
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(state.oxidegl_vertex_array_attrib_i_format(
             vaobj,
@@ -9985,6 +11698,14 @@ unsafe extern "C" fn glVertexArrayAttribLFormat(
         r#type,
         relativeoffset
     );
+    crate::trace::trace_call!(
+        glVertexArrayAttribLFormat,
+        vaobj,
+        attribindex,
+        size,
+        r#type,
+        relativeoffset
+    );
     with_ctx_mut(|mut state| {
         state.oxidegl_vertex_array_attrib_l_format(vaobj, attribindex, size, r#type, relativeoffset)
     })
@@ -10007,6 +11728,15 @@ unsafe extern "C" fn glVertexAttribPointer(
         stride,
         pointer
     );
+    crate::trace::trace_call!(
+        glVertexAttribPointer,
+        index,
+        size,
+        r#type,
+        normalized,
+        stride,
+        pointer
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_vertex_attrib_pointer(
@@ -10036,6 +11766,7 @@ unsafe extern "C" fn glVertexAttribIPointer(
         stride,
         pointer
     );
+    crate::trace::trace_call!(glVertexAttribIPointer, index, size, r#type, stride, pointer);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_vertex_attrib_i_pointer(
@@ -10064,6 +11795,7 @@ unsafe extern "C" fn glVertexAttribLPointer(
         stride,
         pointer
     );
+    crate::trace::trace_call!(glVertexAttribLPointer, index, size, r#type, stride, pointer);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_vertex_attrib_l_pointer(index, size, r#type, stride, pointer)
     })
@@ -10075,6 +11807,7 @@ unsafe extern "C" fn glVertexBindingDivisor(bindingindex: GLuint, divisor: GLuin
         bindingindex,
         divisor
     );
+    crate::trace::trace_call!(glVertexBindingDivisor, bindingindex, divisor);
     with_ctx_mut(|mut state| state.oxidegl_vertex_binding_divisor(bindingindex, divisor))
 }
 #[unsafe(no_mangle)]
@@ -10089,6 +11822,7 @@ unsafe extern "C" fn glVertexArrayBindingDivisor(
         bindingindex,
         divisor
     );
+    crate::trace::trace_call!(glVertexArrayBindingDivisor, vaobj, bindingindex, divisor);
     with_ctx_mut(|mut state| {
         state.oxidegl_vertex_array_binding_divisor(vaobj, bindingindex, divisor)
     })
@@ -10102,6 +11836,7 @@ unsafe extern "C" fn glViewport(x: GLint, y: GLint, width: GLsizei, height: GLsi
         width,
         height
     );
+    crate::trace::trace_call!(glViewport, x, y, width, height);
     with_ctx_mut(|mut state| state.oxidegl_viewport(x, y, width, height))
 }
 #[unsafe(no_mangle)]
@@ -10112,6 +11847,7 @@ unsafe extern "C" fn glViewportArrayv(first: GLuint, count: GLsizei, v: *const G
         count,
         v
     );
+    crate::trace::trace_call!(glViewportArrayv, first, count, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_viewport_arrayv(first, count, v) })
 }
 #[unsafe(no_mangle)]
@@ -10130,6 +11866,7 @@ unsafe extern "C" fn glViewportIndexedf(
         w,
         h
     );
+    crate::trace::trace_call!(glViewportIndexedf, index, x, y, w, h);
     with_ctx_mut(|mut state| state.oxidegl_viewport_indexedf(index, x, y, w, h))
 }
 #[unsafe(no_mangle)]
@@ -10139,6 +11876,7 @@ unsafe extern "C" fn glViewportIndexedfv(index: GLuint, v: *const GLfloat) {
         index,
         v
     );
+    crate::trace::trace_call!(glViewportIndexedfv, index, v);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_viewport_indexedfv(index, v) })
 }
 #[unsafe(no_mangle)]
@@ -10149,6 +11887,7 @@ unsafe extern "C" fn glWaitSync(sync: GLsync, flags: GLbitfield, timeout: GLuint
         flags,
         timeout
     );
+    crate::trace::trace_call!(glWaitSync, sync, flags, timeout);
     with_ctx_mut(|mut state| state.oxidegl_wait_sync(sync, flags, timeout))
 }
 #[unsafe(no_mangle)]
@@ -10165,6 +11904,7 @@ unsafe extern "C" fn glVertexAttribP1uiv(
         normalized,
         value
     );
+    crate::trace::trace_call!(glVertexAttribP1uiv, index, r#type, normalized, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_vertex_attrib_p1uiv(index, r#type.try_into_enum()?, normalized, value)
@@ -10185,6 +11925,7 @@ unsafe extern "C" fn glVertexAttribP2uiv(
         normalized,
         value
     );
+    crate::trace::trace_call!(glVertexAttribP2uiv, index, r#type, normalized, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_vertex_attrib_p2uiv(index, r#type.try_into_enum()?, normalized, value)
@@ -10205,6 +11946,7 @@ unsafe extern "C" fn glVertexAttribP3uiv(
         normalized,
         value
     );
+    crate::trace::trace_call!(glVertexAttribP3uiv, index, r#type, normalized, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_vertex_attrib_p3uiv(index, r#type.try_into_enum()?, normalized, value)
@@ -10225,6 +11967,7 @@ unsafe extern "C" fn glVertexAttribP4uiv(
         normalized,
         value
     );
+    crate::trace::trace_call!(glVertexAttribP4uiv, index, r#type, normalized, value);
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_vertex_attrib_p4uiv(index, r#type.try_into_enum()?, normalized, value)
@@ -10238,6 +11981,7 @@ unsafe extern "C" fn glUniform1d(location: GLint, x: GLdouble) {
         location,
         x
     );
+    crate::trace::trace_call!(glUniform1d, location, x);
     with_ctx_mut(|mut state| state.oxidegl_uniform1d(location, x))
 }
 #[unsafe(no_mangle)]
@@ -10248,6 +11992,7 @@ unsafe extern "C" fn glUniform2d(location: GLint, x: GLdouble, y: GLdouble) {
         x,
         y
     );
+    crate::trace::trace_call!(glUniform2d, location, x, y);
     with_ctx_mut(|mut state| state.oxidegl_uniform2d(location, x, y))
 }
 #[unsafe(no_mangle)]
@@ -10259,6 +12004,7 @@ unsafe extern "C" fn glUniform3d(location: GLint, x: GLdouble, y: GLdouble, z: G
         y,
         z
     );
+    crate::trace::trace_call!(glUniform3d, location, x, y, z);
     with_ctx_mut(|mut state| state.oxidegl_uniform3d(location, x, y, z))
 }
 #[unsafe(no_mangle)]
@@ -10277,6 +12023,7 @@ unsafe extern "C" fn glUniform4d(
         z,
         w
     );
+    crate::trace::trace_call!(glUniform4d, location, x, y, z, w);
     with_ctx_mut(|mut state| state.oxidegl_uniform4d(location, x, y, z, w))
 }
 #[unsafe(no_mangle)]
@@ -10287,6 +12034,7 @@ unsafe extern "C" fn glUniform1dv(location: GLint, count: GLsizei, value: *const
         count,
         value
     );
+    crate::trace::trace_call!(glUniform1dv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform1dv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -10297,6 +12045,7 @@ unsafe extern "C" fn glUniform2dv(location: GLint, count: GLsizei, value: *const
         count,
         value
     );
+    crate::trace::trace_call!(glUniform2dv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform2dv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -10307,6 +12056,7 @@ unsafe extern "C" fn glUniform3dv(location: GLint, count: GLsizei, value: *const
         count,
         value
     );
+    crate::trace::trace_call!(glUniform3dv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform3dv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -10317,6 +12067,7 @@ unsafe extern "C" fn glUniform4dv(location: GLint, count: GLsizei, value: *const
         count,
         value
     );
+    crate::trace::trace_call!(glUniform4dv, location, count, value);
     with_ctx_mut(|mut state| unsafe { state.oxidegl_uniform4dv(location, count, value) })
 }
 #[unsafe(no_mangle)]
@@ -10333,6 +12084,7 @@ unsafe extern "C" fn glUniformMatrix2dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix2dv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix2dv(location, count, transpose, value)
     })
@@ -10351,6 +12103,7 @@ unsafe extern "C" fn glUniformMatrix3dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix3dv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix3dv(location, count, transpose, value)
     })
@@ -10369,6 +12122,7 @@ unsafe extern "C" fn glUniformMatrix4dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix4dv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix4dv(location, count, transpose, value)
     })
@@ -10387,6 +12141,7 @@ unsafe extern "C" fn glUniformMatrix2x3dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix2x3dv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix2x3dv(location, count, transpose, value)
     })
@@ -10405,6 +12160,7 @@ unsafe extern "C" fn glUniformMatrix2x4dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix2x4dv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix2x4dv(location, count, transpose, value)
     })
@@ -10423,6 +12179,7 @@ unsafe extern "C" fn glUniformMatrix3x2dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix3x2dv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix3x2dv(location, count, transpose, value)
     })
@@ -10441,6 +12198,7 @@ unsafe extern "C" fn glUniformMatrix3x4dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix3x4dv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix3x4dv(location, count, transpose, value)
     })
@@ -10459,6 +12217,7 @@ unsafe extern "C" fn glUniformMatrix4x2dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix4x2dv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix4x2dv(location, count, transpose, value)
     })
@@ -10477,6 +12236,7 @@ unsafe extern "C" fn glUniformMatrix4x3dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(glUniformMatrix4x3dv, location, count, transpose, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_uniform_matrix4x3dv(location, count, transpose, value)
     })
@@ -10489,6 +12249,7 @@ unsafe extern "C" fn glProgramUniform1d(program: GLuint, location: GLint, v0: GL
         location,
         v0
     );
+    crate::trace::trace_call!(glProgramUniform1d, program, location, v0);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform1d(program, location, v0))
 }
 #[unsafe(no_mangle)]
@@ -10505,6 +12266,7 @@ unsafe extern "C" fn glProgramUniform1dv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform1dv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform1dv(program, location, count, value)
     })
@@ -10523,6 +12285,7 @@ unsafe extern "C" fn glProgramUniform2d(
         v0,
         v1
     );
+    crate::trace::trace_call!(glProgramUniform2d, program, location, v0, v1);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform2d(program, location, v0, v1))
 }
 #[unsafe(no_mangle)]
@@ -10539,6 +12302,7 @@ unsafe extern "C" fn glProgramUniform2dv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform2dv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform2dv(program, location, count, value)
     })
@@ -10559,6 +12323,7 @@ unsafe extern "C" fn glProgramUniform3d(
         v1,
         v2
     );
+    crate::trace::trace_call!(glProgramUniform3d, program, location, v0, v1, v2);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform3d(program, location, v0, v1, v2))
 }
 #[unsafe(no_mangle)]
@@ -10575,6 +12340,7 @@ unsafe extern "C" fn glProgramUniform3dv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform3dv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform3dv(program, location, count, value)
     })
@@ -10597,6 +12363,7 @@ unsafe extern "C" fn glProgramUniform4d(
         v2,
         v3
     );
+    crate::trace::trace_call!(glProgramUniform4d, program, location, v0, v1, v2, v3);
     with_ctx_mut(|mut state| state.oxidegl_program_uniform4d(program, location, v0, v1, v2, v3))
 }
 #[unsafe(no_mangle)]
@@ -10613,6 +12380,7 @@ unsafe extern "C" fn glProgramUniform4dv(
         count,
         value
     );
+    crate::trace::trace_call!(glProgramUniform4dv, program, location, count, value);
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform4dv(program, location, count, value)
     })
@@ -10633,6 +12401,14 @@ unsafe extern "C" fn glProgramUniformMatrix2dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix2dv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix2dv(program, location, count, transpose, value)
     })
@@ -10653,6 +12429,14 @@ unsafe extern "C" fn glProgramUniformMatrix3dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix3dv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix3dv(program, location, count, transpose, value)
     })
@@ -10673,6 +12457,14 @@ unsafe extern "C" fn glProgramUniformMatrix4dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix4dv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix4dv(program, location, count, transpose, value)
     })
@@ -10693,6 +12485,14 @@ unsafe extern "C" fn glProgramUniformMatrix2x3dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix2x3dv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix2x3dv(program, location, count, transpose, value)
     })
@@ -10713,6 +12513,14 @@ unsafe extern "C" fn glProgramUniformMatrix3x2dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix3x2dv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix3x2dv(program, location, count, transpose, value)
     })
@@ -10733,6 +12541,14 @@ unsafe extern "C" fn glProgramUniformMatrix2x4dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix2x4dv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix2x4dv(program, location, count, transpose, value)
     })
@@ -10753,6 +12569,14 @@ unsafe extern "C" fn glProgramUniformMatrix4x2dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix4x2dv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix4x2dv(program, location, count, transpose, value)
     })
@@ -10773,6 +12597,14 @@ unsafe extern "C" fn glProgramUniformMatrix3x4dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix3x4dv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix3x4dv(program, location, count, transpose, value)
     })
@@ -10793,6 +12625,14 @@ unsafe extern "C" fn glProgramUniformMatrix4x3dv(
         transpose,
         value
     );
+    crate::trace::trace_call!(
+        glProgramUniformMatrix4x3dv,
+        program,
+        location,
+        count,
+        transpose,
+        value
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_program_uniform_matrix4x3dv(program, location, count, transpose, value)
     })
@@ -10813,6 +12653,14 @@ unsafe extern "C" fn glSpecializeShader(
         pConstantIndex,
         pConstantValue
     );
+    crate::trace::trace_call!(
+        glSpecializeShader,
+        shader,
+        pEntryPoint,
+        numSpecializationConstants,
+        pConstantIndex,
+        pConstantValue
+    );
     with_ctx_mut(|mut state| unsafe {
         state.oxidegl_specialize_shader(
             shader,
@@ -10839,6 +12687,14 @@ unsafe extern "C" fn glMultiDrawArraysIndirectCount(
         maxdrawcount,
         stride
     );
+    crate::trace::trace_call!(
+        glMultiDrawArraysIndirectCount,
+        mode,
+        indirect,
+        drawcount,
+        maxdrawcount,
+        stride
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_multi_draw_arrays_indirect_count(
@@ -10869,6 +12725,15 @@ unsafe extern "C" fn glMultiDrawElementsIndirectCount(
         maxdrawcount,
         stride
     );
+    crate::trace::trace_call!(
+        glMultiDrawElementsIndirectCount,
+        mode,
+        r#type,
+        indirect,
+        drawcount,
+        maxdrawcount,
+        stride
+    );
     with_ctx_mut(|mut state| {
         GlResult::normalize(unsafe {
             state.oxidegl_multi_draw_elements_indirect_count(
@@ -10890,5 +12755,6 @@ unsafe extern "C" fn glPolygonOffsetClamp(factor: GLfloat, units: GLfloat, clamp
         units,
         clamp
     );
+    crate::trace::trace_call!(glPolygonOffsetClamp, factor, units, clamp);
     with_ctx_mut(|mut state| state.oxidegl_polygon_offset_clamp(factor, units, clamp))
 }
@@ -0,0 +1,23 @@
+//! C ABI shims for the extension commands OxideGL supports, which are not covered by the generated core shims
+
+use crate::context::with_ctx_mut;
+use oxidegl::gl_types::GLuint;
+
+#[unsafe(no_mangle)]
+unsafe extern "C" fn glMaxShaderCompilerThreadsKHR(count: GLuint) {
+    ::log::trace!(
+        "glMaxShaderCompilerThreadsKHR called, parameters: count: {:?} ",
+        count
+    );
+    crate::trace::trace_call!(glMaxShaderCompilerThreadsKHR, count);
+    with_ctx_mut(|mut state| state.oxidegl_max_shader_compiler_threads(count));
+}
+#[unsafe(no_mangle)]
+unsafe extern "C" fn glMaxShaderCompilerThreadsARB(count: GLuint) {
+    ::log::trace!(
+        "glMaxShaderCompilerThreadsARB called, parameters: count: {:?} ",
+        count
+    );
+    crate::trace::trace_call!(glMaxShaderCompilerThreadsARB, count);
+    with_ctx_mut(|mut state| state.oxidegl_max_shader_compiler_threads(count));
+}
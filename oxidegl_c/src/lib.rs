@@ -2,7 +2,9 @@ use std::{env, ffi::c_void, ptr::NonNull};
 
 use flexi_logger::Logger;
 use log::{debug, trace};
+#[cfg(target_vendor = "apple")]
 use objc2::rc::Retained;
+#[cfg(target_vendor = "apple")]
 use objc2_app_kit::NSView;
 
 use context::{CTX, with_ctx_mut};
@@ -21,12 +23,15 @@ pub mod context;
     clippy::missing_safety_doc,
 )]
 pub mod gl_core;
+pub mod gl_ext;
+pub mod trace;
 
 // Safety: we pray no one is using this symbol name, and prefix our function name with oxidegl
 #[unsafe(no_mangle)]
 /// # Safety
 /// if ctx is Some(a) then a must point to a valid, initalized [`Context`]
 pub unsafe extern "C" fn oxidegl_set_current_context(ctx: Option<NonNull<Context>>) {
+    trace::trace_call!(oxidegl_set_current_context, ctx);
     set_context(ctx);
 }
 pub fn set_context(ctx: Option<NonNull<Context>>) {
@@ -55,8 +60,10 @@ pub fn swap_buffers() {
 #[unsafe(no_mangle)]
 /// # Safety
 /// if ctx is Some(a) then a must point to a valid, initalized [`Context`]
-pub unsafe extern "C" fn oxidegl_swap_buffers(_ctx: Option<NonNull<Context>>) {
+pub unsafe extern "C" fn oxidegl_swap_buffers(ctx: Option<NonNull<Context>>) {
+    trace::trace_call!(oxidegl_swap_buffers, ctx);
     swap_buffers();
+    trace::flush();
 }
 #[must_use]
 pub fn box_ctx(ctx: Context) -> NonNull<Context> {
@@ -70,6 +77,7 @@ pub fn box_ctx(ctx: Context) -> NonNull<Context> {
 /// This needs to be run as early as possible (ideally before the program spawns a thread other than the main thread)
 pub unsafe extern "C" fn oxidegl_platform_init() {
     init_logger();
+    trace::init();
     unsafe {
         oxidegl::debug_init();
     }
@@ -91,6 +99,8 @@ pub(crate) fn init_logger() {
 /// This function must be called at most once per allocated context to avoid a double free
 /// ctx, if Some must point to a valid Context and be exclusive
 pub unsafe extern "C" fn oxidegl_destroy_context(ctx: Option<NonNull<Context>>) {
+    trace::trace_call!(oxidegl_destroy_context, ctx);
+    trace::flush();
     if let Some(c) = ctx {
         // Safety: caller ensures c points to a valid Context struct, allocated via a Box with the same allocator
         // Caller ensures c is the exclusive pointer to ctx
@@ -101,6 +111,7 @@ pub unsafe extern "C" fn oxidegl_destroy_context(ctx: Option<NonNull<Context>>)
 #[unsafe(no_mangle)]
 /// # Safety
 /// view must point to a valid instance of NSView
+#[cfg(target_vendor = "apple")]
 pub unsafe extern "C" fn oxidegl_create_context(
     view: *mut NSView,
     // TODO make these params do something idk
@@ -117,5 +128,7 @@ pub unsafe extern "C" fn oxidegl_create_context(
 
     ctx.set_view(&view);
     debug!("Created context");
-    box_ctx(ctx).as_ptr().cast()
+    let ctx = box_ctx(ctx);
+    trace::trace_call!(oxidegl_create_context, ctx.as_ptr());
+    ctx.as_ptr().cast()
 }
@@ -0,0 +1,467 @@
+//! Capture of the GL call stream to a binary trace file, so that rendering bugs can be reproduced away from the machine
+//! they occured on. Capture is enabled by setting the `OXIDEGL_TRACE` environment variable to the path of the file
+//! the trace should be written to, or by building with the `trace` feature, which writes to `oxidegl.trace` in the
+//! working directory if `OXIDEGL_TRACE` is unset.
+//!
+//! # Format
+//! All integers are little endian. A trace starts with [`TRACE_MAGIC`] and the u32 [`TRACE_FORMAT_VERSION`], followed
+//! by one record per call:
+//! * u16 length of the command name, followed by the UTF-8 command name (e.g. `glDrawArrays`)
+//! * u8 argument count, followed by each argument as a u64. Integers are sign or zero extended, floats are stored as
+//!   their bit pattern and pointers as their address
+//! * u16 number of client memory blobs, followed by each blob: the u8 index of the pointer argument it was read from,
+//!   a u64 length and the bytes themselves
+//!
+//! Arguments that point to an array of pointers (e.g. the strings passed to glShaderSource) produce one blob per element,
+//! in order. Pointer arguments whose pointee size is not known by [`client_memory`], either from the `len` attribute of
+//! the parameter in gl.xml or by hand, are only recorded by address.
+//!
+//! Besides GL commands, traces contain records for the `oxidegl_*` context management entry points. The argument of
+//! `oxidegl_create_context` is the address of the newly created context, which identifies it in subsequent
+//! `oxidegl_set_current_context` and `oxidegl_destroy_context` records.
+use std::{
+    env,
+    ffi::{CStr, c_char},
+    fs::File,
+    io::{self, BufWriter, Write},
+    ptr::NonNull,
+    slice,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use log::{error, info};
+use oxidegl::{
+    context::Context,
+    gl_enums::{
+        GL_COLOR, GL_PATCH_DEFAULT_OUTER_LEVEL, GL_TEXTURE_BORDER_COLOR, GL_UNSIGNED_BYTE,
+        GL_UNSIGNED_SHORT,
+    },
+    gl_types::{GLDEBUGPROC, GLsync},
+};
+
+use crate::context::with_ctx;
+
+mod pointer_lengths;
+
+/// Magic bytes at the start of every trace file
+pub const TRACE_MAGIC: &[u8; 8] = b"OXGLTRCE";
+/// Version of the trace format, incremented on every incompatible change
+pub const TRACE_FORMAT_VERSION: u32 = 1;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static WRITER: Mutex<Option<BufWriter<File>>> = Mutex::new(None);
+
+/// Start capturing a trace if `OXIDEGL_TRACE` is set
+pub(crate) fn init() {
+    let Some(path) = env::var_os("OXIDEGL_TRACE")
+        .or_else(|| cfg!(feature = "trace").then(|| "oxidegl.trace".into()))
+    else {
+        return;
+    };
+    let res = File::create(&path).and_then(|f| {
+        let mut w = BufWriter::new(f);
+        w.write_all(TRACE_MAGIC)?;
+        w.write_all(&TRACE_FORMAT_VERSION.to_le_bytes())?;
+        Ok(w)
+    });
+    match res {
+        Ok(w) => {
+            *WRITER.lock().unwrap() = Some(w);
+            ENABLED.store(true, Ordering::Release);
+            info!("capturing GL call trace to {}", path.display());
+        }
+        Err(e) => error!("failed to create trace file {}: {e}", path.display()),
+    }
+}
+/// Whether trace capture is currently enabled
+#[inline]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+/// Flush the captured trace to disk
+pub(crate) fn flush() {
+    if !is_enabled() {
+        return;
+    }
+    if let Some(w) = WRITER.lock().unwrap().as_mut()
+        && let Err(e) = w.flush()
+    {
+        error!("failed to flush trace file: {e}");
+    }
+}
+
+/// Conversion of a C ABI argument into its raw trace representation
+pub trait TraceArg {
+    fn trace_bits(self) -> u64;
+}
+macro_rules! impl_trace_arg {
+    (unsigned: $($u:ty),*; signed: $($s:ty),*) => {
+        $(impl TraceArg for $u {
+            #[inline]
+            fn trace_bits(self) -> u64 {
+                self as u64
+            }
+        })*
+        $(impl TraceArg for $s {
+            #[inline]
+            fn trace_bits(self) -> u64 {
+                self as i64 as u64
+            }
+        })*
+    };
+}
+impl_trace_arg!(unsigned: u8, u16, u32, u64, usize; signed: i8, i16, i32, i64, isize);
+impl TraceArg for bool {
+    #[inline]
+    fn trace_bits(self) -> u64 {
+        u64::from(self)
+    }
+}
+impl TraceArg for f32 {
+    #[inline]
+    fn trace_bits(self) -> u64 {
+        u64::from(self.to_bits())
+    }
+}
+impl TraceArg for f64 {
+    #[inline]
+    fn trace_bits(self) -> u64 {
+        self.to_bits()
+    }
+}
+impl<T> TraceArg for *const T {
+    #[inline]
+    fn trace_bits(self) -> u64 {
+        self.addr() as u64
+    }
+}
+impl<T> TraceArg for *mut T {
+    #[inline]
+    fn trace_bits(self) -> u64 {
+        self.addr() as u64
+    }
+}
+impl<T> TraceArg for Option<NonNull<T>> {
+    #[inline]
+    fn trace_bits(self) -> u64 {
+        self.map_or(0, |p| p.addr().get() as u64)
+    }
+}
+impl TraceArg for GLDEBUGPROC {
+    #[inline]
+    fn trace_bits(self) -> u64 {
+        self.map_or(0, |f| f as usize as u64)
+    }
+}
+impl TraceArg for GLsync {
+    #[inline]
+    fn trace_bits(self) -> u64 {
+        self.map_or(0, |f| f as usize as u64)
+    }
+}
+
+/// Record a call to the given command, if trace capture is enabled
+macro_rules! trace_call {
+    ($name:ident $(, $arg:expr)* $(,)?) => {
+        if $crate::trace::is_enabled() {
+            #[allow(unused_imports)]
+            use $crate::trace::TraceArg as _;
+            // Safety: the arguments are those of a call to the named command, so any client memory
+            // they reference is valid for the duration of that call
+            unsafe { $crate::trace::record_call(stringify!($name), &[$($arg.trace_bits()),*]) }
+        }
+    };
+}
+pub(crate) use trace_call;
+
+/// Append a call record to the trace
+/// # Safety
+/// `args` must be the arguments of a valid call to the command `name`, in order. Any client memory referenced by
+/// them must be valid for reads
+pub unsafe fn record_call(name: &str, args: &[u64]) {
+    // Safety: guaranteed by caller
+    let blobs = unsafe { client_memory(name, args) };
+    let mut guard = WRITER.lock().unwrap();
+    let Some(w) = guard.as_mut() else {
+        return;
+    };
+    if let Err(e) = write_record(w, name, args, &blobs) {
+        error!("failed to write to trace file, disabling trace capture: {e}");
+        ENABLED.store(false, Ordering::Release);
+        *guard = None;
+    }
+}
+fn write_record(
+    w: &mut impl Write,
+    name: &str,
+    args: &[u64],
+    blobs: &[(usize, Vec<u8>)],
+) -> io::Result<()> {
+    w.write_all(&(name.len() as u16).to_le_bytes())?;
+    w.write_all(name.as_bytes())?;
+    w.write_all(&[args.len() as u8])?;
+    for a in args {
+        w.write_all(&a.to_le_bytes())?;
+    }
+    w.write_all(&(blobs.len() as u16).to_le_bytes())?;
+    for (arg, bytes) in blobs {
+        w.write_all(&[*arg as u8])?;
+        w.write_all(&(bytes.len() as u64).to_le_bytes())?;
+        w.write_all(bytes)?;
+    }
+    Ok(())
+}
+
+/// Length of the client memory referenced by a pointer argument
+#[derive(Debug, Clone, Copy)]
+enum PointerLen {
+    /// `stride` bytes per unit of the integer argument `count`
+    Array { count: usize, stride: i64 },
+    /// a fixed number of bytes
+    Fixed(i64),
+    /// an array of NUL-terminated strings, with as many elements as the integer argument at this index
+    Strings(usize),
+    /// a single NUL-terminated string
+    NulTerminated,
+}
+
+/// Copy the client memory referenced by the pointer arguments of a call
+/// # Safety
+/// see [`record_call`]
+unsafe fn client_memory(name: &str, args: &[u64]) -> Vec<(usize, Vec<u8>)> {
+    let mut blobs: Vec<(usize, Vec<u8>)> = Vec::new();
+    let int = |arg: usize| args[arg] as i64;
+    let ptr = |arg: usize| args[arg] as usize as *const u8;
+    // non-null pointers are always recorded, even when empty, since the replayer can't use the original address
+    let bytes = |blobs: &mut Vec<_>, arg: usize, len: i64| {
+        if !ptr(arg).is_null() {
+            // Safety: caller ensures client memory referenced by the call is valid
+            let mem = unsafe { slice::from_raw_parts(ptr(arg), len.max(0) as usize) };
+            blobs.push((arg, mem.to_vec()));
+        }
+    };
+    // a string of the given length, or a NUL-terminated string if the length is negative
+    let string = |p: *const u8, len: i64| -> Vec<u8> {
+        if len < 0 {
+            // Safety: caller ensures client memory referenced by the call is valid
+            unsafe { CStr::from_ptr(p.cast::<c_char>()) }
+                .to_bytes()
+                .to_vec()
+        } else {
+            // Safety: caller ensures client memory referenced by the call is valid
+            unsafe { slice::from_raw_parts(p, len as usize) }.to_vec()
+        }
+    };
+    // an array of `count` strings, with lengths given by the array at `lengths` (if present)
+    let string_array = |blobs: &mut Vec<_>, arg: usize, count: i64, lengths: Option<usize>| {
+        let strings = ptr(arg).cast::<*const u8>();
+        let lengths = lengths
+            .map(|l| ptr(l).cast::<i32>())
+            .filter(|l| !l.is_null());
+        for i in 0..count.max(0) as usize {
+            // Safety: caller ensures client memory referenced by the call is valid
+            let (s, len) = unsafe {
+                (
+                    strings.add(i).read_unaligned(),
+                    lengths.map_or(-1, |l| i64::from(l.add(i).read_unaligned())),
+                )
+            };
+            blobs.push((arg, string(s, len)));
+        }
+    };
+    let c_str = |blobs: &mut Vec<_>, arg: usize| {
+        if !ptr(arg).is_null() {
+            blobs.push((arg, string(ptr(arg), -1)));
+        }
+    };
+    let index_size = |ty: i64| match ty as u32 {
+        GL_UNSIGNED_BYTE => 1,
+        GL_UNSIGNED_SHORT => 2,
+        _ => 4,
+    };
+    // without a current context the indices are assumed to be a buffer offset, which is never dereferenced
+    let element_buffer_bound = || with_ctx(Context::element_array_buffer_bound).unwrap_or(true);
+
+    match name {
+        // the gl.xml length of the data is COMPSIZE(size)
+        "glNamedBufferSubData" => bytes(&mut blobs, 3, int(2)),
+        "glShaderSource" => {
+            string_array(&mut blobs, 2, int(1), Some(3));
+            // the lengths are needed to replay strings that are not NUL-terminated
+            bytes(&mut blobs, 3, int(1) * 4);
+        }
+        "glGetUniformIndices" => string_array(&mut blobs, 2, int(1), None),
+        "glObjectLabel" | "glPushDebugGroup" if !ptr(3).is_null() => {
+            blobs.push((3, string(ptr(3), int(2))));
+        }
+        "glObjectPtrLabel" if !ptr(2).is_null() => blobs.push((2, string(ptr(2), int(1)))),
+        "glDebugMessageInsert" => blobs.push((5, string(ptr(5), int(4)))),
+        // every core profile point parameter is a single value
+        "glPointParameterfv" | "glPointParameteriv" => bytes(&mut blobs, 1, 4),
+        // 4 floats or ints per viewport or scissor box
+        "glViewportArrayv" | "glScissorArrayv" => bytes(&mut blobs, 2, int(1) * 16),
+        // a near and far GLdouble per viewport
+        "glDepthRangeArrayv" => bytes(&mut blobs, 2, int(1) * 16),
+        // 4 outer or 2 inner tessellation levels
+        "glPatchParameterfv" => {
+            bytes(
+                &mut blobs,
+                1,
+                if int(0) as u32 == GL_PATCH_DEFAULT_OUTER_LEVEL {
+                    16
+                } else {
+                    8
+                },
+            );
+        }
+        "glMultiDrawArrays" => {
+            bytes(&mut blobs, 1, int(3) * 4);
+            bytes(&mut blobs, 2, int(3) * 4);
+        }
+        "glClearBufferiv" | "glClearBufferuiv" | "glClearBufferfv" => {
+            bytes(
+                &mut blobs,
+                2,
+                if int(0) as u32 == GL_COLOR { 16 } else { 4 },
+            );
+        }
+        "glClearNamedFramebufferiv"
+        | "glClearNamedFramebufferuiv"
+        | "glClearNamedFramebufferfv" => {
+            bytes(
+                &mut blobs,
+                3,
+                if int(1) as u32 == GL_COLOR { 16 } else { 4 },
+            );
+        }
+        "glTexParameterfv"
+        | "glTexParameteriv"
+        | "glTexParameterIiv"
+        | "glTexParameterIuiv"
+        | "glTextureParameterfv"
+        | "glTextureParameteriv"
+        | "glTextureParameterIiv"
+        | "glTextureParameterIuiv"
+        | "glSamplerParameterfv"
+        | "glSamplerParameteriv"
+        | "glSamplerParameterIiv"
+        | "glSamplerParameterIuiv" => {
+            bytes(
+                &mut blobs,
+                2,
+                if int(1) as u32 == GL_TEXTURE_BORDER_COLOR {
+                    16
+                } else {
+                    4
+                },
+            );
+        }
+        "glDrawElements"
+        | "glDrawElementsInstanced"
+        | "glDrawElementsBaseVertex"
+        | "glDrawElementsInstancedBaseVertex"
+        | "glDrawElementsInstancedBaseInstance"
+        | "glDrawElementsInstancedBaseVertexBaseInstance" => {
+            if !element_buffer_bound() {
+                bytes(&mut blobs, 3, int(1) * index_size(int(2)));
+            }
+        }
+        "glDrawRangeElements" | "glDrawRangeElementsBaseVertex" => {
+            if !element_buffer_bound() {
+                bytes(&mut blobs, 5, int(3) * index_size(int(4)));
+            }
+        }
+        name => {
+            for &(arg, len) in pointer_lengths::pointer_lengths(name) {
+                match len {
+                    PointerLen::Array { count, stride } => {
+                        bytes(&mut blobs, arg, int(count) * stride);
+                    }
+                    PointerLen::Fixed(len) => bytes(&mut blobs, arg, len),
+                    PointerLen::Strings(count) => string_array(&mut blobs, arg, int(count), None),
+                    PointerLen::NulTerminated => c_str(&mut blobs, arg),
+                }
+            }
+        }
+    }
+    blobs
+}
+
+#[test]
+fn trace_client_memory() {
+    let buffers: [u32; 2] = [3, 5];
+    let offsets: [isize; 2] = [0, 256];
+    let sizes: [isize; 2] = [64, 128];
+    let viewports: [f32; 8] = [0.0, 0.0, 64.0, 32.0, 64.0, 0.0, 64.0, 32.0];
+    let addr = |p: *const u8| p.addr() as u64;
+    let calls = [
+        (
+            "glBindBuffersRange",
+            vec![
+                0,
+                1,
+                2,
+                addr(buffers.as_ptr().cast()),
+                addr(offsets.as_ptr().cast()),
+                addr(sizes.as_ptr().cast()),
+            ],
+        ),
+        (
+            "glViewportArrayv",
+            vec![0, 2, addr(viewports.as_ptr().cast())],
+        ),
+        // a null array is only recorded by address
+        ("glBindBuffersBase", vec![0, 1, 2, 0]),
+        // without a current context the indices are an offset into the element array buffer
+        (
+            "glDrawElements",
+            vec![4, 3, u64::from(GL_UNSIGNED_SHORT), 16],
+        ),
+    ];
+    let mut trace = Vec::new();
+    for (name, args) in &calls {
+        // Safety: the arrays referenced by the calls outlive them
+        let blobs = unsafe { client_memory(name, args) };
+        write_record(&mut trace, name, args, &blobs).unwrap();
+    }
+
+    let mut rest = trace.as_slice();
+    let mut take = |n: usize| {
+        let (head, tail) = rest.split_at(n);
+        rest = tail;
+        head
+    };
+    let mut blobs_of = |name: &str| {
+        let len = u16::from_le_bytes(take(2).try_into().unwrap());
+        assert_eq!(take(len.into()), name.as_bytes());
+        let argc = take(1)[0];
+        take(usize::from(argc) * 8);
+        let count = u16::from_le_bytes(take(2).try_into().unwrap());
+        (0..count)
+            .map(|_| {
+                let arg = take(1)[0];
+                let len = u64::from_le_bytes(take(8).try_into().unwrap());
+                (arg, take(len as usize).to_vec())
+            })
+            .collect::<Vec<_>>()
+    };
+    // Safety: the arrays are valid for reads of their size
+    let as_bytes = |p: *const u8, len: usize| unsafe { slice::from_raw_parts(p, len) }.to_vec();
+    assert_eq!(
+        blobs_of("glBindBuffersRange"),
+        [
+            (3, as_bytes(buffers.as_ptr().cast(), 8)),
+            (4, as_bytes(offsets.as_ptr().cast(), 16)),
+            (5, as_bytes(sizes.as_ptr().cast(), 16)),
+        ]
+    );
+    assert_eq!(
+        blobs_of("glViewportArrayv"),
+        [(2, as_bytes(viewports.as_ptr().cast(), 32))]
+    );
+    assert_eq!(blobs_of("glBindBuffersBase"), []);
+    assert_eq!(blobs_of("glDrawElements"), []);
+}
@@ -0,0 +1,927 @@
+// GENERATED CODE. DO NOT MODIFY
+// GL Command Pointer Argument Lengths
+
+use super::PointerLen::{self, Array, Fixed, NulTerminated, Strings};
+
+/// Client memory referenced by the input pointer arguments of a command as (argument index, length), derived
+/// from the `len` attributes of gl.xml. Arguments whose length depends on the value of another argument in a way
+/// gl.xml doesn't describe (`COMPSIZE`) are missing
+pub(super) fn pointer_lengths(name: &str) -> &'static [(usize, PointerLen)] {
+    match name {
+        "glBindAttribLocation" => &[(2, NulTerminated)],
+        "glBindBuffersBase" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 4,
+            },
+        )],
+        "glBindBuffersRange" => &[
+            (
+                3,
+                Array {
+                    count: 2,
+                    stride: 4,
+                },
+            ),
+            (
+                4,
+                Array {
+                    count: 2,
+                    stride: 8,
+                },
+            ),
+            (
+                5,
+                Array {
+                    count: 2,
+                    stride: 8,
+                },
+            ),
+        ],
+        "glBindFragDataLocation" => &[(2, NulTerminated)],
+        "glBindFragDataLocationIndexed" => &[(3, NulTerminated)],
+        "glBindImageTextures" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glBindSamplers" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glBindTextures" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glBindVertexBuffers" => &[
+            (
+                2,
+                Array {
+                    count: 1,
+                    stride: 4,
+                },
+            ),
+            (
+                3,
+                Array {
+                    count: 1,
+                    stride: 8,
+                },
+            ),
+            (
+                4,
+                Array {
+                    count: 1,
+                    stride: 4,
+                },
+            ),
+        ],
+        "glVertexArrayVertexBuffers" => &[
+            (
+                3,
+                Array {
+                    count: 2,
+                    stride: 4,
+                },
+            ),
+            (
+                4,
+                Array {
+                    count: 2,
+                    stride: 8,
+                },
+            ),
+            (
+                5,
+                Array {
+                    count: 2,
+                    stride: 4,
+                },
+            ),
+        ],
+        "glBufferData" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 1,
+            },
+        )],
+        "glNamedBufferData" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 1,
+            },
+        )],
+        "glBufferStorage" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 1,
+            },
+        )],
+        "glNamedBufferStorage" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 1,
+            },
+        )],
+        "glBufferSubData" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 1,
+            },
+        )],
+        "glCompressedTexImage1D" => &[(
+            6,
+            Array {
+                count: 5,
+                stride: 1,
+            },
+        )],
+        "glCompressedTexImage2D" => &[(
+            7,
+            Array {
+                count: 6,
+                stride: 1,
+            },
+        )],
+        "glCompressedTexImage3D" => &[(
+            8,
+            Array {
+                count: 7,
+                stride: 1,
+            },
+        )],
+        "glCompressedTexSubImage1D" => &[(
+            6,
+            Array {
+                count: 5,
+                stride: 1,
+            },
+        )],
+        "glCompressedTextureSubImage1D" => &[(
+            6,
+            Array {
+                count: 5,
+                stride: 1,
+            },
+        )],
+        "glCompressedTexSubImage2D" => &[(
+            8,
+            Array {
+                count: 7,
+                stride: 1,
+            },
+        )],
+        "glCompressedTextureSubImage2D" => &[(
+            8,
+            Array {
+                count: 7,
+                stride: 1,
+            },
+        )],
+        "glCompressedTexSubImage3D" => &[(
+            10,
+            Array {
+                count: 9,
+                stride: 1,
+            },
+        )],
+        "glCompressedTextureSubImage3D" => &[(
+            10,
+            Array {
+                count: 9,
+                stride: 1,
+            },
+        )],
+        "glCreateShaderProgramv" => &[(2, Strings(1))],
+        "glDebugMessageControl" => &[(
+            4,
+            Array {
+                count: 3,
+                stride: 4,
+            },
+        )],
+        "glDeleteBuffers" => &[(
+            1,
+            Array {
+                count: 0,
+                stride: 4,
+            },
+        )],
+        "glDeleteFramebuffers" => &[(
+            1,
+            Array {
+                count: 0,
+                stride: 4,
+            },
+        )],
+        "glDeleteProgramPipelines" => &[(
+            1,
+            Array {
+                count: 0,
+                stride: 4,
+            },
+        )],
+        "glDeleteQueries" => &[(
+            1,
+            Array {
+                count: 0,
+                stride: 4,
+            },
+        )],
+        "glDeleteRenderbuffers" => &[(
+            1,
+            Array {
+                count: 0,
+                stride: 4,
+            },
+        )],
+        "glDeleteSamplers" => &[(
+            1,
+            Array {
+                count: 0,
+                stride: 4,
+            },
+        )],
+        "glDeleteTextures" => &[(
+            1,
+            Array {
+                count: 0,
+                stride: 4,
+            },
+        )],
+        "glDeleteTransformFeedbacks" => &[(
+            1,
+            Array {
+                count: 0,
+                stride: 4,
+            },
+        )],
+        "glDeleteVertexArrays" => &[(
+            1,
+            Array {
+                count: 0,
+                stride: 4,
+            },
+        )],
+        "glDrawBuffers" => &[(
+            1,
+            Array {
+                count: 0,
+                stride: 4,
+            },
+        )],
+        "glNamedFramebufferDrawBuffers" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glGetActiveUniformsiv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glGetAttribLocation" => &[(1, NulTerminated)],
+        "glGetFragDataIndex" => &[(1, NulTerminated)],
+        "glGetFragDataLocation" => &[(1, NulTerminated)],
+        "glGetProgramResourceiv" => &[(
+            4,
+            Array {
+                count: 3,
+                stride: 4,
+            },
+        )],
+        "glGetProgramResourceIndex" => &[(2, NulTerminated)],
+        "glGetProgramResourceLocation" => &[(2, NulTerminated)],
+        "glGetProgramResourceLocationIndex" => &[(2, NulTerminated)],
+        "glGetSubroutineIndex" => &[(2, NulTerminated)],
+        "glGetSubroutineUniformLocation" => &[(2, NulTerminated)],
+        "glGetUniformBlockIndex" => &[(1, NulTerminated)],
+        "glGetUniformLocation" => &[(1, NulTerminated)],
+        "glInvalidateFramebuffer" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glInvalidateNamedFramebufferData" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glInvalidateSubFramebuffer" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glInvalidateNamedFramebufferSubData" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glProgramBinary" => &[(
+            2,
+            Array {
+                count: 3,
+                stride: 1,
+            },
+        )],
+        "glProgramUniform1iv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 4,
+            },
+        )],
+        "glProgramUniform1fv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 4,
+            },
+        )],
+        "glProgramUniform1uiv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 4,
+            },
+        )],
+        "glProgramUniform2iv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 8,
+            },
+        )],
+        "glProgramUniform2fv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 8,
+            },
+        )],
+        "glProgramUniform2uiv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 8,
+            },
+        )],
+        "glProgramUniform3iv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 12,
+            },
+        )],
+        "glProgramUniform3fv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 12,
+            },
+        )],
+        "glProgramUniform3uiv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 12,
+            },
+        )],
+        "glProgramUniform4iv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 16,
+            },
+        )],
+        "glProgramUniform4fv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 16,
+            },
+        )],
+        "glProgramUniform4uiv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 16,
+            },
+        )],
+        "glProgramUniformMatrix2fv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 16,
+            },
+        )],
+        "glProgramUniformMatrix3fv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 36,
+            },
+        )],
+        "glProgramUniformMatrix4fv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 64,
+            },
+        )],
+        "glProgramUniformMatrix2x3fv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 24,
+            },
+        )],
+        "glProgramUniformMatrix3x2fv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 24,
+            },
+        )],
+        "glProgramUniformMatrix2x4fv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 32,
+            },
+        )],
+        "glProgramUniformMatrix4x2fv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 32,
+            },
+        )],
+        "glProgramUniformMatrix3x4fv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 48,
+            },
+        )],
+        "glProgramUniformMatrix4x3fv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 48,
+            },
+        )],
+        "glScissorIndexedv" => &[(1, Fixed(16))],
+        "glShaderBinary" => &[
+            (
+                1,
+                Array {
+                    count: 0,
+                    stride: 4,
+                },
+            ),
+            (
+                3,
+                Array {
+                    count: 4,
+                    stride: 1,
+                },
+            ),
+        ],
+        "glShaderSource" => &[
+            (2, Strings(1)),
+            (
+                3,
+                Array {
+                    count: 1,
+                    stride: 4,
+                },
+            ),
+        ],
+        "glTransformFeedbackVaryings" => &[(2, Strings(1))],
+        "glUniform1fv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glUniform2fv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 8,
+            },
+        )],
+        "glUniform3fv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 12,
+            },
+        )],
+        "glUniform4fv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 16,
+            },
+        )],
+        "glUniform1iv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glUniform2iv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 8,
+            },
+        )],
+        "glUniform3iv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 12,
+            },
+        )],
+        "glUniform4iv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 16,
+            },
+        )],
+        "glUniformMatrix2fv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 16,
+            },
+        )],
+        "glUniformMatrix3fv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 36,
+            },
+        )],
+        "glUniformMatrix4fv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 64,
+            },
+        )],
+        "glUniformMatrix2x3fv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 24,
+            },
+        )],
+        "glUniformMatrix3x2fv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 24,
+            },
+        )],
+        "glUniformMatrix2x4fv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 32,
+            },
+        )],
+        "glUniformMatrix4x2fv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 32,
+            },
+        )],
+        "glUniformMatrix3x4fv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 48,
+            },
+        )],
+        "glUniformMatrix4x3fv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 48,
+            },
+        )],
+        "glUniform1uiv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glUniform2uiv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 8,
+            },
+        )],
+        "glUniform3uiv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 12,
+            },
+        )],
+        "glUniform4uiv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 16,
+            },
+        )],
+        "glUniformSubroutinesuiv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 4,
+            },
+        )],
+        "glVertexAttrib1dv" => &[(1, Fixed(8))],
+        "glVertexAttrib1fv" => &[(1, Fixed(4))],
+        "glVertexAttrib1sv" => &[(1, Fixed(2))],
+        "glVertexAttrib2dv" => &[(1, Fixed(16))],
+        "glVertexAttrib2fv" => &[(1, Fixed(8))],
+        "glVertexAttrib2sv" => &[(1, Fixed(4))],
+        "glVertexAttrib3dv" => &[(1, Fixed(24))],
+        "glVertexAttrib3fv" => &[(1, Fixed(12))],
+        "glVertexAttrib3sv" => &[(1, Fixed(6))],
+        "glVertexAttrib4Nbv" => &[(1, Fixed(4))],
+        "glVertexAttrib4Niv" => &[(1, Fixed(16))],
+        "glVertexAttrib4Nsv" => &[(1, Fixed(8))],
+        "glVertexAttrib4Nubv" => &[(1, Fixed(4))],
+        "glVertexAttrib4Nuiv" => &[(1, Fixed(16))],
+        "glVertexAttrib4Nusv" => &[(1, Fixed(8))],
+        "glVertexAttrib4bv" => &[(1, Fixed(4))],
+        "glVertexAttrib4dv" => &[(1, Fixed(32))],
+        "glVertexAttrib4fv" => &[(1, Fixed(16))],
+        "glVertexAttrib4iv" => &[(1, Fixed(16))],
+        "glVertexAttrib4sv" => &[(1, Fixed(8))],
+        "glVertexAttrib4ubv" => &[(1, Fixed(4))],
+        "glVertexAttrib4uiv" => &[(1, Fixed(16))],
+        "glVertexAttrib4usv" => &[(1, Fixed(8))],
+        "glVertexAttribI1iv" => &[(1, Fixed(4))],
+        "glVertexAttribI2iv" => &[(1, Fixed(8))],
+        "glVertexAttribI3iv" => &[(1, Fixed(12))],
+        "glVertexAttribI4iv" => &[(1, Fixed(16))],
+        "glVertexAttribI1uiv" => &[(1, Fixed(4))],
+        "glVertexAttribI2uiv" => &[(1, Fixed(8))],
+        "glVertexAttribI3uiv" => &[(1, Fixed(12))],
+        "glVertexAttribI4uiv" => &[(1, Fixed(16))],
+        "glVertexAttribI4bv" => &[(1, Fixed(4))],
+        "glVertexAttribI4sv" => &[(1, Fixed(8))],
+        "glVertexAttribI4ubv" => &[(1, Fixed(4))],
+        "glVertexAttribI4usv" => &[(1, Fixed(8))],
+        "glVertexAttribL1dv" => &[(1, Fixed(8))],
+        "glVertexAttribL2dv" => &[(1, Fixed(16))],
+        "glVertexAttribL3dv" => &[(1, Fixed(24))],
+        "glVertexAttribL4dv" => &[(1, Fixed(32))],
+        "glViewportIndexedfv" => &[(1, Fixed(16))],
+        "glVertexAttribP1uiv" => &[(3, Fixed(4))],
+        "glVertexAttribP2uiv" => &[(3, Fixed(4))],
+        "glVertexAttribP3uiv" => &[(3, Fixed(4))],
+        "glVertexAttribP4uiv" => &[(3, Fixed(4))],
+        "glUniform1dv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 8,
+            },
+        )],
+        "glUniform2dv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 16,
+            },
+        )],
+        "glUniform3dv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 24,
+            },
+        )],
+        "glUniform4dv" => &[(
+            2,
+            Array {
+                count: 1,
+                stride: 32,
+            },
+        )],
+        "glUniformMatrix2dv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 32,
+            },
+        )],
+        "glUniformMatrix3dv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 72,
+            },
+        )],
+        "glUniformMatrix4dv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 128,
+            },
+        )],
+        "glUniformMatrix2x3dv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 48,
+            },
+        )],
+        "glUniformMatrix2x4dv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 64,
+            },
+        )],
+        "glUniformMatrix3x2dv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 48,
+            },
+        )],
+        "glUniformMatrix3x4dv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 96,
+            },
+        )],
+        "glUniformMatrix4x2dv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 64,
+            },
+        )],
+        "glUniformMatrix4x3dv" => &[(
+            3,
+            Array {
+                count: 1,
+                stride: 96,
+            },
+        )],
+        "glProgramUniform1dv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 8,
+            },
+        )],
+        "glProgramUniform2dv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 16,
+            },
+        )],
+        "glProgramUniform3dv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 24,
+            },
+        )],
+        "glProgramUniform4dv" => &[(
+            3,
+            Array {
+                count: 2,
+                stride: 32,
+            },
+        )],
+        "glProgramUniformMatrix2dv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 32,
+            },
+        )],
+        "glProgramUniformMatrix3dv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 72,
+            },
+        )],
+        "glProgramUniformMatrix4dv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 128,
+            },
+        )],
+        "glProgramUniformMatrix2x3dv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 48,
+            },
+        )],
+        "glProgramUniformMatrix3x2dv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 48,
+            },
+        )],
+        "glProgramUniformMatrix2x4dv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 64,
+            },
+        )],
+        "glProgramUniformMatrix4x2dv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 64,
+            },
+        )],
+        "glProgramUniformMatrix3x4dv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 96,
+            },
+        )],
+        "glProgramUniformMatrix4x3dv" => &[(
+            4,
+            Array {
+                count: 2,
+                stride: 96,
+            },
+        )],
+        "glSpecializeShader" => &[
+            (1, NulTerminated),
+            (
+                3,
+                Array {
+                    count: 2,
+                    stride: 4,
+                },
+            ),
+            (
+                4,
+                Array {
+                    count: 2,
+                    stride: 4,
+                },
+            ),
+        ],
+        _ => &[],
+    }
+}
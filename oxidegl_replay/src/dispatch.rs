@@ -0,0 +1,7628 @@
+// GENERATED CODE. DO NOT MODIFY
+// GL Command Trace Replay Dispatch
+
+use super::{run, CallArgs, Outcome};
+use oxidegl::context::Context;
+use oxidegl::conversions::GLenumExt;
+use oxidegl::error::GlResult;
+use oxidegl::gl_types::*;
+
+pub(super) unsafe fn dispatch(ctx: &mut Context, name: &str, args: &CallArgs) -> Outcome {
+    match name {
+        "glActiveShaderProgram" => {
+            let pipeline: GLuint = args.value(0);
+            let program: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_active_shader_program(pipeline, program)
+            })
+        }
+        "glActiveTexture" => {
+            let texture: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_active_texture(texture.try_into_enum()?))
+            })
+        }
+        "glAttachShader" => {
+            let program: GLuint = args.value(0);
+            let shader: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_attach_shader(program, shader)
+            })
+        }
+        "glBeginConditionalRender" => {
+            let id: GLuint = args.value(0);
+            let mode: GLenum = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_begin_conditional_render(id, mode.try_into_enum()?),
+                )
+            })
+        }
+        "glEndConditionalRender" => {
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_end_conditional_render())
+        }
+        "glBeginQuery" => {
+            let target: GLenum = args.value(0);
+            let id: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_begin_query(target.try_into_enum()?, id))
+            })
+        }
+        "glEndQuery" => {
+            let target: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_end_query(target.try_into_enum()?))
+            })
+        }
+        "glBeginQueryIndexed" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            let id: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_begin_query_indexed(
+                    target.try_into_enum()?,
+                    index,
+                    id,
+                ))
+            })
+        }
+        "glEndQueryIndexed" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_end_query_indexed(target.try_into_enum()?, index))
+            })
+        }
+        "glBeginTransformFeedback" => {
+            let primitiveMode: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_begin_transform_feedback(primitiveMode.try_into_enum()?),
+                )
+            })
+        }
+        "glEndTransformFeedback" => {
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_end_transform_feedback())
+        }
+        "glBindAttribLocation" => {
+            let program: GLuint = args.value(0);
+            let index: GLuint = args.value(1);
+            let Some(name) = args.ptr::<GLchar>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_bind_attrib_location(program, index, name)
+            })
+        }
+        "glBindBuffer" => {
+            let target: GLenum = args.value(0);
+            let buffer: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_bind_buffer(target.try_into_enum()?, buffer))
+            })
+        }
+        "glBindBufferBase" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            let buffer: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_bind_buffer_base(
+                    target.try_into_enum()?,
+                    index,
+                    buffer,
+                ))
+            })
+        }
+        "glBindBufferRange" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            let buffer: GLuint = args.value(2);
+            let offset: GLintptr = args.value(3);
+            let size: GLsizeiptr = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_bind_buffer_range(
+                    target.try_into_enum()?,
+                    index,
+                    buffer,
+                    offset,
+                    size,
+                ))
+            })
+        }
+        "glBindBuffersBase" => {
+            let target: GLenum = args.value(0);
+            let first: GLuint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(buffers) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_bind_buffers_base(target.try_into_enum()?, first, count, buffers)
+                })
+            })
+        }
+        "glBindBuffersRange" => {
+            let target: GLenum = args.value(0);
+            let first: GLuint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(buffers) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            let Some(offsets) = args.ptr::<GLintptr>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            let Some(sizes) = args.ptr::<GLsizeiptr>(5) else {
+                return Outcome::MissingClientMemory(5);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_bind_buffers_range(
+                        target.try_into_enum()?,
+                        first,
+                        count,
+                        buffers,
+                        offsets,
+                        sizes,
+                    )
+                })
+            })
+        }
+        "glBindFragDataLocation" => {
+            let program: GLuint = args.value(0);
+            let color: GLuint = args.value(1);
+            let Some(name) = args.ptr::<GLchar>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_bind_frag_data_location(program, color, name)
+            })
+        }
+        "glBindFragDataLocationIndexed" => {
+            let program: GLuint = args.value(0);
+            let colorNumber: GLuint = args.value(1);
+            let index: GLuint = args.value(2);
+            let Some(name) = args.ptr::<GLchar>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_bind_frag_data_location_indexed(program, colorNumber, index, name)
+            })
+        }
+        "glBindFramebuffer" => {
+            let target: GLenum = args.value(0);
+            let framebuffer: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_bind_framebuffer(target.try_into_enum()?, framebuffer),
+                )
+            })
+        }
+        "glBindImageTexture" => {
+            let unit: GLuint = args.value(0);
+            let texture: GLuint = args.value(1);
+            let level: GLint = args.value(2);
+            let layered: GLboolean = args.value(3);
+            let layer: GLint = args.value(4);
+            let access: GLenum = args.value(5);
+            let format: GLenum = args.value(6);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_bind_image_texture(
+                    unit,
+                    texture,
+                    level,
+                    layered,
+                    layer,
+                    access.try_into_enum()?,
+                    format.try_into_enum()?,
+                ))
+            })
+        }
+        "glBindImageTextures" => {
+            let first: GLuint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(textures) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_bind_image_textures(first, count, textures)
+            })
+        }
+        "glBindProgramPipeline" => {
+            let pipeline: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_bind_program_pipeline(pipeline)
+            })
+        }
+        "glBindRenderbuffer" => {
+            let target: GLenum = args.value(0);
+            let renderbuffer: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_bind_renderbuffer(target, renderbuffer)
+            })
+        }
+        "glBindSampler" => {
+            let unit: GLuint = args.value(0);
+            let sampler: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_bind_sampler(unit, sampler))
+        }
+        "glBindSamplers" => {
+            let first: GLuint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(samplers) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_bind_samplers(first, count, samplers)
+            })
+        }
+        "glBindTexture" => {
+            let target: GLenum = args.value(0);
+            let texture: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_bind_texture(target.try_into_enum()?, texture))
+            })
+        }
+        "glBindTextures" => {
+            let first: GLuint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(textures) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_bind_textures(first, count, textures)
+            })
+        }
+        "glBindTextureUnit" => {
+            let unit: GLuint = args.value(0);
+            let texture: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_bind_texture_unit(unit, texture)
+            })
+        }
+        "glBindTransformFeedback" => {
+            let target: GLenum = args.value(0);
+            let id: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_bind_transform_feedback(target, id)
+            })
+        }
+        "glBindVertexArray" => {
+            let array: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_bind_vertex_array(array))
+        }
+        "glBindVertexBuffer" => {
+            let bindingindex: GLuint = args.value(0);
+            let buffer: GLuint = args.value(1);
+            let offset: GLintptr = args.value(2);
+            let stride: GLsizei = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_bind_vertex_buffer(bindingindex, buffer, offset, stride)
+            })
+        }
+        "glVertexArrayVertexBuffer" => {
+            let vaobj: GLuint = args.value(0);
+            let bindingindex: GLuint = args.value(1);
+            let buffer: GLuint = args.value(2);
+            let offset: GLintptr = args.value(3);
+            let stride: GLsizei = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_array_vertex_buffer(
+                    vaobj,
+                    bindingindex,
+                    buffer,
+                    offset,
+                    stride,
+                )
+            })
+        }
+        "glBindVertexBuffers" => {
+            let first: GLuint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(buffers) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let Some(offsets) = args.ptr::<GLintptr>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            let Some(strides) = args.ptr::<GLsizei>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_bind_vertex_buffers(first, count, buffers, offsets, strides)
+            })
+        }
+        "glVertexArrayVertexBuffers" => {
+            let vaobj: GLuint = args.value(0);
+            let first: GLuint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(buffers) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            let Some(offsets) = args.ptr::<GLintptr>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            let Some(strides) = args.ptr::<GLsizei>(5) else {
+                return Outcome::MissingClientMemory(5);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_array_vertex_buffers(
+                    vaobj, first, count, buffers, offsets, strides,
+                )
+            })
+        }
+        "glBlendColor" => {
+            let red: GLfloat = args.value(0);
+            let green: GLfloat = args.value(1);
+            let blue: GLfloat = args.value(2);
+            let alpha: GLfloat = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_blend_color(red, green, blue, alpha)
+            })
+        }
+        "glBlendEquation" => {
+            let mode: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_blend_equation(mode.try_into_enum()?))
+            })
+        }
+        "glBlendEquationi" => {
+            let buf: GLuint = args.value(0);
+            let mode: GLenum = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_blend_equationi(buf, mode.try_into_enum()?))
+            })
+        }
+        "glBlendEquationSeparate" => {
+            let modeRGB: GLenum = args.value(0);
+            let modeAlpha: GLenum = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_blend_equation_separate(
+                    modeRGB.try_into_enum()?,
+                    modeAlpha.try_into_enum()?,
+                ))
+            })
+        }
+        "glBlendEquationSeparatei" => {
+            let buf: GLuint = args.value(0);
+            let modeRGB: GLenum = args.value(1);
+            let modeAlpha: GLenum = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_blend_equation_separatei(
+                    buf,
+                    modeRGB.try_into_enum()?,
+                    modeAlpha.try_into_enum()?,
+                ))
+            })
+        }
+        "glBlendFunc" => {
+            let sfactor: GLenum = args.value(0);
+            let dfactor: GLenum = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_blend_func(sfactor.try_into_enum()?, dfactor.try_into_enum()?),
+                )
+            })
+        }
+        "glBlendFunci" => {
+            let buf: GLuint = args.value(0);
+            let src: GLenum = args.value(1);
+            let dst: GLenum = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_blend_funci(
+                    buf,
+                    src.try_into_enum()?,
+                    dst.try_into_enum()?,
+                ))
+            })
+        }
+        "glBlendFuncSeparate" => {
+            let sfactorRGB: GLenum = args.value(0);
+            let dfactorRGB: GLenum = args.value(1);
+            let sfactorAlpha: GLenum = args.value(2);
+            let dfactorAlpha: GLenum = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_blend_func_separate(
+                    sfactorRGB.try_into_enum()?,
+                    dfactorRGB.try_into_enum()?,
+                    sfactorAlpha.try_into_enum()?,
+                    dfactorAlpha.try_into_enum()?,
+                ))
+            })
+        }
+        "glBlendFuncSeparatei" => {
+            let buf: GLuint = args.value(0);
+            let srcRGB: GLenum = args.value(1);
+            let dstRGB: GLenum = args.value(2);
+            let srcAlpha: GLenum = args.value(3);
+            let dstAlpha: GLenum = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_blend_func_separatei(
+                    buf,
+                    srcRGB.try_into_enum()?,
+                    dstRGB.try_into_enum()?,
+                    srcAlpha.try_into_enum()?,
+                    dstAlpha.try_into_enum()?,
+                ))
+            })
+        }
+        "glBlitFramebuffer" => {
+            let srcX0: GLint = args.value(0);
+            let srcY0: GLint = args.value(1);
+            let srcX1: GLint = args.value(2);
+            let srcY1: GLint = args.value(3);
+            let dstX0: GLint = args.value(4);
+            let dstY0: GLint = args.value(5);
+            let dstX1: GLint = args.value(6);
+            let dstY1: GLint = args.value(7);
+            let mask: GLenum = args.value(8);
+            let filter: GLenum = args.value(9);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_blit_framebuffer(
+                    srcX0,
+                    srcY0,
+                    srcX1,
+                    srcY1,
+                    dstX0,
+                    dstY0,
+                    dstX1,
+                    dstY1,
+                    mask.try_into_enum()?,
+                    filter.try_into_enum()?,
+                ))
+            })
+        }
+        "glBlitNamedFramebuffer" => {
+            let readFramebuffer: GLuint = args.value(0);
+            let drawFramebuffer: GLuint = args.value(1);
+            let srcX0: GLint = args.value(2);
+            let srcY0: GLint = args.value(3);
+            let srcX1: GLint = args.value(4);
+            let srcY1: GLint = args.value(5);
+            let dstX0: GLint = args.value(6);
+            let dstY0: GLint = args.value(7);
+            let dstX1: GLint = args.value(8);
+            let dstY1: GLint = args.value(9);
+            let mask: GLenum = args.value(10);
+            let filter: GLenum = args.value(11);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_blit_named_framebuffer(
+                    readFramebuffer,
+                    drawFramebuffer,
+                    srcX0,
+                    srcY0,
+                    srcX1,
+                    srcY1,
+                    dstX0,
+                    dstY0,
+                    dstX1,
+                    dstY1,
+                    mask.try_into_enum()?,
+                    filter.try_into_enum()?,
+                ))
+            })
+        }
+        "glBufferData" => {
+            let target: GLenum = args.value(0);
+            let size: GLsizeiptr = args.value(1);
+            let Some(data) = args.ptr::<GLvoid>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let usage: GLenum = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_buffer_data(
+                        target.try_into_enum()?,
+                        size,
+                        data,
+                        usage.try_into_enum()?,
+                    )
+                })
+            })
+        }
+        "glNamedBufferData" => {
+            let buffer: GLuint = args.value(0);
+            let size: GLsizeiptr = args.value(1);
+            let Some(data) = args.ptr::<GLvoid>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let usage: GLenum = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_named_buffer_data(buffer, size, data, usage.try_into_enum()?)
+                })
+            })
+        }
+        "glBufferStorage" => {
+            let target: GLenum = args.value(0);
+            let size: GLsizeiptr = args.value(1);
+            let Some(data) = args.ptr::<GLvoid>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let flags: GLenum = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_buffer_storage(
+                        target.try_into_enum()?,
+                        size,
+                        data,
+                        flags.try_into_enum()?,
+                    )
+                })
+            })
+        }
+        "glNamedBufferStorage" => {
+            let buffer: GLuint = args.value(0);
+            let size: GLsizeiptr = args.value(1);
+            let Some(data) = args.ptr::<GLvoid>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let flags: GLenum = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_named_buffer_storage(buffer, size, data, flags.try_into_enum()?)
+                })
+            })
+        }
+        "glBufferSubData" => {
+            let target: GLenum = args.value(0);
+            let offset: GLintptr = args.value(1);
+            let size: GLsizeiptr = args.value(2);
+            let Some(data) = args.ptr::<GLvoid>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_buffer_sub_data(target.try_into_enum()?, offset, size, data)
+                })
+            })
+        }
+        "glNamedBufferSubData" => {
+            let buffer: GLuint = args.value(0);
+            let offset: GLintptr = args.value(1);
+            let size: GLsizeiptr = args.value(2);
+            let Some(data) = args.ptr::<GLvoid>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_named_buffer_sub_data(buffer, offset, size, data)
+            })
+        }
+        "glCheckFramebufferStatus" => {
+            let target: GLenum = args.value(0);
+            run::<GLenum, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_check_framebuffer_status(target.try_into_enum()?))
+            })
+        }
+        "glCheckNamedFramebufferStatus" => {
+            let framebuffer: GLuint = args.value(0);
+            let target: GLenum = args.value(1);
+            run::<GLenum, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_check_named_framebuffer_status(
+                        framebuffer,
+                        target.try_into_enum()?,
+                    ),
+                )
+            })
+        }
+        "glClampColor" => {
+            let target: GLenum = args.value(0);
+            let clamp: GLenum = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_clamp_color(target, clamp.try_into_enum()?))
+            })
+        }
+        "glClear" => {
+            let mask: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_clear(mask.try_into_enum()?))
+            })
+        }
+        "glClearBufferiv" => {
+            let buffer: GLenum = args.value(0);
+            let drawbuffer: GLint = args.value(1);
+            let Some(value) = args.ptr::<GLint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_clear_bufferiv(buffer.try_into_enum()?, drawbuffer, value)
+                })
+            })
+        }
+        "glClearBufferuiv" => {
+            let buffer: GLenum = args.value(0);
+            let drawbuffer: GLint = args.value(1);
+            let Some(value) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_clear_bufferuiv(buffer.try_into_enum()?, drawbuffer, value)
+                })
+            })
+        }
+        "glClearBufferfv" => {
+            let buffer: GLenum = args.value(0);
+            let drawbuffer: GLint = args.value(1);
+            let Some(value) = args.ptr::<GLfloat>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_clear_bufferfv(buffer.try_into_enum()?, drawbuffer, value)
+                })
+            })
+        }
+        "glClearBufferfi" => {
+            let buffer: GLenum = args.value(0);
+            let drawbuffer: GLint = args.value(1);
+            let depth: GLfloat = args.value(2);
+            let stencil: GLint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_clear_bufferfi(
+                    buffer.try_into_enum()?,
+                    drawbuffer,
+                    depth,
+                    stencil,
+                ))
+            })
+        }
+        "glClearNamedFramebufferiv" => {
+            let framebuffer: GLuint = args.value(0);
+            let buffer: GLenum = args.value(1);
+            let drawbuffer: GLint = args.value(2);
+            let Some(value) = args.ptr::<GLint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_clear_named_framebufferiv(
+                        framebuffer,
+                        buffer.try_into_enum()?,
+                        drawbuffer,
+                        value,
+                    )
+                })
+            })
+        }
+        "glClearNamedFramebufferuiv" => {
+            let framebuffer: GLuint = args.value(0);
+            let buffer: GLenum = args.value(1);
+            let drawbuffer: GLint = args.value(2);
+            let Some(value) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_clear_named_framebufferuiv(
+                        framebuffer,
+                        buffer.try_into_enum()?,
+                        drawbuffer,
+                        value,
+                    )
+                })
+            })
+        }
+        "glClearNamedFramebufferfv" => {
+            let framebuffer: GLuint = args.value(0);
+            let buffer: GLenum = args.value(1);
+            let drawbuffer: GLint = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_clear_named_framebufferfv(
+                        framebuffer,
+                        buffer.try_into_enum()?,
+                        drawbuffer,
+                        value,
+                    )
+                })
+            })
+        }
+        "glClearNamedFramebufferfi" => {
+            let framebuffer: GLuint = args.value(0);
+            let buffer: GLenum = args.value(1);
+            let drawbuffer: GLint = args.value(2);
+            let depth: GLfloat = args.value(3);
+            let stencil: GLint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_clear_named_framebufferfi(
+                    framebuffer,
+                    buffer.try_into_enum()?,
+                    drawbuffer,
+                    depth,
+                    stencil,
+                ))
+            })
+        }
+        "glClearBufferData" => {
+            let target: GLenum = args.value(0);
+            let internalformat: GLenum = args.value(1);
+            let format: GLenum = args.value(2);
+            let r#type: GLenum = args.value(3);
+            let Some(data) = args.ptr::<GLvoid>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_clear_buffer_data(
+                        target.try_into_enum()?,
+                        internalformat.try_into_enum()?,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        data,
+                    )
+                })
+            })
+        }
+        "glClearNamedBufferData" => {
+            let buffer: GLuint = args.value(0);
+            let internalformat: GLenum = args.value(1);
+            let format: GLenum = args.value(2);
+            let r#type: GLenum = args.value(3);
+            let Some(data) = args.ptr::<GLvoid>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_clear_named_buffer_data(
+                        buffer,
+                        internalformat.try_into_enum()?,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        data,
+                    )
+                })
+            })
+        }
+        "glClearBufferSubData" => {
+            let target: GLenum = args.value(0);
+            let internalformat: GLenum = args.value(1);
+            let offset: GLintptr = args.value(2);
+            let size: GLsizeiptr = args.value(3);
+            let format: GLenum = args.value(4);
+            let r#type: GLenum = args.value(5);
+            let Some(data) = args.ptr::<GLvoid>(6) else {
+                return Outcome::MissingClientMemory(6);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_clear_buffer_sub_data(
+                        target.try_into_enum()?,
+                        internalformat.try_into_enum()?,
+                        offset,
+                        size,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        data,
+                    )
+                })
+            })
+        }
+        "glClearNamedBufferSubData" => {
+            let buffer: GLuint = args.value(0);
+            let internalformat: GLenum = args.value(1);
+            let offset: GLintptr = args.value(2);
+            let size: GLsizeiptr = args.value(3);
+            let format: GLenum = args.value(4);
+            let r#type: GLenum = args.value(5);
+            let Some(data) = args.ptr::<GLvoid>(6) else {
+                return Outcome::MissingClientMemory(6);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_clear_named_buffer_sub_data(
+                        buffer,
+                        internalformat.try_into_enum()?,
+                        offset,
+                        size,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        data,
+                    )
+                })
+            })
+        }
+        "glClearColor" => {
+            let red: GLfloat = args.value(0);
+            let green: GLfloat = args.value(1);
+            let blue: GLfloat = args.value(2);
+            let alpha: GLfloat = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_clear_color(red, green, blue, alpha)
+            })
+        }
+        "glClearDepth" => {
+            let depth: GLdouble = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_clear_depth(depth))
+        }
+        "glClearDepthf" => {
+            let d: GLfloat = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_clear_depthf(d))
+        }
+        "glClearStencil" => {
+            let s: GLint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_clear_stencil(s))
+        }
+        "glClearTexImage" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let format: GLenum = args.value(2);
+            let r#type: GLenum = args.value(3);
+            let Some(data) = args.ptr::<GLvoid>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_clear_tex_image(
+                        texture,
+                        level,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        data,
+                    )
+                })
+            })
+        }
+        "glClearTexSubImage" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let zoffset: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            let height: GLsizei = args.value(6);
+            let depth: GLsizei = args.value(7);
+            let format: GLenum = args.value(8);
+            let r#type: GLenum = args.value(9);
+            let Some(data) = args.ptr::<GLvoid>(10) else {
+                return Outcome::MissingClientMemory(10);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_clear_tex_sub_image(
+                        texture,
+                        level,
+                        xoffset,
+                        yoffset,
+                        zoffset,
+                        width,
+                        height,
+                        depth,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        data,
+                    )
+                })
+            })
+        }
+        "glClientWaitSync" => {
+            let sync: GLsync = args.value(0);
+            let flags: GLbitfield = args.value(1);
+            let timeout: GLuint64 = args.value(2);
+            run::<GLenum, _, _, _>(ctx, |mut state| {
+                state.oxidegl_client_wait_sync(sync, flags, timeout)
+            })
+        }
+        "glClipControl" => {
+            let origin: GLenum = args.value(0);
+            let depth: GLenum = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_clip_control(origin.try_into_enum()?, depth.try_into_enum()?),
+                )
+            })
+        }
+        "glColorMask" => {
+            let red: GLboolean = args.value(0);
+            let green: GLboolean = args.value(1);
+            let blue: GLboolean = args.value(2);
+            let alpha: GLboolean = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_color_mask(red, green, blue, alpha)
+            })
+        }
+        "glColorMaski" => {
+            let index: GLuint = args.value(0);
+            let r: GLboolean = args.value(1);
+            let g: GLboolean = args.value(2);
+            let b: GLboolean = args.value(3);
+            let a: GLboolean = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_color_maski(index, r, g, b, a)
+            })
+        }
+        "glCompileShader" => {
+            let shader: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_compile_shader(shader))
+        }
+        "glCompressedTexImage1D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let border: GLint = args.value(4);
+            let imageSize: GLsizei = args.value(5);
+            let Some(data) = args.ptr::<GLvoid>(6) else {
+                return Outcome::MissingClientMemory(6);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_compressed_tex_image1_d(
+                        target.try_into_enum()?,
+                        level,
+                        internalformat.try_into_enum()?,
+                        width,
+                        border,
+                        imageSize,
+                        data,
+                    )
+                })
+            })
+        }
+        "glCompressedTexImage2D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            let border: GLint = args.value(5);
+            let imageSize: GLsizei = args.value(6);
+            let Some(data) = args.ptr::<GLvoid>(7) else {
+                return Outcome::MissingClientMemory(7);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_compressed_tex_image2_d(
+                        target.try_into_enum()?,
+                        level,
+                        internalformat.try_into_enum()?,
+                        width,
+                        height,
+                        border,
+                        imageSize,
+                        data,
+                    )
+                })
+            })
+        }
+        "glCompressedTexImage3D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            let depth: GLsizei = args.value(5);
+            let border: GLint = args.value(6);
+            let imageSize: GLsizei = args.value(7);
+            let Some(data) = args.ptr::<GLvoid>(8) else {
+                return Outcome::MissingClientMemory(8);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_compressed_tex_image3_d(
+                        target.try_into_enum()?,
+                        level,
+                        internalformat.try_into_enum()?,
+                        width,
+                        height,
+                        depth,
+                        border,
+                        imageSize,
+                        data,
+                    )
+                })
+            })
+        }
+        "glCompressedTexSubImage1D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let width: GLsizei = args.value(3);
+            let format: GLenum = args.value(4);
+            let imageSize: GLsizei = args.value(5);
+            let Some(data) = args.ptr::<GLvoid>(6) else {
+                return Outcome::MissingClientMemory(6);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_compressed_tex_sub_image1_d(
+                        target.try_into_enum()?,
+                        level,
+                        xoffset,
+                        width,
+                        format.try_into_enum()?,
+                        imageSize,
+                        data,
+                    )
+                })
+            })
+        }
+        "glCompressedTextureSubImage1D" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let width: GLsizei = args.value(3);
+            let format: GLenum = args.value(4);
+            let imageSize: GLsizei = args.value(5);
+            let Some(data) = args.ptr::<GLvoid>(6) else {
+                return Outcome::MissingClientMemory(6);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_compressed_texture_sub_image1_d(
+                        texture,
+                        level,
+                        xoffset,
+                        width,
+                        format.try_into_enum()?,
+                        imageSize,
+                        data,
+                    )
+                })
+            })
+        }
+        "glCompressedTexSubImage2D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let width: GLsizei = args.value(4);
+            let height: GLsizei = args.value(5);
+            let format: GLenum = args.value(6);
+            let imageSize: GLsizei = args.value(7);
+            let Some(data) = args.ptr::<GLvoid>(8) else {
+                return Outcome::MissingClientMemory(8);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_compressed_tex_sub_image2_d(
+                        target.try_into_enum()?,
+                        level,
+                        xoffset,
+                        yoffset,
+                        width,
+                        height,
+                        format.try_into_enum()?,
+                        imageSize,
+                        data,
+                    )
+                })
+            })
+        }
+        "glCompressedTextureSubImage2D" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let width: GLsizei = args.value(4);
+            let height: GLsizei = args.value(5);
+            let format: GLenum = args.value(6);
+            let imageSize: GLsizei = args.value(7);
+            let Some(data) = args.ptr::<GLvoid>(8) else {
+                return Outcome::MissingClientMemory(8);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_compressed_texture_sub_image2_d(
+                        texture,
+                        level,
+                        xoffset,
+                        yoffset,
+                        width,
+                        height,
+                        format.try_into_enum()?,
+                        imageSize,
+                        data,
+                    )
+                })
+            })
+        }
+        "glCompressedTexSubImage3D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let zoffset: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            let height: GLsizei = args.value(6);
+            let depth: GLsizei = args.value(7);
+            let format: GLenum = args.value(8);
+            let imageSize: GLsizei = args.value(9);
+            let Some(data) = args.ptr::<GLvoid>(10) else {
+                return Outcome::MissingClientMemory(10);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_compressed_tex_sub_image3_d(
+                        target.try_into_enum()?,
+                        level,
+                        xoffset,
+                        yoffset,
+                        zoffset,
+                        width,
+                        height,
+                        depth,
+                        format.try_into_enum()?,
+                        imageSize,
+                        data,
+                    )
+                })
+            })
+        }
+        "glCompressedTextureSubImage3D" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let zoffset: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            let height: GLsizei = args.value(6);
+            let depth: GLsizei = args.value(7);
+            let format: GLenum = args.value(8);
+            let imageSize: GLsizei = args.value(9);
+            let Some(data) = args.ptr::<GLvoid>(10) else {
+                return Outcome::MissingClientMemory(10);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_compressed_texture_sub_image3_d(
+                        texture,
+                        level,
+                        xoffset,
+                        yoffset,
+                        zoffset,
+                        width,
+                        height,
+                        depth,
+                        format.try_into_enum()?,
+                        imageSize,
+                        data,
+                    )
+                })
+            })
+        }
+        "glCopyBufferSubData" => {
+            let readTarget: GLenum = args.value(0);
+            let writeTarget: GLenum = args.value(1);
+            let readOffset: GLintptr = args.value(2);
+            let writeOffset: GLintptr = args.value(3);
+            let size: GLsizeiptr = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_copy_buffer_sub_data(
+                    readTarget.try_into_enum()?,
+                    writeTarget.try_into_enum()?,
+                    readOffset,
+                    writeOffset,
+                    size,
+                ))
+            })
+        }
+        "glCopyNamedBufferSubData" => {
+            let readBuffer: GLuint = args.value(0);
+            let writeBuffer: GLuint = args.value(1);
+            let readOffset: GLintptr = args.value(2);
+            let writeOffset: GLintptr = args.value(3);
+            let size: GLsizeiptr = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_copy_named_buffer_sub_data(
+                    readBuffer,
+                    writeBuffer,
+                    readOffset,
+                    writeOffset,
+                    size,
+                )
+            })
+        }
+        "glCopyImageSubData" => {
+            let srcName: GLuint = args.value(0);
+            let srcTarget: GLenum = args.value(1);
+            let srcLevel: GLint = args.value(2);
+            let srcX: GLint = args.value(3);
+            let srcY: GLint = args.value(4);
+            let srcZ: GLint = args.value(5);
+            let dstName: GLuint = args.value(6);
+            let dstTarget: GLenum = args.value(7);
+            let dstLevel: GLint = args.value(8);
+            let dstX: GLint = args.value(9);
+            let dstY: GLint = args.value(10);
+            let dstZ: GLint = args.value(11);
+            let srcWidth: GLsizei = args.value(12);
+            let srcHeight: GLsizei = args.value(13);
+            let srcDepth: GLsizei = args.value(14);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_copy_image_sub_data(
+                    srcName,
+                    srcTarget.try_into_enum()?,
+                    srcLevel,
+                    srcX,
+                    srcY,
+                    srcZ,
+                    dstName,
+                    dstTarget.try_into_enum()?,
+                    dstLevel,
+                    dstX,
+                    dstY,
+                    dstZ,
+                    srcWidth,
+                    srcHeight,
+                    srcDepth,
+                ))
+            })
+        }
+        "glCopyTexImage1D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let x: GLint = args.value(3);
+            let y: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            let border: GLint = args.value(6);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_copy_tex_image1_d(
+                    target.try_into_enum()?,
+                    level,
+                    internalformat.try_into_enum()?,
+                    x,
+                    y,
+                    width,
+                    border,
+                ))
+            })
+        }
+        "glCopyTexImage2D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let x: GLint = args.value(3);
+            let y: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            let height: GLsizei = args.value(6);
+            let border: GLint = args.value(7);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_copy_tex_image2_d(
+                    target.try_into_enum()?,
+                    level,
+                    internalformat.try_into_enum()?,
+                    x,
+                    y,
+                    width,
+                    height,
+                    border,
+                ))
+            })
+        }
+        "glCopyTexSubImage1D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let x: GLint = args.value(3);
+            let y: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_copy_tex_sub_image1_d(
+                    target.try_into_enum()?,
+                    level,
+                    xoffset,
+                    x,
+                    y,
+                    width,
+                ))
+            })
+        }
+        "glCopyTextureSubImage1D" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let x: GLint = args.value(3);
+            let y: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_copy_texture_sub_image1_d(texture, level, xoffset, x, y, width)
+            })
+        }
+        "glCopyTexSubImage2D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let x: GLint = args.value(4);
+            let y: GLint = args.value(5);
+            let width: GLsizei = args.value(6);
+            let height: GLsizei = args.value(7);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_copy_tex_sub_image2_d(
+                    target.try_into_enum()?,
+                    level,
+                    xoffset,
+                    yoffset,
+                    x,
+                    y,
+                    width,
+                    height,
+                ))
+            })
+        }
+        "glCopyTextureSubImage2D" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let x: GLint = args.value(4);
+            let y: GLint = args.value(5);
+            let width: GLsizei = args.value(6);
+            let height: GLsizei = args.value(7);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_copy_texture_sub_image2_d(
+                    texture, level, xoffset, yoffset, x, y, width, height,
+                )
+            })
+        }
+        "glCopyTexSubImage3D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let zoffset: GLint = args.value(4);
+            let x: GLint = args.value(5);
+            let y: GLint = args.value(6);
+            let width: GLsizei = args.value(7);
+            let height: GLsizei = args.value(8);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_copy_tex_sub_image3_d(
+                    target.try_into_enum()?,
+                    level,
+                    xoffset,
+                    yoffset,
+                    zoffset,
+                    x,
+                    y,
+                    width,
+                    height,
+                ))
+            })
+        }
+        "glCopyTextureSubImage3D" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let zoffset: GLint = args.value(4);
+            let x: GLint = args.value(5);
+            let y: GLint = args.value(6);
+            let width: GLsizei = args.value(7);
+            let height: GLsizei = args.value(8);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_copy_texture_sub_image3_d(
+                    texture, level, xoffset, yoffset, zoffset, x, y, width, height,
+                )
+            })
+        }
+        "glCreateBuffers" => {
+            let n: GLsizei = args.value(0);
+            let buffers: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_create_buffers(n, buffers)
+            })
+        }
+        "glCreateFramebuffers" => {
+            let n: GLsizei = args.value(0);
+            let framebuffers: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_create_framebuffers(n, framebuffers)
+            })
+        }
+        "glCreateProgram" => {
+            run::<GLuint, _, _, _>(ctx, |mut state| state.oxidegl_create_program())
+        }
+        "glCreateProgramPipelines" => {
+            let n: GLsizei = args.value(0);
+            let pipelines: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_create_program_pipelines(n, pipelines)
+            })
+        }
+        "glCreateQueries" => {
+            let target: GLenum = args.value(0);
+            let n: GLsizei = args.value(1);
+            let ids: *mut GLuint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_create_queries(target.try_into_enum()?, n, ids)
+                })
+            })
+        }
+        "glCreateRenderbuffers" => {
+            let n: GLsizei = args.value(0);
+            let renderbuffers: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_create_renderbuffers(n, renderbuffers)
+            })
+        }
+        "glCreateSamplers" => {
+            let n: GLsizei = args.value(0);
+            let samplers: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_create_samplers(n, samplers)
+            })
+        }
+        "glCreateShader" => {
+            let r#type: GLenum = args.value(0);
+            run::<GLuint, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_create_shader(r#type.try_into_enum()?))
+            })
+        }
+        "glCreateShaderProgramv" => {
+            let r#type: GLenum = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(strings) = args.ptr_array::<*const GLchar>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<GLuint, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_create_shader_programv(r#type.try_into_enum()?, count, strings)
+                })
+            })
+        }
+        "glCreateTextures" => {
+            let target: GLenum = args.value(0);
+            let n: GLsizei = args.value(1);
+            let textures: *mut GLuint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_create_textures(target.try_into_enum()?, n, textures)
+                })
+            })
+        }
+        "glCreateTransformFeedbacks" => {
+            let n: GLsizei = args.value(0);
+            let ids: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_create_transform_feedbacks(n, ids)
+            })
+        }
+        "glCreateVertexArrays" => {
+            let n: GLsizei = args.value(0);
+            let arrays: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_create_vertex_arrays(n, arrays)
+            })
+        }
+        "glCullFace" => {
+            let mode: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_cull_face(mode.try_into_enum()?))
+            })
+        }
+        "glDebugMessageCallback" => {
+            let callback: GLDEBUGPROC = args.value(0);
+            let Some(userParam) = args.ptr::<GLvoid>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_debug_message_callback(callback, userParam)
+            })
+        }
+        "glDebugMessageControl" => {
+            let source: GLenum = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let severity: GLenum = args.value(2);
+            let count: GLsizei = args.value(3);
+            let Some(ids) = args.ptr::<GLuint>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            let enabled: GLboolean = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_debug_message_control(
+                        source.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        severity.try_into_enum()?,
+                        count,
+                        ids,
+                        enabled,
+                    )
+                })
+            })
+        }
+        "glDebugMessageInsert" => {
+            let source: GLenum = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let id: GLuint = args.value(2);
+            let severity: GLenum = args.value(3);
+            let length: GLsizei = args.value(4);
+            let Some(buf) = args.ptr::<GLchar>(5) else {
+                return Outcome::MissingClientMemory(5);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_debug_message_insert(
+                        source.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        id,
+                        severity.try_into_enum()?,
+                        length,
+                        buf,
+                    )
+                })
+            })
+        }
+        "glDeleteBuffers" => {
+            let n: GLsizei = args.value(0);
+            let Some(buffers) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_delete_buffers(n, buffers)
+            })
+        }
+        "glDeleteFramebuffers" => {
+            let n: GLsizei = args.value(0);
+            let Some(framebuffers) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_delete_framebuffers(n, framebuffers)
+            })
+        }
+        "glDeleteProgram" => {
+            let program: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_delete_program(program))
+        }
+        "glDeleteProgramPipelines" => {
+            let n: GLsizei = args.value(0);
+            let Some(pipelines) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_delete_program_pipelines(n, pipelines)
+            })
+        }
+        "glDeleteQueries" => {
+            let n: GLsizei = args.value(0);
+            let Some(ids) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_delete_queries(n, ids)
+            })
+        }
+        "glDeleteRenderbuffers" => {
+            let n: GLsizei = args.value(0);
+            let Some(renderbuffers) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_delete_renderbuffers(n, renderbuffers)
+            })
+        }
+        "glDeleteSamplers" => {
+            let count: GLsizei = args.value(0);
+            let Some(samplers) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_delete_samplers(count, samplers)
+            })
+        }
+        "glDeleteShader" => {
+            let shader: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_delete_shader(shader))
+        }
+        "glDeleteSync" => {
+            let sync: GLsync = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_delete_sync(sync))
+        }
+        "glDeleteTextures" => {
+            let n: GLsizei = args.value(0);
+            let Some(textures) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_delete_textures(n, textures)
+            })
+        }
+        "glDeleteTransformFeedbacks" => {
+            let n: GLsizei = args.value(0);
+            let Some(ids) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_delete_transform_feedbacks(n, ids)
+            })
+        }
+        "glDeleteVertexArrays" => {
+            let n: GLsizei = args.value(0);
+            let Some(arrays) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_delete_vertex_arrays(n, arrays)
+            })
+        }
+        "glDepthFunc" => {
+            let func: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_depth_func(func.try_into_enum()?))
+            })
+        }
+        "glDepthMask" => {
+            let flag: GLboolean = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_depth_mask(flag))
+        }
+        "glDepthRange" => {
+            let n: GLdouble = args.value(0);
+            let f: GLdouble = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_depth_range(n, f))
+        }
+        "glDepthRangef" => {
+            let n: GLfloat = args.value(0);
+            let f: GLfloat = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_depth_rangef(n, f))
+        }
+        "glDepthRangeArrayv" => {
+            let first: GLuint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(v) = args.ptr::<GLdouble>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_depth_range_arrayv(first, count, v)
+            })
+        }
+        "glDepthRangeIndexed" => {
+            let index: GLuint = args.value(0);
+            let n: GLdouble = args.value(1);
+            let f: GLdouble = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_depth_range_indexed(index, n, f)
+            })
+        }
+        "glDetachShader" => {
+            let program: GLuint = args.value(0);
+            let shader: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_detach_shader(program, shader)
+            })
+        }
+        "glDispatchCompute" => {
+            let num_groups_x: GLuint = args.value(0);
+            let num_groups_y: GLuint = args.value(1);
+            let num_groups_z: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_dispatch_compute(num_groups_x, num_groups_y, num_groups_z)
+            })
+        }
+        "glDispatchComputeIndirect" => {
+            let indirect: GLintptr = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_dispatch_compute_indirect(indirect)
+            })
+        }
+        "glDrawArrays" => {
+            let mode: GLenum = args.value(0);
+            let first: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_draw_arrays(mode.try_into_enum()?, first, count))
+            })
+        }
+        "glDrawArraysIndirect" => {
+            let mode: GLenum = args.value(0);
+            let indirect: *const GLvoid = args.offset_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_draw_arrays_indirect(mode.try_into_enum()?, indirect)
+                })
+            })
+        }
+        "glDrawArraysInstanced" => {
+            let mode: GLenum = args.value(0);
+            let first: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let instancecount: GLsizei = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_draw_arrays_instanced(
+                    mode.try_into_enum()?,
+                    first,
+                    count,
+                    instancecount,
+                ))
+            })
+        }
+        "glDrawArraysInstancedBaseInstance" => {
+            let mode: GLenum = args.value(0);
+            let first: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let instancecount: GLsizei = args.value(3);
+            let baseinstance: GLuint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_draw_arrays_instanced_base_instance(
+                    mode.try_into_enum()?,
+                    first,
+                    count,
+                    instancecount,
+                    baseinstance,
+                ))
+            })
+        }
+        "glDrawBuffer" => {
+            let buf: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_draw_buffer(buf.try_into_enum()?))
+            })
+        }
+        "glNamedFramebufferDrawBuffer" => {
+            let framebuffer: GLuint = args.value(0);
+            let buf: GLenum = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_named_framebuffer_draw_buffer(framebuffer, buf.try_into_enum()?),
+                )
+            })
+        }
+        "glDrawBuffers" => {
+            let n: GLsizei = args.value(0);
+            let Some(bufs) = args.ptr::<GLenum>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_draw_buffers(n, bufs.cast())
+            })
+        }
+        "glNamedFramebufferDrawBuffers" => {
+            let framebuffer: GLuint = args.value(0);
+            let n: GLsizei = args.value(1);
+            let Some(bufs) = args.ptr::<GLenum>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_named_framebuffer_draw_buffers(framebuffer, n, bufs.cast())
+            })
+        }
+        "glDrawElements" => {
+            let mode: GLenum = args.value(0);
+            let count: GLsizei = args.value(1);
+            let r#type: GLenum = args.value(2);
+            let indices: *const GLvoid = args.offset_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_draw_elements(
+                        mode.try_into_enum()?,
+                        count,
+                        r#type.try_into_enum()?,
+                        indices,
+                    )
+                })
+            })
+        }
+        "glDrawElementsBaseVertex" => {
+            let mode: GLenum = args.value(0);
+            let count: GLsizei = args.value(1);
+            let r#type: GLenum = args.value(2);
+            let indices: *const GLvoid = args.offset_ptr(3);
+            let basevertex: GLint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_draw_elements_base_vertex(
+                        mode.try_into_enum()?,
+                        count,
+                        r#type.try_into_enum()?,
+                        indices,
+                        basevertex,
+                    )
+                })
+            })
+        }
+        "glDrawElementsIndirect" => {
+            let mode: GLenum = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let indirect: *const GLvoid = args.offset_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_draw_elements_indirect(
+                        mode.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        indirect,
+                    )
+                })
+            })
+        }
+        "glDrawElementsInstanced" => {
+            let mode: GLenum = args.value(0);
+            let count: GLsizei = args.value(1);
+            let r#type: GLenum = args.value(2);
+            let indices: *const GLvoid = args.offset_ptr(3);
+            let instancecount: GLsizei = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_draw_elements_instanced(
+                        mode.try_into_enum()?,
+                        count,
+                        r#type.try_into_enum()?,
+                        indices,
+                        instancecount,
+                    )
+                })
+            })
+        }
+        "glDrawElementsInstancedBaseInstance" => {
+            let mode: GLenum = args.value(0);
+            let count: GLsizei = args.value(1);
+            let r#type: GLenum = args.value(2);
+            let indices: *const GLvoid = args.offset_ptr(3);
+            let instancecount: GLsizei = args.value(4);
+            let baseinstance: GLuint = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_draw_elements_instanced_base_instance(
+                        mode.try_into_enum()?,
+                        count,
+                        r#type.try_into_enum()?,
+                        indices,
+                        instancecount,
+                        baseinstance,
+                    )
+                })
+            })
+        }
+        "glDrawElementsInstancedBaseVertex" => {
+            let mode: GLenum = args.value(0);
+            let count: GLsizei = args.value(1);
+            let r#type: GLenum = args.value(2);
+            let indices: *const GLvoid = args.offset_ptr(3);
+            let instancecount: GLsizei = args.value(4);
+            let basevertex: GLint = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_draw_elements_instanced_base_vertex(
+                        mode.try_into_enum()?,
+                        count,
+                        r#type.try_into_enum()?,
+                        indices,
+                        instancecount,
+                        basevertex,
+                    )
+                })
+            })
+        }
+        "glDrawElementsInstancedBaseVertexBaseInstance" => {
+            let mode: GLenum = args.value(0);
+            let count: GLsizei = args.value(1);
+            let r#type: GLenum = args.value(2);
+            let indices: *const GLvoid = args.offset_ptr(3);
+            let instancecount: GLsizei = args.value(4);
+            let basevertex: GLint = args.value(5);
+            let baseinstance: GLuint = args.value(6);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_draw_elements_instanced_base_vertex_base_instance(
+                        mode.try_into_enum()?,
+                        count,
+                        r#type.try_into_enum()?,
+                        indices,
+                        instancecount,
+                        basevertex,
+                        baseinstance,
+                    )
+                })
+            })
+        }
+        "glDrawRangeElements" => {
+            let mode: GLenum = args.value(0);
+            let start: GLuint = args.value(1);
+            let end: GLuint = args.value(2);
+            let count: GLsizei = args.value(3);
+            let r#type: GLenum = args.value(4);
+            let indices: *const GLvoid = args.offset_ptr(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_draw_range_elements(
+                        mode.try_into_enum()?,
+                        start,
+                        end,
+                        count,
+                        r#type.try_into_enum()?,
+                        indices,
+                    )
+                })
+            })
+        }
+        "glDrawRangeElementsBaseVertex" => {
+            let mode: GLenum = args.value(0);
+            let start: GLuint = args.value(1);
+            let end: GLuint = args.value(2);
+            let count: GLsizei = args.value(3);
+            let r#type: GLenum = args.value(4);
+            let indices: *const GLvoid = args.offset_ptr(5);
+            let basevertex: GLint = args.value(6);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_draw_range_elements_base_vertex(
+                        mode.try_into_enum()?,
+                        start,
+                        end,
+                        count,
+                        r#type.try_into_enum()?,
+                        indices,
+                        basevertex,
+                    )
+                })
+            })
+        }
+        "glDrawTransformFeedback" => {
+            let mode: GLenum = args.value(0);
+            let id: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_draw_transform_feedback(mode.try_into_enum()?, id),
+                )
+            })
+        }
+        "glDrawTransformFeedbackInstanced" => {
+            let mode: GLenum = args.value(0);
+            let id: GLuint = args.value(1);
+            let instancecount: GLsizei = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_draw_transform_feedback_instanced(
+                    mode.try_into_enum()?,
+                    id,
+                    instancecount,
+                ))
+            })
+        }
+        "glDrawTransformFeedbackStream" => {
+            let mode: GLenum = args.value(0);
+            let id: GLuint = args.value(1);
+            let stream: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_draw_transform_feedback_stream(
+                    mode.try_into_enum()?,
+                    id,
+                    stream,
+                ))
+            })
+        }
+        "glDrawTransformFeedbackStreamInstanced" => {
+            let mode: GLenum = args.value(0);
+            let id: GLuint = args.value(1);
+            let stream: GLuint = args.value(2);
+            let instancecount: GLsizei = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_draw_transform_feedback_stream_instanced(
+                    mode.try_into_enum()?,
+                    id,
+                    stream,
+                    instancecount,
+                ))
+            })
+        }
+        "glDisable" => {
+            let cap: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_disable(cap.try_into_enum()?))
+            })
+        }
+        "glEnable" => {
+            let cap: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_enable(cap.try_into_enum()?))
+            })
+        }
+        "glEnablei" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_enablei(target.try_into_enum()?, index))
+            })
+        }
+        "glDisablei" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_disablei(target.try_into_enum()?, index))
+            })
+        }
+        "glDisableVertexAttribArray" => {
+            let index: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_disable_vertex_attrib_array(index)
+            })
+        }
+        "glEnableVertexAttribArray" => {
+            let index: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_enable_vertex_attrib_array(index)
+            })
+        }
+        "glDisableVertexArrayAttrib" => {
+            let vaobj: GLuint = args.value(0);
+            let index: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_disable_vertex_array_attrib(vaobj, index)
+            })
+        }
+        "glEnableVertexArrayAttrib" => {
+            let vaobj: GLuint = args.value(0);
+            let index: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_enable_vertex_array_attrib(vaobj, index)
+            })
+        }
+        "glFenceSync" => {
+            let condition: GLenum = args.value(0);
+            let flags: GLbitfield = args.value(1);
+            run::<GLsync, _, _, _>(ctx, |mut state| state.oxidegl_fence_sync(condition, flags))
+        }
+        "glFinish" => run::<(), _, _, _>(ctx, |mut state| state.oxidegl_finish()),
+        "glFlush" => run::<(), _, _, _>(ctx, |mut state| state.oxidegl_flush()),
+        "glFlushMappedBufferRange" => {
+            let target: GLenum = args.value(0);
+            let offset: GLintptr = args.value(1);
+            let length: GLsizeiptr = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_flush_mapped_buffer_range(
+                    target.try_into_enum()?,
+                    offset,
+                    length,
+                ))
+            })
+        }
+        "glFlushMappedNamedBufferRange" => {
+            let buffer: GLuint = args.value(0);
+            let offset: GLintptr = args.value(1);
+            let length: GLsizeiptr = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_flush_mapped_named_buffer_range(buffer, offset, length)
+            })
+        }
+        "glFramebufferParameteri" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let param: GLint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_framebuffer_parameteri(
+                    target.try_into_enum()?,
+                    pname.try_into_enum()?,
+                    param,
+                ))
+            })
+        }
+        "glNamedFramebufferParameteri" => {
+            let framebuffer: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let param: GLint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_named_framebuffer_parameteri(
+                    framebuffer,
+                    pname.try_into_enum()?,
+                    param,
+                ))
+            })
+        }
+        "glFramebufferRenderbuffer" => {
+            let target: GLenum = args.value(0);
+            let attachment: GLenum = args.value(1);
+            let renderbuffertarget: GLenum = args.value(2);
+            let renderbuffer: GLuint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_framebuffer_renderbuffer(
+                    target.try_into_enum()?,
+                    attachment.try_into_enum()?,
+                    renderbuffertarget,
+                    renderbuffer,
+                ))
+            })
+        }
+        "glNamedFramebufferRenderbuffer" => {
+            let framebuffer: GLuint = args.value(0);
+            let attachment: GLenum = args.value(1);
+            let renderbuffertarget: GLenum = args.value(2);
+            let renderbuffer: GLuint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_named_framebuffer_renderbuffer(
+                    framebuffer,
+                    attachment.try_into_enum()?,
+                    renderbuffertarget,
+                    renderbuffer,
+                ))
+            })
+        }
+        "glFramebufferTexture1D" => {
+            let target: GLenum = args.value(0);
+            let attachment: GLenum = args.value(1);
+            let textarget: GLenum = args.value(2);
+            let texture: GLuint = args.value(3);
+            let level: GLint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_framebuffer_texture1_d(
+                    target.try_into_enum()?,
+                    attachment.try_into_enum()?,
+                    textarget.try_into_enum()?,
+                    texture,
+                    level,
+                ))
+            })
+        }
+        "glFramebufferTexture2D" => {
+            let target: GLenum = args.value(0);
+            let attachment: GLenum = args.value(1);
+            let textarget: GLenum = args.value(2);
+            let texture: GLuint = args.value(3);
+            let level: GLint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_framebuffer_texture2_d(
+                    target.try_into_enum()?,
+                    attachment.try_into_enum()?,
+                    textarget.try_into_enum()?,
+                    texture,
+                    level,
+                ))
+            })
+        }
+        "glFramebufferTexture3D" => {
+            let target: GLenum = args.value(0);
+            let attachment: GLenum = args.value(1);
+            let textarget: GLenum = args.value(2);
+            let texture: GLuint = args.value(3);
+            let level: GLint = args.value(4);
+            let zoffset: GLint = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_framebuffer_texture3_d(
+                    target.try_into_enum()?,
+                    attachment.try_into_enum()?,
+                    textarget.try_into_enum()?,
+                    texture,
+                    level,
+                    zoffset,
+                ))
+            })
+        }
+        "glFramebufferTexture" => {
+            let target: GLenum = args.value(0);
+            let attachment: GLenum = args.value(1);
+            let texture: GLuint = args.value(2);
+            let level: GLint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_framebuffer_texture(
+                    target.try_into_enum()?,
+                    attachment.try_into_enum()?,
+                    texture,
+                    level,
+                ))
+            })
+        }
+        "glNamedFramebufferTexture" => {
+            let framebuffer: GLuint = args.value(0);
+            let attachment: GLenum = args.value(1);
+            let texture: GLuint = args.value(2);
+            let level: GLint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_named_framebuffer_texture(
+                    framebuffer,
+                    attachment.try_into_enum()?,
+                    texture,
+                    level,
+                ))
+            })
+        }
+        "glFramebufferTextureLayer" => {
+            let target: GLenum = args.value(0);
+            let attachment: GLenum = args.value(1);
+            let texture: GLuint = args.value(2);
+            let level: GLint = args.value(3);
+            let layer: GLint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_framebuffer_texture_layer(
+                    target.try_into_enum()?,
+                    attachment.try_into_enum()?,
+                    texture,
+                    level,
+                    layer,
+                ))
+            })
+        }
+        "glNamedFramebufferTextureLayer" => {
+            let framebuffer: GLuint = args.value(0);
+            let attachment: GLenum = args.value(1);
+            let texture: GLuint = args.value(2);
+            let level: GLint = args.value(3);
+            let layer: GLint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_named_framebuffer_texture_layer(
+                    framebuffer,
+                    attachment.try_into_enum()?,
+                    texture,
+                    level,
+                    layer,
+                ))
+            })
+        }
+        "glFrontFace" => {
+            let mode: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_front_face(mode.try_into_enum()?))
+            })
+        }
+        "glGenBuffers" => {
+            let n: GLsizei = args.value(0);
+            let buffers: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_gen_buffers(n, buffers)
+            })
+        }
+        "glGenerateMipmap" => {
+            let target: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_generate_mipmap(target.try_into_enum()?))
+            })
+        }
+        "glGenerateTextureMipmap" => {
+            let texture: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_generate_texture_mipmap(texture)
+            })
+        }
+        "glGenFramebuffers" => {
+            let n: GLsizei = args.value(0);
+            let framebuffers: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_gen_framebuffers(n, framebuffers)
+            })
+        }
+        "glGenProgramPipelines" => {
+            let n: GLsizei = args.value(0);
+            let pipelines: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_gen_program_pipelines(n, pipelines)
+            })
+        }
+        "glGenQueries" => {
+            let n: GLsizei = args.value(0);
+            let ids: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_gen_queries(n, ids)
+            })
+        }
+        "glGenRenderbuffers" => {
+            let n: GLsizei = args.value(0);
+            let renderbuffers: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_gen_renderbuffers(n, renderbuffers)
+            })
+        }
+        "glGenSamplers" => {
+            let count: GLsizei = args.value(0);
+            let samplers: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_gen_samplers(count, samplers)
+            })
+        }
+        "glGenTextures" => {
+            let n: GLsizei = args.value(0);
+            let textures: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_gen_textures(n, textures)
+            })
+        }
+        "glGenTransformFeedbacks" => {
+            let n: GLsizei = args.value(0);
+            let ids: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_gen_transform_feedbacks(n, ids)
+            })
+        }
+        "glGenVertexArrays" => {
+            let n: GLsizei = args.value(0);
+            let arrays: *mut GLuint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_gen_vertex_arrays(n, arrays)
+            })
+        }
+        "glGetBooleanv" => {
+            let pname: GLenum = args.value(0);
+            let data: *mut GLboolean = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_booleanv(pname.try_into_enum()?, data)
+                })
+            })
+        }
+        "glGetDoublev" => {
+            let pname: GLenum = args.value(0);
+            let data: *mut GLdouble = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_doublev(pname.try_into_enum()?, data)
+                })
+            })
+        }
+        "glGetFloatv" => {
+            let pname: GLenum = args.value(0);
+            let data: *mut GLfloat = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_floatv(pname.try_into_enum()?, data)
+                })
+            })
+        }
+        "glGetIntegerv" => {
+            let pname: GLenum = args.value(0);
+            let data: *mut GLint = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_integerv(pname.try_into_enum()?, data)
+                })
+            })
+        }
+        "glGetBooleani_v" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            let data: *mut GLboolean = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_booleani_v(target.try_into_enum()?, index, data)
+                })
+            })
+        }
+        "glGetIntegeri_v" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            let data: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_integeri_v(target.try_into_enum()?, index, data)
+                })
+            })
+        }
+        "glGetInteger64v" => {
+            let pname: GLenum = args.value(0);
+            let data: *mut GLint64 = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_integer64v(pname.try_into_enum()?, data)
+                })
+            })
+        }
+        "glGetInteger64i_v" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            let data: *mut GLint64 = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_integer64i_v(target.try_into_enum()?, index, data)
+                })
+            })
+        }
+        "glGetFloati_v" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            let data: *mut GLfloat = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_floati_v(target.try_into_enum()?, index, data)
+                })
+            })
+        }
+        "glGetDoublei_v" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            let data: *mut GLdouble = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_doublei_v(target.try_into_enum()?, index, data)
+                })
+            })
+        }
+        "glGetActiveAtomicCounterBufferiv" => {
+            let program: GLuint = args.value(0);
+            let bufferIndex: GLuint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let params: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_active_atomic_counter_bufferiv(
+                        program,
+                        bufferIndex,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetActiveAttrib" => {
+            let program: GLuint = args.value(0);
+            let index: GLuint = args.value(1);
+            let bufSize: GLsizei = args.value(2);
+            let length: *mut GLsizei = args.out_ptr(3);
+            let size: *mut GLint = args.out_ptr(4);
+            let r#type: *mut GLenum = args.out_ptr(5);
+            let name: *mut GLchar = args.out_ptr(6);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_active_attrib(
+                    program,
+                    index,
+                    bufSize,
+                    length,
+                    size,
+                    r#type.cast(),
+                    name,
+                )
+            })
+        }
+        "glGetActiveSubroutineName" => {
+            let program: GLuint = args.value(0);
+            let shadertype: GLenum = args.value(1);
+            let index: GLuint = args.value(2);
+            let bufSize: GLsizei = args.value(3);
+            let length: *mut GLsizei = args.out_ptr(4);
+            let name: *mut GLchar = args.out_ptr(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_active_subroutine_name(
+                        program,
+                        shadertype.try_into_enum()?,
+                        index,
+                        bufSize,
+                        length,
+                        name,
+                    )
+                })
+            })
+        }
+        "glGetActiveSubroutineUniformiv" => {
+            let program: GLuint = args.value(0);
+            let shadertype: GLenum = args.value(1);
+            let index: GLuint = args.value(2);
+            let pname: GLenum = args.value(3);
+            let values: *mut GLint = args.out_ptr(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_active_subroutine_uniformiv(
+                        program,
+                        shadertype.try_into_enum()?,
+                        index,
+                        pname.try_into_enum()?,
+                        values,
+                    )
+                })
+            })
+        }
+        "glGetActiveSubroutineUniformName" => {
+            let program: GLuint = args.value(0);
+            let shadertype: GLenum = args.value(1);
+            let index: GLuint = args.value(2);
+            let bufSize: GLsizei = args.value(3);
+            let length: *mut GLsizei = args.out_ptr(4);
+            let name: *mut GLchar = args.out_ptr(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_active_subroutine_uniform_name(
+                        program,
+                        shadertype.try_into_enum()?,
+                        index,
+                        bufSize,
+                        length,
+                        name,
+                    )
+                })
+            })
+        }
+        "glGetActiveUniform" => {
+            let program: GLuint = args.value(0);
+            let index: GLuint = args.value(1);
+            let bufSize: GLsizei = args.value(2);
+            let length: *mut GLsizei = args.out_ptr(3);
+            let size: *mut GLint = args.out_ptr(4);
+            let r#type: *mut GLenum = args.out_ptr(5);
+            let name: *mut GLchar = args.out_ptr(6);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_active_uniform(
+                    program,
+                    index,
+                    bufSize,
+                    length,
+                    size,
+                    r#type.cast(),
+                    name,
+                )
+            })
+        }
+        "glGetActiveUniformBlockiv" => {
+            let program: GLuint = args.value(0);
+            let uniformBlockIndex: GLuint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let params: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_active_uniform_blockiv(
+                        program,
+                        uniformBlockIndex,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetActiveUniformBlockName" => {
+            let program: GLuint = args.value(0);
+            let uniformBlockIndex: GLuint = args.value(1);
+            let bufSize: GLsizei = args.value(2);
+            let length: *mut GLsizei = args.out_ptr(3);
+            let uniformBlockName: *mut GLchar = args.out_ptr(4);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_active_uniform_block_name(
+                    program,
+                    uniformBlockIndex,
+                    bufSize,
+                    length,
+                    uniformBlockName,
+                )
+            })
+        }
+        "glGetActiveUniformName" => {
+            let program: GLuint = args.value(0);
+            let uniformIndex: GLuint = args.value(1);
+            let bufSize: GLsizei = args.value(2);
+            let length: *mut GLsizei = args.out_ptr(3);
+            let uniformName: *mut GLchar = args.out_ptr(4);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_active_uniform_name(
+                    program,
+                    uniformIndex,
+                    bufSize,
+                    length,
+                    uniformName,
+                )
+            })
+        }
+        "glGetActiveUniformsiv" => {
+            let program: GLuint = args.value(0);
+            let uniformCount: GLsizei = args.value(1);
+            let Some(uniformIndices) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let pname: GLenum = args.value(3);
+            let params: *mut GLint = args.out_ptr(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_active_uniformsiv(
+                        program,
+                        uniformCount,
+                        uniformIndices,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetAttachedShaders" => {
+            let program: GLuint = args.value(0);
+            let maxCount: GLsizei = args.value(1);
+            let count: *mut GLsizei = args.out_ptr(2);
+            let shaders: *mut GLuint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_attached_shaders(program, maxCount, count, shaders)
+            })
+        }
+        "glGetAttribLocation" => {
+            let program: GLuint = args.value(0);
+            let Some(name) = args.ptr::<GLchar>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<GLint, _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_attrib_location(program, name)
+            })
+        }
+        "glGetBufferParameteriv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_buffer_parameteriv(
+                        target.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetBufferParameteri64v" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint64 = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_buffer_parameteri64v(
+                        target.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetNamedBufferParameteriv" => {
+            let buffer: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_named_buffer_parameteriv(
+                        buffer,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetNamedBufferParameteri64v" => {
+            let buffer: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint64 = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_named_buffer_parameteri64v(
+                        buffer,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetBufferPointerv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut *mut GLvoid = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_buffer_pointerv(target.try_into_enum()?, pname, params)
+                })
+            })
+        }
+        "glGetNamedBufferPointerv" => {
+            let buffer: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut *mut GLvoid = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_named_buffer_pointerv(buffer, pname, params)
+            })
+        }
+        "glGetBufferSubData" => {
+            let target: GLenum = args.value(0);
+            let offset: GLintptr = args.value(1);
+            let size: GLsizeiptr = args.value(2);
+            let data: *mut GLvoid = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_buffer_sub_data(target.try_into_enum()?, offset, size, data)
+                })
+            })
+        }
+        "glGetNamedBufferSubData" => {
+            let buffer: GLuint = args.value(0);
+            let offset: GLintptr = args.value(1);
+            let size: GLsizeiptr = args.value(2);
+            let data: *mut GLvoid = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_named_buffer_sub_data(buffer, offset, size, data)
+            })
+        }
+        "glGetCompressedTexImage" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let img: *mut GLvoid = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_compressed_tex_image(target.try_into_enum()?, level, img)
+                })
+            })
+        }
+        "glGetCompressedTextureImage" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let bufSize: GLsizei = args.value(2);
+            let pixels: *mut GLvoid = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_compressed_texture_image(texture, level, bufSize, pixels)
+            })
+        }
+        "glGetnCompressedTexImage" => {
+            let target: GLenum = args.value(0);
+            let lod: GLint = args.value(1);
+            let bufSize: GLsizei = args.value(2);
+            let pixels: *mut GLvoid = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_getn_compressed_tex_image(
+                        target.try_into_enum()?,
+                        lod,
+                        bufSize,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glGetCompressedTextureSubImage" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let zoffset: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            let height: GLsizei = args.value(6);
+            let depth: GLsizei = args.value(7);
+            let bufSize: GLsizei = args.value(8);
+            let pixels: *mut GLvoid = args.out_ptr(9);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_compressed_texture_sub_image(
+                    texture, level, xoffset, yoffset, zoffset, width, height, depth, bufSize,
+                    pixels,
+                )
+            })
+        }
+        "glGetDebugMessageLog" => {
+            let count: GLuint = args.value(0);
+            let bufSize: GLsizei = args.value(1);
+            let sources: *mut GLenum = args.out_ptr(2);
+            let types: *mut GLenum = args.out_ptr(3);
+            let ids: *mut GLuint = args.out_ptr(4);
+            let severities: *mut GLenum = args.out_ptr(5);
+            let lengths: *mut GLsizei = args.out_ptr(6);
+            let messageLog: *mut GLchar = args.out_ptr(7);
+            run::<GLuint, _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_debug_message_log(
+                    count,
+                    bufSize,
+                    sources.cast(),
+                    types.cast(),
+                    ids,
+                    severities.cast(),
+                    lengths,
+                    messageLog,
+                )
+            })
+        }
+        "glGetError" => run::<GLenum, _, _, _>(ctx, |mut state| state.oxidegl_get_error()),
+        "glGetFragDataIndex" => {
+            let program: GLuint = args.value(0);
+            let Some(name) = args.ptr::<GLchar>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<GLint, _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_frag_data_index(program, name)
+            })
+        }
+        "glGetFragDataLocation" => {
+            let program: GLuint = args.value(0);
+            let Some(name) = args.ptr::<GLchar>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<GLint, _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_frag_data_location(program, name)
+            })
+        }
+        "glGetFramebufferAttachmentParameteriv" => {
+            let target: GLenum = args.value(0);
+            let attachment: GLenum = args.value(1);
+            let pname: GLenum = args.value(2);
+            let params: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_framebuffer_attachment_parameteriv(
+                        target.try_into_enum()?,
+                        attachment.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetNamedFramebufferAttachmentParameteriv" => {
+            let framebuffer: GLuint = args.value(0);
+            let attachment: GLenum = args.value(1);
+            let pname: GLenum = args.value(2);
+            let params: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_named_framebuffer_attachment_parameteriv(
+                        framebuffer,
+                        attachment.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetFramebufferParameteriv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_framebuffer_parameteriv(
+                        target.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetNamedFramebufferParameteriv" => {
+            let framebuffer: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let param: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_named_framebuffer_parameteriv(
+                        framebuffer,
+                        pname.try_into_enum()?,
+                        param,
+                    )
+                })
+            })
+        }
+        "glGetGraphicsResetStatus" => {
+            run::<GLenum, _, _, _>(ctx, |mut state| state.oxidegl_get_graphics_reset_status())
+        }
+        "glGetInternalformativ" => {
+            let target: GLenum = args.value(0);
+            let internalformat: GLenum = args.value(1);
+            let pname: GLenum = args.value(2);
+            let count: GLsizei = args.value(3);
+            let params: *mut GLint = args.out_ptr(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_internalformativ(
+                        target.try_into_enum()?,
+                        internalformat.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        count,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetInternalformati64v" => {
+            let target: GLenum = args.value(0);
+            let internalformat: GLenum = args.value(1);
+            let pname: GLenum = args.value(2);
+            let count: GLsizei = args.value(3);
+            let params: *mut GLint64 = args.out_ptr(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_internalformati64v(
+                        target.try_into_enum()?,
+                        internalformat.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        count,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetMultisamplefv" => {
+            let pname: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            let val: *mut GLfloat = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_multisamplefv(pname, index, val)
+            })
+        }
+        "glGetObjectLabel" => {
+            let identifier: GLenum = args.value(0);
+            let name: GLuint = args.value(1);
+            let bufSize: GLsizei = args.value(2);
+            let length: *mut GLsizei = args.out_ptr(3);
+            let label: *mut GLchar = args.out_ptr(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_object_label(
+                        identifier.try_into_enum()?,
+                        name,
+                        bufSize,
+                        length,
+                        label,
+                    )
+                })
+            })
+        }
+        "glGetObjectPtrLabel" => {
+            let Some(ptr) = args.ptr::<GLvoid>(0) else {
+                return Outcome::MissingClientMemory(0);
+            };
+            let bufSize: GLsizei = args.value(1);
+            let length: *mut GLsizei = args.out_ptr(2);
+            let label: *mut GLchar = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_object_ptr_label(ptr, bufSize, length, label)
+            })
+        }
+        "glGetPointerv" => {
+            let pname: GLenum = args.value(0);
+            let params: *mut *mut GLvoid = args.out_ptr(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_pointerv(pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetProgramiv" => {
+            let program: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_programiv(program, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetProgramBinary" => {
+            let program: GLuint = args.value(0);
+            let bufSize: GLsizei = args.value(1);
+            let length: *mut GLsizei = args.out_ptr(2);
+            let binaryFormat: *mut GLenum = args.out_ptr(3);
+            let binary: *mut GLvoid = args.out_ptr(4);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_program_binary(program, bufSize, length, binaryFormat, binary)
+            })
+        }
+        "glGetProgramInfoLog" => {
+            let program: GLuint = args.value(0);
+            let bufSize: GLsizei = args.value(1);
+            let length: *mut GLsizei = args.out_ptr(2);
+            let infoLog: *mut GLchar = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_program_info_log(program, bufSize, length, infoLog)
+            })
+        }
+        "glGetProgramInterfaceiv" => {
+            let program: GLuint = args.value(0);
+            let programInterface: GLenum = args.value(1);
+            let pname: GLenum = args.value(2);
+            let params: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_program_interfaceiv(
+                        program,
+                        programInterface.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetProgramPipelineiv" => {
+            let pipeline: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_program_pipelineiv(pipeline, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetProgramPipelineInfoLog" => {
+            let pipeline: GLuint = args.value(0);
+            let bufSize: GLsizei = args.value(1);
+            let length: *mut GLsizei = args.out_ptr(2);
+            let infoLog: *mut GLchar = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_program_pipeline_info_log(pipeline, bufSize, length, infoLog)
+            })
+        }
+        "glGetProgramResourceiv" => {
+            let program: GLuint = args.value(0);
+            let programInterface: GLenum = args.value(1);
+            let index: GLuint = args.value(2);
+            let propCount: GLsizei = args.value(3);
+            let Some(props) = args.ptr::<GLenum>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            let count: GLsizei = args.value(5);
+            let length: *mut GLsizei = args.out_ptr(6);
+            let params: *mut GLint = args.out_ptr(7);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_program_resourceiv(
+                        program,
+                        programInterface.try_into_enum()?,
+                        index,
+                        propCount,
+                        props.cast(),
+                        count,
+                        length,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetProgramResourceIndex" => {
+            let program: GLuint = args.value(0);
+            let programInterface: GLenum = args.value(1);
+            let Some(name) = args.ptr::<GLchar>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<GLuint, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_program_resource_index(
+                        program,
+                        programInterface.try_into_enum()?,
+                        name,
+                    )
+                })
+            })
+        }
+        "glGetProgramResourceLocation" => {
+            let program: GLuint = args.value(0);
+            let programInterface: GLenum = args.value(1);
+            let Some(name) = args.ptr::<GLchar>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<GLint, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_program_resource_location(
+                        program,
+                        programInterface.try_into_enum()?,
+                        name,
+                    )
+                })
+            })
+        }
+        "glGetProgramResourceLocationIndex" => {
+            let program: GLuint = args.value(0);
+            let programInterface: GLenum = args.value(1);
+            let Some(name) = args.ptr::<GLchar>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<GLint, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_program_resource_location_index(
+                        program,
+                        programInterface.try_into_enum()?,
+                        name,
+                    )
+                })
+            })
+        }
+        "glGetProgramResourceName" => {
+            let program: GLuint = args.value(0);
+            let programInterface: GLenum = args.value(1);
+            let index: GLuint = args.value(2);
+            let bufSize: GLsizei = args.value(3);
+            let length: *mut GLsizei = args.out_ptr(4);
+            let name: *mut GLchar = args.out_ptr(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_program_resource_name(
+                        program,
+                        programInterface.try_into_enum()?,
+                        index,
+                        bufSize,
+                        length,
+                        name,
+                    )
+                })
+            })
+        }
+        "glGetProgramStageiv" => {
+            let program: GLuint = args.value(0);
+            let shadertype: GLenum = args.value(1);
+            let pname: GLenum = args.value(2);
+            let values: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_program_stageiv(
+                        program,
+                        shadertype.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        values,
+                    )
+                })
+            })
+        }
+        "glGetQueryIndexediv" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let params: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_query_indexediv(
+                        target.try_into_enum()?,
+                        index,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetQueryiv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_queryiv(
+                        target.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetQueryObjectiv" => {
+            let id: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_query_objectiv(id, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetQueryObjectuiv" => {
+            let id: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLuint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_query_objectuiv(id, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetQueryObjecti64v" => {
+            let id: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint64 = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_query_objecti64v(id, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetQueryObjectui64v" => {
+            let id: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLuint64 = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_query_objectui64v(id, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetQueryBufferObjecti64v" => {
+            let id: GLuint = args.value(0);
+            let buffer: GLuint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let offset: GLintptr = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_get_query_buffer_objecti64v(
+                    id,
+                    buffer,
+                    pname.try_into_enum()?,
+                    offset,
+                ))
+            })
+        }
+        "glGetQueryBufferObjectiv" => {
+            let id: GLuint = args.value(0);
+            let buffer: GLuint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let offset: GLintptr = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_get_query_buffer_objectiv(
+                    id,
+                    buffer,
+                    pname.try_into_enum()?,
+                    offset,
+                ))
+            })
+        }
+        "glGetQueryBufferObjectui64v" => {
+            let id: GLuint = args.value(0);
+            let buffer: GLuint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let offset: GLintptr = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_get_query_buffer_objectui64v(
+                    id,
+                    buffer,
+                    pname.try_into_enum()?,
+                    offset,
+                ))
+            })
+        }
+        "glGetQueryBufferObjectuiv" => {
+            let id: GLuint = args.value(0);
+            let buffer: GLuint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let offset: GLintptr = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_get_query_buffer_objectuiv(
+                    id,
+                    buffer,
+                    pname.try_into_enum()?,
+                    offset,
+                ))
+            })
+        }
+        "glGetRenderbufferParameteriv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_renderbuffer_parameteriv(
+                        target,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetNamedRenderbufferParameteriv" => {
+            let renderbuffer: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_named_renderbuffer_parameteriv(
+                        renderbuffer,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetSamplerParameteriv" => {
+            let sampler: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_sampler_parameteriv(sampler, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetSamplerParameterIiv" => {
+            let sampler: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_sampler_parameter_iiv(sampler, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetSamplerParameterfv" => {
+            let sampler: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLfloat = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_sampler_parameterfv(sampler, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetSamplerParameterIuiv" => {
+            let sampler: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLuint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_sampler_parameter_iuiv(
+                        sampler,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetShaderiv" => {
+            let shader: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_shaderiv(shader, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetShaderInfoLog" => {
+            let shader: GLuint = args.value(0);
+            let bufSize: GLsizei = args.value(1);
+            let length: *mut GLsizei = args.out_ptr(2);
+            let infoLog: *mut GLchar = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_shader_info_log(shader, bufSize, length, infoLog)
+            })
+        }
+        "glGetShaderPrecisionFormat" => {
+            let shadertype: GLenum = args.value(0);
+            let precisiontype: GLenum = args.value(1);
+            let range: *mut GLint = args.out_ptr(2);
+            let precision: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_shader_precision_format(
+                        shadertype.try_into_enum()?,
+                        precisiontype.try_into_enum()?,
+                        range,
+                        precision,
+                    )
+                })
+            })
+        }
+        "glGetShaderSource" => {
+            let shader: GLuint = args.value(0);
+            let bufSize: GLsizei = args.value(1);
+            let length: *mut GLsizei = args.out_ptr(2);
+            let source: *mut GLchar = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_shader_source(shader, bufSize, length, source)
+            })
+        }
+        "glGetString" => {
+            let name: GLenum = args.value(0);
+            run::<*const GLubyte, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_get_string(name.try_into_enum()?))
+            })
+        }
+        "glGetStringi" => {
+            let name: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            run::<*const GLubyte, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_get_stringi(name.try_into_enum()?, index))
+            })
+        }
+        "glGetSubroutineIndex" => {
+            let program: GLuint = args.value(0);
+            let shadertype: GLenum = args.value(1);
+            let Some(name) = args.ptr::<GLchar>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<GLuint, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_subroutine_index(program, shadertype.try_into_enum()?, name)
+                })
+            })
+        }
+        "glGetSubroutineUniformLocation" => {
+            let program: GLuint = args.value(0);
+            let shadertype: GLenum = args.value(1);
+            let Some(name) = args.ptr::<GLchar>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<GLint, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_subroutine_uniform_location(
+                        program,
+                        shadertype.try_into_enum()?,
+                        name,
+                    )
+                })
+            })
+        }
+        "glGetSynciv" => {
+            let sync: GLsync = args.value(0);
+            let pname: GLenum = args.value(1);
+            let count: GLsizei = args.value(2);
+            let length: *mut GLsizei = args.out_ptr(3);
+            let values: *mut GLint = args.out_ptr(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_synciv(sync, pname.try_into_enum()?, count, length, values)
+                })
+            })
+        }
+        "glGetTexImage" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let format: GLenum = args.value(2);
+            let r#type: GLenum = args.value(3);
+            let pixels: *mut GLvoid = args.out_ptr(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_tex_image(
+                        target.try_into_enum()?,
+                        level,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glGetTextureImage" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let format: GLenum = args.value(2);
+            let r#type: GLenum = args.value(3);
+            let bufSize: GLsizei = args.value(4);
+            let pixels: *mut GLvoid = args.out_ptr(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_texture_image(
+                        texture,
+                        level,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        bufSize,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glGetnTexImage" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let format: GLenum = args.value(2);
+            let r#type: GLenum = args.value(3);
+            let bufSize: GLsizei = args.value(4);
+            let pixels: *mut GLvoid = args.out_ptr(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_getn_tex_image(
+                        target.try_into_enum()?,
+                        level,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        bufSize,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glGetTexLevelParameterfv" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let params: *mut GLfloat = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_tex_level_parameterfv(
+                        target.try_into_enum()?,
+                        level,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetTexLevelParameteriv" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let params: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_tex_level_parameteriv(
+                        target.try_into_enum()?,
+                        level,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetTextureLevelParameterfv" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let params: *mut GLfloat = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_texture_level_parameterfv(
+                        texture,
+                        level,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetTextureLevelParameteriv" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let params: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_texture_level_parameteriv(
+                        texture,
+                        level,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetTexParameterfv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLfloat = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_tex_parameterfv(
+                        target.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetTexParameteriv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_tex_parameteriv(
+                        target.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetTexParameterIiv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_tex_parameter_iiv(
+                        target.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetTexParameterIuiv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLuint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_tex_parameter_iuiv(
+                        target.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetTextureParameterfv" => {
+            let texture: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLfloat = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_texture_parameterfv(texture, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetTextureParameterIiv" => {
+            let texture: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_texture_parameter_iiv(texture, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetTextureParameterIuiv" => {
+            let texture: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLuint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_texture_parameter_iuiv(
+                        texture,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetTextureParameteriv" => {
+            let texture: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_texture_parameteriv(texture, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetTextureSubImage" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let zoffset: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            let height: GLsizei = args.value(6);
+            let depth: GLsizei = args.value(7);
+            let format: GLenum = args.value(8);
+            let r#type: GLenum = args.value(9);
+            let bufSize: GLsizei = args.value(10);
+            let pixels: *mut GLvoid = args.out_ptr(11);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_texture_sub_image(
+                        texture,
+                        level,
+                        xoffset,
+                        yoffset,
+                        zoffset,
+                        width,
+                        height,
+                        depth,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        bufSize,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glGetTransformFeedbackiv" => {
+            let xfb: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let param: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_transform_feedbackiv(xfb, pname.try_into_enum()?, param)
+                })
+            })
+        }
+        "glGetTransformFeedbacki_v" => {
+            let xfb: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let index: GLuint = args.value(2);
+            let param: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_transform_feedbacki_v(
+                        xfb,
+                        pname.try_into_enum()?,
+                        index,
+                        param,
+                    )
+                })
+            })
+        }
+        "glGetTransformFeedbacki64_v" => {
+            let xfb: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let index: GLuint = args.value(2);
+            let param: *mut GLint64 = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_transform_feedbacki64_v(
+                        xfb,
+                        pname.try_into_enum()?,
+                        index,
+                        param,
+                    )
+                })
+            })
+        }
+        "glGetTransformFeedbackVarying" => {
+            let program: GLuint = args.value(0);
+            let index: GLuint = args.value(1);
+            let bufSize: GLsizei = args.value(2);
+            let length: *mut GLsizei = args.out_ptr(3);
+            let size: *mut GLsizei = args.out_ptr(4);
+            let r#type: *mut GLenum = args.out_ptr(5);
+            let name: *mut GLchar = args.out_ptr(6);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_transform_feedback_varying(
+                    program,
+                    index,
+                    bufSize,
+                    length,
+                    size,
+                    r#type.cast(),
+                    name,
+                )
+            })
+        }
+        "glGetUniformfv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let params: *mut GLfloat = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_uniformfv(program, location, params)
+            })
+        }
+        "glGetUniformiv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_uniformiv(program, location, params)
+            })
+        }
+        "glGetUniformuiv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let params: *mut GLuint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_uniformuiv(program, location, params)
+            })
+        }
+        "glGetUniformdv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let params: *mut GLdouble = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_uniformdv(program, location, params)
+            })
+        }
+        "glGetnUniformdv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let bufSize: GLsizei = args.value(2);
+            let params: *mut GLdouble = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_getn_uniformdv(program, location, bufSize, params)
+            })
+        }
+        "glGetnUniformfv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let bufSize: GLsizei = args.value(2);
+            let params: *mut GLfloat = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_getn_uniformfv(program, location, bufSize, params)
+            })
+        }
+        "glGetnUniformiv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let bufSize: GLsizei = args.value(2);
+            let params: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_getn_uniformiv(program, location, bufSize, params)
+            })
+        }
+        "glGetnUniformuiv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let bufSize: GLsizei = args.value(2);
+            let params: *mut GLuint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_getn_uniformuiv(program, location, bufSize, params)
+            })
+        }
+        "glGetUniformBlockIndex" => {
+            let program: GLuint = args.value(0);
+            let Some(uniformBlockName) = args.ptr::<GLchar>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<GLuint, _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_uniform_block_index(program, uniformBlockName)
+            })
+        }
+        "glGetUniformIndices" => {
+            let program: GLuint = args.value(0);
+            let uniformCount: GLsizei = args.value(1);
+            let Some(uniformNames) = args.ptr_array::<*const GLchar>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let uniformIndices: *mut GLuint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_uniform_indices(
+                    program,
+                    uniformCount,
+                    uniformNames,
+                    uniformIndices,
+                )
+            })
+        }
+        "glGetUniformLocation" => {
+            let program: GLuint = args.value(0);
+            let Some(name) = args.ptr::<GLchar>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<GLint, _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_uniform_location(program, name)
+            })
+        }
+        "glGetUniformSubroutineuiv" => {
+            let shadertype: GLenum = args.value(0);
+            let location: GLint = args.value(1);
+            let params: *mut GLuint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_uniform_subroutineuiv(
+                        shadertype.try_into_enum()?,
+                        location,
+                        params,
+                    )
+                })
+            })
+        }
+        "glGetVertexArrayIndexediv" => {
+            let vaobj: GLuint = args.value(0);
+            let index: GLuint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let param: *mut GLint = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_vertex_array_indexediv(
+                        vaobj,
+                        index,
+                        pname.try_into_enum()?,
+                        param,
+                    )
+                })
+            })
+        }
+        "glGetVertexArrayIndexed64iv" => {
+            let vaobj: GLuint = args.value(0);
+            let index: GLuint = args.value(1);
+            let pname: GLenum = args.value(2);
+            let param: *mut GLint64 = args.out_ptr(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_vertex_array_indexed64iv(
+                        vaobj,
+                        index,
+                        pname.try_into_enum()?,
+                        param,
+                    )
+                })
+            })
+        }
+        "glGetVertexArrayiv" => {
+            let vaobj: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let param: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_vertex_arrayiv(vaobj, pname.try_into_enum()?, param)
+                })
+            })
+        }
+        "glGetVertexAttribdv" => {
+            let index: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLdouble = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_vertex_attribdv(index, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetVertexAttribfv" => {
+            let index: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLfloat = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_vertex_attribfv(index, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetVertexAttribiv" => {
+            let index: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_vertex_attribiv(index, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetVertexAttribIiv" => {
+            let index: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_vertex_attrib_iiv(index, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetVertexAttribIuiv" => {
+            let index: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLuint = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_vertex_attrib_iuiv(index, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetVertexAttribLdv" => {
+            let index: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let params: *mut GLdouble = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_get_vertex_attrib_ldv(index, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glGetVertexAttribPointerv" => {
+            let index: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let pointer: *mut *mut GLvoid = args.out_ptr(2);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_get_vertex_attrib_pointerv(index, pname, pointer)
+            })
+        }
+        "glHint" => {
+            let target: GLenum = args.value(0);
+            let mode: GLenum = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_hint(target.try_into_enum()?, mode.try_into_enum()?),
+                )
+            })
+        }
+        "glInvalidateBufferData" => {
+            let buffer: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_invalidate_buffer_data(buffer)
+            })
+        }
+        "glInvalidateBufferSubData" => {
+            let buffer: GLuint = args.value(0);
+            let offset: GLintptr = args.value(1);
+            let length: GLsizeiptr = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_invalidate_buffer_sub_data(buffer, offset, length)
+            })
+        }
+        "glInvalidateFramebuffer" => {
+            let target: GLenum = args.value(0);
+            let numAttachments: GLsizei = args.value(1);
+            let Some(attachments) = args.ptr::<GLenum>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_invalidate_framebuffer(
+                        target.try_into_enum()?,
+                        numAttachments,
+                        attachments.cast(),
+                    )
+                })
+            })
+        }
+        "glInvalidateNamedFramebufferData" => {
+            let framebuffer: GLuint = args.value(0);
+            let numAttachments: GLsizei = args.value(1);
+            let Some(attachments) = args.ptr::<GLenum>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_invalidate_named_framebuffer_data(
+                    framebuffer,
+                    numAttachments,
+                    attachments.cast(),
+                )
+            })
+        }
+        "glInvalidateSubFramebuffer" => {
+            let target: GLenum = args.value(0);
+            let numAttachments: GLsizei = args.value(1);
+            let Some(attachments) = args.ptr::<GLenum>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let x: GLint = args.value(3);
+            let y: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            let height: GLsizei = args.value(6);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_invalidate_sub_framebuffer(
+                        target.try_into_enum()?,
+                        numAttachments,
+                        attachments.cast(),
+                        x,
+                        y,
+                        width,
+                        height,
+                    )
+                })
+            })
+        }
+        "glInvalidateNamedFramebufferSubData" => {
+            let framebuffer: GLuint = args.value(0);
+            let numAttachments: GLsizei = args.value(1);
+            let Some(attachments) = args.ptr::<GLenum>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let x: GLint = args.value(3);
+            let y: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            let height: GLsizei = args.value(6);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_invalidate_named_framebuffer_sub_data(
+                    framebuffer,
+                    numAttachments,
+                    attachments.cast(),
+                    x,
+                    y,
+                    width,
+                    height,
+                )
+            })
+        }
+        "glInvalidateTexImage" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_invalidate_tex_image(texture, level)
+            })
+        }
+        "glInvalidateTexSubImage" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let zoffset: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            let height: GLsizei = args.value(6);
+            let depth: GLsizei = args.value(7);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_invalidate_tex_sub_image(
+                    texture, level, xoffset, yoffset, zoffset, width, height, depth,
+                )
+            })
+        }
+        "glIsBuffer" => {
+            let buffer: GLuint = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_is_buffer(buffer))
+        }
+        "glIsEnabled" => {
+            let cap: GLenum = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_is_enabled(cap.try_into_enum()?))
+            })
+        }
+        "glIsEnabledi" => {
+            let target: GLenum = args.value(0);
+            let index: GLuint = args.value(1);
+            run::<GLboolean, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_is_enabledi(target.try_into_enum()?, index))
+            })
+        }
+        "glIsFramebuffer" => {
+            let framebuffer: GLuint = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_is_framebuffer(framebuffer))
+        }
+        "glIsProgram" => {
+            let program: GLuint = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_is_program(program))
+        }
+        "glIsProgramPipeline" => {
+            let pipeline: GLuint = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_is_program_pipeline(pipeline))
+        }
+        "glIsQuery" => {
+            let id: GLuint = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_is_query(id))
+        }
+        "glIsRenderbuffer" => {
+            let renderbuffer: GLuint = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_is_renderbuffer(renderbuffer))
+        }
+        "glIsSampler" => {
+            let sampler: GLuint = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_is_sampler(sampler))
+        }
+        "glIsShader" => {
+            let shader: GLuint = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_is_shader(shader))
+        }
+        "glIsSync" => {
+            let sync: GLsync = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_is_sync(sync))
+        }
+        "glIsTexture" => {
+            let texture: GLuint = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_is_texture(texture))
+        }
+        "glIsTransformFeedback" => {
+            let id: GLuint = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_is_transform_feedback(id))
+        }
+        "glIsVertexArray" => {
+            let array: GLuint = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_is_vertex_array(array))
+        }
+        "glLineWidth" => {
+            let width: GLfloat = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_line_width(width))
+        }
+        "glLinkProgram" => {
+            let program: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_link_program(program))
+        }
+        "glLogicOp" => {
+            let opcode: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_logic_op(opcode.try_into_enum()?))
+            })
+        }
+        "glMapBuffer" => {
+            let target: GLenum = args.value(0);
+            let access: GLenum = args.value(1);
+            run::<*mut GLvoid, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_map_buffer(target.try_into_enum()?, access.try_into_enum()?),
+                )
+            })
+        }
+        "glMapNamedBuffer" => {
+            let buffer: GLuint = args.value(0);
+            let access: GLenum = args.value(1);
+            run::<*mut GLvoid, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_map_named_buffer(buffer, access.try_into_enum()?))
+            })
+        }
+        "glMapBufferRange" => {
+            let target: GLenum = args.value(0);
+            let offset: GLintptr = args.value(1);
+            let length: GLsizeiptr = args.value(2);
+            let access: GLenum = args.value(3);
+            run::<*mut GLvoid, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_map_buffer_range(
+                    target.try_into_enum()?,
+                    offset,
+                    length,
+                    access.try_into_enum()?,
+                ))
+            })
+        }
+        "glMapNamedBufferRange" => {
+            let buffer: GLuint = args.value(0);
+            let offset: GLintptr = args.value(1);
+            let length: GLsizeiptr = args.value(2);
+            let access: GLenum = args.value(3);
+            run::<*mut GLvoid, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_map_named_buffer_range(
+                    buffer,
+                    offset,
+                    length,
+                    access.try_into_enum()?,
+                ))
+            })
+        }
+        "glMemoryBarrier" => {
+            let barriers: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_memory_barrier(barriers.try_into_enum()?))
+            })
+        }
+        "glMemoryBarrierByRegion" => {
+            let barriers: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_memory_barrier_by_region(barriers.try_into_enum()?),
+                )
+            })
+        }
+        "glMinSampleShading" => {
+            let value: GLfloat = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_min_sample_shading(value))
+        }
+        "glMultiDrawArrays" => {
+            let mode: GLenum = args.value(0);
+            let Some(first) = args.ptr::<GLint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            let Some(count) = args.ptr::<GLsizei>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let drawcount: GLsizei = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_multi_draw_arrays(mode.try_into_enum()?, first, count, drawcount)
+                })
+            })
+        }
+        "glMultiDrawArraysIndirect" => {
+            let mode: GLenum = args.value(0);
+            let indirect: *const GLvoid = args.offset_ptr(1);
+            let drawcount: GLsizei = args.value(2);
+            let stride: GLsizei = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_multi_draw_arrays_indirect(
+                        mode.try_into_enum()?,
+                        indirect,
+                        drawcount,
+                        stride,
+                    )
+                })
+            })
+        }
+        "glMultiDrawElements" => {
+            let mode: GLenum = args.value(0);
+            let Some(count) = args.ptr::<GLsizei>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            let r#type: GLenum = args.value(2);
+            let indices: *mut *const GLvoid = args.out_ptr(3);
+            let drawcount: GLsizei = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_multi_draw_elements(
+                        mode.try_into_enum()?,
+                        count,
+                        r#type.try_into_enum()?,
+                        indices,
+                        drawcount,
+                    )
+                })
+            })
+        }
+        "glMultiDrawElementsBaseVertex" => {
+            let mode: GLenum = args.value(0);
+            let Some(count) = args.ptr::<GLsizei>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            let r#type: GLenum = args.value(2);
+            let indices: *mut *const GLvoid = args.out_ptr(3);
+            let drawcount: GLsizei = args.value(4);
+            let Some(basevertex) = args.ptr::<GLint>(5) else {
+                return Outcome::MissingClientMemory(5);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_multi_draw_elements_base_vertex(
+                        mode.try_into_enum()?,
+                        count,
+                        r#type.try_into_enum()?,
+                        indices,
+                        drawcount,
+                        basevertex,
+                    )
+                })
+            })
+        }
+        "glMultiDrawElementsIndirect" => {
+            let mode: GLenum = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let indirect: *const GLvoid = args.offset_ptr(2);
+            let drawcount: GLsizei = args.value(3);
+            let stride: GLsizei = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_multi_draw_elements_indirect(
+                        mode.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        indirect,
+                        drawcount,
+                        stride,
+                    )
+                })
+            })
+        }
+        "glObjectLabel" => {
+            let identifier: GLenum = args.value(0);
+            let name: GLuint = args.value(1);
+            let length: GLsizei = args.value(2);
+            let Some(label) = args.ptr::<GLchar>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_object_label(identifier.try_into_enum()?, name, length, label)
+                })
+            })
+        }
+        "glObjectPtrLabel" => {
+            let Some(ptr) = args.ptr::<GLvoid>(0) else {
+                return Outcome::MissingClientMemory(0);
+            };
+            let length: GLsizei = args.value(1);
+            let Some(label) = args.ptr::<GLchar>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_object_ptr_label(ptr, length, label)
+            })
+        }
+        "glPatchParameteri" => {
+            let pname: GLenum = args.value(0);
+            let value: GLint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_patch_parameteri(pname.try_into_enum()?, value))
+            })
+        }
+        "glPatchParameterfv" => {
+            let pname: GLenum = args.value(0);
+            let Some(values) = args.ptr::<GLfloat>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_patch_parameterfv(pname.try_into_enum()?, values)
+                })
+            })
+        }
+        "glPauseTransformFeedback" => {
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_pause_transform_feedback())
+        }
+        "glPixelStoref" => {
+            let pname: GLenum = args.value(0);
+            let param: GLfloat = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_pixel_storef(pname.try_into_enum()?, param))
+            })
+        }
+        "glPixelStorei" => {
+            let pname: GLenum = args.value(0);
+            let param: GLint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_pixel_storei(pname.try_into_enum()?, param))
+            })
+        }
+        "glPointParameterf" => {
+            let pname: GLenum = args.value(0);
+            let param: GLfloat = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_point_parameterf(pname, param)
+            })
+        }
+        "glPointParameterfv" => {
+            let pname: GLenum = args.value(0);
+            let Some(params) = args.ptr::<GLfloat>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_point_parameterfv(pname, params)
+            })
+        }
+        "glPointParameteri" => {
+            let pname: GLenum = args.value(0);
+            let param: GLint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_point_parameteri(pname, param)
+            })
+        }
+        "glPointParameteriv" => {
+            let pname: GLenum = args.value(0);
+            let Some(params) = args.ptr::<GLint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_point_parameteriv(pname, params)
+            })
+        }
+        "glPointSize" => {
+            let size: GLfloat = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_point_size(size))
+        }
+        "glPolygonMode" => {
+            let face: GLenum = args.value(0);
+            let mode: GLenum = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_polygon_mode(face.try_into_enum()?, mode.try_into_enum()?),
+                )
+            })
+        }
+        "glPolygonOffset" => {
+            let factor: GLfloat = args.value(0);
+            let units: GLfloat = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_polygon_offset(factor, units))
+        }
+        "glPopDebugGroup" => run::<(), _, _, _>(ctx, |mut state| state.oxidegl_pop_debug_group()),
+        "glPrimitiveRestartIndex" => {
+            let index: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_primitive_restart_index(index)
+            })
+        }
+        "glProgramBinary" => {
+            let program: GLuint = args.value(0);
+            let binaryFormat: GLenum = args.value(1);
+            let Some(binary) = args.ptr::<GLvoid>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let length: GLsizei = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_binary(program, binaryFormat, binary, length)
+            })
+        }
+        "glProgramParameteri" => {
+            let program: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let value: GLint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_program_parameteri(
+                    program,
+                    pname.try_into_enum()?,
+                    value,
+                ))
+            })
+        }
+        "glProgramUniform1i" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform1i(program, location, v0)
+            })
+        }
+        "glProgramUniform1iv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform1iv(program, location, count, value)
+            })
+        }
+        "glProgramUniform1f" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLfloat = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform1f(program, location, v0)
+            })
+        }
+        "glProgramUniform1fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform1fv(program, location, count, value)
+            })
+        }
+        "glProgramUniform1ui" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform1ui(program, location, v0)
+            })
+        }
+        "glProgramUniform1uiv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform1uiv(program, location, count, value)
+            })
+        }
+        "glProgramUniform2i" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLint = args.value(2);
+            let v1: GLint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform2i(program, location, v0, v1)
+            })
+        }
+        "glProgramUniform2iv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform2iv(program, location, count, value)
+            })
+        }
+        "glProgramUniform2f" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLfloat = args.value(2);
+            let v1: GLfloat = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform2f(program, location, v0, v1)
+            })
+        }
+        "glProgramUniform2fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform2fv(program, location, count, value)
+            })
+        }
+        "glProgramUniform2ui" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLuint = args.value(2);
+            let v1: GLuint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform2ui(program, location, v0, v1)
+            })
+        }
+        "glProgramUniform2uiv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform2uiv(program, location, count, value)
+            })
+        }
+        "glProgramUniform3i" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLint = args.value(2);
+            let v1: GLint = args.value(3);
+            let v2: GLint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform3i(program, location, v0, v1, v2)
+            })
+        }
+        "glProgramUniform3iv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform3iv(program, location, count, value)
+            })
+        }
+        "glProgramUniform3f" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLfloat = args.value(2);
+            let v1: GLfloat = args.value(3);
+            let v2: GLfloat = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform3f(program, location, v0, v1, v2)
+            })
+        }
+        "glProgramUniform3fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform3fv(program, location, count, value)
+            })
+        }
+        "glProgramUniform3ui" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLuint = args.value(2);
+            let v1: GLuint = args.value(3);
+            let v2: GLuint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform3ui(program, location, v0, v1, v2)
+            })
+        }
+        "glProgramUniform3uiv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform3uiv(program, location, count, value)
+            })
+        }
+        "glProgramUniform4i" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLint = args.value(2);
+            let v1: GLint = args.value(3);
+            let v2: GLint = args.value(4);
+            let v3: GLint = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform4i(program, location, v0, v1, v2, v3)
+            })
+        }
+        "glProgramUniform4iv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform4iv(program, location, count, value)
+            })
+        }
+        "glProgramUniform4f" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLfloat = args.value(2);
+            let v1: GLfloat = args.value(3);
+            let v2: GLfloat = args.value(4);
+            let v3: GLfloat = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform4f(program, location, v0, v1, v2, v3)
+            })
+        }
+        "glProgramUniform4fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform4fv(program, location, count, value)
+            })
+        }
+        "glProgramUniform4ui" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLuint = args.value(2);
+            let v1: GLuint = args.value(3);
+            let v2: GLuint = args.value(4);
+            let v3: GLuint = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform4ui(program, location, v0, v1, v2, v3)
+            })
+        }
+        "glProgramUniform4uiv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform4uiv(program, location, count, value)
+            })
+        }
+        "glProgramUniformMatrix2fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLfloat>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform_matrix2fv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix3fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLfloat>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform_matrix3fv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix4fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLfloat>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform_matrix4fv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix2x3fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLfloat>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state
+                    .oxidegl_program_uniform_matrix2x3fv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix3x2fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLfloat>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state
+                    .oxidegl_program_uniform_matrix3x2fv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix2x4fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLfloat>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state
+                    .oxidegl_program_uniform_matrix2x4fv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix4x2fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLfloat>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state
+                    .oxidegl_program_uniform_matrix4x2fv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix3x4fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLfloat>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state
+                    .oxidegl_program_uniform_matrix3x4fv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix4x3fv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLfloat>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state
+                    .oxidegl_program_uniform_matrix4x3fv(program, location, count, transpose, value)
+            })
+        }
+        "glProvokingVertex" => {
+            let mode: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_provoking_vertex(mode.try_into_enum()?))
+            })
+        }
+        "glPushDebugGroup" => {
+            let source: GLenum = args.value(0);
+            let id: GLuint = args.value(1);
+            let length: GLsizei = args.value(2);
+            let Some(message) = args.ptr::<GLchar>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_push_debug_group(source.try_into_enum()?, id, length, message)
+                })
+            })
+        }
+        "glQueryCounter" => {
+            let id: GLuint = args.value(0);
+            let target: GLenum = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_query_counter(id, target))
+        }
+        "glReadBuffer" => {
+            let src: GLenum = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_read_buffer(src.try_into_enum()?))
+            })
+        }
+        "glNamedFramebufferReadBuffer" => {
+            let framebuffer: GLuint = args.value(0);
+            let src: GLenum = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_named_framebuffer_read_buffer(framebuffer, src.try_into_enum()?),
+                )
+            })
+        }
+        "glReadPixels" => {
+            let x: GLint = args.value(0);
+            let y: GLint = args.value(1);
+            let width: GLsizei = args.value(2);
+            let height: GLsizei = args.value(3);
+            let format: GLenum = args.value(4);
+            let r#type: GLenum = args.value(5);
+            let pixels: *mut GLvoid = args.out_ptr(6);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_read_pixels(
+                        x,
+                        y,
+                        width,
+                        height,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glReadnPixels" => {
+            let x: GLint = args.value(0);
+            let y: GLint = args.value(1);
+            let width: GLsizei = args.value(2);
+            let height: GLsizei = args.value(3);
+            let format: GLenum = args.value(4);
+            let r#type: GLenum = args.value(5);
+            let bufSize: GLsizei = args.value(6);
+            let data: *mut GLvoid = args.out_ptr(7);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_readn_pixels(
+                        x,
+                        y,
+                        width,
+                        height,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        bufSize,
+                        data,
+                    )
+                })
+            })
+        }
+        "glReleaseShaderCompiler" => {
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_release_shader_compiler())
+        }
+        "glRenderbufferStorage" => {
+            let target: GLenum = args.value(0);
+            let internalformat: GLenum = args.value(1);
+            let width: GLsizei = args.value(2);
+            let height: GLsizei = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_renderbuffer_storage(
+                    target,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                ))
+            })
+        }
+        "glNamedRenderbufferStorage" => {
+            let renderbuffer: GLuint = args.value(0);
+            let internalformat: GLenum = args.value(1);
+            let width: GLsizei = args.value(2);
+            let height: GLsizei = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_named_renderbuffer_storage(
+                    renderbuffer,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                ))
+            })
+        }
+        "glRenderbufferStorageMultisample" => {
+            let target: GLenum = args.value(0);
+            let samples: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_renderbuffer_storage_multisample(
+                    target,
+                    samples,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                ))
+            })
+        }
+        "glNamedRenderbufferStorageMultisample" => {
+            let renderbuffer: GLuint = args.value(0);
+            let samples: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_named_renderbuffer_storage_multisample(
+                    renderbuffer,
+                    samples,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                ))
+            })
+        }
+        "glResumeTransformFeedback" => {
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_resume_transform_feedback())
+        }
+        "glSampleCoverage" => {
+            let value: GLfloat = args.value(0);
+            let invert: GLboolean = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_sample_coverage(value, invert)
+            })
+        }
+        "glSampleMaski" => {
+            let maskNumber: GLuint = args.value(0);
+            let mask: GLbitfield = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_sample_maski(maskNumber, mask)
+            })
+        }
+        "glSamplerParameteri" => {
+            let sampler: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let param: GLint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_sampler_parameteri(
+                    sampler,
+                    pname.try_into_enum()?,
+                    param,
+                ))
+            })
+        }
+        "glSamplerParameteriv" => {
+            let sampler: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let Some(param) = args.ptr::<GLint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_sampler_parameteriv(sampler, pname.try_into_enum()?, param)
+                })
+            })
+        }
+        "glSamplerParameterf" => {
+            let sampler: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let param: GLfloat = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_sampler_parameterf(
+                    sampler,
+                    pname.try_into_enum()?,
+                    param,
+                ))
+            })
+        }
+        "glSamplerParameterfv" => {
+            let sampler: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let Some(param) = args.ptr::<GLfloat>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_sampler_parameterfv(sampler, pname.try_into_enum()?, param)
+                })
+            })
+        }
+        "glSamplerParameterIiv" => {
+            let sampler: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let Some(param) = args.ptr::<GLint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_sampler_parameter_iiv(sampler, pname.try_into_enum()?, param)
+                })
+            })
+        }
+        "glSamplerParameterIuiv" => {
+            let sampler: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let Some(param) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_sampler_parameter_iuiv(sampler, pname.try_into_enum()?, param)
+                })
+            })
+        }
+        "glScissor" => {
+            let x: GLint = args.value(0);
+            let y: GLint = args.value(1);
+            let width: GLsizei = args.value(2);
+            let height: GLsizei = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_scissor(x, y, width, height))
+        }
+        "glScissorArrayv" => {
+            let first: GLuint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(v) = args.ptr::<GLint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_scissor_arrayv(first, count, v)
+            })
+        }
+        "glScissorIndexed" => {
+            let index: GLuint = args.value(0);
+            let left: GLint = args.value(1);
+            let bottom: GLint = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_scissor_indexed(index, left, bottom, width, height)
+            })
+        }
+        "glScissorIndexedv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_scissor_indexedv(index, v)
+            })
+        }
+        "glShaderBinary" => {
+            let count: GLsizei = args.value(0);
+            let Some(shaders) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            let binaryFormat: GLenum = args.value(2);
+            let Some(binary) = args.ptr::<GLvoid>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            let length: GLsizei = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_shader_binary(count, shaders, binaryFormat, binary, length)
+            })
+        }
+        "glShaderSource" => {
+            let shader: GLuint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(string) = args.ptr_array::<*const GLchar>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let Some(length) = args.ptr::<GLint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_shader_source(shader, count, string, length)
+            })
+        }
+        "glShaderStorageBlockBinding" => {
+            let program: GLuint = args.value(0);
+            let storageBlockIndex: GLuint = args.value(1);
+            let storageBlockBinding: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_shader_storage_block_binding(
+                    program,
+                    storageBlockIndex,
+                    storageBlockBinding,
+                )
+            })
+        }
+        "glStencilFunc" => {
+            let func: GLenum = args.value(0);
+            let r#ref: GLint = args.value(1);
+            let mask: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_stencil_func(func.try_into_enum()?, r#ref, mask))
+            })
+        }
+        "glStencilFuncSeparate" => {
+            let face: GLenum = args.value(0);
+            let func: GLenum = args.value(1);
+            let r#ref: GLint = args.value(2);
+            let mask: GLuint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_stencil_func_separate(
+                    face.try_into_enum()?,
+                    func.try_into_enum()?,
+                    r#ref,
+                    mask,
+                ))
+            })
+        }
+        "glStencilMask" => {
+            let mask: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_stencil_mask(mask))
+        }
+        "glStencilMaskSeparate" => {
+            let face: GLenum = args.value(0);
+            let mask: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(
+                    state.oxidegl_stencil_mask_separate(face.try_into_enum()?, mask),
+                )
+            })
+        }
+        "glStencilOp" => {
+            let fail: GLenum = args.value(0);
+            let zfail: GLenum = args.value(1);
+            let zpass: GLenum = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_stencil_op(
+                    fail.try_into_enum()?,
+                    zfail.try_into_enum()?,
+                    zpass.try_into_enum()?,
+                ))
+            })
+        }
+        "glStencilOpSeparate" => {
+            let face: GLenum = args.value(0);
+            let sfail: GLenum = args.value(1);
+            let dpfail: GLenum = args.value(2);
+            let dppass: GLenum = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_stencil_op_separate(
+                    face.try_into_enum()?,
+                    sfail.try_into_enum()?,
+                    dpfail.try_into_enum()?,
+                    dppass.try_into_enum()?,
+                ))
+            })
+        }
+        "glTexBuffer" => {
+            let target: GLenum = args.value(0);
+            let internalformat: GLenum = args.value(1);
+            let buffer: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_tex_buffer(
+                    target.try_into_enum()?,
+                    internalformat.try_into_enum()?,
+                    buffer,
+                ))
+            })
+        }
+        "glTextureBuffer" => {
+            let texture: GLuint = args.value(0);
+            let internalformat: GLenum = args.value(1);
+            let buffer: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_texture_buffer(
+                    texture,
+                    internalformat.try_into_enum()?,
+                    buffer,
+                ))
+            })
+        }
+        "glTexBufferRange" => {
+            let target: GLenum = args.value(0);
+            let internalformat: GLenum = args.value(1);
+            let buffer: GLuint = args.value(2);
+            let offset: GLintptr = args.value(3);
+            let size: GLsizeiptr = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_tex_buffer_range(
+                    target.try_into_enum()?,
+                    internalformat.try_into_enum()?,
+                    buffer,
+                    offset,
+                    size,
+                ))
+            })
+        }
+        "glTextureBufferRange" => {
+            let texture: GLuint = args.value(0);
+            let internalformat: GLenum = args.value(1);
+            let buffer: GLuint = args.value(2);
+            let offset: GLintptr = args.value(3);
+            let size: GLsizeiptr = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_texture_buffer_range(
+                    texture,
+                    internalformat.try_into_enum()?,
+                    buffer,
+                    offset,
+                    size,
+                ))
+            })
+        }
+        "glTexImage1D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let border: GLint = args.value(4);
+            let format: GLenum = args.value(5);
+            let r#type: GLenum = args.value(6);
+            let Some(pixels) = args.ptr::<GLvoid>(7) else {
+                return Outcome::MissingClientMemory(7);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_tex_image1_d(
+                        target.try_into_enum()?,
+                        level,
+                        internalformat.try_into_enum()?,
+                        width,
+                        border,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glTexImage2D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            let border: GLint = args.value(5);
+            let format: GLenum = args.value(6);
+            let r#type: GLenum = args.value(7);
+            let Some(pixels) = args.ptr::<GLvoid>(8) else {
+                return Outcome::MissingClientMemory(8);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_tex_image2_d(
+                        target.try_into_enum()?,
+                        level,
+                        internalformat.try_into_enum()?,
+                        width,
+                        height,
+                        border,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glTexImage2DMultisample" => {
+            let target: GLenum = args.value(0);
+            let samples: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            let fixedsamplelocations: GLboolean = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_tex_image2_d_multisample(
+                    target.try_into_enum()?,
+                    samples,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                    fixedsamplelocations,
+                ))
+            })
+        }
+        "glTexImage3D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            let depth: GLsizei = args.value(5);
+            let border: GLint = args.value(6);
+            let format: GLenum = args.value(7);
+            let r#type: GLenum = args.value(8);
+            let Some(pixels) = args.ptr::<GLvoid>(9) else {
+                return Outcome::MissingClientMemory(9);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_tex_image3_d(
+                        target.try_into_enum()?,
+                        level,
+                        internalformat.try_into_enum()?,
+                        width,
+                        height,
+                        depth,
+                        border,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glTexImage3DMultisample" => {
+            let target: GLenum = args.value(0);
+            let samples: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            let depth: GLsizei = args.value(5);
+            let fixedsamplelocations: GLboolean = args.value(6);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_tex_image3_d_multisample(
+                    target.try_into_enum()?,
+                    samples,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                    depth,
+                    fixedsamplelocations,
+                ))
+            })
+        }
+        "glTexParameterf" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let param: GLfloat = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_tex_parameterf(
+                    target.try_into_enum()?,
+                    pname.try_into_enum()?,
+                    param,
+                ))
+            })
+        }
+        "glTexParameterfv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let Some(params) = args.ptr::<GLfloat>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_tex_parameterfv(
+                        target.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glTexParameteri" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let param: GLint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_tex_parameteri(
+                    target.try_into_enum()?,
+                    pname.try_into_enum()?,
+                    param,
+                ))
+            })
+        }
+        "glTexParameteriv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let Some(params) = args.ptr::<GLint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_tex_parameteriv(
+                        target.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glTexParameterIiv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let Some(params) = args.ptr::<GLint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_tex_parameter_iiv(
+                        target.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glTexParameterIuiv" => {
+            let target: GLenum = args.value(0);
+            let pname: GLenum = args.value(1);
+            let Some(params) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_tex_parameter_iuiv(
+                        target.try_into_enum()?,
+                        pname.try_into_enum()?,
+                        params,
+                    )
+                })
+            })
+        }
+        "glTextureParameterf" => {
+            let texture: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let param: GLfloat = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_texture_parameterf(
+                    texture,
+                    pname.try_into_enum()?,
+                    param,
+                ))
+            })
+        }
+        "glTextureParameterfv" => {
+            let texture: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let Some(param) = args.ptr::<GLfloat>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_texture_parameterfv(texture, pname.try_into_enum()?, param)
+                })
+            })
+        }
+        "glTextureParameteri" => {
+            let texture: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let param: GLint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_texture_parameteri(
+                    texture,
+                    pname.try_into_enum()?,
+                    param,
+                ))
+            })
+        }
+        "glTextureParameterIiv" => {
+            let texture: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let Some(params) = args.ptr::<GLint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_texture_parameter_iiv(texture, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glTextureParameterIuiv" => {
+            let texture: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let Some(params) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_texture_parameter_iuiv(texture, pname.try_into_enum()?, params)
+                })
+            })
+        }
+        "glTextureParameteriv" => {
+            let texture: GLuint = args.value(0);
+            let pname: GLenum = args.value(1);
+            let Some(param) = args.ptr::<GLint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_texture_parameteriv(texture, pname.try_into_enum()?, param)
+                })
+            })
+        }
+        "glTexStorage1D" => {
+            let target: GLenum = args.value(0);
+            let levels: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_tex_storage1_d(
+                    target.try_into_enum()?,
+                    levels,
+                    internalformat.try_into_enum()?,
+                    width,
+                ))
+            })
+        }
+        "glTextureStorage1D" => {
+            let texture: GLuint = args.value(0);
+            let levels: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_texture_storage1_d(
+                    texture,
+                    levels,
+                    internalformat.try_into_enum()?,
+                    width,
+                ))
+            })
+        }
+        "glTexStorage2D" => {
+            let target: GLenum = args.value(0);
+            let levels: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_tex_storage2_d(
+                    target.try_into_enum()?,
+                    levels,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                ))
+            })
+        }
+        "glTextureStorage2D" => {
+            let texture: GLuint = args.value(0);
+            let levels: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_texture_storage2_d(
+                    texture,
+                    levels,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                ))
+            })
+        }
+        "glTexStorage2DMultisample" => {
+            let target: GLenum = args.value(0);
+            let samples: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            let fixedsamplelocations: GLboolean = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_tex_storage2_d_multisample(
+                    target.try_into_enum()?,
+                    samples,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                    fixedsamplelocations,
+                ))
+            })
+        }
+        "glTextureStorage2DMultisample" => {
+            let texture: GLuint = args.value(0);
+            let samples: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            let fixedsamplelocations: GLboolean = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_texture_storage2_d_multisample(
+                    texture,
+                    samples,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                    fixedsamplelocations,
+                ))
+            })
+        }
+        "glTexStorage3D" => {
+            let target: GLenum = args.value(0);
+            let levels: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            let depth: GLsizei = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_tex_storage3_d(
+                    target.try_into_enum()?,
+                    levels,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                    depth,
+                ))
+            })
+        }
+        "glTextureStorage3D" => {
+            let texture: GLuint = args.value(0);
+            let levels: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            let depth: GLsizei = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_texture_storage3_d(
+                    texture,
+                    levels,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                    depth,
+                ))
+            })
+        }
+        "glTexStorage3DMultisample" => {
+            let target: GLenum = args.value(0);
+            let samples: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            let depth: GLsizei = args.value(5);
+            let fixedsamplelocations: GLboolean = args.value(6);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_tex_storage3_d_multisample(
+                    target.try_into_enum()?,
+                    samples,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                    depth,
+                    fixedsamplelocations,
+                ))
+            })
+        }
+        "glTextureStorage3DMultisample" => {
+            let texture: GLuint = args.value(0);
+            let samples: GLsizei = args.value(1);
+            let internalformat: GLenum = args.value(2);
+            let width: GLsizei = args.value(3);
+            let height: GLsizei = args.value(4);
+            let depth: GLsizei = args.value(5);
+            let fixedsamplelocations: GLboolean = args.value(6);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_texture_storage3_d_multisample(
+                    texture,
+                    samples,
+                    internalformat.try_into_enum()?,
+                    width,
+                    height,
+                    depth,
+                    fixedsamplelocations,
+                ))
+            })
+        }
+        "glTexSubImage1D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let width: GLsizei = args.value(3);
+            let format: GLenum = args.value(4);
+            let r#type: GLenum = args.value(5);
+            let Some(pixels) = args.ptr::<GLvoid>(6) else {
+                return Outcome::MissingClientMemory(6);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_tex_sub_image1_d(
+                        target.try_into_enum()?,
+                        level,
+                        xoffset,
+                        width,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glTextureSubImage1D" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let width: GLsizei = args.value(3);
+            let format: GLenum = args.value(4);
+            let r#type: GLenum = args.value(5);
+            let Some(pixels) = args.ptr::<GLvoid>(6) else {
+                return Outcome::MissingClientMemory(6);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_texture_sub_image1_d(
+                        texture,
+                        level,
+                        xoffset,
+                        width,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glTexSubImage2D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let width: GLsizei = args.value(4);
+            let height: GLsizei = args.value(5);
+            let format: GLenum = args.value(6);
+            let r#type: GLenum = args.value(7);
+            let Some(pixels) = args.ptr::<GLvoid>(8) else {
+                return Outcome::MissingClientMemory(8);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_tex_sub_image2_d(
+                        target.try_into_enum()?,
+                        level,
+                        xoffset,
+                        yoffset,
+                        width,
+                        height,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glTextureSubImage2D" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let width: GLsizei = args.value(4);
+            let height: GLsizei = args.value(5);
+            let format: GLenum = args.value(6);
+            let r#type: GLenum = args.value(7);
+            let Some(pixels) = args.ptr::<GLvoid>(8) else {
+                return Outcome::MissingClientMemory(8);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_texture_sub_image2_d(
+                        texture,
+                        level,
+                        xoffset,
+                        yoffset,
+                        width,
+                        height,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glTexSubImage3D" => {
+            let target: GLenum = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let zoffset: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            let height: GLsizei = args.value(6);
+            let depth: GLsizei = args.value(7);
+            let format: GLenum = args.value(8);
+            let r#type: GLenum = args.value(9);
+            let Some(pixels) = args.ptr::<GLvoid>(10) else {
+                return Outcome::MissingClientMemory(10);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_tex_sub_image3_d(
+                        target.try_into_enum()?,
+                        level,
+                        xoffset,
+                        yoffset,
+                        zoffset,
+                        width,
+                        height,
+                        depth,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glTextureSubImage3D" => {
+            let texture: GLuint = args.value(0);
+            let level: GLint = args.value(1);
+            let xoffset: GLint = args.value(2);
+            let yoffset: GLint = args.value(3);
+            let zoffset: GLint = args.value(4);
+            let width: GLsizei = args.value(5);
+            let height: GLsizei = args.value(6);
+            let depth: GLsizei = args.value(7);
+            let format: GLenum = args.value(8);
+            let r#type: GLenum = args.value(9);
+            let Some(pixels) = args.ptr::<GLvoid>(10) else {
+                return Outcome::MissingClientMemory(10);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_texture_sub_image3_d(
+                        texture,
+                        level,
+                        xoffset,
+                        yoffset,
+                        zoffset,
+                        width,
+                        height,
+                        depth,
+                        format.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        pixels,
+                    )
+                })
+            })
+        }
+        "glTextureBarrier" => run::<(), _, _, _>(ctx, |mut state| state.oxidegl_texture_barrier()),
+        "glTextureView" => {
+            let texture: GLuint = args.value(0);
+            let target: GLenum = args.value(1);
+            let origtexture: GLuint = args.value(2);
+            let internalformat: GLenum = args.value(3);
+            let minlevel: GLuint = args.value(4);
+            let numlevels: GLuint = args.value(5);
+            let minlayer: GLuint = args.value(6);
+            let numlayers: GLuint = args.value(7);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_texture_view(
+                    texture,
+                    target.try_into_enum()?,
+                    origtexture,
+                    internalformat.try_into_enum()?,
+                    minlevel,
+                    numlevels,
+                    minlayer,
+                    numlayers,
+                ))
+            })
+        }
+        "glTransformFeedbackBufferBase" => {
+            let xfb: GLuint = args.value(0);
+            let index: GLuint = args.value(1);
+            let buffer: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_transform_feedback_buffer_base(xfb, index, buffer)
+            })
+        }
+        "glTransformFeedbackBufferRange" => {
+            let xfb: GLuint = args.value(0);
+            let index: GLuint = args.value(1);
+            let buffer: GLuint = args.value(2);
+            let offset: GLintptr = args.value(3);
+            let size: GLsizeiptr = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_transform_feedback_buffer_range(xfb, index, buffer, offset, size)
+            })
+        }
+        "glTransformFeedbackVaryings" => {
+            let program: GLuint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(varyings) = args.ptr_array::<*const GLchar>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            let bufferMode: GLenum = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_transform_feedback_varyings(
+                        program,
+                        count,
+                        varyings,
+                        bufferMode.try_into_enum()?,
+                    )
+                })
+            })
+        }
+        "glUniform1f" => {
+            let location: GLint = args.value(0);
+            let v0: GLfloat = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_uniform1f(location, v0))
+        }
+        "glUniform2f" => {
+            let location: GLint = args.value(0);
+            let v0: GLfloat = args.value(1);
+            let v1: GLfloat = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_uniform2f(location, v0, v1))
+        }
+        "glUniform3f" => {
+            let location: GLint = args.value(0);
+            let v0: GLfloat = args.value(1);
+            let v1: GLfloat = args.value(2);
+            let v2: GLfloat = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_uniform3f(location, v0, v1, v2)
+            })
+        }
+        "glUniform4f" => {
+            let location: GLint = args.value(0);
+            let v0: GLfloat = args.value(1);
+            let v1: GLfloat = args.value(2);
+            let v2: GLfloat = args.value(3);
+            let v3: GLfloat = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_uniform4f(location, v0, v1, v2, v3)
+            })
+        }
+        "glUniform1i" => {
+            let location: GLint = args.value(0);
+            let v0: GLint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_uniform1i(location, v0))
+        }
+        "glUniform2i" => {
+            let location: GLint = args.value(0);
+            let v0: GLint = args.value(1);
+            let v1: GLint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_uniform2i(location, v0, v1))
+        }
+        "glUniform3i" => {
+            let location: GLint = args.value(0);
+            let v0: GLint = args.value(1);
+            let v1: GLint = args.value(2);
+            let v2: GLint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_uniform3i(location, v0, v1, v2)
+            })
+        }
+        "glUniform4i" => {
+            let location: GLint = args.value(0);
+            let v0: GLint = args.value(1);
+            let v1: GLint = args.value(2);
+            let v2: GLint = args.value(3);
+            let v3: GLint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_uniform4i(location, v0, v1, v2, v3)
+            })
+        }
+        "glUniform1fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLfloat>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform1fv(location, count, value)
+            })
+        }
+        "glUniform2fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLfloat>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform2fv(location, count, value)
+            })
+        }
+        "glUniform3fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLfloat>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform3fv(location, count, value)
+            })
+        }
+        "glUniform4fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLfloat>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform4fv(location, count, value)
+            })
+        }
+        "glUniform1iv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform1iv(location, count, value)
+            })
+        }
+        "glUniform2iv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform2iv(location, count, value)
+            })
+        }
+        "glUniform3iv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform3iv(location, count, value)
+            })
+        }
+        "glUniform4iv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform4iv(location, count, value)
+            })
+        }
+        "glUniformMatrix2fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix2fv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix3fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix3fv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix4fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix4fv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix2x3fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix2x3fv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix3x2fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix3x2fv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix2x4fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix2x4fv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix4x2fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix4x2fv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix3x4fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix3x4fv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix4x3fv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLfloat>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix4x3fv(location, count, transpose, value)
+            })
+        }
+        "glUniform1ui" => {
+            let location: GLint = args.value(0);
+            let v0: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_uniform1ui(location, v0))
+        }
+        "glUniform2ui" => {
+            let location: GLint = args.value(0);
+            let v0: GLuint = args.value(1);
+            let v1: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_uniform2ui(location, v0, v1))
+        }
+        "glUniform3ui" => {
+            let location: GLint = args.value(0);
+            let v0: GLuint = args.value(1);
+            let v1: GLuint = args.value(2);
+            let v2: GLuint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_uniform3ui(location, v0, v1, v2)
+            })
+        }
+        "glUniform4ui" => {
+            let location: GLint = args.value(0);
+            let v0: GLuint = args.value(1);
+            let v1: GLuint = args.value(2);
+            let v2: GLuint = args.value(3);
+            let v3: GLuint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_uniform4ui(location, v0, v1, v2, v3)
+            })
+        }
+        "glUniform1uiv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform1uiv(location, count, value)
+            })
+        }
+        "glUniform2uiv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform2uiv(location, count, value)
+            })
+        }
+        "glUniform3uiv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform3uiv(location, count, value)
+            })
+        }
+        "glUniform4uiv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform4uiv(location, count, value)
+            })
+        }
+        "glUniformBlockBinding" => {
+            let program: GLuint = args.value(0);
+            let uniformBlockIndex: GLuint = args.value(1);
+            let uniformBlockBinding: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_uniform_block_binding(program, uniformBlockIndex, uniformBlockBinding)
+            })
+        }
+        "glUniformSubroutinesuiv" => {
+            let shadertype: GLenum = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(indices) = args.ptr::<GLuint>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_uniform_subroutinesuiv(
+                        shadertype.try_into_enum()?,
+                        count,
+                        indices,
+                    )
+                })
+            })
+        }
+        "glUnmapBuffer" => {
+            let target: GLenum = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_unmap_buffer(target.try_into_enum()?))
+            })
+        }
+        "glUnmapNamedBuffer" => {
+            let buffer: GLuint = args.value(0);
+            run::<GLboolean, _, _, _>(ctx, |mut state| state.oxidegl_unmap_named_buffer(buffer))
+        }
+        "glUseProgram" => {
+            let program: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_use_program(program))
+        }
+        "glUseProgramStages" => {
+            let pipeline: GLuint = args.value(0);
+            let stages: GLenum = args.value(1);
+            let program: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_use_program_stages(
+                    pipeline,
+                    stages.try_into_enum()?,
+                    program,
+                ))
+            })
+        }
+        "glValidateProgram" => {
+            let program: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_validate_program(program))
+        }
+        "glValidateProgramPipeline" => {
+            let pipeline: GLuint = args.value(0);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_validate_program_pipeline(pipeline)
+            })
+        }
+        "glVertexArrayElementBuffer" => {
+            let vaobj: GLuint = args.value(0);
+            let buffer: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_array_element_buffer(vaobj, buffer)
+            })
+        }
+        "glVertexAttrib1d" => {
+            let index: GLuint = args.value(0);
+            let x: GLdouble = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_vertex_attrib1d(index, x))
+        }
+        "glVertexAttrib1dv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLdouble>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib1dv(index, v)
+            })
+        }
+        "glVertexAttrib1f" => {
+            let index: GLuint = args.value(0);
+            let x: GLfloat = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_vertex_attrib1f(index, x))
+        }
+        "glVertexAttrib1fv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLfloat>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib1fv(index, v)
+            })
+        }
+        "glVertexAttrib1s" => {
+            let index: GLuint = args.value(0);
+            let x: GLshort = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_vertex_attrib1s(index, x))
+        }
+        "glVertexAttrib1sv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLshort>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib1sv(index, v)
+            })
+        }
+        "glVertexAttrib2d" => {
+            let index: GLuint = args.value(0);
+            let x: GLdouble = args.value(1);
+            let y: GLdouble = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_vertex_attrib2d(index, x, y))
+        }
+        "glVertexAttrib2dv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLdouble>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib2dv(index, v)
+            })
+        }
+        "glVertexAttrib2f" => {
+            let index: GLuint = args.value(0);
+            let x: GLfloat = args.value(1);
+            let y: GLfloat = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_vertex_attrib2f(index, x, y))
+        }
+        "glVertexAttrib2fv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLfloat>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib2fv(index, v)
+            })
+        }
+        "glVertexAttrib2s" => {
+            let index: GLuint = args.value(0);
+            let x: GLshort = args.value(1);
+            let y: GLshort = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_vertex_attrib2s(index, x, y))
+        }
+        "glVertexAttrib2sv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLshort>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib2sv(index, v)
+            })
+        }
+        "glVertexAttrib3d" => {
+            let index: GLuint = args.value(0);
+            let x: GLdouble = args.value(1);
+            let y: GLdouble = args.value(2);
+            let z: GLdouble = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib3d(index, x, y, z)
+            })
+        }
+        "glVertexAttrib3dv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLdouble>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib3dv(index, v)
+            })
+        }
+        "glVertexAttrib3f" => {
+            let index: GLuint = args.value(0);
+            let x: GLfloat = args.value(1);
+            let y: GLfloat = args.value(2);
+            let z: GLfloat = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib3f(index, x, y, z)
+            })
+        }
+        "glVertexAttrib3fv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLfloat>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib3fv(index, v)
+            })
+        }
+        "glVertexAttrib3s" => {
+            let index: GLuint = args.value(0);
+            let x: GLshort = args.value(1);
+            let y: GLshort = args.value(2);
+            let z: GLshort = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib3s(index, x, y, z)
+            })
+        }
+        "glVertexAttrib3sv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLshort>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib3sv(index, v)
+            })
+        }
+        "glVertexAttrib4Nbv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLbyte>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4_nbv(index, v)
+            })
+        }
+        "glVertexAttrib4Niv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4_niv(index, v)
+            })
+        }
+        "glVertexAttrib4Nsv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLshort>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4_nsv(index, v)
+            })
+        }
+        "glVertexAttrib4Nub" => {
+            let index: GLuint = args.value(0);
+            let x: GLubyte = args.value(1);
+            let y: GLubyte = args.value(2);
+            let z: GLubyte = args.value(3);
+            let w: GLubyte = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib4_nub(index, x, y, z, w)
+            })
+        }
+        "glVertexAttrib4Nubv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLubyte>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4_nubv(index, v)
+            })
+        }
+        "glVertexAttrib4Nuiv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4_nuiv(index, v)
+            })
+        }
+        "glVertexAttrib4Nusv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLushort>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4_nusv(index, v)
+            })
+        }
+        "glVertexAttrib4bv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLbyte>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4bv(index, v)
+            })
+        }
+        "glVertexAttrib4d" => {
+            let index: GLuint = args.value(0);
+            let x: GLdouble = args.value(1);
+            let y: GLdouble = args.value(2);
+            let z: GLdouble = args.value(3);
+            let w: GLdouble = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib4d(index, x, y, z, w)
+            })
+        }
+        "glVertexAttrib4dv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLdouble>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4dv(index, v)
+            })
+        }
+        "glVertexAttrib4f" => {
+            let index: GLuint = args.value(0);
+            let x: GLfloat = args.value(1);
+            let y: GLfloat = args.value(2);
+            let z: GLfloat = args.value(3);
+            let w: GLfloat = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib4f(index, x, y, z, w)
+            })
+        }
+        "glVertexAttrib4fv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLfloat>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4fv(index, v)
+            })
+        }
+        "glVertexAttrib4iv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4iv(index, v)
+            })
+        }
+        "glVertexAttrib4s" => {
+            let index: GLuint = args.value(0);
+            let x: GLshort = args.value(1);
+            let y: GLshort = args.value(2);
+            let z: GLshort = args.value(3);
+            let w: GLshort = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib4s(index, x, y, z, w)
+            })
+        }
+        "glVertexAttrib4sv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLshort>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4sv(index, v)
+            })
+        }
+        "glVertexAttrib4ubv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLubyte>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4ubv(index, v)
+            })
+        }
+        "glVertexAttrib4uiv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4uiv(index, v)
+            })
+        }
+        "glVertexAttrib4usv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLushort>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib4usv(index, v)
+            })
+        }
+        "glVertexAttribI1i" => {
+            let index: GLuint = args.value(0);
+            let x: GLint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_vertex_attrib_i1i(index, x))
+        }
+        "glVertexAttribI2i" => {
+            let index: GLuint = args.value(0);
+            let x: GLint = args.value(1);
+            let y: GLint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib_i2i(index, x, y)
+            })
+        }
+        "glVertexAttribI3i" => {
+            let index: GLuint = args.value(0);
+            let x: GLint = args.value(1);
+            let y: GLint = args.value(2);
+            let z: GLint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib_i3i(index, x, y, z)
+            })
+        }
+        "glVertexAttribI4i" => {
+            let index: GLuint = args.value(0);
+            let x: GLint = args.value(1);
+            let y: GLint = args.value(2);
+            let z: GLint = args.value(3);
+            let w: GLint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib_i4i(index, x, y, z, w)
+            })
+        }
+        "glVertexAttribI1ui" => {
+            let index: GLuint = args.value(0);
+            let x: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_vertex_attrib_i1ui(index, x))
+        }
+        "glVertexAttribI2ui" => {
+            let index: GLuint = args.value(0);
+            let x: GLuint = args.value(1);
+            let y: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib_i2ui(index, x, y)
+            })
+        }
+        "glVertexAttribI3ui" => {
+            let index: GLuint = args.value(0);
+            let x: GLuint = args.value(1);
+            let y: GLuint = args.value(2);
+            let z: GLuint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib_i3ui(index, x, y, z)
+            })
+        }
+        "glVertexAttribI4ui" => {
+            let index: GLuint = args.value(0);
+            let x: GLuint = args.value(1);
+            let y: GLuint = args.value(2);
+            let z: GLuint = args.value(3);
+            let w: GLuint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib_i4ui(index, x, y, z, w)
+            })
+        }
+        "glVertexAttribI1iv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_i1iv(index, v)
+            })
+        }
+        "glVertexAttribI2iv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_i2iv(index, v)
+            })
+        }
+        "glVertexAttribI3iv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_i3iv(index, v)
+            })
+        }
+        "glVertexAttribI4iv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_i4iv(index, v)
+            })
+        }
+        "glVertexAttribI1uiv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_i1uiv(index, v)
+            })
+        }
+        "glVertexAttribI2uiv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_i2uiv(index, v)
+            })
+        }
+        "glVertexAttribI3uiv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_i3uiv(index, v)
+            })
+        }
+        "glVertexAttribI4uiv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLuint>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_i4uiv(index, v)
+            })
+        }
+        "glVertexAttribI4bv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLbyte>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_i4bv(index, v)
+            })
+        }
+        "glVertexAttribI4sv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLshort>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_i4sv(index, v)
+            })
+        }
+        "glVertexAttribI4ubv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLubyte>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_i4ubv(index, v)
+            })
+        }
+        "glVertexAttribI4usv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLushort>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_i4usv(index, v)
+            })
+        }
+        "glVertexAttribP1ui" => {
+            let index: GLuint = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let normalized: GLboolean = args.value(2);
+            let value: GLuint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_vertex_attrib_p1ui(
+                    index,
+                    r#type.try_into_enum()?,
+                    normalized,
+                    value,
+                ))
+            })
+        }
+        "glVertexAttribP2ui" => {
+            let index: GLuint = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let normalized: GLboolean = args.value(2);
+            let value: GLuint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_vertex_attrib_p2ui(
+                    index,
+                    r#type.try_into_enum()?,
+                    normalized,
+                    value,
+                ))
+            })
+        }
+        "glVertexAttribP3ui" => {
+            let index: GLuint = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let normalized: GLboolean = args.value(2);
+            let value: GLuint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_vertex_attrib_p3ui(
+                    index,
+                    r#type.try_into_enum()?,
+                    normalized,
+                    value,
+                ))
+            })
+        }
+        "glVertexAttribP4ui" => {
+            let index: GLuint = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let normalized: GLboolean = args.value(2);
+            let value: GLuint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_vertex_attrib_p4ui(
+                    index,
+                    r#type.try_into_enum()?,
+                    normalized,
+                    value,
+                ))
+            })
+        }
+        "glVertexAttribL1d" => {
+            let index: GLuint = args.value(0);
+            let x: GLdouble = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_vertex_attrib_l1d(index, x))
+        }
+        "glVertexAttribL2d" => {
+            let index: GLuint = args.value(0);
+            let x: GLdouble = args.value(1);
+            let y: GLdouble = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib_l2d(index, x, y)
+            })
+        }
+        "glVertexAttribL3d" => {
+            let index: GLuint = args.value(0);
+            let x: GLdouble = args.value(1);
+            let y: GLdouble = args.value(2);
+            let z: GLdouble = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib_l3d(index, x, y, z)
+            })
+        }
+        "glVertexAttribL4d" => {
+            let index: GLuint = args.value(0);
+            let x: GLdouble = args.value(1);
+            let y: GLdouble = args.value(2);
+            let z: GLdouble = args.value(3);
+            let w: GLdouble = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib_l4d(index, x, y, z, w)
+            })
+        }
+        "glVertexAttribL1dv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLdouble>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_l1dv(index, v)
+            })
+        }
+        "glVertexAttribL2dv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLdouble>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_l2dv(index, v)
+            })
+        }
+        "glVertexAttribL3dv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLdouble>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_l3dv(index, v)
+            })
+        }
+        "glVertexAttribL4dv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLdouble>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_l4dv(index, v)
+            })
+        }
+        "glVertexAttribBinding" => {
+            let attribindex: GLuint = args.value(0);
+            let bindingindex: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib_binding(attribindex, bindingindex)
+            })
+        }
+        "glVertexArrayAttribBinding" => {
+            let vaobj: GLuint = args.value(0);
+            let attribindex: GLuint = args.value(1);
+            let bindingindex: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_array_attrib_binding(vaobj, attribindex, bindingindex)
+            })
+        }
+        "glVertexAttribDivisor" => {
+            let index: GLuint = args.value(0);
+            let divisor: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib_divisor(index, divisor)
+            })
+        }
+        "glVertexAttribFormat" => {
+            let attribindex: GLuint = args.value(0);
+            let size: GLint = args.value(1);
+            let r#type: GLenum = args.value(2);
+            let normalized: GLboolean = args.value(3);
+            let relativeoffset: GLuint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_vertex_attrib_format(
+                    attribindex,
+                    size,
+                    r#type.try_into_enum()?,
+                    normalized,
+                    relativeoffset,
+                ))
+            })
+        }
+        "glVertexAttribIFormat" => {
+            let attribindex: GLuint = args.value(0);
+            let size: GLint = args.value(1);
+            let r#type: GLenum = args.value(2);
+            let relativeoffset: GLuint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_vertex_attrib_i_format(
+                    attribindex,
+                    size,
+                    r#type.try_into_enum()?,
+                    relativeoffset,
+                ))
+            })
+        }
+        "glVertexAttribLFormat" => {
+            let attribindex: GLuint = args.value(0);
+            let size: GLint = args.value(1);
+            let r#type: GLenum = args.value(2);
+            let relativeoffset: GLuint = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_attrib_l_format(attribindex, size, r#type, relativeoffset)
+            })
+        }
+        "glVertexArrayAttribFormat" => {
+            let vaobj: GLuint = args.value(0);
+            let attribindex: GLuint = args.value(1);
+            let size: GLint = args.value(2);
+            let r#type: GLenum = args.value(3);
+            let normalized: GLboolean = args.value(4);
+            let relativeoffset: GLuint = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_vertex_array_attrib_format(
+                    vaobj,
+                    attribindex,
+                    size,
+                    r#type.try_into_enum()?,
+                    normalized,
+                    relativeoffset,
+                ))
+            })
+        }
+        "glVertexArrayAttribIFormat" => {
+            let vaobj: GLuint = args.value(0);
+            let attribindex: GLuint = args.value(1);
+            let size: GLint = args.value(2);
+            let r#type: GLenum = args.value(3);
+            let relativeoffset: GLuint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(state.oxidegl_vertex_array_attrib_i_format(
+                    vaobj,
+                    attribindex,
+                    size,
+                    r#type.try_into_enum()?,
+                    relativeoffset,
+                ))
+            })
+        }
+        "glVertexArrayAttribLFormat" => {
+            let vaobj: GLuint = args.value(0);
+            let attribindex: GLuint = args.value(1);
+            let size: GLint = args.value(2);
+            let r#type: GLenum = args.value(3);
+            let relativeoffset: GLuint = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_array_attrib_l_format(
+                    vaobj,
+                    attribindex,
+                    size,
+                    r#type,
+                    relativeoffset,
+                )
+            })
+        }
+        "glVertexAttribPointer" => {
+            let index: GLuint = args.value(0);
+            let size: GLint = args.value(1);
+            let r#type: GLenum = args.value(2);
+            let normalized: GLboolean = args.value(3);
+            let stride: GLsizei = args.value(4);
+            let pointer: *const GLvoid = args.offset_ptr(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_vertex_attrib_pointer(
+                        index,
+                        size,
+                        r#type.try_into_enum()?,
+                        normalized,
+                        stride,
+                        pointer,
+                    )
+                })
+            })
+        }
+        "glVertexAttribIPointer" => {
+            let index: GLuint = args.value(0);
+            let size: GLint = args.value(1);
+            let r#type: GLenum = args.value(2);
+            let stride: GLsizei = args.value(3);
+            let pointer: *const GLvoid = args.offset_ptr(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_vertex_attrib_i_pointer(
+                        index,
+                        size,
+                        r#type.try_into_enum()?,
+                        stride,
+                        pointer,
+                    )
+                })
+            })
+        }
+        "glVertexAttribLPointer" => {
+            let index: GLuint = args.value(0);
+            let size: GLint = args.value(1);
+            let r#type: GLenum = args.value(2);
+            let stride: GLsizei = args.value(3);
+            let pointer: *const GLvoid = args.offset_ptr(4);
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_vertex_attrib_l_pointer(index, size, r#type, stride, pointer)
+            })
+        }
+        "glVertexBindingDivisor" => {
+            let bindingindex: GLuint = args.value(0);
+            let divisor: GLuint = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_binding_divisor(bindingindex, divisor)
+            })
+        }
+        "glVertexArrayBindingDivisor" => {
+            let vaobj: GLuint = args.value(0);
+            let bindingindex: GLuint = args.value(1);
+            let divisor: GLuint = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_vertex_array_binding_divisor(vaobj, bindingindex, divisor)
+            })
+        }
+        "glViewport" => {
+            let x: GLint = args.value(0);
+            let y: GLint = args.value(1);
+            let width: GLsizei = args.value(2);
+            let height: GLsizei = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_viewport(x, y, width, height))
+        }
+        "glViewportArrayv" => {
+            let first: GLuint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(v) = args.ptr::<GLfloat>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_viewport_arrayv(first, count, v)
+            })
+        }
+        "glViewportIndexedf" => {
+            let index: GLuint = args.value(0);
+            let x: GLfloat = args.value(1);
+            let y: GLfloat = args.value(2);
+            let w: GLfloat = args.value(3);
+            let h: GLfloat = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_viewport_indexedf(index, x, y, w, h)
+            })
+        }
+        "glViewportIndexedfv" => {
+            let index: GLuint = args.value(0);
+            let Some(v) = args.ptr::<GLfloat>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_viewport_indexedfv(index, v)
+            })
+        }
+        "glWaitSync" => {
+            let sync: GLsync = args.value(0);
+            let flags: GLbitfield = args.value(1);
+            let timeout: GLuint64 = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_wait_sync(sync, flags, timeout)
+            })
+        }
+        "glVertexAttribP1uiv" => {
+            let index: GLuint = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let normalized: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_vertex_attrib_p1uiv(
+                        index,
+                        r#type.try_into_enum()?,
+                        normalized,
+                        value,
+                    )
+                })
+            })
+        }
+        "glVertexAttribP2uiv" => {
+            let index: GLuint = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let normalized: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_vertex_attrib_p2uiv(
+                        index,
+                        r#type.try_into_enum()?,
+                        normalized,
+                        value,
+                    )
+                })
+            })
+        }
+        "glVertexAttribP3uiv" => {
+            let index: GLuint = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let normalized: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_vertex_attrib_p3uiv(
+                        index,
+                        r#type.try_into_enum()?,
+                        normalized,
+                        value,
+                    )
+                })
+            })
+        }
+        "glVertexAttribP4uiv" => {
+            let index: GLuint = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let normalized: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_vertex_attrib_p4uiv(
+                        index,
+                        r#type.try_into_enum()?,
+                        normalized,
+                        value,
+                    )
+                })
+            })
+        }
+        "glUniform1d" => {
+            let location: GLint = args.value(0);
+            let x: GLdouble = args.value(1);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_uniform1d(location, x))
+        }
+        "glUniform2d" => {
+            let location: GLint = args.value(0);
+            let x: GLdouble = args.value(1);
+            let y: GLdouble = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_uniform2d(location, x, y))
+        }
+        "glUniform3d" => {
+            let location: GLint = args.value(0);
+            let x: GLdouble = args.value(1);
+            let y: GLdouble = args.value(2);
+            let z: GLdouble = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| state.oxidegl_uniform3d(location, x, y, z))
+        }
+        "glUniform4d" => {
+            let location: GLint = args.value(0);
+            let x: GLdouble = args.value(1);
+            let y: GLdouble = args.value(2);
+            let z: GLdouble = args.value(3);
+            let w: GLdouble = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_uniform4d(location, x, y, z, w)
+            })
+        }
+        "glUniform1dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLdouble>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform1dv(location, count, value)
+            })
+        }
+        "glUniform2dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLdouble>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform2dv(location, count, value)
+            })
+        }
+        "glUniform3dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLdouble>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform3dv(location, count, value)
+            })
+        }
+        "glUniform4dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let Some(value) = args.ptr::<GLdouble>(2) else {
+                return Outcome::MissingClientMemory(2);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform4dv(location, count, value)
+            })
+        }
+        "glUniformMatrix2dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix2dv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix3dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix3dv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix4dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix4dv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix2x3dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix2x3dv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix2x4dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix2x4dv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix3x2dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix3x2dv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix3x4dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix3x4dv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix4x2dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix4x2dv(location, count, transpose, value)
+            })
+        }
+        "glUniformMatrix4x3dv" => {
+            let location: GLint = args.value(0);
+            let count: GLsizei = args.value(1);
+            let transpose: GLboolean = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_uniform_matrix4x3dv(location, count, transpose, value)
+            })
+        }
+        "glProgramUniform1d" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLdouble = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform1d(program, location, v0)
+            })
+        }
+        "glProgramUniform1dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform1dv(program, location, count, value)
+            })
+        }
+        "glProgramUniform2d" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLdouble = args.value(2);
+            let v1: GLdouble = args.value(3);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform2d(program, location, v0, v1)
+            })
+        }
+        "glProgramUniform2dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform2dv(program, location, count, value)
+            })
+        }
+        "glProgramUniform3d" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLdouble = args.value(2);
+            let v1: GLdouble = args.value(3);
+            let v2: GLdouble = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform3d(program, location, v0, v1, v2)
+            })
+        }
+        "glProgramUniform3dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform3dv(program, location, count, value)
+            })
+        }
+        "glProgramUniform4d" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let v0: GLdouble = args.value(2);
+            let v1: GLdouble = args.value(3);
+            let v2: GLdouble = args.value(4);
+            let v3: GLdouble = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_program_uniform4d(program, location, v0, v1, v2, v3)
+            })
+        }
+        "glProgramUniform4dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let Some(value) = args.ptr::<GLdouble>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform4dv(program, location, count, value)
+            })
+        }
+        "glProgramUniformMatrix2dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLdouble>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform_matrix2dv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix3dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLdouble>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform_matrix3dv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix4dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLdouble>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_program_uniform_matrix4dv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix2x3dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLdouble>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state
+                    .oxidegl_program_uniform_matrix2x3dv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix3x2dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLdouble>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state
+                    .oxidegl_program_uniform_matrix3x2dv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix2x4dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLdouble>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state
+                    .oxidegl_program_uniform_matrix2x4dv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix4x2dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLdouble>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state
+                    .oxidegl_program_uniform_matrix4x2dv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix3x4dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLdouble>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state
+                    .oxidegl_program_uniform_matrix3x4dv(program, location, count, transpose, value)
+            })
+        }
+        "glProgramUniformMatrix4x3dv" => {
+            let program: GLuint = args.value(0);
+            let location: GLint = args.value(1);
+            let count: GLsizei = args.value(2);
+            let transpose: GLboolean = args.value(3);
+            let Some(value) = args.ptr::<GLdouble>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state
+                    .oxidegl_program_uniform_matrix4x3dv(program, location, count, transpose, value)
+            })
+        }
+        "glSpecializeShader" => {
+            let shader: GLuint = args.value(0);
+            let Some(pEntryPoint) = args.ptr::<GLchar>(1) else {
+                return Outcome::MissingClientMemory(1);
+            };
+            let numSpecializationConstants: GLuint = args.value(2);
+            let Some(pConstantIndex) = args.ptr::<GLuint>(3) else {
+                return Outcome::MissingClientMemory(3);
+            };
+            let Some(pConstantValue) = args.ptr::<GLuint>(4) else {
+                return Outcome::MissingClientMemory(4);
+            };
+            run::<(), _, _, _>(ctx, |mut state| unsafe {
+                state.oxidegl_specialize_shader(
+                    shader,
+                    pEntryPoint,
+                    numSpecializationConstants,
+                    pConstantIndex,
+                    pConstantValue,
+                )
+            })
+        }
+        "glMultiDrawArraysIndirectCount" => {
+            let mode: GLenum = args.value(0);
+            let indirect: *const GLvoid = args.offset_ptr(1);
+            let drawcount: GLintptr = args.value(2);
+            let maxdrawcount: GLsizei = args.value(3);
+            let stride: GLsizei = args.value(4);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_multi_draw_arrays_indirect_count(
+                        mode.try_into_enum()?,
+                        indirect,
+                        drawcount,
+                        maxdrawcount,
+                        stride,
+                    )
+                })
+            })
+        }
+        "glMultiDrawElementsIndirectCount" => {
+            let mode: GLenum = args.value(0);
+            let r#type: GLenum = args.value(1);
+            let indirect: *const GLvoid = args.offset_ptr(2);
+            let drawcount: GLintptr = args.value(3);
+            let maxdrawcount: GLsizei = args.value(4);
+            let stride: GLsizei = args.value(5);
+            run::<(), _, _, _>(ctx, |mut state| {
+                GlResult::normalize(unsafe {
+                    state.oxidegl_multi_draw_elements_indirect_count(
+                        mode.try_into_enum()?,
+                        r#type.try_into_enum()?,
+                        indirect,
+                        drawcount,
+                        maxdrawcount,
+                        stride,
+                    )
+                })
+            })
+        }
+        "glPolygonOffsetClamp" => {
+            let factor: GLfloat = args.value(0);
+            let units: GLfloat = args.value(1);
+            let clamp: GLfloat = args.value(2);
+            run::<(), _, _, _>(ctx, |mut state| {
+                state.oxidegl_polygon_offset_clamp(factor, units, clamp)
+            })
+        }
+        _ => Outcome::Unknown,
+    }
+}
@@ -0,0 +1,445 @@
+//! Replay of GL call traces captured by [`oxidegl_c::trace`] against a headless or software [`Context`]
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    ffi::{c_void, CStr},
+    fs,
+    path::Path,
+    pin::Pin,
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use oxidegl::{
+    commands::unimplemented::UNIMPLEMENTED_COMMANDS,
+    context::Context,
+    error::GlResult,
+    gl_enums::{DebugSeverity, DebugSource, DebugType, ErrorCode},
+    gl_types::{GLchar, GLenum, GLsizei, GLsync, GLuint, GLDEBUGPROC},
+};
+use oxidegl_c::trace::{TRACE_FORMAT_VERSION, TRACE_MAGIC};
+
+#[allow(
+    non_snake_case,
+    unused_mut,
+    unused_unsafe,
+    clippy::unit_arg,
+    clippy::missing_safety_doc,
+    clippy::too_many_lines
+)]
+mod dispatch;
+
+/// Size of the scratch memory handed to each output pointer argument of a replayed call
+const SCRATCH_WORDS_PER_ARG: usize = 1 << 17;
+/// Upper bound on the number of output pointer arguments of a single GL command
+const MAX_OUT_ARGS: usize = 16;
+
+/// A single call record read from a trace
+#[derive(Debug)]
+pub struct Call {
+    pub name: String,
+    pub args: Vec<u64>,
+    /// client memory read from pointer arguments, as (argument index, bytes)
+    pub blobs: Vec<(usize, Vec<u8>)>,
+}
+
+/// Parse a trace file into its call records
+pub fn read_trace(path: impl AsRef<Path>) -> Result<Vec<Call>> {
+    let data = fs::read(path)?;
+    let mut r = Reader(&data);
+    ensure!(r.take(8)? == TRACE_MAGIC, "not an OxideGL trace file");
+    let version = u32::from_le_bytes(r.array()?);
+    ensure!(
+        version == TRACE_FORMAT_VERSION,
+        "unsupported trace format version {version} (expected {TRACE_FORMAT_VERSION})"
+    );
+    let mut calls = Vec::new();
+    while !r.0.is_empty() {
+        let name_len = u16::from_le_bytes(r.array()?) as usize;
+        let name = String::from_utf8(r.take(name_len)?.to_vec())?;
+        let arg_count = r.array::<1>()?[0] as usize;
+        let args = (0..arg_count)
+            .map(|_| r.array().map(u64::from_le_bytes))
+            .collect::<Result<_>>()?;
+        let blob_count = u16::from_le_bytes(r.array()?) as usize;
+        let blobs = (0..blob_count)
+            .map(|_| {
+                let arg = r.array::<1>()?[0] as usize;
+                let len = u64::from_le_bytes(r.array()?) as usize;
+                Ok((arg, r.take(len)?.to_vec()))
+            })
+            .collect::<Result<_>>()?;
+        calls.push(Call { name, args, blobs });
+    }
+    Ok(calls)
+}
+struct Reader<'a>(&'a [u8]);
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.0.len() < n {
+            bail!("unexpected end of trace file");
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Ok(head)
+    }
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+}
+
+/// Conversion of a raw trace argument back into its C ABI type
+pub(crate) trait FromTraceBits {
+    fn from_trace_bits(bits: u64) -> Self;
+}
+macro_rules! impl_from_trace_bits {
+    ($($t:ty),*) => {
+        $(impl FromTraceBits for $t {
+            fn from_trace_bits(bits: u64) -> Self {
+                bits as $t
+            }
+        })*
+    };
+}
+impl_from_trace_bits!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl FromTraceBits for bool {
+    fn from_trace_bits(bits: u64) -> Self {
+        bits != 0
+    }
+}
+impl FromTraceBits for f32 {
+    fn from_trace_bits(bits: u64) -> Self {
+        f32::from_bits(bits as u32)
+    }
+}
+impl FromTraceBits for f64 {
+    fn from_trace_bits(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+}
+// Function pointers from the traced process are meaningless here, so they are replayed as null
+impl FromTraceBits for GLDEBUGPROC {
+    fn from_trace_bits(_bits: u64) -> Self {
+        None
+    }
+}
+impl FromTraceBits for GLsync {
+    fn from_trace_bits(_bits: u64) -> Self {
+        None
+    }
+}
+
+/// Arguments of a call being replayed, with pointer arguments redirected to replay-owned memory
+pub(crate) struct CallArgs<'a> {
+    call: &'a Call,
+    /// NUL-terminated copies of the blobs of the call, so recorded strings can be passed without a length
+    blobs: Vec<(usize, Box<[u8]>)>,
+    /// pointer tables for arguments that point to an array of pointers
+    tables: Vec<(usize, Box<[*const u8]>)>,
+    scratch: *mut u64,
+}
+impl<'a> CallArgs<'a> {
+    fn new(call: &'a Call, scratch: &mut [u64]) -> Self {
+        let blobs: Vec<(usize, Box<[u8]>)> = call
+            .blobs
+            .iter()
+            .map(|(arg, bytes)| {
+                let mut bytes = bytes.clone();
+                bytes.push(0);
+                (*arg, bytes.into_boxed_slice())
+            })
+            .collect();
+        let mut tables: Vec<(usize, Box<[*const u8]>)> = Vec::new();
+        for (arg, bytes) in &blobs {
+            match tables.iter_mut().find(|(a, _)| a == arg) {
+                Some((_, t)) => *t = t.iter().copied().chain([bytes.as_ptr()]).collect(),
+                None => tables.push((*arg, Box::new([bytes.as_ptr()]))),
+            }
+        }
+        Self {
+            call,
+            blobs,
+            tables,
+            scratch: scratch.as_mut_ptr(),
+        }
+    }
+    fn raw(&self, arg: usize) -> u64 {
+        self.call.args.get(arg).copied().unwrap_or(0)
+    }
+    /// A non-pointer argument
+    pub(crate) fn value<T: FromTraceBits>(&self, arg: usize) -> T {
+        T::from_trace_bits(self.raw(arg))
+    }
+    /// An input pointer argument, pointing to the recorded client memory. `None` if the pointer was not null but its
+    /// client memory wasn't recorded, since the address is meaningless outside of the traced process
+    pub(crate) fn ptr<T>(&self, arg: usize) -> Option<*const T> {
+        match self.blobs.iter().find(|(a, _)| *a == arg) {
+            Some((_, b)) => Some(b.as_ptr().cast()),
+            None => (self.raw(arg) == 0).then(std::ptr::null),
+        }
+    }
+    /// An input argument that points to an array of pointers, one per recorded blob. `None` like [`CallArgs::ptr`]
+    pub(crate) fn ptr_array<T>(&self, arg: usize) -> Option<*const T> {
+        match self.tables.iter().find(|(a, _)| *a == arg) {
+            Some((_, t)) => Some(t.as_ptr().cast()),
+            None => (self.raw(arg) == 0).then(std::ptr::null),
+        }
+    }
+    /// An input pointer argument that may be an offset into a bound buffer (e.g. the indices of `glDrawElements`).
+    /// Points to the recorded client memory if there is any, otherwise the recorded offset is passed through unchanged
+    pub(crate) fn offset_ptr<T>(&self, arg: usize) -> *const T {
+        self.ptr(arg)
+            .unwrap_or(std::ptr::without_provenance(self.raw(arg) as usize))
+    }
+    /// An output pointer argument. Points to scratch memory owned by the replayer if it was not null
+    pub(crate) fn out_ptr<T>(&self, arg: usize) -> *mut T {
+        if self.raw(arg) == 0 || arg >= MAX_OUT_ARGS {
+            return std::ptr::null_mut();
+        }
+        // Safety: scratch holds MAX_OUT_ARGS regions of SCRATCH_WORDS_PER_ARG words
+        unsafe { self.scratch.add(arg * SCRATCH_WORDS_PER_ARG) }.cast()
+    }
+}
+
+/// Result of dispatching a single call
+pub(crate) enum Outcome {
+    Ok,
+    Error(ErrorCode),
+    Unknown,
+    /// The client memory referenced by the pointer argument with this index wasn't recorded, so the call was not run
+    MissingClientMemory(usize),
+}
+
+/// Run a GL command against `ctx`, setting the GL error on failure like the C entry points do
+pub(crate) fn run<Ret, Err, Res, F>(ctx: &mut Context, f: F) -> Outcome
+where
+    Err: Into<ErrorCode>,
+    Res: GlResult<Ret, Err>,
+    F: FnOnce(Pin<&mut Context>) -> Res,
+{
+    match f(Pin::new(&mut *ctx)).into_result() {
+        Ok(_) => Outcome::Ok,
+        Err(e) => {
+            let e: ErrorCode = e.into();
+            ctx.set_error(e);
+            Outcome::Error(e)
+        }
+    }
+}
+
+thread_local! {
+    static DEBUG_MESSAGES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+unsafe extern "C" fn debug_callback(
+    source: GLenum,
+    typ: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    _length: GLsizei,
+    message: *const GLchar,
+    _user_param: *const c_void,
+) {
+    // Safety: OxideGL passes a valid, NUL-terminated message
+    let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+    fn enum_name(v: Option<impl std::fmt::Debug>, raw: GLenum) -> String {
+        v.map_or_else(|| format!("{raw:#x}"), |v| format!("{v:?}"))
+    }
+    let msg = format!(
+        "{} {} {} ({id}): {message}",
+        enum_name(DebugSeverity::from_repr(severity), severity),
+        enum_name(DebugSource::from_repr(source), source),
+        enum_name(DebugType::from_repr(typ), typ)
+    );
+    DEBUG_MESSAGES.with_borrow_mut(|m| m.push(msg));
+}
+
+/// Which backend replayed contexts render with
+#[derive(clap::ValueEnum, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum ReplayBackend {
+    /// Record rendering work without executing it
+    Headless,
+    /// Render on the CPU with the reference rasterizer
+    Software,
+}
+
+/// Summary of a finished replay
+#[derive(Debug, Default)]
+pub struct ReplayReport {
+    /// (call index, command, error) for every call that raised a GL error
+    pub errors: Vec<(usize, String, ErrorCode)>,
+    /// (call index, command, message) for every debug message emitted during the replay
+    pub debug_messages: Vec<(usize, String, String)>,
+    /// number of calls to commands OxideGL does not implement yet, which were skipped
+    pub skipped: BTreeMap<String, usize>,
+    /// (call index, command, argument index) for every call that was skipped because the client memory referenced by a
+    /// pointer argument wasn't recorded
+    pub missing_client_memory: Vec<(usize, String, usize)>,
+    /// Debug representation of the GL state of every context alive at the end of the trace
+    pub final_states: Vec<String>,
+}
+
+/// Replay a sequence of calls, creating contexts with the given backend and default framebuffer size
+pub fn replay(calls: &[Call], backend: ReplayBackend, dims: (u32, u32)) -> Result<ReplayReport> {
+    let mut report = ReplayReport::default();
+    let mut contexts: HashMap<u64, Box<Context>> = HashMap::new();
+    let mut current: Option<u64> = None;
+    let mut scratch = vec![0u64; SCRATCH_WORDS_PER_ARG * MAX_OUT_ARGS];
+
+    for (idx, call) in calls.iter().enumerate() {
+        let name = call.name.as_str();
+        match name {
+            "oxidegl_create_context" => {
+                let mut ctx = Box::new(match backend {
+                    ReplayBackend::Headless => Context::new_headless(dims.0, dims.1),
+                    ReplayBackend::Software => Context::new_software(dims.0, dims.1),
+                });
+                // collect debug messages for the report instead of forwarding them to the traced process' callback.
+                // The debug state of the current context lives in a thread local, so it has to be swapped out first
+                let mut prev = current.and_then(|c| contexts.get_mut(&c));
+                if let Some(prev) = prev.as_mut() {
+                    prev.made_not_current();
+                }
+                ctx.made_current();
+                // Safety: debug_callback has the signature of a GLDEBUGPROC
+                unsafe {
+                    ctx.oxidegl_debug_message_callback(Some(debug_callback), std::ptr::null())
+                };
+                ctx.made_not_current();
+                if let Some(prev) = prev {
+                    prev.made_current();
+                }
+                contexts.insert(call.args[0], ctx);
+                continue;
+            }
+            "oxidegl_set_current_context" => {
+                let next = Some(call.args[0]).filter(|&c| c != 0);
+                if next != current {
+                    if let Some(prev) = current.and_then(|c| contexts.get_mut(&c)) {
+                        prev.made_not_current();
+                    }
+                    if let Some(c) = next {
+                        contexts
+                            .get_mut(&c)
+                            .ok_or_else(|| {
+                                anyhow!("call {idx}: unknown context {c:#x} made current")
+                            })?
+                            .made_current();
+                    }
+                    current = next;
+                }
+                continue;
+            }
+            "oxidegl_destroy_context" => {
+                if current == Some(call.args[0]) {
+                    current = None;
+                }
+                contexts.remove(&call.args[0]);
+                continue;
+            }
+            // the replayer owns the debug callback of every context
+            "glDebugMessageCallback" => continue,
+            _ => {}
+        }
+        let Some(ctx) = current.and_then(|c| contexts.get_mut(&c)) else {
+            bail!("call {idx}: {name} called without a current context");
+        };
+        if name == "oxidegl_swap_buffers" {
+            ctx.swap_buffers();
+            continue;
+        }
+        if UNIMPLEMENTED_COMMANDS.contains(&name) {
+            *report.skipped.entry(call.name.clone()).or_default() += 1;
+            continue;
+        }
+        let args = CallArgs::new(call, &mut scratch);
+        let outcome = match name {
+            // extension commands aren't covered by the generated dispatch
+            "glMaxShaderCompilerThreadsKHR" | "glMaxShaderCompilerThreadsARB" => {
+                let count: GLuint = args.value(0);
+                run::<(), _, _, _>(ctx, |mut state| {
+                    state.oxidegl_max_shader_compiler_threads(count);
+                })
+            }
+            // Safety: the arguments were recorded from a valid call to this command, input client memory was recorded
+            // (calls missing it are not run) and output pointers are substituted with scratch memory
+            _ => unsafe { dispatch::dispatch(ctx, name, &args) },
+        };
+        match outcome {
+            Outcome::Ok => {}
+            Outcome::Error(e) => report.errors.push((idx, call.name.clone(), e)),
+            Outcome::Unknown => bail!("call {idx}: unknown command {name}"),
+            Outcome::MissingClientMemory(arg) => {
+                report
+                    .missing_client_memory
+                    .push((idx, call.name.clone(), arg));
+            }
+        }
+        for msg in DEBUG_MESSAGES.take() {
+            report.debug_messages.push((idx, call.name.clone(), msg));
+        }
+    }
+    let mut live: Vec<_> = contexts.into_iter().collect();
+    live.sort_by_key(|(k, _)| *k);
+    report.final_states = live
+        .into_iter()
+        .map(|(k, ctx)| format!("context {k:#x}: {:#?}", ctx.gl_state()))
+        .collect();
+    Ok(report)
+}
+
+#[test]
+fn replay_client_memory() {
+    // serialize records the same way the tracer does
+    let mut data = TRACE_MAGIC.to_vec();
+    data.extend(TRACE_FORMAT_VERSION.to_le_bytes());
+    let mut record = |name: &str, args: &[u64], blobs: &[(u8, &[u8])]| {
+        data.extend((name.len() as u16).to_le_bytes());
+        data.extend(name.as_bytes());
+        data.push(args.len() as u8);
+        for a in args {
+            data.extend(a.to_le_bytes());
+        }
+        data.extend((blobs.len() as u16).to_le_bytes());
+        for (arg, bytes) in blobs {
+            data.push(*arg);
+            data.extend((bytes.len() as u64).to_le_bytes());
+            data.extend(*bytes);
+        }
+    };
+    let ctx = 0x1000;
+    record("oxidegl_create_context", &[ctx], &[]);
+    record("oxidegl_set_current_context", &[ctx], &[]);
+    // output pointers are redirected to scratch memory, the address is never used
+    record("glCreateBuffers", &[3, 0xdead_0000], &[]);
+    record(
+        "glNamedBufferStorage",
+        &[1, 4, 0xdead_0000, 0],
+        &[(2, &[1, 2, 3, 4])],
+    );
+    // non-null pointer without recorded client memory
+    record("glNamedBufferStorage", &[2, 4, 0xdead_0000, 0], &[]);
+    // null pointers are passed through
+    record("glNamedBufferStorage", &[3, 4, 0, 0], &[]);
+    record("glScissor", &[0, 0, 1, 1], &[]);
+    // negative width (-1)
+    record("glScissor", &[0, 0, u64::from(u32::MAX), 1], &[]);
+
+    let path =
+        std::env::temp_dir().join(format!("oxidegl_replay_test_{}.trace", std::process::id()));
+    fs::write(&path, &data).unwrap();
+    let calls = read_trace(&path);
+    fs::remove_file(&path).unwrap();
+    let calls = calls.unwrap();
+    assert_eq!(calls.len(), 8);
+    assert_eq!(calls[3].blobs, [(2, vec![1, 2, 3, 4])]);
+
+    let report = replay(&calls, ReplayBackend::Headless, (16, 16)).unwrap();
+    assert_eq!(
+        report.errors,
+        [(7, "glScissor".to_owned(), ErrorCode::InvalidValue)]
+    );
+    assert_eq!(
+        report.missing_client_memory,
+        [(4, "glNamedBufferStorage".to_owned(), 2)]
+    );
+    assert!(report.skipped.is_empty(), "{:?}", report.skipped);
+}
@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use oxidegl_replay::{read_trace, replay, ReplayBackend};
+
+/// Replay a GL call trace captured with OXIDEGL_TRACE and report the resulting errors, debug messages and state
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// Path to the trace file
+    trace: PathBuf,
+    /// Backend to create the replayed contexts with
+    #[arg(short, long, value_enum, default_value_t = ReplayBackend::Headless)]
+    backend: ReplayBackend,
+    /// Width of the default framebuffer of replayed contexts
+    #[arg(long, default_value_t = 800)]
+    width: u32,
+    /// Height of the default framebuffer of replayed contexts
+    #[arg(long, default_value_t = 600)]
+    height: u32,
+    /// Fail if the replay raises any GL errors (useful when replaying a trace as a regression test)
+    #[arg(short, long, default_value_t = false)]
+    deny_errors: bool,
+    /// Don't print the final GL state of each context
+    #[arg(short, long, default_value_t = false)]
+    quiet: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let calls = read_trace(&args.trace)?;
+    println!(
+        "replaying {} calls from {}",
+        calls.len(),
+        args.trace.display()
+    );
+    let report = replay(&calls, args.backend, (args.width, args.height))?;
+    for (idx, cmd, msg) in &report.debug_messages {
+        println!("[{idx}] {cmd}: debug message: {msg}");
+    }
+    for (idx, cmd, err) in &report.errors {
+        println!("[{idx}] {cmd}: GL error {err:?}");
+    }
+    for (cmd, count) in &report.skipped {
+        println!("skipped {count} call(s) to unimplemented command {cmd}");
+    }
+    for (idx, cmd, arg) in &report.missing_client_memory {
+        println!("[{idx}] {cmd}: skipped, client memory of argument {arg} was not recorded");
+    }
+    if !args.quiet {
+        for state in &report.final_states {
+            println!("{state}");
+        }
+    }
+    println!(
+        "replay finished with {} GL error(s) and {} debug message(s)",
+        report.errors.len(),
+        report.debug_messages.len()
+    );
+    if args.deny_errors && !report.errors.is_empty() {
+        bail!("replay raised GL errors");
+    }
+    Ok(())
+}
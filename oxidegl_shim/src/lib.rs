@@ -1,3 +1,4 @@
+#![cfg(target_vendor = "apple")]
 use core_foundation_sys::{
     base::CFEqual,
     bundle::{CFBundleGetFunctionPointerForName, CFBundleGetIdentifier, CFBundleRef},
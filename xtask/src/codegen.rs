@@ -45,6 +45,8 @@ pub struct Parameter<'a> {
     pub name: &'a str,
     pub parameter_type: GLTypes,
     pub group: String,
+    /// `len` attribute of the parameter, describing the length of the array it points to
+    pub len: Option<&'a str>,
 }
 
 #[derive(Clone, Debug)]
@@ -92,6 +94,7 @@ fn get_all_entries<'a>(reg: &'a Document<'a>) -> HashMap<&'a str, GLAPIEntry<'a>
                                         .trim()
                                         .trim_end_matches("ARB")
                                         .to_string(),
+                                    len: child.attribute("len"),
                                 });
                             }
                             _ => {}
@@ -522,6 +525,71 @@ pub fn write_dispatch_impl<T: Write>(w: &mut T, v: &[FnCollection<'_>]) -> Resul
     }
     Ok(())
 }
+pub fn write_replay_impl<T: Write>(w: &mut T, v: &[FnCollection<'_>]) -> Result<()> {
+    const REPLAY_PREFIX: &str = r"
+        // GENERATED CODE. DO NOT MODIFY
+        // GL Command Trace Replay Dispatch
+
+        use super::{run, CallArgs, Outcome};
+        use oxidegl::context::Context;
+        use oxidegl::conversions::GLenumExt;
+        use oxidegl::error::GlResult;
+        use oxidegl::gl_types::*;
+
+        pub(super) unsafe fn dispatch(ctx: &mut Context, name: &str, args: &CallArgs) -> Outcome {
+            match name {
+    ";
+    writeln!(w, "{REPLAY_PREFIX}")?;
+    for item in v {
+        for cmd in item.entries.iter() {
+            let GLAPIEntry::Command {
+                return_type,
+                name,
+                params,
+            } = cmd
+            else {
+                continue;
+            };
+            writeln!(w, "{}", print_replay_arm(name, return_type, params))?;
+        }
+    }
+    writeln!(w, "_ => Outcome::Unknown,\n}}\n}}")?;
+    Ok(())
+}
+pub fn write_pointer_lengths_impl<T: Write>(w: &mut T, v: &[FnCollection<'_>]) -> Result<()> {
+    const POINTER_LENGTHS_PREFIX: &str = r"
+        // GENERATED CODE. DO NOT MODIFY
+        // GL Command Pointer Argument Lengths
+
+        use super::PointerLen::{self, Array, Fixed, NulTerminated, Strings};
+
+        /// Client memory referenced by the input pointer arguments of a command as (argument index, length), derived
+        /// from the `len` attributes of gl.xml. Arguments whose length depends on the value of another argument in a way
+        /// gl.xml doesn't describe (`COMPSIZE`) are missing
+        pub(super) fn pointer_lengths(name: &str) -> &'static [(usize, PointerLen)] {
+            match name {
+    ";
+    writeln!(w, "{POINTER_LENGTHS_PREFIX}")?;
+    for item in v {
+        for cmd in item.entries.iter() {
+            let GLAPIEntry::Command { name, params, .. } = cmd else {
+                continue;
+            };
+            let lens = params
+                .iter()
+                .enumerate()
+                .filter_map(|(i, p)| {
+                    Some(format!("({i}, {})", print_pointer_len(name, p, params)?))
+                })
+                .collect::<Vec<_>>();
+            if !lens.is_empty() {
+                writeln!(w, "\"{name}\" => &[{}],", lens.join(", "))?;
+            }
+        }
+    }
+    writeln!(w, "_ => &[],\n}}\n}}")?;
+    Ok(())
+}
 pub fn write_enum_impl<T: Write>(
     w: &mut T,
     v: &[GLAPIEntry<'_>],
@@ -614,6 +682,27 @@ pub fn write_placeholder_impl<T: Write>(w: &mut T, v: &[FnCollection<'_>]) -> Re
         use crate::error::GlFallible;
         use crate::gl_types::*;";
     writeln!(w, "{PLACEHOLDER_PREFIX} use {ENUMS_PATH}{{{enum_uses}}};\n")?;
+    // in the same order as the placeholders below: commands documented together first, then individual ones
+    let (grouped, individual): (Vec<_>, Vec<_>) = v
+        .iter()
+        .filter(|i| !i.entries.is_empty())
+        .partition(|i| i.entries.len() > 1);
+    let stubbed = grouped
+        .iter()
+        .chain(&individual)
+        .flat_map(|i| i.entries.iter())
+        .filter_map(|e| match e {
+            GLAPIEntry::Command { name, .. } => Some(format!("{name:?}")),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    writeln!(
+        w,
+        "/// GL commands whose method on [`{CONTEXT_STRUCT_NAME}`] is a placeholder that panics, so that callers which can't
+        /// abort (e.g. the trace replayer) can avoid them
+        pub const UNIMPLEMENTED_COMMANDS: &[&str] = &[{stubbed}];\n"
+    )?;
     for item in v {
         match item.entries.len() {
             0 => continue,
@@ -730,7 +819,9 @@ fn sanitize_ident(unsanitized: &str) -> &str {
         _ => unsanitized,
     }
 }
-fn print_dispatch_fn<'a>(name: &'a str, ret_type: GLTypes, params: &[Parameter<'a>]) -> String {
+/// Closure that runs the given command against a `Pin<&mut Context>`, with GLenum parameters converted to their
+/// wrapper enums
+fn print_dispatch_closure(name: &str, params: &[Parameter<'_>]) -> String {
     let is_unsafe = params.iter().any(|p| p.parameter_type.is_pointer());
     let mut shim_is_fallible: bool = false;
     let paramnl = params
@@ -749,7 +840,21 @@ fn print_dispatch_fn<'a>(name: &'a str, ret_type: GLTypes, params: &[Parameter<'
         .collect::<Vec<String>>()
         .join("");
     let snake_case_name = snake_case_from_title_case(name);
-
+    format!(
+        "|mut state|{}{} state.oxide{}({}){}{}",
+        if shim_is_fallible {
+            "GlResult::normalize("
+        } else {
+            ""
+        },
+        if is_unsafe { " unsafe {" } else { "" },
+        snake_case_name,
+        paramnl,
+        if is_unsafe { " }" } else { "" },
+        if shim_is_fallible { ")" } else { "" }
+    )
+}
+fn print_dispatch_fn<'a>(name: &'a str, ret_type: GLTypes, params: &[Parameter<'a>]) -> String {
     let params_trace = params
         .iter()
         .map(|p| format!("{}: {{:?}}", sanitize_ident(p.name)))
@@ -764,17 +869,9 @@ fn print_dispatch_fn<'a>(name: &'a str, ret_type: GLTypes, params: &[Parameter<'
     let body = format!(
         "{{\n
             ::log::trace!(\"{name} called, parameters: {params_trace} \", {params_string});
-            with_ctx_mut(|mut state|{}{} state.oxide{}({}){}{})\n}}",
-        if shim_is_fallible {
-            "GlResult::normalize("
-        } else {
-            ""
-        },
-        if is_unsafe { " unsafe {" } else { "" },
-        snake_case_name,
-        paramnl,
-        if is_unsafe { " }" } else { "" },
-        if shim_is_fallible { ")" } else { "" }
+            crate::trace::trace_call!({name}, {params_string});
+            with_ctx_mut({})\n}}",
+        print_dispatch_closure(name, params)
     );
 
     if params.is_empty() {
@@ -813,6 +910,99 @@ fn print_dispatch_fn<'a>(name: &'a str, ret_type: GLTypes, params: &[Parameter<'
         body
     )
 }
+/// Whether the pointer parameter `param` of `command` can be an offset into a bound buffer rather than client memory, in
+/// which case its recorded value is meaningful on its own
+fn is_buffer_offset(command: &str, param: &str) -> bool {
+    match param {
+        // glDrawElements* and glDrawRangeElements* with a bound element array buffer
+        "indices" => command.starts_with("glDraw"),
+        // glDraw*Indirect and glMultiDraw*Indirect with a bound draw indirect buffer
+        "indirect" => true,
+        // glVertexAttrib*Pointer with a bound array buffer
+        "pointer" => command.starts_with("glVertexAttrib"),
+        _ => false,
+    }
+}
+/// Match arm that decodes the recorded arguments of a call and runs it against the replay context
+fn print_replay_arm(name: &str, ret_type: &GLTypes, params: &[Parameter<'_>]) -> String {
+    let lets = params
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let mut ty = p.parameter_type.clone();
+            if let GLTypes::EnumWrapped(_) = ty.get_pointee_or_self() {
+                ty.replace_pointee_or_self(GLTypes::GLenum);
+            }
+            let ident = sanitize_ident(p.name);
+            // input pointers that aren't buffer offsets can only be replayed if their client memory was captured
+            let client_memory = |accessor: &str, pointee: &GLTypes| {
+                format!(
+                    "let Some({ident}) = args.{accessor}::<{}>({i}) else {{ return Outcome::MissingClientMemory({i}); }};\n",
+                    pointee.rust_type()
+                )
+            };
+            match &ty {
+                GLTypes::PtrTo(_) => format!("let {ident}: {} = args.out_ptr({i});\n", ty.rust_type()),
+                GLTypes::ConstPtrTo(pointee) if pointee.is_pointer() => {
+                    client_memory("ptr_array", pointee)
+                }
+                GLTypes::ConstPtrTo(_) if is_buffer_offset(name, p.name) => {
+                    format!("let {ident}: {} = args.offset_ptr({i});\n", ty.rust_type())
+                }
+                GLTypes::ConstPtrTo(pointee) => client_memory("ptr", pointee),
+                _ => format!("let {ident}: {} = args.value({i});\n", ty.rust_type()),
+            }
+        })
+        .collect::<String>();
+    let ret = match ret_type {
+        GLTypes::GLvoid => "()".to_owned(),
+        t => t.rust_type(),
+    };
+    format!(
+        "\"{name}\" => {{\n{lets}run::<{ret}, _, _, _>(ctx, {})\n}}",
+        print_dispatch_closure(name, params)
+    )
+}
+/// Length of the client memory referenced by the input pointer parameter `param` of `command`, derived from its `len`
+/// attribute. `None` if it isn't an input pointer or its length can't be derived from the other arguments
+fn print_pointer_len(
+    command: &str,
+    param: &Parameter<'_>,
+    params: &[Parameter<'_>],
+) -> Option<String> {
+    let GLTypes::ConstPtrTo(pointee) = &param.parameter_type else {
+        return None;
+    };
+    if is_buffer_offset(command, param.name) {
+        return None;
+    }
+    let len = param.len.unwrap_or("");
+    let arg = |name: &str| params.iter().position(|p| p.name == name);
+    match &**pointee {
+        // arrays of strings, e.g. the sources passed to glShaderSource
+        GLTypes::ConstPtrTo(_) => Some(format!("Strings({})", arg(len)?)),
+        // strings without a length are NUL-terminated
+        GLTypes::GLchar if len.is_empty() || len == format!("COMPSIZE({})", param.name) => {
+            Some("NulTerminated".to_owned())
+        }
+        pointee => {
+            let size = pointee.c_size()?;
+            if let Ok(n) = len.parse::<usize>() {
+                return Some(format!("Fixed({})", n * size));
+            }
+            // e.g. count*4 for the value of glUniform4fv
+            let (count, factor) = match len.split_once('*') {
+                Some((count, factor)) => (count, factor.parse::<usize>().ok()?),
+                None => (len, 1),
+            };
+            Some(format!(
+                "Array {{ count: {}, stride: {} }}",
+                arg(count)?,
+                factor * size
+            ))
+        }
+    }
+}
 fn print_enum_group_enum<'a>(
     w: &mut impl Write,
     name: &'a str,
@@ -1050,6 +1240,24 @@ impl GLTypes {
         let tail = ret_node.tail().unwrap_or("");
         Self::from_c_type_str_prefix_suffix(prefix, body, tail)
     }
+    /// Size of the C type in bytes (1 for void, so that untyped pointers are measured in bytes)
+    fn c_size(&self) -> Option<usize> {
+        Some(match self {
+            Self::GLvoid | Self::GLchar | Self::GLboolean | Self::GLubyte | Self::GLbyte => 1,
+            Self::GLshort | Self::GLushort => 2,
+            Self::GLint
+            | Self::GLuint
+            | Self::GLfloat
+            | Self::GLsizei
+            | Self::GLenum
+            | Self::GLbitfield
+            | Self::EnumWrapped(_) => 4,
+            Self::GLdouble | Self::GLint64 | Self::GLuint64 | Self::GLintptr | Self::GLsizeiptr => {
+                8
+            }
+            _ => return None,
+        })
+    }
     fn is_pointer(&self) -> bool {
         matches!(self, Self::ConstPtrTo(_) | Self::PtrTo(_))
     }
@@ -11,7 +11,10 @@ use dashmap::DashSet;
 use enum_dispatch::enum_dispatch;
 
 use crate::{
-    codegen::{get_vals, write_dispatch_impl, write_enum_impl, write_placeholder_impl},
+    codegen::{
+        get_vals, write_dispatch_impl, write_enum_impl, write_placeholder_impl,
+        write_pointer_lengths_impl, write_replay_impl,
+    },
     open_file_writer,
 };
 
@@ -336,6 +339,12 @@ pub struct GenerateBindings {
     /// Whether to generate enums (enums.rs)
     #[arg(short, long, default_value_t = false)]
     enums: bool,
+    /// Whether to generate trace replay dispatch (replay_dispatch.rs)
+    #[arg(short, long, default_value_t = false)]
+    replay: bool,
+    /// Whether to generate the lengths of pointer arguments captured in traces (pointer_lengths.rs)
+    #[arg(short = 'l', long, default_value_t = false)]
+    pointer_lengths: bool,
 }
 impl TaskTrait for GenerateBindings {
     fn dependencies(&self) -> Option<Box<[Task]>> {
@@ -373,6 +382,22 @@ impl TaskTrait for GenerateBindings {
             rustfmt_file(path_to_write)?;
             println!("generated enums.rs enums and groups");
         }
+        if self.replay {
+            let path_to_write = out_dir.join("replay_dispatch.rs");
+            let mut writer = open_file_writer(&path_to_write)?;
+            write_replay_impl(&mut writer, &funcs)?;
+            drop(writer);
+            rustfmt_file(path_to_write)?;
+            println!("generated replay_dispatch.rs trace replay dispatch");
+        }
+        if self.pointer_lengths {
+            let path_to_write = out_dir.join("pointer_lengths.rs");
+            let mut writer = open_file_writer(&path_to_write)?;
+            write_pointer_lengths_impl(&mut writer, &funcs)?;
+            drop(writer);
+            rustfmt_file(path_to_write)?;
+            println!("generated pointer_lengths.rs trace pointer argument lengths");
+        }
 
         Ok(())
     }